@@ -54,7 +54,7 @@ impl crate::TestCase for CatRank {
                     .reborrow()
                     .init_url(url_size + url_prefix_length as u32);
 
-                url.push_str(URL_PREFIX);
+                url.push_str(URL_PREFIX).unwrap();
                 for _ in 0..url_size {
                     url.push_ascii((97 + rng.next_less_than(26)) as u8);
                 }
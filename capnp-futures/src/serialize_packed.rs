@@ -412,7 +412,8 @@ where
                                     break;
                                 }
                             }
-                            *run_bytes_remaining = words_in_run * 8;
+                            // The run length has to fit in the count byte.
+                            *run_bytes_remaining = std::cmp::min(words_in_run, 255) * 8;
                             *stage = PackedWriteStage::WriteRunWordCount;
                         } else if packed_buf[0] == 255 {
                             // See how long of a run we can make.
@@ -433,7 +434,7 @@ where
                                     }
                                 }
                             }
-                            *run_bytes_remaining = words_in_run * 8;
+                            *run_bytes_remaining = std::cmp::min(words_in_run, 255) * 8;
                             *stage = PackedWriteStage::WriteRunWordCount;
                         } else {
                             *buf_pos = 0;
@@ -668,6 +669,22 @@ pub mod test {
             ],
             &[0, 2],
         );
+
+        // Runs longer than 255 words are split.
+        check_packing(&[0; 257 * 8], &[0, 255, 0, 0]);
+        check_packing(
+            &[1; 257 * 8],
+            &[
+                [0xff].as_slice(),
+                &[1; 8],
+                &[255],
+                &[1; 255 * 8],
+                &[0xff],
+                &[1; 8],
+                &[0],
+            ]
+            .concat(),
+        );
     }
 
     fn round_trip(
@@ -28,11 +28,17 @@ use capnp::Error;
 
 use crate::serialize::AsOutputSegments;
 
+#[derive(Clone, Copy)]
+enum Encoding {
+    Unpacked,
+    Packed,
+}
+
 enum Item<M>
 where
     M: AsOutputSegments,
 {
-    Message(M, oneshot::Sender<M>),
+    Message(M, oneshot::Sender<M>, Encoding),
     Done(Result<(), Error>, oneshot::Sender<()>),
 }
 /// A handle that allows messages to be sent to a write queue.
@@ -75,8 +81,15 @@ where
         let mut rx_stream = tokio_stream::wrappers::UnboundedReceiverStream::new(rx);
         while let Some(item) = rx_stream.next().await {
             match item {
-                Item::Message(m, returner) => {
-                    let result = crate::serialize::write_message(&mut writer, &m).await;
+                Item::Message(m, returner, encoding) => {
+                    let result = match encoding {
+                        Encoding::Unpacked => {
+                            crate::serialize::write_message(&mut writer, &m).await
+                        }
+                        Encoding::Packed => {
+                            crate::serialize_packed::write_message(&mut writer, &m).await
+                        }
+                    };
                     in_flight.fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
                     result?;
                     writer.flush().await?;
@@ -101,9 +114,23 @@ where
     /// Enqueues a message to be written. The returned future resolves once the write
     /// has completed.
     pub fn send(&mut self, message: M) -> impl Future<Output = Result<M, Error>> + Unpin {
+        self.send_with_encoding(message, Encoding::Unpacked)
+    }
+
+    /// Like `send()`, but writes the message using the
+    /// [packed encoding](https://capnproto.org/encoding.html#packing).
+    pub fn send_packed(&mut self, message: M) -> impl Future<Output = Result<M, Error>> + Unpin {
+        self.send_with_encoding(message, Encoding::Packed)
+    }
+
+    fn send_with_encoding(
+        &mut self,
+        message: M,
+        encoding: Encoding,
+    ) -> impl Future<Output = Result<M, Error>> + Unpin {
         let (complete, oneshot) = oneshot::channel();
 
-        let _ = self.sender.send(Item::Message(message, complete));
+        let _ = self.sender.send(Item::Message(message, complete, encoding));
 
         oneshot.map_err(|_| Error::disconnected("WriteQueue has terminated".into()))
     }
@@ -60,27 +60,8 @@ where
 
     let manifest: [PathBuf; 1] = [PathBuf::from_str(&std::env::var("CARGO_MANIFEST_DIR")?)?];
 
-    let globs = path_patterns.into_iter().flat_map(|s| {
-        let is_absolute = s.as_ref().starts_with('/');
-        let closure = move |dir| -> Result<Walk<'static>, BuildError<'static>> {
-            wax::walk(s.as_ref().strip_prefix('/').unwrap_or(s.as_ref()), dir)
-                .map_err(BuildError::into_owned)
-                .map(Walk::into_owned)
-        };
-        if is_absolute {
-            searchpaths.iter().map(closure)
-        } else {
-            manifest.iter().map(closure)
-        }
-    });
-
-    for entry_result in globs {
-        for entry in (entry_result?).flatten() {
-            let path: PathBuf = entry.into_path();
-            if path.is_file() {
-                cmd.file(path);
-            }
-        }
+    for path in discover_files(path_patterns, &manifest, &searchpaths)? {
+        cmd.file(path);
     }
 
     if cmd.file_count() == 0 {
@@ -108,6 +89,50 @@ where
     // When TempDir goes out of scope, it gets deleted
 }
 
+/// Finds the files matching `path_patterns`, relative to `manifest` or, for patterns starting
+/// with `/`, to `searchpaths`. The result is canonicalized, deduplicated and sorted, so that a
+/// file matched by several patterns, or reachable through several directories, is only
+/// compiled once and the order does not depend on the filesystem.
+fn discover_files<I>(
+    path_patterns: I,
+    manifest: &[PathBuf],
+    searchpaths: &[PathBuf],
+) -> Result<Vec<PathBuf>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let globs = path_patterns.into_iter().flat_map(|s| {
+        let is_absolute = s.as_ref().starts_with('/');
+        let closure = move |dir| -> Result<Walk<'static>, BuildError<'static>> {
+            wax::walk(s.as_ref().strip_prefix('/').unwrap_or(s.as_ref()), dir)
+                .map_err(BuildError::into_owned)
+                .map(Walk::into_owned)
+        };
+        if is_absolute {
+            searchpaths.iter().map(closure)
+        } else {
+            manifest.iter().map(closure)
+        }
+    });
+
+    let mut files = Vec::new();
+    for entry_result in globs {
+        for entry in (entry_result?).flatten() {
+            let path: PathBuf = entry.into_path();
+            if path.is_file() {
+                files.push(
+                    path.canonicalize()
+                        .with_context(|| format!("could not canonicalize {path:?}"))?,
+                );
+            }
+        }
+    }
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
 fn append_path(file_path: &Path) -> Result<TokenStream2> {
     let file_stem = file_path
         .file_stem()
@@ -152,6 +177,30 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn discover_files_dedup_test() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let root = dir.path().canonicalize()?;
+        fs::create_dir(root.join("sub"))?;
+        fs::write(root.join("b.capnp"), "")?;
+        fs::write(root.join("sub").join("a.capnp"), "")?;
+        std::os::unix::fs::symlink(root.join("sub"), root.join("link"))?;
+
+        // Both relative patterns match `b.capnp`, and the search path reaches `sub/a.capnp`
+        // through the symlink.
+        let files = discover_files(
+            ["**/*.capnp", "*.capnp", "/*.capnp"],
+            &[root.clone()],
+            &[root.join("link")],
+        )?;
+        assert_eq!(
+            files,
+            [root.join("b.capnp"), root.join("sub").join("a.capnp")]
+        );
+        Ok(())
+    }
+
     #[should_panic]
     #[test]
     fn compile_fail_test() {
@@ -74,6 +74,9 @@ impl RequestHook for Request {
     fn get_brand(&self) -> usize {
         0
     }
+    fn set_deadline(&mut self, _timeout: std::time::Duration) {
+        // The call fails as soon as it is sent.
+    }
     fn send(self: Box<Self>) -> RemotePromise<any_pointer::Owned> {
         let pipeline = Pipeline::new(self.error.clone());
         RemotePromise {
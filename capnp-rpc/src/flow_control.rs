@@ -0,0 +1,108 @@
+// Copyright (c) 2013-2016 Sandstorm Development Group, Inc. and contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Flow control for calls to streaming methods, i.e. methods whose result is declared as
+//! `stream`.
+//!
+//! Like the fixed-window flow controller of the C++ implementation, this lets a stream keep
+//! sending calls without waiting for them to return, as long as the total size of the calls
+//! that have not yet returned fits in a window.
+
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::rc::Rc;
+
+use capnp::capability::Promise;
+use capnp::Error;
+use tokio::sync::Notify;
+
+/// The number of bytes of streaming calls to a single capability that may be in flight before
+/// the promises returned by [`StreamingRequest::send()`](capnp::capability::StreamingRequest::send)
+/// stop resolving.
+pub const STREAM_WINDOW_SIZE: usize = 65536;
+
+/// Tracks the streaming calls in flight to one capability.
+pub(crate) struct FlowController {
+    window_size: usize,
+    in_flight: Cell<usize>,
+
+    /// The first error that a call of the stream returned. Once set, the stream is broken.
+    error: RefCell<Option<Error>>,
+    changed: Notify,
+}
+
+impl FlowController {
+    pub fn new() -> Self {
+        Self {
+            window_size: STREAM_WINDOW_SIZE,
+            in_flight: Cell::new(0),
+            error: RefCell::new(None),
+            changed: Notify::new(),
+        }
+    }
+
+    /// Records a call of `size` bytes as in flight until `ack` completes. Returns a task that
+    /// must be run to observe `ack`, and a promise that resolves once the stream is ready for
+    /// the next call.
+    pub fn send<F>(
+        self: &Rc<Self>,
+        size: usize,
+        ack: F,
+    ) -> (impl Future<Output = Result<(), Error>>, Promise<(), Error>)
+    where
+        F: Future<Output = Result<(), Error>> + 'static,
+    {
+        let this = self.clone();
+        let already_failed = self.error.borrow().is_some();
+        if !already_failed {
+            self.in_flight.set(self.in_flight.get() + size);
+        }
+        let task = async move {
+            let result = ack.await;
+            if !already_failed {
+                this.in_flight.set(this.in_flight.get() - size);
+            }
+            if let Err(e) = result {
+                this.error.borrow_mut().get_or_insert(e);
+            }
+            this.changed.notify_waiters();
+            Ok(())
+        };
+        (task, self.ready())
+    }
+
+    /// Returns a promise that resolves once the calls in flight fit in the window, or that
+    /// rejects with the error of a failed call.
+    pub fn ready(self: &Rc<Self>) -> Promise<(), Error> {
+        let this = self.clone();
+        Promise::from_future(async move {
+            loop {
+                let changed = this.changed.notified();
+                if let Some(e) = &*this.error.borrow() {
+                    return Err(e.clone());
+                }
+                if this.in_flight.get() <= this.window_size {
+                    return Ok(());
+                }
+                changed.await;
+            }
+        })
+    }
+}
@@ -69,10 +69,13 @@ use std::rc::{Rc, Weak};
 use std::task::{Context, Poll};
 use tokio::sync::oneshot;
 
-pub use crate::rpc::Disconnector;
+pub use crate::rpc::{Disconnector, RpcStats, StatsHandle};
 use crate::task_set::TaskSet;
 
-pub use crate::reconnect::{auto_reconnect, lazy_auto_reconnect, SetTarget};
+pub use crate::flow_control::STREAM_WINDOW_SIZE;
+pub use crate::reconnect::{
+    auto_reconnect, auto_reconnect_async, lazy_auto_reconnect, RetryPolicy, SetTarget,
+};
 
 /// Code generated from
 /// [rpc.capnp](https://github.com/capnproto/capnproto/blob/master/c%2B%2B/src/capnp/rpc.capnp).
@@ -100,13 +103,17 @@ macro_rules! pry {
 
 mod attach;
 mod broken;
+mod flow_control;
 mod local;
+pub mod membrane;
 pub mod queued;
 mod reconnect;
 pub mod rpc;
 mod sender_queue;
 mod split;
 mod task_set;
+#[cfg(feature = "test-util")]
+pub mod test_util;
 pub mod twoparty;
 
 use capnp::message;
@@ -214,6 +221,8 @@ where
     // to connection states.
     connection_state: Rc<RefCell<Option<Rc<rpc::ConnectionState<VatId>>>>>,
 
+    table_size_monitor: Rc<RefCell<Option<rpc::TableSizeMonitor>>>,
+
     tasks: TaskSet<Error>,
     handle: crate::task_set::TaskSetHandle<Error>,
 }
@@ -252,6 +261,7 @@ impl<VatId> RpcSystem<VatId> {
             network,
             bootstrap_cap,
             connection_state: Rc::new(RefCell::new(None)),
+            table_size_monitor: Rc::new(RefCell::new(None)),
 
             tasks,
             handle: handle.clone(),
@@ -275,6 +285,7 @@ impl<VatId> RpcSystem<VatId> {
             &self.connection_state,
             self.bootstrap_cap.clone(),
             connection,
+            self.table_size_monitor.clone(),
             self.handle.clone(),
         );
 
@@ -286,9 +297,16 @@ impl<VatId> RpcSystem<VatId> {
     fn accept_loop(&mut self) -> Promise<(), Error> {
         let connection_state_ref = self.connection_state.clone();
         let bootstrap_cap = self.bootstrap_cap.clone();
+        let table_size_monitor = self.table_size_monitor.clone();
         let handle = self.handle.clone();
         Promise::from_future(self.network.accept().map_ok(move |connection| {
-            Self::get_connection_state(&connection_state_ref, bootstrap_cap, connection, handle);
+            Self::get_connection_state(
+                &connection_state_ref,
+                bootstrap_cap,
+                connection,
+                table_size_monitor,
+                handle,
+            );
         }))
     }
 
@@ -300,6 +318,7 @@ impl<VatId> RpcSystem<VatId> {
         connection_state_ref: &Rc<RefCell<Option<Rc<rpc::ConnectionState<VatId>>>>>,
         bootstrap_cap: Box<dyn ClientHook>,
         connection: Box<dyn crate::Connection<VatId>>,
+        table_size_monitor: Rc<RefCell<Option<rpc::TableSizeMonitor>>>,
         mut handle: crate::task_set::TaskSetHandle<Error>,
     ) -> Rc<rpc::ConnectionState<VatId>> {
         // TODO this needs to be updated once we allow more general VatNetworks.
@@ -319,7 +338,12 @@ impl<VatId> RpcSystem<VatId> {
                         Err(e) => Promise::err(Error::failed(format!("{e}"))),
                     }
                 }));
-                rpc::ConnectionState::new(bootstrap_cap, connection, on_disconnect_fulfiller)
+                rpc::ConnectionState::new(
+                    bootstrap_cap,
+                    connection,
+                    on_disconnect_fulfiller,
+                    table_size_monitor,
+                )
             }
         };
         *connection_state_ref.borrow_mut() = Some(result.clone());
@@ -332,6 +356,52 @@ impl<VatId> RpcSystem<VatId> {
     pub fn get_disconnector(&self) -> rpc::Disconnector<VatId> {
         rpc::Disconnector::new(self.connection_state.clone())
     }
+
+    /// Returns the number of entries in each of the connection's RPC tables, or all zeroes if
+    /// there is no connection.
+    pub fn stats(&self) -> RpcStats {
+        self.get_stats_handle().stats()
+    }
+
+    /// Returns a handle that can report `stats()` after this `RpcSystem` has been spawned.
+    pub fn get_stats_handle(&self) -> StatsHandle<VatId> {
+        StatsHandle::new(self.connection_state.clone())
+    }
+
+    /// Arranges for `callback` to be called with a snapshot of the RPC tables whenever one of
+    /// them grows beyond `threshold` entries, which can be a sign of a capability leak. The
+    /// callback runs on the event loop thread while the connection is in the middle of handling
+    /// a message or call, so it should not make calls or drop capabilities itself.
+    pub fn set_table_size_threshold(
+        &mut self,
+        threshold: usize,
+        callback: impl FnMut(RpcStats) + 'static,
+    ) {
+        *self.table_size_monitor.borrow_mut() = Some(rpc::TableSizeMonitor {
+            threshold,
+            callback: Box::new(callback),
+        });
+    }
+
+    /// Returns a promise that gracefully shuts down the connection to this `RpcSystem`'s network.
+    ///
+    /// Once the promise is first polled, new calls are rejected in both directions with
+    /// `ErrorKind::Disconnected`, while calls already in flight are allowed to complete.
+    /// When none remain, or when `timeout` elapses, the connection is closed and any calls
+    /// still outstanding fail with `ErrorKind::Disconnected`. The promise resolves once the
+    /// connection has been torn down.
+    ///
+    /// Like `get_disconnector()`, this should be called before you spawn the `RpcSystem`.
+    pub fn shutdown(&self, timeout: Option<std::time::Duration>) -> Promise<(), Error> {
+        let connection_state = self.connection_state.clone();
+        Promise::from_future(async move {
+            let state = connection_state.borrow().clone();
+            if let Some(state) = state {
+                rpc::ConnectionState::shutdown(&state, timeout).await?;
+            }
+            rpc::Disconnector::new(connection_state).await
+        })
+    }
 }
 
 impl<VatId> Future for RpcSystem<VatId>
@@ -354,6 +424,111 @@ where
     )))
 }
 
+/// Creates a new local RPC client of type `C` out of a server dispatch that may be shared with
+/// other clients, such as one obtained from [`CapabilityServerSet::get_local_server()`]. All
+/// clients made from the same dispatch compare equal and call the same server object.
+pub fn new_client_from_rc<C, S>(
+    dispatch: Rc<<C as capnp::capability::FromServer<S>>::Dispatch>,
+) -> C
+where
+    C: capnp::capability::FromServer<S>,
+{
+    capnp::capability::FromClientHook::new(Box::new(local::Client::from_rc(dispatch)))
+}
+
+/// A server object that can be exported on several connections at once, for example by passing
+/// [`ClientHandle::client()`] as the bootstrap capability of more than one [`RpcSystem`].
+///
+/// The handle keeps count of the references to the server that were made through it, whether
+/// they are held locally or by the export tables of connections on behalf of remote vats, so
+/// that the application can tell when the last of them is gone.
+pub struct ClientHandle<S, C>
+where
+    C: capnp::capability::FromServer<S>,
+{
+    dispatch: Rc<C::Dispatch>,
+    tracker: RefCell<Weak<ReleaseTracker>>,
+}
+
+// Shared by all the clients made from a `ClientHandle` while any of them is alive.
+struct ReleaseTracker {
+    waiters: RefCell<Vec<oneshot::Sender<()>>>,
+}
+
+impl Drop for ReleaseTracker {
+    fn drop(&mut self) {
+        for waiter in self.waiters.get_mut().drain(..) {
+            let _ = waiter.send(());
+        }
+    }
+}
+
+impl<S, C> ClientHandle<S, C>
+where
+    C: capnp::capability::FromServer<S>,
+{
+    pub fn new(s: S) -> Self {
+        Self::from_rc(Rc::new(
+            <C as capnp::capability::FromServer<S>>::from_server(s),
+        ))
+    }
+
+    /// Creates a handle for a server dispatch that may also be referenced by other clients.
+    /// References held by those clients are not counted by the handle.
+    pub fn from_rc(dispatch: Rc<C::Dispatch>) -> Self {
+        Self {
+            dispatch,
+            tracker: RefCell::new(Weak::new()),
+        }
+    }
+
+    /// Returns a new reference to the server, counted by the handle.
+    pub fn client(&self) -> C {
+        let mut tracker = self.tracker.borrow_mut();
+        let guard = match tracker.upgrade() {
+            Some(guard) => guard,
+            None => {
+                let guard = Rc::new(ReleaseTracker {
+                    waiters: RefCell::new(Vec::new()),
+                });
+                *tracker = Rc::downgrade(&guard);
+                guard
+            }
+        };
+        capnp::capability::FromClientHook::new(Box::new(local::Client::with_guard(
+            self.dispatch.clone(),
+            guard,
+        )))
+    }
+
+    /// Returns the server dispatch that the clients made by this handle call.
+    pub fn dispatch(&self) -> &Rc<C::Dispatch> {
+        &self.dispatch
+    }
+
+    /// Returns the number of live references to the server that were made through this handle,
+    /// across all connections that it has been exported on.
+    pub fn strong_count(&self) -> usize {
+        self.tracker.borrow().strong_count()
+    }
+
+    /// Returns a future that completes once all the references to the server that were made
+    /// through this handle have been dropped, or immediately if there are none. Calling
+    /// [`client()`](Self::client) after that starts a new count.
+    pub fn released(&self) -> impl Future<Output = ()> + 'static {
+        let receiver = self.tracker.borrow().upgrade().map(|tracker| {
+            let (sender, receiver) = oneshot::channel();
+            tracker.waiters.borrow_mut().push(sender);
+            receiver
+        });
+        async move {
+            if let Some(receiver) = receiver {
+                let _ = receiver.await;
+            }
+        }
+    }
+}
+
 /// Allows a server to recognize its own capabilities when passed back to it, and obtain the
 /// underlying Server objects associated with them. Holds only weak references to Server objects
 /// allowing Server objects to be dropped when dropped by the remote client. Call the `gc` method
@@ -391,7 +566,7 @@ where
         let ptr =
             Rc::<<C as capnp::capability::FromServer<S>>::Dispatch>::as_ptr(&wrapped) as usize;
         self.caps.insert(ptr, Rc::downgrade(&wrapped));
-        capnp::capability::FromClientHook::new(Box::new(local::Client::from_rc(wrapped)))
+        new_client_from_rc(wrapped)
     }
 
     /// Looks up a capability and returns its underlying server object, if found.
@@ -501,3 +676,26 @@ where
 fn canceled_to_error(_e: tokio::sync::oneshot::error::RecvError) -> Error {
     Error::failed("oneshot was canceled".to_string())
 }
+
+/// Rejects `promise` with `ErrorKind::DeadlineExceeded` if it is still pending after `deadline`,
+/// dropping it so that the call gets canceled. Requires a tokio runtime with the time driver
+/// enabled.
+fn with_deadline<T>(
+    promise: Promise<T, Error>,
+    deadline: Option<std::time::Duration>,
+) -> Promise<T, Error>
+where
+    T: 'static,
+{
+    match deadline {
+        None => promise,
+        Some(timeout) => Promise::from_future(async move {
+            match tokio::time::timeout(timeout, promise).await {
+                Ok(result) => result,
+                Err(_) => Err(Error::deadline_exceeded(format!(
+                    "call did not return within {timeout:?}"
+                ))),
+            }
+        }),
+    }
+}
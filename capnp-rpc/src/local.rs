@@ -181,6 +181,7 @@ pub struct Request {
     interface_id: u64,
     method_id: u16,
     client: Box<dyn ClientHook>,
+    deadline: Option<std::time::Duration>,
 }
 
 impl Request {
@@ -196,6 +197,7 @@ impl Request {
             interface_id,
             method_id,
             client,
+            deadline: None,
         }
     }
 }
@@ -209,6 +211,9 @@ impl RequestHook for Request {
     fn get_brand(&self) -> usize {
         0
     }
+    fn set_deadline(&mut self, timeout: std::time::Duration) {
+        self.deadline = Some(timeout);
+    }
     fn send(self: Box<Self>) -> capability::RemotePromise<any_pointer::Owned> {
         let tmp = *self;
         let Self {
@@ -217,6 +222,7 @@ impl RequestHook for Request {
             interface_id,
             method_id,
             client,
+            deadline,
         } = tmp;
         let params = Params::new(message, cap_table);
 
@@ -246,7 +252,7 @@ impl RequestHook for Request {
         let pipeline = any_pointer::Pipeline::new(Box::new(pipeline));
 
         capability::RemotePromise {
-            promise: Promise::from_future(left),
+            promise: crate::with_deadline(Promise::from_future(left), deadline),
             pipeline,
         }
     }
@@ -303,6 +309,9 @@ where
     S: capability::Server,
 {
     inner: Rc<S>,
+
+    // Shared by all references made from this one, and dropped along with the last of them.
+    guard: Option<Rc<dyn std::any::Any>>,
 }
 
 impl<S> Client<S>
@@ -310,13 +319,18 @@ where
     S: capability::Server,
 {
     pub fn new(server: S) -> Self {
-        Self {
-            inner: Rc::new(server),
-        }
+        Self::from_rc(Rc::new(server))
     }
 
     pub fn from_rc(inner: Rc<S>) -> Self {
-        Self { inner }
+        Self { inner, guard: None }
+    }
+
+    pub fn with_guard(inner: Rc<S>, guard: Rc<dyn std::any::Any>) -> Self {
+        Self {
+            inner,
+            guard: Some(guard),
+        }
     }
 }
 
@@ -327,6 +341,7 @@ where
     fn clone(&self) -> Self {
         Self {
             inner: self.inner.clone(),
+            guard: self.guard.clone(),
         }
     }
 }
@@ -0,0 +1,434 @@
+// Copyright (c) 2013-2017 Sandstorm Development Group, Inc. and contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Membranes, which wrap a capability and every capability that passes through it, so that
+//! a policy sees each call crossing the boundary.
+//!
+//! A capability wrapped with [`wrap()`] is *inside* the membrane, and its holder is outside.
+//! Capabilities returned from calls into the membrane, including pipelined ones, come out
+//! wrapped the same way. Capabilities passed in as parameters get wrapped in the other
+//! direction, so that calls the inside makes on them back out are seen as well.
+//!
+//! Calls crossing the membrane copy their parameters and results.
+
+use capnp::capability::{self, FromClientHook, Promise, RemotePromise};
+use capnp::private::capability::{
+    ClientHook, ParamsHook, PipelineHook, PipelineOp, RequestHook, ResponseHook, ResultsHook,
+};
+use capnp::traits::{Imbue, ImbueMut};
+use capnp::{any_pointer, message, Error};
+
+use futures_util::TryFutureExt;
+use tokio::sync::watch;
+
+use std::rc::Rc;
+
+/// What a [`MembranePolicy`] decides about a call.
+pub enum Decision {
+    /// Let the call through.
+    Allow,
+
+    /// Fail the call with a generic "denied" error.
+    Deny,
+
+    /// Fail the call with this error.
+    Error(Error),
+}
+
+/// Decides which calls may cross a membrane.
+pub trait MembranePolicy {
+    /// Called before each call from outside the membrane on a capability inside it.
+    fn inbound_call(&self, interface_id: u64, method_id: u16) -> Decision;
+
+    /// Called before each call from inside the membrane on a capability that was passed in
+    /// through it. The default allows all of them.
+    fn outbound_call(&self, _interface_id: u64, _method_id: u16) -> Decision {
+        Decision::Allow
+    }
+
+    /// Returns a promise that fails once the membrane is revoked. Calls in flight across the
+    /// membrane are canceled with its error. Returns `None` by default, in which case calls
+    /// in flight always run to completion.
+    fn on_revoked(&self) -> Option<Promise<(), Error>> {
+        None
+    }
+}
+
+/// Wraps `client` in a membrane governed by `policy`.
+pub fn wrap<C>(client: C, policy: impl MembranePolicy + 'static) -> C
+where
+    C: FromClientHook,
+{
+    let membrane = Membrane {
+        policy: Rc::new(policy),
+        reverse: false,
+    };
+    FromClientHook::new(membrane.wrap(client.into_client_hook()))
+}
+
+/// One side of a membrane. `reverse` is true for the capabilities that were passed in.
+#[derive(Clone)]
+struct Membrane {
+    policy: Rc<dyn MembranePolicy>,
+    reverse: bool,
+}
+
+impl Membrane {
+    fn wrap(&self, inner: Box<dyn ClientHook>) -> Box<dyn ClientHook> {
+        Box::new(Client {
+            inner: Rc::new(ClientInner {
+                inner,
+                membrane: self.clone(),
+            }),
+        })
+    }
+
+    fn reversed(&self) -> Self {
+        Self {
+            policy: self.policy.clone(),
+            reverse: !self.reverse,
+        }
+    }
+
+    fn check(&self, interface_id: u64, method_id: u16) -> Result<(), Error> {
+        let decision = if self.reverse {
+            self.policy.outbound_call(interface_id, method_id)
+        } else {
+            self.policy.inbound_call(interface_id, method_id)
+        };
+        match decision {
+            Decision::Allow => Ok(()),
+            Decision::Deny => Err(Error::failed(format!(
+                "call to method {method_id} of interface {interface_id:#x} was denied by a membrane"
+            ))),
+            Decision::Error(e) => Err(e),
+        }
+    }
+
+    /// Copies `content` into a new message, wrapping its capabilities.
+    fn copy(&self, content: any_pointer::Reader) -> capnp::Result<Response> {
+        let mut message = message::Builder::new_default();
+        let mut cap_table = Vec::new();
+        {
+            let mut root: any_pointer::Builder = message.get_root()?;
+            root.imbue_mut(&mut cap_table);
+            root.set_as(content)?;
+        }
+        for cap in &mut cap_table {
+            *cap = cap.take().map(|hook| self.wrap(hook));
+        }
+        Ok(Response { message, cap_table })
+    }
+
+    /// Cancels `promise` with the policy's error once the membrane is revoked.
+    fn revocable<T: 'static>(&self, promise: Promise<T, Error>) -> Promise<T, Error> {
+        match self.policy.on_revoked() {
+            None => promise,
+            Some(revoked) => Promise::from_future(async move {
+                tokio::select! {
+                    result = promise => result,
+                    Err(e) = revoked => Err(e),
+                }
+            }),
+        }
+    }
+}
+
+struct ClientInner {
+    inner: Box<dyn ClientHook>,
+    membrane: Membrane,
+}
+
+#[derive(Clone)]
+struct Client {
+    inner: Rc<ClientInner>,
+}
+
+impl ClientHook for Client {
+    fn add_ref(&self) -> Box<dyn ClientHook> {
+        Box::new(self.clone())
+    }
+
+    fn new_call(
+        &self,
+        interface_id: u64,
+        method_id: u16,
+        size_hint: Option<capnp::MessageSize>,
+    ) -> capability::Request<any_pointer::Owned, any_pointer::Owned> {
+        capability::Request::new(Box::new(Request {
+            message: message::Builder::new_default(),
+            cap_table: Vec::new(),
+            interface_id,
+            method_id,
+            size_hint,
+            client: self.clone(),
+            deadline: None,
+        }))
+    }
+
+    fn call(
+        &self,
+        interface_id: u64,
+        method_id: u16,
+        params: Box<dyn ParamsHook>,
+        mut results: Box<dyn ResultsHook>,
+    ) -> Promise<(), Error> {
+        // Implement call() by copying params and results messages.
+        let mut request = self.new_call(interface_id, method_id, None);
+        pry!(request.get().set_as(pry!(params.get())));
+        let RemotePromise { promise, .. } = request.send();
+        Promise::from_future(
+            promise.and_then(move |response| async move { results.get()?.set_as(response.get()?) }),
+        )
+    }
+
+    fn get_brand(&self) -> usize {
+        0
+    }
+
+    fn get_ptr(&self) -> usize {
+        (self.inner.as_ref()) as *const _ as usize
+    }
+
+    fn get_resolved(&self) -> Option<Box<dyn ClientHook>> {
+        let resolved = self.inner.inner.get_resolved()?;
+        Some(self.inner.membrane.wrap(resolved))
+    }
+
+    fn when_more_resolved(&self) -> Option<Promise<Box<dyn ClientHook>, Error>> {
+        let promise = self.inner.inner.when_more_resolved()?;
+        let membrane = self.inner.membrane.clone();
+        Some(Promise::from_future(
+            promise.map_ok(move |resolved| membrane.wrap(resolved)),
+        ))
+    }
+
+    fn when_resolved(&self) -> Promise<(), Error> {
+        self.inner.inner.when_resolved()
+    }
+}
+
+struct Request {
+    message: message::Builder<message::HeapAllocator>,
+    cap_table: Vec<Option<Box<dyn ClientHook>>>,
+    interface_id: u64,
+    method_id: u16,
+    size_hint: Option<capnp::MessageSize>,
+    client: Client,
+    deadline: Option<std::time::Duration>,
+}
+
+impl Request {
+    /// Builds the request to the capability inside, with the params' capabilities wrapped
+    /// in the other direction.
+    fn forward(self) -> capnp::Result<capability::Request<any_pointer::Owned, any_pointer::Owned>> {
+        let Self {
+            message,
+            mut cap_table,
+            interface_id,
+            method_id,
+            size_hint,
+            client,
+            deadline,
+        } = self;
+        let membrane = &client.inner.membrane;
+        membrane.check(interface_id, method_id)?;
+
+        let reversed = membrane.reversed();
+        for cap in &mut cap_table {
+            *cap = cap.take().map(|hook| reversed.wrap(hook));
+        }
+        let mut params: any_pointer::Reader = message.get_root_as_reader()?;
+        params.imbue(&cap_table);
+
+        let mut request = client
+            .inner
+            .inner
+            .new_call(interface_id, method_id, size_hint);
+        request.get().set_as(params)?;
+        if let Some(timeout) = deadline {
+            request.hook.set_deadline(timeout);
+        }
+        Ok(request)
+    }
+}
+
+impl RequestHook for Request {
+    fn get(&mut self) -> any_pointer::Builder<'_> {
+        let mut result: any_pointer::Builder = self.message.get_root().unwrap();
+        result.imbue_mut(&mut self.cap_table);
+        result
+    }
+
+    fn get_brand(&self) -> usize {
+        0
+    }
+
+    fn set_deadline(&mut self, timeout: std::time::Duration) {
+        self.deadline = Some(timeout);
+    }
+
+    fn send(self: Box<Self>) -> RemotePromise<any_pointer::Owned> {
+        let membrane = self.client.inner.membrane.clone();
+        let request = match self.forward() {
+            Ok(request) => request,
+            Err(e) => {
+                return RemotePromise {
+                    promise: Promise::err(e.clone()),
+                    pipeline: any_pointer::Pipeline::new(Box::new(crate::broken::Pipeline::new(e))),
+                }
+            }
+        };
+        let RemotePromise { promise, pipeline } = request.send();
+
+        let results_membrane = membrane.clone();
+        let promise = Promise::from_future(promise.and_then(move |response| async move {
+            let response = results_membrane.copy(response.get()?)?;
+            Ok(capability::Response::new(Box::new(response)))
+        }));
+        RemotePromise {
+            promise: membrane.revocable(promise),
+            pipeline: any_pointer::Pipeline::new(Box::new(Pipeline {
+                inner: pipeline.hook,
+                membrane,
+            })),
+        }
+    }
+
+    fn tail_send(self: Box<Self>) -> Option<(u32, Promise<(), Error>, Box<dyn PipelineHook>)> {
+        None
+    }
+}
+
+struct Response {
+    message: message::Builder<message::HeapAllocator>,
+    cap_table: Vec<Option<Box<dyn ClientHook>>>,
+}
+
+impl ResponseHook for Response {
+    fn get(&self) -> capnp::Result<any_pointer::Reader<'_>> {
+        let mut result: any_pointer::Reader = self.message.get_root_as_reader()?;
+        result.imbue(&self.cap_table);
+        Ok(result)
+    }
+}
+
+struct Pipeline {
+    inner: Box<dyn PipelineHook>,
+    membrane: Membrane,
+}
+
+impl PipelineHook for Pipeline {
+    fn add_ref(&self) -> Box<dyn PipelineHook> {
+        Box::new(Self {
+            inner: self.inner.add_ref(),
+            membrane: self.membrane.clone(),
+        })
+    }
+
+    fn get_pipelined_cap(&self, ops: &[PipelineOp]) -> Box<dyn ClientHook> {
+        self.membrane.wrap(self.inner.get_pipelined_cap(ops))
+    }
+}
+
+struct Revocation {
+    error: Error,
+    cancel_in_flight: bool,
+    revoked: watch::Sender<bool>,
+}
+
+impl MembranePolicy for Rc<Revocation> {
+    fn inbound_call(&self, _interface_id: u64, _method_id: u16) -> Decision {
+        if *self.revoked.borrow() {
+            Decision::Error(self.error.clone())
+        } else {
+            Decision::Allow
+        }
+    }
+
+    fn outbound_call(&self, interface_id: u64, method_id: u16) -> Decision {
+        self.inbound_call(interface_id, method_id)
+    }
+
+    fn on_revoked(&self) -> Option<Promise<(), Error>> {
+        if !self.cancel_in_flight {
+            return None;
+        }
+        let error = self.error.clone();
+        if *self.revoked.borrow() {
+            return Some(Promise::err(error));
+        }
+        let mut revoked = self.revoked.subscribe();
+        Some(Promise::from_future(async move {
+            match revoked.wait_for(|revoked| *revoked).await {
+                Ok(_) => Err(error),
+                // The membrane is gone, so it will never be revoked.
+                Err(_) => std::future::pending().await,
+            }
+        }))
+    }
+}
+
+/// A capability that can be revoked. Everything obtained through it, such as capabilities
+/// returned from its methods, is revoked along with it.
+pub struct RevocableClient<C> {
+    client: C,
+    revocation: Rc<Revocation>,
+}
+
+impl<C> RevocableClient<C>
+where
+    C: FromClientHook + Clone,
+{
+    /// Wraps `client`. Once revoked, calls fail with `error`, while calls that are already in
+    /// flight run to completion.
+    pub fn new(client: C, error: Error) -> Self {
+        Self::with_options(client, error, false)
+    }
+
+    /// Like `new()`, but if `cancel_in_flight` is true, revoking also cancels the calls in
+    /// flight, which then fail with `error`.
+    pub fn with_options(client: C, error: Error, cancel_in_flight: bool) -> Self {
+        let revocation = Rc::new(Revocation {
+            error,
+            cancel_in_flight,
+            revoked: watch::Sender::new(false),
+        });
+        Self {
+            client: wrap(client, revocation.clone()),
+            revocation,
+        }
+    }
+
+    /// Returns the revocable capability, to hand out in place of the original one.
+    pub fn client(&self) -> C {
+        self.client.clone()
+    }
+
+    /// Makes all further calls through the capability fail.
+    pub fn revoke(&self) {
+        self.revocation.revoked.send_replace(true);
+    }
+
+    /// Returns true once `revoke()` has been called.
+    pub fn is_revoked(&self) -> bool {
+        *self.revocation.revoked.borrow()
+    }
+}
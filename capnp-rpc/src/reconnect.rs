@@ -1,6 +1,8 @@
 use std::cell::RefCell;
+use std::future::Future;
 use std::marker::PhantomData;
 use std::rc::Rc;
+use std::time::Duration;
 
 use capnp::capability::{FromClientHook, Promise};
 use capnp::private::capability::{ClientHook, RequestHook};
@@ -196,6 +198,10 @@ where
         0
     }
 
+    fn set_deadline(&mut self, timeout: std::time::Duration) {
+        self.inner.set_deadline(timeout)
+    }
+
     fn send(self: Box<Self>) -> capnp::capability::RemotePromise<capnp::any_pointer::Owned> {
         let parent = self.parent;
         let mut result = self.inner.send();
@@ -239,3 +245,98 @@ where
     let hook: Box<dyn ClientHook> = Box::new(c.clone());
     (FromClientHook::new(hook), Box::new(c))
 }
+
+/// How [`auto_reconnect_async()`] retries failed connection attempts.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_delay: Duration,
+    max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Makes up to `max_attempts` connection attempts per reconnect, at least one, waiting
+    /// 100 milliseconds after the first failure and doubling the wait after each further one,
+    /// up to 5 seconds.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(5),
+        }
+    }
+
+    /// Sets the wait after the first failed attempt and the limit that doubling it stops at.
+    pub fn backoff(mut self, initial_delay: Duration, max_delay: Duration) -> Self {
+        self.initial_delay = initial_delay;
+        self.max_delay = max_delay.max(initial_delay);
+        self
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Five attempts per reconnect.
+    fn default() -> Self {
+        Self::new(5)
+    }
+}
+
+// Calls `connect` until it succeeds or `policy` runs out of attempts, in which case the error
+// of the last attempt is returned.
+async fn connect_with_retries<F, Fut, C>(
+    connect: Rc<RefCell<F>>,
+    policy: RetryPolicy,
+) -> capnp::Result<capnp::capability::Client>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = capnp::Result<C>>,
+    C: FromClientHook,
+{
+    let mut delay = policy.initial_delay;
+    let mut attempt = 1;
+    loop {
+        let connecting = (connect.borrow_mut())();
+        match connecting.await {
+            Ok(client) => return Ok(capnp::capability::Client::new(client.into_client_hook())),
+            Err(err) if attempt >= policy.max_attempts => return Err(err),
+            Err(_) => {
+                tokio::time::sleep(delay).await;
+                delay = (delay * 2).min(policy.max_delay);
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Like [`auto_reconnect()`], but for an asynchronous `connect`, such as one that opens a
+/// network connection and bootstraps a new `RpcSystem` over it.
+///
+/// Calls made while connecting wait for the connection, which is retried according to
+/// `policy`. If every attempt fails, they fail with the error of the last attempt. As with
+/// any other call, if that error is `ErrorKind::Disconnected`, as it should be for a refused
+/// connection, the next call starts over. Calls that are in flight when the connection is
+/// lost fail rather than being retried, because they may already have taken effect.
+pub fn auto_reconnect_async<F, Fut, C>(
+    connect: F,
+    policy: RetryPolicy,
+) -> (C, Box<dyn SetTarget<C>>)
+where
+    F: FnMut() -> Fut,
+    F: 'static,
+    Fut: Future<Output = capnp::Result<C>>,
+    Fut: 'static,
+    C: FromClientHook,
+    C: 'static,
+{
+    let connect = Rc::new(RefCell::new(connect));
+    let c: Client<_, C> = Client::new(move || {
+        Ok(crate::new_promise_client(Box::pin(connect_with_retries(
+            connect.clone(),
+            policy.clone(),
+        ))))
+    });
+    // Starts connecting, without waiting for the first call.
+    c.get_current();
+    let hook: Box<dyn ClientHook> = Box::new(c.clone());
+    (FromClientHook::new(hook), Box::new(c))
+}
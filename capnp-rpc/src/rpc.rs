@@ -27,7 +27,8 @@ use capnp::capability::Promise;
 use capnp::private::capability::{
     ClientHook, ParamsHook, PipelineHook, PipelineOp, RequestHook, ResponseHook, ResultsHook,
 };
-use capnp::Error;
+use capnp::private::units::BYTES_PER_WORD;
+use capnp::{Error, Word};
 
 use futures_util::{FutureExt, TryFutureExt};
 use std::future::Future;
@@ -42,6 +43,7 @@ use std::rc::{Rc, Weak};
 use std::vec::Vec;
 
 use crate::attach::Attach;
+use crate::flow_control::FlowController;
 use crate::local::ResultsDoneHook;
 use crate::rpc_capnp::{
     bootstrap, call, cap_descriptor, disembargo, exception, finish, message, message_target,
@@ -140,6 +142,32 @@ impl<T> ExportTable<T> {
             idx: 0,
         }
     }
+
+    /// The number of occupied slots.
+    pub fn len(&self) -> usize {
+        self.slots.len() - self.free_ids.len()
+    }
+}
+
+/// A snapshot of the number of entries in each of a connection's RPC tables.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RpcStats {
+    /// Calls that we have sent and not yet finished.
+    pub questions: usize,
+    /// Calls that we have received and not yet finished.
+    pub answers: usize,
+    /// Capabilities that we host and have sent to the peer.
+    pub exports: usize,
+    /// Capabilities that the peer hosts and has sent to us.
+    pub imports: usize,
+    /// Embargoes waiting for their `Disembargo` to come back.
+    pub embargoes: usize,
+}
+
+/// Called with a snapshot of the tables whenever one of them grows beyond `threshold` entries.
+pub struct TableSizeMonitor {
+    pub(crate) threshold: usize,
+    pub(crate) callback: Box<dyn FnMut(RpcStats)>,
 }
 
 struct Question<VatId>
@@ -355,6 +383,10 @@ fn to_pipeline_ops(
     Ok(result)
 }
 
+/// `Exception.Detail` ID under which an error's payload (see [`capnp::Error::raw_payload()`]) is
+/// transmitted, as a single-segment serialized message.
+pub const ERROR_PAYLOAD_DETAIL_ID: u64 = 0xd0b4_6f3a_91c2_e857;
+
 fn from_error(error: &Error, mut builder: exception::Builder) {
     builder.set_reason(error.to_string()[..].into());
     let typ = match error.kind {
@@ -362,12 +394,18 @@ fn from_error(error: &Error, mut builder: exception::Builder) {
         ::capnp::ErrorKind::Overloaded => exception::Type::Overloaded,
         ::capnp::ErrorKind::Disconnected => exception::Type::Disconnected,
         ::capnp::ErrorKind::Unimplemented => exception::Type::Unimplemented,
+        ::capnp::ErrorKind::DeadlineExceeded => exception::Type::Failed,
         ::capnp::ErrorKind::SettingDynamicCapabilitiesIsUnsupported => {
             exception::Type::Unimplemented
         }
         _ => exception::Type::Failed,
     };
     builder.set_type(typ);
+    if let Some(payload) = error.raw_payload() {
+        let mut detail = builder.init_details(1).get(0);
+        detail.set_id(ERROR_PAYLOAD_DETAIL_ID);
+        detail.set_value(Word::words_to_bytes(payload));
+    }
 }
 
 fn remote_exception_to_error(exception: exception::Reader) -> Error {
@@ -385,7 +423,24 @@ fn remote_exception_to_error(exception: exception::Reader) -> Error {
     let reason_str = reason
         .to_str()
         .unwrap_or("<malformed utf-8 in error reason>");
-    ::capnp::Error::from_kind_context(kind, format!("remote exception: {reason_str}"))
+    let mut error =
+        ::capnp::Error::from_kind_context(kind, format!("remote exception: {reason_str}"));
+    if let Ok(details) = exception.get_details() {
+        for detail in details {
+            match detail.get_value() {
+                Ok(value)
+                    if detail.get_id() == ERROR_PAYLOAD_DETAIL_ID
+                        && value.len() % BYTES_PER_WORD == 0 =>
+                {
+                    let mut words = Word::allocate_zeroed_vec(value.len() / BYTES_PER_WORD);
+                    Word::words_to_bytes_mut(&mut words).copy_from_slice(value);
+                    error.set_raw_payload(Some(words));
+                }
+                _ => (),
+            }
+        }
+    }
+    error
 }
 
 pub struct ConnectionErrorHandler<VatId>
@@ -428,6 +483,14 @@ where
     disconnect_fulfiller: RefCell<Option<oneshot::Sender<Promise<(), Error>>>>,
 
     client_downcast_map: RefCell<HashMap<usize, WeakClient<VatId>>>,
+
+    // Set once a graceful shutdown has begun. New incoming and outgoing calls are rejected.
+    shutting_down: Cell<bool>,
+
+    // Fulfilled when there are no more outstanding questions or answers.
+    drain_waiters: RefCell<Vec<oneshot::Sender<()>>>,
+
+    table_size_monitor: Rc<RefCell<Option<TableSizeMonitor>>>,
 }
 
 impl<VatId> ConnectionState<VatId> {
@@ -435,6 +498,7 @@ impl<VatId> ConnectionState<VatId> {
         bootstrap_cap: Box<dyn ClientHook>,
         connection: Box<dyn crate::Connection<VatId>>,
         disconnect_fulfiller: oneshot::Sender<Promise<(), Error>>,
+        table_size_monitor: Rc<RefCell<Option<TableSizeMonitor>>>,
     ) -> (TaskSet<Error>, Rc<Self>) {
         let state = Rc::new(Self {
             bootstrap_cap,
@@ -448,6 +512,9 @@ impl<VatId> ConnectionState<VatId> {
             connection: RefCell::new(Ok(connection)),
             disconnect_fulfiller: RefCell::new(Some(disconnect_fulfiller)),
             client_downcast_map: RefCell::new(HashMap::new()),
+            shutting_down: Cell::new(false),
+            drain_waiters: RefCell::new(Vec::new()),
+            table_size_monitor,
         });
         let (mut handle, tasks) =
             TaskSet::new(Box::new(ConnectionErrorHandler::new(Rc::downgrade(&state))));
@@ -467,6 +534,83 @@ impl<VatId> ConnectionState<VatId> {
         }
     }
 
+    fn shutting_down_error() -> Error {
+        Error::disconnected("connection is shutting down".to_string())
+    }
+
+    /// True if no question is awaiting its `Return` and every answer has had its `Return` sent.
+    fn is_drained(&self) -> bool {
+        self.questions
+            .borrow()
+            .iter()
+            .all(|q| !q.is_awaiting_return)
+            && self
+                .answers
+                .borrow()
+                .slots
+                .values()
+                .all(|a| a.return_has_been_sent)
+    }
+
+    /// Returns the number of entries in each of this connection's tables.
+    pub fn stats(&self) -> RpcStats {
+        RpcStats {
+            questions: self.questions.borrow().len(),
+            answers: self.answers.borrow().slots.len(),
+            exports: self.exports.borrow().len(),
+            imports: self.imports.borrow().slots.len(),
+            embargoes: self.embargoes.borrow().len(),
+        }
+    }
+
+    /// Must be called after a table has grown to `len` entries, while no table is mutably borrowed.
+    fn table_grew(&self, len: usize) {
+        if let Some(monitor) = &mut *self.table_size_monitor.borrow_mut() {
+            if len == monitor.threshold + 1 {
+                (monitor.callback)(self.stats());
+            }
+        }
+    }
+
+    fn notify_if_drained(&self) {
+        if self.drain_waiters.borrow().is_empty() || !self.is_drained() {
+            return;
+        }
+        for waiter in self.drain_waiters.borrow_mut().drain(..) {
+            let _ = waiter.send(());
+        }
+    }
+
+    /// Gracefully shuts down the connection. New calls are rejected in both directions, and the
+    /// returned promise waits for in-flight calls to complete before disconnecting. If `timeout`
+    /// elapses first, the remaining calls fail with `ErrorKind::Disconnected`.
+    pub(crate) fn shutdown(
+        state: &Rc<Self>,
+        timeout: Option<std::time::Duration>,
+    ) -> Promise<(), Error> {
+        state.shutting_down.set(true);
+        let drained: Promise<(), Error> =
+            if state.connection.borrow().is_err() || state.is_drained() {
+                Promise::ok(())
+            } else {
+                let (tx, rx) = oneshot::channel();
+                state.drain_waiters.borrow_mut().push(tx);
+                // The sender is dropped if the connection goes away first; either way we're done.
+                Promise::from_future(rx.map(|_| Ok(())))
+            };
+        let state = state.clone();
+        Promise::from_future(async move {
+            match timeout {
+                Some(timeout) => {
+                    let _ = tokio::time::timeout(timeout, drained).await;
+                }
+                None => drained.await?,
+            }
+            state.disconnect(Error::disconnected("connection shut down".to_string()));
+            Ok(())
+        })
+    }
+
     fn disconnect(&self, error: ::capnp::Error) {
         if self.connection.borrow().is_err() {
             // Already disconnected.
@@ -536,6 +680,8 @@ impl<VatId> ConnectionState<VatId> {
         drop(resolve_ops_to_release);
         // TODO drop tail calls
 
+        self.drain_waiters.borrow_mut().clear();
+
         match *self.connection.borrow_mut() {
             Ok(ref mut c) => {
                 let mut message = c.new_outgoing_message(5);
@@ -609,7 +755,11 @@ impl<VatId> ConnectionState<VatId> {
     }
 
     pub fn bootstrap(state: &Rc<Self>) -> Box<dyn ClientHook> {
+        if state.shutting_down.get() {
+            return broken::new_cap(Self::shutting_down_error());
+        }
         let question_id = state.questions.borrow_mut().push(Question::new());
+        state.table_grew(state.questions.borrow().len());
 
         let (fulfiller, promise) = oneshot::channel();
         let promise = promise.map_err(crate::canceled_to_error);
@@ -747,7 +897,11 @@ impl<VatId> ConnectionState<VatId> {
                 .init_return();
             ret.set_answer_id(answer_id);
 
-            let cap = connection_state.bootstrap_cap.clone();
+            let cap = if connection_state.shutting_down.get() {
+                broken::new_cap(Self::shutting_down_error())
+            } else {
+                connection_state.bootstrap_cap.clone()
+            };
             let mut cap_table = Vec::new();
             let mut payload = ret.init_results();
             {
@@ -760,18 +914,29 @@ impl<VatId> ConnectionState<VatId> {
             Self::write_descriptors(connection_state, &cap_table, payload)
         };
 
-        let slots = &mut connection_state.answers.borrow_mut().slots;
-        let answer = slots.entry(answer_id).or_insert_with(Answer::new);
-        if answer.active {
-            connection_state.release_exports(&result_exports)?;
-            return Err(Error::failed("questionId is already in use".to_string()));
-        }
-        answer.active = true;
-        answer.return_has_been_sent = true;
-        answer.result_exports = result_exports;
-        answer.pipeline = Some(Box::new(SingleCapPipeline::new(
-            connection_state.bootstrap_cap.clone(),
-        )));
+        let grown_len = {
+            let slots = &mut connection_state.answers.borrow_mut().slots;
+            let len = slots.len();
+            let answer = slots.entry(answer_id).or_insert_with(Answer::new);
+            if answer.active {
+                connection_state.release_exports(&result_exports)?;
+                return Err(Error::failed("questionId is already in use".to_string()));
+            }
+            answer.active = true;
+            answer.return_has_been_sent = true;
+            answer.result_exports = result_exports;
+            answer.pipeline = Some(Box::new(SingleCapPipeline::new(
+                if connection_state.shutting_down.get() {
+                    broken::new_cap(Self::shutting_down_error())
+                } else {
+                    connection_state.bootstrap_cap.clone()
+                },
+            )));
+            (slots.len() > len).then_some(slots.len())
+        };
+        if let Some(len) = grown_len {
+            connection_state.table_grew(len);
+        }
 
         let _ = response.send();
         Ok(())
@@ -911,7 +1076,10 @@ impl<VatId> ConnectionState<VatId> {
             }
             Ok(message::Call(call)) => {
                 let call = call?;
-                let capability = connection_state.get_message_target(call.get_target()?)?;
+                let mut capability = connection_state.get_message_target(call.get_target()?)?;
+                if connection_state.shutting_down.get() {
+                    capability = broken::new_cap(Self::shutting_down_error());
+                }
                 let (interface_id, method_id, question_id, cap_table_array, redirect_results) = {
                     let redirect_results = match call.get_send_results_to().which()? {
                         call::send_results_to::Caller(()) => false,
@@ -969,13 +1137,18 @@ impl<VatId> ConnectionState<VatId> {
                         (None, None)
                     };
 
-                {
+                let grown_len = {
                     let slots = &mut connection_state.answers.borrow_mut().slots;
+                    let len = slots.len();
                     let answer = slots.entry(question_id).or_insert(answer);
                     if answer.active {
                         return Err(Error::failed("questionId is already in use".to_string()));
                     }
                     answer.active = true;
+                    (slots.len() > len).then_some(slots.len())
+                };
+                if let Some(len) = grown_len {
+                    connection_state.table_grew(len);
                 }
 
                 let call_promise =
@@ -1162,26 +1335,30 @@ impl<VatId> ConnectionState<VatId> {
                 Self::send_unimplemented(&connection_state, message.as_ref())?;
             }
         }
+        connection_state.notify_if_drained();
         Ok(())
     }
 
     fn answer_has_sent_return(&self, id: AnswerId, result_exports: Vec<ExportId>) {
-        let mut erase = false;
-        let answers_slots = &mut self.answers.borrow_mut().slots;
-        if let Some(a) = answers_slots.get_mut(&id) {
-            a.return_has_been_sent = true;
-            if a.received_finish.get() {
-                erase = true;
+        {
+            let mut erase = false;
+            let answers_slots = &mut self.answers.borrow_mut().slots;
+            if let Some(a) = answers_slots.get_mut(&id) {
+                a.return_has_been_sent = true;
+                if a.received_finish.get() {
+                    erase = true;
+                } else {
+                    a.result_exports = result_exports;
+                }
             } else {
-                a.result_exports = result_exports;
+                unreachable!()
             }
-        } else {
-            unreachable!()
-        }
 
-        if erase {
-            answers_slots.remove(&id);
+            if erase {
+                answers_slots.remove(&id);
+            }
         }
+        self.notify_if_drained();
     }
 
     fn release_export(&self, id: ExportId, refcount: u32) -> ::capnp::Result<()> {
@@ -1414,6 +1591,7 @@ impl<VatId> ConnectionState<VatId> {
                 let exp = Export::new(inner.clone());
                 let export_id = state.exports.borrow_mut().push(exp);
                 state.exports_by_cap.borrow_mut().insert(ptr, export_id);
+                state.table_grew(state.exports.borrow().len());
                 match inner.when_more_resolved() {
                     Some(wrapped) => {
                         // This is a promise.  Arrange for the `Resolve` message to be sent later.
@@ -1463,10 +1641,11 @@ impl<VatId> ConnectionState<VatId> {
     fn import(state: &Rc<Self>, import_id: ImportId, is_promise: bool) -> Box<dyn ClientHook> {
         let connection_state = state.clone();
 
-        let import_client = {
+        let (import_client, grown_len) = {
             let slots = &mut state.imports.borrow_mut().slots;
+            let len = slots.len();
             let v = slots.entry(import_id).or_insert_with(Import::new);
-            if v.import_client.is_some() {
+            let import_client = if v.import_client.is_some() {
                 v.import_client
                     .as_ref()
                     .unwrap()
@@ -1480,8 +1659,12 @@ impl<VatId> ConnectionState<VatId> {
                     (&*import_client.borrow()) as *const _ as usize,
                 ));
                 import_client
-            }
+            };
+            (import_client, (slots.len() > len).then_some(slots.len()))
         };
+        if let Some(len) = grown_len {
+            state.table_grew(len);
+        }
 
         // We just received a copy of this import ID, so the remote refcount has gone up.
         import_client.borrow_mut().add_remote_ref();
@@ -1619,6 +1802,30 @@ impl<VatId> Disconnector<VatId> {
     }
 }
 
+/// Reports the sizes of an `RpcSystem`'s tables. Unlike the `RpcSystem`, this can be kept
+/// around after the system has been spawned.
+pub struct StatsHandle<VatId>
+where
+    VatId: 'static,
+{
+    connection_state: Rc<RefCell<Option<Rc<ConnectionState<VatId>>>>>,
+}
+
+impl<VatId> StatsHandle<VatId> {
+    pub fn new(connection_state: Rc<RefCell<Option<Rc<ConnectionState<VatId>>>>>) -> Self {
+        Self { connection_state }
+    }
+
+    /// Returns the number of entries in each of the connection's tables, or all zeroes if there
+    /// is no connection.
+    pub fn stats(&self) -> RpcStats {
+        match &*self.connection_state.borrow() {
+            Some(state) => state.stats(),
+            None => RpcStats::default(),
+        }
+    }
+}
+
 impl<VatId> Future for Disconnector<VatId>
 where
     VatId: 'static,
@@ -1741,6 +1948,7 @@ where
     target: Client<VatId>,
     message: Box<dyn crate::OutgoingMessage>,
     cap_table: Vec<Option<Box<dyn ClientHook>>>,
+    deadline: Option<std::time::Duration>,
 }
 
 fn get_call(message: &mut Box<dyn crate::OutgoingMessage>) -> ::capnp::Result<call::Builder> {
@@ -1768,6 +1976,7 @@ where
             target,
             message,
             cap_table: Vec::new(),
+            deadline: None,
         })
     }
 
@@ -1799,6 +2008,7 @@ where
         question.is_tail_call = is_tail_call;
 
         let question_id = connection_state.questions.borrow_mut().push(question);
+        connection_state.table_grew(connection_state.questions.borrow().len());
         {
             let mut call_builder: call::Builder = get_call(&mut message).unwrap();
             // Finish and send.
@@ -1845,6 +2055,9 @@ impl<VatId> RequestHook for Request<VatId> {
     fn get_brand<'a>(&self) -> usize {
         self.connection_state.get_brand()
     }
+    fn set_deadline(&mut self, timeout: std::time::Duration) {
+        self.deadline = Some(timeout);
+    }
     fn send(self: Box<Self>) -> ::capnp::capability::RemotePromise<any_pointer::Owned> {
         let tmp = *self;
         let Self {
@@ -1852,7 +2065,13 @@ impl<VatId> RequestHook for Request<VatId> {
             target,
             mut message,
             cap_table,
+            deadline,
         } = tmp;
+        if connection_state.shutting_down.get() {
+            let request =
+                broken::Request::new(ConnectionState::<VatId>::shutting_down_error(), None);
+            return Box::new(request).send();
+        }
         let write_target_result = {
             let call_builder: call::Builder = get_call(&mut message).unwrap();
             target.write_target(call_builder.get_target().unwrap())
@@ -1877,6 +2096,9 @@ impl<VatId> RequestHook for Request<VatId> {
                             .into_reader(),
                     )
                     .unwrap();
+                if let Some(timeout) = deadline {
+                    replacement.set_deadline(timeout);
+                }
                 replacement.send()
             }
             None => {
@@ -1902,12 +2124,28 @@ impl<VatId> RequestHook for Request<VatId> {
                 );
 
                 ::capnp::capability::RemotePromise {
-                    promise: app_promise,
+                    promise: crate::with_deadline(app_promise, deadline),
                     pipeline: any_pointer::Pipeline::new(Box::new(pipeline)),
                 }
             }
         }
     }
+    fn send_streaming(self: Box<Self>) -> Promise<(), Error> {
+        let size = match self
+            .message
+            .get_body_as_reader()
+            .and_then(|body| body.target_size())
+        {
+            Ok(size) => size.word_count as usize * BYTES_PER_WORD,
+            Err(e) => return Promise::err(e),
+        };
+        let connection_state = self.connection_state.clone();
+        let flow_controller = self.target.flow_controller();
+        let ack = self.send().promise.map_ok(|_| ());
+        let (task, ready) = flow_controller.send(size, ack);
+        connection_state.add_task(task);
+        ready
+    }
     fn tail_send(self: Box<Self>) -> Option<(u32, Promise<(), Error>, Box<dyn PipelineHook>)> {
         let tmp = *self;
         let Self {
@@ -1915,10 +2153,14 @@ impl<VatId> RequestHook for Request<VatId> {
             target,
             mut message,
             cap_table,
+            // The results of a tail call go to the caller of the call being answered, and so
+            // are covered by that call's deadline, if any.
+            deadline: _,
         } = tmp;
 
-        if connection_state.connection.borrow().is_err() {
-            // Disconnected; fall back to a regular send() which will fail appropriately.
+        if connection_state.connection.borrow().is_err() || connection_state.shutting_down.get() {
+            // Disconnected or shutting down; fall back to a regular send() which will fail
+            // appropriately.
             return None;
         }
 
@@ -2595,6 +2837,9 @@ where
 
     /// Number of times we've received this import from the peer.
     remote_ref_count: u32,
+
+    /// Created on the first streaming call to this capability.
+    flow_controller: Option<Rc<FlowController>>,
 }
 
 impl<VatId> Drop for ImportClient<VatId> {
@@ -2652,6 +2897,7 @@ where
             connection_state: connection_state.clone(),
             import_id,
             remote_ref_count: 0,
+            flow_controller: None,
         }))
     }
 
@@ -2675,6 +2921,7 @@ where
     connection_state: Rc<ConnectionState<VatId>>,
     question_ref: Rc<RefCell<QuestionRef<VatId>>>,
     ops: Vec<PipelineOp>,
+    flow_controller: Option<Rc<FlowController>>,
 }
 
 impl<VatId> PipelineClient<VatId>
@@ -2690,6 +2937,7 @@ where
             connection_state: connection_state.clone(),
             question_ref,
             ops,
+            flow_controller: None,
         }))
     }
 }
@@ -2724,6 +2972,7 @@ where
     import_id: Option<ImportId>,
     received_call: bool,
     resolution_waiters: crate::sender_queue::SenderQueue<(), Box<dyn ClientHook>>,
+    flow_controller: Option<Rc<FlowController>>,
 }
 
 impl<VatId> PromiseClient<VatId> {
@@ -2739,6 +2988,7 @@ impl<VatId> PromiseClient<VatId> {
             import_id,
             received_call: false,
             resolution_waiters: crate::sender_queue::SenderQueue::new(),
+            flow_controller: None,
         }))
     }
 
@@ -2765,6 +3015,7 @@ impl<VatId> PromiseClient<VatId> {
                 .and_then(std::future::ready);
             let embargo = Embargo::new(fulfiller);
             let embargo_id = connection_state.embargoes.borrow_mut().push(embargo);
+            connection_state.table_grew(connection_state.embargoes.borrow().len());
 
             let mut message = connection_state
                 .new_outgoing_message(50)
@@ -2895,6 +3146,25 @@ impl<VatId> Client<VatId> {
         }
     }
 
+    /// Returns the flow controller shared by all streaming calls to this capability.
+    fn flow_controller(&self) -> Rc<FlowController> {
+        let flow_controller = match &self.variant {
+            ClientVariant::Import(import_client) => &mut import_client.borrow_mut().flow_controller,
+            ClientVariant::Pipeline(pipeline_client) => {
+                &mut pipeline_client.borrow_mut().flow_controller
+            }
+            ClientVariant::Promise(promise_client) => {
+                &mut promise_client.borrow_mut().flow_controller
+            }
+            _ => {
+                unimplemented!()
+            }
+        };
+        flow_controller
+            .get_or_insert_with(|| Rc::new(FlowController::new()))
+            .clone()
+    }
+
     fn from_ptr(ptr: usize, connection_state: &ConnectionState<VatId>) -> Option<Self> {
         match connection_state.client_downcast_map.borrow().get(&ptr) {
             Some(c) => c.upgrade(),
@@ -0,0 +1,69 @@
+//! Helpers for testing code that uses Cap'n Proto interfaces, without opening sockets.
+//!
+//! [`local_pair()`] connects a client to a server through a two-party connection over an
+//! in-memory pipe, so that every call, result and error goes through the same serialization
+//! as it would between processes. [`direct_pair()`] has the same shape but hands out a local
+//! client instead, which dispatches calls straight to the server. A test that takes either
+//! function as a parameter can check that its interface behaves the same way in both cases.
+
+use capnp::capability::FromServer;
+use capnp::Error;
+use futures_util::TryFutureExt;
+use std::future::Future;
+
+use crate::{rpc_twoparty_capnp, twoparty, RpcSystem};
+
+/// How many bytes the in-memory pipe buffers in each direction before writers have to wait.
+const PIPE_CAPACITY: usize = 64 * 1024;
+
+/// Exports `server` as the bootstrap capability of an in-memory two-party connection and
+/// returns a client of type `C` for it, along with the future that drives both ends of the
+/// connection.
+///
+/// The future must be polled for calls to make progress, usually by spawning it on the
+/// `tokio::task::LocalSet` that the test runs in. It finishes once either end disconnects,
+/// and returns an error if the connection fails.
+pub fn local_pair<C, S>(server: S) -> (C, impl Future<Output = Result<(), Error>>)
+where
+    C: FromServer<S>,
+{
+    let bootstrap: C = crate::new_client(server);
+    let (client_stream, server_stream) = tokio::io::duplex(PIPE_CAPACITY);
+    let (client_reader, client_writer) = tokio::io::split(client_stream);
+    let (server_reader, server_writer) = tokio::io::split(server_stream);
+
+    let client_network = Box::new(twoparty::VatNetwork::new(
+        client_reader,
+        client_writer,
+        rpc_twoparty_capnp::Side::Client,
+        Default::default(),
+    ));
+    let mut client_rpc_system = RpcSystem::new(client_network, None);
+    let client: C = client_rpc_system.bootstrap(rpc_twoparty_capnp::Side::Server);
+
+    let server_network = Box::new(twoparty::VatNetwork::new(
+        server_reader,
+        server_writer,
+        rpc_twoparty_capnp::Side::Server,
+        Default::default(),
+    ));
+    let server_rpc_system = RpcSystem::new(
+        server_network,
+        Some(capnp::capability::Client::new(bootstrap.into_client_hook())),
+    );
+
+    let connection = futures_util::future::try_select(client_rpc_system, server_rpc_system)
+        .map_ok(|_| ())
+        .map_err(|e| e.factor_first().0);
+    (client, connection)
+}
+
+/// Like [`local_pair()`], but without a connection: the client is made with
+/// [`new_client()`](crate::new_client) and calls the server directly. The returned future
+/// finishes right away and only exists so that tests can switch between the two functions.
+pub fn direct_pair<C, S>(server: S) -> (C, impl Future<Output = Result<(), Error>>)
+where
+    C: FromServer<S>,
+{
+    (crate::new_client(server), std::future::ready(Ok(())))
+}
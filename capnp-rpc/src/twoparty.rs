@@ -28,11 +28,203 @@ use futures_util::{FutureExt, TryFutureExt};
 use tokio::io::{AsyncRead, AsyncWrite};
 use tokio::sync::oneshot;
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
+use std::pin::Pin;
 use std::rc::{Rc, Weak};
+use std::task::{Context, Poll};
 
 pub type VatId = crate::rpc_twoparty_capnp::Side;
 
+/// How messages are encoded on the wire of a two-party connection.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Compression {
+    /// The standard, unpacked serialization.
+    #[default]
+    None,
+
+    /// The [packed encoding](https://capnproto.org/encoding.html#packing), as in
+    /// `capnp::serialize_packed`. It is only used if the peer also asks for it; otherwise the
+    /// connection falls back to `None`.
+    ///
+    /// To negotiate, each side that asks for packing starts with a message advertising it.
+    /// A peer that asks for packing too answers with an acknowledgement, after which it
+    /// sends packed messages. Any other peer answers with the `unimplemented` message that
+    /// the RPC protocol prescribes for unknown messages, and the connection stays unpacked.
+    Packed,
+}
+
+/// Which way a message went, as reported to a size observer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Outgoing,
+    Incoming,
+}
+
+/// Sizes of a message sent or received by a two-party connection, as reported to the
+/// observer set by [`VatNetwork::set_size_observer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MessageSizes {
+    pub direction: Direction,
+
+    /// The size of the message in the standard, unpacked serialization.
+    pub unpacked_bytes: usize,
+
+    /// The number of bytes that the message took on the wire.
+    pub wire_bytes: usize,
+}
+
+// The payloads of the `obsoleteDelete` messages used to negotiate compression. The RPC
+// system never sends `obsoleteDelete`, and answers it with `unimplemented`.
+const PACKED_ADVERTISEMENT: &str = "capnp-rpc-rust/twoparty/packed";
+const PACKED_ACKNOWLEDGEMENT: &str = "capnp-rpc-rust/twoparty/packed-ack";
+
+type SizeObserver = Rc<dyn Fn(MessageSizes)>;
+
+/// The compression state of a connection, shared by its incoming and outgoing sides.
+struct Framing {
+    // Whether we are still looking at incoming messages for the peer's negotiation messages.
+    negotiating: Cell<bool>,
+    outgoing_packed: Cell<bool>,
+    incoming_packed: Cell<bool>,
+    size_observer: RefCell<Option<SizeObserver>>,
+}
+
+impl Framing {
+    fn new(compression: Compression) -> Self {
+        Self {
+            negotiating: Cell::new(compression == Compression::Packed),
+            outgoing_packed: Cell::new(false),
+            incoming_packed: Cell::new(false),
+            size_observer: RefCell::new(None),
+        }
+    }
+
+    fn send(
+        &self,
+        sender: &mut ::capnp_futures::Sender<
+            Rc<::capnp::message::Builder<::capnp::message::HeapAllocator>>,
+        >,
+        message: Rc<::capnp::message::Builder<::capnp::message::HeapAllocator>>,
+    ) -> Promise<Rc<::capnp::message::Builder<::capnp::message::HeapAllocator>>, ::capnp::Error>
+    {
+        let packed = self.outgoing_packed.get();
+        let observer = self.size_observer.borrow().clone();
+        if let Some(observer) = observer {
+            let unpacked_bytes = ::capnp::serialize::compute_serialized_size_in_words(&message) * 8;
+            let wire_bytes = if packed {
+                let mut counter = ByteCounter(0);
+                match ::capnp::serialize_packed::write_message(&mut counter, &message) {
+                    Ok(()) => counter.0,
+                    Err(e) => return Promise::err(e),
+                }
+            } else {
+                unpacked_bytes
+            };
+            observer(MessageSizes {
+                direction: Direction::Outgoing,
+                unpacked_bytes,
+                wire_bytes,
+            });
+        }
+        if packed {
+            Promise::from_future(sender.send_packed(message))
+        } else {
+            Promise::from_future(sender.send(message))
+        }
+    }
+
+    /// Looks for the peer's negotiation messages. Returns true if `message` was one of them
+    /// and should not be passed on to the RPC system.
+    fn negotiate(
+        &self,
+        message: &::capnp::message::Reader<capnp::serialize::OwnedSegments>,
+        sender: &mut ::capnp_futures::Sender<
+            Rc<::capnp::message::Builder<::capnp::message::HeapAllocator>>,
+        >,
+    ) -> ::capnp::Result<bool> {
+        use crate::rpc_capnp::message;
+
+        let root: message::Reader = message.get_root()?;
+        match root.which() {
+            Ok(message::ObsoleteDelete(payload)) => match payload.get_as::<::capnp::text::Reader>()
+            {
+                Ok(text) if text == PACKED_ADVERTISEMENT => {
+                    // Everything we send after the acknowledgement is packed.
+                    drop(self.send(sender, negotiation_message(PACKED_ACKNOWLEDGEMENT)?));
+                    self.outgoing_packed.set(true);
+                    Ok(true)
+                }
+                Ok(text) if text == PACKED_ACKNOWLEDGEMENT => {
+                    self.incoming_packed.set(true);
+                    self.negotiating.set(false);
+                    Ok(true)
+                }
+                _ => Ok(false),
+            },
+            Ok(message::Unimplemented(Ok(echoed))) => match echoed.which() {
+                Ok(message::ObsoleteDelete(payload))
+                    if payload
+                        .get_as::<::capnp::text::Reader>()
+                        .is_ok_and(|text| text == PACKED_ADVERTISEMENT) =>
+                {
+                    // The peer does not support packing.
+                    self.negotiating.set(false);
+                    Ok(true)
+                }
+                _ => Ok(false),
+            },
+            _ => Ok(false),
+        }
+    }
+}
+
+fn negotiation_message(
+    payload: &str,
+) -> ::capnp::Result<Rc<::capnp::message::Builder<::capnp::message::HeapAllocator>>> {
+    let mut message = ::capnp::message::Builder::new_default();
+    message
+        .init_root::<crate::rpc_capnp::message::Builder>()
+        .init_obsolete_delete()
+        .set_as(payload)?;
+    Ok(Rc::new(message))
+}
+
+/// A writer that only counts the bytes written to it.
+struct ByteCounter(usize);
+
+impl std::io::Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// An `AsyncRead` that counts the bytes read through it.
+struct CountingRead<R> {
+    inner: R,
+    count: usize,
+}
+
+impl<R> AsyncRead for CountingRead<R>
+where
+    R: AsyncRead + Unpin,
+{
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let before = buf.filled().len();
+        let result = Pin::new(&mut self.inner).poll_read(cx, buf);
+        self.count += buf.filled().len() - before;
+        result
+    }
+}
+
 struct IncomingMessage {
     message: ::capnp::message::Reader<capnp::serialize::OwnedSegments>,
 }
@@ -52,6 +244,7 @@ impl crate::IncomingMessage for IncomingMessage {
 struct OutgoingMessage {
     message: ::capnp::message::Builder<::capnp::message::HeapAllocator>,
     sender: ::capnp_futures::Sender<Rc<::capnp::message::Builder<::capnp::message::HeapAllocator>>>,
+    framing: Rc<Framing>,
 }
 
 impl crate::OutgoingMessage for OutgoingMessage {
@@ -73,9 +266,10 @@ impl crate::OutgoingMessage for OutgoingMessage {
         let Self {
             message,
             mut sender,
+            framing,
         } = tmp;
         let m = Rc::new(message);
-        (Promise::from_future(sender.send(m.clone())), m)
+        (framing.send(&mut sender, m.clone()), m)
     }
 
     fn take(self: Box<Self>) -> ::capnp::message::Builder<::capnp::message::HeapAllocator> {
@@ -91,6 +285,7 @@ where
     sender: ::capnp_futures::Sender<Rc<::capnp::message::Builder<::capnp::message::HeapAllocator>>>,
     side: crate::rpc_twoparty_capnp::Side,
     receive_options: ReaderOptions,
+    framing: Rc<Framing>,
     on_disconnect_fulfiller: Option<oneshot::Sender<()>>,
 }
 
@@ -126,6 +321,7 @@ where
         >,
         side: crate::rpc_twoparty_capnp::Side,
         receive_options: ReaderOptions,
+        framing: Rc<Framing>,
         on_disconnect_fulfiller: oneshot::Sender<()>,
     ) -> Self {
         Self {
@@ -134,6 +330,7 @@ where
                 sender,
                 side,
                 receive_options,
+                framing,
                 on_disconnect_fulfiller: Some(on_disconnect_fulfiller),
             })),
         }
@@ -155,9 +352,11 @@ where
         let message = ::capnp::message::Builder::new(
             ::capnp::message::HeapAllocator::new().first_segment_words(first_segment_word_size),
         );
+        let inner = self.inner.borrow();
         Box::new(OutgoingMessage {
             message,
-            sender: self.inner.borrow().sender.clone(),
+            sender: inner.sender.clone(),
+            framing: inner.framing.clone(),
         })
     }
 
@@ -171,10 +370,51 @@ where
         match maybe_input_stream {
             Some(mut s) => {
                 let receive_options = inner.receive_options;
+                let framing = inner.framing.clone();
+                let mut sender = inner.sender.clone();
                 Promise::from_future(async move {
-                    let maybe_message =
-                        ::capnp_futures::serialize::try_read_message(&mut s, receive_options)
-                            .await?;
+                    let maybe_message = loop {
+                        let mut wire = CountingRead {
+                            inner: &mut s,
+                            count: 0,
+                        };
+                        let (maybe_message, unpacked_bytes) = if framing.incoming_packed.get() {
+                            let mut unpacked = CountingRead {
+                                inner: ::capnp_futures::serialize_packed::PackedRead::new(
+                                    &mut wire,
+                                ),
+                                count: 0,
+                            };
+                            let maybe_message = ::capnp_futures::serialize::try_read_message(
+                                &mut unpacked,
+                                receive_options,
+                            )
+                            .await;
+                            (maybe_message, unpacked.count)
+                        } else {
+                            let maybe_message = ::capnp_futures::serialize::try_read_message(
+                                &mut wire,
+                                receive_options,
+                            )
+                            .await;
+                            (maybe_message, wire.count)
+                        };
+                        let maybe_message = maybe_message?;
+                        let observer = framing.size_observer.borrow().clone();
+                        if let (Some(observer), Some(_)) = (observer, &maybe_message) {
+                            observer(MessageSizes {
+                                direction: Direction::Incoming,
+                                unpacked_bytes,
+                                wire_bytes: wire.count,
+                            });
+                        }
+                        match maybe_message {
+                            Some(message)
+                                if framing.negotiating.get()
+                                    && framing.negotiate(&message, &mut sender)? => {}
+                            maybe_message => break maybe_message,
+                        }
+                    };
                     *return_it_here.borrow_mut() = Some(s);
                     Ok(maybe_message.map(|message| {
                         Box::new(IncomingMessage::new(message)) as Box<dyn crate::IncomingMessage>
@@ -208,6 +448,7 @@ where
 
     execution_driver: futures_util::future::Shared<Promise<(), ::capnp::Error>>,
     side: crate::rpc_twoparty_capnp::Side,
+    framing: Rc<Framing>,
 }
 
 /// A two-party vat `VatNetwork` implementation.
@@ -231,6 +472,27 @@ where
         side: crate::rpc_twoparty_capnp::Side,
         receive_options: ReaderOptions,
     ) -> Self
+    where
+        U: AsyncWrite + 'static + Unpin,
+    {
+        Self::new_with_compression(
+            input_stream,
+            output_stream,
+            side,
+            receive_options,
+            Compression::None,
+        )
+    }
+
+    /// Like `new()`, but asks the peer to use `compression` on the connection. See
+    /// [`Compression`] for how it is negotiated.
+    pub fn new_with_compression<U>(
+        input_stream: T,
+        output_stream: U,
+        side: crate::rpc_twoparty_capnp::Side,
+        receive_options: ReaderOptions,
+        compression: Compression,
+    ) -> Self
     where
         U: AsyncWrite + 'static + Unpin,
     {
@@ -238,7 +500,7 @@ where
         let disconnect_promise =
             disconnect_promise.map_err(|_| ::capnp::Error::disconnected("disconnected".into()));
 
-        let (execution_driver, sender) = {
+        let (execution_driver, mut sender) = {
             let (tx, write_queue) = ::capnp_futures::write_queue(output_stream);
 
             // Don't use `.join()` here because we need to make sure to wait for `disconnect_promise` to
@@ -254,15 +516,38 @@ where
             )
         };
 
-        let connection = Connection::new(input_stream, sender, side, receive_options, fulfiller);
+        let framing = Rc::new(Framing::new(compression));
+        if compression == Compression::Packed {
+            // The advertisement has to be the first message on the connection.
+            let advertisement = negotiation_message(PACKED_ADVERTISEMENT)
+                .expect("negotiation message fits in a fresh message");
+            drop(framing.send(&mut sender, advertisement));
+        }
+
+        let connection = Connection::new(
+            input_stream,
+            sender,
+            side,
+            receive_options,
+            framing.clone(),
+            fulfiller,
+        );
         let weak_inner = Rc::downgrade(&connection.inner);
         Self {
             connection: Some(connection),
             weak_connection_inner: weak_inner,
             execution_driver,
             side,
+            framing,
         }
     }
+
+    /// Sets a function to call with the sizes of each message sent or received, for example
+    /// to measure how well compression works. When outgoing messages are packed, computing
+    /// their packed size takes an extra packing pass.
+    pub fn set_size_observer(&mut self, observer: impl Fn(MessageSizes) + 'static) {
+        *self.framing.size_observer.borrow_mut() = Some(Rc::new(observer));
+    }
 }
 
 impl<T> crate::VatNetwork<VatId> for VatNetwork<T>
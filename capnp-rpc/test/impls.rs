@@ -20,8 +20,9 @@
 // THE SOFTWARE.
 
 use crate::test_capnp::{
-    bootstrap, test_call_order, test_capability_server_set, test_extends, test_handle,
-    test_interface, test_more_stuff, test_pipeline,
+    bootstrap, test_call_order, test_capability_server_set, test_diamond, test_diamond_base,
+    test_diamond_left, test_diamond_right, test_extends, test_foo_rejection, test_handle,
+    test_implicit_params, test_interface, test_more_stuff, test_pipeline, test_streaming,
 };
 
 use capnp::capability::Promise;
@@ -112,6 +113,36 @@ impl bootstrap::Server for Bootstrap {
             .set_cap(capnp_rpc::new_client(TestCapabilityServerSet::new()));
         Ok(())
     }
+    async fn test_streaming(
+        &self,
+        _params: bootstrap::TestStreamingParams,
+        mut results: bootstrap::TestStreamingResults,
+    ) -> Result<(), Error> {
+        results
+            .get()
+            .set_cap(capnp_rpc::new_client(TestStreaming::default()));
+        Ok(())
+    }
+
+    async fn test_implicit_params(
+        &self,
+        _params: bootstrap::TestImplicitParamsParams,
+        mut results: bootstrap::TestImplicitParamsResults,
+    ) -> Result<(), Error> {
+        results
+            .get()
+            .set_cap(capnp_rpc::new_client(TestImplicitParams));
+        Ok(())
+    }
+
+    async fn test_diamond(
+        &self,
+        _params: bootstrap::TestDiamondParams,
+        mut results: bootstrap::TestDiamondResults,
+    ) -> Result<(), Error> {
+        results.get().set_cap(capnp_rpc::new_client(TestDiamond));
+        Ok(())
+    }
 }
 
 #[derive(Default)]
@@ -140,7 +171,14 @@ impl test_interface::Server for TestInterface {
         self.increment_call_count();
         let params = params.get()?;
         if params.get_i() != 123 {
-            return Err(Error::failed("expected i to equal 123".to_string()));
+            let mut message = capnp::message::Builder::new_default();
+            let mut rejection = message.init_root::<test_foo_rejection::Builder>();
+            rejection.set_expected_i(123);
+            rejection.set_actual_i(params.get_i());
+            return Err(Error::failed_with_payload(
+                "expected i to equal 123".to_string(),
+                rejection.into_reader(),
+            )?);
         }
         if !params.get_j() {
             return Err(Error::failed("expected j to be true".to_string()));
@@ -172,7 +210,7 @@ impl test_interface::Server for TestInterface {
     }
 }
 
-struct TestExtends;
+pub struct TestExtends;
 
 impl test_interface::Server for TestExtends {
     async fn foo(
@@ -621,6 +659,60 @@ impl test_interface::Server for TestCapDestructor {
     }
 }
 
+/// A `TestInterface` whose `foo()` does not return until a gate is opened.
+pub struct TestGatedInterface {
+    started: RefCell<Option<tokio::sync::oneshot::Sender<()>>>,
+    gate: RefCell<Option<tokio::sync::oneshot::Receiver<()>>>,
+    imp: TestInterface,
+}
+
+impl TestGatedInterface {
+    /// `started` is fulfilled when `foo()` is called; `foo()` returns once `gate` is fulfilled.
+    pub fn new(
+        started: tokio::sync::oneshot::Sender<()>,
+        gate: tokio::sync::oneshot::Receiver<()>,
+    ) -> Self {
+        Self {
+            started: RefCell::new(Some(started)),
+            gate: RefCell::new(Some(gate)),
+            imp: TestInterface::new(),
+        }
+    }
+}
+
+impl test_interface::Server for TestGatedInterface {
+    async fn foo(
+        &self,
+        params: test_interface::FooParams,
+        results: test_interface::FooResults,
+    ) -> Result<(), Error> {
+        if let Some(started) = self.started.take() {
+            let _ = started.send(());
+        }
+        if let Some(gate) = self.gate.take() {
+            gate.await
+                .map_err(|_| Error::failed("gate was dropped".to_string()))?;
+        }
+        self.imp.foo(params, results).await
+    }
+
+    async fn bar(
+        &self,
+        _params: test_interface::BarParams,
+        _results: test_interface::BarResults,
+    ) -> Result<(), Error> {
+        Err(Error::unimplemented("bar is not implemented".to_string()))
+    }
+
+    async fn baz(
+        &self,
+        _params: test_interface::BazParams,
+        _results: test_interface::BazResults,
+    ) -> Result<(), Error> {
+        Err(Error::unimplemented("baz is not implemented".to_string()))
+    }
+}
+
 #[derive(Default)]
 pub struct CssHandle {}
 
@@ -675,3 +767,96 @@ impl test_capability_server_set::Server for TestCapabilityServerSet {
         Ok(())
     }
 }
+
+#[derive(Default)]
+pub struct TestStreaming {
+    total: Cell<u64>,
+    released: Cell<bool>,
+    held: RefCell<Vec<tokio::sync::oneshot::Sender<()>>>,
+}
+
+impl test_streaming::Server for TestStreaming {
+    async fn write(&self, params: test_streaming::WriteParams) -> Result<(), Error> {
+        let len = params.get()?.get_data()?.len() as u64;
+        self.total.set(self.total.get() + len);
+        if self.released.get() {
+            return Ok(());
+        }
+        let (sender, receiver) = tokio::sync::oneshot::channel();
+        self.held.borrow_mut().push(sender);
+        let _ = receiver.await;
+        Ok(())
+    }
+
+    async fn release(
+        &self,
+        _params: test_streaming::ReleaseParams,
+        _results: test_streaming::ReleaseResults,
+    ) -> Result<(), Error> {
+        self.released.set(true);
+        let held = std::mem::take(&mut *self.held.borrow_mut());
+        for sender in held {
+            let _ = sender.send(());
+        }
+        Ok(())
+    }
+
+    async fn total(
+        &self,
+        _params: test_streaming::TotalParams,
+        mut results: test_streaming::TotalResults,
+    ) -> Result<(), Error> {
+        results.get().set_bytes(self.total.get());
+        Ok(())
+    }
+}
+
+pub struct TestImplicitParams;
+
+impl test_implicit_params::Server for TestImplicitParams {
+    async fn generify(
+        &self,
+        params: test_implicit_params::GenerifyParams,
+        mut results: test_implicit_params::GenerifyResults,
+    ) -> Result<(), Error> {
+        // The server sees `T` as `AnyPointer`, so it can pass any value through.
+        results.get().set_result(params.get()?.get_value()?)
+    }
+}
+
+pub struct TestDiamond;
+
+impl test_diamond_base::Server for TestDiamond {
+    async fn describe(
+        &self,
+        _params: test_diamond_base::DescribeParams,
+        mut results: test_diamond_base::DescribeResults,
+    ) -> Result<(), Error> {
+        results.get().set_name("diamond".into());
+        Ok(())
+    }
+}
+
+impl test_diamond_left::Server for TestDiamond {
+    async fn left(
+        &self,
+        _params: test_diamond_left::LeftParams,
+        mut results: test_diamond_left::LeftResults,
+    ) -> Result<(), Error> {
+        results.get().set_n(1);
+        Ok(())
+    }
+}
+
+impl test_diamond_right::Server for TestDiamond {
+    async fn right(
+        &self,
+        _params: test_diamond_right::RightParams,
+        mut results: test_diamond_right::RightResults,
+    ) -> Result<(), Error> {
+        results.get().set_n(2);
+        Ok(())
+    }
+}
+
+impl test_diamond::Server for TestDiamond {}
@@ -1,12 +1,13 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::future::Future;
 use std::rc::Rc;
+use std::time::Duration;
 
 use capnp::capability::{Promise, Response};
 use capnp::Error;
 use capnp_rpc::{
-    auto_reconnect, lazy_auto_reconnect, new_client, new_promise_client, rpc_twoparty_capnp,
-    twoparty, RpcSystem,
+    auto_reconnect, auto_reconnect_async, lazy_auto_reconnect, new_client, new_promise_client,
+    rpc_twoparty_capnp, twoparty, RetryPolicy, RpcSystem,
 };
 use futures_util::future::Shared;
 use futures_util::FutureExt;
@@ -452,3 +453,104 @@ async fn lazy_auto_reconnect_test() {
     assert_eq!(test(&pool, &client, 456, false).unwrap(), "456 false 2");
     assert_eq!(*connect_count.borrow(), 3);
 }
+
+// A loopback server that `connect()` starts anew for each connection, unless it has been
+// told to refuse some attempts first.
+#[derive(Clone, Default)]
+struct RestartingServer {
+    generation: Rc<Cell<usize>>,
+    refuse: Rc<Cell<u32>>,
+    attempts: Rc<Cell<u32>>,
+    current: Rc<RefCell<Option<(TestInterfaceImpl, capnp_rpc::Disconnector<twoparty::VatId>)>>>,
+}
+
+impl RestartingServer {
+    async fn connect(self) -> capnp::Result<test_interface::Client> {
+        self.attempts.set(self.attempts.get() + 1);
+        if self.refuse.get() > 0 {
+            self.refuse.set(self.refuse.get() - 1);
+            return Err(Error::disconnected("connection refused".into()));
+        }
+        let (client_writer, server_reader) = async_byte_channel::channel();
+        let (server_writer, client_reader) = async_byte_channel::channel();
+        let server_network = Box::new(twoparty::VatNetwork::new(
+            server_reader,
+            server_writer,
+            rpc_twoparty_capnp::Side::Server,
+            Default::default(),
+        ));
+        let server = TestInterfaceImpl::new(self.generation.get());
+        self.generation.set(self.generation.get() + 1);
+        let bootstrap: test_interface::Client = new_client(server.clone());
+        let server_rpc_system = RpcSystem::new(server_network, Some(bootstrap.client));
+        *self.current.borrow_mut() = Some((server, server_rpc_system.get_disconnector()));
+        tokio::task::spawn_local(server_rpc_system);
+
+        let client_network = Box::new(twoparty::VatNetwork::new(
+            client_reader,
+            client_writer,
+            rpc_twoparty_capnp::Side::Client,
+            Default::default(),
+        ));
+        let mut client_rpc_system = RpcSystem::new(client_network, None);
+        let client = client_rpc_system.bootstrap(rpc_twoparty_capnp::Side::Server);
+        tokio::task::spawn_local(client_rpc_system);
+        Ok(client)
+    }
+
+    async fn kill(&self) -> capnp::Result<()> {
+        let (_, disconnector) = self.current.borrow_mut().take().expect("server is running");
+        disconnector.await
+    }
+}
+
+async fn call(client: &test_interface::Client, i: u32, j: bool) -> Result<String, Error> {
+    Ok(test_promise(client, i, j)
+        .await?
+        .get()?
+        .get_x()?
+        .to_string()?)
+}
+
+/// auto_reconnect_async() over a twoparty connection whose server is killed and restarted
+#[tokio::test(flavor = "current_thread")]
+async fn auto_reconnect_async_restarted_server() {
+    let pool = LocalSet::new();
+    pool.run_until(async {
+        let server = RestartingServer::default();
+        let connecting = server.clone();
+        let (client, _s) = auto_reconnect_async(
+            move || connecting.clone().connect(),
+            RetryPolicy::new(3).backoff(Duration::from_millis(1), Duration::from_millis(4)),
+        );
+        assert_eq!(call(&client, 1, true).await.unwrap(), "1 true 0");
+
+        // A call in flight when the server goes away fails instead of being retried.
+        let _fulfiller = server.current.borrow().as_ref().unwrap().0.block();
+        let in_flight = tokio::task::spawn_local(test_promise(&client, 2, false));
+        tokio::task::yield_now().await;
+        server.kill().await.unwrap();
+        let err = in_flight.await.unwrap().err().unwrap();
+        assert_eq!(err.kind, capnp::ErrorKind::Disconnected);
+        assert_eq!(call(&client, 3, true).await.unwrap(), "3 true 1");
+        assert_eq!(server.attempts.get(), 2);
+
+        // The server comes back after refusing two attempts.
+        server.kill().await.unwrap();
+        server.refuse.set(2);
+        let err = call(&client, 4, false).await.unwrap_err();
+        assert_eq!(err.kind, capnp::ErrorKind::Disconnected);
+        assert_eq!(call(&client, 5, true).await.unwrap(), "5 true 2");
+        assert_eq!(server.attempts.get(), 5);
+
+        // Three refused attempts exhaust the policy. The next call tries again.
+        server.kill().await.unwrap();
+        server.refuse.set(3);
+        call(&client, 6, false).await.unwrap_err();
+        let err = call(&client, 7, true).await.unwrap_err();
+        assert!(err.extra.contains("connection refused"), "{err}");
+        assert_eq!(server.attempts.get(), 8);
+        assert_eq!(call(&client, 8, false).await.unwrap(), "8 false 3");
+    })
+    .await;
+}
@@ -27,7 +27,9 @@ use capnp::Error;
 use capnp_rpc::{rpc_twoparty_capnp, twoparty, RpcSystem};
 
 use futures_util::{FutureExt, TryFutureExt};
+use std::cell::Cell;
 use std::future::Future;
+use std::rc::Rc;
 use tokio::sync::oneshot;
 
 capnp_import::capnp_import!("test.capnp");
@@ -57,6 +59,18 @@ async fn drop_rpc_system() {
 fn disconnector_setup() -> (
     RpcSystem<capnp_rpc::rpc_twoparty_capnp::Side>,
     RpcSystem<capnp_rpc::rpc_twoparty_capnp::Side>,
+) {
+    let bootstrap: test_capnp::bootstrap::Client = capnp_rpc::new_client(impls::Bootstrap);
+    connect_two_party(bootstrap.client)
+}
+
+/// Returns the client and server ends of an in-process two-party connection whose server
+/// exports `bootstrap`.
+fn connect_two_party(
+    bootstrap: capnp::capability::Client,
+) -> (
+    RpcSystem<capnp_rpc::rpc_twoparty_capnp::Side>,
+    RpcSystem<capnp_rpc::rpc_twoparty_capnp::Side>,
 ) {
     let (client_writer, server_reader) = async_byte_channel::channel();
     let (server_writer, client_reader) = async_byte_channel::channel();
@@ -76,8 +90,7 @@ fn disconnector_setup() -> (
         Default::default(),
     ));
 
-    let bootstrap: test_capnp::bootstrap::Client = capnp_rpc::new_client(impls::Bootstrap);
-    let server_rpc_system = RpcSystem::new(server_network, Some(bootstrap.client));
+    let server_rpc_system = RpcSystem::new(server_network, Some(bootstrap));
 
     (client_rpc_system, server_rpc_system)
 }
@@ -244,7 +257,127 @@ async fn disconnector_disconnects_2() {
     .await;
 }
 
+#[tokio::test]
+async fn shutdown_drains_in_flight_calls() {
+    let pool = tokio::task::LocalSet::new();
+    let (mut client_rpc_system, server_rpc_system) = disconnector_setup();
+
+    let client: test_capnp::bootstrap::Client =
+        client_rpc_system.bootstrap(rpc_twoparty_capnp::Side::Server);
+    let shutdown = server_rpc_system.shutdown(None);
+
+    spawn(&pool, client_rpc_system);
+    spawn(&pool, server_rpc_system);
+
+    pool.run_until(async move {
+        let more_stuff = client
+            .test_more_stuff_request()
+            .send()
+            .promise
+            .await?
+            .get()?
+            .get_cap()?;
+
+        let (started_tx, started_rx) = oneshot::channel();
+        let (gate_tx, gate_rx) = oneshot::channel();
+        let gated: test_capnp::test_interface::Client =
+            capnp_rpc::new_client(impls::TestGatedInterface::new(started_tx, gate_rx));
+
+        // The server calls back into `gated`, which holds the call open until the gate opens.
+        let mut request = more_stuff.call_foo_request();
+        request.get().set_cap(gated);
+        let slow_call = request.send().promise;
+        started_rx.await.map_err(crate::canceled_to_error)?;
+
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
+        tokio::task::spawn_local(shutdown.map(move |r| {
+            let _ = shutdown_tx.send(r);
+        }));
+        tokio::task::yield_now().await;
+
+        // New calls are rejected while draining.
+        match client.test_interface_request().send().promise.await {
+            Err(ref e) if e.kind == ::capnp::ErrorKind::Disconnected => (),
+            Err(e) => panic!("wrong kind of error: {:?}", e),
+            Ok(_) => panic!("call should have been rejected during shutdown"),
+        }
+
+        // The in-flight call still completes.
+        let _ = gate_tx.send(());
+        let response = slow_call.await?;
+        assert_eq!(response.get()?.get_s()?, "bar");
+        drop(response);
+
+        shutdown_rx.await.map_err(crate::canceled_to_error)??;
+
+        match client.test_interface_request().send().promise.await {
+            Err(ref e) if e.kind == ::capnp::ErrorKind::Disconnected => (),
+            _ => panic!("Should have gotten a 'disconnected' error."),
+        }
+        Ok::<(), Error>(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn shutdown_timeout_disconnects_remaining_calls() {
+    let pool = tokio::task::LocalSet::new();
+    let (mut client_rpc_system, server_rpc_system) = disconnector_setup();
+
+    let client: test_capnp::bootstrap::Client =
+        client_rpc_system.bootstrap(rpc_twoparty_capnp::Side::Server);
+    let shutdown = client_rpc_system.shutdown(Some(std::time::Duration::from_millis(50)));
+
+    spawn(&pool, client_rpc_system);
+    spawn(&pool, server_rpc_system);
+
+    pool.run_until(async move {
+        let more_stuff = client
+            .test_more_stuff_request()
+            .send()
+            .promise
+            .await?
+            .get()?
+            .get_cap()?;
+
+        let mut request = more_stuff.never_return_request();
+        request
+            .get()
+            .set_cap(capnp_rpc::new_client(impls::TestInterface::new()));
+        let never_returns = request.send().promise;
+
+        shutdown.await?;
+
+        match never_returns.await {
+            Err(ref e) if e.kind == ::capnp::ErrorKind::Disconnected => (),
+            Err(e) => panic!("wrong kind of error: {:?}", e),
+            Ok(_) => panic!("neverReturn() should not have returned"),
+        }
+        Ok::<(), Error>(())
+    })
+    .await
+    .unwrap();
+}
+
+/// Runs `main` against a server over a plain connection, and again over a packed one.
 async fn rpc_top_level<F, G>(main: F)
+where
+    F: FnOnce(test_capnp::bootstrap::Client) -> G,
+    F: Clone + Send + 'static,
+    G: Future<Output = Result<(), Error>> + 'static,
+{
+    use twoparty::Compression;
+    rpc_top_level_with_compression(Compression::None, Compression::None, main.clone()).await;
+    rpc_top_level_with_compression(Compression::Packed, Compression::Packed, main).await;
+}
+
+/// Returns the total number of bytes that the client's messages took unpacked and on the wire.
+async fn rpc_top_level_with_compression<F, G>(
+    client_compression: twoparty::Compression,
+    server_compression: twoparty::Compression,
+    main: F,
+) -> (usize, usize)
 where
     F: FnOnce(test_capnp::bootstrap::Client) -> G,
     F: Send + 'static,
@@ -255,11 +388,12 @@ where
     let (server_writer, client_reader) = async_byte_channel::channel();
 
     let join_handle = std::thread::spawn(move || {
-        let network = Box::new(twoparty::VatNetwork::new(
+        let network = Box::new(twoparty::VatNetwork::new_with_compression(
             server_reader,
             server_writer,
             rpc_twoparty_capnp::Side::Server,
             Default::default(),
+            server_compression,
         ));
 
         let bootstrap: test_capnp::bootstrap::Client = capnp_rpc::new_client(impls::Bootstrap);
@@ -268,14 +402,21 @@ where
         spawner.block_on(rpc_system).unwrap();
     });
 
-    let network = Box::new(twoparty::VatNetwork::new(
+    let mut network = twoparty::VatNetwork::new_with_compression(
         client_reader,
         client_writer,
         rpc_twoparty_capnp::Side::Client,
         Default::default(),
-    ));
+        client_compression,
+    );
+    let totals = Rc::new(Cell::new((0, 0)));
+    let observed_totals = totals.clone();
+    network.set_size_observer(move |sizes| {
+        let (unpacked, wire) = observed_totals.get();
+        observed_totals.set((unpacked + sizes.unpacked_bytes, wire + sizes.wire_bytes));
+    });
 
-    let mut rpc_system = RpcSystem::new(network, None);
+    let mut rpc_system = RpcSystem::new(Box::new(network), None);
     let client: test_capnp::bootstrap::Client =
         rpc_system.bootstrap(rpc_twoparty_capnp::Side::Server);
 
@@ -289,6 +430,7 @@ where
     // TODO: Even though we are now using tokio::task::LocalSet, this still seems to be necessary
     pool.run_until(disconnector).await.unwrap();
     join_handle.join().unwrap();
+    totals.get()
 }
 
 #[tokio::test]
@@ -296,6 +438,48 @@ async fn do_nothing() {
     rpc_top_level(|_client| async { Ok(()) }).await;
 }
 
+async fn call_foo_repeatedly(client: test_capnp::bootstrap::Client) -> Result<(), Error> {
+    let response = client.test_interface_request().send().promise.await?;
+    let client = response.get()?.get_cap()?;
+    for _ in 0..20 {
+        let response = client.build_foo_request(123, true).send().promise.await?;
+        assert_eq!(response.get()?.get_x()?, "foo");
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn packed_connection_is_smaller_on_the_wire() {
+    use twoparty::Compression;
+    let (unpacked, wire) =
+        rpc_top_level_with_compression(Compression::None, Compression::None, call_foo_repeatedly)
+            .await;
+    assert_eq!(unpacked, wire);
+
+    let (packed_unpacked, packed_wire) = rpc_top_level_with_compression(
+        Compression::Packed,
+        Compression::Packed,
+        call_foo_repeatedly,
+    )
+    .await;
+    assert!(packed_wire < packed_unpacked);
+    assert!(packed_wire < wire);
+}
+
+#[tokio::test]
+async fn packed_connection_falls_back_to_plain() {
+    use twoparty::Compression;
+    let (unpacked, wire) =
+        rpc_top_level_with_compression(Compression::Packed, Compression::None, call_foo_repeatedly)
+            .await;
+    assert_eq!(unpacked, wire);
+
+    let (unpacked, wire) =
+        rpc_top_level_with_compression(Compression::None, Compression::Packed, call_foo_repeatedly)
+            .await;
+    assert_eq!(unpacked, wire);
+}
+
 #[tokio::test]
 async fn basic_rpc_calls() {
     rpc_top_level(|client| async move {
@@ -336,6 +520,414 @@ async fn basic_rpc_calls() {
     .await;
 }
 
+#[tokio::test]
+async fn customized_request() {
+    rpc_top_level(|client| async move {
+        let response = client.test_interface_request().send().promise.await?;
+        let client = response.get()?.get_cap()?;
+
+        // The server rejects any `i` other than 123, so this only succeeds if the hook's
+        // change reaches it.
+        let request = client.build_foo_request_with(5, true, |mut params| {
+            assert_eq!(params.reborrow().get_i(), 5);
+            params.set_i(123);
+        });
+        let response = request.send().promise.await?;
+        assert_eq!(response.get()?.get_x()?, "foo");
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn error_payload() {
+    rpc_top_level(|client| async move {
+        let response = client.test_interface_request().send().promise.await?;
+        let client = response.get()?.get_cap()?;
+
+        let e = match client.build_foo_request(5, true).send().promise.await {
+            Ok(_) => return Err(Error::failed("expected foo() to fail".to_string())),
+            Err(e) => e,
+        };
+        assert_eq!(e.kind, ::capnp::ErrorKind::Failed);
+        let payload = e
+            .get_payload_as::<test_capnp::test_foo_rejection::Owned>()
+            .expect("error should carry a payload")?;
+        let rejection = payload.get()?;
+        assert_eq!(rejection.get_expected_i(), 123);
+        assert_eq!(rejection.get_actual_i(), 5);
+
+        // Errors without a payload still come through as plain errors.
+        let e = match client.bar_request().send().promise.await {
+            Ok(_) => return Err(Error::failed("expected bar() to fail".to_string())),
+            Err(e) => e,
+        };
+        assert!(e.raw_payload().is_none());
+        Ok(())
+    })
+    .await;
+}
+
+/// Connects a `TestInterface` server to a client with `pair`, which is one of the functions in
+/// `capnp_rpc::test_util`, and spawns the connection on the current `LocalSet`.
+fn test_interface_pair<P, F>(pair: P) -> (test_capnp::test_interface::Client, Rc<Cell<u64>>)
+where
+    P: FnOnce(impls::TestInterface) -> (test_capnp::test_interface::Client, F),
+    F: Future<Output = Result<(), Error>> + 'static,
+{
+    let server = impls::TestInterface::new();
+    let call_count = server.get_call_count();
+    let (client, connection) = pair(server);
+    spawn_local(connection);
+    (client, call_count)
+}
+
+/// Returns the errors of a `foo()` call that fails with a payload and of a `bar()` call that is
+/// not implemented.
+async fn test_interface_errors(
+    client: &test_capnp::test_interface::Client,
+) -> Result<(Error, Error), Error> {
+    let foo = match client.build_foo_request(5, true).send().promise.await {
+        Ok(_) => return Err(Error::failed("expected foo() to fail".to_string())),
+        Err(e) => e,
+    };
+    let bar = match client.bar_request().send().promise.await {
+        Ok(_) => return Err(Error::failed("expected bar() to fail".to_string())),
+        Err(e) => e,
+    };
+    Ok((foo, bar))
+}
+
+#[tokio::test]
+async fn test_util_local_pair() {
+    use capnp_rpc::test_util::local_pair;
+
+    tokio::task::LocalSet::new()
+        .run_until(async {
+            let (client, call_count) = test_interface_pair(local_pair);
+            let response = client.build_foo_request(123, true).send().promise.await?;
+            assert_eq!(response.get()?.get_x()?, "foo");
+            assert_eq!(call_count.get(), 1);
+            Ok::<(), Error>(())
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_util_direct_pair() {
+    use capnp_rpc::test_util::direct_pair;
+
+    tokio::task::LocalSet::new()
+        .run_until(async {
+            let (client, call_count) = test_interface_pair(direct_pair);
+            let response = client.build_foo_request(123, true).send().promise.await?;
+            assert_eq!(response.get()?.get_x()?, "foo");
+            assert_eq!(call_count.get(), 1);
+
+            // Without a connection, the client is a handle on the server object itself.
+            let mut set = capnp_rpc::CapabilityServerSet::<
+                impls::TestInterface,
+                test_capnp::test_interface::Client,
+            >::new();
+            assert!(set.get_local_server_of_resolved(&client).is_none());
+            let local: test_capnp::test_interface::Client =
+                set.new_client(impls::TestInterface::new());
+            assert!(set.get_local_server_of_resolved(&local).is_some());
+            Ok::<(), Error>(())
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_util_pairs_report_the_same_errors() {
+    use capnp_rpc::test_util::{direct_pair, local_pair};
+
+    tokio::task::LocalSet::new()
+        .run_until(async {
+            let (local, _) = test_interface_pair(local_pair);
+            let (direct, _) = test_interface_pair(direct_pair);
+            let (local_foo, local_bar) = test_interface_errors(&local).await?;
+            let (direct_foo, direct_bar) = test_interface_errors(&direct).await?;
+
+            // Only the description differs: errors that crossed the connection are prefixed
+            // with "remote exception".
+            for foo in [&local_foo, &direct_foo] {
+                assert_eq!(foo.kind, capnp::ErrorKind::Failed);
+                assert!(foo.extra.ends_with("expected i to equal 123"), "{foo}");
+                let payload = foo
+                    .get_payload_as::<test_capnp::test_foo_rejection::Owned>()
+                    .expect("error should carry a payload")?;
+                assert_eq!(payload.get()?.get_actual_i(), 5);
+            }
+            for bar in [&local_bar, &direct_bar] {
+                assert_eq!(bar.kind, capnp::ErrorKind::Unimplemented);
+                assert!(bar.extra.ends_with("bar is not implemented"), "{bar}");
+                assert!(bar.raw_payload().is_none());
+            }
+            Ok::<(), Error>(())
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn streaming_calls_apply_backpressure() {
+    rpc_top_level(|client| async move {
+        use futures_util::FutureExt;
+
+        const CHUNK_SIZE: usize = 8192;
+
+        let response = client.test_streaming_request().send().promise.await?;
+        let stream = response.get()?.get_cap()?;
+
+        // The server holds every write, so sends stop resolving once the window is full.
+        let mut sent = 0;
+        let blocked = loop {
+            let mut request = stream.write_request();
+            request.get().init_data(CHUNK_SIZE as u32);
+            sent += 1;
+            let mut ready = request.send();
+            match (&mut ready).now_or_never() {
+                Some(result) => result?,
+                None => break ready,
+            }
+            assert!(sent < 100, "stream never applied backpressure");
+        };
+        assert_eq!(sent, capnp_rpc::STREAM_WINDOW_SIZE / CHUNK_SIZE);
+
+        stream.release_request().send().promise.await?;
+        blocked.await?;
+
+        let response = stream.total_request().send().promise.await?;
+        assert_eq!(response.get()?.get_bytes(), (sent * CHUNK_SIZE) as u64);
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn implicit_method_parameters() {
+    rpc_top_level(|client| async move {
+        use crate::test_capnp::{test_all_types, test_foo_rejection};
+
+        let response = client.test_implicit_params_request().send().promise.await?;
+        let cap = response.get()?.get_cap()?;
+
+        let mut request = cap.generify_request::<test_all_types::Owned>();
+        crate::test_util::init_test_message(request.get().init_value());
+        let response = request.send().promise.await?;
+        let result: test_all_types::Reader<'_> = response.get()?.get_result()?;
+        crate::test_util::CheckTestMessage::check_test_message(result);
+
+        let request = cap.build_generify_request_with::<test_foo_rejection::Owned>(|params| {
+            params.init_value().set_expected_i(321);
+        });
+        let response = request.send().promise.await?;
+        assert_eq!(response.get()?.get_result()?.get_expected_i(), 321);
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn forwarder_passes_calls_through() {
+    rpc_top_level(|client| async move {
+        use crate::test_capnp::{test_extends, test_interface};
+
+        let response = client.test_extends_request().send().promise.await?;
+        let forwarder = test_extends::Forwarder(response.get()?.get_cap()?);
+        let client: test_extends::Client = capnp_rpc::new_client(forwarder);
+
+        // A method of the interface itself.
+        let response = client.grault_request().send().promise.await?;
+        crate::test_util::CheckTestMessage::check_test_message(response.get()?);
+
+        // A method inherited from the superclass.
+        let client: test_interface::Client = client.upcast();
+        let mut request = client.foo_request();
+        request.get().set_i(321);
+        request.get().set_j(false);
+        let response = request.send().promise.await?;
+        assert_eq!(response.get()?.get_x()?, "bar");
+
+        // Errors come back as the real server returned them.
+        let mut request = client.foo_request();
+        request.get().set_i(123);
+        let error = match request.send().promise.await {
+            Ok(_) => panic!("expected an error"),
+            Err(e) => e,
+        };
+        assert_eq!(error.kind, ::capnp::ErrorKind::Failed);
+        assert!(
+            error.to_string().contains("expected i to equal 321"),
+            "{error}"
+        );
+        let error = match client.bar_request().send().promise.await {
+            Ok(_) => panic!("expected an error"),
+            Err(e) => e,
+        };
+        assert_eq!(error.kind, ::capnp::ErrorKind::Unimplemented);
+        Ok(())
+    })
+    .await;
+}
+
+async fn expect_error<T>(promise: Promise<T, Error>, expected: &str) {
+    match promise.await {
+        Ok(_) => panic!("expected an error"),
+        Err(e) => assert!(e.to_string().contains(expected), "{e}"),
+    }
+}
+
+#[tokio::test]
+async fn revocable_client() {
+    rpc_top_level(|client| async move {
+        let revocable = capnp_rpc::membrane::RevocableClient::new(
+            client,
+            Error::failed("capability revoked".to_string()),
+        );
+        let client = revocable.client();
+
+        let response = client.test_interface_request().send().promise.await?;
+        let interface = response.get()?.get_cap()?;
+        let mut request = interface.foo_request();
+        request.get().set_i(123);
+        request.get().set_j(true);
+        let foo = request.send().promise.await?;
+        let pipelined = client.test_more_stuff_request().send().pipeline.get_cap();
+        pipelined.get_handle_count_request().send().promise.await?;
+
+        revocable.revoke();
+        assert!(revocable.is_revoked());
+
+        // What was already returned stays readable.
+        assert_eq!(foo.get()?.get_x()?, "foo");
+        response.get()?.get_cap()?;
+
+        // Calls fail, including those on capabilities that came through the membrane.
+        expect_error(
+            client.test_interface_request().send().promise,
+            "capability revoked",
+        )
+        .await;
+        expect_error(interface.bar_request().send().promise, "capability revoked").await;
+        expect_error(
+            pipelined.get_handle_count_request().send().promise,
+            "capability revoked",
+        )
+        .await;
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn revocable_client_cancels_calls_in_flight() {
+    rpc_top_level(|client| async move {
+        let response = client.test_more_stuff_request().send().promise.await?;
+        let revocable = capnp_rpc::membrane::RevocableClient::with_options(
+            response.get()?.get_cap()?,
+            Error::disconnected("capability revoked".to_string()),
+            true,
+        );
+        let client = revocable.client();
+
+        let mut request = client.never_return_request();
+        request
+            .get()
+            .set_cap(capnp_rpc::new_client(impls::TestInterface::new()));
+        let promise = request.send().promise;
+        client.get_handle_count_request().send().promise.await?;
+
+        revocable.revoke();
+        expect_error(promise, "capability revoked").await;
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn membrane_policy_sees_calls_in_both_directions() {
+    use capnp::traits::HasTypeId;
+    use capnp_rpc::membrane::{self, Decision, MembranePolicy};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    struct Audit(Rc<RefCell<Vec<String>>>);
+
+    impl MembranePolicy for Audit {
+        fn inbound_call(&self, interface_id: u64, method_id: u16) -> Decision {
+            self.0.borrow_mut().push(format!("in {method_id}"));
+            if interface_id == test_capnp::test_interface::Client::TYPE_ID && method_id == 1 {
+                Decision::Deny
+            } else {
+                Decision::Allow
+            }
+        }
+
+        fn outbound_call(&self, _interface_id: u64, method_id: u16) -> Decision {
+            self.0.borrow_mut().push(format!("out {method_id}"));
+            Decision::Allow
+        }
+    }
+
+    rpc_top_level(|client| async move {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let client = membrane::wrap(client, Audit(log.clone()));
+
+        let response = client.test_more_stuff_request().send().promise.await?;
+        let more_stuff = response.get()?.get_cap()?;
+
+        // The server calls `foo` back on the capability passed in through the membrane.
+        let mut request = more_stuff.call_foo_request();
+        request
+            .get()
+            .set_cap(capnp_rpc::new_client(impls::TestInterface::new()));
+        let response = request.send().promise.await?;
+        assert_eq!(response.get()?.get_s()?, "bar");
+
+        let response = client.test_interface_request().send().promise.await?;
+        let interface = response.get()?.get_cap()?;
+        expect_error(
+            interface.bar_request().send().promise,
+            "denied by a membrane",
+        )
+        .await;
+
+        assert_eq!(*log.borrow(), ["in 5", "in 0", "out 0", "in 0", "in 1"]);
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn diamond_inheritance() {
+    rpc_top_level(|client| async move {
+        let response = client.test_diamond_request().send().promise.await?;
+        let diamond = response.get()?.get_cap()?;
+
+        let left: test_capnp::test_diamond_left::Client = diamond.clone().upcast();
+        let right: test_capnp::test_diamond_right::Client = diamond.upcast();
+        let response = left.left_request().send().promise.await?;
+        assert_eq!(response.get()?.get_n(), 1);
+        let response = right.right_request().send().promise.await?;
+        assert_eq!(response.get()?.get_n(), 2);
+
+        // The shared base is reachable through either parent.
+        for base in [
+            test_capnp::test_diamond_base::Client::from(left),
+            test_capnp::test_diamond_base::Client::from(right),
+        ] {
+            let response = base.describe_request().send().promise.await?;
+            assert_eq!(response.get()?.get_name()?, "diamond");
+        }
+        Ok(())
+    })
+    .await;
+}
+
 #[tokio::test]
 async fn basic_pipelining() {
     rpc_top_level(|client| async move {
@@ -354,6 +946,10 @@ async fn basic_pipelining() {
         // This is just here to check that code generation for pipelines
         // inside of groups works correctly.
         let _ = promise.pipeline.get_out_box().get_foo().get_cap_in_group();
+        assert_eq!(
+            format!("{:?}", promise.pipeline.get_out_box()),
+            "capnp_rpc_test::test_capnp::test_pipeline::box_::Pipeline"
+        );
 
         let mut pipeline_request = promise.pipeline.get_out_box().get_cap().foo_request();
         pipeline_request.get().set_i(321);
@@ -476,6 +1072,58 @@ async fn release_simple() {
     .await;
 }
 
+#[tokio::test]
+async fn table_stats() {
+    let pool = tokio::task::LocalSet::new();
+    let (mut client_rpc_system, mut server_rpc_system) = disconnector_setup();
+
+    let server_stats = server_rpc_system.get_stats_handle();
+    let exceeded = Rc::new(Cell::new(None));
+    let exceeded1 = exceeded.clone();
+    server_rpc_system.set_table_size_threshold(4, move |stats| exceeded1.set(Some(stats)));
+    assert_eq!(server_rpc_system.stats(), capnp_rpc::RpcStats::default());
+
+    let client: test_capnp::bootstrap::Client =
+        client_rpc_system.bootstrap(rpc_twoparty_capnp::Side::Server);
+    spawn(&pool, client_rpc_system);
+    spawn(&pool, server_rpc_system);
+
+    pool.run_until(async move {
+        let response = client.test_more_stuff_request().send().promise.await?;
+        let client = response.get()?.get_cap()?;
+        drop(response);
+
+        let baseline = server_stats.stats().exports;
+        assert!(baseline + 2 <= 4);
+
+        let mut handles = Vec::new();
+        for _ in 0..2 {
+            let response = client.get_handle_request().send().promise.await?;
+            handles.push(response.get()?.get_handle()?);
+        }
+        assert_eq!(server_stats.stats().exports, baseline + 2);
+
+        // The release messages are delivered before the next call.
+        drop(handles);
+        client.get_handle_count_request().send().promise.await?;
+        assert_eq!(server_stats.stats().exports, baseline);
+        assert_eq!(exceeded.get(), None);
+
+        // Hold on to handles until the export table grows beyond the threshold.
+        let mut leaked = Vec::new();
+        for _ in baseline..5 {
+            let response = client.get_handle_request().send().promise.await?;
+            leaked.push(response.get()?.get_handle()?);
+        }
+        let stats = exceeded.get().expect("threshold callback was not called");
+        assert_eq!(stats.exports, 5);
+        assert_eq!(server_stats.stats().exports, 5);
+        Ok::<(), Error>(())
+    })
+    .await
+    .unwrap();
+}
+
 /*
 #[tokio::test]
 async fn release_on_cancel() {
@@ -721,6 +1369,58 @@ async fn cancel_releases_params() {
     .await;
 }
 
+#[tokio::test]
+async fn deadline_cancels_call() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    rpc_top_level(|client| async move {
+        let response = client.test_more_stuff_request().send().promise.await?;
+        let client = response.get()?.get_cap()?;
+
+        let (fulfiller, promise) = oneshot::channel::<()>();
+        let destroyed = Rc::new(Cell::new(false));
+        let destroyed1 = destroyed.clone();
+        let (destroyed_done_sender, destroyed_done_receiver) = oneshot::channel::<()>();
+        spawn_local(promise.map_err(canceled_to_error).map(move |r| {
+            r?;
+            destroyed1.set(true);
+            let _ = destroyed_done_sender.send(());
+            Ok(())
+        }));
+
+        let mut request = client.never_return_request();
+        request
+            .get()
+            .set_cap(capnp_rpc::new_client(impls::TestCapDestructor::new(
+                fulfiller,
+            )));
+        request.set_deadline(std::time::Duration::from_millis(50));
+        match request.send().promise.await {
+            Err(e) if e.kind == ::capnp::ErrorKind::DeadlineExceeded => (),
+            Err(e) => return Err(Error::failed(format!("unexpected error: {e}"))),
+            Ok(_) => return Err(Error::failed("neverReturn should not return".to_string())),
+        }
+
+        // The server only releases the cap once it has dropped the canceled call.
+        destroyed_done_receiver.map_err(canceled_to_error).await?;
+        if !destroyed.get() {
+            return Err(Error::failed("The cap should be released now.".to_string()));
+        }
+
+        // Calls that return in time are unaffected.
+        let client: crate::test_capnp::test_call_order::Client = client.cast_to();
+        let mut request = client.get_call_sequence_request();
+        request.set_deadline(std::time::Duration::from_secs(10));
+        let response = request.send().promise.await?;
+        if response.get()?.get_n() != 1 {
+            return Err(Error::failed("N should equal 1.".to_string()));
+        }
+        Ok(())
+    })
+    .await;
+}
+
 #[tokio::test]
 async fn dont_hold() {
     rpc_top_level(|client| async move {
@@ -867,6 +1567,44 @@ async fn embargo_error() {
     .await;
 }
 
+#[test]
+fn client_equality() {
+    let a: test_capnp::test_interface::Client = capnp_rpc::new_client(impls::TestInterface::new());
+    let b: test_capnp::test_interface::Client = capnp_rpc::new_client(impls::TestInterface::new());
+    assert!(a == a.clone());
+    assert!(a != b);
+
+    let set: std::collections::HashSet<_> = [a.clone(), b.clone(), a.clone()].into_iter().collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&a) && set.contains(&b));
+}
+
+#[tokio::test]
+async fn client_equality_after_round_trip() {
+    rpc_top_level(|client| async move {
+        let response = client.test_more_stuff_request().send().promise.await?;
+        let client = response.get()?.get_cap()?;
+
+        let cap: crate::test_capnp::test_call_order::Client =
+            capnp_rpc::new_client(crate::impls::TestCallOrder::new());
+        let mut echo_request = client.echo_request();
+        echo_request.get().set_cap(cap.clone());
+        let echo = echo_request.send();
+
+        // The pipelined capability is a promise with a hook of its own.
+        let pipeline = echo.pipeline.get_cap();
+        assert!(pipeline != cap);
+
+        // Coming back over the connection that it was exported on, the capability is
+        // replaced by the local hook that was exported.
+        let response = echo.promise.await?;
+        let returned = response.get()?.get_cap()?;
+        assert!(returned == cap);
+        Ok(())
+    })
+    .await;
+}
+
 #[tokio::test]
 async fn echo_destruction() {
     rpc_top_level(|client| async move {
@@ -901,6 +1639,34 @@ async fn echo_destruction() {
     .await
 }
 
+#[tokio::test]
+async fn optional_capability_field() -> Result<(), Error> {
+    let mut message = capnp_rpc::ImbuedMessageBuilder::new(capnp::message::HeapAllocator::new());
+    let mut root: test_capnp::test_optional_cap::Builder<'_> = message.get_root()?;
+    assert!(root.reborrow().get_cap()?.is_none());
+
+    let server = crate::impls::TestInterface::new();
+    let call_count = server.get_call_count();
+    let client: test_capnp::test_interface::Client = capnp_rpc::new_client(server);
+    root.set_cap(Some(client));
+    assert!(root.has_cap());
+    assert!(root.reborrow_as_reader().get_cap()?.is_some());
+
+    let cap = root.reborrow().get_cap()?.expect("cap should be set");
+    let mut request = cap.foo_request();
+    request.get().set_i(123);
+    request.get().set_j(true);
+    let response = request.send().promise.await?;
+    assert_eq!(response.get()?.get_x()?, "foo");
+    assert_eq!(call_count.get(), 1);
+
+    root.set_cap(None);
+    assert!(!root.has_cap());
+    assert!(root.reborrow().get_cap()?.is_none());
+    assert!(root.into_reader().get_cap()?.is_none());
+    Ok(())
+}
+
 #[test]
 fn local_client_call_not_immediate() {
     let server = crate::impls::TestInterface::new();
@@ -937,6 +1703,114 @@ fn local_client_send_cap() {
     assert_eq!(response.get().unwrap().get_s().unwrap(), "bar");
 }
 
+#[test]
+fn local_client_upcast() {
+    let client: crate::test_capnp::test_extends::Client =
+        capnp_rpc::new_client(crate::impls::TestExtends);
+    let client: crate::test_capnp::test_interface::Client = client.upcast();
+
+    let mut request = client.foo_request();
+    request.get().set_i(321);
+    request.get().set_j(false);
+    let response = tokio::runtime::Runtime::new()
+        .unwrap()
+        .block_on(request.send().promise)
+        .unwrap();
+    assert_eq!(response.get().unwrap().get_x().unwrap(), "bar");
+
+    let client = crate::test_capnp::test_extends::Client::downcast_from(client);
+    let response = tokio::runtime::Runtime::new()
+        .unwrap()
+        .block_on(client.grault_request().send().promise)
+        .unwrap();
+    crate::test_util::CheckTestMessage::check_test_message(response.get().unwrap());
+}
+
+#[test]
+fn interface_method_tables() -> capnp::Result<()> {
+    use crate::test_capnp::{test_all_types, test_extends, test_extends2, test_interface};
+    use capnp::introspect::{Introspect, MethodInfo, TypeVariant};
+
+    // The methods that an interface itself declares, according to its schema.
+    fn declared<T: Introspect>() -> capnp::Result<Vec<MethodInfo>> {
+        let TypeVariant::Capability(raw) = T::introspect().which() else {
+            panic!("expected an interface");
+        };
+        let node = capnp::schema::CapabilitySchema::new(raw).get_proto();
+        let capnp::schema_capnp::node::Interface(interface) = node.which()? else {
+            panic!("expected an interface node");
+        };
+        let mut methods = Vec::new();
+        for (ordinal, method) in interface.get_methods()?.into_iter().enumerate() {
+            methods.push(MethodInfo {
+                interface_id: node.get_id(),
+                ordinal: ordinal as u16,
+                name: method.get_name()?.to_str()?,
+                param_type_id: method.get_param_struct_type(),
+                result_type_id: method.get_result_struct_type(),
+            });
+        }
+        Ok(methods)
+    }
+
+    assert_eq!(
+        test_interface::_private::METHODS,
+        declared::<test_interface::Client>()?
+    );
+    assert_eq!(
+        test_extends::_private::METHODS,
+        [
+            declared::<test_extends::Client>()?,
+            declared::<test_interface::Client>()?
+        ]
+        .concat()
+    );
+    assert_eq!(
+        test_extends2::_private::METHODS,
+        [
+            declared::<test_interface::Client>()?,
+            declared::<test_extends::Client>()?
+        ]
+        .concat()
+    );
+
+    // Calls made with an entry's interface ID and ordinal reach the matching method,
+    // including inherited ones.
+    let find = |name| {
+        test_extends::_private::METHODS
+            .iter()
+            .find(|method| method.name == name)
+            .expect("method should be listed")
+    };
+    let client: test_extends::Client = capnp_rpc::new_client(crate::impls::TestExtends);
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+
+    let foo = find("foo");
+    let mut request = client
+        .client
+        .new_call::<test_interface::foo_params::Owned, test_interface::foo_results::Owned>(
+            foo.interface_id,
+            foo.ordinal,
+            None,
+        );
+    request.get().set_i(321);
+    request.get().set_j(false);
+    let response = runtime.block_on(request.send().promise)?;
+    assert_eq!(response.get()?.get_x()?, "bar");
+
+    let grault = find("grault");
+    let request = client
+        .client
+        .new_call::<test_extends::grault_params::Owned, test_all_types::Owned>(
+            grault.interface_id,
+            grault.ordinal,
+            None,
+        );
+    let response = runtime.block_on(request.send().promise)?;
+    crate::test_util::CheckTestMessage::check_test_message(response.get()?);
+    Ok(())
+}
+
 #[test]
 fn local_client_return_cap() {
     let server = crate::impls::Bootstrap;
@@ -1082,6 +1956,74 @@ fn capability_server_set() {
         .is_none());
 }
 
+#[tokio::test]
+async fn client_handle_shared_between_connections() {
+    use crate::test_capnp::test_interface;
+    use capnp_rpc::ClientHandle;
+
+    let handle: ClientHandle<impls::TestInterface, test_interface::Client> =
+        ClientHandle::new(impls::TestInterface::new());
+    let call_count = handle.dispatch().get_call_count();
+    assert_eq!(handle.strong_count(), 0);
+
+    let released = Rc::new(Cell::new(false));
+    let pool = tokio::task::LocalSet::new();
+    pool.run_until(async move {
+        let mut connections = Vec::new();
+        for _ in 0..2 {
+            let (mut client_rpc_system, server_rpc_system) =
+                connect_two_party(handle.client().client);
+            let client: test_interface::Client =
+                client_rpc_system.bootstrap(rpc_twoparty_capnp::Side::Server);
+            let disconnector = client_rpc_system.get_disconnector();
+            spawn_local(client_rpc_system);
+            let server = tokio::task::spawn_local(server_rpc_system);
+            connections.push((client, disconnector, server));
+        }
+
+        let released2 = released.clone();
+        let on_released = handle.released();
+        tokio::task::spawn_local(async move {
+            on_released.await;
+            released2.set(true);
+        });
+
+        for (client, _, _) in &connections {
+            let mut request = client.foo_request();
+            request.get().set_i(123);
+            request.get().set_j(true);
+            request.send().promise.await.unwrap();
+        }
+        assert_eq!(call_count.get(), 2);
+        assert!(handle.strong_count() >= 2);
+
+        let (client, disconnector, server) = connections.remove(0);
+        drop(client);
+        disconnector.await.unwrap();
+        server.await.unwrap().unwrap();
+        tokio::task::yield_now().await;
+        assert!(!released.get());
+        assert!(handle.strong_count() >= 1);
+
+        // The other connection still reaches the same server.
+        let (client, disconnector, server) = connections.remove(0);
+        let mut request = client.foo_request();
+        request.get().set_i(123);
+        request.get().set_j(true);
+        request.send().promise.await.unwrap();
+        assert_eq!(call_count.get(), 3);
+
+        drop(client);
+        disconnector.await.unwrap();
+        server.await.unwrap().unwrap();
+        tokio::task::yield_now().await;
+        assert!(released.get());
+        assert_eq!(handle.strong_count(), 0);
+        handle.released().await;
+    })
+    .await;
+}
+
 #[tokio::test]
 async fn capability_server_set_rpc() {
     rpc_top_level(|client| async move {
@@ -26,11 +26,14 @@ use alloc::{boxed::Box, vec::Vec};
 
 #[cfg(feature = "alloc")]
 use crate::capability::FromClientHook;
+use crate::introspect::{Type, TypeVariant};
 #[cfg(feature = "alloc")]
 use crate::private::capability::{ClientHook, PipelineHook, PipelineOp};
-use crate::private::layout::{PointerBuilder, PointerReader};
+use crate::private::layout::{PointerBuilder, PointerReader, PointerType, StructReader};
+use crate::schema::{Field, StructSchema};
+use crate::schema_capnp::{field, node};
 use crate::traits::{FromPointerBuilder, FromPointerReader, SetPointerBuilder};
-use crate::Result;
+use crate::{Error, ErrorKind, Result};
 
 #[derive(Copy, Clone)]
 pub struct Owned(());
@@ -75,6 +78,31 @@ impl<'a> Reader<'a> {
         FromPointerReader::get_from_pointer(&self.reader, None)
     }
 
+    /// Like `get_as()`, but first checks the pointer against the schema of `T`.
+    ///
+    /// The pointer must be null or point to the kind of object that `T` is: a struct, a list
+    /// (including text and data), or a capability. For a struct, each pointer field that `T`
+    /// reads, counting only the active member of a union, must in turn be null or point to
+    /// the right kind of object. This catches most attempts to read a struct as the wrong
+    /// type, which `get_as()` lets through as garbage field values. A mismatch is reported
+    /// as a `TypeMismatch` error naming what was expected and what was found. Data fields
+    /// cannot be checked, and nested objects are not checked beyond their kind.
+    pub fn get_as_checked<T: crate::traits::Owned>(&self) -> Result<T::Reader<'a>> {
+        check_pointer(self.reader, T::introspect())?;
+        self.get_as()
+    }
+
+    /// Reads the pointer as a struct described by `schema`, like `Builder::init_dynamic()`.
+    pub fn get_dynamic(
+        &self,
+        schema: crate::schema::StructSchema,
+    ) -> Result<crate::dynamic_struct::Reader<'a>> {
+        Ok(crate::dynamic_struct::Reader::new(
+            self.reader.get_struct(None)?,
+            schema,
+        ))
+    }
+
     #[cfg(feature = "alloc")]
     pub fn get_as_capability<T: FromClientHook>(&self) -> Result<T> {
         Ok(FromClientHook::new(self.reader.get_capability()?))
@@ -157,6 +185,13 @@ impl<'a> Builder<'a> {
         FromPointerBuilder::get_from_pointer(self.builder, None)
     }
 
+    /// Like `get_as()`, but first checks the pointer against the schema of `T`. See
+    /// [`Reader::get_as_checked()`].
+    pub fn get_as_checked<T: crate::traits::Owned>(self) -> Result<T::Builder<'a>> {
+        check_pointer(self.builder.as_reader(), T::introspect())?;
+        self.get_as()
+    }
+
     pub fn init_as<T: FromPointerBuilder<'a>>(self) -> T {
         FromPointerBuilder::init_pointer(self.builder, 0)
     }
@@ -165,6 +200,16 @@ impl<'a> Builder<'a> {
         FromPointerBuilder::init_pointer(self.builder, size)
     }
 
+    /// Like `init_as()`, but returns an error if the message's allocation budget is exhausted.
+    pub fn try_init_as<T: FromPointerBuilder<'a>>(self) -> Result<T> {
+        FromPointerBuilder::try_init_pointer(self.builder, 0)
+    }
+
+    /// Like `initn_as()`, but returns an error if the message's allocation budget is exhausted.
+    pub fn try_initn_as<T: FromPointerBuilder<'a>>(self, size: u32) -> Result<T> {
+        FromPointerBuilder::try_init_pointer(self.builder, size)
+    }
+
     pub fn init_dynamic(
         self,
         schema: crate::schema::StructSchema,
@@ -172,10 +217,10 @@ impl<'a> Builder<'a> {
         if let crate::schema_capnp::node::Which::Struct(s) = schema.proto.which()? {
             Ok(crate::dynamic_struct::Builder::new(
                 self.builder
-                    .init_struct(crate::private::layout::StructSize {
+                    .try_init_struct(crate::private::layout::StructSize {
                         data: s.get_data_word_count(),
                         pointers: s.get_pointer_count(),
-                    }),
+                    })?,
                 schema,
             ))
         } else {
@@ -189,6 +234,25 @@ impl<'a> Builder<'a> {
         SetPointerBuilder::set_pointer_builder(self.builder.reborrow(), value, false)
     }
 
+    /// Sets the pointer to a copy of `value`. Shorthand for `set_as(text::Reader)`.
+    pub fn set_as_text(&mut self, value: &str) {
+        self.builder.reborrow().set_text(value.into());
+    }
+
+    /// Sets the pointer to a copy of `value`. Shorthand for `set_as(&[u8])`.
+    pub fn set_as_data(&mut self, value: &[u8]) {
+        self.builder.reborrow().set_data(value);
+    }
+
+    /// Sets the pointer to a copy of the struct `value`. Unlike `set_as()`, this only accepts
+    /// struct readers, and names the struct type, as in `set_as_struct::<foo::Owned>(foo)`.
+    pub fn set_as_struct<T: crate::traits::OwnedStruct>(
+        &mut self,
+        value: T::Reader<'_>,
+    ) -> Result<()> {
+        self.set_as(value)
+    }
+
     // XXX value should be a user client.
     #[cfg(feature = "alloc")]
     pub fn set_as_capability(&mut self, value: Box<dyn ClientHook>) {
@@ -225,6 +289,112 @@ impl<'a> FromPointerBuilder<'a> for Builder<'a> {
     }
 }
 
+// Returns a `TypeMismatch` error unless `pointer` can hold a value of type `ty`. See
+// `Reader::get_as_checked()`.
+fn check_pointer(pointer: PointerReader<'_>, ty: Type) -> Result<()> {
+    let found = pointer.get_pointer_type()?;
+    if !kind_matches(ty, &found) {
+        let mut error = Error::from_kind(ErrorKind::TypeMismatch);
+        write!(error, "expected a pointer to ");
+        write_type(&mut error, ty)?;
+        write!(error, ", but found {}", describe(&found));
+        return Err(error);
+    }
+    match (ty.which(), found) {
+        (TypeVariant::Struct(schema), PointerType::Struct) => {
+            check_struct_fields(pointer.get_struct(None)?, schema.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn check_struct_fields(reader: StructReader<'_>, schema: StructSchema) -> Result<()> {
+    for field in schema.get_non_union_fields()? {
+        check_field(reader, schema, field)?;
+    }
+    if let node::Struct(st) = schema.get_proto().which()? {
+        if st.get_discriminant_count() > 0 {
+            let discriminant = reader.get_data_field::<u16>(st.get_discriminant_offset() as usize);
+            if let Some(field) = schema.get_field_by_discriminant(discriminant)? {
+                check_field(reader, schema, field)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_field(reader: StructReader<'_>, schema: StructSchema, field: Field) -> Result<()> {
+    let ty = field.get_type();
+    match field.get_proto().which()? {
+        // A group shares the data and pointer sections of its parent.
+        field::Group(_) => match ty.which() {
+            TypeVariant::Struct(group) => check_struct_fields(reader, group.into()),
+            _ => Ok(()),
+        },
+        field::Slot(slot) if ty.is_pointer_type() => {
+            let found = reader
+                .get_pointer_field(slot.get_offset() as usize)
+                .get_pointer_type()?;
+            if kind_matches(ty, &found) {
+                return Ok(());
+            }
+            let mut error = Error::from_kind(ErrorKind::TypeMismatch);
+            write!(
+                error,
+                "field `{}` of `{}` expects ",
+                field.get_proto().get_name()?.to_str()?,
+                schema.get_proto().get_display_name()?.to_str()?
+            );
+            write_type(&mut error, ty)?;
+            write!(error, ", but its pointer holds {}", describe(&found));
+            Err(error)
+        }
+        field::Slot(_) => Ok(()),
+    }
+}
+
+fn kind_matches(ty: Type, found: &PointerType) -> bool {
+    matches!(
+        (ty.which(), found),
+        (_, PointerType::Null)
+            | (TypeVariant::AnyPointer, _)
+            | (TypeVariant::Struct(_), PointerType::Struct)
+            | (
+                TypeVariant::List(_) | TypeVariant::Text | TypeVariant::Data,
+                PointerType::List
+            )
+            | (TypeVariant::Capability(_), PointerType::Capability(_))
+    )
+}
+
+fn write_type(error: &mut Error, ty: Type) -> Result<()> {
+    match ty.which() {
+        TypeVariant::Struct(schema) => write!(
+            error,
+            "struct `{}`",
+            StructSchema::from(schema)
+                .get_proto()
+                .get_display_name()?
+                .to_str()?
+        ),
+        TypeVariant::List(_) => write!(error, "a list"),
+        TypeVariant::Text => write!(error, "text"),
+        TypeVariant::Data => write!(error, "data"),
+        TypeVariant::Capability(_) => write!(error, "a capability"),
+        _ => write!(error, "a non-pointer type"),
+    }
+    Ok(())
+}
+
+fn describe(found: &PointerType) -> &'static str {
+    match found {
+        PointerType::Null => "null",
+        PointerType::Struct => "a struct",
+        PointerType::List => "a list",
+        PointerType::Capability(_) => "a capability",
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl<'a> crate::traits::ImbueMut<'a> for Builder<'a> {
     fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
@@ -224,6 +224,19 @@ where
     pub fn set(&mut self, from: Params::Reader<'_>) -> crate::Result<()> {
         self.hook.get().set_as(from)
     }
+
+    /// Gives the call a deadline of `timeout` after it is sent. If no response has arrived by
+    /// then, the call is canceled and its promise rejects with
+    /// [`ErrorKind::DeadlineExceeded`](crate::ErrorKind::DeadlineExceeded).
+    ///
+    /// Cancellation is delivered to the callee once the promise and any pipelined
+    /// capabilities obtained from it have been dropped.
+    ///
+    /// A request that a server sends as a tail call ignores the deadline: its results go to the
+    /// caller of the call being answered, whose own deadline covers them.
+    pub fn set_deadline(&mut self, timeout: core::time::Duration) {
+        self.hook.set_deadline(timeout)
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -249,6 +262,46 @@ where
     }
 }
 
+/// A call to a streaming method, i.e. one whose result is declared as `stream`, that has not
+/// been sent yet.
+#[cfg(feature = "alloc")]
+pub struct StreamingRequest<Params> {
+    pub marker: PhantomData<Params>,
+    pub hook: Box<dyn RequestHook>,
+}
+
+#[cfg(feature = "alloc")]
+impl<Params> StreamingRequest<Params>
+where
+    Params: Owned,
+{
+    pub fn new(hook: Box<dyn RequestHook>) -> Self {
+        Self {
+            hook,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn get(&mut self) -> Params::Builder<'_> {
+        self.hook.get().get_as().unwrap()
+    }
+
+    pub fn set(&mut self, from: Params::Reader<'_>) -> crate::Result<()> {
+        self.hook.get().set_as(from)
+    }
+
+    /// Sends the call. The returned promise resolves once the stream is ready for another
+    /// call, which over an RPC connection can be well before this call returns: calls are
+    /// sent as long as the bytes in flight fit in a window, and awaiting the promise applies
+    /// backpressure once they don't.
+    ///
+    /// If an earlier call of the stream failed, the promise rejects with that error. To learn
+    /// whether the last calls succeeded, follow them with a call to an ordinary method.
+    pub fn send(self) -> Promise<(), Error> {
+        self.hook.send_streaming()
+    }
+}
+
 /// The values of the parameters passed to a method call, as seen by the server.
 #[cfg(feature = "alloc")]
 pub struct Params<T> {
@@ -352,6 +405,19 @@ impl Client {
         }
     }
 
+    pub fn new_streaming_call<Params>(
+        &self,
+        interface_id: u64,
+        method_id: u16,
+        size_hint: Option<MessageSize>,
+    ) -> StreamingRequest<Params> {
+        let typeless = self.hook.new_call(interface_id, method_id, size_hint);
+        StreamingRequest {
+            hook: typeless.hook,
+            marker: PhantomData,
+        }
+    }
+
     /// If the capability is actually only a promise, the returned promise resolves once the
     /// capability itself has resolved to its final destination (or propagates the exception if
     /// the capability promise is rejected).  This is mainly useful for error-checking in the case
@@ -362,6 +428,27 @@ impl Client {
     }
 }
 
+/// Clients are equal when they share the same underlying hook, as reported by
+/// [`ClientHook::get_ptr()`]. Clones of a client are equal to it, but two clients for the
+/// same remote object need not be: for example, a promise compares unequal to the
+/// capability it resolves to.
+#[cfg(feature = "alloc")]
+impl PartialEq for Client {
+    fn eq(&self, other: &Self) -> bool {
+        self.hook.get_ptr() == other.hook.get_ptr()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Eq for Client {}
+
+#[cfg(feature = "alloc")]
+impl core::hash::Hash for Client {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.hook.get_ptr().hash(state)
+    }
+}
+
 #[cfg(feature = "alloc")]
 // This is an untyped dispatch for an untyped server, which forwards calls directly to dispatch_call
 pub struct UntypedDispatch<_T> {
@@ -63,6 +63,9 @@ impl<'a> crate::traits::FromPointerBuilder<'a> for Builder<'a> {
     fn init_pointer(builder: PointerBuilder<'a>, size: u32) -> Builder<'a> {
         builder.init_data(size)
     }
+    fn try_init_pointer(builder: PointerBuilder<'a>, size: u32) -> Result<Builder<'a>> {
+        builder.try_init_data(size)
+    }
     fn get_from_pointer(
         builder: PointerBuilder<'a>,
         default: Option<&'a [crate::Word]>,
@@ -77,11 +80,98 @@ impl<'a> crate::traits::SetPointerBuilder for Reader<'a> {
         value: Reader<'a>,
         _canonicalize: bool,
     ) -> Result<()> {
-        pointer.set_data(value);
-        Ok(())
+        pointer.try_set_data(value)
     }
 }
 
+mod private {
+    pub trait Sealed {}
+}
+
+/// A number type that data can be viewed as with [`as_slice_of()`]: any bytes of the right size
+/// are a valid value of it. Cannot be implemented outside of this crate.
+pub trait Element: Copy + private::Sealed {
+    #[doc(hidden)]
+    fn from_le_bytes(bytes: &[u8]) -> Self;
+}
+
+macro_rules! element_impl(
+    ($($typ:ty),*) => (
+        $(
+            impl private::Sealed for $typ {}
+
+            impl Element for $typ {
+                #[inline]
+                fn from_le_bytes(bytes: &[u8]) -> Self {
+                    <$typ>::from_le_bytes(bytes.try_into().unwrap())
+                }
+            }
+        )*
+    );
+);
+
+element_impl!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+// Whether `bytes` can be reinterpreted as a `[T]` in place.
+fn can_view_as<T: Element>(bytes: &[u8]) -> bool {
+    let size = core::mem::size_of::<T>();
+    bytes.len() % size == 0
+        && bytes.as_ptr() as usize % core::mem::align_of::<T>() == 0
+        && (size == 1 || cfg!(target_endian = "little"))
+}
+
+/// Views `data` as a slice of little-endian numbers, without copying it.
+///
+/// Returns `None` if the length of `data` is not a multiple of the size of `T`, or if `data` does
+/// not start at a multiple of the alignment of `T`. A Data field as a whole starts at a word
+/// boundary, but a [`subslice()`] of one, or a segment read with the `unaligned` feature, may not.
+/// On big-endian targets, this also returns `None` for any `T` larger than a byte; use
+/// [`to_vec_of()`] there instead.
+pub fn as_slice_of<T: Element>(data: Reader<'_>) -> Option<&[T]> {
+    if data.is_empty() {
+        return Some(&[]);
+    }
+    if !can_view_as::<T>(data) {
+        return None;
+    }
+    let len = data.len() / core::mem::size_of::<T>();
+    Some(unsafe { core::slice::from_raw_parts(data.as_ptr() as *const T, len) })
+}
+
+/// Views `data` as a mutable slice of little-endian numbers, like [`as_slice_of()`], which has
+/// the same requirements.
+pub fn as_mut_slice_of<T: Element>(data: Builder<'_>) -> Option<&mut [T]> {
+    if data.is_empty() {
+        return Some(&mut []);
+    }
+    if !can_view_as::<T>(data) {
+        return None;
+    }
+    let len = data.len() / core::mem::size_of::<T>();
+    Some(unsafe { core::slice::from_raw_parts_mut(data.as_mut_ptr() as *mut T, len) })
+}
+
+/// Copies `data` into a vector of little-endian numbers. Unlike [`as_slice_of()`], this works
+/// whatever the alignment of `data` and the endianness of the target, and only returns `None` if
+/// the length of `data` is not a multiple of the size of `T`.
+#[cfg(feature = "alloc")]
+pub fn to_vec_of<T: Element>(data: Reader<'_>) -> Option<alloc::vec::Vec<T>> {
+    let size = core::mem::size_of::<T>();
+    if data.len() % size != 0 {
+        return None;
+    }
+    Some(data.chunks_exact(size).map(T::from_le_bytes).collect())
+}
+
+/// Returns the bytes of `data` in `range`, without copying them, or `None` if `range` is out of
+/// bounds. This is [`<[u8]>::get()`](slice::get), spelled out for data.
+pub fn subslice<R>(data: Reader<'_>, range: R) -> Option<Reader<'_>>
+where
+    R: core::ops::RangeBounds<usize>,
+{
+    data.get((range.start_bound().cloned(), range.end_bound().cloned()))
+}
+
 impl<'a> From<Reader<'a>> for crate::dynamic_value::Reader<'a> {
     fn from(d: Reader<'a>) -> crate::dynamic_value::Reader<'a> {
         crate::dynamic_value::Reader::Data(d)
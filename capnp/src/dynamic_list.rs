@@ -116,7 +116,10 @@ impl<'a> Reader<'a> {
                 Ok(crate::any_pointer::Reader::new(self.reader.get_pointer_element(index)).into())
             }
             TypeVariant::Capability(cs) => Ok(dynamic_value::Reader::Capability(
-                dynamic_value::Capability::new(cs.into()),
+                dynamic_value::Capability::from_pointer(
+                    cs.into(),
+                    self.reader.get_pointer_element(index),
+                ),
             )),
         }
     }
@@ -311,20 +314,16 @@ impl<'a> Builder<'a> {
                 PrimitiveElement::set(&self.builder, index, e.get_value());
                 Ok(())
             }
-            (TypeVariant::Text, dynamic_value::Reader::Text(t)) => {
-                self.builder
-                    .reborrow()
-                    .get_pointer_element(index)
-                    .set_text(t);
-                Ok(())
-            }
-            (TypeVariant::Data, dynamic_value::Reader::Data(d)) => {
-                self.builder
-                    .reborrow()
-                    .get_pointer_element(index)
-                    .set_data(d);
-                Ok(())
-            }
+            (TypeVariant::Text, dynamic_value::Reader::Text(t)) => self
+                .builder
+                .reborrow()
+                .get_pointer_element(index)
+                .try_set_text(t),
+            (TypeVariant::Data, dynamic_value::Reader::Data(d)) => self
+                .builder
+                .reborrow()
+                .get_pointer_element(index)
+                .try_set_data(d),
             (TypeVariant::Struct(ss), dynamic_value::Reader::Struct(s)) => {
                 assert_eq!(ss, s.get_schema().raw);
                 self.builder
@@ -368,26 +367,28 @@ impl<'a> Builder<'a> {
             TypeVariant::Text => Ok(self
                 .builder
                 .get_pointer_element(index)
-                .init_text(size)
+                .try_init_text(size)?
                 .into()),
             TypeVariant::Data => Ok(self
                 .builder
                 .get_pointer_element(index)
-                .init_data(size)
+                .try_init_data(size)?
                 .into()),
             TypeVariant::List(inner_element_type) => match inner_element_type.which() {
                 TypeVariant::Struct(rbs) => Ok(Builder::new(
-                    self.builder.get_pointer_element(index).init_struct_list(
-                        size,
-                        crate::dynamic_struct::struct_size_from_schema(rbs.into())?,
-                    ),
+                    self.builder
+                        .get_pointer_element(index)
+                        .try_init_struct_list(
+                            size,
+                            crate::dynamic_struct::struct_size_from_schema(rbs.into())?,
+                        )?,
                     inner_element_type,
                 )
                 .into()),
                 _ => Ok(Builder::new(
                     self.builder
                         .get_pointer_element(index)
-                        .init_list(inner_element_type.expected_element_size(), size),
+                        .try_init_list(inner_element_type.expected_element_size(), size)?,
                     inner_element_type,
                 )
                 .into()),
@@ -22,6 +22,191 @@ pub(crate) fn struct_size_from_schema(schema: StructSchema) -> Result<layout::St
     }
 }
 
+fn type_name(ty: &TypeVariant) -> &'static str {
+    match ty {
+        TypeVariant::Void => "Void",
+        TypeVariant::Bool => "Bool",
+        TypeVariant::Int8 => "Int8",
+        TypeVariant::Int16 => "Int16",
+        TypeVariant::Int32 => "Int32",
+        TypeVariant::Int64 => "Int64",
+        TypeVariant::UInt8 => "UInt8",
+        TypeVariant::UInt16 => "UInt16",
+        TypeVariant::UInt32 => "UInt32",
+        TypeVariant::UInt64 => "UInt64",
+        TypeVariant::Float32 => "Float32",
+        TypeVariant::Float64 => "Float64",
+        TypeVariant::Text => "Text",
+        TypeVariant::Data => "Data",
+        TypeVariant::Struct(_) => "Struct",
+        TypeVariant::AnyPointer => "AnyPointer",
+        TypeVariant::Capability(_) => "Capability",
+        TypeVariant::Enum(_) => "Enum",
+        TypeVariant::List(_) => "List",
+    }
+}
+
+fn value_name(value: &dynamic_value::Reader<'_>) -> &'static str {
+    match value {
+        dynamic_value::Reader::Void => "Void",
+        dynamic_value::Reader::Bool(_) => "Bool",
+        dynamic_value::Reader::Int8(_) => "Int8",
+        dynamic_value::Reader::Int16(_) => "Int16",
+        dynamic_value::Reader::Int32(_) => "Int32",
+        dynamic_value::Reader::Int64(_) => "Int64",
+        dynamic_value::Reader::UInt8(_) => "UInt8",
+        dynamic_value::Reader::UInt16(_) => "UInt16",
+        dynamic_value::Reader::UInt32(_) => "UInt32",
+        dynamic_value::Reader::UInt64(_) => "UInt64",
+        dynamic_value::Reader::Float32(_) => "Float32",
+        dynamic_value::Reader::Float64(_) => "Float64",
+        dynamic_value::Reader::Enum(_) => "Enum",
+        dynamic_value::Reader::Text(_) => "Text",
+        dynamic_value::Reader::Data(_) => "Data",
+        dynamic_value::Reader::Struct(_) => "Struct",
+        dynamic_value::Reader::List(_) => "List",
+        dynamic_value::Reader::AnyPointer(_) => "AnyPointer",
+        dynamic_value::Reader::Capability(_) => "Capability",
+    }
+}
+
+fn as_integer(value: &dynamic_value::Reader<'_>) -> Option<i128> {
+    match *value {
+        dynamic_value::Reader::Int8(v) => Some(v.into()),
+        dynamic_value::Reader::Int16(v) => Some(v.into()),
+        dynamic_value::Reader::Int32(v) => Some(v.into()),
+        dynamic_value::Reader::Int64(v) => Some(v.into()),
+        dynamic_value::Reader::UInt8(v) => Some(v.into()),
+        dynamic_value::Reader::UInt16(v) => Some(v.into()),
+        dynamic_value::Reader::UInt32(v) => Some(v.into()),
+        dynamic_value::Reader::UInt64(v) => Some(v.into()),
+        _ => None,
+    }
+}
+
+/// Converts `value` to the variant that `Builder::set()` expects for `field`, if that can be
+/// done without losing information.
+fn coerce<'b>(field: Field, value: dynamic_value::Reader<'b>) -> Result<dynamic_value::Reader<'b>> {
+    use dynamic_value::Reader as V;
+
+    let ty = field.get_type().which();
+    let field_name = field.get_proto().get_name()?.to_str()?;
+    let mismatch = |value: &V<'_>| {
+        let mut error = Error::from_kind(ErrorKind::TypeMismatch);
+        write!(
+            error,
+            "field `{}` expects {}, got {}",
+            field_name,
+            type_name(&ty),
+            value_name(value)
+        );
+        error
+    };
+    let out_of_range = |value: i128| {
+        let mut error = Error::from_kind(ErrorKind::ValueOutOfRange);
+        write!(
+            error,
+            "{} does not fit in field `{}` of type {}",
+            value,
+            field_name,
+            type_name(&ty)
+        );
+        error
+    };
+    macro_rules! integer {
+        ($variant:ident, $t:ty) => {
+            match as_integer(&value) {
+                Some(v) => <$t>::try_from(v)
+                    .map(V::$variant)
+                    .map_err(|_| out_of_range(v)),
+                None => Err(mismatch(&value)),
+            }
+        };
+    }
+
+    match ty {
+        TypeVariant::Void | TypeVariant::AnyPointer | TypeVariant::Capability(_) => Ok(value),
+        TypeVariant::Bool => match value {
+            V::Bool(_) => Ok(value),
+            _ => Err(mismatch(&value)),
+        },
+        TypeVariant::Int8 => integer!(Int8, i8),
+        TypeVariant::Int16 => integer!(Int16, i16),
+        TypeVariant::Int32 => integer!(Int32, i32),
+        TypeVariant::Int64 => integer!(Int64, i64),
+        TypeVariant::UInt8 => integer!(UInt8, u8),
+        TypeVariant::UInt16 => integer!(UInt16, u16),
+        TypeVariant::UInt32 => integer!(UInt32, u32),
+        TypeVariant::UInt64 => integer!(UInt64, u64),
+        TypeVariant::Float32 => match (value, as_integer(&value)) {
+            (V::Float32(_), _) => Ok(value),
+            // f32 has a 24-bit significand.
+            (_, Some(v)) if v.unsigned_abs() <= 1 << 24 => Ok(V::Float32(v as f32)),
+            (_, Some(v)) => Err(out_of_range(v)),
+            _ => Err(mismatch(&value)),
+        },
+        TypeVariant::Float64 => match (value, as_integer(&value)) {
+            (V::Float64(_), _) => Ok(value),
+            (V::Float32(v), _) => Ok(V::Float64(v.into())),
+            // f64 has a 53-bit significand.
+            (_, Some(v)) if v.unsigned_abs() <= 1 << 53 => Ok(V::Float64(v as f64)),
+            (_, Some(v)) => Err(out_of_range(v)),
+            _ => Err(mismatch(&value)),
+        },
+        TypeVariant::Enum(raw) => {
+            let schema = crate::schema::EnumSchema::new(raw);
+            let enumerants = schema.get_enumerants()?;
+            match (value, as_integer(&value)) {
+                (V::Enum(_), _) => Ok(value),
+                (_, Some(v)) if v >= 0 && v < enumerants.len().into() => {
+                    Ok(V::Enum(dynamic_value::Enum::new(v as u16, schema)))
+                }
+                (_, Some(v)) => Err(out_of_range(v)),
+                (V::Text(name), _) => {
+                    let name = name.to_str()?;
+                    for enumerant in enumerants.iter() {
+                        if enumerant.get_proto().get_name()? == name {
+                            return Ok(V::Enum(dynamic_value::Enum::new(
+                                enumerant.get_ordinal(),
+                                schema,
+                            )));
+                        }
+                    }
+                    let mut error = Error::from_kind(ErrorKind::EnumerantNotFound);
+                    write!(
+                        error,
+                        "field `{}` has no enumerant named `{}`",
+                        field_name, name
+                    );
+                    Err(error)
+                }
+                _ => Err(mismatch(&value)),
+            }
+        }
+        TypeVariant::Text => match value {
+            V::Text(_) => Ok(value),
+            _ => Err(mismatch(&value)),
+        },
+        TypeVariant::Data => match value {
+            V::Data(_) => Ok(value),
+            _ => Err(mismatch(&value)),
+        },
+        TypeVariant::List(_) => match value {
+            V::List(_) => Ok(value),
+            _ => Err(mismatch(&value)),
+        },
+        TypeVariant::Struct(raw) => match value {
+            V::Struct(s)
+                if s.get_schema().get_proto().get_id()
+                    == StructSchema::new(raw).get_proto().get_id() =>
+            {
+                Ok(value)
+            }
+            _ => Err(mismatch(&value)),
+        },
+    }
+}
+
 /// A read-only dynamically-typed struct.
 #[derive(Clone, Copy)]
 pub struct Reader<'a> {
@@ -48,6 +233,19 @@ impl<'a> Reader<'a> {
         self.schema
     }
 
+    /// Converts into the typed reader `T`, failing with `TypeMismatch` if the struct is of
+    /// a different type.
+    pub fn downcast<
+        T: crate::traits::HasTypeId + ::core::convert::From<crate::private::layout::StructReader<'a>>,
+    >(
+        self,
+    ) -> Result<T> {
+        if self.get_schema().get_proto().get_id() == T::TYPE_ID {
+            return Ok(self.reader.into());
+        }
+        Err(Error::from_kind(ErrorKind::TypeMismatch))
+    }
+
     pub fn get(self, field: Field) -> Result<dynamic_value::Reader<'a>> {
         assert_eq!(self.schema.raw, field.parent.raw);
         let ty = field.get_type();
@@ -157,11 +355,12 @@ impl<'a> Reader<'a> {
                         };
                         Ok(dynamic_value::Reader::AnyPointer(a1))
                     }
-                    (TypeVariant::Capability(cs), value::Interface(())) => {
-                        Ok(dynamic_value::Reader::Capability(
-                            dynamic_value::Capability::new(cs.into()),
-                        ))
-                    }
+                    (TypeVariant::Capability(cs), value::Interface(())) => Ok(
+                        dynamic_value::Reader::Capability(dynamic_value::Capability::from_pointer(
+                            cs.into(),
+                            self.reader.get_pointer_field(offset as usize),
+                        )),
+                    ),
                     _ => Err(Error::from_kind(ErrorKind::FieldAndDefaultMismatch)),
                 }
             }
@@ -349,7 +548,7 @@ impl<'a> Builder<'a> {
                             // If the type is a generic, then the default value
                             // is always an empty AnyPointer. Ignore that case.
                             if let value::Text(t) = dval {
-                                p.set_text(t?);
+                                p.try_set_text(t?)?;
                             }
                         }
                         Ok(dynamic_value::Builder::Text(p.get_text(None)?))
@@ -360,7 +559,7 @@ impl<'a> Builder<'a> {
                             // If the type is a generic, then the default value
                             // is always an empty AnyPointer. Ignore that case.
                             if let value::Data(d) = dval {
-                                p.set_data(d?);
+                                p.try_set_data(d?)?;
                             }
                         }
                         Ok(dynamic_value::Builder::Data(p.get_data(None)?))
@@ -439,10 +638,13 @@ impl<'a> Builder<'a> {
         }
     }
 
+    /// Same as [`Reader::has()`].
     pub fn has(&self, field: Field) -> Result<bool> {
         self.reborrow_as_reader().has(field)
     }
 
+    /// Like `has()`, for the field with the given name. Returns an error of kind
+    /// `FieldNotFound` if there is no such field.
     pub fn has_named(&self, field_name: &str) -> Result<bool> {
         let field = self.schema.get_field_by_name(field_name)?;
         self.has(field)
@@ -541,13 +743,11 @@ impl<'a> Builder<'a> {
                     }
                     (TypeVariant::Text, dynamic_value::Reader::Text(tv), _) => {
                         let mut p = self.builder.reborrow().get_pointer_field(offset);
-                        p.set_text(tv);
-                        Ok(())
+                        p.try_set_text(tv)
                     }
                     (TypeVariant::Data, dynamic_value::Reader::Data(v), _) => {
                         let mut p = self.builder.reborrow().get_pointer_field(offset);
-                        p.set_data(v);
-                        Ok(())
+                        p.try_set_data(v)
                     }
                     (TypeVariant::List(_), dynamic_value::Reader::List(l), _) => {
                         let mut p = self.builder.reborrow().get_pointer_field(offset);
@@ -603,11 +803,22 @@ impl<'a> Builder<'a> {
         }
     }
 
+    /// Sets the field named `field_name`, first converting `value` to the field's type.
+    ///
+    /// Unlike `set()`, this accepts any integer for an integer, float, or enum field as long as
+    /// the value is representable, an enumerant name as `Text` for an enum field, and an `Int*`
+    /// or `Float32` for a `Float64` field. A struct value must have the same type as the field
+    /// and is deep-copied. Values that cannot be converted produce an error naming the field
+    /// and its type.
     pub fn set_named(&mut self, field_name: &str, value: dynamic_value::Reader<'_>) -> Result<()> {
         let field = self.schema.get_field_by_name(field_name)?;
+        let value = coerce(field, value)?;
         self.set(field, value)
     }
 
+    /// Initializes a struct, `AnyPointer` or group field and returns a builder for it. A struct
+    /// gets all-default content, and a group is cleared. If the field is a member of a union, it
+    /// becomes the active member, like with the generated `init_*()` methods.
     pub fn init(mut self, field: Field) -> Result<dynamic_value::Builder<'a>> {
         assert_eq!(self.schema.raw, field.parent.raw);
         self.set_in_union(field)?;
@@ -621,7 +832,7 @@ impl<'a> Builder<'a> {
                         builder: self
                             .builder
                             .get_pointer_field(offset)
-                            .init_struct(struct_size_from_schema(ss.into())?),
+                            .try_init_struct(struct_size_from_schema(ss.into())?)?,
                     }
                     .into()),
                     TypeVariant::AnyPointer => {
@@ -644,6 +855,8 @@ impl<'a> Builder<'a> {
         }
     }
 
+    /// Like `init()`, for the field with the given name. Returns an error of kind
+    /// `FieldNotFound` if there is no such field.
     pub fn init_named(self, field_name: &str) -> Result<dynamic_value::Builder<'a>> {
         let field = self.schema.get_field_by_name(field_name)?;
         self.init(field)
@@ -661,14 +874,14 @@ impl<'a> Builder<'a> {
                         TypeVariant::Struct(ss) => Ok(dynamic_list::Builder::new(
                             self.builder
                                 .get_pointer_field(offset)
-                                .init_struct_list(size, struct_size_from_schema(ss.into())?),
+                                .try_init_struct_list(size, struct_size_from_schema(ss.into())?)?,
                             element_type,
                         )
                         .into()),
                         _ => Ok(dynamic_list::Builder::new(
                             self.builder
                                 .get_pointer_field(offset)
-                                .init_list(element_type.expected_element_size(), size),
+                                .try_init_list(element_type.expected_element_size(), size)?,
                             element_type,
                         )
                         .into()),
@@ -676,12 +889,12 @@ impl<'a> Builder<'a> {
                     TypeVariant::Text => Ok(self
                         .builder
                         .get_pointer_field(offset)
-                        .init_text(size)
+                        .try_init_text(size)?
                         .into()),
                     TypeVariant::Data => Ok(self
                         .builder
                         .get_pointer_field(offset)
-                        .init_data(size)
+                        .try_init_data(size)?
                         .into()),
 
                     _ => Err(Error::from_kind(
@@ -701,7 +914,8 @@ impl<'a> Builder<'a> {
     }
 
     /// Clears a field, setting it to its default value. For pointer fields,
-    /// this makes the field null.
+    /// this makes the field null. If the field is a member of a union, it becomes
+    /// the active member.
     pub fn clear(&mut self, field: Field) -> Result<()> {
         assert_eq!(self.schema.raw, field.parent.raw);
         self.set_in_union(field)?;
@@ -791,6 +1005,8 @@ impl<'a> Builder<'a> {
         }
     }
 
+    /// Like `clear()`, for the field with the given name. Returns an error of kind
+    /// `FieldNotFound` if there is no such field.
     pub fn clear_named(&mut self, field_name: &str) -> Result<()> {
         let field = self.schema.get_field_by_name(field_name)?;
         self.clear(field)
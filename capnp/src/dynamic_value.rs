@@ -1,7 +1,11 @@
 //! Dynamically typed values.
 
+use core::hash::{Hash, Hasher};
+
 use crate::introspect::{self, TypeVariant};
-use crate::schema_capnp::value;
+use crate::private::layout;
+use crate::schema::{CapabilitySchema, EnumSchema, Field, StructSchema};
+use crate::schema_capnp::{field, node, value};
 use crate::Result;
 use crate::{dynamic_list, dynamic_struct};
 
@@ -308,11 +312,30 @@ impl<'a> From<Enum> for Builder<'a> {
 #[derive(Clone, Copy)]
 pub struct Capability {
     schema: crate::schema::CapabilitySchema,
+
+    /// `ClientHook::get_ptr()` of the capability, if it was read from a message that has it.
+    hook: Option<usize>,
 }
 
 impl Capability {
     pub fn new(schema: crate::schema::CapabilitySchema) -> Self {
-        Self { schema }
+        Self { schema, hook: None }
+    }
+
+    /// Creates a `Capability` for the capability pointed to by `pointer`, remembering the
+    /// identity of its hook.
+    pub(crate) fn from_pointer(
+        schema: crate::schema::CapabilitySchema,
+        pointer: layout::PointerReader<'_>,
+    ) -> Self {
+        #[cfg(feature = "alloc")]
+        let hook = pointer.get_capability().ok().map(|hook| hook.get_ptr());
+        #[cfg(not(feature = "alloc"))]
+        let hook = {
+            let _ = pointer;
+            None
+        };
+        Self { schema, hook }
     }
 
     pub fn get_schema(&self) -> crate::schema::CapabilitySchema {
@@ -331,3 +354,285 @@ impl<'a> From<Capability> for Builder<'a> {
         Builder::Capability(c)
     }
 }
+
+/// Deep equality with the semantics of comparing canonical encodings: a field holding its
+/// default value equals an absent one, lists compare element-wise, structs field-wise by
+/// schema, enums by value, and capabilities by the identity of their hooks. Floats compare
+/// bitwise, so `NaN` equals itself and `0.0` does not equal `-0.0`. Any-pointers compare
+/// by their canonical forms, so ones containing capabilities are never equal.
+///
+/// Traversal stops at the nesting limit that the values were read with, and a value that
+/// exceeds it or fails to read is unequal to everything, including itself.
+impl PartialEq for Reader<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        let nesting_limit = nesting_limit(self).min(nesting_limit(other));
+        values_equal(*self, *other, nesting_limit).unwrap_or(false)
+    }
+}
+
+impl Reader<'_> {
+    /// Feeds this value into `state`, consistently with `PartialEq`: values that compare
+    /// equal produce the same hash.
+    pub fn canonical_hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        if hash_value(*self, state, nesting_limit(self)).is_err() {
+            // Such values are unequal to everything, so any hash is consistent.
+            state.write_u8(0xff);
+        }
+    }
+}
+
+/// The number of levels of pointers that may be followed below `value`.
+fn nesting_limit(value: &Reader<'_>) -> i32 {
+    match value {
+        Reader::Struct(s) => s.reader.get_nesting_limit(),
+        Reader::List(l) => l.reader.get_nesting_limit(),
+        _ => 0,
+    }
+}
+
+fn check_nesting_limit(nesting_limit: i32) -> Result<()> {
+    if nesting_limit < 0 {
+        Err(crate::Error::from_kind(
+            crate::ErrorKind::MessageIsTooDeeplyNested,
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn values_equal(a: Reader<'_>, b: Reader<'_>, nesting_limit: i32) -> Result<bool> {
+    Ok(match (a, b) {
+        (Reader::Void, Reader::Void) => true,
+        (Reader::Bool(x), Reader::Bool(y)) => x == y,
+        (Reader::Int8(x), Reader::Int8(y)) => x == y,
+        (Reader::Int16(x), Reader::Int16(y)) => x == y,
+        (Reader::Int32(x), Reader::Int32(y)) => x == y,
+        (Reader::Int64(x), Reader::Int64(y)) => x == y,
+        (Reader::UInt8(x), Reader::UInt8(y)) => x == y,
+        (Reader::UInt16(x), Reader::UInt16(y)) => x == y,
+        (Reader::UInt32(x), Reader::UInt32(y)) => x == y,
+        (Reader::UInt64(x), Reader::UInt64(y)) => x == y,
+        (Reader::Float32(x), Reader::Float32(y)) => x.to_bits() == y.to_bits(),
+        (Reader::Float64(x), Reader::Float64(y)) => x.to_bits() == y.to_bits(),
+        (Reader::Enum(x), Reader::Enum(y)) => {
+            x.value == y.value && x.schema.get_proto().get_id() == y.schema.get_proto().get_id()
+        }
+        (Reader::Text(x), Reader::Text(y)) => x.as_bytes() == y.as_bytes(),
+        (Reader::Data(x), Reader::Data(y)) => x == y,
+        (Reader::Struct(x), Reader::Struct(y)) => structs_equal(x, y, nesting_limit)?,
+        (Reader::List(x), Reader::List(y)) => lists_equal(x, y, nesting_limit)?,
+        (Reader::AnyPointer(x), Reader::AnyPointer(y)) => any_pointers_equal(x, y)?,
+        (Reader::Capability(x), Reader::Capability(y)) => {
+            x.hook == y.hook && x.schema.get_proto().get_id() == y.schema.get_proto().get_id()
+        }
+        _ => false,
+    })
+}
+
+/// Returns the raw union discriminant of `value`, if its struct has a union.
+fn discriminant(value: dynamic_struct::Reader<'_>) -> Result<Option<u16>> {
+    let node::Struct(st) = value.get_schema().get_proto().which()? else {
+        return Err(crate::Error::from_kind(crate::ErrorKind::NotAStruct));
+    };
+    Ok((st.get_discriminant_count() > 0).then(|| {
+        value
+            .reader
+            .get_data_field::<u16>(st.get_discriminant_offset() as usize)
+    }))
+}
+
+/// Returns the fields of `value` that take part in comparisons: the non-union ones, and the
+/// active union member, if it is known.
+fn compared_fields(value: dynamic_struct::Reader<'_>) -> Result<impl Iterator<Item = Field>> {
+    Ok(value
+        .get_schema()
+        .get_non_union_fields()?
+        .iter()
+        .chain(value.which()?))
+}
+
+/// Returns the nesting limit below `field`, in a struct whose limit is `nesting_limit`.
+/// Groups are part of their parent struct, so they don't count as a level.
+fn field_nesting_limit(field: Field, nesting_limit: i32) -> Result<i32> {
+    Ok(match field.get_proto().which()? {
+        field::Group(_) => nesting_limit,
+        field::Slot(_) => nesting_limit - 1,
+    })
+}
+
+fn structs_equal(
+    a: dynamic_struct::Reader<'_>,
+    b: dynamic_struct::Reader<'_>,
+    nesting_limit: i32,
+) -> Result<bool> {
+    check_nesting_limit(nesting_limit)?;
+    let b_schema = b.get_schema();
+    if a.get_schema().get_proto().get_id() != b_schema.get_proto().get_id()
+        || discriminant(a)? != discriminant(b)?
+    {
+        return Ok(false);
+    }
+    // `b` may have been read with a different copy of the schema, so look its fields up by
+    // index.
+    let b_fields = b_schema.get_fields()?;
+    for field in compared_fields(a)? {
+        let b_field = b_fields.get(field.get_index());
+        if let (Some(a_pointer), Some(b_pointer)) = (pointer_of(a, field)?, pointer_of(b, b_field)?)
+        {
+            // Both hold the default. Stopping here also keeps absent fields of recursive
+            // types from being expanded forever.
+            if a_pointer.is_null() && b_pointer.is_null() {
+                continue;
+            }
+        }
+        let a_value = a.get(field)?;
+        let b_value = b.get(b_field)?;
+        if !values_equal(a_value, b_value, field_nesting_limit(field, nesting_limit)?)? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Returns the pointer holding `field` in `value`, if it is a pointer field.
+fn pointer_of<'a>(
+    value: dynamic_struct::Reader<'a>,
+    field: Field,
+) -> Result<Option<layout::PointerReader<'a>>> {
+    match field.get_proto().which()? {
+        field::Slot(slot) if field.get_type().is_pointer_type() => Ok(Some(
+            value.reader.get_pointer_field(slot.get_offset() as usize),
+        )),
+        _ => Ok(None),
+    }
+}
+
+/// Returns the value that `field` has when it is absent.
+fn default_value(schema: StructSchema, field: Field) -> Result<Reader<'static>> {
+    dynamic_struct::Reader::new(layout::StructReader::new_default(), schema).get(field)
+}
+
+fn same_type(a: introspect::Type, b: introspect::Type) -> bool {
+    match (a.which(), b.which()) {
+        (TypeVariant::Struct(x), TypeVariant::Struct(y)) => {
+            StructSchema::from(x).get_proto().get_id() == StructSchema::from(y).get_proto().get_id()
+        }
+        (TypeVariant::Enum(x), TypeVariant::Enum(y)) => {
+            EnumSchema::from(x).get_proto().get_id() == EnumSchema::from(y).get_proto().get_id()
+        }
+        (TypeVariant::Capability(x), TypeVariant::Capability(y)) => {
+            CapabilitySchema::from(x).get_proto().get_id()
+                == CapabilitySchema::from(y).get_proto().get_id()
+        }
+        (TypeVariant::List(x), TypeVariant::List(y)) => same_type(x, y),
+        (x, y) => core::mem::discriminant(&x) == core::mem::discriminant(&y),
+    }
+}
+
+fn lists_equal(
+    a: dynamic_list::Reader<'_>,
+    b: dynamic_list::Reader<'_>,
+    nesting_limit: i32,
+) -> Result<bool> {
+    check_nesting_limit(nesting_limit)?;
+    if a.len() != b.len() || !same_type(a.element_type(), b.element_type()) {
+        return Ok(false);
+    }
+    for (x, y) in a.iter().zip(b.iter()) {
+        if !values_equal(x?, y?, nesting_limit - 1)? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+#[cfg(feature = "alloc")]
+fn canonical_bytes(value: crate::any_pointer::Reader<'_>) -> Result<alloc::vec::Vec<u8>> {
+    let mut message = crate::message::Builder::new_default();
+    message.set_root_canonical(value)?;
+    Ok(message.get_segments_for_output()[0].to_vec())
+}
+
+#[cfg(feature = "alloc")]
+fn any_pointers_equal(
+    a: crate::any_pointer::Reader<'_>,
+    b: crate::any_pointer::Reader<'_>,
+) -> Result<bool> {
+    Ok(canonical_bytes(a)? == canonical_bytes(b)?)
+}
+
+#[cfg(not(feature = "alloc"))]
+fn any_pointers_equal(
+    a: crate::any_pointer::Reader<'_>,
+    b: crate::any_pointer::Reader<'_>,
+) -> Result<bool> {
+    if a.is_null() && b.is_null() {
+        Ok(true)
+    } else {
+        Err(crate::Error::from_kind(crate::ErrorKind::Unimplemented))
+    }
+}
+
+fn hash_value<H: Hasher>(value: Reader<'_>, state: &mut H, nesting_limit: i32) -> Result<()> {
+    core::mem::discriminant(&value).hash(state);
+    match value {
+        Reader::Void => {}
+        Reader::Bool(x) => x.hash(state),
+        Reader::Int8(x) => x.hash(state),
+        Reader::Int16(x) => x.hash(state),
+        Reader::Int32(x) => x.hash(state),
+        Reader::Int64(x) => x.hash(state),
+        Reader::UInt8(x) => x.hash(state),
+        Reader::UInt16(x) => x.hash(state),
+        Reader::UInt32(x) => x.hash(state),
+        Reader::UInt64(x) => x.hash(state),
+        Reader::Float32(x) => x.to_bits().hash(state),
+        Reader::Float64(x) => x.to_bits().hash(state),
+        Reader::Enum(e) => {
+            e.schema.get_proto().get_id().hash(state);
+            e.value.hash(state);
+        }
+        Reader::Text(t) => t.as_bytes().hash(state),
+        Reader::Data(d) => d.hash(state),
+        Reader::Struct(s) => {
+            check_nesting_limit(nesting_limit)?;
+            s.get_schema().get_proto().get_id().hash(state);
+            discriminant(s)?.hash(state);
+            // Fields holding their defaults are left out, so that they hash like absent ones.
+            let schema = s.get_schema();
+            for field in compared_fields(s)? {
+                if let Some(pointer) = pointer_of(s, field)? {
+                    if pointer.is_null() {
+                        continue;
+                    }
+                }
+                let value = s.get(field)?;
+                let nesting_limit = field_nesting_limit(field, nesting_limit)?;
+                if !values_equal(value, default_value(schema, field)?, nesting_limit)? {
+                    field.get_index().hash(state);
+                    hash_value(value, state, nesting_limit)?;
+                }
+            }
+        }
+        Reader::List(l) => {
+            check_nesting_limit(nesting_limit)?;
+            l.len().hash(state);
+            for element in l.iter() {
+                hash_value(element?, state, nesting_limit - 1)?;
+            }
+        }
+        #[cfg(feature = "alloc")]
+        Reader::AnyPointer(a) => canonical_bytes(a)?.hash(state),
+        #[cfg(not(feature = "alloc"))]
+        Reader::AnyPointer(a) => {
+            if !a.is_null() {
+                return Err(crate::Error::from_kind(crate::ErrorKind::Unimplemented));
+            }
+        }
+        Reader::Capability(c) => {
+            c.schema.get_proto().get_id().hash(state);
+            c.hook.hash(state);
+        }
+    }
+    Ok(())
+}
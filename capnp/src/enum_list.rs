@@ -70,6 +70,61 @@ impl<'a, T: TryFrom<u16, Error = NotInSchema>> Reader<'a, T> {
         let l = self.len();
         ListIter::new(self, l)
     }
+
+    /// Like `iter()`, but the iterator can also drop the values that are not in the schema,
+    /// with [`LenientIter::filter_valid()`].
+    pub fn iter_lenient(self) -> LenientIter<'a, T> {
+        LenientIter { inner: self.iter() }
+    }
+
+    /// Collects the elements into a `Vec`, failing with the first value that is not in the
+    /// schema, for example one added by a newer version of it.
+    #[cfg(feature = "alloc")]
+    pub fn try_collect(&self) -> ::core::result::Result<alloc::vec::Vec<T>, NotInSchema> {
+        (0..self.len()).map(|index| self.get(index)).collect()
+    }
+}
+
+/// An iterator over the elements of an enum list, returned by [`Reader::iter_lenient()`].
+pub struct LenientIter<'a, T> {
+    inner: ListIter<Reader<'a, T>, ::core::result::Result<T, NotInSchema>>,
+}
+
+impl<'a, T: TryFrom<u16, Error = NotInSchema>> LenientIter<'a, T> {
+    /// Skips the values that are not in the schema.
+    pub fn filter_valid(self) -> FilterValid<'a, T> {
+        FilterValid { inner: self }
+    }
+}
+
+impl<'a, T: TryFrom<u16, Error = NotInSchema>> Iterator for LenientIter<'a, T> {
+    type Item = ::core::result::Result<T, NotInSchema>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// An iterator over the elements of an enum list that are in the schema, returned by
+/// [`LenientIter::filter_valid()`].
+pub struct FilterValid<'a, T> {
+    inner: LenientIter<'a, T>,
+}
+
+impl<'a, T: TryFrom<u16, Error = NotInSchema>> Iterator for FilterValid<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.inner.by_ref().find_map(|value| value.ok())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
 }
 
 impl<'a, T: TryFrom<u16, Error = NotInSchema>> FromPointerReader<'a> for Reader<'a, T> {
@@ -156,6 +211,12 @@ impl<'a, T: TryFrom<u16, Error = NotInSchema>> FromPointerBuilder<'a> for Builde
             marker: PhantomData,
         }
     }
+    fn try_init_pointer(builder: PointerBuilder<'a>, size: u32) -> Result<Builder<'a, T>> {
+        Ok(Builder {
+            builder: builder.try_init_list(TwoBytes, size)?,
+            marker: PhantomData,
+        })
+    }
     fn get_from_pointer(
         builder: PointerBuilder<'a>,
         default: Option<&'a [crate::Word]>,
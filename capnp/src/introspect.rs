@@ -310,3 +310,24 @@ impl core::fmt::Debug for RawCapabilitySchema {
         )
     }
 }
+
+/// Describes one method of an interface. Generated code lists the methods of each interface,
+/// including inherited ones, in a `_private::METHODS` table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MethodInfo {
+    /// ID of the interface that declares the method. Together with `ordinal`, this is what
+    /// a call is dispatched on, so for inherited methods it is the ID of the superclass.
+    pub interface_id: u64,
+
+    /// Index of the method within the interface that declares it.
+    pub ordinal: u16,
+
+    /// The method's name, as written in the schema.
+    pub name: &'static str,
+
+    /// ID of the method's params struct.
+    pub param_type_id: u64,
+
+    /// ID of the method's results struct.
+    pub result_type_id: u64,
+}
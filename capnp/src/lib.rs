@@ -52,6 +52,7 @@ pub mod introspect;
 pub mod io;
 pub mod list_list;
 pub mod message;
+pub mod orphan;
 pub mod primitive_list;
 pub mod private;
 pub mod raw;
@@ -64,6 +65,8 @@ pub mod text;
 pub mod text_list;
 pub mod traits;
 
+pub use crate::orphan::Orphan;
+
 #[cfg(feature = "alloc")]
 use alloc::string::String;
 #[cfg(feature = "alloc")]
@@ -177,6 +180,16 @@ pub struct Error {
     #[cfg(feature = "alloc")]
     pub extra: String,
 
+    /// A serialized capnp message carrying structured details about the error, set by
+    /// [`Error::failed_with_payload()`] and read back with [`Error::get_payload_as()`].
+    /// Across an RPC connection, capnp-rpc transmits it as an `Exception` detail.
+    ///
+    /// Adding this field is a breaking change: code that builds an `Error` with a struct
+    /// literal must now set it too, usually to `None`. The constructors, such as
+    /// [`Error::failed()`], are unaffected.
+    #[cfg(feature = "alloc")]
+    pub payload: Option<Vec<Word>>,
+
     /// Backtrace from the error creation
     #[cfg(feature = "backtrace")]
     pub backtrace: std::backtrace::Backtrace,
@@ -195,6 +208,8 @@ impl Clone for Error {
             } else {
                 self.extra.clone()
             },
+            #[cfg(feature = "alloc")]
+            payload: self.payload.clone(),
             #[cfg(feature = "backtrace")]
             backtrace: std::backtrace::Backtrace::disabled(),
         }
@@ -224,6 +239,13 @@ pub enum ErrorKind {
     /// approach based on other methods.
     Unimplemented,
 
+    /// The call did not complete before the deadline set on its request, and has been canceled.
+    /// Like `Overloaded`, the operation might work if tried again later.
+    DeadlineExceeded,
+
+    /// Message could be either unpacked or packed
+    AmbiguousMessageFormat,
+
     /// Buffer is not large enough
     BufferNotLargeEnough,
 
@@ -236,6 +258,15 @@ pub enum ErrorKind {
     /// Don't know how to handle non-STRUCT inline composite.
     CantHandleNonStructInlineComposite,
 
+    /// Adopted object must live in the same message.
+    AdoptedObjectMustLiveInTheSameMessage,
+
+    /// Pushed string does not fit in the text's allocation
+    TextCapacityExceeded,
+
+    /// Message checksum mismatch. Expected {expected}, computed {computed}
+    ChecksumMismatch(u32, u32),
+
     /// Empty buffer
     EmptyBuffer,
 
@@ -245,6 +276,9 @@ pub enum ErrorKind {
     /// Enum value or union discriminant {} was not present in schema
     EnumValueOrUnionDiscriminantNotPresent(NotInSchema),
 
+    /// enumerant not found
+    EnumerantNotFound,
+
     /// Called get_writable_{data|text}_pointer() but existing list pointer is not byte-sized.
     ExistingListPointerIsNotByteSized,
 
@@ -314,6 +348,9 @@ pub enum ErrorKind {
     /// List(Capability) not supported
     ListCapabilityNotSupported,
 
+    /// A list of {len} elements is longer than the 2**29 - 1 elements a list can hold
+    ListTooLong(usize),
+
     /// Malformed double-far pointer.
     MalformedDoubleFarPointer,
 
@@ -365,6 +402,9 @@ pub enum ErrorKind {
     /// Message was not aligned by 8 bytes boundary. Either ensure that message is properly aligned or compile `capnp` crate with \"unaligned\" feature enabled.
     MessageNotAlignedBy8BytesBoundary,
 
+    /// Message's segment table declares more words than `total_message_size_limit_in_words`
+    MessageSizeLimitExceeded(usize),
+
     /// Message's size cannot be represented in usize
     MessageSizeOverflow,
 
@@ -419,6 +459,12 @@ pub enum ErrorKind {
 
     /// Unknown pointer type.
     UnknownPointerType,
+
+    /// Message is in none of the recognized formats
+    UnrecognizedMessageFormat,
+
+    /// value is out of range for the field's type
+    ValueOutOfRange,
 }
 
 impl Error {
@@ -436,6 +482,7 @@ impl Error {
     pub fn failed(description: String) -> Self {
         Self {
             extra: description,
+            payload: None,
             kind: ErrorKind::Failed,
             #[cfg(feature = "backtrace")]
             backtrace: std::backtrace::Backtrace::capture(),
@@ -449,6 +496,7 @@ impl Error {
         return Self {
             kind,
             extra: String::new(),
+            payload: None,
             #[cfg(feature = "backtrace")]
             backtrace: std::backtrace::Backtrace::capture(),
         };
@@ -459,6 +507,7 @@ impl Error {
         Self {
             kind,
             extra: context,
+            payload: None,
             #[cfg(feature = "backtrace")]
             backtrace: std::backtrace::Backtrace::capture(),
         }
@@ -468,6 +517,7 @@ impl Error {
     pub fn overloaded(description: String) -> Self {
         Self {
             extra: description,
+            payload: None,
             kind: ErrorKind::Overloaded,
             #[cfg(feature = "backtrace")]
             backtrace: std::backtrace::Backtrace::capture(),
@@ -477,6 +527,7 @@ impl Error {
     pub fn disconnected(description: String) -> Self {
         Self {
             extra: description,
+            payload: None,
             kind: ErrorKind::Disconnected,
             #[cfg(feature = "backtrace")]
             backtrace: std::backtrace::Backtrace::capture(),
@@ -487,11 +538,76 @@ impl Error {
     pub fn unimplemented(description: String) -> Self {
         Self {
             extra: description,
+            payload: None,
             kind: ErrorKind::Unimplemented,
             #[cfg(feature = "backtrace")]
             backtrace: std::backtrace::Backtrace::capture(),
         }
     }
+
+    #[cfg(feature = "alloc")]
+    pub fn deadline_exceeded(description: String) -> Self {
+        Self {
+            extra: description,
+            payload: None,
+            kind: ErrorKind::DeadlineExceeded,
+            #[cfg(feature = "backtrace")]
+            backtrace: std::backtrace::Backtrace::capture(),
+        }
+    }
+
+    /// Creates a `Failed` error that carries a copy of `payload`, a struct describing the
+    /// error in a form the receiver can decode with [`Error::get_payload_as()`].
+    #[cfg(feature = "alloc")]
+    pub fn failed_with_payload<T: traits::SetPointerBuilder>(
+        description: String,
+        payload: T,
+    ) -> Result<Self> {
+        let mut error = Self::failed(description);
+        error.set_payload(payload)?;
+        Ok(error)
+    }
+
+    /// Attaches a copy of `payload` to this error, replacing any existing payload.
+    #[cfg(feature = "alloc")]
+    pub fn set_payload<T: traits::SetPointerBuilder>(&mut self, payload: T) -> Result<()> {
+        let mut message = message::Builder::new_default();
+        message.set_root(payload)?;
+        let bytes = serialize::write_message_to_words(&message);
+        let mut words = Word::allocate_zeroed_vec(bytes.len() / private::units::BYTES_PER_WORD);
+        Word::words_to_bytes_mut(&mut words).copy_from_slice(&bytes);
+        self.payload = Some(words);
+        Ok(())
+    }
+
+    /// The payload attached to this error, as a single-segment serialized message, if any.
+    #[cfg(feature = "alloc")]
+    pub fn raw_payload(&self) -> Option<&[Word]> {
+        self.payload.as_deref()
+    }
+
+    /// Attaches `payload`, a single-segment serialized message such as one returned by
+    /// [`Error::raw_payload()`], to this error, or removes the payload if it is `None`.
+    #[cfg(feature = "alloc")]
+    pub fn set_raw_payload(&mut self, payload: Option<Vec<Word>>) {
+        self.payload = payload;
+    }
+
+    /// Decodes the payload attached to this error as a `T`. Returns `None` if the error
+    /// carries no payload.
+    #[cfg(feature = "alloc")]
+    pub fn get_payload_as<T: traits::Owned>(
+        &self,
+    ) -> Option<Result<message::TypedReader<serialize::NoAllocSliceSegments<'_>, T>>> {
+        let mut bytes = Word::words_to_bytes(self.payload.as_ref()?);
+        Some(
+            serialize::read_message_from_flat_slice_no_alloc(
+                &mut bytes,
+                message::ReaderOptions::new(),
+            )
+            .map(message::TypedReader::new),
+        )
+    }
 }
 
 #[cfg(feature = "std")]
@@ -512,6 +628,7 @@ impl core::convert::From<::std::io::Error> for Error {
         return Self {
             kind,
             extra: format!("{err}"),
+            payload: None,
             #[cfg(feature = "backtrace")]
             backtrace: std::backtrace::Backtrace::capture(),
         };
@@ -572,6 +689,8 @@ impl core::fmt::Display for ErrorKind {
             Self::Overloaded => write!(fmt, "Overloaded"),
             Self::Disconnected => write!(fmt, "Disconnected"),
             Self::Unimplemented => write!(fmt, "Unimplemented"),
+            Self::DeadlineExceeded => write!(fmt, "Deadline exceeded"),
+            Self::AmbiguousMessageFormat => write!(fmt, "Message could be either unpacked or packed; its format must be given explicitly"),
             Self::BufferNotLargeEnough => write!(fmt, "buffer is not large enough"),
             Self::ExistingListPointerIsNotByteSized => write!(fmt, "Called get_writable_{{data|text}}_pointer() but existing list pointer is not byte-sized."),
             Self::ExistingPointerIsNotAList => write!(fmt, "Called get_writable_{{data|text|list|struct_list}}_pointer() but existing pointer is not a list."),
@@ -580,9 +699,13 @@ impl core::fmt::Display for ErrorKind {
             Self::FourByteSegmentLengthTooBigForUSize => write!(fmt, "Cannot represent 4 byte segment length as usize. This may indicate that you are running on 8 or 16 bit platform or segment is too large"),
             Self::CannotSetAnyPointerFieldToAPrimitiveValue => write!(fmt, "cannot set AnyPointer field to a primitive value"),
             Self::CantHandleNonStructInlineComposite => write!(fmt, "Don't know how to handle non-STRUCT inline composite."),
+            Self::AdoptedObjectMustLiveInTheSameMessage => write!(fmt, "Adopted object must live in the same message."),
+            Self::TextCapacityExceeded => write!(fmt, "Pushed string does not fit in the text's allocation"),
+            Self::ChecksumMismatch(expected, computed) => write!(fmt, "Message checksum mismatch. Expected {expected:#010x}, computed {computed:#010x}"),
             Self::EmptyBuffer => write!(fmt, "empty buffer"),
             Self::EmptySlice => write!(fmt, "empty slice"),
             Self::EnumValueOrUnionDiscriminantNotPresent(val) => write!(fmt, "Enum value or union discriminant {val} was not present in schema"),
+            Self::EnumerantNotFound => write!(fmt, "enumerant not found"),
             Self::ExistingListValueIsIncompatibleWithExpectedType => write!(fmt, "Existing list value is incompatible with expected type."),
             Self::ExpectedAListOrBlob => write!(fmt, "Expected a list or blob."),
             Self::ExpectedAPointerListButGotAListOfDataOnlyStructs => write!(fmt, "Expected a pointer list, but got a list of data-only structs"),
@@ -602,6 +725,7 @@ impl core::fmt::Display for ErrorKind {
             Self::InvalidSegmentId(id) => write!(fmt, "Invalid segment id {id}"),
             Self::ListAnyPointerNotSupported => write!(fmt, "List(AnyPointer) not supported."),
             Self::ListCapabilityNotSupported => write!(fmt, "List(Capability) not supported"),
+            Self::ListTooLong(len) => write!(fmt, "A list of {len} elements is longer than the 2**29 - 1 elements a list can hold"),
             Self::MalformedDoubleFarPointer => write!(fmt, "Malformed double-far pointer."),
             Self::MessageContainsInvalidCapabilityPointer => write!(fmt, "Message contained invalid capability pointer."),
             Self::MessageContainsListPointerOfNonBytesWhereDataWasExpected => write!(fmt, "Message contains list pointer of non-bytes where data was expected."),
@@ -618,6 +742,7 @@ impl core::fmt::Display for ErrorKind {
             Self::MessageEndsPrematurely(header, body) => write!(fmt, "Message ends prematurely. Header claimed {header} words, but message only has {body} words"),
             Self::MessageIsTooDeeplyNested => write!(fmt, "Message is too deeply nested."),
             Self::MessageIsTooDeeplyNestedOrContainsCycles => write!(fmt, "Message is too deeply-nested or contains cycles."),
+            Self::MessageSizeLimitExceeded(val) => write!(fmt, "Message of {val} words exceeds the message size limit"),
             Self::MessageSizeOverflow => write!(fmt, "Message's size cannot be represented in usize"),
             Self::MessageTooLarge(val) => write!(fmt, "Message is too large: {val}"),
             Self::MessageNotAlignedBy8BytesBoundary => write!(fmt, "Message was not aligned by 8 bytes boundary. Either ensure that message is properly aligned or compile `capnp` crate with \"unaligned\" feature enabled."),
@@ -637,6 +762,8 @@ impl core::fmt::Display for ErrorKind {
             Self::UnalignedSegment => write!(fmt, "Detected unaligned segment. You must either ensure all of your segments are 8-byte aligned, or you must enable the \"unaligned\" feature in the capnp crate"),
             Self::UnexepectedFarPointer => write!(fmt, "Unexpected far pointer"),
             Self::UnknownPointerType => write!(fmt, "Unknown pointer type."),
+            Self::UnrecognizedMessageFormat => write!(fmt, "Message is in none of the recognized formats"),
+            Self::ValueOutOfRange => write!(fmt, "value is out of range for the field's type"),
         }
     }
 }
@@ -24,6 +24,7 @@
 use crate::introspect;
 use crate::private::layout::{ListBuilder, ListReader, Pointer, PointerBuilder, PointerReader};
 use crate::traits::{FromPointerBuilder, FromPointerReader, IndexMove, ListIter};
+use crate::Orphan;
 use crate::Result;
 
 #[derive(Clone, Copy)]
@@ -180,6 +181,21 @@ where
     pub fn init(self, index: u32, size: u32) -> T::Builder<'a> {
         FromPointerBuilder::init_pointer(self.builder.get_pointer_element(index), size)
     }
+
+    /// Detaches the element at position `index`, leaving a null list in its place.
+    pub fn disown(&mut self, index: u32) -> Orphan<T> {
+        assert!(index < self.len());
+        Orphan::new(self.builder.reborrow().get_pointer_element(index).disown())
+    }
+
+    /// Attaches `orphan` at position `index`, discarding the element that was there.
+    pub fn adopt(&mut self, index: u32, orphan: Orphan<T>) -> Result<()> {
+        assert!(index < self.len());
+        self.builder
+            .reborrow()
+            .get_pointer_element(index)
+            .adopt(orphan.into_internal())
+    }
 }
 
 impl<'a, T> Builder<'a, T>
@@ -204,6 +220,12 @@ where
             builder: builder.init_list(Pointer, size),
         }
     }
+    fn try_init_pointer(builder: PointerBuilder<'a>, size: u32) -> Result<Builder<'a, T>> {
+        Ok(Builder {
+            marker: ::core::marker::PhantomData,
+            builder: builder.try_init_list(Pointer, size)?,
+        })
+    }
     fn get_from_pointer(
         builder: PointerBuilder<'a>,
         default: Option<&'a [crate::Word]>,
@@ -73,15 +73,20 @@ use alloc::vec::Vec;
 use core::convert::From;
 
 use crate::any_pointer;
+use crate::introspect::TypeVariant;
 use crate::private::arena::{BuilderArena, BuilderArenaImpl};
 use crate::private::arena::{ReaderArena, ReaderArenaImpl};
 use crate::private::layout;
 use crate::private::units::BYTES_PER_WORD;
 use crate::traits::{FromPointerBuilder, SetPointerBuilder};
 use crate::traits::{FromPointerReader, Owned};
+use crate::Orphan;
 use crate::OutputSegments;
 use crate::Result;
 
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+
 /// Options controlling how data is read.
 #[derive(Clone, Copy, Debug)]
 pub struct ReaderOptions {
@@ -113,11 +118,21 @@ pub struct ReaderOptions {
     /// being very large. The default limit of 64 is probably low enough to prevent any chance of
     /// stack overflow, yet high enough that it is never a problem in practice.
     pub nesting_limit: i32,
+
+    /// Limits the total size, in (8-byte) words, of the segments of a message read from a
+    /// stream. The segment table is checked against this limit before any space is allocated
+    /// for the segments, so that a small header claiming huge segments fails right away with
+    /// `MessageSizeLimitExceeded`.
+    ///
+    /// Unlike the traversal limit, this bounds the memory used to hold a message, not the
+    /// work done reading it. A limit of `None` means that no limit is enforced.
+    pub total_message_size_limit_in_words: Option<usize>,
 }
 
 pub const DEFAULT_READER_OPTIONS: ReaderOptions = ReaderOptions {
     traversal_limit_in_words: Some(8 * 1024 * 1024),
     nesting_limit: 64,
+    total_message_size_limit_in_words: Some(8 * 1024 * 1024),
 };
 
 impl Default for ReaderOptions {
@@ -127,7 +142,7 @@ impl Default for ReaderOptions {
 }
 
 impl ReaderOptions {
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         DEFAULT_READER_OPTIONS
     }
 
@@ -140,6 +155,95 @@ impl ReaderOptions {
         self.traversal_limit_in_words = value;
         self
     }
+
+    pub fn total_message_size_limit_in_words(&mut self, value: Option<usize>) -> &mut Self {
+        self.total_message_size_limit_in_words = value;
+        self
+    }
+
+    /// Like `nesting_limit()`, but takes and returns the options by value, so that it can be
+    /// used in a `const` context:
+    ///
+    /// ```
+    /// # use capnp::message::ReaderOptions;
+    /// const OPTIONS: ReaderOptions = ReaderOptions::new().with_nesting_limit(128);
+    /// ```
+    #[must_use]
+    pub const fn with_nesting_limit(mut self, value: i32) -> Self {
+        self.nesting_limit = value;
+        self
+    }
+
+    /// Like `traversal_limit_in_words()`, but by value. See `with_nesting_limit()`.
+    #[must_use]
+    pub const fn with_traversal_limit_in_words(mut self, value: Option<usize>) -> Self {
+        self.traversal_limit_in_words = value;
+        self
+    }
+
+    /// Like `total_message_size_limit_in_words()`, but by value. See `with_nesting_limit()`.
+    #[must_use]
+    pub const fn with_total_message_size_limit_in_words(mut self, value: Option<usize>) -> Self {
+        self.total_message_size_limit_in_words = value;
+        self
+    }
+}
+
+/// Options controlling how much work [`Reader::validate_deep()`] may do.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct ValidationOptions {
+    /// Limits how many words the validation may traverse. Every struct, list and far-pointer
+    /// landing pad is counted each time a pointer reaches it, so a message whose pointers
+    /// share objects counts as large as it would be to read. Lists of void and of zero-sized
+    /// structs count their element count, like they do against
+    /// [`ReaderOptions::traversal_limit_in_words`].
+    ///
+    /// A limit of `None` means that no limit is enforced.
+    pub traversal_limit_in_words: Option<usize>,
+
+    /// Limits how deeply nested the message's objects can be. Cyclic messages are reported
+    /// by this limit or by the traversal limit, whichever is reached first.
+    pub nesting_limit: i32,
+}
+
+impl Default for ValidationOptions {
+    fn default() -> Self {
+        Self {
+            traversal_limit_in_words: DEFAULT_READER_OPTIONS.traversal_limit_in_words,
+            nesting_limit: DEFAULT_READER_OPTIONS.nesting_limit,
+        }
+    }
+}
+
+impl ValidationOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn nesting_limit(&mut self, value: i32) -> &mut Self {
+        self.nesting_limit = value;
+        self
+    }
+
+    pub fn traversal_limit_in_words(&mut self, value: Option<usize>) -> &mut Self {
+        self.traversal_limit_in_words = value;
+        self
+    }
+}
+
+/// What [`Reader::validate_deep()`] found in a valid message.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct ValidationReport {
+    /// The number of words traversed, as counted against
+    /// [`ValidationOptions::traversal_limit_in_words`].
+    pub words_traversed: u64,
+
+    /// The number of capability pointers found.
+    pub cap_count: u32,
+
+    /// The depth of the most deeply nested object. Reading the message with a
+    /// [`ReaderOptions::nesting_limit`] of at least this never fails for nesting.
+    pub max_depth: u32,
 }
 
 /// An object that manages the buffers underlying a Cap'n Proto message reader.
@@ -192,6 +296,50 @@ impl<'a> SegmentArray<'a> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<S> ReaderSegments for alloc::boxed::Box<S>
+where
+    S: ReaderSegments + ?Sized,
+{
+    fn get_segment(&self, idx: u32) -> Option<&[u8]> {
+        (**self).get_segment(idx)
+    }
+
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S> ReaderSegments for alloc::rc::Rc<S>
+where
+    S: ReaderSegments + ?Sized,
+{
+    fn get_segment(&self, idx: u32) -> Option<&[u8]> {
+        (**self).get_segment(idx)
+    }
+
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+}
+
+/// Lets several readers share one set of segments, possibly across threads. See
+/// [`TypedReader::map_segments()`].
+#[cfg(feature = "alloc")]
+impl<S> ReaderSegments for alloc::sync::Arc<S>
+where
+    S: ReaderSegments + ?Sized,
+{
+    fn get_segment(&self, idx: u32) -> Option<&[u8]> {
+        (**self).get_segment(idx)
+    }
+
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+}
+
 impl<'b> ReaderSegments for SegmentArray<'b> {
     fn get_segment(&self, id: u32) -> Option<&[u8]> {
         self.segments.get(id as usize).copied()
@@ -213,6 +361,9 @@ impl<'b> ReaderSegments for [&'b [u8]] {
 }
 
 /// A container used to read a message.
+///
+/// `Reader<S>` is `Send` if `S` is. It is `Sync` if `S` is and the "sync_reader" feature
+/// is enabled, because otherwise the traversal limit is tracked in a `Cell`.
 pub struct Reader<S>
 where
     S: ReaderSegments,
@@ -220,6 +371,19 @@ where
     arena: ReaderArenaImpl<S>,
 }
 
+/// Clones the segments. The clone reads with the same options as the original, but with
+/// a traversal limit of its own, starting over from `ReaderOptions::traversal_limit_in_words`.
+impl<S> Clone for Reader<S>
+where
+    S: ReaderSegments + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            arena: self.arena.clone(),
+        }
+    }
+}
+
 impl<S> Reader<S>
 where
     S: ReaderSegments,
@@ -250,6 +414,57 @@ where
         self.arena.into_segments()
     }
 
+    /// Gets the options that the message is read with.
+    pub fn options(&self) -> ReaderOptions {
+        self.arena.options()
+    }
+
+    /// Replaces the options that the message is read with, for example to raise the traversal
+    /// limit once the message has been checked with [`validate_deep()`](Self::validate_deep).
+    /// The traversal limit starts over from the new value, no matter how much of the old one
+    /// was used up.
+    pub fn with_options(mut self, options: ReaderOptions) -> Self {
+        self.arena.set_options(options);
+        self
+    }
+
+    /// Iterates over the segments of the message, in order, as they were handed to the reader.
+    pub fn segments(&self) -> impl Iterator<Item = &[u8]> {
+        let segments = self.arena.segments();
+        (0..).map_while(move |idx| segments.get_segment(idx))
+    }
+
+    /// Gets the segments of the message in the form that `message::Builder::get_segments_for_output()`
+    /// returns, so that a message that was read can be written out again unchanged, with the same
+    /// segment boundaries, e.g. by `serialize::write_message_segments()`.
+    #[cfg(feature = "alloc")]
+    pub fn output_segments(&self) -> OutputSegments<'_> {
+        let mut segments: Vec<&[u8]> = self.segments().collect();
+        if segments.len() == 1 {
+            OutputSegments::SingleSegment([segments.remove(0)])
+        } else {
+            OutputSegments::MultiSegment(segments)
+        }
+    }
+
+    /// Gets the total size of the segments in bytes, not counting the segment table that
+    /// precedes them in the standard serialization.
+    pub fn total_bytes(&self) -> usize {
+        self.segments().map(<[u8]>::len).sum()
+    }
+
+    /// Converts the segments with `f`, keeping the options. For example,
+    /// `map_segments(Arc::new)` makes a reader that is cheap to clone.
+    pub fn map_segments<S2, F>(self, f: F) -> Reader<S2>
+    where
+        S2: ReaderSegments,
+        F: FnOnce(S) -> S2,
+    {
+        Reader {
+            arena: self.arena.map_segments(f),
+        }
+    }
+
     /// Checks whether the message is [canonical](https://capnproto.org/encoding.html#canonicalization).
     pub fn is_canonical(&self) -> Result<bool> {
         let (segment_start, seg_len) = self.arena.get_segment(0)?;
@@ -276,6 +491,32 @@ where
         Ok(root_is_canonical && all_words_consumed)
     }
 
+    /// Walks every pointer in the message up front, checking that each one is in bounds and
+    /// well-formed: far pointers land on valid landing pads, list element sizes match their
+    /// word counts, and pointer kinds are known. Returns the first problem found, with the
+    /// path of the pointer that led to it, e.g. `at root.ptr3[2].ptr0`. Struct pointer fields
+    /// are named by their index in the pointer section, because the walk does not know the
+    /// schema.
+    ///
+    /// If this succeeds, the message can be read with a `traversal_limit_in_words` of `None`:
+    /// reading every object once costs `words_traversed` words, and no pointer leads out of
+    /// bounds. Checks that need the schema are still done by the accessors, such as whether
+    /// a pointer has the expected kind, and whether text is NUL-terminated.
+    ///
+    /// This does not build any readers and does not count against the reader's own
+    /// traversal limit.
+    #[cfg(feature = "alloc")]
+    pub fn validate_deep(&self, options: &ValidationOptions) -> Result<ValidationReport> {
+        let (segment_start, _seg_len) = self.arena.get_segment(0)?;
+        let pointer_reader = layout::PointerReader::get_root(
+            &self.arena,
+            0,
+            segment_start,
+            self.arena.nesting_limit(),
+        )?;
+        pointer_reader.validate_deep(options)
+    }
+
     /// Gets the [canonical](https://capnproto.org/encoding.html#canonicalization) form
     /// of this message. Works by copying the message twice. For a canonicalization
     /// method that only requires one copy, see `message::Builder::set_root_canonical()`.
@@ -299,17 +540,50 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
+impl Reader<crate::serialize::OwnedSegments> {
+    /// Returns the buffer holding the message's segments. For a message read by
+    /// `serialize::read_message_from_owned()`, this is the buffer that was passed in, which
+    /// still holds the complete serialized message.
+    pub fn into_owned_words(self) -> Vec<crate::Word> {
+        self.into_segments().into_words()
+    }
+}
+
 /// A message reader whose value is known to be of type `T`.
 /// Please see [module documentation](self) for more info about reader type specialization.
+///
+/// Like [`Reader`], this is `Send` and `Sync` depending only on `S`. To read one message from
+/// several threads, share its segments through an `Arc`:
+///
+/// ```ignore
+/// let shared = typed_reader.map_segments(Arc::new);
+/// let for_worker = shared.clone();
+/// std::thread::spawn(move || process(for_worker.get()?));
+/// ```
 pub struct TypedReader<S, T>
 where
     S: ReaderSegments,
     T: Owned,
 {
-    marker: ::core::marker::PhantomData<T>,
+    // `fn() -> T`, so that `T` does not affect `Send` and `Sync`.
+    marker: ::core::marker::PhantomData<fn() -> T>,
     message: Reader<S>,
 }
 
+impl<S, T> Clone for TypedReader<S, T>
+where
+    S: ReaderSegments + Clone,
+    T: Owned,
+{
+    fn clone(&self) -> Self {
+        Self {
+            marker: ::core::marker::PhantomData,
+            message: self.message.clone(),
+        }
+    }
+}
+
 impl<S, T> TypedReader<S, T>
 where
     S: ReaderSegments,
@@ -329,6 +603,15 @@ where
     pub fn into_inner(self) -> Reader<S> {
         self.message
     }
+
+    /// Converts the segments with `f`, as in [`Reader::map_segments()`].
+    pub fn map_segments<S2, F>(self, f: F) -> TypedReader<S2, T>
+    where
+        S2: ReaderSegments,
+        F: FnOnce(S) -> S2,
+    {
+        TypedReader::new(self.message.map_segments(f))
+    }
 }
 
 impl<S, T> From<Reader<S>> for TypedReader<S, T>
@@ -390,12 +673,33 @@ pub unsafe trait Allocator {
     /// `words_used` is always less than or equal to `word_size`, and indicates how many
     /// words (contiguous from the start of the segment) were possibly written with non-zero values.
     ///
+    /// A segment stays in use across `Builder::clear()`, which zeroes it itself, so this
+    /// method is only called once the `Builder` is dropped or `into_allocator()` is called.
+    ///
     /// # Safety
     /// Callers must only call this method on a pointer that has previously been been returned
     /// from `allocate_segment()`, and only once on each such segment. `word_size` must
     /// equal the word size returned from `allocate_segment()`, and `words_used` must be at
     /// most `word_size`.
     unsafe fn deallocate_segment(&mut self, ptr: *mut u8, word_size: u32, words_used: u32);
+
+    /// Hands over ownership of a segment, previously allocated via `allocate_segment()`, as a
+    /// `Vec` holding its first `words_used` words, instead of deallocating it. Returns `None`
+    /// if the segment's memory cannot be owned by a `Vec`, which is the default; the caller
+    /// then copies the segment and deallocates it as usual.
+    ///
+    /// # Safety
+    /// The same as for `deallocate_segment()`. If this returns `Some`, the segment must not
+    /// be deallocated afterwards.
+    #[cfg(feature = "alloc")]
+    unsafe fn take_segment(
+        &mut self,
+        _ptr: *mut u8,
+        _word_size: u32,
+        _words_used: u32,
+    ) -> Option<Vec<crate::Word>> {
+        None
+    }
 }
 
 /// A container used to build a message.
@@ -413,6 +717,15 @@ fn _assert_kinds() {
     fn _assert_reader<S: ReaderSegments + Send>() {
         _assert_send::<Reader<S>>();
     }
+    fn _assert_typed_reader<S: ReaderSegments + Send, T: Owned>() {
+        _assert_send::<TypedReader<S, T>>();
+    }
+    #[cfg(feature = "sync_reader")]
+    fn _assert_sync_reader<S: ReaderSegments + Sync, T: Owned>() {
+        fn _assert_sync<T: Sync>() {}
+        _assert_sync::<Reader<S>>();
+        _assert_sync::<TypedReader<S, T>>();
+    }
     fn _assert_builder<A: Allocator + Send>() {
         _assert_send::<Builder<A>>();
     }
@@ -428,12 +741,29 @@ where
         }
     }
 
+    /// Caps the total number of words that this message may allocate, counting words that
+    /// have already been allocated but not the root pointer. Once the budget would be exceeded,
+    /// allocations fail with an error of kind `Overloaded`: methods that return a `Result`,
+    /// such as `set_root()`, `try_init_root()` and the setters of pointer fields, return that
+    /// error, while infallible methods such as `init_root()` panic with it. The object whose
+    /// allocation failed is left null, and everything written before stays in place.
+    pub fn set_allocation_budget(&mut self, words: usize) {
+        self.arena.set_allocation_budget(Some(words));
+    }
+
+    /// Removes any limit set by `set_allocation_budget()`.
+    pub fn clear_allocation_budget(&mut self) {
+        self.arena.set_allocation_budget(None);
+    }
+
+    /// Returns the number of words allocated so far, not counting the root pointer.
+    pub fn allocated_words(&self) -> usize {
+        self.arena.allocated_words()
+    }
+
     fn get_root_internal(&mut self) -> any_pointer::Builder<'_> {
         if self.arena.is_empty() {
-            self.arena
-                .allocate_segment(1)
-                .expect("allocate root pointer");
-            self.arena.allocate(0, 1).expect("allocate root pointer");
+            self.arena.allocate_root_pointer();
         }
         let (seg_start, _seg_len) = self.arena.get_segment_mut(0);
         let location: *mut u8 = seg_start;
@@ -442,6 +772,37 @@ where
         any_pointer::Builder::new(layout::PointerBuilder::get_root(arena, 0, location))
     }
 
+    /// Allocates a new value of the given type that is not attached to the message tree.
+    /// Returns the value as an orphan, which can later be adopted somewhere in this message,
+    /// along with a builder for filling it in.
+    pub fn new_orphan<T: Owned>(&mut self) -> (Orphan<T>, T::Builder<'_>) {
+        self.new_orphan_list(0)
+    }
+
+    /// Like `new_orphan()`, but for list types, with the given length.
+    pub fn new_orphan_list<T: Owned>(&mut self, length: u32) -> (Orphan<T>, T::Builder<'_>) {
+        // Make sure that the root pointer claims the first word of the first segment.
+        self.get_root_internal();
+        if let TypeVariant::AnyPointer = T::introspect().which() {
+            // An `AnyPointer` builder is itself a pointer, so it needs a word of the message to
+            // live in. Nothing is allocated behind it, so the orphan is null.
+            let (segment_id, word_idx) = self
+                .arena
+                .allocate_anywhere(1)
+                .expect("allocate orphan pointer");
+            let (seg_start, _seg_len) = self.arena.get_segment_mut(segment_id);
+            let location = unsafe { seg_start.add(word_idx as usize * BYTES_PER_WORD) };
+            let mut pointer =
+                layout::PointerBuilder::get_root(&mut self.arena, segment_id, location);
+            let orphan = Orphan::new(pointer.reborrow().orphan_of_detached());
+            return (orphan, FromPointerBuilder::init_pointer(pointer, length));
+        }
+        let (orphan, builder) = layout::OrphanBuilder::new(&mut self.arena, |pointer| {
+            FromPointerBuilder::init_pointer(pointer, length)
+        });
+        (Orphan::new(orphan), builder)
+    }
+
     /// Initializes the root as a value of the given type.
     pub fn init_root<'a, T: FromPointerBuilder<'a>>(&'a mut self) -> T {
         let root = self.get_root_internal();
@@ -454,6 +815,18 @@ where
         root.initn_as(length)
     }
 
+    /// Like `init_root()`, but returns an error if the allocation budget is exhausted.
+    pub fn try_init_root<'a, T: FromPointerBuilder<'a>>(&'a mut self) -> Result<T> {
+        let root = self.get_root_internal();
+        root.try_init_as()
+    }
+
+    /// Like `initn_root()`, but returns an error if the allocation budget is exhausted.
+    pub fn try_initn_root<'a, T: FromPointerBuilder<'a>>(&'a mut self, length: u32) -> Result<T> {
+        let root = self.get_root_internal();
+        root.try_initn_as(length)
+    }
+
     /// Gets the root, interpreting it as the given type.
     pub fn get_root<'a, T: FromPointerBuilder<'a>>(&'a mut self) -> Result<T> {
         let root = self.get_root_internal();
@@ -487,10 +860,7 @@ where
     /// a single segment, containing the full canonicalized message.
     pub fn set_root_canonical<From: SetPointerBuilder>(&mut self, value: From) -> Result<()> {
         if self.arena.is_empty() {
-            self.arena
-                .allocate_segment(1)
-                .expect("allocate root pointer");
-            self.arena.allocate(0, 1).expect("allocate root pointer");
+            self.arena.allocate_root_pointer();
         }
         let (seg_start, _seg_len) = self.arena.get_segment_mut(0);
         let pointer = layout::PointerBuilder::get_root(&mut self.arena, 0, seg_start);
@@ -509,10 +879,34 @@ where
             ReaderOptions {
                 traversal_limit_in_words: None,
                 nesting_limit: i32::MAX,
+                total_message_size_limit_in_words: None,
             },
         )
     }
 
+    /// Converts this builder into a reader that owns the message's segments, so that its type
+    /// does not depend on the allocator.
+    ///
+    /// If the message has a single segment and the allocator can hand it over, as
+    /// `HeapAllocator` can, the segment is moved into the reader without being copied.
+    /// Otherwise the segments are copied into one buffer, as `serialize::read_message()`
+    /// would hold them, and given back to the allocator.
+    #[cfg(feature = "alloc")]
+    pub fn into_owned_reader(mut self) -> Result<Reader<crate::serialize::OwnedSegments>> {
+        if self.arena.is_empty() {
+            self.arena.allocate_root_pointer();
+        }
+        let segments = self.arena.into_owned_segments()?;
+        Ok(Reader::new(
+            segments,
+            ReaderOptions {
+                traversal_limit_in_words: None,
+                nesting_limit: i32::MAX,
+                total_message_size_limit_in_words: None,
+            },
+        ))
+    }
+
     pub fn into_typed<T: Owned>(self) -> TypedBuilder<T, A> {
         TypedBuilder::new(self)
     }
@@ -522,6 +916,16 @@ where
     pub fn into_allocator(self) -> A {
         self.arena.into_allocator()
     }
+
+    /// Resets this builder to an empty message without giving its segments back to the
+    /// allocator. The used part of each segment is zeroed, and the next message is built
+    /// starting from the beginning of the first segment.
+    ///
+    /// This avoids an allocation per message when many messages are built in a loop.
+    /// Orphans created before the call cannot be adopted afterwards.
+    pub fn clear(&mut self) {
+        self.arena.clear();
+    }
 }
 
 impl<A> ReaderSegments for Builder<A>
@@ -574,6 +978,14 @@ where
     pub fn new_default() -> Self {
         Default::default()
     }
+
+    /// Creates a builder whose root is a deep copy of `value`, so that the copy can be
+    /// modified without affecting the message that `value` was read from.
+    pub fn from_reader(value: T::Reader<'_>) -> Result<Self> {
+        let mut builder = Self::new_default();
+        builder.set_root(value)?;
+        Ok(builder)
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -633,6 +1045,15 @@ where
     pub fn into_reader(self) -> TypedReader<Builder<A>, T> {
         TypedReader::new(self.message.into_reader())
     }
+
+    /// Like `into_reader()`, but the reader owns the segments. See
+    /// [`Builder::into_owned_reader()`].
+    #[cfg(feature = "alloc")]
+    pub fn into_owned_typed_reader(
+        self,
+    ) -> Result<TypedReader<crate::serialize::OwnedSegments, T>> {
+        Ok(TypedReader::new(self.message.into_owned_reader()?))
+    }
 }
 
 impl<T, A> From<Builder<A>> for TypedBuilder<T, A>
@@ -733,6 +1154,23 @@ unsafe impl Allocator for HeapAllocator {
         (ptr, size)
     }
 
+    unsafe fn take_segment(
+        &mut self,
+        ptr: *mut u8,
+        word_size: u32,
+        words_used: u32,
+    ) -> Option<Vec<crate::Word>> {
+        // The segment was allocated with the layout of a `Vec<Word>` of `word_size` capacity.
+        self.next_size = SUGGESTED_FIRST_SEGMENT_WORDS;
+        Some(unsafe {
+            Vec::from_raw_parts(
+                ptr as *mut crate::Word,
+                words_used as usize,
+                word_size as usize,
+            )
+        })
+    }
+
     unsafe fn deallocate_segment(&mut self, ptr: *mut u8, word_size: u32, _words_used: u32) {
         unsafe {
             alloc::alloc::dealloc(
@@ -983,4 +1421,13 @@ where
     unsafe fn deallocate_segment(&mut self, ptr: *mut u8, word_size: u32, words_used: u32) {
         (*self).deallocate_segment(ptr, word_size, words_used)
     }
+
+    unsafe fn take_segment(
+        &mut self,
+        ptr: *mut u8,
+        word_size: u32,
+        words_used: u32,
+    ) -> Option<Vec<crate::Word>> {
+        (*self).take_segment(ptr, word_size, words_used)
+    }
 }
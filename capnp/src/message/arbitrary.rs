@@ -0,0 +1,260 @@
+//! Random but valid messages, for property testing.
+//!
+//! [`fill_arbitrary()`] walks the schema of a struct through the `dynamic_struct` API and sets
+//! every field to a random value: primitives get random bits, enums a random enumerant,
+//! text and data a random length up to [`ArbitraryConfig::max_list_length`], lists a random
+//! length with random elements, and unions a random member. Nested structs and lists stop at
+//! [`ArbitraryConfig::max_depth`], which keeps recursive schemas finite. `AnyPointer` and
+//! interface fields are left null.
+//!
+//! ```ignore
+//! use rand::SeedableRng;
+//!
+//! let mut rng = rand::rngs::SmallRng::seed_from_u64(42);
+//! let mut message = capnp::message::Builder::new_default();
+//! let root = message.init_root::<foo::Builder>();
+//! capnp::message::arbitrary::fill_arbitrary::<foo::Owned>(root, &mut rng, &Default::default())?;
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use rand_core::RngCore;
+
+use crate::introspect::TypeVariant;
+use crate::schema::{EnumSchema, Field};
+use crate::schema_capnp::field;
+use crate::traits::Owned;
+use crate::{dynamic_list, dynamic_struct, dynamic_value, Error, ErrorKind, Result};
+
+/// Options controlling the messages built by [`fill_arbitrary()`].
+#[derive(Clone, Debug)]
+pub struct ArbitraryConfig {
+    /// Limits how deeply structs and lists are nested below the root. Struct and list fields
+    /// deeper than this are left null. Groups don't count, since they are part of their parent.
+    pub max_depth: u32,
+
+    /// Limits the number of elements of a list, and the number of characters of a text or
+    /// bytes of a data value.
+    pub max_list_length: u32,
+
+    /// The characters that text values are made of. Text values are empty if this is empty.
+    pub alphabet: String,
+}
+
+impl Default for ArbitraryConfig {
+    fn default() -> Self {
+        Self {
+            max_depth: 4,
+            max_list_length: 8,
+            alphabet: "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789 ".into(),
+        }
+    }
+}
+
+impl ArbitraryConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn max_depth(&mut self, value: u32) -> &mut Self {
+        self.max_depth = value;
+        self
+    }
+
+    pub fn max_list_length(&mut self, value: u32) -> &mut Self {
+        self.max_list_length = value;
+        self
+    }
+
+    pub fn alphabet(&mut self, value: impl Into<String>) -> &mut Self {
+        self.alphabet = value.into();
+        self
+    }
+}
+
+/// Fills `builder` with random values, as described in the [module documentation](self).
+///
+/// `builder` is usually a freshly initialized struct, such as the result of
+/// `message.init_root::<foo::Builder>()`. Fields that end up null or default keep whatever
+/// `builder` already held, so a builder with existing content is not cleared first.
+///
+/// Returns an error if `T` is not a struct or list type.
+pub fn fill_arbitrary<'a, T>(
+    builder: T::Builder<'a>,
+    rng: &mut impl RngCore,
+    config: &ArbitraryConfig,
+) -> Result<()>
+where
+    T: Owned,
+    T::Builder<'a>: Into<dynamic_value::Builder<'a>>,
+{
+    match builder.into() {
+        dynamic_value::Builder::Struct(builder) => fill_struct(builder, rng, config, 0),
+        dynamic_value::Builder::List(builder) => fill_list(builder, rng, config, 0),
+        _ => Err(Error::failed(String::from(
+            "fill_arbitrary() needs a struct or list type",
+        ))),
+    }
+}
+
+fn fill_struct(
+    mut builder: dynamic_struct::Builder<'_>,
+    rng: &mut impl RngCore,
+    config: &ArbitraryConfig,
+    depth: u32,
+) -> Result<()> {
+    let schema = builder.get_schema();
+    for field in schema.get_non_union_fields()? {
+        fill_field(&mut builder, field, rng, config, depth)?;
+    }
+    let union_fields = schema.get_union_fields()?;
+    if !union_fields.is_empty() {
+        let field = union_fields.get(random_below(rng, union_fields.len().into()) as u16);
+        // Makes `field` the active member, even if it stays at its default.
+        builder.clear(field)?;
+        fill_field(&mut builder, field, rng, config, depth)?;
+    }
+    Ok(())
+}
+
+fn fill_field(
+    builder: &mut dynamic_struct::Builder<'_>,
+    field: Field,
+    rng: &mut impl RngCore,
+    config: &ArbitraryConfig,
+    depth: u32,
+) -> Result<()> {
+    if let field::Group(_) = field.get_proto().which()? {
+        let dynamic_value::Builder::Struct(group) = builder.reborrow().init(field)? else {
+            return Err(Error::from_kind(ErrorKind::NotAStruct));
+        };
+        return fill_struct(group, rng, config, depth);
+    }
+
+    let ty = field.get_type();
+    match ty.which() {
+        TypeVariant::Struct(_) if depth < config.max_depth => {
+            let dynamic_value::Builder::Struct(child) = builder.reborrow().init(field)? else {
+                return Err(Error::from_kind(ErrorKind::NotAStruct));
+            };
+            fill_struct(child, rng, config, depth + 1)
+        }
+        TypeVariant::List(_) if depth < config.max_depth => {
+            let length = random_below(rng, u64::from(config.max_list_length) + 1) as u32;
+            let dynamic_value::Builder::List(list) = builder.reborrow().initn(field, length)?
+            else {
+                return Err(Error::from_kind(ErrorKind::TypeMismatch));
+            };
+            fill_list(list, rng, config, depth + 1)
+        }
+        TypeVariant::Text => {
+            let text = random_text(rng, config);
+            builder.set(field, dynamic_value::Reader::Text(text.as_str().into()))
+        }
+        TypeVariant::Data => {
+            let data = random_data(rng, config);
+            builder.set(field, dynamic_value::Reader::Data(&data))
+        }
+        variant => match random_primitive(variant, rng)? {
+            Some(value) => builder.set(field, value),
+            None => Ok(()),
+        },
+    }
+}
+
+fn fill_list(
+    mut builder: dynamic_list::Builder<'_>,
+    rng: &mut impl RngCore,
+    config: &ArbitraryConfig,
+    depth: u32,
+) -> Result<()> {
+    for index in 0..builder.len() {
+        match builder.element_type().which() {
+            TypeVariant::Struct(_) => {
+                let dynamic_value::Builder::Struct(element) = builder.reborrow().get(index)? else {
+                    return Err(Error::from_kind(ErrorKind::NotAStruct));
+                };
+                fill_struct(element, rng, config, depth)?;
+            }
+            TypeVariant::List(_) if depth < config.max_depth => {
+                let length = random_below(rng, u64::from(config.max_list_length) + 1) as u32;
+                let dynamic_value::Builder::List(element) =
+                    builder.reborrow().init(index, length)?
+                else {
+                    return Err(Error::from_kind(ErrorKind::TypeMismatch));
+                };
+                fill_list(element, rng, config, depth + 1)?;
+            }
+            TypeVariant::Text => {
+                let text = random_text(rng, config);
+                builder.set(index, dynamic_value::Reader::Text(text.as_str().into()))?;
+            }
+            TypeVariant::Data => {
+                let data = random_data(rng, config);
+                builder.set(index, dynamic_value::Reader::Data(&data))?;
+            }
+            variant => {
+                if let Some(value) = random_primitive(variant, rng)? {
+                    builder.set(index, value)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns a random value of a primitive or enum type, or `None` for the types that are left
+/// at their defaults: void, pointers beyond the depth limit, `AnyPointer` and interfaces.
+fn random_primitive(
+    variant: TypeVariant,
+    rng: &mut impl RngCore,
+) -> Result<Option<dynamic_value::Reader<'static>>> {
+    Ok(Some(match variant {
+        TypeVariant::Bool => dynamic_value::Reader::Bool(rng.next_u32() & 1 == 1),
+        TypeVariant::Int8 => dynamic_value::Reader::Int8(rng.next_u32() as i8),
+        TypeVariant::Int16 => dynamic_value::Reader::Int16(rng.next_u32() as i16),
+        TypeVariant::Int32 => dynamic_value::Reader::Int32(rng.next_u32() as i32),
+        TypeVariant::Int64 => dynamic_value::Reader::Int64(rng.next_u64() as i64),
+        TypeVariant::UInt8 => dynamic_value::Reader::UInt8(rng.next_u32() as u8),
+        TypeVariant::UInt16 => dynamic_value::Reader::UInt16(rng.next_u32() as u16),
+        TypeVariant::UInt32 => dynamic_value::Reader::UInt32(rng.next_u32()),
+        TypeVariant::UInt64 => dynamic_value::Reader::UInt64(rng.next_u64()),
+        TypeVariant::Float32 => dynamic_value::Reader::Float32(f32::from_bits(rng.next_u32())),
+        TypeVariant::Float64 => dynamic_value::Reader::Float64(f64::from_bits(rng.next_u64())),
+        TypeVariant::Enum(raw) => {
+            let schema = EnumSchema::new(raw);
+            let count = schema.get_enumerants()?.len();
+            let value = random_below(rng, count.into()) as u16;
+            dynamic_value::Reader::Enum(dynamic_value::Enum::new(value, schema))
+        }
+        _ => return Ok(None),
+    }))
+}
+
+fn random_text(rng: &mut impl RngCore, config: &ArbitraryConfig) -> String {
+    let alphabet = config.alphabet.chars().collect::<Vec<_>>();
+    if alphabet.is_empty() {
+        return String::new();
+    }
+    let length = random_below(rng, u64::from(config.max_list_length) + 1);
+    (0..length)
+        .map(|_| alphabet[random_below(rng, alphabet.len() as u64) as usize])
+        .collect()
+}
+
+fn random_data(rng: &mut impl RngCore, config: &ArbitraryConfig) -> Vec<u8> {
+    let mut data =
+        alloc::vec![0; random_below(rng, u64::from(config.max_list_length) + 1) as usize];
+    rng.fill_bytes(&mut data);
+    data
+}
+
+/// Returns a random number in `0..bound`, or 0 if `bound` is 0.
+fn random_below(rng: &mut impl RngCore, bound: u64) -> u64 {
+    if bound == 0 {
+        0
+    } else {
+        rng.next_u64() % bound
+    }
+}
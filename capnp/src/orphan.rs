@@ -0,0 +1,67 @@
+// Copyright (c) 2013-2015 Sandstorm Development Group, Inc. and contributors
+// Licensed under the MIT License:
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Objects that have been detached from a message tree.
+
+use core::marker::PhantomData;
+
+use crate::private::layout::OrphanBuilder;
+
+/// An object of type `T` that belongs to a message but is not currently reachable from the
+/// message root.
+///
+/// Orphans are created by the `disown_*()` accessors on generated builders, or directly by
+/// [`message::Builder::new_orphan()`](crate::message::Builder::new_orphan). They can be linked
+/// back into the same message with the corresponding `adopt_*()` accessor. Adopting an orphan
+/// into a different message fails with
+/// [`ErrorKind::AdoptedObjectMustLiveInTheSameMessage`](crate::ErrorKind::AdoptedObjectMustLiveInTheSameMessage).
+///
+/// Dropping an orphan leaves its object unreachable. The space it occupies is not reclaimed until
+/// the message itself is dropped.
+pub struct Orphan<T> {
+    builder: OrphanBuilder,
+    marker: PhantomData<T>,
+}
+
+impl<T> Orphan<T> {
+    #[doc(hidden)]
+    pub fn new(builder: OrphanBuilder) -> Self {
+        Self {
+            builder,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns an orphan that holds nothing. Adopting it sets the target pointer to null.
+    pub fn null() -> Self {
+        Self::new(OrphanBuilder::null())
+    }
+
+    #[doc(hidden)]
+    pub fn into_internal(self) -> OrphanBuilder {
+        self.builder
+    }
+
+    /// Returns true if this orphan was made by disowning a null pointer.
+    pub fn is_null(&self) -> bool {
+        self.builder.is_null()
+    }
+}
@@ -237,6 +237,12 @@ impl<'a, T: PrimitiveElement> FromPointerBuilder<'a> for Builder<'a, T> {
             marker: marker::PhantomData,
         }
     }
+    fn try_init_pointer(builder: PointerBuilder<'a>, size: u32) -> Result<Builder<'a, T>> {
+        Ok(Builder {
+            builder: builder.try_init_list(T::element_size(), size)?,
+            marker: marker::PhantomData,
+        })
+    }
     fn get_from_pointer(
         builder: PointerBuilder<'a>,
         default: Option<&'a [crate::Word]>,
@@ -272,6 +278,61 @@ impl<'a, T: PrimitiveElement> Builder<'a, T> {
             builder: self.builder.reborrow(),
         }
     }
+
+    /// Copies the elements of `src` into the list. Panics if `src.len()` is not equal to
+    /// `len()`.
+    ///
+    /// When the list's wire layout matches the in-memory layout of `[T]`, this is a single
+    /// `memcpy`. That is the case for lists of one-byte elements, and for lists of multi-byte
+    /// numbers on little-endian targets, but never for `List(Bool)`, which packs eight
+    /// elements per byte.
+    pub fn set_from_slice(&mut self, src: &[T])
+    where
+        T: Copy,
+    {
+        assert_eq!(
+            src.len(),
+            self.len() as usize,
+            "source slice length does not match list length"
+        );
+        if self.has_native_layout() {
+            let bytes = self.builder.as_raw_bytes();
+            let len = core::mem::size_of_val(src);
+            debug_assert!(len <= bytes.len());
+            unsafe {
+                core::ptr::copy_nonoverlapping(src.as_ptr() as *const u8, bytes.as_mut_ptr(), len);
+            }
+        } else {
+            for (index, value) in src.iter().enumerate() {
+                PrimitiveElement::set(&self.builder, index as u32, *value);
+            }
+        }
+    }
+
+    /// Sets every element of the list to `value`.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Copy,
+    {
+        for index in 0..self.len() {
+            PrimitiveElement::set(&self.builder, index, value);
+        }
+    }
+
+    /// Returns a mutable view of the list as a native Rust slice, like
+    /// [`as_slice()`](Self::as_slice), which has the same compile-time requirements.
+    pub fn as_mut_slice(&mut self) -> Option<&mut [T]> {
+        self.as_slice()
+    }
+
+    // Whether the elements are laid out on the wire exactly as in a `[T]`.
+    fn has_native_layout(&self) -> bool {
+        let size = core::mem::size_of::<T>();
+        self.builder.get_element_size() == T::element_size()
+            && size > 0
+            && data_bits_per_element(T::element_size()) as usize == 8 * size
+            && (size == 1 || cfg!(target_endian = "little"))
+    }
 }
 
 impl<'a, T> crate::traits::SetPointerBuilder for Reader<'a, T>
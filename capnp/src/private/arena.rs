@@ -19,6 +19,8 @@
 // THE SOFTWARE.
 
 use core::slice;
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::{AtomicUsize, Ordering};
 #[cfg(feature = "alloc")]
 use smallvec::SmallVec;
 
@@ -54,6 +56,7 @@ pub trait ReaderArena {
 
 pub struct ReaderArenaImpl<S> {
     segments: S,
+    options: message::ReaderOptions,
     read_limiter: ReadLimiter,
     nesting_limit: i32,
 }
@@ -74,14 +77,49 @@ where
         let limiter = ReadLimiter::new(options.traversal_limit_in_words);
         Self {
             segments,
+            options,
             read_limiter: limiter,
             nesting_limit: options.nesting_limit,
         }
     }
 
+    pub fn segments(&self) -> &S {
+        &self.segments
+    }
+
+    pub fn options(&self) -> message::ReaderOptions {
+        self.options
+    }
+
+    /// Replaces the options. The read limit starts over from the new traversal limit.
+    pub fn set_options(&mut self, options: message::ReaderOptions) {
+        self.options = options;
+        self.read_limiter = ReadLimiter::new(options.traversal_limit_in_words);
+        self.nesting_limit = options.nesting_limit;
+    }
+
     pub fn into_segments(self) -> S {
         self.segments
     }
+
+    /// Replaces the segments, keeping the options. The read limit starts over.
+    pub fn map_segments<S2, F>(self, f: F) -> ReaderArenaImpl<S2>
+    where
+        S2: ReaderSegments,
+        F: FnOnce(S) -> S2,
+    {
+        ReaderArenaImpl::new(f(self.segments), self.options)
+    }
+}
+
+impl<S> Clone for ReaderArenaImpl<S>
+where
+    S: ReaderSegments + Clone,
+{
+    // The clone gets its own read limit, so reads through it don't count against this one.
+    fn clone(&self) -> Self {
+        Self::new(self.segments.clone(), self.options)
+    }
 }
 
 impl<S> ReaderArena for ReaderArenaImpl<S>
@@ -151,10 +189,16 @@ where
 }
 
 pub trait BuilderArena: ReaderArena {
-    fn allocate(&mut self, segment_id: u32, amount: WordCount32) -> Option<u32>;
-    fn allocate_anywhere(&mut self, amount: u32) -> (SegmentId, u32);
+    /// Tries to allocate `amount` words in the given segment. Returns `Ok(None)` if the segment
+    /// does not have enough room, and an error if the allocation budget would be exceeded.
+    fn allocate(&mut self, segment_id: u32, amount: WordCount32) -> Result<Option<u32>>;
+    fn allocate_anywhere(&mut self, amount: u32) -> Result<(SegmentId, u32)>;
     fn get_segment_mut(&mut self, id: u32) -> (*mut u8, u32);
 
+    /// An identifier for the message that owns this arena, unique for the life of the process.
+    /// Orphans carry it so that they can only be adopted back into the same message.
+    fn message_id(&self) -> usize;
+
     fn as_reader(&self) -> &dyn ReaderArena;
 }
 
@@ -229,6 +273,12 @@ where
     allocator: Option<A>, // None if has already be deallocated.
 
     segments: BuilderSegmentArray,
+
+    /// Maximum number of words that may be allocated, not counting the root pointer.
+    budget: Option<usize>,
+
+    /// Number of words allocated so far, not counting the root pointer.
+    allocated_words: usize,
 }
 
 pub struct BuilderArenaImpl<A>
@@ -236,6 +286,23 @@ where
     A: Allocator,
 {
     inner: BuilderArenaImplInner<A>,
+    message_id: usize,
+}
+
+#[cfg(target_has_atomic = "ptr")]
+static NEXT_MESSAGE_ID: AtomicUsize = AtomicUsize::new(1);
+
+/// Returns an id for a new message. Targets without atomic read-modify-write operations get 0
+/// every time, so on those an orphan can be adopted into any message.
+fn next_message_id() -> usize {
+    #[cfg(target_has_atomic = "ptr")]
+    {
+        NEXT_MESSAGE_ID.fetch_add(1, Ordering::Relaxed)
+    }
+    #[cfg(not(target_has_atomic = "ptr"))]
+    {
+        0
+    }
 }
 
 impl<A> BuilderArenaImpl<A>
@@ -247,7 +314,10 @@ where
             inner: BuilderArenaImplInner {
                 allocator: Some(allocator),
                 segments: Default::default(),
+                budget: None,
+                allocated_words: 0,
             },
+            message_id: next_message_id(),
         }
     }
 
@@ -256,6 +326,37 @@ where
         self.inner.allocate_segment(minimum_size)
     }
 
+    /// Allocates the first segment and claims its first word for the root pointer. The root
+    /// pointer is not charged against the allocation budget.
+    pub fn allocate_root_pointer(&mut self) {
+        self.inner
+            .allocate_segment(1)
+            .expect("allocate root pointer");
+        self.inner
+            .try_allocate(0, 1)
+            .expect("allocate root pointer");
+    }
+
+    /// Limits the total number of words that may be allocated, counting words that have
+    /// already been allocated. Once the budget is exhausted, allocations fail with
+    /// an `Overloaded` error.
+    pub fn set_allocation_budget(&mut self, words: Option<usize>) {
+        self.inner.budget = words;
+    }
+
+    /// Returns the number of words allocated so far, not counting the root pointer.
+    pub fn allocated_words(&self) -> usize {
+        self.inner.allocated_words
+    }
+
+    /// Zeroes the used part of every segment and resets the allocation cursors, so that the
+    /// next message is built in the same memory. The segments stay allocated.
+    pub fn clear(&mut self) {
+        self.inner.clear();
+        // Orphans taken from the old message must not be adoptable into the new one.
+        self.message_id = next_message_id();
+    }
+
     pub fn get_segments_for_output(&self) -> OutputSegments {
         let reff = &self.inner;
         if reff.segments.len() == 1 {
@@ -271,8 +372,25 @@ where
         } else {
             #[cfg(feature = "alloc")]
             {
-                let mut v = alloc::vec::Vec::with_capacity(reff.segments.len());
-                for seg in &reff.segments {
+                // Segments left unused after `clear()` hold nothing that could be pointed to.
+                let used = reff
+                    .segments
+                    .iter()
+                    .rposition(|seg| seg.allocated > 0)
+                    .map_or(1, |idx| idx + 1);
+                if used == 1 {
+                    let seg = &reff.segments[0];
+                    // See safety argument in above branch.
+                    let slice = unsafe {
+                        slice::from_raw_parts(
+                            seg.ptr as *const _,
+                            seg.allocated as usize * BYTES_PER_WORD,
+                        )
+                    };
+                    return OutputSegments::SingleSegment([slice]);
+                }
+                let mut v = alloc::vec::Vec::with_capacity(used);
+                for seg in &reff.segments[..used] {
                     // See safety argument in above branch.
                     let slice = unsafe {
                         slice::from_raw_parts(
@@ -299,6 +417,46 @@ where
         self.len() == 0
     }
 
+    /// Moves the used part of the segments into an `OwnedSegments`. A lone segment is taken
+    /// over from the allocator if it allows it; otherwise the segments are copied, and
+    /// deallocated when `self` is dropped.
+    #[cfg(feature = "alloc")]
+    pub fn into_owned_segments(mut self) -> Result<crate::serialize::OwnedSegments> {
+        let inner = &mut self.inner;
+        // Segments left unused after `clear()` hold nothing that could be pointed to.
+        let used = inner
+            .segments
+            .iter()
+            .rposition(|seg| seg.allocated > 0)
+            .map_or(1, |idx| idx + 1);
+        let mut lengths = crate::serialize::SegmentLengthsBuilder::with_capacity(used);
+        for seg in &inner.segments[..used] {
+            lengths.try_push_segment(seg.allocated as usize)?;
+        }
+
+        if used == 1 {
+            let seg = &inner.segments[0];
+            let allocator = inner.allocator.as_mut().unwrap();
+            if let Some(words) =
+                unsafe { allocator.take_segment(seg.ptr, seg.capacity, seg.allocated) }
+            {
+                inner.segments.remove(0);
+                return Ok(lengths.into_owned_segments_from(words));
+            }
+        }
+
+        let mut segments = lengths.into_owned_segments();
+        let mut offset = 0;
+        for seg in &inner.segments[..used] {
+            let len = seg.allocated as usize * BYTES_PER_WORD;
+            // The segment is only borrowed until `self` is dropped at the end of this method.
+            let bytes = unsafe { slice::from_raw_parts(seg.ptr as *const u8, len) };
+            segments[offset..offset + len].copy_from_slice(bytes);
+            offset += len;
+        }
+        Ok(segments)
+    }
+
     /// Retrieves the underlying `Allocator`, deallocating all currently-allocated
     /// segments.
     pub fn into_allocator(mut self) -> A {
@@ -356,7 +514,24 @@ where
         Ok(())
     }
 
-    fn allocate(&mut self, segment_id: u32, amount: WordCount32) -> Option<u32> {
+    /// Returns an error if allocating `amount` more words would exceed the budget.
+    fn check_budget(&self, amount: WordCount32) -> Result<()> {
+        match self.budget {
+            Some(budget) if self.allocated_words + amount as usize > budget => {
+                let mut error = Error::from_kind(ErrorKind::Overloaded);
+                write!(
+                    error,
+                    "allocation budget of {budget} words exceeded: {} words already allocated, \
+                     {amount} more requested",
+                    self.allocated_words
+                );
+                Err(error)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn try_allocate(&mut self, segment_id: u32, amount: WordCount32) -> Option<u32> {
         let seg = &mut self.segments[segment_id as usize];
         if amount > seg.capacity - seg.allocated {
             None
@@ -367,23 +542,50 @@ where
         }
     }
 
-    fn allocate_anywhere(&mut self, amount: u32) -> (SegmentId, u32) {
+    fn allocate(&mut self, segment_id: u32, amount: WordCount32) -> Result<Option<u32>> {
+        self.check_budget(amount)?;
+        let result = self.try_allocate(segment_id, amount);
+        if result.is_some() {
+            self.allocated_words += amount as usize;
+        }
+        Ok(result)
+    }
+
+    fn allocate_anywhere(&mut self, amount: u32) -> Result<(SegmentId, u32)> {
+        self.check_budget(amount)?;
+        self.allocated_words += amount as usize;
+
         // first try the existing segments, then try allocating a new segment.
         let allocated_len = self.segments.len() as u32;
         for segment_id in 0..allocated_len {
-            if let Some(idx) = self.allocate(segment_id, amount) {
-                return (segment_id, idx);
+            if let Some(idx) = self.try_allocate(segment_id, amount) {
+                return Ok((segment_id, idx));
             }
         }
 
         // Need to allocate a new segment.
 
         self.allocate_segment(amount).expect("allocate new segment");
-        (
+        Ok((
             allocated_len,
-            self.allocate(allocated_len, amount)
+            self.try_allocate(allocated_len, amount)
                 .expect("use freshly-allocated segment"),
-        )
+        ))
+    }
+
+    fn clear(&mut self) {
+        #[cfg(feature = "alloc")]
+        let segments = self.segments.iter_mut();
+        #[cfg(not(feature = "alloc"))]
+        let segments = self.segments.segment.iter_mut();
+        for (idx, seg) in segments.enumerate() {
+            unsafe {
+                core::ptr::write_bytes(seg.ptr, 0, seg.allocated as usize * BYTES_PER_WORD);
+            }
+            // The first word of the first segment stays reserved for the root pointer.
+            seg.allocated = u32::from(idx == 0);
+        }
+        self.allocated_words = 0;
     }
 
     fn deallocate_all(&mut self) {
@@ -414,11 +616,11 @@ impl<A> BuilderArena for BuilderArenaImpl<A>
 where
     A: Allocator,
 {
-    fn allocate(&mut self, segment_id: u32, amount: WordCount32) -> Option<u32> {
+    fn allocate(&mut self, segment_id: u32, amount: WordCount32) -> Result<Option<u32>> {
         self.inner.allocate(segment_id, amount)
     }
 
-    fn allocate_anywhere(&mut self, amount: u32) -> (SegmentId, u32) {
+    fn allocate_anywhere(&mut self, amount: u32) -> Result<(SegmentId, u32)> {
         self.inner.allocate_anywhere(amount)
     }
 
@@ -426,6 +628,10 @@ where
         self.inner.get_segment_mut(id)
     }
 
+    fn message_id(&self) -> usize {
+        self.message_id
+    }
+
     fn as_reader(&self) -> &dyn ReaderArena {
         self
     }
@@ -34,7 +34,21 @@ pub trait ResponseHook {
 pub trait RequestHook {
     fn get(&mut self) -> any_pointer::Builder<'_>;
     fn get_brand(&self) -> usize;
+
+    /// Cancels the call if it has not returned within `timeout` of being sent. Tail calls,
+    /// sent with `tail_send()`, ignore it. The default implementation ignores it too.
+    fn set_deadline(&mut self, _timeout: core::time::Duration) {}
+
     fn send(self: Box<Self>) -> RemotePromise<any_pointer::Owned>;
+
+    /// Sends the call as part of a stream of calls to a method declared with `-> stream`.
+    /// The returned promise resolves once the stream is ready for the next call. By default
+    /// that is when this call has returned, which allows a single call in flight.
+    fn send_streaming(self: Box<Self>) -> Promise<(), crate::Error> {
+        let promise = self.send().promise;
+        Promise::from_future(async move { promise.await.map(|_| ()) })
+    }
+
     fn tail_send(
         self: Box<Self>,
     ) -> Option<(
@@ -65,7 +79,8 @@ pub trait ClientHook {
     /// returns an identifier for that connection.
     fn get_brand(&self) -> usize;
 
-    /// Returns a (locally) unique identifier for this capabilitiy.
+    /// Returns a (locally) unique identifier for this capabilitiy. Hooks made by `add_ref()`
+    /// return the same identifier, which is what `capability::Client` equality compares.
     fn get_ptr(&self) -> usize;
 
     /// If this ClientHook is a promise that has already resolved, returns the inner, resolved version
@@ -346,10 +346,12 @@ impl WirePointer {
 
 mod wire_helpers {
     #[cfg(feature = "alloc")]
-    use alloc::boxed::Box;
+    use alloc::{boxed::Box, string::String, vec::Vec};
     use core::{ptr, slice};
 
     use crate::data;
+    #[cfg(feature = "alloc")]
+    use crate::message::ValidationReport;
     use crate::private::arena::*;
     #[cfg(feature = "alloc")]
     use crate::private::capability::ClientHook;
@@ -357,8 +359,8 @@ mod wire_helpers {
     use crate::private::layout::{data_bits_per_element, pointers_per_element};
     use crate::private::layout::{CapTableBuilder, CapTableReader};
     use crate::private::layout::{
-        ElementSize, ListBuilder, ListReader, StructBuilder, StructReader, StructSize, WirePointer,
-        WirePointerKind,
+        ElementSize, ListBuilder, ListReader, OrphanBuilder, StructBuilder, StructReader,
+        StructSize, WirePointer, WirePointerKind, ORPHAN_SEGMENT_ID,
     };
     use crate::private::units::*;
     use crate::text;
@@ -415,7 +417,11 @@ mod wire_helpers {
         segment_id: u32,
         amount: WordCount32,
         kind: WirePointerKind,
-    ) -> (*mut u8, *mut WirePointer, u32) {
+    ) -> Result<(*mut u8, *mut WirePointer, u32)> {
+        if segment_id == ORPHAN_SEGMENT_ID {
+            return allocate_orphan(arena, reff, amount, kind);
+        }
+
         let is_null = (*reff).is_null();
         if !is_null {
             zero_object(arena, segment_id, reff)
@@ -423,17 +429,51 @@ mod wire_helpers {
 
         if amount == 0 && kind == WirePointerKind::Struct {
             (*reff).set_kind_and_target_for_empty_struct();
-            return (reff as *mut _, reff, segment_id);
+            return Ok((reff as *mut _, reff, segment_id));
+        }
+
+        let result = allocate_target(arena, reff, segment_id, amount, kind);
+        if result.is_err() {
+            // The old object has already been zeroed, so don't leave `reff` pointing at it.
+            ptr::write_bytes(reff, 0, 1);
         }
+        result
+    }
 
-        match arena.allocate(segment_id, amount) {
+    unsafe fn allocate_orphan(
+        arena: &mut dyn BuilderArena,
+        reff: *mut WirePointer,
+        amount: WordCount32,
+        kind: WirePointerKind,
+    ) -> Result<(*mut u8, *mut WirePointer, u32)> {
+        //# `reff` is the tag of an `OrphanBuilder`, which lives outside of the message. Nothing
+        //# in the message points at the new object, so it can go anywhere, and the orphan
+        //# records where it went.
+        let (segment_id, word_idx) = arena.allocate_anywhere(amount)?;
+        let (seg_start, _seg_len) = arena.get_segment_mut(segment_id);
+        let ptr = seg_start.add(word_idx as usize * BYTES_PER_WORD);
+        (*reff).set_kind_with_zero_offset(kind);
+        let orphan = reff as *mut OrphanBuilder;
+        (*orphan).segment_id = segment_id;
+        (*orphan).location = ptr;
+        Ok((ptr, reff, segment_id))
+    }
+
+    unsafe fn allocate_target(
+        arena: &mut dyn BuilderArena,
+        reff: *mut WirePointer,
+        segment_id: u32,
+        amount: WordCount32,
+        kind: WirePointerKind,
+    ) -> Result<(*mut u8, *mut WirePointer, u32)> {
+        match arena.allocate(segment_id, amount)? {
             None => {
                 //# Need to allocate in a different segment. We'll need to
                 //# allocate an extra pointer worth of space to act as
                 //# the landing pad for a far pointer.
 
                 let amount_plus_ref = amount + POINTER_SIZE_IN_WORDS as u32;
-                let (segment_id, word_idx) = arena.allocate_anywhere(amount_plus_ref);
+                let (segment_id, word_idx) = arena.allocate_anywhere(amount_plus_ref)?;
                 let (seg_start, _seg_len) = arena.get_segment_mut(segment_id);
                 let ptr = seg_start.offset(word_idx as isize * BYTES_PER_WORD as isize);
 
@@ -448,13 +488,13 @@ mod wire_helpers {
 
                 let ptr1 = ptr.add(BYTES_PER_WORD);
                 (*reff).set_kind_and_target(kind, ptr1);
-                (ptr1, reff, segment_id)
+                Ok((ptr1, reff, segment_id))
             }
             Some(idx) => {
                 let (seg_start, _seg_len) = arena.get_segment_mut(segment_id);
                 let ptr = (seg_start).offset(idx as isize * BYTES_PER_WORD as isize);
                 (*reff).set_kind_and_target(kind, ptr);
-                (ptr, reff, segment_id)
+                Ok((ptr, reff, segment_id))
             }
         }
     }
@@ -556,6 +596,10 @@ mod wire_helpers {
         //# reachable.
 
         match (*reff).kind() {
+            WirePointerKind::Other if (*reff).is_capability() => {
+                // A capability pointer only holds an index into the cap table, so there
+                // is no object in the message to zero.
+            }
             WirePointerKind::Struct | WirePointerKind::List | WirePointerKind::Other => {
                 zero_object_helper(arena, segment_id, reff, WirePointer::mut_target(reff))
             }
@@ -834,6 +878,327 @@ mod wire_helpers {
         Ok(result)
     }
 
+    /// A struct or list whose pointers are still being visited by `validate_deep()`.
+    #[cfg(feature = "alloc")]
+    struct ValidationFrame {
+        segment_id: u32,
+        // The first pointer of a struct or pointer list, or the first word after the tag
+        // of a struct list.
+        start: *const u8,
+        layout: FrameLayout,
+        // The number of pointers in the object, and the index of the next one to visit.
+        count: u64,
+        next: u64,
+        // The depth of the objects that the pointers point to.
+        child_depth: u32,
+    }
+
+    #[cfg(feature = "alloc")]
+    #[derive(Clone, Copy)]
+    enum FrameLayout {
+        Struct,
+        PointerList,
+        StructList { data_words: u16, pointers: u16 },
+    }
+
+    #[cfg(feature = "alloc")]
+    impl ValidationFrame {
+        unsafe fn pointer(&self, index: u64) -> *const WirePointer {
+            let word = match self.layout {
+                FrameLayout::Struct | FrameLayout::PointerList => index,
+                FrameLayout::StructList {
+                    data_words,
+                    pointers,
+                } => {
+                    let pointers = u64::from(pointers);
+                    (index / pointers) * (u64::from(data_words) + pointers)
+                        + u64::from(data_words)
+                        + index % pointers
+                }
+            };
+            self.start.add(word as usize * BYTES_PER_WORD) as *const WirePointer
+        }
+
+        fn write_step(&self, index: u64, path: &mut String) {
+            use core::fmt::Write;
+            let _ = match self.layout {
+                FrameLayout::Struct => write!(path, ".ptr{index}"),
+                FrameLayout::PointerList => write!(path, "[{index}]"),
+                FrameLayout::StructList { pointers, .. } => {
+                    let pointers = u64::from(pointers);
+                    write!(path, "[{}].ptr{}", index / pointers, index % pointers)
+                }
+            };
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    struct Validation<'a> {
+        arena: &'a dyn ReaderArena,
+        nesting_limit: i32,
+        traversal_limit_in_words: Option<u64>,
+        report: ValidationReport,
+    }
+
+    #[cfg(feature = "alloc")]
+    impl Validation<'_> {
+        // Unlike `bounds_check()`, this does not count against the arena's read limit.
+        fn check_interval(&self, segment_id: u32, start: *const u8, words: u64) -> Result<()> {
+            let (segment_start, segment_len) = self.arena.get_segment(segment_id)?;
+            let start = start as usize;
+            let segment_start = segment_start as usize;
+            if start < segment_start
+                || ((start - segment_start) / BYTES_PER_WORD) as u64 + words
+                    > u64::from(segment_len)
+            {
+                return Err(Error::from_kind(
+                    ErrorKind::MessageContainsOutOfBoundsPointer,
+                ));
+            }
+            Ok(())
+        }
+
+        fn word_at(&self, segment_id: u32, position: u32, words: u64) -> Result<*const u8> {
+            let (segment_start, segment_len) = self.arena.get_segment(segment_id)?;
+            if u64::from(position) + words > u64::from(segment_len) {
+                return Err(Error::from_kind(
+                    ErrorKind::MessageContainsOutOfBoundsPointer,
+                ));
+            }
+            Ok(unsafe { segment_start.add(position as usize * BYTES_PER_WORD) })
+        }
+
+        fn traverse(&mut self, words: u64) -> Result<()> {
+            self.report.words_traversed = self.report.words_traversed.saturating_add(words);
+            match self.traversal_limit_in_words {
+                Some(limit) if self.report.words_traversed > limit => {
+                    Err(Error::from_kind(ErrorKind::ReadLimitExceeded))
+                }
+                _ => Ok(()),
+            }
+        }
+
+        // Like `follow_fars()`, but also rejects landing pads that the accessors would
+        // refuse to read through.
+        unsafe fn follow_fars(
+            &mut self,
+            reff: *const WirePointer,
+            segment_id: u32,
+        ) -> Result<(*const u8, *const WirePointer, u32)> {
+            if (*reff).kind() != WirePointerKind::Far {
+                let ptr = WirePointer::target_from_segment(reff, self.arena, segment_id)?;
+                return Ok((ptr, reff, segment_id));
+            }
+
+            let pad_words = if (*reff).is_double_far() { 2 } else { 1 };
+            let far_segment_id = (*reff).far_segment_id();
+            let pad = self.word_at(far_segment_id, (*reff).far_position_in_segment(), pad_words)?
+                as *const WirePointer;
+            self.traverse(pad_words)?;
+
+            if !(*reff).is_double_far() {
+                if (*pad).kind() == WirePointerKind::Far {
+                    return Err(Error::from_kind(ErrorKind::UnexepectedFarPointer));
+                }
+                let ptr = WirePointer::target_from_segment(pad, self.arena, far_segment_id)?;
+                Ok((ptr, pad, far_segment_id))
+            } else {
+                let tag = pad.offset(1);
+                if (*pad).kind() != WirePointerKind::Far
+                    || (*pad).is_double_far()
+                    || (*tag).kind() == WirePointerKind::Far
+                {
+                    return Err(Error::from_kind(ErrorKind::MalformedDoubleFarPointer));
+                }
+                let target_segment_id = (*pad).far_segment_id();
+                let ptr = self.word_at(target_segment_id, (*pad).far_position_in_segment(), 0)?;
+                Ok((ptr, tag, target_segment_id))
+            }
+        }
+
+        /// Checks the object that `reff` points to. Returns a frame for its pointers, if it
+        /// has any.
+        unsafe fn visit(
+            &mut self,
+            segment_id: u32,
+            reff: *const WirePointer,
+            depth: u32,
+        ) -> Result<Option<ValidationFrame>> {
+            if (*reff).is_null() {
+                return Ok(None);
+            }
+            if i64::from(depth) >= i64::from(self.nesting_limit) {
+                return Err(Error::from_kind(ErrorKind::MessageIsTooDeeplyNested));
+            }
+            self.report.max_depth = self.report.max_depth.max(depth + 1);
+
+            let (ptr, reff, segment_id) = self.follow_fars(reff, segment_id)?;
+
+            match (*reff).kind() {
+                WirePointerKind::Struct => {
+                    let words = u64::from((*reff).struct_word_size());
+                    self.check_interval(segment_id, ptr, words)?;
+                    self.traverse(words)?;
+
+                    let count = (*reff).struct_ptr_count();
+                    if count == 0 {
+                        return Ok(None);
+                    }
+                    Ok(Some(ValidationFrame {
+                        segment_id,
+                        start: ptr.add((*reff).struct_data_size() as usize * BYTES_PER_WORD),
+                        layout: FrameLayout::Struct,
+                        count: u64::from(count),
+                        next: 0,
+                        child_depth: depth + 1,
+                    }))
+                }
+                WirePointerKind::List => {
+                    let element_count = (*reff).list_element_count();
+                    match (*reff).list_element_size() {
+                        Void => {
+                            // Lists of void can claim to be arbitrarily large without having
+                            // sent actual data.
+                            self.traverse(u64::from(element_count))?;
+                            Ok(None)
+                        }
+                        Bit | Byte | TwoBytes | FourBytes | EightBytes => {
+                            let words = u64::from(round_bits_up_to_words(
+                                u64::from(element_count)
+                                    * u64::from(data_bits_per_element((*reff).list_element_size())),
+                            ));
+                            self.check_interval(segment_id, ptr, words)?;
+                            self.traverse(words)?;
+                            Ok(None)
+                        }
+                        Pointer => {
+                            let words = u64::from(element_count) * WORDS_PER_POINTER as u64;
+                            self.check_interval(segment_id, ptr, words)?;
+                            self.traverse(words)?;
+                            if element_count == 0 {
+                                return Ok(None);
+                            }
+                            Ok(Some(ValidationFrame {
+                                segment_id,
+                                start: ptr,
+                                layout: FrameLayout::PointerList,
+                                count: u64::from(element_count),
+                                next: 0,
+                                child_depth: depth + 1,
+                            }))
+                        }
+                        InlineComposite => {
+                            let word_count = u64::from((*reff).list_inline_composite_word_count());
+                            self.check_interval(
+                                segment_id,
+                                ptr,
+                                word_count + POINTER_SIZE_IN_WORDS as u64,
+                            )?;
+
+                            let tag: *const WirePointer = ptr as *const _;
+                            if (*tag).kind() != WirePointerKind::Struct {
+                                return Err(Error::from_kind(
+                                    ErrorKind::CantHandleNonStructInlineComposite,
+                                ));
+                            }
+                            let count = u64::from((*tag).inline_composite_list_element_count());
+                            let words_per_element = u64::from((*tag).struct_word_size());
+                            if count * words_per_element > word_count {
+                                return Err(Error::from_kind(
+                                    ErrorKind::InlineCompositeListsElementsOverrunItsWordCount,
+                                ));
+                            }
+                            self.traverse(word_count + POINTER_SIZE_IN_WORDS as u64)?;
+                            if words_per_element == 0 {
+                                // Lists of zero-sized structs can claim to be arbitrarily
+                                // large without having sent actual data.
+                                self.traverse(count)?;
+                            }
+
+                            let pointers = (*tag).struct_ptr_count();
+                            if count == 0 || pointers == 0 {
+                                return Ok(None);
+                            }
+                            Ok(Some(ValidationFrame {
+                                segment_id,
+                                start: ptr.add(BYTES_PER_WORD),
+                                layout: FrameLayout::StructList {
+                                    data_words: (*tag).struct_data_size(),
+                                    pointers,
+                                },
+                                count: count * u64::from(pointers),
+                                next: 0,
+                                // Struct list elements take up a level of nesting of their
+                                // own, just like they do for `ListReader::get_struct_element()`.
+                                child_depth: depth + 2,
+                            }))
+                        }
+                    }
+                }
+                WirePointerKind::Far => Err(Error::from_kind(ErrorKind::MalformedDoubleFarPointer)),
+                WirePointerKind::Other => {
+                    if (*reff).is_capability() {
+                        self.report.cap_count += 1;
+                        Ok(None)
+                    } else {
+                        Err(Error::from_kind(ErrorKind::UnknownPointerType))
+                    }
+                }
+            }
+        }
+    }
+
+    /// Checks every object reachable from `reff`, depth first and without recursion. The first
+    /// error found gets the path of the pointer that led to it appended to its `extra` field.
+    #[cfg(feature = "alloc")]
+    pub unsafe fn validate_deep(
+        arena: &dyn ReaderArena,
+        segment_id: u32,
+        reff: *const WirePointer,
+        nesting_limit: i32,
+        traversal_limit_in_words: Option<u64>,
+    ) -> Result<ValidationReport> {
+        let mut validation = Validation {
+            arena,
+            nesting_limit,
+            traversal_limit_in_words,
+            report: ValidationReport::default(),
+        };
+        let mut stack: Vec<ValidationFrame> = Vec::new();
+        let mut pending = Some((segment_id, reff, 0));
+        loop {
+            if let Some((segment_id, reff, depth)) = pending.take() {
+                match validation.visit(segment_id, reff, depth) {
+                    Ok(Some(frame)) => stack.push(frame),
+                    Ok(None) => (),
+                    Err(mut e) => {
+                        let mut path = String::from("root");
+                        for frame in &stack {
+                            frame.write_step(frame.next - 1, &mut path);
+                        }
+                        if !e.extra.is_empty() {
+                            e.extra.push(' ');
+                        }
+                        e.extra.push_str("at ");
+                        e.extra.push_str(&path);
+                        return Err(e);
+                    }
+                }
+            }
+            let Some(frame) = stack.last_mut() else {
+                break;
+            };
+            if frame.next == frame.count {
+                stack.pop();
+                continue;
+            }
+            let index = frame.next;
+            frame.next += 1;
+            pending = Some((frame.segment_id, frame.pointer(index), frame.child_depth));
+        }
+        Ok(validation.report)
+    }
+
     // Helper for copy_message().
     unsafe fn copy_struct(
         arena: &mut dyn BuilderArena,
@@ -843,7 +1208,7 @@ mod wire_helpers {
         src: *const u8,
         data_size: isize,
         pointer_count: isize,
-    ) {
+    ) -> Result<()> {
         ptr::copy_nonoverlapping(src, dst, data_size as usize * BYTES_PER_WORD);
 
         let src_refs: *const WirePointer = (src as *const WirePointer).offset(data_size);
@@ -856,8 +1221,9 @@ mod wire_helpers {
                 cap_table,
                 dst_refs.offset(ii),
                 src_refs.offset(ii),
-            );
+            )?;
         }
+        Ok(())
     }
 
     // Copies from a trusted message.
@@ -868,12 +1234,12 @@ mod wire_helpers {
         cap_table: CapTableBuilder,
         dst: *mut WirePointer,
         src: *const WirePointer,
-    ) -> (*mut u8, *mut WirePointer, u32) {
+    ) -> Result<(*mut u8, *mut WirePointer, u32)> {
         match (*src).kind() {
             WirePointerKind::Struct => {
                 if (*src).is_null() {
                     ptr::write_bytes(dst, 0, 1);
-                    (ptr::null_mut(), dst, segment_id)
+                    Ok((ptr::null_mut(), dst, segment_id))
                 } else {
                     let src_ptr = WirePointer::target(src);
                     let (dst_ptr, dst, segment_id) = allocate(
@@ -882,7 +1248,7 @@ mod wire_helpers {
                         segment_id,
                         (*src).struct_word_size(),
                         WirePointerKind::Struct,
-                    );
+                    )?;
                     copy_struct(
                         arena,
                         segment_id,
@@ -891,12 +1257,12 @@ mod wire_helpers {
                         src_ptr,
                         (*src).struct_data_size() as isize,
                         (*src).struct_ptr_count() as isize,
-                    );
+                    )?;
                     (*dst).set_struct_size_from_pieces(
                         (*src).struct_data_size(),
                         (*src).struct_ptr_count(),
                     );
-                    (dst_ptr, dst, segment_id)
+                    Ok((dst_ptr, dst, segment_id))
                 }
             }
             WirePointerKind::List => match (*src).list_element_size() {
@@ -912,7 +1278,7 @@ mod wire_helpers {
                     );
                     let src_ptr = WirePointer::target(src);
                     let (dst_ptr, dst, segment_id) =
-                        allocate(arena, dst, segment_id, word_count, WirePointerKind::List);
+                        allocate(arena, dst, segment_id, word_count, WirePointerKind::List)?;
                     ptr::copy_nonoverlapping(
                         src_ptr,
                         dst_ptr,
@@ -922,7 +1288,7 @@ mod wire_helpers {
                         (*src).list_element_size(),
                         (*src).list_element_count(),
                     );
-                    (dst_ptr, dst, segment_id)
+                    Ok((dst_ptr, dst, segment_id))
                 }
 
                 ElementSize::Pointer => {
@@ -933,7 +1299,7 @@ mod wire_helpers {
                         segment_id,
                         (*src).list_element_count(),
                         WirePointerKind::List,
-                    );
+                    )?;
                     for ii in 0..((*src).list_element_count() as isize) {
                         copy_message(
                             arena,
@@ -941,11 +1307,11 @@ mod wire_helpers {
                             cap_table,
                             dst_refs.offset(ii * BYTES_PER_WORD as isize) as *mut WirePointer,
                             src_refs.offset(ii),
-                        );
+                        )?;
                     }
                     (*dst)
                         .set_list_size_and_count(ElementSize::Pointer, (*src).list_element_count());
-                    (dst_refs, dst, segment_id)
+                    Ok((dst_refs, dst, segment_id))
                 }
                 ElementSize::InlineComposite => {
                     let src_ptr = WirePointer::target(src);
@@ -955,7 +1321,7 @@ mod wire_helpers {
                         segment_id,
                         (*src).list_inline_composite_word_count() + 1,
                         WirePointerKind::List,
-                    );
+                    )?;
 
                     (*dst).set_list_inline_composite((*src).list_inline_composite_word_count());
 
@@ -977,7 +1343,7 @@ mod wire_helpers {
                             src_element,
                             (*src_tag).struct_data_size() as isize,
                             (*src_tag).struct_ptr_count() as isize,
-                        );
+                        )?;
                         src_element = src_element.offset(
                             BYTES_PER_WORD as isize * (*src_tag).struct_word_size() as isize,
                         );
@@ -985,7 +1351,7 @@ mod wire_helpers {
                             BYTES_PER_WORD as isize * (*src_tag).struct_word_size() as isize,
                         );
                     }
-                    (dst_ptr, dst, segment_id)
+                    Ok((dst_ptr, dst, segment_id))
                 }
             },
             WirePointerKind::Other => {
@@ -1003,7 +1369,7 @@ mod wire_helpers {
         dst: *mut WirePointer,
         src_segment_id: u32,
         src: *mut WirePointer,
-    ) {
+    ) -> Result<()> {
         //# Make *dst point to the same object as *src. Both must
         //# reside in the same message, but can be in different
         //# segments. Not always-inline because this is rarely used.
@@ -1027,10 +1393,11 @@ mod wire_helpers {
                 src_segment_id,
                 src,
                 WirePointer::mut_target(src),
-            );
+            )?;
         } else {
             ptr::copy_nonoverlapping(src, dst, 1);
         }
+        Ok(())
     }
 
     pub unsafe fn transfer_pointer_split(
@@ -1040,7 +1407,7 @@ mod wire_helpers {
         src_segment_id: u32,
         src_tag: *mut WirePointer,
         src_ptr: *mut u8,
-    ) {
+    ) -> Result<()> {
         // Like the other transfer_pointer, but splits src into a tag and a
         // target. Particularly useful for OrphanBuilder.
 
@@ -1058,10 +1425,10 @@ mod wire_helpers {
             // Need to create a far pointer. Try to allocate it in the same segment as the source,
             // so that it doesn't need to be a double-far.
 
-            match arena.allocate(src_segment_id, 1) {
+            match arena.allocate(src_segment_id, 1)? {
                 None => {
                     //# Darn, need a double-far.
-                    let (far_segment_id, word_idx) = arena.allocate_anywhere(2);
+                    let (far_segment_id, word_idx) = arena.allocate_anywhere(2)?;
                     let (seg_start, _seg_len) = arena.get_segment_mut(far_segment_id);
                     let landing_pad: *mut WirePointer =
                         (seg_start as *mut WirePointer).offset(word_idx as isize);
@@ -1104,6 +1471,78 @@ mod wire_helpers {
                 }
             }
         }
+        Ok(())
+    }
+
+    pub unsafe fn orphan_from_pointer(
+        arena: &mut dyn BuilderArena,
+        segment_id: u32,
+        reff: *mut WirePointer,
+    ) -> OrphanBuilder {
+        //# Captures the object that `reff` points to as an orphan. Does not modify `reff`.
+        let message_id = arena.message_id();
+        if (*reff).is_null() {
+            return OrphanBuilder {
+                tag: ptr::read(reff),
+                segment_id,
+                location: ptr::null_mut(),
+                message_id,
+            };
+        }
+        if (*reff).kind() == WirePointerKind::Other {
+            // Capability pointers are position-independent, so only the tag matters. Use a
+            // dummy location so that the orphan is non-null.
+            return OrphanBuilder {
+                tag: ptr::read(reff),
+                segment_id,
+                location: ptr::NonNull::dangling().as_ptr(),
+                message_id,
+            };
+        }
+        let Ok((location, tag, segment_id)) =
+            follow_builder_fars(arena, reff, WirePointer::mut_target(reff), segment_id)
+        else {
+            unreachable!("following far pointers in a builder does not fail")
+        };
+        let mut tag = ptr::read(tag);
+        tag.set_kind_with_zero_offset(tag.kind());
+        OrphanBuilder {
+            tag,
+            segment_id,
+            location,
+            message_id,
+        }
+    }
+
+    pub unsafe fn adopt(
+        arena: &mut dyn BuilderArena,
+        segment_id: u32,
+        reff: *mut WirePointer,
+        mut orphan: OrphanBuilder,
+    ) -> Result<()> {
+        //# The caller must check that `orphan` belongs to this arena.
+        if !(*reff).is_null() {
+            zero_object(arena, segment_id, reff);
+        }
+        ptr::write_bytes(reff, 0, 1);
+
+        if orphan.location.is_null() {
+            return Ok(());
+        }
+        if orphan.tag.is_positional() {
+            transfer_pointer_split(
+                arena,
+                segment_id,
+                reff,
+                orphan.segment_id,
+                &mut orphan.tag,
+                orphan.location,
+            )?;
+        } else {
+            // FAR and OTHER pointers are position-independent, so we can just copy.
+            ptr::copy_nonoverlapping(&orphan.tag, reff, 1);
+        }
+        Ok(())
     }
 
     #[inline]
@@ -1113,17 +1552,17 @@ mod wire_helpers {
         segment_id: u32,
         cap_table: CapTableBuilder,
         size: StructSize,
-    ) -> StructBuilder<'_> {
+    ) -> Result<StructBuilder<'_>> {
         let (ptr, reff, segment_id) = allocate(
             arena,
             reff,
             segment_id,
             size.total(),
             WirePointerKind::Struct,
-        );
+        )?;
         (*reff).set_struct_size(size);
 
-        StructBuilder {
+        Ok(StructBuilder {
             arena,
             segment_id,
             cap_table,
@@ -1131,7 +1570,7 @@ mod wire_helpers {
             pointers: ptr.offset((size.data as usize) as isize * BYTES_PER_WORD as isize) as *mut _,
             data_size: u32::from(size.data) * (BITS_PER_WORD as BitCount32),
             pointer_count: size.pointers,
-        }
+        })
     }
 
     #[inline]
@@ -1147,15 +1586,9 @@ mod wire_helpers {
 
         if (*reff).is_null() {
             match default {
-                None => {
-                    return Ok(init_struct_pointer(
-                        arena, reff, segment_id, cap_table, size,
-                    ))
-                }
+                None => return init_struct_pointer(arena, reff, segment_id, cap_table, size),
                 Some(d) if (*(d.as_ptr() as *const WirePointer)).is_null() => {
-                    return Ok(init_struct_pointer(
-                        arena, reff, segment_id, cap_table, size,
-                    ))
+                    return init_struct_pointer(arena, reff, segment_id, cap_table, size)
                 }
                 Some(d) => {
                     let (new_ref_target, new_reff, new_segment_id) = copy_message(
@@ -1164,7 +1597,7 @@ mod wire_helpers {
                         cap_table,
                         reff,
                         d.as_ptr() as *const WirePointer,
-                    );
+                    )?;
                     reff = new_reff;
                     segment_id = new_segment_id;
                     ref_target = new_ref_target;
@@ -1197,11 +1630,11 @@ mod wire_helpers {
             let total_size =
                 u32::from(new_data_size) + u32::from(new_pointer_count) * WORDS_PER_POINTER as u32;
 
-            //# Don't let allocate() zero out the object just yet.
+            //# Don't let allocate()? zero out the object just yet.
             zero_pointer_and_fars(arena, segment_id, reff)?;
 
             let (ptr, reff, segment_id) =
-                allocate(arena, reff, segment_id, total_size, WirePointerKind::Struct);
+                allocate(arena, reff, segment_id, total_size, WirePointerKind::Struct)?;
             (*reff).set_struct_size_from_pieces(new_data_size, new_pointer_count);
 
             // Copy data section.
@@ -1218,7 +1651,7 @@ mod wire_helpers {
                     new_pointer_section.offset(i),
                     old_segment_id,
                     old_pointer_section.offset(i),
-                );
+                )?;
             }
 
             ptr::write_bytes(
@@ -1257,7 +1690,7 @@ mod wire_helpers {
         cap_table: CapTableBuilder,
         element_count: ElementCount32,
         element_size: ElementSize,
-    ) -> ListBuilder<'_> {
+    ) -> Result<ListBuilder<'_>> {
         assert!(
             element_size != InlineComposite,
             "Should have called initStructListPointer() instead"
@@ -1268,11 +1701,11 @@ mod wire_helpers {
         let step = data_size + pointer_count * BITS_PER_POINTER as u32;
         let word_count = round_bits_up_to_words(u64::from(element_count) * u64::from(step));
         let (ptr, reff, segment_id) =
-            allocate(arena, reff, segment_id, word_count, WirePointerKind::List);
+            allocate(arena, reff, segment_id, word_count, WirePointerKind::List)?;
 
         (*reff).set_list_size_and_count(element_size, element_count);
 
-        ListBuilder {
+        Ok(ListBuilder {
             arena,
             segment_id,
             cap_table,
@@ -1282,7 +1715,7 @@ mod wire_helpers {
             element_size,
             struct_data_size: data_size,
             struct_pointer_count: pointer_count as u16,
-        }
+        })
     }
 
     #[inline]
@@ -1293,7 +1726,7 @@ mod wire_helpers {
         cap_table: CapTableBuilder,
         element_count: ElementCount32,
         element_size: StructSize,
-    ) -> ListBuilder<'_> {
+    ) -> Result<ListBuilder<'_>> {
         let words_per_element = element_size.total();
 
         //# Allocate the list, prefixed by a single WirePointer.
@@ -1304,7 +1737,7 @@ mod wire_helpers {
             segment_id,
             POINTER_SIZE_IN_WORDS as u32 + word_count,
             WirePointerKind::List,
-        );
+        )?;
         let ptr = ptr as *mut WirePointer;
 
         //# Initialize the pointer.
@@ -1317,7 +1750,7 @@ mod wire_helpers {
 
         let ptr1 = ptr.add(POINTER_SIZE_IN_WORDS);
 
-        ListBuilder {
+        Ok(ListBuilder {
             arena,
             segment_id,
             cap_table,
@@ -1327,7 +1760,7 @@ mod wire_helpers {
             element_size: ElementSize::InlineComposite,
             struct_data_size: u32::from(element_size.data) * (BITS_PER_WORD as u32),
             struct_pointer_count: element_size.pointers,
-        }
+        })
     }
 
     #[inline]
@@ -1356,7 +1789,7 @@ mod wire_helpers {
                 cap_table,
                 orig_ref,
                 default_value as *const WirePointer,
-            );
+            )?;
             orig_ref_target = new_orig_ref_target;
             orig_ref = new_orig_ref;
             orig_segment_id = new_orig_segment_id;
@@ -1486,7 +1919,7 @@ mod wire_helpers {
                 cap_table,
                 orig_ref,
                 default_value as *const WirePointer,
-            );
+            )?;
             orig_ref_target = new_orig_ref_target;
             orig_ref = new_orig_ref;
             orig_segment_id = new_orig_segment_id;
@@ -1544,7 +1977,7 @@ mod wire_helpers {
                 u32::from(new_data_size) + u32::from(new_pointer_count) * WORDS_PER_POINTER as u32;
             let total_size = new_step * element_count;
 
-            // Don't let allocate() zero out the object just yet.
+            // Don't let allocate()? zero out the object just yet.
             zero_pointer_and_fars(arena, orig_segment_id, orig_ref)?;
 
             let (mut new_ptr, new_ref, new_segment_id) = allocate(
@@ -1553,7 +1986,7 @@ mod wire_helpers {
                 orig_segment_id,
                 total_size + POINTER_SIZE_IN_WORDS as u32,
                 WirePointerKind::List,
-            );
+            )?;
             (*new_ref).set_list_inline_composite(total_size);
 
             let new_tag: *mut WirePointer = new_ptr as *mut _;
@@ -1580,7 +2013,7 @@ mod wire_helpers {
                         new_pointer_section.offset(jj),
                         old_segment_id,
                         old_pointer_section.offset(jj),
-                    );
+                    )?;
                 }
 
                 dst = dst.offset(new_step as isize);
@@ -1614,14 +2047,14 @@ mod wire_helpers {
 
             if old_size == ElementSize::Void {
                 // Nothing to copy, just allocate a new list.
-                Ok(init_struct_list_pointer(
+                init_struct_list_pointer(
                     arena,
                     orig_ref,
                     orig_segment_id,
                     cap_table,
                     element_count,
                     element_size,
-                ))
+                )
             } else {
                 // Upgrade to an inline composite list.
 
@@ -1645,7 +2078,7 @@ mod wire_helpers {
                     + u32::from(new_pointer_count) * WORDS_PER_POINTER as u32;
                 let total_words = element_count * new_step;
 
-                // Don't let allocate() zero out the object just yet.
+                // Don't let allocate()? zero out the object just yet.
                 zero_pointer_and_fars(arena, orig_segment_id, orig_ref)?;
 
                 let (mut new_ptr, new_ref, new_segment_id) = allocate(
@@ -1654,7 +2087,7 @@ mod wire_helpers {
                     orig_segment_id,
                     total_words + POINTER_SIZE_IN_WORDS as u32,
                     WirePointerKind::List,
-                );
+                )?;
                 (*new_ref).set_list_inline_composite(total_words);
 
                 let tag: *mut WirePointer = new_ptr as *mut _;
@@ -1669,7 +2102,13 @@ mod wire_helpers {
                     let mut dst = new_ptr.offset(new_data_size as isize * BYTES_PER_WORD as isize);
                     let mut src: *mut WirePointer = old_ptr as *mut _;
                     for _ in 0..element_count {
-                        transfer_pointer(arena, new_segment_id, dst as *mut _, old_segment_id, src);
+                        transfer_pointer(
+                            arena,
+                            new_segment_id,
+                            dst as *mut _,
+                            old_segment_id,
+                            src,
+                        )?;
                         dst = dst.offset(new_step as isize * BYTES_PER_WORD as isize);
                         src = src.offset(1);
                     }
@@ -1712,10 +2151,13 @@ mod wire_helpers {
         reff: *mut WirePointer,
         segment_id: u32,
         size: ByteCount32,
-    ) -> SegmentAnd<text::Builder<'_>> {
+    ) -> Result<SegmentAnd<text::Builder<'_>>> {
         //# The byte list must include a NUL terminator.
         let byte_size = size + 1;
 
+        // The tag of a new orphan does not outlive the allocation, so the builder can't track it.
+        let tracks_tag = segment_id != ORPHAN_SEGMENT_ID;
+
         //# Allocate the space.
         let (ptr, reff, segment_id) = allocate(
             arena,
@@ -1723,15 +2165,20 @@ mod wire_helpers {
             segment_id,
             round_bytes_up_to_words(byte_size),
             WirePointerKind::List,
-        );
+        )?;
 
         //# Initialize the pointer.
         (*reff).set_list_size_and_count(Byte, byte_size);
 
-        SegmentAnd {
+        let bytes = slice::from_raw_parts_mut(ptr, size as usize);
+        Ok(SegmentAnd {
             segment_id,
-            value: text::Builder::new(slice::from_raw_parts_mut(ptr, size as usize)),
-        }
+            value: if tracks_tag {
+                text::Builder::with_tag(bytes, 0, &mut *reff)
+            } else {
+                text::Builder::with_pos(bytes, 0)
+            },
+        })
     }
 
     #[inline]
@@ -1740,16 +2187,16 @@ mod wire_helpers {
         reff: *mut WirePointer,
         segment_id: u32,
         value: crate::text::Reader<'_>,
-    ) -> SegmentAnd<text::Builder<'a>> {
+    ) -> Result<SegmentAnd<text::Builder<'a>>> {
         let value_bytes = value.as_bytes();
         // TODO make sure the string is not longer than 2 ** 29.
-        let mut allocation = init_text_pointer(arena, reff, segment_id, value_bytes.len() as u32);
+        let mut allocation = init_text_pointer(arena, reff, segment_id, value_bytes.len() as u32)?;
         allocation
             .value
             .reborrow()
             .as_bytes_mut()
             .copy_from_slice(value_bytes);
-        allocation
+        Ok(allocation)
     }
 
     #[inline]
@@ -1769,7 +2216,7 @@ mod wire_helpers {
                         Default::default(),
                         reff,
                         d.as_ptr() as *const _,
-                    );
+                    )?;
                     reff = new_reff;
                     segment_id = new_segment_id;
                     new_ref_target
@@ -1796,9 +2243,10 @@ mod wire_helpers {
         }
 
         // Subtract 1 from the size for the NUL terminator.
-        Ok(text::Builder::with_pos(
+        Ok(text::Builder::with_tag(
             slice::from_raw_parts_mut(ptr, (count - 1) as usize),
             (count - 1) as usize,
+            &mut *reff,
         ))
     }
 
@@ -1808,7 +2256,7 @@ mod wire_helpers {
         reff: *mut WirePointer,
         segment_id: u32,
         size: ByteCount32,
-    ) -> SegmentAnd<data::Builder<'_>> {
+    ) -> Result<SegmentAnd<data::Builder<'_>>> {
         //# Allocate the space.
         let (ptr, reff, segment_id) = allocate(
             arena,
@@ -1816,15 +2264,15 @@ mod wire_helpers {
             segment_id,
             round_bytes_up_to_words(size),
             WirePointerKind::List,
-        );
+        )?;
 
         //# Initialize the pointer.
         (*reff).set_list_size_and_count(Byte, size);
 
-        SegmentAnd {
+        Ok(SegmentAnd {
             segment_id,
             value: data::builder_from_raw_parts(ptr, size),
-        }
+        })
     }
 
     #[inline]
@@ -1833,10 +2281,10 @@ mod wire_helpers {
         reff: *mut WirePointer,
         segment_id: u32,
         value: &[u8],
-    ) -> SegmentAnd<data::Builder<'a>> {
-        let allocation = init_data_pointer(arena, reff, segment_id, value.len() as u32);
+    ) -> Result<SegmentAnd<data::Builder<'a>>> {
+        let allocation = init_data_pointer(arena, reff, segment_id, value.len() as u32)?;
         ptr::copy_nonoverlapping(value.as_ptr(), allocation.value.as_mut_ptr(), value.len());
-        allocation
+        Ok(allocation)
     }
 
     #[inline]
@@ -1856,7 +2304,7 @@ mod wire_helpers {
                         Default::default(),
                         reff,
                         d.as_ptr() as *const _,
-                    );
+                    )?;
                     reff = new_reff;
                     segment_id = new_segment_id;
                     new_ref_target
@@ -1932,7 +2380,7 @@ mod wire_helpers {
         let total_size: WordCount32 = data_words + u32::from(ptr_count) * WORDS_PER_POINTER as u32;
 
         let (ptr, reff, segment_id) =
-            allocate(arena, reff, segment_id, total_size, WirePointerKind::Struct);
+            allocate(arena, reff, segment_id, total_size, WirePointerKind::Struct)?;
         (*reff).set_struct_size_from_pieces(data_words as u16, ptr_count);
 
         if value.data_size == 1 {
@@ -1995,7 +2443,7 @@ mod wire_helpers {
         if value.element_size != ElementSize::InlineComposite {
             //# List of non-structs.
             let (ptr, reff, segment_id) =
-                allocate(arena, reff, segment_id, total_size, WirePointerKind::List);
+                allocate(arena, reff, segment_id, total_size, WirePointerKind::List)?;
 
             if value.struct_pointer_count == 1 {
                 //# List of pointers.
@@ -2102,7 +2550,7 @@ mod wire_helpers {
                 segment_id,
                 total_size + POINTER_SIZE_IN_WORDS as u32,
                 WirePointerKind::List,
-            );
+            )?;
             (*reff).set_list_inline_composite(total_size);
 
             let tag: *mut WirePointer = ptr as *mut _;
@@ -2936,6 +3384,26 @@ impl<'a> PointerReader<'a> {
         }
     }
 
+    #[cfg(feature = "alloc")]
+    pub fn validate_deep(
+        &self,
+        options: &crate::message::ValidationOptions,
+    ) -> Result<crate::message::ValidationReport> {
+        if self.pointer.is_null() {
+            Ok(Default::default())
+        } else {
+            unsafe {
+                wire_helpers::validate_deep(
+                    self.arena,
+                    self.segment_id,
+                    self.pointer,
+                    options.nesting_limit,
+                    options.traversal_limit_in_words.map(|limit| limit as u64),
+                )
+            }
+        }
+    }
+
     pub fn get_struct(self, default: Option<&'a [crate::Word]>) -> Result<StructReader<'a>> {
         let reff: *const WirePointer = if self.pointer.is_null() {
             zero_pointer()
@@ -3097,6 +3565,15 @@ pub struct PointerBuilder<'a> {
     pointer: *mut WirePointer,
 }
 
+/// Unwraps the result of an allocation made on behalf of an infallible builder method. Such
+/// allocations can only fail once the message's allocation budget has been exhausted.
+fn expect_allocation<T>(result: Result<T>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(e) => panic!("{e}"),
+    }
+}
+
 impl<'a> PointerBuilder<'a> {
     #[inline]
     pub fn get_root(arena: &'a mut dyn BuilderArena, segment_id: u32, location: *mut u8) -> Self {
@@ -3220,6 +3697,12 @@ impl<'a> PointerBuilder<'a> {
     }
 
     pub fn init_struct(self, size: StructSize) -> StructBuilder<'a> {
+        expect_allocation(self.try_init_struct(size))
+    }
+
+    /// Like `init_struct()`, but returns an error instead of panicking if the message's
+    /// allocation budget is exhausted.
+    pub fn try_init_struct(self, size: StructSize) -> Result<StructBuilder<'a>> {
         unsafe {
             wire_helpers::init_struct_pointer(
                 self.arena,
@@ -3236,6 +3719,16 @@ impl<'a> PointerBuilder<'a> {
         element_size: ElementSize,
         element_count: ElementCount32,
     ) -> ListBuilder<'a> {
+        expect_allocation(self.try_init_list(element_size, element_count))
+    }
+
+    /// Like `init_list()`, but returns an error instead of panicking if the message's
+    /// allocation budget is exhausted.
+    pub fn try_init_list(
+        self,
+        element_size: ElementSize,
+        element_count: ElementCount32,
+    ) -> Result<ListBuilder<'a>> {
         unsafe {
             wire_helpers::init_list_pointer(
                 self.arena,
@@ -3253,6 +3746,16 @@ impl<'a> PointerBuilder<'a> {
         element_count: ElementCount32,
         element_size: StructSize,
     ) -> ListBuilder<'a> {
+        expect_allocation(self.try_init_struct_list(element_count, element_size))
+    }
+
+    /// Like `init_struct_list()`, but returns an error instead of panicking if the message's
+    /// allocation budget is exhausted.
+    pub fn try_init_struct_list(
+        self,
+        element_count: ElementCount32,
+        element_size: StructSize,
+    ) -> Result<ListBuilder<'a>> {
         unsafe {
             wire_helpers::init_struct_list_pointer(
                 self.arena,
@@ -3266,14 +3769,32 @@ impl<'a> PointerBuilder<'a> {
     }
 
     pub fn init_text(self, size: ByteCount32) -> text::Builder<'a> {
+        expect_allocation(self.try_init_text(size))
+    }
+
+    /// Like `init_text()`, but returns an error instead of panicking if the message's
+    /// allocation budget is exhausted.
+    pub fn try_init_text(self, size: ByteCount32) -> Result<text::Builder<'a>> {
         unsafe {
-            wire_helpers::init_text_pointer(self.arena, self.pointer, self.segment_id, size).value
+            Ok(
+                wire_helpers::init_text_pointer(self.arena, self.pointer, self.segment_id, size)?
+                    .value,
+            )
         }
     }
 
     pub fn init_data(self, size: ByteCount32) -> data::Builder<'a> {
+        expect_allocation(self.try_init_data(size))
+    }
+
+    /// Like `init_data()`, but returns an error instead of panicking if the message's
+    /// allocation budget is exhausted.
+    pub fn try_init_data(self, size: ByteCount32) -> Result<data::Builder<'a>> {
         unsafe {
-            wire_helpers::init_data_pointer(self.arena, self.pointer, self.segment_id, size).value
+            Ok(
+                wire_helpers::init_data_pointer(self.arena, self.pointer, self.segment_id, size)?
+                    .value,
+            )
         }
     }
 
@@ -3306,15 +3827,29 @@ impl<'a> PointerBuilder<'a> {
     }
 
     pub fn set_text(&mut self, value: crate::text::Reader<'_>) {
+        expect_allocation(self.try_set_text(value))
+    }
+
+    /// Like `set_text()`, but returns an error instead of panicking if the message's
+    /// allocation budget is exhausted.
+    pub fn try_set_text(&mut self, value: crate::text::Reader<'_>) -> Result<()> {
         unsafe {
-            wire_helpers::set_text_pointer(self.arena, self.pointer, self.segment_id, value);
+            wire_helpers::set_text_pointer(self.arena, self.pointer, self.segment_id, value)?;
         }
+        Ok(())
     }
 
     pub fn set_data(&mut self, value: &[u8]) {
+        expect_allocation(self.try_set_data(value))
+    }
+
+    /// Like `set_data()`, but returns an error instead of panicking if the message's
+    /// allocation budget is exhausted.
+    pub fn try_set_data(&mut self, value: &[u8]) -> Result<()> {
         unsafe {
-            wire_helpers::set_data_pointer(self.arena, self.pointer, self.segment_id, value);
+            wire_helpers::set_data_pointer(self.arena, self.pointer, self.segment_id, value)?;
         }
+        Ok(())
     }
 
     #[cfg(feature = "alloc")]
@@ -3366,6 +3901,32 @@ impl<'a> PointerBuilder<'a> {
         }
     }
 
+    /// Detaches the pointed-to object from the message tree, leaving this pointer null.
+    pub fn disown(self) -> OrphanBuilder {
+        unsafe {
+            let orphan =
+                wire_helpers::orphan_from_pointer(self.arena, self.segment_id, self.pointer);
+            ptr::write_bytes(self.pointer, 0, 1);
+            orphan
+        }
+    }
+
+    /// Like `disown()`, but leaves this pointer in place. The result aliases the pointed-to
+    /// object, so this pointer must be unreachable from the message root.
+    pub(crate) fn orphan_of_detached(self) -> OrphanBuilder {
+        unsafe { wire_helpers::orphan_from_pointer(self.arena, self.segment_id, self.pointer) }
+    }
+
+    /// Points this pointer at `orphan`, discarding whatever it pointed to before.
+    pub fn adopt(self, orphan: OrphanBuilder) -> Result<()> {
+        if !orphan.is_null() && orphan.message_id != self.arena.message_id() {
+            return Err(Error::from_kind(
+                ErrorKind::AdoptedObjectMustLiveInTheSameMessage,
+            ));
+        }
+        unsafe { wire_helpers::adopt(self.arena, self.segment_id, self.pointer, orphan) }
+    }
+
     pub fn as_reader(&self) -> PointerReader<'_> {
         PointerReader {
             arena: self.arena.as_reader(),
@@ -3387,6 +3948,63 @@ impl<'a> PointerBuilder<'a> {
     }
 }
 
+/// The segment id of the pointer that `OrphanBuilder::new()` hands out. That pointer is the
+/// orphan's tag rather than a word of the message.
+const ORPHAN_SEGMENT_ID: u32 = u32::MAX;
+
+/// An object that belongs to a message but is not reachable from its root, as produced by
+/// `PointerBuilder::disown()` or `OrphanBuilder::new()`. `PointerBuilder::adopt()` links it
+/// back in.
+///
+/// Dropping an orphan leaves its object unreachable; the space is not reclaimed.
+// `tag` comes first so that `wire_helpers::allocate_orphan()` can find the orphan from it.
+#[repr(C)]
+pub struct OrphanBuilder {
+    tag: WirePointer,
+    segment_id: u32,
+    location: *mut u8,
+    message_id: usize,
+}
+
+impl OrphanBuilder {
+    /// Allocates a new object that is not reachable from the message root. `init` receives a
+    /// pointer to initialize, which places the object anywhere in `arena` rather than at the
+    /// pointer itself. The result of `init` must not keep that pointer.
+    pub(crate) fn new<'a, T>(
+        arena: &'a mut dyn BuilderArena,
+        init: impl FnOnce(PointerBuilder<'a>) -> T,
+    ) -> (Self, T) {
+        let mut orphan = Self {
+            tag: unsafe { mem::zeroed() },
+            segment_id: 0,
+            location: ptr::null_mut(),
+            message_id: arena.message_id(),
+        };
+        let value = init(PointerBuilder {
+            arena,
+            cap_table: Default::default(),
+            segment_id: ORPHAN_SEGMENT_ID,
+            pointer: &mut orphan.tag,
+        });
+        (orphan, value)
+    }
+
+    /// Returns an orphan that holds nothing.
+    pub fn null() -> Self {
+        Self {
+            tag: unsafe { mem::zeroed() },
+            segment_id: 0,
+            location: ptr::null_mut(),
+            message_id: 0,
+        }
+    }
+
+    #[inline]
+    pub fn is_null(&self) -> bool {
+        self.location.is_null()
+    }
+}
+
 #[derive(Clone, Copy)]
 pub struct StructReader<'a> {
     arena: &'a dyn ReaderArena,
@@ -3425,6 +4043,11 @@ impl<'a> StructReader<'a> {
         self.pointer_count
     }
 
+    /// How many more levels of pointers may be followed below this struct.
+    pub fn get_nesting_limit(&self) -> i32 {
+        self.nesting_limit
+    }
+
     pub fn get_pointer_section_as_list(&self) -> ListReader<'a> {
         ListReader {
             arena: self.arena,
@@ -3909,6 +4532,11 @@ impl<'a> ListReader<'a> {
         self.element_size
     }
 
+    /// How many more levels of pointers may be followed below this list.
+    pub fn get_nesting_limit(&self) -> i32 {
+        self.nesting_limit
+    }
+
     pub(crate) fn into_raw_bytes(self) -> &'a [u8] {
         if self.element_count == 0 {
             // Explictly handle this case to avoid forming a slice to a null pointer,
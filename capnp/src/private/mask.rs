@@ -60,3 +60,39 @@ impl Mask for f64 {
         Self::from_bits(value.to_bits() ^ mask)
     }
 }
+
+/// Bits of the quiet NaN that generated setters write for every NaN `f32` when code is
+/// generated with canonical NaNs.
+pub const CANONICAL_NAN_F32_BITS: u32 = 0x7fc0_0000;
+
+/// Bits of the quiet NaN that generated setters write for every NaN `f64` when code is
+/// generated with canonical NaNs.
+pub const CANONICAL_NAN_F64_BITS: u64 = 0x7ff8_0000_0000_0000;
+
+/// Replaces NaNs by the canonical quiet NaN, so that messages holding equal values encode to
+/// identical words regardless of the NaN payload a computation produced.
+pub trait CanonicalizeNan {
+    fn canonicalize_nan(self) -> Self;
+}
+
+impl CanonicalizeNan for f32 {
+    #[inline]
+    fn canonicalize_nan(self) -> Self {
+        if self.is_nan() {
+            Self::from_bits(CANONICAL_NAN_F32_BITS)
+        } else {
+            self
+        }
+    }
+}
+
+impl CanonicalizeNan for f64 {
+    #[inline]
+    fn canonicalize_nan(self) -> Self {
+        if self.is_nan() {
+            Self::from_bits(CANONICAL_NAN_F64_BITS)
+        } else {
+            self
+        }
+    }
+}
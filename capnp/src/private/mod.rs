@@ -26,7 +26,7 @@
 pub mod arena;
 pub mod capability;
 pub mod layout;
-mod mask;
+pub mod mask;
 mod primitive;
 mod read_limiter;
 pub mod units;
@@ -34,3 +34,13 @@ mod zero;
 
 #[cfg(test)]
 mod layout_test;
+
+/// Converts the length of a Rust collection into a list length, failing with
+/// `ErrorKind::ListTooLong` instead of truncating when a list cannot hold that many elements.
+/// Used by generated code that builds lists out of `Vec`s and slices.
+pub fn checked_len(len: usize) -> crate::Result<u32> {
+    match u32::try_from(len) {
+        Ok(len) if len < 1 << 29 => Ok(len),
+        _ => Err(crate::Error::from_kind(crate::ErrorKind::ListTooLong(len))),
+    }
+}
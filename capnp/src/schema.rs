@@ -12,6 +12,8 @@ use crate::Result;
 
 #[cfg(feature = "std")]
 use std::collections::hash_map::HashMap;
+#[cfg(all(feature = "std", feature = "alloc"))]
+use std::sync::{Arc, Mutex};
 
 #[cfg(all(feature = "std", feature = "alloc"))]
 // Builds introspection information at runtime to allow building a StructSchema
@@ -337,6 +339,193 @@ impl std::ops::Drop for DynamicSchema {
     }
 }
 
+#[cfg(all(feature = "std", feature = "alloc"))]
+#[no_mangle]
+#[inline(never)]
+fn loaded_field_marker(_: u16) -> crate::introspect::Type {
+    panic!("loaded_field_marker should never be called!");
+}
+
+/// Builds struct schemas out of the `_private::ENCODED_NODE` arrays of generated code, which can
+/// come from any number of crates.
+///
+/// The schema that generated code returns from `introspect()` only knows the field types that
+/// the same code generator run saw. A `SchemaLoader` instead resolves field types by ID among all
+/// of the nodes that were added to it, so a struct whose fields refer to types generated in
+/// another crate can be described, as long as the nodes of those types were added too.
+///
+/// Each struct schema is leaked the first time it is resolved, so that it can be handed out with
+/// a `'static` lifetime like a generated one. Type parameters of generic structs resolve to
+/// `AnyPointer`.
+#[cfg(all(feature = "std", feature = "alloc"))]
+#[derive(Default)]
+pub struct SchemaLoader {
+    nodes: Arc<Mutex<LoadedNodes>>,
+}
+
+#[cfg(all(feature = "std", feature = "alloc"))]
+#[derive(Default)]
+struct LoadedNodes {
+    encoded: HashMap<u64, &'static [crate::Word]>,
+    structs: HashMap<u64, &'static LoadedStruct>,
+}
+
+/// The leaked form of a struct resolved by a `SchemaLoader`. A `RawBrandedStructSchema` whose
+/// `field_types` is `loaded_field_marker` always has a `generic` that points to one of these.
+#[cfg(all(feature = "std", feature = "alloc"))]
+#[repr(C)]
+struct LoadedStruct {
+    raw: introspect::RawStructSchema,
+    nodes: Arc<Mutex<LoadedNodes>>,
+}
+
+#[cfg(all(feature = "std", feature = "alloc"))]
+impl SchemaLoader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the node encoded in `encoded_node`, which should be the `_private::ENCODED_NODE`
+    /// of a generated type, and returns its ID. Adding a node that is already present does
+    /// nothing.
+    pub fn add(&self, encoded_node: &'static [crate::Word]) -> Result<u64> {
+        let id = read_node(encoded_node)?.get_id();
+        self.nodes
+            .lock()
+            .unwrap()
+            .encoded
+            .entry(id)
+            .or_insert(encoded_node);
+        Ok(id)
+    }
+
+    /// Returns the schema of the struct with ID `id`. The struct must have been added, and so
+    /// must every struct, enum and interface that its fields refer to, or else looking up the
+    /// type of such a field will panic.
+    pub fn get_struct(&self, id: u64) -> Result<StructSchema> {
+        let mut nodes = self.nodes.lock().unwrap();
+        Ok(load_struct(&self.nodes, &mut nodes, id)?.into())
+    }
+}
+
+#[cfg(all(feature = "std", feature = "alloc"))]
+fn read_node(encoded_node: &'static [crate::Word]) -> Result<node::Reader<'static>> {
+    crate::any_pointer::Reader::new(unsafe {
+        layout::PointerReader::get_root_unchecked(encoded_node.as_ptr() as *const u8)
+    })
+    .get_as()
+}
+
+#[cfg(all(feature = "std", feature = "alloc"))]
+fn get_loaded(nodes: &LoadedNodes, id: u64) -> Result<&'static [crate::Word]> {
+    match nodes.encoded.get(&id) {
+        Some(encoded_node) => Ok(encoded_node),
+        None => Err(crate::Error::failed(format!(
+            "node {id:#x} has not been added to the SchemaLoader"
+        ))),
+    }
+}
+
+#[cfg(all(feature = "std", feature = "alloc"))]
+fn load_struct(
+    shared: &Arc<Mutex<LoadedNodes>>,
+    nodes: &mut LoadedNodes,
+    id: u64,
+) -> Result<RawBrandedStructSchema> {
+    let loaded = match nodes.structs.get(&id) {
+        Some(loaded) => *loaded,
+        None => {
+            let encoded_node = get_loaded(nodes, id)?;
+            let node::Struct(st) = read_node(encoded_node)?.which()? else {
+                return Err(crate::Error::failed(format!(
+                    "node {id:#x} is not a struct"
+                )));
+            };
+            let (nonunion_members, members_by_discriminant) = DynamicSchema::get_indexes(st);
+            let loaded: &'static LoadedStruct = Box::leak(Box::new(LoadedStruct {
+                raw: introspect::RawStructSchema {
+                    encoded_node,
+                    nonunion_members,
+                    members_by_discriminant,
+                },
+                nodes: shared.clone(),
+            }));
+            nodes.structs.insert(id, loaded);
+            loaded
+        }
+    };
+    Ok(RawBrandedStructSchema {
+        generic: &loaded.raw,
+        field_types: loaded_field_marker,
+        annotation_types: dynamic_annotation_marker,
+    })
+}
+
+#[cfg(all(feature = "std", feature = "alloc"))]
+fn load_type(
+    shared: &Arc<Mutex<LoadedNodes>>,
+    nodes: &mut LoadedNodes,
+    ty: crate::schema_capnp::type_::Reader,
+) -> Result<introspect::Type> {
+    use crate::schema_capnp::type_;
+    Ok(match ty.which()? {
+        type_::Void(()) => TypeVariant::Void,
+        type_::Bool(()) => TypeVariant::Bool,
+        type_::Int8(()) => TypeVariant::Int8,
+        type_::Int16(()) => TypeVariant::Int16,
+        type_::Int32(()) => TypeVariant::Int32,
+        type_::Int64(()) => TypeVariant::Int64,
+        type_::Uint8(()) => TypeVariant::UInt8,
+        type_::Uint16(()) => TypeVariant::UInt16,
+        type_::Uint32(()) => TypeVariant::UInt32,
+        type_::Uint64(()) => TypeVariant::UInt64,
+        type_::Float32(()) => TypeVariant::Float32,
+        type_::Float64(()) => TypeVariant::Float64,
+        type_::Text(()) => TypeVariant::Text,
+        type_::Data(()) => TypeVariant::Data,
+        type_::List(list) => TypeVariant::List(load_type(shared, nodes, list.get_element_type()?)?),
+        type_::Enum(e) => TypeVariant::Enum(RawEnumSchema {
+            encoded_node: get_loaded(nodes, e.get_type_id())?,
+            annotation_types: dynamic_annotation_marker,
+        }),
+        type_::Struct(s) => TypeVariant::Struct(load_struct(shared, nodes, s.get_type_id())?),
+        type_::Interface(i) => TypeVariant::Capability(RawCapabilitySchema {
+            encoded_node: get_loaded(nodes, i.get_type_id())?,
+        }),
+        type_::AnyPointer(_) => TypeVariant::AnyPointer,
+    }
+    .into())
+}
+
+#[cfg(all(feature = "std", feature = "alloc"))]
+fn loaded_field_type(parent: StructSchema, index: u16) -> introspect::Type {
+    // SAFETY: only `load_struct()` pairs `loaded_field_marker` with a `generic`, and it always
+    // points to the `raw` field at the start of a leaked `LoadedStruct`.
+    let loaded = unsafe {
+        &*(parent.raw.generic as *const introspect::RawStructSchema as *const LoadedStruct)
+    };
+    let mut nodes = loaded.nodes.lock().unwrap();
+    let field = parent.get_fields().unwrap().get(index).get_proto();
+    let ty = match field.which().unwrap() {
+        field::Slot(slot) => load_type(&loaded.nodes, &mut nodes, slot.get_type().unwrap()),
+        field::Group(group) => load_struct(&loaded.nodes, &mut nodes, group.get_type_id())
+            .map(|group| TypeVariant::Struct(group).into()),
+    };
+    match ty {
+        Ok(ty) => ty,
+        Err(e) => panic!(
+            "cannot resolve the type of field `{}` of `{}`: {e}",
+            field.get_name().unwrap().to_str().unwrap(),
+            parent
+                .get_proto()
+                .get_display_name()
+                .unwrap()
+                .to_str()
+                .unwrap()
+        ),
+    }
+}
+
 /// A struct node, with generics applied.
 #[derive(Clone, Copy)]
 pub struct StructSchema {
@@ -458,6 +647,12 @@ impl Field {
     }
 
     pub fn get_type(&self) -> introspect::Type {
+        #[cfg(all(feature = "std", feature = "alloc"))]
+        #[allow(clippy::fn_address_comparisons)]
+        if self.parent.raw.field_types == loaded_field_marker {
+            return loaded_field_type(self.parent, self.index);
+        }
+
         #[allow(clippy::fn_address_comparisons)]
         if self.parent.raw.field_types == dynamic_field_marker {
             let mut found: Option<crate::schema_capnp::type_::Reader> = None;
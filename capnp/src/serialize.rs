@@ -31,6 +31,15 @@ use core::convert::TryInto;
 #[cfg(feature = "alloc")]
 use core::ops::Deref;
 
+#[cfg(all(feature = "std", feature = "alloc"))]
+mod auto;
+#[cfg(feature = "std")]
+mod crc32c;
+#[cfg(all(feature = "std", feature = "alloc"))]
+pub use auto::{
+    detect_format, read_message_auto, read_message_with_format, write_message_auto, Format,
+    DETECT_FORMAT_PREFIX_LEN,
+};
 pub(crate) mod no_alloc_buffer_segments;
 pub use no_alloc_buffer_segments::{
     NoAllocBufferSegments, NoAllocSegmentTableInfo, NoAllocSliceSegments,
@@ -167,25 +176,39 @@ impl<T: Deref<Target = [u8]>> message::ReaderSegments for BufferSegments<T> {
 /// The segments are guaranteed to be 8-byte aligned.
 #[cfg(feature = "alloc")]
 pub struct OwnedSegments {
-    // Each pair represents a segment inside of `owned_space`.
+    // Each pair represents a segment inside of `owned_space`, after `segments_start`.
     // (starting index (in words), ending index (in words))
     segment_indices: Vec<(usize, usize)>,
 
     owned_space: Vec<crate::Word>,
+
+    // Number of words before the first segment, i.e. the length of the segment table
+    // if `owned_space` holds a complete serialized message.
+    segments_start: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl OwnedSegments {
+    /// Returns the buffer holding the segments. If these segments came from
+    /// `read_message_from_owned()`, this is the buffer that was passed to it, segment table
+    /// included.
+    pub fn into_words(self) -> Vec<crate::Word> {
+        self.owned_space
+    }
 }
 
 #[cfg(feature = "alloc")]
 impl core::ops::Deref for OwnedSegments {
     type Target = [u8];
     fn deref(&self) -> &[u8] {
-        crate::Word::words_to_bytes(&self.owned_space[..])
+        crate::Word::words_to_bytes(&self.owned_space[self.segments_start..])
     }
 }
 
 #[cfg(feature = "alloc")]
 impl core::ops::DerefMut for OwnedSegments {
     fn deref_mut(&mut self) -> &mut [u8] {
-        crate::Word::words_to_bytes_mut(&mut self.owned_space[..])
+        crate::Word::words_to_bytes_mut(&mut self.owned_space[self.segments_start..])
     }
 }
 
@@ -253,6 +276,18 @@ impl SegmentLengthsBuilder {
         OwnedSegments {
             segment_indices: self.segment_indices,
             owned_space,
+            segments_start: 0,
+        }
+    }
+
+    /// Constructs an `OwnedSegments` whose segments are laid out back to back at the start
+    /// of `owned_space`.
+    pub(crate) fn into_owned_segments_from(self, owned_space: Vec<crate::Word>) -> OwnedSegments {
+        assert!(self.total_words <= owned_space.len());
+        OwnedSegments {
+            segment_indices: self.segment_indices,
+            owned_space,
+            segments_start: 0,
         }
     }
 
@@ -283,6 +318,38 @@ impl SegmentLengthsBuilder {
     }
 }
 
+/// Reads a serialized message (including a segment table) from `words`, taking ownership of
+/// the buffer instead of copying the segments out of it. `words` is allowed to extend beyond
+/// the end of the message. Use `message::Reader::into_owned_words()` to get the buffer back,
+/// for example to forward the message unchanged after inspecting it.
+#[cfg(feature = "alloc")]
+pub fn read_message_from_owned(
+    words: Vec<crate::Word>,
+    options: message::ReaderOptions,
+) -> Result<message::Reader<OwnedSegments>> {
+    let mut bytes = crate::Word::words_to_bytes(&words);
+    let Some(segment_lengths_builder) = read_segment_table(&mut bytes, options)? else {
+        return Err(Error::from_kind(ErrorKind::EmptyBuffer));
+    };
+    let available_words = bytes.len() / BYTES_PER_WORD;
+    let segments_start = words.len() - available_words;
+    let num_words = segment_lengths_builder.total_words();
+    if num_words > available_words {
+        return Err(Error::from_kind(ErrorKind::MessageEndsPrematurely(
+            num_words,
+            available_words,
+        )));
+    }
+    Ok(message::Reader::new(
+        OwnedSegments {
+            segment_indices: segment_lengths_builder.to_segment_indices(),
+            owned_space: words,
+            segments_start,
+        },
+        options,
+    ))
+}
+
 /// Reads a serialized message from a stream with the provided options.
 ///
 /// For optimal performance, `read` should be a buffered reader type.
@@ -394,6 +461,13 @@ where
         num_segment_counts_read += 1;
     }
 
+    if let Some(limit) = options.total_message_size_limit_in_words {
+        if total_body_words > limit {
+            return Err(Error::from_kind(ErrorKind::MessageSizeLimitExceeded(
+                total_body_words,
+            )));
+        }
+    }
     if let Some(limit) = options.traversal_limit_in_words {
         if total_body_words > limit {
             return Err(Error::from_kind(ErrorKind::MessageTooLarge(
@@ -463,6 +537,27 @@ where
         }
     }
 
+    let (segment_count, mut segment_lengths_builder) = parse_segment_table_first(&buf)?;
+    if segment_count > 1 {
+        if segment_count < 4 {
+            // small enough that we can reuse our existing buffer
+            read.read_exact(&mut buf)?;
+            parse_segment_table_rest(&mut segment_lengths_builder, &buf, segment_count)?;
+        } else {
+            let mut segment_sizes = vec![0u8; (segment_count & !1) * 4];
+            read.read_exact(&mut segment_sizes[..])?;
+            parse_segment_table_rest(&mut segment_lengths_builder, &segment_sizes, segment_count)?;
+        }
+    }
+    check_message_size(&segment_lengths_builder, options)?;
+
+    Ok(Some(segment_lengths_builder))
+}
+
+/// Parses the first word of a segment table. Returns the segment count, and a builder holding
+/// the length of the first segment.
+#[cfg(feature = "alloc")]
+fn parse_segment_table_first(buf: &[u8; 8]) -> Result<(usize, SegmentLengthsBuilder)> {
     let segment_count = u32::from_le_bytes(buf[0..4].try_into().unwrap()).wrapping_add(1) as usize;
 
     if segment_count >= SEGMENTS_COUNT_LIMIT || segment_count == 0 {
@@ -474,29 +569,41 @@ where
     let mut segment_lengths_builder = SegmentLengthsBuilder::with_capacity(segment_count);
     segment_lengths_builder
         .try_push_segment(u32::from_le_bytes(buf[4..8].try_into().unwrap()) as usize)?;
-    if segment_count > 1 {
-        if segment_count < 4 {
-            read.read_exact(&mut buf)?;
-            for idx in 0..(segment_count - 1) {
-                let segment_len =
-                    u32::from_le_bytes(buf[(idx * 4)..(idx + 1) * 4].try_into().unwrap()) as usize;
-                segment_lengths_builder.try_push_segment(segment_len)?;
-            }
-        } else {
-            let mut segment_sizes = vec![0u8; (segment_count & !1) * 4];
-            read.read_exact(&mut segment_sizes[..])?;
-            for idx in 0..(segment_count - 1) {
-                let segment_len =
-                    u32::from_le_bytes(segment_sizes[(idx * 4)..(idx + 1) * 4].try_into().unwrap())
-                        as usize;
-                segment_lengths_builder.try_push_segment(segment_len)?;
-            }
-        }
+    Ok((segment_count, segment_lengths_builder))
+}
+
+/// Parses the rest of a segment table with `segment_count` segments, i.e. the
+/// `(segment_count & !1) * 4` bytes that follow its first word.
+#[cfg(feature = "alloc")]
+fn parse_segment_table_rest(
+    segment_lengths_builder: &mut SegmentLengthsBuilder,
+    buf: &[u8],
+    segment_count: usize,
+) -> Result<()> {
+    for idx in 0..(segment_count - 1) {
+        let segment_len =
+            u32::from_le_bytes(buf[(idx * 4)..(idx + 1) * 4].try_into().unwrap()) as usize;
+        segment_lengths_builder.try_push_segment(segment_len)?;
     }
+    Ok(())
+}
 
-    // Don't accept a message which the receiver couldn't possibly traverse without hitting the
-    // traversal limit. Without this check, a malicious client could transmit a very large segment
-    // size to make the receiver allocate excessive space and possibly crash.
+/// Don't accept a message larger than the size limit, or one which the receiver couldn't
+/// possibly traverse without hitting the traversal limit. Without this check, a malicious client
+/// could transmit a very large segment size to make the receiver allocate excessive space and
+/// possibly crash.
+#[cfg(feature = "alloc")]
+fn check_message_size(
+    segment_lengths_builder: &SegmentLengthsBuilder,
+    options: message::ReaderOptions,
+) -> Result<()> {
+    if let Some(limit) = options.total_message_size_limit_in_words {
+        if segment_lengths_builder.total_words() > limit {
+            return Err(Error::from_kind(ErrorKind::MessageSizeLimitExceeded(
+                segment_lengths_builder.total_words(),
+            )));
+        }
+    }
     if let Some(limit) = options.traversal_limit_in_words {
         if segment_lengths_builder.total_words() > limit {
             return Err(Error::from_kind(ErrorKind::MessageTooLarge(
@@ -504,8 +611,7 @@ where
             )));
         }
     }
-
-    Ok(Some(segment_lengths_builder))
+    Ok(())
 }
 
 #[cfg(feature = "alloc")]
@@ -587,6 +693,128 @@ where
     write_segments(&mut write, segments)
 }
 
+/// Like `write_message()`, but hands the segment table and the segments to `write` together,
+/// with `write_vectored()`, so that a multi-segment message can go out in a single system call
+/// without first being copied into one buffer. Returns the number of bytes written.
+///
+/// `segments` is typically the result of `message::Builder::get_segments_for_output()`.
+/// Partial writes are resumed where they stopped. If `write` accepts no bytes at all from a
+/// vectored write, the rest of the message is written with `write_all()`, one buffer at a
+/// time. `flush()` will not be called on the writer.
+#[cfg(feature = "std")]
+pub fn write_message_vectored<W>(mut write: W, segments: &[&[u8]]) -> Result<usize>
+where
+    W: std::io::Write,
+{
+    let mut table = SegmentTable::new(segments);
+    let mut parts = VectoredParts::new(table.fill(segments), segments);
+    let total = parts.remaining_bytes();
+    let mut slices = Vec::with_capacity(parts.parts.len());
+    while !parts.is_empty() {
+        slices.clear();
+        slices.extend(parts.io_slices());
+        match write.write_vectored(&slices) {
+            Ok(0) => {
+                for part in parts.remaining() {
+                    std::io::Write::write_all(&mut write, part)?;
+                }
+                break;
+            }
+            Ok(n) => parts.advance(n),
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(total)
+}
+
+/// Like `write_message()`, but follows the message with a 4-byte little-endian CRC-32C
+/// computed over the segment table and all segment bytes.
+///
+/// Use [`read_message_with_crc()`] to read the message back. The plain [`read_message()`]
+/// will decode the message itself, but leaves the trailing checksum in the stream, so any
+/// subsequent message read from the same stream will fail to parse.
+#[cfg(feature = "std")]
+pub fn write_message_with_crc<W, A>(write: W, message: &message::Builder<A>) -> Result<()>
+where
+    W: std::io::Write,
+    A: message::Allocator,
+{
+    let mut write = ChecksumWriter {
+        inner: write,
+        crc: crc32c::Crc32c::new(),
+    };
+    write_message(&mut write, message)?;
+    let crc = write.crc.finish();
+    std::io::Write::write_all(&mut write.inner, &crc.to_le_bytes())?;
+    Ok(())
+}
+
+/// Reads a message written by [`write_message_with_crc()`], verifying its checksum.
+///
+/// Returns an error of kind `ChecksumMismatch` if the message was read in full but its
+/// checksum does not match, and `PrematureEndOfFile` if the stream ends before the checksum.
+/// Corruption of the segment table itself may instead surface as a framing error such as
+/// `InvalidNumberOfSegments` or `MessageTooLarge`.
+#[cfg(all(feature = "std", feature = "alloc"))]
+pub fn read_message_with_crc<R>(
+    read: R,
+    options: message::ReaderOptions,
+) -> Result<message::Reader<OwnedSegments>>
+where
+    R: std::io::Read,
+{
+    let mut read = ChecksumReader {
+        inner: read,
+        crc: crc32c::Crc32c::new(),
+    };
+    let message = read_message(&mut read, options)?;
+    let computed = read.crc.finish();
+    let mut buf = [0; 4];
+    std::io::Read::read_exact(&mut read.inner, &mut buf)?;
+    let expected = u32::from_le_bytes(buf);
+    if expected != computed {
+        return Err(Error::from_kind(ErrorKind::ChecksumMismatch(
+            expected, computed,
+        )));
+    }
+    Ok(message)
+}
+
+#[cfg(feature = "std")]
+struct ChecksumWriter<W> {
+    inner: W,
+    crc: crc32c::Crc32c,
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> std::io::Write for ChecksumWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.crc.update(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(all(feature = "std", feature = "alloc"))]
+struct ChecksumReader<R> {
+    inner: R,
+    crc: crc32c::Crc32c,
+}
+
+#[cfg(all(feature = "std", feature = "alloc"))]
+impl<R: std::io::Read> std::io::Read for ChecksumReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.crc.update(&buf[..n]);
+        Ok(n)
+    }
+}
+
 fn write_segment_table<W>(write: &mut W, segments: &[&[u8]]) -> Result<()>
 where
     W: Write,
@@ -656,6 +884,102 @@ where
     Ok(())
 }
 
+// Number of bytes of segment table that `SegmentTable` holds without allocating, which
+// covers messages with up to 31 segments.
+#[cfg(feature = "std")]
+const SEGMENT_TABLE_STACK_BYTES: usize = 128;
+
+/// The encoded segment table of a message, on the stack unless the message has many segments.
+#[cfg(feature = "std")]
+struct SegmentTable {
+    stack: [u8; SEGMENT_TABLE_STACK_BYTES],
+    heap: Vec<u8>,
+    len: usize,
+}
+
+#[cfg(feature = "std")]
+impl SegmentTable {
+    fn new(segments: &[&[u8]]) -> Self {
+        let len = segment_table_words(segments.len()) * BYTES_PER_WORD;
+        Self {
+            stack: [0; SEGMENT_TABLE_STACK_BYTES],
+            heap: if len > SEGMENT_TABLE_STACK_BYTES {
+                vec![0; len]
+            } else {
+                Vec::new()
+            },
+            len,
+        }
+    }
+
+    /// Encodes the table for `segments`, which must contain at least one segment.
+    fn fill(&mut self, segments: &[&[u8]]) -> &[u8] {
+        let table = if self.heap.is_empty() {
+            &mut self.stack[..self.len]
+        } else {
+            &mut self.heap[..]
+        };
+        table[0..4].copy_from_slice(&(segments.len() as u32 - 1).to_le_bytes());
+        for (idx, segment) in segments.iter().enumerate() {
+            table[(idx + 1) * 4..(idx + 2) * 4]
+                .copy_from_slice(&((segment.len() / BYTES_PER_WORD) as u32).to_le_bytes());
+        }
+        table
+    }
+}
+
+/// The buffers of a vectored write that have not been written yet.
+#[cfg(feature = "std")]
+struct VectoredParts<'a> {
+    parts: Vec<&'a [u8]>,
+    first: usize,
+}
+
+#[cfg(feature = "std")]
+impl<'a> VectoredParts<'a> {
+    fn new(table: &'a [u8], segments: &[&'a [u8]]) -> Self {
+        let mut parts = Vec::with_capacity(segments.len() + 1);
+        parts.push(table);
+        parts.extend(
+            segments
+                .iter()
+                .copied()
+                .filter(|segment| !segment.is_empty()),
+        );
+        Self { parts, first: 0 }
+    }
+
+    fn remaining(&self) -> &[&'a [u8]] {
+        &self.parts[self.first..]
+    }
+
+    fn remaining_bytes(&self) -> usize {
+        self.remaining().iter().map(|part| part.len()).sum()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.first == self.parts.len()
+    }
+
+    fn io_slices(&self) -> impl Iterator<Item = std::io::IoSlice<'a>> + '_ {
+        self.remaining()
+            .iter()
+            .map(|&part| std::io::IoSlice::new(part))
+    }
+
+    /// Drops the first `n` bytes, which the writer has accepted.
+    fn advance(&mut self, mut n: usize) {
+        while self.first < self.parts.len() && n >= self.parts[self.first].len() {
+            n -= self.parts[self.first].len();
+            self.first += 1;
+        }
+        if n > 0 {
+            let part = &mut self.parts[self.first];
+            *part = &part[n..];
+        }
+    }
+}
+
 /// Writes segments to `write`.
 fn write_segments<W, R: message::ReaderSegments + ?Sized>(write: &mut W, segments: &R) -> Result<()>
 where
@@ -671,10 +995,169 @@ where
     Ok(())
 }
 
+/// Asynchronously reads a serialized message from `read` with the provided options.
+///
+/// All intermediate state lives in the returned future, so dropping it part way through
+/// leaves nothing behind that could affect a later call. The bytes already consumed from
+/// `read` are lost, however, so a stream whose read was cancelled is no longer positioned at
+/// a message boundary.
+#[cfg(feature = "async")]
+pub async fn read_message_async<R>(
+    mut read: R,
+    options: message::ReaderOptions,
+) -> Result<message::Reader<OwnedSegments>>
+where
+    R: futures_io::AsyncRead + Unpin,
+{
+    match try_read_message_async(&mut read, options).await? {
+        Some(message) => Ok(message),
+        None => Err(Error::from_kind(ErrorKind::PrematureEndOfFile)),
+    }
+}
+
+/// Like `read_message_async()`, but returns None instead of an error if there are zero bytes
+/// left in `read`.
+#[cfg(feature = "async")]
+pub async fn try_read_message_async<R>(
+    mut read: R,
+    options: message::ReaderOptions,
+) -> Result<Option<message::Reader<OwnedSegments>>>
+where
+    R: futures_io::AsyncRead + Unpin,
+{
+    // read the first Word, which contains segment_count and the 1st segment length
+    let mut buf: [u8; 8] = [0; 8];
+    {
+        let n = read_async(&mut read, &mut buf[..]).await?;
+        if n == 0 {
+            // Clean EOF on message boundary
+            return Ok(None);
+        } else if n < 8 {
+            read_exact_async(&mut read, &mut buf[n..]).await?;
+        }
+    }
+
+    let (segment_count, mut segment_lengths_builder) = parse_segment_table_first(&buf)?;
+    if segment_count > 1 {
+        let mut segment_sizes = vec![0u8; (segment_count & !1) * 4];
+        read_exact_async(&mut read, &mut segment_sizes[..]).await?;
+        parse_segment_table_rest(&mut segment_lengths_builder, &segment_sizes, segment_count)?;
+    }
+    check_message_size(&segment_lengths_builder, options)?;
+
+    let mut owned_segments = segment_lengths_builder.into_owned_segments();
+    read_exact_async(&mut read, &mut owned_segments[..]).await?;
+    Ok(Some(message::Reader::new(owned_segments, options)))
+}
+
+/// Asynchronously writes the provided message to `write`. Does not flush `write`.
+///
+/// As with `read_message_async()`, dropping the returned future part way through leaves no
+/// state behind, but `write` may have received part of the message.
+#[cfg(feature = "async")]
+pub async fn write_message_async<W, A>(mut write: W, message: &message::Builder<A>) -> Result<()>
+where
+    W: futures_io::AsyncWrite + Unpin,
+    A: message::Allocator,
+{
+    let segments = message.get_segments_for_output();
+    let mut table = Vec::new();
+    write_segment_table_internal(&mut table, &segments[..])?;
+    write_all_async(&mut write, &table).await?;
+    for segment in segments.iter() {
+        write_all_async(&mut write, segment).await?;
+    }
+    Ok(())
+}
+
+/// Asynchronous version of `write_message_vectored()`. Does not flush `write`.
+#[cfg(feature = "async")]
+pub async fn write_message_vectored_async<W>(mut write: W, segments: &[&[u8]]) -> Result<usize>
+where
+    W: futures_io::AsyncWrite + Unpin,
+{
+    let mut table = SegmentTable::new(segments);
+    let mut parts = VectoredParts::new(table.fill(segments), segments);
+    let total = parts.remaining_bytes();
+    let mut slices = Vec::with_capacity(parts.parts.len());
+    while !parts.is_empty() {
+        slices.clear();
+        slices.extend(parts.io_slices());
+        let result = core::future::poll_fn(|cx| {
+            core::pin::Pin::new(&mut write).poll_write_vectored(cx, &slices)
+        })
+        .await;
+        match result {
+            Ok(0) => {
+                for part in parts.remaining() {
+                    write_all_async(&mut write, part).await?;
+                }
+                break;
+            }
+            Ok(n) => parts.advance(n),
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(total)
+}
+
+#[cfg(feature = "async")]
+async fn read_async<R>(read: &mut R, buf: &mut [u8]) -> Result<usize>
+where
+    R: futures_io::AsyncRead + Unpin,
+{
+    loop {
+        let result =
+            core::future::poll_fn(|cx| core::pin::Pin::new(&mut *read).poll_read(cx, buf)).await;
+        match result {
+            Ok(n) => return Ok(n),
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+async fn read_exact_async<R>(read: &mut R, mut buf: &mut [u8]) -> Result<()>
+where
+    R: futures_io::AsyncRead + Unpin,
+{
+    while !buf.is_empty() {
+        match read_async(read, buf).await? {
+            0 => return Err(Error::from_kind(ErrorKind::PrematureEndOfFile)),
+            n => buf = &mut buf[n..],
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+async fn write_all_async<W>(write: &mut W, mut buf: &[u8]) -> Result<()>
+where
+    W: futures_io::AsyncWrite + Unpin,
+{
+    while !buf.is_empty() {
+        let result =
+            core::future::poll_fn(|cx| core::pin::Pin::new(&mut *write).poll_write(cx, buf)).await;
+        match result {
+            Ok(0) => return Err(std::io::Error::from(std::io::ErrorKind::WriteZero).into()),
+            Ok(n) => buf = &buf[n..],
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(())
+}
+
+// Number of words in the segment table of a message with `segment_count` segments.
+fn segment_table_words(segment_count: usize) -> usize {
+    (segment_count / 2) + 1
+}
+
 fn compute_serialized_size<R: message::ReaderSegments + ?Sized>(segments: &R) -> usize {
-    // Table size
     let len = segments.len();
-    let mut size = (len / 2) + 1;
+    let mut size = segment_table_words(len);
     for i in 0..len {
         let segment = segments.get_segment(i as u32).unwrap();
         size += segment.len() / BYTES_PER_WORD;
@@ -694,6 +1177,18 @@ where
     compute_serialized_size(&message.get_segments_for_output())
 }
 
+/// Returns the number of (8-byte) words that [`write_message()`](fn.write_message.html) writes
+/// for a message whose root is a copy of an object of size `size`, such as a struct's
+/// `total_size()`, including the segment table.
+///
+/// This is exact for a message built with `message::Builder::set_root()`, or with
+/// `init_root()` and no overwritten pointers, in a first segment large enough to hold the
+/// whole message. The capabilities counted in `size` are not part of the serialized message.
+pub fn compute_serialized_size_of_root(size: crate::MessageSize) -> usize {
+    // The root pointer comes before the object.
+    segment_table_words(1) + 1 + size.word_count as usize
+}
+
 #[cfg(feature = "alloc")]
 #[cfg(test)]
 pub mod test {
@@ -704,8 +1199,8 @@ pub mod test {
     use quickcheck::{quickcheck, TestResult};
 
     use super::{
-        flatten_segments, read_message, read_message_from_flat_slice, read_segment_table,
-        try_read_message, write_segment_table, write_segments,
+        flatten_segments, read_message, read_message_from_flat_slice, read_message_from_owned,
+        read_segment_table, try_read_message, write_segment_table, write_segments,
     };
     use crate::message;
     use crate::message::ReaderSegments;
@@ -901,6 +1396,50 @@ pub mod test {
         assert!(read_segment_table(&mut &buf[..], message::ReaderOptions::new()).is_err());
     }
 
+    /// A segment table for two segments of 2^32 - 1 words each, i.e. 64 GiB in all.
+    pub const HUGE_SEGMENT_TABLE: [u8; 16] = [
+        1, 0, 0, 0, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0,
+    ];
+
+    #[test]
+    fn test_read_message_size_limit() {
+        let mut options = message::ReaderOptions::new();
+        options.traversal_limit_in_words(None);
+
+        // The error comes from the segment table check, before space for the segments is
+        // allocated or their contents are read.
+        let mut read = &HUGE_SEGMENT_TABLE[..];
+        let Err(e) = read_message(&mut read, options) else {
+            panic!("expected the message to be rejected");
+        };
+        assert_eq!(
+            e.kind,
+            crate::ErrorKind::MessageSizeLimitExceeded(2 * 0xffff_ffff)
+        );
+        assert!(read.is_empty());
+
+        let mut buffer = [0u8; 64];
+        let Err(e) = super::read_message_no_alloc(&HUGE_SEGMENT_TABLE[..], &mut buffer, options)
+        else {
+            panic!("expected the message to be rejected");
+        };
+        assert_eq!(
+            e.kind,
+            crate::ErrorKind::MessageSizeLimitExceeded(2 * 0xffff_ffff)
+        );
+
+        options.total_message_size_limit_in_words(Some(3));
+        let mut buf = vec![];
+        write_message_segments(&mut buf, &[vec![crate::word(0, 0, 0, 0, 0, 0, 0, 0); 4]]);
+        let Err(e) = read_message(&mut &buf[..], options) else {
+            panic!("expected the message to be rejected");
+        };
+        assert_eq!(e.kind, crate::ErrorKind::MessageSizeLimitExceeded(4));
+
+        options.total_message_size_limit_in_words(Some(4));
+        assert!(read_message(&mut &buf[..], options).is_ok());
+    }
+
     #[test]
     fn test_write_segment_table() {
         let mut buf = vec![];
@@ -1081,6 +1620,203 @@ pub mod test {
         }
     }
 
+    #[test]
+    fn read_message_from_owned_does_not_copy() {
+        let segments = vec![
+            vec![1, 0, 0, 0, 0, 0, 0, 0],
+            vec![2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0],
+            vec![4, 0, 0, 0, 0, 0, 0, 0],
+        ];
+        let borrowed_segments: &[&[u8]] = &segments
+            .iter()
+            .map(|segment| &segment[..])
+            .collect::<Vec<_>>()[..];
+        let bytes = flatten_segments(borrowed_segments);
+        let mut words = crate::Word::allocate_zeroed_vec(bytes.len() / 8);
+        crate::Word::words_to_bytes_mut(&mut words).copy_from_slice(&bytes);
+        let buffer = words.as_ptr() as *const u8;
+
+        let message = read_message_from_owned(words, message::ReaderOptions::new()).unwrap();
+        let result_segments = message.into_segments();
+        // Three segments take a two-word segment table.
+        let mut expected = buffer.wrapping_add(16);
+        for (idx, segment) in segments.iter().enumerate() {
+            let result = result_segments.get_segment(idx as u32).unwrap();
+            assert_eq!(result, &segment[..]);
+            assert_eq!(result.as_ptr(), expected);
+            expected = expected.wrapping_add(segment.len());
+        }
+
+        let words = result_segments.into_words();
+        let message = read_message_from_owned(words, message::ReaderOptions::new()).unwrap();
+        let words = message.into_owned_words();
+        assert_eq!(words.as_ptr() as *const u8, buffer);
+        assert_eq!(crate::Word::words_to_bytes(&words), &bytes[..]);
+    }
+
+    #[test]
+    fn read_message_from_owned_too_short() {
+        let e = read_message_from_owned(Vec::new(), message::ReaderOptions::new())
+            .err()
+            .unwrap();
+        assert_eq!(e.kind, crate::ErrorKind::EmptyBuffer);
+
+        let mut buf = vec![];
+        write_message_segments(&mut buf, &[vec![crate::word(1, 0, 0, 0, 0, 0, 0, 0); 3]]);
+        let mut words = crate::Word::allocate_zeroed_vec(buf.len() / 8 - 1);
+        crate::Word::words_to_bytes_mut(&mut words).copy_from_slice(&buf[..buf.len() - 8]);
+        let e = read_message_from_owned(words, message::ReaderOptions::new())
+            .err()
+            .unwrap();
+        assert_eq!(e.kind, crate::ErrorKind::MessageEndsPrematurely(3, 2));
+    }
+
+    #[cfg(all(feature = "std", feature = "alloc"))]
+    #[test]
+    fn crc_round_trip_and_bit_flips() {
+        use super::{read_message_with_crc, write_message_with_crc};
+        use crate::ErrorKind;
+
+        let mut m = message::Builder::new(
+            message::HeapAllocator::new()
+                .first_segment_words(2)
+                .allocation_strategy(message::AllocationStrategy::FixedSize),
+        );
+        {
+            let root: crate::any_pointer::Builder = m.init_root();
+            let mut list: crate::primitive_list::Builder<u64> = root.initn_as(3);
+            for i in 0..3 {
+                list.set(i, u64::from(i) * 0x0101_0101);
+            }
+        }
+        let segment_count = m.get_segments_for_output().len();
+        assert!(segment_count > 1);
+        let table_bytes = (segment_count / 2 + 1) * 8;
+
+        let mut bytes = Vec::new();
+        write_message_with_crc(&mut bytes, &m).unwrap();
+
+        let reader = read_message_with_crc(&bytes[..], message::ReaderOptions::new()).unwrap();
+        let list: crate::primitive_list::Reader<u64> = reader.get_root().unwrap();
+        assert_eq!(list.get(2), 2 * 0x0101_0101);
+
+        // The plain reader decodes the message but leaves the checksum behind.
+        let mut remaining = &bytes[..];
+        read_message(&mut remaining, message::ReaderOptions::new()).unwrap();
+        assert_eq!(remaining.len(), 4);
+        assert!(try_read_message(&mut remaining, message::ReaderOptions::new()).is_err());
+
+        for bit in 0..bytes.len() * 8 {
+            let mut corrupted = bytes.clone();
+            corrupted[bit / 8] ^= 1 << (bit % 8);
+            let Err(e) = read_message_with_crc(&corrupted[..], message::ReaderOptions::new())
+            else {
+                panic!("flipping bit {bit} was not detected");
+            };
+            if bit / 8 >= table_bytes {
+                assert!(
+                    matches!(e.kind, ErrorKind::ChecksumMismatch(..)),
+                    "bit {bit}: {e}"
+                );
+            }
+        }
+
+        let e = read_message_with_crc(&bytes[..bytes.len() - 2], message::ReaderOptions::new())
+            .err()
+            .unwrap();
+        assert_eq!(e.kind, ErrorKind::PrematureEndOfFile);
+    }
+
+    /// Accepts at most `limit` bytes per call, spread over as many buffers as needed, and
+    /// returns zero from `write_vectored()` once `vectored_calls` runs out.
+    #[cfg(feature = "std")]
+    struct FragmentingWriter {
+        data: Vec<u8>,
+        limit: usize,
+        vectored_calls: usize,
+    }
+
+    #[cfg(feature = "std")]
+    impl std::io::Write for FragmentingWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            let n = buf.len().min(self.limit);
+            self.data.extend_from_slice(&buf[..n]);
+            Ok(n)
+        }
+
+        fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
+            if self.vectored_calls == 0 {
+                return Ok(0);
+            }
+            self.vectored_calls -= 1;
+            let mut written = 0;
+            for buf in bufs {
+                let n = buf.len().min(self.limit - written);
+                self.data.extend_from_slice(&buf[..n]);
+                written += n;
+            }
+            Ok(written)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn write_message_vectored() {
+        use super::write_message_vectored;
+
+        // 1, 2, 3 and 40 segments, the last with a segment table that does not fit on the stack.
+        for words in [vec![3], vec![1, 0], vec![2, 5, 1], vec![1; 40]] {
+            let segments = words
+                .iter()
+                .enumerate()
+                .map(|(idx, &len)| vec![crate::word(idx as u8, 1, 2, 3, 4, 5, 6, 7); len])
+                .collect::<Vec<_>>();
+            let borrowed = segments
+                .iter()
+                .map(|segment| crate::Word::words_to_bytes(segment))
+                .collect::<Vec<_>>();
+            let expected = flatten_segments(&borrowed[..]);
+
+            for limit in [1, 3, 8, 13, usize::MAX] {
+                let mut writer = FragmentingWriter {
+                    data: Vec::new(),
+                    limit,
+                    vectored_calls: usize::MAX,
+                };
+                assert_eq!(
+                    write_message_vectored(&mut writer, &borrowed).unwrap(),
+                    expected.len()
+                );
+                assert_eq!(writer.data, expected, "limit {limit}");
+            }
+
+            // Falls back to sequential writes part way through.
+            let mut writer = FragmentingWriter {
+                data: Vec::new(),
+                limit: 5,
+                vectored_calls: 2,
+            };
+            assert_eq!(
+                write_message_vectored(&mut writer, &borrowed).unwrap(),
+                expected.len()
+            );
+            assert_eq!(writer.data, expected);
+        }
+
+        let mut m = message::Builder::new_default();
+        m.initn_root::<crate::text::Builder>(5)
+            .push_str("hello")
+            .unwrap();
+        let mut bytes = Vec::new();
+        let written = write_message_vectored(&mut bytes, &m.get_segments_for_output()).unwrap();
+        assert_eq!(written, bytes.len());
+        assert_eq!(bytes, super::write_message_to_words(&m));
+    }
+
     #[test]
     fn compute_serialized_size() {
         const LIST_LENGTH_IN_WORDS: u32 = 5;
@@ -0,0 +1,166 @@
+//! Reading and writing of messages stored in one of several formats, telling the formats apart
+//! by the first bytes of the input.
+
+use crate::message;
+use crate::serialize::{OwnedSegments, SEGMENTS_COUNT_LIMIT};
+use crate::{serialize, serialize_packed};
+use crate::{Error, ErrorKind, Result};
+
+/// The format of a stored message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// The standard framing of [`serialize::write_message()`].
+    Raw,
+
+    /// The packed encoding of [`serialize_packed::write_message()`].
+    Packed,
+
+    /// The packed encoding, compressed with gzip.
+    #[cfg(feature = "gzip")]
+    GzipPacked,
+}
+
+/// The number of bytes that [`detect_format()`] looks at: the first word of the message, plus
+/// the tag byte in front of it if the message is packed.
+pub const DETECT_FORMAT_PREFIX_LEN: usize = 9;
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Tells the format of a message from `prefix`, its first [`DETECT_FORMAT_PREFIX_LEN`] bytes
+/// (or all of it, if it is shorter).
+///
+/// The first word of a message, packed or not, holds the number of segments minus one and the
+/// length of the first segment. The prefix is taken to be:
+///
+///  * gzip-compressed, if it starts with the gzip magic number `1f 8b`. This never starts a
+///    plausible raw or packed message, since it would declare far too many segments.
+///  * raw, if its first word declares at most [`SEGMENTS_COUNT_LIMIT`] segments and a first
+///    segment that is not empty.
+///  * packed, if it starts with a tag byte, followed by one non-zero byte for each bit set in the
+///    tag (a packer never writes a zero byte there), that unpack into a word which is plausible
+///    in the same way.
+///
+/// A prefix that is plausible both as a raw and as a packed message, such as that of a raw
+/// message with 273 segments, fails with `AmbiguousMessageFormat`; one that is plausible as
+/// neither fails with `UnrecognizedMessageFormat`, as does a gzip-compressed one without the
+/// `gzip` feature. Use [`read_message_with_format()`] to read such messages anyway.
+pub fn detect_format(prefix: &[u8]) -> Result<Format> {
+    if prefix.is_empty() {
+        return Err(Error::from_kind(ErrorKind::PrematureEndOfFile));
+    }
+    if prefix.starts_with(&GZIP_MAGIC) {
+        #[cfg(feature = "gzip")]
+        return Ok(Format::GzipPacked);
+        #[cfg(not(feature = "gzip"))]
+        return Err(Error::from_kind_context(
+            ErrorKind::UnrecognizedMessageFormat,
+            "the message is gzip-compressed, which needs the `gzip` feature".into(),
+        ));
+    }
+    let raw = prefix.get(..8).is_some_and(is_plausible_first_word);
+    let packed = unpack_first_word(prefix).is_some_and(|word| is_plausible_first_word(&word));
+    match (raw, packed) {
+        (true, false) => Ok(Format::Raw),
+        (false, true) => Ok(Format::Packed),
+        (true, true) => Err(Error::from_kind(ErrorKind::AmbiguousMessageFormat)),
+        (false, false) => Err(Error::from_kind(ErrorKind::UnrecognizedMessageFormat)),
+    }
+}
+
+fn is_plausible_first_word(word: &[u8]) -> bool {
+    let segment_count = u32::from_le_bytes(word[0..4].try_into().unwrap()) as usize + 1;
+    let first_segment_len = u32::from_le_bytes(word[4..8].try_into().unwrap());
+    segment_count <= SEGMENTS_COUNT_LIMIT && first_segment_len != 0
+}
+
+// Returns the first word of packed input, or None if `prefix` does not start with a tag byte and
+// as many non-zero bytes as it has bits set.
+fn unpack_first_word(prefix: &[u8]) -> Option<[u8; 8]> {
+    let (&tag, mut rest) = prefix.split_first()?;
+    let mut word = [0; 8];
+    for (i, byte) in word.iter_mut().enumerate() {
+        if tag & (1 << i) != 0 {
+            let (&next, tail) = rest.split_first()?;
+            if next == 0 {
+                return None;
+            }
+            *byte = next;
+            rest = tail;
+        }
+    }
+    Some(word)
+}
+
+/// Reads a message in any of the formats of [`Format`], telling them apart with
+/// [`detect_format()`].
+///
+/// Packed and compressed messages are read through a buffer, which may take bytes past the end
+/// of the message from `read`. This is meant for files that hold a single message.
+pub fn read_message_auto<R>(
+    mut read: R,
+    options: message::ReaderOptions,
+) -> Result<message::Reader<OwnedSegments>>
+where
+    R: std::io::Read,
+{
+    let mut prefix = [0; DETECT_FORMAT_PREFIX_LEN];
+    let mut len = 0;
+    while len < prefix.len() {
+        match std::io::Read::read(&mut read, &mut prefix[len..]) {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    let format = detect_format(&prefix[..len])?;
+    read_message_with_format(
+        std::io::Read::chain(&prefix[..len], read),
+        format,
+        options,
+    )
+}
+
+/// Reads a message in `format`, without looking at what the input holds.
+pub fn read_message_with_format<R>(
+    read: R,
+    format: Format,
+    options: message::ReaderOptions,
+) -> Result<message::Reader<OwnedSegments>>
+where
+    R: std::io::Read,
+{
+    match format {
+        Format::Raw => serialize::read_message(read, options),
+        Format::Packed => serialize_packed::read_message(std::io::BufReader::new(read), options),
+        #[cfg(feature = "gzip")]
+        Format::GzipPacked => serialize_packed::read_message(
+            std::io::BufReader::new(flate2::read::GzDecoder::new(read)),
+            options,
+        ),
+    }
+}
+
+/// Writes a message in `format`, so that [`read_message_auto()`] can read it back.
+pub fn write_message_auto<W, A>(
+    write: W,
+    message: &message::Builder<A>,
+    format: Format,
+) -> Result<()>
+where
+    W: std::io::Write,
+    A: message::Allocator,
+{
+    match format {
+        Format::Raw => serialize::write_message(write, message),
+        Format::Packed => serialize_packed::write_message(write, message),
+        #[cfg(feature = "gzip")]
+        Format::GzipPacked => {
+            let mut encoder =
+                flate2::write::GzEncoder::new(write, flate2::Compression::default());
+            serialize_packed::write_message(&mut encoder, message)?;
+            encoder.finish()?;
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Table-driven CRC-32C (Castagnoli), used to add an integrity check to serialized messages.
+
+/// Reflected form of the Castagnoli polynomial 0x1EDC6F41.
+const POLYNOMIAL: u32 = 0x82f6_3b78;
+
+const TABLE: [u32; 256] = build_table();
+
+const fn build_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+/// Incremental CRC-32C state.
+#[derive(Clone, Copy)]
+pub(crate) struct Crc32c {
+    state: u32,
+}
+
+impl Crc32c {
+    pub(crate) fn new() -> Self {
+        Self { state: !0 }
+    }
+
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        let mut crc = self.state;
+        for &b in bytes {
+            crc = TABLE[((crc ^ u32::from(b)) & 0xff) as usize] ^ (crc >> 8);
+        }
+        self.state = crc;
+    }
+
+    pub(crate) fn finish(self) -> u32 {
+        !self.state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Crc32c;
+
+    fn checksum(bytes: &[u8]) -> u32 {
+        let mut crc = Crc32c::new();
+        crc.update(bytes);
+        crc.finish()
+    }
+
+    #[test]
+    fn known_vectors() {
+        // Check values from RFC 3720, appendix B.4.
+        assert_eq!(checksum(b""), 0);
+        assert_eq!(checksum(b"123456789"), 0xe306_9283);
+        assert_eq!(checksum(&[0u8; 32]), 0x8a91_36aa);
+        assert_eq!(checksum(&[0xffu8; 32]), 0x62a8_ab43);
+    }
+
+    #[test]
+    fn incremental() {
+        let mut crc = Crc32c::new();
+        crc.update(b"1234");
+        crc.update(b"56789");
+        assert_eq!(crc.finish(), checksum(b"123456789"));
+    }
+}
@@ -630,6 +630,22 @@ mod tests {
         helper(&[1, 1]);
     }
 
+    #[test]
+    fn message_size_limit() {
+        use crate::serialize::test::HUGE_SEGMENT_TABLE;
+
+        let mut packed = Vec::new();
+        PackedWrite { inner: &mut packed }
+            .write_all(&HUGE_SEGMENT_TABLE)
+            .unwrap();
+        let mut options = ReaderOptions::new();
+        options.traversal_limit_in_words(None);
+        let Err(e) = read_message(&mut &packed[..], options) else {
+            panic!("expected the message to be rejected");
+        };
+        assert_eq!(e.kind, ErrorKind::MessageSizeLimitExceeded(2 * 0xffff_ffff));
+    }
+
     #[test]
     fn packed_segment_table() {
         let packed_buf = &[0x11, 4, 1, 0, 1, 0, 0];
@@ -23,10 +23,16 @@
 //!
 //! A `text::Reader<'a>` wraps a `&'a [u8]` that is expected but not guaranteed
 //! to contain UTF-8 encoded text.
+//!
+//! Reading a text field only checks the framing of the value, not its encoding, so the
+//! getters generated for text fields succeed on invalid UTF-8. Use
+//! [`Reader::as_bytes()`] to get at the raw bytes, [`Reader::to_string_lossy()`] to replace
+//! invalid sequences, or [`Reader::to_str()`] to reject them.
 
 use core::str;
 
-use crate::Result;
+use crate::private::layout::{ElementSize, WirePointer};
+use crate::{Error, ErrorKind, Result};
 
 #[derive(Copy, Clone)]
 pub struct Owned(());
@@ -155,6 +161,16 @@ impl<'a> Reader<'a> {
         d
     }
 
+    /// The text in byte range `range`, without copying it, or `None` if `range` is out of
+    /// bounds. The range need not fall on character boundaries.
+    #[inline]
+    pub fn subslice<R>(self, range: R) -> Option<Reader<'a>>
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        crate::data::subslice(self.as_bytes(), range).map(Reader)
+    }
+
     /// Converts to a `str`, returning a error if the data contains invalid utf-8.
     #[inline]
     pub fn to_str(self) -> core::result::Result<&'a str, core::str::Utf8Error> {
@@ -168,49 +184,81 @@ impl<'a> Reader<'a> {
     pub fn to_string(self) -> core::result::Result<alloc::string::String, core::str::Utf8Error> {
         Ok(self.to_str()?.into())
     }
+
+    #[cfg(feature = "alloc")]
+    /// Converts to a `str`, replacing invalid utf-8 sequences with `U+FFFD REPLACEMENT
+    /// CHARACTER`. Only allocates if there are any.
+    #[inline]
+    pub fn to_string_lossy(self) -> alloc::borrow::Cow<'a, str> {
+        alloc::string::String::from_utf8_lossy(self.as_bytes())
+    }
 }
 
 pub struct Builder<'a> {
-    /// Does not include the trailing null byte.
+    /// The full allocation, which does not include the trailing null byte.
     bytes: &'a mut [u8],
 
+    /// The string's current length. Bytes past this point are zero.
+    len: usize,
+
     /// Position at which `push_ascii()` and `push_str()` will write to.
     pos: usize,
+
+    /// The list pointer describing `bytes` in its message, if any. Its element count is kept
+    /// at `len + 1` so that the null terminator follows the current contents.
+    tag: Option<&'a mut WirePointer>,
 }
 
 impl<'a> core::cmp::PartialEq for Builder<'a> {
     fn eq(&self, other: &Self) -> bool {
-        self.bytes == other.bytes
+        self.bytes[..self.len] == other.bytes[..other.len]
     }
 }
 
 impl<'a> core::cmp::PartialEq<&'a str> for Builder<'a> {
     fn eq(&self, other: &&'a str) -> bool {
-        self.bytes == other.as_bytes()
+        &self.bytes[..self.len] == other.as_bytes()
     }
 }
 
 impl<'a> core::cmp::PartialEq<Builder<'a>> for &'a str {
     fn eq(&self, other: &Builder<'a>) -> bool {
-        self.as_bytes() == other.bytes
+        self.as_bytes() == &other.bytes[..other.len]
     }
 }
 
 impl<'a> Builder<'a> {
     #[inline]
     pub fn new(bytes: &mut [u8]) -> Builder<'_> {
-        Builder { bytes, pos: 0 }
+        Self::with_pos(bytes, 0)
     }
 
     #[inline]
     pub fn with_pos(bytes: &mut [u8], pos: usize) -> Builder<'_> {
-        Builder { bytes, pos }
+        Builder {
+            len: bytes.len(),
+            bytes,
+            pos,
+            tag: None,
+        }
+    }
+
+    /// Like `with_pos()`, but keeps the element count of `tag`, the list pointer to `bytes`,
+    /// in sync when the length changes.
+    #[inline]
+    pub(crate) fn with_tag(bytes: &'a mut [u8], pos: usize, tag: &'a mut WirePointer) -> Self {
+        Builder {
+            len: bytes.len(),
+            bytes,
+            pos,
+            tag: Some(tag),
+        }
     }
 
     /// The string's length, in bytes.
     #[inline]
     pub fn len(&self) -> usize {
-        self.bytes.len()
+        self.len
     }
 
     #[inline]
@@ -218,15 +266,22 @@ impl<'a> Builder<'a> {
         self.len() == 0
     }
 
+    /// The number of bytes that the string can grow to with `push_str()`. This is fixed by
+    /// the allocation made when the text was initialized.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.bytes.len()
+    }
+
     #[inline]
     pub fn as_bytes(self) -> &'a [u8] {
-        self.bytes
+        &self.bytes[..self.len]
     }
 
     /// Converts to a `str`, returning a error if the data contains invalid utf-8.
     #[inline]
     pub fn to_str(self) -> core::result::Result<&'a str, core::str::Utf8Error> {
-        str::from_utf8(self.bytes)
+        str::from_utf8(self.as_bytes())
     }
 
     #[cfg(feature = "alloc")]
@@ -236,32 +291,78 @@ impl<'a> Builder<'a> {
         Ok(self.to_str()?.into())
     }
 
+    #[cfg(feature = "alloc")]
+    /// Converts to a `str`, replacing invalid utf-8 sequences with `U+FFFD REPLACEMENT
+    /// CHARACTER`. Only allocates if there are any.
+    #[inline]
+    pub fn to_string_lossy(self) -> alloc::borrow::Cow<'a, str> {
+        alloc::string::String::from_utf8_lossy(self.as_bytes())
+    }
+
     #[inline]
     pub fn as_bytes_mut(self) -> &'a mut [u8] {
-        &mut self.bytes[..]
+        &mut self.bytes[..self.len]
     }
 
-    /// Writes a single ascii character at position `pos` and increments `pos`.
+    fn set_len(&mut self, len: usize) {
+        self.len = len;
+        if let Some(tag) = &mut self.tag {
+            tag.set_list_size_and_count(ElementSize::Byte, len as u32 + 1);
+        }
+    }
+
+    /// Writes a single ascii character at position `pos` and increments `pos`, growing the
+    /// string if needed. Panics if the string is at capacity.
     #[inline]
     pub fn push_ascii(&mut self, ascii: u8) {
         assert!(ascii < 128);
         self.bytes[self.pos] = ascii;
         self.pos += 1;
+        if self.pos > self.len {
+            self.set_len(self.pos);
+        }
     }
 
-    /// Writes a string at position `pos` and increases `pos` a corresponding amount.
+    /// Writes a string at position `pos` and increases `pos` a corresponding amount, growing
+    /// the string if needed. Returns an error, without writing anything, if the string would
+    /// grow past its capacity.
+    ///
+    /// Right after `init_*()`, the string is already as long as its capacity, filled with
+    /// zeros, and `pos` is 0. Pushing then overwrites those zeros, and any push past the end
+    /// fails with `TextCapacityExceeded`. To build the string up from empty, call `truncate()`
+    /// or `clear()` first.
     #[inline]
-    pub fn push_str(&mut self, string: &str) {
+    pub fn push_str(&mut self, string: &str) -> Result<()> {
         let bytes = string.as_bytes();
-        self.bytes[self.pos..(self.pos + bytes.len())].copy_from_slice(bytes);
-        self.pos += bytes.len();
+        let end = self.pos + bytes.len();
+        if end > self.capacity() {
+            return Err(Error::from_kind(ErrorKind::TextCapacityExceeded));
+        }
+        self.bytes[self.pos..end].copy_from_slice(bytes);
+        self.pos = end;
+        if self.pos > self.len {
+            self.set_len(self.pos);
+        }
+        Ok(())
     }
 
-    /// Zeroes all data and resets `pos`.
-    pub fn clear(&mut self) {
-        for b in &mut self.bytes[..self.pos] {
+    /// Shortens the string to `new_len` bytes, zeroing the removed bytes. Has no effect if
+    /// `new_len` is not less than the current length. The capacity is unchanged, so the
+    /// string can grow again with `push_str()`.
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len >= self.len {
+            return;
+        }
+        for b in &mut self.bytes[new_len..self.len] {
             *b = 0;
         }
+        self.set_len(new_len);
+        self.pos = self.pos.min(new_len);
+    }
+
+    /// Truncates the string to zero length and resets `pos`.
+    pub fn clear(&mut self) {
+        self.truncate(0);
         self.pos = 0;
     }
 
@@ -269,18 +370,20 @@ impl<'a> Builder<'a> {
     pub fn reborrow(&mut self) -> Builder<'_> {
         Builder {
             bytes: self.bytes,
+            len: self.len,
             pos: self.pos,
+            tag: self.tag.as_deref_mut(),
         }
     }
 
     #[inline]
     pub fn into_reader(self) -> Reader<'a> {
-        Reader(self.bytes)
+        Reader(&self.bytes[..self.len])
     }
 
     #[inline]
     pub fn reborrow_as_reader(&self) -> Reader<'_> {
-        Reader(self.bytes)
+        Reader(&self.bytes[..self.len])
     }
 }
 
@@ -297,6 +400,12 @@ impl<'a> crate::traits::FromPointerBuilder<'a> for Builder<'a> {
     fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, size: u32) -> Builder<'a> {
         builder.init_text(size)
     }
+    fn try_init_pointer(
+        builder: crate::private::layout::PointerBuilder<'a>,
+        size: u32,
+    ) -> Result<Builder<'a>> {
+        builder.try_init_text(size)
+    }
     fn get_from_pointer(
         builder: crate::private::layout::PointerBuilder<'a>,
         default: Option<&'a [crate::Word]>,
@@ -311,8 +420,7 @@ impl<'a> crate::traits::SetPointerBuilder for Reader<'a> {
         value: Reader<'a>,
         _canonicalize: bool,
     ) -> Result<()> {
-        pointer.set_text(value);
-        Ok(())
+        pointer.try_set_text(value)
     }
 }
 
@@ -324,8 +432,7 @@ impl<'a> crate::traits::SetPointerBuilder for &'a str {
         value: &'a str,
         _canonicalize: bool,
     ) -> Result<()> {
-        pointer.set_text(value.into());
-        Ok(())
+        pointer.try_set_text(value.into())
     }
 }
 
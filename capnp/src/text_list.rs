@@ -136,6 +136,25 @@ impl<'a> Builder<'a> {
             .set_text(value);
     }
 
+    /// Sets the elements of the list to the strings yielded by `values`, in order. Panics if
+    /// `values` does not yield exactly `len()` items.
+    pub fn set_all<I>(&mut self, values: I)
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut count = 0;
+        for value in values {
+            assert!(count < self.len(), "more values than list elements");
+            self.builder
+                .reborrow()
+                .get_pointer_element(count)
+                .set_text(value.as_ref().into());
+            count += 1;
+        }
+        assert_eq!(count, self.len(), "fewer values than list elements");
+    }
+
     pub fn into_reader(self) -> Reader<'a> {
         Reader {
             reader: self.builder.into_reader(),
@@ -155,6 +174,11 @@ impl<'a> FromPointerBuilder<'a> for Builder<'a> {
             builder: builder.init_list(Pointer, size),
         }
     }
+    fn try_init_pointer(builder: PointerBuilder<'a>, size: u32) -> Result<Builder<'a>> {
+        Ok(Builder {
+            builder: builder.try_init_list(Pointer, size)?,
+        })
+    }
     fn get_from_pointer(
         builder: PointerBuilder<'a>,
         default: Option<&'a [crate::Word]>,
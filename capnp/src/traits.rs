@@ -74,6 +74,13 @@ pub trait Pipelined {
 
 pub trait FromPointerBuilder<'a>: Sized {
     fn init_pointer(builder: PointerBuilder<'a>, length: u32) -> Self;
+
+    /// Like `init_pointer()`, but returns an error instead of panicking if the message's
+    /// allocation budget is exhausted. The default implementation calls `init_pointer()`.
+    fn try_init_pointer(builder: PointerBuilder<'a>, length: u32) -> Result<Self> {
+        Ok(Self::init_pointer(builder, length))
+    }
+
     fn get_from_pointer(
         builder: PointerBuilder<'a>,
         default: Option<&'a [crate::Word]>,
@@ -142,11 +149,13 @@ impl<U, T: IndexMove<u32, U>> ::core::iter::Iterator for ListIter<T, U> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.size as usize, Some(self.size as usize))
+        let remaining = (self.size - self.index) as usize;
+        (remaining, Some(remaining))
     }
 
     fn nth(&mut self, p: usize) -> Option<U> {
-        if self.index + (p as u32) < self.size {
+        // Compared as `usize`, so that a huge `p` can neither wrap nor overflow.
+        if p < (self.size - self.index) as usize {
             self.index += p as u32;
             let result = self.list.index_move(self.index);
             self.index += 1;
@@ -207,11 +216,13 @@ impl<U, T: IndexMove<u16, U>> ::core::iter::Iterator for ShortListIter<T, U> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.size as usize, Some(self.size as usize))
+        let remaining = (self.size - self.index) as usize;
+        (remaining, Some(remaining))
     }
 
     fn nth(&mut self, p: usize) -> Option<U> {
-        if self.index + (p as u16) < self.size {
+        // Compared as `usize`, so that a huge `p` can neither wrap nor overflow.
+        if p < (self.size - self.index) as usize {
             self.index += p as u16;
             let result = self.list.index_move(self.index);
             self.index += 1;
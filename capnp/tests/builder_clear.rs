@@ -0,0 +1,86 @@
+#![cfg(feature = "alloc")]
+
+use capnp::message::{self, Allocator, HeapAllocator};
+use capnp::{primitive_list, serialize, text};
+
+/// Counts the segments handed out by a `HeapAllocator`.
+struct CountingAllocator {
+    inner: HeapAllocator,
+    allocations: usize,
+}
+
+unsafe impl Allocator for CountingAllocator {
+    fn allocate_segment(&mut self, minimum_size: u32) -> (*mut u8, u32) {
+        self.allocations += 1;
+        self.inner.allocate_segment(minimum_size)
+    }
+
+    unsafe fn deallocate_segment(&mut self, ptr: *mut u8, word_size: u32, words_used: u32) {
+        unsafe { self.inner.deallocate_segment(ptr, word_size, words_used) }
+    }
+}
+
+#[test]
+pub fn clear_reuses_segments() {
+    let mut builder = message::Builder::new(CountingAllocator {
+        inner: HeapAllocator::new(),
+        allocations: 0,
+    });
+
+    {
+        let mut list: primitive_list::Builder<u64> = builder.initn_root(16);
+        for i in 0..16 {
+            list.set(i, u64::MAX - u64::from(i));
+        }
+    }
+    let first_segment = builder.get_segments_for_output()[0].as_ptr();
+
+    builder.clear();
+    builder.set_root("hi").unwrap();
+    assert_eq!(builder.get_segments_for_output()[0].as_ptr(), first_segment);
+
+    let mut bytes = Vec::new();
+    serialize::write_message(&mut bytes, &builder).unwrap();
+    let reader = serialize::read_message(bytes.as_slice(), message::ReaderOptions::new()).unwrap();
+    assert_eq!(reader.get_root::<text::Reader>().unwrap(), "hi");
+
+    // Nothing of the first message is left after the second one.
+    let segments = builder.get_segments_for_output();
+    assert_eq!(segments.len(), 1);
+    assert_eq!(segments[0].len(), 2 * 8);
+    assert!(segments[0][8..].starts_with(b"hi\0"));
+    assert!(segments[0][11..].iter().all(|&b| b == 0));
+
+    assert_eq!(builder.into_allocator().allocations, 1);
+}
+
+#[test]
+pub fn clear_drops_trailing_segments_from_output() {
+    let mut builder = message::Builder::new(CountingAllocator {
+        inner: HeapAllocator::new().first_segment_words(4),
+        allocations: 0,
+    });
+
+    {
+        let mut list: primitive_list::Builder<u64> = builder.initn_root(16);
+        list.set(15, 1);
+    }
+    assert_eq!(builder.get_segments_for_output().len(), 2);
+
+    builder.clear();
+    builder.set_root("x").unwrap();
+    assert_eq!(builder.get_segments_for_output().len(), 1);
+
+    {
+        let mut list: primitive_list::Builder<u64> = builder.initn_root(16);
+        list.set(0, 7);
+    }
+    let mut bytes = Vec::new();
+    serialize::write_message(&mut bytes, &builder).unwrap();
+    let reader = serialize::read_message(bytes.as_slice(), message::ReaderOptions::new()).unwrap();
+    let list = reader.get_root::<primitive_list::Reader<u64>>().unwrap();
+    assert_eq!(list.get(0), 7);
+    assert!((1..16).all(|i| list.get(i) == 0));
+
+    assert_eq!(builder.into_allocator().allocations, 2);
+}
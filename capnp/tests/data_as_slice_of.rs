@@ -0,0 +1,91 @@
+#![cfg(feature = "alloc")]
+
+use capnp::{data, message, text};
+
+#[test]
+fn reader_views() {
+    let mut message = message::Builder::new_default();
+    let bytes: Vec<u8> = [1.5f32, -2.0, 0.25]
+        .iter()
+        .flat_map(|f| f.to_le_bytes())
+        .chain([7, 8])
+        .collect();
+    message.set_root(&bytes[..]).unwrap();
+    let reader = message.get_root_as_reader::<data::Reader>().unwrap();
+    let floats = data::subslice(reader, ..12).unwrap();
+
+    // Aligned, since the data starts at a word boundary.
+    #[cfg(all(target_endian = "little", not(feature = "unaligned")))]
+    {
+        assert_eq!(
+            data::as_slice_of::<f32>(floats),
+            Some(&[1.5, -2.0, 0.25][..])
+        );
+        assert_eq!(data::as_slice_of::<u32>(floats).unwrap().len(), 3);
+        assert_eq!(data::as_slice_of::<u16>(reader).unwrap()[6], 0x0807);
+    }
+    assert_eq!(data::as_slice_of::<u8>(reader), Some(&bytes[..]));
+    assert_eq!(data::as_slice_of::<f64>(&[]), Some(&[][..]));
+
+    // Wrong length.
+    assert_eq!(data::as_slice_of::<f32>(reader), None);
+    assert_eq!(data::as_slice_of::<u64>(floats), None);
+    assert_eq!(data::to_vec_of::<f32>(reader), None);
+
+    // Misaligned.
+    let misaligned = data::subslice(reader, 2..10).unwrap();
+    assert_eq!(data::as_slice_of::<u32>(misaligned), None);
+    #[cfg(all(target_endian = "little", not(feature = "unaligned")))]
+    assert_eq!(data::as_slice_of::<i16>(misaligned).unwrap().len(), 4);
+
+    // Copying works either way.
+    assert_eq!(data::to_vec_of::<f32>(floats), Some(vec![1.5, -2.0, 0.25]));
+    assert_eq!(
+        data::to_vec_of::<u32>(data::subslice(reader, 2..14).unwrap()),
+        Some(vec![0x0000_3fc0, 0x0000_c000, 0x0807_3e80])
+    );
+
+    assert_eq!(data::subslice(reader, 12..), Some(&[7, 8][..]));
+    assert_eq!(data::subslice(reader, 12..15), None);
+}
+
+#[test]
+fn builder_views() {
+    let mut message = message::Builder::new_default();
+    let builder = message.initn_root::<data::Builder>(18);
+
+    #[cfg(all(target_endian = "little", not(feature = "unaligned")))]
+    {
+        let words = data::as_mut_slice_of::<u64>(&mut builder[..16]).unwrap();
+        words[0] = 0x0102_0304_0506_0708;
+        words[1] = u64::MAX;
+        assert_eq!(builder[0], 0x08);
+    }
+    #[cfg(not(all(target_endian = "little", not(feature = "unaligned"))))]
+    builder[..16].copy_from_slice(&[
+        8, 7, 6, 5, 4, 3, 2, 1, 255, 255, 255, 255, 255, 255, 255, 255,
+    ]);
+
+    // Wrong length.
+    assert!(data::as_mut_slice_of::<u64>(&mut builder[..]).is_none());
+
+    // Misaligned.
+    assert!(data::as_mut_slice_of::<u32>(&mut builder[1..9]).is_none());
+    data::as_mut_slice_of::<u8>(&mut builder[16..]).unwrap()[1] = 9;
+
+    let reader = message.get_root_as_reader::<data::Reader>().unwrap();
+    assert_eq!(
+        data::to_vec_of::<u64>(&reader[..16]),
+        Some(vec![0x0102_0304_0506_0708, u64::MAX])
+    );
+    assert_eq!(data::to_vec_of::<i16>(&reader[16..]), Some(vec![0x0900]));
+}
+
+#[test]
+fn text_subslice() {
+    let text: text::Reader = "grüße".into();
+    assert_eq!(text.subslice(..2).unwrap(), "gr");
+    assert_eq!(text.subslice(4..).unwrap(), "ße");
+    assert!(text.subslice(3..4).unwrap().to_str().is_err());
+    assert!(text.subslice(..8).is_none());
+}
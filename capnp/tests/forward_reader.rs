@@ -0,0 +1,63 @@
+#![cfg(feature = "alloc")]
+
+use capnp::message::{self, ReaderOptions};
+use capnp::{serialize, text_list};
+
+fn build_text_list<A: message::Allocator>(message: &mut message::Builder<A>, len: u32) {
+    let mut list: text_list::Builder = message.initn_root(len);
+    for i in 0..len {
+        list.set(i, format!("element {i}").as_str().into());
+    }
+}
+
+#[test]
+fn forward_multi_segment_message() {
+    let allocator = message::HeapAllocator::new()
+        .first_segment_words(4)
+        .allocation_strategy(message::AllocationStrategy::FixedSize);
+    let mut message = message::Builder::new(allocator);
+    build_text_list(&mut message, 10);
+    let segment_count = message.get_segments_for_output().len();
+    assert!(segment_count > 1);
+    let mut original = Vec::new();
+    serialize::write_message(&mut original, &message).unwrap();
+
+    let reader = serialize::read_message(&original[..], ReaderOptions::new()).unwrap();
+    let list: text_list::Reader = reader.get_root().unwrap();
+    assert_eq!(list.get(9).unwrap(), "element 9");
+
+    let output = reader.output_segments();
+    assert_eq!(output.len(), segment_count);
+    assert_eq!(reader.segments().count(), segment_count);
+    for (segment, expected) in reader
+        .segments()
+        .zip(message.get_segments_for_output().iter())
+    {
+        assert_eq!(segment, *expected);
+    }
+    let table_bytes = (segment_count / 2 + 1) * 8;
+    assert_eq!(reader.total_bytes() + table_bytes, original.len());
+
+    let mut forwarded = Vec::new();
+    serialize::write_message_segments(&mut forwarded, &output).unwrap();
+    assert_eq!(forwarded, original);
+}
+
+#[test]
+fn forward_single_segment_message() {
+    let mut message = message::Builder::new_default();
+    build_text_list(&mut message, 3);
+    let original = serialize::write_message_to_words(&message);
+
+    let reader =
+        serialize::read_message_from_flat_slice(&mut &original[..], ReaderOptions::new()).unwrap();
+    let output = reader.output_segments();
+    assert!(matches!(
+        output,
+        capnp::OutputSegments::SingleSegment([segment]) if segment.len() == reader.total_bytes()
+    ));
+
+    let mut forwarded = Vec::new();
+    serialize::write_message_segments(&mut forwarded, &output).unwrap();
+    assert_eq!(forwarded, original);
+}
@@ -0,0 +1,120 @@
+#![cfg(feature = "alloc")]
+
+use capnp::schema_capnp::{annotation, ElementSize};
+use capnp::{data_list, enum_list, list_list, message, primitive_list, struct_list, text_list};
+
+#[test]
+fn primitive_list_try_get() {
+    let mut message = message::Builder::new_default();
+    let mut list: primitive_list::Builder<u16> = message.initn_root(2);
+    list.set(0, 10);
+    list.set(1, 20);
+    assert_eq!(list.try_get(1), Some(20));
+    assert_eq!(list.try_get(2), None);
+    assert_eq!(list.try_get(u32::MAX), None);
+
+    let list = list.into_reader();
+    assert_eq!(list.get(0), 10);
+    assert_eq!(list.try_get(0), Some(10));
+    assert_eq!(list.try_get(2), None);
+}
+
+#[test]
+fn struct_list_try_get() {
+    let mut message = message::Builder::new_default();
+    let mut list: struct_list::Builder<annotation::Owned> = message.initn_root(2);
+    list.reborrow().get(1).set_id(7);
+    assert_eq!(list.reborrow().try_get(1).map(|a| a.get_id()), Some(7));
+    assert!(list.reborrow().try_get(2).is_none());
+
+    let list = list.into_reader();
+    assert_eq!(list.get(1).get_id(), 7);
+    assert_eq!(list.try_get(1).map(|a| a.get_id()), Some(7));
+    assert!(list.try_get(2).is_none());
+}
+
+#[test]
+fn text_and_data_list_try_get() {
+    let mut message = message::Builder::new_default();
+    let mut list: text_list::Builder = message.initn_root(1);
+    list.set(0, "hi".into());
+    assert!(list.reborrow().try_get(0).is_some());
+    assert!(list.reborrow().try_get(1).is_none());
+    let list = list.into_reader();
+    assert_eq!(list.try_get(0).unwrap().unwrap(), "hi");
+    assert!(list.try_get(1).is_none());
+
+    let mut message = message::Builder::new_default();
+    let mut list: data_list::Builder = message.initn_root(1);
+    list.set(0, &[1, 2]);
+    assert!(list.reborrow().try_get(0).is_some());
+    assert!(list.reborrow().try_get(1).is_none());
+    let list = list.into_reader();
+    assert_eq!(list.try_get(0).unwrap().unwrap(), &[1, 2]);
+    assert!(list.try_get(1).is_none());
+}
+
+#[test]
+fn enum_list_try_get() {
+    let mut message = message::Builder::new_default();
+    let mut list: enum_list::Builder<ElementSize> = message.initn_root(1);
+    list.set(0, ElementSize::Pointer);
+    assert_eq!(list.try_get(0), Some(Ok(ElementSize::Pointer)));
+    assert_eq!(list.try_get(1), None);
+    let list = list.into_reader();
+    assert_eq!(list.get(0), Ok(ElementSize::Pointer));
+    assert_eq!(list.try_get(1), None);
+}
+
+#[test]
+fn list_list_try_get() {
+    let mut message = message::Builder::new_default();
+    let mut list: list_list::Builder<primitive_list::Owned<u8>> = message.initn_root(1);
+    list.reborrow().init(0, 3);
+    assert_eq!(list.reborrow().try_get(0).unwrap().unwrap().len(), 3);
+    assert!(list.reborrow().try_get(1).is_none());
+    let list = list.into_reader();
+    assert_eq!(list.try_get(0).unwrap().unwrap().len(), 3);
+    assert!(list.try_get(1).is_none());
+}
+
+#[test]
+#[should_panic]
+fn primitive_list_get_out_of_range_panics() {
+    let mut message = message::Builder::new_default();
+    let list: primitive_list::Builder<u16> = message.initn_root(2);
+    list.into_reader().get(2);
+}
+
+#[test]
+#[should_panic]
+fn struct_list_get_out_of_range_panics() {
+    let mut message = message::Builder::new_default();
+    let list: struct_list::Builder<annotation::Owned> = message.initn_root(2);
+    list.into_reader().get(2);
+}
+
+#[test]
+fn list_iter_nth_and_size_hint() {
+    let mut message = message::Builder::new_default();
+    let mut list: primitive_list::Builder<u32> = message.initn_root(4);
+    for i in 0..4 {
+        list.set(i, i * 10);
+    }
+    let list = list.into_reader();
+
+    let mut iter = list.iter();
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    assert_eq!(iter.nth(1), Some(20));
+    assert_eq!(iter.size_hint(), (1, Some(1)));
+
+    // An index that does not fit in the list's `u32` indices must not wrap around.
+    let mut iter = list.iter();
+    assert_eq!(iter.nth((u32::MAX as usize).saturating_add(1)), None);
+    assert_eq!(iter.next(), None);
+    let mut iter = list.iter();
+    iter.next();
+    assert_eq!(iter.nth(usize::MAX), None);
+}
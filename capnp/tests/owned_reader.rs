@@ -0,0 +1,94 @@
+#![cfg(feature = "alloc")]
+
+use capnp::message::{self, ReaderSegments};
+use capnp::schema_capnp::node;
+use capnp::{serialize, text_list};
+
+fn build_text_list<A: message::Allocator>(message: &mut message::Builder<A>, len: u32) {
+    let mut list: text_list::Builder = message.initn_root(len);
+    for i in 0..len {
+        list.set(i, format!("element {i}").as_str().into());
+    }
+}
+
+fn check_text_list<S: ReaderSegments>(reader: &message::Reader<S>, len: u32) {
+    let list: text_list::Reader = reader.get_root().unwrap();
+    assert_eq!(list.len(), len);
+    for (i, text) in list.iter().enumerate() {
+        assert_eq!(text.unwrap(), format!("element {i}").as_str());
+    }
+}
+
+#[test]
+fn heap_segment_is_moved() {
+    let mut message = message::Builder::new_default();
+    build_text_list(&mut message, 10);
+    let output = message.get_segments_for_output();
+    assert_eq!(output.len(), 1);
+    let (ptr, len) = (output[0].as_ptr(), output[0].len());
+
+    let reader = message.into_owned_reader().unwrap();
+    check_text_list(&reader, 10);
+    let segments = reader.into_segments();
+    assert_eq!(segments.len(), 1);
+    let segment = segments.get_segment(0).unwrap();
+    assert_eq!(segment.as_ptr(), ptr);
+    assert_eq!(segment.len(), len);
+}
+
+#[test]
+fn heap_segments_are_copied() {
+    let allocator = message::HeapAllocator::new()
+        .first_segment_words(4)
+        .allocation_strategy(message::AllocationStrategy::FixedSize);
+    let mut message = message::Builder::new(allocator);
+    build_text_list(&mut message, 10);
+    let expected = serialize::write_message_segments_to_words(&message);
+    assert!(message.get_segments_for_output().len() > 1);
+
+    let reader = message.into_owned_reader().unwrap();
+    check_text_list(&reader, 10);
+    assert_eq!(
+        serialize::write_message_segments_to_words(&reader.into_segments()),
+        expected
+    );
+}
+
+#[test]
+fn scratch_space_segments_are_copied() {
+    let mut buffer = capnp::Word::allocate_zeroed_vec(8);
+    let reader = {
+        let allocator = message::ScratchSpaceHeapAllocator::new(capnp::Word::words_to_bytes_mut(
+            &mut buffer[..],
+        ));
+        let mut message = message::Builder::new(allocator);
+        build_text_list(&mut message, 10);
+        assert!(message.get_segments_for_output().len() > 1);
+        message.into_owned_reader().unwrap()
+    };
+    check_text_list(&reader, 10);
+
+    // The scratch space was given back, and zeroed, once the builder was consumed.
+    for w in buffer {
+        assert_eq!(w, capnp::word(0, 0, 0, 0, 0, 0, 0, 0));
+    }
+}
+
+#[test]
+fn empty_builder() {
+    let reader = message::Builder::new_default().into_owned_reader().unwrap();
+    let root: node::Reader = reader.get_root().unwrap();
+    assert_eq!(root.get_id(), 0);
+}
+
+fn build_node(id: u64) -> message::TypedReader<serialize::OwnedSegments, node::Owned> {
+    let mut message = message::TypedBuilder::<node::Owned>::new_default();
+    message.init_root().set_id(id);
+    message.into_owned_typed_reader().unwrap()
+}
+
+#[test]
+fn typed_reader() {
+    let reader = build_node(0x1234);
+    assert_eq!(reader.get().unwrap().get_id(), 0x1234);
+}
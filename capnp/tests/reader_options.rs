@@ -0,0 +1,73 @@
+#![cfg(feature = "alloc")]
+
+use capnp::message::{self, ReaderOptions};
+use capnp::{primitive_list, serialize, ErrorKind};
+
+const OPTIONS: ReaderOptions = ReaderOptions::new()
+    .with_nesting_limit(128)
+    .with_traversal_limit_in_words(Some(150))
+    .with_total_message_size_limit_in_words(None);
+
+fn read_list(reader: &message::Reader<serialize::OwnedSegments>) -> capnp::Result<u32> {
+    let list: primitive_list::Reader<u64> = reader.get_root()?;
+    Ok(list.len())
+}
+
+#[test]
+fn const_options() {
+    assert_eq!(OPTIONS.nesting_limit, 128);
+    assert_eq!(OPTIONS.traversal_limit_in_words, Some(150));
+    assert_eq!(OPTIONS.total_message_size_limit_in_words, None);
+}
+
+#[test]
+fn mutable_setters() {
+    let mut options = ReaderOptions::new();
+    options
+        .nesting_limit(128)
+        .traversal_limit_in_words(Some(150))
+        .total_message_size_limit_in_words(None);
+    assert_eq!(options.nesting_limit, 128);
+    assert_eq!(options.traversal_limit_in_words, Some(150));
+    assert_eq!(options.total_message_size_limit_in_words, None);
+}
+
+#[test]
+fn raise_and_lower_limits() {
+    let mut builder = message::Builder::new_default();
+    builder.initn_root::<primitive_list::Builder<u64>>(100);
+    let bytes = serialize::write_message_to_words(&builder);
+
+    let reader = serialize::read_message(bytes.as_slice(), OPTIONS).unwrap();
+    assert_eq!(read_list(&reader).unwrap(), 100);
+    assert_eq!(
+        read_list(&reader).unwrap_err().kind,
+        ErrorKind::ReadLimitExceeded
+    );
+
+    // Raising the limit gives the reader a fresh budget.
+    let reader = reader.with_options(OPTIONS.with_traversal_limit_in_words(Some(250)));
+    assert_eq!(reader.options().traversal_limit_in_words, Some(250));
+    assert_eq!(reader.options().nesting_limit, 128);
+    assert_eq!(read_list(&reader).unwrap(), 100);
+    assert_eq!(read_list(&reader).unwrap(), 100);
+    assert!(read_list(&reader).is_err());
+
+    let reader = reader.with_options(OPTIONS.with_traversal_limit_in_words(None));
+    for _ in 0..10 {
+        assert_eq!(read_list(&reader).unwrap(), 100);
+    }
+
+    // Lowering it below the size of the list makes the next read fail.
+    let reader = reader.with_options(OPTIONS.with_traversal_limit_in_words(Some(50)));
+    assert_eq!(
+        read_list(&reader).unwrap_err().kind,
+        ErrorKind::ReadLimitExceeded
+    );
+
+    let reader = reader.with_options(OPTIONS.with_nesting_limit(0));
+    assert_eq!(
+        read_list(&reader).unwrap_err().kind,
+        ErrorKind::NestingLimitExceeded
+    );
+}
@@ -0,0 +1,223 @@
+#![cfg(feature = "async")]
+
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+use capnp::{message, serialize};
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+/// Polls `future` to completion. The streams below wake their task before returning
+/// `Pending`, so busy-polling is enough.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+/// An in-memory stream that moves at most one byte per poll, and returns `Pending` on every
+/// other poll.
+#[derive(Default)]
+struct Trickle {
+    data: Vec<u8>,
+    position: usize,
+    ready: bool,
+}
+
+impl Trickle {
+    fn new(data: Vec<u8>) -> Self {
+        Self {
+            data,
+            ..Self::default()
+        }
+    }
+
+    fn take_turn(&mut self, cx: &mut Context<'_>) -> bool {
+        self.ready = !self.ready;
+        if !self.ready {
+            cx.waker().wake_by_ref();
+        }
+        self.ready
+    }
+}
+
+impl futures_io::AsyncRead for Trickle {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        if !self.take_turn(cx) {
+            return Poll::Pending;
+        }
+        if buf.is_empty() || self.position == self.data.len() {
+            return Poll::Ready(Ok(0));
+        }
+        buf[0] = self.data[self.position];
+        self.position += 1;
+        Poll::Ready(Ok(1))
+    }
+}
+
+impl futures_io::AsyncWrite for Trickle {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        if !self.take_turn(cx) {
+            return Poll::Pending;
+        }
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+        self.data.push(buf[0]);
+        Poll::Ready(Ok(1))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Builds a message with `segments_wanted` or more segments, the last of which is large.
+fn build_message(segments_wanted: usize) -> message::Builder<message::HeapAllocator> {
+    let mut message = message::Builder::new(message::HeapAllocator::new().first_segment_words(1));
+    let mut list = message.initn_root::<capnp::text_list::Builder<'_>>(segments_wanted as u32);
+    for idx in 0..segments_wanted {
+        let text = "x".repeat(idx * 4000);
+        list.set(idx as u32, text.as_str().into());
+    }
+    assert!(message.get_segments_for_output().len() >= segments_wanted);
+    message
+}
+
+fn check_message(reader: &message::Reader<serialize::OwnedSegments>, segments_wanted: usize) {
+    let list = reader.get_root::<capnp::text_list::Reader<'_>>().unwrap();
+    assert_eq!(list.len() as usize, segments_wanted);
+    for idx in 0..segments_wanted {
+        assert_eq!(list.get(idx as u32).unwrap().len(), idx * 4000);
+    }
+}
+
+#[test]
+fn round_trip_one_byte_at_a_time() {
+    // Covers single-segment messages and segment tables that do and don't fit in one word.
+    for segments_wanted in [1, 2, 3, 4, 9] {
+        let message = build_message(segments_wanted);
+
+        let mut write = Trickle::default();
+        block_on(serialize::write_message_async(&mut write, &message)).unwrap();
+        assert_eq!(write.data, serialize::write_message_to_words(&message));
+
+        // Two messages back to back, then a clean end of stream.
+        let mut data = write.data.clone();
+        data.extend_from_slice(&write.data);
+        let mut read = Trickle::new(data);
+        for _ in 0..2 {
+            let reader = block_on(serialize::read_message_async(
+                &mut read,
+                message::ReaderOptions::new(),
+            ))
+            .unwrap();
+            check_message(&reader, segments_wanted);
+        }
+        assert!(block_on(serialize::try_read_message_async(
+            &mut read,
+            message::ReaderOptions::new()
+        ))
+        .unwrap()
+        .is_none());
+    }
+}
+
+#[test]
+fn write_vectored_one_byte_at_a_time() {
+    for segments_wanted in [1, 2, 9] {
+        let message = build_message(segments_wanted);
+        let segments = message.get_segments_for_output();
+
+        let mut write = Trickle::default();
+        let written = block_on(serialize::write_message_vectored_async(
+            &mut write, &segments,
+        ))
+        .unwrap();
+        assert_eq!(write.data, serialize::write_message_to_words(&message));
+        assert_eq!(written, write.data.len());
+
+        let reader = block_on(serialize::read_message_async(
+            Trickle::new(write.data),
+            message::ReaderOptions::new(),
+        ))
+        .unwrap();
+        check_message(&reader, segments_wanted);
+    }
+}
+
+#[test]
+fn truncated_message() {
+    let bytes = serialize::write_message_to_words(&build_message(4));
+    for len in [0, 3, 8, 12, 20, bytes.len() - 1] {
+        let Err(error) = block_on(serialize::read_message_async(
+            Trickle::new(bytes[..len].to_vec()),
+            message::ReaderOptions::new(),
+        )) else {
+            panic!("expected reading {len} bytes to fail");
+        };
+        assert_eq!(error.kind, capnp::ErrorKind::PrematureEndOfFile, "{len}");
+    }
+}
+
+#[test]
+fn message_too_large() {
+    let bytes = serialize::write_message_to_words(&build_message(4));
+    let mut options = message::ReaderOptions::new();
+    options.traversal_limit_in_words(Some(16));
+    let Err(error) = block_on(serialize::read_message_async(Trickle::new(bytes), options)) else {
+        panic!("expected the message to be rejected");
+    };
+    assert!(matches!(error.kind, capnp::ErrorKind::MessageTooLarge(_)));
+}
+
+#[test]
+fn cancelled_read_does_not_affect_later_reads() {
+    let bytes = serialize::write_message_to_words(&build_message(4));
+
+    // Drop a read in the middle of the segment table, and another in the middle of a segment.
+    for polls in [30, 3000] {
+        let mut read = Trickle::new(bytes.clone());
+        {
+            let future = serialize::read_message_async(&mut read, message::ReaderOptions::new());
+            let mut future = std::pin::pin!(future);
+            let waker = Waker::from(Arc::new(NoopWaker));
+            let mut cx = Context::from_waker(&waker);
+            for _ in 0..polls {
+                assert!(future.as_mut().poll(&mut cx).is_pending());
+            }
+        }
+        assert!(read.position > 0 && read.position < bytes.len());
+
+        let reader = block_on(serialize::read_message_async(
+            Trickle::new(bytes.clone()),
+            message::ReaderOptions::new(),
+        ))
+        .unwrap();
+        check_message(&reader, 4);
+    }
+}
@@ -0,0 +1,92 @@
+#![cfg(all(feature = "std", feature = "alloc"))]
+
+use capnp::message::{self, ReaderOptions};
+use capnp::serialize::{self, Format};
+use capnp::{serialize_packed, text_list, ErrorKind};
+
+fn build_message() -> message::Builder<message::HeapAllocator> {
+    let mut message = message::Builder::new_default();
+    let mut list: text_list::Builder = message.initn_root(3);
+    for i in 0..3 {
+        list.set(i, format!("element {i}").as_str().into());
+    }
+    message
+}
+
+fn check_message(bytes: &[u8]) {
+    let reader = serialize::read_message_auto(bytes, ReaderOptions::new()).unwrap();
+    let list: text_list::Reader = reader.get_root().unwrap();
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.get(2).unwrap(), "element 2");
+}
+
+#[test]
+fn read_raw_and_packed() {
+    let message = build_message();
+
+    let mut raw = Vec::new();
+    serialize::write_message_auto(&mut raw, &message, Format::Raw).unwrap();
+    assert_eq!(raw, serialize::write_message_to_words(&message));
+    assert_eq!(serialize::detect_format(&raw).unwrap(), Format::Raw);
+    check_message(&raw);
+
+    let mut packed = Vec::new();
+    serialize::write_message_auto(&mut packed, &message, Format::Packed).unwrap();
+    let mut expected = Vec::new();
+    serialize_packed::write_message(&mut expected, &message).unwrap();
+    assert_eq!(packed, expected);
+    assert_eq!(serialize::detect_format(&packed).unwrap(), Format::Packed);
+    check_message(&packed);
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn read_gzip_packed() {
+    let message = build_message();
+    let mut compressed = Vec::new();
+    serialize::write_message_auto(&mut compressed, &message, Format::GzipPacked).unwrap();
+    assert_eq!(&compressed[..2], [0x1f, 0x8b]);
+    assert_eq!(
+        serialize::detect_format(&compressed).unwrap(),
+        Format::GzipPacked
+    );
+    check_message(&compressed);
+}
+
+#[test]
+fn ambiguous_input() {
+    // As a raw message: 273 segments, the first of which is one word long. As a packed one: a
+    // single segment of one word. Both are plausible, so neither is picked.
+    let mut crafted = vec![0x10, 0x01, 0, 0, 1, 0, 0, 0];
+    crafted.extend_from_slice(&[0; 8]);
+    let error = serialize::read_message_auto(&crafted[..], ReaderOptions::new())
+        .err()
+        .unwrap();
+    assert_eq!(error.kind, ErrorKind::AmbiguousMessageFormat);
+
+    // Forcing the format reads it as that.
+    let reader =
+        serialize::read_message_with_format(&crafted[..], Format::Packed, ReaderOptions::new())
+            .unwrap();
+    assert_eq!(reader.segments().count(), 1);
+}
+
+#[test]
+fn unrecognized_input() {
+    // Far too many segments either way.
+    let error = serialize::detect_format(&[0xff; 16]).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::UnrecognizedMessageFormat);
+
+    let error = serialize::read_message_auto(&[][..], ReaderOptions::new())
+        .err()
+        .unwrap();
+    assert_eq!(error.kind, ErrorKind::PrematureEndOfFile);
+
+    #[cfg(not(feature = "gzip"))]
+    assert_eq!(
+        serialize::detect_format(&[0x1f, 0x8b, 8, 0])
+            .unwrap_err()
+            .kind,
+        ErrorKind::UnrecognizedMessageFormat
+    );
+}
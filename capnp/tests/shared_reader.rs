@@ -0,0 +1,51 @@
+#![cfg(feature = "alloc")]
+
+use std::sync::Arc;
+
+use capnp::message::{self, ReaderOptions, TypedReader};
+use capnp::{primitive_list, serialize};
+
+#[test]
+pub fn read_arc_backed_message_from_two_threads() {
+    let mut builder = message::Builder::new_default();
+    {
+        let mut list: primitive_list::Builder<u64> = builder.initn_root(1000);
+        for i in 0..1000 {
+            list.set(i, u64::from(i) * 3);
+        }
+    }
+    let bytes = serialize::write_message_to_words(&builder);
+
+    let reader = serialize::read_message(bytes.as_slice(), ReaderOptions::new()).unwrap();
+    let shared: TypedReader<_, primitive_list::Owned<u64>> =
+        TypedReader::new(reader).map_segments(Arc::new);
+
+    let workers: Vec<_> = (0..2)
+        .map(|_| {
+            let typed = shared.clone();
+            std::thread::spawn(move || typed.get().unwrap().iter().sum::<u64>())
+        })
+        .collect();
+    for worker in workers {
+        assert_eq!(worker.join().unwrap(), 3 * 999 * 1000 / 2);
+    }
+    assert_eq!(shared.get().unwrap().len(), 1000);
+}
+
+#[test]
+pub fn clone_starts_a_new_traversal_limit() {
+    let mut builder = message::Builder::new_default();
+    builder.initn_root::<primitive_list::Builder<u64>>(100);
+    let bytes = serialize::write_message_to_words(&builder);
+
+    let mut options = ReaderOptions::new();
+    options.traversal_limit_in_words(Some(150));
+    let reader = serialize::read_message(bytes.as_slice(), options)
+        .unwrap()
+        .map_segments(Arc::new);
+    let typed: TypedReader<_, primitive_list::Owned<u64>> = reader.into_typed();
+
+    typed.get().unwrap();
+    assert!(typed.get().is_err());
+    typed.clone().get().unwrap();
+}
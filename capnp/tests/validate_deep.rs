@@ -0,0 +1,161 @@
+#![cfg(feature = "alloc")]
+
+use capnp::message::{self, ReaderOptions, ValidationOptions};
+use capnp::{word, ErrorKind, Word};
+
+fn validate(
+    segments: &[&[Word]],
+    options: &ValidationOptions,
+) -> capnp::Result<message::ValidationReport> {
+    let segments: Vec<&[u8]> = segments.iter().map(|s| Word::words_to_bytes(s)).collect();
+    let message = message::Reader::new(message::SegmentArray::new(&segments), Default::default());
+    message.validate_deep(options)
+}
+
+#[test]
+fn validate_deep_valid_message() {
+    let mut builder = message::Builder::new_default();
+    {
+        let mut root: capnp::text_list::Builder = builder.initn_root(3);
+        root.set(0, "foo".into());
+        root.set(1, "bar".into());
+        root.set(2, "a longer string".into());
+    }
+    let words = capnp::serialize::write_message_segments_to_words(&builder);
+    let message =
+        capnp::serialize::read_message_from_flat_slice(&mut &words[..], ReaderOptions::new())
+            .unwrap();
+
+    let report = message.validate_deep(&ValidationOptions::new()).unwrap();
+    assert_eq!(report.words_traversed, 3 + 1 + 1 + 2);
+    assert_eq!(report.cap_count, 0);
+    assert_eq!(report.max_depth, 2);
+
+    let mut options = ReaderOptions::new();
+    options.traversal_limit_in_words(None);
+    let segments = message.into_segments();
+    let message = message::Reader::new(segments, options);
+    let root: capnp::text_list::Reader = message.get_root().unwrap();
+    assert_eq!(root.get(2).unwrap(), "a longer string");
+}
+
+#[test]
+fn validate_deep_null_root() {
+    let segment = &[word(0, 0, 0, 0, 0, 0, 0, 0)];
+    let report = validate(&[segment], &ValidationOptions::new()).unwrap();
+    assert_eq!(report, message::ValidationReport::default());
+}
+
+#[test]
+fn validate_deep_out_of_bounds() {
+    let segment = &[
+        // Root: struct with one pointer.
+        word(0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00),
+        // List of two structs with one data word and one pointer, four words in all.
+        word(0x01, 0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00),
+        word(0x08, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00),
+        word(0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00),
+        word(0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00),
+        word(0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00),
+        // Struct pointer 100 words past the end of the segment.
+        word(0x90, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00),
+    ];
+    let e = validate(&[segment], &ValidationOptions::new()).unwrap_err();
+    assert_eq!(e.kind, ErrorKind::MessageContainsOutOfBoundsPointer);
+    assert_eq!(e.extra, "at root.ptr0[1].ptr0");
+}
+
+#[test]
+fn validate_deep_list_overruns_segment() {
+    let segment = &[
+        // Root: list of 16 bytes, but only one word follows.
+        word(0x01, 0x00, 0x00, 0x00, 0x82, 0x00, 0x00, 0x00),
+        word(0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00),
+    ];
+    let e = validate(&[segment], &ValidationOptions::new()).unwrap_err();
+    assert_eq!(e.kind, ErrorKind::MessageContainsOutOfBoundsPointer);
+    assert_eq!(e.extra, "at root");
+}
+
+#[test]
+fn validate_deep_far_pointer_to_far_pointer() {
+    // Each segment's only word is a far pointer to the other.
+    let segment0 = &[word(0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00)];
+    let segment1 = &[word(0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00)];
+    let e = validate(&[segment0, segment1], &ValidationOptions::new()).unwrap_err();
+    assert_eq!(e.kind, ErrorKind::UnexepectedFarPointer);
+    assert_eq!(e.extra, "at root");
+}
+
+#[test]
+fn validate_deep_cyclic_far_pointers() {
+    let segment0 = &[
+        // Root: struct with one pointer.
+        word(0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00),
+        // Double-far pointer to the landing pad in segment 1.
+        word(0x06, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00),
+    ];
+    let segment1 = &[
+        // Landing pad: far pointer back to the struct above, which holds the double-far pointer.
+        word(0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00),
+        // Tag: struct with one pointer.
+        word(0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00),
+    ];
+
+    let e = validate(&[segment0, segment1], &ValidationOptions::new()).unwrap_err();
+    assert_eq!(e.kind, ErrorKind::MessageIsTooDeeplyNested);
+    assert_eq!(e.extra, format!("at root{}", ".ptr0".repeat(64)));
+
+    let mut options = ValidationOptions::new();
+    options
+        .nesting_limit(i32::MAX)
+        .traversal_limit_in_words(Some(1000));
+    let e = validate(&[segment0, segment1], &options).unwrap_err();
+    assert_eq!(e.kind, ErrorKind::ReadLimitExceeded);
+}
+
+#[test]
+fn validate_deep_malformed_double_far() {
+    let segment0 = &[word(0x06, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00)];
+    let segment1 = &[
+        // The landing pad should be a far pointer, not a struct pointer.
+        word(0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00),
+        word(0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00),
+    ];
+    let e = validate(&[segment0, segment1], &ValidationOptions::new()).unwrap_err();
+    assert_eq!(e.kind, ErrorKind::MalformedDoubleFarPointer);
+}
+
+#[test]
+fn validate_deep_void_list_amplification() {
+    // Root: list of 2^29 - 1 voids, which takes no space in the message.
+    let segment = &[word(0x01, 0x00, 0x00, 0x00, 0xf8, 0xff, 0xff, 0xff)];
+    let e = validate(&[segment], &ValidationOptions::new()).unwrap_err();
+    assert_eq!(e.kind, ErrorKind::ReadLimitExceeded);
+
+    let mut options = ValidationOptions::new();
+    options.traversal_limit_in_words(None);
+    let report = validate(&[segment], &options).unwrap();
+    assert_eq!(report.words_traversed, (1 << 29) - 1);
+}
+
+#[test]
+fn validate_deep_deep_nesting() {
+    // A chain of structs, each holding a pointer to the next.
+    const DEPTH: usize = 100_000;
+    let mut segment = vec![word(0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00); DEPTH];
+    segment.push(word(0, 0, 0, 0, 0, 0, 0, 0));
+
+    let e = validate(&[&segment], &ValidationOptions::new()).unwrap_err();
+    assert_eq!(e.kind, ErrorKind::MessageIsTooDeeplyNested);
+
+    let mut options = ValidationOptions::new();
+    options.nesting_limit(DEPTH as i32);
+    let report = validate(&[&segment], &options).unwrap();
+    assert_eq!(report.max_depth, DEPTH as u32);
+    assert_eq!(report.words_traversed, DEPTH as u64);
+
+    options.nesting_limit(DEPTH as i32 - 1);
+    let e = validate(&[&segment], &options).unwrap_err();
+    assert_eq!(e.kind, ErrorKind::MessageIsTooDeeplyNested);
+}
@@ -0,0 +1,182 @@
+//! Finds the `using` aliases in a schema file, for
+//! [`CodeGenerationCommand::reexport_aliases`](crate::codegen::CodeGenerationCommand::reexport_aliases).
+//!
+//! The compiler resolves aliases before it calls the plugin, so they never appear in the
+//! `CodeGeneratorRequest`. This module recovers them from the schema source instead. It only
+//! understands as much of the grammar as it needs to: comments, strings, and the nesting of
+//! `struct` and `interface` scopes.
+
+/// A `using` declaration.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Alias {
+    /// 1-based line of the `using` keyword.
+    pub line: usize,
+
+    /// Names of the enclosing structs and interfaces, outermost first.
+    pub scope: Vec<String>,
+
+    /// The name the alias declares.
+    pub name: String,
+
+    /// The file named by `import "..."`, if the target starts with one.
+    pub import: Option<String>,
+
+    /// Whether the target starts with `.`, which refers to the top level of the file.
+    pub absolute: bool,
+
+    /// The names that follow the import, if any, or that make up the whole target.
+    pub path: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Ident(&'a str),
+    Str(&'a str),
+    Punct(char),
+}
+
+fn tokenize(source: &str) -> Vec<(usize, Token<'_>)> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let c = bytes[pos];
+        match c {
+            b'\n' => {
+                line += 1;
+                pos += 1;
+            }
+            b'#' => {
+                while pos < bytes.len() && bytes[pos] != b'\n' {
+                    pos += 1;
+                }
+            }
+            b'"' => {
+                let start = pos + 1;
+                pos = start;
+                while pos < bytes.len() && bytes[pos] != b'"' {
+                    match bytes[pos] {
+                        b'\\' => pos += 1,
+                        b'\n' => line += 1,
+                        _ => (),
+                    }
+                    pos += 1;
+                }
+                tokens.push((line, Token::Str(&source[start..pos.min(bytes.len())])));
+                pos += 1;
+            }
+            _ if c.is_ascii_alphanumeric() || c == b'_' => {
+                let start = pos;
+                while pos < bytes.len()
+                    && (bytes[pos].is_ascii_alphanumeric() || bytes[pos] == b'_')
+                {
+                    pos += 1;
+                }
+                tokens.push((line, Token::Ident(&source[start..pos])));
+            }
+            _ if c.is_ascii_whitespace() => pos += 1,
+            _ => {
+                // Multi-byte characters can only appear in comments and strings.
+                tokens.push((line, Token::Punct(c as char)));
+                pos += 1;
+            }
+        }
+    }
+    tokens
+}
+
+/// Returns the aliases declared in `source` at file scope and in `struct` and `interface`
+/// scopes. Aliases of generic instances, such as `using T = Foo(Text);`, are left out.
+pub(crate) fn parse(source: &str) -> Vec<Alias> {
+    let tokens = tokenize(source);
+    let mut aliases = Vec::new();
+
+    // For every open brace, the name of the struct or interface it belongs to.
+    let mut scopes: Vec<Option<String>> = Vec::new();
+    let mut pending_scope = None;
+
+    for (index, (line, token)) in tokens.iter().enumerate() {
+        match token {
+            Token::Ident("struct" | "interface") => {
+                if let Some((_, Token::Ident(name))) = tokens.get(index + 1) {
+                    pending_scope = Some(name.to_string());
+                }
+            }
+            Token::Ident("using") => {
+                let at_statement_start =
+                    index == 0 || matches!(tokens[index - 1].1, Token::Punct(';' | '{' | '}'));
+                if at_statement_start && scopes.iter().all(Option::is_some) {
+                    if let Some(alias) = parse_using(&tokens[index + 1..]) {
+                        aliases.push(Alias {
+                            line: *line,
+                            scope: scopes.iter().flatten().cloned().collect(),
+                            ..alias
+                        });
+                    }
+                }
+            }
+            Token::Punct('{') => scopes.push(pending_scope.take()),
+            Token::Punct('}') => {
+                scopes.pop();
+            }
+            Token::Punct(';') => pending_scope = None,
+            _ => (),
+        }
+    }
+    aliases
+}
+
+/// Parses what follows `using`, up to the `;`.
+fn parse_using(tokens: &[(usize, Token<'_>)]) -> Option<Alias> {
+    let mut tokens = tokens.iter().map(|(_, token)| token);
+
+    let mut name = None;
+    let mut next = tokens.next()?;
+    if let Token::Ident(ident) = next {
+        if tokens.clone().next() == Some(&Token::Punct('=')) {
+            name = Some(ident.to_string());
+            tokens.next();
+            next = tokens.next()?;
+        }
+    }
+
+    let mut import = None;
+    if next == &Token::Ident("import") {
+        let Token::Str(file) = tokens.next()? else {
+            return None;
+        };
+        import = Some(file.to_string());
+        next = tokens.next()?;
+    }
+
+    let absolute = import.is_none() && next == &Token::Punct('.');
+    let mut path = Vec::new();
+    loop {
+        if !path.is_empty() || import.is_some() || absolute {
+            match next {
+                Token::Punct('.') => next = tokens.next()?,
+                Token::Punct(';') => break,
+                _ => return None,
+            }
+        }
+        let Token::Ident(component) = next else {
+            return None;
+        };
+        path.push(component.to_string());
+        next = tokens.next()?;
+    }
+
+    let name = match name {
+        Some(name) => name,
+        None => path.last()?.clone(),
+    };
+    Some(Alias {
+        line: 0,
+        scope: Vec::new(),
+        name,
+        import,
+        absolute,
+        path,
+    })
+}
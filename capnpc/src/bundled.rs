@@ -0,0 +1,124 @@
+//! The Cap'n Proto release that this crate is developed against, and the schema files that it
+//! ships for use in imports.
+
+use std::fmt;
+use std::str::FromStr;
+
+// Keep in sync with `CAPNP_VERSION` in capnp-import/build.rs, which builds this release from the
+// capstone subtree when no matching `capnp` executable is installed.
+const CAPNP_VERSION: &str = "2.0-fs";
+
+const RUST_CAPNP: &str = include_str!("../rust.capnp");
+
+/// A Cap'n Proto release version, such as `1.0.2` or `2.0-fs`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CompilerVersion {
+    pub major: u32,
+    pub minor: u32,
+
+    /// Zero when the version string leaves it out, as in `2.0-fs`.
+    pub micro: u32,
+
+    /// The part after the `-`, as in `fs` or `dev`, if any.
+    pub suffix: Option<String>,
+}
+
+impl FromStr for CompilerVersion {
+    type Err = ::capnp::Error;
+
+    /// Parses either a bare version or the output of `capnp --version`.
+    fn from_str(s: &str) -> ::capnp::Result<Self> {
+        let invalid = || ::capnp::Error::failed(format!("Invalid Cap'n Proto version `{s}`."));
+        let s = s.trim();
+        let version = s.strip_prefix("Cap'n Proto version ").unwrap_or(s);
+        let (numbers, suffix) = match version.split_once('-') {
+            Some((numbers, suffix)) => (numbers, Some(suffix.to_string())),
+            None => (version, None),
+        };
+        let mut numbers = numbers.split('.').map(|n| n.parse::<u32>());
+        let major = numbers.next().ok_or_else(invalid)?.map_err(|_| invalid())?;
+        let minor = numbers.next().ok_or_else(invalid)?.map_err(|_| invalid())?;
+        let micro = numbers.next().unwrap_or(Ok(0)).map_err(|_| invalid())?;
+        if numbers.next().is_some() {
+            return Err(invalid());
+        }
+        Ok(Self {
+            major,
+            minor,
+            micro,
+            suffix,
+        })
+    }
+}
+
+impl fmt::Display for CompilerVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)?;
+        if self.micro != 0 {
+            write!(f, ".{}", self.micro)?;
+        }
+        if let Some(suffix) = &self.suffix {
+            write!(f, "-{suffix}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the version of the Cap'n Proto compiler that this crate is developed against, which is
+/// the one that `capnp_import` builds when no matching `capnp` executable is installed.
+pub fn compiler_version() -> CompilerVersion {
+    CAPNP_VERSION
+        .parse()
+        .expect("CAPNP_VERSION is a valid version")
+}
+
+/// Returns the contents of a schema file that this crate ships, looked up by the path that
+/// schemas import it by, with or without the leading `/`.
+///
+/// Only `rust.capnp`, which declares the annotations understood by the code generator, is
+/// available. Files like `capnp/c++.capnp` come with the `capnp` executable instead.
+pub fn standard_import(name: &str) -> Option<&'static str> {
+    match name.strip_prefix('/').unwrap_or(name) {
+        "rust.capnp" => Some(RUST_CAPNP),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_compiler_version() {
+    let version = compiler_version();
+    assert_eq!(version.to_string(), CAPNP_VERSION);
+    assert_eq!(
+        "Cap'n Proto version 2.0-fs\n"
+            .parse::<CompilerVersion>()
+            .unwrap(),
+        version
+    );
+    assert_eq!(
+        "1.0.2".parse::<CompilerVersion>().unwrap(),
+        CompilerVersion {
+            major: 1,
+            minor: 0,
+            micro: 2,
+            suffix: None,
+        }
+    );
+    assert!("1".parse::<CompilerVersion>().is_err());
+    assert!("1.x".parse::<CompilerVersion>().is_err());
+    assert!("1.0.2.3".parse::<CompilerVersion>().is_err());
+
+    // The release that capnp-import builds from the capstone subtree.
+    let cmake = include_str!("../../capnp-import/capstone/c++/CMakeLists.txt");
+    assert!(cmake.contains(&format!("set(VERSION {CAPNP_VERSION})")));
+}
+
+#[test]
+fn test_standard_import() {
+    let rust_capnp = standard_import("/rust.capnp").unwrap();
+    assert_eq!(standard_import("rust.capnp"), Some(rust_capnp));
+    assert!(rust_capnp.contains(&format!(
+        "annotation name @{:#x}",
+        crate::codegen::NAME_ANNOTATION_ID
+    )));
+    assert_eq!(standard_import("capnp/c++.capnp"), None);
+}
@@ -0,0 +1,149 @@
+//! The incremental generation cache enabled by [`CompilerCommand::cache`](crate::CompilerCommand::cache).
+//!
+//! The cache file records a hash over the compiler options and the contents of every schema
+//! file that the previous run read, including imports, along with the paths of those files.
+//! A later run with the same options rehashes the same files, and skips the compiler if the
+//! hash still matches.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+use crate::convert_io_err;
+
+/// Name of the cache file, which lives in the output directory.
+pub(crate) const CACHE_FILE_NAME: &str = ".capnpc-cache.json";
+
+// Bumped whenever the meaning of the cache file changes.
+const CACHE_FORMAT_VERSION: u32 = 1;
+
+pub(crate) struct Cache {
+    pub hash: u64,
+    pub files: Vec<PathBuf>,
+}
+
+impl Cache {
+    /// Reads the cache file in `output_path`. Returns `None` if it is missing or malformed.
+    pub fn read(output_path: &Path) -> Option<Self> {
+        let text = std::fs::read_to_string(output_path.join(CACHE_FILE_NAME)).ok()?;
+        let mut parser = Parser {
+            rest: text.as_str(),
+        };
+        parser.expect("{")?;
+        parser.expect("\"hash\"")?;
+        parser.expect(":")?;
+        let hash = u64::from_str_radix(&parser.string()?, 16).ok()?;
+        parser.expect(",")?;
+        parser.expect("\"files\"")?;
+        parser.expect(":")?;
+        parser.expect("[")?;
+        let mut files = Vec::new();
+        if parser.expect("]").is_none() {
+            loop {
+                files.push(PathBuf::from(parser.string()?));
+                if parser.expect(",").is_none() {
+                    parser.expect("]")?;
+                    break;
+                }
+            }
+        }
+        parser.expect("}")?;
+        Some(Self { hash, files })
+    }
+
+    /// Writes the cache file into `output_path`.
+    pub fn write(&self, output_path: &Path) -> ::capnp::Result<()> {
+        let mut text = format!("{{\n  \"hash\": \"{:016x}\",\n  \"files\": [", self.hash);
+        for (index, file) in self.files.iter().enumerate() {
+            let Some(file) = file.to_str() else {
+                return Err(::capnp::Error::failed(format!(
+                    "Cannot cache non-UTF-8 path `{}`.",
+                    file.display()
+                )));
+            };
+            text.push_str(if index == 0 { "\n    " } else { ",\n    " });
+            push_json_string(&mut text, file);
+        }
+        text.push_str("\n  ]\n}\n");
+        std::fs::create_dir_all(output_path).map_err(convert_io_err)?;
+        std::fs::write(output_path.join(CACHE_FILE_NAME), text).map_err(convert_io_err)
+    }
+
+    /// Removes the cache file from `output_path`, if there is one.
+    pub fn remove(output_path: &Path) -> ::capnp::Result<()> {
+        match std::fs::remove_file(output_path.join(CACHE_FILE_NAME)) {
+            Err(error) if error.kind() != std::io::ErrorKind::NotFound => {
+                Err(convert_io_err(error))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Hashes `options` together with the paths and contents of `files`. Returns `None` if
+/// one of the files cannot be read, in which case nothing should be considered cached.
+pub(crate) fn hash_inputs(options: &str, files: &[PathBuf]) -> Option<u64> {
+    let mut hasher = DefaultHasher::new();
+    CACHE_FORMAT_VERSION.hash(&mut hasher);
+    env!("CARGO_PKG_VERSION").hash(&mut hasher);
+    options.hash(&mut hasher);
+    for file in files {
+        file.hash(&mut hasher);
+        std::fs::read(file).ok()?.hash(&mut hasher);
+    }
+    Some(hasher.finish())
+}
+
+fn push_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+// Parses just the JSON that `Cache::write()` produces.
+struct Parser<'a> {
+    rest: &'a str,
+}
+
+impl Parser<'_> {
+    fn expect(&mut self, token: &str) -> Option<()> {
+        self.rest = self.rest.trim_start().strip_prefix(token)?;
+        Some(())
+    }
+
+    fn string(&mut self) -> Option<String> {
+        self.expect("\"")?;
+        let mut result = String::new();
+        let mut chars = self.rest.char_indices();
+        while let Some((index, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.rest = &self.rest[index + 1..];
+                    return Some(result);
+                }
+                '\\' => match chars.next()?.1 {
+                    '"' => result.push('"'),
+                    '\\' => result.push('\\'),
+                    'u' => {
+                        let start = chars.next()?.0;
+                        for _ in 0..3 {
+                            chars.next()?;
+                        }
+                        let code = u32::from_str_radix(self.rest.get(start..start + 4)?, 16);
+                        result.push(char::from_u32(code.ok()?)?);
+                    }
+                    _ => return None,
+                },
+                c => result.push(c),
+            }
+        }
+        None
+    }
+}
@@ -19,9 +19,11 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
 // THE SOFTWARE.
 
+use std::cell::RefCell;
 use std::collections;
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::rc::Rc;
 
 use capnp;
 use capnp::schema_capnp::{self, type_};
@@ -29,8 +31,92 @@ use capnp::Error;
 
 use self::FormattedText::{BlankLine, Branch, Indent, Line};
 use crate::codegen_types::{do_branding, Leaf, RustNodeInfo, RustTypeInfo, TypeParameterTexts};
+use crate::convenience_builders::{
+    build_impl_for_list_type, generate_params_struct, generate_request_builders,
+    get_params_struct_path_string, vec_of_list_element_types, ParamsStructText,
+};
 use crate::convert_io_err;
 use crate::pointer_constants::generate_pointer_constant;
+use crate::provenance::Provenance;
+
+/// Naming scheme for the generated field accessors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AccessorStyle {
+    /// Getters are named `get_foo()`, matching the C++ API.
+    #[default]
+    CapnpGet,
+
+    /// Getters are named `foo()`, and builders also get chainable `with_foo(value)` setters.
+    /// Names that are Rust keywords or that clash with the methods every struct has get a
+    /// trailing underscore, so a field named `type` is read with `type_()`.
+    RustNaming,
+}
+
+/// Which generated functions are marked `#[inline]`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InlineHints {
+    /// Field accessors are marked: the getters of readers, builders and pipelines, setters,
+    /// `has_*()`, `which()`, `init_*()` and the like.
+    #[default]
+    Default,
+
+    /// Field accessors are marked, and so are the schema functions such as
+    /// `get_field_types()`.
+    All,
+
+    /// Nothing is marked, which can speed up debug builds.
+    None,
+}
+
+/// How the generated files are formatted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// As the code generator emits it, with two-space indentation and long lines.
+    #[default]
+    Raw,
+
+    /// Reformatted with the `prettyplease` crate. Requires the `prettyplease` feature.
+    #[cfg(feature = "prettyplease")]
+    PrettyPlease,
+}
+
+/// A function that returns extra items for the code generated for a struct, enum or
+/// interface. See [`CodeGenerationCommand::add_module_extension`].
+pub type ModuleExtension = dyn Fn(&GeneratorContext<'_>, u64) -> ::capnp::Result<Option<String>>;
+
+/// A schema file, identified either by its ID or by its path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SchemaFile {
+    /// The file with this ID.
+    Id(u64),
+
+    /// The file whose name, as reported by the schema compiler, ends with this path.
+    Path(PathBuf),
+}
+
+impl From<u64> for SchemaFile {
+    fn from(id: u64) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl From<PathBuf> for SchemaFile {
+    fn from(path: PathBuf) -> Self {
+        Self::Path(path)
+    }
+}
+
+impl From<&Path> for SchemaFile {
+    fn from(path: &Path) -> Self {
+        Self::Path(path.to_path_buf())
+    }
+}
+
+impl From<&str> for SchemaFile {
+    fn from(path: &str) -> Self {
+        Self::Path(PathBuf::from(path))
+    }
+}
 
 /// An invocation of the capnpc-rust code generation plugin.
 pub struct CodeGenerationCommand {
@@ -38,7 +124,21 @@ pub struct CodeGenerationCommand {
     default_parent_module: Vec<String>,
     raw_code_generator_request_path: Option<PathBuf>,
     capnp_root: String,
+    file_capnp_roots: Vec<(SchemaFile, String)>,
     crates_provide_map: HashMap<u64, String>,
+    generate_convenience_builders: bool,
+    no_alloc: bool,
+    lenient_enums: bool,
+    canonical_nans: bool,
+    accessor_style: AccessorStyle,
+    inline_hints: InlineHints,
+    split_output_threshold: Option<usize>,
+    skip_files: HashSet<u64>,
+    alias_source_dirs: Option<Vec<PathBuf>>,
+    file_cfgs: Vec<(SchemaFile, String)>,
+    format: Format,
+    module_extensions: Vec<Rc<ModuleExtension>>,
+    provenance: Option<Provenance>,
 }
 
 impl Default for CodeGenerationCommand {
@@ -48,7 +148,21 @@ impl Default for CodeGenerationCommand {
             default_parent_module: Vec::new(),
             raw_code_generator_request_path: None,
             capnp_root: "::capnp".into(),
+            file_capnp_roots: Vec::new(),
             crates_provide_map: HashMap::new(),
+            generate_convenience_builders: true,
+            no_alloc: false,
+            lenient_enums: false,
+            canonical_nans: false,
+            accessor_style: AccessorStyle::CapnpGet,
+            inline_hints: InlineHints::Default,
+            split_output_threshold: None,
+            skip_files: HashSet::new(),
+            alias_source_dirs: None,
+            file_cfgs: Vec::new(),
+            format: Format::Raw,
+            module_extensions: Vec::new(),
+            provenance: None,
         }
     }
 }
@@ -86,6 +200,19 @@ impl CodeGenerationCommand {
         self
     }
 
+    /// Overrides [`capnp_root`](Self::capnp_root) for the code generated for `file`, for
+    /// example when different schema files are built against different facades that re-export
+    /// the `capnp` crate. A path matches the files whose name, as reported by the schema
+    /// compiler, ends with it.
+    ///
+    /// Generation fails if a requested file refers to a type declared in a file with a
+    /// different root, since the generated types would not be compatible. The error lists
+    /// every such reference.
+    pub fn capnp_root_for_file(&mut self, file: impl Into<SchemaFile>, root: &str) -> &mut Self {
+        self.file_capnp_roots.push((file.into(), root.into()));
+        self
+    }
+
     /// Sets the raw code generator request output path.
     pub fn raw_code_generator_request_path<P>(&mut self, path: P) -> &mut Self
     where
@@ -108,20 +235,234 @@ impl CodeGenerationCommand {
         self
     }
 
+    /// Sets whether to generate the plain Rust params structs, their union enums, the
+    /// `TryFrom<Reader>` impls that read a message back into them, and the
+    /// `build_*_request()` client methods that consume them. Defaults to `true`.
+    ///
+    /// Each `build_foo_request()` also gets a `build_foo_request_with()` sibling, which takes
+    /// the same arguments plus a closure that can adjust the params builder afterwards, e.g.
+    /// to fill in fields that have no plain Rust representation. When the params struct has
+    /// fields annotated with `$Rust.required`, there is also a `build_foo_request_checked()`,
+    /// which fails if the arguments leave any of them null.
+    ///
+    /// Lists are sized with `capnp::private::checked_len()`, so a `Vec` with more elements than
+    /// a list can hold makes `build_capnp_struct()` and `build_foo_request_checked()` return a
+    /// `ListTooLong` error, and makes the other `build_foo_request*()` methods panic.
+    ///
+    /// For non-generic structs, the params structs can also be read from a
+    /// `dynamic_struct::Reader` with `TryFrom` and written into a `dynamic_struct::Builder`
+    /// with `to_dynamic()`.
+    ///
+    /// Disabling this leaves the Reader/Builder/Pipeline/Client code untouched and
+    /// substantially shrinks the generated files.
+    pub fn generate_convenience_builders(&mut self, value: bool) -> &mut Self {
+        self.generate_convenience_builders = value;
+        self
+    }
+
+    /// Sets whether the generated code must build without the `alloc` crate. Defaults to `false`.
+    ///
+    /// When enabled, the convenience builders are skipped and generation fails if the schema
+    /// declares interfaces or fields of interface type, since capabilities are heap-allocated.
+    /// The output then only depends on the parts of the `capnp` crate that are available with
+    /// `default-features = false`.
+    pub fn no_alloc(&mut self, value: bool) -> &mut Self {
+        self.no_alloc = value;
+        self
+    }
+
+    /// Sets whether to generate lenient accessors for enums. Defaults to `false`.
+    ///
+    /// When enabled, each enum `Foo` gets a companion `FooOrUnknown` type that also holds
+    /// enumerant values missing from the schema, and each enum field `foo` gets
+    /// `get_foo_lenient()` and `set_foo_lenient()` accessors that use it. The getters never
+    /// fail, and the setters write unknown values back unchanged, so messages from newer
+    /// schema versions round-trip. Union members only get the setter.
+    ///
+    /// The files declaring the enums must be generated with this option too.
+    pub fn lenient_enums(&mut self, value: bool) -> &mut Self {
+        self.lenient_enums = value;
+        self
+    }
+
+    /// Sets whether float setters write every NaN as the canonical quiet NaN. Defaults to
+    /// `false`.
+    ///
+    /// NaNs produced by different computations, platforms or compilers can carry different
+    /// payloads, so messages holding "the same" NaN would otherwise not encode identically.
+    /// With this option, the `set_*` methods of `Float32` and `Float64` fields store NaNs as
+    /// [`CANONICAL_NAN_F32_BITS`](capnp::private::mask::CANONICAL_NAN_F32_BITS) or
+    /// [`CANONICAL_NAN_F64_BITS`](capnp::private::mask::CANONICAL_NAN_F64_BITS) before
+    /// XOR-ing them with the field's default. Getters return the stored bits unchanged, and
+    /// lists are written as given.
+    ///
+    /// A NaN default is treated as the canonical NaN too, so setting any NaN on such a field
+    /// stores zero. This matches what other implementations do as long as the schema's NaN
+    /// default has the canonical bits, which is what the schema compiler produces for `nan`;
+    /// for a default with any other payload, non-NaN values would be masked differently.
+    pub fn canonical_nans(&mut self, value: bool) -> &mut Self {
+        self.canonical_nans = value;
+        self
+    }
+
+    /// Sets how field accessors are named. Defaults to [`AccessorStyle::CapnpGet`].
+    ///
+    /// Only getters and the new `with_*` setters are affected; `set_*`, `init_*` and `has_*`
+    /// keep their names in both styles. Code using the generated types must be written for
+    /// the chosen style.
+    pub fn accessor_style(&mut self, style: AccessorStyle) -> &mut Self {
+        self.accessor_style = style;
+        self
+    }
+
+    /// Sets which generated functions are marked `#[inline]`. Defaults to
+    /// [`InlineHints::Default`].
+    pub fn inline_hints(&mut self, hints: InlineHints) -> &mut Self {
+        self.inline_hints = hints;
+        self
+    }
+
+    /// Sets how the generated files are formatted. Defaults to [`Format::Raw`].
+    ///
+    /// With [`Format::PrettyPlease`], each file is parsed with `syn` and printed again with
+    /// `prettyplease`, which gives stable, rustfmt-like output that is easier to diff when
+    /// checked in. Comments other than doc comments are dropped, except for the header at the
+    /// top of the file. A file that cannot be parsed is written unformatted, with a warning on
+    /// stderr.
+    pub fn format_output(&mut self, format: Format) -> &mut Self {
+        self.format = format;
+        self
+    }
+
+    /// Records `provenance`, along with the crate version and the default parent module, in
+    /// the header of each generated file. See [`crate::provenance`].
+    pub fn provenance_header(&mut self, provenance: Provenance) -> &mut Self {
+        self.provenance = Some(provenance);
+        self
+    }
+
+    /// Adds a function that can append items to the code generated for each struct, enum and
+    /// interface, such as impls converting to types of the calling crate.
+    ///
+    /// `extension` is called with the ID of each such node, groups and the implicit param and
+    /// result structs of methods included, and its output is appended to the node's module,
+    /// after everything generated for it. Enums do not get a public module, so their
+    /// extensions follow the enum's own items in the enclosing module. Extensions run in the
+    /// order they were added, and generation fails if one of them returns an error.
+    ///
+    /// The [`GeneratorContext`] gives access to the schema, e.g. through
+    /// [`GeneratorContext::get_node`] and [`GeneratorContext::get_fields`], and to the module
+    /// paths of the generated code through [`GeneratorContext::get_qualified_module`]. The
+    /// Rust types of fields can be spelled with [`RustTypeInfo::type_string`].
+    pub fn add_module_extension(
+        &mut self,
+        extension: impl Fn(&GeneratorContext<'_>, u64) -> ::capnp::Result<Option<String>> + 'static,
+    ) -> &mut Self {
+        self.module_extensions.push(Rc::new(extension));
+        self
+    }
+
+    /// Splits generated files that would be larger than `threshold_bytes`.
+    ///
+    /// For such a schema file `foo.capnp`, each top-level struct or interface module `bar` is
+    /// written to `foo_capnp/bar.rs`, and `foo_capnp.rs` becomes a shim that pulls it back in
+    /// with `pub mod bar { include!("foo_capnp/bar.rs"); }`. Enums and constants stay in the
+    /// shim. Module paths are unchanged, so code using the generated types does not need to
+    /// change.
+    pub fn split_output(&mut self, threshold_bytes: usize) -> &mut Self {
+        self.split_output_threshold = Some(threshold_bytes);
+        self
+    }
+
+    /// Skips writing output for the requested files with the given IDs.
+    ///
+    /// This is meant for files that only declare annotations, such as a shared
+    /// `annotations.capnp` picked up along with the schemas that use it. Generated code only
+    /// refers to the module of an annotation if the annotation is generic, which a top-level
+    /// annotation can't be, so such files do not need to be generated or included. Generation
+    /// fails if a skipped file declares anything other than annotations.
+    pub fn skip_files(&mut self, ids: impl IntoIterator<Item = u64>) -> &mut Self {
+        self.skip_files.extend(ids);
+        self
+    }
+
+    /// Re-exports the `using` aliases declared at file scope and in structs and interfaces.
+    ///
+    /// Each alias becomes a `pub use` in the module generated for its scope, so that
+    /// `using Foo = import "bar.capnp".Baz;` in `foo.capnp` turns into
+    /// `pub use crate::bar_capnp::baz as foo;` in `foo_capnp`. Structs and interfaces are
+    /// re-exported by module and enums by type. Aliases of whole files, constants,
+    /// annotations, generic parameters and generic instances are left out.
+    ///
+    /// The compiler omits declarations of imported files that the schema does not otherwise
+    /// use. The module path of such an alias target is derived from the import's file name,
+    /// the default parent module and `crates_provide_map`, so `$Rust.name` and
+    /// `$Rust.parentModule` annotations in the imported file are not taken into account.
+    ///
+    /// The compiler does not pass aliases on to plugins, so they are read from the schema
+    /// sources instead: each requested file is looked up under `source_dirs`, in order, by
+    /// the file name in the request. Generation fails if a source cannot be found, or if an
+    /// alias would take the name of a nested declaration or of another alias.
+    pub fn reexport_aliases(
+        &mut self,
+        source_dirs: impl IntoIterator<Item = PathBuf>,
+    ) -> &mut Self {
+        self.alias_source_dirs = Some(source_dirs.into_iter().collect());
+        self
+    }
+
+    /// Only compiles the code generated for `file` if the `cfg` predicate holds, for example
+    /// `feature = "admin"`.
+    ///
+    /// The contents of the generated module are moved into a private module marked
+    /// `#[cfg(feature = "admin")]` and glob re-exported from there, so the module itself
+    /// always exists and can be brought in with `include!` or `capnp_import!` as usual, but is
+    /// empty unless the predicate holds. A path matches the files whose name, as reported by
+    /// the schema compiler, ends with it.
+    ///
+    /// Generation fails if a requested file refers to a type declared in `file` without being
+    /// gated by the same predicate, since its generated code would not build with the
+    /// predicate unset. The error lists every such reference.
+    pub fn cfg_for_file(
+        &mut self,
+        file: impl Into<SchemaFile>,
+        cfg: impl Into<String>,
+    ) -> &mut Self {
+        self.file_cfgs.push((file.into(), cfg.into()));
+        self
+    }
+
     /// Generates Rust code according to a `schema_capnp::code_generator_request` read from `inp`.
     pub fn run<T>(&mut self, inp: T) -> ::capnp::Result<()>
     where
         T: std::io::Read,
     {
         use capnp::serialize;
-        use std::io::Write;
 
         let message = serialize::read_message(inp, capnp::message::ReaderOptions::new())?;
 
-        let ctx = GeneratorContext::new_from_code_generation_command(self, &message)?;
+        let mut ctx = GeneratorContext::new_from_code_generation_command(self, &message)?;
+        let file_cfgs = resolve_file_settings(&ctx, &self.file_cfgs, |name, existing, cfg| {
+            format!("`{name}` is gated by both `cfg({existing})` and `cfg({cfg})`")
+        })?;
+        check_cfg_references(&ctx, &file_cfgs, &self.skip_files)?;
+        let file_capnp_roots =
+            resolve_file_settings(&ctx, &self.file_capnp_roots, |name, existing, root| {
+                format!("`{name}` is given both `{existing}` and `{root}` as its capnp root")
+            })?;
+        check_capnp_root_references(self, &ctx, &file_capnp_roots)?;
 
         for requested_file in ctx.request.get_requested_files()? {
             let id = requested_file.get_id();
+            if self.skip_files.contains(&id) {
+                check_annotations_only(&ctx, id)?;
+                continue;
+            }
+            let cfg = file_cfgs.get(&id).map(String::as_str);
+            ctx.capnp_root = file_capnp_roots
+                .get(&id)
+                .unwrap_or(&self.capnp_root)
+                .clone();
             let mut filepath = self.output_directory.to_path_buf();
             let requested = ::std::path::PathBuf::from(requested_file.get_filename()?.to_str()?);
             filepath.push(requested);
@@ -132,7 +473,7 @@ impl CodeGenerationCommand {
             let root_name = path_to_stem_string(&filepath)?.replace('-', "_");
             filepath.set_file_name(&format!("{root_name}_capnp.rs"));
 
-            let lines =
+            let header =
                 Branch(vec![
                 Line(
                     "// @generated by the capnpc-rust plugin to the Cap'n Proto schema compiler."
@@ -140,34 +481,84 @@ impl CodeGenerationCommand {
                 ),
                 line("// DO NOT EDIT."),
                 Line(format!("// source: {}", requested_file.get_filename()?.to_str()?)),
+                Branch(
+                    self.provenance
+                        .iter()
+                        .flat_map(|p| p.header_lines(&self.default_parent_module))
+                        .map(Line)
+                        .collect(),
+                ),
                 BlankLine,
-                generate_node(&ctx, id, &root_name, &mut String::new(), &mut String::new(), false)?,
             ]);
 
-            let text = stringify(&lines);
+            ctx.pointer_constant_words.borrow_mut().clear();
+            let lines = Branch(vec![
+                header.clone(),
+                gate_with_cfg(
+                    cfg,
+                    generate_node(
+                        &ctx,
+                        id,
+                        &root_name,
+                        &mut String::new(),
+                        &mut String::new(),
+                        false,
+                    )?,
+                ),
+            ]);
 
-            let previous_text = ::std::fs::read(&filepath);
-            if previous_text.is_ok() && previous_text.unwrap() == text.as_bytes() {
-                // File is unchanged. Do not write it so that builds with the
-                // output as part of the source work in read-only filesystems
-                // and so timestamp-based build systems and watchers do not get
-                // confused.
-                continue;
-            }
+            let text = stringify(&lines);
 
-            // It would be simpler to use the ? operator instead of a pattern match, but then the error message
-            // would not include `filepath`.
-            match ::std::fs::File::create(&filepath) {
-                Ok(mut writer) => {
-                    writer.write_all(text.as_bytes()).map_err(convert_io_err)?;
-                }
-                Err(e) => {
-                    let _ = writeln!(
-                        &mut ::std::io::stderr(),
-                        "could not open file {filepath:?} for writing: {e}"
-                    );
-                    return Err(convert_io_err(e));
+            match self.split_output_threshold {
+                Some(threshold) if text.len() > threshold => {
+                    let split_dir = filepath.with_file_name(format!("{root_name}_capnp"));
+                    ::std::fs::create_dir_all(&split_dir).map_err(convert_io_err)?;
+
+                    // Regenerating the nested nodes in the same order hoists each shared
+                    // constant array into the same module as in the unsplit output.
+                    ctx.pointer_constant_words.borrow_mut().clear();
+                    let mut shim = vec![generate_file_constants(&ctx, id)?];
+                    for nested_node in ctx.get_node(id, id)?.get_nested_nodes()? {
+                        let nested_id = nested_node.get_id();
+                        let node_text = generate_node(
+                            &ctx,
+                            nested_id,
+                            ctx.get_last_name(nested_id)?,
+                            &mut String::new(),
+                            &mut String::new(),
+                            false,
+                        )?;
+                        match split_module(&node_text) {
+                            Some((mod_line, mod_name, attributes, body)) => {
+                                let mut split_lines = vec![header.clone()];
+                                split_lines.extend(body);
+                                write_output(
+                                    self.format,
+                                    &split_dir.join(format!("{mod_name}.rs")),
+                                    &stringify(&Branch(split_lines)),
+                                )?;
+                                shim.push(BlankLine);
+                                shim.push(line(mod_line));
+                                shim.push(indent(attributes));
+                                shim.push(indent(Line(format!(
+                                    "include!(\"{root_name}_capnp/{mod_name}.rs\");"
+                                ))));
+                                shim.push(line("}"));
+                            }
+                            None => shim.push(node_text),
+                        }
+                    }
+                    if let Some(reexports) = ctx.alias_reexports.get(&id) {
+                        shim.push(BlankLine);
+                        shim.push(Branch(reexports.clone()));
+                    }
+                    write_output(
+                        self.format,
+                        &filepath,
+                        &stringify(&Branch(vec![header, gate_with_cfg(cfg, Branch(shim))])),
+                    )?;
                 }
+                _ => write_output(self.format, &filepath, &text)?,
             }
         }
 
@@ -184,354 +575,1354 @@ impl CodeGenerationCommand {
     }
 }
 
-pub struct GeneratorContext<'a> {
-    pub request: schema_capnp::code_generator_request::Reader<'a>,
-    pub node_map: collections::hash_map::HashMap<u64, schema_capnp::node::Reader<'a>>,
-    pub scope_map: collections::hash_map::HashMap<u64, Vec<String>>,
-
-    /// Map from node ID to the node ID of its parent scope. This is equal to node.scope_id
-    /// for all nodes except for autogenerated interface Param and Result structs;
-    /// those have scope_id set to 0. See the comment on paramStructType in schema.capnp.
-    pub node_parents: collections::hash_map::HashMap<u64, u64>,
-
-    /// Root path for referencing things in the `capnp` crate from the generated code.
-    pub capnp_root: String,
+// Maps the IDs of the files in the request to the values set for them with a per-file
+// option such as `cfg_for_file()`. Returns an error, described by `conflict(name, existing,
+// value)`, if a file is given two different values.
+fn resolve_file_settings(
+    ctx: &GeneratorContext,
+    file_settings: &[(SchemaFile, String)],
+    conflict: impl Fn(&str, &str, &str) -> String,
+) -> ::capnp::Result<HashMap<u64, String>> {
+    let mut result: HashMap<u64, String> = HashMap::new();
+    for node in ctx.node_map.values() {
+        if !matches!(node.which()?, schema_capnp::node::File(())) {
+            continue;
+        }
+        let name = node.get_display_name()?.to_str()?;
+        for (file, value) in file_settings {
+            let matches = match file {
+                SchemaFile::Id(id) => *id == node.get_id(),
+                SchemaFile::Path(path) => Path::new(name).ends_with(path),
+            };
+            if !matches {
+                continue;
+            }
+            match result.get(&node.get_id()) {
+                Some(existing) if existing != value => {
+                    return Err(Error::failed(conflict(name, existing, value)));
+                }
+                _ => {
+                    result.insert(node.get_id(), value.clone());
+                }
+            }
+        }
+    }
+    Ok(result)
 }
 
-impl<'a> GeneratorContext<'a> {
-    pub fn new(
-        message: &'a capnp::message::Reader<capnp::serialize::OwnedSegments>,
-    ) -> ::capnp::Result<GeneratorContext<'a>> {
-        GeneratorContext::new_from_code_generation_command(&Default::default(), message)
+// Returns the ID of the file that declares the node `id`.
+fn file_of_node(ctx: &GeneratorContext, mut id: u64) -> u64 {
+    while let Some(&parent) = ctx.node_parents.get(&id) {
+        if parent == 0 {
+            break;
+        }
+        id = parent;
     }
+    id
+}
 
-    fn new_from_code_generation_command(
-        code_generation_command: &CodeGenerationCommand,
-        message: &'a capnp::message::Reader<capnp::serialize::OwnedSegments>,
-    ) -> ::capnp::Result<GeneratorContext<'a>> {
-        let mut default_parent_module_scope = vec!["crate".to_string()];
-        default_parent_module_scope
-            .extend_from_slice(&code_generation_command.default_parent_module[..]);
-
-        let mut ctx = GeneratorContext {
-            request: message.get_root()?,
-            node_map: collections::hash_map::HashMap::<u64, schema_capnp::node::Reader<'a>>::new(),
-            scope_map: collections::hash_map::HashMap::<u64, Vec<String>>::new(),
-            node_parents: collections::hash_map::HashMap::new(),
-            capnp_root: code_generation_command.capnp_root.clone(),
-        };
-
-        let crates_provide = &code_generation_command.crates_provide_map;
-
-        for node in ctx.request.get_nodes()? {
-            ctx.node_map.insert(node.get_id(), node);
-            ctx.node_parents.insert(node.get_id(), node.get_scope_id());
+// Adds the IDs of the structs, enums and interfaces that `typ` refers to, brand bindings
+// included, to `ids`.
+fn collect_type_references(typ: type_::Reader, ids: &mut Vec<u64>) -> ::capnp::Result<()> {
+    let brand = match typ.which()? {
+        type_::List(list) => return collect_type_references(list.get_element_type()?, ids),
+        type_::Enum(e) => {
+            ids.push(e.get_type_id());
+            e.get_brand()?
+        }
+        type_::Struct(s) => {
+            ids.push(s.get_type_id());
+            s.get_brand()?
         }
+        type_::Interface(i) => {
+            ids.push(i.get_type_id());
+            i.get_brand()?
+        }
+        _ => return Ok(()),
+    };
+    collect_brand_references(brand, ids)
+}
 
-        // Fix up "anonymous" method params and results scopes.
-        for node in ctx.request.get_nodes()? {
-            if let Ok(schema_capnp::node::Interface(interface_reader)) = node.which() {
-                for method in interface_reader.get_methods()? {
-                    let param_struct_type = method.get_param_struct_type();
-                    if ctx.node_parents.get(&param_struct_type) == Some(&0) {
-                        ctx.node_parents.insert(param_struct_type, node.get_id());
-                    }
-                    let result_struct_type = method.get_result_struct_type();
-                    if ctx.node_parents.get(&result_struct_type) == Some(&0) {
-                        ctx.node_parents.insert(result_struct_type, node.get_id());
-                    }
+fn collect_brand_references(
+    brand: schema_capnp::brand::Reader,
+    ids: &mut Vec<u64>,
+) -> ::capnp::Result<()> {
+    for scope in brand.get_scopes()? {
+        if let schema_capnp::brand::scope::Bind(bindings) = scope.which()? {
+            for binding in bindings? {
+                if let schema_capnp::brand::binding::Type(typ) = binding.which()? {
+                    collect_type_references(typ?, ids)?;
                 }
             }
         }
+    }
+    Ok(())
+}
 
-        for requested_file in ctx.request.get_requested_files()? {
-            let id = requested_file.get_id();
-
-            for import in requested_file.get_imports()? {
-                let importpath = ::std::path::Path::new(import.get_name()?.to_str()?);
-                let root_name: String = format!(
-                    "{}_capnp",
-                    path_to_stem_string(importpath)?.replace('-', "_")
-                );
-                let parent_module_scope = if let Some(krate) = crates_provide.get(&import.get_id())
-                {
-                    vec![format!("::{krate}")]
-                } else {
-                    default_parent_module_scope.clone()
-                };
-
-                ctx.populate_scope_map(
-                    parent_module_scope,
-                    root_name,
-                    NameKind::Verbatim,
-                    import.get_id(),
-                )?;
+// Returns `(member, referenced ID)` for every type that the node `node` refers to, where
+// `member` names the field, method or superclass the reference comes from.
+fn node_type_references(node: schema_capnp::node::Reader) -> ::capnp::Result<Vec<(String, u64)>> {
+    let mut result = Vec::new();
+    match node.which()? {
+        schema_capnp::node::Struct(st) => {
+            for field in st.get_fields()? {
+                if let schema_capnp::field::Slot(slot) = field.which()? {
+                    let mut ids = Vec::new();
+                    collect_type_references(slot.get_type()?, &mut ids)?;
+                    let name = field.get_name()?.to_string()?;
+                    result.extend(ids.into_iter().map(|id| (name.clone(), id)));
+                }
             }
-
-            let root_name = path_to_stem_string(requested_file.get_filename()?.to_str()?)?;
-            let root_mod = format!("{}_capnp", root_name.replace('-', "_"));
-            ctx.populate_scope_map(
-                default_parent_module_scope.clone(),
-                root_mod,
-                NameKind::Verbatim,
-                id,
-            )?;
         }
-        Ok(ctx)
+        schema_capnp::node::Interface(interface) => {
+            for superclass in interface.get_superclasses()? {
+                result.push(("(superclass)".to_string(), superclass.get_id()));
+                let mut ids = Vec::new();
+                collect_brand_references(superclass.get_brand()?, &mut ids)?;
+                result.extend(ids.into_iter().map(|id| ("(superclass)".to_string(), id)));
+            }
+            for method in interface.get_methods()? {
+                let name = method.get_name()?.to_string()?;
+                let mut ids = vec![
+                    method.get_param_struct_type(),
+                    method.get_result_struct_type(),
+                ];
+                collect_brand_references(method.get_param_brand()?, &mut ids)?;
+                collect_brand_references(method.get_result_brand()?, &mut ids)?;
+                result.extend(ids.into_iter().map(|id| (name.clone(), id)));
+            }
+        }
+        schema_capnp::node::Const(c) => {
+            let mut ids = Vec::new();
+            collect_type_references(c.get_type()?, &mut ids)?;
+            result.extend(ids.into_iter().map(|id| ("(type)".to_string(), id)));
+        }
+        _ => {}
     }
+    Ok(result)
+}
 
-    fn get_last_name(&self, id: u64) -> ::capnp::Result<&str> {
-        match self.scope_map.get(&id) {
-            None => Err(Error::failed(format!("node not found: {id}"))),
-            Some(v) => match v.last() {
-                None => Err(Error::failed(format!("node has no scope: {id}"))),
-                Some(n) => Ok(n),
-            },
+// Returns an error listing every reference from a generated file to a type declared in a
+// file that is gated by a cfg predicate the referring file is not gated by.
+fn check_cfg_references(
+    ctx: &GeneratorContext,
+    file_cfgs: &HashMap<u64, String>,
+    skip_files: &HashSet<u64>,
+) -> ::capnp::Result<()> {
+    if file_cfgs.is_empty() {
+        return Ok(());
+    }
+    let mut offending = Vec::new();
+    for requested_file in ctx.request.get_requested_files()? {
+        let file_id = requested_file.get_id();
+        if skip_files.contains(&file_id) {
+            continue;
+        }
+        let file_cfg = file_cfgs.get(&file_id);
+        for id in file_node_ids(ctx, file_id) {
+            let node = ctx.get_node(id, file_id)?;
+            for (member, target) in node_type_references(node)? {
+                let target_file = file_of_node(ctx, target);
+                let Some(target_cfg) = file_cfgs.get(&target_file) else {
+                    continue;
+                };
+                if file_cfg == Some(target_cfg) {
+                    continue;
+                }
+                let target_name = match ctx.node_map.get(&target) {
+                    Some(target) => target.get_display_name()?.to_str()?.to_string(),
+                    None => format!("{target:#018x}"),
+                };
+                offending.push(format!(
+                    "  `{}.{member}` refers to `{target_name}`, which is gated by `cfg({target_cfg})`",
+                    node.get_display_name()?.to_str()?,
+                ));
+            }
         }
     }
+    if offending.is_empty() {
+        return Ok(());
+    }
+    offending.sort();
+    offending.dedup();
+    Err(Error::failed(format!(
+        "generated code refers to types that are only compiled under a cfg predicate it is \
+         not gated by:\n{}",
+        offending.join("\n")
+    )))
+}
 
-    fn populate_scope_map(
-        &mut self,
-        mut ancestor_scope_names: Vec<String>,
-        mut current_node_name: String,
-        current_name_kind: NameKind,
-        node_id: u64,
-    ) -> ::capnp::Result<()> {
-        // unused nodes in imported files might be omitted from the node map
-        let Some(&node_reader) = self.node_map.get(&node_id) else {
-            return Ok(());
-        };
-
-        for annotation in node_reader.get_annotations()? {
-            if annotation.get_id() == NAME_ANNOTATION_ID {
-                current_node_name = name_annotation_value(annotation)?.to_string();
-            } else if annotation.get_id() == PARENT_MODULE_ANNOTATION_ID {
-                let head = ancestor_scope_names[0].clone();
-                ancestor_scope_names.clear();
-                ancestor_scope_names.push(head);
-                ancestor_scope_names.append(&mut get_parent_module(annotation)?);
+// Returns an error listing every reference from a generated file to a type declared in a
+// file whose generated code uses a different capnp root. Files provided by other crates are
+// left out, since their root is up to the crate that generates them.
+fn check_capnp_root_references(
+    command: &CodeGenerationCommand,
+    ctx: &GeneratorContext,
+    file_capnp_roots: &HashMap<u64, String>,
+) -> ::capnp::Result<()> {
+    if file_capnp_roots.is_empty() {
+        return Ok(());
+    }
+    let root_of = |file_id| {
+        file_capnp_roots
+            .get(&file_id)
+            .unwrap_or(&command.capnp_root)
+    };
+    let mut offending = Vec::new();
+    for requested_file in ctx.request.get_requested_files()? {
+        let file_id = requested_file.get_id();
+        if command.skip_files.contains(&file_id) {
+            continue;
+        }
+        let file_root = root_of(file_id);
+        for id in file_node_ids(ctx, file_id) {
+            let node = ctx.get_node(id, file_id)?;
+            for (member, target) in node_type_references(node)? {
+                let target_file = file_of_node(ctx, target);
+                if command.crates_provide_map.contains_key(&target_file) {
+                    continue;
+                }
+                let target_root = root_of(target_file);
+                if target_root == file_root {
+                    continue;
+                }
+                let target_name = match ctx.node_map.get(&target) {
+                    Some(target) => target.get_display_name()?.to_str()?.to_string(),
+                    None => format!("{target:#018x}"),
+                };
+                offending.push(format!(
+                    "  `{}.{member}` (capnp root `{file_root}`) refers to `{target_name}` \
+                     (capnp root `{target_root}`)",
+                    node.get_display_name()?.to_str()?,
+                ));
             }
         }
+    }
+    if offending.is_empty() {
+        return Ok(());
+    }
+    offending.sort();
+    offending.dedup();
+    Err(Error::failed(format!(
+        "generated code refers to types generated against a different capnp root:\n{}",
+        offending.join("\n")
+    )))
+}
 
-        let mut scope_names = ancestor_scope_names;
-        scope_names.push(capnp_name_to_rust_name(
-            &current_node_name,
-            current_name_kind,
-        ));
+// Moves `body` into a private module that only exists if the `cfg` predicate holds, and
+// re-exports its contents.
+fn gate_with_cfg(cfg: Option<&str>, body: FormattedText) -> FormattedText {
+    match cfg {
+        None => body,
+        Some(cfg) => Branch(vec![
+            Line(format!("#[cfg({cfg})]")),
+            line("mod _cfg_gated {"),
+            indent(body),
+            line("}"),
+            Line(format!("#[cfg({cfg})]")),
+            line("pub use self::_cfg_gated::*;"),
+        ]),
+    }
+}
 
-        self.scope_map.insert(node_id, scope_names.clone());
+// Returns an error unless the file `file_id` declares nothing but annotations.
+fn check_annotations_only(ctx: &GeneratorContext, file_id: u64) -> ::capnp::Result<()> {
+    let file = ctx.get_node(file_id, file_id)?;
+    for nested_node in file.get_nested_nodes()? {
+        let node = ctx.get_node(nested_node.get_id(), file_id)?;
+        if !matches!(node.which()?, schema_capnp::node::Annotation(_)) {
+            return Err(Error::failed(format!(
+                "cannot skip `{}`: it declares `{}`, which is not an annotation",
+                file.get_display_name()?.to_str()?,
+                node.get_display_name()?.to_str()?
+            )));
+        }
+    }
+    Ok(())
+}
 
-        let nested_nodes = node_reader.get_nested_nodes()?;
-        for nested_node in nested_nodes {
-            let nested_node_id = nested_node.get_id();
-            match self.node_map.get(&nested_node_id) {
-                None => {}
-                Some(node_reader) => match node_reader.which() {
-                    Ok(schema_capnp::node::Enum(_enum_reader)) => {
-                        self.populate_scope_map(
-                            scope_names.clone(),
-                            nested_node.get_name()?.to_string()?,
-                            NameKind::Verbatim,
-                            nested_node_id,
-                        )?;
-                    }
-                    _ => {
-                        self.populate_scope_map(
-                            scope_names.clone(),
-                            nested_node.get_name()?.to_string()?,
-                            NameKind::Module,
-                            nested_node_id,
-                        )?;
-                    }
-                },
-            }
+// What a `using` alias refers to.
+enum AliasTarget {
+    // A node in the request.
+    Node(u64),
+
+    // A declaration of an imported file that the compiler left out of the request because
+    // nothing else uses it: the module path of its file, and the names leading to it.
+    Unlisted(String, Vec<String>),
+}
+
+// Builds `GeneratorContext::alias_reexports`. See `CodeGenerationCommand::reexport_aliases()`.
+fn alias_reexports(
+    ctx: &GeneratorContext,
+    command: &CodeGenerationCommand,
+    source_dirs: &[PathBuf],
+) -> ::capnp::Result<HashMap<u64, Vec<FormattedText>>> {
+    let mut result: HashMap<u64, Vec<FormattedText>> = HashMap::new();
+    for requested_file in ctx.request.get_requested_files()? {
+        let file_id = requested_file.get_id();
+        if command.skip_files.contains(&file_id) {
+            continue;
         }
+        let filename = requested_file.get_filename()?.to_str()?;
+        let Some(source) = source_dirs
+            .iter()
+            .find_map(|dir| std::fs::read_to_string(dir.join(filename)).ok())
+        else {
+            return Err(Error::failed(format!(
+                "cannot re-export the aliases of `{filename}`: it is not in any of {source_dirs:?}"
+            )));
+        };
+        let aliases = crate::aliases::parse(&source);
 
-        if let Ok(schema_capnp::node::Struct(struct_reader)) = node_reader.which() {
-            let fields = struct_reader.get_fields()?;
-            for field in fields {
-                if let Ok(schema_capnp::field::Group(group)) = field.which() {
-                    self.populate_scope_map(
-                        scope_names.clone(),
-                        get_field_name(field)?.to_string(),
-                        NameKind::Module,
-                        group.get_type_id(),
-                    )?;
+        // For each scope, the Rust names already taken in its module, and by what.
+        let mut taken: HashMap<u64, HashMap<String, String>> = HashMap::new();
+        for alias in &aliases {
+            let Some(scope_id) = alias_scope(ctx, file_id, &alias.scope)? else {
+                continue;
+            };
+            let (rust_name, path) =
+                match resolve_alias(ctx, command, requested_file, &aliases, alias, 0)? {
+                    Some(AliasTarget::Node(id)) => match ctx.node_map[&id].which()? {
+                        schema_capnp::node::Struct(_) | schema_capnp::node::Interface(_) => {
+                            (module_name(&alias.name), ctx.get_qualified_module(id))
+                        }
+                        schema_capnp::node::Enum(_) => {
+                            (alias.name.clone(), ctx.get_qualified_module(id))
+                        }
+                        _ => continue,
+                    },
+                    Some(AliasTarget::Unlisted(file_module, names)) if !names.is_empty() => {
+                        let mut path = file_module;
+                        for name in &names {
+                            path.push_str("::");
+                            path.push_str(&module_name(name));
+                        }
+                        (module_name(&alias.name), path)
+                    }
+                    _ => continue,
+                };
+
+            let names = match taken.entry(scope_id) {
+                collections::hash_map::Entry::Occupied(entry) => entry.into_mut(),
+                collections::hash_map::Entry::Vacant(entry) => {
+                    entry.insert(scope_item_names(ctx, scope_id)?)
                 }
+            };
+            let description = format!("alias `{}` at {filename}:{}", alias.name, alias.line);
+            if let Some(other) = names.get(&rust_name) {
+                return Err(Error::failed(format!(
+                    "{description} conflicts with {other}: both are named `{rust_name}` in the \
+                     generated code"
+                )));
             }
+            result
+                .entry(scope_id)
+                .or_default()
+                .push(Line(format!("pub use {path} as {rust_name};")));
+            names.insert(rust_name, description);
         }
-
-        Ok(())
-    }
-
-    pub fn get_qualified_module(&self, type_id: u64) -> String {
-        self.scope_map[&type_id].join("::")
     }
+    Ok(result)
 }
 
-/// Like `format!(...)`, but adds a `capnp=ctx.capnp_root` argument.
-macro_rules! fmt(
-    ($ctx:ident, $($arg:tt)*) => ( format!($($arg)*, capnp=$ctx.capnp_root) )
-);
-
-pub(crate) use fmt;
-
-fn path_to_stem_string<P: AsRef<::std::path::Path>>(path: P) -> ::capnp::Result<String> {
-    match path.as_ref().file_stem() {
-        None => Err(Error::failed(format!(
-            "file has no stem: {:?}",
-            path.as_ref()
-        ))),
-        Some(stem) => match stem.to_owned().into_string() {
-            Err(os_string) => Err(Error::failed(format!("bad filename: {os_string:?}"))),
-            Ok(s) => Ok(s),
-        },
+// Returns the ID of the struct or interface that the names in `scope` lead to from the file
+// `file_id`, or the file itself if `scope` is empty.
+fn alias_scope(
+    ctx: &GeneratorContext,
+    file_id: u64,
+    scope: &[String],
+) -> ::capnp::Result<Option<u64>> {
+    let mut id = file_id;
+    for name in scope {
+        let Some(nested_id) = nested_node_id(ctx, id, name)? else {
+            return Ok(None);
+        };
+        id = nested_id;
     }
+    Ok(Some(id))
 }
 
-fn snake_to_upper_case(s: &str) -> String {
-    let mut result_chars: Vec<char> = Vec::new();
-    for c in s.chars() {
-        if c == '_' {
-            result_chars.push('_');
-        } else {
-            result_chars.push(c.to_ascii_uppercase());
+// Returns the ID of the declaration `name` nested in the node `id`.
+fn nested_node_id(ctx: &GeneratorContext, id: u64, name: &str) -> ::capnp::Result<Option<u64>> {
+    let Some(node) = ctx.node_map.get(&id) else {
+        return Ok(None);
+    };
+    for nested_node in node.get_nested_nodes()? {
+        if nested_node.get_name()?.to_str()? == name {
+            return Ok(Some(nested_node.get_id()));
         }
     }
-    result_chars.into_iter().collect()
+    Ok(None)
 }
 
-fn snake_to_camel_case(s: &str) -> String {
-    let mut result_chars: Vec<char> = Vec::new();
-    let mut capitalize = true;
-    for c in s.chars() {
-        if capitalize {
-            result_chars.push(c.to_ascii_uppercase());
-            capitalize = false;
-        } else if c == '_' {
-            capitalize = true;
+// Looks up the target of `alias` the way the schema compiler does: the first name of the
+// path is searched for in the scope of the alias and then in each enclosing scope, among
+// both nested declarations and other aliases. Returns `None` for builtin types, generic
+// parameters and anything else that is not a declaration.
+fn resolve_alias(
+    ctx: &GeneratorContext,
+    command: &CodeGenerationCommand,
+    requested_file: schema_capnp::code_generator_request::requested_file::Reader,
+    aliases: &[crate::aliases::Alias],
+    alias: &crate::aliases::Alias,
+    depth: usize,
+) -> ::capnp::Result<Option<AliasTarget>> {
+    // Aliases of aliases that go around in circles are rejected by the compiler, but don't
+    // rely on it.
+    if depth > aliases.len() {
+        return Ok(None);
+    }
+
+    let file_id = requested_file.get_id();
+    let mut names = &alias.path[..];
+    let mut target = if let Some(import_name) = &alias.import {
+        let Some(import) = requested_file.get_imports()?.iter().find(|import| {
+            import.get_name().ok().and_then(|n| n.to_str().ok()) == Some(import_name)
+        }) else {
+            return Ok(None);
+        };
+        let import_id = import.get_id();
+        if ctx.node_map.contains_key(&import_id) {
+            AliasTarget::Node(import_id)
         } else {
-            result_chars.push(c);
+            let stem = path_to_stem_string(Path::new(import_name))?.replace('-', "_");
+            let parent = match command.crates_provide_map.get(&import_id) {
+                Some(krate) => format!("::{krate}"),
+                None => {
+                    let mut parent = vec!["crate".to_string()];
+                    parent.extend_from_slice(&command.default_parent_module);
+                    parent.join("::")
+                }
+            };
+            AliasTarget::Unlisted(format!("{parent}::{stem}_capnp"), Vec::new())
+        }
+    } else if alias.absolute {
+        AliasTarget::Node(file_id)
+    } else {
+        let Some((first, rest)) = names.split_first() else {
+            return Ok(None);
+        };
+        names = rest;
+        let mut found = None;
+        for depth_of_scope in (0..=alias.scope.len()).rev() {
+            let scope = &alias.scope[..depth_of_scope];
+            if let Some(scope_id) = alias_scope(ctx, file_id, scope)? {
+                if let Some(id) = nested_node_id(ctx, scope_id, first)? {
+                    found = Some(AliasTarget::Node(id));
+                    break;
+                }
+            }
+            if let Some(other) = aliases
+                .iter()
+                .find(|other| other.scope == scope && &other.name == first && *other != alias)
+            {
+                found = resolve_alias(ctx, command, requested_file, aliases, other, depth + 1)?;
+                break;
+            }
         }
+        let Some(found) = found else {
+            return Ok(None);
+        };
+        found
+    };
+
+    for name in names {
+        target = match target {
+            AliasTarget::Node(id) => match nested_node_id(ctx, id, name)? {
+                Some(nested_id) if ctx.node_map.contains_key(&nested_id) => {
+                    AliasTarget::Node(nested_id)
+                }
+                Some(_) => AliasTarget::Unlisted(ctx.get_qualified_module(id), vec![name.clone()]),
+                None => return Ok(None),
+            },
+            AliasTarget::Unlisted(file_module, mut path) => {
+                path.push(name.clone());
+                AliasTarget::Unlisted(file_module, path)
+            }
+        };
     }
-    result_chars.into_iter().collect()
+    Ok(Some(target))
 }
 
-fn camel_to_snake_case(s: &str) -> String {
-    let mut result_chars: Vec<char> = Vec::new();
-    let mut first_char = true;
-    for c in s.chars() {
-        if c.is_uppercase() && !first_char {
-            result_chars.push('_');
+// Returns the Rust names of the items generated for the declarations and groups in the
+// scope `id`, along with a description of each for error messages.
+fn scope_item_names(ctx: &GeneratorContext, id: u64) -> ::capnp::Result<HashMap<String, String>> {
+    let mut names = HashMap::new();
+    let node = ctx.get_node(id, id)?;
+    for nested_node in node.get_nested_nodes()? {
+        let nested_id = nested_node.get_id();
+        if let Some(scope) = ctx.scope_map.get(&nested_id) {
+            names.insert(
+                scope[scope.len() - 1].clone(),
+                format!("`{}`", ctx.display_names[&nested_id]),
+            );
         }
-        result_chars.push(c.to_ascii_lowercase());
-        first_char = false;
     }
-    result_chars.into_iter().collect()
+    if let schema_capnp::node::Struct(struct_reader) = node.which()? {
+        for field in struct_reader.get_fields()? {
+            if let schema_capnp::field::Group(group) = field.which()? {
+                if let Some(scope) = ctx.scope_map.get(&group.get_type_id()) {
+                    names.insert(
+                        scope[scope.len() - 1].clone(),
+                        format!("group `{}`", ctx.display_names[&group.get_type_id()]),
+                    );
+                }
+            }
+        }
+        for item in STRUCT_MODULE_ITEMS {
+            names.insert(item.to_string(), format!("the generated `{item}`"));
+        }
+    }
+    Ok(names)
 }
 
-fn capitalize_first_letter(s: &str) -> String {
-    let mut result_chars: Vec<char> = Vec::new();
-    for c in s.chars() {
-        result_chars.push(c)
+// Writes `text` to `filepath`, unless the file already has exactly that content.
+// Formats `text` according to `format` and writes it to `filepath`.
+fn write_output(format: Format, filepath: &Path, text: &str) -> ::capnp::Result<()> {
+    match format {
+        Format::Raw => write_if_changed(filepath, text),
+        #[cfg(feature = "prettyplease")]
+        Format::PrettyPlease => write_if_changed(filepath, &pretty_print(filepath, text)),
     }
-    result_chars[0] = result_chars[0].to_ascii_uppercase();
-    result_chars.into_iter().collect()
 }
 
-/// Formats a u64 into a string representation of the hex value, with
-/// separating underscores. Used instead of simple hex formatting to prevent
-/// clippy warnings in autogenerated code. This is loosely based off of
-/// similar functionality in the `separator` crate.
-fn format_u64(value: u64) -> String {
-    let hex = format!("{value:#x}");
-    let mut separated = hex[0..2].to_string();
-    let mut place = hex.len() - 2;
-    let mut later_loop = false;
+// Reformats `text` with `prettyplease`, keeping the comment lines at its top, which the
+// parser would otherwise drop. Returns `text` unchanged if it does not parse.
+#[cfg(feature = "prettyplease")]
+fn pretty_print(filepath: &Path, text: &str) -> String {
+    use std::io::Write;
 
-    for ch in hex[2..].chars() {
-        if later_loop && place % 4 == 0 {
-            separated.push('_');
+    let mut header_len = 0;
+    for line in text.split_inclusive('\n') {
+        if !line.starts_with("//") && !line.trim().is_empty() {
+            break;
+        }
+        header_len += line.len();
+    }
+    let (header, body) = text.split_at(header_len);
+    match syn::parse_file(body) {
+        Ok(file) => format!("{header}{}", prettyplease::unparse(&file)),
+        Err(e) => {
+            let _ = writeln!(
+                &mut ::std::io::stderr(),
+                "warning: could not format {filepath:?}, writing it unformatted: {e}"
+            );
+            text.to_string()
         }
-
-        separated.push(ch);
-        later_loop = true;
-        place -= 1;
     }
-
-    separated
 }
 
+#[cfg(feature = "prettyplease")]
 #[test]
-fn test_camel_to_snake_case() {
-    assert_eq!(camel_to_snake_case("fooBar"), "foo_bar".to_string());
-    assert_eq!(camel_to_snake_case("FooBar"), "foo_bar".to_string());
-    assert_eq!(camel_to_snake_case("fooBarBaz"), "foo_bar_baz".to_string());
-    assert_eq!(camel_to_snake_case("FooBarBaz"), "foo_bar_baz".to_string());
-    assert_eq!(camel_to_snake_case("helloWorld"), "hello_world".to_string());
-    assert_eq!(camel_to_snake_case("HelloWorld"), "hello_world".to_string());
-    assert_eq!(camel_to_snake_case("uint32Id"), "uint32_id".to_string());
+fn test_pretty_print() {
+    let path = Path::new("foo_capnp.rs");
+    let raw = "// @generated\n// source: foo.capnp\n\npub mod foo {\n  #[inline]\n  pub fn get(self) -> ::capnp::Result<u32> { ::core::result::Result::Ok(self.reader.get_data_field::<u32>(0)) }\n}\n";
+    let formatted = pretty_print(path, raw);
+    assert!(
+        formatted
+            .starts_with("// @generated\n// source: foo.capnp\n\npub mod foo {\n    #[inline]\n"),
+        "{formatted}"
+    );
+    assert_eq!(pretty_print(path, &formatted), formatted);
 
-    assert_eq!(camel_to_snake_case("fooBar_"), "foo_bar_".to_string());
+    let unparsable = "// @generated\n\npub mod foo {\n";
+    assert_eq!(pretty_print(path, unparsable), unparsable);
 }
 
-#[derive(PartialEq, Clone)]
-pub enum FormattedText {
-    Indent(Box<FormattedText>),
-    Branch(Vec<FormattedText>),
-    Line(String),
-    BlankLine,
-}
+fn write_if_changed(filepath: &Path, text: &str) -> ::capnp::Result<()> {
+    use std::io::Write;
 
-impl From<Vec<FormattedText>> for FormattedText {
-    fn from(value: Vec<FormattedText>) -> Self {
-        Branch(value)
+    let previous_text = ::std::fs::read(filepath);
+    if previous_text.is_ok() && previous_text.unwrap() == text.as_bytes() {
+        // File is unchanged. Do not write it so that builds with the
+        // output as part of the source work in read-only filesystems
+        // and so timestamp-based build systems and watchers do not get
+        // confused.
+        return Ok(());
     }
-}
-
-pub fn indent(inner: impl Into<FormattedText>) -> FormattedText {
-    Indent(Box::new(inner.into()))
-}
-
-pub fn line(inner: impl ToString) -> FormattedText {
-    Line(inner.to_string())
-}
 
-fn to_lines(ft: &FormattedText, indent: usize) -> Vec<String> {
-    match ft {
-        Indent(ft) => to_lines(ft, indent + 1),
-        Branch(fts) => {
-            let mut result = Vec::new();
-            for ft in fts {
-                for line in &to_lines(ft, indent) {
-                    result.push(line.clone()); // TODO there's probably a better way to do this.
-                }
-            }
-            result
+    // It would be simpler to use the ? operator instead of a pattern match, but then the error message
+    // would not include `filepath`.
+    match ::std::fs::File::create(filepath) {
+        Ok(mut writer) => {
+            writer.write_all(text.as_bytes()).map_err(convert_io_err)?;
         }
-        Line(s) => {
-            let mut s1: String = " ".repeat(indent * 2);
-            s1.push_str(s);
-            vec![s1.to_string()]
+        Err(e) => {
+            let _ = writeln!(
+                &mut ::std::io::stderr(),
+                "could not open file {filepath:?} for writing: {e}"
+            );
+            return Err(convert_io_err(e));
         }
-        BlankLine => vec!["".to_string()],
     }
+    Ok(())
 }
 
-fn stringify(ft: &FormattedText) -> String {
-    let mut result = to_lines(ft, 0).join("\n");
-    result.push('\n');
-    result.to_string()
+// If `node_text` is a `pub mod name { ... }` block as generated for structs and interfaces,
+// returns its opening line, the module name, the inner attributes of the module, and the
+// rest of the contents of the block. Inner attributes are not allowed in an `include!`d
+// file, so they need to stay in the enclosing module.
+fn split_module(
+    node_text: &FormattedText,
+) -> Option<(&str, &str, Vec<FormattedText>, Vec<FormattedText>)> {
+    let Branch(parts) = node_text else {
+        return None;
+    };
+    let start = parts.iter().position(|part| !matches!(part, BlankLine))?;
+    let [Line(mod_line), body @ .., Line(close)] = &parts[start..] else {
+        return None;
+    };
+    let mod_name = mod_line.strip_prefix("pub mod ")?.split(' ').next()?;
+    if close != "}" {
+        return None;
+    }
+    let mut contents = Vec::new();
+    for part in body {
+        match part {
+            Indent(inner) => match &**inner {
+                Branch(children) => contents.extend(children.iter().cloned()),
+                other => contents.push(other.clone()),
+            },
+            other => contents.push(other.clone()),
+        }
+    }
+    let attribute_count = contents
+        .iter()
+        .take_while(|part| matches!(part, Line(l) if l.starts_with("#![")))
+        .count();
+    let rest = contents.split_off(attribute_count);
+    Some((mod_line, mod_name, contents, rest))
 }
 
-const RUST_KEYWORDS: [&str; 53] = [
-    "abstract", "alignof", "as", "be", "become", "box", "break", "const", "continue", "crate",
+pub struct GeneratorContext<'a> {
+    pub request: schema_capnp::code_generator_request::Reader<'a>,
+    pub node_map: collections::hash_map::HashMap<u64, schema_capnp::node::Reader<'a>>,
+    pub scope_map: collections::hash_map::HashMap<u64, Vec<String>>,
+
+    /// Map from node ID to the node's display name, such as `foo.capnp:Bar.Baz`, for error
+    /// messages. Unlike `node_map`, this also has the nested nodes of imported files that
+    /// the compiler omitted from the request.
+    pub display_names: collections::hash_map::HashMap<u64, String>,
+
+    /// Map from node ID to the node ID of its parent scope. This is equal to node.scope_id
+    /// for all nodes except for autogenerated interface Param and Result structs;
+    /// those have scope_id set to 0. See the comment on paramStructType in schema.capnp.
+    pub node_parents: collections::hash_map::HashMap<u64, u64>,
+
+    /// Root path for referencing things in the `capnp` crate from the generated code.
+    pub capnp_root: String,
+
+    /// Whether to emit the params structs and `build_*_request()` client methods.
+    pub generate_convenience_builders: bool,
+
+    /// Whether the generated code must build without the `alloc` crate.
+    pub no_alloc: bool,
+
+    /// Whether to emit `FooOrUnknown` enum companions and `*_lenient()` accessors.
+    pub lenient_enums: bool,
+
+    /// Whether float setters store NaNs as the canonical quiet NaN.
+    pub canonical_nans: bool,
+
+    /// How field accessors are named.
+    pub accessor_style: AccessorStyle,
+
+    /// Which generated functions are marked `#[inline]`.
+    pub inline_hints: InlineHints,
+
+    /// Map from struct node ID to whether that struct is part of a cycle of by-value
+    /// references, i.e. whether its params struct could contain itself without a `Box`.
+    /// Lists don't count, since the params structs hold them in a `Vec`.
+    pub struct_cycles: collections::hash_map::HashMap<u64, bool>,
+
+    /// Map from the encoded words of each struct or list constant generated so far in the
+    /// current file to the path of the static holding them, so that constants with identical
+    /// values share one array.
+    pub pointer_constant_words: RefCell<HashMap<Vec<u8>, String>>,
+
+    /// Map from the encoded words of each pointer field default generated so far in the
+    /// current struct to the name of the `_private` static holding them, so that fields with
+    /// identical defaults share one array. Groups have their own `_private` module, and so
+    /// their own map.
+    pub default_value_words: RefCell<HashMap<Vec<u8>, String>>,
+
+    /// Names of the implicit type parameters of the method whose client methods are being
+    /// generated, such as `T` in `foo @0 [T] (value :T) -> ()`. Implicit parameters become
+    /// `AnyPointer` while this is empty, as they do in the `Server` trait.
+    pub implicit_method_parameters: RefCell<Vec<String>>,
+
+    /// Map from the ID of a file, struct or interface to the `pub use` lines re-exporting
+    /// the `using` aliases declared in it. Empty unless aliases are re-exported.
+    pub alias_reexports: HashMap<u64, Vec<FormattedText>>,
+
+    module_extensions: Vec<Rc<ModuleExtension>>,
+}
+
+impl<'a> GeneratorContext<'a> {
+    pub fn new(
+        message: &'a capnp::message::Reader<capnp::serialize::OwnedSegments>,
+    ) -> ::capnp::Result<GeneratorContext<'a>> {
+        GeneratorContext::new_from_code_generation_command(&Default::default(), message)
+    }
+
+    fn new_from_code_generation_command(
+        code_generation_command: &CodeGenerationCommand,
+        message: &'a capnp::message::Reader<capnp::serialize::OwnedSegments>,
+    ) -> ::capnp::Result<GeneratorContext<'a>> {
+        let mut default_parent_module_scope = vec!["crate".to_string()];
+        default_parent_module_scope
+            .extend_from_slice(&code_generation_command.default_parent_module[..]);
+
+        let mut ctx = GeneratorContext {
+            request: message.get_root()?,
+            node_map: collections::hash_map::HashMap::<u64, schema_capnp::node::Reader<'a>>::new(),
+            scope_map: collections::hash_map::HashMap::<u64, Vec<String>>::new(),
+            display_names: collections::hash_map::HashMap::new(),
+            node_parents: collections::hash_map::HashMap::new(),
+            capnp_root: code_generation_command.capnp_root.clone(),
+            generate_convenience_builders: code_generation_command.generate_convenience_builders
+                && !code_generation_command.no_alloc,
+            no_alloc: code_generation_command.no_alloc,
+            lenient_enums: code_generation_command.lenient_enums,
+            canonical_nans: code_generation_command.canonical_nans,
+            accessor_style: code_generation_command.accessor_style,
+            inline_hints: code_generation_command.inline_hints,
+            struct_cycles: collections::hash_map::HashMap::new(),
+            pointer_constant_words: RefCell::new(HashMap::new()),
+            default_value_words: RefCell::new(HashMap::new()),
+            implicit_method_parameters: RefCell::new(Vec::new()),
+            alias_reexports: HashMap::new(),
+            module_extensions: code_generation_command.module_extensions.clone(),
+        };
+
+        let crates_provide = &code_generation_command.crates_provide_map;
+
+        for node in ctx.request.get_nodes()? {
+            ctx.node_map.insert(node.get_id(), node);
+            ctx.node_parents.insert(node.get_id(), node.get_scope_id());
+        }
+
+        // Fix up "anonymous" method params and results scopes.
+        for node in ctx.request.get_nodes()? {
+            if let Ok(schema_capnp::node::Interface(interface_reader)) = node.which() {
+                for method in interface_reader.get_methods()? {
+                    let param_struct_type = method.get_param_struct_type();
+                    if ctx.node_parents.get(&param_struct_type) == Some(&0) {
+                        ctx.node_parents.insert(param_struct_type, node.get_id());
+                    }
+                    let result_struct_type = method.get_result_struct_type();
+                    if ctx.node_parents.get(&result_struct_type) == Some(&0) {
+                        ctx.node_parents.insert(result_struct_type, node.get_id());
+                    }
+                }
+            }
+        }
+
+        for &id in ctx.node_map.keys() {
+            let on_cycle = ctx.struct_reaches(id, id)?;
+            ctx.struct_cycles.insert(id, on_cycle);
+        }
+
+        for requested_file in ctx.request.get_requested_files()? {
+            let id = requested_file.get_id();
+
+            for import in requested_file.get_imports()? {
+                let importpath = ::std::path::Path::new(import.get_name()?.to_str()?);
+                let root_name: String = format!(
+                    "{}_capnp",
+                    path_to_stem_string(importpath)?.replace('-', "_")
+                );
+                let parent_module_scope = if let Some(krate) = crates_provide.get(&import.get_id())
+                {
+                    vec![format!("::{krate}")]
+                } else {
+                    default_parent_module_scope.clone()
+                };
+
+                ctx.populate_scope_map(
+                    parent_module_scope,
+                    root_name,
+                    NameKind::Verbatim,
+                    import.get_id(),
+                )?;
+            }
+
+            let root_name = path_to_stem_string(requested_file.get_filename()?.to_str()?)?;
+            let root_mod = format!("{}_capnp", root_name.replace('-', "_"));
+            ctx.populate_scope_map(
+                default_parent_module_scope.clone(),
+                root_mod,
+                NameKind::Verbatim,
+                id,
+            )?;
+        }
+
+        if let Some(source_dirs) = &code_generation_command.alias_source_dirs {
+            ctx.alias_reexports = alias_reexports(&ctx, code_generation_command, source_dirs)?;
+        }
+        Ok(ctx)
+    }
+
+    /// The structs that a params struct for `id` holds by value: the types of its struct
+    /// fields (wrapped in `Option` or a union enum variant) and its groups.
+    fn struct_value_edges(&self, id: u64) -> ::capnp::Result<Vec<u64>> {
+        let mut result = Vec::new();
+        let Some(node) = self.node_map.get(&id) else {
+            return Ok(result);
+        };
+        if let schema_capnp::node::Struct(st) = node.which()? {
+            for field in st.get_fields()? {
+                match field.which()? {
+                    schema_capnp::field::Group(group) => result.push(group.get_type_id()),
+                    schema_capnp::field::Slot(slot) => {
+                        if let type_::Struct(s) = slot.get_type()?.which()? {
+                            result.push(s.get_type_id());
+                        }
+                    }
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Whether a path of by-value references leads from struct `from` to struct `to`.
+    fn struct_reaches(&self, from: u64, to: u64) -> ::capnp::Result<bool> {
+        let mut visited = HashSet::new();
+        let mut stack = self.struct_value_edges(from)?;
+        while let Some(id) = stack.pop() {
+            if id == to {
+                return Ok(true);
+            }
+            if visited.insert(id) {
+                stack.extend(self.struct_value_edges(id)?);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Whether the params struct for `parent` must box its by-value reference to `child`,
+    /// because `child` refers back to `parent`.
+    pub(crate) fn needs_box(&self, parent: u64, child: u64) -> bool {
+        self.struct_cycles.get(&child) == Some(&true)
+            && self.struct_reaches(child, parent).unwrap_or(true)
+    }
+
+    /// Returns the name of the module or type generated for node `id`, without its path.
+    pub fn get_last_name(&self, id: u64) -> ::capnp::Result<&str> {
+        match self.scope_map.get(&id) {
+            None => Err(self.missing_node_error(id, id)),
+            Some(v) => match v.last() {
+                None => Err(Error::failed(format!("node has no scope: {id:#018x}"))),
+                Some(n) => Ok(n),
+            },
+        }
+    }
+
+    /// Looks up node `id`, which the node `referrer` refers to. Pass `id` itself as the
+    /// referrer when there is no better one.
+    pub fn get_node(
+        &self,
+        id: u64,
+        referrer: u64,
+    ) -> ::capnp::Result<schema_capnp::node::Reader<'a>> {
+        match self.node_map.get(&id) {
+            Some(&node) => Ok(node),
+            None => Err(self.missing_node_error(id, referrer)),
+        }
+    }
+
+    /// Returns the fields of the struct `id`, or an error if `id` is not a struct.
+    pub fn get_fields(
+        &self,
+        id: u64,
+    ) -> ::capnp::Result<capnp::struct_list::Reader<'a, schema_capnp::field::Owned>> {
+        match self.get_node(id, id)?.which()? {
+            schema_capnp::node::Struct(st) => st.get_fields(),
+            _ => Err(Error::failed(format!("node {id:#018x} is not a struct"))),
+        }
+    }
+
+    fn missing_node_error(&self, id: u64, referrer: u64) -> Error {
+        let mut description = format!("node {id:#018x}");
+        if let Some(name) = self.display_names.get(&id) {
+            description.push_str(&format!(" (`{name}`)"));
+        }
+        if referrer != id {
+            match self.display_names.get(&referrer) {
+                Some(name) => description.push_str(&format!(", referenced from `{name}`,")),
+                None => description.push_str(&format!(", referenced from node {referrer:#018x},")),
+            }
+        }
+        description.push_str(
+            " is missing from the code generator request. Check that `crates_provide_map()` \
+             names the crate that provides the file declaring it, and that the file is \
+             imported with the right path.",
+        );
+        Error::failed(description)
+    }
+
+    /// Adds the name of `field` of struct `struct_id` to an error from generating code for
+    /// the field.
+    fn field_error(
+        &self,
+        struct_id: u64,
+        field: schema_capnp::field::Reader,
+        error: Error,
+    ) -> Error {
+        let field_name = field
+            .get_name()
+            .ok()
+            .and_then(|name| name.to_str().ok())
+            .unwrap_or("?");
+        let struct_name = match self.display_names.get(&struct_id) {
+            Some(name) => name.clone(),
+            None => format!("{struct_id:#018x}"),
+        };
+        Error::from_kind_context(
+            error.kind,
+            format!(
+                "in field `{field_name}` of `{struct_name}`: {}",
+                error.extra
+            ),
+        )
+    }
+
+    fn populate_scope_map(
+        &mut self,
+        mut ancestor_scope_names: Vec<String>,
+        mut current_node_name: String,
+        current_name_kind: NameKind,
+        node_id: u64,
+    ) -> ::capnp::Result<()> {
+        // unused nodes in imported files might be omitted from the node map
+        let Some(&node_reader) = self.node_map.get(&node_id) else {
+            return Ok(());
+        };
+
+        for annotation in node_reader.get_annotations()? {
+            if annotation.get_id() == NAME_ANNOTATION_ID {
+                current_node_name = name_annotation_value(annotation)?.to_string();
+            } else if annotation.get_id() == PARENT_MODULE_ANNOTATION_ID {
+                let head = ancestor_scope_names[0].clone();
+                ancestor_scope_names.clear();
+                ancestor_scope_names.push(head);
+                ancestor_scope_names.append(&mut get_parent_module(annotation)?);
+            }
+        }
+
+        let mut scope_names = ancestor_scope_names;
+        scope_names.push(capnp_name_to_rust_name(
+            &current_node_name,
+            current_name_kind,
+        ));
+
+        self.scope_map.insert(node_id, scope_names.clone());
+        let display_name = node_reader.get_display_name()?.to_string()?;
+        self.display_names.insert(node_id, display_name.clone());
+
+        let nested_nodes = node_reader.get_nested_nodes()?;
+        for nested_node in nested_nodes {
+            let nested_node_id = nested_node.get_id();
+            match self.node_map.get(&nested_node_id) {
+                None => {
+                    // Still name it, in case something refers to it anyway.
+                    let separator = match node_reader.which() {
+                        Ok(schema_capnp::node::File(())) => ':',
+                        _ => '.',
+                    };
+                    self.display_names.insert(
+                        nested_node_id,
+                        format!(
+                            "{display_name}{separator}{}",
+                            nested_node.get_name()?.to_str()?
+                        ),
+                    );
+                }
+                Some(node_reader) => match node_reader.which() {
+                    Ok(schema_capnp::node::Enum(_enum_reader)) => {
+                        self.populate_scope_map(
+                            scope_names.clone(),
+                            nested_node.get_name()?.to_string()?,
+                            NameKind::Verbatim,
+                            nested_node_id,
+                        )?;
+                    }
+                    _ => {
+                        self.populate_scope_map(
+                            scope_names.clone(),
+                            nested_node.get_name()?.to_string()?,
+                            NameKind::Module,
+                            nested_node_id,
+                        )?;
+                    }
+                },
+            }
+        }
+
+        if let Ok(schema_capnp::node::Struct(struct_reader)) = node_reader.which() {
+            let fields = struct_reader.get_fields()?;
+            for field in fields {
+                if let Ok(schema_capnp::field::Group(group)) = field.which() {
+                    self.populate_scope_map(
+                        scope_names.clone(),
+                        get_field_name(field)?.to_string(),
+                        NameKind::Module,
+                        group.get_type_id(),
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the path of the module or type generated for node `type_id`, such as
+    /// `crate::foo_capnp::bar`.
+    pub fn get_qualified_module(&self, type_id: u64) -> String {
+        self.scope_map[&type_id].join("::")
+    }
+
+    /// Returns the `#[inline]` attribute for a field accessor, or nothing if inline hints are
+    /// disabled.
+    pub fn inline_accessor(&self) -> FormattedText {
+        match self.inline_hints {
+            InlineHints::Default | InlineHints::All => line("#[inline]"),
+            InlineHints::None => Branch(Vec::new()),
+        }
+    }
+
+    /// Returns the `#[inline]` attribute for a schema function such as `get_field_types()`,
+    /// which only gets one with [`InlineHints::All`].
+    pub fn inline_schema_fn(&self) -> FormattedText {
+        match self.inline_hints {
+            InlineHints::All => line("#[inline]"),
+            InlineHints::Default | InlineHints::None => Branch(Vec::new()),
+        }
+    }
+
+    /// Returns the name of the getter for the field whose snake-case name is `styled_name`.
+    pub fn getter_name(&self, styled_name: &str) -> String {
+        match self.accessor_style {
+            AccessorStyle::CapnpGet => format!("get_{styled_name}"),
+            AccessorStyle::RustNaming => {
+                let mut name = styled_name.to_string();
+                if RUST_KEYWORDS.contains(&styled_name) || STRUCT_METHODS.contains(&styled_name) {
+                    name.push('_');
+                }
+                name
+            }
+        }
+    }
+
+    /// Returns the name of the `*_as()` getter for an `AnyPointer` field.
+    fn checked_getter_name(&self, styled_name: &str) -> String {
+        match self.accessor_style {
+            AccessorStyle::CapnpGet => format!("get_{styled_name}_as"),
+            AccessorStyle::RustNaming => format!("{styled_name}_as"),
+        }
+    }
+
+    /// Returns the name of the `*_lenient()` getter for an enum field.
+    fn lenient_getter_name(&self, styled_name: &str) -> String {
+        match self.accessor_style {
+            AccessorStyle::CapnpGet => format!("get_{styled_name}_lenient"),
+            AccessorStyle::RustNaming => format!("{styled_name}_lenient"),
+        }
+    }
+}
+
+/// Like `format!(...)`, but adds a `capnp=ctx.capnp_root` argument.
+macro_rules! fmt(
+    ($ctx:ident, $($arg:tt)*) => ( format!($($arg)*, capnp=$ctx.capnp_root) )
+);
+
+pub(crate) use fmt;
+
+fn path_to_stem_string<P: AsRef<::std::path::Path>>(path: P) -> ::capnp::Result<String> {
+    match path.as_ref().file_stem() {
+        None => Err(Error::failed(format!(
+            "file has no stem: {:?}",
+            path.as_ref()
+        ))),
+        Some(stem) => match stem.to_owned().into_string() {
+            Err(os_string) => Err(Error::failed(format!("bad filename: {os_string:?}"))),
+            Ok(s) => Ok(s),
+        },
+    }
+}
+
+fn snake_to_upper_case(s: &str) -> String {
+    let mut result_chars: Vec<char> = Vec::new();
+    for c in s.chars() {
+        if c == '_' {
+            result_chars.push('_');
+        } else {
+            result_chars.push(c.to_ascii_uppercase());
+        }
+    }
+    result_chars.into_iter().collect()
+}
+
+pub(crate) fn snake_to_camel_case(s: &str) -> String {
+    let mut result_chars: Vec<char> = Vec::new();
+    let mut capitalize = true;
+    for c in s.chars() {
+        if capitalize {
+            result_chars.push(c.to_ascii_uppercase());
+            capitalize = false;
+        } else if c == '_' {
+            capitalize = true;
+        } else {
+            result_chars.push(c);
+        }
+    }
+    result_chars.into_iter().collect()
+}
+
+pub(crate) fn camel_to_snake_case(s: &str) -> String {
+    let mut result_chars: Vec<char> = Vec::new();
+    let mut first_char = true;
+    for c in s.chars() {
+        if c.is_uppercase() && !first_char {
+            result_chars.push('_');
+        }
+        result_chars.push(c.to_ascii_lowercase());
+        first_char = false;
+    }
+    result_chars.into_iter().collect()
+}
+
+pub(crate) fn capitalize_first_letter(s: &str) -> String {
+    let mut result_chars: Vec<char> = Vec::new();
+    for c in s.chars() {
+        result_chars.push(c)
+    }
+    result_chars[0] = result_chars[0].to_ascii_uppercase();
+    result_chars.into_iter().collect()
+}
+
+/// Formats a u64 into a string representation of the hex value, with
+/// separating underscores. Used instead of simple hex formatting to prevent
+/// clippy warnings in autogenerated code. This is loosely based off of
+/// similar functionality in the `separator` crate.
+fn format_u64(value: u64) -> String {
+    let hex = format!("{value:#x}");
+    let mut separated = hex[0..2].to_string();
+    let mut place = hex.len() - 2;
+    let mut later_loop = false;
+
+    for ch in hex[2..].chars() {
+        if later_loop && place % 4 == 0 {
+            separated.push('_');
+        }
+
+        separated.push(ch);
+        later_loop = true;
+        place -= 1;
+    }
+
+    separated
+}
+
+#[test]
+fn test_camel_to_snake_case() {
+    assert_eq!(camel_to_snake_case("fooBar"), "foo_bar".to_string());
+    assert_eq!(camel_to_snake_case("FooBar"), "foo_bar".to_string());
+    assert_eq!(camel_to_snake_case("fooBarBaz"), "foo_bar_baz".to_string());
+    assert_eq!(camel_to_snake_case("FooBarBaz"), "foo_bar_baz".to_string());
+    assert_eq!(camel_to_snake_case("helloWorld"), "hello_world".to_string());
+    assert_eq!(camel_to_snake_case("HelloWorld"), "hello_world".to_string());
+    assert_eq!(camel_to_snake_case("uint32Id"), "uint32_id".to_string());
+
+    assert_eq!(camel_to_snake_case("fooBar_"), "foo_bar_".to_string());
+}
+
+#[test]
+fn test_missing_node_error() {
+    use capnp::schema_capnp::{code_generator_request, node};
+
+    const FILE_ID: u64 = 0xa1b2c3d4e5f60718;
+    const USER_ID: u64 = 0xb1b2c3d4e5f60718;
+    const MISSING_ID: u64 = 0xc1b2c3d4e5f60718;
+
+    // `struct User { missing @0 :Missing; }` and `struct Missing {}` in test.capnp.
+    let mut message = capnp::message::Builder::new_default();
+    let mut request = message.init_root::<code_generator_request::Builder<'_>>();
+    let mut nodes = request.reborrow().init_nodes(3);
+    let mut file = nodes.reborrow().get(0);
+    file.set_id(FILE_ID);
+    file.set_display_name("test.capnp".into());
+    file.set_file(());
+    let mut nested = file.init_nested_nodes(2);
+    nested.reborrow().get(0).set_name("User".into());
+    nested.reborrow().get(0).set_id(USER_ID);
+    nested.reborrow().get(1).set_name("Missing".into());
+    nested.reborrow().get(1).set_id(MISSING_ID);
+    for (index, id, name) in [(1, USER_ID, "User"), (2, MISSING_ID, "Missing")] {
+        let mut node = nodes.reborrow().get(index);
+        node.set_id(id);
+        node.set_scope_id(FILE_ID);
+        node.set_display_name(format!("test.capnp:{name}")[..].into());
+        node.set_display_name_prefix_length(11);
+        node.init_struct();
+    }
+    let node::Struct(user) = nodes.reborrow().get(1).which().unwrap() else {
+        unreachable!()
+    };
+    let mut user = user;
+    user.set_pointer_count(1);
+    let mut field = user.init_fields(1).get(0);
+    field.set_name("missing".into());
+    field.set_discriminant_value(schema_capnp::field::NO_DISCRIMINANT);
+    let slot = field.init_slot();
+    slot.init_type().init_struct().set_type_id(MISSING_ID);
+    let mut file = request.init_requested_files(1).get(0);
+    file.set_id(FILE_ID);
+    file.set_filename("test.capnp".into());
+
+    let words = capnp::serialize::write_message_to_words(&message);
+    let message =
+        capnp::serialize::read_message(words.as_slice(), capnp::message::ReaderOptions::new())
+            .unwrap();
+    let mut ctx = GeneratorContext::new(&message).unwrap();
+    ctx.node_map.remove(&MISSING_ID);
+
+    let error = generate_node(
+        &ctx,
+        USER_ID,
+        "user",
+        &mut String::new(),
+        &mut String::new(),
+        false,
+    )
+    .err()
+    .unwrap();
+    let description = error.to_string();
+    assert!(
+        description.contains("in field `missing` of `test.capnp:User`"),
+        "{description}"
+    );
+    assert!(
+        description.contains("node 0xc1b2c3d4e5f60718 (`test.capnp:Missing`)"),
+        "{description}"
+    );
+    assert!(
+        description.contains("crates_provide_map()"),
+        "{description}"
+    );
+}
+
+#[test]
+fn test_skip_files_rejects_types() {
+    use capnp::schema_capnp::code_generator_request;
+
+    const FILE_ID: u64 = 0xa1b2c3d4e5f60718;
+    const USER_ID: u64 = 0xb1b2c3d4e5f60718;
+
+    // `struct User {}` in test.capnp.
+    let mut message = capnp::message::Builder::new_default();
+    let mut request = message.init_root::<code_generator_request::Builder<'_>>();
+    let mut nodes = request.reborrow().init_nodes(2);
+    let mut file = nodes.reborrow().get(0);
+    file.set_id(FILE_ID);
+    file.set_display_name("test.capnp".into());
+    file.set_file(());
+    let mut nested = file.init_nested_nodes(1).get(0);
+    nested.set_name("User".into());
+    nested.set_id(USER_ID);
+    let mut node = nodes.get(1);
+    node.set_id(USER_ID);
+    node.set_scope_id(FILE_ID);
+    node.set_display_name("test.capnp:User".into());
+    node.set_display_name_prefix_length(11);
+    node.init_struct();
+    let mut file = request.init_requested_files(1).get(0);
+    file.set_id(FILE_ID);
+    file.set_filename("test.capnp".into());
+
+    let words = capnp::serialize::write_message_to_words(&message);
+    let error = CodeGenerationCommand::new()
+        .output_directory(std::env::temp_dir())
+        .skip_files([FILE_ID])
+        .run(words.as_slice())
+        .unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("cannot skip `test.capnp`: it declares `test.capnp:User`"),
+        "{error}"
+    );
+}
+
+#[derive(PartialEq, Clone)]
+pub enum FormattedText {
+    Indent(Box<FormattedText>),
+    Branch(Vec<FormattedText>),
+    Line(String),
+    BlankLine,
+}
+
+impl From<Vec<FormattedText>> for FormattedText {
+    fn from(value: Vec<FormattedText>) -> Self {
+        Branch(value)
+    }
+}
+
+pub fn indent(inner: impl Into<FormattedText>) -> FormattedText {
+    Indent(Box::new(inner.into()))
+}
+
+pub fn line(inner: impl ToString) -> FormattedText {
+    Line(inner.to_string())
+}
+
+fn to_lines(ft: &FormattedText, indent: usize) -> Vec<String> {
+    match ft {
+        Indent(ft) => to_lines(ft, indent + 1),
+        Branch(fts) => {
+            let mut result = Vec::new();
+            for ft in fts {
+                for line in &to_lines(ft, indent) {
+                    result.push(line.clone()); // TODO there's probably a better way to do this.
+                }
+            }
+            result
+        }
+        Line(s) => {
+            let mut s1: String = " ".repeat(indent * 2);
+            s1.push_str(s);
+            vec![s1.to_string()]
+        }
+        BlankLine => vec!["".to_string()],
+    }
+}
+
+fn stringify(ft: &FormattedText) -> String {
+    let mut result = to_lines(ft, 0).join("\n");
+    result.push('\n');
+    result.to_string()
+}
+
+const RUST_KEYWORDS: [&str; 53] = [
+    "abstract", "alignof", "as", "be", "become", "box", "break", "const", "continue", "crate",
     "do", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let",
     "loop", "macro", "match", "mod", "move", "mut", "offsetof", "once", "override", "priv", "proc",
     "pub", "pure", "ref", "return", "self", "sizeof", "static", "struct", "super", "trait", "true",
     "type", "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
 ];
 
+/// Methods that every generated struct reader or builder has, and that a getter named after a
+/// field must therefore not shadow.
+const STRUCT_METHODS: [&str; 7] = [
+    "copy_union_from",
+    "into_reader",
+    "reborrow",
+    "reborrow_as_reader",
+    "serialized_words",
+    "total_size",
+    "which",
+];
+
 fn module_name(camel_case: &str) -> String {
     let mut name = camel_to_snake_case(camel_case);
     if RUST_KEYWORDS.contains(&&*name) {
@@ -540,10 +1931,27 @@ fn module_name(camel_case: &str) -> String {
     name
 }
 
+// Items defined in the module generated for a struct, which the variants of its `Which`
+// enum must not be re-exported over.
+const STRUCT_MODULE_ITEMS: &[&str] = &[
+    "Owned",
+    "Reader",
+    "Builder",
+    "Pipeline",
+    "Which",
+    "WhichReader",
+    "WhichBuilder",
+];
+
 // Annotation IDs, as defined in rust.capnp.
-const NAME_ANNOTATION_ID: u64 = 0xc2fe4c6d100166d0;
+pub(crate) const NAME_ANNOTATION_ID: u64 = 0xc2fe4c6d100166d0;
 const PARENT_MODULE_ANNOTATION_ID: u64 = 0xabee386cd1450364;
 const OPTION_ANNOTATION_ID: u64 = 0xabfef22c4ee1964e;
+const TRACK_PRESENCE_ANNOTATION_ID: u64 = 0xe3a27f4c1b9d8a65;
+const COMPATIBLE_WITH_ANNOTATION_ID: u64 = 0x95354582d8e4df23;
+const VISITOR_ANNOTATION_ID: u64 = 0xd4c8e1f27a3b6905;
+const REQUIRED_ANNOTATION_ID: u64 = 0xa4bd9ede9ab83fcb;
+const PARAMS_NAME_ANNOTATION_ID: u64 = 0x950cf6e95af67867;
 
 fn name_annotation_value(annotation: schema_capnp::annotation::Reader) -> capnp::Result<&str> {
     if let schema_capnp::value::Text(t) = annotation.get_value()?.which()? {
@@ -564,7 +1972,7 @@ fn name_annotation_value(annotation: schema_capnp::annotation::Reader) -> capnp:
     }
 }
 
-fn get_field_name(field: schema_capnp::field::Reader) -> capnp::Result<&str> {
+pub(crate) fn get_field_name(field: schema_capnp::field::Reader) -> capnp::Result<&str> {
     for annotation in field.get_annotations()? {
         if annotation.get_id() == NAME_ANNOTATION_ID {
             return name_annotation_value(annotation);
@@ -608,7 +2016,7 @@ fn capnp_name_to_rust_name(capnp_name: &str, name_kind: NameKind) -> String {
     }
 }
 
-fn is_option_field(field: schema_capnp::field::Reader) -> capnp::Result<bool> {
+pub(crate) fn is_option_field(field: schema_capnp::field::Reader) -> capnp::Result<bool> {
     use capnp::schema_capnp::*;
 
     let enabled = field
@@ -617,24 +2025,86 @@ fn is_option_field(field: schema_capnp::field::Reader) -> capnp::Result<bool> {
         .any(|a| a.get_id() == OPTION_ANNOTATION_ID);
 
     if enabled {
-        let supported = match field.which()? {
-            field::Which::Group(_) => false,
+        let name = get_field_name(field)?;
+        match field.which()? {
+            field::Which::Group(_) => {
+                // A group is laid out inline in its parent struct, so there is no null pointer
+                // that could stand for absence. Use a union with a Void member instead.
+                return Err(capnp::Error::failed(format!(
+                    "$Rust.option annotation is not supported on group field `{name}`; \
+                     consider a union with a Void member to represent absence"
+                )));
+            }
             field::Which::Slot(field) => {
-                let ty = field.get_type()?;
-                ty.is_pointer()? && !matches!(ty.which()?, type_::Interface(_))
+                if !field.get_type()?.is_pointer()? {
+                    return Err(capnp::Error::failed(format!(
+                        "$Rust.option annotation only supported on pointer fields, but `{name}` \
+                         is not a pointer field"
+                    )));
+                }
             }
+        }
+    }
+
+    Ok(enabled)
+}
+
+fn is_required_field(field: schema_capnp::field::Reader) -> capnp::Result<bool> {
+    use capnp::schema_capnp::*;
+
+    let enabled = field
+        .get_annotations()?
+        .iter()
+        .any(|a| a.get_id() == REQUIRED_ANNOTATION_ID);
+
+    if enabled {
+        let is_pointer = match field.which()? {
+            field::Which::Group(_) => false,
+            field::Which::Slot(field) => field.get_type()?.is_pointer()?,
         };
-        if !supported {
-            return Err(capnp::Error::failed(
-                "$Rust.option annotation only supported on pointer fields (support for optional interfaces isn't implemented yet)".to_string(),
-            ));
+        if !is_pointer {
+            return Err(capnp::Error::failed(format!(
+                "$Rust.required annotation only supported on pointer fields, but `{}` \
+                 is not a pointer field",
+                get_field_name(field)?
+            )));
         }
     }
 
     Ok(enabled)
 }
 
-fn prim_default(value: &schema_capnp::value::Reader) -> ::capnp::Result<Option<String>> {
+/// Whether values of this type are (or contain) capabilities, which need `alloc`.
+fn type_contains_interface(ty: schema_capnp::type_::Reader) -> capnp::Result<bool> {
+    match ty.which()? {
+        type_::Interface(_) => Ok(true),
+        type_::List(ot) => type_contains_interface(ot.get_element_type()?),
+        _ => Ok(false),
+    }
+}
+
+/// The bits of a `Float32` default, which the stored value is XOR-ed with.
+fn f32_default_bits(ctx: &GeneratorContext, f: f32) -> u32 {
+    if ctx.canonical_nans && f.is_nan() {
+        capnp::private::mask::CANONICAL_NAN_F32_BITS
+    } else {
+        f.to_bits()
+    }
+}
+
+/// The bits of a `Float64` default, which the stored value is XOR-ed with.
+fn f64_default_bits(ctx: &GeneratorContext, f: f64) -> u64 {
+    if ctx.canonical_nans && f.is_nan() {
+        capnp::private::mask::CANONICAL_NAN_F64_BITS
+    } else {
+        f.to_bits()
+    }
+}
+
+fn prim_default(
+    ctx: &GeneratorContext,
+    value: &schema_capnp::value::Reader,
+) -> ::capnp::Result<Option<String>> {
     use capnp::schema_capnp::value;
     match value.which()? {
         value::Bool(false)
@@ -658,11 +2128,11 @@ fn prim_default(value: &schema_capnp::value::Reader) -> ::capnp::Result<Option<S
         value::Uint64(i) => Ok(Some(i.to_string())),
         value::Float32(f) => match f.classify() {
             ::std::num::FpCategory::Zero => Ok(None),
-            _ => Ok(Some(format!("{}u32", f.to_bits()))),
+            _ => Ok(Some(format!("{}u32", f32_default_bits(ctx, f)))),
         },
         value::Float64(f) => match f.classify() {
             ::std::num::FpCategory::Zero => Ok(None),
-            _ => Ok(Some(format!("{}u64", f.to_bits()))),
+            _ => Ok(Some(format!("{}u64", f64_default_bits(ctx, f)))),
         },
         _ => Err(Error::failed(
             "Non-primitive value found where primitive was expected.".to_string(),
@@ -671,17 +2141,19 @@ fn prim_default(value: &schema_capnp::value::Reader) -> ::capnp::Result<Option<S
 }
 
 // Gets the full list ordered of generic parameters for a node. Outer scopes come first.
-fn get_params(ctx: &GeneratorContext, mut node_id: u64) -> ::capnp::Result<Vec<String>> {
+pub(crate) fn get_params(ctx: &GeneratorContext, mut node_id: u64) -> ::capnp::Result<Vec<String>> {
     let mut result = Vec::new();
 
+    let mut child_id = node_id;
     while node_id != 0 {
-        let node = ctx.node_map[&node_id];
+        let node = ctx.get_node(node_id, child_id)?;
         let parameters = node.get_parameters()?;
 
         for parameter in parameters.into_iter().rev() {
             result.push(parameter.get_name()?.to_str()?.into());
         }
 
+        child_id = node_id;
         node_id = node.get_scope_id();
     }
 
@@ -732,6 +2204,12 @@ pub fn getter_text(
         field::Slot(reg_field) => {
             let mut default_decl = None;
             let offset = reg_field.get_offset() as usize;
+            if ctx.no_alloc && type_contains_interface(reg_field.get_type()?)? {
+                return Err(Error::failed(format!(
+                    "field `{}` has a capability type, which is not supported with `no_alloc`",
+                    get_field_name(*field)?
+                )));
+            }
             let module_string = if is_reader { "Reader" } else { "Builder" };
             let module = if is_reader {
                 Leaf::Reader("'a")
@@ -776,14 +2254,14 @@ pub fn getter_text(
                     fmt!(ctx, "::core::result::Result<{typ},{capnp}::NotInSchema>"),
                 ),
                 type_::AnyPointer(_) if !raw_type.is_parameter()? => (false, typ.clone()),
-                type_::Interface(_) => (
-                    true,
-                    fmt!(
-                        ctx,
-                        "{capnp}::Result<{}>",
-                        raw_type.type_string(ctx, Leaf::Client)?
-                    ),
-                ),
+                type_::Interface(_) => {
+                    let client = raw_type.type_string(ctx, Leaf::Client)?;
+                    if should_get_option {
+                        (true, fmt!(ctx, "{capnp}::Result<Option<{client}>>"))
+                    } else {
+                        (true, fmt!(ctx, "{capnp}::Result<{client}>"))
+                    }
+                }
                 _ if raw_type.is_prim()? => (false, typ.clone()),
                 _ => (true, fmt!(ctx, "{capnp}::Result<{typ}>")),
             };
@@ -826,10 +2304,10 @@ pub fn getter_text(
                     primitive_case(&typ, &member, offset, i, 0)
                 }
                 (type_::Float32(()), value::Float32(f)) => {
-                    primitive_case(&typ, &member, offset, f.to_bits(), 0)
+                    primitive_case(&typ, &member, offset, f32_default_bits(ctx, f), 0)
                 }
                 (type_::Float64(()), value::Float64(f)) => {
-                    primitive_case(&typ, &member, offset, f.to_bits(), 0)
+                    primitive_case(&typ, &member, offset, f64_default_bits(ctx, f), 0)
                 }
                 (type_::Enum(_), value::Enum(d)) => {
                     if d == 0 {
@@ -845,13 +2323,14 @@ pub fn getter_text(
                 | (type_::List(_), value::List(_))
                 | (type_::Struct(_), value::Struct(_)) => {
                     let default = if reg_field.get_had_explicit_default() {
-                        default_decl = Some(crate::pointer_constants::word_array_declaration(
-                            ctx,
-                            &default_name,
-                            ::capnp::raw::get_struct_pointer_section(default_value).get(0),
-                            crate::pointer_constants::WordArrayDeclarationOptions { public: true },
-                        )?);
-                        format!("::core::option::Option::Some(&_private::{default_name}[..])")
+                        let (name, declaration) =
+                            crate::pointer_constants::default_value_declaration(
+                                ctx,
+                                &default_name,
+                                ::capnp::raw::get_struct_pointer_section(default_value).get(0),
+                            )?;
+                        default_decl = declaration;
+                        format!("::core::option::Option::Some(&_private::{name}[..])")
                     } else {
                         "::core::option::Option::None".to_string()
                     };
@@ -909,41 +2388,41 @@ pub fn getter_text(
     }
 }
 
-fn zero_fields_of_group(
+/// A piece of the storage of a group, as found by `walk_group_storage()`.
+enum GroupStorage {
+    /// The discriminant of a union in the group.
+    Discriminant(u32),
+    Bool(u32),
+    /// A numeric field, with the name of its Rust type.
+    Numeric(String, u32),
+    Enum(u32),
+    Pointer(u32),
+}
+
+/// Calls `visit` on each piece of storage of the fields of group `node_id`, including those of
+/// its nested groups. Pieces shared by several union members are visited once for each.
+fn walk_group_storage(
     ctx: &GeneratorContext,
     node_id: u64,
-    clear: &mut bool,
-) -> ::capnp::Result<FormattedText> {
+    visit: &mut dyn FnMut(GroupStorage),
+) -> ::capnp::Result<()> {
     use capnp::schema_capnp::{field, node, type_};
-    match ctx.node_map[&node_id].which()? {
+    match ctx.get_node(node_id, node_id)?.which()? {
         node::Struct(st) => {
-            let mut result = Vec::new();
             if st.get_discriminant_count() != 0 {
-                result.push(Line(format!(
-                    "self.builder.set_data_field::<u16>({}, 0);",
-                    st.get_discriminant_offset()
-                )));
+                visit(GroupStorage::Discriminant(st.get_discriminant_offset()));
             }
             let fields = st.get_fields()?;
             for field in fields {
                 match field.which()? {
                     field::Group(group) => {
-                        result.push(zero_fields_of_group(ctx, group.get_type_id(), clear)?);
+                        walk_group_storage(ctx, group.get_type_id(), visit)?;
                     }
                     field::Slot(slot) => {
                         let typ = slot.get_type()?.which()?;
                         match typ {
                             type_::Void(()) => {}
-                            type_::Bool(()) => {
-                                let line = Line(format!(
-                                    "self.builder.set_bool_field({}, false);",
-                                    slot.get_offset()
-                                ));
-                                // PERF could dedup more efficiently
-                                if !result.contains(&line) {
-                                    result.push(line)
-                                }
-                            }
+                            type_::Bool(()) => visit(GroupStorage::Bool(slot.get_offset())),
                             type_::Int8(())
                             | type_::Int16(())
                             | type_::Int32(())
@@ -953,56 +2432,101 @@ fn zero_fields_of_group(
                             | type_::Uint32(())
                             | type_::Uint64(())
                             | type_::Float32(())
-                            | type_::Float64(()) => {
-                                let line = Line(format!(
-                                    "self.builder.set_data_field::<{0}>({1}, 0{0});",
-                                    slot.get_type()?.type_string(ctx, Leaf::Builder("'a"))?,
-                                    slot.get_offset()
-                                ));
-                                // PERF could dedup more efficiently
-                                if !result.contains(&line) {
-                                    result.push(line)
-                                }
-                            }
-                            type_::Enum(_) => {
-                                let line = Line(format!(
-                                    "self.builder.set_data_field::<u16>({}, 0u16);",
-                                    slot.get_offset()
-                                ));
-                                // PERF could dedup more efficiently
-                                if !result.contains(&line) {
-                                    result.push(line)
-                                }
-                            }
+                            | type_::Float64(()) => visit(GroupStorage::Numeric(
+                                slot.get_type()?.type_string(ctx, Leaf::Builder("'a"))?,
+                                slot.get_offset(),
+                            )),
+                            type_::Enum(_) => visit(GroupStorage::Enum(slot.get_offset())),
                             type_::Struct(_)
                             | type_::List(_)
                             | type_::Text(())
                             | type_::Data(())
                             | type_::AnyPointer(_)
                             | type_::Interface(_) => {
-                                // Is this the right thing to do for interfaces?
-                                let line = Line(format!(
-                                    "self.builder.reborrow().get_pointer_field({}).clear();",
-                                    slot.get_offset()
-                                ));
-                                *clear = true;
-                                // PERF could dedup more efficiently
-                                if !result.contains(&line) {
-                                    result.push(line)
-                                }
+                                visit(GroupStorage::Pointer(slot.get_offset()))
                             }
                         }
                     }
                 }
             }
-            Ok(Branch(result))
+            Ok(())
         }
         _ => Err(Error::failed(
-            "zero_fields_of_groupd() expected a struct".to_string(),
+            "walk_group_storage() expected a struct".to_string(),
         )),
     }
 }
 
+fn zero_fields_of_group(
+    ctx: &GeneratorContext,
+    node_id: u64,
+    clear: &mut bool,
+) -> ::capnp::Result<FormattedText> {
+    let mut result = Vec::new();
+    walk_group_storage(ctx, node_id, &mut |storage| {
+        let line = match storage {
+            GroupStorage::Discriminant(offset) => {
+                Line(format!("self.builder.set_data_field::<u16>({offset}, 0);"))
+            }
+            GroupStorage::Bool(offset) => {
+                Line(format!("self.builder.set_bool_field({offset}, false);"))
+            }
+            GroupStorage::Numeric(typ, offset) => Line(format!(
+                "self.builder.set_data_field::<{typ}>({offset}, 0{typ});"
+            )),
+            GroupStorage::Enum(offset) => Line(format!(
+                "self.builder.set_data_field::<u16>({offset}, 0u16);"
+            )),
+            GroupStorage::Pointer(offset) => {
+                // Is this the right thing to do for interfaces?
+                *clear = true;
+                Line(format!(
+                    "self.builder.reborrow().get_pointer_field({offset}).clear();"
+                ))
+            }
+        };
+        // PERF could dedup more efficiently
+        if !result.contains(&line) {
+            result.push(line)
+        }
+    })?;
+    Ok(Branch(result))
+}
+
+/// Returns the conditions under which some field of group `node_id` deviates from its default,
+/// that is, some of its storage is not zero.
+fn group_presence_conditions(
+    ctx: &GeneratorContext,
+    node_id: u64,
+    member: &str,
+) -> ::capnp::Result<Vec<String>> {
+    let mut result = Vec::new();
+    walk_group_storage(ctx, node_id, &mut |storage| {
+        let condition = match storage {
+            GroupStorage::Discriminant(offset) | GroupStorage::Enum(offset) => {
+                format!("self.{member}.get_data_field::<u16>({offset}) != 0")
+            }
+            GroupStorage::Bool(offset) => format!("self.{member}.get_bool_field({offset})"),
+            GroupStorage::Numeric(typ, offset) => {
+                // Compare the bits of floats, so that -0.0 counts as set.
+                let typ = match typ.as_str() {
+                    "f32" => "u32",
+                    "f64" => "u64",
+                    typ => typ,
+                };
+                format!("self.{member}.get_data_field::<{typ}>({offset}) != 0")
+            }
+            GroupStorage::Pointer(offset) => {
+                format!("!self.{member}.is_pointer_field_null({offset})")
+            }
+        };
+        if !result.contains(&condition) {
+            result.push(condition)
+        }
+    })?;
+    Ok(result)
+}
+
 fn generate_setter(
     ctx: &GeneratorContext,
     discriminant_offset: u32,
@@ -1012,7 +2536,7 @@ fn generate_setter(
     rust_struct_impl_inner: &mut String,
     is_params_struct: bool,
     //params_struct_params: &mut HashSet<String>, //TODO Still thinking about generics
-    node_name: &str,
+    node_id: u64,
 ) -> ::capnp::Result<FormattedText> {
     use capnp::schema_capnp::*;
 
@@ -1025,6 +2549,14 @@ fn generate_setter(
     let mut initn_interior = Vec::new();
     let mut initter_params = Vec::new();
     let mut no_discriminant = true;
+    let mut orphan_type = None;
+    // The element type of a list of primitives, which also gets an `init_*_from_slice()`.
+    let mut slice_element_type = None;
+    // The type of an integer field, which also gets a checked `set_*_from()`.
+    let mut integer_type = None;
+    // The item bound of a `List(Text)` or `List(Data)` field, which also gets a
+    // `set_*_from_iter()`.
+    let mut iter_item_bound = None;
 
     let discriminant_value = field.get_discriminant_value();
     if discriminant_value != field::NO_DISCRIMINANT {
@@ -1060,7 +2592,7 @@ fn generate_setter(
                         )
                         .as_str(),
                     );
-                    rust_struct_impl_inner.push_str(format!("\n  {params_struct_impl_prefix}_{styled_name}.build_capnp_struct(builder.reborrow().init_{styled_name}());").as_str());
+                    rust_struct_impl_inner.push_str(format!("\n  {params_struct_impl_prefix}_{styled_name}.build_capnp_struct(builder.reborrow().init_{styled_name}())?;").as_str());
                 }
                 "".to_string()
             } else {
@@ -1092,7 +2624,7 @@ fn generate_setter(
                     (Some("()".to_string()), None)
                 }
                 type_::Bool(()) => {
-                    match prim_default(&reg_field.get_default_value()?)? {
+                    match prim_default(ctx, &reg_field.get_default_value()?)? {
                         None => {
                             setter_interior.push(Line(format!(
                                 "self.builder.set_bool_field({offset}, value);"
@@ -1110,11 +2642,32 @@ fn generate_setter(
                         );
                         rust_struct_impl_inner.push_str(format!("\n  builder.set_{styled_name}({params_struct_impl_prefix}_{styled_name});").as_str());
                     }
-                    (Some("bool".to_string()), None)
-                }
-                _ if typ.is_prim()? => {
-                    let tstr = typ.type_string(ctx, Leaf::Reader("'a"))?;
-                    match prim_default(&reg_field.get_default_value()?)? {
+                    (Some("bool".to_string()), None)
+                }
+                _ if typ.is_prim()? => {
+                    let tstr = typ.type_string(ctx, Leaf::Reader("'a"))?;
+                    if matches!(
+                        typ.which()?,
+                        type_::Int8(())
+                            | type_::Int16(())
+                            | type_::Int32(())
+                            | type_::Int64(())
+                            | type_::Uint8(())
+                            | type_::Uint16(())
+                            | type_::Uint32(())
+                            | type_::Uint64(())
+                    ) {
+                        integer_type = Some(tstr.clone());
+                    }
+                    if ctx.canonical_nans
+                        && matches!(typ.which()?, type_::Float32(()) | type_::Float64(()))
+                    {
+                        setter_interior.push(Line(fmt!(
+                            ctx,
+                            "let value = {capnp}::private::mask::CanonicalizeNan::canonicalize_nan(value);"
+                        )));
+                    }
+                    match prim_default(ctx, &reg_field.get_default_value()?)? {
                         None => {
                             setter_interior.push(Line(format!(
                                 "self.builder.set_data_field::<{tstr}>({offset}, value);"
@@ -1141,6 +2694,7 @@ fn generate_setter(
                     initter_interior.push(Line(format!(
                         "self.builder.get_pointer_field({offset}).init_text(size)"
                     )));
+                    orphan_type = Some(typ.type_string(ctx, Leaf::Owned)?);
                     initter_params.push("size: u32");
                     if no_discriminant {
                         rust_struct_inner.push_str(
@@ -1160,6 +2714,7 @@ fn generate_setter(
                     initter_interior.push(Line(format!(
                         "self.builder.get_pointer_field({offset}).init_data(size)"
                     )));
+                    orphan_type = Some(typ.type_string(ctx, Leaf::Owned)?);
                     initter_params.push("size: u32");
                     if no_discriminant {
                         rust_struct_inner.push_str(
@@ -1180,6 +2735,19 @@ fn generate_setter(
                     initter_params.push("size: u32");
                     initter_interior.push(
                         Line(fmt!(ctx,"{capnp}::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field({offset}), size)")));
+                    orphan_type = Some(typ.type_string(ctx, Leaf::Owned)?);
+                    let element_type = ot1.get_element_type()?;
+                    if element_type.is_prim()? {
+                        slice_element_type = Some(element_type.type_string(ctx, Leaf::Owned)?);
+                    }
+                    // Buffering the items needs a `Vec`.
+                    if !ctx.no_alloc {
+                        iter_item_bound = match element_type.which()? {
+                            type_::Text(()) => Some("str"),
+                            type_::Data(()) => Some("[u8]"),
+                            _ => None,
+                        };
+                    }
 
                     if no_discriminant {
                         if let Ok(vec_of_list_element_types) =
@@ -1194,6 +2762,7 @@ fn generate_setter(
                             );
                             rust_struct_impl_inner.push_str(
                                 build_impl_for_list_type(
+                                    ctx,
                                     styled_name,
                                     ot1.reborrow(),
                                     false,
@@ -1232,33 +2801,38 @@ fn generate_setter(
                         );
                         rust_struct_impl_inner.push_str(format!("\n  builder.set_{styled_name}({params_struct_impl_prefix}_{styled_name});").as_str());
                     }
-                    if !reg_field.get_had_explicit_default() {
-                        setter_interior.push(Line(format!(
-                            "self.builder.set_data_field::<u16>({offset}, value as u16);"
-                        )));
+                    let raw_setter = if !reg_field.get_had_explicit_default() {
+                        format!("self.builder.set_data_field::<u16>({offset}, value as u16);")
                     } else {
                         match reg_field.get_default_value()?.which()? {
-                            schema_capnp::value::Enum(d) => {
-                                setter_interior.push(Line(format!(
-                                    "self.builder.set_data_field_mask::<u16>({offset}, value as u16, {d});"
-                                )));
-                            }
+                            schema_capnp::value::Enum(d) => format!(
+                                "self.builder.set_data_field_mask::<u16>({offset}, value as u16, {d});"
+                            ),
                             _ => return Err(Error::failed("enum default not an Enum".to_string())),
                         }
                     };
+                    setter_interior.push(Line(raw_setter.clone()));
+                    if ctx.lenient_enums {
+                        let mut interior = setter_interior[..setter_interior.len() - 1].to_vec();
+                        interior.push(Line(raw_setter.replace("value as u16", "u16::from(value)")));
+                        result.push(ctx.inline_accessor());
+                        result.push(Line(format!(
+                            "pub fn set_{styled_name}_lenient(&mut self, value: {the_mod}OrUnknown) {{"
+                        )));
+                        result.push(indent(interior));
+                        result.push(line("}"));
+                    }
                     (Some(the_mod), None)
                 }
                 type_::Struct(st) => {
                     return_result = true;
                     initter_interior.push(
                       Line(fmt!(ctx,"{capnp}::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field({offset}), 0)")));
+                    orphan_type = Some(typ.type_string(ctx, Leaf::Owned)?);
 
                     let type_string = get_params_struct_path_string(ctx, st)?;
                     if no_discriminant && get_params(ctx, st.get_type_id())?.is_empty() {
-                        if type_string
-                            .rfind(snake_to_camel_case(node_name).as_str())
-                            .is_some()
-                        {
+                        if ctx.needs_box(node_id, st.get_type_id()) {
                             rust_struct_inner.push_str(
                                 format!(
                                     "{params_struct_prefix}_{styled_name}: Option<Box<{}>>,",
@@ -1266,7 +2840,7 @@ fn generate_setter(
                                 )
                                 .as_str(),
                             );
-                            rust_struct_impl_inner.push_str(format!("\n  if let Some(st) = {params_struct_impl_prefix}_{styled_name} {{st.build_capnp_struct(builder.reborrow().init_{styled_name}());}}").as_str());
+                            rust_struct_impl_inner.push_str(format!("\n  if let Some(st) = {params_struct_impl_prefix}_{styled_name} {{st.build_capnp_struct(builder.reborrow().init_{styled_name}())?;}}").as_str());
                         } else {
                             rust_struct_inner.push_str(
                                 format!(
@@ -1275,7 +2849,7 @@ fn generate_setter(
                                 )
                                 .as_str(),
                             );
-                            rust_struct_impl_inner.push_str(format!("\n  if let Some(st) = {params_struct_impl_prefix}_{styled_name} {{st.build_capnp_struct(builder.reborrow().init_{styled_name}());}}").as_str());
+                            rust_struct_impl_inner.push_str(format!("\n  if let Some(st) = {params_struct_impl_prefix}_{styled_name} {{st.build_capnp_struct(builder.reborrow().init_{styled_name}())?;}}").as_str());
                         }
                     }
 
@@ -1303,22 +2877,33 @@ fn generate_setter(
                     }
                 }
                 type_::Interface(i_t) => {
+                    let client_type = if is_option_field(*field)? {
+                        setter_interior.push(Branch(vec![
+                            line("match value {"),
+                            indent(vec![
+                                Line(format!("::core::option::Option::Some(value) => self.builder.reborrow().get_pointer_field({offset}).set_capability(value.client.hook),")),
+                                Line(format!("::core::option::Option::None => self.builder.reborrow().get_pointer_field({offset}).clear(),")),
+                            ]),
+                            line("}"),
+                        ]));
+                        format!("Option<{}>", typ.type_string(ctx, Leaf::Client)?)
+                    } else {
+                        setter_interior.push(Line(format!(
+                            "self.builder.reborrow().get_pointer_field({offset}).set_capability(value.client.hook);"
+                        )));
+                        typ.type_string(ctx, Leaf::Client)?
+                    };
                     if no_discriminant && get_params(ctx, i_t.get_type_id())?.is_empty() {
                         rust_struct_inner.push_str(
-                            format!(
-                                "{params_struct_prefix}_{styled_name}: {},",
-                                typ.type_string(ctx, Leaf::Client)?
-                            )
-                            .as_str(),
+                            format!("{params_struct_prefix}_{styled_name}: {client_type},")
+                                .as_str(),
                         );
                         rust_struct_impl_inner.push_str(format!("\n  builder.set_{styled_name}({params_struct_impl_prefix}_{styled_name});").as_str());
                     }
-                    setter_interior.push(Line(format!(
-                        "self.builder.reborrow().get_pointer_field({offset}).set_capability(value.client.hook);"
-                    )));
-                    (Some(typ.type_string(ctx, Leaf::Client)?), None)
+                    (Some(client_type), None)
                 }
                 type_::AnyPointer(_) => {
+                    orphan_type = Some(typ.type_string(ctx, Leaf::Owned)?);
                     if typ.is_parameter()? {
                         //let reader_type = typ.type_string(ctx, Leaf::Reader("'a"))?;
                         //params_struct_string.push_str(format!("\n   pub {styled_name}: {reader_type},").as_str());
@@ -1330,7 +2915,7 @@ fn generate_setter(
 
                         let builder_type = typ.type_string(ctx, Leaf::Builder("'a"))?;
 
-                        result.push(line("#[inline]"));
+                        result.push(ctx.inline_accessor());
                         result.push(Line(format!(
                             "pub fn initn_{styled_name}(self, length: u32) -> {builder_type} {{"
                         )));
@@ -1366,15 +2951,50 @@ fn generate_setter(
         } else {
             "".into()
         };
-        result.push(line("#[inline]"));
+        result.push(ctx.inline_accessor());
         result.push(Line(format!(
             "pub fn set_{styled_name}(&mut self, {setter_param}: {reader_type}) {return_type} {{"
         )));
         result.push(indent(setter_interior));
         result.push(line("}"));
+        if let Some(integer_type) = &integer_type {
+            // Accepts any integer type, so that widening the field in the schema does not
+            // break callers, and fails on values that do not fit instead of truncating them.
+            result.push(ctx.inline_accessor());
+            result.push(Line(fmt!(ctx,
+                "pub fn set_{styled_name}_from(&mut self, value: impl ::core::convert::TryInto<{integer_type}>) -> {capnp}::Result<()> {{"
+            )));
+            result.push(indent(vec![
+                Line(fmt!(ctx, "let value = value.try_into().map_err(|_| {capnp}::Error::from_kind({capnp}::ErrorKind::ValueOutOfRange))?;")),
+                Line(format!("self.set_{styled_name}(value);")),
+                line("::core::result::Result::Ok(())"),
+            ]));
+            result.push(line("}"));
+        }
+        if ctx.accessor_style == AccessorStyle::RustNaming {
+            let (return_type, set_call, ret) = if return_result {
+                (
+                    fmt!(ctx, "{capnp}::Result<Self>"),
+                    format!("self.set_{styled_name}(value)?;"),
+                    "::core::result::Result::Ok(self)",
+                )
+            } else {
+                (
+                    "Self".into(),
+                    format!("self.set_{styled_name}(value);"),
+                    "self",
+                )
+            };
+            result.push(ctx.inline_accessor());
+            result.push(Line(format!(
+                "pub fn with_{styled_name}(mut self, value: {reader_type}) -> {return_type} {{"
+            )));
+            result.push(indent(vec![Line(set_call), line(ret)]));
+            result.push(line("}"));
+        }
     }
     if let Some(builder_type) = maybe_builder_type {
-        result.push(line("#[inline]"));
+        result.push(ctx.inline_accessor());
         let args = initter_params.join(", ");
         let mutable = if initter_mut { "mut " } else { "" };
         result.push(Line(format!(
@@ -1382,184 +3002,767 @@ fn generate_setter(
         )));
         result.push(indent(initter_interior));
         result.push(line("}"));
+        if let Some(element_type) = slice_element_type {
+            result.push(ctx.inline_accessor());
+            result.push(Line(fmt!(ctx,
+                "pub fn init_{styled_name}_from_slice(self, values: &[{element_type}]) -> {capnp}::Result<{builder_type}> {{"
+            )));
+            result.push(indent(vec![
+                Line(fmt!(
+                    ctx,
+                    "let size = {capnp}::private::checked_len(values.len())?;"
+                )),
+                Line(format!("let mut list = self.init_{styled_name}(size);")),
+                line("list.set_from_slice(values);"),
+                line("::core::result::Result::Ok(list)"),
+            ]));
+            result.push(line("}"));
+        }
+        if let Some(item_bound) = iter_item_bound {
+            // The list's length has to be known before it is allocated, so the items are
+            // collected first. An empty iterator still sets the field to an empty list.
+            result.push(ctx.inline_accessor());
+            result.push(Line(fmt!(ctx,
+                "pub fn set_{styled_name}_from_iter<_I>(&mut self, values: _I) -> {capnp}::Result<()> where _I: ::core::iter::IntoIterator, _I::Item: ::core::convert::AsRef<{item_bound}> {{"
+            )));
+            result.push(indent(vec![
+                line("let values: Vec<_I::Item> = ::core::iter::Iterator::collect(::core::iter::IntoIterator::into_iter(values));"),
+                Line(fmt!(ctx, "let size = {capnp}::private::checked_len(values.len())?;")),
+                Line(format!("self.reborrow().init_{styled_name}(size).set_all(values);")),
+                line("::core::result::Result::Ok(())"),
+            ]));
+            result.push(line("}"));
+        }
+    }
+    if let (Some(orphan_type), field::Slot(reg_field)) = (orphan_type, field.which()?) {
+        let offset = reg_field.get_offset();
+        result.push(ctx.inline_accessor());
+        result.push(Line(fmt!(
+            ctx,
+            "pub fn disown_{styled_name}(&mut self) -> {capnp}::Orphan<{orphan_type}> {{"
+        )));
+        let disown = fmt!(
+            ctx,
+            "{capnp}::Orphan::new(self.builder.reborrow().get_pointer_field({offset}).disown())"
+        );
+        if no_discriminant {
+            result.push(indent(Line(disown)));
+        } else {
+            // The pointer slot may belong to another member of the union.
+            result.push(indent(vec![
+                Line(format!(
+                    "if self.builder.get_data_field::<u16>({}) != {discriminant_value} {{",
+                    discriminant_offset as usize
+                )),
+                indent(Line(fmt!(ctx, "return {capnp}::Orphan::null();"))),
+                line("}"),
+                Line(disown),
+            ]));
+        }
+        result.push(line("}"));
+        result.push(ctx.inline_accessor());
+        result.push(Line(fmt!(ctx,
+            "pub fn adopt_{styled_name}(&mut self, orphan: {capnp}::Orphan<{orphan_type}>) -> {capnp}::Result<()> {{"
+        )));
+        let adopt = format!(
+            "self.builder.reborrow().get_pointer_field({offset}).adopt(orphan.into_internal())"
+        );
+        if no_discriminant {
+            result.push(indent(Line(adopt)));
+        } else {
+            // Only switch the union over once the adoption has succeeded.
+            result.push(indent(vec![
+                Line(format!("{adopt}?;")),
+                Line(format!(
+                    "self.builder.set_data_field::<u16>({}, {});",
+                    discriminant_offset as usize, discriminant_value as usize
+                )),
+                line("::core::result::Result::Ok(())"),
+            ]));
+        }
+        result.push(line("}"));
+    }
+    if let field::Slot(reg_field) = field.which()? {
+        let offset = reg_field.get_offset();
+        let typ = reg_field.get_type()?;
+        let size_param = match typ.which()? {
+            type_::Struct(_) => Some(""),
+            type_::List(_) | type_::Text(()) | type_::Data(()) => Some("size: u32"),
+            _ => None,
+        };
+        if let Some(size_param) = size_param {
+            let size_arg = if size_param.is_empty() { "" } else { "size" };
+            let absent = if no_discriminant {
+                format!("self.builder.is_pointer_field_null({offset})")
+            } else {
+                format!(
+                    "self.builder.get_data_field::<u16>({}) != {discriminant_value} || self.builder.is_pointer_field_null({offset})",
+                    discriminant_offset as usize
+                )
+            };
+            result.push(ctx.inline_accessor());
+            result.push(Line(fmt!(
+                ctx,
+                "pub fn get_or_init_{styled_name}(&mut self, {size_param}) -> {capnp}::Result<{}> {{",
+                typ.type_string(ctx, Leaf::Builder("'_"))?
+            )));
+            result.push(indent(vec![
+                Line(format!("if {absent} {{")),
+                indent(Line(format!(
+                    "::core::result::Result::Ok(self.reborrow().init_{styled_name}({size_arg}))"
+                ))),
+                line("} else {"),
+                indent(Line(fmt!(
+                    ctx,
+                    "{capnp}::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field({offset}), ::core::option::Option::None)"
+                ))),
+                line("}"),
+            ]));
+            result.push(line("}"));
+        }
     }
     Ok(Branch(result))
 }
-fn get_params_struct_path_string(
+fn is_presence_tracked(node: schema_capnp::node::Reader) -> capnp::Result<bool> {
+    Ok(node
+        .get_annotations()?
+        .iter()
+        .any(|a| a.get_id() == TRACK_PRESENCE_ANNOTATION_ID))
+}
+
+/// Generates the `FooWithPresence` struct for a struct annotated with `$Rust.trackPresence`.
+/// It mirrors each primitive field outside of the union as an `Option`.
+fn generate_presence_struct(
     ctx: &GeneratorContext,
-    struct_reader: capnp::schema_capnp::type_::struct_::Reader,
-) -> capnp::Result<String> {
-    Ok(format!(
-        "{}::{}",
-        ctx.get_qualified_module(struct_reader.get_type_id()),
-        snake_to_camel_case(ctx.get_last_name(struct_reader.get_type_id())?)
-    ))
+    params: &TypeParameterTexts,
+    fields: capnp::struct_list::Reader<schema_capnp::field::Owned>,
+    type_name: &str,
+) -> capnp::Result<FormattedText> {
+    use capnp::schema_capnp::*;
+
+    let mut members = Vec::new();
+    let mut reads = Vec::new();
+    let mut writes = Vec::new();
+    for field in fields {
+        if field.get_discriminant_value() != field::NO_DISCRIMINANT {
+            continue;
+        }
+        let field::Slot(slot) = field.which()? else {
+            continue;
+        };
+        let typ = slot.get_type()?;
+        let (fallible, compare) = match typ.which()? {
+            type_::Bool(())
+            | type_::Int8(())
+            | type_::Int16(())
+            | type_::Int32(())
+            | type_::Int64(())
+            | type_::Uint8(())
+            | type_::Uint16(())
+            | type_::Uint32(())
+            | type_::Uint64(()) => ("", ""),
+            // Compare bits so that a NaN default still counts as unset.
+            type_::Float32(()) | type_::Float64(()) => ("", ".to_bits()"),
+            type_::Enum(_) => ("?", ""),
+            _ => continue,
+        };
+        let styled_name = camel_to_snake_case(get_field_name(field)?);
+        members.push(Line(format!(
+            "pub {styled_name}: ::core::option::Option<{}>,",
+            typ.type_string(ctx, Leaf::Owned)?
+        )));
+        let getter = ctx.getter_name(&styled_name);
+        reads.push(Line(format!(
+            "{styled_name}: {{ let value = reader.{getter}(){fallible}; \
+             if value{compare} != default.{getter}(){fallible}{compare} {{ \
+             ::core::option::Option::Some(value) }} else {{ ::core::option::Option::None }} }},"
+        )));
+        writes.push(Line(format!(
+            "if let ::core::option::Option::Some(value) = self.{styled_name} {{ builder.set_{styled_name}(value); }}"
+        )));
+    }
+
+    let mut unused = Vec::new();
+    if members.is_empty() {
+        // Keep the generated functions free of unused-variable warnings.
+        unused.push(line("let _ = (reader, default);"));
+        writes.push(line("let _ = &mut builder;"));
+    }
+
+    let with_presence = format!("{type_name}WithPresence");
+    Ok(Branch(vec![
+        Line(format!(
+            "/// Mirrors the primitive fields of `{type_name}` as `Option`s, where `None` means unset."
+        )),
+        line("#[derive(Clone, Copy, Debug, Default, PartialEq)]"),
+        Line(format!("pub struct {with_presence} {{")),
+        indent(members),
+        line("}"),
+        Line(format!("impl {with_presence} {{")),
+        indent(vec![
+            line("/// Reads each field as `Some` if it holds a value other than its default. The wire"),
+            line("/// format does not record presence, so a field explicitly set to its default reads"),
+            line("/// back as `None`."),
+            Line(fmt!(
+                ctx,
+                "pub fn read_from<'a,{0}>(reader: Reader<'a,{0}>) -> {capnp}::Result<Self> {1} {{",
+                params.params,
+                params.where_clause
+            )),
+            indent(vec![
+                Line(fmt!(
+                    ctx,
+                    "let default: Reader<'_,{}> = {capnp}::private::layout::StructReader::new_default().into();",
+                    params.params
+                )),
+                Branch(unused),
+                line("::core::result::Result::Ok(Self {"),
+                indent(reads),
+                line("})"),
+            ]),
+            line("}"),
+            line("/// Writes the fields that are `Some`, leaving the others untouched."),
+            Line(format!(
+                "pub fn write_to<{0}>(&self, mut builder: Builder<'_,{0}>) {1} {{",
+                params.params, params.where_clause
+            )),
+            indent(writes),
+            line("}"),
+        ]),
+        line("}"),
+    ]))
 }
-fn vec_of_list_element_types(
+
+fn has_visitor(node: schema_capnp::node::Reader) -> capnp::Result<bool> {
+    Ok(node
+        .get_annotations()?
+        .iter()
+        .any(|a| a.get_id() == VISITOR_ANNOTATION_ID))
+}
+
+/// Generates the `FooVisitor` trait and `visit_foo()` driver for a struct annotated with
+/// `$Rust.visitor`.
+fn generate_visitor(
     ctx: &GeneratorContext,
-    list: type_::list::Reader,
-) -> capnp::Result<String> {
-    match list.get_element_type()?.which()? {
-        type_::Which::Void(()) => Ok("Vec<()>".to_string()),
-        type_::Which::Bool(()) => Ok("Vec<bool>".to_string()),
-        type_::Which::Int8(()) => Ok("Vec<i8>".to_string()),
-        type_::Which::Int16(()) => Ok("Vec<i16>".to_string()),
-        type_::Which::Int32(()) => Ok("Vec<i32>".to_string()),
-        type_::Which::Int64(()) => Ok("Vec<i64>".to_string()),
-        type_::Which::Uint8(()) => Ok("Vec<u8>".to_string()),
-        type_::Which::Uint16(()) => Ok("Vec<u16>".to_string()),
-        type_::Which::Uint32(()) => Ok("Vec<u32>".to_string()),
-        type_::Which::Uint64(()) => Ok("Vec<u64>".to_string()),
-        type_::Which::Float32(()) => Ok("Vec<f32>".to_string()),
-        type_::Which::Float64(()) => Ok("Vec<f64>".to_string()),
-        type_::Which::Text(_) => Ok("Vec<String>".to_string()),
-        type_::Which::Data(_) => Ok("Vec<Vec<u8>>".to_string()),
-        type_::Which::List(l) => Ok(format!("Vec<{}>", vec_of_list_element_types(ctx, l)?)),
-        type_::Which::Enum(enum_type) => {
-            if get_params(ctx, enum_type.get_type_id())?.is_empty() {
-                let enum_mod = ctx.get_qualified_module(enum_type.get_type_id());
-                return Ok(format!("Vec<{enum_mod}>"));
-            }
-            Err(Error::failed("generics not supported here yet".to_string()))
-        }
-        type_::Which::Struct(struct_reader) => {
-            if get_params(ctx, struct_reader.get_type_id())?.is_empty() {
-                return Ok(format!(
-                    "Vec<{}>",
-                    get_params_struct_path_string(ctx, struct_reader)?
-                ));
+    node_id: u64,
+    type_name: &str,
+    node_name: &str,
+) -> capnp::Result<FormattedText> {
+    let mut methods = Vec::new();
+    let statements = generate_visit_fields(ctx, node_id, "reader", "", &mut methods)?;
+    let visitor = format!("{type_name}Visitor");
+    Ok(Branch(vec![
+        Line(format!(
+            "/// Has a method for each field of `{type_name}`, including the fields of its groups."
+        )),
+        Line(format!("/// See [`visit_{node_name}()`].")),
+        Line(format!("pub trait {visitor} {{")),
+        indent(methods),
+        line("}"),
+        line("/// Calls the method of `visitor` for each field of `reader` in order, descending into"),
+        line("/// groups. Of the fields of a union, only the one that is set is visited."),
+        Line(fmt!(
+            ctx,
+            "pub fn visit_{node_name}(reader: Reader<'_>, visitor: &mut impl {visitor}) -> {capnp}::Result<()> {{"
+        )),
+        indent(statements),
+        indent(line("::core::result::Result::Ok(())")),
+        line("}"),
+    ]))
+}
+
+/// Generates the statements of `visit_foo()` for the fields of struct or group `struct_id`,
+/// which is read from `src`, and pushes the corresponding visitor methods, whose names
+/// start with `visit_{prefix}`, onto `methods`.
+fn generate_visit_fields(
+    ctx: &GeneratorContext,
+    struct_id: u64,
+    src: &str,
+    prefix: &str,
+    methods: &mut Vec<FormattedText>,
+) -> capnp::Result<Vec<FormattedText>> {
+    use capnp::schema_capnp::*;
+
+    let node::Struct(st) = ctx.get_node(struct_id, struct_id)?.which()? else {
+        return Err(Error::failed("expected a struct".to_string()));
+    };
+    let mut statements = Vec::new();
+    let mut union_arms = Vec::new();
+    // Where the union is visited, which is at its first member.
+    let mut union_position = None;
+    for field in st.get_fields()? {
+        let name = get_field_name(field)?;
+        let styled_name = camel_to_snake_case(name);
+        let is_union_field = field.get_discriminant_value() != field::NO_DISCRIMINANT;
+
+        // `body` visits `value`, which is bound to the field's value below.
+        let (body, fallible) = match field.which()? {
+            field::Group(group) => {
+                let group_src = format!("group_{prefix}{styled_name}");
+                let mut body = vec![Line(format!("let {group_src} = value;"))];
+                body.extend(generate_visit_fields(
+                    ctx,
+                    group.get_type_id(),
+                    &group_src,
+                    &format!("{prefix}{styled_name}_"),
+                    methods,
+                )?);
+                (body, false)
             }
-            Err(Error::failed("generics not supported here yet".to_string()))
-        }
-        type_::Which::Interface(i_t) => {
-            if get_params(ctx, i_t.get_type_id())?.is_empty() {
-                let the_mod = ctx.get_qualified_module(i_t.get_type_id());
-                return Ok(format!("Vec<{the_mod}::Client>"));
+            field::Slot(slot) => {
+                let typ = slot.get_type()?;
+                let method = format!("visit_{prefix}{styled_name}");
+                if let type_::Void(()) = typ.which()? {
+                    methods.push(Line(fmt!(
+                        ctx,
+                        "fn {method}(&mut self) -> {capnp}::Result<()>;"
+                    )));
+                    let body = vec![Line(format!("visitor.{method}()?;"))];
+                    if is_union_field {
+                        union_position.get_or_insert(statements.len());
+                        union_arms.push((
+                            format!(
+                                "{}::Which::{}(())",
+                                ctx.get_qualified_module(struct_id),
+                                capitalize_first_letter(name)
+                            ),
+                            body,
+                        ));
+                    } else {
+                        statements.push(Branch(body));
+                    }
+                    continue;
+                }
+                // These match the getter's return type.
+                let (value_type, fallible) = match typ.which()? {
+                    type_::Interface(_) => (typ.type_string(ctx, Leaf::Client)?, true),
+                    type_::Enum(_) => (typ.type_string(ctx, Leaf::Owned)?, true),
+                    type_::AnyPointer(_) => (typ.type_string(ctx, Leaf::Reader("'_"))?, false),
+                    _ if typ.is_prim()? => (typ.type_string(ctx, Leaf::Owned)?, false),
+                    _ => (typ.type_string(ctx, Leaf::Reader("'_"))?, true),
+                };
+                let value_type = if is_option_field(field)? {
+                    format!("::core::option::Option<{value_type}>")
+                } else {
+                    value_type
+                };
+                methods.push(Line(fmt!(
+                    ctx,
+                    "fn {method}(&mut self, value: {value_type}) -> {capnp}::Result<()>;"
+                )));
+                (vec![Line(format!("visitor.{method}(value)?;"))], fallible)
             }
-            Err(Error::failed("generics not supported here yet".to_string()))
+        };
+
+        if is_union_field {
+            union_position.get_or_insert(statements.len());
+            let unwrap = if fallible {
+                vec![line("let value = value?;")]
+            } else {
+                Vec::new()
+            };
+            union_arms.push((
+                format!(
+                    "{}::Which::{}(value)",
+                    ctx.get_qualified_module(struct_id),
+                    capitalize_first_letter(name)
+                ),
+                [unwrap, body].concat(),
+            ));
+        } else {
+            statements.push(Line(format!(
+                "{{ let value = {src}.{}(){};",
+                ctx.getter_name(&styled_name),
+                if fallible { "?" } else { "" }
+            )));
+            statements.push(indent(body));
+            statements.push(line("}"));
         }
-        type_::Which::AnyPointer(_) => Err(Error::failed(
-            "Lists of anypointers not supported here yet".to_string(),
-        )),
-    }
-}
-fn build_impl_for_list_type(
-    name: &str,
-    list: type_::list::Reader,
-    union: bool,
-    is_params_struct: bool,
-) -> capnp::Result<String> {
-    let vec_source: String;
-    if union {
-        vec_source = "t".to_string();
-    } else if is_params_struct {
-        vec_source = format!("_{name}");
-    } else {
-        vec_source = format!("self._{name}");
     }
-    Ok(match list.reborrow().get_element_type()?.which()? {
-        type_::Which::Text(_) => {
-            format!(
-                "
-            \nif {vec_source}.len() > 0 {{
-                let mut list_builder = builder.reborrow().init_{name}({vec_source}.len() as u32);
-                for (i, item) in {vec_source}.into_iter().enumerate() {{
-                    list_builder.reborrow().set(i as u32, item.as_str().into());
-                }}
-            }}"
-            )
+
+    if let Some(position) = union_position {
+        let mut arms = Vec::new();
+        for (pattern, body) in union_arms {
+            arms.push(Line(format!("{pattern} => {{")));
+            arms.push(indent(body));
+            arms.push(line("}"));
         }
-        type_::Which::Data(_) => {
-            format!(
-                "
-            \nif {vec_source}.len() > 0 {{
-                let mut list_builder = builder.reborrow().init_{name}({vec_source}.len() as u32);
-                for (i, item) in {vec_source}.into_iter().enumerate() {{
-                    list_builder.reborrow().set(i as u32, item.as_slice());
-                }}
-        }}"
-            )
+        statements.insert(
+            position,
+            Branch(vec![
+                Line(format!("match {src}.which()? {{")),
+                indent(arms),
+                line("}"),
+            ]),
+        );
+    }
+    Ok(statements)
+}
+
+/// Returns the target type ID of a `$Rust.compatibleWith` annotation on `node`, if any.
+fn compatible_with_target(node: schema_capnp::node::Reader) -> capnp::Result<Option<u64>> {
+    for annotation in node.get_annotations()? {
+        if annotation.get_id() == COMPATIBLE_WITH_ANNOTATION_ID {
+            return match annotation.get_value()?.which()? {
+                schema_capnp::value::Uint64(id) => Ok(Some(id)),
+                _ => Err(Error::failed(
+                    "expected compatibleWith annotation value to be of type UInt64".to_string(),
+                )),
+            };
         }
-        type_::Which::List(_) => {
-            format!(
-                "
-            \nif {vec_source}.len() > 0 {{
-                let mut list_builder = builder.reborrow().init_{name}({vec_source}.len() as u32);
-                for (i, item) in {vec_source}.into_iter().enumerate() {{
-                    {}
-                }}
-            }}",
-                build_list_of_list_impl(list.reborrow())?
+    }
+    Ok(None)
+}
+
+/// Returns true if a value of primitive type `from` can be converted to `to` with `From`,
+/// without loss.
+fn is_widening(
+    from: schema_capnp::type_::Reader,
+    to: schema_capnp::type_::Reader,
+) -> capnp::Result<bool> {
+    use capnp::schema_capnp::type_::*;
+    Ok(matches!(
+        (from.which()?, to.which()?),
+        (Int8(()), Int16(()) | Int32(()) | Int64(()))
+            | (Int16(()), Int32(()) | Int64(()))
+            | (Int32(()), Int64(()))
+            | (
+                Uint8(()),
+                Uint16(()) | Uint32(()) | Uint64(()) | Int16(()) | Int32(()) | Int64(())
             )
+            | (Uint16(()), Uint32(()) | Uint64(()) | Int32(()) | Int64(()))
+            | (Uint32(()), Uint64(()) | Int64(()))
+            | (Float32(()), Float64(()))
+    ))
+}
+
+/// Returns true if `from` is a non-generic struct type whose `$Rust.compatibleWith`
+/// annotation names the struct type `to`.
+fn is_upgradable_struct(
+    ctx: &GeneratorContext,
+    from: schema_capnp::type_::Reader,
+    to: schema_capnp::type_::Reader,
+) -> capnp::Result<bool> {
+    use capnp::schema_capnp::type_;
+    let (type_::Struct(from), type_::Struct(to)) = (from.which()?, to.which()?) else {
+        return Ok(false);
+    };
+    let Some(node) = ctx.node_map.get(&from.get_type_id()) else {
+        return Ok(false);
+    };
+    Ok(!node.get_is_generic() && compatible_with_target(*node)? == Some(to.get_type_id()))
+}
+
+/// Generates the statements that copy `value`, a reader of type `from`, into the field of
+/// type `to` that `setter` (e.g. `set_foo`) and `initter` write on the builder `dst`.
+/// Returns `None` if the types are not compatible.
+fn generate_upgrade_value(
+    ctx: &GeneratorContext,
+    from: schema_capnp::type_::Reader,
+    to: schema_capnp::type_::Reader,
+    dst: &str,
+    setter: &str,
+    initter: &str,
+) -> capnp::Result<Option<Vec<FormattedText>>> {
+    use capnp::schema_capnp::type_;
+    if from.type_string(ctx, Leaf::Owned)? == to.type_string(ctx, Leaf::Owned)? {
+        let statement = match from.which()? {
+            type_::Void(()) => format!("{dst}.reborrow().{setter}(());"),
+            type_::Struct(_) | type_::List(_) => format!("{dst}.reborrow().{setter}(value)?;"),
+            type_::AnyPointer(_) => format!("{dst}.reborrow().{initter}().set_as(value)?;"),
+            _ => format!("{dst}.reborrow().{setter}(value);"),
+        };
+        return Ok(Some(vec![Line(statement)]));
+    }
+    if from.is_prim()? && to.is_prim()? && is_widening(from, to)? {
+        return Ok(Some(vec![Line(format!(
+            "{dst}.reborrow().{setter}(value.into());"
+        ))]));
+    }
+    if is_upgradable_struct(ctx, from, to)? {
+        return Ok(Some(vec![Line(format!(
+            "value.upgrade_into({dst}.reborrow().{initter}())?;"
+        ))]));
+    }
+    let (type_::List(from), type_::List(to)) = (from.which()?, to.which()?) else {
+        return Ok(None);
+    };
+    let (from, to) = (from.get_element_type()?, to.get_element_type()?);
+    let copy_element = if is_upgradable_struct(ctx, from, to)? {
+        "value.get(index).upgrade_into(list.reborrow().get(index))?;"
+    } else if from.is_prim()? && to.is_prim()? && is_widening(from, to)? {
+        "list.set(index, value.get(index).into());"
+    } else {
+        return Ok(None);
+    };
+    Ok(Some(vec![
+        Line(format!(
+            "let mut list = {dst}.reborrow().{initter}(value.len());"
+        )),
+        line("for index in 0..value.len() {"),
+        indent(line(copy_element)),
+        line("}"),
+    ]))
+}
+
+/// Generates the statements that copy the fields of the struct or group `from_id`, read
+/// from `src`, into the builder `dst` of the struct or group `to_id`. The fields that are not
+/// copied are added to `skipped` and `defaulted`, prefixed with `path`.
+#[allow(clippy::too_many_arguments)]
+fn generate_upgrade_fields(
+    ctx: &GeneratorContext,
+    from_id: u64,
+    to_id: u64,
+    src: &str,
+    dst: &str,
+    path: &str,
+    skipped: &mut Vec<String>,
+    defaulted: &mut Vec<String>,
+) -> capnp::Result<Vec<FormattedText>> {
+    use capnp::schema_capnp::*;
+
+    let (node::Struct(from_struct), node::Struct(to_struct)) = (
+        ctx.get_node(from_id, from_id)?.which()?,
+        ctx.get_node(to_id, from_id)?.which()?,
+    ) else {
+        return Err(Error::failed("expected a struct".to_string()));
+    };
+    let from_fields = from_struct.get_fields()?;
+    let to_fields = to_struct.get_fields()?;
+
+    let mut to_by_name = HashMap::new();
+    for field in to_fields {
+        to_by_name.insert(get_field_name(field)?, field);
+    }
+    for field in to_fields {
+        let name = get_field_name(field)?;
+        if !from_fields
+            .iter()
+            .any(|f| get_field_name(f).map(|n| n == name).unwrap_or(false))
+        {
+            defaulted.push(format!("{path}{name}"));
         }
-        type_::Which::Struct(_) => {
-            format!(
-                "
-            \nif {vec_source}.len() > 0 {{
-                let mut list_builder = builder.reborrow().init_{name}({vec_source}.len() as u32);
-                for (i, item) in {vec_source}.into_iter().enumerate() {{
-                    item.build_capnp_struct(list_builder.reborrow().get(i as u32));
-                }}
-            }}"
-            )
+    }
+
+    let depth = path.matches('.').count() + 1;
+    let mut statements = Vec::new();
+    let mut union_arms = Vec::new();
+    for field in from_fields {
+        let name = get_field_name(field)?;
+        let Some(&to_field) = to_by_name.get(name) else {
+            skipped.push(format!("`{path}{name}` (no such field in the target)"));
+            continue;
+        };
+        let styled_name = camel_to_snake_case(name);
+        let to_styled_name = camel_to_snake_case(get_field_name(to_field)?);
+        let is_union_field = field.get_discriminant_value() != field::NO_DISCRIMINANT;
+        let to_is_union_field = to_field.get_discriminant_value() != field::NO_DISCRIMINANT;
+
+        // `body` copies `value`, which is bound to the field's value below.
+        let (body, fallible, optional) = match (field.which()?, to_field.which()?) {
+            (field::Group(from_group), field::Group(to_group)) => {
+                let group_src = format!("src{depth}");
+                let group_dst = format!("dst{depth}");
+                let inner = generate_upgrade_fields(
+                    ctx,
+                    from_group.get_type_id(),
+                    to_group.get_type_id(),
+                    &group_src,
+                    &group_dst,
+                    &format!("{path}{name}."),
+                    skipped,
+                    defaulted,
+                )?;
+                if inner.is_empty() && !to_is_union_field {
+                    continue;
+                }
+                let get_group = if to_is_union_field {
+                    format!("init_{to_styled_name}")
+                } else {
+                    ctx.getter_name(&to_styled_name)
+                };
+                let mut body = vec![
+                    Line(format!("let {group_src} = value;")),
+                    Line(format!(
+                        "let mut {group_dst} = {dst}.reborrow().{get_group}();"
+                    )),
+                ];
+                if inner.is_empty() {
+                    body = vec![Line(format!("{dst}.reborrow().{get_group}();"))];
+                } else {
+                    body.extend(inner);
+                }
+                (body, false, false)
+            }
+            (field::Slot(from_slot), field::Slot(to_slot)) => {
+                let from_type = from_slot.get_type()?;
+                let to_type = to_slot.get_type()?;
+                if !is_union_field && matches!(from_type.which()?, type_::Void(())) {
+                    continue;
+                }
+                let optional = is_option_field(field)?;
+                if let type_::Interface(_) = from_type.which()? {
+                    if optional || is_option_field(to_field)? {
+                        skipped.push(format!("`{path}{name}` (incompatible type)"));
+                        continue;
+                    }
+                }
+                let Some(body) = generate_upgrade_value(
+                    ctx,
+                    from_type,
+                    to_type,
+                    dst,
+                    &format!("set_{to_styled_name}"),
+                    &format!("init_{to_styled_name}"),
+                )?
+                else {
+                    skipped.push(format!("`{path}{name}` (incompatible type)"));
+                    continue;
+                };
+                let fallible =
+                    !(from_type.is_prim()? || matches!(from_type.which()?, type_::AnyPointer(_)));
+                (body, fallible, optional)
+            }
+            _ => {
+                skipped.push(format!("`{path}{name}` (incompatible type)"));
+                continue;
+            }
+        };
+
+        if is_union_field {
+            let variant = capitalize_first_letter(name);
+            let which = ctx.get_qualified_module(from_id);
+            let (binding, unwrap) = match field.which()? {
+                field::Slot(slot) if matches!(slot.get_type()?.which()?, type_::Void(())) => {
+                    ("()", Vec::new())
+                }
+                _ if fallible => ("value", vec![line("let value = value?;")]),
+                _ => ("value", Vec::new()),
+            };
+            union_arms.push((
+                format!("::core::result::Result::Ok({which}::Which::{variant}({binding}))"),
+                Branch(vec![Branch(unwrap), Branch(body)]),
+            ));
+        } else {
+            let get = format!(
+                "{src}.{}(){}",
+                ctx.getter_name(&styled_name),
+                if fallible { "?" } else { "" }
+            );
+            if optional {
+                statements.push(Line(format!(
+                    "if let ::core::option::Option::Some(value) = {get} {{"
+                )));
+            } else {
+                statements.push(Line(format!("{{ let value = {get};")));
+            }
+            statements.push(indent(body));
+            statements.push(line("}"));
         }
-        type_::Which::Interface(_) => {
-            format!(
-                "
-            \nif {vec_source}.len() > 0 {{
-                let mut list_builder = builder.reborrow().init_{name}({vec_source}.len() as u32);
-                for (i, item) in {vec_source}.into_iter().enumerate() {{
-                    list_builder.reborrow().set(i as u32, item.client.hook);
-                }}
-            }}"
-            )
+    }
+
+    match union_arms.len() {
+        0 => (),
+        1 => {
+            let (pattern, body) = union_arms.pop().unwrap();
+            statements.push(Line(format!("if let {pattern} = {src}.which() {{")));
+            statements.push(indent(body));
+            statements.push(line("}"));
         }
-        type_::Which::AnyPointer(_) => "".to_string(),
         _ => {
-            format!(
-                "
-            \nif {vec_source}.len() > 0 {{
-                let mut list_builder = builder.reborrow().init_{name}({vec_source}.len() as u32);
-                for (i, item) in {vec_source}.into_iter().enumerate() {{
-                    list_builder.reborrow().set(i as u32, item);
-                }}
-            }}"
-            )
-        }
-    })
-}
-fn build_list_of_list_impl(list: type_::list::Reader) -> capnp::Result<String> {
-    Ok(match list.reborrow().get_element_type()?.which()? {
-        type_::Which::Text(_) => {
-            "\nlist_builder.reborrow().set(i as u32, item.as_str().into());".to_string()
-        }
-        type_::Which::Data(_) => {
-            "\nlist_builder.reborrow().set(i as u32, item.as_slice());".to_string()
-        }
-        type_::Which::List(reader) => {
-            format!("\n
-                if item.len() > 0 {{
-                    let mut list_builder = list_builder.reborrow().init(i as u32, item.len() as u32);
-                    for (i, item) in item.into_iter().enumerate() {{ {} }}
-                }}",
-                build_list_of_list_impl(reader)?)
+            statements.push(Line(format!("match {src}.which() {{")));
+            let mut arms = Vec::new();
+            for (pattern, body) in union_arms {
+                arms.push(Line(format!("{pattern} => {{")));
+                arms.push(indent(body));
+                arms.push(line("}"));
+            }
+            arms.push(line("_ => {}"));
+            statements.push(indent(arms));
+            statements.push(line("}"));
         }
-        type_::Which::Struct(_) => {
-            "\nitem.build_capnp_struct(list_builder.reborrow().get(i as u32));".to_string()
+    }
+    Ok(statements)
+}
+
+/// Generates `upgrade_into()` for a struct annotated with `$Rust.compatibleWith`.
+fn generate_upgrade_into(
+    ctx: &GeneratorContext,
+    node_id: u64,
+    target_id: u64,
+) -> capnp::Result<FormattedText> {
+    let Some(target) = ctx.node_map.get(&target_id) else {
+        return Err(Error::failed(format!(
+            "compatibleWith target {target_id:#x} is not part of this code generation request"
+        )));
+    };
+    if !matches!(target.which()?, schema_capnp::node::Struct(_)) || target.get_is_generic() {
+        return Err(Error::failed(format!(
+            "compatibleWith target {target_id:#x} must be a non-generic struct"
+        )));
+    }
+    let target_module = ctx.get_qualified_module(target_id);
+
+    let mut skipped = Vec::new();
+    let mut defaulted = Vec::new();
+    let body = generate_upgrade_fields(
+        ctx,
+        node_id,
+        target_id,
+        "self",
+        "target",
+        "",
+        &mut skipped,
+        &mut defaulted,
+    )?;
+
+    let mut doc = vec![
+        Line(format!(
+            "/// Copies this struct into `target`, a `{target_module}` builder, matching fields by name."
+        )),
+        line("/// Primitive fields are widened where needed and nested structs are upgraded in turn."),
+    ];
+    if !skipped.is_empty() {
+        doc.push(line("///"));
+        doc.push(line("/// Fields that are not copied:"));
+        for field in &skipped {
+            doc.push(Line(format!("/// - {field}")));
         }
-        type_::Which::Interface(_) => {
-            "\nlist_builder.reborrow().set(i as u32, item.client.hook);".to_string()
+    }
+    if !defaulted.is_empty() {
+        doc.push(line("///"));
+        doc.push(line("/// Target fields that keep their default values:"));
+        for field in &defaulted {
+            doc.push(Line(format!("/// - `{field}`")));
         }
-        type_::Which::AnyPointer(_) => "".to_string(),
-        _ => "\nlist_builder.reborrow().set(i as u32, item);".to_string(),
-    })
+    }
+
+    let unused = if body.is_empty() {
+        line("let _ = (self, &mut target);")
+    } else {
+        Branch(Vec::new())
+    };
+    Ok(Branch(vec![
+        Branch(doc),
+        Line(fmt!(
+            ctx,
+            "pub fn upgrade_into(self, mut target: {target_module}::Builder<'_>) -> {capnp}::Result<()> {{"
+        )),
+        indent(vec![
+            unused,
+            Branch(body),
+            line("::core::result::Result::Ok(())"),
+        ]),
+        line("}"),
+    ]))
 }
+
 fn used_params_of_group(
     ctx: &GeneratorContext,
     group_id: u64,
     used_params: &mut HashSet<String>,
 ) -> capnp::Result<()> {
-    let node = ctx.node_map[&group_id];
+    let node = ctx.get_node(group_id, group_id)?;
     match node.which()? {
         schema_capnp::node::Struct(st) => {
             for field in st.get_fields()? {
@@ -1607,7 +3810,7 @@ fn used_params_of_type(
 
         type_::AnyPointer(ap) => {
             if let type_::any_pointer::Parameter(def) = ap.which()? {
-                let the_struct = &ctx.node_map[&def.get_scope_id()];
+                let the_struct = ctx.get_node(def.get_scope_id(), def.get_scope_id())?;
                 let parameters = the_struct.get_parameters()?;
                 let parameter = parameters.get(u32::from(def.get_parameter_index()));
                 let parameter_name = parameter.get_name()?.to_str()?;
@@ -1678,6 +3881,7 @@ fn generate_union(
     generate_params: bool,
     union_only_struct: bool,
     params_union_name: &String,
+    node_id: u64,
 ) -> ::capnp::Result<(
     FormattedText,
     FormattedText,
@@ -1744,7 +3948,7 @@ fn generate_union(
                                     format!("\n _{enumerant_name}({}),", vec_of_list_element_types)
                                         .as_str(),
                                 );
-                                params_impl_interior.push_str(format!("\n {params_union_name}::_{enumerant_name}(t) => {{\n{}\n}},", build_impl_for_list_type(camel.as_str(), l.reborrow(), true, false)?).as_str());
+                                params_impl_interior.push_str(format!("\n {params_union_name}::_{enumerant_name}(t) => {{\n{}\n}},", build_impl_for_list_type(ctx, camel.as_str(), l.reborrow(), true, false)?).as_str());
                             }
                         }
                         type_::Which::Enum(e) => {
@@ -1756,31 +3960,16 @@ fn generate_union(
                         }
                         type_::Which::Struct(struct_reader) => {
                             let path_string = get_params_struct_path_string(ctx, struct_reader)?;
-                            let mut possibly_cyclical = false;
-                            if let node::Struct(st) =
-                                ctx.node_map[&struct_reader.get_type_id()].which()?
-                            {
-                                for field in st.get_fields()? {
-                                    match field.which()? {
-                                        field::Which::Slot(sl) => match sl.get_type()?.which()? {
-                                            type_::Which::List(_) => possibly_cyclical = true,
-                                            type_::Which::Struct(_) => possibly_cyclical = true,
-                                            _ => (),
-                                        },
-                                        field::Which::Group(_) => possibly_cyclical = true,
-                                    }
-                                }
-                            };
-                            if possibly_cyclical {
+                            if ctx.needs_box(node_id, struct_reader.get_type_id()) {
                                 params_enum_string.push_str(
                                     format!("\n _{enumerant_name}(Box<{}>),", path_string).as_str(),
                                 );
-                                params_impl_interior.push_str(format!("\n {params_union_name}::_{enumerant_name}(t) => t.build_capnp_struct(builder.reborrow().init_{}()),", camel.as_str()).as_str());
+                                params_impl_interior.push_str(format!("\n {params_union_name}::_{enumerant_name}(t) => t.build_capnp_struct(builder.reborrow().init_{}())?,", camel.as_str()).as_str());
                             } else {
                                 params_enum_string.push_str(
                                     format!("\n _{enumerant_name}({}),", path_string).as_str(),
                                 );
-                                params_impl_interior.push_str(format!("\n {params_union_name}::_{enumerant_name}(t) => t.build_capnp_struct(builder.reborrow().init_{}()),", camel.as_str()).as_str());
+                                params_impl_interior.push_str(format!("\n {params_union_name}::_{enumerant_name}(t) => t.build_capnp_struct(builder.reborrow().init_{}())?,", camel.as_str()).as_str());
                             }
                         }
                         type_::Which::Interface(i_t) => {
@@ -1864,7 +4053,20 @@ fn generate_union(
                         }
                     }
                 }
-                field::Which::Group(_) => (),
+                field::Which::Group(group) => {
+                    let id = group.get_type_id();
+                    if get_params(ctx, id)?.is_empty() {
+                        params_enum_string.push_str(
+                            format!(
+                                "\n _{enumerant_name}({}::{}),",
+                                ctx.get_qualified_module(id),
+                                snake_to_camel_case(ctx.get_last_name(id)?)
+                            )
+                            .as_str(),
+                        );
+                        params_impl_interior.push_str(format!("\n {params_union_name}::_{enumerant_name}(t) => t.build_capnp_struct(builder.reborrow().init_{}())?,", camel.as_str()).as_str());
+                    }
+                }
             }
         }
 
@@ -1876,7 +4078,7 @@ fn generate_union(
         getter_interior.push(Branch(vec![
             Line(format!("{dvalue} => {{")),
             indent(Line(format!(
-                "::core::result::Result::Ok({}(",
+                "::core::result::Result::Ok(Which::{}(",
                 enumerant_name.clone()
             ))),
             indent(indent(get)),
@@ -1974,7 +4176,7 @@ fn generate_union(
     ));
 
     let getter_result = Branch(vec![
-        line("#[inline]"),
+        ctx.inline_accessor(),
         Line(fmt!(ctx,
             "pub fn which(self) -> ::core::result::Result<{concrete_type}, {capnp}::NotInSchema> {{"
         )),
@@ -1993,7 +4195,137 @@ fn generate_union(
     Ok((result, getter_result, typedef, default_decls))
 }
 
+/// Generates the statements that copy the field `field` from the struct reader `other.reader`
+/// into `self.builder` as stored, without decoding its value. A group's union copies its
+/// active variant only.
+fn generate_copy_field(
+    ctx: &GeneratorContext,
+    field: schema_capnp::field::Reader,
+) -> capnp::Result<Vec<FormattedText>> {
+    use capnp::schema_capnp::*;
+
+    match field.which()? {
+        field::Slot(slot) => {
+            let offset = slot.get_offset();
+            let width = match slot.get_type()?.which()? {
+                type_::Void(()) => return Ok(Vec::new()),
+                type_::Bool(()) => {
+                    return Ok(vec![Line(format!(
+                        "self.builder.set_bool_field({offset}, other.reader.get_bool_field({offset}));"
+                    ))]);
+                }
+                type_::Int8(()) | type_::Uint8(()) => "u8",
+                type_::Int16(()) | type_::Uint16(()) | type_::Enum(_) => "u16",
+                type_::Int32(()) | type_::Uint32(()) | type_::Float32(()) => "u32",
+                type_::Int64(()) | type_::Uint64(()) | type_::Float64(()) => "u64",
+                _ => {
+                    return Ok(vec![Line(fmt!(ctx,
+                        "{capnp}::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field({offset}), {capnp}::any_pointer::Reader::new(other.reader.get_pointer_field({offset})), false)?;"
+                    ))]);
+                }
+            };
+            Ok(vec![Line(format!(
+                "self.builder.set_data_field::<{width}>({offset}, other.reader.get_data_field::<{width}>({offset}));"
+            ))])
+        }
+        field::Group(group) => {
+            let group_id = group.get_type_id();
+            let node::Struct(group) = ctx.get_node(group_id, group_id)?.which()? else {
+                return Err(Error::failed("expected a group".to_string()));
+            };
+            let mut statements = Vec::new();
+            let mut arms = Vec::new();
+            for field in group.get_fields()? {
+                let copy = generate_copy_field(ctx, field)?;
+                let discriminant_value = field.get_discriminant_value();
+                if discriminant_value == field::NO_DISCRIMINANT {
+                    statements.extend(copy);
+                } else {
+                    arms.push(Line(format!("{discriminant_value} => {{")));
+                    arms.push(indent(copy));
+                    arms.push(line("}"));
+                }
+            }
+            if group.get_discriminant_count() > 0 {
+                let offset = group.get_discriminant_offset();
+                arms.push(Line(fmt!(
+                    ctx,
+                    "x => return ::core::result::Result::Err({capnp}::NotInSchema(x).into()),"
+                )));
+                statements.push(Line(format!(
+                    "let discriminant = other.reader.get_data_field::<u16>({offset});"
+                )));
+                statements.push(Line(format!(
+                    "self.builder.set_data_field::<u16>({offset}, discriminant);"
+                )));
+                statements.push(line("match discriminant {"));
+                statements.push(indent(arms));
+                statements.push(line("}"));
+            }
+            Ok(statements)
+        }
+    }
+}
+
+/// Generates `copy_union_from()` for the builder of a struct or group with a union.
+fn generate_copy_union_from(
+    ctx: &GeneratorContext,
+    discriminant_offset: u32,
+    union_fields: &[schema_capnp::field::Reader],
+    params: &TypeParameterTexts,
+) -> capnp::Result<FormattedText> {
+    use capnp::schema_capnp::*;
+
+    let mut arms = Vec::new();
+    for field in union_fields {
+        let discriminant_value = field.get_discriminant_value();
+        let mut body = Vec::new();
+        if let field::Group(_) = field.which()? {
+            // Sets the discriminant and clears the fields of the group.
+            let styled_name = camel_to_snake_case(get_field_name(*field)?);
+            body.push(Line(format!("self.reborrow().init_{styled_name}();")));
+        } else {
+            body.push(Line(format!(
+                "self.builder.set_data_field::<u16>({discriminant_offset}, {discriminant_value});"
+            )));
+        }
+        body.extend(generate_copy_field(ctx, *field)?);
+        arms.push(Line(format!("{discriminant_value} => {{")));
+        arms.push(indent(body));
+        arms.push(line("}"));
+    }
+    arms.push(Line(fmt!(
+        ctx,
+        "x => return ::core::result::Result::Err({capnp}::NotInSchema(x).into()),"
+    )));
+
+    Ok(Branch(vec![
+        line("/// Sets the union to the variant that is active in `other`, copied from there as stored."),
+        line("/// The fields outside the union are left unchanged."),
+        line("///"),
+        line("/// Returns an `EnumValueOrUnionDiscriminantNotPresent` error without changing anything if"),
+        line("/// `other` has a variant that is not in the schema, for example one added by a newer version"),
+        line("/// of it. A union nested in a group of the active variant is handled the same way, but by"),
+        line("/// then the group has been partially copied."),
+        Line(fmt!(
+            ctx,
+            "pub fn copy_union_from(&mut self, other: Reader<'_,{}>) -> {capnp}::Result<()> {{",
+            params.params
+        )),
+        indent(vec![
+            Line(format!(
+                "match other.reader.get_data_field::<u16>({discriminant_offset}) {{"
+            )),
+            indent(arms),
+            line("}"),
+            line("::core::result::Result::Ok(())"),
+        ]),
+        line("}"),
+    ]))
+}
+
 fn generate_haser(
+    ctx: &GeneratorContext,
     discriminant_offset: u32,
     styled_name: &str,
     field: &schema_capnp::field::Reader,
@@ -2002,46 +4334,470 @@ fn generate_haser(
     use capnp::schema_capnp::*;
 
     let mut result = Vec::new();
-    let mut interior = Vec::new();
-    let member = if is_reader { "reader" } else { "builder" };
+    let mut interior = Vec::new();
+    let member = if is_reader { "reader" } else { "builder" };
+
+    let discriminant_value = field.get_discriminant_value();
+    if discriminant_value != field::NO_DISCRIMINANT {
+        result.push(ctx.inline_accessor());
+        result.push(Line(format!("pub fn is_{styled_name}(&self) -> bool {{")));
+        result.push(indent(Line(format!(
+            "self.{}.get_data_field::<u16>({}) == {}",
+            member, discriminant_offset as usize, discriminant_value as usize
+        ))));
+        result.push(line("}"));
+
+        interior.push(Line(format!(
+            "if self.{}.get_data_field::<u16>({}) != {} {{ return false; }}",
+            member, discriminant_offset as usize, discriminant_value as usize
+        )));
+    }
+    match field.which() {
+        Err(_) => {}
+        Ok(field::Group(group)) => {
+            let mut conditions =
+                group_presence_conditions(ctx, group.get_type_id(), member)?.into_iter();
+            match conditions.next() {
+                None => interior.push(line("false")),
+                Some(first) => {
+                    interior.push(Line(first));
+                    interior.push(indent(Branch(
+                        conditions.map(|c| Line(format!("|| {c}"))).collect(),
+                    )));
+                }
+            }
+            result.push(line(
+                "/// Whether any field of this group differs from its default. This reads each",
+            ));
+            result.push(line(
+                "/// field of the group, but does not follow pointers: a field that is set to an",
+            ));
+            result.push(line("/// empty struct or list counts as present."));
+            result.push(ctx.inline_accessor());
+            result.push(Line(format!("pub fn has_{styled_name}(&self) -> bool {{")));
+            result.push(indent(interior));
+            result.push(line("}"));
+        }
+        Ok(field::Slot(reg_field)) => match reg_field.get_type()?.which()? {
+            type_::Text(())
+            | type_::Data(())
+            | type_::List(_)
+            | type_::Struct(_)
+            | type_::Interface(_)
+            | type_::AnyPointer(_) => {
+                if is_reader {
+                    interior.push(Line(format!(
+                        "!self.{member}.get_pointer_field({}).is_null()",
+                        reg_field.get_offset()
+                    )));
+                } else {
+                    interior.push(Line(format!(
+                        "!self.{member}.is_pointer_field_null({})",
+                        reg_field.get_offset()
+                    )));
+                }
+                result.push(ctx.inline_accessor());
+                result.push(Line(format!("pub fn has_{styled_name}(&self) -> bool {{")));
+                result.push(indent(interior));
+                result.push(line("}"));
+            }
+            _ => {}
+        },
+    }
+
+    Ok(Branch(result))
+}
+
+/// Collects the fields of struct or group `struct_id` that are annotated with `$Rust.required`,
+/// including those of its groups. Each comes with its name, prefixed by the names of the
+/// enclosing groups, and the expression that tells whether it is missing.
+fn required_field_checks(
+    ctx: &GeneratorContext,
+    struct_id: u64,
+    member: &str,
+    conditions: &[String],
+    prefix: &str,
+    checks: &mut Vec<(String, String)>,
+) -> capnp::Result<()> {
+    use capnp::schema_capnp::*;
+
+    let node::Struct(st) = ctx.node_map[&struct_id].which()? else {
+        return Err(Error::failed("expected a struct".to_string()));
+    };
+    for field in st.get_fields()? {
+        let mut conditions = conditions.to_vec();
+        // A field of a union member that is not set is not checked.
+        if field.get_discriminant_value() != field::NO_DISCRIMINANT {
+            conditions.push(format!(
+                "self.{member}.get_data_field::<u16>({}) == {}",
+                st.get_discriminant_offset(),
+                field.get_discriminant_value()
+            ));
+        }
+        let name = format!("{prefix}{}", field.get_name()?.to_str()?);
+        match field.which()? {
+            field::Group(group) => {
+                required_field_checks(
+                    ctx,
+                    group.get_type_id(),
+                    member,
+                    &conditions,
+                    &format!("{name}."),
+                    checks,
+                )?;
+            }
+            field::Slot(slot) => {
+                if !is_required_field(field)? {
+                    continue;
+                }
+                let offset = slot.get_offset();
+                conditions.push(if member == "reader" {
+                    format!("self.reader.get_pointer_field({offset}).is_null()")
+                } else {
+                    format!("self.builder.is_pointer_field_null({offset})")
+                });
+                checks.push((name, conditions.join(" && ")));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Generates `validate()` for the Reader or Builder of struct or group `struct_id`, if it has
+/// fields annotated with `$Rust.required`.
+fn generate_validate(
+    ctx: &GeneratorContext,
+    struct_id: u64,
+    is_reader: bool,
+) -> capnp::Result<FormattedText> {
+    let member = if is_reader { "reader" } else { "builder" };
+    let mut checks = Vec::new();
+    required_field_checks(ctx, struct_id, member, &[], "", &mut checks)?;
+    if checks.is_empty() {
+        return Ok(Branch(Vec::new()));
+    }
+    let missing: Vec<_> = checks
+        .into_iter()
+        .map(|(name, check)| Line(format!("(\"{name}\", {check}),")))
+        .collect();
+    Ok(Branch(vec![
+        line("/// Fails if any of the fields annotated with `$Rust.required` is null, listing them in"),
+        line("/// the error. Fields of union members that are not set are not checked."),
+        Line(fmt!(ctx, "pub fn validate(&self) -> {capnp}::Result<()> {{")),
+        indent(vec![
+            line("let missing = ["),
+            indent(missing),
+            line("];"),
+            line("if !missing.iter().any(|&(_, is_missing)| is_missing) {"),
+            indent(line("return ::core::result::Result::Ok(());")),
+            line("}"),
+            Line(fmt!(
+                ctx,
+                "let mut error = {capnp}::Error::from_kind({capnp}::ErrorKind::Failed);"
+            )),
+            Line(format!(
+                "::core::write!(error, \"missing required fields of `{}`:\");",
+                ctx.node_map[&struct_id].get_display_name()?.to_str()?
+            )),
+            line("let mut separator = \" \";"),
+            line("for &(name, is_missing) in &missing {"),
+            indent(vec![
+                line("if is_missing {"),
+                indent(vec![
+                    line("::core::write!(error, \"{separator}{name}\");"),
+                    line("separator = \", \";"),
+                ]),
+                line("}"),
+            ]),
+            line("}"),
+            line("::core::result::Result::Err(error)"),
+        ]),
+        line("}"),
+    ]))
+}
+
+/// Whether struct or group `struct_id` has fields annotated with `$Rust.required`, which
+/// gives it a `validate()` method.
+pub(crate) fn has_required_fields(ctx: &GeneratorContext, struct_id: u64) -> capnp::Result<bool> {
+    let mut checks = Vec::new();
+    required_field_checks(ctx, struct_id, "reader", &[], "", &mut checks)?;
+    Ok(!checks.is_empty())
+}
+
+/// Documents how the getter of a list of enums reads values that are not in the schema.
+fn enum_list_getter_doc(field: schema_capnp::field::Reader) -> capnp::Result<FormattedText> {
+    use capnp::schema_capnp::*;
+
+    if let field::Slot(slot) = field.which()? {
+        if let type_::List(list) = slot.get_type()?.which()? {
+            if let type_::Enum(_) = list.get_element_type()?.which()? {
+                return Ok(Branch(vec![
+                    line("/// Elements whose value is not in the schema read as `Err(NotInSchema)`. Use"),
+                    line("/// `try_collect()` to fail on the first of them, or `iter_lenient().filter_valid()`"),
+                    line("/// to skip them."),
+                ]));
+            }
+        }
+    }
+    Ok(Branch(Vec::new()))
+}
+
+fn generate_lenient_enum_getter(
+    ctx: &GeneratorContext,
+    styled_name: &str,
+    field: &schema_capnp::field::Reader,
+    is_reader: bool,
+) -> ::capnp::Result<FormattedText> {
+    use capnp::schema_capnp::*;
+
+    let mut result = Vec::new();
+    if !ctx.lenient_enums {
+        return Ok(Branch(result));
+    }
+    let member = if is_reader { "reader" } else { "builder" };
+    if let field::Slot(reg_field) = field.which()? {
+        if let type_::Enum(e) = reg_field.get_type()?.which()? {
+            let the_mod = ctx.get_qualified_module(e.get_type_id());
+            let offset = reg_field.get_offset();
+            let raw = match reg_field.get_default_value()?.which()? {
+                value::Enum(0) => format!("self.{member}.get_data_field::<u16>({offset})"),
+                value::Enum(d) => {
+                    format!("self.{member}.get_data_field_mask::<u16>({offset}, {d})")
+                }
+                _ => return Err(Error::failed("enum default not an Enum".to_string())),
+            };
+            result.push(ctx.inline_accessor());
+            result.push(Line(format!(
+                "pub fn {}(self) -> {the_mod}OrUnknown {{",
+                ctx.lenient_getter_name(styled_name)
+            )));
+            result.push(indent(Line(format!("{the_mod}OrUnknown::from({raw})"))));
+            result.push(line("}"));
+        }
+    }
+    Ok(Branch(result))
+}
+
+// For an `AnyPointer` field that is not a generic parameter, generates a `get_foo_as::<T>()`
+// getter that reads it with `get_as_checked()`.
+fn generate_checked_any_pointer_getter(
+    ctx: &GeneratorContext,
+    styled_name: &str,
+    field: &schema_capnp::field::Reader,
+    is_reader: bool,
+) -> ::capnp::Result<FormattedText> {
+    use capnp::schema_capnp::*;
+
+    let mut result = Vec::new();
+    if let field::Slot(reg_field) = field.which()? {
+        if let type_::AnyPointer(any_pointer) = reg_field.get_type()?.which()? {
+            if let type_::any_pointer::Unconstrained(_) = any_pointer.which()? {
+                let offset = reg_field.get_offset();
+                let (member, leaf, module) = if is_reader {
+                    ("reader", "Reader", "Reader")
+                } else {
+                    ("builder", "Builder", "Builder")
+                };
+                result.push(ctx.inline_accessor());
+                result.push(Line(fmt!(
+                    ctx,
+                    "pub fn {}<_T: {capnp}::traits::Owned>(self) -> {capnp}::Result<_T::{leaf}<'a>> {{",
+                    ctx.checked_getter_name(styled_name)
+                )));
+                result.push(indent(Line(fmt!(
+                    ctx,
+                    "{capnp}::any_pointer::{module}::new(self.{member}.get_pointer_field({offset})).get_as_checked::<_T>()"
+                ))));
+                result.push(line("}"));
+            }
+        }
+    }
+    Ok(Branch(result))
+}
+
+/// The ID of `StreamResult` from stream.capnp, which a method declared as `-> stream` has as
+/// its result type.
+const STREAM_RESULT_TYPE_ID: u64 = 0x995f9a3377c0b16e;
+
+/// Generates the `METHODS` table entry for the method at `ordinal` of interface `interface_id`.
+fn method_info(
+    ctx: &GeneratorContext,
+    interface_id: u64,
+    ordinal: usize,
+    method: schema_capnp::method::Reader,
+) -> capnp::Result<FormattedText> {
+    Ok(Line(fmt!(
+        ctx,
+        "{capnp}::introspect::MethodInfo {{ interface_id: {}, ordinal: {ordinal}, name: {:?}, param_type_id: {}, result_type_id: {} }},",
+        format_u64(interface_id),
+        method.get_name()?.to_str()?,
+        format_u64(method.get_param_struct_type()),
+        format_u64(method.get_result_struct_type())
+    )))
+}
+
+/// Returns the name that the modules of `method`'s anonymous params and results structs are
+/// named after: the value of its `$Rust.paramsName` annotation if it has one, or else the
+/// method's name.
+fn method_struct_base_name(method: schema_capnp::method::Reader) -> capnp::Result<&str> {
+    for annotation in method.get_annotations()? {
+        if annotation.get_id() == PARAMS_NAME_ANNOTATION_ID {
+            let schema_capnp::value::Text(t) = annotation.get_value()?.which()? else {
+                return Err(Error::failed(
+                    "expected $Rust.paramsName annotation value to be of type Text".to_string(),
+                ));
+            };
+            let name = t?.to_str()?;
+            if name.is_empty() || !name.chars().all(|c| c == '_' || c.is_alphanumeric()) {
+                return Err(Error::failed(format!(
+                    "$Rust.paramsName annotation value `{name}` of method `{}` must be \
+                     non-empty and only contain alphanumeric characters and '_'",
+                    method.get_name()?.to_str()?
+                )));
+            }
+            return Ok(name);
+        }
+    }
+    Ok(method.get_name()?.to_str()?)
+}
+
+/// Returns the name of the module generated for `method`'s params or results struct `id`,
+/// if the struct is anonymous, which puts the module in the interface's module.
+fn method_struct_module(
+    ctx: &GeneratorContext,
+    interface_id: u64,
+    method: schema_capnp::method::Reader,
+    id: u64,
+    suffix: &str,
+) -> capnp::Result<Option<String>> {
+    if id == STREAM_RESULT_TYPE_ID || ctx.get_node(id, interface_id)?.get_scope_id() != 0 {
+        return Ok(None);
+    }
+    Ok(Some(module_name(&format!(
+        "{}{suffix}",
+        method_struct_base_name(method)?
+    ))))
+}
+
+/// Fails if two of the modules generated inside interface `interface_id`'s module would have
+/// the same name, such as the params modules of method `getFoo` and of a method annotated with
+/// `$Rust.paramsName("getFoo")`, or a params module and a nested struct.
+fn check_method_struct_modules(
+    ctx: &GeneratorContext,
+    interface_id: u64,
+    interface: schema_capnp::node::interface::Reader,
+) -> capnp::Result<()> {
+    let node = ctx.get_node(interface_id, interface_id)?;
+    let mut owners: HashMap<String, String> = HashMap::new();
+    for nested in node.get_nested_nodes()? {
+        let Some(nested_node) = ctx.node_map.get(&nested.get_id()) else {
+            continue;
+        };
+        if let Ok(schema_capnp::node::Struct(_) | schema_capnp::node::Interface(_)) =
+            nested_node.which()
+        {
+            let name = nested.get_name()?.to_str()?;
+            owners.insert(module_name(name), format!("nested node `{name}`"));
+        }
+    }
+    for method in interface.get_methods()? {
+        let owner = format!("method `{}`", method.get_name()?.to_str()?);
+        for (id, suffix) in [
+            (method.get_param_struct_type(), "Params"),
+            (method.get_result_struct_type(), "Results"),
+        ] {
+            let Some(module) = method_struct_module(ctx, interface_id, method, id, suffix)? else {
+                continue;
+            };
+            if let Some(other) = owners.insert(module.clone(), owner.clone()) {
+                return Err(Error::failed(format!(
+                    "{other} and {owner} of interface `{}` both generate a module named \
+                     `{module}`; rename one of them or give the method a $Rust.paramsName \
+                     annotation",
+                    node.get_display_name()?.to_str()?
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Generates the methods of interface `interface_id`'s `Server` trait for a `Forwarder`,
+/// each of which makes the same call on the wrapped client and copies back the response.
+/// Returns `None` if the interface is generic or has generic methods.
+fn generate_forwarder_methods(
+    ctx: &GeneratorContext,
+    interface_id: u64,
+) -> capnp::Result<Option<Vec<FormattedText>>> {
+    let node = ctx.get_node(interface_id, interface_id)?;
+    let schema_capnp::node::Interface(interface) = node.which()? else {
+        return Err(Error::failed(format!(
+            "superclass {interface_id} is not an interface"
+        )));
+    };
+    if node.get_is_generic() {
+        return Ok(None);
+    }
+
+    let the_mod = ctx.get_qualified_module(interface_id);
+    let mut result = Vec::new();
+    for (ordinal, method) in interface.get_methods()?.into_iter().enumerate() {
+        if !method.get_implicit_parameters()?.is_empty() {
+            return Ok(None);
+        }
+        let name = method.get_name()?.to_str()?;
 
-    let discriminant_value = field.get_discriminant_value();
-    if discriminant_value != field::NO_DISCRIMINANT {
-        interior.push(Line(format!(
-            "if self.{}.get_data_field::<u16>({}) != {} {{ return false; }}",
-            member, discriminant_offset as usize, discriminant_value as usize
+        // Anonymous params and results structs are generated inside the interface's module.
+        let struct_type = |id: u64, suffix: &str, brand| {
+            let scope = match method_struct_module(ctx, interface_id, method, id, suffix)? {
+                Some(module) => format!("{the_mod}::{module}"),
+                None => ctx.get_qualified_module(id),
+            };
+            do_branding(ctx, id, brand, Leaf::Owned, &scope)
+        };
+        let param_type = struct_type(
+            method.get_param_struct_type(),
+            "Params",
+            method.get_param_brand()?,
+        )?;
+        if method.get_result_struct_type() == STREAM_RESULT_TYPE_ID {
+            result.push(Line(fmt!(
+                ctx,
+                "async fn {}(&self, params: {capnp}::capability::Params<{param_type}>) -> ::core::result::Result<(), {capnp}::Error> {{",
+                module_name(name)
+            )));
+            result.push(indent(vec![
+                Line(format!(
+                    "let mut request = self.0.client.new_streaming_call::<{param_type}>({the_mod}::_private::TYPE_ID, {ordinal}, ::core::option::Option::None);"
+                )),
+                line("request.set(params.get()?)?;"),
+                line("request.send().await"),
+            ]));
+            result.push(line("}"));
+            continue;
+        }
+        let result_type = struct_type(
+            method.get_result_struct_type(),
+            "Results",
+            method.get_result_brand()?,
+        )?;
+
+        result.push(Line(fmt!(
+            ctx,
+            "async fn {}(&self, params: {capnp}::capability::Params<{param_type}>, mut results: {capnp}::capability::Results<{result_type}>) -> ::core::result::Result<(), {capnp}::Error> {{",
+            module_name(name)
         )));
+        result.push(indent(vec![
+            Line(format!(
+                "let mut request = self.0.client.new_call::<{param_type}, {result_type}>({the_mod}::_private::TYPE_ID, {ordinal}, ::core::option::Option::None);"
+            )),
+            line("request.set(params.get()?)?;"),
+            line("let response = request.send().promise.await?;"),
+            line("results.set(response.get()?)"),
+        ]));
+        result.push(line("}"));
     }
-    match field.which() {
-        Err(_) | Ok(field::Group(_)) => {}
-        Ok(field::Slot(reg_field)) => match reg_field.get_type()?.which()? {
-            type_::Text(())
-            | type_::Data(())
-            | type_::List(_)
-            | type_::Struct(_)
-            | type_::Interface(_)
-            | type_::AnyPointer(_) => {
-                if is_reader {
-                    interior.push(Line(format!(
-                        "!self.{member}.get_pointer_field({}).is_null()",
-                        reg_field.get_offset()
-                    )));
-                } else {
-                    interior.push(Line(format!(
-                        "!self.{member}.is_pointer_field_null({})",
-                        reg_field.get_offset()
-                    )));
-                }
-                result.push(line("#[inline]"));
-                result.push(Line(format!("pub fn has_{styled_name}(&self) -> bool {{")));
-                result.push(indent(interior));
-                result.push(line("}"));
-            }
-            _ => {}
-        },
-    }
-
-    Ok(Branch(result))
+    Ok(Some(result))
 }
 
 fn generate_pipeline_getter(
@@ -2050,7 +4806,7 @@ fn generate_pipeline_getter(
 ) -> ::capnp::Result<FormattedText> {
     use capnp::schema_capnp::{field, type_};
 
-    let name = get_field_name(field)?;
+    let getter_name = ctx.getter_name(&camel_to_snake_case(get_field_name(field)?));
 
     match field.which()? {
         field::Group(group) => {
@@ -2063,11 +4819,9 @@ fn generate_pipeline_getter(
 
             let the_mod = ctx.get_qualified_module(group.get_type_id());
             Ok(Branch(vec![
+                ctx.inline_accessor(),
                 Line(format!(
-                    "pub fn get_{}(&self) -> {}::Pipeline{} {{",
-                    camel_to_snake_case(name),
-                    the_mod,
-                    params_string
+                    "pub fn {getter_name}(&self) -> {the_mod}::Pipeline{params_string} {{"
                 )),
                 indent(Line(fmt!(
                     ctx,
@@ -2081,14 +4835,16 @@ fn generate_pipeline_getter(
             match typ.which()? {
                 type_::Struct(_) | type_::AnyPointer(_) => {
                     Ok(Branch(vec![
-                        Line(format!("pub fn get_{}(&self) -> {} {{", camel_to_snake_case(name), typ.type_string(ctx, Leaf::Pipeline)?)),
+                        ctx.inline_accessor(),
+                        Line(format!("pub fn {getter_name}(&self) -> {} {{", typ.type_string(ctx, Leaf::Pipeline)?)),
                         indent(Line(fmt!(ctx,"{capnp}::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field({}))", reg_field.get_offset()))),
                         line("}")
                     ]))
                 }
                 type_::Interface(_) => {
                     Ok(Branch(vec![
-                        Line(format!("pub fn get_{}(&self) -> {} {{", camel_to_snake_case(name), typ.type_string(ctx, Leaf::Client)?)),
+                        ctx.inline_accessor(),
+                        Line(format!("pub fn {getter_name}(&self) -> {} {{", typ.type_string(ctx, Leaf::Client)?)),
                         indent(Line(fmt!(ctx,"{capnp}::capability::FromClientHook::new(self._typeless.get_pointer_field({}).as_cap())", reg_field.get_offset()))),
                         line("}")
                     ]))
@@ -2101,6 +4857,18 @@ fn generate_pipeline_getter(
     }
 }
 
+/// Returns the declaration of `DISPLAY_NAME`, the name of the node as written in the schema,
+/// such as `addressbook.capnp:Person.employment`, which the panic messages of the other schema
+/// functions in the same module refer to.
+fn display_name_declaration(
+    node_reader: schema_capnp::node::Reader,
+) -> ::capnp::Result<FormattedText> {
+    Ok(Line(format!(
+        "pub const DISPLAY_NAME: &str = {:?};",
+        node_reader.get_display_name()?.to_str()?
+    )))
+}
+
 fn generate_get_field_types(
     ctx: &GeneratorContext,
     node_reader: schema_capnp::node::Reader,
@@ -2114,8 +4882,12 @@ fn generate_get_field_types(
     for (index, field) in st.get_fields()?.iter().enumerate() {
         match field.which()? {
             field::Slot(slot) => {
+                // This is the first place that resolves the types of the fields, so it is
+                // where a type missing from the request gets reported.
                 let raw_type = slot.get_type()?;
-                let typ = raw_type.type_string(ctx, Leaf::Owned)?;
+                let typ = raw_type
+                    .type_string(ctx, Leaf::Owned)
+                    .map_err(|e| ctx.field_error(node_reader.get_id(), field, e))?;
                 branches.push(Line(fmt!(
                     ctx,
                     "{} => <{} as {capnp}::introspect::Introspect>::introspect(),",
@@ -2144,10 +4916,10 @@ fn generate_get_field_types(
         }
     }
     let body = if branches.is_empty() {
-        Line("panic!(\"invalid field index {}\", index)".into())
+        Line("panic!(\"invalid field index {} of {}\", index, DISPLAY_NAME)".into())
     } else {
         branches.push(Line(
-            "_ => panic!(\"invalid field index {}\", index),".into(),
+            "_ => panic!(\"invalid field index {} of {}\", index, DISPLAY_NAME),".into(),
         ));
         Branch(vec![
             Line("match index {".into()),
@@ -2157,6 +4929,7 @@ fn generate_get_field_types(
     };
     if !node_reader.get_is_generic() {
         Ok(Branch(vec![
+            ctx.inline_schema_fn(),
             Line(fmt!(
                 ctx,
                 "pub fn get_field_types(index: u16) -> {capnp}::introspect::Type {{"
@@ -2167,6 +4940,7 @@ fn generate_get_field_types(
     } else {
         let params = node_reader.parameters_texts(ctx);
         Ok(Branch(vec![
+            ctx.inline_schema_fn(),
             Line(fmt!(
                 ctx,
                 "pub fn get_field_types<{0}>(index: u16) -> {capnp}::introspect::Type {1} {{",
@@ -2181,13 +4955,14 @@ fn generate_get_field_types(
 
 fn annotation_branch(
     ctx: &GeneratorContext,
+    node_id: u64,
     annotation: schema_capnp::annotation::Reader,
     child_index: Option<u16>,
     index: u32,
 ) -> ::capnp::Result<FormattedText> {
     use capnp::schema_capnp::node;
     let id = annotation.get_id();
-    let annotation_decl = ctx.node_map[&id];
+    let annotation_decl = ctx.get_node(id, node_id)?;
     let node::Annotation(a) = annotation_decl.which()? else {
         return Err(Error::failed("not an annotation node".into()));
     };
@@ -2218,19 +4993,29 @@ fn generate_get_annotation_types(
     let mut branches = vec![];
 
     for (idx, annotation) in node_reader.get_annotations()?.iter().enumerate() {
-        branches.push(annotation_branch(ctx, annotation, None, idx as u32)?);
+        branches.push(annotation_branch(
+            ctx,
+            node_reader.get_id(),
+            annotation,
+            None,
+            idx as u32,
+        )?);
     }
 
     match node_reader.which()? {
         node::Struct(s) => {
             for (fidx, field) in s.get_fields()?.iter().enumerate() {
                 for (idx, annotation) in field.get_annotations()?.iter().enumerate() {
-                    branches.push(annotation_branch(
-                        ctx,
-                        annotation,
-                        Some(fidx as u16),
-                        idx as u32,
-                    )?);
+                    branches.push(
+                        annotation_branch(
+                            ctx,
+                            node_reader.get_id(),
+                            annotation,
+                            Some(fidx as u16),
+                            idx as u32,
+                        )
+                        .map_err(|e| ctx.field_error(node_reader.get_id(), field, e))?,
+                    );
                 }
             }
         }
@@ -2239,6 +5024,7 @@ fn generate_get_annotation_types(
                 for (idx, annotation) in enumerant.get_annotations()?.iter().enumerate() {
                     branches.push(annotation_branch(
                         ctx,
+                        node_reader.get_id(),
                         annotation,
                         Some(fidx as u16),
                         idx as u32,
@@ -2250,10 +5036,14 @@ fn generate_get_annotation_types(
     }
 
     let body = if branches.is_empty() {
-        Line("panic!(\"invalid annotation indices ({:?}, {}) \", child_index, index)".into())
+        Line(
+            "panic!(\"invalid annotation indices ({:?}, {}) of {}\", child_index, index, DISPLAY_NAME)"
+                .into(),
+        )
     } else {
         branches.push(Line(
-            "_ => panic!(\"invalid annotation indices ({:?}, {}) \", child_index, index),".into(),
+            "_ => panic!(\"invalid annotation indices ({:?}, {}) of {}\", child_index, index, DISPLAY_NAME),"
+                .into(),
         ));
         indent(vec![
             Line("match (child_index, index) {".into()),
@@ -2264,6 +5054,7 @@ fn generate_get_annotation_types(
 
     if !node_reader.get_is_generic() {
         Ok(Branch(vec![
+            ctx.inline_schema_fn(),
             Line(fmt!(ctx,"pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> {capnp}::introspect::Type {{")),
             indent(body),
             Line("}".into()),
@@ -2271,6 +5062,7 @@ fn generate_get_annotation_types(
     } else {
         let params = node_reader.parameters_texts(ctx);
         Ok(Branch(vec![
+            ctx.inline_schema_fn(),
             Line(fmt!(ctx,
                 "pub fn get_annotation_types<{0}>(child_index: Option<u16>, index: u32) -> {capnp}::introspect::Type {1} {{",
                 params.params, params.where_clause
@@ -2281,9 +5073,262 @@ fn generate_get_annotation_types(
     }
 }
 
-fn generate_members_by_discriminant(
+/// Generates a table of the annotations on the fields of a struct, or on the enumerants of an
+/// enum, along with a function that reads one of their values through the schema in
+/// `ENCODED_NODE`. Generic structs get nothing, because their annotation types depend on the
+/// brand.
+fn generate_child_annotation_lookup(
+    ctx: &GeneratorContext,
     node_reader: schema_capnp::node::Reader,
 ) -> ::capnp::Result<FormattedText> {
+    use capnp::schema_capnp::node;
+
+    let mut pairs = Vec::new();
+    // Associated consts (on the enum) can't elide `'static`, but clippy flags it on module consts.
+    let (child, lifetime, lookup) = match node_reader.which()? {
+        node::Struct(s) => {
+            if node_reader.get_is_generic() {
+                return Ok(Branch(Vec::new()));
+            }
+            for (index, field) in s.get_fields()?.iter().enumerate() {
+                for annotation in field.get_annotations()? {
+                    pairs.push(format!("({index}, {}),", format_u64(annotation.get_id())));
+                }
+            }
+            (
+                "field",
+                "",
+                fmt!(ctx, "{capnp}::schema::StructSchema::new({capnp}::introspect::RawBrandedStructSchema {{ generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }}).get_fields().ok()?"),
+            )
+        }
+        node::Enum(e) => {
+            for (index, enumerant) in e.get_enumerants()?.iter().enumerate() {
+                for annotation in enumerant.get_annotations()? {
+                    pairs.push(format!("({index}, {}),", format_u64(annotation.get_id())));
+                }
+            }
+            let name_as_mod = module_name(ctx.get_last_name(node_reader.get_id())?);
+            (
+                "enumerant",
+                "'static ",
+                fmt!(ctx, "{capnp}::schema::EnumSchema::new({capnp}::introspect::RawEnumSchema {{ encoded_node: &{name_as_mod}::ENCODED_NODE, annotation_types: {name_as_mod}::get_annotation_types }}).get_enumerants().ok()?"),
+            )
+        }
+        _ => return Ok(Branch(Vec::new())),
+    };
+
+    let upper = child.to_uppercase();
+    Ok(Branch(vec![
+        Line(format!(
+            "/// The `({child}_index, annotation_id)` pair of each annotation on a {child}."
+        )),
+        Line(format!("pub const {upper}_ANNOTATIONS: &{lifetime}[(u16, u64)] = &[")),
+        indent(pairs.into_iter().map(Line).collect::<Vec<_>>()),
+        line("];"),
+        Line(format!(
+            "/// Returns the value of annotation `annotation_id` on the {child} at `{child}_index`, if"
+        )),
+        line("/// it has one."),
+        Line(fmt!(ctx,
+            "pub fn {child}_annotation({child}_index: u16, annotation_id: u64) -> ::core::option::Option<{capnp}::dynamic_value::Reader<'static>> {{"
+        )),
+        indent(vec![
+            Line(format!("let children = {lookup};")),
+            Line(format!("if {child}_index >= children.len() {{")),
+            indent(line("return ::core::option::Option::None;")),
+            line("}"),
+            Line(format!(
+                "children.get({child}_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()"
+            )),
+        ]),
+        line("}"),
+    ]))
+}
+
+/// Generates `FieldCounts`, `variable_words_for()` and, for non-group structs,
+/// `MIN_SIZE_WORDS` and `total_words_for()`, for sizing message buffers ahead of time.
+// Emits `_private::layout`, which exposes the wire layout of each field: byte offsets
+// for data fields, bit offsets for bools, pointer indices for pointer fields, and the
+// discriminant offset and values for unions.
+fn generate_layout_constants(
+    struct_reader: schema_capnp::node::struct_::Reader,
+) -> ::capnp::Result<FormattedText> {
+    use capnp::schema_capnp::*;
+
+    let mut consts = Vec::new();
+    if struct_reader.get_discriminant_count() > 0 {
+        consts.push(Line(format!(
+            "pub const DISCRIMINANT_OFFSET: usize = {};",
+            struct_reader.get_discriminant_offset() as usize * 2
+        )));
+    }
+    for field in struct_reader.get_fields()? {
+        let name = snake_to_upper_case(&camel_to_snake_case(get_field_name(field)?));
+        if field.get_discriminant_value() != field::NO_DISCRIMINANT {
+            consts.push(Line(format!(
+                "pub const FIELD_{name}_DISCRIMINANT: u16 = {};",
+                field.get_discriminant_value()
+            )));
+        }
+        let field::Slot(reg_field) = field.which()? else {
+            continue;
+        };
+        let offset = reg_field.get_offset() as usize;
+        let byte_size = match reg_field.get_type()?.which()? {
+            type_::Void(()) => continue,
+            type_::Bool(()) => {
+                consts.push(Line(format!(
+                    "pub const FIELD_{name}_BIT_OFFSET: usize = {offset};"
+                )));
+                continue;
+            }
+            type_::Int8(()) | type_::Uint8(()) => 1,
+            type_::Int16(()) | type_::Uint16(()) | type_::Enum(_) => 2,
+            type_::Int32(()) | type_::Uint32(()) | type_::Float32(()) => 4,
+            type_::Int64(()) | type_::Uint64(()) | type_::Float64(()) => 8,
+            type_::Text(())
+            | type_::Data(())
+            | type_::List(_)
+            | type_::Struct(_)
+            | type_::Interface(_)
+            | type_::AnyPointer(_) => {
+                consts.push(Line(format!(
+                    "pub const FIELD_{name}_POINTER_INDEX: usize = {offset};"
+                )));
+                continue;
+            }
+        };
+        consts.push(Line(format!(
+            "pub const FIELD_{name}_OFFSET: usize = {};",
+            offset * byte_size
+        )));
+    }
+
+    Ok(Branch(vec![
+        line("pub mod layout {"),
+        indent(consts),
+        line("}"),
+    ]))
+}
+
+fn generate_size_bounds(
+    ctx: &GeneratorContext,
+    struct_reader: schema_capnp::node::struct_::Reader,
+) -> ::capnp::Result<FormattedText> {
+    use capnp::schema_capnp::*;
+
+    let mut count_fields = Vec::new();
+    let mut count_defaults = Vec::new();
+    let mut terms = Vec::new();
+    for field in struct_reader.get_fields()? {
+        let name = module_name(get_field_name(field)?);
+        let (default, words) = match field.which()? {
+            field::Group(group) => {
+                let the_mod = ctx.get_qualified_module(group.get_type_id());
+                count_fields.push(Line(format!("pub {name}: {the_mod}::FieldCounts,")));
+                count_defaults.push(Line(format!("{name}: {the_mod}::FieldCounts::new(),")));
+                terms.push(format!("{the_mod}::variable_words_for(counts.{name})"));
+                continue;
+            }
+            field::Slot(reg_field) => match reg_field.get_type()?.which()? {
+                type_::Text(()) => ("0".to_string(), format!("(counts.{name} + 8) / 8")),
+                type_::Data(()) => ("0".to_string(), format!("(counts.{name} + 7) / 8")),
+                type_::Struct(st) => (
+                    format!(
+                        "{}::MIN_SIZE_WORDS - 1",
+                        ctx.get_qualified_module(st.get_type_id())
+                    ),
+                    format!("counts.{name}"),
+                ),
+                type_::AnyPointer(_) => ("0".to_string(), format!("counts.{name}")),
+                type_::List(ot) => {
+                    let words = match ot.get_element_type()?.which()? {
+                        type_::Void(()) => "0".to_string(),
+                        type_::Bool(()) => format!("(counts.{name} + 63) / 64"),
+                        type_::Int8(()) | type_::Uint8(()) => format!("(counts.{name} + 7) / 8"),
+                        type_::Int16(()) | type_::Uint16(()) | type_::Enum(_) => {
+                            format!("(counts.{name} * 2 + 7) / 8")
+                        }
+                        type_::Int32(()) | type_::Uint32(()) | type_::Float32(()) => {
+                            format!("(counts.{name} * 4 + 7) / 8")
+                        }
+                        type_::Struct(st) => format!(
+                            "1 + counts.{name} * ({}::MIN_SIZE_WORDS - 1)",
+                            ctx.get_qualified_module(st.get_type_id())
+                        ),
+                        // 64-bit values, and pointers whose targets are not counted.
+                        _ => format!("counts.{name}"),
+                    };
+                    ("0".to_string(), words)
+                }
+                _ => continue,
+            },
+        };
+        count_fields.push(Line(format!("pub {name}: usize,")));
+        count_defaults.push(Line(format!("{name}: {default},")));
+        terms.push(words);
+    }
+
+    let mut result = vec![
+        line("/// Sizes of the variable-size fields of this struct, for computing message size bounds."),
+        line("///"),
+        line("/// Text and data fields are measured in bytes, excluding the NUL terminator of text, and"),
+        line("/// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding"),
+        line("/// the pointer to them. Objects that list elements point to are not included."),
+        line("#[derive(Clone, Copy, Debug, PartialEq, Eq)]"),
+        line("pub struct FieldCounts {"),
+        indent(count_fields),
+        line("}"),
+        line("impl FieldCounts {"),
+        indent(vec![
+            line("/// Zero for all fields except structs, which get the size of a struct with no content."),
+            line("pub const fn new() -> Self {"),
+            indent(line("Self {")),
+            indent(indent(count_defaults)),
+            indent(line("}")),
+            line("}"),
+        ]),
+        line("}"),
+        line("impl ::core::default::Default for FieldCounts {"),
+        indent(line("fn default() -> Self { Self::new() }")),
+        line("}"),
+        line("/// An upper bound on the words that the variable-size fields take up beyond the struct itself."),
+    ];
+    if terms.is_empty() {
+        result.push(line(
+            "pub const fn variable_words_for(_counts: FieldCounts) -> usize { 0 }",
+        ));
+    } else {
+        result.push(line(
+            "pub const fn variable_words_for(counts: FieldCounts) -> usize {",
+        ));
+        result.push(indent(Line(terms.join(" + "))));
+        result.push(line("}"));
+    }
+
+    if !struct_reader.get_is_group() {
+        result.push(line("/// Words taken up by a message holding just this struct: its data and pointer sections"));
+        result.push(line("/// plus the root pointer."));
+        result.push(Line(format!(
+            "pub const MIN_SIZE_WORDS: usize = {} + {} + 1;",
+            struct_reader.get_data_word_count(),
+            struct_reader.get_pointer_count()
+        )));
+        result.push(line("/// An upper bound on the words that a single-segment message holding this struct takes up."));
+        result.push(line(
+            "pub const fn total_words_for(counts: FieldCounts) -> usize {",
+        ));
+        result.push(indent(line("MIN_SIZE_WORDS + variable_words_for(counts)")));
+        result.push(line("}"));
+    }
+    Ok(Branch(result))
+}
+
+// Returns the `_private` statics that `RAW_SCHEMA` refers to, and, for a struct with a
+// union, the `UNION_MEMBERS` table and `discriminant_name()` of the struct module.
+fn generate_members_by_discriminant(
+    node_reader: schema_capnp::node::Reader,
+) -> ::capnp::Result<(FormattedText, FormattedText)> {
     use capnp::schema_capnp::field;
     let st = match node_reader.which()? {
         schema_capnp::node::Struct(st) => st,
@@ -2297,7 +5342,7 @@ fn generate_members_by_discriminant(
         if disc == field::NO_DISCRIMINANT {
             nonunion_member_indexes.push(index);
         } else {
-            union_member_indexes.push((disc, index));
+            union_member_indexes.push((disc, index, field.get_name()?.to_str()?));
         }
     }
     union_member_indexes.sort();
@@ -2312,16 +5357,38 @@ fn generate_members_by_discriminant(
     nonunion_string += "];";
 
     let mut members_by_disc: String = "pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[".into();
+    let mut union_members = Vec::new();
     for idx in 0..union_member_indexes.len() {
-        let (disc, index) = union_member_indexes[idx];
+        let (disc, index, name) = union_member_indexes[idx];
         assert_eq!(idx, disc as usize);
         members_by_disc += &format!("{index}");
         if idx + 1 < union_member_indexes.len() {
             members_by_disc += ",";
         }
+        union_members.push(format!("({disc}, {name:?}, {index})"));
     }
     members_by_disc += "];";
-    Ok(Branch(vec![Line(nonunion_string), Line(members_by_disc)]))
+
+    let union_metadata = if union_members.is_empty() {
+        Branch(Vec::new())
+    } else {
+        Branch(vec![
+            line("/// The members of the union, as `(discriminant, name, field index)` in discriminant order."),
+            Line(format!(
+                "pub static UNION_MEMBERS: &[(u16, &str, u16)] = &[{}];",
+                union_members.join(", ")
+            )),
+            line("/// Returns the schema name of the union member with discriminant `d`."),
+            line("pub fn discriminant_name(d: u16) -> ::core::option::Option<&'static str> {"),
+            indent(line("UNION_MEMBERS.get(d as usize).map(|member| member.1)")),
+            line("}"),
+            BlankLine,
+        ])
+    };
+    Ok((
+        Branch(vec![Line(nonunion_string), Line(members_by_disc)]),
+        union_metadata,
+    ))
 }
 
 // We need this to work around the fact that Rust does not allow typedefs
@@ -2332,9 +5399,25 @@ fn get_ty_params_of_brand(
 ) -> ::capnp::Result<String> {
     let mut acc = HashSet::new();
     get_ty_params_of_brand_helper(ctx, &mut acc, brand)?;
+
+    // List the parameters in declaration order (outer scopes first, as in `get_params()`)
+    // rather than in `HashSet` order, so that the generated code is the same on every run.
+    let scope_depth = |mut scope_id: u64| {
+        let mut depth = 0;
+        while let Some(node) = ctx.node_map.get(&scope_id) {
+            scope_id = node.get_scope_id();
+            depth += 1;
+        }
+        depth
+    };
+    let mut acc: Vec<(u64, u16)> = acc.into_iter().collect();
+    acc.sort_by_key(|&(scope_id, parameter_index)| {
+        (scope_depth(scope_id), scope_id, parameter_index)
+    });
+
     let mut result = String::new();
-    for (scope_id, parameter_index) in acc.into_iter() {
-        let node = ctx.node_map[&scope_id];
+    for (scope_id, parameter_index) in acc {
+        let node = ctx.get_node(scope_id, scope_id)?;
         let p = node.get_parameters()?.get(u32::from(parameter_index));
         result.push_str(p.get_name()?.to_str()?);
         result.push(',');
@@ -2393,33 +5476,247 @@ fn get_ty_params_of_type_helper(
 
 fn get_ty_params_of_brand_helper(
     ctx: &GeneratorContext,
-    accumulator: &mut HashSet<(u64, u16)>,
-    brand: schema_capnp::brand::Reader,
-) -> ::capnp::Result<()> {
-    for scope in brand.get_scopes()? {
-        let scope_id = scope.get_scope_id();
-        match scope.which()? {
-            schema_capnp::brand::scope::Bind(bind) => {
-                for binding in bind? {
-                    match binding.which()? {
-                        schema_capnp::brand::binding::Unbound(()) => {}
-                        schema_capnp::brand::binding::Type(t) => {
-                            get_ty_params_of_type_helper(ctx, accumulator, t?)?
-                        }
+    accumulator: &mut HashSet<(u64, u16)>,
+    brand: schema_capnp::brand::Reader,
+) -> ::capnp::Result<()> {
+    for scope in brand.get_scopes()? {
+        let scope_id = scope.get_scope_id();
+        match scope.which()? {
+            schema_capnp::brand::scope::Bind(bind) => {
+                for binding in bind? {
+                    match binding.which()? {
+                        schema_capnp::brand::binding::Unbound(()) => {}
+                        schema_capnp::brand::binding::Type(t) => {
+                            get_ty_params_of_type_helper(ctx, accumulator, t?)?
+                        }
+                    }
+                }
+            }
+            schema_capnp::brand::scope::Inherit(()) => {
+                let parameters = ctx.get_node(scope_id, scope_id)?.get_parameters()?;
+                for idx in 0..parameters.len() {
+                    accumulator.insert((scope_id, idx as u16));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Adds to `accumulator` the indices of the implicit method parameters that `brand` binds.
+fn get_implicit_params_of_brand(
+    accumulator: &mut HashSet<u16>,
+    brand: schema_capnp::brand::Reader,
+) -> ::capnp::Result<()> {
+    for scope in brand.get_scopes()? {
+        if let schema_capnp::brand::scope::Bind(bind) = scope.which()? {
+            for binding in bind? {
+                if let schema_capnp::brand::binding::Type(t) = binding.which()? {
+                    get_implicit_params_of_type(accumulator, t?)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn get_implicit_params_of_type(
+    accumulator: &mut HashSet<u16>,
+    typ: schema_capnp::type_::Reader,
+) -> ::capnp::Result<()> {
+    match typ.which()? {
+        type_::AnyPointer(p) => {
+            if let type_::any_pointer::ImplicitMethodParameter(p) = p.which()? {
+                accumulator.insert(p.get_parameter_index());
+            }
+        }
+        type_::List(list) => get_implicit_params_of_type(accumulator, list.get_element_type()?)?,
+        type_::Enum(e) => get_implicit_params_of_brand(accumulator, e.get_brand()?)?,
+        type_::Struct(s) => get_implicit_params_of_brand(accumulator, s.get_brand()?)?,
+        type_::Interface(i) => get_implicit_params_of_brand(accumulator, i.get_brand()?)?,
+        _ => {}
+    }
+    Ok(())
+}
+
+// Returns the IDs of the file `file_id` and of all nodes declared in it, including groups
+// and the implicit param and result structs of methods, in ascending order.
+fn file_node_ids(ctx: &GeneratorContext, file_id: u64) -> Vec<u64> {
+    let mut ids = ctx
+        .node_map
+        .keys()
+        .copied()
+        .filter(|&id| {
+            let mut id = id;
+            while id != file_id {
+                match ctx.node_parents.get(&id) {
+                    Some(&parent) if parent != 0 => id = parent,
+                    _ => return false,
+                }
+            }
+            true
+        })
+        .collect::<Vec<_>>();
+    ids.sort_unstable();
+    ids
+}
+
+// Hashes the canonical encodings of the nodes `ids` with 64-bit FNV-1a. Nodes do not record
+// source positions, so the result only changes if the schema does.
+fn schema_fingerprint(ctx: &GeneratorContext, ids: &[u64]) -> ::capnp::Result<u64> {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for &id in ids {
+        let node = ctx.get_node(id, id)?;
+        let allocator = capnp::message::HeapAllocator::new()
+            .first_segment_words(node.total_size()?.word_count as u32 + 1);
+        let mut message = capnp::message::Builder::new(allocator);
+        message.set_root_canonical(node)?;
+        let words = message.get_segments_for_output()[0];
+        for byte in id
+            .to_le_bytes()
+            .iter()
+            .chain(&(words.len() as u64).to_le_bytes())
+            .chain(words)
+        {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+    Ok(hash)
+}
+
+// Generates the constants that identify the schema of the file `file_id`.
+fn generate_file_constants(ctx: &GeneratorContext, file_id: u64) -> ::capnp::Result<FormattedText> {
+    let ids = file_node_ids(ctx, file_id);
+    let fingerprint = schema_fingerprint(ctx, &ids)?;
+    let ids = ids
+        .iter()
+        .map(|id| format!("0x{id:016x}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Ok(Branch(vec![
+        line("/// The ID of the schema file."),
+        Line(format!("pub const FILE_ID: u64 = 0x{file_id:016x};")),
+        line("/// The IDs of the file and of all nodes declared in it, in ascending order."),
+        Line(format!("pub static NODE_IDS: &[u64] = &[{ids}];")),
+        line("/// A hash of the canonical encodings of the nodes in `NODE_IDS`. Equal fingerprints mean"),
+        line("/// equal schemas, regardless of formatting and comments."),
+        Line(format!(
+            "pub const SCHEMA_FINGERPRINT: u64 = 0x{fingerprint:016x};"
+        )),
+    ]))
+}
+
+// Returns the items that the extensions added with `add_module_extension()` generate for the
+// node `node_id`.
+fn generate_module_extensions(
+    ctx: &GeneratorContext,
+    node_id: u64,
+) -> ::capnp::Result<FormattedText> {
+    let mut result = Vec::new();
+    for extension in &ctx.module_extensions {
+        if let Some(text) = extension(ctx, node_id)? {
+            result.extend(text.lines().map(|l| Line(l.to_string())));
+        }
+    }
+    Ok(Branch(result))
+}
+
+/// Fails if two members of node `node_id` generate Rust items with the same name, which rustc
+/// would otherwise report against the generated code. `members` pairs each generated name with
+/// a description of the member it comes from, such as "field `fooBar`".
+fn check_member_names(
+    ctx: &GeneratorContext,
+    node_id: u64,
+    members: Vec<(String, String)>,
+) -> ::capnp::Result<()> {
+    let mut owners: HashMap<String, String> = HashMap::new();
+    for (name, owner) in members {
+        match owners.get(&name) {
+            Some(other) if *other != owner => {
+                return Err(Error::failed(format!(
+                    "{other} and {owner} of `{}` both generate a Rust item named `{name}`; \
+                     rename one of them with a $Rust.name annotation",
+                    ctx.get_node(node_id, node_id)?
+                        .get_display_name()?
+                        .to_str()?
+                )));
+            }
+            _ => {
+                owners.insert(name, owner);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the names of the accessors generated for the fields of a struct, paired with the
+/// field that each one belongs to, for `check_member_names()`.
+fn field_member_names(
+    ctx: &GeneratorContext,
+    struct_reader: schema_capnp::node::struct_::Reader,
+) -> ::capnp::Result<Vec<(String, String)>> {
+    use capnp::schema_capnp::{field, type_};
+
+    let mut members = Vec::new();
+    for field in struct_reader.get_fields()? {
+        let styled_name = camel_to_snake_case(get_field_name(field)?);
+        let owner = format!("field `{}`", field.get_name()?.to_str()?);
+        let mut names = Vec::new();
+        if field.get_discriminant_value() == field::NO_DISCRIMINANT {
+            names.push(ctx.getter_name(&styled_name));
+        }
+        match field.which()? {
+            field::Group(_) => names.push(format!("init_{styled_name}")),
+            field::Slot(slot) => {
+                let typ = slot.get_type()?;
+                let (setter, initter, haser) = match typ.which()? {
+                    type_::Text(()) | type_::Data(()) | type_::List(_) | type_::Struct(_) => {
+                        (true, true, true)
                     }
+                    type_::AnyPointer(_) => (typ.is_parameter()?, true, true),
+                    type_::Interface(_) => (true, false, true),
+                    _ => (true, false, false),
+                };
+                if setter {
+                    names.push(format!("set_{styled_name}"));
                 }
-            }
-            schema_capnp::brand::scope::Inherit(()) => {
-                let parameters = ctx.node_map[&scope_id].get_parameters()?;
-                for idx in 0..parameters.len() {
-                    accumulator.insert((scope_id, idx as u16));
+                if initter {
+                    names.push(format!("init_{styled_name}"));
+                }
+                if haser {
+                    names.push(format!("has_{styled_name}"));
                 }
             }
         }
+        members.extend(names.into_iter().map(|name| (name, owner.clone())));
     }
-    Ok(())
+    Ok(members)
+}
+
+/// The node that [`generate_node()`] is generating code for, as it hands it to the generator
+/// for the node's kind.
+#[derive(Clone, Copy)]
+struct NodeContext<'a, 'b> {
+    ctx: &'a GeneratorContext<'b>,
+    id: u64,
+
+    /// The name of the module that holds the code of the node.
+    name: &'a str,
+
+    reader: schema_capnp::node::Reader<'b>,
 }
 
+/// Generates the code of node `node_id`, named `node_name`, and of the nodes nested in it.
+///
+/// This generates the nested nodes and then hands them to the generator for the kind of the
+/// node, such as [`generate_struct_node()`], which places them in its output.
+///
+/// The fields of the convenience params struct of a struct node are written as text into
+/// `rust_struct_inner`, and the statements of its `build_capnp_struct()` into
+/// `rust_struct_impl_inner`. If `is_params_struct`, the node is the params or results struct of
+/// a method, and the caller uses the text as the arguments and body of the client's
+/// `build_*_request()` methods instead of generating a params struct.
 fn generate_node(
     ctx: &GeneratorContext,
     node_id: u64,
@@ -2430,10 +5727,12 @@ fn generate_node(
 ) -> ::capnp::Result<FormattedText> {
     use capnp::schema_capnp::*;
 
-    let mut output: Vec<FormattedText> = Vec::new();
     let mut nested_output: Vec<FormattedText> = Vec::new();
 
-    let node_reader = &ctx.node_map[&node_id];
+    let node_reader = ctx.get_node(
+        node_id,
+        ctx.node_parents.get(&node_id).copied().unwrap_or(node_id),
+    )?;
     let nested_nodes = node_reader.get_nested_nodes()?;
     for nested_node in nested_nodes {
         let id = nested_node.get_id();
@@ -2446,1156 +5745,1715 @@ fn generate_node(
             false,
         )?);
     }
+    if let Some(reexports) = ctx.alias_reexports.get(&node_id) {
+        nested_output.push(BlankLine);
+        nested_output.push(Branch(reexports.clone()));
+    }
 
+    let node = NodeContext {
+        ctx,
+        id: node_id,
+        name: node_name,
+        reader: node_reader,
+    };
     match node_reader.which()? {
-        node::File(()) => {
-            output.push(Branch(nested_output));
-        }
-        node::Struct(struct_reader) => {
-            let params = node_reader.parameters_texts(ctx);
-            output.push(BlankLine);
-
-            let is_generic = node_reader.get_is_generic();
-            if is_generic {
-                output.push(Line(format!(
-                    "pub mod {} {{ /* {} */",
-                    node_name,
-                    params.expanded_list.join(",")
-                )));
-            } else {
-                output.push(Line(format!("pub mod {node_name} {{")));
-            }
-            let bracketed_params = if params.params.is_empty() {
-                "".to_string()
-            } else {
-                format!("<{}>", params.params)
-            };
+        node::File(()) => generate_file_node(&node, nested_output),
+        node::Struct(struct_reader) => generate_struct_node(
+            &node,
+            struct_reader,
+            nested_output,
+            rust_struct_inner,
+            rust_struct_impl_inner,
+            is_params_struct,
+        ),
+        node::Enum(enum_reader) => generate_enum_node(&node, enum_reader),
+        node::Interface(interface) => generate_interface_node(&node, interface, nested_output),
+        node::Const(c) => generate_const_node(&node, c),
+        node::Annotation(annotation_reader) => generate_annotation_node(&node, annotation_reader),
+    }
+}
 
-            let mut preamble = Vec::new();
-            let mut builder_members = Vec::new();
-            let mut reader_members = Vec::new();
-            let mut union_fields = Vec::new();
-            let mut which_enums = Vec::new();
-            let mut pipeline_impl_interior = Vec::new();
-            let mut private_mod_interior = Vec::new();
+/// Generates the constants of file `node`, followed by `nested_output`, the code of the nodes
+/// declared in it.
+fn generate_file_node(
+    node: &NodeContext,
+    nested_output: Vec<FormattedText>,
+) -> ::capnp::Result<FormattedText> {
+    Ok(Branch(vec![
+        generate_file_constants(node.ctx, node.id)?,
+        Branch(nested_output),
+    ]))
+}
 
-            let data_size = struct_reader.get_data_word_count();
-            let pointer_size = struct_reader.get_pointer_count();
-            let discriminant_count = struct_reader.get_discriminant_count();
-            let discriminant_offset = struct_reader.get_discriminant_offset();
+/// Generates the module of struct `node`, with its `Reader`, `Builder` and `Pipeline`, its
+/// convenience params struct, and `nested_output` along with the code of its groups.
+///
+/// See [`generate_node()`] for `rust_struct_inner`, `rust_struct_impl_inner` and
+/// `is_params_struct`.
+fn generate_struct_node<'a>(
+    node: &NodeContext<'_, 'a>,
+    struct_reader: schema_capnp::node::struct_::Reader<'a>,
+    nested_output: Vec<FormattedText>,
+    rust_struct_inner: &mut String,
+    rust_struct_impl_inner: &mut String,
+    is_params_struct: bool,
+) -> ::capnp::Result<FormattedText> {
+    // The struct gets its own `_private` module, so it starts over with the defaults, and the
+    // enclosing struct, if this is one of its groups, picks up where it left off.
+    let outer_default_value_words = node.ctx.default_value_words.take();
+    let result = generate_struct_module(
+        node,
+        struct_reader,
+        nested_output,
+        rust_struct_inner,
+        rust_struct_impl_inner,
+        is_params_struct,
+    );
+    node.ctx
+        .default_value_words
+        .replace(outer_default_value_words);
+    result
+}
 
-            private_mod_interior.push(crate::pointer_constants::node_word_array_declaration(
-                ctx,
-                "ENCODED_NODE",
-                *node_reader,
-                crate::pointer_constants::WordArrayDeclarationOptions { public: true },
-            )?);
+fn generate_struct_module<'a>(
+    node: &NodeContext<'_, 'a>,
+    struct_reader: schema_capnp::node::struct_::Reader<'a>,
+    mut nested_output: Vec<FormattedText>,
+    rust_struct_inner: &mut String,
+    rust_struct_impl_inner: &mut String,
+    is_params_struct: bool,
+) -> ::capnp::Result<FormattedText> {
+    use capnp::schema_capnp::*;
 
-            private_mod_interior.push(generate_get_field_types(ctx, *node_reader)?);
-            private_mod_interior.push(generate_get_annotation_types(ctx, *node_reader)?);
+    let NodeContext {
+        ctx,
+        id: node_id,
+        name: node_name,
+        reader: node_reader,
+    } = *node;
+    let mut output = Vec::new();
+    check_member_names(ctx, node_id, field_member_names(ctx, struct_reader)?)?;
+    let params = node_reader.parameters_texts(ctx);
+    output.push(BlankLine);
+
+    let is_generic = node_reader.get_is_generic();
+    if is_generic {
+        output.push(Line(format!(
+            "pub mod {} {{ /* {} */",
+            node_name,
+            params.expanded_list.join(",")
+        )));
+    } else {
+        output.push(Line(format!("pub mod {node_name} {{")));
+    }
+    let bracketed_params = if params.params.is_empty() {
+        "".to_string()
+    } else {
+        format!("<{}>", params.params)
+    };
 
-            // `static` instead of `const` so that this has a fixed memory address
-            // and we can check equality of `RawStructSchema` values by comparing pointers.
-            private_mod_interior.push(Branch(vec![
-                Line(fmt!(ctx,"pub static RAW_SCHEMA: {capnp}::introspect::RawStructSchema = {capnp}::introspect::RawStructSchema {{")),
-                indent(vec![
-                    Line("encoded_node: &ENCODED_NODE,".into()),
-                    Line("nonunion_members: NONUNION_MEMBERS,".into()),
-                    Line("members_by_discriminant: MEMBERS_BY_DISCRIMINANT,".into()),
-                ]),
-                Line("};".into()),
-            ]));
+    let mut preamble = Vec::new();
+    let mut builder_members = Vec::new();
+    let mut reader_members = Vec::new();
+    let mut union_fields = Vec::new();
+    let mut which_enums = Vec::new();
+    let mut pipeline_impl_interior = Vec::new();
+    let mut private_mod_interior = Vec::new();
 
-            private_mod_interior.push(generate_members_by_discriminant(*node_reader)?);
+    let data_size = struct_reader.get_data_word_count();
+    let pointer_size = struct_reader.get_pointer_count();
+    let discriminant_count = struct_reader.get_discriminant_count();
+    let discriminant_offset = struct_reader.get_discriminant_offset();
 
-            let mut params_struct_string = String::new();
-            let mut params_struct_impl_string = String::new();
-            params_struct_string
-                .push_str(format!("pub struct {} {{", snake_to_camel_case(node_name)).as_str());
-            params_struct_impl_string
-                .push_str(format!("impl {} {{", snake_to_camel_case(node_name)).as_str());
-            params_struct_impl_string.push_str(
-                format!(
-                    "\npub fn build_capnp_struct<'a,{}>(self, mut builder: Builder<'a,{}>) {} {{",
-                    params.params, params.params, params.where_clause
-                )
-                .as_str(),
-            );
-            let mut union_only_struct = true;
+    private_mod_interior.push(crate::pointer_constants::node_word_array_declaration(
+        ctx,
+        "ENCODED_NODE",
+        node_reader,
+        crate::pointer_constants::WordArrayDeclarationOptions { public: true },
+    )?);
+
+    private_mod_interior.push(display_name_declaration(node_reader)?);
+    private_mod_interior.push(generate_get_field_types(ctx, node_reader)?);
+    private_mod_interior.push(generate_get_annotation_types(ctx, node_reader)?);
+
+    // `static` instead of `const` so that this has a fixed memory address
+    // and we can check equality of `RawStructSchema` values by comparing pointers.
+    private_mod_interior.push(Branch(vec![
+        Line(fmt!(ctx,"pub static RAW_SCHEMA: {capnp}::introspect::RawStructSchema = {capnp}::introspect::RawStructSchema {{")),
+        indent(vec![
+            Line("encoded_node: &ENCODED_NODE,".into()),
+            Line("nonunion_members: NONUNION_MEMBERS,".into()),
+            Line("members_by_discriminant: MEMBERS_BY_DISCRIMINANT,".into()),
+        ]),
+        Line("};".into()),
+    ]));
 
-            let fields = struct_reader.get_fields()?;
-            for field in fields {
-                let name = get_field_name(field)?;
-                let styled_name = camel_to_snake_case(name);
+    let (members_by_discriminant, union_metadata) = generate_members_by_discriminant(node_reader)?;
+    private_mod_interior.push(members_by_discriminant);
+    private_mod_interior.push(generate_layout_constants(struct_reader)?);
 
-                let discriminant_value = field.get_discriminant_value();
-                let is_union_field = discriminant_value != field::NO_DISCRIMINANT;
-
-                if !is_union_field {
-                    union_only_struct = false;
-                    pipeline_impl_interior.push(generate_pipeline_getter(ctx, field)?);
-                    let (ty, get, default_decl) = getter_text(ctx, &field, true, true)?;
-                    if let Some(default) = default_decl {
-                        private_mod_interior.push(default.clone());
-                    }
-                    reader_members.push(Branch(vec![
-                        line("#[inline]"),
-                        Line(format!("pub fn get_{styled_name}(self) {ty} {{")),
-                        indent(get),
-                        line("}"),
-                    ]));
-
-                    let (ty_b, get_b, _) = getter_text(ctx, &field, false, true)?;
-                    builder_members.push(Branch(vec![
-                        line("#[inline]"),
-                        Line(format!("pub fn get_{styled_name}(self) {ty_b} {{")),
-                        indent(get_b),
-                        line("}"),
-                    ]));
-                } else {
-                    union_fields.push(field);
-                }
+    let mut params_struct = ParamsStructText::new(ctx, node_name, &params);
+    let mut union_only_struct = true;
 
-                builder_members.push(generate_setter(
-                    ctx,
-                    discriminant_offset,
-                    &styled_name,
-                    &field,
-                    rust_struct_inner,
-                    rust_struct_impl_inner,
-                    is_params_struct,
-                    node_name,
-                )?);
+    let fields = struct_reader.get_fields()?;
+    for field in fields {
+        let name = get_field_name(field)?;
+        let styled_name = camel_to_snake_case(name);
+
+        let discriminant_value = field.get_discriminant_value();
+        let is_union_field = discriminant_value != field::NO_DISCRIMINANT;
+
+        if !is_union_field {
+            union_only_struct = false;
+            pipeline_impl_interior.push(generate_pipeline_getter(ctx, field)?);
+            let (ty, get, default_decl) = getter_text(ctx, &field, true, true)?;
+            if let Some(default) = default_decl {
+                private_mod_interior.push(default.clone());
+            }
+            reader_members.push(Branch(vec![
+                enum_list_getter_doc(field)?,
+                ctx.inline_accessor(),
+                Line(format!(
+                    "pub fn {}(self) {ty} {{",
+                    ctx.getter_name(&styled_name)
+                )),
+                indent(get),
+                line("}"),
+            ]));
+            reader_members.push(generate_lenient_enum_getter(
+                ctx,
+                &styled_name,
+                &field,
+                true,
+            )?);
+            reader_members.push(generate_checked_any_pointer_getter(
+                ctx,
+                &styled_name,
+                &field,
+                true,
+            )?);
 
-                reader_members.push(generate_haser(
-                    discriminant_offset,
-                    &styled_name,
-                    &field,
-                    true,
-                )?);
-                builder_members.push(generate_haser(
-                    discriminant_offset,
-                    &styled_name,
-                    &field,
-                    false,
-                )?);
+            let (ty_b, get_b, _) = getter_text(ctx, &field, false, true)?;
+            builder_members.push(Branch(vec![
+                ctx.inline_accessor(),
+                Line(format!(
+                    "pub fn {}(self) {ty_b} {{",
+                    ctx.getter_name(&styled_name)
+                )),
+                indent(get_b),
+                line("}"),
+            ]));
+            builder_members.push(generate_lenient_enum_getter(
+                ctx,
+                &styled_name,
+                &field,
+                false,
+            )?);
+            builder_members.push(generate_checked_any_pointer_getter(
+                ctx,
+                &styled_name,
+                &field,
+                false,
+            )?);
+        } else {
+            union_fields.push(field);
+        }
 
-                if let Ok(field::Group(group)) = field.which() {
-                    let id = group.get_type_id();
-                    let text = generate_node(
-                        ctx,
-                        id,
-                        ctx.get_last_name(id)?,
-                        &mut String::new(),
-                        &mut String::new(),
-                        false,
-                    )?;
-                    nested_output.push(text);
-                }
-            }
-            let mut params_enum_string = String::new();
-            let mut params_union_name: String;
-            if discriminant_count > 0 {
-                if union_only_struct {
-                    params_union_name = snake_to_camel_case(node_name);
-                    params_enum_string = format!("pub enum {params_union_name} {{");
-                    params_struct_string = "".to_string();
-                } else {
-                    params_union_name = snake_to_camel_case(node_name);
-                    params_union_name.push_str("Union");
-                    params_enum_string = format!("pub enum {params_union_name} {{");
-                }
-                params_enum_string.push_str("\n UNINITIALIZED,");
+        builder_members.push(generate_setter(
+            ctx,
+            discriminant_offset,
+            &styled_name,
+            &field,
+            rust_struct_inner,
+            rust_struct_impl_inner,
+            is_params_struct,
+            node_id,
+        )?);
 
-                let (which_enums1, union_getter, typedef, mut default_decls) = generate_union(
-                    ctx,
-                    discriminant_offset,
-                    &union_fields,
-                    true,
-                    &params,
-                    &mut params_struct_string,
-                    &mut params_struct_impl_string,
-                    &mut params_enum_string,
-                    true,
-                    union_only_struct,
-                    &params_union_name,
-                )?;
-                which_enums.push(which_enums1);
-                which_enums.push(typedef);
-                reader_members.push(union_getter);
+        reader_members.push(generate_haser(
+            ctx,
+            discriminant_offset,
+            &styled_name,
+            &field,
+            true,
+        )?);
+        builder_members.push(generate_haser(
+            ctx,
+            discriminant_offset,
+            &styled_name,
+            &field,
+            false,
+        )?);
 
-                private_mod_interior.append(&mut default_decls);
+        if let Ok(field::Group(group)) = field.which() {
+            let id = group.get_type_id();
+            let text = generate_node(
+                ctx,
+                id,
+                ctx.get_last_name(id)?,
+                &mut String::new(),
+                &mut String::new(),
+                false,
+            )?;
+            nested_output.push(text);
+        }
+    }
+    reader_members.push(generate_validate(ctx, node_id, true)?);
+    builder_members.push(generate_validate(ctx, node_id, false)?);
+    if discriminant_count > 0 {
+        if union_only_struct {
+            params_struct.union_name = snake_to_camel_case(node_name);
+            params_struct.union_enum = format!("pub enum {} {{", params_struct.union_name);
+            params_struct.declaration = "".to_string();
+        } else {
+            params_struct.union_name = snake_to_camel_case(node_name);
+            params_struct.union_name.push_str("Union");
+            params_struct.union_enum = format!("pub enum {} {{", params_struct.union_name);
+        }
+        params_struct.union_enum.push_str("\n UNINITIALIZED,");
 
-                let (_, union_getter, typedef, _) = generate_union(
-                    ctx,
-                    discriminant_offset,
-                    &union_fields,
-                    false,
-                    &params,
-                    &mut params_struct_string,
-                    &mut params_struct_impl_string,
-                    &mut params_enum_string,
-                    false,
-                    union_only_struct,
-                    &params_union_name,
-                )?;
-                which_enums.push(typedef);
-                builder_members.push(union_getter);
+        let (which_enums1, union_getter, typedef, mut default_decls) = generate_union(
+            ctx,
+            discriminant_offset,
+            &union_fields,
+            true,
+            &params,
+            &mut params_struct.declaration,
+            &mut params_struct.build_impl,
+            &mut params_struct.union_enum,
+            true,
+            union_only_struct,
+            &params_struct.union_name,
+            node_id,
+        )?;
+        which_enums.push(which_enums1);
+        which_enums.push(typedef);
+        reader_members.push(union_getter);
+
+        private_mod_interior.append(&mut default_decls);
+
+        let (_, union_getter, typedef, _) = generate_union(
+            ctx,
+            discriminant_offset,
+            &union_fields,
+            false,
+            &params,
+            &mut params_struct.declaration,
+            &mut params_struct.build_impl,
+            &mut params_struct.union_enum,
+            false,
+            union_only_struct,
+            &params_struct.union_name,
+            node_id,
+        )?;
+        which_enums.push(typedef);
+        builder_members.push(union_getter);
+        builder_members.push(generate_copy_union_from(
+            ctx,
+            discriminant_offset,
+            &union_fields,
+            &params,
+        )?);
 
-                let mut reexports = String::new();
-                reexports.push_str("pub use self::Which::{");
-                let mut whichs = Vec::new();
-                for f in &union_fields {
-                    whichs.push(capitalize_first_letter(get_field_name(*f)?));
-                }
-                reexports.push_str(&whichs.join(","));
-                reexports.push_str("};");
-                preamble.push(Line(reexports));
-                preamble.push(BlankLine);
+        let mut reexports = String::new();
+        reexports.push_str("pub use self::Which::{");
+        let mut whichs = Vec::new();
+        for f in &union_fields {
+            let variant = capitalize_first_letter(get_field_name(*f)?);
+            // Those would clash with the types of the same name in this module.
+            if !STRUCT_MODULE_ITEMS.contains(&&*variant) {
+                whichs.push(variant);
             }
+        }
+        reexports.push_str(&whichs.join(","));
+        reexports.push_str("};");
+        preamble.push(Line(reexports));
+        preamble.push(BlankLine);
+        preamble.push(union_metadata);
+    }
 
-            if !params_enum_string.is_empty() {
-                params_enum_string.push_str("\n}");
-            }
+    if !is_params_struct && ctx.generate_convenience_builders {
+        output.push(generate_params_struct(
+            ctx,
+            node_id,
+            node_name,
+            &params,
+            is_generic,
+            params_struct,
+            rust_struct_inner,
+            rust_struct_impl_inner,
+            &fields.iter().collect::<Vec<_>>(),
+            &union_fields,
+            union_only_struct && discriminant_count > 0,
+        )?);
+    }
 
-            if !is_params_struct {
-                params_struct_string.push_str(rust_struct_inner);
-                params_struct_impl_string.push_str(rust_struct_impl_inner);
-                params_struct_impl_string.push_str("  \n}}");
-                if !params_struct_string.is_empty() {
-                    params_struct_string.push_str("  \n}");
-                }
-                output.push(Branch(vec![
-                    Line(params_struct_string),
-                    Line(params_struct_impl_string),
-                    Line(params_enum_string),
-                ]));
-            }
+    if let Some(target_id) = compatible_with_target(node_reader)? {
+        if is_generic {
+            return Err(Error::failed(format!(
+                "compatibleWith is not supported on generic struct {node_name}"
+            )));
+        }
+        reader_members.push(generate_upgrade_into(ctx, node_id, target_id)?);
+    }
 
-            let builder_struct_size =
-                Branch(vec![
-                    Line(fmt!(ctx,"impl <'a,{0}> {capnp}::traits::HasStructSize for Builder<'a,{0}> {1} {{",
-                                 params.params, params.where_clause)),
-                                 indent(Line(
-                        fmt!(ctx,"const STRUCT_SIZE: {capnp}::private::layout::StructSize = {capnp}::private::layout::StructSize {{ data: {}, pointers: {} }};", data_size as usize, pointer_size as usize))),
-                   line("}")]);
-
-            private_mod_interior.push(Line(format!(
-                "pub const TYPE_ID: u64 = {};",
-                format_u64(node_id)
+    if !is_params_struct && is_presence_tracked(node_reader)? {
+        output.push(generate_presence_struct(
+            ctx,
+            &params,
+            fields,
+            &snake_to_camel_case(node_name),
+        )?);
+    }
+
+    if !is_params_struct && has_visitor(node_reader)? {
+        if is_generic {
+            return Err(Error::failed(format!(
+                "visitor is not supported on generic struct {node_name}"
             )));
+        }
+        output.push(generate_visitor(
+            ctx,
+            node_id,
+            &snake_to_camel_case(node_name),
+            node_name,
+        )?);
+    }
 
-            let from_pointer_builder_impl =
-                Branch(vec![
-                    Line(fmt!(ctx,"impl <'a,{0}> {capnp}::traits::FromPointerBuilder<'a> for Builder<'a,{0}> {1} {{", params.params, params.where_clause)),
-                    indent(vec![
-                        Line(fmt!(ctx,"fn init_pointer(builder: {capnp}::private::layout::PointerBuilder<'a>, _size: u32) -> Self {{")),
-                        indent(Line(fmt!(ctx,"builder.init_struct(<Self as {capnp}::traits::HasStructSize>::STRUCT_SIZE).into()"))),
-                        line("}"),
-                        Line(fmt!(ctx,"fn get_from_pointer(builder: {capnp}::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [{capnp}::Word]>) -> {capnp}::Result<Self> {{")),
-                        indent(Line(fmt!(ctx,"::core::result::Result::Ok(builder.get_struct(<Self as {capnp}::traits::HasStructSize>::STRUCT_SIZE, default)?.into())"))),
-                        line("}")
-                    ]),
-                    line("}"),
-                    BlankLine]);
+    let builder_struct_size =
+        Branch(vec![
+            Line(fmt!(ctx,"impl <'a,{0}> {capnp}::traits::HasStructSize for Builder<'a,{0}> {1} {{",
+                         params.params, params.where_clause)),
+                         indent(Line(
+                fmt!(ctx,"const STRUCT_SIZE: {capnp}::private::layout::StructSize = {capnp}::private::layout::StructSize {{ data: {}, pointers: {} }};", data_size as usize, pointer_size as usize))),
+           line("}")]);
+
+    private_mod_interior.push(Line(format!(
+        "pub const TYPE_ID: u64 = {};",
+        format_u64(node_id)
+    )));
 
-            let accessors = vec![
-                Branch(preamble),
-                (if !is_generic {
-                    Branch(vec![
-                        Line("#[derive(Copy, Clone)]".into()),
-                        line("pub struct Owned(());"),
-                        Line(fmt!(ctx,"impl {capnp}::introspect::Introspect for Owned {{ fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Struct({capnp}::introspect::RawBrandedStructSchema {{ generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }}).into() }} }}")),
-                        Line(fmt!(ctx, "impl {capnp}::traits::Owned for Owned {{ type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }}")),
-                        Line(fmt!(ctx,"impl {capnp}::traits::OwnedStruct for Owned {{ type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }}")),
-                        Line(fmt!(ctx,"impl {capnp}::traits::Pipelined for Owned {{ type Pipeline = Pipeline; }}"))
-                    ])
-                } else {
-                    Branch(vec![
-                        Line("#[derive(Copy, Clone)]".into()),
-                        Line(format!("pub struct Owned<{}> {{", params.params)),
-                            indent(Line(params.phantom_data_type.clone())),
-                        line("}"),
-                        Line(fmt!(ctx,"impl <{0}> {capnp}::introspect::Introspect for Owned <{0}> {1} {{ fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Struct({capnp}::introspect::RawBrandedStructSchema {{ generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<{0}>, annotation_types: _private::get_annotation_types::<{0}> }}).into() }} }}",
-                            params.params, params.where_clause)),
-                        Line(fmt!(ctx,"impl <{0}> {capnp}::traits::Owned for Owned <{0}> {1} {{ type Reader<'a> = Reader<'a, {0}>; type Builder<'a> = Builder<'a, {0}>; }}",
-                            params.params, params.where_clause)),
-                        Line(fmt!(ctx,"impl <{0}> {capnp}::traits::OwnedStruct for Owned <{0}> {1} {{ type Reader<'a> = Reader<'a, {0}>; type Builder<'a> = Builder<'a, {0}>; }}",
-                            params.params, params.where_clause)),
-                        Line(fmt!(ctx,"impl <{0}> {capnp}::traits::Pipelined for Owned<{0}> {1} {{ type Pipeline = Pipeline{2}; }}",
-                            params.params, params.where_clause, bracketed_params)),
-                    ])
-                }),
-                BlankLine,
-                (if !is_generic {
-                    Line(fmt!(ctx,"pub struct Reader<'a> {{ reader: {capnp}::private::layout::StructReader<'a> }}"))
-                } else {
-                    Branch(vec![
-                        Line(format!("pub struct Reader<'a,{}> {} {{", params.params, params.where_clause)),
-                        indent(vec![
-                            Line(fmt!(ctx,"reader: {capnp}::private::layout::StructReader<'a>,")),
-                            Line(params.phantom_data_type.clone()),
-                        ]),
-                        line("}")
-                    ])
-                }),
-                // Manually implement Copy/Clone because `derive` only kicks in if all of
-                // the parameters are known to implement Copy/Clone.
-                Branch(vec![
-                    Line(format!("impl <'a,{0}> ::core::marker::Copy for Reader<'a,{0}> {1} {{}}",
-                                 params.params, params.where_clause)),
-                    Line(format!("impl <'a,{0}> ::core::clone::Clone for Reader<'a,{0}> {1} {{",
-                                 params.params, params.where_clause)),
-                    indent(Line("fn clone(&self) -> Self { *self }".into())),
-                    Line("}".into())]),
-                BlankLine,
-                Branch(vec![
-                        Line(fmt!(ctx,"impl <'a,{0}> {capnp}::traits::HasTypeId for Reader<'a,{0}> {1} {{",
-                            params.params, params.where_clause)),
-                        indent(vec![line("const TYPE_ID: u64 = _private::TYPE_ID;")]),
-                    line("}")]),
-                Line(fmt!(ctx,"impl <'a,{0}> ::core::convert::From<{capnp}::private::layout::StructReader<'a>> for Reader<'a,{0}> {1} {{",
-                            params.params, params.where_clause)),
+    let from_pointer_builder_impl =
+        Branch(vec![
+            Line(fmt!(ctx,"impl <'a,{0}> {capnp}::traits::FromPointerBuilder<'a> for Builder<'a,{0}> {1} {{", params.params, params.where_clause)),
+            indent(vec![
+                Line(fmt!(ctx,"fn init_pointer(builder: {capnp}::private::layout::PointerBuilder<'a>, _size: u32) -> Self {{")),
+                indent(Line(fmt!(ctx,"builder.init_struct(<Self as {capnp}::traits::HasStructSize>::STRUCT_SIZE).into()"))),
+                line("}"),
+                Line(fmt!(ctx,"fn try_init_pointer(builder: {capnp}::private::layout::PointerBuilder<'a>, _size: u32) -> {capnp}::Result<Self> {{")),
+                indent(Line(fmt!(ctx,"::core::result::Result::Ok(builder.try_init_struct(<Self as {capnp}::traits::HasStructSize>::STRUCT_SIZE)?.into())"))),
+                line("}"),
+                Line(fmt!(ctx,"fn get_from_pointer(builder: {capnp}::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [{capnp}::Word]>) -> {capnp}::Result<Self> {{")),
+                indent(Line(fmt!(ctx,"::core::result::Result::Ok(builder.get_struct(<Self as {capnp}::traits::HasStructSize>::STRUCT_SIZE, default)?.into())"))),
+                line("}")
+            ]),
+            line("}"),
+            BlankLine]);
+
+    let accessors = vec![
+        Branch(preamble),
+        generate_size_bounds(ctx, struct_reader)?,
+        (if !is_generic {
+            Branch(vec![
+                Line("#[derive(Copy, Clone)]".into()),
+                line("pub struct Owned(());"),
+                line("impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }"),
+                Line(fmt!(ctx,"impl {capnp}::introspect::Introspect for Owned {{ fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Struct({capnp}::introspect::RawBrandedStructSchema {{ generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }}).into() }} }}")),
+                Line(fmt!(ctx, "impl {capnp}::traits::Owned for Owned {{ type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }}")),
+                Line(fmt!(ctx,"impl {capnp}::traits::OwnedStruct for Owned {{ type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }}")),
+                Line(fmt!(ctx,"impl {capnp}::traits::Pipelined for Owned {{ type Pipeline = Pipeline; }}"))
+            ])
+        } else {
+            Branch(vec![
+                Line("#[derive(Copy, Clone)]".into()),
+                Line(format!("pub struct Owned<{}> {{", params.params)),
+                    indent(Line(params.phantom_data_type.clone())),
+                line("}"),
+                Line(format!("impl <{0}> ::core::fmt::Debug for Owned <{0}> {1} {{ fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {{ f.write_str(_private::DISPLAY_NAME) }} }}",
+                    params.params, params.where_clause)),
+                Line(fmt!(ctx,"impl <{0}> {capnp}::introspect::Introspect for Owned <{0}> {1} {{ fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Struct({capnp}::introspect::RawBrandedStructSchema {{ generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<{0}>, annotation_types: _private::get_annotation_types::<{0}> }}).into() }} }}",
+                    params.params, params.where_clause)),
+                Line(fmt!(ctx,"impl <{0}> {capnp}::traits::Owned for Owned <{0}> {1} {{ type Reader<'a> = Reader<'a, {0}>; type Builder<'a> = Builder<'a, {0}>; }}",
+                    params.params, params.where_clause)),
+                Line(fmt!(ctx,"impl <{0}> {capnp}::traits::OwnedStruct for Owned <{0}> {1} {{ type Reader<'a> = Reader<'a, {0}>; type Builder<'a> = Builder<'a, {0}>; }}",
+                    params.params, params.where_clause)),
+                Line(fmt!(ctx,"impl <{0}> {capnp}::traits::Pipelined for Owned<{0}> {1} {{ type Pipeline = Pipeline{2}; }}",
+                    params.params, params.where_clause, bracketed_params)),
+            ])
+        }),
+        BlankLine,
+        (if !is_generic {
+            Line(fmt!(ctx,"pub struct Reader<'a> {{ reader: {capnp}::private::layout::StructReader<'a> }}"))
+        } else {
+            Branch(vec![
+                Line(format!("pub struct Reader<'a,{}> {} {{", params.params, params.where_clause)),
                 indent(vec![
-                    Line(fmt!(ctx,"fn from(reader: {capnp}::private::layout::StructReader<'a>) -> Self {{")),
-                    indent(Line(format!("Self {{ reader, {} }}", params.phantom_data_value))),
-                    line("}")
+                    Line(fmt!(ctx,"reader: {capnp}::private::layout::StructReader<'a>,")),
+                    Line(params.phantom_data_type.clone()),
                 ]),
+                line("}")
+            ])
+        }),
+        // Manually implement Copy/Clone because `derive` only kicks in if all of
+        // the parameters are known to implement Copy/Clone.
+        Branch(vec![
+            Line(format!("impl <'a,{0}> ::core::marker::Copy for Reader<'a,{0}> {1} {{}}",
+                         params.params, params.where_clause)),
+            Line(format!("impl <'a,{0}> ::core::clone::Clone for Reader<'a,{0}> {1} {{",
+                         params.params, params.where_clause)),
+            indent(Line("fn clone(&self) -> Self { *self }".into())),
+            Line("}".into())]),
+        BlankLine,
+        Branch(vec![
+                Line(fmt!(ctx,"impl <'a,{0}> {capnp}::traits::HasTypeId for Reader<'a,{0}> {1} {{",
+                    params.params, params.where_clause)),
+                indent(vec![line("const TYPE_ID: u64 = _private::TYPE_ID;")]),
+            line("}")]),
+        Line(fmt!(ctx,"impl <'a,{0}> ::core::convert::From<{capnp}::private::layout::StructReader<'a>> for Reader<'a,{0}> {1} {{",
+                    params.params, params.where_clause)),
+        indent(vec![
+            Line(fmt!(ctx,"fn from(reader: {capnp}::private::layout::StructReader<'a>) -> Self {{")),
+            indent(Line(format!("Self {{ reader, {} }}", params.phantom_data_value))),
+            line("}")
+        ]),
+        line("}"),
+        BlankLine,
+        Line(fmt!(ctx,"impl <'a,{0}> ::core::convert::From<Reader<'a,{0}>> for {capnp}::dynamic_value::Reader<'a> {1} {{",
+                    params.params, params.where_clause)),
+        indent(vec![
+            Line(format!("fn from(reader: Reader<'a,{0}>) -> Self {{", params.params)),
+            indent(Line(fmt!(ctx,"Self::Struct({capnp}::dynamic_struct::Reader::new(reader.reader, {capnp}::schema::StructSchema::new({capnp}::introspect::RawBrandedStructSchema {{ generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<{0}>, annotation_types: _private::get_annotation_types::<{0}>}})))", params.params))),
+            line("}")
+        ]),
+        line("}"),
+        BlankLine,
+        Line(format!("impl <'a,{0}> ::core::fmt::Debug for Reader<'a,{0}> {1} {{",
+                    params.params, params.where_clause)),
+        indent(vec![
+            Line("fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {".into()),
+            indent(Line(fmt!(ctx,"core::fmt::Debug::fmt(&::core::convert::Into::<{capnp}::dynamic_value::Reader<'_>>::into(*self), f)"))),
+            line("}")
+        ]),
+        line("}"),
+
+        BlankLine,
+
+        Line(fmt!(ctx,"impl <'a,{0}> {capnp}::traits::FromPointerReader<'a> for Reader<'a,{0}> {1} {{",
+            params.params, params.where_clause)),
+        indent(vec![
+            Line(fmt!(ctx,"fn get_from_pointer(reader: &{capnp}::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [{capnp}::Word]>) -> {capnp}::Result<Self> {{")),
+            indent(line("::core::result::Result::Ok(reader.get_struct(default)?.into())")),
+            line("}")
+        ]),
+        line("}"),
+        BlankLine,
+        Line(fmt!(ctx,"impl <'a,{0}> {capnp}::traits::IntoInternalStructReader<'a> for Reader<'a,{0}> {1} {{",
+                    params.params, params.where_clause)),
+        indent(vec![
+            Line(fmt!(ctx,"fn into_internal_struct_reader(self) -> {capnp}::private::layout::StructReader<'a> {{")),
+            indent(line("self.reader")),
+            line("}")
+        ]),
+        line("}"),
+        BlankLine,
+        Line(fmt!(ctx,"impl <'a,{0}> {capnp}::traits::Imbue<'a> for Reader<'a,{0}> {1} {{",
+            params.params, params.where_clause)),
+        indent(vec![
+            Line(fmt!(ctx,"fn imbue(&mut self, cap_table: &'a {capnp}::private::layout::CapTable) {{")),
+            indent(Line(fmt!(ctx,"self.reader.imbue({capnp}::private::layout::CapTableReader::Plain(cap_table))"))),
+            line("}")
+        ]),
+        line("}"),
+        BlankLine,
+        Line(format!("impl <'a,{0}> Reader<'a,{0}> {1} {{", params.params, params.where_clause)),
+        indent(vec![
+                Line(format!("pub fn reborrow(&self) -> Reader<'_,{}> {{",params.params)),
+                indent(line("Self { .. *self }")),
                 line("}"),
                 BlankLine,
-                Line(fmt!(ctx,"impl <'a,{0}> ::core::convert::From<Reader<'a,{0}>> for {capnp}::dynamic_value::Reader<'a> {1} {{",
-                            params.params, params.where_clause)),
-                indent(vec![
-                    Line(format!("fn from(reader: Reader<'a,{0}>) -> Self {{", params.params)),
-                    indent(Line(fmt!(ctx,"Self::Struct({capnp}::dynamic_struct::Reader::new(reader.reader, {capnp}::schema::StructSchema::new({capnp}::introspect::RawBrandedStructSchema {{ generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<{0}>, annotation_types: _private::get_annotation_types::<{0}>}})))", params.params))),
-                    line("}")
+                Line(fmt!(ctx,"pub fn total_size(&self) -> {capnp}::Result<{capnp}::MessageSize> {{")),
+                indent(line("self.reader.total_size()")),
+                line("}"),
+                BlankLine,
+                line("/// The number of words that serializing a message with a copy of this struct as its"),
+                line("/// root takes, including the segment table."),
+                Line(fmt!(ctx,"pub fn serialized_words(&self) -> {capnp}::Result<usize> {{")),
+                indent(Line(fmt!(ctx,"self.total_size().map({capnp}::serialize::compute_serialized_size_of_root)"))),
+                line("}"),
+                BlankLine,
+                line("/// Views this struct through the dynamic API."),
+                Line(fmt!(ctx,"pub fn as_dynamic(self) -> {capnp}::dynamic_struct::Reader<'a> {{")),
+                indent(Line(fmt!(ctx,"::core::convert::Into::<{capnp}::dynamic_value::Reader<'a>>::into(self).downcast()"))),
+                line("}")]),
+        indent(reader_members),
+        line("}"),
+        BlankLine,
+        (if !is_generic {
+            Line(fmt!(ctx,"pub struct Builder<'a> {{ builder: {capnp}::private::layout::StructBuilder<'a> }}"))
+        } else {
+            Branch(vec![
+                Line(format!("pub struct Builder<'a,{}> {} {{",
+                             params.params, params.where_clause)),
+                    indent(vec![
+                    Line(fmt!(ctx, "builder: {capnp}::private::layout::StructBuilder<'a>,")),
+                    Line(params.phantom_data_type.clone()),
                 ]),
+                line("}")
+            ])
+        }),
+        builder_struct_size,
+        Branch(vec![
+            Line(fmt!(ctx,"impl <'a,{0}> {capnp}::traits::HasTypeId for Builder<'a,{0}> {1} {{",
+                         params.params, params.where_clause)),
+            indent(vec![
+                line("const TYPE_ID: u64 = _private::TYPE_ID;")]),
+            line("}")
+        ]),
+        Line(fmt!(ctx,
+            "impl <'a,{0}> ::core::convert::From<{capnp}::private::layout::StructBuilder<'a>> for Builder<'a,{0}> {1} {{",
+            params.params, params.where_clause)),
+        indent(vec![
+                Line(fmt!(ctx,"fn from(builder: {capnp}::private::layout::StructBuilder<'a>) -> Self {{")),
+                indent(Line(format!("Self {{ builder, {} }}", params.phantom_data_value))),
+                line("}")
+        ]),
+        line("}"),
+        BlankLine,
+        Line(fmt!(ctx,"impl <'a,{0}> ::core::convert::From<Builder<'a,{0}>> for {capnp}::dynamic_value::Builder<'a> {1} {{",
+                    params.params, params.where_clause)),
+        indent(vec![
+                Line(format!("fn from(builder: Builder<'a,{0}>) -> Self {{", params.params)),
+                indent(Line(fmt!(ctx,"Self::Struct({capnp}::dynamic_struct::Builder::new(builder.builder, {capnp}::schema::StructSchema::new({capnp}::introspect::RawBrandedStructSchema {{ generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<{0}>, annotation_types: _private::get_annotation_types::<{0}>}})))", params.params))),
+                line("}")
+        ]),
+        line("}"),
+        BlankLine,
+
+        Line(fmt!(ctx,"impl <'a,{0}> {capnp}::traits::ImbueMut<'a> for Builder<'a,{0}> {1} {{",
+                     params.params, params.where_clause)),
+        indent(vec![
+                Line(fmt!(ctx,"fn imbue_mut(&mut self, cap_table: &'a mut {capnp}::private::layout::CapTable) {{")),
+                indent(Line(fmt!(ctx,"self.builder.imbue({capnp}::private::layout::CapTableBuilder::Plain(cap_table))"))),
+                line("}")]),
+        line("}"),
+        BlankLine,
+
+        from_pointer_builder_impl,
+        Line(fmt!(ctx,
+            "impl <'a,{0}> {capnp}::traits::SetPointerBuilder for Reader<'a,{0}> {1} {{",
+            params.params, params.where_clause)),
+        indent(Line(fmt!(ctx,"fn set_pointer_builder(mut pointer: {capnp}::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> {capnp}::Result<()> {{ pointer.set_struct(&value.reader, canonicalize) }}"))),
+        line("}"),
+        BlankLine,
+        Line(format!("impl <'a,{0}> Builder<'a,{0}> {1} {{", params.params, params.where_clause)),
+        indent(vec![
+                Line(format!("pub fn into_reader(self) -> Reader<'a,{}> {{", params.params)),
+                indent(line("self.builder.into_reader().into()")),
+                line("}"),
+                Line(format!("pub fn reborrow(&mut self) -> Builder<'_,{}> {{", params.params)),
+                (if !is_generic {
+                    indent(line("Builder { builder: self.builder.reborrow() }"))
+                } else {
+                    indent(line("Builder { builder: self.builder.reborrow(), ..*self }"))
+                }),
                 line("}"),
-                BlankLine,
-                Line(format!("impl <'a,{0}> ::core::fmt::Debug for Reader<'a,{0}> {1} {{",
-                            params.params, params.where_clause)),
-                indent(vec![
-                    Line("fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {".into()),
-                    indent(Line(fmt!(ctx,"core::fmt::Debug::fmt(&::core::convert::Into::<{capnp}::dynamic_value::Reader<'_>>::into(*self), f)"))),
-                    line("}")
-                ]),
+                Line(format!("pub fn reborrow_as_reader(&self) -> Reader<'_,{}> {{", params.params)),
+                indent(line("self.builder.as_reader().into()")),
                 line("}"),
 
                 BlankLine,
-
-                Line(fmt!(ctx,"impl <'a,{0}> {capnp}::traits::FromPointerReader<'a> for Reader<'a,{0}> {1} {{",
-                    params.params, params.where_clause)),
-                indent(vec![
-                    Line(fmt!(ctx,"fn get_from_pointer(reader: &{capnp}::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [{capnp}::Word]>) -> {capnp}::Result<Self> {{")),
-                    indent(line("::core::result::Result::Ok(reader.get_struct(default)?.into())")),
-                    line("}")
-                ]),
+                Line(fmt!(ctx,"pub fn total_size(&self) -> {capnp}::Result<{capnp}::MessageSize> {{")),
+                indent(line("self.builder.as_reader().total_size()")),
                 line("}"),
                 BlankLine,
-                Line(fmt!(ctx,"impl <'a,{0}> {capnp}::traits::IntoInternalStructReader<'a> for Reader<'a,{0}> {1} {{",
-                            params.params, params.where_clause)),
-                indent(vec![
-                    Line(fmt!(ctx,"fn into_internal_struct_reader(self) -> {capnp}::private::layout::StructReader<'a> {{")),
-                    indent(line("self.reader")),
-                    line("}")
-                ]),
+                line("/// The number of words that serializing a message with a copy of this struct as its"),
+                line("/// root takes, including the segment table."),
+                Line(fmt!(ctx,"pub fn serialized_words(&self) -> {capnp}::Result<usize> {{")),
+                indent(Line(fmt!(ctx,"self.total_size().map({capnp}::serialize::compute_serialized_size_of_root)"))),
                 line("}"),
                 BlankLine,
-                Line(fmt!(ctx,"impl <'a,{0}> {capnp}::traits::Imbue<'a> for Reader<'a,{0}> {1} {{",
-                    params.params, params.where_clause)),
-                indent(vec![
-                    Line(fmt!(ctx,"fn imbue(&mut self, cap_table: &'a {capnp}::private::layout::CapTable) {{")),
-                    indent(Line(fmt!(ctx,"self.reader.imbue({capnp}::private::layout::CapTableReader::Plain(cap_table))"))),
-                    line("}")
-                ]),
+                line("/// Views this struct through the dynamic API, for as long as this builder is borrowed."),
+                Line(fmt!(ctx,"pub fn as_dynamic_mut(&mut self) -> {capnp}::dynamic_struct::Builder<'_> {{")),
+                indent(Line(fmt!(ctx,"::core::convert::Into::<{capnp}::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()"))),
                 line("}"),
                 BlankLine,
-                Line(format!("impl <'a,{0}> Reader<'a,{0}> {1} {{", params.params, params.where_clause)),
+                line("/// Overwrites this struct with a copy of `src`, which must be a struct of this same type."),
+                line("/// Fails with `TypeMismatch`, naming both types, if it is not."),
+                Line(fmt!(ctx,"pub fn copy_from_dynamic(&mut self, src: {capnp}::dynamic_struct::Reader<'_>) -> {capnp}::Result<()> {{")),
                 indent(vec![
-                        Line(format!("pub fn reborrow(&self) -> Reader<'_,{}> {{",params.params)),
-                        indent(line("Self { .. *self }")),
-                        line("}"),
-                        BlankLine,
-                        Line(fmt!(ctx,"pub fn total_size(&self) -> {capnp}::Result<{capnp}::MessageSize> {{")),
-                        indent(line("self.reader.total_size()")),
-                        line("}")]),
-                indent(reader_members),
-                line("}"),
-                BlankLine,
-                (if !is_generic {
-                    Line(fmt!(ctx,"pub struct Builder<'a> {{ builder: {capnp}::private::layout::StructBuilder<'a> }}"))
-                } else {
-                    Branch(vec![
-                        Line(format!("pub struct Builder<'a,{}> {} {{",
-                                     params.params, params.where_clause)),
-                            indent(vec![
-                            Line(fmt!(ctx, "builder: {capnp}::private::layout::StructBuilder<'a>,")),
-                            Line(params.phantom_data_type.clone()),
-                        ]),
-                        line("}")
-                    ])
-                }),
-                builder_struct_size,
-                Branch(vec![
-                    Line(fmt!(ctx,"impl <'a,{0}> {capnp}::traits::HasTypeId for Builder<'a,{0}> {1} {{",
-                                 params.params, params.where_clause)),
+                    line("let src_proto = src.get_schema().get_proto();"),
+                    line("if src_proto.get_id() != _private::TYPE_ID {"),
                     indent(vec![
-                        line("const TYPE_ID: u64 = _private::TYPE_ID;")]),
-                    line("}")
+                        Line(fmt!(ctx,"let mut error = {capnp}::Error::from_kind({capnp}::ErrorKind::TypeMismatch);")),
+                        line("::core::write!(error, \"cannot copy a `{}` into a `{}`\", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);"),
+                        line("return ::core::result::Result::Err(error);"),
+                    ]),
+                    line("}"),
+                    Line(format!("let src: Reader<'_,{}> = src.downcast()?;", params.params)),
+                    line("self.builder.copy_content_from(&src.reader)"),
                 ]),
-                Line(fmt!(ctx,
-                    "impl <'a,{0}> ::core::convert::From<{capnp}::private::layout::StructBuilder<'a>> for Builder<'a,{0}> {1} {{",
-                    params.params, params.where_clause)),
-                indent(vec![
-                        Line(fmt!(ctx,"fn from(builder: {capnp}::private::layout::StructBuilder<'a>) -> Self {{")),
-                        indent(Line(format!("Self {{ builder, {} }}", params.phantom_data_value))),
-                        line("}")
+                line("}")
                 ]),
-                line("}"),
-                BlankLine,
-                Line(fmt!(ctx,"impl <'a,{0}> ::core::convert::From<Builder<'a,{0}>> for {capnp}::dynamic_value::Builder<'a> {1} {{",
-                            params.params, params.where_clause)),
+        indent(builder_members),
+        line("}"),
+        BlankLine,
+        Line(format!("impl <'a,{0}> ::core::fmt::Debug for Builder<'a,{0}> {1} {{",
+                    params.params, params.where_clause)),
+        indent(vec![
+            Line("fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {".into()),
+            indent(line("::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)")),
+            line("}")
+        ]),
+        line("}"),
+        BlankLine,
+        (if is_generic {
+            Branch(vec![
+                Line(format!("pub struct Pipeline{bracketed_params} {{")),
                 indent(vec![
-                        Line(format!("fn from(builder: Builder<'a,{0}>) -> Self {{", params.params)),
-                        indent(Line(fmt!(ctx,"Self::Struct({capnp}::dynamic_struct::Builder::new(builder.builder, {capnp}::schema::StructSchema::new({capnp}::introspect::RawBrandedStructSchema {{ generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<{0}>, annotation_types: _private::get_annotation_types::<{0}>}})))", params.params))),
-                        line("}")
+                    Line(fmt!(ctx,"_typeless: {capnp}::any_pointer::Pipeline,")),
+                    Line(params.phantom_data_type),
                 ]),
-                line("}"),
-                BlankLine,
+                line("}")
+            ])
+        } else {
+            Line(fmt!(ctx,"pub struct Pipeline {{ _typeless: {capnp}::any_pointer::Pipeline }}"))
+        }),
+        Line(fmt!(ctx,"impl{bracketed_params} {capnp}::capability::FromTypelessPipeline for Pipeline{bracketed_params} {{")),
+        indent(vec![
+                Line(fmt!(ctx,"fn new(typeless: {capnp}::any_pointer::Pipeline) -> Self {{")),
+                indent(Line(format!("Self {{ _typeless: typeless, {} }}", params.phantom_data_value))),
+                line("}")]),
+        line("}"),
+        Line(format!("impl{bracketed_params} ::core::fmt::Debug for Pipeline{bracketed_params} {{")),
+        indent(vec![
+            Line("fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {".into()),
+            indent(line("f.write_str(::core::any::type_name::<Self>())")),
+            line("}")
+        ]),
+        line("}"),
+        Line(format!("impl{0} Pipeline{0} {1} {{", bracketed_params,
+                     params.pipeline_where_clause)),
+        indent(pipeline_impl_interior),
+        line("}"),
+        generate_child_annotation_lookup(ctx, node_reader)?,
+        line("pub mod _private {"),
+        indent(private_mod_interior),
+        line("}"),
+    ];
 
-                Line(fmt!(ctx,"impl <'a,{0}> {capnp}::traits::ImbueMut<'a> for Builder<'a,{0}> {1} {{",
-                             params.params, params.where_clause)),
-                indent(vec![
-                        Line(fmt!(ctx,"fn imbue_mut(&mut self, cap_table: &'a mut {capnp}::private::layout::CapTable) {{")),
-                        indent(Line(fmt!(ctx,"self.builder.imbue({capnp}::private::layout::CapTableBuilder::Plain(cap_table))"))),
-                        line("}")]),
-                line("}"),
-                BlankLine,
+    output.push(indent(vec![
+        Branch(accessors),
+        Branch(which_enums),
+        Branch(nested_output),
+        generate_module_extensions(ctx, node_id)?,
+    ]));
+    output.push(line("}"));
 
-                from_pointer_builder_impl,
-                Line(fmt!(ctx,
-                    "impl <'a,{0}> {capnp}::traits::SetPointerBuilder for Reader<'a,{0}> {1} {{",
-                    params.params, params.where_clause)),
-                indent(Line(fmt!(ctx,"fn set_pointer_builder(mut pointer: {capnp}::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> {capnp}::Result<()> {{ pointer.set_struct(&value.reader, canonicalize) }}"))),
-                line("}"),
-                BlankLine,
-                Line(format!("impl <'a,{0}> Builder<'a,{0}> {1} {{", params.params, params.where_clause)),
-                indent(vec![
-                        Line(format!("pub fn into_reader(self) -> Reader<'a,{}> {{", params.params)),
-                        indent(line("self.builder.into_reader().into()")),
-                        line("}"),
-                        Line(format!("pub fn reborrow(&mut self) -> Builder<'_,{}> {{", params.params)),
-                        (if !is_generic {
-                            indent(line("Builder { builder: self.builder.reborrow() }"))
-                        } else {
-                            indent(line("Builder { builder: self.builder.reborrow(), ..*self }"))
-                        }),
-                        line("}"),
-                        Line(format!("pub fn reborrow_as_reader(&self) -> Reader<'_,{}> {{", params.params)),
-                        indent(line("self.builder.as_reader().into()")),
-                        line("}"),
-
-                        BlankLine,
-                        Line(fmt!(ctx,"pub fn total_size(&self) -> {capnp}::Result<{capnp}::MessageSize> {{")),
-                        indent(line("self.builder.as_reader().total_size()")),
-                        line("}")
-                        ]),
-                indent(builder_members),
-                line("}"),
-                BlankLine,
-                (if is_generic {
-                    Branch(vec![
-                        Line(format!("pub struct Pipeline{bracketed_params} {{")),
-                        indent(vec![
-                            Line(fmt!(ctx,"_typeless: {capnp}::any_pointer::Pipeline,")),
-                            Line(params.phantom_data_type),
-                        ]),
-                        line("}")
-                    ])
-                } else {
-                    Line(fmt!(ctx,"pub struct Pipeline {{ _typeless: {capnp}::any_pointer::Pipeline }}"))
-                }),
-                Line(fmt!(ctx,"impl{bracketed_params} {capnp}::capability::FromTypelessPipeline for Pipeline{bracketed_params} {{")),
-                indent(vec![
-                        Line(fmt!(ctx,"fn new(typeless: {capnp}::any_pointer::Pipeline) -> Self {{")),
-                        indent(Line(format!("Self {{ _typeless: typeless, {} }}", params.phantom_data_value))),
-                        line("}")]),
-                line("}"),
-                Line(format!("impl{0} Pipeline{0} {1} {{", bracketed_params,
-                             params.pipeline_where_clause)),
-                indent(pipeline_impl_interior),
-                line("}"),
-                line("mod _private {"),
-                indent(private_mod_interior),
-                line("}"),
-            ];
+    Ok(Branch(output))
+}
 
-            output.push(indent(vec![
-                Branch(accessors),
-                Branch(which_enums),
-                Branch(nested_output),
-            ]));
-            output.push(line("}"));
-        }
-
-        node::Enum(enum_reader) => {
-            let last_name = ctx.get_last_name(node_id)?;
-            let name_as_mod = module_name(last_name);
-            output.push(BlankLine);
-
-            let mut members = Vec::new();
-            let mut match_branches = Vec::new();
-            let enumerants = enum_reader.get_enumerants()?;
-            for (ii, enumerant) in enumerants.into_iter().enumerate() {
-                let enumerant = capitalize_first_letter(get_enumerant_name(enumerant)?);
-                members.push(Line(format!("{enumerant} = {ii},")));
-                match_branches.push(Line(format!(
-                    "{ii} => ::core::result::Result::Ok(Self::{enumerant}),"
-                )));
-            }
-            match_branches.push(Line(fmt!(
-                ctx,
-                "n => ::core::result::Result::Err({capnp}::NotInSchema(n)),"
-            )));
+/// Generates the Rust enum of enum `node`, and the module holding its schema.
+fn generate_enum_node<'a>(
+    node: &NodeContext<'_, 'a>,
+    enum_reader: schema_capnp::node::enum_::Reader<'a>,
+) -> ::capnp::Result<FormattedText> {
+    let NodeContext {
+        ctx,
+        id: node_id,
+        reader: node_reader,
+        ..
+    } = *node;
+    let mut output = Vec::new();
+    let last_name = ctx.get_last_name(node_id)?;
+    let name_as_mod = module_name(last_name);
+    output.push(BlankLine);
+
+    let enumerants = enum_reader.get_enumerants()?;
+    let mut variants = Vec::new();
+    for enumerant in enumerants {
+        variants.push((
+            capitalize_first_letter(get_enumerant_name(enumerant)?),
+            format!("enumerant `{}`", enumerant.get_name()?.to_str()?),
+        ));
+    }
+    check_member_names(ctx, node_id, variants)?;
+
+    let mut members = Vec::new();
+    let mut match_branches = Vec::new();
+    let mut values = Vec::new();
+    for (ii, enumerant) in enumerants.into_iter().enumerate() {
+        let enumerant = capitalize_first_letter(get_enumerant_name(enumerant)?);
+        members.push(Line(format!("{enumerant} = {ii},")));
+        values.push(format!("Self::{enumerant}"));
+        match_branches.push(Line(format!(
+            "{ii} => ::core::result::Result::Ok(Self::{enumerant}),"
+        )));
+    }
+    match_branches.push(Line(fmt!(
+        ctx,
+        "n => ::core::result::Result::Err({capnp}::NotInSchema(n)),"
+    )));
 
-            output.push(Branch(vec![
-                line("#[repr(u16)]"),
-                line("#[derive(Clone, Copy, Debug, PartialEq, Eq)]"),
-                Line(format!("pub enum {last_name} {{")),
-                indent(members),
-                line("}"),
-            ]));
+    output.push(Branch(vec![
+        line("#[repr(u16)]"),
+        line("#[derive(Clone, Copy, Debug, PartialEq, Eq)]"),
+        Line(format!("pub enum {last_name} {{")),
+        indent(members),
+        line("}"),
+    ]));
 
-            output.push(BlankLine);
-            output.push(Branch(vec![
-                Line(fmt!(ctx,
-                    "impl {capnp}::introspect::Introspect for {last_name} {{"
-                )),
-                indent(Line(fmt!(ctx,
-                    "fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Enum({capnp}::introspect::RawEnumSchema {{ encoded_node: &{0}::ENCODED_NODE, annotation_types: {0}::get_annotation_types }}).into() }}", name_as_mod))),
-                Line("}".into()),
-            ]));
+    output.push(BlankLine);
+    output.push(Branch(vec![
+        Line(fmt!(ctx,
+            "impl {capnp}::introspect::Introspect for {last_name} {{"
+        )),
+        indent(Line(fmt!(ctx,
+            "fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Enum({capnp}::introspect::RawEnumSchema {{ encoded_node: &{0}::ENCODED_NODE, annotation_types: {0}::get_annotation_types }}).into() }}", name_as_mod))),
+        Line("}".into()),
+    ]));
 
-            output.push(Branch(vec![
-                Line(fmt!(ctx,"impl <'a> ::core::convert::From<{last_name}> for {capnp}::dynamic_value::Reader<'a> {{")),
-                indent(Line(fmt!(ctx,
-                    "fn from(e: {last_name}) -> Self {{ {capnp}::dynamic_value::Enum::new(e.into(), {capnp}::introspect::RawEnumSchema {{ encoded_node: &{0}::ENCODED_NODE, annotation_types: {0}::get_annotation_types }}.into()).into() }}", name_as_mod ))),
-                Line("}".into())
-            ]));
+    output.push(Branch(vec![
+        Line(format!("impl {last_name} {{")),
+        indent(vec![
+            line("/// Every enumerant, in ordinal order."),
+            Line(format!(
+                "pub const VALUES: &[Self] = &[{}];",
+                values.join(", ")
+            )),
+            line("/// The number of enumerants."),
+            Line(format!("pub const COUNT: u16 = {};", values.len())),
+            line("/// Iterates over every enumerant, in ordinal order."),
+            line("pub fn iter() -> impl ::core::iter::Iterator<Item = Self> {"),
+            indent(line("Self::VALUES.iter().copied()")),
+            line("}"),
+        ]),
+        indent(generate_child_annotation_lookup(ctx, node_reader)?),
+        line("}"),
+    ]));
 
-            output.push(Branch(vec![
-                Line(format!(
-                    "impl ::core::convert::TryFrom<u16> for {last_name} {{"
-                )),
-                indent(Line(
-                    fmt!(ctx,"type Error = {capnp}::NotInSchema;"),
-                )),
-                indent(vec![
-                    Line(
-                        format!("fn try_from(value: u16) -> ::core::result::Result<Self, <{last_name} as ::core::convert::TryFrom<u16>>::Error> {{")
-                    ),
-                    indent(vec![
-                        line("match value {"),
-                        indent(match_branches),
-                        line("}"),
-                    ]),
-                    line("}"),
-                ]),
-                line("}"),
-                Line(format!("impl From<{last_name}> for u16 {{")),
-                indent(line("#[inline]")),
-                indent(Line(format!(
-                    "fn from(x: {last_name}) -> u16 {{ x as u16 }}"
-                ))),
-                line("}"),
-            ]));
+    output.push(Branch(vec![
+        Line(fmt!(ctx,"impl <'a> ::core::convert::From<{last_name}> for {capnp}::dynamic_value::Reader<'a> {{")),
+        indent(Line(fmt!(ctx,
+            "fn from(e: {last_name}) -> Self {{ {capnp}::dynamic_value::Enum::new(e.into(), {capnp}::introspect::RawEnumSchema {{ encoded_node: &{0}::ENCODED_NODE, annotation_types: {0}::get_annotation_types }}.into()).into() }}", name_as_mod ))),
+        Line("}".into())
+    ]));
 
-            output.push(Branch(vec![
-                Line(fmt!(
-                    ctx,
-                    "impl {capnp}::traits::HasTypeId for {last_name} {{"
-                )),
-                indent(Line(format!(
-                    "const TYPE_ID: u64 = {}u64;",
-                    format_u64(node_id)
-                ))),
+    output.push(Branch(vec![
+        Line(format!(
+            "impl ::core::convert::TryFrom<u16> for {last_name} {{"
+        )),
+        indent(Line(
+            fmt!(ctx,"type Error = {capnp}::NotInSchema;"),
+        )),
+        indent(vec![
+            Line(
+                format!("fn try_from(value: u16) -> ::core::result::Result<Self, <{last_name} as ::core::convert::TryFrom<u16>>::Error> {{")
+            ),
+            indent(vec![
+                line("match value {"),
+                indent(match_branches),
                 line("}"),
-            ]));
-
-            output.push(Branch(vec![
-                Line(format!("mod {} {{", name_as_mod)),
-                Branch(vec![
-                    crate::pointer_constants::node_word_array_declaration(
-                        ctx,
-                        "ENCODED_NODE",
-                        *node_reader,
-                        crate::pointer_constants::WordArrayDeclarationOptions { public: true },
-                    )?,
-                    generate_get_annotation_types(ctx, *node_reader)?,
-                ]),
-                Line("}".into()),
-            ]));
-        }
-
-        node::Interface(interface) => {
-            let params = node_reader.parameters_texts(ctx);
-            output.push(BlankLine);
-
-            let is_generic = node_reader.get_is_generic();
-
-            let names = &ctx.scope_map[&node_id];
-            let mut client_impl_interior = Vec::new();
-            let mut server_interior = Vec::new();
-            let mut mod_interior = Vec::new();
-            let mut dispatch_arms = Vec::new();
-            let mut private_mod_interior = Vec::new();
+            ]),
+            line("}"),
+        ]),
+        line("}"),
+        Line(format!("impl ::core::convert::From<{last_name}> for u16 {{")),
+        indent(ctx.inline_accessor()),
+        indent(Line(format!(
+            "fn from(x: {last_name}) -> u16 {{ x as u16 }}"
+        ))),
+        line("}"),
+    ]));
 
-            let bracketed_params = if params.params.is_empty() {
-                "".to_string()
-            } else {
-                format!("<{}>", params.params)
-            };
+    if ctx.lenient_enums {
+        let or_unknown = format!("{last_name}OrUnknown");
+        output.push(Branch(vec![
+            Line(format!("/// A [`{last_name}`], or a raw value that is not in the schema.")),
+            line("#[derive(Clone, Copy, Debug, PartialEq, Eq)]"),
+            Line(format!("pub enum {or_unknown} {{")),
+            indent(vec![
+                Line(format!("Known({last_name}),")),
+                line("Unknown(u16),"),
+            ]),
+            line("}"),
+            Line(format!("impl ::core::convert::From<u16> for {or_unknown} {{")),
+            indent(ctx.inline_accessor()),
+            indent(Line(format!(
+                "fn from(value: u16) -> Self {{ match <{last_name} as ::core::convert::TryFrom<u16>>::try_from(value) {{ ::core::result::Result::Ok(e) => Self::Known(e), ::core::result::Result::Err(_) => Self::Unknown(value) }} }}"
+            ))),
+            line("}"),
+            Line(format!("impl ::core::convert::From<{or_unknown}> for u16 {{")),
+            indent(ctx.inline_accessor()),
+            indent(Line(format!(
+                "fn from(x: {or_unknown}) -> u16 {{ match x {{ {or_unknown}::Known(e) => e as u16, {or_unknown}::Unknown(n) => n }} }}"
+            ))),
+            line("}"),
+            Line(format!("impl ::core::convert::From<{last_name}> for {or_unknown} {{")),
+            indent(ctx.inline_accessor()),
+            indent(Line(format!(
+                "fn from(e: {last_name}) -> Self {{ Self::Known(e) }}"
+            ))),
+            line("}"),
+        ]));
+    }
 
-            private_mod_interior.push(Line(format!(
-                "pub const TYPE_ID: u64 = {};",
-                format_u64(node_id)
-            )));
+    output.push(Branch(vec![
+        Line(fmt!(
+            ctx,
+            "impl {capnp}::traits::HasTypeId for {last_name} {{"
+        )),
+        indent(Line(format!(
+            "const TYPE_ID: u64 = {}u64;",
+            format_u64(node_id)
+        ))),
+        line("}"),
+    ]));
 
-            private_mod_interior.push(crate::pointer_constants::node_word_array_declaration(
+    output.push(Branch(vec![
+        Line(format!("mod {} {{", name_as_mod)),
+        Branch(vec![
+            crate::pointer_constants::node_word_array_declaration(
                 ctx,
                 "ENCODED_NODE",
-                *node_reader,
+                node_reader,
                 crate::pointer_constants::WordArrayDeclarationOptions { public: true },
-            )?);
+            )?,
+            display_name_declaration(node_reader)?,
+            generate_get_annotation_types(ctx, node_reader)?,
+        ]),
+        Line("}".into()),
+    ]));
+    output.push(generate_module_extensions(ctx, node_id)?);
 
-            mod_interior.push(line("#![allow(unused_variables)]"));
-            let methods = interface.get_methods()?;
-            for (ordinal, method) in methods.into_iter().enumerate() {
-                let name = method.get_name()?.to_str()?;
-
-                let param_id = method.get_param_struct_type();
-                let param_node = &ctx.node_map[&param_id];
-                let mut builder_params_string = String::new();
-                let mut builder_params_inner_string = String::new();
-                let (param_scopes, params_ty_params) = if param_node.get_scope_id() == 0 {
-                    let mut names = names.clone();
-                    let local_name = module_name(&format!("{name}Params"));
-                    nested_output.push(generate_node(
-                        ctx,
-                        param_id,
-                        &local_name,
-                        &mut builder_params_string,
-                        &mut builder_params_inner_string,
-                        true,
-                    )?);
-                    names.push(local_name);
-                    (names, params.params.clone())
-                } else {
-                    (
-                        ctx.scope_map[&param_node.get_id()].clone(),
-                        get_ty_params_of_brand(ctx, method.get_param_brand()?)?,
-                    )
-                };
-                let param_type = do_branding(
-                    ctx,
-                    param_id,
-                    method.get_param_brand()?,
-                    Leaf::Owned,
-                    &param_scopes.join("::"),
-                )?;
+    Ok(Branch(output))
+}
 
-                let result_id = method.get_result_struct_type();
-                let result_node = &ctx.node_map[&result_id];
-                let (result_scopes, results_ty_params) = if result_node.get_scope_id() == 0 {
-                    let mut names = names.clone();
-                    let local_name = module_name(&format!("{name}Results"));
-                    nested_output.push(generate_node(
-                        ctx,
-                        result_id,
-                        &local_name,
-                        &mut String::new(),
-                        &mut String::new(),
-                        true,
-                    )?);
-                    names.push(local_name);
-                    (names, params.params.clone())
-                } else {
-                    (
-                        ctx.scope_map[&result_node.get_id()].clone(),
-                        get_ty_params_of_brand(ctx, method.get_result_brand()?)?,
-                    )
-                };
-                let result_type = do_branding(
-                    ctx,
-                    result_id,
-                    method.get_result_brand()?,
-                    Leaf::Owned,
-                    &result_scopes.join("::"),
-                )?;
+/// Generates the module of interface `node`, with its `Client` and `Server`, the params and
+/// results structs of its methods, and `nested_output`.
+fn generate_interface_node<'a>(
+    node: &NodeContext<'_, 'a>,
+    interface: schema_capnp::node::interface::Reader<'a>,
+    mut nested_output: Vec<FormattedText>,
+) -> ::capnp::Result<FormattedText> {
+    use capnp::schema_capnp::*;
 
-                dispatch_arms.push(
-                    Line(fmt!(ctx,
-                        "{ordinal} => server.{}({capnp}::private::capability::internal_get_typed_params(params), {capnp}::private::capability::internal_get_typed_results(results)).await,",
-                        module_name(name))));
-                mod_interior.push(Line(fmt!(
-                    ctx,
-                    "pub type {}Params<{}> = {capnp}::capability::Params<{}>;",
-                    capitalize_first_letter(name),
-                    params_ty_params,
-                    param_type
-                )));
-                mod_interior.push(Line(fmt!(
-                    ctx,
-                    "pub type {}Results<{}> = {capnp}::capability::Results<{}>;",
-                    capitalize_first_letter(name),
-                    results_ty_params,
-                    result_type
-                )));
-                server_interior.push(
-                    Line(fmt!(ctx,
-                        "async fn {}(&self, _: {}Params<{}>, _: {}Results<{}>) -> Result<(), {capnp}::Error> {{ Result::<(), capnp::Error>::Err({capnp}::Error::unimplemented(\"method {}::Server::{} not implemented\".to_string())) }}",
-                        module_name(name),
-                        capitalize_first_letter(name), params_ty_params,
-                        capitalize_first_letter(name), results_ty_params,
-                        node_name, module_name(name)
-                    )));
+    let NodeContext {
+        ctx,
+        id: node_id,
+        name: node_name,
+        reader: node_reader,
+    } = *node;
+    let mut output = Vec::new();
+    if ctx.no_alloc {
+        return Err(Error::failed(format!(
+            "interface `{}` cannot be generated with `no_alloc`",
+            node_reader.get_display_name()?.to_str()?
+        )));
+    }
+    let params = node_reader.parameters_texts(ctx);
+    output.push(BlankLine);
 
-                client_impl_interior.push(Line(fmt!(
-                    ctx,
-                    "pub fn {}_request(&self) -> {capnp}::capability::Request<{},{}> {{",
-                    camel_to_snake_case(name),
-                    param_type,
-                    result_type
-                )));
+    let is_generic = node_reader.get_is_generic();
 
-                client_impl_interior.push(indent(Line(format!(
-                    "self.client.new_call(_private::TYPE_ID, {ordinal}, ::core::option::Option::None)"
-                ))));
-                client_impl_interior.push(line("}"));
+    let names = &ctx.scope_map[&node_id];
+    let mut client_impl_interior = Vec::new();
+    let mut server_interior = Vec::new();
+    let mut mod_interior = Vec::new();
+    let mut dispatch_arms = Vec::new();
+    let mut private_mod_interior = Vec::new();
+
+    let bracketed_params = if params.params.is_empty() {
+        "".to_string()
+    } else {
+        format!("<{}>", params.params)
+    };
+
+    private_mod_interior.push(Line(format!(
+        "pub const TYPE_ID: u64 = {};",
+        format_u64(node_id)
+    )));
+    private_mod_interior.push(display_name_declaration(node_reader)?);
+
+    private_mod_interior.push(crate::pointer_constants::node_word_array_declaration(
+        ctx,
+        "ENCODED_NODE",
+        node_reader,
+        crate::pointer_constants::WordArrayDeclarationOptions { public: true },
+    )?);
+
+    check_method_struct_modules(ctx, node_id, interface)?;
+    let mut client_methods = Vec::new();
+    for method in interface.get_methods()? {
+        let name = method.get_name()?.to_str()?;
+        let owner = format!("method `{name}`");
+        let styled_name = camel_to_snake_case(name);
+        client_methods.push((format!("{styled_name}_request"), owner.clone()));
+        if ctx.generate_convenience_builders {
+            client_methods.push((format!("build_{styled_name}_request"), owner.clone()));
+            client_methods.push((format!("build_{styled_name}_request_with"), owner));
+        }
+    }
+    check_member_names(ctx, node_id, client_methods)?;
+
+    mod_interior.push(line("#![allow(unused_variables)]"));
+    let mut method_infos = Vec::new();
+    let methods = interface.get_methods()?;
+    for (ordinal, method) in methods.into_iter().enumerate() {
+        let name = method.get_name()?.to_str()?;
+        method_infos.push(method_info(ctx, node_id, ordinal, method)?);
+
+        let param_id = method.get_param_struct_type();
+        let param_node = &ctx.get_node(param_id, node_id)?;
+        let mut builder_params_string = String::new();
+        let mut builder_params_inner_string = String::new();
+        let (param_scopes, params_ty_params) = if param_node.get_scope_id() == 0 {
+            let mut names = names.clone();
+            let local_name = module_name(&format!("{}Params", method_struct_base_name(method)?));
+            nested_output.push(generate_node(
+                ctx,
+                param_id,
+                &local_name,
+                &mut builder_params_string,
+                &mut builder_params_inner_string,
+                true,
+            )?);
+            names.push(local_name);
+            (names, params.params.clone())
+        } else {
+            (
+                ctx.scope_map[&param_node.get_id()].clone(),
+                get_ty_params_of_brand(ctx, method.get_param_brand()?)?,
+            )
+        };
+        let param_type = do_branding(
+            ctx,
+            param_id,
+            method.get_param_brand()?,
+            Leaf::Owned,
+            &param_scopes.join("::"),
+        )?;
+
+        let result_id = method.get_result_struct_type();
+        let is_streaming = result_id == STREAM_RESULT_TYPE_ID;
+        let (result_scopes, results_ty_params) = if is_streaming {
+            (Vec::new(), String::new())
+        } else if ctx.get_node(result_id, node_id)?.get_scope_id() == 0 {
+            let mut names = names.clone();
+            let local_name = module_name(&format!("{}Results", method_struct_base_name(method)?));
+            nested_output.push(generate_node(
+                ctx,
+                result_id,
+                &local_name,
+                &mut String::new(),
+                &mut String::new(),
+                true,
+            )?);
+            names.push(local_name);
+            (names, params.params.clone())
+        } else {
+            (
+                ctx.scope_map[&result_id].clone(),
+                get_ty_params_of_brand(ctx, method.get_result_brand()?)?,
+            )
+        };
+        let result_type = if is_streaming {
+            String::new()
+        } else {
+            do_branding(
+                ctx,
+                result_id,
+                method.get_result_brand()?,
+                Leaf::Owned,
+                &result_scopes.join("::"),
+            )?
+        };
 
-                let params_type_string = format!(", {builder_params_string}");
-                let param_build_call =
-                    format!("let mut builder = req.get();\n{builder_params_inner_string}");
+        // Implicit parameters, such as `T` in `foo @0 [T] (value :T) -> ()`, become type
+        // parameters of the client methods. The server sees them as `AnyPointer`, since
+        // the types are chosen by each caller.
+        // Parameters that appear in neither type are left out, since callers could not
+        // infer them.
+        let mut used_implicit_parameters = HashSet::new();
+        get_implicit_params_of_brand(&mut used_implicit_parameters, method.get_param_brand()?)?;
+        get_implicit_params_of_brand(&mut used_implicit_parameters, method.get_result_brand()?)?;
+        let anonymous_struct = param_node.get_scope_id() == 0
+            || (!is_streaming && ctx.get_node(result_id, node_id)?.get_scope_id() == 0);
+        let mut implicit_parameters = Vec::new();
+        let mut bounds = Vec::new();
+        for (index, parameter) in method.get_implicit_parameters()?.iter().enumerate() {
+            let parameter = parameter.get_name()?.to_string()?;
+            if anonymous_struct || used_implicit_parameters.contains(&(index as u16)) {
+                if params.expanded_list.contains(&parameter) {
+                    return Err(Error::failed(format!(
+                        "implicit parameter `{parameter}` of method `{name}` of `{}` has the same name as a parameter of an enclosing scope, which Rust does not allow",
+                        node_reader.get_display_name()?.to_str()?
+                    )));
+                }
+                bounds.push(fmt!(ctx, "{parameter}: {capnp}::traits::Owned"));
+            }
+            implicit_parameters.push(parameter);
+        }
+        let method_generics = if bounds.is_empty() {
+            String::new()
+        } else {
+            format!("<{}>", bounds.join(", "))
+        };
+        *ctx.implicit_method_parameters.borrow_mut() = implicit_parameters;
+        let client_param_type = do_branding(
+            ctx,
+            param_id,
+            method.get_param_brand()?,
+            Leaf::Owned,
+            &param_scopes.join("::"),
+        )?;
+        let client_result_type = if is_streaming {
+            String::new()
+        } else {
+            do_branding(
+                ctx,
+                result_id,
+                method.get_result_brand()?,
+                Leaf::Owned,
+                &result_scopes.join("::"),
+            )?
+        };
+        let param_builder_type = do_branding(
+            ctx,
+            param_id,
+            method.get_param_brand()?,
+            Leaf::Builder("'_"),
+            &param_scopes.join("::"),
+        )?;
+        ctx.implicit_method_parameters.borrow_mut().clear();
+
+        mod_interior.push(Line(fmt!(
+            ctx,
+            "pub type {}Params<{}> = {capnp}::capability::Params<{}>;",
+            capitalize_first_letter(name),
+            params_ty_params,
+            param_type
+        )));
 
-                client_impl_interior.push(Line(fmt!(
+        // A streaming method has no results, and its server only sees the params.
+        let (request_type, new_call) = if is_streaming {
+            dispatch_arms.push(Line(fmt!(ctx,
+                "{ordinal} => server.{}({capnp}::private::capability::internal_get_typed_params(params)).await,",
+                module_name(name))));
+            server_interior.push(
+                Line(fmt!(ctx,
+                    "async fn {}(&self, _: {}Params<{}>) -> Result<(), {capnp}::Error> {{ Result::<(), capnp::Error>::Err({capnp}::Error::unimplemented(\"method {}::Server::{} not implemented\".to_string())) }}",
+                    module_name(name),
+                    capitalize_first_letter(name), params_ty_params,
+                    node_name, module_name(name)
+                )));
+            (
+                fmt!(
                     ctx,
-                    "pub fn build_{}_request(&self{}) -> {capnp}::capability::Request<{},{}> {} {{",
-                    camel_to_snake_case(name),
-                    params_type_string,
-                    param_type,
-                    result_type,
-                    params.where_clause
+                    "{capnp}::capability::StreamingRequest<{client_param_type}>"
+                ),
+                "new_streaming_call",
+            )
+        } else {
+            dispatch_arms.push(
+                Line(fmt!(ctx,
+                    "{ordinal} => server.{}({capnp}::private::capability::internal_get_typed_params(params), {capnp}::private::capability::internal_get_typed_results(results)).await,",
+                    module_name(name))));
+            mod_interior.push(Line(fmt!(
+                ctx,
+                "pub type {}Results<{}> = {capnp}::capability::Results<{}>;",
+                capitalize_first_letter(name),
+                results_ty_params,
+                result_type
+            )));
+            server_interior.push(
+                Line(fmt!(ctx,
+                    "async fn {}(&self, _: {}Params<{}>, _: {}Results<{}>) -> Result<(), {capnp}::Error> {{ Result::<(), capnp::Error>::Err({capnp}::Error::unimplemented(\"method {}::Server::{} not implemented\".to_string())) }}",
+                    module_name(name),
+                    capitalize_first_letter(name), params_ty_params,
+                    capitalize_first_letter(name), results_ty_params,
+                    node_name, module_name(name)
                 )));
+            (
+                fmt!(
+                    ctx,
+                    "{capnp}::capability::Request<{client_param_type},{client_result_type}>"
+                ),
+                "new_call",
+            )
+        };
 
-                client_impl_interior.push(indent(Line(fmt!(ctx,
-                    "let mut req: {capnp}::capability::Request<{},{}> = self.client.new_call(_private::TYPE_ID, {ordinal}, ::core::option::Option::None);
-                    {}
-                    req",
-                    param_type,
-                    result_type,
-                    param_build_call
-                ))));
-                client_impl_interior.push(line("}"));
-
-                method.get_annotations()?;
-            }
-
-            let mut base_dispatch_arms = Vec::new();
-            let server_base = {
-                let mut base_traits = Vec::new();
-
-                fn find_super_interfaces<'a>(
-                    interface: schema_capnp::node::interface::Reader<'a>,
-                    all_extends: &mut Vec<
-                        <schema_capnp::superclass::Owned as capnp::traits::OwnedStruct>::Reader<'a>,
-                    >,
-                    ctx: &GeneratorContext<'a>,
-                ) -> ::capnp::Result<()> {
-                    let extends = interface.get_superclasses()?;
-                    for superclass in extends {
-                        if let node::Interface(interface) =
-                            ctx.node_map[&superclass.get_id()].which()?
-                        {
-                            find_super_interfaces(interface, all_extends, ctx)?;
-                        }
-                        all_extends.push(superclass);
-                    }
-                    Ok(())
-                }
+        client_impl_interior.push(Line(format!(
+            "pub fn {}_request{method_generics}(&self) -> {request_type} {{",
+            camel_to_snake_case(name),
+        )));
 
-                let mut extends = Vec::new();
-                find_super_interfaces(interface, &mut extends, ctx)?;
-                for interface in &extends {
-                    let type_id = interface.get_id();
-                    let brand = interface.get_brand()?;
-                    let the_mod = ctx.get_qualified_module(type_id);
+        client_impl_interior.push(indent(Line(format!(
+            "self.client.{new_call}(_private::TYPE_ID, {ordinal}, ::core::option::Option::None)"
+        ))));
+        client_impl_interior.push(line("}"));
 
-                    base_dispatch_arms.push(Line(format!(
-                        "0x{type_id:x} => {}::dispatch_call_internal(&self.server, method_id, params, results).await,",
-                        do_branding(
-                            ctx, type_id, brand, Leaf::ServerDispatch, &the_mod)?)));
-                    base_traits.push(do_branding(ctx, type_id, brand, Leaf::Server, &the_mod)?);
-                }
-                if !extends.is_empty() {
-                    format!(": {}", base_traits.join(" + "))
-                } else {
-                    "".to_string()
+        if ctx.generate_convenience_builders {
+            client_impl_interior.extend(generate_request_builders(
+                ctx,
+                param_id,
+                name,
+                ordinal,
+                &method_generics,
+                &request_type,
+                new_call,
+                &param_builder_type,
+                &params.where_clause,
+                &builder_params_string,
+                &builder_params_inner_string,
+            )?);
+        }
+
+        method.get_annotations()?;
+    }
+
+    let mut base_dispatch_arms = Vec::new();
+    let mut upcast_impls = Vec::new();
+    let mut superclass_ids = Vec::new();
+    let server_base = {
+        let mut base_traits = Vec::new();
+
+        fn find_super_interfaces<'a>(
+            interface: schema_capnp::node::interface::Reader<'a>,
+            all_extends: &mut Vec<
+                <schema_capnp::superclass::Owned as capnp::traits::OwnedStruct>::Reader<'a>,
+            >,
+            ctx: &GeneratorContext<'a>,
+        ) -> ::capnp::Result<()> {
+            let extends = interface.get_superclasses()?;
+            for superclass in extends {
+                if let node::Interface(interface) = ctx
+                    .get_node(superclass.get_id(), superclass.get_id())?
+                    .which()?
+                {
+                    find_super_interfaces(interface, all_extends, ctx)?;
                 }
-            };
+                all_extends.push(superclass);
+            }
+            Ok(())
+        }
 
-            mod_interior.push(BlankLine);
-            mod_interior.push(Line(format!("pub struct Client{bracketed_params} {{")));
-            mod_interior.push(indent(Line(fmt!(
-                ctx,
-                "pub client: {capnp}::capability::Client,"
-            ))));
-            if is_generic {
-                mod_interior.push(indent(Line(params.phantom_data_type.clone())));
+        let mut extends = Vec::new();
+        find_super_interfaces(interface, &mut extends, ctx)?;
+        let mut upcast_targets = HashSet::new();
+        let mut inherited = HashSet::new();
+        for interface in &extends {
+            let type_id = interface.get_id();
+            let first_visit = inherited.insert(type_id);
+            if first_visit {
+                superclass_ids.push(type_id);
+                if let node::Interface(super_interface) = ctx.get_node(type_id, node_id)?.which()? {
+                    for (ordinal, method) in super_interface.get_methods()?.into_iter().enumerate()
+                    {
+                        method_infos.push(method_info(ctx, type_id, ordinal, method)?);
+                    }
+                }
             }
-            mod_interior.push(line("}"));
-            mod_interior.push(
-                Branch(vec![
-                    Line(fmt!(ctx,"impl {bracketed_params} {capnp}::capability::FromClientHook for Client{bracketed_params} {{")),
-                    indent(Line(fmt!(ctx,"fn new(hook: Box<dyn ({capnp}::private::capability::ClientHook)>) -> Self {{"))),
-                    indent(indent(Line(fmt!(ctx,"Self {{ client: {capnp}::capability::Client::new(hook), {} }}", params.phantom_data_value)))),
-                    indent(line("}")),
-                    indent(Line(fmt!(ctx,"fn into_client_hook(self) -> Box<dyn ({capnp}::private::capability::ClientHook)> {{"))),
-                    indent(indent(line("self.client.hook"))),
-                    indent(line("}")),
-                    indent(Line(fmt!(ctx,"fn as_client_hook(&self) -> &dyn ({capnp}::private::capability::ClientHook) {{"))),
-                    indent(indent(line("&*self.client.hook"))),
+            let brand = interface.get_brand()?;
+            let the_mod = ctx.get_qualified_module(type_id);
+
+            // Diamond inheritance can reach the same superclass more than once.
+            let super_client = do_branding(ctx, type_id, brand, Leaf::Client, &the_mod)?;
+            if upcast_targets.insert(super_client.clone()) {
+                upcast_impls.push(Branch(vec![
+                    Line(format!(
+                        "impl <{0}> ::core::convert::From<Client{1}> for {2} {3} {{",
+                        params.params, bracketed_params, super_client, params.where_clause
+                    )),
+                    indent(Line(format!(
+                        "fn from(client: Client{bracketed_params}) -> Self {{"
+                    ))),
+                    indent(indent(Line(fmt!(
+                        ctx,
+                        "{capnp}::capability::FromClientHook::new(client.client.hook)"
+                    )))),
                     indent(line("}")),
                     line("}"),
-                    Line(fmt!(ctx,"impl {bracketed_params} {capnp}::introspect::Introspect for Client{bracketed_params} {{ fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Capability({capnp}::introspect::RawCapabilitySchema {{ encoded_node: &_private::ENCODED_NODE }}).into() }} }}")),
-                    ]));
+                ]));
+            }
 
-            mod_interior.push(if !is_generic {
-                Branch(vec![
-                    Line("#[derive(Copy, Clone)]".into()),
-                    line("pub struct Owned(());"),
-                    Line(fmt!(ctx,"impl {capnp}::introspect::Introspect for Owned {{ fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Capability({capnp}::introspect::RawCapabilitySchema {{ encoded_node: &_private::ENCODED_NODE }}).into() }} }}")),
-                    line("impl ::capnp::traits::Owned for Owned { type Reader<'a> = Client; type Builder<'a> = Client; }"),
-                    Line(fmt!(ctx,"impl {capnp}::traits::Pipelined for Owned {{ type Pipeline = Client; }}"))])
-            } else {
-                Branch(vec![
-                    Line("#[derive(Copy, Clone)]".into()),
-                    Line(format!("pub struct Owned<{}> {} {{", params.params, params.where_clause)),
-                    indent(Line(params.phantom_data_type.clone())),
+            // Calls are routed by interface ID alone, so each ancestor gets one arm,
+            // from the first path that reaches it.
+            if first_visit {
+                base_dispatch_arms.push(Line(format!(
+                    "0x{type_id:x} => {}::dispatch_call_internal(&self.server, method_id, params, results).await,",
+                    do_branding(
+                        ctx, type_id, brand, Leaf::ServerDispatch, &the_mod)?)));
+            }
+            let base_trait = do_branding(ctx, type_id, brand, Leaf::Server, &the_mod)?;
+            if !base_traits.contains(&base_trait) {
+                base_traits.push(base_trait);
+            }
+        }
+        if !extends.is_empty() {
+            format!(": {}", base_traits.join(" + "))
+        } else {
+            "".to_string()
+        }
+    };
+
+    private_mod_interior.push(Branch(vec![
+        Line(fmt!(
+            ctx,
+            "pub static METHODS: &[{capnp}::introspect::MethodInfo] = &["
+        )),
+        indent(method_infos),
+        line("];"),
+    ]));
+
+    mod_interior.push(BlankLine);
+    mod_interior.push(Line(format!("pub struct Client{bracketed_params} {{")));
+    mod_interior.push(indent(Line(fmt!(
+        ctx,
+        "pub client: {capnp}::capability::Client,"
+    ))));
+    if is_generic {
+        mod_interior.push(indent(Line(params.phantom_data_type.clone())));
+    }
+    mod_interior.push(line("}"));
+    mod_interior.push(
+        Branch(vec![
+            Line(fmt!(ctx,"impl {bracketed_params} {capnp}::capability::FromClientHook for Client{bracketed_params} {{")),
+            indent(Line(fmt!(ctx,"fn new(hook: Box<dyn ({capnp}::private::capability::ClientHook)>) -> Self {{"))),
+            indent(indent(Line(fmt!(ctx,"Self {{ client: {capnp}::capability::Client::new(hook), {} }}", params.phantom_data_value)))),
+            indent(line("}")),
+            indent(Line(fmt!(ctx,"fn into_client_hook(self) -> Box<dyn ({capnp}::private::capability::ClientHook)> {{"))),
+            indent(indent(line("self.client.hook"))),
+            indent(line("}")),
+            indent(Line(fmt!(ctx,"fn as_client_hook(&self) -> &dyn ({capnp}::private::capability::ClientHook) {{"))),
+            indent(indent(line("&*self.client.hook"))),
+            indent(line("}")),
+            line("}"),
+            Line(fmt!(ctx,"impl {bracketed_params} {capnp}::introspect::Introspect for Client{bracketed_params} {{ fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Capability({capnp}::introspect::RawCapabilitySchema {{ encoded_node: &_private::ENCODED_NODE }}).into() }} }}")),
+            ]));
+
+    mod_interior.push(if !is_generic {
+        Branch(vec![
+            Line("#[derive(Copy, Clone)]".into()),
+            line("pub struct Owned(());"),
+            line("impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }"),
+            Line(fmt!(ctx,"impl {capnp}::introspect::Introspect for Owned {{ fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Capability({capnp}::introspect::RawCapabilitySchema {{ encoded_node: &_private::ENCODED_NODE }}).into() }} }}")),
+            line("impl ::capnp::traits::Owned for Owned { type Reader<'a> = Client; type Builder<'a> = Client; }"),
+            Line(fmt!(ctx,"impl {capnp}::traits::Pipelined for Owned {{ type Pipeline = Client; }}"))])
+    } else {
+        Branch(vec![
+            Line("#[derive(Copy, Clone)]".into()),
+            Line(format!("pub struct Owned<{}> {} {{", params.params, params.where_clause)),
+            indent(Line(params.phantom_data_type.clone())),
+            line("}"),
+            Line(format!("impl <{0}> ::core::fmt::Debug for Owned <{0}> {1} {{ fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {{ f.write_str(_private::DISPLAY_NAME) }} }}",
+                params.params, params.where_clause)),
+            Line(fmt!(ctx,
+                      "impl <{0}> {capnp}::introspect::Introspect for Owned <{0}> {1} {{ fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Capability({capnp}::introspect::RawCapabilitySchema {{ encoded_node: &_private::ENCODED_NODE }}).into() }} }}",
+                      params.params, params.where_clause)),
+            Line(fmt!(ctx,
+                "impl <{0}> {capnp}::traits::Owned for Owned <{0}> {1} {{ type Reader<'a> = Client<{0}>; type Builder<'a> = Client<{0}>; }}",
+                params.params, params.where_clause)),
+            Line(fmt!(ctx,
+                "impl <{0}> {capnp}::traits::Pipelined for Owned <{0}> {1} {{ type Pipeline = Client{2}; }}",
+                params.params, params.where_clause, bracketed_params))])
+    });
+
+    mod_interior.push(Branch(vec![
+        Line(fmt!(ctx,"impl <'a,{0}> {capnp}::traits::FromPointerReader<'a> for Client<{0}> {1} {{",
+            params.params, params.where_clause)),
+        indent(vec![
+                Line(fmt!(ctx,"fn get_from_pointer(reader: &{capnp}::private::layout::PointerReader<'a>, _default: ::core::option::Option<&'a [{capnp}::Word]>) -> {capnp}::Result<Self> {{")),
+                indent(Line(fmt!(ctx,"::core::result::Result::Ok({capnp}::capability::FromClientHook::new(reader.get_capability()?))"))),
+                line("}")]),
+        line("}")]));
+
+    mod_interior.push(Branch(vec![
+        Line(fmt!(ctx,"impl <'a,{0}> {capnp}::traits::FromPointerBuilder<'a> for Client<{0}> {1} {{",
+                     params.params, params.where_clause)),
+        indent(vec![
+                    Line(fmt!(ctx,"fn init_pointer(_builder: {capnp}::private::layout::PointerBuilder<'a>, _size: u32) -> Self {{")),
+                    indent(line("unimplemented!()")),
                     line("}"),
-                    Line(fmt!(ctx,
-                              "impl <{0}> {capnp}::introspect::Introspect for Owned <{0}> {1} {{ fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Capability({capnp}::introspect::RawCapabilitySchema {{ encoded_node: &_private::ENCODED_NODE }}).into() }} }}",
-                              params.params, params.where_clause)),
-                    Line(fmt!(ctx,
-                        "impl <{0}> {capnp}::traits::Owned for Owned <{0}> {1} {{ type Reader<'a> = Client<{0}>; type Builder<'a> = Client<{0}>; }}",
-                        params.params, params.where_clause)),
-                    Line(fmt!(ctx,
-                        "impl <{0}> {capnp}::traits::Pipelined for Owned <{0}> {1} {{ type Pipeline = Client{2}; }}",
-                        params.params, params.where_clause, bracketed_params))])
-            });
+                    Line(fmt!(ctx,"fn get_from_pointer(builder: {capnp}::private::layout::PointerBuilder<'a>, _default: ::core::option::Option<&'a [{capnp}::Word]>) -> {capnp}::Result<Self> {{")),
+                    indent(Line(fmt!(ctx,"::core::result::Result::Ok({capnp}::capability::FromClientHook::new(builder.get_capability()?))"))),
+                    line("}")]),
+        line("}"),
+        BlankLine]));
 
-            mod_interior.push(Branch(vec![
-                Line(fmt!(ctx,"impl <'a,{0}> {capnp}::traits::FromPointerReader<'a> for Client<{0}> {1} {{",
-                    params.params, params.where_clause)),
-                indent(vec![
-                        Line(fmt!(ctx,"fn get_from_pointer(reader: &{capnp}::private::layout::PointerReader<'a>, _default: ::core::option::Option<&'a [{capnp}::Word]>) -> {capnp}::Result<Self> {{")),
-                        indent(Line(fmt!(ctx,"::core::result::Result::Ok({capnp}::capability::FromClientHook::new(reader.get_capability()?))"))),
-                        line("}")]),
-                line("}")]));
+    mod_interior.push(Branch(vec![
+        Line(fmt!(ctx,
+            "impl <{0}> {capnp}::traits::SetPointerBuilder for Client<{0}> {1} {{",
+            params.params, params.where_clause)),
+        indent(vec![
+                    Line(fmt!(ctx,"fn set_pointer_builder(mut pointer: {capnp}::private::layout::PointerBuilder<'_>, from: Self, _canonicalize: bool) -> {capnp}::Result<()> {{")),
+                    indent(Line("pointer.set_capability(from.client.hook);".to_string())),
+                    indent(Line("::core::result::Result::Ok(())".to_string())),
+                    line("}")
+                ]
+        ),
+        line("}")]));
 
-            mod_interior.push(Branch(vec![
-                Line(fmt!(ctx,"impl <'a,{0}> {capnp}::traits::FromPointerBuilder<'a> for Client<{0}> {1} {{",
-                             params.params, params.where_clause)),
-                indent(vec![
-                            Line(fmt!(ctx,"fn init_pointer(_builder: {capnp}::private::layout::PointerBuilder<'a>, _size: u32) -> Self {{")),
-                            indent(line("unimplemented!()")),
-                            line("}"),
-                            Line(fmt!(ctx,"fn get_from_pointer(builder: {capnp}::private::layout::PointerBuilder<'a>, _default: ::core::option::Option<&'a [{capnp}::Word]>) -> {capnp}::Result<Self> {{")),
-                            indent(Line(fmt!(ctx,"::core::result::Result::Ok({capnp}::capability::FromClientHook::new(builder.get_capability()?))"))),
-                            line("}")]),
-                line("}"),
-                BlankLine]));
+    mod_interior.push(Branch(vec![
+        Line(fmt!(
+            ctx,
+            "impl {bracketed_params} {capnp}::traits::HasTypeId for Client{bracketed_params} {{"
+        )),
+        indent(Line("const TYPE_ID: u64 = _private::TYPE_ID;".to_string())),
+        line("}"),
+    ]));
 
-            mod_interior.push(Branch(vec![
-                Line(fmt!(ctx,
-                    "impl <{0}> {capnp}::traits::SetPointerBuilder for Client<{0}> {1} {{",
-                    params.params, params.where_clause)),
-                indent(vec![
-                            Line(fmt!(ctx,"fn set_pointer_builder(mut pointer: {capnp}::private::layout::PointerBuilder<'_>, from: Self, _canonicalize: bool) -> {capnp}::Result<()> {{")),
-                            indent(Line("pointer.set_capability(from.client.hook);".to_string())),
-                            indent(Line("::core::result::Result::Ok(())".to_string())),
-                            line("}")
-                        ]
-                ),
-                line("}")]));
+    mod_interior.push(Branch(vec![
+        Line(format!(
+            "impl {bracketed_params} Clone for Client{bracketed_params} {{"
+        )),
+        indent(line("fn clone(&self) -> Self {")),
+        indent(indent(Line(fmt!(
+            ctx,
+            "Self {{ client: {capnp}::capability::Client::new(self.client.hook.add_ref()), {} }}",
+            params.phantom_data_value
+        )))),
+        indent(line("}")),
+        line("}"),
+    ]));
 
-            mod_interior.push(Branch(vec![
-                Line(fmt!(ctx,
-                    "impl {bracketed_params} {capnp}::traits::HasTypeId for Client{bracketed_params} {{"
-                )),
-                indent(Line(
-                    "const TYPE_ID: u64 = _private::TYPE_ID;".to_string(),
-                )),
-                line("}"),
-            ]));
+    mod_interior.push(Branch(vec![
+        line("/// Compares the underlying hooks. See `capnp::capability::Client`."),
+        Line(format!(
+            "impl {bracketed_params} ::core::cmp::PartialEq for Client{bracketed_params} {{"
+        )),
+        indent(line("fn eq(&self, other: &Self) -> bool {")),
+        indent(indent(line("self.client == other.client"))),
+        indent(line("}")),
+        line("}"),
+        Line(format!(
+            "impl {bracketed_params} ::core::cmp::Eq for Client{bracketed_params} {{}}"
+        )),
+        Line(format!(
+            "impl {bracketed_params} ::core::hash::Hash for Client{bracketed_params} {{"
+        )),
+        indent(line(
+            "fn hash<_H: ::core::hash::Hasher>(&self, state: &mut _H) {",
+        )),
+        indent(indent(line(
+            "::core::hash::Hash::hash(&self.client, state)",
+        ))),
+        indent(line("}")),
+        line("}"),
+    ]));
 
-            mod_interior.push(
-                Branch(vec![
-                    Line(format!("impl {bracketed_params} Clone for Client{bracketed_params} {{")),
-                    indent(line("fn clone(&self) -> Self {")),
-                    indent(indent(Line(fmt!(ctx,"Self {{ client: {capnp}::capability::Client::new(self.client.hook.add_ref()), {} }}", params.phantom_data_value)))),
-                    indent(line("}")),
-                    line("}")]));
+    client_impl_interior.push(Branch(vec![
+        line("/// Converts this client into a client for one of its superclasses."),
+        line("pub fn upcast<_U>(self) -> _U where Self: ::core::convert::Into<_U> {"),
+        indent(line("::core::convert::Into::into(self)")),
+        line("}"),
+        line("/// Reinterprets any client as this interface, without checking that the"),
+        line("/// underlying capability actually implements it."),
+        Line(fmt!(
+            ctx,
+            "pub fn downcast_from<_C: {capnp}::capability::FromClientHook>(client: _C) -> Self {{"
+        )),
+        indent(Line(fmt!(
+            ctx,
+            "{capnp}::capability::FromClientHook::new(client.into_client_hook())"
+        ))),
+        line("}"),
+    ]));
 
-            mod_interior.push(Branch(vec![
-                Line(format!(
-                    "impl {bracketed_params} Client{bracketed_params} {{"
-                )),
-                indent(client_impl_interior),
-                line("}"),
-            ]));
+    mod_interior.push(Branch(vec![
+        Line(format!(
+            "impl {bracketed_params} Client{bracketed_params} {{"
+        )),
+        indent(client_impl_interior),
+        line("}"),
+    ]));
+    mod_interior.push(Branch(upcast_impls));
 
-            mod_interior.push(Branch(vec![
-                line("#[allow(async_fn_in_trait)]"),
-                Line(format!(
-                    "pub trait Server<{}> {} {} {{",
-                    params.params, server_base, params.where_clause
-                )),
-                indent(server_interior),
-                line("}"),
-            ]));
+    mod_interior.push(Branch(vec![
+        line("#[allow(async_fn_in_trait)]"),
+        Line(format!(
+            "pub trait Server<{}> {} {} {{",
+            params.params, server_base, params.where_clause
+        )),
+        indent(server_interior),
+        line("}"),
+    ]));
 
-            mod_interior.push(Branch(vec![
-                Line(format!(
-                    "pub struct ServerDispatch<_T,{}> {{",
-                    params.params
-                )),
-                indent(line("pub server: _T,")),
-                indent(if is_generic {
-                    vec![Line(params.phantom_data_type.clone())]
-                } else {
-                    vec![]
-                }),
+    if !is_generic {
+        let mut forwarder_impls = Vec::new();
+        for &interface_id in [node_id].iter().chain(&superclass_ids) {
+            let Some(methods) = generate_forwarder_methods(ctx, interface_id)? else {
+                forwarder_impls.clear();
+                break;
+            };
+            let server = if interface_id == node_id {
+                "Server".to_string()
+            } else {
+                format!("{}::Server", ctx.get_qualified_module(interface_id))
+            };
+            forwarder_impls.push(Branch(vec![
+                Line(format!("impl {server} for Forwarder {{")),
+                indent(methods),
                 line("}"),
             ]));
-
+        }
+        if !forwarder_impls.is_empty() {
             mod_interior.push(Branch(vec![
-                Line(
-                    fmt!(ctx,"impl <_S: Server{1} + 'static, {0}> {capnp}::capability::FromServer<_S> for Client{1} {2}  {{",
-                            params.params, bracketed_params, params.where_clause_with_static)),
-                indent(vec![
-                    Line(format!("type Dispatch = ServerDispatch<_S, {}>;", params.params)),
-                    Line(format!("fn from_server(s: _S) -> ServerDispatch<_S, {}> {{", params.params)),
-                    indent(Line(format!("ServerDispatch {{ server: s, {} }}", params.phantom_data_value))),
-                    line("}"),
-                ]),
-                line("}"),
+                line(
+                    "/// A `Server` that forwards every call, including calls to methods inherited",
+                ),
+                line("/// from superclasses, to the wrapped client and returns its response."),
+                line("pub struct Forwarder(pub Client);"),
+                Branch(forwarder_impls),
             ]));
+        }
+    }
 
-            mod_interior.push(
-                Branch(vec![
-                    (if is_generic {
-                        Line(format!("impl <{}, _T: Server{}> ::core::ops::Deref for ServerDispatch<_T,{}> {} {{", params.params, bracketed_params, params.params, params.where_clause))
-                    } else {
-                        line("impl <_T: Server> ::core::ops::Deref for ServerDispatch<_T> {")
-                    }),
-                    indent(line("type Target = _T;")),
-                    indent(line("fn deref(&self) -> &_T { &self.server}")),
-                    line("}"),
-                    ]));
+    mod_interior.push(Branch(vec![
+        Line(format!(
+            "pub struct ServerDispatch<_T,{}> {{",
+            params.params
+        )),
+        indent(line("pub server: _T,")),
+        indent(if is_generic {
+            vec![Line(params.phantom_data_type.clone())]
+        } else {
+            vec![]
+        }),
+        line("}"),
+    ]));
 
-            mod_interior.push(
-                Branch(vec![
-                    (if is_generic {
-                        Line(format!("impl <{}, _T: Server{}> ::core::ops::DerefMut for ServerDispatch<_T,{}> {} {{", params.params, bracketed_params, params.params, params.where_clause))
-                    } else {
-                        line("impl <_T: Server> ::core::ops::DerefMut for ServerDispatch<_T> {")
-                    }),
-                    indent(line("fn deref_mut(&mut self) -> &mut _T { &mut self.server}")),
-                    line("}"),
-                    ]));
+    mod_interior.push(Branch(vec![
+        Line(
+            fmt!(ctx,"impl <_S: Server{1} + 'static, {0}> {capnp}::capability::FromServer<_S> for Client{1} {2}  {{",
+                    params.params, bracketed_params, params.where_clause_with_static)),
+        indent(vec![
+            Line(format!("type Dispatch = ServerDispatch<_S, {}>;", params.params)),
+            Line(format!("fn from_server(s: _S) -> ServerDispatch<_S, {}> {{", params.params)),
+            indent(Line(format!("ServerDispatch {{ server: s, {} }}", params.phantom_data_value))),
+            line("}"),
+        ]),
+        line("}"),
+    ]));
 
-            mod_interior.push(
-                Branch(vec![
-                    (if is_generic {
-                        Line(fmt!(ctx,"impl <{}, _T: Server{}> {capnp}::capability::Server for ServerDispatch<_T,{}> {} {{", params.params, bracketed_params, params.params, params.where_clause))
-                    } else {
-                        Line(fmt!(ctx,"impl <_T: Server> {capnp}::capability::Server for ServerDispatch<_T> {{"))
-                    }),
-                    indent(Line(fmt!(ctx,"async fn dispatch_call(&self, interface_id: u64, method_id: u16, params: {capnp}::capability::Params<{capnp}::any_pointer::Owned>, results: {capnp}::capability::Results<{capnp}::any_pointer::Owned>) -> Result<(), {capnp}::Error> {{"))),
-                    indent(indent(line("match interface_id {"))),
-                    indent(indent(indent(line("_private::TYPE_ID => Self::dispatch_call_internal(&self.server, method_id, params, results).await,")))),
-                    indent(indent(indent(base_dispatch_arms))),
-                    indent(indent(indent(Line(fmt!(ctx,"_ =>  Err({capnp}::Error::unimplemented(\"Method not implemented.\".to_string())) "))))),
-                    indent(indent(line("}"))),
-                    indent(line("}")),
-                    line("}")]));
+    mod_interior.push(Branch(vec![
+        (if is_generic {
+            Line(format!(
+                "impl <{}, _T: Server{}> ::core::ops::Deref for ServerDispatch<_T,{}> {} {{",
+                params.params, bracketed_params, params.params, params.where_clause
+            ))
+        } else {
+            line("impl <_T: Server> ::core::ops::Deref for ServerDispatch<_T> {")
+        }),
+        indent(line("type Target = _T;")),
+        indent(line("fn deref(&self) -> &_T { &self.server}")),
+        line("}"),
+    ]));
 
-            mod_interior.push(
-                Branch(vec![
-                    (if is_generic {
-                        Line(format!("impl <{}, _T: Server{}> ServerDispatch<_T,{}> {} {{", params.params, bracketed_params, params.params, params.where_clause))
-                    } else {
-                        line("impl <_T :Server> ServerDispatch<_T> {")
-                    }),
-                    indent(Line(fmt!(ctx,"pub async fn dispatch_call_internal(server: &_T, method_id: u16, params: {capnp}::capability::Params<{capnp}::any_pointer::Owned>, results: {capnp}::capability::Results<{capnp}::any_pointer::Owned>) -> Result<(), {capnp}::Error> {{"))),
-                    indent(indent(indent(line("match method_id {")))),
-                    indent(indent(indent(indent(dispatch_arms)))),
-                    indent(indent(indent(indent(Line(fmt!(ctx,"_ => Err({capnp}::Error::unimplemented(\"Method not implemented.\".to_string())) ")))))),
-                    indent(indent(line("}"))),
-                    indent(line("}")),
-                    line("}")]));
+    mod_interior.push(Branch(vec![
+        (if is_generic {
+            Line(format!(
+                "impl <{}, _T: Server{}> ::core::ops::DerefMut for ServerDispatch<_T,{}> {} {{",
+                params.params, bracketed_params, params.params, params.where_clause
+            ))
+        } else {
+            line("impl <_T: Server> ::core::ops::DerefMut for ServerDispatch<_T> {")
+        }),
+        indent(line(
+            "fn deref_mut(&mut self) -> &mut _T { &mut self.server}",
+        )),
+        line("}"),
+    ]));
 
-            mod_interior.push(Branch(vec![
-                line("pub mod _private {"),
-                indent(private_mod_interior),
-                line("}"),
-            ]));
+    mod_interior.push(
+        Branch(vec![
+            (if is_generic {
+                Line(fmt!(ctx,"impl <{}, _T: Server{}> {capnp}::capability::Server for ServerDispatch<_T,{}> {} {{", params.params, bracketed_params, params.params, params.where_clause))
+            } else {
+                Line(fmt!(ctx,"impl <_T: Server> {capnp}::capability::Server for ServerDispatch<_T> {{"))
+            }),
+            indent(Line(fmt!(ctx,"async fn dispatch_call(&self, interface_id: u64, method_id: u16, params: {capnp}::capability::Params<{capnp}::any_pointer::Owned>, results: {capnp}::capability::Results<{capnp}::any_pointer::Owned>) -> Result<(), {capnp}::Error> {{"))),
+            indent(indent(line("match interface_id {"))),
+            indent(indent(indent(line("_private::TYPE_ID => Self::dispatch_call_internal(&self.server, method_id, params, results).await,")))),
+            indent(indent(indent(base_dispatch_arms))),
+            indent(indent(indent(Line(fmt!(ctx,"_ =>  Err({capnp}::Error::unimplemented(\"Method not implemented.\".to_string())) "))))),
+            indent(indent(line("}"))),
+            indent(line("}")),
+            line("}")]));
+
+    mod_interior.push(
+        Branch(vec![
+            (if is_generic {
+                Line(format!("impl <{}, _T: Server{}> ServerDispatch<_T,{}> {} {{", params.params, bracketed_params, params.params, params.where_clause))
+            } else {
+                line("impl <_T :Server> ServerDispatch<_T> {")
+            }),
+            indent(Line(fmt!(ctx,"pub async fn dispatch_call_internal(server: &_T, method_id: u16, params: {capnp}::capability::Params<{capnp}::any_pointer::Owned>, results: {capnp}::capability::Results<{capnp}::any_pointer::Owned>) -> Result<(), {capnp}::Error> {{"))),
+            indent(indent(indent(line("match method_id {")))),
+            indent(indent(indent(indent(dispatch_arms)))),
+            indent(indent(indent(indent(Line(fmt!(ctx,"_ => Err({capnp}::Error::unimplemented(\"Method not implemented.\".to_string())) ")))))),
+            indent(indent(line("}"))),
+            indent(line("}")),
+            line("}")]));
+
+    mod_interior.push(Branch(vec![
+        line("pub mod _private {"),
+        indent(private_mod_interior),
+        line("}"),
+    ]));
 
-            mod_interior.push(Branch(vec![Branch(nested_output)]));
+    mod_interior.push(Branch(vec![Branch(nested_output)]));
+    mod_interior.push(generate_module_extensions(ctx, node_id)?);
 
-            output.push(BlankLine);
-            if is_generic {
-                output.push(Line(format!(
-                    "pub mod {} {{ /* ({}) */",
-                    node_name,
-                    params.expanded_list.join(",")
-                )));
-            } else {
-                output.push(Line(format!("pub mod {node_name} {{")));
-            }
-            output.push(indent(mod_interior));
-            output.push(line("}"));
-        }
+    output.push(BlankLine);
+    if is_generic {
+        output.push(Line(format!(
+            "pub mod {} {{ /* ({}) */",
+            node_name,
+            params.expanded_list.join(",")
+        )));
+    } else {
+        output.push(Line(format!("pub mod {node_name} {{")));
+    }
+    output.push(indent(mod_interior));
+    output.push(line("}"));
 
-        node::Const(c) => {
-            let styled_name = snake_to_upper_case(ctx.get_last_name(node_id)?);
+    Ok(Branch(output))
+}
 
-            let typ = c.get_type()?;
-            let formatted_text = match (typ.which()?, c.get_value()?.which()?) {
-                (type_::Void(()), value::Void(())) => {
-                    Line(format!("pub const {styled_name}: () = ();"))
-                }
-                (type_::Bool(()), value::Bool(b)) => {
-                    Line(format!("pub const {styled_name}: bool = {b};"))
-                }
-                (type_::Int8(()), value::Int8(i)) => {
-                    Line(format!("pub const {styled_name}: i8 = {i};"))
-                }
-                (type_::Int16(()), value::Int16(i)) => {
-                    Line(format!("pub const {styled_name}: i16 = {i};"))
-                }
-                (type_::Int32(()), value::Int32(i)) => {
-                    Line(format!("pub const {styled_name}: i32 = {i};"))
-                }
-                (type_::Int64(()), value::Int64(i)) => {
-                    Line(format!("pub const {styled_name}: i64 = {i};"))
-                }
-                (type_::Uint8(()), value::Uint8(i)) => {
-                    Line(format!("pub const {styled_name}: u8 = {i};"))
-                }
-                (type_::Uint16(()), value::Uint16(i)) => {
-                    Line(format!("pub const {styled_name}: u16 = {i};"))
-                }
-                (type_::Uint32(()), value::Uint32(i)) => {
-                    Line(format!("pub const {styled_name}: u32 = {i};"))
-                }
-                (type_::Uint64(()), value::Uint64(i)) => {
-                    Line(format!("pub const {styled_name}: u64 = {i};"))
-                }
+/// Generates the Rust constant of constant `node`.
+fn generate_const_node<'a>(
+    node: &NodeContext<'_, 'a>,
+    c: schema_capnp::node::const_::Reader<'a>,
+) -> ::capnp::Result<FormattedText> {
+    use capnp::schema_capnp::*;
 
-                (type_::Float32(()), value::Float32(f)) => {
-                    Line(format!("pub const {styled_name}: f32 = {f:e}f32;"))
-                }
+    let NodeContext {
+        ctx, id: node_id, ..
+    } = *node;
+    let mut output = Vec::new();
+    let styled_name = snake_to_upper_case(ctx.get_last_name(node_id)?);
+
+    let typ = c.get_type()?;
+    let formatted_text = match (typ.which()?, c.get_value()?.which()?) {
+        (type_::Void(()), value::Void(())) => Line(format!("pub const {styled_name}: () = ();")),
+        (type_::Bool(()), value::Bool(b)) => Line(format!("pub const {styled_name}: bool = {b};")),
+        (type_::Int8(()), value::Int8(i)) => Line(format!("pub const {styled_name}: i8 = {i};")),
+        (type_::Int16(()), value::Int16(i)) => Line(format!("pub const {styled_name}: i16 = {i};")),
+        (type_::Int32(()), value::Int32(i)) => Line(format!("pub const {styled_name}: i32 = {i};")),
+        (type_::Int64(()), value::Int64(i)) => Line(format!("pub const {styled_name}: i64 = {i};")),
+        (type_::Uint8(()), value::Uint8(i)) => Line(format!("pub const {styled_name}: u8 = {i};")),
+        (type_::Uint16(()), value::Uint16(i)) => {
+            Line(format!("pub const {styled_name}: u16 = {i};"))
+        }
+        (type_::Uint32(()), value::Uint32(i)) => {
+            Line(format!("pub const {styled_name}: u32 = {i};"))
+        }
+        (type_::Uint64(()), value::Uint64(i)) => {
+            Line(format!("pub const {styled_name}: u64 = {i};"))
+        }
 
-                (type_::Float64(()), value::Float64(f)) => {
-                    Line(format!("pub const {styled_name}: f64 = {f:e}f64;"))
-                }
-
-                (type_::Enum(e), value::Enum(v)) => {
-                    if let Some(node) = ctx.node_map.get(&e.get_type_id()) {
-                        match node.which()? {
-                            node::Enum(e) => {
-                                let enumerants = e.get_enumerants()?;
-                                if let Some(enumerant) = enumerants.try_get(u32::from(v)) {
-                                    let variant =
-                                        capitalize_first_letter(get_enumerant_name(enumerant)?);
-                                    let type_string = typ.type_string(ctx, Leaf::Owned)?;
-                                    Line(format!(
-                                        "pub const {}: {} = {}::{};",
-                                        styled_name, &type_string, &type_string, variant
-                                    ))
-                                } else {
-                                    return Err(Error::failed(format!(
-                                        "enumerant out of range: {v}"
-                                    )));
-                                }
-                            }
-                            _ => {
-                                return Err(Error::failed(format!(
-                                    "bad enum type ID: {}",
-                                    e.get_type_id()
-                                )));
-                            }
+        (type_::Float32(()), value::Float32(f)) => {
+            Line(format!("pub const {styled_name}: f32 = {f:e}f32;"))
+        }
+
+        (type_::Float64(()), value::Float64(f)) => {
+            Line(format!("pub const {styled_name}: f64 = {f:e}f64;"))
+        }
+
+        (type_::Enum(e), value::Enum(v)) => {
+            if let Some(node) = ctx.node_map.get(&e.get_type_id()) {
+                match node.which()? {
+                    node::Enum(e) => {
+                        let enumerants = e.get_enumerants()?;
+                        if let Some(enumerant) = enumerants.try_get(u32::from(v)) {
+                            let variant = capitalize_first_letter(get_enumerant_name(enumerant)?);
+                            let type_string = typ.type_string(ctx, Leaf::Owned)?;
+                            Line(format!(
+                                "pub const {}: {} = {}::{};",
+                                styled_name, &type_string, &type_string, variant
+                            ))
+                        } else {
+                            return Err(Error::failed(format!("enumerant out of range: {v}")));
                         }
-                    } else {
+                    }
+                    _ => {
                         return Err(Error::failed(format!(
                             "bad enum type ID: {}",
                             e.get_type_id()
                         )));
                     }
                 }
+            } else {
+                return Err(Error::failed(format!(
+                    "bad enum type ID: {}",
+                    e.get_type_id()
+                )));
+            }
+        }
 
-                (type_::Text(()), value::Text(t)) => Line(format!(
-                    "pub const {styled_name}: &str = {:?};",
-                    t?.to_str()?
-                )),
-                (type_::Data(()), value::Data(d)) => {
-                    Line(format!("pub const {styled_name}: &[u8] = &{:?};", d?))
-                }
-
-                (type_::List(_), value::List(v)) => {
-                    generate_pointer_constant(ctx, &styled_name, typ, v)?
-                }
-                (type_::Struct(_), value::Struct(v)) => {
-                    generate_pointer_constant(ctx, &styled_name, typ, v)?
-                }
+        (type_::Text(()), value::Text(t)) => Line(format!(
+            "pub const {styled_name}: &str = {:?};",
+            t?.to_str()?
+        )),
+        (type_::Data(()), value::Data(d)) => {
+            Line(format!("pub const {styled_name}: &[u8] = &{:?};", d?))
+        }
 
-                (type_::Interface(_t), value::Interface(())) => {
-                    return Err(Error::unimplemented("interface constants".to_string()));
-                }
-                (type_::AnyPointer(_), value::AnyPointer(_pr)) => {
-                    return Err(Error::unimplemented("anypointer constants".to_string()));
-                }
+        (type_::List(_), value::List(v)) => {
+            generate_pointer_constant(ctx, node_id, &styled_name, typ, v)?
+        }
+        (type_::Struct(_), value::Struct(v)) => {
+            generate_pointer_constant(ctx, node_id, &styled_name, typ, v)?
+        }
 
-                _ => {
-                    return Err(Error::failed("type does not match value".to_string()));
-                }
-            };
+        (type_::Interface(_t), value::Interface(())) => {
+            return Err(Error::unimplemented("interface constants".to_string()));
+        }
+        (type_::AnyPointer(_), value::AnyPointer(_pr)) => {
+            return Err(Error::unimplemented("anypointer constants".to_string()));
+        }
 
-            output.push(formatted_text);
+        _ => {
+            return Err(Error::failed("type does not match value".to_string()));
         }
+    };
 
-        node::Annotation(annotation_reader) => {
-            let is_generic = node_reader.get_is_generic();
-            let params = node_reader.parameters_texts(ctx);
-            let last_name = ctx.get_last_name(node_id)?;
-            let mut interior = vec![];
-            interior.push(Line(format!("pub const ID: u64 = 0x{:x};", node_id)));
+    output.push(formatted_text);
 
-            let ty = annotation_reader.get_type()?;
-            if !is_generic {
-                interior.push(Line(fmt!(ctx,
-                    "pub fn get_type() -> {capnp}::introspect::Type {{ <{} as {capnp}::introspect::Introspect>::introspect() }}", ty.type_string(ctx, Leaf::Owned)?)));
-            } else {
-                interior.push(Line(fmt!(ctx,"pub fn get_type<{0}>() -> {capnp}::introspect::Type {1} {{ <{2} as {capnp}::introspect::Introspect>::introspect() }}", params.params, params.where_clause, ty.type_string(ctx, Leaf::Owned)?)));
-            }
-            output.push(Branch(vec![
-                Line(format!("pub mod {} {{", last_name)),
-                indent(interior),
-                Line("}".into()),
-            ]));
-        }
+    Ok(Branch(output))
+}
+
+/// Generates the module of annotation `node`, which holds its ID and type.
+fn generate_annotation_node<'a>(
+    node: &NodeContext<'_, 'a>,
+    annotation_reader: schema_capnp::node::annotation::Reader<'a>,
+) -> ::capnp::Result<FormattedText> {
+    let NodeContext {
+        ctx,
+        id: node_id,
+        reader: node_reader,
+        ..
+    } = *node;
+    let mut output = Vec::new();
+    let is_generic = node_reader.get_is_generic();
+    let params = node_reader.parameters_texts(ctx);
+    let last_name = ctx.get_last_name(node_id)?;
+    let mut interior = vec![];
+    interior.push(Line(format!("pub const ID: u64 = 0x{:x};", node_id)));
+
+    let ty = annotation_reader.get_type()?;
+    interior.push(ctx.inline_schema_fn());
+    if !is_generic {
+        interior.push(Line(fmt!(ctx,
+            "pub fn get_type() -> {capnp}::introspect::Type {{ <{} as {capnp}::introspect::Introspect>::introspect() }}", ty.type_string(ctx, Leaf::Owned)?)));
+    } else {
+        interior.push(Line(fmt!(ctx,"pub fn get_type<{0}>() -> {capnp}::introspect::Type {1} {{ <{2} as {capnp}::introspect::Introspect>::introspect() }}", params.params, params.where_clause, ty.type_string(ctx, Leaf::Owned)?)));
     }
+    output.push(Branch(vec![
+        Line(format!("pub mod {} {{", last_name)),
+        indent(interior),
+        Line("}".into()),
+    ]));
 
     Ok(Branch(output))
 }
@@ -264,32 +264,26 @@ impl<'a> RustTypeInfo for type_::Reader<'a> {
                 }
             }
             type_::Enum(en) => Ok(ctx.get_qualified_module(en.get_type_id())),
-            type_::AnyPointer(pointer) => match pointer.which()? {
-                type_::any_pointer::Parameter(def) => {
-                    let the_struct = &ctx.node_map[&def.get_scope_id()];
-                    let parameters = the_struct.get_parameters()?;
-                    let parameter = parameters.get(u32::from(def.get_parameter_index()));
-                    let parameter_name = parameter.get_name()?.to_str()?;
-                    match module {
-                        Leaf::Owned => Ok(parameter_name.to_string()),
-                        Leaf::Reader(lifetime) => Ok(fmt!(
-                            ctx,
-                            "<{parameter_name} as {capnp}::traits::Owned>::Reader<{lifetime}>"
-                        )),
-                        Leaf::Builder(lifetime) => Ok(fmt!(
-                            ctx,
-                            "<{parameter_name} as {capnp}::traits::Owned>::Builder<{lifetime}>"
-                        )),
-                        Leaf::Pipeline => Ok(fmt!(
-                            ctx,
-                            "<{parameter_name} as {capnp}::traits::Pipelined>::Pipeline"
-                        )),
-                        _ => Err(Error::unimplemented(
-                            "unimplemented any_pointer leaf".to_string(),
-                        )),
-                    }
-                }
-                _ => match module {
+            type_::AnyPointer(pointer) => match any_pointer_parameter_name(ctx, pointer)? {
+                Some(parameter_name) => match module {
+                    Leaf::Owned => Ok(parameter_name),
+                    Leaf::Reader(lifetime) => Ok(fmt!(
+                        ctx,
+                        "<{parameter_name} as {capnp}::traits::Owned>::Reader<{lifetime}>"
+                    )),
+                    Leaf::Builder(lifetime) => Ok(fmt!(
+                        ctx,
+                        "<{parameter_name} as {capnp}::traits::Owned>::Builder<{lifetime}>"
+                    )),
+                    Leaf::Pipeline => Ok(fmt!(
+                        ctx,
+                        "<{parameter_name} as {capnp}::traits::Pipelined>::Pipeline"
+                    )),
+                    _ => Err(Error::unimplemented(
+                        "unimplemented any_pointer leaf".to_string(),
+                    )),
+                },
+                None => match module {
                     Leaf::Reader(lifetime) => {
                         Ok(fmt!(ctx, "{capnp}::any_pointer::Reader<{lifetime}>"))
                     }
@@ -356,6 +350,29 @@ impl<'a> RustTypeInfo for type_::Reader<'a> {
     }
 }
 
+/// Returns the Rust name of the type parameter that `pointer` refers to, or `None` if it
+/// is an unconstrained `AnyPointer` or an implicit method parameter outside of
+/// `ctx.implicit_method_parameters`.
+fn any_pointer_parameter_name(
+    ctx: &GeneratorContext,
+    pointer: type_::any_pointer::Reader,
+) -> Result<Option<String>, Error> {
+    match pointer.which()? {
+        type_::any_pointer::Parameter(def) => {
+            let the_struct = ctx.get_node(def.get_scope_id(), def.get_scope_id())?;
+            let parameters = the_struct.get_parameters()?;
+            let parameter = parameters.get(u32::from(def.get_parameter_index()));
+            Ok(Some(parameter.get_name()?.to_string()?))
+        }
+        type_::any_pointer::ImplicitMethodParameter(def) => Ok(ctx
+            .implicit_method_parameters
+            .borrow()
+            .get(usize::from(def.get_parameter_index()))
+            .cloned()),
+        type_::any_pointer::Unconstrained(_) => Ok(None),
+    }
+}
+
 pub fn do_branding(
     ctx: &GeneratorContext,
     node_id: u64,
@@ -370,15 +387,24 @@ pub fn do_branding(
     }
     let brand_scopes = brand_scopes; // freeze
     let mut current_node_id = node_id;
+    let mut child_id = node_id;
     let mut accumulator: Vec<Vec<String>> = Vec::new();
     loop {
-        let current_node = ctx.node_map[&current_node_id];
+        let current_node = ctx.get_node(current_node_id, child_id)?;
         let params = current_node.get_parameters()?;
         let mut arguments: Vec<String> = Vec::new();
         match brand_scopes.get(&current_node_id) {
             None => {
-                for _ in params {
-                    arguments.push(fmt!(ctx, "{capnp}::any_pointer::Owned"));
+                // Method brands leave the params and results structs of a method unbound,
+                // but those structs are generic over the method's implicit parameters.
+                let implicit = ctx.implicit_method_parameters.borrow();
+                for index in 0..params.len() {
+                    match implicit.get(index as usize) {
+                        Some(name) if current_node.get_scope_id() == 0 => {
+                            arguments.push(name.clone())
+                        }
+                        _ => arguments.push(fmt!(ctx, "{capnp}::any_pointer::Owned")),
+                    }
                 }
             }
             Some(scope) => match scope.which()? {
@@ -404,6 +430,7 @@ pub fn do_branding(
             },
         }
         accumulator.push(arguments);
+        child_id = current_node_id;
         current_node_id = match ctx.node_parents.get(&current_node_id).copied() {
             Some(0) | None => break,
             Some(id) => id,
@@ -442,7 +469,7 @@ pub fn get_type_parameters(ctx: &GeneratorContext, node_id: u64) -> Vec<String>
     let mut current_node_id = node_id;
     let mut accumulator: Vec<Vec<String>> = Vec::new();
     loop {
-        let current_node = ctx.node_map[&current_node_id];
+        let current_node = ctx.get_node(current_node_id, current_node_id).unwrap();
         let mut params = Vec::new();
         for param in current_node.get_parameters().unwrap() {
             params.push(param.get_name().unwrap().to_string().unwrap());
@@ -0,0 +1,637 @@
+//! Generates the convenience builders: the plain Rust struct that mirrors each generated
+//! struct (its "params struct") together with `build_capnp_struct()` and the conversions back
+//! from a reader, and the `build_*_request()` methods of interface clients, which take the
+//! fields of a method's params struct as arguments.
+//!
+//! The fields and the statements that build them are written by `generate_setter()` and
+//! `generate_union()` in [`codegen`](crate::codegen), alongside the setters whose code they
+//! share. This module assembles them.
+//!
+//! None of this is generated when
+//! [`CodeGenerationCommand::generate_convenience_builders`](crate::codegen::CodeGenerationCommand::generate_convenience_builders)
+//! is turned off.
+
+use capnp::schema_capnp::{self, type_};
+use capnp::Error;
+
+use crate::codegen::FormattedText::{Branch, Line};
+use crate::codegen::{
+    camel_to_snake_case, capitalize_first_letter, fmt, get_field_name, get_params,
+    has_required_fields, indent, is_option_field, line, snake_to_camel_case, FormattedText,
+    GeneratorContext,
+};
+use crate::codegen_types::{RustTypeInfo, TypeParameterTexts};
+
+/// The params struct of a struct node, as far as the generators of its fields and of its union
+/// have written it.
+pub(crate) struct ParamsStructText {
+    /// The declaration of the struct, up to its last field. Empty if the struct is only a
+    /// union, whose params type is then the enum instead.
+    pub(crate) declaration: String,
+
+    /// The `impl` holding `build_capnp_struct()`, up to its last statement.
+    pub(crate) build_impl: String,
+
+    /// The declaration of the enum of the union fields, up to its last variant, if the struct
+    /// has a union.
+    pub(crate) union_enum: String,
+
+    /// The name of that enum.
+    pub(crate) union_name: String,
+}
+
+impl ParamsStructText {
+    /// Starts the params struct of the struct whose module is `node_name`.
+    pub(crate) fn new(
+        ctx: &GeneratorContext,
+        node_name: &str,
+        params: &TypeParameterTexts,
+    ) -> Self {
+        let mut declaration = String::new();
+        let mut build_impl = String::new();
+        declaration.push_str(format!("pub struct {} {{", snake_to_camel_case(node_name)).as_str());
+        build_impl.push_str(format!("impl {} {{", snake_to_camel_case(node_name)).as_str());
+        build_impl.push_str(
+            fmt!(
+                ctx,
+                "\npub fn build_capnp_struct<'a,{}>(self, mut builder: Builder<'a,{}>) -> {capnp}::Result<()> {} {{",
+                params.params,
+                params.params,
+                params.where_clause
+            )
+            .as_str(),
+        );
+        Self {
+            declaration,
+            build_impl,
+            union_enum: String::new(),
+            union_name: String::new(),
+        }
+    }
+}
+
+/// Finishes the params struct of struct `node_id`, given the fields and statements that
+/// `generate_setter()` wrote for its non-union fields, and adds its conversion from a reader
+/// and, unless the struct is generic, its conversions to and from the dynamic API.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn generate_params_struct(
+    ctx: &GeneratorContext,
+    node_id: u64,
+    node_name: &str,
+    params: &TypeParameterTexts,
+    is_generic: bool,
+    mut text: ParamsStructText,
+    rust_struct_inner: &str,
+    rust_struct_impl_inner: &str,
+    fields: &[schema_capnp::field::Reader],
+    union_fields: &[schema_capnp::field::Reader],
+    union_only_struct: bool,
+) -> capnp::Result<FormattedText> {
+    if !text.union_enum.is_empty() {
+        text.union_enum.push_str("\n}");
+    }
+    text.declaration.push_str(rust_struct_inner);
+    text.build_impl.push_str(rust_struct_impl_inner);
+    text.build_impl
+        .push_str("\n  ::core::result::Result::Ok(())\n}}");
+    if !text.declaration.is_empty() {
+        text.declaration.push_str("  \n}");
+    }
+    let params_type_name = snake_to_camel_case(node_name);
+    Ok(Branch(vec![
+        Line(text.declaration),
+        Line(text.build_impl),
+        Line(text.union_enum),
+        generate_params_struct_from_reader(
+            ctx,
+            node_id,
+            params,
+            fields,
+            union_fields,
+            union_only_struct,
+            &params_type_name,
+            &text.union_name,
+        )?,
+        if is_generic {
+            Branch(Vec::new())
+        } else {
+            generate_params_struct_dynamic(ctx, &params_type_name)
+        },
+    ]))
+}
+
+/// Generates the `build_{name}_request()` methods of an interface client for method `name`,
+/// whose params struct is `param_id`. `builder_params_string` holds the fields of the params
+/// struct written as arguments, and `builder_params_inner_string` the statements that set them
+/// on `builder`, both as `generate_setter()` wrote them.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn generate_request_builders(
+    ctx: &GeneratorContext,
+    param_id: u64,
+    name: &str,
+    ordinal: usize,
+    method_generics: &str,
+    request_type: &str,
+    new_call: &str,
+    param_builder_type: &str,
+    where_clause: &str,
+    builder_params_string: &str,
+    builder_params_inner_string: &str,
+) -> capnp::Result<Vec<FormattedText>> {
+    let mut result = Vec::new();
+    let params_type_string = format!(", {builder_params_string}");
+    // The arguments are written by a closure so that lists which are too long
+    // can fail with `?`, like they do in `build_capnp_struct()`.
+    let param_build_call = fmt!(
+        ctx,
+        "let fill_params = |mut builder: {param_builder_type}| -> {capnp}::Result<()> {{\n{builder_params_inner_string}\n::core::result::Result::Ok(())\n}};"
+    );
+    let panic_on_error = format!(
+        "if let ::core::result::Result::Err(e) = fill_params(req.get()) {{ ::core::panic!(\"cannot build the params of `{name}`: {{}}\", e) }}"
+    );
+
+    result.push(Line(format!(
+        "pub fn build_{}_request{method_generics}(&self{}) -> {request_type} {} {{",
+        camel_to_snake_case(name),
+        params_type_string,
+        where_clause
+    )));
+
+    result.push(indent(Line(format!(
+        "let mut req: {request_type} = self.client.{new_call}(_private::TYPE_ID, {ordinal}, ::core::option::Option::None);
+                        {param_build_call}
+                        {panic_on_error}
+                        req"
+    ))));
+    result.push(line("}"));
+
+    let mut args = builder_params_string
+        .trim()
+        .trim_end_matches(',')
+        .to_string();
+    if !args.is_empty() {
+        args.push_str(", ");
+    }
+    result.push(Line(format!(
+        "pub fn build_{}_request_with{method_generics}(&self, {}customize: impl ::core::ops::FnOnce({})) -> {request_type} {} {{",
+        camel_to_snake_case(name),
+        args,
+        param_builder_type,
+        where_clause
+    )));
+    result.push(indent(Line(format!(
+        "let mut req: {request_type} = self.client.{new_call}(_private::TYPE_ID, {ordinal}, ::core::option::Option::None);
+                        {param_build_call}
+                        {panic_on_error}
+                        customize(req.get());
+                        req"
+    ))));
+    result.push(line("}"));
+
+    if has_required_fields(ctx, param_id)? {
+        result.push(Line(fmt!(
+            ctx,
+            "pub fn build_{}_request_checked{method_generics}(&self{}) -> {capnp}::Result<{request_type}> {} {{",
+            camel_to_snake_case(name),
+            params_type_string,
+            where_clause
+        )));
+        result.push(indent(Line(format!(
+            "let mut req: {request_type} = self.client.{new_call}(_private::TYPE_ID, {ordinal}, ::core::option::Option::None);
+                            {param_build_call}
+                            fill_params(req.get())?;
+                            req.get().validate()?;
+                            ::core::result::Result::Ok(req)"
+        ))));
+        result.push(line("}"));
+    }
+    Ok(result)
+}
+
+pub(crate) fn get_params_struct_path_string(
+    ctx: &GeneratorContext,
+    struct_reader: capnp::schema_capnp::type_::struct_::Reader,
+) -> capnp::Result<String> {
+    Ok(format!(
+        "{}::{}",
+        ctx.get_qualified_module(struct_reader.get_type_id()),
+        snake_to_camel_case(ctx.get_last_name(struct_reader.get_type_id())?)
+    ))
+}
+pub(crate) fn vec_of_list_element_types(
+    ctx: &GeneratorContext,
+    list: type_::list::Reader,
+) -> capnp::Result<String> {
+    match list.get_element_type()?.which()? {
+        type_::Which::Void(()) => Ok("Vec<()>".to_string()),
+        type_::Which::Bool(()) => Ok("Vec<bool>".to_string()),
+        type_::Which::Int8(()) => Ok("Vec<i8>".to_string()),
+        type_::Which::Int16(()) => Ok("Vec<i16>".to_string()),
+        type_::Which::Int32(()) => Ok("Vec<i32>".to_string()),
+        type_::Which::Int64(()) => Ok("Vec<i64>".to_string()),
+        type_::Which::Uint8(()) => Ok("Vec<u8>".to_string()),
+        type_::Which::Uint16(()) => Ok("Vec<u16>".to_string()),
+        type_::Which::Uint32(()) => Ok("Vec<u32>".to_string()),
+        type_::Which::Uint64(()) => Ok("Vec<u64>".to_string()),
+        type_::Which::Float32(()) => Ok("Vec<f32>".to_string()),
+        type_::Which::Float64(()) => Ok("Vec<f64>".to_string()),
+        type_::Which::Text(_) => Ok("Vec<String>".to_string()),
+        type_::Which::Data(_) => Ok("Vec<Vec<u8>>".to_string()),
+        type_::Which::List(l) => Ok(format!("Vec<{}>", vec_of_list_element_types(ctx, l)?)),
+        type_::Which::Enum(enum_type) => {
+            if get_params(ctx, enum_type.get_type_id())?.is_empty() {
+                let enum_mod = ctx.get_qualified_module(enum_type.get_type_id());
+                return Ok(format!("Vec<{enum_mod}>"));
+            }
+            Err(Error::failed("generics not supported here yet".to_string()))
+        }
+        type_::Which::Struct(struct_reader) => {
+            if get_params(ctx, struct_reader.get_type_id())?.is_empty() {
+                return Ok(format!(
+                    "Vec<{}>",
+                    get_params_struct_path_string(ctx, struct_reader)?
+                ));
+            }
+            Err(Error::failed("generics not supported here yet".to_string()))
+        }
+        type_::Which::Interface(i_t) => {
+            if get_params(ctx, i_t.get_type_id())?.is_empty() {
+                let the_mod = ctx.get_qualified_module(i_t.get_type_id());
+                return Ok(format!("Vec<{the_mod}::Client>"));
+            }
+            Err(Error::failed("generics not supported here yet".to_string()))
+        }
+        type_::Which::AnyPointer(_) => Err(Error::failed(
+            "Lists of anypointers not supported here yet".to_string(),
+        )),
+    }
+}
+pub(crate) fn build_impl_for_list_type(
+    ctx: &GeneratorContext,
+    name: &str,
+    list: type_::list::Reader,
+    union: bool,
+    is_params_struct: bool,
+) -> capnp::Result<String> {
+    let vec_source: String;
+    if union {
+        vec_source = "t".to_string();
+    } else if is_params_struct {
+        vec_source = format!("_{name}");
+    } else {
+        vec_source = format!("self._{name}");
+    }
+    let checked_len = fmt!(ctx, "{capnp}::private::checked_len");
+    Ok(match list.reborrow().get_element_type()?.which()? {
+        type_::Which::Text(_) => {
+            format!(
+                "
+            \nif {vec_source}.len() > 0 {{
+                let mut list_builder = builder.reborrow().init_{name}({checked_len}({vec_source}.len())?);
+                for (i, item) in {vec_source}.into_iter().enumerate() {{
+                    list_builder.reborrow().set(i as u32, item.as_str().into());
+                }}
+            }}"
+            )
+        }
+        type_::Which::Data(_) => {
+            format!(
+                "
+            \nif {vec_source}.len() > 0 {{
+                let mut list_builder = builder.reborrow().init_{name}({checked_len}({vec_source}.len())?);
+                for (i, item) in {vec_source}.into_iter().enumerate() {{
+                    list_builder.reborrow().set(i as u32, item.as_slice());
+                }}
+        }}"
+            )
+        }
+        type_::Which::List(_) => {
+            format!(
+                "
+            \nif {vec_source}.len() > 0 {{
+                let mut list_builder = builder.reborrow().init_{name}({checked_len}({vec_source}.len())?);
+                for (i, item) in {vec_source}.into_iter().enumerate() {{
+                    {}
+                }}
+            }}",
+                build_list_of_list_impl(ctx, list.reborrow())?
+            )
+        }
+        type_::Which::Struct(_) => {
+            format!(
+                "
+            \nif {vec_source}.len() > 0 {{
+                let mut list_builder = builder.reborrow().init_{name}({checked_len}({vec_source}.len())?);
+                for (i, item) in {vec_source}.into_iter().enumerate() {{
+                    item.build_capnp_struct(list_builder.reborrow().get(i as u32))?;
+                }}
+            }}"
+            )
+        }
+        type_::Which::Interface(_) => {
+            format!(
+                "
+            \nif {vec_source}.len() > 0 {{
+                let mut list_builder = builder.reborrow().init_{name}({checked_len}({vec_source}.len())?);
+                for (i, item) in {vec_source}.into_iter().enumerate() {{
+                    list_builder.reborrow().set(i as u32, item.client.hook);
+                }}
+            }}"
+            )
+        }
+        type_::Which::AnyPointer(_) => "".to_string(),
+        _ => {
+            format!(
+                "
+            \nif {vec_source}.len() > 0 {{
+                let mut list_builder = builder.reborrow().init_{name}({checked_len}({vec_source}.len())?);
+                for (i, item) in {vec_source}.into_iter().enumerate() {{
+                    list_builder.reborrow().set(i as u32, item);
+                }}
+            }}"
+            )
+        }
+    })
+}
+fn build_list_of_list_impl(
+    ctx: &GeneratorContext,
+    list: type_::list::Reader,
+) -> capnp::Result<String> {
+    let checked_len = fmt!(ctx, "{capnp}::private::checked_len");
+    Ok(match list.reborrow().get_element_type()?.which()? {
+        type_::Which::Text(_) => {
+            "\nlist_builder.reborrow().set(i as u32, item.as_str().into());".to_string()
+        }
+        type_::Which::Data(_) => {
+            "\nlist_builder.reborrow().set(i as u32, item.as_slice());".to_string()
+        }
+        type_::Which::List(reader) => {
+            format!("\n
+                if item.len() > 0 {{
+                    let mut list_builder = list_builder.reborrow().init(i as u32, {checked_len}(item.len())?);
+                    for (i, item) in item.into_iter().enumerate() {{ {} }}
+                }}",
+                build_list_of_list_impl(ctx, reader)?)
+        }
+        type_::Which::Struct(_) => {
+            "\nitem.build_capnp_struct(list_builder.reborrow().get(i as u32))?;".to_string()
+        }
+        type_::Which::Interface(_) => {
+            "\nlist_builder.reborrow().set(i as u32, item.client.hook);".to_string()
+        }
+        type_::Which::AnyPointer(_) => "".to_string(),
+        _ => "\nlist_builder.reborrow().set(i as u32, item);".to_string(),
+    })
+}
+/// Returns an expression that converts `value`, a reader for a value of type `typ`, into the
+/// type used for it in the convenience params struct.
+fn params_value_from_reader(
+    ctx: &GeneratorContext,
+    typ: type_::Reader,
+    value: &str,
+    boxed: bool,
+) -> capnp::Result<String> {
+    Ok(match typ.which()? {
+        type_::Text(()) => format!("{value}.to_string()?"),
+        type_::Data(()) => format!("{value}.to_vec()"),
+        type_::Struct(st) => {
+            let conversion = format!(
+                "<{} as ::core::convert::TryFrom<_>>::try_from({value})?",
+                get_params_struct_path_string(ctx, st)?
+            );
+            if boxed {
+                format!("Box::new({conversion})")
+            } else {
+                conversion
+            }
+        }
+        type_::List(list) => {
+            let element_type = list.get_element_type()?;
+            // Struct lists and primitive lists yield their elements directly; every other kind
+            // of list yields a `Result` per element.
+            let item = match element_type.which()? {
+                type_::Text(())
+                | type_::Data(())
+                | type_::List(_)
+                | type_::Enum(_)
+                | type_::Interface(_) => "item?",
+                _ => "item",
+            };
+            fmt!(
+                ctx,
+                "{value}.iter().map(|item| {capnp}::Result::Ok({})).collect::<{capnp}::Result<Vec<_>>>()?",
+                params_value_from_reader(ctx, element_type, item, false)?
+            )
+        }
+        type_::AnyPointer(_) => fmt!(
+            ctx,
+            "{value}.get_as_capability::<{capnp}::capability::Client>()?.hook"
+        ),
+        _ => value.to_string(),
+    })
+}
+
+/// Returns an expression that converts `value`, the result of reading `field` (either from its
+/// getter or from a `Which` variant), into the type used for it in the convenience params
+/// struct, or `None` if the params struct does not carry the field.
+fn params_field_from_reader(
+    ctx: &GeneratorContext,
+    field: schema_capnp::field::Reader,
+    value: &str,
+    in_union: bool,
+    node_id: u64,
+) -> capnp::Result<Option<String>> {
+    let reg_field = match field.which()? {
+        schema_capnp::field::Group(group) => {
+            let id = group.get_type_id();
+            if !get_params(ctx, id)?.is_empty() {
+                return Ok(None);
+            }
+            return Ok(Some(format!(
+                "<{}::{} as ::core::convert::TryFrom<_>>::try_from({value})?",
+                ctx.get_qualified_module(id),
+                snake_to_camel_case(ctx.get_last_name(id)?)
+            )));
+        }
+        schema_capnp::field::Slot(reg_field) => reg_field,
+    };
+    let typ = reg_field.get_type()?;
+    let is_option = is_option_field(field)?;
+    let result = match typ.which()? {
+        type_::Void(()) | type_::Bool(()) => value.to_string(),
+        _ if typ.is_prim()? => value.to_string(),
+        type_::Enum(_) => format!("{value}?"),
+        type_::Text(()) | type_::Data(()) | type_::List(_) => {
+            if let type_::List(list) = typ.which()? {
+                if vec_of_list_element_types(ctx, list).is_err() {
+                    return Ok(None);
+                }
+            }
+            if is_option {
+                format!(
+                    "match {value}? {{ ::core::option::Option::Some(v) => {}, ::core::option::Option::None => ::core::default::Default::default() }}",
+                    params_value_from_reader(ctx, typ, "v", false)?
+                )
+            } else {
+                params_value_from_reader(ctx, typ, &format!("{value}?"), false)?
+            }
+        }
+        type_::Struct(st) => {
+            if !in_union && !get_params(ctx, st.get_type_id())?.is_empty() {
+                return Ok(None);
+            }
+            let boxed = ctx.needs_box(node_id, st.get_type_id());
+            if in_union {
+                params_value_from_reader(ctx, typ, &format!("{value}?"), boxed)?
+            } else if is_option {
+                format!(
+                    "match {value}? {{ ::core::option::Option::Some(v) => ::core::option::Option::Some({}), ::core::option::Option::None => ::core::option::Option::None }}",
+                    params_value_from_reader(ctx, typ, "v", boxed)?
+                )
+            } else {
+                format!(
+                    "if reader.has_{}() {{ ::core::option::Option::Some({}) }} else {{ ::core::option::Option::None }}",
+                    camel_to_snake_case(get_field_name(field)?),
+                    params_value_from_reader(ctx, typ, &format!("{value}?"), boxed)?
+                )
+            }
+        }
+        type_::Interface(i_t) => {
+            if !get_params(ctx, i_t.get_type_id())?.is_empty() {
+                return Ok(None);
+            }
+            format!("{value}?")
+        }
+        type_::AnyPointer(_) => {
+            if typ.is_parameter()? {
+                return Ok(None);
+            }
+            if is_option {
+                fmt!(
+                    ctx,
+                    "match {value} {{ ::core::option::Option::Some(v) => {}, ::core::option::Option::None => return ::core::result::Result::Err({capnp}::Error::failed(\"field `{}` is null\".into())) }}",
+                    params_value_from_reader(ctx, typ, "v", false)?,
+                    get_field_name(field)?
+                )
+            } else {
+                params_value_from_reader(ctx, typ, value, false)?
+            }
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(result))
+}
+
+/// Generates the `TryFrom<Reader>` impl that reads a struct back into its convenience params
+/// struct (or, for a struct that is only a union, its params enum).
+fn generate_params_struct_from_reader(
+    ctx: &GeneratorContext,
+    node_id: u64,
+    params: &TypeParameterTexts,
+    fields: &[schema_capnp::field::Reader],
+    union_fields: &[schema_capnp::field::Reader],
+    union_only_struct: bool,
+    params_type_name: &str,
+    params_union_name: &str,
+) -> capnp::Result<FormattedText> {
+    let mut union_arms = Vec::new();
+    for field in union_fields {
+        let variant = capitalize_first_letter(get_field_name(*field)?);
+        union_arms.push(
+            match params_field_from_reader(ctx, *field, "value", true, node_id)? {
+                Some(value) => Line(format!(
+                    "Which::{variant}(value) => {params_union_name}::_{variant}({value}),"
+                )),
+                None => Line(format!(
+                    "Which::{variant}(_) => {params_union_name}::UNINITIALIZED,"
+                )),
+            },
+        );
+    }
+    let body = if union_only_struct {
+        Branch(vec![
+            line("::core::result::Result::Ok(match reader.which()? {"),
+            indent(union_arms),
+            line("})"),
+        ])
+    } else {
+        let mut members = Vec::new();
+        for field in fields {
+            if field.get_discriminant_value() != schema_capnp::field::NO_DISCRIMINANT {
+                continue;
+            }
+            let styled_name = camel_to_snake_case(get_field_name(*field)?);
+            let getter = format!("reader.{}()", ctx.getter_name(&styled_name));
+            if let Some(value) = params_field_from_reader(ctx, *field, &getter, false, node_id)? {
+                members.push(Line(format!("_{styled_name}: {value},")));
+            }
+        }
+        if !union_fields.is_empty() {
+            members.push(Branch(vec![
+                line("uni: match reader.which()? {"),
+                indent(union_arms),
+                line("},"),
+            ]));
+        }
+        Branch(vec![
+            line("::core::result::Result::Ok(Self {"),
+            indent(members),
+            line("})"),
+        ])
+    };
+
+    Ok(Branch(vec![
+        Line(format!(
+            "impl <'a,{0}> ::core::convert::TryFrom<Reader<'a,{0}>> for {params_type_name} {1} {{",
+            params.params, params.where_clause
+        )),
+        indent(vec![
+            Line(fmt!(ctx, "type Error = {capnp}::Error;")),
+            Line(fmt!(
+                ctx,
+                "fn try_from(reader: Reader<'a,{}>) -> {capnp}::Result<Self> {{",
+                params.params
+            )),
+            indent(body),
+            line("}"),
+        ]),
+        line("}"),
+    ]))
+}
+
+/// Generates the conversions between the convenience params struct of a non-generic struct
+/// and the dynamic API, which go through the typed `Reader` and `Builder`, so that the params
+/// struct can be used with tools that only see `dynamic_struct` values.
+fn generate_params_struct_dynamic(ctx: &GeneratorContext, params_type_name: &str) -> FormattedText {
+    Branch(vec![
+        Line(fmt!(
+            ctx,
+            "impl <'a> ::core::convert::TryFrom<{capnp}::dynamic_struct::Reader<'a>> for {params_type_name} {{"
+        )),
+        indent(vec![
+            Line(fmt!(ctx, "type Error = {capnp}::Error;")),
+            Line(fmt!(
+                ctx,
+                "fn try_from(reader: {capnp}::dynamic_struct::Reader<'a>) -> {capnp}::Result<Self> {{"
+            )),
+            indent(line(
+                "<Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)",
+            )),
+            line("}"),
+        ]),
+        line("}"),
+        Line(format!("impl {params_type_name} {{")),
+        indent(vec![
+            line("/// Writes the value into `builder`, which must be a struct of this type."),
+            Line(fmt!(
+                ctx,
+                "pub fn to_dynamic(self, builder: {capnp}::dynamic_struct::Builder<'_>) -> {capnp}::Result<()> {{"
+            )),
+            indent(vec![
+                line("self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)"),
+            ]),
+            line("}"),
+        ]),
+        line("}"),
+    ])
+}
@@ -66,16 +66,27 @@
 //! ```ignore
 //!   capnp compile -orust:$OUT_DIR --src-prefix=schema schema/foo.capnp schema/bar.capnp
 //! ```
+//!
+//! The generated code contains no `unsafe`, so it can be included in crates that use
+//! `#![forbid(unsafe_code)]`.
 
+mod aliases;
+mod bundled;
+mod cache;
 pub mod codegen;
 pub mod codegen_types;
+mod convenience_builders;
 mod pointer_constants;
+pub mod provenance;
+pub mod schema_diff;
 
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     path::{Path, PathBuf},
 };
 
+pub use bundled::{compiler_version, standard_import, CompilerVersion};
+
 // Copied from capnp/src/lib.rs, where this conversion lives behind the "std" feature flag,
 // which we don't want to depend on here.
 pub(crate) fn convert_io_err(err: std::io::Error) -> capnp::Error {
@@ -92,34 +103,135 @@ pub(crate) fn convert_io_err(err: std::io::Error) -> capnp::Error {
     capnp::Error::from_kind_context(kind, format!("{err}"))
 }
 
+// Returns the serialized `CodeGeneratorRequest` that the command produced. Errors include what
+// the command wrote to stderr; otherwise that is passed on.
 fn run_command(
     mut command: ::std::process::Command,
     mut code_generation_command: codegen::CodeGenerationCommand,
-) -> ::capnp::Result<()> {
-    let mut p = command.spawn().map_err(convert_io_err)?;
-    code_generation_command.run(p.stdout.take().unwrap())?;
-    let exit_status = p.wait().map_err(convert_io_err)?;
-    if !exit_status.success() {
-        Err(::capnp::Error::failed(format!(
-            "Non-success exit status: {exit_status}"
-        )))
-    } else {
-        Ok(())
+) -> ::capnp::Result<Vec<u8>> {
+    use std::io::Write;
+
+    let output = command.output().map_err(convert_io_err)?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !output.status.success() {
+        return Err(::capnp::Error::failed(format!(
+            "Non-success exit status: {}: {}",
+            output.status,
+            stderr.trim_end()
+        )));
+    }
+    std::io::stderr()
+        .write_all(&output.stderr)
+        .map_err(convert_io_err)?;
+    code_generation_command.run(output.stdout.as_slice())?;
+    Ok(output.stdout)
+}
+
+/// Appends the schema files under `dir` to `files`, depth first and in file name order.
+/// Symbolic links are followed, but each directory is visited once, and hidden directories
+/// are skipped.
+fn collect_schemas(
+    dir: &Path,
+    visited: &mut HashSet<PathBuf>,
+    files: &mut Vec<PathBuf>,
+) -> std::io::Result<()> {
+    // A directory reached again through a symbolic link, such as one back to an ancestor.
+    if !visited.insert(dir.canonicalize()?) {
+        return Ok(());
+    }
+    let mut entries = std::fs::read_dir(dir)?.collect::<std::io::Result<Vec<_>>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        let path = entry.path();
+        let metadata = std::fs::metadata(&path)?;
+        if metadata.is_dir() {
+            if !entry.file_name().to_string_lossy().starts_with('.') {
+                collect_schemas(&path, visited, files)?;
+            }
+        } else if metadata.is_file() && path.extension().is_some_and(|ext| ext == "capnp") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// A fresh directory under `std::env::temp_dir()`, removed along with its contents on drop.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new() -> std::io::Result<Self> {
+        static NEXT_ID: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+        loop {
+            let id = NEXT_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            let path = std::env::temp_dir().join(format!("capnpc-{}-{id}", std::process::id()));
+            match std::fs::create_dir(&path) {
+                Ok(()) => return Ok(Self(path)),
+                // Left over from an earlier process with the same id.
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e),
+            }
+        }
     }
+
+    fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Compiles schema source text held in memory and returns the serialized
+/// `CodeGeneratorRequest`, for use with [`codegen::GeneratorContext`] or the dynamic API.
+///
+/// Each source is a `(virtual path, content)` pair. Virtual paths must be relative, and
+/// sources can import each other by those paths, either relatively (`import "foo.capnp"`)
+/// or absolutely (`import "/foo.capnp"`).
+///
+/// See [`CompilerCommand::compile_from_memory`] for control over the `capnp` executable.
+pub fn compile_from_memory(
+    sources: &[(&str, &str)],
+    import_paths: &[PathBuf],
+) -> ::capnp::Result<Vec<u8>> {
+    let mut command = CompilerCommand::new();
+    for import_path in import_paths {
+        command.import_path(import_path);
+    }
+    command.compile_from_memory(sources)
 }
 
 /// A builder object for schema compiler commands.
 #[derive(Default)]
 pub struct CompilerCommand {
     files: Vec<PathBuf>,
+    dirs: Vec<PathBuf>,
     src_prefixes: Vec<PathBuf>,
     import_paths: Vec<PathBuf>,
     no_standard_import: bool,
     executable_path: Option<PathBuf>,
+    prefer_external: bool,
     output_path: Option<PathBuf>,
     default_parent_module: Vec<String>,
     raw_code_generator_request_path: Option<PathBuf>,
     crate_provides_map: HashMap<u64, String>,
+    skip_convenience_builders: bool,
+    no_alloc: bool,
+    lenient_enums: bool,
+    canonical_nans: bool,
+    accessor_style: codegen::AccessorStyle,
+    inline_hints: codegen::InlineHints,
+    split_output_threshold: Option<usize>,
+    skip_files: Vec<u64>,
+    file_cfgs: Vec<(codegen::SchemaFile, String)>,
+    format: codegen::Format,
+    provenance_header: bool,
+    reexport_aliases: bool,
+    working_dir: Option<PathBuf>,
+    cache: bool,
+    force: bool,
 }
 
 impl CompilerCommand {
@@ -137,6 +249,23 @@ impl CompilerCommand {
         self
     }
 
+    /// Adds every `*.capnp` file under `dir` to be compiled, searching subdirectories
+    /// recursively, and registers `dir` as both a source prefix and an import path, so that
+    /// the schemas can import each other by their paths relative to `dir`.
+    ///
+    /// Hidden directories such as `.git` are skipped. Symlinked directories are followed,
+    /// but each directory is visited at most once. `run()` fails if `dir` contains no
+    /// schema files.
+    pub fn dir<P>(&mut self, dir: P) -> &mut Self
+    where
+        P: AsRef<Path>,
+    {
+        self.dirs.push(dir.as_ref().to_path_buf());
+        self.src_prefix(&dir);
+        self.import_path(&dir);
+        self
+    }
+
     pub fn file_count(&self) -> usize {
         self.files.len()
     }
@@ -237,7 +366,8 @@ impl CompilerCommand {
         self
     }
 
-    /// Sets the output directory of generated code. Default is OUT_DIR
+    /// Sets the output directory of generated code. Default is OUT_DIR, which is only consulted
+    /// when this is not set.
     pub fn output_path<P>(&mut self, path: P) -> &mut Self
     where
         P: AsRef<Path>,
@@ -256,13 +386,95 @@ impl CompilerCommand {
         self
     }
 
+    /// Uses an installed `capnp` executable: `path` if given, and otherwise the one named by the
+    /// `CAPNP` environment variable or, failing that, the one found in `PATH`. This replaces any
+    /// executable given to `capnp_executable()`, such as one that a wrapper like `capnp_import`
+    /// bundles.
+    ///
+    /// The compiler always runs as a child process, whose `CodeGeneratorRequest` is read from its
+    /// standard output and handed to [`codegen::CodeGenerationCommand`].
+    pub fn use_external_compiler(&mut self, path: Option<PathBuf>) -> &mut Self {
+        self.prefer_external = path.is_none();
+        self.executable_path = path;
+        self
+    }
+
+    /// Uses the executable named by the `CAPNP` environment variable, if it is set when the
+    /// command runs, in place of the one given to `capnp_executable()`.
+    pub fn prefer_external(&mut self) -> &mut Self {
+        self.prefer_external = true;
+        self
+    }
+
+    /// Sets the directory that relative paths are resolved against: input files, source
+    /// prefixes, import paths, the output path and the raw code generator request path.
+    /// Defaults to the current directory of the process.
+    ///
+    /// Together with `output_path()`, this makes the command independent of the environment
+    /// that Cargo sets up for build scripts.
+    pub fn working_dir<P>(&mut self, dir: P) -> &mut Self
+    where
+        P: AsRef<Path>,
+    {
+        self.working_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Resolves `path` against the working directory, if one was set.
+    fn resolve(&self, path: &Path) -> PathBuf {
+        match &self.working_dir {
+            Some(dir) => dir.join(path),
+            None => path.to_path_buf(),
+        }
+    }
+
+    /// Lists the schema files under `dir`, as added by `dir()`.
+    fn schemas_in_dir(&self, dir: &Path) -> ::capnp::Result<Vec<PathBuf>> {
+        let root = self.resolve(dir);
+        let mut found = Vec::new();
+        collect_schemas(&root, &mut HashSet::new(), &mut found).map_err(|error| {
+            ::capnp::Error::failed(format!(
+                "Unable to read schema directory `{}`: {error}.",
+                dir.display()
+            ))
+        })?;
+        // Keep the paths relative to `dir`, so that they match the source prefix.
+        let files = found
+            .iter()
+            .map(|path| dir.join(path.strip_prefix(&root).unwrap_or(path)))
+            .collect::<Vec<_>>();
+
+        if files.is_empty() {
+            return Err(::capnp::Error::failed(format!(
+                "No schema files (`*.capnp`) found in directory `{}`.",
+                dir.display()
+            )));
+        }
+        Ok(files)
+    }
+
+    /// The `capnp` executable to run, or `None` to look it up in `PATH`.
+    fn executable(&self) -> Option<PathBuf> {
+        let external = match self.prefer_external {
+            true => ::std::env::var_os("CAPNP").filter(|capnp| !capnp.is_empty()),
+            false => None,
+        };
+        external
+            .map(PathBuf::from)
+            .or_else(|| self.executable_path.clone())
+    }
+
     /// Internal function for starting to build a capnp command.
     fn new_command(&self) -> ::std::process::Command {
-        if let Some(executable) = &self.executable_path {
+        let mut command = if let Some(executable) = self.executable() {
             ::std::process::Command::new(executable)
         } else {
             ::std::process::Command::new("capnp")
+        };
+        if let Some(dir) = &self.working_dir {
+            command.current_dir(dir);
         }
+        command
     }
 
     /// Sets the default parent module. This indicates the scope in your crate where you will
@@ -298,56 +510,382 @@ impl CompilerCommand {
         self
     }
 
-    /// Runs the command.
-    /// Returns an error if `OUT_DIR` or a custom output directory was not set, or if `capnp compile` fails.
-    pub fn run(&mut self) -> ::capnp::Result<()> {
-        match self.new_command().arg("--version").output() {
-            Err(error) => {
+    /// Sets whether to generate the plain Rust params structs and `build_*_request()`
+    /// client methods. Defaults to `true`.
+    ///
+    /// See [`codegen::CodeGenerationCommand::generate_convenience_builders`].
+    pub fn generate_convenience_builders(&mut self, value: bool) -> &mut Self {
+        self.skip_convenience_builders = !value;
+        self
+    }
+
+    /// Sets whether the generated code must build without the `alloc` crate. Defaults to `false`.
+    ///
+    /// See [`codegen::CodeGenerationCommand::no_alloc`].
+    pub fn no_alloc(&mut self, value: bool) -> &mut Self {
+        self.no_alloc = value;
+        self
+    }
+
+    /// Sets whether to generate `FooOrUnknown` enum companions and `*_lenient()` accessors
+    /// for enum fields. Defaults to `false`.
+    ///
+    /// See [`codegen::CodeGenerationCommand::lenient_enums`].
+    pub fn lenient_enums(&mut self, value: bool) -> &mut Self {
+        self.lenient_enums = value;
+        self
+    }
+
+    /// Sets whether float setters write every NaN as the canonical quiet NaN, so that
+    /// messages encode reproducibly. Defaults to `false`.
+    ///
+    /// See [`codegen::CodeGenerationCommand::canonical_nans`].
+    pub fn canonical_nans(&mut self, value: bool) -> &mut Self {
+        self.canonical_nans = value;
+        self
+    }
+
+    /// Sets how field accessors are named. Defaults to
+    /// [`codegen::AccessorStyle::CapnpGet`].
+    ///
+    /// See [`codegen::CodeGenerationCommand::accessor_style`].
+    pub fn accessor_style(&mut self, style: codegen::AccessorStyle) -> &mut Self {
+        self.accessor_style = style;
+        self
+    }
+
+    /// Sets which generated functions are marked `#[inline]`. Defaults to
+    /// [`codegen::InlineHints::Default`].
+    ///
+    /// See [`codegen::CodeGenerationCommand::inline_hints`].
+    pub fn inline_hints(&mut self, hints: codegen::InlineHints) -> &mut Self {
+        self.inline_hints = hints;
+        self
+    }
+
+    /// Sets how the generated files are formatted. Defaults to [`codegen::Format::Raw`].
+    ///
+    /// See [`codegen::CodeGenerationCommand::format_output`].
+    pub fn format_output(&mut self, format: codegen::Format) -> &mut Self {
+        self.format = format;
+        self
+    }
+
+    /// Records the crate version, the source prefixes, the import paths, the default parent
+    /// module and a hash of the other options in the header of each generated file, so that
+    /// [`verify_headers()`](Self::verify_headers) can find files that were generated
+    /// differently. Off by default, which keeps the header as it was. See [`provenance`].
+    pub fn provenance_header(&mut self) -> &mut Self {
+        self.provenance_header = true;
+        self
+    }
+
+    /// Reads the header of every file generated by capnpc-rust at or below `path`, and returns
+    /// the recorded values that differ from what this command would record. Files generated
+    /// without [`provenance_header()`](Self::provenance_header) are reported as missing every
+    /// value; other Rust files are ignored.
+    pub fn verify_headers<P>(&self, path: P) -> ::capnp::Result<Vec<provenance::HeaderDrift>>
+    where
+        P: AsRef<Path>,
+    {
+        provenance::verify(
+            &self.resolve(path.as_ref()),
+            &self.provenance().fields(&self.default_parent_module),
+        )
+    }
+
+    fn provenance(&self) -> provenance::Provenance {
+        let mut crate_provides = self.crate_provides_map.iter().collect::<Vec<_>>();
+        crate_provides.sort();
+        // Like `cache_options()`, minus everything that depends on where the build runs.
+        let options = format!(
+            "{:?}",
+            (
+                self.no_standard_import,
+                crate_provides,
+                self.skip_convenience_builders,
+                self.no_alloc,
+                self.lenient_enums,
+                self.canonical_nans,
+                self.accessor_style,
+                self.inline_hints,
+                self.reexport_aliases,
+                &self.file_cfgs,
+                self.format,
+                (self.split_output_threshold, &self.skip_files),
+            )
+        );
+        provenance::Provenance {
+            src_prefixes: self
+                .src_prefixes
+                .iter()
+                .map(|path| self.normalize(path))
+                .collect(),
+            import_paths: self
+                .import_paths
+                .iter()
+                .map(|path| self.normalize(path))
+                .collect(),
+            options_hash: provenance::stable_hash(&options),
+        }
+    }
+
+    /// Spells `path` relative to the working directory where possible, with `/` separators
+    /// and without `.` components.
+    fn normalize(&self, path: &Path) -> String {
+        let base = match &self.working_dir {
+            Some(dir) => Some(dir.clone()),
+            None => std::env::current_dir().ok(),
+        };
+        let path = match base
+            .as_deref()
+            .and_then(|base| path.strip_prefix(base).ok())
+        {
+            Some(relative) if path.is_absolute() => relative,
+            _ => path,
+        };
+        let components = path
+            .components()
+            .filter(|c| !matches!(c, std::path::Component::CurDir))
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        if path.has_root() {
+            format!("/{}", components[1..].join("/"))
+        } else {
+            components.join("/")
+        }
+    }
+
+    /// Splits generated files larger than `threshold_bytes` into one file per top-level
+    /// module, keeping the module paths unchanged.
+    ///
+    /// See [`codegen::CodeGenerationCommand::split_output`].
+    pub fn split_output(&mut self, threshold_bytes: usize) -> &mut Self {
+        self.split_output_threshold = Some(threshold_bytes);
+        self
+    }
+
+    /// Skips writing output for the given files, which must only declare annotations, so that
+    /// schemas importing them build without an extra `include!`.
+    ///
+    /// See [`codegen::CodeGenerationCommand::skip_files`].
+    pub fn skip_files(&mut self, ids: impl IntoIterator<Item = u64>) -> &mut Self {
+        self.skip_files.extend(ids);
+        self
+    }
+
+    /// Only compiles the code generated for `file` if the `cfg` predicate holds, for example
+    /// `feature = "admin"`.
+    ///
+    /// See [`codegen::CodeGenerationCommand::cfg_for_file`].
+    pub fn cfg_for_file(
+        &mut self,
+        file: impl Into<codegen::SchemaFile>,
+        cfg: impl Into<String>,
+    ) -> &mut Self {
+        self.file_cfgs.push((file.into(), cfg.into()));
+        self
+    }
+
+    /// Sets whether to re-export `using` aliases as `pub use` items in the generated modules.
+    /// Defaults to `false`.
+    ///
+    /// The schema sources are looked up under the source prefixes and the working directory.
+    ///
+    /// See [`codegen::CodeGenerationCommand::reexport_aliases`].
+    pub fn reexport_aliases(&mut self, value: bool) -> &mut Self {
+        self.reexport_aliases = value;
+        self
+    }
+
+    /// Sets whether to skip compilation when nothing changed since the previous run.
+    /// Defaults to `false`.
+    ///
+    /// When enabled, `run()` stores a hash over the compiler options and the contents of all
+    /// schema files that were read, imports included, in `.capnpc-cache.json` in the output
+    /// directory. The next `run()` with the same options rehashes those files and returns
+    /// without invoking `capnp` if the hash still matches. Imports that cannot be located on
+    /// disk disable the cache for that run.
+    pub fn cache(&mut self, value: bool) -> &mut Self {
+        self.cache = value;
+        self
+    }
+
+    /// Makes `run()` compile even if the cache says nothing changed. The cache is still
+    /// updated afterwards.
+    pub fn force(&mut self) -> &mut Self {
+        self.force = true;
+        self
+    }
+
+    /// Describes everything besides the schema files that affects the generated code.
+    fn cache_options(&self, files: &[PathBuf], output_path: &Path) -> String {
+        let mut crate_provides = self.crate_provides_map.iter().collect::<Vec<_>>();
+        crate_provides.sort();
+        format!(
+            "{:?}",
+            (
+                (
+                    files,
+                    &self.src_prefixes,
+                    &self.import_paths,
+                    self.no_standard_import,
+                    self.executable(),
+                    output_path,
+                ),
+                &self.default_parent_module,
+                &self.raw_code_generator_request_path,
+                crate_provides,
+                self.skip_convenience_builders,
+                self.no_alloc,
+                self.lenient_enums,
+                self.canonical_nans,
+                (
+                    self.accessor_style,
+                    self.inline_hints,
+                    self.reexport_aliases,
+                    &self.file_cfgs,
+                    self.format,
+                    self.provenance_header,
+                ),
+                self.split_output_threshold,
+                &self.skip_files,
+                &self.working_dir,
+            )
+        )
+    }
+
+    /// Finds the schema files on disk that the compiler read to produce `request`.
+    ///
+    /// The compiler only reports display names, which are relative to the directory the file
+    /// was found in, so every candidate location that exists is included. Returns `None` if a
+    /// file cannot be found at all.
+    fn schema_dependencies(
+        &self,
+        files: &[PathBuf],
+        request: &[u8],
+    ) -> ::capnp::Result<Option<Vec<PathBuf>>> {
+        use capnp::schema_capnp::{code_generator_request, node};
+
+        let message =
+            capnp::serialize::read_message(request, capnp::message::ReaderOptions::new())?;
+        let request: code_generator_request::Reader<'_> = message.get_root()?;
+
+        let mut roots = vec![PathBuf::new()];
+        roots.extend(self.src_prefixes.iter().cloned());
+        roots.extend(self.import_paths.iter().cloned());
+        let mut roots = roots
+            .iter()
+            .map(|root| self.resolve(root))
+            .collect::<Vec<_>>();
+        if !self.no_standard_import {
+            if let Some(dir) = self.executable().as_ref().and_then(|path| path.parent()) {
+                roots.push(self.resolve(&dir.join("../include")));
+            }
+            roots.push(PathBuf::from("/usr/local/include"));
+            roots.push(PathBuf::from("/usr/include"));
+        }
+
+        let mut dependencies = files
+            .iter()
+            .map(|file| self.resolve(file))
+            .collect::<Vec<_>>();
+        for node in request.get_nodes()? {
+            if !matches!(node.which()?, node::File(())) {
+                continue;
+            }
+            let display_name = node.get_display_name()?.to_str()?;
+            let found = roots
+                .iter()
+                .map(|root| root.join(display_name))
+                .filter(|path| path.is_file())
+                .collect::<Vec<_>>();
+            if found.is_empty() {
+                return Ok(None);
+            }
+            dependencies.extend(found);
+        }
+        dependencies.sort();
+        dependencies.dedup();
+        Ok(Some(dependencies))
+    }
+
+    /// Compiles schema source text held in memory instead of the files added with `file()`,
+    /// and returns the serialized `CodeGeneratorRequest` rather than generating code.
+    ///
+    /// The sources are written to a temporary directory that is removed afterwards, including
+    /// on error. Import paths, `no_standard_import()` and `capnp_executable()` are honored;
+    /// files, source prefixes and output options are ignored.
+    ///
+    /// See [`compile_from_memory`] for the meaning of `sources`.
+    pub fn compile_from_memory(&self, sources: &[(&str, &str)]) -> ::capnp::Result<Vec<u8>> {
+        let dir = TempDir::new().map_err(convert_io_err)?;
+
+        let mut paths = Vec::with_capacity(sources.len());
+        for (virtual_path, content) in sources {
+            let relative = Path::new(virtual_path);
+            if !relative
+                .components()
+                .all(|c| matches!(c, std::path::Component::Normal(_)))
+            {
                 return Err(::capnp::Error::failed(format!(
-                    "Failed to execute `capnp --version`: {error}. \
-                     Please verify that version 0.5.2 or higher of the capnp executable \
-                     is installed on your system. See https://capnproto.org/install.html"
-                )))
+                    "Invalid virtual path `{virtual_path}`: must be relative and must not \
+                     contain `.` or `..` components."
+                )));
             }
-            Ok(output) => {
-                if !output.status.success() {
-                    return Err(::capnp::Error::failed(format!(
-                        "`capnp --version` returned an error: {:?}. \
-                         Please verify that version 0.5.2 or higher of the capnp executable \
-                         is installed on your system. See https://capnproto.org/install.html",
-                        output.status
-                    )));
-                }
-                // TODO Parse the version string?
+            let path = dir.path().join(relative);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).map_err(convert_io_err)?;
             }
+            std::fs::write(&path, content).map_err(convert_io_err)?;
+            paths.push(path);
         }
 
         let mut command = self.new_command();
-
-        // We remove PWD from the env to avoid the following warning.
-        // kj/filesystem-disk-unix.c++:1690:
-        //    warning: PWD environment variable doesn't match current directory
         command.env_remove("PWD");
-
         command.arg("compile").arg("-o").arg("-");
 
         if self.no_standard_import {
             command.arg("--no-standard-import");
         }
 
+        // Lets the virtual files import each other by absolute path.
+        command.arg(format!("--import-path={}", dir.path().display()));
         for import_path in &self.import_paths {
-            command.arg(&format!("--import-path={}", import_path.display()));
+            command.arg(format!("--import-path={}", import_path.display()));
+        }
+        command.arg(format!("--src-prefix={}", dir.path().display()));
+        command.args(&paths);
+
+        let output = command.output().map_err(|error| {
+            ::capnp::Error::failed(format!("Failed to execute `{command:?}`: {error}."))
+        })?;
+        if !output.status.success() {
+            return Err(::capnp::Error::failed(format!(
+                "`capnp compile` returned {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim_end()
+            )));
         }
+        Ok(output.stdout)
+    }
 
-        for src_prefix in &self.src_prefixes {
-            command.arg(&format!("--src-prefix={}", src_prefix.display()));
+    /// Runs the command.
+    /// Returns an error if `OUT_DIR` or a custom output directory was not set, or if `capnp compile` fails.
+    pub fn run(&mut self) -> ::capnp::Result<()> {
+        let mut files = self.files.clone();
+        for dir in &self.dirs {
+            files.extend(self.schemas_in_dir(dir)?);
         }
 
-        for file in &self.files {
-            std::fs::metadata(file).map_err(|error| {
-                let current_dir = match std::env::current_dir() {
-                    Ok(current_dir) => format!("`{}`", current_dir.display()),
-                    Err(..) => "<unknown working directory>".to_string(),
+        for file in &files {
+            std::fs::metadata(self.resolve(file)).map_err(|error| {
+                let current_dir = match &self.working_dir {
+                    Some(dir) => format!("`{}`", dir.display()),
+                    None => match std::env::current_dir() {
+                        Ok(current_dir) => format!("`{}`", current_dir.display()),
+                        Err(..) => "<unknown working directory>".to_string(),
+                    },
                 };
 
                 ::capnp::Error::failed(format!(
@@ -358,12 +896,10 @@ impl CompilerCommand {
                     error
                 ))
             })?;
-
-            command.arg(file);
         }
 
         let output_path = if let Some(output_path) = &self.output_path {
-            output_path.clone()
+            self.resolve(output_path)
         } else {
             // Try `OUT_DIR` by default
             PathBuf::from(::std::env::var("OUT_DIR").map_err(|error| {
@@ -375,24 +911,113 @@ impl CompilerCommand {
             })?)
         };
 
-        command.stdout(::std::process::Stdio::piped());
-        command.stderr(::std::process::Stdio::inherit());
+        let cache_options = self.cache_options(&files, &output_path);
+        if self.cache && !self.force {
+            if let Some(cache) = cache::Cache::read(&output_path) {
+                if cache::hash_inputs(&cache_options, &cache.files) == Some(cache.hash) {
+                    return Ok(());
+                }
+            }
+        }
+
+        let version = match self.new_command().arg("--version").output() {
+            Err(error) => {
+                return Err(::capnp::Error::failed(format!(
+                    "Failed to execute `capnp --version`: {error}. \
+                     Please verify that version 0.5.2 or higher of the capnp executable \
+                     is installed on your system. See https://capnproto.org/install.html"
+                )))
+            }
+            Ok(output) => {
+                if !output.status.success() {
+                    return Err(::capnp::Error::failed(format!(
+                        "`capnp --version` returned an error: {:?}. \
+                         Please verify that version 0.5.2 or higher of the capnp executable \
+                         is installed on your system. See https://capnproto.org/install.html",
+                        output.status
+                    )));
+                }
+                String::from_utf8_lossy(&output.stdout).trim().to_string()
+            }
+        };
+
+        let mut command = self.new_command();
+
+        // We remove PWD from the env to avoid the following warning.
+        // kj/filesystem-disk-unix.c++:1690:
+        //    warning: PWD environment variable doesn't match current directory
+        command.env_remove("PWD");
+
+        command.arg("compile").arg("-o").arg("-");
+
+        if self.no_standard_import {
+            command.arg("--no-standard-import");
+        }
+
+        for import_path in &self.import_paths {
+            command.arg(format!("--import-path={}", import_path.display()));
+        }
+
+        for src_prefix in &self.src_prefixes {
+            command.arg(format!("--src-prefix={}", src_prefix.display()));
+        }
+
+        command.args(&files);
 
         let mut code_generation_command = crate::codegen::CodeGenerationCommand::new();
         code_generation_command
-            .output_directory(output_path)
+            .output_directory(&output_path)
             .default_parent_module(self.default_parent_module.clone())
-            .crates_provide_map(self.crate_provides_map.clone());
+            .crates_provide_map(self.crate_provides_map.clone())
+            .generate_convenience_builders(!self.skip_convenience_builders)
+            .no_alloc(self.no_alloc)
+            .lenient_enums(self.lenient_enums)
+            .canonical_nans(self.canonical_nans)
+            .accessor_style(self.accessor_style)
+            .inline_hints(self.inline_hints)
+            .format_output(self.format)
+            .skip_files(self.skip_files.iter().copied());
+        if self.provenance_header {
+            code_generation_command.provenance_header(self.provenance());
+        }
+        for (file, cfg) in &self.file_cfgs {
+            code_generation_command.cfg_for_file(file.clone(), cfg.clone());
+        }
+        if self.reexport_aliases {
+            let mut source_dirs = self
+                .src_prefixes
+                .iter()
+                .map(|prefix| self.resolve(prefix))
+                .collect::<Vec<_>>();
+            source_dirs.push(self.resolve(Path::new("")));
+            code_generation_command.reexport_aliases(source_dirs);
+        }
+        if let Some(threshold) = self.split_output_threshold {
+            code_generation_command.split_output(threshold);
+        }
         if let Some(raw_code_generator_request_path) = &self.raw_code_generator_request_path {
             code_generation_command
-                .raw_code_generator_request_path(raw_code_generator_request_path.clone());
+                .raw_code_generator_request_path(self.resolve(raw_code_generator_request_path));
         }
 
         let cmd_string = format!("{:?}", &command);
-        run_command(command, code_generation_command).map_err(|error| {
+        let request = run_command(command, code_generation_command).map_err(|error| {
             ::capnp::Error::failed(format!(
-                "Error while trying to execute `{cmd_string}`: {error}."
+                "Error while trying to execute `{cmd_string}` ({version}, capnpc expects version {}): {error}.",
+                compiler_version()
             ))
-        })
+        })?;
+
+        if self.cache {
+            let dependencies = self.schema_dependencies(&files, &request)?;
+            match dependencies.and_then(|files| {
+                let hash = cache::hash_inputs(&cache_options, &files)?;
+                Some(cache::Cache { hash, files })
+            }) {
+                Some(cache) => cache.write(&output_path)?,
+                None => cache::Cache::remove(&output_path)?,
+            }
+        }
+        Ok(())
     }
 }
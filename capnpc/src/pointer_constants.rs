@@ -20,7 +20,7 @@
 
 use capnp::{any_pointer, message};
 
-use crate::codegen::FormattedText::{Branch, Indent, Line};
+use crate::codegen::FormattedText::{Branch, Line};
 use crate::codegen::{fmt, indent, line, FormattedText, GeneratorContext};
 use crate::codegen_types::{Leaf, RustTypeInfo};
 use capnp::schema_capnp::type_;
@@ -30,18 +30,24 @@ pub struct WordArrayDeclarationOptions {
     pub public: bool,
 }
 
-fn word_array_declaration_aux<T: ::capnp::traits::SetPointerBuilder>(
-    ctx: &GeneratorContext,
-    name: &str,
+// Serializes `value` as the root of a single-segment message and returns the segment.
+fn message_words<T: ::capnp::traits::SetPointerBuilder>(
     value: T,
     total_size: ::capnp::MessageSize,
-    options: WordArrayDeclarationOptions,
-) -> ::capnp::Result<FormattedText> {
+) -> ::capnp::Result<Vec<u8>> {
     let allocator =
         message::HeapAllocator::new().first_segment_words(total_size.word_count as u32 + 1);
     let mut message = message::Builder::new(allocator);
     message.set_root(value)?;
-    let words = message.get_segments_for_output()[0];
+    Ok(message.get_segments_for_output()[0].to_vec())
+}
+
+fn words_declaration(
+    ctx: &GeneratorContext,
+    name: &str,
+    words: &[u8],
+    options: WordArrayDeclarationOptions,
+) -> FormattedText {
     let mut words_lines = Vec::new();
     for index in 0..(words.len() / 8) {
         let bytes = &words[(index * 8)..(index + 1) * 8];
@@ -62,7 +68,7 @@ fn word_array_declaration_aux<T: ::capnp::traits::SetPointerBuilder>(
     // `static` instead of `const` because these arrays can be large
     // and consts get inlined at each usage.
     let vis = if options.public { "pub " } else { "" };
-    Ok(Branch(vec![
+    Branch(vec![
         Line(fmt!(
             ctx,
             "{}static {}: [{capnp}::Word; {}] = [",
@@ -72,16 +78,18 @@ fn word_array_declaration_aux<T: ::capnp::traits::SetPointerBuilder>(
         )),
         indent(Branch(words_lines)),
         line("];"),
-    ]))
+    ])
 }
 
-pub fn word_array_declaration(
+fn word_array_declaration_aux<T: ::capnp::traits::SetPointerBuilder>(
     ctx: &GeneratorContext,
     name: &str,
-    value: any_pointer::Reader,
+    value: T,
+    total_size: ::capnp::MessageSize,
     options: WordArrayDeclarationOptions,
 ) -> ::capnp::Result<FormattedText> {
-    word_array_declaration_aux(ctx, name, value, value.target_size()?, options)
+    let words = message_words(value, total_size)?;
+    Ok(words_declaration(ctx, name, &words, options))
 }
 
 pub fn node_word_array_declaration(
@@ -93,33 +101,86 @@ pub fn node_word_array_declaration(
     word_array_declaration_aux(ctx, name, value, value.total_size()?, options)
 }
 
+/// Returns the name of the static in the `_private` module of the struct being generated that
+/// holds `value`, the explicit default of a pointer field, along with its declaration if it is
+/// the first default with that encoding in the struct. Later fields with identical defaults
+/// share the static of the first.
+pub fn default_value_declaration(
+    ctx: &GeneratorContext,
+    name: &str,
+    value: any_pointer::Reader,
+) -> ::capnp::Result<(String, Option<FormattedText>)> {
+    let words = message_words(value, value.target_size()?)?;
+    if let Some(existing) = ctx.default_value_words.borrow().get(&words) {
+        return Ok((existing.clone(), None));
+    }
+    let declaration = words_declaration(
+        ctx,
+        name,
+        &words,
+        WordArrayDeclarationOptions { public: true },
+    );
+    ctx.default_value_words
+        .borrow_mut()
+        .insert(words, name.to_string());
+    Ok((name.to_string(), Some(declaration)))
+}
+
+/// Generates the `constant::Reader` static for a struct or list constant, along with
+/// a function of the same name in snake case that returns the typed reader.
+///
+/// The words of the constant are stored in a hidden static next to it. Constants in
+/// the same file with identical encodings share a single such static.
 pub fn generate_pointer_constant(
     ctx: &GeneratorContext,
+    node_id: u64,
     styled_name: &str,
     typ: type_::Reader,
     value: any_pointer::Reader,
 ) -> ::capnp::Result<FormattedText> {
-    Ok(Branch(vec![
-        Line(fmt!(
-            ctx,
-            "pub static {}: {capnp}::constant::Reader<{}> = {{",
-            styled_name,
-            typ.type_string(ctx, Leaf::Owned)?
-        )),
-        Indent(Box::new(Branch(vec![
-            word_array_declaration(
+    let words = message_words(value, value.target_size()?)?;
+    let scope = &ctx.scope_map[&node_id];
+    let mut result = Vec::new();
+    let existing = ctx.pointer_constant_words.borrow().get(&words).cloned();
+    let words_path = match existing {
+        Some(path) => path,
+        None => {
+            let words_name = format!("_{styled_name}_WORDS");
+            result.push(line("#[doc(hidden)]"));
+            result.push(words_declaration(
                 ctx,
-                "WORDS",
-                value,
-                WordArrayDeclarationOptions { public: false },
-            )?,
-            Line(fmt!(ctx, "{capnp}::constant::Reader {{")),
-            Indent(Box::new(Branch(vec![
-                Line("phantom: ::core::marker::PhantomData,".into()),
-                Line("words: &WORDS,".into()),
-            ]))),
-            Line("}".into()),
-        ]))),
-        line("};"),
-    ]))
+                &words_name,
+                &words,
+                WordArrayDeclarationOptions { public: true },
+            ));
+            let path = format!("{}::{words_name}", scope[..scope.len() - 1].join("::"));
+            ctx.pointer_constant_words
+                .borrow_mut()
+                .insert(words, path.clone());
+            path
+        }
+    };
+
+    result.push(Line(fmt!(
+        ctx,
+        "pub static {}: {capnp}::constant::Reader<{}> = {capnp}::constant::Reader {{",
+        styled_name,
+        typ.type_string(ctx, Leaf::Owned)?
+    )));
+    result.push(indent(Branch(vec![
+        line("phantom: ::core::marker::PhantomData,"),
+        Line(format!("words: &{words_path},")),
+    ])));
+    result.push(line("};"));
+
+    result.push(Line(format!(
+        "pub fn {}() -> {} {{",
+        scope[scope.len() - 1],
+        typ.type_string(ctx, Leaf::Reader("'static"))?
+    )));
+    result.push(indent(Line(format!(
+        "{styled_name}.get().expect(\"constant words are well-formed\")"
+    ))));
+    result.push(line("}"));
+    Ok(Branch(result))
 }
@@ -0,0 +1,159 @@
+//! Records how each generated file was produced in its header, so that files generated with
+//! different options can be found later.
+//!
+//! With [`CompilerCommand::provenance_header`](crate::CompilerCommand::provenance_header), the
+//! header of every generated file gets a block like this one:
+//!
+//! ```text
+//! // capnpc-version: 0.18.0
+//! // capnpc-src-prefixes: ["schema"]
+//! // capnpc-import-paths: ["../common"]
+//! // capnpc-default-parent-module: ["schema"]
+//! // capnpc-options-hash: 6f0a3c8e1d2b4957
+//! ```
+//!
+//! [`CompilerCommand::verify_headers`](crate::CompilerCommand::verify_headers) reads the block
+//! back and reports the files whose recorded values differ from the current command.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::convert_io_err;
+
+const KEY_PREFIX: &str = "// capnpc-";
+
+/// The values recorded in the header of a generated file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Provenance {
+    /// The source prefixes, relative to the working directory, with `/` separators.
+    pub src_prefixes: Vec<String>,
+
+    /// The import paths, relative to the working directory, with `/` separators.
+    pub import_paths: Vec<String>,
+
+    /// A hash of the other options that affect the generated code. Paths that depend on where
+    /// the build runs, such as the output directory, are left out.
+    pub options_hash: u64,
+}
+
+impl Provenance {
+    /// The recorded fields, in the order they appear in the header.
+    pub(crate) fn fields(&self, default_parent_module: &[String]) -> Vec<(&'static str, String)> {
+        vec![
+            ("version", env!("CARGO_PKG_VERSION").to_string()),
+            ("src-prefixes", format!("{:?}", self.src_prefixes)),
+            ("import-paths", format!("{:?}", self.import_paths)),
+            (
+                "default-parent-module",
+                format!("{default_parent_module:?}"),
+            ),
+            ("options-hash", format!("{:016x}", self.options_hash)),
+        ]
+    }
+
+    /// The comment lines of the header block.
+    pub(crate) fn header_lines(&self, default_parent_module: &[String]) -> Vec<String> {
+        self.fields(default_parent_module)
+            .into_iter()
+            .map(|(key, value)| format!("{KEY_PREFIX}{key}: {value}"))
+            .collect()
+    }
+}
+
+/// A recorded header value that differs from the current command.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HeaderDrift {
+    /// The generated file.
+    pub path: PathBuf,
+
+    /// The field that differs, e.g. `import-paths`.
+    pub field: String,
+
+    /// The recorded value, or `None` if the file has no such field.
+    pub recorded: Option<String>,
+
+    /// The value for the current command.
+    pub current: String,
+}
+
+impl fmt::Display for HeaderDrift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.recorded {
+            Some(recorded) => write!(
+                f,
+                "{}: `{}` was generated with {}, but is now {}",
+                self.path.display(),
+                self.field,
+                recorded,
+                self.current
+            ),
+            None => write!(
+                f,
+                "{}: `{}` is not recorded in the header",
+                self.path.display(),
+                self.field
+            ),
+        }
+    }
+}
+
+/// Hashes `text` with 64-bit FNV-1a, which, unlike `DefaultHasher`, gives the same result
+/// with every toolchain.
+pub(crate) fn stable_hash(text: &str) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in text.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+/// Compares the header of every file generated by capnpc-rust at or below `path` with
+/// `expected`.
+pub(crate) fn verify(
+    path: &Path,
+    expected: &[(&'static str, String)],
+) -> ::capnp::Result<Vec<HeaderDrift>> {
+    let mut files = Vec::new();
+    collect_rust_files(path, &mut files)?;
+    files.sort();
+
+    let mut drifts = Vec::new();
+    for file in files {
+        let text = std::fs::read_to_string(&file).map_err(convert_io_err)?;
+        if !text.starts_with("// @generated by the capnpc-rust plugin") {
+            continue;
+        }
+        let recorded = text
+            .lines()
+            .take_while(|line| line.starts_with("//"))
+            .filter_map(|line| line.strip_prefix(KEY_PREFIX)?.split_once(": "))
+            .collect::<Vec<_>>();
+        for (key, current) in expected {
+            let value = recorded
+                .iter()
+                .find(|(recorded_key, _)| recorded_key == key)
+                .map(|(_, value)| *value);
+            if value != Some(current.as_str()) {
+                drifts.push(HeaderDrift {
+                    path: file.clone(),
+                    field: key.to_string(),
+                    recorded: value.map(str::to_string),
+                    current: current.clone(),
+                });
+            }
+        }
+    }
+    Ok(drifts)
+}
+
+fn collect_rust_files(path: &Path, files: &mut Vec<PathBuf>) -> ::capnp::Result<()> {
+    if path.is_dir() {
+        for entry in std::fs::read_dir(path).map_err(convert_io_err)? {
+            collect_rust_files(&entry.map_err(convert_io_err)?.path(), files)?;
+        }
+    } else if path.extension().is_some_and(|extension| extension == "rs") {
+        files.push(path.to_path_buf());
+    }
+    Ok(())
+}
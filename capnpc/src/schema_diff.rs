@@ -0,0 +1,524 @@
+//! Finds the changes between two versions of a schema that break wire compatibility, so
+//! that CI can reject them.
+//!
+//! [`diff()`] compares two serialized `CodeGeneratorRequest`s, such as the ones written by
+//! [`CompilerCommand::raw_code_generator_request_path`](crate::CompilerCommand::raw_code_generator_request_path)
+//! or returned by [`compile_from_memory`](crate::compile_from_memory):
+//!
+//! ```ignore
+//! // Written by `raw_code_generator_request_path()` in the build of the last release.
+//! let old = std::fs::read("schema/released.bin")?;
+//! let new = std::fs::read(concat!(env!("OUT_DIR"), "/request.bin"))?;
+//! for change in capnpc::schema_diff::diff(&old, &new)? {
+//!     if change.severity == capnpc::schema_diff::Severity::Breaking {
+//!         panic!("{}: {change}", change.path);
+//!     }
+//! }
+//! ```
+//!
+//! Nodes are matched by ID, and by display name when an ID changed. Fields are matched by
+//! ordinal (groups, which have none, by name), enumerants by their value, and methods by
+//! name, so renaming any of these is not reported. Any change to a field type is reported as
+//! breaking, even the few that the encoding tolerates, such as `Text` to `Data`.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use capnp::message::ReaderOptions;
+use capnp::schema_capnp::{code_generator_request, field, node, type_};
+use capnp::serialize;
+
+/// Whether a change can break communication between the old and the new schema.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// Messages or calls of one version can be misread, or rejected, by the other.
+    Breaking,
+
+    /// The versions can communicate, like after adding a field.
+    Benign,
+}
+
+/// The kind of change that an [`Incompatibility`] describes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IncompatibilityKind {
+    /// A struct, enum or interface no longer exists.
+    RemovedNode,
+
+    /// A node kept its name but got a new ID.
+    ChangedNodeId,
+
+    /// A node with the same ID is now a different kind of node, e.g. an enum instead of a struct.
+    ChangedNodeKind,
+
+    RemovedField,
+    AddedField,
+
+    /// A field, or the union discriminant of a struct, is stored at a different offset.
+    ChangedFieldOffset,
+
+    ChangedFieldType,
+
+    /// A field got a different discriminant value, or moved into or out of a union.
+    ChangedDiscriminant,
+
+    RemovedEnumerant,
+    AddedEnumerant,
+
+    RemovedMethod,
+    AddedMethod,
+
+    /// A method kept its name but got a different ordinal.
+    ChangedMethodOrdinal,
+
+    /// A method's params or results are a different struct type.
+    ChangedMethodType,
+}
+
+impl IncompatibilityKind {
+    pub fn severity(self) -> Severity {
+        match self {
+            Self::AddedField | Self::AddedEnumerant | Self::AddedMethod => Severity::Benign,
+            _ => Severity::Breaking,
+        }
+    }
+}
+
+/// A change found by [`diff()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Incompatibility {
+    /// The display name of the changed node, such as `foo.capnp:Bar`, followed by the name of
+    /// the changed field, enumerant or method, if any, as in `foo.capnp:Bar.baz`.
+    pub path: String,
+
+    pub kind: IncompatibilityKind,
+
+    /// Equal to `kind.severity()`.
+    pub severity: Severity,
+
+    pub message: String,
+}
+
+impl fmt::Display for Incompatibility {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{}", self.message)
+    }
+}
+
+/// Compares the schemas in two serialized `CodeGeneratorRequest`s and returns the changes
+/// from `old` to `new`, in the order of the nodes in `old`, followed by the additions.
+///
+/// Returns an error if either request cannot be read.
+pub fn diff(old: &[u8], new: &[u8]) -> ::capnp::Result<Vec<Incompatibility>> {
+    let old_message = serialize::read_message(old, ReaderOptions::new())?;
+    let new_message = serialize::read_message(new, ReaderOptions::new())?;
+    let old = Schema::new(old_message.get_root()?)?;
+    let new = Schema::new(new_message.get_root()?)?;
+
+    let mut differ = Differ {
+        old: &old,
+        new: &new,
+        result: Vec::new(),
+    };
+    for old_node in &old.nodes {
+        let id = old_node.get_id();
+        let path = old.name(id);
+        if let Some(new_node) = new.by_id.get(&id) {
+            differ.node(&path, *old_node, *new_node)?;
+        } else if let Some(new_node) = new.by_name.get(&path) {
+            differ.push(
+                &path,
+                IncompatibilityKind::ChangedNodeId,
+                format!(
+                    "`{path}` changed its ID from {id:#018x} to {:#018x}",
+                    new_node.get_id()
+                ),
+            );
+            differ.node(&path, *old_node, *new_node)?;
+        } else if !is_part_of_parent(*old_node)? {
+            differ.push(
+                &path,
+                IncompatibilityKind::RemovedNode,
+                format!("`{path}` was removed"),
+            );
+        }
+    }
+    Ok(differ.result)
+}
+
+// Whether `node` is a group or the params or results struct of a method, whose changes are
+// reported as changes to the field or method they belong to.
+fn is_part_of_parent(node: node::Reader) -> ::capnp::Result<bool> {
+    Ok(match node.which()? {
+        node::Struct(st) => st.get_is_group() || node.get_scope_id() == 0,
+        _ => false,
+    })
+}
+
+struct Schema<'a> {
+    nodes: Vec<node::Reader<'a>>,
+    by_id: HashMap<u64, node::Reader<'a>>,
+    by_name: HashMap<String, node::Reader<'a>>,
+}
+
+impl<'a> Schema<'a> {
+    fn new(request: code_generator_request::Reader<'a>) -> ::capnp::Result<Self> {
+        let mut schema = Schema {
+            nodes: Vec::new(),
+            by_id: HashMap::new(),
+            by_name: HashMap::new(),
+        };
+        for node in request.get_nodes()? {
+            schema.nodes.push(node);
+            schema.by_id.insert(node.get_id(), node);
+            schema
+                .by_name
+                .insert(node.get_display_name()?.to_string()?, node);
+        }
+        Ok(schema)
+    }
+
+    fn name(&self, id: u64) -> String {
+        match self
+            .by_id
+            .get(&id)
+            .and_then(|node| node.get_display_name().ok()?.to_string().ok())
+        {
+            Some(name) => name,
+            None => format!("{id:#018x}"),
+        }
+    }
+
+    fn type_name(&self, ty: type_::Reader) -> ::capnp::Result<String> {
+        Ok(match ty.which()? {
+            type_::Void(()) => "Void".into(),
+            type_::Bool(()) => "Bool".into(),
+            type_::Int8(()) => "Int8".into(),
+            type_::Int16(()) => "Int16".into(),
+            type_::Int32(()) => "Int32".into(),
+            type_::Int64(()) => "Int64".into(),
+            type_::Uint8(()) => "UInt8".into(),
+            type_::Uint16(()) => "UInt16".into(),
+            type_::Uint32(()) => "UInt32".into(),
+            type_::Uint64(()) => "UInt64".into(),
+            type_::Float32(()) => "Float32".into(),
+            type_::Float64(()) => "Float64".into(),
+            type_::Text(()) => "Text".into(),
+            type_::Data(()) => "Data".into(),
+            type_::List(list) => format!("List({})", self.type_name(list.get_element_type()?)?),
+            type_::Enum(e) => self.name(e.get_type_id()),
+            type_::Struct(st) => self.name(st.get_type_id()),
+            type_::Interface(interface) => self.name(interface.get_type_id()),
+            type_::AnyPointer(_) => "AnyPointer".into(),
+        })
+    }
+}
+
+// Whether values of types `a` and `b` are encoded the same way. Brands are ignored, since
+// generic parameters are all encoded as pointers.
+fn same_type(a: type_::Reader, b: type_::Reader) -> ::capnp::Result<bool> {
+    Ok(match (a.which()?, b.which()?) {
+        (type_::List(a), type_::List(b)) => {
+            same_type(a.get_element_type()?, b.get_element_type()?)?
+        }
+        (type_::Enum(a), type_::Enum(b)) => a.get_type_id() == b.get_type_id(),
+        (type_::Struct(a), type_::Struct(b)) => a.get_type_id() == b.get_type_id(),
+        (type_::Interface(a), type_::Interface(b)) => a.get_type_id() == b.get_type_id(),
+        (a, b) => core::mem::discriminant(&a) == core::mem::discriminant(&b),
+    })
+}
+
+#[derive(PartialEq, Eq)]
+enum FieldKey {
+    Ordinal(u16),
+    Name(String),
+}
+
+fn field_key(field: field::Reader) -> ::capnp::Result<FieldKey> {
+    Ok(match field.get_ordinal().which()? {
+        field::ordinal::Explicit(ordinal) => FieldKey::Ordinal(ordinal),
+        field::ordinal::Implicit(()) => FieldKey::Name(field.get_name()?.to_string()?),
+    })
+}
+
+fn describe_discriminant(value: u16) -> String {
+    if value == field::NO_DISCRIMINANT {
+        "none".into()
+    } else {
+        value.to_string()
+    }
+}
+
+struct Differ<'a, 'b> {
+    old: &'b Schema<'a>,
+    new: &'b Schema<'a>,
+    result: Vec<Incompatibility>,
+}
+
+impl<'a, 'b> Differ<'a, 'b> {
+    fn push(&mut self, path: &str, kind: IncompatibilityKind, message: String) {
+        self.result.push(Incompatibility {
+            path: path.to_string(),
+            kind,
+            severity: kind.severity(),
+            message,
+        });
+    }
+
+    fn node(&mut self, path: &str, old: node::Reader, new: node::Reader) -> ::capnp::Result<()> {
+        match (old.which()?, new.which()?) {
+            (node::Struct(old), node::Struct(new)) => self.struct_(path, old, new),
+            (node::Enum(old), node::Enum(new)) => self.enum_(path, old, new),
+            (node::Interface(old), node::Interface(new)) => self.interface(path, old, new),
+            (old_which, new_which)
+                if core::mem::discriminant(&old_which) == core::mem::discriminant(&new_which) =>
+            {
+                Ok(())
+            }
+            _ => {
+                self.push(
+                    path,
+                    IncompatibilityKind::ChangedNodeKind,
+                    format!(
+                        "`{path}` changed from {} to {}",
+                        node_kind(old)?,
+                        node_kind(new)?
+                    ),
+                );
+                Ok(())
+            }
+        }
+    }
+
+    fn struct_(
+        &mut self,
+        path: &str,
+        old: node::struct_::Reader,
+        new: node::struct_::Reader,
+    ) -> ::capnp::Result<()> {
+        if old.get_discriminant_count() > 0
+            && new.get_discriminant_count() > 0
+            && old.get_discriminant_offset() != new.get_discriminant_offset()
+        {
+            self.push(
+                path,
+                IncompatibilityKind::ChangedFieldOffset,
+                format!(
+                    "the union discriminant of `{path}` moved from offset {} to offset {}",
+                    old.get_discriminant_offset(),
+                    new.get_discriminant_offset()
+                ),
+            );
+        }
+
+        let old_fields = old.get_fields()?;
+        let new_fields = new.get_fields()?;
+        let mut matched = vec![false; new_fields.len() as usize];
+        for old_field in old_fields {
+            let name = old_field.get_name()?.to_str()?;
+            let field_path = format!("{path}.{name}");
+            let key = field_key(old_field)?;
+            let mut new_field = None;
+            for (index, field) in new_fields.iter().enumerate() {
+                if field_key(field)? == key {
+                    matched[index] = true;
+                    new_field = Some(field);
+                    break;
+                }
+            }
+            match new_field {
+                Some(new_field) => self.field(&field_path, path, old_field, new_field)?,
+                None => self.push(
+                    &field_path,
+                    IncompatibilityKind::RemovedField,
+                    format!("field `{name}` of `{path}` was removed"),
+                ),
+            }
+        }
+        for (new_field, matched) in new_fields.iter().zip(matched) {
+            if !matched {
+                let name = new_field.get_name()?.to_str()?;
+                self.push(
+                    &format!("{path}.{name}"),
+                    IncompatibilityKind::AddedField,
+                    format!("field `{name}` was added to `{path}`"),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn field(
+        &mut self,
+        path: &str,
+        struct_path: &str,
+        old: field::Reader,
+        new: field::Reader,
+    ) -> ::capnp::Result<()> {
+        let name = old.get_name()?.to_str()?;
+        if old.get_discriminant_value() != new.get_discriminant_value() {
+            self.push(
+                path,
+                IncompatibilityKind::ChangedDiscriminant,
+                format!(
+                    "field `{name}` of `{struct_path}` changed its discriminant from {} to {}",
+                    describe_discriminant(old.get_discriminant_value()),
+                    describe_discriminant(new.get_discriminant_value())
+                ),
+            );
+        }
+        match (old.which()?, new.which()?) {
+            (field::Slot(old), field::Slot(new)) => {
+                let (old_type, new_type) = (old.get_type()?, new.get_type()?);
+                if !same_type(old_type, new_type)? {
+                    self.push(
+                        path,
+                        IncompatibilityKind::ChangedFieldType,
+                        format!(
+                            "field `{name}` of `{struct_path}` changed its type from `{}` to `{}`",
+                            self.old.type_name(old_type)?,
+                            self.new.type_name(new_type)?
+                        ),
+                    );
+                }
+                if old.get_offset() != new.get_offset() {
+                    self.push(
+                        path,
+                        IncompatibilityKind::ChangedFieldOffset,
+                        format!(
+                            "field `{name}` of `{struct_path}` moved from offset {} to offset {}",
+                            old.get_offset(),
+                            new.get_offset()
+                        ),
+                    );
+                }
+            }
+            // The groups themselves are compared as nodes.
+            (field::Group(_), field::Group(_)) => (),
+            _ => self.push(
+                path,
+                IncompatibilityKind::ChangedFieldType,
+                format!("field `{name}` of `{struct_path}` changed between a group and a slot"),
+            ),
+        }
+        Ok(())
+    }
+
+    fn enum_(
+        &mut self,
+        path: &str,
+        old: node::enum_::Reader,
+        new: node::enum_::Reader,
+    ) -> ::capnp::Result<()> {
+        let old_enumerants = old.get_enumerants()?;
+        let new_enumerants = new.get_enumerants()?;
+        for value in new_enumerants.len()..old_enumerants.len() {
+            let name = old_enumerants.get(value).get_name()?.to_str()?;
+            self.push(
+                &format!("{path}.{name}"),
+                IncompatibilityKind::RemovedEnumerant,
+                format!("enumerant `{name}` ({value}) of `{path}` was removed"),
+            );
+        }
+        for value in old_enumerants.len()..new_enumerants.len() {
+            let name = new_enumerants.get(value).get_name()?.to_str()?;
+            self.push(
+                &format!("{path}.{name}"),
+                IncompatibilityKind::AddedEnumerant,
+                format!("enumerant `{name}` ({value}) was added to `{path}`"),
+            );
+        }
+        Ok(())
+    }
+
+    fn interface(
+        &mut self,
+        path: &str,
+        old: node::interface::Reader,
+        new: node::interface::Reader,
+    ) -> ::capnp::Result<()> {
+        let old_methods = old.get_methods()?;
+        let new_methods = new.get_methods()?;
+        let mut old_names = Vec::new();
+        for method in old_methods {
+            old_names.push(method.get_name()?.to_str()?);
+        }
+        let mut new_names = Vec::new();
+        for method in new_methods {
+            new_names.push(method.get_name()?.to_str()?);
+        }
+
+        for (ordinal, (old_method, name)) in old_methods.iter().zip(&old_names).enumerate() {
+            let method_path = format!("{path}.{name}");
+            let new_method = match new_names.iter().position(|new_name| new_name == name) {
+                Some(new_ordinal) if new_ordinal != ordinal => {
+                    self.push(
+                        &method_path,
+                        IncompatibilityKind::ChangedMethodOrdinal,
+                        format!(
+                            "method `{name}` of `{path}` moved from @{ordinal} to @{new_ordinal}"
+                        ),
+                    );
+                    continue;
+                }
+                Some(_) => new_methods.get(ordinal as u32),
+                // A method that was renamed keeps its ordinal.
+                None if ordinal < new_names.len() && !old_names.contains(&new_names[ordinal]) => {
+                    new_methods.get(ordinal as u32)
+                }
+                None => {
+                    self.push(
+                        &method_path,
+                        IncompatibilityKind::RemovedMethod,
+                        format!("method `{name}` of `{path}` was removed"),
+                    );
+                    continue;
+                }
+            };
+            for (what, old_id, new_id) in [
+                (
+                    "params",
+                    old_method.get_param_struct_type(),
+                    new_method.get_param_struct_type(),
+                ),
+                (
+                    "results",
+                    old_method.get_result_struct_type(),
+                    new_method.get_result_struct_type(),
+                ),
+            ] {
+                if old_id != new_id {
+                    self.push(
+                        &method_path,
+                        IncompatibilityKind::ChangedMethodType,
+                        format!(
+                            "method `{name}` of `{path}` changed its {what} from `{}` to `{}`",
+                            self.old.name(old_id),
+                            self.new.name(new_id)
+                        ),
+                    );
+                }
+            }
+        }
+        for (ordinal, name) in new_names.iter().enumerate().skip(old_names.len()) {
+            if !old_names.contains(name) {
+                self.push(
+                    &format!("{path}.{name}"),
+                    IncompatibilityKind::AddedMethod,
+                    format!("method `{name}` was added to `{path}` at @{ordinal}"),
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+fn node_kind(node: node::Reader) -> ::capnp::Result<&'static str> {
+    Ok(match node.which()? {
+        node::File(()) => "a file",
+        node::Struct(_) => "a struct",
+        node::Enum(_) => "an enum",
+        node::Interface(_) => "an interface",
+        node::Const(_) => "a constant",
+        node::Annotation(_) => "an annotation",
+    })
+}
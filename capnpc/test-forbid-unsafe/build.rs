@@ -0,0 +1,48 @@
+capnp_import::capnp_extract_bin!();
+
+fn main() {
+    let output_dir = commandhandle().unwrap();
+    let cmdpath = output_dir.path().join("capnp");
+    let out_dir =
+        std::path::PathBuf::from(std::env::var("OUT_DIR").expect("OUT_DIR env var is not set"));
+
+    capnpc::CompilerCommand::new()
+        .capnp_executable(&cmdpath)
+        .crate_provides("external_crate", [0xe6f94f52f7be8fe2])
+        .file("../test/test.capnp")
+        .file("../test/in-submodule.capnp")
+        .file("../test/in-other-submodule.capnp")
+        .file("../test/test-recursion.capnp")
+        .file("../test/test-upgrade-v1.capnp")
+        .file("../test/test-upgrade-v2.capnp")
+        .file("../test/test-reserved-names.capnp")
+        .src_prefix("../test/")
+        .import_path("..")
+        .run()
+        .expect("compiling schema");
+
+    // The options that change what gets emitted.
+    capnpc::CompilerCommand::new()
+        .capnp_executable(&cmdpath)
+        .file("../test/test-lenient-enums.capnp")
+        .file("../test/test-canonical-nans.capnp")
+        .file("../test/test-no-convenience-builders.capnp")
+        .src_prefix("../test/")
+        .import_path("..")
+        .output_path(out_dir.join("options"))
+        .lenient_enums(true)
+        .canonical_nans(true)
+        .generate_convenience_builders(false)
+        .run()
+        .expect("compiling schema");
+
+    capnpc::CompilerCommand::new()
+        .capnp_executable(&cmdpath)
+        .file("../test/test-split.capnp")
+        .src_prefix("../test/")
+        .import_path("..")
+        .output_path(out_dir.join("split"))
+        .split_output(0)
+        .run()
+        .expect("compiling schema");
+}
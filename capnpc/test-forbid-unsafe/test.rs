@@ -0,0 +1,63 @@
+//! Builds the generated code for the test schemas in a crate that forbids `unsafe`, so that
+//! any `unsafe` the code generator starts to emit fails the build.
+
+#![forbid(unsafe_code)]
+
+pub mod test_capnp {
+    include!(concat!(env!("OUT_DIR"), "/test_capnp.rs"));
+}
+
+pub mod foo {
+    pub mod bar {
+        pub mod in_submodule_capnp {
+            include!(concat!(env!("OUT_DIR"), "/in_submodule_capnp.rs"));
+        }
+    }
+}
+
+pub mod baz {
+    pub mod in_other_submodule_capnp {
+        include!(concat!(env!("OUT_DIR"), "/in_other_submodule_capnp.rs"));
+    }
+}
+
+pub mod test_recursion_capnp {
+    include!(concat!(env!("OUT_DIR"), "/test_recursion_capnp.rs"));
+}
+
+pub mod test_upgrade_v1_capnp {
+    include!(concat!(env!("OUT_DIR"), "/test_upgrade_v1_capnp.rs"));
+}
+
+pub mod test_upgrade_v2_capnp {
+    include!(concat!(env!("OUT_DIR"), "/test_upgrade_v2_capnp.rs"));
+}
+
+pub mod test_reserved_names_capnp {
+    include!(concat!(env!("OUT_DIR"), "/test_reserved_names_capnp.rs"));
+}
+
+pub mod test_lenient_enums_capnp {
+    include!(concat!(
+        env!("OUT_DIR"),
+        "/options/test_lenient_enums_capnp.rs"
+    ));
+}
+
+pub mod test_canonical_nans_capnp {
+    include!(concat!(
+        env!("OUT_DIR"),
+        "/options/test_canonical_nans_capnp.rs"
+    ));
+}
+
+pub mod test_no_convenience_builders_capnp {
+    include!(concat!(
+        env!("OUT_DIR"),
+        "/options/test_no_convenience_builders_capnp.rs"
+    ));
+}
+
+pub mod test_split_capnp {
+    include!(concat!(env!("OUT_DIR"), "/split/test_split_capnp.rs"));
+}
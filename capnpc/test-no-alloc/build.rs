@@ -0,0 +1,13 @@
+capnp_import::capnp_extract_bin!();
+
+fn main() {
+    let output_dir = commandhandle().unwrap();
+    let cmdpath = output_dir.path().join("capnp");
+
+    capnpc::CompilerCommand::new()
+        .capnp_executable(cmdpath)
+        .no_alloc(true)
+        .file("test-no-alloc.capnp")
+        .run()
+        .expect("compiling schema");
+}
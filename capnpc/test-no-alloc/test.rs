@@ -0,0 +1,109 @@
+//! Compile test for code generated with `no_alloc(true)`.
+//!
+//! The library itself is built as `#![no_std]` against a `capstone` without its
+//! `alloc` feature, so any generated item that reaches for `Vec`, `Box` or
+//! `String` fails the build. Build it on its own (`cargo build -p capnpc-test-no-alloc`),
+//! since a workspace-wide build unifies `capstone`'s features with the other members.
+
+#![cfg_attr(not(test), no_std)]
+
+pub mod test_no_alloc_capnp {
+    include!(concat!(env!("OUT_DIR"), "/test_no_alloc_capnp.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_no_alloc_capnp::{frame, pair, reading, Mode};
+    use capnp::message::{self, SingleSegmentAllocator};
+    use capnp::{text, Word};
+
+    // The elements of `readings` and the value of `latest` are not covered by the frame's
+    // own counts, so their variable-size fields are added separately.
+    const FRAME_WORDS: usize = frame::total_words_for(frame::FieldCounts {
+        readings: 2,
+        samples: 3,
+        latest: pair::MIN_SIZE_WORDS - 1 + (6 + 8) / 8 + reading::MIN_SIZE_WORDS - 1,
+        ..frame::FieldCounts::new()
+    }) + reading::variable_words_for(reading::FieldCounts {
+        tag: 5,
+        ..reading::FieldCounts::new()
+    });
+
+    #[test]
+    fn build_and_read_frame() {
+        let mut buffer = [capnp::word(0, 0, 0, 0, 0, 0, 0, 0); FRAME_WORDS];
+        let mut message = message::Builder::new(SingleSegmentAllocator::new(
+            Word::words_to_bytes_mut(&mut buffer),
+        ));
+        {
+            let mut frame = message.init_root::<frame::Builder<'_>>();
+            frame.set_sequence(42);
+            {
+                let mut readings = frame.reborrow().init_readings(2);
+                let mut first = readings.reborrow().get(0);
+                first.set_sensor(3);
+                first.set_value(0.25);
+                first.set_tag("first".into());
+                first.set_error_code(17);
+                let mut second = readings.get(1);
+                second.set_mode(Mode::Fault);
+                second.set_ok(());
+                second.reborrow().get_calibration().set_offset(9);
+            }
+            {
+                let mut samples = frame.reborrow().init_samples(3);
+                samples.set(0, -1);
+                samples.set(1, 0);
+                samples.set(2, 1);
+            }
+            {
+                let mut latest = frame.reborrow().init_latest();
+                latest.set_key("latest".into()).unwrap();
+                latest.init_value().set_sensor(11);
+            }
+        }
+
+        let frame = message.get_root_as_reader::<frame::Reader<'_>>().unwrap();
+        assert_eq!(frame.get_sequence(), 42);
+        let readings = frame.get_readings().unwrap();
+        assert_eq!(readings.len(), 2);
+        let first = readings.get(0);
+        assert_eq!(first.get_sensor(), 3);
+        assert_eq!(first.get_value(), 0.25);
+        assert_eq!(first.get_mode().unwrap(), Mode::Sampling);
+        assert_eq!(first.get_tag().unwrap(), "first");
+        assert!(matches!(
+            first.which().unwrap(),
+            reading::Which::ErrorCode(17)
+        ));
+        let calibration = first.get_calibration();
+        assert_eq!(calibration.get_offset(), -4);
+        assert_eq!(calibration.get_scale(), 1.5);
+        let second = readings.get(1);
+        assert_eq!(second.get_mode().unwrap(), Mode::Fault);
+        assert!(matches!(second.which().unwrap(), reading::Which::Ok(())));
+        assert_eq!(second.get_calibration().get_offset(), 9);
+
+        let samples = frame.get_samples().unwrap();
+        assert_eq!(samples.iter().collect::<std::vec::Vec<i16>>(), [-1, 0, 1]);
+
+        let latest: pair::Reader<'_, text::Owned, reading::Owned> = frame.get_latest().unwrap();
+        assert_eq!(latest.get_key().unwrap(), "latest");
+        assert_eq!(latest.get_value().unwrap().get_sensor(), 11);
+    }
+
+    #[test]
+    fn constants() {
+        use crate::test_no_alloc_capnp::{DEFAULT_READING, GREETING, MAX_SENSORS};
+
+        assert_eq!(MAX_SENSORS, 16);
+        assert_eq!(GREETING, "hello");
+        let reading = DEFAULT_READING.get().unwrap();
+        assert_eq!(reading.get_sensor(), 7);
+        assert_eq!(reading.get_tag().unwrap(), "boot");
+        assert!(matches!(
+            reading.which().unwrap(),
+            reading::Which::ErrorCode(3)
+        ));
+    }
+}
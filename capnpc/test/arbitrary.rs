@@ -0,0 +1,123 @@
+use crate::test_capnp::{
+    test_all_types, test_big_struct, test_complex_list, test_groups, test_lists, test_union,
+};
+use capnp::message::arbitrary::{fill_arbitrary, ArbitraryConfig};
+use capnp::message::{self, ReaderOptions};
+use capnp::{dynamic_struct, dynamic_value, serialize, Word};
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+
+fn arbitrary_message<T>(
+    seed: u64,
+    config: &ArbitraryConfig,
+) -> message::Builder<message::HeapAllocator>
+where
+    T: capnp::traits::Owned,
+    for<'a> T::Builder<'a>: Into<dynamic_value::Builder<'a>>,
+{
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let mut message = message::Builder::new_default();
+    fill_arbitrary::<T>(message.init_root(), &mut rng, config).unwrap();
+    message
+}
+
+fn describe<'a, T>(root: T::Reader<'a>) -> String
+where
+    T: capnp::traits::Owned,
+    T::Reader<'a>: Into<dynamic_value::Reader<'a>>,
+{
+    let root: dynamic_value::Reader<'a> = root.into();
+    format!("{:?}", root.downcast::<dynamic_struct::Reader<'a>>())
+}
+
+// Builds arbitrary messages, serializes and rereads them, and checks that the reread
+// message has the same content and canonical form as the original.
+fn check_round_trips<T>()
+where
+    T: capnp::traits::Owned,
+    for<'a> T::Builder<'a>: Into<dynamic_value::Builder<'a>>,
+    for<'a> T::Reader<'a>: Into<dynamic_value::Reader<'a>>,
+{
+    let config = ArbitraryConfig::default();
+    for seed in 0..32 {
+        let message = arbitrary_message::<T>(seed, &config);
+        let original = describe::<T>(message.get_root_as_reader::<T::Reader<'_>>().unwrap());
+
+        let words = serialize::write_message_to_words(&message);
+        let reread =
+            serialize::read_message_from_flat_slice(&mut &words[..], ReaderOptions::new()).unwrap();
+        assert_eq!(
+            original,
+            describe::<T>(reread.get_root::<T::Reader<'_>>().unwrap()),
+            "seed {seed}"
+        );
+
+        let canonical = reread.canonicalize().unwrap();
+        let segments = &[Word::words_to_bytes(&canonical)];
+        let canonical_message =
+            message::Reader::new(message::SegmentArray::new(segments), ReaderOptions::new());
+        assert!(canonical_message.is_canonical().unwrap(), "seed {seed}");
+        assert_eq!(
+            canonical_message.canonicalize().unwrap(),
+            canonical,
+            "seed {seed}"
+        );
+        assert_eq!(
+            original,
+            describe::<T>(canonical_message.get_root::<T::Reader<'_>>().unwrap()),
+            "seed {seed}"
+        );
+    }
+}
+
+#[test]
+fn round_trip_all_types() {
+    check_round_trips::<test_all_types::Owned>();
+}
+
+#[test]
+fn round_trip_unions_and_groups() {
+    check_round_trips::<test_union::Owned>();
+    check_round_trips::<test_groups::Owned>();
+}
+
+#[test]
+fn round_trip_lists() {
+    check_round_trips::<test_lists::Owned>();
+    check_round_trips::<test_complex_list::Owned>();
+    check_round_trips::<test_big_struct::Owned>();
+}
+
+#[test]
+fn same_seed_same_message() {
+    let config = ArbitraryConfig::default();
+    let first = arbitrary_message::<test_all_types::Owned>(7, &config);
+    let second = arbitrary_message::<test_all_types::Owned>(7, &config);
+    assert_eq!(
+        serialize::write_message_to_words(&first),
+        serialize::write_message_to_words(&second)
+    );
+}
+
+#[test]
+fn respects_config() {
+    let mut config = ArbitraryConfig::new();
+    config.max_depth(1).max_list_length(3).alphabet("xy");
+    for seed in 0..32 {
+        let message = arbitrary_message::<test_all_types::Owned>(seed, &config);
+        let root = message
+            .get_root_as_reader::<test_all_types::Reader<'_>>()
+            .unwrap();
+        let text = root.get_text_field().unwrap().to_str().unwrap();
+        assert!(text.len() <= 3 && text.chars().all(|c| c == 'x' || c == 'y'));
+        assert!(root.get_data_field().unwrap().len() <= 3);
+        assert!(root.get_struct_list().unwrap().len() <= 3);
+        assert!(root.get_enum_field().is_ok());
+
+        // The struct field sits at the depth limit, so its own pointers stay null.
+        let child = root.get_struct_field().unwrap();
+        assert!(!child.has_struct_field());
+        assert!(!child.has_int32_list());
+        assert!(child.get_text_field().unwrap().len() <= 3);
+    }
+}
@@ -10,6 +10,10 @@ fn main() {
         .file("test.capnp")
         .file("in-submodule.capnp")
         .file("in-other-submodule.capnp")
+        .file("test-recursion.capnp")
+        .file("test-upgrade-v1.capnp")
+        .file("test-upgrade-v2.capnp")
+        .file("test-reserved-names.capnp")
         .file("schema/test-in-dir.capnp")
         .file("schema-with-src-prefix/test-in-src-prefix-dir.capnp")
         .import_path("..")
@@ -46,6 +50,162 @@ fn main() {
         .run()
         .expect("compiling schema");
 
+    let mut output_path =
+        std::path::PathBuf::from(std::env::var("OUT_DIR").expect("OUT_DIR env var is not set"));
+    output_path.push("no-convenience-builders");
+    capnpc::CompilerCommand::new()
+        .capnp_executable(&cmdpath)
+        .file("test-no-convenience-builders.capnp")
+        .import_path("..")
+        .output_path(output_path)
+        .generate_convenience_builders(false)
+        .run()
+        .expect("compiling schema");
+
+    let mut output_path =
+        std::path::PathBuf::from(std::env::var("OUT_DIR").expect("OUT_DIR env var is not set"));
+    output_path.push("lenient-enums");
+    capnpc::CompilerCommand::new()
+        .capnp_executable(&cmdpath)
+        .file("test-lenient-enums.capnp")
+        .import_path("..")
+        .output_path(output_path)
+        .lenient_enums(true)
+        .run()
+        .expect("compiling schema");
+
+    let mut output_path =
+        std::path::PathBuf::from(std::env::var("OUT_DIR").expect("OUT_DIR env var is not set"));
+    output_path.push("canonical-nans");
+    capnpc::CompilerCommand::new()
+        .capnp_executable(&cmdpath)
+        .file("test-canonical-nans.capnp")
+        .import_path("..")
+        .output_path(output_path)
+        .canonical_nans(true)
+        .run()
+        .expect("compiling schema");
+
+    // The same schema, once with each accessor style.
+    for (module, style) in [
+        ("capnp_get", capnpc::codegen::AccessorStyle::CapnpGet),
+        ("rust_naming", capnpc::codegen::AccessorStyle::RustNaming),
+    ] {
+        let mut output_path =
+            std::path::PathBuf::from(std::env::var("OUT_DIR").expect("OUT_DIR env var is not set"));
+        output_path.push(module);
+        capnpc::CompilerCommand::new()
+            .capnp_executable(&cmdpath)
+            .file("test-accessor-style.capnp")
+            .file("test-reserved-names.capnp")
+            .import_path("..")
+            .output_path(output_path)
+            .default_parent_module(vec![module.into()])
+            .lenient_enums(true)
+            .accessor_style(style)
+            .run()
+            .expect("compiling schema");
+    }
+
+    // The same schema, once split into a file per top-level module and once not.
+    for (dir, threshold) in [("split", Some(0)), ("unsplit", None)] {
+        let mut output_path =
+            std::path::PathBuf::from(std::env::var("OUT_DIR").expect("OUT_DIR env var is not set"));
+        output_path.push(dir);
+        let mut command = capnpc::CompilerCommand::new();
+        command
+            .capnp_executable(&cmdpath)
+            .file("test-split.capnp")
+            .import_path("..")
+            .output_path(output_path);
+        if let Some(threshold) = threshold {
+            command.split_output(threshold);
+        }
+        command.run().expect("compiling schema");
+    }
+
+    // The same split schema, once gated by a predicate that holds and once by one that doesn't.
+    for (dir, module, cfg) in [
+        ("cfg-on", "cfg_on", "all()"),
+        ("cfg-off", "cfg_off", "any()"),
+    ] {
+        let mut output_path =
+            std::path::PathBuf::from(std::env::var("OUT_DIR").expect("OUT_DIR env var is not set"));
+        output_path.push(dir);
+        capnpc::CompilerCommand::new()
+            .capnp_executable(&cmdpath)
+            .file("test-split.capnp")
+            .import_path("..")
+            .output_path(output_path)
+            .default_parent_module(vec![module.into()])
+            .split_output(0)
+            .cfg_for_file("test-split.capnp", cfg)
+            .run()
+            .expect("compiling schema");
+    }
+
+    // Only the schema importing the annotations gets an output file.
+    let mut output_path =
+        std::path::PathBuf::from(std::env::var("OUT_DIR").expect("OUT_DIR env var is not set"));
+    output_path.push("skip-files");
+    capnpc::CompilerCommand::new()
+        .capnp_executable(&cmdpath)
+        .file("test-annotations-only.capnp")
+        .file("test-import-annotations.capnp")
+        .import_path("..")
+        .output_path(output_path)
+        .skip_files([0x8670b0e3f3c1b03a])
+        .run()
+        .expect("compiling schema");
+
+    let mut output_path =
+        std::path::PathBuf::from(std::env::var("OUT_DIR").expect("OUT_DIR env var is not set"));
+    output_path.push("aliases");
+    capnpc::CompilerCommand::new()
+        .capnp_executable(&cmdpath)
+        .crate_provides("external_crate", [0xe6f94f52f7be8fe2])
+        .file("test-aliases.capnp")
+        .import_path("..")
+        .output_path(output_path)
+        .reexport_aliases(true)
+        .run()
+        .expect("compiling schema");
+
+    // An extension that gives every struct a `field_count()`.
+    let mut output_path =
+        std::path::PathBuf::from(std::env::var("OUT_DIR").expect("OUT_DIR env var is not set"));
+    output_path.push("extensions");
+    let source = std::fs::read_to_string("test-split.capnp").expect("reading schema");
+    let request = capnpc::CompilerCommand::new()
+        .capnp_executable(&cmdpath)
+        .compile_from_memory(&[("test-split.capnp", &source)])
+        .expect("compiling schema");
+    capnpc::codegen::CodeGenerationCommand::new()
+        .output_directory(output_path)
+        .default_parent_module(vec!["extensions".into()])
+        .add_module_extension(|ctx, id| {
+            // Fails for enums and interfaces.
+            let Ok(fields) = ctx.get_fields(id) else {
+                return Ok(None);
+            };
+            Ok(Some(format!(
+                "pub fn field_count() -> u32 {{\n  {}\n}}",
+                fields.len()
+            )))
+        })
+        .run(request.as_slice())
+        .expect("generating code");
+
+    // Keep a copy of the compiler around for tests that invoke it at runtime.
+    let mut runtime_capnp =
+        std::path::PathBuf::from(std::env::var("OUT_DIR").expect("OUT_DIR env var is not set"));
+    runtime_capnp.push("capnp");
+    std::fs::copy(&cmdpath, &runtime_capnp).expect("copying capnp executable");
+    println!(
+        "cargo:rustc-env=CAPNP_EXECUTABLE={}",
+        runtime_capnp.display()
+    );
+
     // Have to do this test last
     std::env::remove_var("OUT_DIR");
     let error = capnpc::CompilerCommand::new()
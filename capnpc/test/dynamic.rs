@@ -189,6 +189,29 @@ fn test_generic_annotation() -> ::capnp::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_child_annotations() {
+    use crate::test_capnp::{test_child_annotations, test_weight, TestEnumerantAnnotations};
+
+    assert_eq!(
+        test_child_annotations::FIELD_ANNOTATIONS,
+        &[(1, test_weight::ID)]
+    );
+    let weight = test_child_annotations::field_annotation(1, test_weight::ID).unwrap();
+    assert_eq!(7u32, weight.downcast::<u32>());
+    assert!(test_child_annotations::field_annotation(0, test_weight::ID).is_none());
+    assert!(test_child_annotations::field_annotation(1, test_weight::ID + 1).is_none());
+    assert!(test_child_annotations::field_annotation(2, test_weight::ID).is_none());
+
+    assert_eq!(
+        TestEnumerantAnnotations::ENUMERANT_ANNOTATIONS,
+        &[(1, test_weight::ID)]
+    );
+    let weight = TestEnumerantAnnotations::enumerant_annotation(1, test_weight::ID).unwrap();
+    assert_eq!(42u32, weight.downcast::<u32>());
+    assert!(TestEnumerantAnnotations::enumerant_annotation(0, test_weight::ID).is_none());
+}
+
 #[test]
 fn test_complex_list() {
     use crate::test_capnp::test_complex_list;
@@ -246,3 +269,632 @@ fn test_stringify() {
     let stringified = format!("{:?}", root.into_reader());
     assert_eq!(stringified, "(voidField = (), boolField = false, int8Field = 3, int16Field = 0, int32Field = 0, int64Field = 0, uInt8Field = 0, uInt16Field = 0, uInt32Field = 0, uInt64Field = 0, float32Field = 0, float64Field = 0, textField = \"hello world\", dataField = 0x\"01020304057fff\", structField = (voidField = (), boolField = false, int8Field = 0, int16Field = 0, int32Field = 0, int64Field = 0, uInt8Field = 0, uInt16Field = 0, uInt32Field = 123456, uInt64Field = 0, float32Field = 0, float64Field = 0, enumField = foo), enumField = bar, boolList = [false, true])");
 }
+
+#[test]
+fn layout_constants() {
+    use crate::test_capnp::{test_groups, test_union};
+    use capnp::introspect::{Introspect, TypeVariant};
+    use capnp::schema::StructSchema;
+    use capnp::schema_capnp::{field, node};
+
+    fn schema_of<T: Introspect>() -> StructSchema {
+        match T::introspect().which() {
+            TypeVariant::Struct(raw) => StructSchema::new(raw),
+            _ => panic!("expected a struct"),
+        }
+    }
+
+    // Returns the field's discriminant value and its offset, in units of the field's size.
+    fn slot(schema: StructSchema, name: &str) -> (u16, usize) {
+        let field = schema.get_field_by_name(name).unwrap().get_proto();
+        match field.which().unwrap() {
+            field::Slot(slot) => (field.get_discriminant_value(), slot.get_offset() as usize),
+            field::Group(_) => (field.get_discriminant_value(), 0),
+        }
+    }
+
+    let union1 = schema_of::<test_union::union1::Owned>();
+    {
+        use test_union::union1::_private::layout;
+        let node::Struct(st) = union1.get_proto().which().unwrap() else {
+            panic!("expected a struct");
+        };
+        assert_eq!(
+            layout::DISCRIMINANT_OFFSET,
+            st.get_discriminant_offset() as usize * 2
+        );
+
+        let (d, offset) = slot(union1, "u1f0s1");
+        assert_eq!(d, layout::FIELD_U1F0S1_DISCRIMINANT);
+        assert_eq!(offset, layout::FIELD_U1F0S1_BIT_OFFSET);
+
+        let (d, offset) = slot(union1, "u1f1s1");
+        assert_eq!(d, layout::FIELD_U1F1S1_DISCRIMINANT);
+        assert_eq!(offset, layout::FIELD_U1F1S1_BIT_OFFSET);
+
+        let (d, offset) = slot(union1, "u1f1s8");
+        assert_eq!(d, layout::FIELD_U1F1S8_DISCRIMINANT);
+        assert_eq!(offset, layout::FIELD_U1F1S8_OFFSET);
+
+        let (d, offset) = slot(union1, "u1f0s16");
+        assert_eq!(d, layout::FIELD_U1F0S16_DISCRIMINANT);
+        assert_eq!(offset * 2, layout::FIELD_U1F0S16_OFFSET);
+
+        let (d, offset) = slot(union1, "u1f1s32");
+        assert_eq!(d, layout::FIELD_U1F1S32_DISCRIMINANT);
+        assert_eq!(offset * 4, layout::FIELD_U1F1S32_OFFSET);
+
+        let (d, offset) = slot(union1, "u1f0s64");
+        assert_eq!(d, layout::FIELD_U1F0S64_DISCRIMINANT);
+        assert_eq!(offset * 8, layout::FIELD_U1F0S64_OFFSET);
+
+        let (d, offset) = slot(union1, "u1f1sp");
+        assert_eq!(d, layout::FIELD_U1F1SP_DISCRIMINANT);
+        assert_eq!(offset, layout::FIELD_U1F1SP_POINTER_INDEX);
+    }
+
+    let root = schema_of::<test_union::Owned>();
+    assert_eq!(
+        slot(root, "bit0").1,
+        test_union::_private::layout::FIELD_BIT0_BIT_OFFSET
+    );
+
+    let groups = schema_of::<test_groups::groups::Owned>();
+    {
+        use test_groups::groups::_private::layout;
+        assert_eq!(slot(groups, "foo").0, layout::FIELD_FOO_DISCRIMINANT);
+        assert_eq!(slot(groups, "bar").0, layout::FIELD_BAR_DISCRIMINANT);
+        assert_eq!(slot(groups, "baz").0, layout::FIELD_BAZ_DISCRIMINANT);
+    }
+}
+
+#[test]
+fn set_named_coerces_values() {
+    use crate::test_capnp::TestEnum;
+    use capnp::text;
+
+    let mut source = message::Builder::new_default();
+    {
+        let mut root: test_all_types::Builder<'_> = source.init_root();
+        root.set_u_int32_field(12345);
+        root.set_text_field("inner".into());
+    }
+    let source_root: dynamic_value::Reader<'_> = source
+        .get_root_as_reader::<test_all_types::Reader<'_>>()
+        .unwrap()
+        .into();
+
+    let mut message = message::Builder::new_default();
+    let root: test_all_types::Builder<'_> = message.init_root();
+    let root: dynamic_value::Builder<'_> = root.into();
+    let mut root = root.downcast::<dynamic_struct::Builder<'_>>();
+
+    root.set_named("int64Field", 7i8.into()).unwrap();
+    root.set_named("uInt16Field", 300i32.into()).unwrap();
+    root.set_named("int8Field", (-5i64).into()).unwrap();
+    root.set_named("float64Field", 1.5f32.into()).unwrap();
+    root.set_named("float32Field", 42u32.into()).unwrap();
+    root.set_named("textField", text::Reader::from("hello").into())
+        .unwrap();
+    root.set_named("enumField", 2u16.into()).unwrap();
+    root.set_named("structField", source_root).unwrap();
+    drop(source);
+
+    {
+        let reader = message
+            .get_root_as_reader::<test_all_types::Reader<'_>>()
+            .unwrap();
+        assert_eq!(reader.get_int64_field(), 7);
+        assert_eq!(reader.get_u_int16_field(), 300);
+        assert_eq!(reader.get_int8_field(), -5);
+        assert_eq!(reader.get_float64_field(), 1.5);
+        assert_eq!(reader.get_float32_field(), 42.0);
+        assert_eq!(reader.get_text_field().unwrap(), "hello");
+        assert_eq!(reader.get_enum_field().unwrap(), TestEnum::Baz);
+        let inner = reader.get_struct_field().unwrap();
+        assert_eq!(inner.get_u_int32_field(), 12345);
+        assert_eq!(inner.get_text_field().unwrap(), "inner");
+    }
+
+    let mut root = message.get_root::<test_all_types::Builder<'_>>().unwrap();
+    let root: dynamic_value::Builder<'_> = root.reborrow().into();
+    let mut root = root.downcast::<dynamic_struct::Builder<'_>>();
+    root.set_named("enumField", text::Reader::from("garply").into())
+        .unwrap();
+    assert_eq!(
+        root.reborrow_as_reader()
+            .get_named("enumField")
+            .unwrap()
+            .downcast::<dynamic_value::Enum>()
+            .get_value(),
+        TestEnum::Garply as u16
+    );
+
+    let err = root.set_named("uInt8Field", 256u16.into()).unwrap_err();
+    assert_eq!(err.kind, capnp::ErrorKind::ValueOutOfRange);
+    assert!(err.extra.contains("uInt8Field"), "{err}");
+
+    let err = root.set_named("uInt32Field", (-1i8).into()).unwrap_err();
+    assert_eq!(err.kind, capnp::ErrorKind::ValueOutOfRange);
+
+    let err = root.set_named("enumField", 8u16.into()).unwrap_err();
+    assert_eq!(err.kind, capnp::ErrorKind::ValueOutOfRange);
+
+    let err = root
+        .set_named("enumField", text::Reader::from("nope").into())
+        .unwrap_err();
+    assert_eq!(err.kind, capnp::ErrorKind::EnumerantNotFound);
+    assert!(err.extra.contains("nope"), "{err}");
+
+    let err = root.set_named("textField", 1i32.into()).unwrap_err();
+    assert_eq!(err.kind, capnp::ErrorKind::TypeMismatch);
+    assert!(err.extra.contains("textField"), "{err}");
+    assert!(err.extra.contains("Text"), "{err}");
+
+    let err = root.set_named("int32Field", 1.0f64.into()).unwrap_err();
+    assert_eq!(err.kind, capnp::ErrorKind::TypeMismatch);
+
+    let err = root
+        .set_named("float64Field", (1u64 << 60).into())
+        .unwrap_err();
+    assert_eq!(err.kind, capnp::ErrorKind::ValueOutOfRange);
+}
+
+fn root(message: &message::Builder<message::HeapAllocator>) -> dynamic_value::Reader<'_> {
+    message
+        .get_root_as_reader::<test_all_types::Reader<'_>>()
+        .unwrap()
+        .into()
+}
+
+fn canonical_hash(value: dynamic_value::Reader<'_>) -> u64 {
+    use std::hash::Hasher;
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    value.canonical_hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn value_equality() {
+    let mut static_message = message::Builder::new_default();
+    test_util::init_test_message(static_message.init_root());
+    // The same contents, with the pointers allocated in a different order.
+    let mut reordered_message = message::Builder::new_default();
+    {
+        let mut root: test_all_types::Builder<'_> = reordered_message.init_root();
+        root.reborrow().init_struct_list(3);
+        root.reborrow().init_struct_field();
+        root.set_text_field("foo".into());
+        test_util::init_test_message(root);
+    }
+    assert_ne!(
+        static_message.get_segments_for_output()[0],
+        reordered_message.get_segments_for_output()[0]
+    );
+
+    let expected = root(&static_message);
+    assert!(expected == root(&reordered_message));
+    assert_eq!(
+        canonical_hash(expected),
+        canonical_hash(root(&reordered_message))
+    );
+
+    let mut different = message::Builder::new_default();
+    {
+        let mut root: test_all_types::Builder<'_> = different.init_root();
+        test_util::init_test_message(root.reborrow());
+        root.get_struct_list()
+            .unwrap()
+            .get(2)
+            .set_text_field("x".into());
+    }
+    assert!(expected != root(&different));
+
+    // Values of different types never compare equal.
+    assert!(dynamic_value::Reader::from(1u8) != dynamic_value::Reader::from(1u16));
+    assert!(dynamic_value::Reader::from(f64::NAN) == dynamic_value::Reader::from(f64::NAN));
+    assert!(dynamic_value::Reader::from(0.0f32) != dynamic_value::Reader::from(-0.0f32));
+}
+
+#[test]
+fn value_equality_of_defaults() {
+    // Nested structs that only hold defaults equal absent ones.
+    let mut explicit = message::Builder::new_default();
+    {
+        let mut root: test_all_types::Builder<'_> = explicit.init_root();
+        root.set_int32_field(0);
+        root.set_text_field("".into());
+        let mut inner = root.init_struct_field().init_struct_field();
+        inner.set_enum_field(crate::test_capnp::TestEnum::Foo);
+        inner.init_struct_field().set_float64_field(0.0);
+    }
+    let mut empty = message::Builder::new_default();
+    empty.init_root::<test_all_types::Builder<'_>>();
+    assert!(root(&explicit) == root(&empty));
+    assert_eq!(
+        canonical_hash(root(&explicit)),
+        canonical_hash(root(&empty))
+    );
+
+    // A near miss: one deeply nested field is not the default.
+    let mut near_miss = message::Builder::new_default();
+    {
+        let root: test_all_types::Builder<'_> = near_miss.init_root();
+        let inner = root.init_struct_field().init_struct_field();
+        inner.init_struct_field().set_float64_field(0.5);
+    }
+    assert!(root(&near_miss) != root(&empty));
+    assert!(root(&near_miss) != root(&explicit));
+
+    // Empty lists equal absent ones, but not empty lists of another type.
+    let mut lists = message::Builder::new_default();
+    {
+        let mut root: test_all_types::Builder<'_> = lists.init_root();
+        root.reborrow().init_text_list(0);
+        root.init_data_list(0);
+    }
+    assert!(root(&lists) == root(&empty));
+    let lists = root(&lists).downcast::<dynamic_struct::Reader<'_>>();
+    assert!(lists.get_named("textList").unwrap() != lists.get_named("dataList").unwrap());
+}
+
+#[test]
+fn value_equality_respects_nesting_limit() {
+    let mut message = message::Builder::new_default();
+    {
+        let mut inner: test_all_types::Builder<'_> = message.init_root();
+        for _ in 0..10 {
+            inner = inner.init_struct_field();
+        }
+        inner.set_int32_field(1);
+    }
+    let words = capnp::serialize::write_message_to_words(&message);
+
+    let read = |nesting_limit| {
+        let mut options = message::ReaderOptions::new();
+        options.nesting_limit(nesting_limit);
+        capnp::serialize::read_message_from_flat_slice(&mut &words[..], options).unwrap()
+    };
+    let deep = read(64);
+    let shallow = read(5);
+    let deep: dynamic_value::Reader<'_> = deep
+        .get_root::<test_all_types::Reader<'_>>()
+        .unwrap()
+        .into();
+    let shallow: dynamic_value::Reader<'_> = shallow
+        .get_root::<test_all_types::Reader<'_>>()
+        .unwrap()
+        .into();
+    assert!(deep == deep);
+    assert!(shallow != shallow);
+    assert!(deep != shallow);
+}
+
+#[test]
+fn edit_union_by_name() {
+    use crate::test_capnp::{test_groups, test_union};
+
+    let mut message = message::Builder::new_default();
+    let root: test_groups::Builder<'_> = message.init_root();
+    let mut root = dynamic_value::Builder::from(root).downcast::<dynamic_struct::Builder<'_>>();
+    let mut groups = root
+        .reborrow()
+        .get_named("groups")
+        .unwrap()
+        .downcast::<dynamic_struct::Builder<'_>>();
+
+    // Initializing a group in the union selects it.
+    let mut baz = groups
+        .reborrow()
+        .init_named("baz")
+        .unwrap()
+        .downcast::<dynamic_struct::Builder<'_>>();
+    baz.set_named("corge", 7i32.into()).unwrap();
+    baz.set_named("grault", "abc".into()).unwrap();
+    assert!(baz.has_named("grault").unwrap());
+    assert!(!baz.has_named("garply").unwrap());
+    baz.clear_named("grault").unwrap();
+    assert!(!baz.has_named("grault").unwrap());
+    assert_eq!(
+        groups
+            .which()
+            .unwrap()
+            .unwrap()
+            .get_proto()
+            .get_name()
+            .unwrap(),
+        "baz"
+    );
+    {
+        let reader = message
+            .get_root_as_reader::<test_groups::Reader<'_>>()
+            .unwrap();
+        let test_groups::groups::Baz(baz) = reader.get_groups().which().unwrap() else {
+            panic!("expected baz");
+        };
+        assert_eq!(baz.get_corge(), 7);
+        assert!(!baz.has_grault());
+    }
+
+    // Clearing a union member selects it too, and `has` is false for inactive members.
+    let mut root =
+        dynamic_value::Builder::from(message.get_root::<test_groups::Builder<'_>>().unwrap())
+            .downcast::<dynamic_struct::Builder<'_>>();
+    let mut groups = root
+        .reborrow()
+        .get_named("groups")
+        .unwrap()
+        .downcast::<dynamic_struct::Builder<'_>>();
+    groups.clear_named("bar").unwrap();
+    assert!(groups.has_named("bar").unwrap());
+    assert!(!groups.has_named("baz").unwrap());
+
+    // Unknown names are errors.
+    assert_eq!(
+        groups.has_named("qux").unwrap_err().kind,
+        capnp::ErrorKind::FieldNotFound
+    );
+    assert_eq!(
+        groups.clear_named("qux").unwrap_err().kind,
+        capnp::ErrorKind::FieldNotFound
+    );
+    assert!(matches!(
+        groups.init_named("qux"),
+        Err(capnp::Error {
+            kind: capnp::ErrorKind::FieldNotFound,
+            ..
+        })
+    ));
+
+    {
+        let reader = message
+            .get_root_as_reader::<test_groups::Reader<'_>>()
+            .unwrap();
+        let test_groups::groups::Bar(bar) = reader.get_groups().which().unwrap() else {
+            panic!("expected bar");
+        };
+        assert_eq!(bar.get_corge(), 0);
+    }
+
+    // A pointer member of a union only counts as present while it is active.
+    let mut message = message::Builder::new_default();
+    let root: test_union::Builder<'_> = message.init_root();
+    let mut root = dynamic_value::Builder::from(root).downcast::<dynamic_struct::Builder<'_>>();
+    let mut union0 = root
+        .reborrow()
+        .get_named("union0")
+        .unwrap()
+        .downcast::<dynamic_struct::Builder<'_>>();
+    union0.set_named("u0f0sp", "text".into()).unwrap();
+    assert!(union0.has_named("u0f0sp").unwrap());
+    union0.clear_named("u0f1s32").unwrap();
+    assert!(!union0.has_named("u0f0sp").unwrap());
+    let reader = message
+        .get_root_as_reader::<test_union::Reader<'_>>()
+        .unwrap();
+    assert!(matches!(
+        reader.get_union0().which().unwrap(),
+        test_union::union0::U0f1s32(0)
+    ));
+}
+
+#[test]
+fn params_struct_through_dynamic() {
+    use crate::test_capnp::test_unnamed_union;
+    use crate::test_recursion_capnp::{forest, tree};
+
+    // Fill in a struct with a union by field name, as a JSON decoder would.
+    let mut message = message::Builder::new_default();
+    let root: test_unnamed_union::Builder<'_> = message.init_root();
+    let mut root = dynamic_value::Builder::from(root).downcast::<dynamic_struct::Builder<'_>>();
+    root.set_named("before", "before".into()).unwrap();
+    root.set_named("bar", 321u32.into()).unwrap();
+    root.set_named("after", "after".into()).unwrap();
+
+    let mut plain = test_unnamed_union::TestUnnamedUnion::try_from(root.into_reader()).unwrap();
+    assert_eq!(plain._before, "before");
+    assert!(matches!(
+        plain.uni,
+        test_unnamed_union::TestUnnamedUnionUnion::_Bar(321)
+    ));
+    plain._middle = 7;
+    plain.uni = test_unnamed_union::TestUnnamedUnionUnion::_Foo(5);
+
+    let mut copy = message::Builder::new_default();
+    let root: test_unnamed_union::Builder<'_> = copy.init_root();
+    plain
+        .to_dynamic(dynamic_value::Builder::from(root).downcast())
+        .unwrap();
+    let reader = copy
+        .get_root_as_reader::<test_unnamed_union::Reader<'_>>()
+        .unwrap();
+    let dynamic = dynamic_value::Reader::from(reader).downcast::<dynamic_struct::Reader<'_>>();
+    assert_eq!(
+        dynamic
+            .which()
+            .unwrap()
+            .unwrap()
+            .get_proto()
+            .get_name()
+            .unwrap(),
+        "foo"
+    );
+    assert_eq!(dynamic.get_named("foo").unwrap().downcast::<u16>(), 5);
+    assert_eq!(dynamic.get_named("middle").unwrap().downcast::<u16>(), 7);
+
+    // Nested structs, some of them unset.
+    let forest = forest::Forest {
+        _name: "root".into(),
+        _children: vec![forest::Forest {
+            _name: "child".into(),
+            _children: Vec::new(),
+            _tree: None,
+        }],
+        _tree: Some(tree::Tree {
+            _value: 5,
+            _left: Some(Box::new(tree::Tree {
+                _value: 6,
+                _left: None,
+                _right: None,
+            })),
+            _right: None,
+        }),
+    };
+    let mut message = message::Builder::new_default();
+    let root: forest::Builder<'_> = message.init_root();
+    forest
+        .to_dynamic(dynamic_value::Builder::from(root).downcast())
+        .unwrap();
+    let reader = message.get_root_as_reader::<forest::Reader<'_>>().unwrap();
+    let plain = forest::Forest::try_from(
+        dynamic_value::Reader::from(reader).downcast::<dynamic_struct::Reader<'_>>(),
+    )
+    .unwrap();
+    assert_eq!(plain._name, "root");
+    assert_eq!(plain._children[0]._name, "child");
+    assert!(plain._children[0]._tree.is_none());
+    let tree = plain._tree.unwrap();
+    assert_eq!(tree._value, 5);
+    assert_eq!(tree._left.unwrap()._value, 6);
+    assert!(tree._right.is_none());
+
+    // A struct of another type is rejected.
+    let reader = message.get_root_as_reader::<forest::Reader<'_>>().unwrap();
+    let error = test_unnamed_union::TestUnnamedUnion::try_from(
+        dynamic_value::Reader::from(reader).downcast::<dynamic_struct::Reader<'_>>(),
+    )
+    .err()
+    .unwrap();
+    assert_eq!(error.kind, capnp::ErrorKind::TypeMismatch);
+}
+
+#[test]
+fn schema_loader_across_crates() {
+    use crate::test_capnp::use_external_struct;
+    use capnp::schema::SchemaLoader;
+    use external_crate::external_capnp::opts;
+
+    let loader = SchemaLoader::new();
+    let id = loader
+        .add(&use_external_struct::_private::ENCODED_NODE)
+        .unwrap();
+    assert_eq!(id, use_external_struct::_private::TYPE_ID);
+    loader
+        .add(&use_external_struct::group::_private::ENCODED_NODE)
+        .unwrap();
+
+    // The field's type lives in another crate whose node was not added yet.
+    let schema = loader.get_struct(id).unwrap();
+    let error = loader.get_struct(opts::_private::TYPE_ID).err().unwrap();
+    assert!(error.extra.contains("has not been added"), "{error}");
+    loader.add(&opts::_private::ENCODED_NODE).unwrap();
+
+    let mut message = message::Builder::new_default();
+    let mut root = message
+        .init_root::<capnp::any_pointer::Builder<'_>>()
+        .init_dynamic(schema)
+        .unwrap();
+    let mut opts_builder = root
+        .reborrow()
+        .init_named("opts")
+        .unwrap()
+        .downcast::<dynamic_struct::Builder<'_>>();
+    opts_builder.set_named("field", "inner".into()).unwrap();
+    let mut list = root
+        .reborrow()
+        .initn_named("optsList", 2)
+        .unwrap()
+        .downcast::<dynamic_list::Builder<'_>>();
+    for i in 0..2 {
+        let mut element = list
+            .reborrow()
+            .get(i)
+            .unwrap()
+            .downcast::<dynamic_struct::Builder<'_>>();
+        element
+            .set_named("field", format!("element {i}").as_str().into())
+            .unwrap();
+    }
+    let mut group = root
+        .init_named("group")
+        .unwrap()
+        .downcast::<dynamic_struct::Builder<'_>>();
+    let mut group_opts = group
+        .reborrow()
+        .init_named("opts")
+        .unwrap()
+        .downcast::<dynamic_struct::Builder<'_>>();
+    group_opts.set_named("field", "grouped".into()).unwrap();
+
+    // The generated code of both crates agrees with what was written.
+    let reader = message
+        .get_root_as_reader::<use_external_struct::Reader<'_>>()
+        .unwrap();
+    assert_eq!(reader.get_opts().unwrap().get_field().unwrap(), "inner");
+    assert_eq!(
+        reader.get_opts_list().unwrap().get(1).get_field().unwrap(),
+        "element 1"
+    );
+    assert_eq!(
+        reader.get_group().get_opts().unwrap().get_field().unwrap(),
+        "grouped"
+    );
+
+    // And so does reading it back through the loader.
+    let root = message
+        .get_root_as_reader::<capnp::any_pointer::Reader<'_>>()
+        .unwrap()
+        .get_dynamic(schema)
+        .unwrap();
+    let opts_reader = root
+        .get_named("opts")
+        .unwrap()
+        .downcast::<dynamic_struct::Reader<'_>>();
+    assert_eq!(
+        opts_reader.get_schema().get_proto().get_id(),
+        opts::_private::TYPE_ID
+    );
+    let text = opts_reader
+        .get_named("field")
+        .unwrap()
+        .downcast::<capnp::text::Reader<'_>>();
+    assert_eq!(text, "inner");
+    let list = root
+        .get_named("optsList")
+        .unwrap()
+        .downcast::<dynamic_list::Reader<'_>>();
+    let element = list
+        .get(0)
+        .unwrap()
+        .downcast::<dynamic_struct::Reader<'_>>()
+        .get_named("field")
+        .unwrap()
+        .downcast::<capnp::text::Reader<'_>>();
+    assert_eq!(element, "element 0");
+}
+
+#[test]
+fn typed_dynamic_round_trip() {
+    use crate::test_capnp::test_defaults;
+
+    let mut source = message::Builder::new_default();
+    let mut root: test_all_types::Builder<'_> = source.init_root();
+    test_util::dynamic_init_test_message(root.as_dynamic_mut());
+    test_util::dynamic_check_test_message(root.reborrow_as_reader().as_dynamic());
+
+    let mut target = message::Builder::new_default();
+    let mut copy: test_all_types::Builder<'_> = target.init_root();
+    copy.copy_from_dynamic(root.reborrow_as_reader().as_dynamic())
+        .unwrap();
+    test_util::dynamic_check_test_message(copy.reborrow_as_reader().as_dynamic());
+
+    let mut other = message::Builder::new_default();
+    let mut mismatched: test_defaults::Builder<'_> = other.init_root();
+    let error = mismatched
+        .copy_from_dynamic(root.reborrow_as_reader().as_dynamic())
+        .unwrap_err();
+    assert_eq!(error.kind, capnp::ErrorKind::TypeMismatch);
+    assert!(error.extra.contains("test.capnp:TestAllTypes"), "{error}");
+    assert!(error.extra.contains("test.capnp:TestDefaults"), "{error}");
+    assert!(!mismatched.reborrow_as_reader().has_text_field());
+}
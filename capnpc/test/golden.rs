@@ -0,0 +1,88 @@
+//! Checks the code generated for the schemas that ship with the runtime crates against copies
+//! kept in `golden/`, so that changes to the code generator that should not change its output
+//! can be verified not to.
+//!
+//! After an intended change to the output, run the tests with `UPDATE_GOLDEN=1` set to rewrite
+//! the copies, and review the difference along with the change.
+
+use std::path::Path;
+
+// Generates code for `sources`, given as `(path, content)`, and compares each output file with
+// the one of the same name in `golden/`.
+fn check_golden(sources: &[(&str, &str)], capnp_root: &str) {
+    let request = capnpc::CompilerCommand::new()
+        .capnp_executable(env!("CAPNP_EXECUTABLE"))
+        .compile_from_memory(sources)
+        .unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    capnpc::codegen::CodeGenerationCommand::new()
+        .output_directory(dir.path())
+        .capnp_root(capnp_root)
+        .run(request.as_slice())
+        .unwrap();
+
+    let golden_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("golden");
+    let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+    let mut outputs = std::fs::read_dir(dir.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect::<Vec<_>>();
+    outputs.sort();
+    assert!(!outputs.is_empty());
+    for output in outputs {
+        let name = output.file_name().unwrap();
+        let generated = std::fs::read_to_string(&output).unwrap();
+        let golden_path = golden_dir.join(name);
+        if update {
+            std::fs::write(&golden_path, &generated).unwrap();
+            continue;
+        }
+        let golden = std::fs::read_to_string(&golden_path)
+            .unwrap_or_else(|error| panic!("reading `{}`: {error}", golden_path.display()));
+        if let Some((line_number, (generated_line, golden_line))) = generated
+            .lines()
+            .zip(golden.lines())
+            .enumerate()
+            .find(|(_, (generated_line, golden_line))| generated_line != golden_line)
+        {
+            panic!(
+                "`{}` differs from the generated code at line {}:\n  golden:    {golden_line}\n  generated: {generated_line}",
+                golden_path.display(),
+                line_number + 1,
+            );
+        }
+        assert_eq!(
+            generated,
+            golden,
+            "`{}` differs from the generated code in its length",
+            golden_path.display()
+        );
+    }
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn schema_capnp() {
+    check_golden(
+        &[("schema.capnp", include_str!("../../capnp/schema.capnp"))],
+        "crate",
+    );
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn rpc_capnp() {
+    check_golden(
+        &[
+            (
+                "rpc.capnp",
+                include_str!("../../capnp-rpc/schema/rpc.capnp"),
+            ),
+            (
+                "rpc-twoparty.capnp",
+                include_str!("../../capnp-rpc/schema/rpc-twoparty.capnp"),
+            ),
+        ],
+        "::capnp",
+    );
+}
@@ -0,0 +1,11442 @@
+// @generated by the capnpc-rust plugin to the Cap'n Proto schema compiler.
+// DO NOT EDIT.
+// source: rpc.capnp
+
+/// The ID of the schema file.
+pub const FILE_ID: u64 = 0xb312981b2552a250;
+/// The IDs of the file and of all nodes declared in it, in ascending order.
+pub static NODE_IDS: &[u64] = &[0x836a53ce789d4cd4, 0x8523ddc40b86b8b0, 0x91b79f1f808db032, 0x95bc14545813fbc1, 0x9a0e61223d96743b, 0x9c6a046bfbc1ac5a, 0x9e19b28d3db3573a, 0xad1a6c0d7dd07497, 0xb28c96e23f4cbd58, 0xb312981b2552a250, 0xbbc29655fa89086e, 0xd37007fde1f0027d, 0xd37d2eb2c2f80e63, 0xd4c9b56290554016, 0xd562b4df655bdd4d, 0xd625b7063acf691a, 0xd6c14f121d44f8dd, 0xd800b1d6cd6f1ca0, 0xdae8b0f61aab5f99, 0xe94ccf8031176ec4, 0xf316944415569081, 0xf964368b0fbd3711, 0xfbe1980490e001af];
+/// A hash of the canonical encodings of the nodes in `NODE_IDS`. Equal fingerprints mean
+/// equal schemas, regardless of formatting and comments.
+pub const SCHEMA_FINGERPRINT: u64 = 0xe5b798eb685accfc;
+
+pub mod message {
+
+impl Message {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+ match self {
+ Message::UNINITIALIZED => (),
+ Message::_Unimplemented(t) => t.build_capnp_struct(builder.reborrow().init_unimplemented())?,
+ Message::_Abort(t) => t.build_capnp_struct(builder.reborrow().init_abort())?,
+ Message::_Call(t) => t.build_capnp_struct(builder.reborrow().init_call())?,
+ Message::_Return(t) => t.build_capnp_struct(builder.reborrow().init_return())?,
+ Message::_Finish(t) => t.build_capnp_struct(builder.reborrow().init_finish())?,
+ Message::_Resolve(t) => t.build_capnp_struct(builder.reborrow().init_resolve())?,
+ Message::_Release(t) => t.build_capnp_struct(builder.reborrow().init_release())?,
+ Message::_ObsoleteSave(t) => builder.reborrow().init_obsolete_save().set_as_capability(t),
+ Message::_Bootstrap(t) => t.build_capnp_struct(builder.reborrow().init_bootstrap())?,
+ Message::_ObsoleteDelete(t) => builder.reborrow().init_obsolete_delete().set_as_capability(t),
+ Message::_Provide(t) => t.build_capnp_struct(builder.reborrow().init_provide())?,
+ Message::_Accept(t) => t.build_capnp_struct(builder.reborrow().init_accept())?,
+ Message::_Join(t) => t.build_capnp_struct(builder.reborrow().init_join())?,
+ Message::_Disembargo(t) => t.build_capnp_struct(builder.reborrow().init_disembargo())?,
+ }
+  ::core::result::Result::Ok(())
+}}
+pub enum Message {
+ UNINITIALIZED,
+ _Unimplemented(Box<crate::rpc_capnp::message::Message>),
+ _Abort(crate::rpc_capnp::exception::Exception),
+ _Call(crate::rpc_capnp::call::Call),
+ _Return(crate::rpc_capnp::return_::Return),
+ _Finish(crate::rpc_capnp::finish::Finish),
+ _Resolve(crate::rpc_capnp::resolve::Resolve),
+ _Release(crate::rpc_capnp::release::Release),
+ _ObsoleteSave(Box<dyn ::capnp::private::capability::ClientHook>),
+ _Bootstrap(crate::rpc_capnp::bootstrap::Bootstrap),
+ _ObsoleteDelete(Box<dyn ::capnp::private::capability::ClientHook>),
+ _Provide(crate::rpc_capnp::provide::Provide),
+ _Accept(crate::rpc_capnp::accept::Accept),
+ _Join(crate::rpc_capnp::join::Join),
+ _Disembargo(crate::rpc_capnp::disembargo::Disembargo),
+}
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Message  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(match reader.which()? {
+      Which::Unimplemented(value) => Message::_Unimplemented(Box::new(<crate::rpc_capnp::message::Message as ::core::convert::TryFrom<_>>::try_from(value?)?)),
+      Which::Abort(value) => Message::_Abort(<crate::rpc_capnp::exception::Exception as ::core::convert::TryFrom<_>>::try_from(value?)?),
+      Which::Call(value) => Message::_Call(<crate::rpc_capnp::call::Call as ::core::convert::TryFrom<_>>::try_from(value?)?),
+      Which::Return(value) => Message::_Return(<crate::rpc_capnp::return_::Return as ::core::convert::TryFrom<_>>::try_from(value?)?),
+      Which::Finish(value) => Message::_Finish(<crate::rpc_capnp::finish::Finish as ::core::convert::TryFrom<_>>::try_from(value?)?),
+      Which::Resolve(value) => Message::_Resolve(<crate::rpc_capnp::resolve::Resolve as ::core::convert::TryFrom<_>>::try_from(value?)?),
+      Which::Release(value) => Message::_Release(<crate::rpc_capnp::release::Release as ::core::convert::TryFrom<_>>::try_from(value?)?),
+      Which::ObsoleteSave(value) => Message::_ObsoleteSave(value.get_as_capability::<::capnp::capability::Client>()?.hook),
+      Which::Bootstrap(value) => Message::_Bootstrap(<crate::rpc_capnp::bootstrap::Bootstrap as ::core::convert::TryFrom<_>>::try_from(value?)?),
+      Which::ObsoleteDelete(value) => Message::_ObsoleteDelete(value.get_as_capability::<::capnp::capability::Client>()?.hook),
+      Which::Provide(value) => Message::_Provide(<crate::rpc_capnp::provide::Provide as ::core::convert::TryFrom<_>>::try_from(value?)?),
+      Which::Accept(value) => Message::_Accept(<crate::rpc_capnp::accept::Accept as ::core::convert::TryFrom<_>>::try_from(value?)?),
+      Which::Join(value) => Message::_Join(<crate::rpc_capnp::join::Join as ::core::convert::TryFrom<_>>::try_from(value?)?),
+      Which::Disembargo(value) => Message::_Disembargo(<crate::rpc_capnp::disembargo::Disembargo as ::core::convert::TryFrom<_>>::try_from(value?)?),
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for Message {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl Message {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  pub use self::Which::{Unimplemented,Abort,Call,Return,Finish,Resolve,Release,ObsoleteSave,Bootstrap,ObsoleteDelete,Provide,Accept,Join,Disembargo};
+
+  /// The members of the union, as `(discriminant, name, field index)` in discriminant order.
+  pub static UNION_MEMBERS: &[(u16, &str, u16)] = &[(0, "unimplemented", 0), (1, "abort", 1), (2, "call", 2), (3, "return", 3), (4, "finish", 4), (5, "resolve", 5), (6, "release", 6), (7, "obsoleteSave", 7), (8, "bootstrap", 8), (9, "obsoleteDelete", 9), (10, "provide", 10), (11, "accept", 11), (12, "join", 12), (13, "disembargo", 13)];
+  /// Returns the schema name of the union member with discriminant `d`.
+  pub fn discriminant_name(d: u16) -> ::core::option::Option<&'static str> {
+    UNION_MEMBERS.get(d as usize).map(|member| member.1)
+  }
+
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub unimplemented: usize,
+    pub abort: usize,
+    pub call: usize,
+    pub return_: usize,
+    pub finish: usize,
+    pub resolve: usize,
+    pub release: usize,
+    pub obsolete_save: usize,
+    pub bootstrap: usize,
+    pub obsolete_delete: usize,
+    pub provide: usize,
+    pub accept: usize,
+    pub join: usize,
+    pub disembargo: usize,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        unimplemented: crate::rpc_capnp::message::MIN_SIZE_WORDS - 1,
+        abort: crate::rpc_capnp::exception::MIN_SIZE_WORDS - 1,
+        call: crate::rpc_capnp::call::MIN_SIZE_WORDS - 1,
+        return_: crate::rpc_capnp::return_::MIN_SIZE_WORDS - 1,
+        finish: crate::rpc_capnp::finish::MIN_SIZE_WORDS - 1,
+        resolve: crate::rpc_capnp::resolve::MIN_SIZE_WORDS - 1,
+        release: crate::rpc_capnp::release::MIN_SIZE_WORDS - 1,
+        obsolete_save: 0,
+        bootstrap: crate::rpc_capnp::bootstrap::MIN_SIZE_WORDS - 1,
+        obsolete_delete: 0,
+        provide: crate::rpc_capnp::provide::MIN_SIZE_WORDS - 1,
+        accept: crate::rpc_capnp::accept::MIN_SIZE_WORDS - 1,
+        join: crate::rpc_capnp::join::MIN_SIZE_WORDS - 1,
+        disembargo: crate::rpc_capnp::disembargo::MIN_SIZE_WORDS - 1,
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    counts.unimplemented + counts.abort + counts.call + counts.return_ + counts.finish + counts.resolve + counts.release + counts.obsolete_save + counts.bootstrap + counts.obsolete_delete + counts.provide + counts.accept + counts.join + counts.disembargo
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 1 + 1 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn is_unimplemented(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 0
+    }
+    #[inline]
+    pub fn has_unimplemented(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 0 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn is_abort(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 1
+    }
+    #[inline]
+    pub fn has_abort(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 1 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn is_call(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 2
+    }
+    #[inline]
+    pub fn has_call(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 2 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn is_return(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 3
+    }
+    #[inline]
+    pub fn has_return(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 3 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn is_finish(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 4
+    }
+    #[inline]
+    pub fn has_finish(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 4 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn is_resolve(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 5
+    }
+    #[inline]
+    pub fn has_resolve(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 5 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn is_release(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 6
+    }
+    #[inline]
+    pub fn has_release(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 6 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn is_obsolete_save(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 7
+    }
+    #[inline]
+    pub fn has_obsolete_save(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 7 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn is_bootstrap(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 8
+    }
+    #[inline]
+    pub fn has_bootstrap(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 8 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn is_obsolete_delete(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 9
+    }
+    #[inline]
+    pub fn has_obsolete_delete(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 9 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn is_provide(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 10
+    }
+    #[inline]
+    pub fn has_provide(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 10 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn is_accept(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 11
+    }
+    #[inline]
+    pub fn has_accept(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 11 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn is_join(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 12
+    }
+    #[inline]
+    pub fn has_join(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 12 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn is_disembargo(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 13
+    }
+    #[inline]
+    pub fn has_disembargo(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 13 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn which(self) -> ::core::result::Result<WhichReader<'a,>, ::capnp::NotInSchema> {
+      match self.reader.get_data_field::<u16>(0) {
+        0 => {
+          ::core::result::Result::Ok(Which::Unimplemented(
+            ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        1 => {
+          ::core::result::Result::Ok(Which::Abort(
+            ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        2 => {
+          ::core::result::Result::Ok(Which::Call(
+            ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        3 => {
+          ::core::result::Result::Ok(Which::Return(
+            ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        4 => {
+          ::core::result::Result::Ok(Which::Finish(
+            ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        5 => {
+          ::core::result::Result::Ok(Which::Resolve(
+            ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        6 => {
+          ::core::result::Result::Ok(Which::Release(
+            ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        7 => {
+          ::core::result::Result::Ok(Which::ObsoleteSave(
+            ::capnp::any_pointer::Reader::new(self.reader.get_pointer_field(0))
+          ))
+        }
+        8 => {
+          ::core::result::Result::Ok(Which::Bootstrap(
+            ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        9 => {
+          ::core::result::Result::Ok(Which::ObsoleteDelete(
+            ::capnp::any_pointer::Reader::new(self.reader.get_pointer_field(0))
+          ))
+        }
+        10 => {
+          ::core::result::Result::Ok(Which::Provide(
+            ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        11 => {
+          ::core::result::Result::Ok(Which::Accept(
+            ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        12 => {
+          ::core::result::Result::Ok(Which::Join(
+            ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        13 => {
+          ::core::result::Result::Ok(Which::Disembargo(
+            ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        x => ::core::result::Result::Err(::capnp::NotInSchema(x))
+      }
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 1, pointers: 1 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn set_unimplemented(&mut self, value: crate::rpc_capnp::message::Reader<'_>) -> ::capnp::Result<()> {
+      self.builder.set_data_field::<u16>(0, 0);
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_unimplemented(self, ) -> crate::rpc_capnp::message::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 0);
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_unimplemented(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::message::Owned> {
+      if self.builder.get_data_field::<u16>(0) != 0 {
+        return ::capnp::Orphan::null();
+      }
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_unimplemented(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::message::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(0, 0);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_or_init_unimplemented(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::message::Builder<'_>> {
+      if self.builder.get_data_field::<u16>(0) != 0 || self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_unimplemented())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn is_unimplemented(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 0
+    }
+    #[inline]
+    pub fn has_unimplemented(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 0 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn set_abort(&mut self, value: crate::rpc_capnp::exception::Reader<'_>) -> ::capnp::Result<()> {
+      self.builder.set_data_field::<u16>(0, 1);
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_abort(self, ) -> crate::rpc_capnp::exception::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 1);
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_abort(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::exception::Owned> {
+      if self.builder.get_data_field::<u16>(0) != 1 {
+        return ::capnp::Orphan::null();
+      }
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_abort(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::exception::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(0, 1);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_or_init_abort(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::exception::Builder<'_>> {
+      if self.builder.get_data_field::<u16>(0) != 1 || self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_abort())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn is_abort(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 1
+    }
+    #[inline]
+    pub fn has_abort(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 1 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn set_call(&mut self, value: crate::rpc_capnp::call::Reader<'_>) -> ::capnp::Result<()> {
+      self.builder.set_data_field::<u16>(0, 2);
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_call(self, ) -> crate::rpc_capnp::call::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 2);
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_call(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::call::Owned> {
+      if self.builder.get_data_field::<u16>(0) != 2 {
+        return ::capnp::Orphan::null();
+      }
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_call(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::call::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(0, 2);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_or_init_call(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::call::Builder<'_>> {
+      if self.builder.get_data_field::<u16>(0) != 2 || self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_call())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn is_call(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 2
+    }
+    #[inline]
+    pub fn has_call(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 2 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn set_return(&mut self, value: crate::rpc_capnp::return_::Reader<'_>) -> ::capnp::Result<()> {
+      self.builder.set_data_field::<u16>(0, 3);
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_return(self, ) -> crate::rpc_capnp::return_::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 3);
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_return(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::return_::Owned> {
+      if self.builder.get_data_field::<u16>(0) != 3 {
+        return ::capnp::Orphan::null();
+      }
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_return(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::return_::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(0, 3);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_or_init_return(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::return_::Builder<'_>> {
+      if self.builder.get_data_field::<u16>(0) != 3 || self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_return())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn is_return(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 3
+    }
+    #[inline]
+    pub fn has_return(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 3 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn set_finish(&mut self, value: crate::rpc_capnp::finish::Reader<'_>) -> ::capnp::Result<()> {
+      self.builder.set_data_field::<u16>(0, 4);
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_finish(self, ) -> crate::rpc_capnp::finish::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 4);
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_finish(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::finish::Owned> {
+      if self.builder.get_data_field::<u16>(0) != 4 {
+        return ::capnp::Orphan::null();
+      }
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_finish(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::finish::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(0, 4);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_or_init_finish(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::finish::Builder<'_>> {
+      if self.builder.get_data_field::<u16>(0) != 4 || self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_finish())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn is_finish(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 4
+    }
+    #[inline]
+    pub fn has_finish(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 4 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn set_resolve(&mut self, value: crate::rpc_capnp::resolve::Reader<'_>) -> ::capnp::Result<()> {
+      self.builder.set_data_field::<u16>(0, 5);
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_resolve(self, ) -> crate::rpc_capnp::resolve::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 5);
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_resolve(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::resolve::Owned> {
+      if self.builder.get_data_field::<u16>(0) != 5 {
+        return ::capnp::Orphan::null();
+      }
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_resolve(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::resolve::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(0, 5);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_or_init_resolve(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::resolve::Builder<'_>> {
+      if self.builder.get_data_field::<u16>(0) != 5 || self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_resolve())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn is_resolve(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 5
+    }
+    #[inline]
+    pub fn has_resolve(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 5 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn set_release(&mut self, value: crate::rpc_capnp::release::Reader<'_>) -> ::capnp::Result<()> {
+      self.builder.set_data_field::<u16>(0, 6);
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_release(self, ) -> crate::rpc_capnp::release::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 6);
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_release(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::release::Owned> {
+      if self.builder.get_data_field::<u16>(0) != 6 {
+        return ::capnp::Orphan::null();
+      }
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_release(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::release::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(0, 6);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_or_init_release(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::release::Builder<'_>> {
+      if self.builder.get_data_field::<u16>(0) != 6 || self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_release())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn is_release(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 6
+    }
+    #[inline]
+    pub fn has_release(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 6 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn init_obsolete_save(self, ) -> ::capnp::any_pointer::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 7);
+      let mut result = ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(0));
+      result.clear();
+      result
+    }
+    #[inline]
+    pub fn disown_obsolete_save(&mut self) -> ::capnp::Orphan<::capnp::any_pointer::Owned> {
+      if self.builder.get_data_field::<u16>(0) != 7 {
+        return ::capnp::Orphan::null();
+      }
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_obsolete_save(&mut self, orphan: ::capnp::Orphan<::capnp::any_pointer::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(0, 7);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn is_obsolete_save(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 7
+    }
+    #[inline]
+    pub fn has_obsolete_save(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 7 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn set_bootstrap(&mut self, value: crate::rpc_capnp::bootstrap::Reader<'_>) -> ::capnp::Result<()> {
+      self.builder.set_data_field::<u16>(0, 8);
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_bootstrap(self, ) -> crate::rpc_capnp::bootstrap::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 8);
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_bootstrap(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::bootstrap::Owned> {
+      if self.builder.get_data_field::<u16>(0) != 8 {
+        return ::capnp::Orphan::null();
+      }
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_bootstrap(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::bootstrap::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(0, 8);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_or_init_bootstrap(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::bootstrap::Builder<'_>> {
+      if self.builder.get_data_field::<u16>(0) != 8 || self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_bootstrap())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn is_bootstrap(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 8
+    }
+    #[inline]
+    pub fn has_bootstrap(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 8 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn init_obsolete_delete(self, ) -> ::capnp::any_pointer::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 9);
+      let mut result = ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(0));
+      result.clear();
+      result
+    }
+    #[inline]
+    pub fn disown_obsolete_delete(&mut self) -> ::capnp::Orphan<::capnp::any_pointer::Owned> {
+      if self.builder.get_data_field::<u16>(0) != 9 {
+        return ::capnp::Orphan::null();
+      }
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_obsolete_delete(&mut self, orphan: ::capnp::Orphan<::capnp::any_pointer::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(0, 9);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn is_obsolete_delete(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 9
+    }
+    #[inline]
+    pub fn has_obsolete_delete(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 9 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn set_provide(&mut self, value: crate::rpc_capnp::provide::Reader<'_>) -> ::capnp::Result<()> {
+      self.builder.set_data_field::<u16>(0, 10);
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_provide(self, ) -> crate::rpc_capnp::provide::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 10);
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_provide(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::provide::Owned> {
+      if self.builder.get_data_field::<u16>(0) != 10 {
+        return ::capnp::Orphan::null();
+      }
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_provide(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::provide::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(0, 10);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_or_init_provide(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::provide::Builder<'_>> {
+      if self.builder.get_data_field::<u16>(0) != 10 || self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_provide())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn is_provide(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 10
+    }
+    #[inline]
+    pub fn has_provide(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 10 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn set_accept(&mut self, value: crate::rpc_capnp::accept::Reader<'_>) -> ::capnp::Result<()> {
+      self.builder.set_data_field::<u16>(0, 11);
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_accept(self, ) -> crate::rpc_capnp::accept::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 11);
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_accept(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::accept::Owned> {
+      if self.builder.get_data_field::<u16>(0) != 11 {
+        return ::capnp::Orphan::null();
+      }
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_accept(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::accept::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(0, 11);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_or_init_accept(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::accept::Builder<'_>> {
+      if self.builder.get_data_field::<u16>(0) != 11 || self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_accept())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn is_accept(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 11
+    }
+    #[inline]
+    pub fn has_accept(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 11 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn set_join(&mut self, value: crate::rpc_capnp::join::Reader<'_>) -> ::capnp::Result<()> {
+      self.builder.set_data_field::<u16>(0, 12);
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_join(self, ) -> crate::rpc_capnp::join::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 12);
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_join(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::join::Owned> {
+      if self.builder.get_data_field::<u16>(0) != 12 {
+        return ::capnp::Orphan::null();
+      }
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_join(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::join::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(0, 12);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_or_init_join(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::join::Builder<'_>> {
+      if self.builder.get_data_field::<u16>(0) != 12 || self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_join())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn is_join(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 12
+    }
+    #[inline]
+    pub fn has_join(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 12 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn set_disembargo(&mut self, value: crate::rpc_capnp::disembargo::Reader<'_>) -> ::capnp::Result<()> {
+      self.builder.set_data_field::<u16>(0, 13);
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_disembargo(self, ) -> crate::rpc_capnp::disembargo::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 13);
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_disembargo(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::disembargo::Owned> {
+      if self.builder.get_data_field::<u16>(0) != 13 {
+        return ::capnp::Orphan::null();
+      }
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_disembargo(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::disembargo::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(0, 13);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_or_init_disembargo(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::disembargo::Builder<'_>> {
+      if self.builder.get_data_field::<u16>(0) != 13 || self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_disembargo())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn is_disembargo(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 13
+    }
+    #[inline]
+    pub fn has_disembargo(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 13 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn which(self) -> ::core::result::Result<WhichBuilder<'a,>, ::capnp::NotInSchema> {
+      match self.builder.get_data_field::<u16>(0) {
+        0 => {
+          ::core::result::Result::Ok(Which::Unimplemented(
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        1 => {
+          ::core::result::Result::Ok(Which::Abort(
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        2 => {
+          ::core::result::Result::Ok(Which::Call(
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        3 => {
+          ::core::result::Result::Ok(Which::Return(
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        4 => {
+          ::core::result::Result::Ok(Which::Finish(
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        5 => {
+          ::core::result::Result::Ok(Which::Resolve(
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        6 => {
+          ::core::result::Result::Ok(Which::Release(
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        7 => {
+          ::core::result::Result::Ok(Which::ObsoleteSave(
+            ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(0))
+          ))
+        }
+        8 => {
+          ::core::result::Result::Ok(Which::Bootstrap(
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        9 => {
+          ::core::result::Result::Ok(Which::ObsoleteDelete(
+            ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(0))
+          ))
+        }
+        10 => {
+          ::core::result::Result::Ok(Which::Provide(
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        11 => {
+          ::core::result::Result::Ok(Which::Accept(
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        12 => {
+          ::core::result::Result::Ok(Which::Join(
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        13 => {
+          ::core::result::Result::Ok(Which::Disembargo(
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        x => ::core::result::Result::Err(::capnp::NotInSchema(x))
+      }
+    }
+    /// Sets the union to the variant that is active in `other`, copied from there as stored.
+    /// The fields outside the union are left unchanged.
+    ///
+    /// Returns an `EnumValueOrUnionDiscriminantNotPresent` error without changing anything if
+    /// `other` has a variant that is not in the schema, for example one added by a newer version
+    /// of it. A union nested in a group of the active variant is handled the same way, but by
+    /// then the group has been partially copied.
+    pub fn copy_union_from(&mut self, other: Reader<'_,>) -> ::capnp::Result<()> {
+      match other.reader.get_data_field::<u16>(0) {
+        0 => {
+          self.builder.set_data_field::<u16>(0, 0);
+          ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        1 => {
+          self.builder.set_data_field::<u16>(0, 1);
+          ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        2 => {
+          self.builder.set_data_field::<u16>(0, 2);
+          ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        3 => {
+          self.builder.set_data_field::<u16>(0, 3);
+          ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        4 => {
+          self.builder.set_data_field::<u16>(0, 4);
+          ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        5 => {
+          self.builder.set_data_field::<u16>(0, 5);
+          ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        6 => {
+          self.builder.set_data_field::<u16>(0, 6);
+          ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        7 => {
+          self.builder.set_data_field::<u16>(0, 7);
+          ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        8 => {
+          self.builder.set_data_field::<u16>(0, 8);
+          ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        9 => {
+          self.builder.set_data_field::<u16>(0, 9);
+          ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        10 => {
+          self.builder.set_data_field::<u16>(0, 10);
+          ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        11 => {
+          self.builder.set_data_field::<u16>(0, 11);
+          ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        12 => {
+          self.builder.set_data_field::<u16>(0, 12);
+          ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        13 => {
+          self.builder.set_data_field::<u16>(0, 13);
+          ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        x => return ::core::result::Result::Err(::capnp::NotInSchema(x).into()),
+      }
+      ::core::result::Result::Ok(())
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 232] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(50, 176, 141, 128, 31, 159, 183, 145),
+      ::capnp::word(10, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(80, 162, 82, 37, 27, 152, 18, 179),
+      ::capnp::word(1, 0, 7, 0, 0, 0, 14, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 146, 0, 0, 0),
+      ::capnp::word(29, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(25, 0, 0, 0, 23, 3, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 46, 99, 97, 112, 110),
+      ::capnp::word(112, 58, 77, 101, 115, 115, 97, 103),
+      ::capnp::word(101, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(56, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 255, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(121, 1, 0, 0, 114, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(120, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(132, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(1, 0, 254, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(129, 1, 0, 0, 50, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(124, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(136, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(3, 0, 253, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 2, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(133, 1, 0, 0, 42, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(128, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(140, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(4, 0, 252, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 3, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(137, 1, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(132, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(144, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(5, 0, 251, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 4, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(141, 1, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(136, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(148, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(6, 0, 250, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 5, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(145, 1, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(140, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(152, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(7, 0, 249, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 6, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(149, 1, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(144, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(156, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(9, 0, 248, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(153, 1, 0, 0, 106, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(152, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(164, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(2, 0, 247, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 8, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(161, 1, 0, 0, 82, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(160, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(172, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(10, 0, 246, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 9, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(169, 1, 0, 0, 122, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(168, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(180, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(11, 0, 245, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 10, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(177, 1, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(172, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(184, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(12, 0, 244, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 11, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(181, 1, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(176, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(188, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(13, 0, 243, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 12, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(185, 1, 0, 0, 42, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(180, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(192, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(8, 0, 242, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 13, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(189, 1, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(188, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(200, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(117, 110, 105, 109, 112, 108, 101, 109),
+      ::capnp::word(101, 110, 116, 101, 100, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(50, 176, 141, 128, 31, 159, 183, 145),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(97, 98, 111, 114, 116, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(26, 105, 207, 58, 6, 183, 37, 214),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(99, 97, 108, 108, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(212, 76, 157, 120, 206, 83, 106, 131),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 101, 116, 117, 114, 110, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(58, 87, 179, 61, 141, 178, 25, 158),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(102, 105, 110, 105, 115, 104, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(99, 14, 248, 194, 178, 46, 125, 211),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 101, 115, 111, 108, 118, 101, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(110, 8, 137, 250, 85, 150, 194, 187),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 101, 108, 101, 97, 115, 101, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(151, 116, 208, 125, 13, 108, 26, 173),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(111, 98, 115, 111, 108, 101, 116, 101),
+      ::capnp::word(83, 97, 118, 101, 0, 0, 0, 0),
+      ::capnp::word(18, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(18, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(98, 111, 111, 116, 115, 116, 114, 97),
+      ::capnp::word(112, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(196, 110, 23, 49, 128, 207, 76, 233),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(111, 98, 115, 111, 108, 101, 116, 101),
+      ::capnp::word(68, 101, 108, 101, 116, 101, 0, 0),
+      ::capnp::word(18, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(18, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(112, 114, 111, 118, 105, 100, 101, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(90, 172, 193, 251, 107, 4, 106, 156),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(97, 99, 99, 101, 112, 116, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(22, 64, 85, 144, 98, 181, 201, 212),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(106, 111, 105, 110, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(175, 1, 224, 144, 4, 152, 225, 251),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 105, 115, 101, 109, 98, 97, 114),
+      ::capnp::word(103, 111, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(17, 55, 189, 15, 139, 54, 100, 249),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc.capnp:Message";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <crate::rpc_capnp::message::Owned as ::capnp::introspect::Introspect>::introspect(),
+        1 => <crate::rpc_capnp::exception::Owned as ::capnp::introspect::Introspect>::introspect(),
+        2 => <crate::rpc_capnp::call::Owned as ::capnp::introspect::Introspect>::introspect(),
+        3 => <crate::rpc_capnp::return_::Owned as ::capnp::introspect::Introspect>::introspect(),
+        4 => <crate::rpc_capnp::finish::Owned as ::capnp::introspect::Introspect>::introspect(),
+        5 => <crate::rpc_capnp::resolve::Owned as ::capnp::introspect::Introspect>::introspect(),
+        6 => <crate::rpc_capnp::release::Owned as ::capnp::introspect::Introspect>::introspect(),
+        7 => <::capnp::any_pointer::Owned as ::capnp::introspect::Introspect>::introspect(),
+        8 => <crate::rpc_capnp::bootstrap::Owned as ::capnp::introspect::Introspect>::introspect(),
+        9 => <::capnp::any_pointer::Owned as ::capnp::introspect::Introspect>::introspect(),
+        10 => <crate::rpc_capnp::provide::Owned as ::capnp::introspect::Introspect>::introspect(),
+        11 => <crate::rpc_capnp::accept::Owned as ::capnp::introspect::Introspect>::introspect(),
+        12 => <crate::rpc_capnp::join::Owned as ::capnp::introspect::Introspect>::introspect(),
+        13 => <crate::rpc_capnp::disembargo::Owned as ::capnp::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[0,1,2,3,4,5,6,7,8,9,10,11,12,13];
+    pub mod layout {
+      pub const DISCRIMINANT_OFFSET: usize = 0;
+      pub const FIELD_UNIMPLEMENTED_DISCRIMINANT: u16 = 0;
+      pub const FIELD_UNIMPLEMENTED_POINTER_INDEX: usize = 0;
+      pub const FIELD_ABORT_DISCRIMINANT: u16 = 1;
+      pub const FIELD_ABORT_POINTER_INDEX: usize = 0;
+      pub const FIELD_CALL_DISCRIMINANT: u16 = 2;
+      pub const FIELD_CALL_POINTER_INDEX: usize = 0;
+      pub const FIELD_RETURN_DISCRIMINANT: u16 = 3;
+      pub const FIELD_RETURN_POINTER_INDEX: usize = 0;
+      pub const FIELD_FINISH_DISCRIMINANT: u16 = 4;
+      pub const FIELD_FINISH_POINTER_INDEX: usize = 0;
+      pub const FIELD_RESOLVE_DISCRIMINANT: u16 = 5;
+      pub const FIELD_RESOLVE_POINTER_INDEX: usize = 0;
+      pub const FIELD_RELEASE_DISCRIMINANT: u16 = 6;
+      pub const FIELD_RELEASE_POINTER_INDEX: usize = 0;
+      pub const FIELD_OBSOLETE_SAVE_DISCRIMINANT: u16 = 7;
+      pub const FIELD_OBSOLETE_SAVE_POINTER_INDEX: usize = 0;
+      pub const FIELD_BOOTSTRAP_DISCRIMINANT: u16 = 8;
+      pub const FIELD_BOOTSTRAP_POINTER_INDEX: usize = 0;
+      pub const FIELD_OBSOLETE_DELETE_DISCRIMINANT: u16 = 9;
+      pub const FIELD_OBSOLETE_DELETE_POINTER_INDEX: usize = 0;
+      pub const FIELD_PROVIDE_DISCRIMINANT: u16 = 10;
+      pub const FIELD_PROVIDE_POINTER_INDEX: usize = 0;
+      pub const FIELD_ACCEPT_DISCRIMINANT: u16 = 11;
+      pub const FIELD_ACCEPT_POINTER_INDEX: usize = 0;
+      pub const FIELD_JOIN_DISCRIMINANT: u16 = 12;
+      pub const FIELD_JOIN_POINTER_INDEX: usize = 0;
+      pub const FIELD_DISEMBARGO_DISCRIMINANT: u16 = 13;
+      pub const FIELD_DISEMBARGO_POINTER_INDEX: usize = 0;
+    }
+    pub const TYPE_ID: u64 = 0x91b7_9f1f_808d_b032;
+  }
+  pub enum Which<A0,A1,A2,A3,A4,A5,A6,A7,A8,A9,A10,A11,A12,A13> {
+    Unimplemented(A0),
+    Abort(A1),
+    Call(A2),
+    Return(A3),
+    Finish(A4),
+    Resolve(A5),
+    Release(A6),
+    ObsoleteSave(A7),
+    Bootstrap(A8),
+    ObsoleteDelete(A9),
+    Provide(A10),
+    Accept(A11),
+    Join(A12),
+    Disembargo(A13),
+  }
+  pub type WhichReader<'a,> = Which<::capnp::Result<crate::rpc_capnp::message::Reader<'a>>,::capnp::Result<crate::rpc_capnp::exception::Reader<'a>>,::capnp::Result<crate::rpc_capnp::call::Reader<'a>>,::capnp::Result<crate::rpc_capnp::return_::Reader<'a>>,::capnp::Result<crate::rpc_capnp::finish::Reader<'a>>,::capnp::Result<crate::rpc_capnp::resolve::Reader<'a>>,::capnp::Result<crate::rpc_capnp::release::Reader<'a>>,::capnp::any_pointer::Reader<'a>,::capnp::Result<crate::rpc_capnp::bootstrap::Reader<'a>>,::capnp::any_pointer::Reader<'a>,::capnp::Result<crate::rpc_capnp::provide::Reader<'a>>,::capnp::Result<crate::rpc_capnp::accept::Reader<'a>>,::capnp::Result<crate::rpc_capnp::join::Reader<'a>>,::capnp::Result<crate::rpc_capnp::disembargo::Reader<'a>>>;
+  pub type WhichBuilder<'a,> = Which<::capnp::Result<crate::rpc_capnp::message::Builder<'a>>,::capnp::Result<crate::rpc_capnp::exception::Builder<'a>>,::capnp::Result<crate::rpc_capnp::call::Builder<'a>>,::capnp::Result<crate::rpc_capnp::return_::Builder<'a>>,::capnp::Result<crate::rpc_capnp::finish::Builder<'a>>,::capnp::Result<crate::rpc_capnp::resolve::Builder<'a>>,::capnp::Result<crate::rpc_capnp::release::Builder<'a>>,::capnp::any_pointer::Builder<'a>,::capnp::Result<crate::rpc_capnp::bootstrap::Builder<'a>>,::capnp::any_pointer::Builder<'a>,::capnp::Result<crate::rpc_capnp::provide::Builder<'a>>,::capnp::Result<crate::rpc_capnp::accept::Builder<'a>>,::capnp::Result<crate::rpc_capnp::join::Builder<'a>>,::capnp::Result<crate::rpc_capnp::disembargo::Builder<'a>>>;
+}
+
+pub mod bootstrap {
+pub struct Bootstrap {
+ pub _question_id: u32,
+ pub _deprecated_object_id: Box<dyn ::capnp::private::capability::ClientHook>,  
+}
+impl Bootstrap {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+  builder.set_question_id(self._question_id);
+  builder.reborrow().init_deprecated_object_id().set_as_capability(self._deprecated_object_id);
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Bootstrap  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _question_id: reader.get_question_id(),
+      _deprecated_object_id: reader.get_deprecated_object_id().get_as_capability::<::capnp::capability::Client>()?.hook,
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for Bootstrap {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl Bootstrap {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub deprecated_object_id: usize,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        deprecated_object_id: 0,
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    counts.deprecated_object_id
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 1 + 1 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_question_id(self) -> u32 {
+      self.reader.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn get_deprecated_object_id(self) -> ::capnp::any_pointer::Reader<'a> {
+      ::capnp::any_pointer::Reader::new(self.reader.get_pointer_field(0))
+    }
+    #[inline]
+    pub fn get_deprecated_object_id_as<_T: ::capnp::traits::Owned>(self) -> ::capnp::Result<_T::Reader<'a>> {
+      ::capnp::any_pointer::Reader::new(self.reader.get_pointer_field(0)).get_as_checked::<_T>()
+    }
+    #[inline]
+    pub fn has_deprecated_object_id(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 1, pointers: 1 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_question_id(self) -> u32 {
+      self.builder.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn set_question_id(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(0, value);
+    }
+    #[inline]
+    pub fn set_question_id_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_question_id(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_deprecated_object_id(self) -> ::capnp::any_pointer::Builder<'a> {
+      ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(0))
+    }
+    #[inline]
+    pub fn get_deprecated_object_id_as<_T: ::capnp::traits::Owned>(self) -> ::capnp::Result<_T::Builder<'a>> {
+      ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(0)).get_as_checked::<_T>()
+    }
+    #[inline]
+    pub fn init_deprecated_object_id(self, ) -> ::capnp::any_pointer::Builder<'a> {
+      let mut result = ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(0));
+      result.clear();
+      result
+    }
+    #[inline]
+    pub fn disown_deprecated_object_id(&mut self) -> ::capnp::Orphan<::capnp::any_pointer::Owned> {
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_deprecated_object_id(&mut self, orphan: ::capnp::Orphan<::capnp::any_pointer::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn has_deprecated_object_id(&self) -> bool {
+      !self.builder.is_pointer_field_null(0)
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+    #[inline]
+    pub fn get_deprecated_object_id(&self) -> ::capnp::any_pointer::Pipeline {
+      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+    }
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 50] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(196, 110, 23, 49, 128, 207, 76, 233),
+      ::capnp::word(10, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(80, 162, 82, 37, 27, 152, 18, 179),
+      ::capnp::word(1, 0, 7, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 162, 0, 0, 0),
+      ::capnp::word(29, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(25, 0, 0, 0, 119, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 46, 99, 97, 112, 110),
+      ::capnp::word(112, 58, 66, 111, 111, 116, 115, 116),
+      ::capnp::word(114, 97, 112, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(8, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(41, 0, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(40, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(52, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(49, 0, 0, 0, 154, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(52, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(64, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(113, 117, 101, 115, 116, 105, 111, 110),
+      ::capnp::word(73, 100, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 101, 112, 114, 101, 99, 97, 116),
+      ::capnp::word(101, 100, 79, 98, 106, 101, 99, 116),
+      ::capnp::word(73, 100, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(18, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(18, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc.capnp:Bootstrap";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        1 => <::capnp::any_pointer::Owned as ::capnp::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_QUESTION_ID_OFFSET: usize = 0;
+      pub const FIELD_DEPRECATED_OBJECT_ID_POINTER_INDEX: usize = 0;
+    }
+    pub const TYPE_ID: u64 = 0xe94c_cf80_3117_6ec4;
+  }
+}
+
+pub mod call {
+pub struct Call {
+ pub _question_id: u32,
+ pub _target: Option<crate::rpc_capnp::message_target::MessageTarget>,
+ pub _interface_id: u64,
+ pub _method_id: u16,
+ pub _params: Option<crate::rpc_capnp::payload::Payload>,
+ pub _send_results_to: crate::rpc_capnp::call::send_results_to::SendResultsTo,
+ pub _allow_third_party_tail_call: bool,
+ pub _no_promise_pipelining: bool,
+ pub _only_promise_pipeline: bool,  
+}
+impl Call {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+  builder.set_question_id(self._question_id);
+  if let Some(st) = self._target {st.build_capnp_struct(builder.reborrow().init_target())?;}
+  builder.set_interface_id(self._interface_id);
+  builder.set_method_id(self._method_id);
+  if let Some(st) = self._params {st.build_capnp_struct(builder.reborrow().init_params())?;}
+  self._send_results_to.build_capnp_struct(builder.reborrow().init_send_results_to())?;
+  builder.set_allow_third_party_tail_call(self._allow_third_party_tail_call);
+  builder.set_no_promise_pipelining(self._no_promise_pipelining);
+  builder.set_only_promise_pipeline(self._only_promise_pipeline);
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Call  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _question_id: reader.get_question_id(),
+      _target: if reader.has_target() { ::core::option::Option::Some(<crate::rpc_capnp::message_target::MessageTarget as ::core::convert::TryFrom<_>>::try_from(reader.get_target()?)?) } else { ::core::option::Option::None },
+      _interface_id: reader.get_interface_id(),
+      _method_id: reader.get_method_id(),
+      _params: if reader.has_params() { ::core::option::Option::Some(<crate::rpc_capnp::payload::Payload as ::core::convert::TryFrom<_>>::try_from(reader.get_params()?)?) } else { ::core::option::Option::None },
+      _send_results_to: <crate::rpc_capnp::call::send_results_to::SendResultsTo as ::core::convert::TryFrom<_>>::try_from(reader.get_send_results_to())?,
+      _allow_third_party_tail_call: reader.get_allow_third_party_tail_call(),
+      _no_promise_pipelining: reader.get_no_promise_pipelining(),
+      _only_promise_pipeline: reader.get_only_promise_pipeline(),
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for Call {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl Call {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub target: usize,
+    pub params: usize,
+    pub send_results_to: crate::rpc_capnp::call::send_results_to::FieldCounts,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        target: crate::rpc_capnp::message_target::MIN_SIZE_WORDS - 1,
+        params: crate::rpc_capnp::payload::MIN_SIZE_WORDS - 1,
+        send_results_to: crate::rpc_capnp::call::send_results_to::FieldCounts::new(),
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    counts.target + counts.params + crate::rpc_capnp::call::send_results_to::variable_words_for(counts.send_results_to)
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 3 + 3 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_question_id(self) -> u32 {
+      self.reader.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn get_target(self) -> ::capnp::Result<crate::rpc_capnp::message_target::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_target(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_interface_id(self) -> u64 {
+      self.reader.get_data_field::<u64>(1)
+    }
+    #[inline]
+    pub fn get_method_id(self) -> u16 {
+      self.reader.get_data_field::<u16>(2)
+    }
+    #[inline]
+    pub fn get_params(self) -> ::capnp::Result<crate::rpc_capnp::payload::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_params(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
+    }
+    #[inline]
+    pub fn get_send_results_to(self) -> crate::rpc_capnp::call::send_results_to::Reader<'a> {
+      self.reader.into()
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_send_results_to(&self) -> bool {
+      self.reader.get_data_field::<u16>(3) != 0
+        || !self.reader.is_pointer_field_null(2)
+    }
+    #[inline]
+    pub fn get_allow_third_party_tail_call(self) -> bool {
+      self.reader.get_bool_field(128)
+    }
+    #[inline]
+    pub fn get_no_promise_pipelining(self) -> bool {
+      self.reader.get_bool_field(129)
+    }
+    #[inline]
+    pub fn get_only_promise_pipeline(self) -> bool {
+      self.reader.get_bool_field(130)
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 3, pointers: 3 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_question_id(self) -> u32 {
+      self.builder.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn set_question_id(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(0, value);
+    }
+    #[inline]
+    pub fn set_question_id_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_question_id(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_target(self) -> ::capnp::Result<crate::rpc_capnp::message_target::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_target(&mut self, value: crate::rpc_capnp::message_target::Reader<'_>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_target(self, ) -> crate::rpc_capnp::message_target::Builder<'a> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_target(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::message_target::Owned> {
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_target(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::message_target::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_target(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::message_target::Builder<'_>> {
+      if self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_target())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_target(&self) -> bool {
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn get_interface_id(self) -> u64 {
+      self.builder.get_data_field::<u64>(1)
+    }
+    #[inline]
+    pub fn set_interface_id(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(1, value);
+    }
+    #[inline]
+    pub fn set_interface_id_from(&mut self, value: impl ::core::convert::TryInto<u64>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_interface_id(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_method_id(self) -> u16 {
+      self.builder.get_data_field::<u16>(2)
+    }
+    #[inline]
+    pub fn set_method_id(&mut self, value: u16)  {
+      self.builder.set_data_field::<u16>(2, value);
+    }
+    #[inline]
+    pub fn set_method_id_from(&mut self, value: impl ::core::convert::TryInto<u16>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_method_id(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_params(self) -> ::capnp::Result<crate::rpc_capnp::payload::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_params(&mut self, value: crate::rpc_capnp::payload::Reader<'_>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(1), value, false)
+    }
+    #[inline]
+    pub fn init_params(self, ) -> crate::rpc_capnp::payload::Builder<'a> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), 0)
+    }
+    #[inline]
+    pub fn disown_params(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::payload::Owned> {
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+    }
+    #[inline]
+    pub fn adopt_params(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::payload::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(1).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_params(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::payload::Builder<'_>> {
+      if self.builder.is_pointer_field_null(1) {
+        ::core::result::Result::Ok(self.reborrow().init_params())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(1), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_params(&self) -> bool {
+      !self.builder.is_pointer_field_null(1)
+    }
+    #[inline]
+    pub fn get_send_results_to(self) -> crate::rpc_capnp::call::send_results_to::Builder<'a> {
+      self.builder.into()
+    }
+    #[inline]
+    pub fn init_send_results_to(mut self, ) -> crate::rpc_capnp::call::send_results_to::Builder<'a> {
+      self.builder.set_data_field::<u16>(3, 0);
+      self.builder.reborrow().get_pointer_field(2).clear();
+      self.builder.into()
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_send_results_to(&self) -> bool {
+      self.builder.get_data_field::<u16>(3) != 0
+        || !self.builder.is_pointer_field_null(2)
+    }
+    #[inline]
+    pub fn get_allow_third_party_tail_call(self) -> bool {
+      self.builder.get_bool_field(128)
+    }
+    #[inline]
+    pub fn set_allow_third_party_tail_call(&mut self, value: bool)  {
+      self.builder.set_bool_field(128, value);
+    }
+    #[inline]
+    pub fn get_no_promise_pipelining(self) -> bool {
+      self.builder.get_bool_field(129)
+    }
+    #[inline]
+    pub fn set_no_promise_pipelining(&mut self, value: bool)  {
+      self.builder.set_bool_field(129, value);
+    }
+    #[inline]
+    pub fn get_only_promise_pipeline(self) -> bool {
+      self.builder.get_bool_field(130)
+    }
+    #[inline]
+    pub fn set_only_promise_pipeline(&mut self, value: bool)  {
+      self.builder.set_bool_field(130, value);
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+    #[inline]
+    pub fn get_target(&self) -> crate::rpc_capnp::message_target::Pipeline {
+      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+    }
+    #[inline]
+    pub fn get_params(&self) -> crate::rpc_capnp::payload::Pipeline {
+      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(1))
+    }
+    #[inline]
+    pub fn get_send_results_to(&self) -> crate::rpc_capnp::call::send_results_to::Pipeline {
+      ::capnp::capability::FromTypelessPipeline::new(self._typeless.noop())
+    }
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 154] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(212, 76, 157, 120, 206, 83, 106, 131),
+      ::capnp::word(10, 0, 0, 0, 1, 0, 3, 0),
+      ::capnp::word(80, 162, 82, 37, 27, 152, 18, 179),
+      ::capnp::word(3, 0, 7, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 122, 0, 0, 0),
+      ::capnp::word(25, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 255, 1, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 46, 99, 97, 112, 110),
+      ::capnp::word(112, 58, 67, 97, 108, 108, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(36, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(237, 0, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(236, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(248, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(245, 0, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(240, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(252, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(2, 0, 0, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 2, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(249, 0, 0, 0, 98, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(248, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(4, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(3, 0, 0, 0, 2, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 3, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(1, 1, 0, 0, 74, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(12, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(7, 0, 0, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 4, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 1, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(4, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(16, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(153, 95, 171, 26, 246, 176, 232, 218),
+      ::capnp::word(13, 1, 0, 0, 114, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(4, 0, 0, 0, 128, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 8, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(249, 0, 0, 0, 194, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(252, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(8, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(5, 0, 0, 0, 129, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 9, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(5, 1, 0, 0, 162, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(20, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(6, 0, 0, 0, 130, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 10, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(17, 1, 0, 0, 162, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(20, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(32, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(113, 117, 101, 115, 116, 105, 111, 110),
+      ::capnp::word(73, 100, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(116, 97, 114, 103, 101, 116, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(193, 251, 19, 88, 84, 20, 188, 149),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 110, 116, 101, 114, 102, 97, 99),
+      ::capnp::word(101, 73, 100, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(109, 101, 116, 104, 111, 100, 73, 100),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(7, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(7, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(112, 97, 114, 97, 109, 115, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(59, 116, 150, 61, 34, 97, 14, 154),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 101, 110, 100, 82, 101, 115, 117),
+      ::capnp::word(108, 116, 115, 84, 111, 0, 0, 0),
+      ::capnp::word(97, 108, 108, 111, 119, 84, 104, 105),
+      ::capnp::word(114, 100, 80, 97, 114, 116, 121, 84),
+      ::capnp::word(97, 105, 108, 67, 97, 108, 108, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(110, 111, 80, 114, 111, 109, 105, 115),
+      ::capnp::word(101, 80, 105, 112, 101, 108, 105, 110),
+      ::capnp::word(105, 110, 103, 0, 0, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(111, 110, 108, 121, 80, 114, 111, 109),
+      ::capnp::word(105, 115, 101, 80, 105, 112, 101, 108),
+      ::capnp::word(105, 110, 101, 0, 0, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc.capnp:Call";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        1 => <crate::rpc_capnp::message_target::Owned as ::capnp::introspect::Introspect>::introspect(),
+        2 => <u64 as ::capnp::introspect::Introspect>::introspect(),
+        3 => <u16 as ::capnp::introspect::Introspect>::introspect(),
+        4 => <crate::rpc_capnp::payload::Owned as ::capnp::introspect::Introspect>::introspect(),
+        5 => <crate::rpc_capnp::call::send_results_to::Owned as ::capnp::introspect::Introspect>::introspect(),
+        6 => <bool as ::capnp::introspect::Introspect>::introspect(),
+        7 => <bool as ::capnp::introspect::Introspect>::introspect(),
+        8 => <bool as ::capnp::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1,2,3,4,5,6,7,8];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_QUESTION_ID_OFFSET: usize = 0;
+      pub const FIELD_TARGET_POINTER_INDEX: usize = 0;
+      pub const FIELD_INTERFACE_ID_OFFSET: usize = 8;
+      pub const FIELD_METHOD_ID_OFFSET: usize = 4;
+      pub const FIELD_PARAMS_POINTER_INDEX: usize = 1;
+      pub const FIELD_ALLOW_THIRD_PARTY_TAIL_CALL_BIT_OFFSET: usize = 128;
+      pub const FIELD_NO_PROMISE_PIPELINING_BIT_OFFSET: usize = 129;
+      pub const FIELD_ONLY_PROMISE_PIPELINE_BIT_OFFSET: usize = 130;
+    }
+    pub const TYPE_ID: u64 = 0x836a_53ce_789d_4cd4;
+  }
+
+  pub mod send_results_to {
+  
+  impl SendResultsTo {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+ match self {
+ SendResultsTo::UNINITIALIZED => (),
+ SendResultsTo::_Caller(t) => builder.reborrow().set_caller(t),
+ SendResultsTo::_Yourself(t) => builder.reborrow().set_yourself(t),
+ SendResultsTo::_ThirdParty(t) => builder.reborrow().init_third_party().set_as_capability(t),
+ }
+  ::core::result::Result::Ok(())
+}}
+  pub enum SendResultsTo {
+ UNINITIALIZED,
+ _Caller(()),
+ _Yourself(()),
+ _ThirdParty(Box<dyn ::capnp::private::capability::ClientHook>),
+}
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for SendResultsTo  {
+    type Error = ::capnp::Error;
+    fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(match reader.which()? {
+        Which::Caller(value) => SendResultsTo::_Caller(value),
+        Which::Yourself(value) => SendResultsTo::_Yourself(value),
+        Which::ThirdParty(value) => SendResultsTo::_ThirdParty(value.get_as_capability::<::capnp::capability::Client>()?.hook),
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for SendResultsTo {
+    type Error = ::capnp::Error;
+    fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl SendResultsTo {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    pub use self::Which::{Caller,Yourself,ThirdParty};
+
+    /// The members of the union, as `(discriminant, name, field index)` in discriminant order.
+    pub static UNION_MEMBERS: &[(u16, &str, u16)] = &[(0, "caller", 0), (1, "yourself", 1), (2, "thirdParty", 2)];
+    /// Returns the schema name of the union member with discriminant `d`.
+    pub fn discriminant_name(d: u16) -> ::core::option::Option<&'static str> {
+      UNION_MEMBERS.get(d as usize).map(|member| member.1)
+    }
+
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+      pub third_party: usize,
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+          third_party: 0,
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(counts: FieldCounts) -> usize {
+      counts.third_party
+    }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+        self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn is_caller(&self) -> bool {
+        self.reader.get_data_field::<u16>(3) == 0
+      }
+      #[inline]
+      pub fn is_yourself(&self) -> bool {
+        self.reader.get_data_field::<u16>(3) == 1
+      }
+      #[inline]
+      pub fn is_third_party(&self) -> bool {
+        self.reader.get_data_field::<u16>(3) == 2
+      }
+      #[inline]
+      pub fn has_third_party(&self) -> bool {
+        if self.reader.get_data_field::<u16>(3) != 2 { return false; }
+        !self.reader.get_pointer_field(2).is_null()
+      }
+      #[inline]
+      pub fn which(self) -> ::core::result::Result<WhichReader<'a,>, ::capnp::NotInSchema> {
+        match self.reader.get_data_field::<u16>(3) {
+          0 => {
+            ::core::result::Result::Ok(Which::Caller(
+              ()
+            ))
+          }
+          1 => {
+            ::core::result::Result::Ok(Which::Yourself(
+              ()
+            ))
+          }
+          2 => {
+            ::core::result::Result::Ok(Which::ThirdParty(
+              ::capnp::any_pointer::Reader::new(self.reader.get_pointer_field(2))
+            ))
+          }
+          x => ::core::result::Result::Err(::capnp::NotInSchema(x))
+        }
+      }
+    }
+
+    pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+    impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 3, pointers: 3 };
+    }
+    impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+        self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn set_caller(&mut self, _value: ())  {
+        self.builder.set_data_field::<u16>(3, 0);
+      }
+      #[inline]
+      pub fn is_caller(&self) -> bool {
+        self.builder.get_data_field::<u16>(3) == 0
+      }
+      #[inline]
+      pub fn set_yourself(&mut self, _value: ())  {
+        self.builder.set_data_field::<u16>(3, 1);
+      }
+      #[inline]
+      pub fn is_yourself(&self) -> bool {
+        self.builder.get_data_field::<u16>(3) == 1
+      }
+      #[inline]
+      pub fn init_third_party(self, ) -> ::capnp::any_pointer::Builder<'a> {
+        self.builder.set_data_field::<u16>(3, 2);
+        let mut result = ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(2));
+        result.clear();
+        result
+      }
+      #[inline]
+      pub fn disown_third_party(&mut self) -> ::capnp::Orphan<::capnp::any_pointer::Owned> {
+        if self.builder.get_data_field::<u16>(3) != 2 {
+          return ::capnp::Orphan::null();
+        }
+        ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(2).disown())
+      }
+      #[inline]
+      pub fn adopt_third_party(&mut self, orphan: ::capnp::Orphan<::capnp::any_pointer::Owned>) -> ::capnp::Result<()> {
+        self.builder.reborrow().get_pointer_field(2).adopt(orphan.into_internal())?;
+        self.builder.set_data_field::<u16>(3, 2);
+        ::core::result::Result::Ok(())
+      }
+      #[inline]
+      pub fn is_third_party(&self) -> bool {
+        self.builder.get_data_field::<u16>(3) == 2
+      }
+      #[inline]
+      pub fn has_third_party(&self) -> bool {
+        if self.builder.get_data_field::<u16>(3) != 2 { return false; }
+        !self.builder.is_pointer_field_null(2)
+      }
+      #[inline]
+      pub fn which(self) -> ::core::result::Result<WhichBuilder<'a,>, ::capnp::NotInSchema> {
+        match self.builder.get_data_field::<u16>(3) {
+          0 => {
+            ::core::result::Result::Ok(Which::Caller(
+              ()
+            ))
+          }
+          1 => {
+            ::core::result::Result::Ok(Which::Yourself(
+              ()
+            ))
+          }
+          2 => {
+            ::core::result::Result::Ok(Which::ThirdParty(
+              ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(2))
+            ))
+          }
+          x => ::core::result::Result::Err(::capnp::NotInSchema(x))
+        }
+      }
+      /// Sets the union to the variant that is active in `other`, copied from there as stored.
+      /// The fields outside the union are left unchanged.
+      ///
+      /// Returns an `EnumValueOrUnionDiscriminantNotPresent` error without changing anything if
+      /// `other` has a variant that is not in the schema, for example one added by a newer version
+      /// of it. A union nested in a group of the active variant is handled the same way, but by
+      /// then the group has been partially copied.
+      pub fn copy_union_from(&mut self, other: Reader<'_,>) -> ::capnp::Result<()> {
+        match other.reader.get_data_field::<u16>(3) {
+          0 => {
+            self.builder.set_data_field::<u16>(3, 0);
+          }
+          1 => {
+            self.builder.set_data_field::<u16>(3, 1);
+          }
+          2 => {
+            self.builder.set_data_field::<u16>(3, 2);
+            ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(2), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(2)), false)?;
+          }
+          x => return ::core::result::Result::Err(::capnp::NotInSchema(x).into()),
+        }
+        ::core::result::Result::Ok(())
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+      let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [::capnp::Word; 64] = [
+        ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+        ::capnp::word(153, 95, 171, 26, 246, 176, 232, 218),
+        ::capnp::word(15, 0, 0, 0, 1, 0, 3, 0),
+        ::capnp::word(212, 76, 157, 120, 206, 83, 106, 131),
+        ::capnp::word(3, 0, 7, 0, 1, 0, 3, 0),
+        ::capnp::word(3, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(21, 0, 0, 0, 234, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(25, 0, 0, 0, 175, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(114, 112, 99, 46, 99, 97, 112, 110),
+        ::capnp::word(112, 58, 67, 97, 108, 108, 46, 115),
+        ::capnp::word(101, 110, 100, 82, 101, 115, 117, 108),
+        ::capnp::word(116, 115, 84, 111, 0, 0, 0, 0),
+        ::capnp::word(12, 0, 0, 0, 3, 0, 4, 0),
+        ::capnp::word(0, 0, 255, 255, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 1, 0, 5, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(69, 0, 0, 0, 58, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(64, 0, 0, 0, 3, 0, 1, 0),
+        ::capnp::word(76, 0, 0, 0, 2, 0, 1, 0),
+        ::capnp::word(1, 0, 254, 255, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 1, 0, 6, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(73, 0, 0, 0, 74, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(72, 0, 0, 0, 3, 0, 1, 0),
+        ::capnp::word(84, 0, 0, 0, 2, 0, 1, 0),
+        ::capnp::word(2, 0, 253, 255, 2, 0, 0, 0),
+        ::capnp::word(0, 0, 1, 0, 7, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(81, 0, 0, 0, 90, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(80, 0, 0, 0, 3, 0, 1, 0),
+        ::capnp::word(92, 0, 0, 0, 2, 0, 1, 0),
+        ::capnp::word(99, 97, 108, 108, 101, 114, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(121, 111, 117, 114, 115, 101, 108, 102),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(116, 104, 105, 114, 100, 80, 97, 114),
+        ::capnp::word(116, 121, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(18, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(18, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "rpc.capnp:Call.sendResultsTo";
+      pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        match index {
+          0 => <() as ::capnp::introspect::Introspect>::introspect(),
+          1 => <() as ::capnp::introspect::Introspect>::introspect(),
+          2 => <::capnp::any_pointer::Owned as ::capnp::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[0,1,2];
+      pub mod layout {
+        pub const DISCRIMINANT_OFFSET: usize = 6;
+        pub const FIELD_CALLER_DISCRIMINANT: u16 = 0;
+        pub const FIELD_YOURSELF_DISCRIMINANT: u16 = 1;
+        pub const FIELD_THIRD_PARTY_DISCRIMINANT: u16 = 2;
+        pub const FIELD_THIRD_PARTY_POINTER_INDEX: usize = 2;
+      }
+      pub const TYPE_ID: u64 = 0xdae8_b0f6_1aab_5f99;
+    }
+    pub enum Which<A0> {
+      Caller(()),
+      Yourself(()),
+      ThirdParty(A0),
+    }
+    pub type WhichReader<'a,> = Which<::capnp::any_pointer::Reader<'a>>;
+    pub type WhichBuilder<'a,> = Which<::capnp::any_pointer::Builder<'a>>;
+  }
+}
+
+pub mod return_ {
+pub struct Return {
+   pub uni: ReturnUnion,
+ pub _answer_id: u32,
+ pub _release_param_caps: bool,
+ pub _no_finish_needed: bool,  
+}
+impl Return {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+ match self.uni {
+ ReturnUnion::UNINITIALIZED => (),
+ ReturnUnion::_Results(t) => t.build_capnp_struct(builder.reborrow().init_results())?,
+ ReturnUnion::_Exception(t) => t.build_capnp_struct(builder.reborrow().init_exception())?,
+ ReturnUnion::_Canceled(t) => builder.reborrow().set_canceled(t),
+ ReturnUnion::_ResultsSentElsewhere(t) => builder.reborrow().set_results_sent_elsewhere(t),
+ ReturnUnion::_TakeFromOtherQuestion(t) => builder.reborrow().set_take_from_other_question(t),
+ ReturnUnion::_AcceptFromThirdParty(t) => builder.reborrow().init_accept_from_third_party().set_as_capability(t),
+ }
+  builder.set_answer_id(self._answer_id);
+  builder.set_release_param_caps(self._release_param_caps);
+  builder.set_no_finish_needed(self._no_finish_needed);
+  ::core::result::Result::Ok(())
+}}
+pub enum ReturnUnion {
+ UNINITIALIZED,
+ _Results(crate::rpc_capnp::payload::Payload),
+ _Exception(crate::rpc_capnp::exception::Exception),
+ _Canceled(()),
+ _ResultsSentElsewhere(()),
+ _TakeFromOtherQuestion(u32),
+ _AcceptFromThirdParty(Box<dyn ::capnp::private::capability::ClientHook>),
+}
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Return  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _answer_id: reader.get_answer_id(),
+      _release_param_caps: reader.get_release_param_caps(),
+      _no_finish_needed: reader.get_no_finish_needed(),
+      uni: match reader.which()? {
+        Which::Results(value) => ReturnUnion::_Results(<crate::rpc_capnp::payload::Payload as ::core::convert::TryFrom<_>>::try_from(value?)?),
+        Which::Exception(value) => ReturnUnion::_Exception(<crate::rpc_capnp::exception::Exception as ::core::convert::TryFrom<_>>::try_from(value?)?),
+        Which::Canceled(value) => ReturnUnion::_Canceled(value),
+        Which::ResultsSentElsewhere(value) => ReturnUnion::_ResultsSentElsewhere(value),
+        Which::TakeFromOtherQuestion(value) => ReturnUnion::_TakeFromOtherQuestion(value),
+        Which::AcceptFromThirdParty(value) => ReturnUnion::_AcceptFromThirdParty(value.get_as_capability::<::capnp::capability::Client>()?.hook),
+      },
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for Return {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl Return {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  pub use self::Which::{Results,Exception,Canceled,ResultsSentElsewhere,TakeFromOtherQuestion,AcceptFromThirdParty};
+
+  /// The members of the union, as `(discriminant, name, field index)` in discriminant order.
+  pub static UNION_MEMBERS: &[(u16, &str, u16)] = &[(0, "results", 2), (1, "exception", 3), (2, "canceled", 4), (3, "resultsSentElsewhere", 5), (4, "takeFromOtherQuestion", 6), (5, "acceptFromThirdParty", 7)];
+  /// Returns the schema name of the union member with discriminant `d`.
+  pub fn discriminant_name(d: u16) -> ::core::option::Option<&'static str> {
+    UNION_MEMBERS.get(d as usize).map(|member| member.1)
+  }
+
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub results: usize,
+    pub exception: usize,
+    pub accept_from_third_party: usize,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        results: crate::rpc_capnp::payload::MIN_SIZE_WORDS - 1,
+        exception: crate::rpc_capnp::exception::MIN_SIZE_WORDS - 1,
+        accept_from_third_party: 0,
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    counts.results + counts.exception + counts.accept_from_third_party
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 2 + 1 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_answer_id(self) -> u32 {
+      self.reader.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn get_release_param_caps(self) -> bool {
+      self.reader.get_bool_field_mask(32, true)
+    }
+    #[inline]
+    pub fn is_results(&self) -> bool {
+      self.reader.get_data_field::<u16>(3) == 0
+    }
+    #[inline]
+    pub fn has_results(&self) -> bool {
+      if self.reader.get_data_field::<u16>(3) != 0 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn is_exception(&self) -> bool {
+      self.reader.get_data_field::<u16>(3) == 1
+    }
+    #[inline]
+    pub fn has_exception(&self) -> bool {
+      if self.reader.get_data_field::<u16>(3) != 1 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn is_canceled(&self) -> bool {
+      self.reader.get_data_field::<u16>(3) == 2
+    }
+    #[inline]
+    pub fn is_results_sent_elsewhere(&self) -> bool {
+      self.reader.get_data_field::<u16>(3) == 3
+    }
+    #[inline]
+    pub fn is_take_from_other_question(&self) -> bool {
+      self.reader.get_data_field::<u16>(3) == 4
+    }
+    #[inline]
+    pub fn is_accept_from_third_party(&self) -> bool {
+      self.reader.get_data_field::<u16>(3) == 5
+    }
+    #[inline]
+    pub fn has_accept_from_third_party(&self) -> bool {
+      if self.reader.get_data_field::<u16>(3) != 5 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_no_finish_needed(self) -> bool {
+      self.reader.get_bool_field(33)
+    }
+    #[inline]
+    pub fn which(self) -> ::core::result::Result<WhichReader<'a,>, ::capnp::NotInSchema> {
+      match self.reader.get_data_field::<u16>(3) {
+        0 => {
+          ::core::result::Result::Ok(Which::Results(
+            ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        1 => {
+          ::core::result::Result::Ok(Which::Exception(
+            ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        2 => {
+          ::core::result::Result::Ok(Which::Canceled(
+            ()
+          ))
+        }
+        3 => {
+          ::core::result::Result::Ok(Which::ResultsSentElsewhere(
+            ()
+          ))
+        }
+        4 => {
+          ::core::result::Result::Ok(Which::TakeFromOtherQuestion(
+            self.reader.get_data_field::<u32>(2)
+          ))
+        }
+        5 => {
+          ::core::result::Result::Ok(Which::AcceptFromThirdParty(
+            ::capnp::any_pointer::Reader::new(self.reader.get_pointer_field(0))
+          ))
+        }
+        x => ::core::result::Result::Err(::capnp::NotInSchema(x))
+      }
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 2, pointers: 1 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_answer_id(self) -> u32 {
+      self.builder.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn set_answer_id(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(0, value);
+    }
+    #[inline]
+    pub fn set_answer_id_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_answer_id(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_release_param_caps(self) -> bool {
+      self.builder.get_bool_field_mask(32, true)
+    }
+    #[inline]
+    pub fn set_release_param_caps(&mut self, value: bool)  {
+      self.builder.set_bool_field_mask(32, value, true);
+    }
+    #[inline]
+    pub fn set_results(&mut self, value: crate::rpc_capnp::payload::Reader<'_>) -> ::capnp::Result<()> {
+      self.builder.set_data_field::<u16>(3, 0);
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_results(self, ) -> crate::rpc_capnp::payload::Builder<'a> {
+      self.builder.set_data_field::<u16>(3, 0);
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_results(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::payload::Owned> {
+      if self.builder.get_data_field::<u16>(3) != 0 {
+        return ::capnp::Orphan::null();
+      }
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_results(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::payload::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(3, 0);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_or_init_results(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::payload::Builder<'_>> {
+      if self.builder.get_data_field::<u16>(3) != 0 || self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_results())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn is_results(&self) -> bool {
+      self.builder.get_data_field::<u16>(3) == 0
+    }
+    #[inline]
+    pub fn has_results(&self) -> bool {
+      if self.builder.get_data_field::<u16>(3) != 0 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn set_exception(&mut self, value: crate::rpc_capnp::exception::Reader<'_>) -> ::capnp::Result<()> {
+      self.builder.set_data_field::<u16>(3, 1);
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_exception(self, ) -> crate::rpc_capnp::exception::Builder<'a> {
+      self.builder.set_data_field::<u16>(3, 1);
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_exception(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::exception::Owned> {
+      if self.builder.get_data_field::<u16>(3) != 1 {
+        return ::capnp::Orphan::null();
+      }
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_exception(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::exception::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(3, 1);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_or_init_exception(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::exception::Builder<'_>> {
+      if self.builder.get_data_field::<u16>(3) != 1 || self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_exception())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn is_exception(&self) -> bool {
+      self.builder.get_data_field::<u16>(3) == 1
+    }
+    #[inline]
+    pub fn has_exception(&self) -> bool {
+      if self.builder.get_data_field::<u16>(3) != 1 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn set_canceled(&mut self, _value: ())  {
+      self.builder.set_data_field::<u16>(3, 2);
+    }
+    #[inline]
+    pub fn is_canceled(&self) -> bool {
+      self.builder.get_data_field::<u16>(3) == 2
+    }
+    #[inline]
+    pub fn set_results_sent_elsewhere(&mut self, _value: ())  {
+      self.builder.set_data_field::<u16>(3, 3);
+    }
+    #[inline]
+    pub fn is_results_sent_elsewhere(&self) -> bool {
+      self.builder.get_data_field::<u16>(3) == 3
+    }
+    #[inline]
+    pub fn set_take_from_other_question(&mut self, value: u32)  {
+      self.builder.set_data_field::<u16>(3, 4);
+      self.builder.set_data_field::<u32>(2, value);
+    }
+    #[inline]
+    pub fn set_take_from_other_question_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_take_from_other_question(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn is_take_from_other_question(&self) -> bool {
+      self.builder.get_data_field::<u16>(3) == 4
+    }
+    #[inline]
+    pub fn init_accept_from_third_party(self, ) -> ::capnp::any_pointer::Builder<'a> {
+      self.builder.set_data_field::<u16>(3, 5);
+      let mut result = ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(0));
+      result.clear();
+      result
+    }
+    #[inline]
+    pub fn disown_accept_from_third_party(&mut self) -> ::capnp::Orphan<::capnp::any_pointer::Owned> {
+      if self.builder.get_data_field::<u16>(3) != 5 {
+        return ::capnp::Orphan::null();
+      }
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_accept_from_third_party(&mut self, orphan: ::capnp::Orphan<::capnp::any_pointer::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(3, 5);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn is_accept_from_third_party(&self) -> bool {
+      self.builder.get_data_field::<u16>(3) == 5
+    }
+    #[inline]
+    pub fn has_accept_from_third_party(&self) -> bool {
+      if self.builder.get_data_field::<u16>(3) != 5 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn get_no_finish_needed(self) -> bool {
+      self.builder.get_bool_field(33)
+    }
+    #[inline]
+    pub fn set_no_finish_needed(&mut self, value: bool)  {
+      self.builder.set_bool_field(33, value);
+    }
+    #[inline]
+    pub fn which(self) -> ::core::result::Result<WhichBuilder<'a,>, ::capnp::NotInSchema> {
+      match self.builder.get_data_field::<u16>(3) {
+        0 => {
+          ::core::result::Result::Ok(Which::Results(
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        1 => {
+          ::core::result::Result::Ok(Which::Exception(
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        2 => {
+          ::core::result::Result::Ok(Which::Canceled(
+            ()
+          ))
+        }
+        3 => {
+          ::core::result::Result::Ok(Which::ResultsSentElsewhere(
+            ()
+          ))
+        }
+        4 => {
+          ::core::result::Result::Ok(Which::TakeFromOtherQuestion(
+            self.builder.get_data_field::<u32>(2)
+          ))
+        }
+        5 => {
+          ::core::result::Result::Ok(Which::AcceptFromThirdParty(
+            ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(0))
+          ))
+        }
+        x => ::core::result::Result::Err(::capnp::NotInSchema(x))
+      }
+    }
+    /// Sets the union to the variant that is active in `other`, copied from there as stored.
+    /// The fields outside the union are left unchanged.
+    ///
+    /// Returns an `EnumValueOrUnionDiscriminantNotPresent` error without changing anything if
+    /// `other` has a variant that is not in the schema, for example one added by a newer version
+    /// of it. A union nested in a group of the active variant is handled the same way, but by
+    /// then the group has been partially copied.
+    pub fn copy_union_from(&mut self, other: Reader<'_,>) -> ::capnp::Result<()> {
+      match other.reader.get_data_field::<u16>(3) {
+        0 => {
+          self.builder.set_data_field::<u16>(3, 0);
+          ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        1 => {
+          self.builder.set_data_field::<u16>(3, 1);
+          ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        2 => {
+          self.builder.set_data_field::<u16>(3, 2);
+        }
+        3 => {
+          self.builder.set_data_field::<u16>(3, 3);
+        }
+        4 => {
+          self.builder.set_data_field::<u16>(3, 4);
+          self.builder.set_data_field::<u32>(2, other.reader.get_data_field::<u32>(2));
+        }
+        5 => {
+          self.builder.set_data_field::<u16>(3, 5);
+          ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        x => return ::core::result::Result::Err(::capnp::NotInSchema(x).into()),
+      }
+      ::core::result::Result::Ok(())
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 164] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(58, 87, 179, 61, 141, 178, 25, 158),
+      ::capnp::word(10, 0, 0, 0, 1, 0, 2, 0),
+      ::capnp::word(80, 162, 82, 37, 27, 152, 18, 179),
+      ::capnp::word(1, 0, 7, 0, 0, 0, 6, 0),
+      ::capnp::word(3, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 138, 0, 0, 0),
+      ::capnp::word(29, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(25, 0, 0, 0, 255, 1, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 46, 99, 97, 112, 110),
+      ::capnp::word(112, 58, 82, 101, 116, 117, 114, 110),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(36, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(237, 0, 0, 0, 74, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(236, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(248, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(1, 0, 0, 0, 32, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(245, 0, 0, 0, 138, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(248, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(4, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(3, 0, 255, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 2, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(1, 1, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(252, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(8, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(4, 0, 254, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 3, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(5, 1, 0, 0, 82, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(4, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(16, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(5, 0, 253, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 4, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(13, 1, 0, 0, 74, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(24, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(6, 0, 252, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 5, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 1, 0, 0, 170, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(24, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(36, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(7, 0, 251, 255, 2, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 6, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(33, 1, 0, 0, 178, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(36, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(48, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(8, 0, 250, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(45, 1, 0, 0, 170, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(48, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(60, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(2, 0, 0, 0, 33, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 8, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(57, 1, 0, 0, 122, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(56, 1, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(68, 1, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(97, 110, 115, 119, 101, 114, 73, 100),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 101, 108, 101, 97, 115, 101, 80),
+      ::capnp::word(97, 114, 97, 109, 67, 97, 112, 115),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(1, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 101, 115, 117, 108, 116, 115, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(59, 116, 150, 61, 34, 97, 14, 154),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(101, 120, 99, 101, 112, 116, 105, 111),
+      ::capnp::word(110, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(26, 105, 207, 58, 6, 183, 37, 214),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(99, 97, 110, 99, 101, 108, 101, 100),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 101, 115, 117, 108, 116, 115, 83),
+      ::capnp::word(101, 110, 116, 69, 108, 115, 101, 119),
+      ::capnp::word(104, 101, 114, 101, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(116, 97, 107, 101, 70, 114, 111, 109),
+      ::capnp::word(79, 116, 104, 101, 114, 81, 117, 101),
+      ::capnp::word(115, 116, 105, 111, 110, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(97, 99, 99, 101, 112, 116, 70, 114),
+      ::capnp::word(111, 109, 84, 104, 105, 114, 100, 80),
+      ::capnp::word(97, 114, 116, 121, 0, 0, 0, 0),
+      ::capnp::word(18, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(18, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(110, 111, 70, 105, 110, 105, 115, 104),
+      ::capnp::word(78, 101, 101, 100, 101, 100, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc.capnp:Return";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        1 => <bool as ::capnp::introspect::Introspect>::introspect(),
+        2 => <crate::rpc_capnp::payload::Owned as ::capnp::introspect::Introspect>::introspect(),
+        3 => <crate::rpc_capnp::exception::Owned as ::capnp::introspect::Introspect>::introspect(),
+        4 => <() as ::capnp::introspect::Introspect>::introspect(),
+        5 => <() as ::capnp::introspect::Introspect>::introspect(),
+        6 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        7 => <::capnp::any_pointer::Owned as ::capnp::introspect::Introspect>::introspect(),
+        8 => <bool as ::capnp::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1,8];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[2,3,4,5,6,7];
+    pub mod layout {
+      pub const DISCRIMINANT_OFFSET: usize = 6;
+      pub const FIELD_ANSWER_ID_OFFSET: usize = 0;
+      pub const FIELD_RELEASE_PARAM_CAPS_BIT_OFFSET: usize = 32;
+      pub const FIELD_RESULTS_DISCRIMINANT: u16 = 0;
+      pub const FIELD_RESULTS_POINTER_INDEX: usize = 0;
+      pub const FIELD_EXCEPTION_DISCRIMINANT: u16 = 1;
+      pub const FIELD_EXCEPTION_POINTER_INDEX: usize = 0;
+      pub const FIELD_CANCELED_DISCRIMINANT: u16 = 2;
+      pub const FIELD_RESULTS_SENT_ELSEWHERE_DISCRIMINANT: u16 = 3;
+      pub const FIELD_TAKE_FROM_OTHER_QUESTION_DISCRIMINANT: u16 = 4;
+      pub const FIELD_TAKE_FROM_OTHER_QUESTION_OFFSET: usize = 8;
+      pub const FIELD_ACCEPT_FROM_THIRD_PARTY_DISCRIMINANT: u16 = 5;
+      pub const FIELD_ACCEPT_FROM_THIRD_PARTY_POINTER_INDEX: usize = 0;
+      pub const FIELD_NO_FINISH_NEEDED_BIT_OFFSET: usize = 33;
+    }
+    pub const TYPE_ID: u64 = 0x9e19_b28d_3db3_573a;
+  }
+  pub enum Which<A0,A1,A2> {
+    Results(A0),
+    Exception(A1),
+    Canceled(()),
+    ResultsSentElsewhere(()),
+    TakeFromOtherQuestion(u32),
+    AcceptFromThirdParty(A2),
+  }
+  pub type WhichReader<'a,> = Which<::capnp::Result<crate::rpc_capnp::payload::Reader<'a>>,::capnp::Result<crate::rpc_capnp::exception::Reader<'a>>,::capnp::any_pointer::Reader<'a>>;
+  pub type WhichBuilder<'a,> = Which<::capnp::Result<crate::rpc_capnp::payload::Builder<'a>>,::capnp::Result<crate::rpc_capnp::exception::Builder<'a>>,::capnp::any_pointer::Builder<'a>>;
+}
+
+pub mod finish {
+pub struct Finish {
+ pub _question_id: u32,
+ pub _release_result_caps: bool,
+ pub _require_early_cancellation_workaround: bool,  
+}
+impl Finish {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+  builder.set_question_id(self._question_id);
+  builder.set_release_result_caps(self._release_result_caps);
+  builder.set_require_early_cancellation_workaround(self._require_early_cancellation_workaround);
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Finish  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _question_id: reader.get_question_id(),
+      _release_result_caps: reader.get_release_result_caps(),
+      _require_early_cancellation_workaround: reader.get_require_early_cancellation_workaround(),
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for Finish {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl Finish {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(_counts: FieldCounts) -> usize { 0 }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 1 + 0 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_question_id(self) -> u32 {
+      self.reader.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn get_release_result_caps(self) -> bool {
+      self.reader.get_bool_field_mask(32, true)
+    }
+    #[inline]
+    pub fn get_require_early_cancellation_workaround(self) -> bool {
+      self.reader.get_bool_field_mask(33, true)
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 1, pointers: 0 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_question_id(self) -> u32 {
+      self.builder.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn set_question_id(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(0, value);
+    }
+    #[inline]
+    pub fn set_question_id_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_question_id(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_release_result_caps(self) -> bool {
+      self.builder.get_bool_field_mask(32, true)
+    }
+    #[inline]
+    pub fn set_release_result_caps(&mut self, value: bool)  {
+      self.builder.set_bool_field_mask(32, value, true);
+    }
+    #[inline]
+    pub fn get_require_early_cancellation_workaround(self) -> bool {
+      self.builder.get_bool_field_mask(33, true)
+    }
+    #[inline]
+    pub fn set_require_early_cancellation_workaround(&mut self, value: bool)  {
+      self.builder.set_bool_field_mask(33, value, true);
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 69] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(99, 14, 248, 194, 178, 46, 125, 211),
+      ::capnp::word(10, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(80, 162, 82, 37, 27, 152, 18, 179),
+      ::capnp::word(0, 0, 7, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 138, 0, 0, 0),
+      ::capnp::word(29, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(25, 0, 0, 0, 175, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 46, 99, 97, 112, 110),
+      ::capnp::word(112, 58, 70, 105, 110, 105, 115, 104),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(12, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(69, 0, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(68, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(80, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(1, 0, 0, 0, 32, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(77, 0, 0, 0, 146, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(80, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(92, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(2, 0, 0, 0, 33, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 2, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(89, 0, 0, 0, 26, 1, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(112, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(113, 117, 101, 115, 116, 105, 111, 110),
+      ::capnp::word(73, 100, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 101, 108, 101, 97, 115, 101, 82),
+      ::capnp::word(101, 115, 117, 108, 116, 67, 97, 112),
+      ::capnp::word(115, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(1, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 101, 113, 117, 105, 114, 101, 69),
+      ::capnp::word(97, 114, 108, 121, 67, 97, 110, 99),
+      ::capnp::word(101, 108, 108, 97, 116, 105, 111, 110),
+      ::capnp::word(87, 111, 114, 107, 97, 114, 111, 117),
+      ::capnp::word(110, 100, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(1, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc.capnp:Finish";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        1 => <bool as ::capnp::introspect::Introspect>::introspect(),
+        2 => <bool as ::capnp::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1,2];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_QUESTION_ID_OFFSET: usize = 0;
+      pub const FIELD_RELEASE_RESULT_CAPS_BIT_OFFSET: usize = 32;
+      pub const FIELD_REQUIRE_EARLY_CANCELLATION_WORKAROUND_BIT_OFFSET: usize = 33;
+    }
+    pub const TYPE_ID: u64 = 0xd37d_2eb2_c2f8_0e63;
+  }
+}
+
+pub mod resolve {
+pub struct Resolve {
+   pub uni: ResolveUnion,
+ pub _promise_id: u32,  
+}
+impl Resolve {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+ match self.uni {
+ ResolveUnion::UNINITIALIZED => (),
+ ResolveUnion::_Cap(t) => t.build_capnp_struct(builder.reborrow().init_cap())?,
+ ResolveUnion::_Exception(t) => t.build_capnp_struct(builder.reborrow().init_exception())?,
+ }
+  builder.set_promise_id(self._promise_id);
+  ::core::result::Result::Ok(())
+}}
+pub enum ResolveUnion {
+ UNINITIALIZED,
+ _Cap(crate::rpc_capnp::cap_descriptor::CapDescriptor),
+ _Exception(crate::rpc_capnp::exception::Exception),
+}
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Resolve  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _promise_id: reader.get_promise_id(),
+      uni: match reader.which()? {
+        Which::Cap(value) => ResolveUnion::_Cap(<crate::rpc_capnp::cap_descriptor::CapDescriptor as ::core::convert::TryFrom<_>>::try_from(value?)?),
+        Which::Exception(value) => ResolveUnion::_Exception(<crate::rpc_capnp::exception::Exception as ::core::convert::TryFrom<_>>::try_from(value?)?),
+      },
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for Resolve {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl Resolve {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  pub use self::Which::{Cap,Exception};
+
+  /// The members of the union, as `(discriminant, name, field index)` in discriminant order.
+  pub static UNION_MEMBERS: &[(u16, &str, u16)] = &[(0, "cap", 1), (1, "exception", 2)];
+  /// Returns the schema name of the union member with discriminant `d`.
+  pub fn discriminant_name(d: u16) -> ::core::option::Option<&'static str> {
+    UNION_MEMBERS.get(d as usize).map(|member| member.1)
+  }
+
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub cap: usize,
+    pub exception: usize,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        cap: crate::rpc_capnp::cap_descriptor::MIN_SIZE_WORDS - 1,
+        exception: crate::rpc_capnp::exception::MIN_SIZE_WORDS - 1,
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    counts.cap + counts.exception
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 1 + 1 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_promise_id(self) -> u32 {
+      self.reader.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn is_cap(&self) -> bool {
+      self.reader.get_data_field::<u16>(2) == 0
+    }
+    #[inline]
+    pub fn has_cap(&self) -> bool {
+      if self.reader.get_data_field::<u16>(2) != 0 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn is_exception(&self) -> bool {
+      self.reader.get_data_field::<u16>(2) == 1
+    }
+    #[inline]
+    pub fn has_exception(&self) -> bool {
+      if self.reader.get_data_field::<u16>(2) != 1 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn which(self) -> ::core::result::Result<WhichReader<'a,>, ::capnp::NotInSchema> {
+      match self.reader.get_data_field::<u16>(2) {
+        0 => {
+          ::core::result::Result::Ok(Which::Cap(
+            ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        1 => {
+          ::core::result::Result::Ok(Which::Exception(
+            ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        x => ::core::result::Result::Err(::capnp::NotInSchema(x))
+      }
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 1, pointers: 1 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_promise_id(self) -> u32 {
+      self.builder.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn set_promise_id(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(0, value);
+    }
+    #[inline]
+    pub fn set_promise_id_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_promise_id(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn set_cap(&mut self, value: crate::rpc_capnp::cap_descriptor::Reader<'_>) -> ::capnp::Result<()> {
+      self.builder.set_data_field::<u16>(2, 0);
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_cap(self, ) -> crate::rpc_capnp::cap_descriptor::Builder<'a> {
+      self.builder.set_data_field::<u16>(2, 0);
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_cap(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::cap_descriptor::Owned> {
+      if self.builder.get_data_field::<u16>(2) != 0 {
+        return ::capnp::Orphan::null();
+      }
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_cap(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::cap_descriptor::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(2, 0);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_or_init_cap(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::cap_descriptor::Builder<'_>> {
+      if self.builder.get_data_field::<u16>(2) != 0 || self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_cap())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn is_cap(&self) -> bool {
+      self.builder.get_data_field::<u16>(2) == 0
+    }
+    #[inline]
+    pub fn has_cap(&self) -> bool {
+      if self.builder.get_data_field::<u16>(2) != 0 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn set_exception(&mut self, value: crate::rpc_capnp::exception::Reader<'_>) -> ::capnp::Result<()> {
+      self.builder.set_data_field::<u16>(2, 1);
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_exception(self, ) -> crate::rpc_capnp::exception::Builder<'a> {
+      self.builder.set_data_field::<u16>(2, 1);
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_exception(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::exception::Owned> {
+      if self.builder.get_data_field::<u16>(2) != 1 {
+        return ::capnp::Orphan::null();
+      }
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_exception(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::exception::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(2, 1);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_or_init_exception(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::exception::Builder<'_>> {
+      if self.builder.get_data_field::<u16>(2) != 1 || self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_exception())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn is_exception(&self) -> bool {
+      self.builder.get_data_field::<u16>(2) == 1
+    }
+    #[inline]
+    pub fn has_exception(&self) -> bool {
+      if self.builder.get_data_field::<u16>(2) != 1 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn which(self) -> ::core::result::Result<WhichBuilder<'a,>, ::capnp::NotInSchema> {
+      match self.builder.get_data_field::<u16>(2) {
+        0 => {
+          ::core::result::Result::Ok(Which::Cap(
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        1 => {
+          ::core::result::Result::Ok(Which::Exception(
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        x => ::core::result::Result::Err(::capnp::NotInSchema(x))
+      }
+    }
+    /// Sets the union to the variant that is active in `other`, copied from there as stored.
+    /// The fields outside the union are left unchanged.
+    ///
+    /// Returns an `EnumValueOrUnionDiscriminantNotPresent` error without changing anything if
+    /// `other` has a variant that is not in the schema, for example one added by a newer version
+    /// of it. A union nested in a group of the active variant is handled the same way, but by
+    /// then the group has been partially copied.
+    pub fn copy_union_from(&mut self, other: Reader<'_,>) -> ::capnp::Result<()> {
+      match other.reader.get_data_field::<u16>(2) {
+        0 => {
+          self.builder.set_data_field::<u16>(2, 0);
+          ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        1 => {
+          self.builder.set_data_field::<u16>(2, 1);
+          ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        x => return ::core::result::Result::Err(::capnp::NotInSchema(x).into()),
+      }
+      ::core::result::Result::Ok(())
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 64] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(110, 8, 137, 250, 85, 150, 194, 187),
+      ::capnp::word(10, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(80, 162, 82, 37, 27, 152, 18, 179),
+      ::capnp::word(1, 0, 7, 0, 0, 0, 2, 0),
+      ::capnp::word(2, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 146, 0, 0, 0),
+      ::capnp::word(29, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(25, 0, 0, 0, 175, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 46, 99, 97, 112, 110),
+      ::capnp::word(112, 58, 82, 101, 115, 111, 108, 118),
+      ::capnp::word(101, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(12, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(69, 0, 0, 0, 82, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(68, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(80, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(1, 0, 255, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(77, 0, 0, 0, 34, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(72, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(84, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(2, 0, 254, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 2, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(81, 0, 0, 0, 82, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(80, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(92, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(112, 114, 111, 109, 105, 115, 101, 73),
+      ::capnp::word(100, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(99, 97, 112, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(176, 184, 134, 11, 196, 221, 35, 133),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(101, 120, 99, 101, 112, 116, 105, 111),
+      ::capnp::word(110, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(26, 105, 207, 58, 6, 183, 37, 214),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc.capnp:Resolve";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        1 => <crate::rpc_capnp::cap_descriptor::Owned as ::capnp::introspect::Introspect>::introspect(),
+        2 => <crate::rpc_capnp::exception::Owned as ::capnp::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[1,2];
+    pub mod layout {
+      pub const DISCRIMINANT_OFFSET: usize = 4;
+      pub const FIELD_PROMISE_ID_OFFSET: usize = 0;
+      pub const FIELD_CAP_DISCRIMINANT: u16 = 0;
+      pub const FIELD_CAP_POINTER_INDEX: usize = 0;
+      pub const FIELD_EXCEPTION_DISCRIMINANT: u16 = 1;
+      pub const FIELD_EXCEPTION_POINTER_INDEX: usize = 0;
+    }
+    pub const TYPE_ID: u64 = 0xbbc2_9655_fa89_086e;
+  }
+  pub enum Which<A0,A1> {
+    Cap(A0),
+    Exception(A1),
+  }
+  pub type WhichReader<'a,> = Which<::capnp::Result<crate::rpc_capnp::cap_descriptor::Reader<'a>>,::capnp::Result<crate::rpc_capnp::exception::Reader<'a>>>;
+  pub type WhichBuilder<'a,> = Which<::capnp::Result<crate::rpc_capnp::cap_descriptor::Builder<'a>>,::capnp::Result<crate::rpc_capnp::exception::Builder<'a>>>;
+}
+
+pub mod release {
+pub struct Release {
+ pub _id: u32,
+ pub _reference_count: u32,  
+}
+impl Release {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+  builder.set_id(self._id);
+  builder.set_reference_count(self._reference_count);
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Release  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _id: reader.get_id(),
+      _reference_count: reader.get_reference_count(),
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for Release {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl Release {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(_counts: FieldCounts) -> usize { 0 }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 1 + 0 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_id(self) -> u32 {
+      self.reader.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn get_reference_count(self) -> u32 {
+      self.reader.get_data_field::<u32>(1)
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 1, pointers: 0 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_id(self) -> u32 {
+      self.builder.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn set_id(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(0, value);
+    }
+    #[inline]
+    pub fn set_id_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_id(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_reference_count(self) -> u32 {
+      self.builder.get_data_field::<u32>(1)
+    }
+    #[inline]
+    pub fn set_reference_count(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(1, value);
+    }
+    #[inline]
+    pub fn set_reference_count_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_reference_count(value);
+      ::core::result::Result::Ok(())
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 48] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(151, 116, 208, 125, 13, 108, 26, 173),
+      ::capnp::word(10, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(80, 162, 82, 37, 27, 152, 18, 179),
+      ::capnp::word(0, 0, 7, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 146, 0, 0, 0),
+      ::capnp::word(29, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(25, 0, 0, 0, 119, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 46, 99, 97, 112, 110),
+      ::capnp::word(112, 58, 82, 101, 108, 101, 97, 115),
+      ::capnp::word(101, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(8, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(41, 0, 0, 0, 26, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(36, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(48, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(1, 0, 0, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(45, 0, 0, 0, 122, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(44, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(56, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(105, 100, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 101, 102, 101, 114, 101, 110, 99),
+      ::capnp::word(101, 67, 111, 117, 110, 116, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc.capnp:Release";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        1 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_ID_OFFSET: usize = 0;
+      pub const FIELD_REFERENCE_COUNT_OFFSET: usize = 4;
+    }
+    pub const TYPE_ID: u64 = 0xad1a_6c0d_7dd0_7497;
+  }
+}
+
+pub mod disembargo {
+pub struct Disembargo {
+ pub _target: Option<crate::rpc_capnp::message_target::MessageTarget>,
+ pub _context: crate::rpc_capnp::disembargo::context::Context,  
+}
+impl Disembargo {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+  if let Some(st) = self._target {st.build_capnp_struct(builder.reborrow().init_target())?;}
+  self._context.build_capnp_struct(builder.reborrow().init_context())?;
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Disembargo  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _target: if reader.has_target() { ::core::option::Option::Some(<crate::rpc_capnp::message_target::MessageTarget as ::core::convert::TryFrom<_>>::try_from(reader.get_target()?)?) } else { ::core::option::Option::None },
+      _context: <crate::rpc_capnp::disembargo::context::Context as ::core::convert::TryFrom<_>>::try_from(reader.get_context())?,
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for Disembargo {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl Disembargo {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub target: usize,
+    pub context: crate::rpc_capnp::disembargo::context::FieldCounts,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        target: crate::rpc_capnp::message_target::MIN_SIZE_WORDS - 1,
+        context: crate::rpc_capnp::disembargo::context::FieldCounts::new(),
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    counts.target + crate::rpc_capnp::disembargo::context::variable_words_for(counts.context)
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 1 + 1 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_target(self) -> ::capnp::Result<crate::rpc_capnp::message_target::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_target(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_context(self) -> crate::rpc_capnp::disembargo::context::Reader<'a> {
+      self.reader.into()
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_context(&self) -> bool {
+      self.reader.get_data_field::<u16>(2) != 0
+        || self.reader.get_data_field::<u32>(0) != 0
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 1, pointers: 1 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_target(self) -> ::capnp::Result<crate::rpc_capnp::message_target::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_target(&mut self, value: crate::rpc_capnp::message_target::Reader<'_>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_target(self, ) -> crate::rpc_capnp::message_target::Builder<'a> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_target(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::message_target::Owned> {
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_target(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::message_target::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_target(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::message_target::Builder<'_>> {
+      if self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_target())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_target(&self) -> bool {
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn get_context(self) -> crate::rpc_capnp::disembargo::context::Builder<'a> {
+      self.builder.into()
+    }
+    #[inline]
+    pub fn init_context(self, ) -> crate::rpc_capnp::disembargo::context::Builder<'a> {
+      self.builder.set_data_field::<u16>(2, 0);
+      self.builder.set_data_field::<u32>(0, 0u32);
+      self.builder.into()
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_context(&self) -> bool {
+      self.builder.get_data_field::<u16>(2) != 0
+        || self.builder.get_data_field::<u32>(0) != 0
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+    #[inline]
+    pub fn get_target(&self) -> crate::rpc_capnp::message_target::Pipeline {
+      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+    }
+    #[inline]
+    pub fn get_context(&self) -> crate::rpc_capnp::disembargo::context::Pipeline {
+      ::capnp::capability::FromTypelessPipeline::new(self._typeless.noop())
+    }
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 40] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(17, 55, 189, 15, 139, 54, 100, 249),
+      ::capnp::word(10, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(80, 162, 82, 37, 27, 152, 18, 179),
+      ::capnp::word(1, 0, 7, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 170, 0, 0, 0),
+      ::capnp::word(29, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(25, 0, 0, 0, 119, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 46, 99, 97, 112, 110),
+      ::capnp::word(112, 58, 68, 105, 115, 101, 109, 98),
+      ::capnp::word(97, 114, 103, 111, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(8, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(41, 0, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(36, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(48, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(77, 221, 91, 101, 223, 180, 98, 213),
+      ::capnp::word(45, 0, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(116, 97, 114, 103, 101, 116, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(193, 251, 19, 88, 84, 20, 188, 149),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(99, 111, 110, 116, 101, 120, 116, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc.capnp:Disembargo";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <crate::rpc_capnp::message_target::Owned as ::capnp::introspect::Introspect>::introspect(),
+        1 => <crate::rpc_capnp::disembargo::context::Owned as ::capnp::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_TARGET_POINTER_INDEX: usize = 0;
+    }
+    pub const TYPE_ID: u64 = 0xf964_368b_0fbd_3711;
+  }
+
+  pub mod context {
+  
+  impl Context {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+ match self {
+ Context::UNINITIALIZED => (),
+ Context::_SenderLoopback(t) => builder.reborrow().set_sender_loopback(t),
+ Context::_ReceiverLoopback(t) => builder.reborrow().set_receiver_loopback(t),
+ Context::_Accept(t) => builder.reborrow().set_accept(t),
+ Context::_Provide(t) => builder.reborrow().set_provide(t),
+ }
+  ::core::result::Result::Ok(())
+}}
+  pub enum Context {
+ UNINITIALIZED,
+ _SenderLoopback(u32),
+ _ReceiverLoopback(u32),
+ _Accept(()),
+ _Provide(u32),
+}
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Context  {
+    type Error = ::capnp::Error;
+    fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(match reader.which()? {
+        Which::SenderLoopback(value) => Context::_SenderLoopback(value),
+        Which::ReceiverLoopback(value) => Context::_ReceiverLoopback(value),
+        Which::Accept(value) => Context::_Accept(value),
+        Which::Provide(value) => Context::_Provide(value),
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for Context {
+    type Error = ::capnp::Error;
+    fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl Context {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    pub use self::Which::{SenderLoopback,ReceiverLoopback,Accept,Provide};
+
+    /// The members of the union, as `(discriminant, name, field index)` in discriminant order.
+    pub static UNION_MEMBERS: &[(u16, &str, u16)] = &[(0, "senderLoopback", 0), (1, "receiverLoopback", 1), (2, "accept", 2), (3, "provide", 3)];
+    /// Returns the schema name of the union member with discriminant `d`.
+    pub fn discriminant_name(d: u16) -> ::core::option::Option<&'static str> {
+      UNION_MEMBERS.get(d as usize).map(|member| member.1)
+    }
+
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(_counts: FieldCounts) -> usize { 0 }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+        self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn is_sender_loopback(&self) -> bool {
+        self.reader.get_data_field::<u16>(2) == 0
+      }
+      #[inline]
+      pub fn is_receiver_loopback(&self) -> bool {
+        self.reader.get_data_field::<u16>(2) == 1
+      }
+      #[inline]
+      pub fn is_accept(&self) -> bool {
+        self.reader.get_data_field::<u16>(2) == 2
+      }
+      #[inline]
+      pub fn is_provide(&self) -> bool {
+        self.reader.get_data_field::<u16>(2) == 3
+      }
+      #[inline]
+      pub fn which(self) -> ::core::result::Result<WhichReader, ::capnp::NotInSchema> {
+        match self.reader.get_data_field::<u16>(2) {
+          0 => {
+            ::core::result::Result::Ok(Which::SenderLoopback(
+              self.reader.get_data_field::<u32>(0)
+            ))
+          }
+          1 => {
+            ::core::result::Result::Ok(Which::ReceiverLoopback(
+              self.reader.get_data_field::<u32>(0)
+            ))
+          }
+          2 => {
+            ::core::result::Result::Ok(Which::Accept(
+              ()
+            ))
+          }
+          3 => {
+            ::core::result::Result::Ok(Which::Provide(
+              self.reader.get_data_field::<u32>(0)
+            ))
+          }
+          x => ::core::result::Result::Err(::capnp::NotInSchema(x))
+        }
+      }
+    }
+
+    pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+    impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 1, pointers: 1 };
+    }
+    impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+        self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn set_sender_loopback(&mut self, value: u32)  {
+        self.builder.set_data_field::<u16>(2, 0);
+        self.builder.set_data_field::<u32>(0, value);
+      }
+      #[inline]
+      pub fn set_sender_loopback_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+        let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+        self.set_sender_loopback(value);
+        ::core::result::Result::Ok(())
+      }
+      #[inline]
+      pub fn is_sender_loopback(&self) -> bool {
+        self.builder.get_data_field::<u16>(2) == 0
+      }
+      #[inline]
+      pub fn set_receiver_loopback(&mut self, value: u32)  {
+        self.builder.set_data_field::<u16>(2, 1);
+        self.builder.set_data_field::<u32>(0, value);
+      }
+      #[inline]
+      pub fn set_receiver_loopback_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+        let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+        self.set_receiver_loopback(value);
+        ::core::result::Result::Ok(())
+      }
+      #[inline]
+      pub fn is_receiver_loopback(&self) -> bool {
+        self.builder.get_data_field::<u16>(2) == 1
+      }
+      #[inline]
+      pub fn set_accept(&mut self, _value: ())  {
+        self.builder.set_data_field::<u16>(2, 2);
+      }
+      #[inline]
+      pub fn is_accept(&self) -> bool {
+        self.builder.get_data_field::<u16>(2) == 2
+      }
+      #[inline]
+      pub fn set_provide(&mut self, value: u32)  {
+        self.builder.set_data_field::<u16>(2, 3);
+        self.builder.set_data_field::<u32>(0, value);
+      }
+      #[inline]
+      pub fn set_provide_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+        let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+        self.set_provide(value);
+        ::core::result::Result::Ok(())
+      }
+      #[inline]
+      pub fn is_provide(&self) -> bool {
+        self.builder.get_data_field::<u16>(2) == 3
+      }
+      #[inline]
+      pub fn which(self) -> ::core::result::Result<WhichBuilder, ::capnp::NotInSchema> {
+        match self.builder.get_data_field::<u16>(2) {
+          0 => {
+            ::core::result::Result::Ok(Which::SenderLoopback(
+              self.builder.get_data_field::<u32>(0)
+            ))
+          }
+          1 => {
+            ::core::result::Result::Ok(Which::ReceiverLoopback(
+              self.builder.get_data_field::<u32>(0)
+            ))
+          }
+          2 => {
+            ::core::result::Result::Ok(Which::Accept(
+              ()
+            ))
+          }
+          3 => {
+            ::core::result::Result::Ok(Which::Provide(
+              self.builder.get_data_field::<u32>(0)
+            ))
+          }
+          x => ::core::result::Result::Err(::capnp::NotInSchema(x))
+        }
+      }
+      /// Sets the union to the variant that is active in `other`, copied from there as stored.
+      /// The fields outside the union are left unchanged.
+      ///
+      /// Returns an `EnumValueOrUnionDiscriminantNotPresent` error without changing anything if
+      /// `other` has a variant that is not in the schema, for example one added by a newer version
+      /// of it. A union nested in a group of the active variant is handled the same way, but by
+      /// then the group has been partially copied.
+      pub fn copy_union_from(&mut self, other: Reader<'_,>) -> ::capnp::Result<()> {
+        match other.reader.get_data_field::<u16>(2) {
+          0 => {
+            self.builder.set_data_field::<u16>(2, 0);
+            self.builder.set_data_field::<u32>(0, other.reader.get_data_field::<u32>(0));
+          }
+          1 => {
+            self.builder.set_data_field::<u16>(2, 1);
+            self.builder.set_data_field::<u32>(0, other.reader.get_data_field::<u32>(0));
+          }
+          2 => {
+            self.builder.set_data_field::<u16>(2, 2);
+          }
+          3 => {
+            self.builder.set_data_field::<u16>(2, 3);
+            self.builder.set_data_field::<u32>(0, other.reader.get_data_field::<u32>(0));
+          }
+          x => return ::core::result::Result::Err(::capnp::NotInSchema(x).into()),
+        }
+        ::core::result::Result::Ok(())
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+      let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [::capnp::Word; 80] = [
+        ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+        ::capnp::word(77, 221, 91, 101, 223, 180, 98, 213),
+        ::capnp::word(21, 0, 0, 0, 1, 0, 1, 0),
+        ::capnp::word(17, 55, 189, 15, 139, 54, 100, 249),
+        ::capnp::word(1, 0, 7, 0, 1, 0, 4, 0),
+        ::capnp::word(2, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(21, 0, 0, 0, 234, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(25, 0, 0, 0, 231, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(114, 112, 99, 46, 99, 97, 112, 110),
+        ::capnp::word(112, 58, 68, 105, 115, 101, 109, 98),
+        ::capnp::word(97, 114, 103, 111, 46, 99, 111, 110),
+        ::capnp::word(116, 101, 120, 116, 0, 0, 0, 0),
+        ::capnp::word(16, 0, 0, 0, 3, 0, 4, 0),
+        ::capnp::word(0, 0, 255, 255, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(97, 0, 0, 0, 122, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(96, 0, 0, 0, 3, 0, 1, 0),
+        ::capnp::word(108, 0, 0, 0, 2, 0, 1, 0),
+        ::capnp::word(1, 0, 254, 255, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 1, 0, 2, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(105, 0, 0, 0, 138, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(108, 0, 0, 0, 3, 0, 1, 0),
+        ::capnp::word(120, 0, 0, 0, 2, 0, 1, 0),
+        ::capnp::word(2, 0, 253, 255, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 1, 0, 3, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(117, 0, 0, 0, 58, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(112, 0, 0, 0, 3, 0, 1, 0),
+        ::capnp::word(124, 0, 0, 0, 2, 0, 1, 0),
+        ::capnp::word(3, 0, 252, 255, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 1, 0, 4, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(121, 0, 0, 0, 66, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(116, 0, 0, 0, 3, 0, 1, 0),
+        ::capnp::word(128, 0, 0, 0, 2, 0, 1, 0),
+        ::capnp::word(115, 101, 110, 100, 101, 114, 76, 111),
+        ::capnp::word(111, 112, 98, 97, 99, 107, 0, 0),
+        ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(114, 101, 99, 101, 105, 118, 101, 114),
+        ::capnp::word(76, 111, 111, 112, 98, 97, 99, 107),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(97, 99, 99, 101, 112, 116, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(112, 114, 111, 118, 105, 100, 101, 0),
+        ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "rpc.capnp:Disembargo.context";
+      pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        match index {
+          0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+          1 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+          2 => <() as ::capnp::introspect::Introspect>::introspect(),
+          3 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[0,1,2,3];
+      pub mod layout {
+        pub const DISCRIMINANT_OFFSET: usize = 4;
+        pub const FIELD_SENDER_LOOPBACK_DISCRIMINANT: u16 = 0;
+        pub const FIELD_SENDER_LOOPBACK_OFFSET: usize = 0;
+        pub const FIELD_RECEIVER_LOOPBACK_DISCRIMINANT: u16 = 1;
+        pub const FIELD_RECEIVER_LOOPBACK_OFFSET: usize = 0;
+        pub const FIELD_ACCEPT_DISCRIMINANT: u16 = 2;
+        pub const FIELD_PROVIDE_DISCRIMINANT: u16 = 3;
+        pub const FIELD_PROVIDE_OFFSET: usize = 0;
+      }
+      pub const TYPE_ID: u64 = 0xd562_b4df_655b_dd4d;
+    }
+    pub enum Which {
+      SenderLoopback(u32),
+      ReceiverLoopback(u32),
+      Accept(()),
+      Provide(u32),
+    }
+    pub type WhichReader = Which;
+    pub type WhichBuilder = Which;
+  }
+}
+
+pub mod provide {
+pub struct Provide {
+ pub _question_id: u32,
+ pub _target: Option<crate::rpc_capnp::message_target::MessageTarget>,
+ pub _recipient: Box<dyn ::capnp::private::capability::ClientHook>,  
+}
+impl Provide {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+  builder.set_question_id(self._question_id);
+  if let Some(st) = self._target {st.build_capnp_struct(builder.reborrow().init_target())?;}
+  builder.reborrow().init_recipient().set_as_capability(self._recipient);
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Provide  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _question_id: reader.get_question_id(),
+      _target: if reader.has_target() { ::core::option::Option::Some(<crate::rpc_capnp::message_target::MessageTarget as ::core::convert::TryFrom<_>>::try_from(reader.get_target()?)?) } else { ::core::option::Option::None },
+      _recipient: reader.get_recipient().get_as_capability::<::capnp::capability::Client>()?.hook,
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for Provide {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl Provide {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub target: usize,
+    pub recipient: usize,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        target: crate::rpc_capnp::message_target::MIN_SIZE_WORDS - 1,
+        recipient: 0,
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    counts.target + counts.recipient
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 1 + 2 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_question_id(self) -> u32 {
+      self.reader.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn get_target(self) -> ::capnp::Result<crate::rpc_capnp::message_target::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_target(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_recipient(self) -> ::capnp::any_pointer::Reader<'a> {
+      ::capnp::any_pointer::Reader::new(self.reader.get_pointer_field(1))
+    }
+    #[inline]
+    pub fn get_recipient_as<_T: ::capnp::traits::Owned>(self) -> ::capnp::Result<_T::Reader<'a>> {
+      ::capnp::any_pointer::Reader::new(self.reader.get_pointer_field(1)).get_as_checked::<_T>()
+    }
+    #[inline]
+    pub fn has_recipient(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 1, pointers: 2 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_question_id(self) -> u32 {
+      self.builder.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn set_question_id(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(0, value);
+    }
+    #[inline]
+    pub fn set_question_id_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_question_id(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_target(self) -> ::capnp::Result<crate::rpc_capnp::message_target::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_target(&mut self, value: crate::rpc_capnp::message_target::Reader<'_>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_target(self, ) -> crate::rpc_capnp::message_target::Builder<'a> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_target(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::message_target::Owned> {
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_target(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::message_target::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_target(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::message_target::Builder<'_>> {
+      if self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_target())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_target(&self) -> bool {
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn get_recipient(self) -> ::capnp::any_pointer::Builder<'a> {
+      ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(1))
+    }
+    #[inline]
+    pub fn get_recipient_as<_T: ::capnp::traits::Owned>(self) -> ::capnp::Result<_T::Builder<'a>> {
+      ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(1)).get_as_checked::<_T>()
+    }
+    #[inline]
+    pub fn init_recipient(self, ) -> ::capnp::any_pointer::Builder<'a> {
+      let mut result = ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(1));
+      result.clear();
+      result
+    }
+    #[inline]
+    pub fn disown_recipient(&mut self) -> ::capnp::Orphan<::capnp::any_pointer::Owned> {
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+    }
+    #[inline]
+    pub fn adopt_recipient(&mut self, orphan: ::capnp::Orphan<::capnp::any_pointer::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(1).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn has_recipient(&self) -> bool {
+      !self.builder.is_pointer_field_null(1)
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+    #[inline]
+    pub fn get_target(&self) -> crate::rpc_capnp::message_target::Pipeline {
+      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+    }
+    #[inline]
+    pub fn get_recipient(&self) -> ::capnp::any_pointer::Pipeline {
+      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(1))
+    }
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 64] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(90, 172, 193, 251, 107, 4, 106, 156),
+      ::capnp::word(10, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(80, 162, 82, 37, 27, 152, 18, 179),
+      ::capnp::word(2, 0, 7, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 146, 0, 0, 0),
+      ::capnp::word(29, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(25, 0, 0, 0, 175, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 46, 99, 97, 112, 110),
+      ::capnp::word(112, 58, 80, 114, 111, 118, 105, 100),
+      ::capnp::word(101, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(12, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(69, 0, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(68, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(80, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(77, 0, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(72, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(84, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(2, 0, 0, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 2, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(81, 0, 0, 0, 82, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(80, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(92, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(113, 117, 101, 115, 116, 105, 111, 110),
+      ::capnp::word(73, 100, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(116, 97, 114, 103, 101, 116, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(193, 251, 19, 88, 84, 20, 188, 149),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 101, 99, 105, 112, 105, 101, 110),
+      ::capnp::word(116, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(18, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(18, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc.capnp:Provide";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        1 => <crate::rpc_capnp::message_target::Owned as ::capnp::introspect::Introspect>::introspect(),
+        2 => <::capnp::any_pointer::Owned as ::capnp::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1,2];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_QUESTION_ID_OFFSET: usize = 0;
+      pub const FIELD_TARGET_POINTER_INDEX: usize = 0;
+      pub const FIELD_RECIPIENT_POINTER_INDEX: usize = 1;
+    }
+    pub const TYPE_ID: u64 = 0x9c6a_046b_fbc1_ac5a;
+  }
+}
+
+pub mod accept {
+pub struct Accept {
+ pub _question_id: u32,
+ pub _provision: Box<dyn ::capnp::private::capability::ClientHook>,
+ pub _embargo: bool,  
+}
+impl Accept {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+  builder.set_question_id(self._question_id);
+  builder.reborrow().init_provision().set_as_capability(self._provision);
+  builder.set_embargo(self._embargo);
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Accept  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _question_id: reader.get_question_id(),
+      _provision: reader.get_provision().get_as_capability::<::capnp::capability::Client>()?.hook,
+      _embargo: reader.get_embargo(),
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for Accept {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl Accept {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub provision: usize,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        provision: 0,
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    counts.provision
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 1 + 1 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_question_id(self) -> u32 {
+      self.reader.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn get_provision(self) -> ::capnp::any_pointer::Reader<'a> {
+      ::capnp::any_pointer::Reader::new(self.reader.get_pointer_field(0))
+    }
+    #[inline]
+    pub fn get_provision_as<_T: ::capnp::traits::Owned>(self) -> ::capnp::Result<_T::Reader<'a>> {
+      ::capnp::any_pointer::Reader::new(self.reader.get_pointer_field(0)).get_as_checked::<_T>()
+    }
+    #[inline]
+    pub fn has_provision(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_embargo(self) -> bool {
+      self.reader.get_bool_field(32)
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 1, pointers: 1 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_question_id(self) -> u32 {
+      self.builder.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn set_question_id(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(0, value);
+    }
+    #[inline]
+    pub fn set_question_id_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_question_id(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_provision(self) -> ::capnp::any_pointer::Builder<'a> {
+      ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(0))
+    }
+    #[inline]
+    pub fn get_provision_as<_T: ::capnp::traits::Owned>(self) -> ::capnp::Result<_T::Builder<'a>> {
+      ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(0)).get_as_checked::<_T>()
+    }
+    #[inline]
+    pub fn init_provision(self, ) -> ::capnp::any_pointer::Builder<'a> {
+      let mut result = ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(0));
+      result.clear();
+      result
+    }
+    #[inline]
+    pub fn disown_provision(&mut self) -> ::capnp::Orphan<::capnp::any_pointer::Owned> {
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_provision(&mut self, orphan: ::capnp::Orphan<::capnp::any_pointer::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn has_provision(&self) -> bool {
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn get_embargo(self) -> bool {
+      self.builder.get_bool_field(32)
+    }
+    #[inline]
+    pub fn set_embargo(&mut self, value: bool)  {
+      self.builder.set_bool_field(32, value);
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+    #[inline]
+    pub fn get_provision(&self) -> ::capnp::any_pointer::Pipeline {
+      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+    }
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 64] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(22, 64, 85, 144, 98, 181, 201, 212),
+      ::capnp::word(10, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(80, 162, 82, 37, 27, 152, 18, 179),
+      ::capnp::word(1, 0, 7, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 138, 0, 0, 0),
+      ::capnp::word(29, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(25, 0, 0, 0, 175, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 46, 99, 97, 112, 110),
+      ::capnp::word(112, 58, 65, 99, 99, 101, 112, 116),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(12, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(69, 0, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(68, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(80, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(77, 0, 0, 0, 82, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(76, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(88, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(2, 0, 0, 0, 32, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 2, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(85, 0, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(80, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(92, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(113, 117, 101, 115, 116, 105, 111, 110),
+      ::capnp::word(73, 100, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(112, 114, 111, 118, 105, 115, 105, 111),
+      ::capnp::word(110, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(18, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(18, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(101, 109, 98, 97, 114, 103, 111, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc.capnp:Accept";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        1 => <::capnp::any_pointer::Owned as ::capnp::introspect::Introspect>::introspect(),
+        2 => <bool as ::capnp::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1,2];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_QUESTION_ID_OFFSET: usize = 0;
+      pub const FIELD_PROVISION_POINTER_INDEX: usize = 0;
+      pub const FIELD_EMBARGO_BIT_OFFSET: usize = 32;
+    }
+    pub const TYPE_ID: u64 = 0xd4c9_b562_9055_4016;
+  }
+}
+
+pub mod join {
+pub struct Join {
+ pub _question_id: u32,
+ pub _target: Option<crate::rpc_capnp::message_target::MessageTarget>,
+ pub _key_part: Box<dyn ::capnp::private::capability::ClientHook>,  
+}
+impl Join {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+  builder.set_question_id(self._question_id);
+  if let Some(st) = self._target {st.build_capnp_struct(builder.reborrow().init_target())?;}
+  builder.reborrow().init_key_part().set_as_capability(self._key_part);
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Join  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _question_id: reader.get_question_id(),
+      _target: if reader.has_target() { ::core::option::Option::Some(<crate::rpc_capnp::message_target::MessageTarget as ::core::convert::TryFrom<_>>::try_from(reader.get_target()?)?) } else { ::core::option::Option::None },
+      _key_part: reader.get_key_part().get_as_capability::<::capnp::capability::Client>()?.hook,
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for Join {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl Join {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub target: usize,
+    pub key_part: usize,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        target: crate::rpc_capnp::message_target::MIN_SIZE_WORDS - 1,
+        key_part: 0,
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    counts.target + counts.key_part
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 1 + 2 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_question_id(self) -> u32 {
+      self.reader.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn get_target(self) -> ::capnp::Result<crate::rpc_capnp::message_target::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_target(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_key_part(self) -> ::capnp::any_pointer::Reader<'a> {
+      ::capnp::any_pointer::Reader::new(self.reader.get_pointer_field(1))
+    }
+    #[inline]
+    pub fn get_key_part_as<_T: ::capnp::traits::Owned>(self) -> ::capnp::Result<_T::Reader<'a>> {
+      ::capnp::any_pointer::Reader::new(self.reader.get_pointer_field(1)).get_as_checked::<_T>()
+    }
+    #[inline]
+    pub fn has_key_part(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 1, pointers: 2 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_question_id(self) -> u32 {
+      self.builder.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn set_question_id(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(0, value);
+    }
+    #[inline]
+    pub fn set_question_id_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_question_id(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_target(self) -> ::capnp::Result<crate::rpc_capnp::message_target::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_target(&mut self, value: crate::rpc_capnp::message_target::Reader<'_>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_target(self, ) -> crate::rpc_capnp::message_target::Builder<'a> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_target(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::message_target::Owned> {
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_target(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::message_target::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_target(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::message_target::Builder<'_>> {
+      if self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_target())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_target(&self) -> bool {
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn get_key_part(self) -> ::capnp::any_pointer::Builder<'a> {
+      ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(1))
+    }
+    #[inline]
+    pub fn get_key_part_as<_T: ::capnp::traits::Owned>(self) -> ::capnp::Result<_T::Builder<'a>> {
+      ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(1)).get_as_checked::<_T>()
+    }
+    #[inline]
+    pub fn init_key_part(self, ) -> ::capnp::any_pointer::Builder<'a> {
+      let mut result = ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(1));
+      result.clear();
+      result
+    }
+    #[inline]
+    pub fn disown_key_part(&mut self) -> ::capnp::Orphan<::capnp::any_pointer::Owned> {
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+    }
+    #[inline]
+    pub fn adopt_key_part(&mut self, orphan: ::capnp::Orphan<::capnp::any_pointer::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(1).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn has_key_part(&self) -> bool {
+      !self.builder.is_pointer_field_null(1)
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+    #[inline]
+    pub fn get_target(&self) -> crate::rpc_capnp::message_target::Pipeline {
+      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+    }
+    #[inline]
+    pub fn get_key_part(&self) -> ::capnp::any_pointer::Pipeline {
+      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(1))
+    }
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 62] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(175, 1, 224, 144, 4, 152, 225, 251),
+      ::capnp::word(10, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(80, 162, 82, 37, 27, 152, 18, 179),
+      ::capnp::word(2, 0, 7, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 122, 0, 0, 0),
+      ::capnp::word(25, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 175, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 46, 99, 97, 112, 110),
+      ::capnp::word(112, 58, 74, 111, 105, 110, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(12, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(69, 0, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(68, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(80, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(77, 0, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(72, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(84, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(2, 0, 0, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 2, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(81, 0, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(76, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(88, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(113, 117, 101, 115, 116, 105, 111, 110),
+      ::capnp::word(73, 100, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(116, 97, 114, 103, 101, 116, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(193, 251, 19, 88, 84, 20, 188, 149),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(107, 101, 121, 80, 97, 114, 116, 0),
+      ::capnp::word(18, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(18, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc.capnp:Join";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        1 => <crate::rpc_capnp::message_target::Owned as ::capnp::introspect::Introspect>::introspect(),
+        2 => <::capnp::any_pointer::Owned as ::capnp::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1,2];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_QUESTION_ID_OFFSET: usize = 0;
+      pub const FIELD_TARGET_POINTER_INDEX: usize = 0;
+      pub const FIELD_KEY_PART_POINTER_INDEX: usize = 1;
+    }
+    pub const TYPE_ID: u64 = 0xfbe1_9804_90e0_01af;
+  }
+}
+
+pub mod message_target {
+
+impl MessageTarget {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+ match self {
+ MessageTarget::UNINITIALIZED => (),
+ MessageTarget::_ImportedCap(t) => builder.reborrow().set_imported_cap(t),
+ MessageTarget::_PromisedAnswer(t) => t.build_capnp_struct(builder.reborrow().init_promised_answer())?,
+ }
+  ::core::result::Result::Ok(())
+}}
+pub enum MessageTarget {
+ UNINITIALIZED,
+ _ImportedCap(u32),
+ _PromisedAnswer(crate::rpc_capnp::promised_answer::PromisedAnswer),
+}
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for MessageTarget  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(match reader.which()? {
+      Which::ImportedCap(value) => MessageTarget::_ImportedCap(value),
+      Which::PromisedAnswer(value) => MessageTarget::_PromisedAnswer(<crate::rpc_capnp::promised_answer::PromisedAnswer as ::core::convert::TryFrom<_>>::try_from(value?)?),
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for MessageTarget {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl MessageTarget {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  pub use self::Which::{ImportedCap,PromisedAnswer};
+
+  /// The members of the union, as `(discriminant, name, field index)` in discriminant order.
+  pub static UNION_MEMBERS: &[(u16, &str, u16)] = &[(0, "importedCap", 0), (1, "promisedAnswer", 1)];
+  /// Returns the schema name of the union member with discriminant `d`.
+  pub fn discriminant_name(d: u16) -> ::core::option::Option<&'static str> {
+    UNION_MEMBERS.get(d as usize).map(|member| member.1)
+  }
+
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub promised_answer: usize,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        promised_answer: crate::rpc_capnp::promised_answer::MIN_SIZE_WORDS - 1,
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    counts.promised_answer
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 1 + 1 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn is_imported_cap(&self) -> bool {
+      self.reader.get_data_field::<u16>(2) == 0
+    }
+    #[inline]
+    pub fn is_promised_answer(&self) -> bool {
+      self.reader.get_data_field::<u16>(2) == 1
+    }
+    #[inline]
+    pub fn has_promised_answer(&self) -> bool {
+      if self.reader.get_data_field::<u16>(2) != 1 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn which(self) -> ::core::result::Result<WhichReader<'a,>, ::capnp::NotInSchema> {
+      match self.reader.get_data_field::<u16>(2) {
+        0 => {
+          ::core::result::Result::Ok(Which::ImportedCap(
+            self.reader.get_data_field::<u32>(0)
+          ))
+        }
+        1 => {
+          ::core::result::Result::Ok(Which::PromisedAnswer(
+            ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        x => ::core::result::Result::Err(::capnp::NotInSchema(x))
+      }
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 1, pointers: 1 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn set_imported_cap(&mut self, value: u32)  {
+      self.builder.set_data_field::<u16>(2, 0);
+      self.builder.set_data_field::<u32>(0, value);
+    }
+    #[inline]
+    pub fn set_imported_cap_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_imported_cap(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn is_imported_cap(&self) -> bool {
+      self.builder.get_data_field::<u16>(2) == 0
+    }
+    #[inline]
+    pub fn set_promised_answer(&mut self, value: crate::rpc_capnp::promised_answer::Reader<'_>) -> ::capnp::Result<()> {
+      self.builder.set_data_field::<u16>(2, 1);
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_promised_answer(self, ) -> crate::rpc_capnp::promised_answer::Builder<'a> {
+      self.builder.set_data_field::<u16>(2, 1);
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_promised_answer(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::promised_answer::Owned> {
+      if self.builder.get_data_field::<u16>(2) != 1 {
+        return ::capnp::Orphan::null();
+      }
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_promised_answer(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::promised_answer::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(2, 1);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_or_init_promised_answer(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::promised_answer::Builder<'_>> {
+      if self.builder.get_data_field::<u16>(2) != 1 || self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_promised_answer())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn is_promised_answer(&self) -> bool {
+      self.builder.get_data_field::<u16>(2) == 1
+    }
+    #[inline]
+    pub fn has_promised_answer(&self) -> bool {
+      if self.builder.get_data_field::<u16>(2) != 1 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn which(self) -> ::core::result::Result<WhichBuilder<'a,>, ::capnp::NotInSchema> {
+      match self.builder.get_data_field::<u16>(2) {
+        0 => {
+          ::core::result::Result::Ok(Which::ImportedCap(
+            self.builder.get_data_field::<u32>(0)
+          ))
+        }
+        1 => {
+          ::core::result::Result::Ok(Which::PromisedAnswer(
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        x => ::core::result::Result::Err(::capnp::NotInSchema(x))
+      }
+    }
+    /// Sets the union to the variant that is active in `other`, copied from there as stored.
+    /// The fields outside the union are left unchanged.
+    ///
+    /// Returns an `EnumValueOrUnionDiscriminantNotPresent` error without changing anything if
+    /// `other` has a variant that is not in the schema, for example one added by a newer version
+    /// of it. A union nested in a group of the active variant is handled the same way, but by
+    /// then the group has been partially copied.
+    pub fn copy_union_from(&mut self, other: Reader<'_,>) -> ::capnp::Result<()> {
+      match other.reader.get_data_field::<u16>(2) {
+        0 => {
+          self.builder.set_data_field::<u16>(2, 0);
+          self.builder.set_data_field::<u32>(0, other.reader.get_data_field::<u32>(0));
+        }
+        1 => {
+          self.builder.set_data_field::<u16>(2, 1);
+          ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        x => return ::core::result::Result::Err(::capnp::NotInSchema(x).into()),
+      }
+      ::core::result::Result::Ok(())
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 49] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(193, 251, 19, 88, 84, 20, 188, 149),
+      ::capnp::word(10, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(80, 162, 82, 37, 27, 152, 18, 179),
+      ::capnp::word(1, 0, 7, 0, 0, 0, 2, 0),
+      ::capnp::word(2, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 194, 0, 0, 0),
+      ::capnp::word(29, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(25, 0, 0, 0, 119, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 46, 99, 97, 112, 110),
+      ::capnp::word(112, 58, 77, 101, 115, 115, 97, 103),
+      ::capnp::word(101, 84, 97, 114, 103, 101, 116, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(8, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 255, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(41, 0, 0, 0, 98, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(40, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(52, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(1, 0, 254, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(49, 0, 0, 0, 122, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(48, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(60, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(105, 109, 112, 111, 114, 116, 101, 100),
+      ::capnp::word(67, 97, 112, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(112, 114, 111, 109, 105, 115, 101, 100),
+      ::capnp::word(65, 110, 115, 119, 101, 114, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(160, 28, 111, 205, 214, 177, 0, 216),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc.capnp:MessageTarget";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        1 => <crate::rpc_capnp::promised_answer::Owned as ::capnp::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[0,1];
+    pub mod layout {
+      pub const DISCRIMINANT_OFFSET: usize = 4;
+      pub const FIELD_IMPORTED_CAP_DISCRIMINANT: u16 = 0;
+      pub const FIELD_IMPORTED_CAP_OFFSET: usize = 0;
+      pub const FIELD_PROMISED_ANSWER_DISCRIMINANT: u16 = 1;
+      pub const FIELD_PROMISED_ANSWER_POINTER_INDEX: usize = 0;
+    }
+    pub const TYPE_ID: u64 = 0x95bc_1454_5813_fbc1;
+  }
+  pub enum Which<A0> {
+    ImportedCap(u32),
+    PromisedAnswer(A0),
+  }
+  pub type WhichReader<'a,> = Which<::capnp::Result<crate::rpc_capnp::promised_answer::Reader<'a>>>;
+  pub type WhichBuilder<'a,> = Which<::capnp::Result<crate::rpc_capnp::promised_answer::Builder<'a>>>;
+}
+
+pub mod payload {
+pub struct Payload {
+ pub _content: Box<dyn ::capnp::private::capability::ClientHook>,
+ pub _cap_table: Vec<crate::rpc_capnp::cap_descriptor::CapDescriptor>,  
+}
+impl Payload {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+  builder.reborrow().init_content().set_as_capability(self._content);
+            
+if self._cap_table.len() > 0 {
+                let mut list_builder = builder.reborrow().init_cap_table(::capnp::private::checked_len(self._cap_table.len())?);
+                for (i, item) in self._cap_table.into_iter().enumerate() {
+                    item.build_capnp_struct(list_builder.reborrow().get(i as u32))?;
+                }
+            }
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Payload  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _content: reader.get_content().get_as_capability::<::capnp::capability::Client>()?.hook,
+      _cap_table: reader.get_cap_table()?.iter().map(|item| ::capnp::Result::Ok(<crate::rpc_capnp::cap_descriptor::CapDescriptor as ::core::convert::TryFrom<_>>::try_from(item)?)).collect::<::capnp::Result<Vec<_>>>()?,
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for Payload {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl Payload {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub content: usize,
+    pub cap_table: usize,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        content: 0,
+        cap_table: 0,
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    counts.content + 1 + counts.cap_table * (crate::rpc_capnp::cap_descriptor::MIN_SIZE_WORDS - 1)
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 0 + 2 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_content(self) -> ::capnp::any_pointer::Reader<'a> {
+      ::capnp::any_pointer::Reader::new(self.reader.get_pointer_field(0))
+    }
+    #[inline]
+    pub fn get_content_as<_T: ::capnp::traits::Owned>(self) -> ::capnp::Result<_T::Reader<'a>> {
+      ::capnp::any_pointer::Reader::new(self.reader.get_pointer_field(0)).get_as_checked::<_T>()
+    }
+    #[inline]
+    pub fn has_content(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_cap_table(self) -> ::capnp::Result<::capnp::struct_list::Reader<'a,crate::rpc_capnp::cap_descriptor::Owned>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_cap_table(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 0, pointers: 2 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_content(self) -> ::capnp::any_pointer::Builder<'a> {
+      ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(0))
+    }
+    #[inline]
+    pub fn get_content_as<_T: ::capnp::traits::Owned>(self) -> ::capnp::Result<_T::Builder<'a>> {
+      ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(0)).get_as_checked::<_T>()
+    }
+    #[inline]
+    pub fn init_content(self, ) -> ::capnp::any_pointer::Builder<'a> {
+      let mut result = ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(0));
+      result.clear();
+      result
+    }
+    #[inline]
+    pub fn disown_content(&mut self) -> ::capnp::Orphan<::capnp::any_pointer::Owned> {
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_content(&mut self, orphan: ::capnp::Orphan<::capnp::any_pointer::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn has_content(&self) -> bool {
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn get_cap_table(self) -> ::capnp::Result<::capnp::struct_list::Builder<'a,crate::rpc_capnp::cap_descriptor::Owned>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_cap_table(&mut self, value: ::capnp::struct_list::Reader<'a,crate::rpc_capnp::cap_descriptor::Owned>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(1), value, false)
+    }
+    #[inline]
+    pub fn init_cap_table(self, size: u32) -> ::capnp::struct_list::Builder<'a,crate::rpc_capnp::cap_descriptor::Owned> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
+    }
+    #[inline]
+    pub fn disown_cap_table(&mut self) -> ::capnp::Orphan<::capnp::struct_list::Owned<crate::rpc_capnp::cap_descriptor::Owned>> {
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+    }
+    #[inline]
+    pub fn adopt_cap_table(&mut self, orphan: ::capnp::Orphan<::capnp::struct_list::Owned<crate::rpc_capnp::cap_descriptor::Owned>>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(1).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_cap_table(&mut self, size: u32) -> ::capnp::Result<::capnp::struct_list::Builder<'_,crate::rpc_capnp::cap_descriptor::Owned>> {
+      if self.builder.is_pointer_field_null(1) {
+        ::core::result::Result::Ok(self.reborrow().init_cap_table(size))
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(1), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_cap_table(&self) -> bool {
+      !self.builder.is_pointer_field_null(1)
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+    #[inline]
+    pub fn get_content(&self) -> ::capnp::any_pointer::Pipeline {
+      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+    }
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 52] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(59, 116, 150, 61, 34, 97, 14, 154),
+      ::capnp::word(10, 0, 0, 0, 1, 0, 0, 0),
+      ::capnp::word(80, 162, 82, 37, 27, 152, 18, 179),
+      ::capnp::word(2, 0, 7, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 146, 0, 0, 0),
+      ::capnp::word(29, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(25, 0, 0, 0, 119, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 46, 99, 97, 112, 110),
+      ::capnp::word(112, 58, 80, 97, 121, 108, 111, 97),
+      ::capnp::word(100, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(8, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(41, 0, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(36, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(48, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(1, 0, 0, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(45, 0, 0, 0, 74, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(44, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(72, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(99, 111, 110, 116, 101, 110, 116, 0),
+      ::capnp::word(18, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(18, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(99, 97, 112, 84, 97, 98, 108, 101),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(14, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(176, 184, 134, 11, 196, 221, 35, 133),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(14, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc.capnp:Payload";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <::capnp::any_pointer::Owned as ::capnp::introspect::Introspect>::introspect(),
+        1 => <::capnp::struct_list::Owned<crate::rpc_capnp::cap_descriptor::Owned> as ::capnp::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_CONTENT_POINTER_INDEX: usize = 0;
+      pub const FIELD_CAP_TABLE_POINTER_INDEX: usize = 1;
+    }
+    pub const TYPE_ID: u64 = 0x9a0e_6122_3d96_743b;
+  }
+}
+
+pub mod cap_descriptor {
+pub struct CapDescriptor {
+   pub uni: CapDescriptorUnion,
+ pub _attached_fd: u8,  
+}
+impl CapDescriptor {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+ match self.uni {
+ CapDescriptorUnion::UNINITIALIZED => (),
+ CapDescriptorUnion::_None(t) => builder.reborrow().set_none(t),
+ CapDescriptorUnion::_SenderHosted(t) => builder.reborrow().set_sender_hosted(t),
+ CapDescriptorUnion::_SenderPromise(t) => builder.reborrow().set_sender_promise(t),
+ CapDescriptorUnion::_ReceiverHosted(t) => builder.reborrow().set_receiver_hosted(t),
+ CapDescriptorUnion::_ReceiverAnswer(t) => t.build_capnp_struct(builder.reborrow().init_receiver_answer())?,
+ CapDescriptorUnion::_ThirdPartyHosted(t) => t.build_capnp_struct(builder.reborrow().init_third_party_hosted())?,
+ }
+  builder.set_attached_fd(self._attached_fd);
+  ::core::result::Result::Ok(())
+}}
+pub enum CapDescriptorUnion {
+ UNINITIALIZED,
+ _None(()),
+ _SenderHosted(u32),
+ _SenderPromise(u32),
+ _ReceiverHosted(u32),
+ _ReceiverAnswer(crate::rpc_capnp::promised_answer::PromisedAnswer),
+ _ThirdPartyHosted(crate::rpc_capnp::third_party_cap_descriptor::ThirdPartyCapDescriptor),
+}
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for CapDescriptor  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _attached_fd: reader.get_attached_fd(),
+      uni: match reader.which()? {
+        Which::None(value) => CapDescriptorUnion::_None(value),
+        Which::SenderHosted(value) => CapDescriptorUnion::_SenderHosted(value),
+        Which::SenderPromise(value) => CapDescriptorUnion::_SenderPromise(value),
+        Which::ReceiverHosted(value) => CapDescriptorUnion::_ReceiverHosted(value),
+        Which::ReceiverAnswer(value) => CapDescriptorUnion::_ReceiverAnswer(<crate::rpc_capnp::promised_answer::PromisedAnswer as ::core::convert::TryFrom<_>>::try_from(value?)?),
+        Which::ThirdPartyHosted(value) => CapDescriptorUnion::_ThirdPartyHosted(<crate::rpc_capnp::third_party_cap_descriptor::ThirdPartyCapDescriptor as ::core::convert::TryFrom<_>>::try_from(value?)?),
+      },
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for CapDescriptor {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl CapDescriptor {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  pub use self::Which::{None,SenderHosted,SenderPromise,ReceiverHosted,ReceiverAnswer,ThirdPartyHosted};
+
+  /// The members of the union, as `(discriminant, name, field index)` in discriminant order.
+  pub static UNION_MEMBERS: &[(u16, &str, u16)] = &[(0, "none", 0), (1, "senderHosted", 1), (2, "senderPromise", 2), (3, "receiverHosted", 3), (4, "receiverAnswer", 4), (5, "thirdPartyHosted", 5)];
+  /// Returns the schema name of the union member with discriminant `d`.
+  pub fn discriminant_name(d: u16) -> ::core::option::Option<&'static str> {
+    UNION_MEMBERS.get(d as usize).map(|member| member.1)
+  }
+
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub receiver_answer: usize,
+    pub third_party_hosted: usize,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        receiver_answer: crate::rpc_capnp::promised_answer::MIN_SIZE_WORDS - 1,
+        third_party_hosted: crate::rpc_capnp::third_party_cap_descriptor::MIN_SIZE_WORDS - 1,
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    counts.receiver_answer + counts.third_party_hosted
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 1 + 1 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn is_none(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 0
+    }
+    #[inline]
+    pub fn is_sender_hosted(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 1
+    }
+    #[inline]
+    pub fn is_sender_promise(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 2
+    }
+    #[inline]
+    pub fn is_receiver_hosted(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 3
+    }
+    #[inline]
+    pub fn is_receiver_answer(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 4
+    }
+    #[inline]
+    pub fn has_receiver_answer(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 4 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn is_third_party_hosted(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 5
+    }
+    #[inline]
+    pub fn has_third_party_hosted(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 5 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_attached_fd(self) -> u8 {
+      self.reader.get_data_field_mask::<u8>(2, 255)
+    }
+    #[inline]
+    pub fn which(self) -> ::core::result::Result<WhichReader<'a,>, ::capnp::NotInSchema> {
+      match self.reader.get_data_field::<u16>(0) {
+        0 => {
+          ::core::result::Result::Ok(Which::None(
+            ()
+          ))
+        }
+        1 => {
+          ::core::result::Result::Ok(Which::SenderHosted(
+            self.reader.get_data_field::<u32>(1)
+          ))
+        }
+        2 => {
+          ::core::result::Result::Ok(Which::SenderPromise(
+            self.reader.get_data_field::<u32>(1)
+          ))
+        }
+        3 => {
+          ::core::result::Result::Ok(Which::ReceiverHosted(
+            self.reader.get_data_field::<u32>(1)
+          ))
+        }
+        4 => {
+          ::core::result::Result::Ok(Which::ReceiverAnswer(
+            ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        5 => {
+          ::core::result::Result::Ok(Which::ThirdPartyHosted(
+            ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        x => ::core::result::Result::Err(::capnp::NotInSchema(x))
+      }
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 1, pointers: 1 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn set_none(&mut self, _value: ())  {
+      self.builder.set_data_field::<u16>(0, 0);
+    }
+    #[inline]
+    pub fn is_none(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 0
+    }
+    #[inline]
+    pub fn set_sender_hosted(&mut self, value: u32)  {
+      self.builder.set_data_field::<u16>(0, 1);
+      self.builder.set_data_field::<u32>(1, value);
+    }
+    #[inline]
+    pub fn set_sender_hosted_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_sender_hosted(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn is_sender_hosted(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 1
+    }
+    #[inline]
+    pub fn set_sender_promise(&mut self, value: u32)  {
+      self.builder.set_data_field::<u16>(0, 2);
+      self.builder.set_data_field::<u32>(1, value);
+    }
+    #[inline]
+    pub fn set_sender_promise_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_sender_promise(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn is_sender_promise(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 2
+    }
+    #[inline]
+    pub fn set_receiver_hosted(&mut self, value: u32)  {
+      self.builder.set_data_field::<u16>(0, 3);
+      self.builder.set_data_field::<u32>(1, value);
+    }
+    #[inline]
+    pub fn set_receiver_hosted_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_receiver_hosted(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn is_receiver_hosted(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 3
+    }
+    #[inline]
+    pub fn set_receiver_answer(&mut self, value: crate::rpc_capnp::promised_answer::Reader<'_>) -> ::capnp::Result<()> {
+      self.builder.set_data_field::<u16>(0, 4);
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_receiver_answer(self, ) -> crate::rpc_capnp::promised_answer::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 4);
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_receiver_answer(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::promised_answer::Owned> {
+      if self.builder.get_data_field::<u16>(0) != 4 {
+        return ::capnp::Orphan::null();
+      }
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_receiver_answer(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::promised_answer::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(0, 4);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_or_init_receiver_answer(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::promised_answer::Builder<'_>> {
+      if self.builder.get_data_field::<u16>(0) != 4 || self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_receiver_answer())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn is_receiver_answer(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 4
+    }
+    #[inline]
+    pub fn has_receiver_answer(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 4 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn set_third_party_hosted(&mut self, value: crate::rpc_capnp::third_party_cap_descriptor::Reader<'_>) -> ::capnp::Result<()> {
+      self.builder.set_data_field::<u16>(0, 5);
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_third_party_hosted(self, ) -> crate::rpc_capnp::third_party_cap_descriptor::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 5);
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_third_party_hosted(&mut self) -> ::capnp::Orphan<crate::rpc_capnp::third_party_cap_descriptor::Owned> {
+      if self.builder.get_data_field::<u16>(0) != 5 {
+        return ::capnp::Orphan::null();
+      }
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_third_party_hosted(&mut self, orphan: ::capnp::Orphan<crate::rpc_capnp::third_party_cap_descriptor::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(0, 5);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_or_init_third_party_hosted(&mut self, ) -> ::capnp::Result<crate::rpc_capnp::third_party_cap_descriptor::Builder<'_>> {
+      if self.builder.get_data_field::<u16>(0) != 5 || self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_third_party_hosted())
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn is_third_party_hosted(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 5
+    }
+    #[inline]
+    pub fn has_third_party_hosted(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 5 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn get_attached_fd(self) -> u8 {
+      self.builder.get_data_field_mask::<u8>(2, 255)
+    }
+    #[inline]
+    pub fn set_attached_fd(&mut self, value: u8)  {
+      self.builder.set_data_field_mask::<u8>(2, value, 255);
+    }
+    #[inline]
+    pub fn set_attached_fd_from(&mut self, value: impl ::core::convert::TryInto<u8>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_attached_fd(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn which(self) -> ::core::result::Result<WhichBuilder<'a,>, ::capnp::NotInSchema> {
+      match self.builder.get_data_field::<u16>(0) {
+        0 => {
+          ::core::result::Result::Ok(Which::None(
+            ()
+          ))
+        }
+        1 => {
+          ::core::result::Result::Ok(Which::SenderHosted(
+            self.builder.get_data_field::<u32>(1)
+          ))
+        }
+        2 => {
+          ::core::result::Result::Ok(Which::SenderPromise(
+            self.builder.get_data_field::<u32>(1)
+          ))
+        }
+        3 => {
+          ::core::result::Result::Ok(Which::ReceiverHosted(
+            self.builder.get_data_field::<u32>(1)
+          ))
+        }
+        4 => {
+          ::core::result::Result::Ok(Which::ReceiverAnswer(
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        5 => {
+          ::core::result::Result::Ok(Which::ThirdPartyHosted(
+            ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        x => ::core::result::Result::Err(::capnp::NotInSchema(x))
+      }
+    }
+    /// Sets the union to the variant that is active in `other`, copied from there as stored.
+    /// The fields outside the union are left unchanged.
+    ///
+    /// Returns an `EnumValueOrUnionDiscriminantNotPresent` error without changing anything if
+    /// `other` has a variant that is not in the schema, for example one added by a newer version
+    /// of it. A union nested in a group of the active variant is handled the same way, but by
+    /// then the group has been partially copied.
+    pub fn copy_union_from(&mut self, other: Reader<'_,>) -> ::capnp::Result<()> {
+      match other.reader.get_data_field::<u16>(0) {
+        0 => {
+          self.builder.set_data_field::<u16>(0, 0);
+        }
+        1 => {
+          self.builder.set_data_field::<u16>(0, 1);
+          self.builder.set_data_field::<u32>(1, other.reader.get_data_field::<u32>(1));
+        }
+        2 => {
+          self.builder.set_data_field::<u16>(0, 2);
+          self.builder.set_data_field::<u32>(1, other.reader.get_data_field::<u32>(1));
+        }
+        3 => {
+          self.builder.set_data_field::<u16>(0, 3);
+          self.builder.set_data_field::<u32>(1, other.reader.get_data_field::<u32>(1));
+        }
+        4 => {
+          self.builder.set_data_field::<u16>(0, 4);
+          ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        5 => {
+          self.builder.set_data_field::<u16>(0, 5);
+          ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), ::capnp::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        x => return ::core::result::Result::Err(::capnp::NotInSchema(x).into()),
+      }
+      ::core::result::Result::Ok(())
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 129] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(176, 184, 134, 11, 196, 221, 35, 133),
+      ::capnp::word(10, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(80, 162, 82, 37, 27, 152, 18, 179),
+      ::capnp::word(1, 0, 7, 0, 0, 0, 6, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 194, 0, 0, 0),
+      ::capnp::word(29, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(25, 0, 0, 0, 143, 1, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 46, 99, 97, 112, 110),
+      ::capnp::word(112, 58, 67, 97, 112, 68, 101, 115),
+      ::capnp::word(99, 114, 105, 112, 116, 111, 114, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(28, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 255, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(181, 0, 0, 0, 42, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(176, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(188, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(1, 0, 254, 255, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(185, 0, 0, 0, 106, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(184, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(196, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(2, 0, 253, 255, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 2, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(193, 0, 0, 0, 114, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(192, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(204, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(3, 0, 252, 255, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 3, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(201, 0, 0, 0, 122, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(200, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(212, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(4, 0, 251, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 4, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(209, 0, 0, 0, 122, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(208, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(220, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(5, 0, 250, 255, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 5, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(217, 0, 0, 0, 138, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(220, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(232, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(6, 0, 0, 0, 2, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 6, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(229, 0, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(228, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(240, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(110, 111, 110, 101, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 101, 110, 100, 101, 114, 72, 111),
+      ::capnp::word(115, 116, 101, 100, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 101, 110, 100, 101, 114, 80, 114),
+      ::capnp::word(111, 109, 105, 115, 101, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 101, 99, 101, 105, 118, 101, 114),
+      ::capnp::word(72, 111, 115, 116, 101, 100, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 101, 99, 101, 105, 118, 101, 114),
+      ::capnp::word(65, 110, 115, 119, 101, 114, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(160, 28, 111, 205, 214, 177, 0, 216),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(116, 104, 105, 114, 100, 80, 97, 114),
+      ::capnp::word(116, 121, 72, 111, 115, 116, 101, 100),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(125, 2, 240, 225, 253, 7, 112, 211),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(97, 116, 116, 97, 99, 104, 101, 100),
+      ::capnp::word(70, 100, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(6, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(6, 0, 255, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc.capnp:CapDescriptor";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <() as ::capnp::introspect::Introspect>::introspect(),
+        1 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        2 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        3 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        4 => <crate::rpc_capnp::promised_answer::Owned as ::capnp::introspect::Introspect>::introspect(),
+        5 => <crate::rpc_capnp::third_party_cap_descriptor::Owned as ::capnp::introspect::Introspect>::introspect(),
+        6 => <u8 as ::capnp::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[6];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[0,1,2,3,4,5];
+    pub mod layout {
+      pub const DISCRIMINANT_OFFSET: usize = 0;
+      pub const FIELD_NONE_DISCRIMINANT: u16 = 0;
+      pub const FIELD_SENDER_HOSTED_DISCRIMINANT: u16 = 1;
+      pub const FIELD_SENDER_HOSTED_OFFSET: usize = 4;
+      pub const FIELD_SENDER_PROMISE_DISCRIMINANT: u16 = 2;
+      pub const FIELD_SENDER_PROMISE_OFFSET: usize = 4;
+      pub const FIELD_RECEIVER_HOSTED_DISCRIMINANT: u16 = 3;
+      pub const FIELD_RECEIVER_HOSTED_OFFSET: usize = 4;
+      pub const FIELD_RECEIVER_ANSWER_DISCRIMINANT: u16 = 4;
+      pub const FIELD_RECEIVER_ANSWER_POINTER_INDEX: usize = 0;
+      pub const FIELD_THIRD_PARTY_HOSTED_DISCRIMINANT: u16 = 5;
+      pub const FIELD_THIRD_PARTY_HOSTED_POINTER_INDEX: usize = 0;
+      pub const FIELD_ATTACHED_FD_OFFSET: usize = 2;
+    }
+    pub const TYPE_ID: u64 = 0x8523_ddc4_0b86_b8b0;
+  }
+  pub enum Which<A0,A1> {
+    None(()),
+    SenderHosted(u32),
+    SenderPromise(u32),
+    ReceiverHosted(u32),
+    ReceiverAnswer(A0),
+    ThirdPartyHosted(A1),
+  }
+  pub type WhichReader<'a,> = Which<::capnp::Result<crate::rpc_capnp::promised_answer::Reader<'a>>,::capnp::Result<crate::rpc_capnp::third_party_cap_descriptor::Reader<'a>>>;
+  pub type WhichBuilder<'a,> = Which<::capnp::Result<crate::rpc_capnp::promised_answer::Builder<'a>>,::capnp::Result<crate::rpc_capnp::third_party_cap_descriptor::Builder<'a>>>;
+}
+
+pub mod promised_answer {
+pub struct PromisedAnswer {
+ pub _question_id: u32,
+ pub _transform: Vec<crate::rpc_capnp::promised_answer::op::Op>,  
+}
+impl PromisedAnswer {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+  builder.set_question_id(self._question_id);
+            
+if self._transform.len() > 0 {
+                let mut list_builder = builder.reborrow().init_transform(::capnp::private::checked_len(self._transform.len())?);
+                for (i, item) in self._transform.into_iter().enumerate() {
+                    item.build_capnp_struct(list_builder.reborrow().get(i as u32))?;
+                }
+            }
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for PromisedAnswer  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _question_id: reader.get_question_id(),
+      _transform: reader.get_transform()?.iter().map(|item| ::capnp::Result::Ok(<crate::rpc_capnp::promised_answer::op::Op as ::core::convert::TryFrom<_>>::try_from(item)?)).collect::<::capnp::Result<Vec<_>>>()?,
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for PromisedAnswer {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl PromisedAnswer {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub transform: usize,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        transform: 0,
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    1 + counts.transform * (crate::rpc_capnp::promised_answer::op::MIN_SIZE_WORDS - 1)
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 1 + 1 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_question_id(self) -> u32 {
+      self.reader.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn get_transform(self) -> ::capnp::Result<::capnp::struct_list::Reader<'a,crate::rpc_capnp::promised_answer::op::Owned>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_transform(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 1, pointers: 1 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_question_id(self) -> u32 {
+      self.builder.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn set_question_id(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(0, value);
+    }
+    #[inline]
+    pub fn set_question_id_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_question_id(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_transform(self) -> ::capnp::Result<::capnp::struct_list::Builder<'a,crate::rpc_capnp::promised_answer::op::Owned>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_transform(&mut self, value: ::capnp::struct_list::Reader<'a,crate::rpc_capnp::promised_answer::op::Owned>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_transform(self, size: u32) -> ::capnp::struct_list::Builder<'a,crate::rpc_capnp::promised_answer::op::Owned> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), size)
+    }
+    #[inline]
+    pub fn disown_transform(&mut self) -> ::capnp::Orphan<::capnp::struct_list::Owned<crate::rpc_capnp::promised_answer::op::Owned>> {
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_transform(&mut self, orphan: ::capnp::Orphan<::capnp::struct_list::Owned<crate::rpc_capnp::promised_answer::op::Owned>>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_transform(&mut self, size: u32) -> ::capnp::Result<::capnp::struct_list::Builder<'_,crate::rpc_capnp::promised_answer::op::Owned>> {
+      if self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_transform(size))
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_transform(&self) -> bool {
+      !self.builder.is_pointer_field_null(0)
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 57] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(160, 28, 111, 205, 214, 177, 0, 216),
+      ::capnp::word(10, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(80, 162, 82, 37, 27, 152, 18, 179),
+      ::capnp::word(1, 0, 7, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 202, 0, 0, 0),
+      ::capnp::word(33, 0, 0, 0, 23, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(41, 0, 0, 0, 119, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 46, 99, 97, 112, 110),
+      ::capnp::word(112, 58, 80, 114, 111, 109, 105, 115),
+      ::capnp::word(101, 100, 65, 110, 115, 119, 101, 114),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(4, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(129, 144, 86, 21, 68, 148, 22, 243),
+      ::capnp::word(1, 0, 0, 0, 26, 0, 0, 0),
+      ::capnp::word(79, 112, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(41, 0, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(40, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(52, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(49, 0, 0, 0, 82, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(48, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(76, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(113, 117, 101, 115, 116, 105, 111, 110),
+      ::capnp::word(73, 100, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(116, 114, 97, 110, 115, 102, 111, 114),
+      ::capnp::word(109, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(14, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(129, 144, 86, 21, 68, 148, 22, 243),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(14, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc.capnp:PromisedAnswer";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        1 => <::capnp::struct_list::Owned<crate::rpc_capnp::promised_answer::op::Owned> as ::capnp::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_QUESTION_ID_OFFSET: usize = 0;
+      pub const FIELD_TRANSFORM_POINTER_INDEX: usize = 0;
+    }
+    pub const TYPE_ID: u64 = 0xd800_b1d6_cd6f_1ca0;
+  }
+
+  pub mod op {
+  
+  impl Op {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+ match self {
+ Op::UNINITIALIZED => (),
+ Op::_Noop(t) => builder.reborrow().set_noop(t),
+ Op::_GetPointerField(t) => builder.reborrow().set_get_pointer_field(t),
+ }
+  ::core::result::Result::Ok(())
+}}
+  pub enum Op {
+ UNINITIALIZED,
+ _Noop(()),
+ _GetPointerField(u16),
+}
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Op  {
+    type Error = ::capnp::Error;
+    fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(match reader.which()? {
+        Which::Noop(value) => Op::_Noop(value),
+        Which::GetPointerField(value) => Op::_GetPointerField(value),
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for Op {
+    type Error = ::capnp::Error;
+    fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl Op {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    pub use self::Which::{Noop,GetPointerField};
+
+    /// The members of the union, as `(discriminant, name, field index)` in discriminant order.
+    pub static UNION_MEMBERS: &[(u16, &str, u16)] = &[(0, "noop", 0), (1, "getPointerField", 1)];
+    /// Returns the schema name of the union member with discriminant `d`.
+    pub fn discriminant_name(d: u16) -> ::core::option::Option<&'static str> {
+      UNION_MEMBERS.get(d as usize).map(|member| member.1)
+    }
+
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(_counts: FieldCounts) -> usize { 0 }
+    /// Words taken up by a message holding just this struct: its data and pointer sections
+    /// plus the root pointer.
+    pub const MIN_SIZE_WORDS: usize = 1 + 0 + 1;
+    /// An upper bound on the words that a single-segment message holding this struct takes up.
+    pub const fn total_words_for(counts: FieldCounts) -> usize {
+      MIN_SIZE_WORDS + variable_words_for(counts)
+    }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+        self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn is_noop(&self) -> bool {
+        self.reader.get_data_field::<u16>(0) == 0
+      }
+      #[inline]
+      pub fn is_get_pointer_field(&self) -> bool {
+        self.reader.get_data_field::<u16>(0) == 1
+      }
+      #[inline]
+      pub fn which(self) -> ::core::result::Result<WhichReader, ::capnp::NotInSchema> {
+        match self.reader.get_data_field::<u16>(0) {
+          0 => {
+            ::core::result::Result::Ok(Which::Noop(
+              ()
+            ))
+          }
+          1 => {
+            ::core::result::Result::Ok(Which::GetPointerField(
+              self.reader.get_data_field::<u16>(1)
+            ))
+          }
+          x => ::core::result::Result::Err(::capnp::NotInSchema(x))
+        }
+      }
+    }
+
+    pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+    impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 1, pointers: 0 };
+    }
+    impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+        self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn set_noop(&mut self, _value: ())  {
+        self.builder.set_data_field::<u16>(0, 0);
+      }
+      #[inline]
+      pub fn is_noop(&self) -> bool {
+        self.builder.get_data_field::<u16>(0) == 0
+      }
+      #[inline]
+      pub fn set_get_pointer_field(&mut self, value: u16)  {
+        self.builder.set_data_field::<u16>(0, 1);
+        self.builder.set_data_field::<u16>(1, value);
+      }
+      #[inline]
+      pub fn set_get_pointer_field_from(&mut self, value: impl ::core::convert::TryInto<u16>) -> ::capnp::Result<()> {
+        let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+        self.set_get_pointer_field(value);
+        ::core::result::Result::Ok(())
+      }
+      #[inline]
+      pub fn is_get_pointer_field(&self) -> bool {
+        self.builder.get_data_field::<u16>(0) == 1
+      }
+      #[inline]
+      pub fn which(self) -> ::core::result::Result<WhichBuilder, ::capnp::NotInSchema> {
+        match self.builder.get_data_field::<u16>(0) {
+          0 => {
+            ::core::result::Result::Ok(Which::Noop(
+              ()
+            ))
+          }
+          1 => {
+            ::core::result::Result::Ok(Which::GetPointerField(
+              self.builder.get_data_field::<u16>(1)
+            ))
+          }
+          x => ::core::result::Result::Err(::capnp::NotInSchema(x))
+        }
+      }
+      /// Sets the union to the variant that is active in `other`, copied from there as stored.
+      /// The fields outside the union are left unchanged.
+      ///
+      /// Returns an `EnumValueOrUnionDiscriminantNotPresent` error without changing anything if
+      /// `other` has a variant that is not in the schema, for example one added by a newer version
+      /// of it. A union nested in a group of the active variant is handled the same way, but by
+      /// then the group has been partially copied.
+      pub fn copy_union_from(&mut self, other: Reader<'_,>) -> ::capnp::Result<()> {
+        match other.reader.get_data_field::<u16>(0) {
+          0 => {
+            self.builder.set_data_field::<u16>(0, 0);
+          }
+          1 => {
+            self.builder.set_data_field::<u16>(0, 1);
+            self.builder.set_data_field::<u16>(1, other.reader.get_data_field::<u16>(1));
+          }
+          x => return ::core::result::Result::Err(::capnp::NotInSchema(x).into()),
+        }
+        ::core::result::Result::Ok(())
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+      let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [::capnp::Word; 49] = [
+        ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+        ::capnp::word(129, 144, 86, 21, 68, 148, 22, 243),
+        ::capnp::word(25, 0, 0, 0, 1, 0, 1, 0),
+        ::capnp::word(160, 28, 111, 205, 214, 177, 0, 216),
+        ::capnp::word(0, 0, 7, 0, 0, 0, 2, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(21, 0, 0, 0, 226, 0, 0, 0),
+        ::capnp::word(33, 0, 0, 0, 7, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(29, 0, 0, 0, 119, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(114, 112, 99, 46, 99, 97, 112, 110),
+        ::capnp::word(112, 58, 80, 114, 111, 109, 105, 115),
+        ::capnp::word(101, 100, 65, 110, 115, 119, 101, 114),
+        ::capnp::word(46, 79, 112, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+        ::capnp::word(8, 0, 0, 0, 3, 0, 4, 0),
+        ::capnp::word(0, 0, 255, 255, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(41, 0, 0, 0, 42, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(36, 0, 0, 0, 3, 0, 1, 0),
+        ::capnp::word(48, 0, 0, 0, 2, 0, 1, 0),
+        ::capnp::word(1, 0, 254, 255, 1, 0, 0, 0),
+        ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(45, 0, 0, 0, 130, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(44, 0, 0, 0, 3, 0, 1, 0),
+        ::capnp::word(56, 0, 0, 0, 2, 0, 1, 0),
+        ::capnp::word(110, 111, 111, 112, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(103, 101, 116, 80, 111, 105, 110, 116),
+        ::capnp::word(101, 114, 70, 105, 101, 108, 100, 0),
+        ::capnp::word(7, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(7, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "rpc.capnp:PromisedAnswer.Op";
+      pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        match index {
+          0 => <() as ::capnp::introspect::Introspect>::introspect(),
+          1 => <u16 as ::capnp::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[0,1];
+      pub mod layout {
+        pub const DISCRIMINANT_OFFSET: usize = 0;
+        pub const FIELD_NOOP_DISCRIMINANT: u16 = 0;
+        pub const FIELD_GET_POINTER_FIELD_DISCRIMINANT: u16 = 1;
+        pub const FIELD_GET_POINTER_FIELD_OFFSET: usize = 2;
+      }
+      pub const TYPE_ID: u64 = 0xf316_9444_1556_9081;
+    }
+    pub enum Which {
+      Noop(()),
+      GetPointerField(u16),
+    }
+    pub type WhichReader = Which;
+    pub type WhichBuilder = Which;
+  }
+}
+
+pub mod third_party_cap_descriptor {
+pub struct ThirdPartyCapDescriptor {
+ pub _id: Box<dyn ::capnp::private::capability::ClientHook>,
+ pub _vine_id: u32,  
+}
+impl ThirdPartyCapDescriptor {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+  builder.reborrow().init_id().set_as_capability(self._id);
+  builder.set_vine_id(self._vine_id);
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for ThirdPartyCapDescriptor  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _id: reader.get_id().get_as_capability::<::capnp::capability::Client>()?.hook,
+      _vine_id: reader.get_vine_id(),
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for ThirdPartyCapDescriptor {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl ThirdPartyCapDescriptor {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub id: usize,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        id: 0,
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    counts.id
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 1 + 1 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_id(self) -> ::capnp::any_pointer::Reader<'a> {
+      ::capnp::any_pointer::Reader::new(self.reader.get_pointer_field(0))
+    }
+    #[inline]
+    pub fn get_id_as<_T: ::capnp::traits::Owned>(self) -> ::capnp::Result<_T::Reader<'a>> {
+      ::capnp::any_pointer::Reader::new(self.reader.get_pointer_field(0)).get_as_checked::<_T>()
+    }
+    #[inline]
+    pub fn has_id(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_vine_id(self) -> u32 {
+      self.reader.get_data_field::<u32>(0)
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 1, pointers: 1 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_id(self) -> ::capnp::any_pointer::Builder<'a> {
+      ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(0))
+    }
+    #[inline]
+    pub fn get_id_as<_T: ::capnp::traits::Owned>(self) -> ::capnp::Result<_T::Builder<'a>> {
+      ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(0)).get_as_checked::<_T>()
+    }
+    #[inline]
+    pub fn init_id(self, ) -> ::capnp::any_pointer::Builder<'a> {
+      let mut result = ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(0));
+      result.clear();
+      result
+    }
+    #[inline]
+    pub fn disown_id(&mut self) -> ::capnp::Orphan<::capnp::any_pointer::Owned> {
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_id(&mut self, orphan: ::capnp::Orphan<::capnp::any_pointer::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn has_id(&self) -> bool {
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn get_vine_id(self) -> u32 {
+      self.builder.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn set_vine_id(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(0, value);
+    }
+    #[inline]
+    pub fn set_vine_id_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_vine_id(value);
+      ::core::result::Result::Ok(())
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+    #[inline]
+    pub fn get_id(&self) -> ::capnp::any_pointer::Pipeline {
+      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+    }
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 49] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(125, 2, 240, 225, 253, 7, 112, 211),
+      ::capnp::word(10, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(80, 162, 82, 37, 27, 152, 18, 179),
+      ::capnp::word(1, 0, 7, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 18, 1, 0, 0),
+      ::capnp::word(37, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(33, 0, 0, 0, 119, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 46, 99, 97, 112, 110),
+      ::capnp::word(112, 58, 84, 104, 105, 114, 100, 80),
+      ::capnp::word(97, 114, 116, 121, 67, 97, 112, 68),
+      ::capnp::word(101, 115, 99, 114, 105, 112, 116, 111),
+      ::capnp::word(114, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(8, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(41, 0, 0, 0, 26, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(36, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(48, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(45, 0, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(40, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(52, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(105, 100, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(18, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(18, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(118, 105, 110, 101, 73, 100, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc.capnp:ThirdPartyCapDescriptor";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <::capnp::any_pointer::Owned as ::capnp::introspect::Introspect>::introspect(),
+        1 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_ID_POINTER_INDEX: usize = 0;
+      pub const FIELD_VINE_ID_OFFSET: usize = 0;
+    }
+    pub const TYPE_ID: u64 = 0xd370_07fd_e1f0_027d;
+  }
+}
+
+pub mod exception {
+pub struct Exception {
+ pub _reason: String,
+ pub _obsolete_is_callers_fault: bool,
+ pub _obsolete_durability: u16,
+ pub _type: crate::rpc_capnp::exception::Type,
+ pub _trace: String,
+ pub _details: Vec<crate::rpc_capnp::exception::detail::Detail>,  
+}
+impl Exception {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+  builder.set_reason(self._reason.as_str().into());
+  builder.set_obsolete_is_callers_fault(self._obsolete_is_callers_fault);
+  builder.set_obsolete_durability(self._obsolete_durability);
+  builder.set_type(self._type);
+  builder.set_trace(self._trace.as_str().into());
+            
+if self._details.len() > 0 {
+                let mut list_builder = builder.reborrow().init_details(::capnp::private::checked_len(self._details.len())?);
+                for (i, item) in self._details.into_iter().enumerate() {
+                    item.build_capnp_struct(list_builder.reborrow().get(i as u32))?;
+                }
+            }
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Exception  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _reason: reader.get_reason()?.to_string()?,
+      _obsolete_is_callers_fault: reader.get_obsolete_is_callers_fault(),
+      _obsolete_durability: reader.get_obsolete_durability(),
+      _type: reader.get_type()?,
+      _trace: reader.get_trace()?.to_string()?,
+      _details: reader.get_details()?.iter().map(|item| ::capnp::Result::Ok(<crate::rpc_capnp::exception::detail::Detail as ::core::convert::TryFrom<_>>::try_from(item)?)).collect::<::capnp::Result<Vec<_>>>()?,
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for Exception {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl Exception {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub reason: usize,
+    pub trace: usize,
+    pub details: usize,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        reason: 0,
+        trace: 0,
+        details: 0,
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    (counts.reason + 8) / 8 + (counts.trace + 8) / 8 + 1 + counts.details * (crate::rpc_capnp::exception::detail::MIN_SIZE_WORDS - 1)
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 1 + 3 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_reason(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_reason(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_obsolete_is_callers_fault(self) -> bool {
+      self.reader.get_bool_field(0)
+    }
+    #[inline]
+    pub fn get_obsolete_durability(self) -> u16 {
+      self.reader.get_data_field::<u16>(1)
+    }
+    #[inline]
+    pub fn get_type(self) -> ::core::result::Result<crate::rpc_capnp::exception::Type,::capnp::NotInSchema> {
+      ::core::convert::TryInto::try_into(self.reader.get_data_field::<u16>(2))
+    }
+    #[inline]
+    pub fn get_trace(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_trace(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
+    }
+    #[inline]
+    pub fn get_details(self) -> ::capnp::Result<::capnp::struct_list::Reader<'a,crate::rpc_capnp::exception::detail::Owned>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(2), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_details(&self) -> bool {
+      !self.reader.get_pointer_field(2).is_null()
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 1, pointers: 3 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_reason(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_reason(&mut self, value: ::capnp::text::Reader<'_>)  {
+      self.builder.reborrow().get_pointer_field(0).set_text(value);
+    }
+    #[inline]
+    pub fn init_reason(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(0).init_text(size)
+    }
+    #[inline]
+    pub fn disown_reason(&mut self) -> ::capnp::Orphan<::capnp::text::Owned> {
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_reason(&mut self, orphan: ::capnp::Orphan<::capnp::text::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_reason(&mut self, size: u32) -> ::capnp::Result<::capnp::text::Builder<'_>> {
+      if self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_reason(size))
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_reason(&self) -> bool {
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn get_obsolete_is_callers_fault(self) -> bool {
+      self.builder.get_bool_field(0)
+    }
+    #[inline]
+    pub fn set_obsolete_is_callers_fault(&mut self, value: bool)  {
+      self.builder.set_bool_field(0, value);
+    }
+    #[inline]
+    pub fn get_obsolete_durability(self) -> u16 {
+      self.builder.get_data_field::<u16>(1)
+    }
+    #[inline]
+    pub fn set_obsolete_durability(&mut self, value: u16)  {
+      self.builder.set_data_field::<u16>(1, value);
+    }
+    #[inline]
+    pub fn set_obsolete_durability_from(&mut self, value: impl ::core::convert::TryInto<u16>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_obsolete_durability(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_type(self) -> ::core::result::Result<crate::rpc_capnp::exception::Type,::capnp::NotInSchema> {
+      ::core::convert::TryInto::try_into(self.builder.get_data_field::<u16>(2))
+    }
+    #[inline]
+    pub fn set_type(&mut self, value: crate::rpc_capnp::exception::Type)  {
+      self.builder.set_data_field::<u16>(2, value as u16);
+    }
+    #[inline]
+    pub fn get_trace(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_trace(&mut self, value: ::capnp::text::Reader<'_>)  {
+      self.builder.reborrow().get_pointer_field(1).set_text(value);
+    }
+    #[inline]
+    pub fn init_trace(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(1).init_text(size)
+    }
+    #[inline]
+    pub fn disown_trace(&mut self) -> ::capnp::Orphan<::capnp::text::Owned> {
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+    }
+    #[inline]
+    pub fn adopt_trace(&mut self, orphan: ::capnp::Orphan<::capnp::text::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(1).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_trace(&mut self, size: u32) -> ::capnp::Result<::capnp::text::Builder<'_>> {
+      if self.builder.is_pointer_field_null(1) {
+        ::core::result::Result::Ok(self.reborrow().init_trace(size))
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(1), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_trace(&self) -> bool {
+      !self.builder.is_pointer_field_null(1)
+    }
+    #[inline]
+    pub fn get_details(self) -> ::capnp::Result<::capnp::struct_list::Builder<'a,crate::rpc_capnp::exception::detail::Owned>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(2), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_details(&mut self, value: ::capnp::struct_list::Reader<'a,crate::rpc_capnp::exception::detail::Owned>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(2), value, false)
+    }
+    #[inline]
+    pub fn init_details(self, size: u32) -> ::capnp::struct_list::Builder<'a,crate::rpc_capnp::exception::detail::Owned> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), size)
+    }
+    #[inline]
+    pub fn disown_details(&mut self) -> ::capnp::Orphan<::capnp::struct_list::Owned<crate::rpc_capnp::exception::detail::Owned>> {
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(2).disown())
+    }
+    #[inline]
+    pub fn adopt_details(&mut self, orphan: ::capnp::Orphan<::capnp::struct_list::Owned<crate::rpc_capnp::exception::detail::Owned>>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(2).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_details(&mut self, size: u32) -> ::capnp::Result<::capnp::struct_list::Builder<'_,crate::rpc_capnp::exception::detail::Owned>> {
+      if self.builder.is_pointer_field_null(2) {
+        ::core::result::Result::Ok(self.reborrow().init_details(size))
+      } else {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(2), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_details(&self) -> bool {
+      !self.builder.is_pointer_field_null(2)
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 121] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(26, 105, 207, 58, 6, 183, 37, 214),
+      ::capnp::word(10, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(80, 162, 82, 37, 27, 152, 18, 179),
+      ::capnp::word(3, 0, 7, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 162, 0, 0, 0),
+      ::capnp::word(29, 0, 0, 0, 39, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(49, 0, 0, 0, 87, 1, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 46, 99, 97, 112, 110),
+      ::capnp::word(112, 58, 69, 120, 99, 101, 112, 116),
+      ::capnp::word(105, 111, 110, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(88, 189, 76, 63, 226, 150, 140, 178),
+      ::capnp::word(9, 0, 0, 0, 42, 0, 0, 0),
+      ::capnp::word(221, 248, 68, 29, 18, 79, 193, 214),
+      ::capnp::word(5, 0, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(84, 121, 112, 101, 0, 0, 0, 0),
+      ::capnp::word(68, 101, 116, 97, 105, 108, 0, 0),
+      ::capnp::word(24, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(153, 0, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(148, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(160, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(2, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(157, 0, 0, 0, 186, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(160, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(172, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(3, 0, 0, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 2, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(169, 0, 0, 0, 154, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(172, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(184, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(1, 0, 0, 0, 2, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 3, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(181, 0, 0, 0, 42, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(176, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(188, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(4, 0, 0, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 4, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(185, 0, 0, 0, 50, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(180, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(192, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(5, 0, 0, 0, 2, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 5, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(189, 0, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(184, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(212, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(114, 101, 97, 115, 111, 110, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(111, 98, 115, 111, 108, 101, 116, 101),
+      ::capnp::word(73, 115, 67, 97, 108, 108, 101, 114),
+      ::capnp::word(115, 70, 97, 117, 108, 116, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(111, 98, 115, 111, 108, 101, 116, 101),
+      ::capnp::word(68, 117, 114, 97, 98, 105, 108, 105),
+      ::capnp::word(116, 121, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(7, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(7, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(116, 121, 112, 101, 0, 0, 0, 0),
+      ::capnp::word(15, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(88, 189, 76, 63, 226, 150, 140, 178),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(15, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(116, 114, 97, 99, 101, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 101, 116, 97, 105, 108, 115, 0),
+      ::capnp::word(14, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(16, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(221, 248, 68, 29, 18, 79, 193, 214),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(14, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc.capnp:Exception";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <::capnp::text::Owned as ::capnp::introspect::Introspect>::introspect(),
+        1 => <bool as ::capnp::introspect::Introspect>::introspect(),
+        2 => <u16 as ::capnp::introspect::Introspect>::introspect(),
+        3 => <crate::rpc_capnp::exception::Type as ::capnp::introspect::Introspect>::introspect(),
+        4 => <::capnp::text::Owned as ::capnp::introspect::Introspect>::introspect(),
+        5 => <::capnp::struct_list::Owned<crate::rpc_capnp::exception::detail::Owned> as ::capnp::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1,2,3,4,5];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_REASON_POINTER_INDEX: usize = 0;
+      pub const FIELD_OBSOLETE_IS_CALLERS_FAULT_BIT_OFFSET: usize = 0;
+      pub const FIELD_OBSOLETE_DURABILITY_OFFSET: usize = 2;
+      pub const FIELD_TYPE_OFFSET: usize = 4;
+      pub const FIELD_TRACE_POINTER_INDEX: usize = 1;
+      pub const FIELD_DETAILS_POINTER_INDEX: usize = 2;
+    }
+    pub const TYPE_ID: u64 = 0xd625_b706_3acf_691a;
+  }
+
+  #[repr(u16)]
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub enum Type {
+    Failed = 0,
+    Overloaded = 1,
+    Disconnected = 2,
+    Unimplemented = 3,
+  }
+
+  impl ::capnp::introspect::Introspect for Type {
+    fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Enum(::capnp::introspect::RawEnumSchema { encoded_node: &type_::ENCODED_NODE, annotation_types: type_::get_annotation_types }).into() }
+  }
+  impl Type {
+    /// Every enumerant, in ordinal order.
+    pub const VALUES: &[Self] = &[Self::Failed, Self::Overloaded, Self::Disconnected, Self::Unimplemented];
+    /// The number of enumerants.
+    pub const COUNT: u16 = 4;
+    /// Iterates over every enumerant, in ordinal order.
+    pub fn iter() -> impl ::core::iter::Iterator<Item = Self> {
+      Self::VALUES.iter().copied()
+    }
+    /// The `(enumerant_index, annotation_id)` pair of each annotation on a enumerant.
+    pub const ENUMERANT_ANNOTATIONS: &'static [(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the enumerant at `enumerant_index`, if
+    /// it has one.
+    pub fn enumerant_annotation(enumerant_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+      let children = ::capnp::schema::EnumSchema::new(::capnp::introspect::RawEnumSchema { encoded_node: &type_::ENCODED_NODE, annotation_types: type_::get_annotation_types }).get_enumerants().ok()?;
+      if enumerant_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(enumerant_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+  }
+  impl <'a> ::core::convert::From<Type> for ::capnp::dynamic_value::Reader<'a> {
+    fn from(e: Type) -> Self { ::capnp::dynamic_value::Enum::new(e.into(), ::capnp::introspect::RawEnumSchema { encoded_node: &type_::ENCODED_NODE, annotation_types: type_::get_annotation_types }.into()).into() }
+  }
+  impl ::core::convert::TryFrom<u16> for Type {
+    type Error = ::capnp::NotInSchema;
+    fn try_from(value: u16) -> ::core::result::Result<Self, <Type as ::core::convert::TryFrom<u16>>::Error> {
+      match value {
+        0 => ::core::result::Result::Ok(Self::Failed),
+        1 => ::core::result::Result::Ok(Self::Overloaded),
+        2 => ::core::result::Result::Ok(Self::Disconnected),
+        3 => ::core::result::Result::Ok(Self::Unimplemented),
+        n => ::core::result::Result::Err(::capnp::NotInSchema(n)),
+      }
+    }
+  }
+  impl ::core::convert::From<Type> for u16 {
+    #[inline]
+    fn from(x: Type) -> u16 { x as u16 }
+  }
+  impl ::capnp::traits::HasTypeId for Type {
+    const TYPE_ID: u64 = 0xb28c_96e2_3f4c_bd58u64;
+  }
+  mod type_ {
+  pub static ENCODED_NODE: [::capnp::Word; 37] = [
+    ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+    ::capnp::word(88, 189, 76, 63, 226, 150, 140, 178),
+    ::capnp::word(20, 0, 0, 0, 2, 0, 0, 0),
+    ::capnp::word(26, 105, 207, 58, 6, 183, 37, 214),
+    ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ::capnp::word(21, 0, 0, 0, 202, 0, 0, 0),
+    ::capnp::word(33, 0, 0, 0, 7, 0, 0, 0),
+    ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ::capnp::word(29, 0, 0, 0, 103, 0, 0, 0),
+    ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ::capnp::word(114, 112, 99, 46, 99, 97, 112, 110),
+    ::capnp::word(112, 58, 69, 120, 99, 101, 112, 116),
+    ::capnp::word(105, 111, 110, 46, 84, 121, 112, 101),
+    ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+    ::capnp::word(16, 0, 0, 0, 1, 0, 2, 0),
+    ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ::capnp::word(41, 0, 0, 0, 58, 0, 0, 0),
+    ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+    ::capnp::word(33, 0, 0, 0, 90, 0, 0, 0),
+    ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ::capnp::word(2, 0, 0, 0, 0, 0, 0, 0),
+    ::capnp::word(29, 0, 0, 0, 106, 0, 0, 0),
+    ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ::capnp::word(3, 0, 0, 0, 0, 0, 0, 0),
+    ::capnp::word(25, 0, 0, 0, 114, 0, 0, 0),
+    ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ::capnp::word(102, 97, 105, 108, 101, 100, 0, 0),
+    ::capnp::word(111, 118, 101, 114, 108, 111, 97, 100),
+    ::capnp::word(101, 100, 0, 0, 0, 0, 0, 0),
+    ::capnp::word(100, 105, 115, 99, 111, 110, 110, 101),
+    ::capnp::word(99, 116, 101, 100, 0, 0, 0, 0),
+    ::capnp::word(117, 110, 105, 109, 112, 108, 101, 109),
+    ::capnp::word(101, 110, 116, 101, 100, 0, 0, 0),
+  ];
+  pub const DISPLAY_NAME: &str = "rpc.capnp:Exception.Type";
+  pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+    panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+  }
+  }
+
+  pub mod detail {
+  pub struct Detail {
+ pub _id: u64,
+ pub _value: Vec<u8>,  
+}
+  impl Detail {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+  builder.set_id(self._id);
+  builder.set_value(self._value.as_slice().into());
+  ::core::result::Result::Ok(())
+}}
+  
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Detail  {
+    type Error = ::capnp::Error;
+    fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(Self {
+        _id: reader.get_id(),
+        _value: reader.get_value()?.to_vec(),
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for Detail {
+    type Error = ::capnp::Error;
+    fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl Detail {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+      pub value: usize,
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+          value: 0,
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(counts: FieldCounts) -> usize {
+      (counts.value + 7) / 8
+    }
+    /// Words taken up by a message holding just this struct: its data and pointer sections
+    /// plus the root pointer.
+    pub const MIN_SIZE_WORDS: usize = 1 + 1 + 1;
+    /// An upper bound on the words that a single-segment message holding this struct takes up.
+    pub const fn total_words_for(counts: FieldCounts) -> usize {
+      MIN_SIZE_WORDS + variable_words_for(counts)
+    }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+        self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn get_id(self) -> u64 {
+        self.reader.get_data_field::<u64>(0)
+      }
+      #[inline]
+      pub fn get_value(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn has_value(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
+    }
+
+    pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+    impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 1, pointers: 1 };
+    }
+    impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+        self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn get_id(self) -> u64 {
+        self.builder.get_data_field::<u64>(0)
+      }
+      #[inline]
+      pub fn set_id(&mut self, value: u64)  {
+        self.builder.set_data_field::<u64>(0, value);
+      }
+      #[inline]
+      pub fn set_id_from(&mut self, value: impl ::core::convert::TryInto<u64>) -> ::capnp::Result<()> {
+        let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+        self.set_id(value);
+        ::core::result::Result::Ok(())
+      }
+      #[inline]
+      pub fn get_value(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn set_value(&mut self, value: ::capnp::data::Reader<'_>)  {
+        self.builder.reborrow().get_pointer_field(0).set_data(value);
+      }
+      #[inline]
+      pub fn init_value(self, size: u32) -> ::capnp::data::Builder<'a> {
+        self.builder.get_pointer_field(0).init_data(size)
+      }
+      #[inline]
+      pub fn disown_value(&mut self) -> ::capnp::Orphan<::capnp::data::Owned> {
+        ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+      }
+      #[inline]
+      pub fn adopt_value(&mut self, orphan: ::capnp::Orphan<::capnp::data::Owned>) -> ::capnp::Result<()> {
+        self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+      }
+      #[inline]
+      pub fn get_or_init_value(&mut self, size: u32) -> ::capnp::Result<::capnp::data::Builder<'_>> {
+        if self.builder.is_pointer_field_null(0) {
+          ::core::result::Result::Ok(self.reborrow().init_value(size))
+        } else {
+          ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+        }
+      }
+      #[inline]
+      pub fn has_value(&self) -> bool {
+        !self.builder.is_pointer_field_null(0)
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+      let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [::capnp::Word; 48] = [
+        ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+        ::capnp::word(221, 248, 68, 29, 18, 79, 193, 214),
+        ::capnp::word(20, 0, 0, 0, 1, 0, 1, 0),
+        ::capnp::word(26, 105, 207, 58, 6, 183, 37, 214),
+        ::capnp::word(1, 0, 7, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(21, 0, 0, 0, 218, 0, 0, 0),
+        ::capnp::word(33, 0, 0, 0, 7, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(29, 0, 0, 0, 119, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(114, 112, 99, 46, 99, 97, 112, 110),
+        ::capnp::word(112, 58, 69, 120, 99, 101, 112, 116),
+        ::capnp::word(105, 111, 110, 46, 68, 101, 116, 97),
+        ::capnp::word(105, 108, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+        ::capnp::word(8, 0, 0, 0, 3, 0, 4, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(41, 0, 0, 0, 26, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(36, 0, 0, 0, 3, 0, 1, 0),
+        ::capnp::word(48, 0, 0, 0, 2, 0, 1, 0),
+        ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(45, 0, 0, 0, 50, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(40, 0, 0, 0, 3, 0, 1, 0),
+        ::capnp::word(52, 0, 0, 0, 2, 0, 1, 0),
+        ::capnp::word(105, 100, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(118, 97, 108, 117, 101, 0, 0, 0),
+        ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "rpc.capnp:Exception.Detail";
+      pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        match index {
+          0 => <u64 as ::capnp::introspect::Introspect>::introspect(),
+          1 => <::capnp::data::Owned as ::capnp::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[0,1];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+      pub mod layout {
+        pub const FIELD_ID_OFFSET: usize = 0;
+        pub const FIELD_VALUE_POINTER_INDEX: usize = 0;
+      }
+      pub const TYPE_ID: u64 = 0xd6c1_4f12_1d44_f8dd;
+    }
+  }
+}
@@ -0,0 +1,2112 @@
+// @generated by the capnpc-rust plugin to the Cap'n Proto schema compiler.
+// DO NOT EDIT.
+// source: rpc-twoparty.capnp
+
+/// The ID of the schema file.
+pub const FILE_ID: u64 = 0xa184c7885cdaf2a1;
+/// The IDs of the file and of all nodes declared in it, in ascending order.
+pub static NODE_IDS: &[u64] = &[0x89f389b6fd4082c1, 0x95b29059097fca83, 0x9d263a3630b7ebee, 0x9fd69ebc87b9719c, 0xa184c7885cdaf2a1, 0xb47f4979672cb59d, 0xb88d09a9c5f39817, 0xd20b909fee733a8e];
+/// A hash of the canonical encodings of the nodes in `NODE_IDS`. Equal fingerprints mean
+/// equal schemas, regardless of formatting and comments.
+pub const SCHEMA_FINGERPRINT: u64 = 0x4f6f71f4a517ff13;
+
+#[repr(u16)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+  Server = 0,
+  Client = 1,
+}
+
+impl ::capnp::introspect::Introspect for Side {
+  fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Enum(::capnp::introspect::RawEnumSchema { encoded_node: &side::ENCODED_NODE, annotation_types: side::get_annotation_types }).into() }
+}
+impl Side {
+  /// Every enumerant, in ordinal order.
+  pub const VALUES: &[Self] = &[Self::Server, Self::Client];
+  /// The number of enumerants.
+  pub const COUNT: u16 = 2;
+  /// Iterates over every enumerant, in ordinal order.
+  pub fn iter() -> impl ::core::iter::Iterator<Item = Self> {
+    Self::VALUES.iter().copied()
+  }
+  /// The `(enumerant_index, annotation_id)` pair of each annotation on a enumerant.
+  pub const ENUMERANT_ANNOTATIONS: &'static [(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the enumerant at `enumerant_index`, if
+  /// it has one.
+  pub fn enumerant_annotation(enumerant_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::EnumSchema::new(::capnp::introspect::RawEnumSchema { encoded_node: &side::ENCODED_NODE, annotation_types: side::get_annotation_types }).get_enumerants().ok()?;
+    if enumerant_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(enumerant_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+}
+impl <'a> ::core::convert::From<Side> for ::capnp::dynamic_value::Reader<'a> {
+  fn from(e: Side) -> Self { ::capnp::dynamic_value::Enum::new(e.into(), ::capnp::introspect::RawEnumSchema { encoded_node: &side::ENCODED_NODE, annotation_types: side::get_annotation_types }.into()).into() }
+}
+impl ::core::convert::TryFrom<u16> for Side {
+  type Error = ::capnp::NotInSchema;
+  fn try_from(value: u16) -> ::core::result::Result<Self, <Side as ::core::convert::TryFrom<u16>>::Error> {
+    match value {
+      0 => ::core::result::Result::Ok(Self::Server),
+      1 => ::core::result::Result::Ok(Self::Client),
+      n => ::core::result::Result::Err(::capnp::NotInSchema(n)),
+    }
+  }
+}
+impl ::core::convert::From<Side> for u16 {
+  #[inline]
+  fn from(x: Side) -> u16 { x as u16 }
+}
+impl ::capnp::traits::HasTypeId for Side {
+  const TYPE_ID: u64 = 0x9fd6_9ebc_87b9_719cu64;
+}
+mod side {
+pub static ENCODED_NODE: [::capnp::Word; 25] = [
+  ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+  ::capnp::word(156, 113, 185, 135, 188, 158, 214, 159),
+  ::capnp::word(19, 0, 0, 0, 2, 0, 0, 0),
+  ::capnp::word(161, 242, 218, 92, 136, 199, 132, 161),
+  ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+  ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+  ::capnp::word(21, 0, 0, 0, 194, 0, 0, 0),
+  ::capnp::word(29, 0, 0, 0, 7, 0, 0, 0),
+  ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+  ::capnp::word(25, 0, 0, 0, 55, 0, 0, 0),
+  ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+  ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+  ::capnp::word(114, 112, 99, 45, 116, 119, 111, 112),
+  ::capnp::word(97, 114, 116, 121, 46, 99, 97, 112),
+  ::capnp::word(110, 112, 58, 83, 105, 100, 101, 0),
+  ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+  ::capnp::word(8, 0, 0, 0, 1, 0, 2, 0),
+  ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+  ::capnp::word(17, 0, 0, 0, 58, 0, 0, 0),
+  ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+  ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+  ::capnp::word(9, 0, 0, 0, 58, 0, 0, 0),
+  ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+  ::capnp::word(115, 101, 114, 118, 101, 114, 0, 0),
+  ::capnp::word(99, 108, 105, 101, 110, 116, 0, 0),
+];
+pub const DISPLAY_NAME: &str = "rpc-twoparty.capnp:Side";
+pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+  panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+}
+}
+
+pub mod vat_id {
+pub struct VatId {
+ pub _side: crate::rpc_twoparty_capnp::Side,  
+}
+impl VatId {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+  builder.set_side(self._side);
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for VatId  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _side: reader.get_side()?,
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for VatId {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl VatId {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(_counts: FieldCounts) -> usize { 0 }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 1 + 0 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_side(self) -> ::core::result::Result<crate::rpc_twoparty_capnp::Side,::capnp::NotInSchema> {
+      ::core::convert::TryInto::try_into(self.reader.get_data_field::<u16>(0))
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 1, pointers: 0 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_side(self) -> ::core::result::Result<crate::rpc_twoparty_capnp::Side,::capnp::NotInSchema> {
+      ::core::convert::TryInto::try_into(self.builder.get_data_field::<u16>(0))
+    }
+    #[inline]
+    pub fn set_side(&mut self, value: crate::rpc_twoparty_capnp::Side)  {
+      self.builder.set_data_field::<u16>(0, value as u16);
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 33] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(142, 58, 115, 238, 159, 144, 11, 210),
+      ::capnp::word(19, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(161, 242, 218, 92, 136, 199, 132, 161),
+      ::capnp::word(0, 0, 7, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 202, 0, 0, 0),
+      ::capnp::word(33, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(29, 0, 0, 0, 63, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 45, 116, 119, 111, 112),
+      ::capnp::word(97, 114, 116, 121, 46, 99, 97, 112),
+      ::capnp::word(110, 112, 58, 86, 97, 116, 73, 100),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(4, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 42, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(20, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(115, 105, 100, 101, 0, 0, 0, 0),
+      ::capnp::word(15, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(156, 113, 185, 135, 188, 158, 214, 159),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(15, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc-twoparty.capnp:VatId";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <crate::rpc_twoparty_capnp::Side as ::capnp::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_SIDE_OFFSET: usize = 0;
+    }
+    pub const TYPE_ID: u64 = 0xd20b_909f_ee73_3a8e;
+  }
+}
+
+pub mod provision_id {
+pub struct ProvisionId {
+ pub _join_id: u32,  
+}
+impl ProvisionId {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+  builder.set_join_id(self._join_id);
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for ProvisionId  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _join_id: reader.get_join_id(),
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for ProvisionId {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl ProvisionId {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(_counts: FieldCounts) -> usize { 0 }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 1 + 0 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_join_id(self) -> u32 {
+      self.reader.get_data_field::<u32>(0)
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 1, pointers: 0 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_join_id(self) -> u32 {
+      self.builder.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn set_join_id(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(0, value);
+    }
+    #[inline]
+    pub fn set_join_id_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_join_id(value);
+      ::core::result::Result::Ok(())
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 33] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(23, 152, 243, 197, 169, 9, 141, 184),
+      ::capnp::word(19, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(161, 242, 218, 92, 136, 199, 132, 161),
+      ::capnp::word(0, 0, 7, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 250, 0, 0, 0),
+      ::capnp::word(33, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(29, 0, 0, 0, 63, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 45, 116, 119, 111, 112),
+      ::capnp::word(97, 114, 116, 121, 46, 99, 97, 112),
+      ::capnp::word(110, 112, 58, 80, 114, 111, 118, 105),
+      ::capnp::word(115, 105, 111, 110, 73, 100, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(4, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(20, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(106, 111, 105, 110, 73, 100, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc-twoparty.capnp:ProvisionId";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_JOIN_ID_OFFSET: usize = 0;
+    }
+    pub const TYPE_ID: u64 = 0xb88d_09a9_c5f3_9817;
+  }
+}
+
+pub mod recipient_id {
+pub struct RecipientId {  
+}
+impl RecipientId {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for RecipientId  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(Self {
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for RecipientId {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl RecipientId {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(_counts: FieldCounts) -> usize { 0 }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 0 + 0 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 0, pointers: 0 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 17] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(193, 130, 64, 253, 182, 137, 243, 137),
+      ::capnp::word(19, 0, 0, 0, 1, 0, 0, 0),
+      ::capnp::word(161, 242, 218, 92, 136, 199, 132, 161),
+      ::capnp::word(0, 0, 7, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 250, 0, 0, 0),
+      ::capnp::word(33, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 45, 116, 119, 111, 112),
+      ::capnp::word(97, 114, 116, 121, 46, 99, 97, 112),
+      ::capnp::word(110, 112, 58, 82, 101, 99, 105, 112),
+      ::capnp::word(105, 101, 110, 116, 73, 100, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc-twoparty.capnp:RecipientId";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      panic!("invalid field index {} of {}", index, DISPLAY_NAME)
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+    }
+    pub const TYPE_ID: u64 = 0x89f3_89b6_fd40_82c1;
+  }
+}
+
+pub mod third_party_cap_id {
+pub struct ThirdPartyCapId {  
+}
+impl ThirdPartyCapId {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for ThirdPartyCapId  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(Self {
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for ThirdPartyCapId {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl ThirdPartyCapId {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(_counts: FieldCounts) -> usize { 0 }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 0 + 0 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 0, pointers: 0 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 18] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(157, 181, 44, 103, 121, 73, 127, 180),
+      ::capnp::word(19, 0, 0, 0, 1, 0, 0, 0),
+      ::capnp::word(161, 242, 218, 92, 136, 199, 132, 161),
+      ::capnp::word(0, 0, 7, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 26, 1, 0, 0),
+      ::capnp::word(37, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 45, 116, 119, 111, 112),
+      ::capnp::word(97, 114, 116, 121, 46, 99, 97, 112),
+      ::capnp::word(110, 112, 58, 84, 104, 105, 114, 100),
+      ::capnp::word(80, 97, 114, 116, 121, 67, 97, 112),
+      ::capnp::word(73, 100, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc-twoparty.capnp:ThirdPartyCapId";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      panic!("invalid field index {} of {}", index, DISPLAY_NAME)
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+    }
+    pub const TYPE_ID: u64 = 0xb47f_4979_672c_b59d;
+  }
+}
+
+pub mod join_key_part {
+pub struct JoinKeyPart {
+ pub _join_id: u32,
+ pub _part_count: u16,
+ pub _part_num: u16,  
+}
+impl JoinKeyPart {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+  builder.set_join_id(self._join_id);
+  builder.set_part_count(self._part_count);
+  builder.set_part_num(self._part_num);
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for JoinKeyPart  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _join_id: reader.get_join_id(),
+      _part_count: reader.get_part_count(),
+      _part_num: reader.get_part_num(),
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for JoinKeyPart {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl JoinKeyPart {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(_counts: FieldCounts) -> usize { 0 }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 1 + 0 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_join_id(self) -> u32 {
+      self.reader.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn get_part_count(self) -> u16 {
+      self.reader.get_data_field::<u16>(2)
+    }
+    #[inline]
+    pub fn get_part_num(self) -> u16 {
+      self.reader.get_data_field::<u16>(3)
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 1, pointers: 0 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_join_id(self) -> u32 {
+      self.builder.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn set_join_id(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(0, value);
+    }
+    #[inline]
+    pub fn set_join_id_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_join_id(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_part_count(self) -> u16 {
+      self.builder.get_data_field::<u16>(2)
+    }
+    #[inline]
+    pub fn set_part_count(&mut self, value: u16)  {
+      self.builder.set_data_field::<u16>(2, value);
+    }
+    #[inline]
+    pub fn set_part_count_from(&mut self, value: impl ::core::convert::TryInto<u16>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_part_count(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_part_num(self) -> u16 {
+      self.builder.get_data_field::<u16>(3)
+    }
+    #[inline]
+    pub fn set_part_num(&mut self, value: u16)  {
+      self.builder.set_data_field::<u16>(3, value);
+    }
+    #[inline]
+    pub fn set_part_num_from(&mut self, value: impl ::core::convert::TryInto<u16>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_part_num(value);
+      ::core::result::Result::Ok(())
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 64] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(131, 202, 127, 9, 89, 144, 178, 149),
+      ::capnp::word(19, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(161, 242, 218, 92, 136, 199, 132, 161),
+      ::capnp::word(0, 0, 7, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 250, 0, 0, 0),
+      ::capnp::word(33, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(29, 0, 0, 0, 175, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 45, 116, 119, 111, 112),
+      ::capnp::word(97, 114, 116, 121, 46, 99, 97, 112),
+      ::capnp::word(110, 112, 58, 74, 111, 105, 110, 75),
+      ::capnp::word(101, 121, 80, 97, 114, 116, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(12, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(69, 0, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(64, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(76, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(1, 0, 0, 0, 2, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(73, 0, 0, 0, 82, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(72, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(84, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(2, 0, 0, 0, 3, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 2, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(81, 0, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(76, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(88, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(106, 111, 105, 110, 73, 100, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(112, 97, 114, 116, 67, 111, 117, 110),
+      ::capnp::word(116, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(7, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(7, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(112, 97, 114, 116, 78, 117, 109, 0),
+      ::capnp::word(7, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(7, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc-twoparty.capnp:JoinKeyPart";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        1 => <u16 as ::capnp::introspect::Introspect>::introspect(),
+        2 => <u16 as ::capnp::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1,2];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_JOIN_ID_OFFSET: usize = 0;
+      pub const FIELD_PART_COUNT_OFFSET: usize = 4;
+      pub const FIELD_PART_NUM_OFFSET: usize = 6;
+    }
+    pub const TYPE_ID: u64 = 0x95b2_9059_097f_ca83;
+  }
+}
+
+pub mod join_result {
+pub struct JoinResult {
+ pub _join_id: u32,
+ pub _succeeded: bool,
+ pub _cap: Box<dyn ::capnp::private::capability::ClientHook>,  
+}
+impl JoinResult {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> ::capnp::Result<()>  {
+  builder.set_join_id(self._join_id);
+  builder.set_succeeded(self._succeeded);
+  builder.reborrow().init_cap().set_as_capability(self._cap);
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for JoinResult  {
+  type Error = ::capnp::Error;
+  fn try_from(reader: Reader<'a,>) -> ::capnp::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _join_id: reader.get_join_id(),
+      _succeeded: reader.get_succeeded(),
+      _cap: reader.get_cap().get_as_capability::<::capnp::capability::Client>()?.hook,
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<::capnp::dynamic_struct::Reader<'a>> for JoinResult {
+  type Error = ::capnp::Error;
+  fn try_from(reader: ::capnp::dynamic_struct::Reader<'a>) -> ::capnp::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl JoinResult {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: ::capnp::dynamic_struct::Builder<'_>) -> ::capnp::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub cap: usize,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        cap: 0,
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    counts.cap
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 1 + 1 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> ::capnp::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<::capnp::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_join_id(self) -> u32 {
+      self.reader.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn get_succeeded(self) -> bool {
+      self.reader.get_bool_field(32)
+    }
+    #[inline]
+    pub fn get_cap(self) -> ::capnp::any_pointer::Reader<'a> {
+      ::capnp::any_pointer::Reader::new(self.reader.get_pointer_field(0))
+    }
+    #[inline]
+    pub fn get_cap_as<_T: ::capnp::traits::Owned>(self) -> ::capnp::Result<_T::Reader<'a>> {
+      ::capnp::any_pointer::Reader::new(self.reader.get_pointer_field(0)).get_as_checked::<_T>()
+    }
+    #[inline]
+    pub fn has_cap(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 1, pointers: 1 };
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> ::capnp::Result<usize> {
+      self.total_size().map(::capnp::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> ::capnp::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<::capnp::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: ::capnp::dynamic_struct::Reader<'_>) -> ::capnp::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_join_id(self) -> u32 {
+      self.builder.get_data_field::<u32>(0)
+    }
+    #[inline]
+    pub fn set_join_id(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(0, value);
+    }
+    #[inline]
+    pub fn set_join_id_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> ::capnp::Result<()> {
+      let value = value.try_into().map_err(|_| ::capnp::Error::from_kind(::capnp::ErrorKind::ValueOutOfRange))?;
+      self.set_join_id(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_succeeded(self) -> bool {
+      self.builder.get_bool_field(32)
+    }
+    #[inline]
+    pub fn set_succeeded(&mut self, value: bool)  {
+      self.builder.set_bool_field(32, value);
+    }
+    #[inline]
+    pub fn get_cap(self) -> ::capnp::any_pointer::Builder<'a> {
+      ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(0))
+    }
+    #[inline]
+    pub fn get_cap_as<_T: ::capnp::traits::Owned>(self) -> ::capnp::Result<_T::Builder<'a>> {
+      ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(0)).get_as_checked::<_T>()
+    }
+    #[inline]
+    pub fn init_cap(self, ) -> ::capnp::any_pointer::Builder<'a> {
+      let mut result = ::capnp::any_pointer::Builder::new(self.builder.get_pointer_field(0));
+      result.clear();
+      result
+    }
+    #[inline]
+    pub fn disown_cap(&mut self) -> ::capnp::Orphan<::capnp::any_pointer::Owned> {
+      ::capnp::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_cap(&mut self, orphan: ::capnp::Orphan<::capnp::any_pointer::Owned>) -> ::capnp::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn has_cap(&self) -> bool {
+      !self.builder.is_pointer_field_null(0)
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+    #[inline]
+    pub fn get_cap(&self) -> ::capnp::any_pointer::Pipeline {
+      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+    }
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<::capnp::dynamic_value::Reader<'static>> {
+    let children = ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 64] = [
+      ::capnp::word(0, 0, 0, 0, 5, 0, 6, 0),
+      ::capnp::word(238, 235, 183, 48, 54, 58, 38, 157),
+      ::capnp::word(19, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(161, 242, 218, 92, 136, 199, 132, 161),
+      ::capnp::word(1, 0, 7, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 242, 0, 0, 0),
+      ::capnp::word(33, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(29, 0, 0, 0, 175, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 112, 99, 45, 116, 119, 111, 112),
+      ::capnp::word(97, 114, 116, 121, 46, 99, 97, 112),
+      ::capnp::word(110, 112, 58, 74, 111, 105, 110, 82),
+      ::capnp::word(101, 115, 117, 108, 116, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(12, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(69, 0, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(64, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(76, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(1, 0, 0, 0, 32, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(73, 0, 0, 0, 82, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(72, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(84, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(2, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 2, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(81, 0, 0, 0, 34, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(76, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(88, 0, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(106, 111, 105, 110, 73, 100, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 117, 99, 99, 101, 101, 100, 101),
+      ::capnp::word(100, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(99, 97, 112, 0, 0, 0, 0, 0),
+      ::capnp::word(18, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 3, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(18, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "rpc-twoparty.capnp:JoinResult";
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        1 => <bool as ::capnp::introspect::Introspect>::introspect(),
+        2 => <::capnp::any_pointer::Owned as ::capnp::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1,2];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_JOIN_ID_OFFSET: usize = 0;
+      pub const FIELD_SUCCEEDED_BIT_OFFSET: usize = 32;
+      pub const FIELD_CAP_POINTER_INDEX: usize = 0;
+    }
+    pub const TYPE_ID: u64 = 0x9d26_3a36_30b7_ebee;
+  }
+}
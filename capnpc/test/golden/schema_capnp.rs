@@ -0,0 +1,18491 @@
+// @generated by the capnpc-rust plugin to the Cap'n Proto schema compiler.
+// DO NOT EDIT.
+// source: schema.capnp
+
+/// The ID of the schema file.
+pub const FILE_ID: u64 = 0xa93fc509624c72d9;
+/// The IDs of the file and of all nodes declared in it, in ascending order.
+pub static NODE_IDS: &[u64] = &[0x87e739250a60ea97, 0x8e3b5f79fe593656, 0x903455f06065422b, 0x9500cce23b334d80, 0x978a7cebdc549a4d, 0x97b14cbe7cfec712, 0x9aad50a41f4af45f, 0x9dd1f724f4614a85, 0x9e0e78711a7f87a9, 0x9ea0b19b37fb4435, 0xa93fc509624c72d9, 0xa9962a9ed0a4d7f8, 0xabd73485a9636bc9, 0xac3a6f60ef4cc6d3, 0xae504193122357e5, 0xb18aa5ac7a0d9420, 0xb54ab3364333f598, 0xb9521bccf10fa3b1, 0xbaefc9120c56e274, 0xbb90d5c287870be6, 0xbfc546f6210ad7ce, 0xc2573fe8a23e49f1, 0xc2ba9038898e1fa2, 0xc42305476bb4746f, 0xc863cd16969ee7fc, 0xcafccddb68db1d11, 0xce23dcd2d7b00c9b, 0xcfea0eb02e810062, 0xd07378ede1f9cc60, 0xd1958f7dba521926, 0xd85d305b7d839963, 0xdebf55bbfa0fc242, 0xe682ab4cf923a417, 0xe82753cff0c2218f, 0xec1619d4400a0290, 0xed8bca69f7fb0cbf, 0xf1c8950dab257542, 0xf38e1de3041357ae];
+/// A hash of the canonical encodings of the nodes in `NODE_IDS`. Equal fingerprints mean
+/// equal schemas, regardless of formatting and comments.
+pub const SCHEMA_FINGERPRINT: u64 = 0x85e4b85f5496b2e6;
+
+pub mod node {
+pub struct Node {
+   pub uni: NodeUnion,
+ pub _id: u64,
+ pub _display_name: String,
+ pub _display_name_prefix_length: u32,
+ pub _scope_id: u64,
+ pub _nested_nodes: Vec<crate::schema_capnp::node::nested_node::NestedNode>,
+ pub _annotations: Vec<crate::schema_capnp::annotation::Annotation>,
+ pub _parameters: Vec<crate::schema_capnp::node::parameter::Parameter>,
+ pub _is_generic: bool,  
+}
+impl Node {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+ match self.uni {
+ NodeUnion::UNINITIALIZED => (),
+ NodeUnion::_File(t) => builder.reborrow().set_file(t),
+ NodeUnion::_Struct(t) => t.build_capnp_struct(builder.reborrow().init_struct())?,
+ NodeUnion::_Enum(t) => t.build_capnp_struct(builder.reborrow().init_enum())?,
+ NodeUnion::_Interface(t) => t.build_capnp_struct(builder.reborrow().init_interface())?,
+ NodeUnion::_Const(t) => t.build_capnp_struct(builder.reborrow().init_const())?,
+ NodeUnion::_Annotation(t) => t.build_capnp_struct(builder.reborrow().init_annotation())?,
+ }
+  builder.set_id(self._id);
+  builder.set_display_name(self._display_name.as_str().into());
+  builder.set_display_name_prefix_length(self._display_name_prefix_length);
+  builder.set_scope_id(self._scope_id);
+            
+if self._nested_nodes.len() > 0 {
+                let mut list_builder = builder.reborrow().init_nested_nodes(crate::private::checked_len(self._nested_nodes.len())?);
+                for (i, item) in self._nested_nodes.into_iter().enumerate() {
+                    item.build_capnp_struct(list_builder.reborrow().get(i as u32))?;
+                }
+            }
+            
+if self._annotations.len() > 0 {
+                let mut list_builder = builder.reborrow().init_annotations(crate::private::checked_len(self._annotations.len())?);
+                for (i, item) in self._annotations.into_iter().enumerate() {
+                    item.build_capnp_struct(list_builder.reborrow().get(i as u32))?;
+                }
+            }
+            
+if self._parameters.len() > 0 {
+                let mut list_builder = builder.reborrow().init_parameters(crate::private::checked_len(self._parameters.len())?);
+                for (i, item) in self._parameters.into_iter().enumerate() {
+                    item.build_capnp_struct(list_builder.reborrow().get(i as u32))?;
+                }
+            }
+  builder.set_is_generic(self._is_generic);
+  ::core::result::Result::Ok(())
+}}
+pub enum NodeUnion {
+ UNINITIALIZED,
+ _File(()),
+ _Struct(crate::schema_capnp::node::struct_::Struct),
+ _Enum(crate::schema_capnp::node::enum_::Enum),
+ _Interface(crate::schema_capnp::node::interface::Interface),
+ _Const(crate::schema_capnp::node::const_::Const),
+ _Annotation(crate::schema_capnp::node::annotation::Annotation),
+}
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Node  {
+  type Error = crate::Error;
+  fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _id: reader.get_id(),
+      _display_name: reader.get_display_name()?.to_string()?,
+      _display_name_prefix_length: reader.get_display_name_prefix_length(),
+      _scope_id: reader.get_scope_id(),
+      _nested_nodes: reader.get_nested_nodes()?.iter().map(|item| crate::Result::Ok(<crate::schema_capnp::node::nested_node::NestedNode as ::core::convert::TryFrom<_>>::try_from(item)?)).collect::<crate::Result<Vec<_>>>()?,
+      _annotations: reader.get_annotations()?.iter().map(|item| crate::Result::Ok(<crate::schema_capnp::annotation::Annotation as ::core::convert::TryFrom<_>>::try_from(item)?)).collect::<crate::Result<Vec<_>>>()?,
+      _parameters: reader.get_parameters()?.iter().map(|item| crate::Result::Ok(<crate::schema_capnp::node::parameter::Parameter as ::core::convert::TryFrom<_>>::try_from(item)?)).collect::<crate::Result<Vec<_>>>()?,
+      _is_generic: reader.get_is_generic(),
+      uni: match reader.which()? {
+        Which::File(value) => NodeUnion::_File(value),
+        Which::Struct(value) => NodeUnion::_Struct(<crate::schema_capnp::node::struct_::Struct as ::core::convert::TryFrom<_>>::try_from(value)?),
+        Which::Enum(value) => NodeUnion::_Enum(<crate::schema_capnp::node::enum_::Enum as ::core::convert::TryFrom<_>>::try_from(value)?),
+        Which::Interface(value) => NodeUnion::_Interface(<crate::schema_capnp::node::interface::Interface as ::core::convert::TryFrom<_>>::try_from(value)?),
+        Which::Const(value) => NodeUnion::_Const(<crate::schema_capnp::node::const_::Const as ::core::convert::TryFrom<_>>::try_from(value)?),
+        Which::Annotation(value) => NodeUnion::_Annotation(<crate::schema_capnp::node::annotation::Annotation as ::core::convert::TryFrom<_>>::try_from(value)?),
+      },
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Node {
+  type Error = crate::Error;
+  fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl Node {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  pub use self::Which::{File,Struct,Enum,Interface,Const,Annotation};
+
+  /// The members of the union, as `(discriminant, name, field index)` in discriminant order.
+  pub static UNION_MEMBERS: &[(u16, &str, u16)] = &[(0, "file", 6), (1, "struct", 7), (2, "enum", 8), (3, "interface", 9), (4, "const", 10), (5, "annotation", 11)];
+  /// Returns the schema name of the union member with discriminant `d`.
+  pub fn discriminant_name(d: u16) -> ::core::option::Option<&'static str> {
+    UNION_MEMBERS.get(d as usize).map(|member| member.1)
+  }
+
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub display_name: usize,
+    pub nested_nodes: usize,
+    pub annotations: usize,
+    pub struct_: crate::schema_capnp::node::struct_::FieldCounts,
+    pub enum_: crate::schema_capnp::node::enum_::FieldCounts,
+    pub interface: crate::schema_capnp::node::interface::FieldCounts,
+    pub const_: crate::schema_capnp::node::const_::FieldCounts,
+    pub annotation: crate::schema_capnp::node::annotation::FieldCounts,
+    pub parameters: usize,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        display_name: 0,
+        nested_nodes: 0,
+        annotations: 0,
+        struct_: crate::schema_capnp::node::struct_::FieldCounts::new(),
+        enum_: crate::schema_capnp::node::enum_::FieldCounts::new(),
+        interface: crate::schema_capnp::node::interface::FieldCounts::new(),
+        const_: crate::schema_capnp::node::const_::FieldCounts::new(),
+        annotation: crate::schema_capnp::node::annotation::FieldCounts::new(),
+        parameters: 0,
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    (counts.display_name + 8) / 8 + 1 + counts.nested_nodes * (crate::schema_capnp::node::nested_node::MIN_SIZE_WORDS - 1) + 1 + counts.annotations * (crate::schema_capnp::annotation::MIN_SIZE_WORDS - 1) + crate::schema_capnp::node::struct_::variable_words_for(counts.struct_) + crate::schema_capnp::node::enum_::variable_words_for(counts.enum_) + crate::schema_capnp::node::interface::variable_words_for(counts.interface) + crate::schema_capnp::node::const_::variable_words_for(counts.const_) + crate::schema_capnp::node::annotation::variable_words_for(counts.annotation) + 1 + counts.parameters * (crate::schema_capnp::node::parameter::MIN_SIZE_WORDS - 1)
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 5 + 6 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+      self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> crate::Result<usize> {
+      self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_id(self) -> u64 {
+      self.reader.get_data_field::<u64>(0)
+    }
+    #[inline]
+    pub fn get_display_name(self) -> crate::Result<crate::text::Reader<'a>> {
+      crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_display_name(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_display_name_prefix_length(self) -> u32 {
+      self.reader.get_data_field::<u32>(2)
+    }
+    #[inline]
+    pub fn get_scope_id(self) -> u64 {
+      self.reader.get_data_field::<u64>(2)
+    }
+    #[inline]
+    pub fn get_nested_nodes(self) -> crate::Result<crate::struct_list::Reader<'a,crate::schema_capnp::node::nested_node::Owned>> {
+      crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_nested_nodes(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
+    }
+    #[inline]
+    pub fn get_annotations(self) -> crate::Result<crate::struct_list::Reader<'a,crate::schema_capnp::annotation::Owned>> {
+      crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(2), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_annotations(&self) -> bool {
+      !self.reader.get_pointer_field(2).is_null()
+    }
+    #[inline]
+    pub fn is_file(&self) -> bool {
+      self.reader.get_data_field::<u16>(6) == 0
+    }
+    #[inline]
+    pub fn is_struct(&self) -> bool {
+      self.reader.get_data_field::<u16>(6) == 1
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_struct(&self) -> bool {
+      if self.reader.get_data_field::<u16>(6) != 1 { return false; }
+      self.reader.get_data_field::<u16>(7) != 0
+        || self.reader.get_data_field::<u16>(12) != 0
+        || self.reader.get_data_field::<u16>(13) != 0
+        || self.reader.get_bool_field(224)
+        || self.reader.get_data_field::<u16>(15) != 0
+        || self.reader.get_data_field::<u32>(8) != 0
+        || !self.reader.is_pointer_field_null(3)
+    }
+    #[inline]
+    pub fn is_enum(&self) -> bool {
+      self.reader.get_data_field::<u16>(6) == 2
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_enum(&self) -> bool {
+      if self.reader.get_data_field::<u16>(6) != 2 { return false; }
+      !self.reader.is_pointer_field_null(3)
+    }
+    #[inline]
+    pub fn is_interface(&self) -> bool {
+      self.reader.get_data_field::<u16>(6) == 3
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_interface(&self) -> bool {
+      if self.reader.get_data_field::<u16>(6) != 3 { return false; }
+      !self.reader.is_pointer_field_null(3)
+        || !self.reader.is_pointer_field_null(4)
+    }
+    #[inline]
+    pub fn is_const(&self) -> bool {
+      self.reader.get_data_field::<u16>(6) == 4
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_const(&self) -> bool {
+      if self.reader.get_data_field::<u16>(6) != 4 { return false; }
+      !self.reader.is_pointer_field_null(3)
+        || !self.reader.is_pointer_field_null(4)
+    }
+    #[inline]
+    pub fn is_annotation(&self) -> bool {
+      self.reader.get_data_field::<u16>(6) == 5
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_annotation(&self) -> bool {
+      if self.reader.get_data_field::<u16>(6) != 5 { return false; }
+      !self.reader.is_pointer_field_null(3)
+        || self.reader.get_bool_field(112)
+        || self.reader.get_bool_field(113)
+        || self.reader.get_bool_field(114)
+        || self.reader.get_bool_field(115)
+        || self.reader.get_bool_field(116)
+        || self.reader.get_bool_field(117)
+        || self.reader.get_bool_field(118)
+        || self.reader.get_bool_field(119)
+        || self.reader.get_bool_field(120)
+        || self.reader.get_bool_field(121)
+        || self.reader.get_bool_field(122)
+        || self.reader.get_bool_field(123)
+    }
+    #[inline]
+    pub fn get_parameters(self) -> crate::Result<crate::struct_list::Reader<'a,crate::schema_capnp::node::parameter::Owned>> {
+      crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(5), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_parameters(&self) -> bool {
+      !self.reader.get_pointer_field(5).is_null()
+    }
+    #[inline]
+    pub fn get_is_generic(self) -> bool {
+      self.reader.get_bool_field(288)
+    }
+    #[inline]
+    pub fn which(self) -> ::core::result::Result<WhichReader<'a,>, crate::NotInSchema> {
+      match self.reader.get_data_field::<u16>(6) {
+        0 => {
+          ::core::result::Result::Ok(Which::File(
+            ()
+          ))
+        }
+        1 => {
+          ::core::result::Result::Ok(Which::Struct(
+            self.reader.into()
+          ))
+        }
+        2 => {
+          ::core::result::Result::Ok(Which::Enum(
+            self.reader.into()
+          ))
+        }
+        3 => {
+          ::core::result::Result::Ok(Which::Interface(
+            self.reader.into()
+          ))
+        }
+        4 => {
+          ::core::result::Result::Ok(Which::Const(
+            self.reader.into()
+          ))
+        }
+        5 => {
+          ::core::result::Result::Ok(Which::Annotation(
+            self.reader.into()
+          ))
+        }
+        x => ::core::result::Result::Err(crate::NotInSchema(x))
+      }
+    }
+  }
+
+  pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+  impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 5, pointers: 6 };
+  }
+  impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+      self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> crate::Result<usize> {
+      self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_id(self) -> u64 {
+      self.builder.get_data_field::<u64>(0)
+    }
+    #[inline]
+    pub fn set_id(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(0, value);
+    }
+    #[inline]
+    pub fn set_id_from(&mut self, value: impl ::core::convert::TryInto<u64>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_id(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_display_name(self) -> crate::Result<crate::text::Builder<'a>> {
+      crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_display_name(&mut self, value: crate::text::Reader<'_>)  {
+      self.builder.reborrow().get_pointer_field(0).set_text(value);
+    }
+    #[inline]
+    pub fn init_display_name(self, size: u32) -> crate::text::Builder<'a> {
+      self.builder.get_pointer_field(0).init_text(size)
+    }
+    #[inline]
+    pub fn disown_display_name(&mut self) -> crate::Orphan<crate::text::Owned> {
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_display_name(&mut self, orphan: crate::Orphan<crate::text::Owned>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_display_name(&mut self, size: u32) -> crate::Result<crate::text::Builder<'_>> {
+      if self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_display_name(size))
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_display_name(&self) -> bool {
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn get_display_name_prefix_length(self) -> u32 {
+      self.builder.get_data_field::<u32>(2)
+    }
+    #[inline]
+    pub fn set_display_name_prefix_length(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(2, value);
+    }
+    #[inline]
+    pub fn set_display_name_prefix_length_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_display_name_prefix_length(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_scope_id(self) -> u64 {
+      self.builder.get_data_field::<u64>(2)
+    }
+    #[inline]
+    pub fn set_scope_id(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(2, value);
+    }
+    #[inline]
+    pub fn set_scope_id_from(&mut self, value: impl ::core::convert::TryInto<u64>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_scope_id(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_nested_nodes(self) -> crate::Result<crate::struct_list::Builder<'a,crate::schema_capnp::node::nested_node::Owned>> {
+      crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_nested_nodes(&mut self, value: crate::struct_list::Reader<'a,crate::schema_capnp::node::nested_node::Owned>) -> crate::Result<()> {
+      crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(1), value, false)
+    }
+    #[inline]
+    pub fn init_nested_nodes(self, size: u32) -> crate::struct_list::Builder<'a,crate::schema_capnp::node::nested_node::Owned> {
+      crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
+    }
+    #[inline]
+    pub fn disown_nested_nodes(&mut self) -> crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::node::nested_node::Owned>> {
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+    }
+    #[inline]
+    pub fn adopt_nested_nodes(&mut self, orphan: crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::node::nested_node::Owned>>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(1).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_nested_nodes(&mut self, size: u32) -> crate::Result<crate::struct_list::Builder<'_,crate::schema_capnp::node::nested_node::Owned>> {
+      if self.builder.is_pointer_field_null(1) {
+        ::core::result::Result::Ok(self.reborrow().init_nested_nodes(size))
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(1), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_nested_nodes(&self) -> bool {
+      !self.builder.is_pointer_field_null(1)
+    }
+    #[inline]
+    pub fn get_annotations(self) -> crate::Result<crate::struct_list::Builder<'a,crate::schema_capnp::annotation::Owned>> {
+      crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(2), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_annotations(&mut self, value: crate::struct_list::Reader<'a,crate::schema_capnp::annotation::Owned>) -> crate::Result<()> {
+      crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(2), value, false)
+    }
+    #[inline]
+    pub fn init_annotations(self, size: u32) -> crate::struct_list::Builder<'a,crate::schema_capnp::annotation::Owned> {
+      crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), size)
+    }
+    #[inline]
+    pub fn disown_annotations(&mut self) -> crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>> {
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(2).disown())
+    }
+    #[inline]
+    pub fn adopt_annotations(&mut self, orphan: crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(2).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_annotations(&mut self, size: u32) -> crate::Result<crate::struct_list::Builder<'_,crate::schema_capnp::annotation::Owned>> {
+      if self.builder.is_pointer_field_null(2) {
+        ::core::result::Result::Ok(self.reborrow().init_annotations(size))
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(2), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_annotations(&self) -> bool {
+      !self.builder.is_pointer_field_null(2)
+    }
+    #[inline]
+    pub fn set_file(&mut self, _value: ())  {
+      self.builder.set_data_field::<u16>(6, 0);
+    }
+    #[inline]
+    pub fn is_file(&self) -> bool {
+      self.builder.get_data_field::<u16>(6) == 0
+    }
+    #[inline]
+    pub fn init_struct(mut self, ) -> crate::schema_capnp::node::struct_::Builder<'a> {
+      self.builder.set_data_field::<u16>(6, 1);
+      self.builder.set_data_field::<u16>(7, 0u16);
+      self.builder.set_data_field::<u16>(12, 0u16);
+      self.builder.set_data_field::<u16>(13, 0u16);
+      self.builder.set_bool_field(224, false);
+      self.builder.set_data_field::<u16>(15, 0u16);
+      self.builder.set_data_field::<u32>(8, 0u32);
+      self.builder.reborrow().get_pointer_field(3).clear();
+      self.builder.into()
+    }
+    #[inline]
+    pub fn is_struct(&self) -> bool {
+      self.builder.get_data_field::<u16>(6) == 1
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_struct(&self) -> bool {
+      if self.builder.get_data_field::<u16>(6) != 1 { return false; }
+      self.builder.get_data_field::<u16>(7) != 0
+        || self.builder.get_data_field::<u16>(12) != 0
+        || self.builder.get_data_field::<u16>(13) != 0
+        || self.builder.get_bool_field(224)
+        || self.builder.get_data_field::<u16>(15) != 0
+        || self.builder.get_data_field::<u32>(8) != 0
+        || !self.builder.is_pointer_field_null(3)
+    }
+    #[inline]
+    pub fn init_enum(mut self, ) -> crate::schema_capnp::node::enum_::Builder<'a> {
+      self.builder.set_data_field::<u16>(6, 2);
+      self.builder.reborrow().get_pointer_field(3).clear();
+      self.builder.into()
+    }
+    #[inline]
+    pub fn is_enum(&self) -> bool {
+      self.builder.get_data_field::<u16>(6) == 2
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_enum(&self) -> bool {
+      if self.builder.get_data_field::<u16>(6) != 2 { return false; }
+      !self.builder.is_pointer_field_null(3)
+    }
+    #[inline]
+    pub fn init_interface(mut self, ) -> crate::schema_capnp::node::interface::Builder<'a> {
+      self.builder.set_data_field::<u16>(6, 3);
+      self.builder.reborrow().get_pointer_field(3).clear();
+      self.builder.reborrow().get_pointer_field(4).clear();
+      self.builder.into()
+    }
+    #[inline]
+    pub fn is_interface(&self) -> bool {
+      self.builder.get_data_field::<u16>(6) == 3
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_interface(&self) -> bool {
+      if self.builder.get_data_field::<u16>(6) != 3 { return false; }
+      !self.builder.is_pointer_field_null(3)
+        || !self.builder.is_pointer_field_null(4)
+    }
+    #[inline]
+    pub fn init_const(mut self, ) -> crate::schema_capnp::node::const_::Builder<'a> {
+      self.builder.set_data_field::<u16>(6, 4);
+      self.builder.reborrow().get_pointer_field(3).clear();
+      self.builder.reborrow().get_pointer_field(4).clear();
+      self.builder.into()
+    }
+    #[inline]
+    pub fn is_const(&self) -> bool {
+      self.builder.get_data_field::<u16>(6) == 4
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_const(&self) -> bool {
+      if self.builder.get_data_field::<u16>(6) != 4 { return false; }
+      !self.builder.is_pointer_field_null(3)
+        || !self.builder.is_pointer_field_null(4)
+    }
+    #[inline]
+    pub fn init_annotation(mut self, ) -> crate::schema_capnp::node::annotation::Builder<'a> {
+      self.builder.set_data_field::<u16>(6, 5);
+      self.builder.reborrow().get_pointer_field(3).clear();
+      self.builder.set_bool_field(112, false);
+      self.builder.set_bool_field(113, false);
+      self.builder.set_bool_field(114, false);
+      self.builder.set_bool_field(115, false);
+      self.builder.set_bool_field(116, false);
+      self.builder.set_bool_field(117, false);
+      self.builder.set_bool_field(118, false);
+      self.builder.set_bool_field(119, false);
+      self.builder.set_bool_field(120, false);
+      self.builder.set_bool_field(121, false);
+      self.builder.set_bool_field(122, false);
+      self.builder.set_bool_field(123, false);
+      self.builder.into()
+    }
+    #[inline]
+    pub fn is_annotation(&self) -> bool {
+      self.builder.get_data_field::<u16>(6) == 5
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_annotation(&self) -> bool {
+      if self.builder.get_data_field::<u16>(6) != 5 { return false; }
+      !self.builder.is_pointer_field_null(3)
+        || self.builder.get_bool_field(112)
+        || self.builder.get_bool_field(113)
+        || self.builder.get_bool_field(114)
+        || self.builder.get_bool_field(115)
+        || self.builder.get_bool_field(116)
+        || self.builder.get_bool_field(117)
+        || self.builder.get_bool_field(118)
+        || self.builder.get_bool_field(119)
+        || self.builder.get_bool_field(120)
+        || self.builder.get_bool_field(121)
+        || self.builder.get_bool_field(122)
+        || self.builder.get_bool_field(123)
+    }
+    #[inline]
+    pub fn get_parameters(self) -> crate::Result<crate::struct_list::Builder<'a,crate::schema_capnp::node::parameter::Owned>> {
+      crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(5), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_parameters(&mut self, value: crate::struct_list::Reader<'a,crate::schema_capnp::node::parameter::Owned>) -> crate::Result<()> {
+      crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(5), value, false)
+    }
+    #[inline]
+    pub fn init_parameters(self, size: u32) -> crate::struct_list::Builder<'a,crate::schema_capnp::node::parameter::Owned> {
+      crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(5), size)
+    }
+    #[inline]
+    pub fn disown_parameters(&mut self) -> crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::node::parameter::Owned>> {
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(5).disown())
+    }
+    #[inline]
+    pub fn adopt_parameters(&mut self, orphan: crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::node::parameter::Owned>>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(5).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_parameters(&mut self, size: u32) -> crate::Result<crate::struct_list::Builder<'_,crate::schema_capnp::node::parameter::Owned>> {
+      if self.builder.is_pointer_field_null(5) {
+        ::core::result::Result::Ok(self.reborrow().init_parameters(size))
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(5), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_parameters(&self) -> bool {
+      !self.builder.is_pointer_field_null(5)
+    }
+    #[inline]
+    pub fn get_is_generic(self) -> bool {
+      self.builder.get_bool_field(288)
+    }
+    #[inline]
+    pub fn set_is_generic(&mut self, value: bool)  {
+      self.builder.set_bool_field(288, value);
+    }
+    #[inline]
+    pub fn which(self) -> ::core::result::Result<WhichBuilder<'a,>, crate::NotInSchema> {
+      match self.builder.get_data_field::<u16>(6) {
+        0 => {
+          ::core::result::Result::Ok(Which::File(
+            ()
+          ))
+        }
+        1 => {
+          ::core::result::Result::Ok(Which::Struct(
+            self.builder.into()
+          ))
+        }
+        2 => {
+          ::core::result::Result::Ok(Which::Enum(
+            self.builder.into()
+          ))
+        }
+        3 => {
+          ::core::result::Result::Ok(Which::Interface(
+            self.builder.into()
+          ))
+        }
+        4 => {
+          ::core::result::Result::Ok(Which::Const(
+            self.builder.into()
+          ))
+        }
+        5 => {
+          ::core::result::Result::Ok(Which::Annotation(
+            self.builder.into()
+          ))
+        }
+        x => ::core::result::Result::Err(crate::NotInSchema(x))
+      }
+    }
+    /// Sets the union to the variant that is active in `other`, copied from there as stored.
+    /// The fields outside the union are left unchanged.
+    ///
+    /// Returns an `EnumValueOrUnionDiscriminantNotPresent` error without changing anything if
+    /// `other` has a variant that is not in the schema, for example one added by a newer version
+    /// of it. A union nested in a group of the active variant is handled the same way, but by
+    /// then the group has been partially copied.
+    pub fn copy_union_from(&mut self, other: Reader<'_,>) -> crate::Result<()> {
+      match other.reader.get_data_field::<u16>(6) {
+        0 => {
+          self.builder.set_data_field::<u16>(6, 0);
+        }
+        1 => {
+          self.reborrow().init_struct();
+          self.builder.set_data_field::<u16>(7, other.reader.get_data_field::<u16>(7));
+          self.builder.set_data_field::<u16>(12, other.reader.get_data_field::<u16>(12));
+          self.builder.set_data_field::<u16>(13, other.reader.get_data_field::<u16>(13));
+          self.builder.set_bool_field(224, other.reader.get_bool_field(224));
+          self.builder.set_data_field::<u16>(15, other.reader.get_data_field::<u16>(15));
+          self.builder.set_data_field::<u32>(8, other.reader.get_data_field::<u32>(8));
+          crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(3), crate::any_pointer::Reader::new(other.reader.get_pointer_field(3)), false)?;
+        }
+        2 => {
+          self.reborrow().init_enum();
+          crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(3), crate::any_pointer::Reader::new(other.reader.get_pointer_field(3)), false)?;
+        }
+        3 => {
+          self.reborrow().init_interface();
+          crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(3), crate::any_pointer::Reader::new(other.reader.get_pointer_field(3)), false)?;
+          crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(4), crate::any_pointer::Reader::new(other.reader.get_pointer_field(4)), false)?;
+        }
+        4 => {
+          self.reborrow().init_const();
+          crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(3), crate::any_pointer::Reader::new(other.reader.get_pointer_field(3)), false)?;
+          crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(4), crate::any_pointer::Reader::new(other.reader.get_pointer_field(4)), false)?;
+        }
+        5 => {
+          self.reborrow().init_annotation();
+          crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(3), crate::any_pointer::Reader::new(other.reader.get_pointer_field(3)), false)?;
+          self.builder.set_bool_field(112, other.reader.get_bool_field(112));
+          self.builder.set_bool_field(113, other.reader.get_bool_field(113));
+          self.builder.set_bool_field(114, other.reader.get_bool_field(114));
+          self.builder.set_bool_field(115, other.reader.get_bool_field(115));
+          self.builder.set_bool_field(116, other.reader.get_bool_field(116));
+          self.builder.set_bool_field(117, other.reader.get_bool_field(117));
+          self.builder.set_bool_field(118, other.reader.get_bool_field(118));
+          self.builder.set_bool_field(119, other.reader.get_bool_field(119));
+          self.builder.set_bool_field(120, other.reader.get_bool_field(120));
+          self.builder.set_bool_field(121, other.reader.get_bool_field(121));
+          self.builder.set_bool_field(122, other.reader.get_bool_field(122));
+          self.builder.set_bool_field(123, other.reader.get_bool_field(123));
+        }
+        x => return ::core::result::Result::Err(crate::NotInSchema(x).into()),
+      }
+      ::core::result::Result::Ok(())
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+  impl crate::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+    let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [crate::Word; 225] = [
+      crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+      crate::word(23, 164, 35, 249, 76, 171, 130, 230),
+      crate::word(13, 0, 0, 0, 1, 0, 5, 0),
+      crate::word(217, 114, 76, 98, 9, 197, 63, 169),
+      crate::word(6, 0, 7, 0, 0, 0, 6, 0),
+      crate::word(6, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(21, 0, 0, 0, 146, 0, 0, 0),
+      crate::word(29, 0, 0, 0, 55, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(73, 0, 0, 0, 23, 3, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+      crate::word(97, 112, 110, 112, 58, 78, 111, 100),
+      crate::word(101, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(12, 0, 0, 0, 1, 0, 1, 0),
+      crate::word(177, 163, 15, 241, 204, 27, 82, 185),
+      crate::word(17, 0, 0, 0, 82, 0, 0, 0),
+      crate::word(66, 194, 15, 250, 187, 85, 191, 222),
+      crate::word(17, 0, 0, 0, 90, 0, 0, 0),
+      crate::word(174, 87, 19, 4, 227, 29, 142, 243),
+      crate::word(17, 0, 0, 0, 90, 0, 0, 0),
+      crate::word(80, 97, 114, 97, 109, 101, 116, 101),
+      crate::word(114, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(78, 101, 115, 116, 101, 100, 78, 111),
+      crate::word(100, 101, 0, 0, 0, 0, 0, 0),
+      crate::word(83, 111, 117, 114, 99, 101, 73, 110),
+      crate::word(102, 111, 0, 0, 0, 0, 0, 0),
+      crate::word(56, 0, 0, 0, 3, 0, 4, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(121, 1, 0, 0, 26, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(116, 1, 0, 0, 3, 0, 1, 0),
+      crate::word(128, 1, 0, 0, 2, 0, 1, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 1, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(125, 1, 0, 0, 98, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(124, 1, 0, 0, 3, 0, 1, 0),
+      crate::word(136, 1, 0, 0, 2, 0, 1, 0),
+      crate::word(2, 0, 0, 0, 2, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 2, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(133, 1, 0, 0, 194, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(136, 1, 0, 0, 3, 0, 1, 0),
+      crate::word(148, 1, 0, 0, 2, 0, 1, 0),
+      crate::word(3, 0, 0, 0, 2, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 3, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(145, 1, 0, 0, 66, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(140, 1, 0, 0, 3, 0, 1, 0),
+      crate::word(152, 1, 0, 0, 2, 0, 1, 0),
+      crate::word(6, 0, 0, 0, 1, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 4, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(149, 1, 0, 0, 98, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(148, 1, 0, 0, 3, 0, 1, 0),
+      crate::word(176, 1, 0, 0, 2, 0, 1, 0),
+      crate::word(7, 0, 0, 0, 2, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 5, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(173, 1, 0, 0, 98, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(172, 1, 0, 0, 3, 0, 1, 0),
+      crate::word(200, 1, 0, 0, 2, 0, 1, 0),
+      crate::word(8, 0, 255, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 6, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(197, 1, 0, 0, 42, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(192, 1, 0, 0, 3, 0, 1, 0),
+      crate::word(204, 1, 0, 0, 2, 0, 1, 0),
+      crate::word(9, 0, 254, 255, 0, 0, 0, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(53, 68, 251, 55, 155, 177, 160, 158),
+      crate::word(201, 1, 0, 0, 58, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(10, 0, 253, 255, 0, 0, 0, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(152, 245, 51, 67, 54, 179, 74, 181),
+      crate::word(177, 1, 0, 0, 42, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(11, 0, 252, 255, 0, 0, 0, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(143, 33, 194, 240, 207, 83, 39, 232),
+      crate::word(153, 1, 0, 0, 82, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(12, 0, 251, 255, 0, 0, 0, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(32, 148, 13, 122, 172, 165, 138, 177),
+      crate::word(133, 1, 0, 0, 50, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(13, 0, 250, 255, 0, 0, 0, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(144, 2, 10, 64, 212, 25, 22, 236),
+      crate::word(109, 1, 0, 0, 90, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(4, 0, 0, 0, 5, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 32, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(89, 1, 0, 0, 90, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(88, 1, 0, 0, 3, 0, 1, 0),
+      crate::word(116, 1, 0, 0, 2, 0, 1, 0),
+      crate::word(5, 0, 0, 0, 32, 1, 0, 0),
+      crate::word(0, 0, 1, 0, 33, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(113, 1, 0, 0, 82, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(112, 1, 0, 0, 3, 0, 1, 0),
+      crate::word(124, 1, 0, 0, 2, 0, 1, 0),
+      crate::word(105, 100, 0, 0, 0, 0, 0, 0),
+      crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(100, 105, 115, 112, 108, 97, 121, 78),
+      crate::word(97, 109, 101, 0, 0, 0, 0, 0),
+      crate::word(12, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(12, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(100, 105, 115, 112, 108, 97, 121, 78),
+      crate::word(97, 109, 101, 80, 114, 101, 102, 105),
+      crate::word(120, 76, 101, 110, 103, 116, 104, 0),
+      crate::word(8, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(8, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(115, 99, 111, 112, 101, 73, 100, 0),
+      crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(110, 101, 115, 116, 101, 100, 78, 111),
+      crate::word(100, 101, 115, 0, 0, 0, 0, 0),
+      crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(66, 194, 15, 250, 187, 85, 191, 222),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(97, 110, 110, 111, 116, 97, 116, 105),
+      crate::word(111, 110, 115, 0, 0, 0, 0, 0),
+      crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(66, 117, 37, 171, 13, 149, 200, 241),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(102, 105, 108, 101, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(115, 116, 114, 117, 99, 116, 0, 0),
+      crate::word(101, 110, 117, 109, 0, 0, 0, 0),
+      crate::word(105, 110, 116, 101, 114, 102, 97, 99),
+      crate::word(101, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(99, 111, 110, 115, 116, 0, 0, 0),
+      crate::word(97, 110, 110, 111, 116, 97, 116, 105),
+      crate::word(111, 110, 0, 0, 0, 0, 0, 0),
+      crate::word(112, 97, 114, 97, 109, 101, 116, 101),
+      crate::word(114, 115, 0, 0, 0, 0, 0, 0),
+      crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(177, 163, 15, 241, 204, 27, 82, 185),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(105, 115, 71, 101, 110, 101, 114, 105),
+      crate::word(99, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "schema.capnp:Node";
+    pub fn get_field_types(index: u16) -> crate::introspect::Type {
+      match index {
+        0 => <u64 as crate::introspect::Introspect>::introspect(),
+        1 => <crate::text::Owned as crate::introspect::Introspect>::introspect(),
+        2 => <u32 as crate::introspect::Introspect>::introspect(),
+        3 => <u64 as crate::introspect::Introspect>::introspect(),
+        4 => <crate::struct_list::Owned<crate::schema_capnp::node::nested_node::Owned> as crate::introspect::Introspect>::introspect(),
+        5 => <crate::struct_list::Owned<crate::schema_capnp::annotation::Owned> as crate::introspect::Introspect>::introspect(),
+        6 => <() as crate::introspect::Introspect>::introspect(),
+        7 => <crate::schema_capnp::node::struct_::Owned as crate::introspect::Introspect>::introspect(),
+        8 => <crate::schema_capnp::node::enum_::Owned as crate::introspect::Introspect>::introspect(),
+        9 => <crate::schema_capnp::node::interface::Owned as crate::introspect::Introspect>::introspect(),
+        10 => <crate::schema_capnp::node::const_::Owned as crate::introspect::Introspect>::introspect(),
+        11 => <crate::schema_capnp::node::annotation::Owned as crate::introspect::Introspect>::introspect(),
+        12 => <crate::struct_list::Owned<crate::schema_capnp::node::parameter::Owned> as crate::introspect::Introspect>::introspect(),
+        13 => <bool as crate::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1,2,3,4,5,12,13];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[6,7,8,9,10,11];
+    pub mod layout {
+      pub const DISCRIMINANT_OFFSET: usize = 12;
+      pub const FIELD_ID_OFFSET: usize = 0;
+      pub const FIELD_DISPLAY_NAME_POINTER_INDEX: usize = 0;
+      pub const FIELD_DISPLAY_NAME_PREFIX_LENGTH_OFFSET: usize = 8;
+      pub const FIELD_SCOPE_ID_OFFSET: usize = 16;
+      pub const FIELD_NESTED_NODES_POINTER_INDEX: usize = 1;
+      pub const FIELD_ANNOTATIONS_POINTER_INDEX: usize = 2;
+      pub const FIELD_FILE_DISCRIMINANT: u16 = 0;
+      pub const FIELD_STRUCT_DISCRIMINANT: u16 = 1;
+      pub const FIELD_ENUM_DISCRIMINANT: u16 = 2;
+      pub const FIELD_INTERFACE_DISCRIMINANT: u16 = 3;
+      pub const FIELD_CONST_DISCRIMINANT: u16 = 4;
+      pub const FIELD_ANNOTATION_DISCRIMINANT: u16 = 5;
+      pub const FIELD_PARAMETERS_POINTER_INDEX: usize = 5;
+      pub const FIELD_IS_GENERIC_BIT_OFFSET: usize = 288;
+    }
+    pub const TYPE_ID: u64 = 0xe682_ab4c_f923_a417;
+  }
+  pub enum Which<A0,A1,A2,A3,A4> {
+    File(()),
+    Struct(A0),
+    Enum(A1),
+    Interface(A2),
+    Const(A3),
+    Annotation(A4),
+  }
+  pub type WhichReader<'a,> = Which<crate::schema_capnp::node::struct_::Reader<'a>,crate::schema_capnp::node::enum_::Reader<'a>,crate::schema_capnp::node::interface::Reader<'a>,crate::schema_capnp::node::const_::Reader<'a>,crate::schema_capnp::node::annotation::Reader<'a>>;
+  pub type WhichBuilder<'a,> = Which<crate::schema_capnp::node::struct_::Builder<'a>,crate::schema_capnp::node::enum_::Builder<'a>,crate::schema_capnp::node::interface::Builder<'a>,crate::schema_capnp::node::const_::Builder<'a>,crate::schema_capnp::node::annotation::Builder<'a>>;
+
+  pub mod parameter {
+  pub struct Parameter {
+ pub _name: String,  
+}
+  impl Parameter {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+  builder.set_name(self._name.as_str().into());
+  ::core::result::Result::Ok(())
+}}
+  
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Parameter  {
+    type Error = crate::Error;
+    fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(Self {
+        _name: reader.get_name()?.to_string()?,
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Parameter {
+    type Error = crate::Error;
+    fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl Parameter {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+      pub name: usize,
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+          name: 0,
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(counts: FieldCounts) -> usize {
+      (counts.name + 8) / 8
+    }
+    /// Words taken up by a message holding just this struct: its data and pointer sections
+    /// plus the root pointer.
+    pub const MIN_SIZE_WORDS: usize = 0 + 1 + 1;
+    /// An upper bound on the words that a single-segment message holding this struct takes up.
+    pub const fn total_words_for(counts: FieldCounts) -> usize {
+      MIN_SIZE_WORDS + variable_words_for(counts)
+    }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+        self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn get_name(self) -> crate::Result<crate::text::Reader<'a>> {
+        crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn has_name(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
+    }
+
+    pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+    impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 0, pointers: 1 };
+    }
+    impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+        self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn get_name(self) -> crate::Result<crate::text::Builder<'a>> {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn set_name(&mut self, value: crate::text::Reader<'_>)  {
+        self.builder.reborrow().get_pointer_field(0).set_text(value);
+      }
+      #[inline]
+      pub fn init_name(self, size: u32) -> crate::text::Builder<'a> {
+        self.builder.get_pointer_field(0).init_text(size)
+      }
+      #[inline]
+      pub fn disown_name(&mut self) -> crate::Orphan<crate::text::Owned> {
+        crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+      }
+      #[inline]
+      pub fn adopt_name(&mut self, orphan: crate::Orphan<crate::text::Owned>) -> crate::Result<()> {
+        self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+      }
+      #[inline]
+      pub fn get_or_init_name(&mut self, size: u32) -> crate::Result<crate::text::Builder<'_>> {
+        if self.builder.is_pointer_field_null(0) {
+          ::core::result::Result::Ok(self.reborrow().init_name(size))
+        } else {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+        }
+      }
+      #[inline]
+      pub fn has_name(&self) -> bool {
+        !self.builder.is_pointer_field_null(0)
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+    impl crate::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+      let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [crate::Word; 33] = [
+        crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+        crate::word(177, 163, 15, 241, 204, 27, 82, 185),
+        crate::word(18, 0, 0, 0, 1, 0, 0, 0),
+        crate::word(23, 164, 35, 249, 76, 171, 130, 230),
+        crate::word(1, 0, 7, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 226, 0, 0, 0),
+        crate::word(33, 0, 0, 0, 7, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(29, 0, 0, 0, 63, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+        crate::word(97, 112, 110, 112, 58, 78, 111, 100),
+        crate::word(101, 46, 80, 97, 114, 97, 109, 101),
+        crate::word(116, 101, 114, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 1, 0, 1, 0),
+        crate::word(4, 0, 0, 0, 3, 0, 4, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(13, 0, 0, 0, 42, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(8, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(20, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(110, 97, 109, 101, 0, 0, 0, 0),
+        crate::word(12, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(12, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "schema.capnp:Node.Parameter";
+      pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        match index {
+          0 => <crate::text::Owned as crate::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[0];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+      pub mod layout {
+        pub const FIELD_NAME_POINTER_INDEX: usize = 0;
+      }
+      pub const TYPE_ID: u64 = 0xb952_1bcc_f10f_a3b1;
+    }
+  }
+
+  pub mod nested_node {
+  pub struct NestedNode {
+ pub _name: String,
+ pub _id: u64,  
+}
+  impl NestedNode {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+  builder.set_name(self._name.as_str().into());
+  builder.set_id(self._id);
+  ::core::result::Result::Ok(())
+}}
+  
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for NestedNode  {
+    type Error = crate::Error;
+    fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(Self {
+        _name: reader.get_name()?.to_string()?,
+        _id: reader.get_id(),
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for NestedNode {
+    type Error = crate::Error;
+    fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl NestedNode {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+      pub name: usize,
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+          name: 0,
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(counts: FieldCounts) -> usize {
+      (counts.name + 8) / 8
+    }
+    /// Words taken up by a message holding just this struct: its data and pointer sections
+    /// plus the root pointer.
+    pub const MIN_SIZE_WORDS: usize = 1 + 1 + 1;
+    /// An upper bound on the words that a single-segment message holding this struct takes up.
+    pub const fn total_words_for(counts: FieldCounts) -> usize {
+      MIN_SIZE_WORDS + variable_words_for(counts)
+    }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+        self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn get_name(self) -> crate::Result<crate::text::Reader<'a>> {
+        crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn has_name(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
+      #[inline]
+      pub fn get_id(self) -> u64 {
+        self.reader.get_data_field::<u64>(0)
+      }
+    }
+
+    pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+    impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 1, pointers: 1 };
+    }
+    impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+        self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn get_name(self) -> crate::Result<crate::text::Builder<'a>> {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn set_name(&mut self, value: crate::text::Reader<'_>)  {
+        self.builder.reborrow().get_pointer_field(0).set_text(value);
+      }
+      #[inline]
+      pub fn init_name(self, size: u32) -> crate::text::Builder<'a> {
+        self.builder.get_pointer_field(0).init_text(size)
+      }
+      #[inline]
+      pub fn disown_name(&mut self) -> crate::Orphan<crate::text::Owned> {
+        crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+      }
+      #[inline]
+      pub fn adopt_name(&mut self, orphan: crate::Orphan<crate::text::Owned>) -> crate::Result<()> {
+        self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+      }
+      #[inline]
+      pub fn get_or_init_name(&mut self, size: u32) -> crate::Result<crate::text::Builder<'_>> {
+        if self.builder.is_pointer_field_null(0) {
+          ::core::result::Result::Ok(self.reborrow().init_name(size))
+        } else {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+        }
+      }
+      #[inline]
+      pub fn has_name(&self) -> bool {
+        !self.builder.is_pointer_field_null(0)
+      }
+      #[inline]
+      pub fn get_id(self) -> u64 {
+        self.builder.get_data_field::<u64>(0)
+      }
+      #[inline]
+      pub fn set_id(&mut self, value: u64)  {
+        self.builder.set_data_field::<u64>(0, value);
+      }
+      #[inline]
+      pub fn set_id_from(&mut self, value: impl ::core::convert::TryInto<u64>) -> crate::Result<()> {
+        let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+        self.set_id(value);
+        ::core::result::Result::Ok(())
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+    impl crate::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+      let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [crate::Word; 48] = [
+        crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+        crate::word(66, 194, 15, 250, 187, 85, 191, 222),
+        crate::word(18, 0, 0, 0, 1, 0, 1, 0),
+        crate::word(23, 164, 35, 249, 76, 171, 130, 230),
+        crate::word(1, 0, 7, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 234, 0, 0, 0),
+        crate::word(33, 0, 0, 0, 7, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(29, 0, 0, 0, 119, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+        crate::word(97, 112, 110, 112, 58, 78, 111, 100),
+        crate::word(101, 46, 78, 101, 115, 116, 101, 100),
+        crate::word(78, 111, 100, 101, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 1, 0, 1, 0),
+        crate::word(8, 0, 0, 0, 3, 0, 4, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(41, 0, 0, 0, 42, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(36, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(48, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 1, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(45, 0, 0, 0, 26, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(40, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(52, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(110, 97, 109, 101, 0, 0, 0, 0),
+        crate::word(12, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(12, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(105, 100, 0, 0, 0, 0, 0, 0),
+        crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "schema.capnp:Node.NestedNode";
+      pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        match index {
+          0 => <crate::text::Owned as crate::introspect::Introspect>::introspect(),
+          1 => <u64 as crate::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[0,1];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+      pub mod layout {
+        pub const FIELD_NAME_POINTER_INDEX: usize = 0;
+        pub const FIELD_ID_OFFSET: usize = 0;
+      }
+      pub const TYPE_ID: u64 = 0xdebf_55bb_fa0f_c242;
+    }
+  }
+
+  pub mod source_info {
+  pub struct SourceInfo {
+ pub _id: u64,
+ pub _doc_comment: String,
+ pub _members: Vec<crate::schema_capnp::node::source_info::member::Member>,  
+}
+  impl SourceInfo {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+  builder.set_id(self._id);
+  builder.set_doc_comment(self._doc_comment.as_str().into());
+            
+if self._members.len() > 0 {
+                let mut list_builder = builder.reborrow().init_members(crate::private::checked_len(self._members.len())?);
+                for (i, item) in self._members.into_iter().enumerate() {
+                    item.build_capnp_struct(list_builder.reborrow().get(i as u32))?;
+                }
+            }
+  ::core::result::Result::Ok(())
+}}
+  
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for SourceInfo  {
+    type Error = crate::Error;
+    fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(Self {
+        _id: reader.get_id(),
+        _doc_comment: reader.get_doc_comment()?.to_string()?,
+        _members: reader.get_members()?.iter().map(|item| crate::Result::Ok(<crate::schema_capnp::node::source_info::member::Member as ::core::convert::TryFrom<_>>::try_from(item)?)).collect::<crate::Result<Vec<_>>>()?,
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for SourceInfo {
+    type Error = crate::Error;
+    fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl SourceInfo {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+      pub doc_comment: usize,
+      pub members: usize,
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+          doc_comment: 0,
+          members: 0,
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(counts: FieldCounts) -> usize {
+      (counts.doc_comment + 8) / 8 + 1 + counts.members * (crate::schema_capnp::node::source_info::member::MIN_SIZE_WORDS - 1)
+    }
+    /// Words taken up by a message holding just this struct: its data and pointer sections
+    /// plus the root pointer.
+    pub const MIN_SIZE_WORDS: usize = 1 + 2 + 1;
+    /// An upper bound on the words that a single-segment message holding this struct takes up.
+    pub const fn total_words_for(counts: FieldCounts) -> usize {
+      MIN_SIZE_WORDS + variable_words_for(counts)
+    }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+        self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn get_id(self) -> u64 {
+        self.reader.get_data_field::<u64>(0)
+      }
+      #[inline]
+      pub fn get_doc_comment(self) -> crate::Result<crate::text::Reader<'a>> {
+        crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn has_doc_comment(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
+      #[inline]
+      pub fn get_members(self) -> crate::Result<crate::struct_list::Reader<'a,crate::schema_capnp::node::source_info::member::Owned>> {
+        crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(1), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn has_members(&self) -> bool {
+        !self.reader.get_pointer_field(1).is_null()
+      }
+    }
+
+    pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+    impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 1, pointers: 2 };
+    }
+    impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+        self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn get_id(self) -> u64 {
+        self.builder.get_data_field::<u64>(0)
+      }
+      #[inline]
+      pub fn set_id(&mut self, value: u64)  {
+        self.builder.set_data_field::<u64>(0, value);
+      }
+      #[inline]
+      pub fn set_id_from(&mut self, value: impl ::core::convert::TryInto<u64>) -> crate::Result<()> {
+        let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+        self.set_id(value);
+        ::core::result::Result::Ok(())
+      }
+      #[inline]
+      pub fn get_doc_comment(self) -> crate::Result<crate::text::Builder<'a>> {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn set_doc_comment(&mut self, value: crate::text::Reader<'_>)  {
+        self.builder.reborrow().get_pointer_field(0).set_text(value);
+      }
+      #[inline]
+      pub fn init_doc_comment(self, size: u32) -> crate::text::Builder<'a> {
+        self.builder.get_pointer_field(0).init_text(size)
+      }
+      #[inline]
+      pub fn disown_doc_comment(&mut self) -> crate::Orphan<crate::text::Owned> {
+        crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+      }
+      #[inline]
+      pub fn adopt_doc_comment(&mut self, orphan: crate::Orphan<crate::text::Owned>) -> crate::Result<()> {
+        self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+      }
+      #[inline]
+      pub fn get_or_init_doc_comment(&mut self, size: u32) -> crate::Result<crate::text::Builder<'_>> {
+        if self.builder.is_pointer_field_null(0) {
+          ::core::result::Result::Ok(self.reborrow().init_doc_comment(size))
+        } else {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+        }
+      }
+      #[inline]
+      pub fn has_doc_comment(&self) -> bool {
+        !self.builder.is_pointer_field_null(0)
+      }
+      #[inline]
+      pub fn get_members(self) -> crate::Result<crate::struct_list::Builder<'a,crate::schema_capnp::node::source_info::member::Owned>> {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(1), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn set_members(&mut self, value: crate::struct_list::Reader<'a,crate::schema_capnp::node::source_info::member::Owned>) -> crate::Result<()> {
+        crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(1), value, false)
+      }
+      #[inline]
+      pub fn init_members(self, size: u32) -> crate::struct_list::Builder<'a,crate::schema_capnp::node::source_info::member::Owned> {
+        crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
+      }
+      #[inline]
+      pub fn disown_members(&mut self) -> crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::node::source_info::member::Owned>> {
+        crate::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+      }
+      #[inline]
+      pub fn adopt_members(&mut self, orphan: crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::node::source_info::member::Owned>>) -> crate::Result<()> {
+        self.builder.reborrow().get_pointer_field(1).adopt(orphan.into_internal())
+      }
+      #[inline]
+      pub fn get_or_init_members(&mut self, size: u32) -> crate::Result<crate::struct_list::Builder<'_,crate::schema_capnp::node::source_info::member::Owned>> {
+        if self.builder.is_pointer_field_null(1) {
+          ::core::result::Result::Ok(self.reborrow().init_members(size))
+        } else {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(1), ::core::option::Option::None)
+        }
+      }
+      #[inline]
+      pub fn has_members(&self) -> bool {
+        !self.builder.is_pointer_field_null(1)
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+    impl crate::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+      let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [crate::Word; 71] = [
+        crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+        crate::word(174, 87, 19, 4, 227, 29, 142, 243),
+        crate::word(18, 0, 0, 0, 1, 0, 1, 0),
+        crate::word(23, 164, 35, 249, 76, 171, 130, 230),
+        crate::word(2, 0, 7, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 234, 0, 0, 0),
+        crate::word(33, 0, 0, 0, 23, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(41, 0, 0, 0, 175, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+        crate::word(97, 112, 110, 112, 58, 78, 111, 100),
+        crate::word(101, 46, 83, 111, 117, 114, 99, 101),
+        crate::word(73, 110, 102, 111, 0, 0, 0, 0),
+        crate::word(4, 0, 0, 0, 1, 0, 1, 0),
+        crate::word(162, 31, 142, 137, 56, 144, 186, 194),
+        crate::word(1, 0, 0, 0, 58, 0, 0, 0),
+        crate::word(77, 101, 109, 98, 101, 114, 0, 0),
+        crate::word(12, 0, 0, 0, 3, 0, 4, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(69, 0, 0, 0, 26, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(64, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(76, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 1, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(73, 0, 0, 0, 90, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(72, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(84, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(2, 0, 0, 0, 1, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 2, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(81, 0, 0, 0, 66, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(76, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(104, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(105, 100, 0, 0, 0, 0, 0, 0),
+        crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(100, 111, 99, 67, 111, 109, 109, 101),
+        crate::word(110, 116, 0, 0, 0, 0, 0, 0),
+        crate::word(12, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(12, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(109, 101, 109, 98, 101, 114, 115, 0),
+        crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(162, 31, 142, 137, 56, 144, 186, 194),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "schema.capnp:Node.SourceInfo";
+      pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        match index {
+          0 => <u64 as crate::introspect::Introspect>::introspect(),
+          1 => <crate::text::Owned as crate::introspect::Introspect>::introspect(),
+          2 => <crate::struct_list::Owned<crate::schema_capnp::node::source_info::member::Owned> as crate::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[0,1,2];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+      pub mod layout {
+        pub const FIELD_ID_OFFSET: usize = 0;
+        pub const FIELD_DOC_COMMENT_POINTER_INDEX: usize = 0;
+        pub const FIELD_MEMBERS_POINTER_INDEX: usize = 1;
+      }
+      pub const TYPE_ID: u64 = 0xf38e_1de3_0413_57ae;
+    }
+
+    pub mod member {
+    pub struct Member {
+ pub _doc_comment: String,  
+}
+    impl Member {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+  builder.set_doc_comment(self._doc_comment.as_str().into());
+  ::core::result::Result::Ok(())
+}}
+    
+    impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Member  {
+      type Error = crate::Error;
+      fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(Self {
+          _doc_comment: reader.get_doc_comment()?.to_string()?,
+        })
+      }
+    }
+    impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Member {
+      type Error = crate::Error;
+      fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+        <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+      }
+    }
+    impl Member {
+      /// Writes the value into `builder`, which must be a struct of this type.
+      pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+        self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+      }
+    }
+      /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+      ///
+      /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+      /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+      /// the pointer to them. Objects that list elements point to are not included.
+      #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+      pub struct FieldCounts {
+        pub doc_comment: usize,
+      }
+      impl FieldCounts {
+        /// Zero for all fields except structs, which get the size of a struct with no content.
+        pub const fn new() -> Self {
+          Self {
+            doc_comment: 0,
+          }
+        }
+      }
+      impl ::core::default::Default for FieldCounts {
+        fn default() -> Self { Self::new() }
+      }
+      /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+      pub const fn variable_words_for(counts: FieldCounts) -> usize {
+        (counts.doc_comment + 8) / 8
+      }
+      /// Words taken up by a message holding just this struct: its data and pointer sections
+      /// plus the root pointer.
+      pub const MIN_SIZE_WORDS: usize = 0 + 1 + 1;
+      /// An upper bound on the words that a single-segment message holding this struct takes up.
+      pub const fn total_words_for(counts: FieldCounts) -> usize {
+        MIN_SIZE_WORDS + variable_words_for(counts)
+      }
+      #[derive(Copy, Clone)]
+      pub struct Owned(());
+      impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+      impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+      impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+      impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+      impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+      pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+      impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+      impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+        fn clone(&self) -> Self { *self }
+      }
+
+      impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+        const TYPE_ID: u64 = _private::TYPE_ID;
+      }
+      impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+        fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+          Self { reader,  }
+        }
+      }
+
+      impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+        fn from(reader: Reader<'a,>) -> Self {
+          Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+        }
+      }
+
+      impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+          core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+        }
+      }
+
+      impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+        fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+          ::core::result::Result::Ok(reader.get_struct(default)?.into())
+        }
+      }
+
+      impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+        fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+          self.reader
+        }
+      }
+
+      impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+        fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+          self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+        }
+      }
+
+      impl <'a,> Reader<'a,>  {
+        pub fn reborrow(&self) -> Reader<'_,> {
+          Self { .. *self }
+        }
+
+        pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+          self.reader.total_size()
+        }
+
+        /// The number of words that serializing a message with a copy of this struct as its
+        /// root takes, including the segment table.
+        pub fn serialized_words(&self) -> crate::Result<usize> {
+          self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+        }
+
+        /// Views this struct through the dynamic API.
+        pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+          ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+        }
+        #[inline]
+        pub fn get_doc_comment(self) -> crate::Result<crate::text::Reader<'a>> {
+          crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+        }
+        #[inline]
+        pub fn has_doc_comment(&self) -> bool {
+          !self.reader.get_pointer_field(0).is_null()
+        }
+      }
+
+      pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+      impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+        const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 0, pointers: 1 };
+      }
+      impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+        const TYPE_ID: u64 = _private::TYPE_ID;
+      }
+      impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+        fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+          Self { builder,  }
+        }
+      }
+
+      impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+        fn from(builder: Builder<'a,>) -> Self {
+          Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+        }
+      }
+
+      impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+        fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+          self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+        }
+      }
+
+      impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+        fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+          builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+        }
+        fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+          ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+        }
+        fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+          ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+        }
+      }
+
+      impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+        fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+      }
+
+      impl <'a,> Builder<'a,>  {
+        pub fn into_reader(self) -> Reader<'a,> {
+          self.builder.into_reader().into()
+        }
+        pub fn reborrow(&mut self) -> Builder<'_,> {
+          Builder { builder: self.builder.reborrow() }
+        }
+        pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+          self.builder.as_reader().into()
+        }
+
+        pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+          self.builder.as_reader().total_size()
+        }
+
+        /// The number of words that serializing a message with a copy of this struct as its
+        /// root takes, including the segment table.
+        pub fn serialized_words(&self) -> crate::Result<usize> {
+          self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+        }
+
+        /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+        pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+          ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+        }
+
+        /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+        /// Fails with `TypeMismatch`, naming both types, if it is not.
+        pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+          let src_proto = src.get_schema().get_proto();
+          if src_proto.get_id() != _private::TYPE_ID {
+            let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+            ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+            return ::core::result::Result::Err(error);
+          }
+          let src: Reader<'_,> = src.downcast()?;
+          self.builder.copy_content_from(&src.reader)
+        }
+        #[inline]
+        pub fn get_doc_comment(self) -> crate::Result<crate::text::Builder<'a>> {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+        }
+        #[inline]
+        pub fn set_doc_comment(&mut self, value: crate::text::Reader<'_>)  {
+          self.builder.reborrow().get_pointer_field(0).set_text(value);
+        }
+        #[inline]
+        pub fn init_doc_comment(self, size: u32) -> crate::text::Builder<'a> {
+          self.builder.get_pointer_field(0).init_text(size)
+        }
+        #[inline]
+        pub fn disown_doc_comment(&mut self) -> crate::Orphan<crate::text::Owned> {
+          crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
+        pub fn adopt_doc_comment(&mut self, orphan: crate::Orphan<crate::text::Owned>) -> crate::Result<()> {
+          self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+        }
+        #[inline]
+        pub fn get_or_init_doc_comment(&mut self, size: u32) -> crate::Result<crate::text::Builder<'_>> {
+          if self.builder.is_pointer_field_null(0) {
+            ::core::result::Result::Ok(self.reborrow().init_doc_comment(size))
+          } else {
+            crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+          }
+        }
+        #[inline]
+        pub fn has_doc_comment(&self) -> bool {
+          !self.builder.is_pointer_field_null(0)
+        }
+      }
+
+      impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+          ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+        }
+      }
+
+      pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+      impl crate::capability::FromTypelessPipeline for Pipeline {
+        fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+          Self { _typeless: typeless,  }
+        }
+      }
+      impl ::core::fmt::Debug for Pipeline {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+          f.write_str(::core::any::type_name::<Self>())
+        }
+      }
+      impl Pipeline  {
+      }
+      /// The `(field_index, annotation_id)` pair of each annotation on a field.
+      pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+      ];
+      /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+      /// it has one.
+      pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+        let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+        if field_index >= children.len() {
+          return ::core::option::Option::None;
+        }
+        children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+      }
+      pub mod _private {
+        pub static ENCODED_NODE: [crate::Word; 35] = [
+          crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+          crate::word(162, 31, 142, 137, 56, 144, 186, 194),
+          crate::word(29, 0, 0, 0, 1, 0, 0, 0),
+          crate::word(174, 87, 19, 4, 227, 29, 142, 243),
+          crate::word(1, 0, 7, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(21, 0, 0, 0, 34, 1, 0, 0),
+          crate::word(37, 0, 0, 0, 7, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(33, 0, 0, 0, 63, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+          crate::word(97, 112, 110, 112, 58, 78, 111, 100),
+          crate::word(101, 46, 83, 111, 117, 114, 99, 101),
+          crate::word(73, 110, 102, 111, 46, 77, 101, 109),
+          crate::word(98, 101, 114, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 1, 0, 1, 0),
+          crate::word(4, 0, 0, 0, 3, 0, 4, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 1, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(13, 0, 0, 0, 90, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(12, 0, 0, 0, 3, 0, 1, 0),
+          crate::word(24, 0, 0, 0, 2, 0, 1, 0),
+          crate::word(100, 111, 99, 67, 111, 109, 109, 101),
+          crate::word(110, 116, 0, 0, 0, 0, 0, 0),
+          crate::word(12, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(12, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ];
+        pub const DISPLAY_NAME: &str = "schema.capnp:Node.SourceInfo.Member";
+        pub fn get_field_types(index: u16) -> crate::introspect::Type {
+          match index {
+            0 => <crate::text::Owned as crate::introspect::Introspect>::introspect(),
+            _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+          }
+        }
+        pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+          panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+        }
+        pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+          encoded_node: &ENCODED_NODE,
+          nonunion_members: NONUNION_MEMBERS,
+          members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+        };
+        pub static NONUNION_MEMBERS : &[u16] = &[0];
+        pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+        pub mod layout {
+          pub const FIELD_DOC_COMMENT_POINTER_INDEX: usize = 0;
+        }
+        pub const TYPE_ID: u64 = 0xc2ba_9038_898e_1fa2;
+      }
+    }
+  }
+
+  pub mod struct_ {
+  pub struct Struct {
+ pub _data_word_count: u16,
+ pub _pointer_count: u16,
+ pub _preferred_list_encoding: crate::schema_capnp::ElementSize,
+ pub _is_group: bool,
+ pub _discriminant_count: u16,
+ pub _discriminant_offset: u32,
+ pub _fields: Vec<crate::schema_capnp::field::Field>,  
+}
+  impl Struct {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+  builder.set_data_word_count(self._data_word_count);
+  builder.set_pointer_count(self._pointer_count);
+  builder.set_preferred_list_encoding(self._preferred_list_encoding);
+  builder.set_is_group(self._is_group);
+  builder.set_discriminant_count(self._discriminant_count);
+  builder.set_discriminant_offset(self._discriminant_offset);
+            
+if self._fields.len() > 0 {
+                let mut list_builder = builder.reborrow().init_fields(crate::private::checked_len(self._fields.len())?);
+                for (i, item) in self._fields.into_iter().enumerate() {
+                    item.build_capnp_struct(list_builder.reborrow().get(i as u32))?;
+                }
+            }
+  ::core::result::Result::Ok(())
+}}
+  
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Struct  {
+    type Error = crate::Error;
+    fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(Self {
+        _data_word_count: reader.get_data_word_count(),
+        _pointer_count: reader.get_pointer_count(),
+        _preferred_list_encoding: reader.get_preferred_list_encoding()?,
+        _is_group: reader.get_is_group(),
+        _discriminant_count: reader.get_discriminant_count(),
+        _discriminant_offset: reader.get_discriminant_offset(),
+        _fields: reader.get_fields()?.iter().map(|item| crate::Result::Ok(<crate::schema_capnp::field::Field as ::core::convert::TryFrom<_>>::try_from(item)?)).collect::<crate::Result<Vec<_>>>()?,
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Struct {
+    type Error = crate::Error;
+    fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl Struct {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+      pub fields: usize,
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+          fields: 0,
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(counts: FieldCounts) -> usize {
+      1 + counts.fields * (crate::schema_capnp::field::MIN_SIZE_WORDS - 1)
+    }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+        self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn get_data_word_count(self) -> u16 {
+        self.reader.get_data_field::<u16>(7)
+      }
+      #[inline]
+      pub fn get_pointer_count(self) -> u16 {
+        self.reader.get_data_field::<u16>(12)
+      }
+      #[inline]
+      pub fn get_preferred_list_encoding(self) -> ::core::result::Result<crate::schema_capnp::ElementSize,crate::NotInSchema> {
+        ::core::convert::TryInto::try_into(self.reader.get_data_field::<u16>(13))
+      }
+      #[inline]
+      pub fn get_is_group(self) -> bool {
+        self.reader.get_bool_field(224)
+      }
+      #[inline]
+      pub fn get_discriminant_count(self) -> u16 {
+        self.reader.get_data_field::<u16>(15)
+      }
+      #[inline]
+      pub fn get_discriminant_offset(self) -> u32 {
+        self.reader.get_data_field::<u32>(8)
+      }
+      #[inline]
+      pub fn get_fields(self) -> crate::Result<crate::struct_list::Reader<'a,crate::schema_capnp::field::Owned>> {
+        crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(3), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn has_fields(&self) -> bool {
+        !self.reader.get_pointer_field(3).is_null()
+      }
+    }
+
+    pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+    impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 5, pointers: 6 };
+    }
+    impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+        self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn get_data_word_count(self) -> u16 {
+        self.builder.get_data_field::<u16>(7)
+      }
+      #[inline]
+      pub fn set_data_word_count(&mut self, value: u16)  {
+        self.builder.set_data_field::<u16>(7, value);
+      }
+      #[inline]
+      pub fn set_data_word_count_from(&mut self, value: impl ::core::convert::TryInto<u16>) -> crate::Result<()> {
+        let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+        self.set_data_word_count(value);
+        ::core::result::Result::Ok(())
+      }
+      #[inline]
+      pub fn get_pointer_count(self) -> u16 {
+        self.builder.get_data_field::<u16>(12)
+      }
+      #[inline]
+      pub fn set_pointer_count(&mut self, value: u16)  {
+        self.builder.set_data_field::<u16>(12, value);
+      }
+      #[inline]
+      pub fn set_pointer_count_from(&mut self, value: impl ::core::convert::TryInto<u16>) -> crate::Result<()> {
+        let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+        self.set_pointer_count(value);
+        ::core::result::Result::Ok(())
+      }
+      #[inline]
+      pub fn get_preferred_list_encoding(self) -> ::core::result::Result<crate::schema_capnp::ElementSize,crate::NotInSchema> {
+        ::core::convert::TryInto::try_into(self.builder.get_data_field::<u16>(13))
+      }
+      #[inline]
+      pub fn set_preferred_list_encoding(&mut self, value: crate::schema_capnp::ElementSize)  {
+        self.builder.set_data_field::<u16>(13, value as u16);
+      }
+      #[inline]
+      pub fn get_is_group(self) -> bool {
+        self.builder.get_bool_field(224)
+      }
+      #[inline]
+      pub fn set_is_group(&mut self, value: bool)  {
+        self.builder.set_bool_field(224, value);
+      }
+      #[inline]
+      pub fn get_discriminant_count(self) -> u16 {
+        self.builder.get_data_field::<u16>(15)
+      }
+      #[inline]
+      pub fn set_discriminant_count(&mut self, value: u16)  {
+        self.builder.set_data_field::<u16>(15, value);
+      }
+      #[inline]
+      pub fn set_discriminant_count_from(&mut self, value: impl ::core::convert::TryInto<u16>) -> crate::Result<()> {
+        let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+        self.set_discriminant_count(value);
+        ::core::result::Result::Ok(())
+      }
+      #[inline]
+      pub fn get_discriminant_offset(self) -> u32 {
+        self.builder.get_data_field::<u32>(8)
+      }
+      #[inline]
+      pub fn set_discriminant_offset(&mut self, value: u32)  {
+        self.builder.set_data_field::<u32>(8, value);
+      }
+      #[inline]
+      pub fn set_discriminant_offset_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> crate::Result<()> {
+        let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+        self.set_discriminant_offset(value);
+        ::core::result::Result::Ok(())
+      }
+      #[inline]
+      pub fn get_fields(self) -> crate::Result<crate::struct_list::Builder<'a,crate::schema_capnp::field::Owned>> {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(3), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn set_fields(&mut self, value: crate::struct_list::Reader<'a,crate::schema_capnp::field::Owned>) -> crate::Result<()> {
+        crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(3), value, false)
+      }
+      #[inline]
+      pub fn init_fields(self, size: u32) -> crate::struct_list::Builder<'a,crate::schema_capnp::field::Owned> {
+        crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), size)
+      }
+      #[inline]
+      pub fn disown_fields(&mut self) -> crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::field::Owned>> {
+        crate::Orphan::new(self.builder.reborrow().get_pointer_field(3).disown())
+      }
+      #[inline]
+      pub fn adopt_fields(&mut self, orphan: crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::field::Owned>>) -> crate::Result<()> {
+        self.builder.reborrow().get_pointer_field(3).adopt(orphan.into_internal())
+      }
+      #[inline]
+      pub fn get_or_init_fields(&mut self, size: u32) -> crate::Result<crate::struct_list::Builder<'_,crate::schema_capnp::field::Owned>> {
+        if self.builder.is_pointer_field_null(3) {
+          ::core::result::Result::Ok(self.reborrow().init_fields(size))
+        } else {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(3), ::core::option::Option::None)
+        }
+      }
+      #[inline]
+      pub fn has_fields(&self) -> bool {
+        !self.builder.is_pointer_field_null(3)
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+    impl crate::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+      let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [crate::Word; 134] = [
+        crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+        crate::word(53, 68, 251, 55, 155, 177, 160, 158),
+        crate::word(18, 0, 0, 0, 1, 0, 5, 0),
+        crate::word(23, 164, 35, 249, 76, 171, 130, 230),
+        crate::word(6, 0, 7, 0, 1, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 202, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(25, 0, 0, 0, 143, 1, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+        crate::word(97, 112, 110, 112, 58, 78, 111, 100),
+        crate::word(101, 46, 115, 116, 114, 117, 99, 116),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(28, 0, 0, 0, 3, 0, 4, 0),
+        crate::word(0, 0, 0, 0, 7, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 7, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(181, 0, 0, 0, 114, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(180, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(192, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(1, 0, 0, 0, 12, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 8, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(189, 0, 0, 0, 106, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(188, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(200, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(2, 0, 0, 0, 13, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 9, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(197, 0, 0, 0, 178, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(200, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(212, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(3, 0, 0, 0, 224, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 10, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(209, 0, 0, 0, 66, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(204, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(216, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(4, 0, 0, 0, 15, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 11, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(213, 0, 0, 0, 146, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(216, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(228, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(5, 0, 0, 0, 8, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 12, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(225, 0, 0, 0, 154, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(228, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(240, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(6, 0, 0, 0, 3, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 13, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(237, 0, 0, 0, 58, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(232, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(4, 1, 0, 0, 2, 0, 1, 0),
+        crate::word(100, 97, 116, 97, 87, 111, 114, 100),
+        crate::word(67, 111, 117, 110, 116, 0, 0, 0),
+        crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(112, 111, 105, 110, 116, 101, 114, 67),
+        crate::word(111, 117, 110, 116, 0, 0, 0, 0),
+        crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(112, 114, 101, 102, 101, 114, 114, 101),
+        crate::word(100, 76, 105, 115, 116, 69, 110, 99),
+        crate::word(111, 100, 105, 110, 103, 0, 0, 0),
+        crate::word(15, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(38, 25, 82, 186, 125, 143, 149, 209),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(15, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(105, 115, 71, 114, 111, 117, 112, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(100, 105, 115, 99, 114, 105, 109, 105),
+        crate::word(110, 97, 110, 116, 67, 111, 117, 110),
+        crate::word(116, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(100, 105, 115, 99, 114, 105, 109, 105),
+        crate::word(110, 97, 110, 116, 79, 102, 102, 115),
+        crate::word(101, 116, 0, 0, 0, 0, 0, 0),
+        crate::word(8, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(8, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(102, 105, 101, 108, 100, 115, 0, 0),
+        crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(95, 244, 74, 31, 164, 80, 173, 154),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "schema.capnp:Node.struct";
+      pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        match index {
+          0 => <u16 as crate::introspect::Introspect>::introspect(),
+          1 => <u16 as crate::introspect::Introspect>::introspect(),
+          2 => <crate::schema_capnp::ElementSize as crate::introspect::Introspect>::introspect(),
+          3 => <bool as crate::introspect::Introspect>::introspect(),
+          4 => <u16 as crate::introspect::Introspect>::introspect(),
+          5 => <u32 as crate::introspect::Introspect>::introspect(),
+          6 => <crate::struct_list::Owned<crate::schema_capnp::field::Owned> as crate::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[0,1,2,3,4,5,6];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+      pub mod layout {
+        pub const FIELD_DATA_WORD_COUNT_OFFSET: usize = 14;
+        pub const FIELD_POINTER_COUNT_OFFSET: usize = 24;
+        pub const FIELD_PREFERRED_LIST_ENCODING_OFFSET: usize = 26;
+        pub const FIELD_IS_GROUP_BIT_OFFSET: usize = 224;
+        pub const FIELD_DISCRIMINANT_COUNT_OFFSET: usize = 30;
+        pub const FIELD_DISCRIMINANT_OFFSET_OFFSET: usize = 32;
+        pub const FIELD_FIELDS_POINTER_INDEX: usize = 3;
+      }
+      pub const TYPE_ID: u64 = 0x9ea0_b19b_37fb_4435;
+    }
+  }
+
+  pub mod enum_ {
+  pub struct Enum {
+ pub _enumerants: Vec<crate::schema_capnp::enumerant::Enumerant>,  
+}
+  impl Enum {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+            
+if self._enumerants.len() > 0 {
+                let mut list_builder = builder.reborrow().init_enumerants(crate::private::checked_len(self._enumerants.len())?);
+                for (i, item) in self._enumerants.into_iter().enumerate() {
+                    item.build_capnp_struct(list_builder.reborrow().get(i as u32))?;
+                }
+            }
+  ::core::result::Result::Ok(())
+}}
+  
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Enum  {
+    type Error = crate::Error;
+    fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(Self {
+        _enumerants: reader.get_enumerants()?.iter().map(|item| crate::Result::Ok(<crate::schema_capnp::enumerant::Enumerant as ::core::convert::TryFrom<_>>::try_from(item)?)).collect::<crate::Result<Vec<_>>>()?,
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Enum {
+    type Error = crate::Error;
+    fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl Enum {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+      pub enumerants: usize,
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+          enumerants: 0,
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(counts: FieldCounts) -> usize {
+      1 + counts.enumerants * (crate::schema_capnp::enumerant::MIN_SIZE_WORDS - 1)
+    }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+        self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn get_enumerants(self) -> crate::Result<crate::struct_list::Reader<'a,crate::schema_capnp::enumerant::Owned>> {
+        crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(3), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn has_enumerants(&self) -> bool {
+        !self.reader.get_pointer_field(3).is_null()
+      }
+    }
+
+    pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+    impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 5, pointers: 6 };
+    }
+    impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+        self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn get_enumerants(self) -> crate::Result<crate::struct_list::Builder<'a,crate::schema_capnp::enumerant::Owned>> {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(3), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn set_enumerants(&mut self, value: crate::struct_list::Reader<'a,crate::schema_capnp::enumerant::Owned>) -> crate::Result<()> {
+        crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(3), value, false)
+      }
+      #[inline]
+      pub fn init_enumerants(self, size: u32) -> crate::struct_list::Builder<'a,crate::schema_capnp::enumerant::Owned> {
+        crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), size)
+      }
+      #[inline]
+      pub fn disown_enumerants(&mut self) -> crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::enumerant::Owned>> {
+        crate::Orphan::new(self.builder.reborrow().get_pointer_field(3).disown())
+      }
+      #[inline]
+      pub fn adopt_enumerants(&mut self, orphan: crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::enumerant::Owned>>) -> crate::Result<()> {
+        self.builder.reborrow().get_pointer_field(3).adopt(orphan.into_internal())
+      }
+      #[inline]
+      pub fn get_or_init_enumerants(&mut self, size: u32) -> crate::Result<crate::struct_list::Builder<'_,crate::schema_capnp::enumerant::Owned>> {
+        if self.builder.is_pointer_field_null(3) {
+          ::core::result::Result::Ok(self.reborrow().init_enumerants(size))
+        } else {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(3), ::core::option::Option::None)
+        }
+      }
+      #[inline]
+      pub fn has_enumerants(&self) -> bool {
+        !self.builder.is_pointer_field_null(3)
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+    impl crate::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+      let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [crate::Word; 36] = [
+        crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+        crate::word(152, 245, 51, 67, 54, 179, 74, 181),
+        crate::word(18, 0, 0, 0, 1, 0, 5, 0),
+        crate::word(23, 164, 35, 249, 76, 171, 130, 230),
+        crate::word(6, 0, 7, 0, 1, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 186, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 63, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+        crate::word(97, 112, 110, 112, 58, 78, 111, 100),
+        crate::word(101, 46, 101, 110, 117, 109, 0, 0),
+        crate::word(4, 0, 0, 0, 3, 0, 4, 0),
+        crate::word(0, 0, 0, 0, 3, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 14, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(13, 0, 0, 0, 90, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(12, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(40, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(101, 110, 117, 109, 101, 114, 97, 110),
+        crate::word(116, 115, 0, 0, 0, 0, 0, 0),
+        crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(77, 154, 84, 220, 235, 124, 138, 151),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "schema.capnp:Node.enum";
+      pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        match index {
+          0 => <crate::struct_list::Owned<crate::schema_capnp::enumerant::Owned> as crate::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[0];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+      pub mod layout {
+        pub const FIELD_ENUMERANTS_POINTER_INDEX: usize = 3;
+      }
+      pub const TYPE_ID: u64 = 0xb54a_b336_4333_f598;
+    }
+  }
+
+  pub mod interface {
+  pub struct Interface {
+ pub _methods: Vec<crate::schema_capnp::method::Method>,
+ pub _superclasses: Vec<crate::schema_capnp::superclass::Superclass>,  
+}
+  impl Interface {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+            
+if self._methods.len() > 0 {
+                let mut list_builder = builder.reborrow().init_methods(crate::private::checked_len(self._methods.len())?);
+                for (i, item) in self._methods.into_iter().enumerate() {
+                    item.build_capnp_struct(list_builder.reborrow().get(i as u32))?;
+                }
+            }
+            
+if self._superclasses.len() > 0 {
+                let mut list_builder = builder.reborrow().init_superclasses(crate::private::checked_len(self._superclasses.len())?);
+                for (i, item) in self._superclasses.into_iter().enumerate() {
+                    item.build_capnp_struct(list_builder.reborrow().get(i as u32))?;
+                }
+            }
+  ::core::result::Result::Ok(())
+}}
+  
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Interface  {
+    type Error = crate::Error;
+    fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(Self {
+        _methods: reader.get_methods()?.iter().map(|item| crate::Result::Ok(<crate::schema_capnp::method::Method as ::core::convert::TryFrom<_>>::try_from(item)?)).collect::<crate::Result<Vec<_>>>()?,
+        _superclasses: reader.get_superclasses()?.iter().map(|item| crate::Result::Ok(<crate::schema_capnp::superclass::Superclass as ::core::convert::TryFrom<_>>::try_from(item)?)).collect::<crate::Result<Vec<_>>>()?,
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Interface {
+    type Error = crate::Error;
+    fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl Interface {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+      pub methods: usize,
+      pub superclasses: usize,
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+          methods: 0,
+          superclasses: 0,
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(counts: FieldCounts) -> usize {
+      1 + counts.methods * (crate::schema_capnp::method::MIN_SIZE_WORDS - 1) + 1 + counts.superclasses * (crate::schema_capnp::superclass::MIN_SIZE_WORDS - 1)
+    }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+        self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn get_methods(self) -> crate::Result<crate::struct_list::Reader<'a,crate::schema_capnp::method::Owned>> {
+        crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(3), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn has_methods(&self) -> bool {
+        !self.reader.get_pointer_field(3).is_null()
+      }
+      #[inline]
+      pub fn get_superclasses(self) -> crate::Result<crate::struct_list::Reader<'a,crate::schema_capnp::superclass::Owned>> {
+        crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(4), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn has_superclasses(&self) -> bool {
+        !self.reader.get_pointer_field(4).is_null()
+      }
+    }
+
+    pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+    impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 5, pointers: 6 };
+    }
+    impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+        self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn get_methods(self) -> crate::Result<crate::struct_list::Builder<'a,crate::schema_capnp::method::Owned>> {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(3), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn set_methods(&mut self, value: crate::struct_list::Reader<'a,crate::schema_capnp::method::Owned>) -> crate::Result<()> {
+        crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(3), value, false)
+      }
+      #[inline]
+      pub fn init_methods(self, size: u32) -> crate::struct_list::Builder<'a,crate::schema_capnp::method::Owned> {
+        crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), size)
+      }
+      #[inline]
+      pub fn disown_methods(&mut self) -> crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::method::Owned>> {
+        crate::Orphan::new(self.builder.reborrow().get_pointer_field(3).disown())
+      }
+      #[inline]
+      pub fn adopt_methods(&mut self, orphan: crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::method::Owned>>) -> crate::Result<()> {
+        self.builder.reborrow().get_pointer_field(3).adopt(orphan.into_internal())
+      }
+      #[inline]
+      pub fn get_or_init_methods(&mut self, size: u32) -> crate::Result<crate::struct_list::Builder<'_,crate::schema_capnp::method::Owned>> {
+        if self.builder.is_pointer_field_null(3) {
+          ::core::result::Result::Ok(self.reborrow().init_methods(size))
+        } else {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(3), ::core::option::Option::None)
+        }
+      }
+      #[inline]
+      pub fn has_methods(&self) -> bool {
+        !self.builder.is_pointer_field_null(3)
+      }
+      #[inline]
+      pub fn get_superclasses(self) -> crate::Result<crate::struct_list::Builder<'a,crate::schema_capnp::superclass::Owned>> {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(4), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn set_superclasses(&mut self, value: crate::struct_list::Reader<'a,crate::schema_capnp::superclass::Owned>) -> crate::Result<()> {
+        crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(4), value, false)
+      }
+      #[inline]
+      pub fn init_superclasses(self, size: u32) -> crate::struct_list::Builder<'a,crate::schema_capnp::superclass::Owned> {
+        crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(4), size)
+      }
+      #[inline]
+      pub fn disown_superclasses(&mut self) -> crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::superclass::Owned>> {
+        crate::Orphan::new(self.builder.reborrow().get_pointer_field(4).disown())
+      }
+      #[inline]
+      pub fn adopt_superclasses(&mut self, orphan: crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::superclass::Owned>>) -> crate::Result<()> {
+        self.builder.reborrow().get_pointer_field(4).adopt(orphan.into_internal())
+      }
+      #[inline]
+      pub fn get_or_init_superclasses(&mut self, size: u32) -> crate::Result<crate::struct_list::Builder<'_,crate::schema_capnp::superclass::Owned>> {
+        if self.builder.is_pointer_field_null(4) {
+          ::core::result::Result::Ok(self.reborrow().init_superclasses(size))
+        } else {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(4), ::core::option::Option::None)
+        }
+      }
+      #[inline]
+      pub fn has_superclasses(&self) -> bool {
+        !self.builder.is_pointer_field_null(4)
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+    impl crate::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+      let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [crate::Word; 56] = [
+        crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+        crate::word(143, 33, 194, 240, 207, 83, 39, 232),
+        crate::word(18, 0, 0, 0, 1, 0, 5, 0),
+        crate::word(23, 164, 35, 249, 76, 171, 130, 230),
+        crate::word(6, 0, 7, 0, 1, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 226, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(25, 0, 0, 0, 119, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+        crate::word(97, 112, 110, 112, 58, 78, 111, 100),
+        crate::word(101, 46, 105, 110, 116, 101, 114, 102),
+        crate::word(97, 99, 101, 0, 0, 0, 0, 0),
+        crate::word(8, 0, 0, 0, 3, 0, 4, 0),
+        crate::word(0, 0, 0, 0, 3, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 15, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(41, 0, 0, 0, 66, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(36, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(64, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(1, 0, 0, 0, 4, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 31, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(61, 0, 0, 0, 106, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(60, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(88, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(109, 101, 116, 104, 111, 100, 115, 0),
+        crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(128, 77, 51, 59, 226, 204, 0, 149),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(115, 117, 112, 101, 114, 99, 108, 97),
+        crate::word(115, 115, 101, 115, 0, 0, 0, 0),
+        crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(248, 215, 164, 208, 158, 42, 150, 169),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "schema.capnp:Node.interface";
+      pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        match index {
+          0 => <crate::struct_list::Owned<crate::schema_capnp::method::Owned> as crate::introspect::Introspect>::introspect(),
+          1 => <crate::struct_list::Owned<crate::schema_capnp::superclass::Owned> as crate::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[0,1];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+      pub mod layout {
+        pub const FIELD_METHODS_POINTER_INDEX: usize = 3;
+        pub const FIELD_SUPERCLASSES_POINTER_INDEX: usize = 4;
+      }
+      pub const TYPE_ID: u64 = 0xe827_53cf_f0c2_218f;
+    }
+  }
+
+  pub mod const_ {
+  pub struct Const {
+ pub _type: Option<crate::schema_capnp::type_::Type>,
+ pub _value: Option<crate::schema_capnp::value::Value>,  
+}
+  impl Const {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+  if let Some(st) = self._type {st.build_capnp_struct(builder.reborrow().init_type())?;}
+  if let Some(st) = self._value {st.build_capnp_struct(builder.reborrow().init_value())?;}
+  ::core::result::Result::Ok(())
+}}
+  
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Const  {
+    type Error = crate::Error;
+    fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(Self {
+        _type: if reader.has_type() { ::core::option::Option::Some(<crate::schema_capnp::type_::Type as ::core::convert::TryFrom<_>>::try_from(reader.get_type()?)?) } else { ::core::option::Option::None },
+        _value: if reader.has_value() { ::core::option::Option::Some(<crate::schema_capnp::value::Value as ::core::convert::TryFrom<_>>::try_from(reader.get_value()?)?) } else { ::core::option::Option::None },
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Const {
+    type Error = crate::Error;
+    fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl Const {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+      pub type_: usize,
+      pub value: usize,
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+          type_: crate::schema_capnp::type_::MIN_SIZE_WORDS - 1,
+          value: crate::schema_capnp::value::MIN_SIZE_WORDS - 1,
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(counts: FieldCounts) -> usize {
+      counts.type_ + counts.value
+    }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+        self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn get_type(self) -> crate::Result<crate::schema_capnp::type_::Reader<'a>> {
+        crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(3), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn has_type(&self) -> bool {
+        !self.reader.get_pointer_field(3).is_null()
+      }
+      #[inline]
+      pub fn get_value(self) -> crate::Result<crate::schema_capnp::value::Reader<'a>> {
+        crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(4), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn has_value(&self) -> bool {
+        !self.reader.get_pointer_field(4).is_null()
+      }
+    }
+
+    pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+    impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 5, pointers: 6 };
+    }
+    impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+        self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn get_type(self) -> crate::Result<crate::schema_capnp::type_::Builder<'a>> {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(3), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn set_type(&mut self, value: crate::schema_capnp::type_::Reader<'_>) -> crate::Result<()> {
+        crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(3), value, false)
+      }
+      #[inline]
+      pub fn init_type(self, ) -> crate::schema_capnp::type_::Builder<'a> {
+        crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), 0)
+      }
+      #[inline]
+      pub fn disown_type(&mut self) -> crate::Orphan<crate::schema_capnp::type_::Owned> {
+        crate::Orphan::new(self.builder.reborrow().get_pointer_field(3).disown())
+      }
+      #[inline]
+      pub fn adopt_type(&mut self, orphan: crate::Orphan<crate::schema_capnp::type_::Owned>) -> crate::Result<()> {
+        self.builder.reborrow().get_pointer_field(3).adopt(orphan.into_internal())
+      }
+      #[inline]
+      pub fn get_or_init_type(&mut self, ) -> crate::Result<crate::schema_capnp::type_::Builder<'_>> {
+        if self.builder.is_pointer_field_null(3) {
+          ::core::result::Result::Ok(self.reborrow().init_type())
+        } else {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(3), ::core::option::Option::None)
+        }
+      }
+      #[inline]
+      pub fn has_type(&self) -> bool {
+        !self.builder.is_pointer_field_null(3)
+      }
+      #[inline]
+      pub fn get_value(self) -> crate::Result<crate::schema_capnp::value::Builder<'a>> {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(4), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn set_value(&mut self, value: crate::schema_capnp::value::Reader<'_>) -> crate::Result<()> {
+        crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(4), value, false)
+      }
+      #[inline]
+      pub fn init_value(self, ) -> crate::schema_capnp::value::Builder<'a> {
+        crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(4), 0)
+      }
+      #[inline]
+      pub fn disown_value(&mut self) -> crate::Orphan<crate::schema_capnp::value::Owned> {
+        crate::Orphan::new(self.builder.reborrow().get_pointer_field(4).disown())
+      }
+      #[inline]
+      pub fn adopt_value(&mut self, orphan: crate::Orphan<crate::schema_capnp::value::Owned>) -> crate::Result<()> {
+        self.builder.reborrow().get_pointer_field(4).adopt(orphan.into_internal())
+      }
+      #[inline]
+      pub fn get_or_init_value(&mut self, ) -> crate::Result<crate::schema_capnp::value::Builder<'_>> {
+        if self.builder.is_pointer_field_null(4) {
+          ::core::result::Result::Ok(self.reborrow().init_value())
+        } else {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(4), ::core::option::Option::None)
+        }
+      }
+      #[inline]
+      pub fn has_value(&self) -> bool {
+        !self.builder.is_pointer_field_null(4)
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+    impl crate::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+      #[inline]
+      pub fn get_type(&self) -> crate::schema_capnp::type_::Pipeline {
+        crate::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(3))
+      }
+      #[inline]
+      pub fn get_value(&self) -> crate::schema_capnp::value::Pipeline {
+        crate::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(4))
+      }
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+      let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [crate::Word; 46] = [
+        crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+        crate::word(32, 148, 13, 122, 172, 165, 138, 177),
+        crate::word(18, 0, 0, 0, 1, 0, 5, 0),
+        crate::word(23, 164, 35, 249, 76, 171, 130, 230),
+        crate::word(6, 0, 7, 0, 1, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 194, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 119, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+        crate::word(97, 112, 110, 112, 58, 78, 111, 100),
+        crate::word(101, 46, 99, 111, 110, 115, 116, 0),
+        crate::word(8, 0, 0, 0, 3, 0, 4, 0),
+        crate::word(0, 0, 0, 0, 3, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 16, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(41, 0, 0, 0, 42, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(36, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(48, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(1, 0, 0, 0, 4, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 17, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(45, 0, 0, 0, 50, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(40, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(52, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(116, 121, 112, 101, 0, 0, 0, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(96, 204, 249, 225, 237, 120, 115, 208),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(118, 97, 108, 117, 101, 0, 0, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(155, 12, 176, 215, 210, 220, 35, 206),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "schema.capnp:Node.const";
+      pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        match index {
+          0 => <crate::schema_capnp::type_::Owned as crate::introspect::Introspect>::introspect(),
+          1 => <crate::schema_capnp::value::Owned as crate::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[0,1];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+      pub mod layout {
+        pub const FIELD_TYPE_POINTER_INDEX: usize = 3;
+        pub const FIELD_VALUE_POINTER_INDEX: usize = 4;
+      }
+      pub const TYPE_ID: u64 = 0xb18a_a5ac_7a0d_9420;
+    }
+  }
+
+  pub mod annotation {
+  pub struct Annotation {
+ pub _type: Option<crate::schema_capnp::type_::Type>,
+ pub _targets_file: bool,
+ pub _targets_const: bool,
+ pub _targets_enum: bool,
+ pub _targets_enumerant: bool,
+ pub _targets_struct: bool,
+ pub _targets_field: bool,
+ pub _targets_union: bool,
+ pub _targets_group: bool,
+ pub _targets_interface: bool,
+ pub _targets_method: bool,
+ pub _targets_param: bool,
+ pub _targets_annotation: bool,  
+}
+  impl Annotation {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+  if let Some(st) = self._type {st.build_capnp_struct(builder.reborrow().init_type())?;}
+  builder.set_targets_file(self._targets_file);
+  builder.set_targets_const(self._targets_const);
+  builder.set_targets_enum(self._targets_enum);
+  builder.set_targets_enumerant(self._targets_enumerant);
+  builder.set_targets_struct(self._targets_struct);
+  builder.set_targets_field(self._targets_field);
+  builder.set_targets_union(self._targets_union);
+  builder.set_targets_group(self._targets_group);
+  builder.set_targets_interface(self._targets_interface);
+  builder.set_targets_method(self._targets_method);
+  builder.set_targets_param(self._targets_param);
+  builder.set_targets_annotation(self._targets_annotation);
+  ::core::result::Result::Ok(())
+}}
+  
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Annotation  {
+    type Error = crate::Error;
+    fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(Self {
+        _type: if reader.has_type() { ::core::option::Option::Some(<crate::schema_capnp::type_::Type as ::core::convert::TryFrom<_>>::try_from(reader.get_type()?)?) } else { ::core::option::Option::None },
+        _targets_file: reader.get_targets_file(),
+        _targets_const: reader.get_targets_const(),
+        _targets_enum: reader.get_targets_enum(),
+        _targets_enumerant: reader.get_targets_enumerant(),
+        _targets_struct: reader.get_targets_struct(),
+        _targets_field: reader.get_targets_field(),
+        _targets_union: reader.get_targets_union(),
+        _targets_group: reader.get_targets_group(),
+        _targets_interface: reader.get_targets_interface(),
+        _targets_method: reader.get_targets_method(),
+        _targets_param: reader.get_targets_param(),
+        _targets_annotation: reader.get_targets_annotation(),
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Annotation {
+    type Error = crate::Error;
+    fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl Annotation {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+      pub type_: usize,
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+          type_: crate::schema_capnp::type_::MIN_SIZE_WORDS - 1,
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(counts: FieldCounts) -> usize {
+      counts.type_
+    }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+        self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn get_type(self) -> crate::Result<crate::schema_capnp::type_::Reader<'a>> {
+        crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(3), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn has_type(&self) -> bool {
+        !self.reader.get_pointer_field(3).is_null()
+      }
+      #[inline]
+      pub fn get_targets_file(self) -> bool {
+        self.reader.get_bool_field(112)
+      }
+      #[inline]
+      pub fn get_targets_const(self) -> bool {
+        self.reader.get_bool_field(113)
+      }
+      #[inline]
+      pub fn get_targets_enum(self) -> bool {
+        self.reader.get_bool_field(114)
+      }
+      #[inline]
+      pub fn get_targets_enumerant(self) -> bool {
+        self.reader.get_bool_field(115)
+      }
+      #[inline]
+      pub fn get_targets_struct(self) -> bool {
+        self.reader.get_bool_field(116)
+      }
+      #[inline]
+      pub fn get_targets_field(self) -> bool {
+        self.reader.get_bool_field(117)
+      }
+      #[inline]
+      pub fn get_targets_union(self) -> bool {
+        self.reader.get_bool_field(118)
+      }
+      #[inline]
+      pub fn get_targets_group(self) -> bool {
+        self.reader.get_bool_field(119)
+      }
+      #[inline]
+      pub fn get_targets_interface(self) -> bool {
+        self.reader.get_bool_field(120)
+      }
+      #[inline]
+      pub fn get_targets_method(self) -> bool {
+        self.reader.get_bool_field(121)
+      }
+      #[inline]
+      pub fn get_targets_param(self) -> bool {
+        self.reader.get_bool_field(122)
+      }
+      #[inline]
+      pub fn get_targets_annotation(self) -> bool {
+        self.reader.get_bool_field(123)
+      }
+    }
+
+    pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+    impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 5, pointers: 6 };
+    }
+    impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+        self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn get_type(self) -> crate::Result<crate::schema_capnp::type_::Builder<'a>> {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(3), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn set_type(&mut self, value: crate::schema_capnp::type_::Reader<'_>) -> crate::Result<()> {
+        crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(3), value, false)
+      }
+      #[inline]
+      pub fn init_type(self, ) -> crate::schema_capnp::type_::Builder<'a> {
+        crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), 0)
+      }
+      #[inline]
+      pub fn disown_type(&mut self) -> crate::Orphan<crate::schema_capnp::type_::Owned> {
+        crate::Orphan::new(self.builder.reborrow().get_pointer_field(3).disown())
+      }
+      #[inline]
+      pub fn adopt_type(&mut self, orphan: crate::Orphan<crate::schema_capnp::type_::Owned>) -> crate::Result<()> {
+        self.builder.reborrow().get_pointer_field(3).adopt(orphan.into_internal())
+      }
+      #[inline]
+      pub fn get_or_init_type(&mut self, ) -> crate::Result<crate::schema_capnp::type_::Builder<'_>> {
+        if self.builder.is_pointer_field_null(3) {
+          ::core::result::Result::Ok(self.reborrow().init_type())
+        } else {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(3), ::core::option::Option::None)
+        }
+      }
+      #[inline]
+      pub fn has_type(&self) -> bool {
+        !self.builder.is_pointer_field_null(3)
+      }
+      #[inline]
+      pub fn get_targets_file(self) -> bool {
+        self.builder.get_bool_field(112)
+      }
+      #[inline]
+      pub fn set_targets_file(&mut self, value: bool)  {
+        self.builder.set_bool_field(112, value);
+      }
+      #[inline]
+      pub fn get_targets_const(self) -> bool {
+        self.builder.get_bool_field(113)
+      }
+      #[inline]
+      pub fn set_targets_const(&mut self, value: bool)  {
+        self.builder.set_bool_field(113, value);
+      }
+      #[inline]
+      pub fn get_targets_enum(self) -> bool {
+        self.builder.get_bool_field(114)
+      }
+      #[inline]
+      pub fn set_targets_enum(&mut self, value: bool)  {
+        self.builder.set_bool_field(114, value);
+      }
+      #[inline]
+      pub fn get_targets_enumerant(self) -> bool {
+        self.builder.get_bool_field(115)
+      }
+      #[inline]
+      pub fn set_targets_enumerant(&mut self, value: bool)  {
+        self.builder.set_bool_field(115, value);
+      }
+      #[inline]
+      pub fn get_targets_struct(self) -> bool {
+        self.builder.get_bool_field(116)
+      }
+      #[inline]
+      pub fn set_targets_struct(&mut self, value: bool)  {
+        self.builder.set_bool_field(116, value);
+      }
+      #[inline]
+      pub fn get_targets_field(self) -> bool {
+        self.builder.get_bool_field(117)
+      }
+      #[inline]
+      pub fn set_targets_field(&mut self, value: bool)  {
+        self.builder.set_bool_field(117, value);
+      }
+      #[inline]
+      pub fn get_targets_union(self) -> bool {
+        self.builder.get_bool_field(118)
+      }
+      #[inline]
+      pub fn set_targets_union(&mut self, value: bool)  {
+        self.builder.set_bool_field(118, value);
+      }
+      #[inline]
+      pub fn get_targets_group(self) -> bool {
+        self.builder.get_bool_field(119)
+      }
+      #[inline]
+      pub fn set_targets_group(&mut self, value: bool)  {
+        self.builder.set_bool_field(119, value);
+      }
+      #[inline]
+      pub fn get_targets_interface(self) -> bool {
+        self.builder.get_bool_field(120)
+      }
+      #[inline]
+      pub fn set_targets_interface(&mut self, value: bool)  {
+        self.builder.set_bool_field(120, value);
+      }
+      #[inline]
+      pub fn get_targets_method(self) -> bool {
+        self.builder.get_bool_field(121)
+      }
+      #[inline]
+      pub fn set_targets_method(&mut self, value: bool)  {
+        self.builder.set_bool_field(121, value);
+      }
+      #[inline]
+      pub fn get_targets_param(self) -> bool {
+        self.builder.get_bool_field(122)
+      }
+      #[inline]
+      pub fn set_targets_param(&mut self, value: bool)  {
+        self.builder.set_bool_field(122, value);
+      }
+      #[inline]
+      pub fn get_targets_annotation(self) -> bool {
+        self.builder.get_bool_field(123)
+      }
+      #[inline]
+      pub fn set_targets_annotation(&mut self, value: bool)  {
+        self.builder.set_bool_field(123, value);
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+    impl crate::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+      #[inline]
+      pub fn get_type(&self) -> crate::schema_capnp::type_::Pipeline {
+        crate::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(3))
+      }
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+      let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [crate::Word; 227] = [
+        crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+        crate::word(144, 2, 10, 64, 212, 25, 22, 236),
+        crate::word(18, 0, 0, 0, 1, 0, 5, 0),
+        crate::word(23, 164, 35, 249, 76, 171, 130, 230),
+        crate::word(6, 0, 7, 0, 1, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 234, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(25, 0, 0, 0, 223, 2, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+        crate::word(97, 112, 110, 112, 58, 78, 111, 100),
+        crate::word(101, 46, 97, 110, 110, 111, 116, 97),
+        crate::word(116, 105, 111, 110, 0, 0, 0, 0),
+        crate::word(52, 0, 0, 0, 3, 0, 4, 0),
+        crate::word(0, 0, 0, 0, 3, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 18, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(93, 1, 0, 0, 42, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(88, 1, 0, 0, 3, 0, 1, 0),
+        crate::word(100, 1, 0, 0, 2, 0, 1, 0),
+        crate::word(1, 0, 0, 0, 112, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 19, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(97, 1, 0, 0, 98, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(96, 1, 0, 0, 3, 0, 1, 0),
+        crate::word(108, 1, 0, 0, 2, 0, 1, 0),
+        crate::word(2, 0, 0, 0, 113, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 20, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(105, 1, 0, 0, 106, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(104, 1, 0, 0, 3, 0, 1, 0),
+        crate::word(116, 1, 0, 0, 2, 0, 1, 0),
+        crate::word(3, 0, 0, 0, 114, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 21, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(113, 1, 0, 0, 98, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(112, 1, 0, 0, 3, 0, 1, 0),
+        crate::word(124, 1, 0, 0, 2, 0, 1, 0),
+        crate::word(4, 0, 0, 0, 115, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 22, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(121, 1, 0, 0, 138, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(124, 1, 0, 0, 3, 0, 1, 0),
+        crate::word(136, 1, 0, 0, 2, 0, 1, 0),
+        crate::word(5, 0, 0, 0, 116, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 23, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(133, 1, 0, 0, 114, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(132, 1, 0, 0, 3, 0, 1, 0),
+        crate::word(144, 1, 0, 0, 2, 0, 1, 0),
+        crate::word(6, 0, 0, 0, 117, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 24, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(141, 1, 0, 0, 106, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(140, 1, 0, 0, 3, 0, 1, 0),
+        crate::word(152, 1, 0, 0, 2, 0, 1, 0),
+        crate::word(7, 0, 0, 0, 118, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 25, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(149, 1, 0, 0, 106, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(148, 1, 0, 0, 3, 0, 1, 0),
+        crate::word(160, 1, 0, 0, 2, 0, 1, 0),
+        crate::word(8, 0, 0, 0, 119, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 26, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(157, 1, 0, 0, 106, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(156, 1, 0, 0, 3, 0, 1, 0),
+        crate::word(168, 1, 0, 0, 2, 0, 1, 0),
+        crate::word(9, 0, 0, 0, 120, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 27, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(165, 1, 0, 0, 138, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(168, 1, 0, 0, 3, 0, 1, 0),
+        crate::word(180, 1, 0, 0, 2, 0, 1, 0),
+        crate::word(10, 0, 0, 0, 121, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 28, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(177, 1, 0, 0, 114, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(176, 1, 0, 0, 3, 0, 1, 0),
+        crate::word(188, 1, 0, 0, 2, 0, 1, 0),
+        crate::word(11, 0, 0, 0, 122, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 29, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(185, 1, 0, 0, 106, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(184, 1, 0, 0, 3, 0, 1, 0),
+        crate::word(196, 1, 0, 0, 2, 0, 1, 0),
+        crate::word(12, 0, 0, 0, 123, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 30, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(193, 1, 0, 0, 146, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(196, 1, 0, 0, 3, 0, 1, 0),
+        crate::word(208, 1, 0, 0, 2, 0, 1, 0),
+        crate::word(116, 121, 112, 101, 0, 0, 0, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(96, 204, 249, 225, 237, 120, 115, 208),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(116, 97, 114, 103, 101, 116, 115, 70),
+        crate::word(105, 108, 101, 0, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(116, 97, 114, 103, 101, 116, 115, 67),
+        crate::word(111, 110, 115, 116, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(116, 97, 114, 103, 101, 116, 115, 69),
+        crate::word(110, 117, 109, 0, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(116, 97, 114, 103, 101, 116, 115, 69),
+        crate::word(110, 117, 109, 101, 114, 97, 110, 116),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(116, 97, 114, 103, 101, 116, 115, 83),
+        crate::word(116, 114, 117, 99, 116, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(116, 97, 114, 103, 101, 116, 115, 70),
+        crate::word(105, 101, 108, 100, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(116, 97, 114, 103, 101, 116, 115, 85),
+        crate::word(110, 105, 111, 110, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(116, 97, 114, 103, 101, 116, 115, 71),
+        crate::word(114, 111, 117, 112, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(116, 97, 114, 103, 101, 116, 115, 73),
+        crate::word(110, 116, 101, 114, 102, 97, 99, 101),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(116, 97, 114, 103, 101, 116, 115, 77),
+        crate::word(101, 116, 104, 111, 100, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(116, 97, 114, 103, 101, 116, 115, 80),
+        crate::word(97, 114, 97, 109, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(116, 97, 114, 103, 101, 116, 115, 65),
+        crate::word(110, 110, 111, 116, 97, 116, 105, 111),
+        crate::word(110, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "schema.capnp:Node.annotation";
+      pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        match index {
+          0 => <crate::schema_capnp::type_::Owned as crate::introspect::Introspect>::introspect(),
+          1 => <bool as crate::introspect::Introspect>::introspect(),
+          2 => <bool as crate::introspect::Introspect>::introspect(),
+          3 => <bool as crate::introspect::Introspect>::introspect(),
+          4 => <bool as crate::introspect::Introspect>::introspect(),
+          5 => <bool as crate::introspect::Introspect>::introspect(),
+          6 => <bool as crate::introspect::Introspect>::introspect(),
+          7 => <bool as crate::introspect::Introspect>::introspect(),
+          8 => <bool as crate::introspect::Introspect>::introspect(),
+          9 => <bool as crate::introspect::Introspect>::introspect(),
+          10 => <bool as crate::introspect::Introspect>::introspect(),
+          11 => <bool as crate::introspect::Introspect>::introspect(),
+          12 => <bool as crate::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[0,1,2,3,4,5,6,7,8,9,10,11,12];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+      pub mod layout {
+        pub const FIELD_TYPE_POINTER_INDEX: usize = 3;
+        pub const FIELD_TARGETS_FILE_BIT_OFFSET: usize = 112;
+        pub const FIELD_TARGETS_CONST_BIT_OFFSET: usize = 113;
+        pub const FIELD_TARGETS_ENUM_BIT_OFFSET: usize = 114;
+        pub const FIELD_TARGETS_ENUMERANT_BIT_OFFSET: usize = 115;
+        pub const FIELD_TARGETS_STRUCT_BIT_OFFSET: usize = 116;
+        pub const FIELD_TARGETS_FIELD_BIT_OFFSET: usize = 117;
+        pub const FIELD_TARGETS_UNION_BIT_OFFSET: usize = 118;
+        pub const FIELD_TARGETS_GROUP_BIT_OFFSET: usize = 119;
+        pub const FIELD_TARGETS_INTERFACE_BIT_OFFSET: usize = 120;
+        pub const FIELD_TARGETS_METHOD_BIT_OFFSET: usize = 121;
+        pub const FIELD_TARGETS_PARAM_BIT_OFFSET: usize = 122;
+        pub const FIELD_TARGETS_ANNOTATION_BIT_OFFSET: usize = 123;
+      }
+      pub const TYPE_ID: u64 = 0xec16_19d4_400a_0290;
+    }
+  }
+}
+
+pub mod field {
+pub struct Field {
+   pub uni: FieldUnion,
+ pub _name: String,
+ pub _code_order: u16,
+ pub _annotations: Vec<crate::schema_capnp::annotation::Annotation>,
+ pub _discriminant_value: u16,
+ pub _ordinal: crate::schema_capnp::field::ordinal::Ordinal,  
+}
+impl Field {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+ match self.uni {
+ FieldUnion::UNINITIALIZED => (),
+ FieldUnion::_Slot(t) => t.build_capnp_struct(builder.reborrow().init_slot())?,
+ FieldUnion::_Group(t) => t.build_capnp_struct(builder.reborrow().init_group())?,
+ }
+  builder.set_name(self._name.as_str().into());
+  builder.set_code_order(self._code_order);
+            
+if self._annotations.len() > 0 {
+                let mut list_builder = builder.reborrow().init_annotations(crate::private::checked_len(self._annotations.len())?);
+                for (i, item) in self._annotations.into_iter().enumerate() {
+                    item.build_capnp_struct(list_builder.reborrow().get(i as u32))?;
+                }
+            }
+  builder.set_discriminant_value(self._discriminant_value);
+  self._ordinal.build_capnp_struct(builder.reborrow().init_ordinal())?;
+  ::core::result::Result::Ok(())
+}}
+pub enum FieldUnion {
+ UNINITIALIZED,
+ _Slot(crate::schema_capnp::field::slot::Slot),
+ _Group(crate::schema_capnp::field::group::Group),
+}
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Field  {
+  type Error = crate::Error;
+  fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _name: reader.get_name()?.to_string()?,
+      _code_order: reader.get_code_order(),
+      _annotations: reader.get_annotations()?.iter().map(|item| crate::Result::Ok(<crate::schema_capnp::annotation::Annotation as ::core::convert::TryFrom<_>>::try_from(item)?)).collect::<crate::Result<Vec<_>>>()?,
+      _discriminant_value: reader.get_discriminant_value(),
+      _ordinal: <crate::schema_capnp::field::ordinal::Ordinal as ::core::convert::TryFrom<_>>::try_from(reader.get_ordinal())?,
+      uni: match reader.which()? {
+        Which::Slot(value) => FieldUnion::_Slot(<crate::schema_capnp::field::slot::Slot as ::core::convert::TryFrom<_>>::try_from(value)?),
+        Which::Group(value) => FieldUnion::_Group(<crate::schema_capnp::field::group::Group as ::core::convert::TryFrom<_>>::try_from(value)?),
+      },
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Field {
+  type Error = crate::Error;
+  fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl Field {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  pub use self::Which::{Slot,Group};
+
+  /// The members of the union, as `(discriminant, name, field index)` in discriminant order.
+  pub static UNION_MEMBERS: &[(u16, &str, u16)] = &[(0, "slot", 4), (1, "group", 5)];
+  /// Returns the schema name of the union member with discriminant `d`.
+  pub fn discriminant_name(d: u16) -> ::core::option::Option<&'static str> {
+    UNION_MEMBERS.get(d as usize).map(|member| member.1)
+  }
+
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub name: usize,
+    pub annotations: usize,
+    pub slot: crate::schema_capnp::field::slot::FieldCounts,
+    pub group: crate::schema_capnp::field::group::FieldCounts,
+    pub ordinal: crate::schema_capnp::field::ordinal::FieldCounts,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        name: 0,
+        annotations: 0,
+        slot: crate::schema_capnp::field::slot::FieldCounts::new(),
+        group: crate::schema_capnp::field::group::FieldCounts::new(),
+        ordinal: crate::schema_capnp::field::ordinal::FieldCounts::new(),
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    (counts.name + 8) / 8 + 1 + counts.annotations * (crate::schema_capnp::annotation::MIN_SIZE_WORDS - 1) + crate::schema_capnp::field::slot::variable_words_for(counts.slot) + crate::schema_capnp::field::group::variable_words_for(counts.group) + crate::schema_capnp::field::ordinal::variable_words_for(counts.ordinal)
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 3 + 4 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+      self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> crate::Result<usize> {
+      self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_name(self) -> crate::Result<crate::text::Reader<'a>> {
+      crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_name(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_code_order(self) -> u16 {
+      self.reader.get_data_field::<u16>(0)
+    }
+    #[inline]
+    pub fn get_annotations(self) -> crate::Result<crate::struct_list::Reader<'a,crate::schema_capnp::annotation::Owned>> {
+      crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_annotations(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
+    }
+    #[inline]
+    pub fn get_discriminant_value(self) -> u16 {
+      self.reader.get_data_field_mask::<u16>(1, 65535)
+    }
+    #[inline]
+    pub fn is_slot(&self) -> bool {
+      self.reader.get_data_field::<u16>(4) == 0
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_slot(&self) -> bool {
+      if self.reader.get_data_field::<u16>(4) != 0 { return false; }
+      self.reader.get_data_field::<u32>(1) != 0
+        || !self.reader.is_pointer_field_null(2)
+        || !self.reader.is_pointer_field_null(3)
+        || self.reader.get_bool_field(128)
+    }
+    #[inline]
+    pub fn is_group(&self) -> bool {
+      self.reader.get_data_field::<u16>(4) == 1
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_group(&self) -> bool {
+      if self.reader.get_data_field::<u16>(4) != 1 { return false; }
+      self.reader.get_data_field::<u64>(2) != 0
+    }
+    #[inline]
+    pub fn get_ordinal(self) -> crate::schema_capnp::field::ordinal::Reader<'a> {
+      self.reader.into()
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_ordinal(&self) -> bool {
+      self.reader.get_data_field::<u16>(5) != 0
+        || self.reader.get_data_field::<u16>(6) != 0
+    }
+    #[inline]
+    pub fn which(self) -> ::core::result::Result<WhichReader<'a,>, crate::NotInSchema> {
+      match self.reader.get_data_field::<u16>(4) {
+        0 => {
+          ::core::result::Result::Ok(Which::Slot(
+            self.reader.into()
+          ))
+        }
+        1 => {
+          ::core::result::Result::Ok(Which::Group(
+            self.reader.into()
+          ))
+        }
+        x => ::core::result::Result::Err(crate::NotInSchema(x))
+      }
+    }
+  }
+
+  pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+  impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 3, pointers: 4 };
+  }
+  impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+      self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> crate::Result<usize> {
+      self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_name(self) -> crate::Result<crate::text::Builder<'a>> {
+      crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_name(&mut self, value: crate::text::Reader<'_>)  {
+      self.builder.reborrow().get_pointer_field(0).set_text(value);
+    }
+    #[inline]
+    pub fn init_name(self, size: u32) -> crate::text::Builder<'a> {
+      self.builder.get_pointer_field(0).init_text(size)
+    }
+    #[inline]
+    pub fn disown_name(&mut self) -> crate::Orphan<crate::text::Owned> {
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_name(&mut self, orphan: crate::Orphan<crate::text::Owned>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_name(&mut self, size: u32) -> crate::Result<crate::text::Builder<'_>> {
+      if self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_name(size))
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_name(&self) -> bool {
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn get_code_order(self) -> u16 {
+      self.builder.get_data_field::<u16>(0)
+    }
+    #[inline]
+    pub fn set_code_order(&mut self, value: u16)  {
+      self.builder.set_data_field::<u16>(0, value);
+    }
+    #[inline]
+    pub fn set_code_order_from(&mut self, value: impl ::core::convert::TryInto<u16>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_code_order(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_annotations(self) -> crate::Result<crate::struct_list::Builder<'a,crate::schema_capnp::annotation::Owned>> {
+      crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_annotations(&mut self, value: crate::struct_list::Reader<'a,crate::schema_capnp::annotation::Owned>) -> crate::Result<()> {
+      crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(1), value, false)
+    }
+    #[inline]
+    pub fn init_annotations(self, size: u32) -> crate::struct_list::Builder<'a,crate::schema_capnp::annotation::Owned> {
+      crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
+    }
+    #[inline]
+    pub fn disown_annotations(&mut self) -> crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>> {
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+    }
+    #[inline]
+    pub fn adopt_annotations(&mut self, orphan: crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(1).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_annotations(&mut self, size: u32) -> crate::Result<crate::struct_list::Builder<'_,crate::schema_capnp::annotation::Owned>> {
+      if self.builder.is_pointer_field_null(1) {
+        ::core::result::Result::Ok(self.reborrow().init_annotations(size))
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(1), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_annotations(&self) -> bool {
+      !self.builder.is_pointer_field_null(1)
+    }
+    #[inline]
+    pub fn get_discriminant_value(self) -> u16 {
+      self.builder.get_data_field_mask::<u16>(1, 65535)
+    }
+    #[inline]
+    pub fn set_discriminant_value(&mut self, value: u16)  {
+      self.builder.set_data_field_mask::<u16>(1, value, 65535);
+    }
+    #[inline]
+    pub fn set_discriminant_value_from(&mut self, value: impl ::core::convert::TryInto<u16>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_discriminant_value(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn init_slot(mut self, ) -> crate::schema_capnp::field::slot::Builder<'a> {
+      self.builder.set_data_field::<u16>(4, 0);
+      self.builder.set_data_field::<u32>(1, 0u32);
+      self.builder.reborrow().get_pointer_field(2).clear();
+      self.builder.reborrow().get_pointer_field(3).clear();
+      self.builder.set_bool_field(128, false);
+      self.builder.into()
+    }
+    #[inline]
+    pub fn is_slot(&self) -> bool {
+      self.builder.get_data_field::<u16>(4) == 0
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_slot(&self) -> bool {
+      if self.builder.get_data_field::<u16>(4) != 0 { return false; }
+      self.builder.get_data_field::<u32>(1) != 0
+        || !self.builder.is_pointer_field_null(2)
+        || !self.builder.is_pointer_field_null(3)
+        || self.builder.get_bool_field(128)
+    }
+    #[inline]
+    pub fn init_group(self, ) -> crate::schema_capnp::field::group::Builder<'a> {
+      self.builder.set_data_field::<u16>(4, 1);
+      self.builder.set_data_field::<u64>(2, 0u64);
+      self.builder.into()
+    }
+    #[inline]
+    pub fn is_group(&self) -> bool {
+      self.builder.get_data_field::<u16>(4) == 1
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_group(&self) -> bool {
+      if self.builder.get_data_field::<u16>(4) != 1 { return false; }
+      self.builder.get_data_field::<u64>(2) != 0
+    }
+    #[inline]
+    pub fn get_ordinal(self) -> crate::schema_capnp::field::ordinal::Builder<'a> {
+      self.builder.into()
+    }
+    #[inline]
+    pub fn init_ordinal(self, ) -> crate::schema_capnp::field::ordinal::Builder<'a> {
+      self.builder.set_data_field::<u16>(5, 0);
+      self.builder.set_data_field::<u16>(6, 0u16);
+      self.builder.into()
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_ordinal(&self) -> bool {
+      self.builder.get_data_field::<u16>(5) != 0
+        || self.builder.get_data_field::<u16>(6) != 0
+    }
+    #[inline]
+    pub fn which(self) -> ::core::result::Result<WhichBuilder<'a,>, crate::NotInSchema> {
+      match self.builder.get_data_field::<u16>(4) {
+        0 => {
+          ::core::result::Result::Ok(Which::Slot(
+            self.builder.into()
+          ))
+        }
+        1 => {
+          ::core::result::Result::Ok(Which::Group(
+            self.builder.into()
+          ))
+        }
+        x => ::core::result::Result::Err(crate::NotInSchema(x))
+      }
+    }
+    /// Sets the union to the variant that is active in `other`, copied from there as stored.
+    /// The fields outside the union are left unchanged.
+    ///
+    /// Returns an `EnumValueOrUnionDiscriminantNotPresent` error without changing anything if
+    /// `other` has a variant that is not in the schema, for example one added by a newer version
+    /// of it. A union nested in a group of the active variant is handled the same way, but by
+    /// then the group has been partially copied.
+    pub fn copy_union_from(&mut self, other: Reader<'_,>) -> crate::Result<()> {
+      match other.reader.get_data_field::<u16>(4) {
+        0 => {
+          self.reborrow().init_slot();
+          self.builder.set_data_field::<u32>(1, other.reader.get_data_field::<u32>(1));
+          crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(2), crate::any_pointer::Reader::new(other.reader.get_pointer_field(2)), false)?;
+          crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(3), crate::any_pointer::Reader::new(other.reader.get_pointer_field(3)), false)?;
+          self.builder.set_bool_field(128, other.reader.get_bool_field(128));
+        }
+        1 => {
+          self.reborrow().init_group();
+          self.builder.set_data_field::<u64>(2, other.reader.get_data_field::<u64>(2));
+        }
+        x => return ::core::result::Result::Err(crate::NotInSchema(x).into()),
+      }
+      ::core::result::Result::Ok(())
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+  impl crate::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+    #[inline]
+    pub fn get_ordinal(&self) -> crate::schema_capnp::field::ordinal::Pipeline {
+      crate::capability::FromTypelessPipeline::new(self._typeless.noop())
+    }
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+    let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [crate::Word; 113] = [
+      crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+      crate::word(95, 244, 74, 31, 164, 80, 173, 154),
+      crate::word(13, 0, 0, 0, 1, 0, 3, 0),
+      crate::word(217, 114, 76, 98, 9, 197, 63, 169),
+      crate::word(4, 0, 7, 0, 0, 0, 2, 0),
+      crate::word(4, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(21, 0, 0, 0, 154, 0, 0, 0),
+      crate::word(29, 0, 0, 0, 23, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(41, 0, 0, 0, 143, 1, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+      crate::word(97, 112, 110, 112, 58, 70, 105, 101),
+      crate::word(108, 100, 0, 0, 0, 0, 0, 0),
+      crate::word(4, 0, 0, 0, 1, 0, 1, 0),
+      crate::word(18, 199, 254, 124, 190, 76, 177, 151),
+      crate::word(1, 0, 0, 0, 122, 0, 0, 0),
+      crate::word(110, 111, 68, 105, 115, 99, 114, 105),
+      crate::word(109, 105, 110, 97, 110, 116, 0, 0),
+      crate::word(28, 0, 0, 0, 3, 0, 4, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(181, 0, 0, 0, 42, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(176, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(188, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 1, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(185, 0, 0, 0, 82, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(184, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(196, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(2, 0, 0, 0, 1, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 2, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(193, 0, 0, 0, 98, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(192, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(220, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(3, 0, 0, 0, 1, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 3, 0, 0, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(217, 0, 0, 0, 146, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(220, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(232, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(4, 0, 255, 255, 0, 0, 0, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(111, 116, 180, 107, 71, 5, 35, 196),
+      crate::word(229, 0, 0, 0, 42, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(5, 0, 254, 255, 0, 0, 0, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(17, 29, 219, 104, 219, 205, 252, 202),
+      crate::word(205, 0, 0, 0, 50, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(6, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(230, 11, 135, 135, 194, 213, 144, 187),
+      crate::word(181, 0, 0, 0, 66, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(110, 97, 109, 101, 0, 0, 0, 0),
+      crate::word(12, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(12, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(99, 111, 100, 101, 79, 114, 100, 101),
+      crate::word(114, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(97, 110, 110, 111, 116, 97, 116, 105),
+      crate::word(111, 110, 115, 0, 0, 0, 0, 0),
+      crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(66, 117, 37, 171, 13, 149, 200, 241),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(100, 105, 115, 99, 114, 105, 109, 105),
+      crate::word(110, 97, 110, 116, 86, 97, 108, 117),
+      crate::word(101, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(7, 0, 255, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(115, 108, 111, 116, 0, 0, 0, 0),
+      crate::word(103, 114, 111, 117, 112, 0, 0, 0),
+      crate::word(111, 114, 100, 105, 110, 97, 108, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "schema.capnp:Field";
+    pub fn get_field_types(index: u16) -> crate::introspect::Type {
+      match index {
+        0 => <crate::text::Owned as crate::introspect::Introspect>::introspect(),
+        1 => <u16 as crate::introspect::Introspect>::introspect(),
+        2 => <crate::struct_list::Owned<crate::schema_capnp::annotation::Owned> as crate::introspect::Introspect>::introspect(),
+        3 => <u16 as crate::introspect::Introspect>::introspect(),
+        4 => <crate::schema_capnp::field::slot::Owned as crate::introspect::Introspect>::introspect(),
+        5 => <crate::schema_capnp::field::group::Owned as crate::introspect::Introspect>::introspect(),
+        6 => <crate::schema_capnp::field::ordinal::Owned as crate::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1,2,3,6];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[4,5];
+    pub mod layout {
+      pub const DISCRIMINANT_OFFSET: usize = 8;
+      pub const FIELD_NAME_POINTER_INDEX: usize = 0;
+      pub const FIELD_CODE_ORDER_OFFSET: usize = 0;
+      pub const FIELD_ANNOTATIONS_POINTER_INDEX: usize = 1;
+      pub const FIELD_DISCRIMINANT_VALUE_OFFSET: usize = 2;
+      pub const FIELD_SLOT_DISCRIMINANT: u16 = 0;
+      pub const FIELD_GROUP_DISCRIMINANT: u16 = 1;
+    }
+    pub const TYPE_ID: u64 = 0x9aad_50a4_1f4a_f45f;
+  }
+  pub enum Which<A0,A1> {
+    Slot(A0),
+    Group(A1),
+  }
+  pub type WhichReader<'a,> = Which<crate::schema_capnp::field::slot::Reader<'a>,crate::schema_capnp::field::group::Reader<'a>>;
+  pub type WhichBuilder<'a,> = Which<crate::schema_capnp::field::slot::Builder<'a>,crate::schema_capnp::field::group::Builder<'a>>;
+  pub const NO_DISCRIMINANT: u16 = 65535;
+
+  pub mod slot {
+  pub struct Slot {
+ pub _offset: u32,
+ pub _type: Option<crate::schema_capnp::type_::Type>,
+ pub _default_value: Option<crate::schema_capnp::value::Value>,
+ pub _had_explicit_default: bool,  
+}
+  impl Slot {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+  builder.set_offset(self._offset);
+  if let Some(st) = self._type {st.build_capnp_struct(builder.reborrow().init_type())?;}
+  if let Some(st) = self._default_value {st.build_capnp_struct(builder.reborrow().init_default_value())?;}
+  builder.set_had_explicit_default(self._had_explicit_default);
+  ::core::result::Result::Ok(())
+}}
+  
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Slot  {
+    type Error = crate::Error;
+    fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(Self {
+        _offset: reader.get_offset(),
+        _type: if reader.has_type() { ::core::option::Option::Some(<crate::schema_capnp::type_::Type as ::core::convert::TryFrom<_>>::try_from(reader.get_type()?)?) } else { ::core::option::Option::None },
+        _default_value: if reader.has_default_value() { ::core::option::Option::Some(<crate::schema_capnp::value::Value as ::core::convert::TryFrom<_>>::try_from(reader.get_default_value()?)?) } else { ::core::option::Option::None },
+        _had_explicit_default: reader.get_had_explicit_default(),
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Slot {
+    type Error = crate::Error;
+    fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl Slot {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+      pub type_: usize,
+      pub default_value: usize,
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+          type_: crate::schema_capnp::type_::MIN_SIZE_WORDS - 1,
+          default_value: crate::schema_capnp::value::MIN_SIZE_WORDS - 1,
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(counts: FieldCounts) -> usize {
+      counts.type_ + counts.default_value
+    }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+        self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn get_offset(self) -> u32 {
+        self.reader.get_data_field::<u32>(1)
+      }
+      #[inline]
+      pub fn get_type(self) -> crate::Result<crate::schema_capnp::type_::Reader<'a>> {
+        crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(2), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn has_type(&self) -> bool {
+        !self.reader.get_pointer_field(2).is_null()
+      }
+      #[inline]
+      pub fn get_default_value(self) -> crate::Result<crate::schema_capnp::value::Reader<'a>> {
+        crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(3), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn has_default_value(&self) -> bool {
+        !self.reader.get_pointer_field(3).is_null()
+      }
+      #[inline]
+      pub fn get_had_explicit_default(self) -> bool {
+        self.reader.get_bool_field(128)
+      }
+    }
+
+    pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+    impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 3, pointers: 4 };
+    }
+    impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+        self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn get_offset(self) -> u32 {
+        self.builder.get_data_field::<u32>(1)
+      }
+      #[inline]
+      pub fn set_offset(&mut self, value: u32)  {
+        self.builder.set_data_field::<u32>(1, value);
+      }
+      #[inline]
+      pub fn set_offset_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> crate::Result<()> {
+        let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+        self.set_offset(value);
+        ::core::result::Result::Ok(())
+      }
+      #[inline]
+      pub fn get_type(self) -> crate::Result<crate::schema_capnp::type_::Builder<'a>> {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(2), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn set_type(&mut self, value: crate::schema_capnp::type_::Reader<'_>) -> crate::Result<()> {
+        crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(2), value, false)
+      }
+      #[inline]
+      pub fn init_type(self, ) -> crate::schema_capnp::type_::Builder<'a> {
+        crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), 0)
+      }
+      #[inline]
+      pub fn disown_type(&mut self) -> crate::Orphan<crate::schema_capnp::type_::Owned> {
+        crate::Orphan::new(self.builder.reborrow().get_pointer_field(2).disown())
+      }
+      #[inline]
+      pub fn adopt_type(&mut self, orphan: crate::Orphan<crate::schema_capnp::type_::Owned>) -> crate::Result<()> {
+        self.builder.reborrow().get_pointer_field(2).adopt(orphan.into_internal())
+      }
+      #[inline]
+      pub fn get_or_init_type(&mut self, ) -> crate::Result<crate::schema_capnp::type_::Builder<'_>> {
+        if self.builder.is_pointer_field_null(2) {
+          ::core::result::Result::Ok(self.reborrow().init_type())
+        } else {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(2), ::core::option::Option::None)
+        }
+      }
+      #[inline]
+      pub fn has_type(&self) -> bool {
+        !self.builder.is_pointer_field_null(2)
+      }
+      #[inline]
+      pub fn get_default_value(self) -> crate::Result<crate::schema_capnp::value::Builder<'a>> {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(3), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn set_default_value(&mut self, value: crate::schema_capnp::value::Reader<'_>) -> crate::Result<()> {
+        crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(3), value, false)
+      }
+      #[inline]
+      pub fn init_default_value(self, ) -> crate::schema_capnp::value::Builder<'a> {
+        crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), 0)
+      }
+      #[inline]
+      pub fn disown_default_value(&mut self) -> crate::Orphan<crate::schema_capnp::value::Owned> {
+        crate::Orphan::new(self.builder.reborrow().get_pointer_field(3).disown())
+      }
+      #[inline]
+      pub fn adopt_default_value(&mut self, orphan: crate::Orphan<crate::schema_capnp::value::Owned>) -> crate::Result<()> {
+        self.builder.reborrow().get_pointer_field(3).adopt(orphan.into_internal())
+      }
+      #[inline]
+      pub fn get_or_init_default_value(&mut self, ) -> crate::Result<crate::schema_capnp::value::Builder<'_>> {
+        if self.builder.is_pointer_field_null(3) {
+          ::core::result::Result::Ok(self.reborrow().init_default_value())
+        } else {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(3), ::core::option::Option::None)
+        }
+      }
+      #[inline]
+      pub fn has_default_value(&self) -> bool {
+        !self.builder.is_pointer_field_null(3)
+      }
+      #[inline]
+      pub fn get_had_explicit_default(self) -> bool {
+        self.builder.get_bool_field(128)
+      }
+      #[inline]
+      pub fn set_had_explicit_default(&mut self, value: bool)  {
+        self.builder.set_bool_field(128, value);
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+    impl crate::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+      #[inline]
+      pub fn get_type(&self) -> crate::schema_capnp::type_::Pipeline {
+        crate::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(2))
+      }
+      #[inline]
+      pub fn get_default_value(&self) -> crate::schema_capnp::value::Pipeline {
+        crate::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(3))
+      }
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+      let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [crate::Word; 79] = [
+        crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+        crate::word(111, 116, 180, 107, 71, 5, 35, 196),
+        crate::word(19, 0, 0, 0, 1, 0, 3, 0),
+        crate::word(95, 244, 74, 31, 164, 80, 173, 154),
+        crate::word(4, 0, 7, 0, 1, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 194, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 231, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+        crate::word(97, 112, 110, 112, 58, 70, 105, 101),
+        crate::word(108, 100, 46, 115, 108, 111, 116, 0),
+        crate::word(16, 0, 0, 0, 3, 0, 4, 0),
+        crate::word(0, 0, 0, 0, 1, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 4, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(97, 0, 0, 0, 58, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(92, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(104, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(1, 0, 0, 0, 2, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 5, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(101, 0, 0, 0, 42, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(96, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(108, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(2, 0, 0, 0, 3, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 6, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(105, 0, 0, 0, 106, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(104, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(116, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(3, 0, 0, 0, 128, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 10, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(113, 0, 0, 0, 154, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(116, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(128, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(111, 102, 102, 115, 101, 116, 0, 0),
+        crate::word(8, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(8, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(116, 121, 112, 101, 0, 0, 0, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(96, 204, 249, 225, 237, 120, 115, 208),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(100, 101, 102, 97, 117, 108, 116, 86),
+        crate::word(97, 108, 117, 101, 0, 0, 0, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(155, 12, 176, 215, 210, 220, 35, 206),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(104, 97, 100, 69, 120, 112, 108, 105),
+        crate::word(99, 105, 116, 68, 101, 102, 97, 117),
+        crate::word(108, 116, 0, 0, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "schema.capnp:Field.slot";
+      pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        match index {
+          0 => <u32 as crate::introspect::Introspect>::introspect(),
+          1 => <crate::schema_capnp::type_::Owned as crate::introspect::Introspect>::introspect(),
+          2 => <crate::schema_capnp::value::Owned as crate::introspect::Introspect>::introspect(),
+          3 => <bool as crate::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[0,1,2,3];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+      pub mod layout {
+        pub const FIELD_OFFSET_OFFSET: usize = 4;
+        pub const FIELD_TYPE_POINTER_INDEX: usize = 2;
+        pub const FIELD_DEFAULT_VALUE_POINTER_INDEX: usize = 3;
+        pub const FIELD_HAD_EXPLICIT_DEFAULT_BIT_OFFSET: usize = 128;
+      }
+      pub const TYPE_ID: u64 = 0xc423_0547_6bb4_746f;
+    }
+  }
+
+  pub mod group {
+  pub struct Group {
+ pub _type_id: u64,  
+}
+  impl Group {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+  builder.set_type_id(self._type_id);
+  ::core::result::Result::Ok(())
+}}
+  
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Group  {
+    type Error = crate::Error;
+    fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(Self {
+        _type_id: reader.get_type_id(),
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Group {
+    type Error = crate::Error;
+    fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl Group {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(_counts: FieldCounts) -> usize { 0 }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+        self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn get_type_id(self) -> u64 {
+        self.reader.get_data_field::<u64>(2)
+      }
+    }
+
+    pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+    impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 3, pointers: 4 };
+    }
+    impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+        self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn get_type_id(self) -> u64 {
+        self.builder.get_data_field::<u64>(2)
+      }
+      #[inline]
+      pub fn set_type_id(&mut self, value: u64)  {
+        self.builder.set_data_field::<u64>(2, value);
+      }
+      #[inline]
+      pub fn set_type_id_from(&mut self, value: impl ::core::convert::TryInto<u64>) -> crate::Result<()> {
+        let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+        self.set_type_id(value);
+        ::core::result::Result::Ok(())
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+    impl crate::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+      let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [crate::Word; 32] = [
+        crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+        crate::word(17, 29, 219, 104, 219, 205, 252, 202),
+        crate::word(19, 0, 0, 0, 1, 0, 3, 0),
+        crate::word(95, 244, 74, 31, 164, 80, 173, 154),
+        crate::word(4, 0, 7, 0, 1, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 202, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(25, 0, 0, 0, 63, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+        crate::word(97, 112, 110, 112, 58, 70, 105, 101),
+        crate::word(108, 100, 46, 103, 114, 111, 117, 112),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(4, 0, 0, 0, 3, 0, 4, 0),
+        crate::word(0, 0, 0, 0, 2, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 7, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(13, 0, 0, 0, 58, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(8, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(20, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(116, 121, 112, 101, 73, 100, 0, 0),
+        crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "schema.capnp:Field.group";
+      pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        match index {
+          0 => <u64 as crate::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[0];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+      pub mod layout {
+        pub const FIELD_TYPE_ID_OFFSET: usize = 16;
+      }
+      pub const TYPE_ID: u64 = 0xcafc_cddb_68db_1d11;
+    }
+  }
+
+  pub mod ordinal {
+  
+  impl Ordinal {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+ match self {
+ Ordinal::UNINITIALIZED => (),
+ Ordinal::_Implicit(t) => builder.reborrow().set_implicit(t),
+ Ordinal::_Explicit(t) => builder.reborrow().set_explicit(t),
+ }
+  ::core::result::Result::Ok(())
+}}
+  pub enum Ordinal {
+ UNINITIALIZED,
+ _Implicit(()),
+ _Explicit(u16),
+}
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Ordinal  {
+    type Error = crate::Error;
+    fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(match reader.which()? {
+        Which::Implicit(value) => Ordinal::_Implicit(value),
+        Which::Explicit(value) => Ordinal::_Explicit(value),
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Ordinal {
+    type Error = crate::Error;
+    fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl Ordinal {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    pub use self::Which::{Implicit,Explicit};
+
+    /// The members of the union, as `(discriminant, name, field index)` in discriminant order.
+    pub static UNION_MEMBERS: &[(u16, &str, u16)] = &[(0, "implicit", 0), (1, "explicit", 1)];
+    /// Returns the schema name of the union member with discriminant `d`.
+    pub fn discriminant_name(d: u16) -> ::core::option::Option<&'static str> {
+      UNION_MEMBERS.get(d as usize).map(|member| member.1)
+    }
+
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(_counts: FieldCounts) -> usize { 0 }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+        self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn is_implicit(&self) -> bool {
+        self.reader.get_data_field::<u16>(5) == 0
+      }
+      #[inline]
+      pub fn is_explicit(&self) -> bool {
+        self.reader.get_data_field::<u16>(5) == 1
+      }
+      #[inline]
+      pub fn which(self) -> ::core::result::Result<WhichReader, crate::NotInSchema> {
+        match self.reader.get_data_field::<u16>(5) {
+          0 => {
+            ::core::result::Result::Ok(Which::Implicit(
+              ()
+            ))
+          }
+          1 => {
+            ::core::result::Result::Ok(Which::Explicit(
+              self.reader.get_data_field::<u16>(6)
+            ))
+          }
+          x => ::core::result::Result::Err(crate::NotInSchema(x))
+        }
+      }
+    }
+
+    pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+    impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 3, pointers: 4 };
+    }
+    impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+        self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn set_implicit(&mut self, _value: ())  {
+        self.builder.set_data_field::<u16>(5, 0);
+      }
+      #[inline]
+      pub fn is_implicit(&self) -> bool {
+        self.builder.get_data_field::<u16>(5) == 0
+      }
+      #[inline]
+      pub fn set_explicit(&mut self, value: u16)  {
+        self.builder.set_data_field::<u16>(5, 1);
+        self.builder.set_data_field::<u16>(6, value);
+      }
+      #[inline]
+      pub fn set_explicit_from(&mut self, value: impl ::core::convert::TryInto<u16>) -> crate::Result<()> {
+        let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+        self.set_explicit(value);
+        ::core::result::Result::Ok(())
+      }
+      #[inline]
+      pub fn is_explicit(&self) -> bool {
+        self.builder.get_data_field::<u16>(5) == 1
+      }
+      #[inline]
+      pub fn which(self) -> ::core::result::Result<WhichBuilder, crate::NotInSchema> {
+        match self.builder.get_data_field::<u16>(5) {
+          0 => {
+            ::core::result::Result::Ok(Which::Implicit(
+              ()
+            ))
+          }
+          1 => {
+            ::core::result::Result::Ok(Which::Explicit(
+              self.builder.get_data_field::<u16>(6)
+            ))
+          }
+          x => ::core::result::Result::Err(crate::NotInSchema(x))
+        }
+      }
+      /// Sets the union to the variant that is active in `other`, copied from there as stored.
+      /// The fields outside the union are left unchanged.
+      ///
+      /// Returns an `EnumValueOrUnionDiscriminantNotPresent` error without changing anything if
+      /// `other` has a variant that is not in the schema, for example one added by a newer version
+      /// of it. A union nested in a group of the active variant is handled the same way, but by
+      /// then the group has been partially copied.
+      pub fn copy_union_from(&mut self, other: Reader<'_,>) -> crate::Result<()> {
+        match other.reader.get_data_field::<u16>(5) {
+          0 => {
+            self.builder.set_data_field::<u16>(5, 0);
+          }
+          1 => {
+            self.builder.set_data_field::<u16>(5, 1);
+            self.builder.set_data_field::<u16>(6, other.reader.get_data_field::<u16>(6));
+          }
+          x => return ::core::result::Result::Err(crate::NotInSchema(x).into()),
+        }
+        ::core::result::Result::Ok(())
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+    impl crate::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+      let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [crate::Word; 49] = [
+        crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+        crate::word(230, 11, 135, 135, 194, 213, 144, 187),
+        crate::word(19, 0, 0, 0, 1, 0, 3, 0),
+        crate::word(95, 244, 74, 31, 164, 80, 173, 154),
+        crate::word(4, 0, 7, 0, 1, 0, 2, 0),
+        crate::word(5, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 218, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(25, 0, 0, 0, 119, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+        crate::word(97, 112, 110, 112, 58, 70, 105, 101),
+        crate::word(108, 100, 46, 111, 114, 100, 105, 110),
+        crate::word(97, 108, 0, 0, 0, 0, 0, 0),
+        crate::word(8, 0, 0, 0, 3, 0, 4, 0),
+        crate::word(0, 0, 255, 255, 0, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 8, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(41, 0, 0, 0, 74, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(40, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(52, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(1, 0, 254, 255, 6, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 9, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(49, 0, 0, 0, 74, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(48, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(60, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(105, 109, 112, 108, 105, 99, 105, 116),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(101, 120, 112, 108, 105, 99, 105, 116),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "schema.capnp:Field.ordinal";
+      pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        match index {
+          0 => <() as crate::introspect::Introspect>::introspect(),
+          1 => <u16 as crate::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[0,1];
+      pub mod layout {
+        pub const DISCRIMINANT_OFFSET: usize = 10;
+        pub const FIELD_IMPLICIT_DISCRIMINANT: u16 = 0;
+        pub const FIELD_EXPLICIT_DISCRIMINANT: u16 = 1;
+        pub const FIELD_EXPLICIT_OFFSET: usize = 12;
+      }
+      pub const TYPE_ID: u64 = 0xbb90_d5c2_8787_0be6;
+    }
+    pub enum Which {
+      Implicit(()),
+      Explicit(u16),
+    }
+    pub type WhichReader = Which;
+    pub type WhichBuilder = Which;
+  }
+}
+
+pub mod enumerant {
+pub struct Enumerant {
+ pub _name: String,
+ pub _code_order: u16,
+ pub _annotations: Vec<crate::schema_capnp::annotation::Annotation>,  
+}
+impl Enumerant {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+  builder.set_name(self._name.as_str().into());
+  builder.set_code_order(self._code_order);
+            
+if self._annotations.len() > 0 {
+                let mut list_builder = builder.reborrow().init_annotations(crate::private::checked_len(self._annotations.len())?);
+                for (i, item) in self._annotations.into_iter().enumerate() {
+                    item.build_capnp_struct(list_builder.reborrow().get(i as u32))?;
+                }
+            }
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Enumerant  {
+  type Error = crate::Error;
+  fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _name: reader.get_name()?.to_string()?,
+      _code_order: reader.get_code_order(),
+      _annotations: reader.get_annotations()?.iter().map(|item| crate::Result::Ok(<crate::schema_capnp::annotation::Annotation as ::core::convert::TryFrom<_>>::try_from(item)?)).collect::<crate::Result<Vec<_>>>()?,
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Enumerant {
+  type Error = crate::Error;
+  fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl Enumerant {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub name: usize,
+    pub annotations: usize,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        name: 0,
+        annotations: 0,
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    (counts.name + 8) / 8 + 1 + counts.annotations * (crate::schema_capnp::annotation::MIN_SIZE_WORDS - 1)
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 1 + 2 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+      self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> crate::Result<usize> {
+      self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_name(self) -> crate::Result<crate::text::Reader<'a>> {
+      crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_name(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_code_order(self) -> u16 {
+      self.reader.get_data_field::<u16>(0)
+    }
+    #[inline]
+    pub fn get_annotations(self) -> crate::Result<crate::struct_list::Reader<'a,crate::schema_capnp::annotation::Owned>> {
+      crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_annotations(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
+    }
+  }
+
+  pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+  impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 1, pointers: 2 };
+  }
+  impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+      self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> crate::Result<usize> {
+      self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_name(self) -> crate::Result<crate::text::Builder<'a>> {
+      crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_name(&mut self, value: crate::text::Reader<'_>)  {
+      self.builder.reborrow().get_pointer_field(0).set_text(value);
+    }
+    #[inline]
+    pub fn init_name(self, size: u32) -> crate::text::Builder<'a> {
+      self.builder.get_pointer_field(0).init_text(size)
+    }
+    #[inline]
+    pub fn disown_name(&mut self) -> crate::Orphan<crate::text::Owned> {
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_name(&mut self, orphan: crate::Orphan<crate::text::Owned>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_name(&mut self, size: u32) -> crate::Result<crate::text::Builder<'_>> {
+      if self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_name(size))
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_name(&self) -> bool {
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn get_code_order(self) -> u16 {
+      self.builder.get_data_field::<u16>(0)
+    }
+    #[inline]
+    pub fn set_code_order(&mut self, value: u16)  {
+      self.builder.set_data_field::<u16>(0, value);
+    }
+    #[inline]
+    pub fn set_code_order_from(&mut self, value: impl ::core::convert::TryInto<u16>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_code_order(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_annotations(self) -> crate::Result<crate::struct_list::Builder<'a,crate::schema_capnp::annotation::Owned>> {
+      crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_annotations(&mut self, value: crate::struct_list::Reader<'a,crate::schema_capnp::annotation::Owned>) -> crate::Result<()> {
+      crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(1), value, false)
+    }
+    #[inline]
+    pub fn init_annotations(self, size: u32) -> crate::struct_list::Builder<'a,crate::schema_capnp::annotation::Owned> {
+      crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
+    }
+    #[inline]
+    pub fn disown_annotations(&mut self) -> crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>> {
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+    }
+    #[inline]
+    pub fn adopt_annotations(&mut self, orphan: crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(1).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_annotations(&mut self, size: u32) -> crate::Result<crate::struct_list::Builder<'_,crate::schema_capnp::annotation::Owned>> {
+      if self.builder.is_pointer_field_null(1) {
+        ::core::result::Result::Ok(self.reborrow().init_annotations(size))
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(1), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_annotations(&self) -> bool {
+      !self.builder.is_pointer_field_null(1)
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+  impl crate::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+    let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [crate::Word; 68] = [
+      crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+      crate::word(77, 154, 84, 220, 235, 124, 138, 151),
+      crate::word(13, 0, 0, 0, 1, 0, 1, 0),
+      crate::word(217, 114, 76, 98, 9, 197, 63, 169),
+      crate::word(2, 0, 7, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(21, 0, 0, 0, 186, 0, 0, 0),
+      crate::word(29, 0, 0, 0, 7, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(25, 0, 0, 0, 175, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+      crate::word(97, 112, 110, 112, 58, 69, 110, 117),
+      crate::word(109, 101, 114, 97, 110, 116, 0, 0),
+      crate::word(0, 0, 0, 0, 1, 0, 1, 0),
+      crate::word(12, 0, 0, 0, 3, 0, 4, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(69, 0, 0, 0, 42, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(64, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(76, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 1, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(73, 0, 0, 0, 82, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(72, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(84, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(2, 0, 0, 0, 1, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 2, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(81, 0, 0, 0, 98, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(80, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(108, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(110, 97, 109, 101, 0, 0, 0, 0),
+      crate::word(12, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(12, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(99, 111, 100, 101, 79, 114, 100, 101),
+      crate::word(114, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(97, 110, 110, 111, 116, 97, 116, 105),
+      crate::word(111, 110, 115, 0, 0, 0, 0, 0),
+      crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(66, 117, 37, 171, 13, 149, 200, 241),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "schema.capnp:Enumerant";
+    pub fn get_field_types(index: u16) -> crate::introspect::Type {
+      match index {
+        0 => <crate::text::Owned as crate::introspect::Introspect>::introspect(),
+        1 => <u16 as crate::introspect::Introspect>::introspect(),
+        2 => <crate::struct_list::Owned<crate::schema_capnp::annotation::Owned> as crate::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1,2];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_NAME_POINTER_INDEX: usize = 0;
+      pub const FIELD_CODE_ORDER_OFFSET: usize = 0;
+      pub const FIELD_ANNOTATIONS_POINTER_INDEX: usize = 1;
+    }
+    pub const TYPE_ID: u64 = 0x978a_7ceb_dc54_9a4d;
+  }
+}
+
+pub mod superclass {
+pub struct Superclass {
+ pub _id: u64,
+ pub _brand: Option<crate::schema_capnp::brand::Brand>,  
+}
+impl Superclass {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+  builder.set_id(self._id);
+  if let Some(st) = self._brand {st.build_capnp_struct(builder.reborrow().init_brand())?;}
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Superclass  {
+  type Error = crate::Error;
+  fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _id: reader.get_id(),
+      _brand: if reader.has_brand() { ::core::option::Option::Some(<crate::schema_capnp::brand::Brand as ::core::convert::TryFrom<_>>::try_from(reader.get_brand()?)?) } else { ::core::option::Option::None },
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Superclass {
+  type Error = crate::Error;
+  fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl Superclass {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub brand: usize,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        brand: crate::schema_capnp::brand::MIN_SIZE_WORDS - 1,
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    counts.brand
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 1 + 1 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+      self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> crate::Result<usize> {
+      self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_id(self) -> u64 {
+      self.reader.get_data_field::<u64>(0)
+    }
+    #[inline]
+    pub fn get_brand(self) -> crate::Result<crate::schema_capnp::brand::Reader<'a>> {
+      crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_brand(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+  }
+
+  pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+  impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 1, pointers: 1 };
+  }
+  impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+      self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> crate::Result<usize> {
+      self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_id(self) -> u64 {
+      self.builder.get_data_field::<u64>(0)
+    }
+    #[inline]
+    pub fn set_id(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(0, value);
+    }
+    #[inline]
+    pub fn set_id_from(&mut self, value: impl ::core::convert::TryInto<u64>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_id(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_brand(self) -> crate::Result<crate::schema_capnp::brand::Builder<'a>> {
+      crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_brand(&mut self, value: crate::schema_capnp::brand::Reader<'_>) -> crate::Result<()> {
+      crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_brand(self, ) -> crate::schema_capnp::brand::Builder<'a> {
+      crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_brand(&mut self) -> crate::Orphan<crate::schema_capnp::brand::Owned> {
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_brand(&mut self, orphan: crate::Orphan<crate::schema_capnp::brand::Owned>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_brand(&mut self, ) -> crate::Result<crate::schema_capnp::brand::Builder<'_>> {
+      if self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_brand())
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_brand(&self) -> bool {
+      !self.builder.is_pointer_field_null(0)
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+  impl crate::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+    #[inline]
+    pub fn get_brand(&self) -> crate::schema_capnp::brand::Pipeline {
+      crate::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+    }
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+    let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [crate::Word; 47] = [
+      crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+      crate::word(248, 215, 164, 208, 158, 42, 150, 169),
+      crate::word(13, 0, 0, 0, 1, 0, 1, 0),
+      crate::word(217, 114, 76, 98, 9, 197, 63, 169),
+      crate::word(1, 0, 7, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(21, 0, 0, 0, 194, 0, 0, 0),
+      crate::word(29, 0, 0, 0, 7, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(25, 0, 0, 0, 119, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+      crate::word(97, 112, 110, 112, 58, 83, 117, 112),
+      crate::word(101, 114, 99, 108, 97, 115, 115, 0),
+      crate::word(0, 0, 0, 0, 1, 0, 1, 0),
+      crate::word(8, 0, 0, 0, 3, 0, 4, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(41, 0, 0, 0, 26, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(36, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(48, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 1, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(45, 0, 0, 0, 50, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(40, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(52, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(105, 100, 0, 0, 0, 0, 0, 0),
+      crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(98, 114, 97, 110, 100, 0, 0, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(43, 66, 101, 96, 240, 85, 52, 144),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "schema.capnp:Superclass";
+    pub fn get_field_types(index: u16) -> crate::introspect::Type {
+      match index {
+        0 => <u64 as crate::introspect::Introspect>::introspect(),
+        1 => <crate::schema_capnp::brand::Owned as crate::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_ID_OFFSET: usize = 0;
+      pub const FIELD_BRAND_POINTER_INDEX: usize = 0;
+    }
+    pub const TYPE_ID: u64 = 0xa996_2a9e_d0a4_d7f8;
+  }
+}
+
+pub mod method {
+pub struct Method {
+ pub _name: String,
+ pub _code_order: u16,
+ pub _param_struct_type: u64,
+ pub _result_struct_type: u64,
+ pub _annotations: Vec<crate::schema_capnp::annotation::Annotation>,
+ pub _param_brand: Option<crate::schema_capnp::brand::Brand>,
+ pub _result_brand: Option<crate::schema_capnp::brand::Brand>,
+ pub _implicit_parameters: Vec<crate::schema_capnp::node::parameter::Parameter>,  
+}
+impl Method {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+  builder.set_name(self._name.as_str().into());
+  builder.set_code_order(self._code_order);
+  builder.set_param_struct_type(self._param_struct_type);
+  builder.set_result_struct_type(self._result_struct_type);
+            
+if self._annotations.len() > 0 {
+                let mut list_builder = builder.reborrow().init_annotations(crate::private::checked_len(self._annotations.len())?);
+                for (i, item) in self._annotations.into_iter().enumerate() {
+                    item.build_capnp_struct(list_builder.reborrow().get(i as u32))?;
+                }
+            }
+  if let Some(st) = self._param_brand {st.build_capnp_struct(builder.reborrow().init_param_brand())?;}
+  if let Some(st) = self._result_brand {st.build_capnp_struct(builder.reborrow().init_result_brand())?;}
+            
+if self._implicit_parameters.len() > 0 {
+                let mut list_builder = builder.reborrow().init_implicit_parameters(crate::private::checked_len(self._implicit_parameters.len())?);
+                for (i, item) in self._implicit_parameters.into_iter().enumerate() {
+                    item.build_capnp_struct(list_builder.reborrow().get(i as u32))?;
+                }
+            }
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Method  {
+  type Error = crate::Error;
+  fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _name: reader.get_name()?.to_string()?,
+      _code_order: reader.get_code_order(),
+      _param_struct_type: reader.get_param_struct_type(),
+      _result_struct_type: reader.get_result_struct_type(),
+      _annotations: reader.get_annotations()?.iter().map(|item| crate::Result::Ok(<crate::schema_capnp::annotation::Annotation as ::core::convert::TryFrom<_>>::try_from(item)?)).collect::<crate::Result<Vec<_>>>()?,
+      _param_brand: if reader.has_param_brand() { ::core::option::Option::Some(<crate::schema_capnp::brand::Brand as ::core::convert::TryFrom<_>>::try_from(reader.get_param_brand()?)?) } else { ::core::option::Option::None },
+      _result_brand: if reader.has_result_brand() { ::core::option::Option::Some(<crate::schema_capnp::brand::Brand as ::core::convert::TryFrom<_>>::try_from(reader.get_result_brand()?)?) } else { ::core::option::Option::None },
+      _implicit_parameters: reader.get_implicit_parameters()?.iter().map(|item| crate::Result::Ok(<crate::schema_capnp::node::parameter::Parameter as ::core::convert::TryFrom<_>>::try_from(item)?)).collect::<crate::Result<Vec<_>>>()?,
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Method {
+  type Error = crate::Error;
+  fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl Method {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub name: usize,
+    pub annotations: usize,
+    pub param_brand: usize,
+    pub result_brand: usize,
+    pub implicit_parameters: usize,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        name: 0,
+        annotations: 0,
+        param_brand: crate::schema_capnp::brand::MIN_SIZE_WORDS - 1,
+        result_brand: crate::schema_capnp::brand::MIN_SIZE_WORDS - 1,
+        implicit_parameters: 0,
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    (counts.name + 8) / 8 + 1 + counts.annotations * (crate::schema_capnp::annotation::MIN_SIZE_WORDS - 1) + counts.param_brand + counts.result_brand + 1 + counts.implicit_parameters * (crate::schema_capnp::node::parameter::MIN_SIZE_WORDS - 1)
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 3 + 5 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+      self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> crate::Result<usize> {
+      self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_name(self) -> crate::Result<crate::text::Reader<'a>> {
+      crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_name(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_code_order(self) -> u16 {
+      self.reader.get_data_field::<u16>(0)
+    }
+    #[inline]
+    pub fn get_param_struct_type(self) -> u64 {
+      self.reader.get_data_field::<u64>(1)
+    }
+    #[inline]
+    pub fn get_result_struct_type(self) -> u64 {
+      self.reader.get_data_field::<u64>(2)
+    }
+    #[inline]
+    pub fn get_annotations(self) -> crate::Result<crate::struct_list::Reader<'a,crate::schema_capnp::annotation::Owned>> {
+      crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_annotations(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
+    }
+    #[inline]
+    pub fn get_param_brand(self) -> crate::Result<crate::schema_capnp::brand::Reader<'a>> {
+      crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(2), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_param_brand(&self) -> bool {
+      !self.reader.get_pointer_field(2).is_null()
+    }
+    #[inline]
+    pub fn get_result_brand(self) -> crate::Result<crate::schema_capnp::brand::Reader<'a>> {
+      crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(3), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_result_brand(&self) -> bool {
+      !self.reader.get_pointer_field(3).is_null()
+    }
+    #[inline]
+    pub fn get_implicit_parameters(self) -> crate::Result<crate::struct_list::Reader<'a,crate::schema_capnp::node::parameter::Owned>> {
+      crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(4), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_implicit_parameters(&self) -> bool {
+      !self.reader.get_pointer_field(4).is_null()
+    }
+  }
+
+  pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+  impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 3, pointers: 5 };
+  }
+  impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+      self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> crate::Result<usize> {
+      self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_name(self) -> crate::Result<crate::text::Builder<'a>> {
+      crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_name(&mut self, value: crate::text::Reader<'_>)  {
+      self.builder.reborrow().get_pointer_field(0).set_text(value);
+    }
+    #[inline]
+    pub fn init_name(self, size: u32) -> crate::text::Builder<'a> {
+      self.builder.get_pointer_field(0).init_text(size)
+    }
+    #[inline]
+    pub fn disown_name(&mut self) -> crate::Orphan<crate::text::Owned> {
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_name(&mut self, orphan: crate::Orphan<crate::text::Owned>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_name(&mut self, size: u32) -> crate::Result<crate::text::Builder<'_>> {
+      if self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_name(size))
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_name(&self) -> bool {
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn get_code_order(self) -> u16 {
+      self.builder.get_data_field::<u16>(0)
+    }
+    #[inline]
+    pub fn set_code_order(&mut self, value: u16)  {
+      self.builder.set_data_field::<u16>(0, value);
+    }
+    #[inline]
+    pub fn set_code_order_from(&mut self, value: impl ::core::convert::TryInto<u16>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_code_order(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_param_struct_type(self) -> u64 {
+      self.builder.get_data_field::<u64>(1)
+    }
+    #[inline]
+    pub fn set_param_struct_type(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(1, value);
+    }
+    #[inline]
+    pub fn set_param_struct_type_from(&mut self, value: impl ::core::convert::TryInto<u64>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_param_struct_type(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_result_struct_type(self) -> u64 {
+      self.builder.get_data_field::<u64>(2)
+    }
+    #[inline]
+    pub fn set_result_struct_type(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(2, value);
+    }
+    #[inline]
+    pub fn set_result_struct_type_from(&mut self, value: impl ::core::convert::TryInto<u64>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_result_struct_type(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_annotations(self) -> crate::Result<crate::struct_list::Builder<'a,crate::schema_capnp::annotation::Owned>> {
+      crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_annotations(&mut self, value: crate::struct_list::Reader<'a,crate::schema_capnp::annotation::Owned>) -> crate::Result<()> {
+      crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(1), value, false)
+    }
+    #[inline]
+    pub fn init_annotations(self, size: u32) -> crate::struct_list::Builder<'a,crate::schema_capnp::annotation::Owned> {
+      crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
+    }
+    #[inline]
+    pub fn disown_annotations(&mut self) -> crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>> {
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+    }
+    #[inline]
+    pub fn adopt_annotations(&mut self, orphan: crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(1).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_annotations(&mut self, size: u32) -> crate::Result<crate::struct_list::Builder<'_,crate::schema_capnp::annotation::Owned>> {
+      if self.builder.is_pointer_field_null(1) {
+        ::core::result::Result::Ok(self.reborrow().init_annotations(size))
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(1), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_annotations(&self) -> bool {
+      !self.builder.is_pointer_field_null(1)
+    }
+    #[inline]
+    pub fn get_param_brand(self) -> crate::Result<crate::schema_capnp::brand::Builder<'a>> {
+      crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(2), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_param_brand(&mut self, value: crate::schema_capnp::brand::Reader<'_>) -> crate::Result<()> {
+      crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(2), value, false)
+    }
+    #[inline]
+    pub fn init_param_brand(self, ) -> crate::schema_capnp::brand::Builder<'a> {
+      crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), 0)
+    }
+    #[inline]
+    pub fn disown_param_brand(&mut self) -> crate::Orphan<crate::schema_capnp::brand::Owned> {
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(2).disown())
+    }
+    #[inline]
+    pub fn adopt_param_brand(&mut self, orphan: crate::Orphan<crate::schema_capnp::brand::Owned>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(2).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_param_brand(&mut self, ) -> crate::Result<crate::schema_capnp::brand::Builder<'_>> {
+      if self.builder.is_pointer_field_null(2) {
+        ::core::result::Result::Ok(self.reborrow().init_param_brand())
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(2), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_param_brand(&self) -> bool {
+      !self.builder.is_pointer_field_null(2)
+    }
+    #[inline]
+    pub fn get_result_brand(self) -> crate::Result<crate::schema_capnp::brand::Builder<'a>> {
+      crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(3), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_result_brand(&mut self, value: crate::schema_capnp::brand::Reader<'_>) -> crate::Result<()> {
+      crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(3), value, false)
+    }
+    #[inline]
+    pub fn init_result_brand(self, ) -> crate::schema_capnp::brand::Builder<'a> {
+      crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), 0)
+    }
+    #[inline]
+    pub fn disown_result_brand(&mut self) -> crate::Orphan<crate::schema_capnp::brand::Owned> {
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(3).disown())
+    }
+    #[inline]
+    pub fn adopt_result_brand(&mut self, orphan: crate::Orphan<crate::schema_capnp::brand::Owned>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(3).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_result_brand(&mut self, ) -> crate::Result<crate::schema_capnp::brand::Builder<'_>> {
+      if self.builder.is_pointer_field_null(3) {
+        ::core::result::Result::Ok(self.reborrow().init_result_brand())
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(3), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_result_brand(&self) -> bool {
+      !self.builder.is_pointer_field_null(3)
+    }
+    #[inline]
+    pub fn get_implicit_parameters(self) -> crate::Result<crate::struct_list::Builder<'a,crate::schema_capnp::node::parameter::Owned>> {
+      crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(4), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_implicit_parameters(&mut self, value: crate::struct_list::Reader<'a,crate::schema_capnp::node::parameter::Owned>) -> crate::Result<()> {
+      crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(4), value, false)
+    }
+    #[inline]
+    pub fn init_implicit_parameters(self, size: u32) -> crate::struct_list::Builder<'a,crate::schema_capnp::node::parameter::Owned> {
+      crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(4), size)
+    }
+    #[inline]
+    pub fn disown_implicit_parameters(&mut self) -> crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::node::parameter::Owned>> {
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(4).disown())
+    }
+    #[inline]
+    pub fn adopt_implicit_parameters(&mut self, orphan: crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::node::parameter::Owned>>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(4).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_implicit_parameters(&mut self, size: u32) -> crate::Result<crate::struct_list::Builder<'_,crate::schema_capnp::node::parameter::Owned>> {
+      if self.builder.is_pointer_field_null(4) {
+        ::core::result::Result::Ok(self.reborrow().init_implicit_parameters(size))
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(4), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_implicit_parameters(&self) -> bool {
+      !self.builder.is_pointer_field_null(4)
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+  impl crate::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+    #[inline]
+    pub fn get_param_brand(&self) -> crate::schema_capnp::brand::Pipeline {
+      crate::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(2))
+    }
+    #[inline]
+    pub fn get_result_brand(&self) -> crate::schema_capnp::brand::Pipeline {
+      crate::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(3))
+    }
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+    let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [crate::Word; 154] = [
+      crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+      crate::word(128, 77, 51, 59, 226, 204, 0, 149),
+      crate::word(13, 0, 0, 0, 1, 0, 3, 0),
+      crate::word(217, 114, 76, 98, 9, 197, 63, 169),
+      crate::word(5, 0, 7, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(21, 0, 0, 0, 162, 0, 0, 0),
+      crate::word(29, 0, 0, 0, 7, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(25, 0, 0, 0, 199, 1, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+      crate::word(97, 112, 110, 112, 58, 77, 101, 116),
+      crate::word(104, 111, 100, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 1, 0, 1, 0),
+      crate::word(32, 0, 0, 0, 3, 0, 4, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(209, 0, 0, 0, 42, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(204, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(216, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 1, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(213, 0, 0, 0, 82, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(212, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(224, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(3, 0, 0, 0, 1, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 2, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(221, 0, 0, 0, 130, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(220, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(232, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(5, 0, 0, 0, 2, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 3, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(229, 0, 0, 0, 138, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(232, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(244, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(7, 0, 0, 0, 1, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 4, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(241, 0, 0, 0, 98, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(240, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(12, 1, 0, 0, 2, 0, 1, 0),
+      crate::word(4, 0, 0, 0, 2, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 5, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(9, 1, 0, 0, 90, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(8, 1, 0, 0, 3, 0, 1, 0),
+      crate::word(20, 1, 0, 0, 2, 0, 1, 0),
+      crate::word(6, 0, 0, 0, 3, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 6, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(17, 1, 0, 0, 98, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(16, 1, 0, 0, 3, 0, 1, 0),
+      crate::word(28, 1, 0, 0, 2, 0, 1, 0),
+      crate::word(2, 0, 0, 0, 4, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 7, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(25, 1, 0, 0, 154, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(28, 1, 0, 0, 3, 0, 1, 0),
+      crate::word(56, 1, 0, 0, 2, 0, 1, 0),
+      crate::word(110, 97, 109, 101, 0, 0, 0, 0),
+      crate::word(12, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(12, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(99, 111, 100, 101, 79, 114, 100, 101),
+      crate::word(114, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(112, 97, 114, 97, 109, 83, 116, 114),
+      crate::word(117, 99, 116, 84, 121, 112, 101, 0),
+      crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(114, 101, 115, 117, 108, 116, 83, 116),
+      crate::word(114, 117, 99, 116, 84, 121, 112, 101),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(97, 110, 110, 111, 116, 97, 116, 105),
+      crate::word(111, 110, 115, 0, 0, 0, 0, 0),
+      crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(66, 117, 37, 171, 13, 149, 200, 241),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(112, 97, 114, 97, 109, 66, 114, 97),
+      crate::word(110, 100, 0, 0, 0, 0, 0, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(43, 66, 101, 96, 240, 85, 52, 144),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(114, 101, 115, 117, 108, 116, 66, 114),
+      crate::word(97, 110, 100, 0, 0, 0, 0, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(43, 66, 101, 96, 240, 85, 52, 144),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(105, 109, 112, 108, 105, 99, 105, 116),
+      crate::word(80, 97, 114, 97, 109, 101, 116, 101),
+      crate::word(114, 115, 0, 0, 0, 0, 0, 0),
+      crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(177, 163, 15, 241, 204, 27, 82, 185),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "schema.capnp:Method";
+    pub fn get_field_types(index: u16) -> crate::introspect::Type {
+      match index {
+        0 => <crate::text::Owned as crate::introspect::Introspect>::introspect(),
+        1 => <u16 as crate::introspect::Introspect>::introspect(),
+        2 => <u64 as crate::introspect::Introspect>::introspect(),
+        3 => <u64 as crate::introspect::Introspect>::introspect(),
+        4 => <crate::struct_list::Owned<crate::schema_capnp::annotation::Owned> as crate::introspect::Introspect>::introspect(),
+        5 => <crate::schema_capnp::brand::Owned as crate::introspect::Introspect>::introspect(),
+        6 => <crate::schema_capnp::brand::Owned as crate::introspect::Introspect>::introspect(),
+        7 => <crate::struct_list::Owned<crate::schema_capnp::node::parameter::Owned> as crate::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1,2,3,4,5,6,7];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_NAME_POINTER_INDEX: usize = 0;
+      pub const FIELD_CODE_ORDER_OFFSET: usize = 0;
+      pub const FIELD_PARAM_STRUCT_TYPE_OFFSET: usize = 8;
+      pub const FIELD_RESULT_STRUCT_TYPE_OFFSET: usize = 16;
+      pub const FIELD_ANNOTATIONS_POINTER_INDEX: usize = 1;
+      pub const FIELD_PARAM_BRAND_POINTER_INDEX: usize = 2;
+      pub const FIELD_RESULT_BRAND_POINTER_INDEX: usize = 3;
+      pub const FIELD_IMPLICIT_PARAMETERS_POINTER_INDEX: usize = 4;
+    }
+    pub const TYPE_ID: u64 = 0x9500_cce2_3b33_4d80;
+  }
+}
+
+pub mod type_ {
+
+impl Type {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+ match self {
+ Type::UNINITIALIZED => (),
+ Type::_Void(t) => builder.reborrow().set_void(t),
+ Type::_Bool(t) => builder.reborrow().set_bool(t),
+ Type::_Int8(t) => builder.reborrow().set_int8(t),
+ Type::_Int16(t) => builder.reborrow().set_int16(t),
+ Type::_Int32(t) => builder.reborrow().set_int32(t),
+ Type::_Int64(t) => builder.reborrow().set_int64(t),
+ Type::_Uint8(t) => builder.reborrow().set_uint8(t),
+ Type::_Uint16(t) => builder.reborrow().set_uint16(t),
+ Type::_Uint32(t) => builder.reborrow().set_uint32(t),
+ Type::_Uint64(t) => builder.reborrow().set_uint64(t),
+ Type::_Float32(t) => builder.reborrow().set_float32(t),
+ Type::_Float64(t) => builder.reborrow().set_float64(t),
+ Type::_Text(t) => builder.reborrow().set_text(t),
+ Type::_Data(t) => builder.reborrow().set_data(t),
+ Type::_List(t) => t.build_capnp_struct(builder.reborrow().init_list())?,
+ Type::_Enum(t) => t.build_capnp_struct(builder.reborrow().init_enum())?,
+ Type::_Struct(t) => t.build_capnp_struct(builder.reborrow().init_struct())?,
+ Type::_Interface(t) => t.build_capnp_struct(builder.reborrow().init_interface())?,
+ Type::_AnyPointer(t) => t.build_capnp_struct(builder.reborrow().init_any_pointer())?,
+ }
+  ::core::result::Result::Ok(())
+}}
+pub enum Type {
+ UNINITIALIZED,
+ _Void(()),
+ _Bool(()),
+ _Int8(()),
+ _Int16(()),
+ _Int32(()),
+ _Int64(()),
+ _Uint8(()),
+ _Uint16(()),
+ _Uint32(()),
+ _Uint64(()),
+ _Float32(()),
+ _Float64(()),
+ _Text(()),
+ _Data(()),
+ _List(crate::schema_capnp::type_::list::List),
+ _Enum(crate::schema_capnp::type_::enum_::Enum),
+ _Struct(crate::schema_capnp::type_::struct_::Struct),
+ _Interface(crate::schema_capnp::type_::interface::Interface),
+ _AnyPointer(crate::schema_capnp::type_::any_pointer::AnyPointer),
+}
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Type  {
+  type Error = crate::Error;
+  fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+    ::core::result::Result::Ok(match reader.which()? {
+      Which::Void(value) => Type::_Void(value),
+      Which::Bool(value) => Type::_Bool(value),
+      Which::Int8(value) => Type::_Int8(value),
+      Which::Int16(value) => Type::_Int16(value),
+      Which::Int32(value) => Type::_Int32(value),
+      Which::Int64(value) => Type::_Int64(value),
+      Which::Uint8(value) => Type::_Uint8(value),
+      Which::Uint16(value) => Type::_Uint16(value),
+      Which::Uint32(value) => Type::_Uint32(value),
+      Which::Uint64(value) => Type::_Uint64(value),
+      Which::Float32(value) => Type::_Float32(value),
+      Which::Float64(value) => Type::_Float64(value),
+      Which::Text(value) => Type::_Text(value),
+      Which::Data(value) => Type::_Data(value),
+      Which::List(value) => Type::_List(<crate::schema_capnp::type_::list::List as ::core::convert::TryFrom<_>>::try_from(value)?),
+      Which::Enum(value) => Type::_Enum(<crate::schema_capnp::type_::enum_::Enum as ::core::convert::TryFrom<_>>::try_from(value)?),
+      Which::Struct(value) => Type::_Struct(<crate::schema_capnp::type_::struct_::Struct as ::core::convert::TryFrom<_>>::try_from(value)?),
+      Which::Interface(value) => Type::_Interface(<crate::schema_capnp::type_::interface::Interface as ::core::convert::TryFrom<_>>::try_from(value)?),
+      Which::AnyPointer(value) => Type::_AnyPointer(<crate::schema_capnp::type_::any_pointer::AnyPointer as ::core::convert::TryFrom<_>>::try_from(value)?),
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Type {
+  type Error = crate::Error;
+  fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl Type {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  pub use self::Which::{Void,Bool,Int8,Int16,Int32,Int64,Uint8,Uint16,Uint32,Uint64,Float32,Float64,Text,Data,List,Enum,Struct,Interface,AnyPointer};
+
+  /// The members of the union, as `(discriminant, name, field index)` in discriminant order.
+  pub static UNION_MEMBERS: &[(u16, &str, u16)] = &[(0, "void", 0), (1, "bool", 1), (2, "int8", 2), (3, "int16", 3), (4, "int32", 4), (5, "int64", 5), (6, "uint8", 6), (7, "uint16", 7), (8, "uint32", 8), (9, "uint64", 9), (10, "float32", 10), (11, "float64", 11), (12, "text", 12), (13, "data", 13), (14, "list", 14), (15, "enum", 15), (16, "struct", 16), (17, "interface", 17), (18, "anyPointer", 18)];
+  /// Returns the schema name of the union member with discriminant `d`.
+  pub fn discriminant_name(d: u16) -> ::core::option::Option<&'static str> {
+    UNION_MEMBERS.get(d as usize).map(|member| member.1)
+  }
+
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub list: crate::schema_capnp::type_::list::FieldCounts,
+    pub enum_: crate::schema_capnp::type_::enum_::FieldCounts,
+    pub struct_: crate::schema_capnp::type_::struct_::FieldCounts,
+    pub interface: crate::schema_capnp::type_::interface::FieldCounts,
+    pub any_pointer: crate::schema_capnp::type_::any_pointer::FieldCounts,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        list: crate::schema_capnp::type_::list::FieldCounts::new(),
+        enum_: crate::schema_capnp::type_::enum_::FieldCounts::new(),
+        struct_: crate::schema_capnp::type_::struct_::FieldCounts::new(),
+        interface: crate::schema_capnp::type_::interface::FieldCounts::new(),
+        any_pointer: crate::schema_capnp::type_::any_pointer::FieldCounts::new(),
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    crate::schema_capnp::type_::list::variable_words_for(counts.list) + crate::schema_capnp::type_::enum_::variable_words_for(counts.enum_) + crate::schema_capnp::type_::struct_::variable_words_for(counts.struct_) + crate::schema_capnp::type_::interface::variable_words_for(counts.interface) + crate::schema_capnp::type_::any_pointer::variable_words_for(counts.any_pointer)
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 3 + 1 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+      self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> crate::Result<usize> {
+      self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn is_void(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 0
+    }
+    #[inline]
+    pub fn is_bool(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 1
+    }
+    #[inline]
+    pub fn is_int8(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 2
+    }
+    #[inline]
+    pub fn is_int16(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 3
+    }
+    #[inline]
+    pub fn is_int32(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 4
+    }
+    #[inline]
+    pub fn is_int64(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 5
+    }
+    #[inline]
+    pub fn is_uint8(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 6
+    }
+    #[inline]
+    pub fn is_uint16(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 7
+    }
+    #[inline]
+    pub fn is_uint32(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 8
+    }
+    #[inline]
+    pub fn is_uint64(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 9
+    }
+    #[inline]
+    pub fn is_float32(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 10
+    }
+    #[inline]
+    pub fn is_float64(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 11
+    }
+    #[inline]
+    pub fn is_text(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 12
+    }
+    #[inline]
+    pub fn is_data(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 13
+    }
+    #[inline]
+    pub fn is_list(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 14
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_list(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 14 { return false; }
+      !self.reader.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn is_enum(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 15
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_enum(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 15 { return false; }
+      self.reader.get_data_field::<u64>(1) != 0
+        || !self.reader.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn is_struct(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 16
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_struct(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 16 { return false; }
+      self.reader.get_data_field::<u64>(1) != 0
+        || !self.reader.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn is_interface(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 17
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_interface(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 17 { return false; }
+      self.reader.get_data_field::<u64>(1) != 0
+        || !self.reader.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn is_any_pointer(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 18
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_any_pointer(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 18 { return false; }
+      self.reader.get_data_field::<u16>(4) != 0
+        || self.reader.get_data_field::<u16>(5) != 0
+        || self.reader.get_data_field::<u64>(2) != 0
+    }
+    #[inline]
+    pub fn which(self) -> ::core::result::Result<WhichReader<'a,>, crate::NotInSchema> {
+      match self.reader.get_data_field::<u16>(0) {
+        0 => {
+          ::core::result::Result::Ok(Which::Void(
+            ()
+          ))
+        }
+        1 => {
+          ::core::result::Result::Ok(Which::Bool(
+            ()
+          ))
+        }
+        2 => {
+          ::core::result::Result::Ok(Which::Int8(
+            ()
+          ))
+        }
+        3 => {
+          ::core::result::Result::Ok(Which::Int16(
+            ()
+          ))
+        }
+        4 => {
+          ::core::result::Result::Ok(Which::Int32(
+            ()
+          ))
+        }
+        5 => {
+          ::core::result::Result::Ok(Which::Int64(
+            ()
+          ))
+        }
+        6 => {
+          ::core::result::Result::Ok(Which::Uint8(
+            ()
+          ))
+        }
+        7 => {
+          ::core::result::Result::Ok(Which::Uint16(
+            ()
+          ))
+        }
+        8 => {
+          ::core::result::Result::Ok(Which::Uint32(
+            ()
+          ))
+        }
+        9 => {
+          ::core::result::Result::Ok(Which::Uint64(
+            ()
+          ))
+        }
+        10 => {
+          ::core::result::Result::Ok(Which::Float32(
+            ()
+          ))
+        }
+        11 => {
+          ::core::result::Result::Ok(Which::Float64(
+            ()
+          ))
+        }
+        12 => {
+          ::core::result::Result::Ok(Which::Text(
+            ()
+          ))
+        }
+        13 => {
+          ::core::result::Result::Ok(Which::Data(
+            ()
+          ))
+        }
+        14 => {
+          ::core::result::Result::Ok(Which::List(
+            self.reader.into()
+          ))
+        }
+        15 => {
+          ::core::result::Result::Ok(Which::Enum(
+            self.reader.into()
+          ))
+        }
+        16 => {
+          ::core::result::Result::Ok(Which::Struct(
+            self.reader.into()
+          ))
+        }
+        17 => {
+          ::core::result::Result::Ok(Which::Interface(
+            self.reader.into()
+          ))
+        }
+        18 => {
+          ::core::result::Result::Ok(Which::AnyPointer(
+            self.reader.into()
+          ))
+        }
+        x => ::core::result::Result::Err(crate::NotInSchema(x))
+      }
+    }
+  }
+
+  pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+  impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 3, pointers: 1 };
+  }
+  impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+      self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> crate::Result<usize> {
+      self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn set_void(&mut self, _value: ())  {
+      self.builder.set_data_field::<u16>(0, 0);
+    }
+    #[inline]
+    pub fn is_void(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 0
+    }
+    #[inline]
+    pub fn set_bool(&mut self, _value: ())  {
+      self.builder.set_data_field::<u16>(0, 1);
+    }
+    #[inline]
+    pub fn is_bool(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 1
+    }
+    #[inline]
+    pub fn set_int8(&mut self, _value: ())  {
+      self.builder.set_data_field::<u16>(0, 2);
+    }
+    #[inline]
+    pub fn is_int8(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 2
+    }
+    #[inline]
+    pub fn set_int16(&mut self, _value: ())  {
+      self.builder.set_data_field::<u16>(0, 3);
+    }
+    #[inline]
+    pub fn is_int16(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 3
+    }
+    #[inline]
+    pub fn set_int32(&mut self, _value: ())  {
+      self.builder.set_data_field::<u16>(0, 4);
+    }
+    #[inline]
+    pub fn is_int32(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 4
+    }
+    #[inline]
+    pub fn set_int64(&mut self, _value: ())  {
+      self.builder.set_data_field::<u16>(0, 5);
+    }
+    #[inline]
+    pub fn is_int64(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 5
+    }
+    #[inline]
+    pub fn set_uint8(&mut self, _value: ())  {
+      self.builder.set_data_field::<u16>(0, 6);
+    }
+    #[inline]
+    pub fn is_uint8(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 6
+    }
+    #[inline]
+    pub fn set_uint16(&mut self, _value: ())  {
+      self.builder.set_data_field::<u16>(0, 7);
+    }
+    #[inline]
+    pub fn is_uint16(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 7
+    }
+    #[inline]
+    pub fn set_uint32(&mut self, _value: ())  {
+      self.builder.set_data_field::<u16>(0, 8);
+    }
+    #[inline]
+    pub fn is_uint32(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 8
+    }
+    #[inline]
+    pub fn set_uint64(&mut self, _value: ())  {
+      self.builder.set_data_field::<u16>(0, 9);
+    }
+    #[inline]
+    pub fn is_uint64(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 9
+    }
+    #[inline]
+    pub fn set_float32(&mut self, _value: ())  {
+      self.builder.set_data_field::<u16>(0, 10);
+    }
+    #[inline]
+    pub fn is_float32(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 10
+    }
+    #[inline]
+    pub fn set_float64(&mut self, _value: ())  {
+      self.builder.set_data_field::<u16>(0, 11);
+    }
+    #[inline]
+    pub fn is_float64(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 11
+    }
+    #[inline]
+    pub fn set_text(&mut self, _value: ())  {
+      self.builder.set_data_field::<u16>(0, 12);
+    }
+    #[inline]
+    pub fn is_text(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 12
+    }
+    #[inline]
+    pub fn set_data(&mut self, _value: ())  {
+      self.builder.set_data_field::<u16>(0, 13);
+    }
+    #[inline]
+    pub fn is_data(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 13
+    }
+    #[inline]
+    pub fn init_list(mut self, ) -> crate::schema_capnp::type_::list::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 14);
+      self.builder.reborrow().get_pointer_field(0).clear();
+      self.builder.into()
+    }
+    #[inline]
+    pub fn is_list(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 14
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_list(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 14 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn init_enum(mut self, ) -> crate::schema_capnp::type_::enum_::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 15);
+      self.builder.set_data_field::<u64>(1, 0u64);
+      self.builder.reborrow().get_pointer_field(0).clear();
+      self.builder.into()
+    }
+    #[inline]
+    pub fn is_enum(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 15
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_enum(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 15 { return false; }
+      self.builder.get_data_field::<u64>(1) != 0
+        || !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn init_struct(mut self, ) -> crate::schema_capnp::type_::struct_::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 16);
+      self.builder.set_data_field::<u64>(1, 0u64);
+      self.builder.reborrow().get_pointer_field(0).clear();
+      self.builder.into()
+    }
+    #[inline]
+    pub fn is_struct(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 16
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_struct(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 16 { return false; }
+      self.builder.get_data_field::<u64>(1) != 0
+        || !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn init_interface(mut self, ) -> crate::schema_capnp::type_::interface::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 17);
+      self.builder.set_data_field::<u64>(1, 0u64);
+      self.builder.reborrow().get_pointer_field(0).clear();
+      self.builder.into()
+    }
+    #[inline]
+    pub fn is_interface(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 17
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_interface(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 17 { return false; }
+      self.builder.get_data_field::<u64>(1) != 0
+        || !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn init_any_pointer(self, ) -> crate::schema_capnp::type_::any_pointer::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 18);
+      self.builder.set_data_field::<u16>(4, 0);
+      self.builder.set_data_field::<u16>(5, 0);
+      self.builder.set_data_field::<u64>(2, 0u64);
+      self.builder.set_data_field::<u16>(5, 0u16);
+      self.builder.into()
+    }
+    #[inline]
+    pub fn is_any_pointer(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 18
+    }
+    /// Whether any field of this group differs from its default. This reads each
+    /// field of the group, but does not follow pointers: a field that is set to an
+    /// empty struct or list counts as present.
+    #[inline]
+    pub fn has_any_pointer(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 18 { return false; }
+      self.builder.get_data_field::<u16>(4) != 0
+        || self.builder.get_data_field::<u16>(5) != 0
+        || self.builder.get_data_field::<u64>(2) != 0
+    }
+    #[inline]
+    pub fn which(self) -> ::core::result::Result<WhichBuilder<'a,>, crate::NotInSchema> {
+      match self.builder.get_data_field::<u16>(0) {
+        0 => {
+          ::core::result::Result::Ok(Which::Void(
+            ()
+          ))
+        }
+        1 => {
+          ::core::result::Result::Ok(Which::Bool(
+            ()
+          ))
+        }
+        2 => {
+          ::core::result::Result::Ok(Which::Int8(
+            ()
+          ))
+        }
+        3 => {
+          ::core::result::Result::Ok(Which::Int16(
+            ()
+          ))
+        }
+        4 => {
+          ::core::result::Result::Ok(Which::Int32(
+            ()
+          ))
+        }
+        5 => {
+          ::core::result::Result::Ok(Which::Int64(
+            ()
+          ))
+        }
+        6 => {
+          ::core::result::Result::Ok(Which::Uint8(
+            ()
+          ))
+        }
+        7 => {
+          ::core::result::Result::Ok(Which::Uint16(
+            ()
+          ))
+        }
+        8 => {
+          ::core::result::Result::Ok(Which::Uint32(
+            ()
+          ))
+        }
+        9 => {
+          ::core::result::Result::Ok(Which::Uint64(
+            ()
+          ))
+        }
+        10 => {
+          ::core::result::Result::Ok(Which::Float32(
+            ()
+          ))
+        }
+        11 => {
+          ::core::result::Result::Ok(Which::Float64(
+            ()
+          ))
+        }
+        12 => {
+          ::core::result::Result::Ok(Which::Text(
+            ()
+          ))
+        }
+        13 => {
+          ::core::result::Result::Ok(Which::Data(
+            ()
+          ))
+        }
+        14 => {
+          ::core::result::Result::Ok(Which::List(
+            self.builder.into()
+          ))
+        }
+        15 => {
+          ::core::result::Result::Ok(Which::Enum(
+            self.builder.into()
+          ))
+        }
+        16 => {
+          ::core::result::Result::Ok(Which::Struct(
+            self.builder.into()
+          ))
+        }
+        17 => {
+          ::core::result::Result::Ok(Which::Interface(
+            self.builder.into()
+          ))
+        }
+        18 => {
+          ::core::result::Result::Ok(Which::AnyPointer(
+            self.builder.into()
+          ))
+        }
+        x => ::core::result::Result::Err(crate::NotInSchema(x))
+      }
+    }
+    /// Sets the union to the variant that is active in `other`, copied from there as stored.
+    /// The fields outside the union are left unchanged.
+    ///
+    /// Returns an `EnumValueOrUnionDiscriminantNotPresent` error without changing anything if
+    /// `other` has a variant that is not in the schema, for example one added by a newer version
+    /// of it. A union nested in a group of the active variant is handled the same way, but by
+    /// then the group has been partially copied.
+    pub fn copy_union_from(&mut self, other: Reader<'_,>) -> crate::Result<()> {
+      match other.reader.get_data_field::<u16>(0) {
+        0 => {
+          self.builder.set_data_field::<u16>(0, 0);
+        }
+        1 => {
+          self.builder.set_data_field::<u16>(0, 1);
+        }
+        2 => {
+          self.builder.set_data_field::<u16>(0, 2);
+        }
+        3 => {
+          self.builder.set_data_field::<u16>(0, 3);
+        }
+        4 => {
+          self.builder.set_data_field::<u16>(0, 4);
+        }
+        5 => {
+          self.builder.set_data_field::<u16>(0, 5);
+        }
+        6 => {
+          self.builder.set_data_field::<u16>(0, 6);
+        }
+        7 => {
+          self.builder.set_data_field::<u16>(0, 7);
+        }
+        8 => {
+          self.builder.set_data_field::<u16>(0, 8);
+        }
+        9 => {
+          self.builder.set_data_field::<u16>(0, 9);
+        }
+        10 => {
+          self.builder.set_data_field::<u16>(0, 10);
+        }
+        11 => {
+          self.builder.set_data_field::<u16>(0, 11);
+        }
+        12 => {
+          self.builder.set_data_field::<u16>(0, 12);
+        }
+        13 => {
+          self.builder.set_data_field::<u16>(0, 13);
+        }
+        14 => {
+          self.reborrow().init_list();
+          crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), crate::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        15 => {
+          self.reborrow().init_enum();
+          self.builder.set_data_field::<u64>(1, other.reader.get_data_field::<u64>(1));
+          crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), crate::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        16 => {
+          self.reborrow().init_struct();
+          self.builder.set_data_field::<u64>(1, other.reader.get_data_field::<u64>(1));
+          crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), crate::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        17 => {
+          self.reborrow().init_interface();
+          self.builder.set_data_field::<u64>(1, other.reader.get_data_field::<u64>(1));
+          crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), crate::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        18 => {
+          self.reborrow().init_any_pointer();
+          let discriminant = other.reader.get_data_field::<u16>(4);
+          self.builder.set_data_field::<u16>(4, discriminant);
+          match discriminant {
+            0 => {
+              let discriminant = other.reader.get_data_field::<u16>(5);
+              self.builder.set_data_field::<u16>(5, discriminant);
+              match discriminant {
+                0 => {
+                }
+                1 => {
+                }
+                2 => {
+                }
+                3 => {
+                }
+                x => return ::core::result::Result::Err(crate::NotInSchema(x).into()),
+              }
+            }
+            1 => {
+              self.builder.set_data_field::<u64>(2, other.reader.get_data_field::<u64>(2));
+              self.builder.set_data_field::<u16>(5, other.reader.get_data_field::<u16>(5));
+            }
+            2 => {
+              self.builder.set_data_field::<u16>(5, other.reader.get_data_field::<u16>(5));
+            }
+            x => return ::core::result::Result::Err(crate::NotInSchema(x).into()),
+          }
+        }
+        x => return ::core::result::Result::Err(crate::NotInSchema(x).into()),
+      }
+      ::core::result::Result::Ok(())
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+  impl crate::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+    let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [crate::Word; 269] = [
+      crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+      crate::word(96, 204, 249, 225, 237, 120, 115, 208),
+      crate::word(13, 0, 0, 0, 1, 0, 3, 0),
+      crate::word(217, 114, 76, 98, 9, 197, 63, 169),
+      crate::word(1, 0, 7, 0, 0, 0, 19, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(21, 0, 0, 0, 146, 0, 0, 0),
+      crate::word(29, 0, 0, 0, 7, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(25, 0, 0, 0, 47, 4, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+      crate::word(97, 112, 110, 112, 58, 84, 121, 112),
+      crate::word(101, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 1, 0, 1, 0),
+      crate::word(76, 0, 0, 0, 3, 0, 4, 0),
+      crate::word(0, 0, 255, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(5, 2, 0, 0, 42, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(12, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(1, 0, 254, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 1, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(9, 2, 0, 0, 42, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(4, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(16, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(2, 0, 253, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 2, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(13, 2, 0, 0, 42, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(8, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(20, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(3, 0, 252, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 3, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(17, 2, 0, 0, 50, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(12, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(24, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(4, 0, 251, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 4, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(21, 2, 0, 0, 50, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(16, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(28, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(5, 0, 250, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 5, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(25, 2, 0, 0, 50, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(20, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(32, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(6, 0, 249, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 6, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(29, 2, 0, 0, 50, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(24, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(36, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(7, 0, 248, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 7, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(33, 2, 0, 0, 58, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(28, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(40, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(8, 0, 247, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 8, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(37, 2, 0, 0, 58, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(32, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(44, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(9, 0, 246, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 9, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(41, 2, 0, 0, 58, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(36, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(48, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(10, 0, 245, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 10, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(45, 2, 0, 0, 66, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(40, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(52, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(11, 0, 244, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 11, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(49, 2, 0, 0, 66, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(44, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(56, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(12, 0, 243, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 12, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(53, 2, 0, 0, 42, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(48, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(60, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(13, 0, 242, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 13, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(57, 2, 0, 0, 42, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(52, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(64, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(14, 0, 241, 255, 0, 0, 0, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(151, 234, 96, 10, 37, 57, 231, 135),
+      crate::word(61, 2, 0, 0, 42, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(15, 0, 240, 255, 0, 0, 0, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(169, 135, 127, 26, 113, 120, 14, 158),
+      crate::word(37, 2, 0, 0, 42, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(16, 0, 239, 255, 0, 0, 0, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(211, 198, 76, 239, 96, 111, 58, 172),
+      crate::word(13, 2, 0, 0, 58, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(17, 0, 238, 255, 0, 0, 0, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(191, 12, 251, 247, 105, 202, 139, 237),
+      crate::word(245, 1, 0, 0, 82, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(18, 0, 237, 255, 0, 0, 0, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(241, 73, 62, 162, 232, 63, 87, 194),
+      crate::word(225, 1, 0, 0, 90, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(118, 111, 105, 100, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(98, 111, 111, 108, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(105, 110, 116, 56, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(105, 110, 116, 49, 54, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(105, 110, 116, 51, 50, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(105, 110, 116, 54, 52, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(117, 105, 110, 116, 56, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(117, 105, 110, 116, 49, 54, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(117, 105, 110, 116, 51, 50, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(117, 105, 110, 116, 54, 52, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(102, 108, 111, 97, 116, 51, 50, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(102, 108, 111, 97, 116, 54, 52, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(116, 101, 120, 116, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(100, 97, 116, 97, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(108, 105, 115, 116, 0, 0, 0, 0),
+      crate::word(101, 110, 117, 109, 0, 0, 0, 0),
+      crate::word(115, 116, 114, 117, 99, 116, 0, 0),
+      crate::word(105, 110, 116, 101, 114, 102, 97, 99),
+      crate::word(101, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(97, 110, 121, 80, 111, 105, 110, 116),
+      crate::word(101, 114, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "schema.capnp:Type";
+    pub fn get_field_types(index: u16) -> crate::introspect::Type {
+      match index {
+        0 => <() as crate::introspect::Introspect>::introspect(),
+        1 => <() as crate::introspect::Introspect>::introspect(),
+        2 => <() as crate::introspect::Introspect>::introspect(),
+        3 => <() as crate::introspect::Introspect>::introspect(),
+        4 => <() as crate::introspect::Introspect>::introspect(),
+        5 => <() as crate::introspect::Introspect>::introspect(),
+        6 => <() as crate::introspect::Introspect>::introspect(),
+        7 => <() as crate::introspect::Introspect>::introspect(),
+        8 => <() as crate::introspect::Introspect>::introspect(),
+        9 => <() as crate::introspect::Introspect>::introspect(),
+        10 => <() as crate::introspect::Introspect>::introspect(),
+        11 => <() as crate::introspect::Introspect>::introspect(),
+        12 => <() as crate::introspect::Introspect>::introspect(),
+        13 => <() as crate::introspect::Introspect>::introspect(),
+        14 => <crate::schema_capnp::type_::list::Owned as crate::introspect::Introspect>::introspect(),
+        15 => <crate::schema_capnp::type_::enum_::Owned as crate::introspect::Introspect>::introspect(),
+        16 => <crate::schema_capnp::type_::struct_::Owned as crate::introspect::Introspect>::introspect(),
+        17 => <crate::schema_capnp::type_::interface::Owned as crate::introspect::Introspect>::introspect(),
+        18 => <crate::schema_capnp::type_::any_pointer::Owned as crate::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18];
+    pub mod layout {
+      pub const DISCRIMINANT_OFFSET: usize = 0;
+      pub const FIELD_VOID_DISCRIMINANT: u16 = 0;
+      pub const FIELD_BOOL_DISCRIMINANT: u16 = 1;
+      pub const FIELD_INT8_DISCRIMINANT: u16 = 2;
+      pub const FIELD_INT16_DISCRIMINANT: u16 = 3;
+      pub const FIELD_INT32_DISCRIMINANT: u16 = 4;
+      pub const FIELD_INT64_DISCRIMINANT: u16 = 5;
+      pub const FIELD_UINT8_DISCRIMINANT: u16 = 6;
+      pub const FIELD_UINT16_DISCRIMINANT: u16 = 7;
+      pub const FIELD_UINT32_DISCRIMINANT: u16 = 8;
+      pub const FIELD_UINT64_DISCRIMINANT: u16 = 9;
+      pub const FIELD_FLOAT32_DISCRIMINANT: u16 = 10;
+      pub const FIELD_FLOAT64_DISCRIMINANT: u16 = 11;
+      pub const FIELD_TEXT_DISCRIMINANT: u16 = 12;
+      pub const FIELD_DATA_DISCRIMINANT: u16 = 13;
+      pub const FIELD_LIST_DISCRIMINANT: u16 = 14;
+      pub const FIELD_ENUM_DISCRIMINANT: u16 = 15;
+      pub const FIELD_STRUCT_DISCRIMINANT: u16 = 16;
+      pub const FIELD_INTERFACE_DISCRIMINANT: u16 = 17;
+      pub const FIELD_ANY_POINTER_DISCRIMINANT: u16 = 18;
+    }
+    pub const TYPE_ID: u64 = 0xd073_78ed_e1f9_cc60;
+  }
+  pub enum Which<A0,A1,A2,A3,A4> {
+    Void(()),
+    Bool(()),
+    Int8(()),
+    Int16(()),
+    Int32(()),
+    Int64(()),
+    Uint8(()),
+    Uint16(()),
+    Uint32(()),
+    Uint64(()),
+    Float32(()),
+    Float64(()),
+    Text(()),
+    Data(()),
+    List(A0),
+    Enum(A1),
+    Struct(A2),
+    Interface(A3),
+    AnyPointer(A4),
+  }
+  pub type WhichReader<'a,> = Which<crate::schema_capnp::type_::list::Reader<'a>,crate::schema_capnp::type_::enum_::Reader<'a>,crate::schema_capnp::type_::struct_::Reader<'a>,crate::schema_capnp::type_::interface::Reader<'a>,crate::schema_capnp::type_::any_pointer::Reader<'a>>;
+  pub type WhichBuilder<'a,> = Which<crate::schema_capnp::type_::list::Builder<'a>,crate::schema_capnp::type_::enum_::Builder<'a>,crate::schema_capnp::type_::struct_::Builder<'a>,crate::schema_capnp::type_::interface::Builder<'a>,crate::schema_capnp::type_::any_pointer::Builder<'a>>;
+
+  pub mod list {
+  pub struct List {
+ pub _element_type: Option<Box<crate::schema_capnp::type_::Type>>,  
+}
+  impl List {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+  if let Some(st) = self._element_type {st.build_capnp_struct(builder.reborrow().init_element_type())?;}
+  ::core::result::Result::Ok(())
+}}
+  
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for List  {
+    type Error = crate::Error;
+    fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(Self {
+        _element_type: if reader.has_element_type() { ::core::option::Option::Some(Box::new(<crate::schema_capnp::type_::Type as ::core::convert::TryFrom<_>>::try_from(reader.get_element_type()?)?)) } else { ::core::option::Option::None },
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for List {
+    type Error = crate::Error;
+    fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl List {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+      pub element_type: usize,
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+          element_type: crate::schema_capnp::type_::MIN_SIZE_WORDS - 1,
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(counts: FieldCounts) -> usize {
+      counts.element_type
+    }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+        self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn get_element_type(self) -> crate::Result<crate::schema_capnp::type_::Reader<'a>> {
+        crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn has_element_type(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
+    }
+
+    pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+    impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 3, pointers: 1 };
+    }
+    impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+        self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn get_element_type(self) -> crate::Result<crate::schema_capnp::type_::Builder<'a>> {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn set_element_type(&mut self, value: crate::schema_capnp::type_::Reader<'_>) -> crate::Result<()> {
+        crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+      }
+      #[inline]
+      pub fn init_element_type(self, ) -> crate::schema_capnp::type_::Builder<'a> {
+        crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+      }
+      #[inline]
+      pub fn disown_element_type(&mut self) -> crate::Orphan<crate::schema_capnp::type_::Owned> {
+        crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+      }
+      #[inline]
+      pub fn adopt_element_type(&mut self, orphan: crate::Orphan<crate::schema_capnp::type_::Owned>) -> crate::Result<()> {
+        self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+      }
+      #[inline]
+      pub fn get_or_init_element_type(&mut self, ) -> crate::Result<crate::schema_capnp::type_::Builder<'_>> {
+        if self.builder.is_pointer_field_null(0) {
+          ::core::result::Result::Ok(self.reborrow().init_element_type())
+        } else {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+        }
+      }
+      #[inline]
+      pub fn has_element_type(&self) -> bool {
+        !self.builder.is_pointer_field_null(0)
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+    impl crate::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+      #[inline]
+      pub fn get_element_type(&self) -> crate::schema_capnp::type_::Pipeline {
+        crate::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+      }
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+      let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [crate::Word; 32] = [
+        crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+        crate::word(151, 234, 96, 10, 37, 57, 231, 135),
+        crate::word(18, 0, 0, 0, 1, 0, 3, 0),
+        crate::word(96, 204, 249, 225, 237, 120, 115, 208),
+        crate::word(1, 0, 7, 0, 1, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 186, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 63, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+        crate::word(97, 112, 110, 112, 58, 84, 121, 112),
+        crate::word(101, 46, 108, 105, 115, 116, 0, 0),
+        crate::word(4, 0, 0, 0, 3, 0, 4, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 14, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(13, 0, 0, 0, 98, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(12, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(24, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(101, 108, 101, 109, 101, 110, 116, 84),
+        crate::word(121, 112, 101, 0, 0, 0, 0, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(96, 204, 249, 225, 237, 120, 115, 208),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "schema.capnp:Type.list";
+      pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        match index {
+          0 => <crate::schema_capnp::type_::Owned as crate::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[0];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+      pub mod layout {
+        pub const FIELD_ELEMENT_TYPE_POINTER_INDEX: usize = 0;
+      }
+      pub const TYPE_ID: u64 = 0x87e7_3925_0a60_ea97;
+    }
+  }
+
+  pub mod enum_ {
+  pub struct Enum {
+ pub _type_id: u64,
+ pub _brand: Option<crate::schema_capnp::brand::Brand>,  
+}
+  impl Enum {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+  builder.set_type_id(self._type_id);
+  if let Some(st) = self._brand {st.build_capnp_struct(builder.reborrow().init_brand())?;}
+  ::core::result::Result::Ok(())
+}}
+  
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Enum  {
+    type Error = crate::Error;
+    fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(Self {
+        _type_id: reader.get_type_id(),
+        _brand: if reader.has_brand() { ::core::option::Option::Some(<crate::schema_capnp::brand::Brand as ::core::convert::TryFrom<_>>::try_from(reader.get_brand()?)?) } else { ::core::option::Option::None },
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Enum {
+    type Error = crate::Error;
+    fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl Enum {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+      pub brand: usize,
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+          brand: crate::schema_capnp::brand::MIN_SIZE_WORDS - 1,
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(counts: FieldCounts) -> usize {
+      counts.brand
+    }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+        self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn get_type_id(self) -> u64 {
+        self.reader.get_data_field::<u64>(1)
+      }
+      #[inline]
+      pub fn get_brand(self) -> crate::Result<crate::schema_capnp::brand::Reader<'a>> {
+        crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn has_brand(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
+    }
+
+    pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+    impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 3, pointers: 1 };
+    }
+    impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+        self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn get_type_id(self) -> u64 {
+        self.builder.get_data_field::<u64>(1)
+      }
+      #[inline]
+      pub fn set_type_id(&mut self, value: u64)  {
+        self.builder.set_data_field::<u64>(1, value);
+      }
+      #[inline]
+      pub fn set_type_id_from(&mut self, value: impl ::core::convert::TryInto<u64>) -> crate::Result<()> {
+        let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+        self.set_type_id(value);
+        ::core::result::Result::Ok(())
+      }
+      #[inline]
+      pub fn get_brand(self) -> crate::Result<crate::schema_capnp::brand::Builder<'a>> {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn set_brand(&mut self, value: crate::schema_capnp::brand::Reader<'_>) -> crate::Result<()> {
+        crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+      }
+      #[inline]
+      pub fn init_brand(self, ) -> crate::schema_capnp::brand::Builder<'a> {
+        crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+      }
+      #[inline]
+      pub fn disown_brand(&mut self) -> crate::Orphan<crate::schema_capnp::brand::Owned> {
+        crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+      }
+      #[inline]
+      pub fn adopt_brand(&mut self, orphan: crate::Orphan<crate::schema_capnp::brand::Owned>) -> crate::Result<()> {
+        self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+      }
+      #[inline]
+      pub fn get_or_init_brand(&mut self, ) -> crate::Result<crate::schema_capnp::brand::Builder<'_>> {
+        if self.builder.is_pointer_field_null(0) {
+          ::core::result::Result::Ok(self.reborrow().init_brand())
+        } else {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+        }
+      }
+      #[inline]
+      pub fn has_brand(&self) -> bool {
+        !self.builder.is_pointer_field_null(0)
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+    impl crate::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+      #[inline]
+      pub fn get_brand(&self) -> crate::schema_capnp::brand::Pipeline {
+        crate::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+      }
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+      let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [crate::Word; 46] = [
+        crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+        crate::word(169, 135, 127, 26, 113, 120, 14, 158),
+        crate::word(18, 0, 0, 0, 1, 0, 3, 0),
+        crate::word(96, 204, 249, 225, 237, 120, 115, 208),
+        crate::word(1, 0, 7, 0, 1, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 186, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 119, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+        crate::word(97, 112, 110, 112, 58, 84, 121, 112),
+        crate::word(101, 46, 101, 110, 117, 109, 0, 0),
+        crate::word(8, 0, 0, 0, 3, 0, 4, 0),
+        crate::word(0, 0, 0, 0, 1, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 15, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(41, 0, 0, 0, 58, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(36, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(48, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 21, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(45, 0, 0, 0, 50, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(40, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(52, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(116, 121, 112, 101, 73, 100, 0, 0),
+        crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(98, 114, 97, 110, 100, 0, 0, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(43, 66, 101, 96, 240, 85, 52, 144),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "schema.capnp:Type.enum";
+      pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        match index {
+          0 => <u64 as crate::introspect::Introspect>::introspect(),
+          1 => <crate::schema_capnp::brand::Owned as crate::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[0,1];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+      pub mod layout {
+        pub const FIELD_TYPE_ID_OFFSET: usize = 8;
+        pub const FIELD_BRAND_POINTER_INDEX: usize = 0;
+      }
+      pub const TYPE_ID: u64 = 0x9e0e_7871_1a7f_87a9;
+    }
+  }
+
+  pub mod struct_ {
+  pub struct Struct {
+ pub _type_id: u64,
+ pub _brand: Option<crate::schema_capnp::brand::Brand>,  
+}
+  impl Struct {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+  builder.set_type_id(self._type_id);
+  if let Some(st) = self._brand {st.build_capnp_struct(builder.reborrow().init_brand())?;}
+  ::core::result::Result::Ok(())
+}}
+  
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Struct  {
+    type Error = crate::Error;
+    fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(Self {
+        _type_id: reader.get_type_id(),
+        _brand: if reader.has_brand() { ::core::option::Option::Some(<crate::schema_capnp::brand::Brand as ::core::convert::TryFrom<_>>::try_from(reader.get_brand()?)?) } else { ::core::option::Option::None },
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Struct {
+    type Error = crate::Error;
+    fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl Struct {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+      pub brand: usize,
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+          brand: crate::schema_capnp::brand::MIN_SIZE_WORDS - 1,
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(counts: FieldCounts) -> usize {
+      counts.brand
+    }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+        self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn get_type_id(self) -> u64 {
+        self.reader.get_data_field::<u64>(1)
+      }
+      #[inline]
+      pub fn get_brand(self) -> crate::Result<crate::schema_capnp::brand::Reader<'a>> {
+        crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn has_brand(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
+    }
+
+    pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+    impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 3, pointers: 1 };
+    }
+    impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+        self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn get_type_id(self) -> u64 {
+        self.builder.get_data_field::<u64>(1)
+      }
+      #[inline]
+      pub fn set_type_id(&mut self, value: u64)  {
+        self.builder.set_data_field::<u64>(1, value);
+      }
+      #[inline]
+      pub fn set_type_id_from(&mut self, value: impl ::core::convert::TryInto<u64>) -> crate::Result<()> {
+        let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+        self.set_type_id(value);
+        ::core::result::Result::Ok(())
+      }
+      #[inline]
+      pub fn get_brand(self) -> crate::Result<crate::schema_capnp::brand::Builder<'a>> {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn set_brand(&mut self, value: crate::schema_capnp::brand::Reader<'_>) -> crate::Result<()> {
+        crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+      }
+      #[inline]
+      pub fn init_brand(self, ) -> crate::schema_capnp::brand::Builder<'a> {
+        crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+      }
+      #[inline]
+      pub fn disown_brand(&mut self) -> crate::Orphan<crate::schema_capnp::brand::Owned> {
+        crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+      }
+      #[inline]
+      pub fn adopt_brand(&mut self, orphan: crate::Orphan<crate::schema_capnp::brand::Owned>) -> crate::Result<()> {
+        self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+      }
+      #[inline]
+      pub fn get_or_init_brand(&mut self, ) -> crate::Result<crate::schema_capnp::brand::Builder<'_>> {
+        if self.builder.is_pointer_field_null(0) {
+          ::core::result::Result::Ok(self.reborrow().init_brand())
+        } else {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+        }
+      }
+      #[inline]
+      pub fn has_brand(&self) -> bool {
+        !self.builder.is_pointer_field_null(0)
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+    impl crate::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+      #[inline]
+      pub fn get_brand(&self) -> crate::schema_capnp::brand::Pipeline {
+        crate::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+      }
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+      let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [crate::Word; 47] = [
+        crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+        crate::word(211, 198, 76, 239, 96, 111, 58, 172),
+        crate::word(18, 0, 0, 0, 1, 0, 3, 0),
+        crate::word(96, 204, 249, 225, 237, 120, 115, 208),
+        crate::word(1, 0, 7, 0, 1, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 202, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(25, 0, 0, 0, 119, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+        crate::word(97, 112, 110, 112, 58, 84, 121, 112),
+        crate::word(101, 46, 115, 116, 114, 117, 99, 116),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(8, 0, 0, 0, 3, 0, 4, 0),
+        crate::word(0, 0, 0, 0, 1, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 16, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(41, 0, 0, 0, 58, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(36, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(48, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 22, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(45, 0, 0, 0, 50, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(40, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(52, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(116, 121, 112, 101, 73, 100, 0, 0),
+        crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(98, 114, 97, 110, 100, 0, 0, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(43, 66, 101, 96, 240, 85, 52, 144),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "schema.capnp:Type.struct";
+      pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        match index {
+          0 => <u64 as crate::introspect::Introspect>::introspect(),
+          1 => <crate::schema_capnp::brand::Owned as crate::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[0,1];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+      pub mod layout {
+        pub const FIELD_TYPE_ID_OFFSET: usize = 8;
+        pub const FIELD_BRAND_POINTER_INDEX: usize = 0;
+      }
+      pub const TYPE_ID: u64 = 0xac3a_6f60_ef4c_c6d3;
+    }
+  }
+
+  pub mod interface {
+  pub struct Interface {
+ pub _type_id: u64,
+ pub _brand: Option<crate::schema_capnp::brand::Brand>,  
+}
+  impl Interface {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+  builder.set_type_id(self._type_id);
+  if let Some(st) = self._brand {st.build_capnp_struct(builder.reborrow().init_brand())?;}
+  ::core::result::Result::Ok(())
+}}
+  
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Interface  {
+    type Error = crate::Error;
+    fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(Self {
+        _type_id: reader.get_type_id(),
+        _brand: if reader.has_brand() { ::core::option::Option::Some(<crate::schema_capnp::brand::Brand as ::core::convert::TryFrom<_>>::try_from(reader.get_brand()?)?) } else { ::core::option::Option::None },
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Interface {
+    type Error = crate::Error;
+    fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl Interface {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+      pub brand: usize,
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+          brand: crate::schema_capnp::brand::MIN_SIZE_WORDS - 1,
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(counts: FieldCounts) -> usize {
+      counts.brand
+    }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+        self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn get_type_id(self) -> u64 {
+        self.reader.get_data_field::<u64>(1)
+      }
+      #[inline]
+      pub fn get_brand(self) -> crate::Result<crate::schema_capnp::brand::Reader<'a>> {
+        crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn has_brand(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
+    }
+
+    pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+    impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 3, pointers: 1 };
+    }
+    impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+        self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn get_type_id(self) -> u64 {
+        self.builder.get_data_field::<u64>(1)
+      }
+      #[inline]
+      pub fn set_type_id(&mut self, value: u64)  {
+        self.builder.set_data_field::<u64>(1, value);
+      }
+      #[inline]
+      pub fn set_type_id_from(&mut self, value: impl ::core::convert::TryInto<u64>) -> crate::Result<()> {
+        let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+        self.set_type_id(value);
+        ::core::result::Result::Ok(())
+      }
+      #[inline]
+      pub fn get_brand(self) -> crate::Result<crate::schema_capnp::brand::Builder<'a>> {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn set_brand(&mut self, value: crate::schema_capnp::brand::Reader<'_>) -> crate::Result<()> {
+        crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+      }
+      #[inline]
+      pub fn init_brand(self, ) -> crate::schema_capnp::brand::Builder<'a> {
+        crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+      }
+      #[inline]
+      pub fn disown_brand(&mut self) -> crate::Orphan<crate::schema_capnp::brand::Owned> {
+        crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+      }
+      #[inline]
+      pub fn adopt_brand(&mut self, orphan: crate::Orphan<crate::schema_capnp::brand::Owned>) -> crate::Result<()> {
+        self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+      }
+      #[inline]
+      pub fn get_or_init_brand(&mut self, ) -> crate::Result<crate::schema_capnp::brand::Builder<'_>> {
+        if self.builder.is_pointer_field_null(0) {
+          ::core::result::Result::Ok(self.reborrow().init_brand())
+        } else {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+        }
+      }
+      #[inline]
+      pub fn has_brand(&self) -> bool {
+        !self.builder.is_pointer_field_null(0)
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+    impl crate::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+      #[inline]
+      pub fn get_brand(&self) -> crate::schema_capnp::brand::Pipeline {
+        crate::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+      }
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+      let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [crate::Word; 47] = [
+        crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+        crate::word(191, 12, 251, 247, 105, 202, 139, 237),
+        crate::word(18, 0, 0, 0, 1, 0, 3, 0),
+        crate::word(96, 204, 249, 225, 237, 120, 115, 208),
+        crate::word(1, 0, 7, 0, 1, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 226, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(25, 0, 0, 0, 119, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+        crate::word(97, 112, 110, 112, 58, 84, 121, 112),
+        crate::word(101, 46, 105, 110, 116, 101, 114, 102),
+        crate::word(97, 99, 101, 0, 0, 0, 0, 0),
+        crate::word(8, 0, 0, 0, 3, 0, 4, 0),
+        crate::word(0, 0, 0, 0, 1, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 17, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(41, 0, 0, 0, 58, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(36, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(48, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 23, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(45, 0, 0, 0, 50, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(40, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(52, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(116, 121, 112, 101, 73, 100, 0, 0),
+        crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(98, 114, 97, 110, 100, 0, 0, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(43, 66, 101, 96, 240, 85, 52, 144),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "schema.capnp:Type.interface";
+      pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        match index {
+          0 => <u64 as crate::introspect::Introspect>::introspect(),
+          1 => <crate::schema_capnp::brand::Owned as crate::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[0,1];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+      pub mod layout {
+        pub const FIELD_TYPE_ID_OFFSET: usize = 8;
+        pub const FIELD_BRAND_POINTER_INDEX: usize = 0;
+      }
+      pub const TYPE_ID: u64 = 0xed8b_ca69_f7fb_0cbf;
+    }
+  }
+
+  pub mod any_pointer {
+  
+  impl AnyPointer {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+ match self {
+ AnyPointer::UNINITIALIZED => (),
+ AnyPointer::_Unconstrained(t) => t.build_capnp_struct(builder.reborrow().init_unconstrained())?,
+ AnyPointer::_Parameter(t) => t.build_capnp_struct(builder.reborrow().init_parameter())?,
+ AnyPointer::_ImplicitMethodParameter(t) => t.build_capnp_struct(builder.reborrow().init_implicit_method_parameter())?,
+ }
+  ::core::result::Result::Ok(())
+}}
+  pub enum AnyPointer {
+ UNINITIALIZED,
+ _Unconstrained(crate::schema_capnp::type_::any_pointer::unconstrained::Unconstrained),
+ _Parameter(crate::schema_capnp::type_::any_pointer::parameter::Parameter),
+ _ImplicitMethodParameter(crate::schema_capnp::type_::any_pointer::implicit_method_parameter::ImplicitMethodParameter),
+}
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for AnyPointer  {
+    type Error = crate::Error;
+    fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(match reader.which()? {
+        Which::Unconstrained(value) => AnyPointer::_Unconstrained(<crate::schema_capnp::type_::any_pointer::unconstrained::Unconstrained as ::core::convert::TryFrom<_>>::try_from(value)?),
+        Which::Parameter(value) => AnyPointer::_Parameter(<crate::schema_capnp::type_::any_pointer::parameter::Parameter as ::core::convert::TryFrom<_>>::try_from(value)?),
+        Which::ImplicitMethodParameter(value) => AnyPointer::_ImplicitMethodParameter(<crate::schema_capnp::type_::any_pointer::implicit_method_parameter::ImplicitMethodParameter as ::core::convert::TryFrom<_>>::try_from(value)?),
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for AnyPointer {
+    type Error = crate::Error;
+    fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl AnyPointer {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    pub use self::Which::{Unconstrained,Parameter,ImplicitMethodParameter};
+
+    /// The members of the union, as `(discriminant, name, field index)` in discriminant order.
+    pub static UNION_MEMBERS: &[(u16, &str, u16)] = &[(0, "unconstrained", 0), (1, "parameter", 1), (2, "implicitMethodParameter", 2)];
+    /// Returns the schema name of the union member with discriminant `d`.
+    pub fn discriminant_name(d: u16) -> ::core::option::Option<&'static str> {
+      UNION_MEMBERS.get(d as usize).map(|member| member.1)
+    }
+
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+      pub unconstrained: crate::schema_capnp::type_::any_pointer::unconstrained::FieldCounts,
+      pub parameter: crate::schema_capnp::type_::any_pointer::parameter::FieldCounts,
+      pub implicit_method_parameter: crate::schema_capnp::type_::any_pointer::implicit_method_parameter::FieldCounts,
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+          unconstrained: crate::schema_capnp::type_::any_pointer::unconstrained::FieldCounts::new(),
+          parameter: crate::schema_capnp::type_::any_pointer::parameter::FieldCounts::new(),
+          implicit_method_parameter: crate::schema_capnp::type_::any_pointer::implicit_method_parameter::FieldCounts::new(),
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(counts: FieldCounts) -> usize {
+      crate::schema_capnp::type_::any_pointer::unconstrained::variable_words_for(counts.unconstrained) + crate::schema_capnp::type_::any_pointer::parameter::variable_words_for(counts.parameter) + crate::schema_capnp::type_::any_pointer::implicit_method_parameter::variable_words_for(counts.implicit_method_parameter)
+    }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+        self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn is_unconstrained(&self) -> bool {
+        self.reader.get_data_field::<u16>(4) == 0
+      }
+      /// Whether any field of this group differs from its default. This reads each
+      /// field of the group, but does not follow pointers: a field that is set to an
+      /// empty struct or list counts as present.
+      #[inline]
+      pub fn has_unconstrained(&self) -> bool {
+        if self.reader.get_data_field::<u16>(4) != 0 { return false; }
+        self.reader.get_data_field::<u16>(5) != 0
+      }
+      #[inline]
+      pub fn is_parameter(&self) -> bool {
+        self.reader.get_data_field::<u16>(4) == 1
+      }
+      /// Whether any field of this group differs from its default. This reads each
+      /// field of the group, but does not follow pointers: a field that is set to an
+      /// empty struct or list counts as present.
+      #[inline]
+      pub fn has_parameter(&self) -> bool {
+        if self.reader.get_data_field::<u16>(4) != 1 { return false; }
+        self.reader.get_data_field::<u64>(2) != 0
+          || self.reader.get_data_field::<u16>(5) != 0
+      }
+      #[inline]
+      pub fn is_implicit_method_parameter(&self) -> bool {
+        self.reader.get_data_field::<u16>(4) == 2
+      }
+      /// Whether any field of this group differs from its default. This reads each
+      /// field of the group, but does not follow pointers: a field that is set to an
+      /// empty struct or list counts as present.
+      #[inline]
+      pub fn has_implicit_method_parameter(&self) -> bool {
+        if self.reader.get_data_field::<u16>(4) != 2 { return false; }
+        self.reader.get_data_field::<u16>(5) != 0
+      }
+      #[inline]
+      pub fn which(self) -> ::core::result::Result<WhichReader<'a,>, crate::NotInSchema> {
+        match self.reader.get_data_field::<u16>(4) {
+          0 => {
+            ::core::result::Result::Ok(Which::Unconstrained(
+              self.reader.into()
+            ))
+          }
+          1 => {
+            ::core::result::Result::Ok(Which::Parameter(
+              self.reader.into()
+            ))
+          }
+          2 => {
+            ::core::result::Result::Ok(Which::ImplicitMethodParameter(
+              self.reader.into()
+            ))
+          }
+          x => ::core::result::Result::Err(crate::NotInSchema(x))
+        }
+      }
+    }
+
+    pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+    impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 3, pointers: 1 };
+    }
+    impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+        self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn init_unconstrained(self, ) -> crate::schema_capnp::type_::any_pointer::unconstrained::Builder<'a> {
+        self.builder.set_data_field::<u16>(4, 0);
+        self.builder.set_data_field::<u16>(5, 0);
+        self.builder.into()
+      }
+      #[inline]
+      pub fn is_unconstrained(&self) -> bool {
+        self.builder.get_data_field::<u16>(4) == 0
+      }
+      /// Whether any field of this group differs from its default. This reads each
+      /// field of the group, but does not follow pointers: a field that is set to an
+      /// empty struct or list counts as present.
+      #[inline]
+      pub fn has_unconstrained(&self) -> bool {
+        if self.builder.get_data_field::<u16>(4) != 0 { return false; }
+        self.builder.get_data_field::<u16>(5) != 0
+      }
+      #[inline]
+      pub fn init_parameter(self, ) -> crate::schema_capnp::type_::any_pointer::parameter::Builder<'a> {
+        self.builder.set_data_field::<u16>(4, 1);
+        self.builder.set_data_field::<u64>(2, 0u64);
+        self.builder.set_data_field::<u16>(5, 0u16);
+        self.builder.into()
+      }
+      #[inline]
+      pub fn is_parameter(&self) -> bool {
+        self.builder.get_data_field::<u16>(4) == 1
+      }
+      /// Whether any field of this group differs from its default. This reads each
+      /// field of the group, but does not follow pointers: a field that is set to an
+      /// empty struct or list counts as present.
+      #[inline]
+      pub fn has_parameter(&self) -> bool {
+        if self.builder.get_data_field::<u16>(4) != 1 { return false; }
+        self.builder.get_data_field::<u64>(2) != 0
+          || self.builder.get_data_field::<u16>(5) != 0
+      }
+      #[inline]
+      pub fn init_implicit_method_parameter(self, ) -> crate::schema_capnp::type_::any_pointer::implicit_method_parameter::Builder<'a> {
+        self.builder.set_data_field::<u16>(4, 2);
+        self.builder.set_data_field::<u16>(5, 0u16);
+        self.builder.into()
+      }
+      #[inline]
+      pub fn is_implicit_method_parameter(&self) -> bool {
+        self.builder.get_data_field::<u16>(4) == 2
+      }
+      /// Whether any field of this group differs from its default. This reads each
+      /// field of the group, but does not follow pointers: a field that is set to an
+      /// empty struct or list counts as present.
+      #[inline]
+      pub fn has_implicit_method_parameter(&self) -> bool {
+        if self.builder.get_data_field::<u16>(4) != 2 { return false; }
+        self.builder.get_data_field::<u16>(5) != 0
+      }
+      #[inline]
+      pub fn which(self) -> ::core::result::Result<WhichBuilder<'a,>, crate::NotInSchema> {
+        match self.builder.get_data_field::<u16>(4) {
+          0 => {
+            ::core::result::Result::Ok(Which::Unconstrained(
+              self.builder.into()
+            ))
+          }
+          1 => {
+            ::core::result::Result::Ok(Which::Parameter(
+              self.builder.into()
+            ))
+          }
+          2 => {
+            ::core::result::Result::Ok(Which::ImplicitMethodParameter(
+              self.builder.into()
+            ))
+          }
+          x => ::core::result::Result::Err(crate::NotInSchema(x))
+        }
+      }
+      /// Sets the union to the variant that is active in `other`, copied from there as stored.
+      /// The fields outside the union are left unchanged.
+      ///
+      /// Returns an `EnumValueOrUnionDiscriminantNotPresent` error without changing anything if
+      /// `other` has a variant that is not in the schema, for example one added by a newer version
+      /// of it. A union nested in a group of the active variant is handled the same way, but by
+      /// then the group has been partially copied.
+      pub fn copy_union_from(&mut self, other: Reader<'_,>) -> crate::Result<()> {
+        match other.reader.get_data_field::<u16>(4) {
+          0 => {
+            self.reborrow().init_unconstrained();
+            let discriminant = other.reader.get_data_field::<u16>(5);
+            self.builder.set_data_field::<u16>(5, discriminant);
+            match discriminant {
+              0 => {
+              }
+              1 => {
+              }
+              2 => {
+              }
+              3 => {
+              }
+              x => return ::core::result::Result::Err(crate::NotInSchema(x).into()),
+            }
+          }
+          1 => {
+            self.reborrow().init_parameter();
+            self.builder.set_data_field::<u64>(2, other.reader.get_data_field::<u64>(2));
+            self.builder.set_data_field::<u16>(5, other.reader.get_data_field::<u16>(5));
+          }
+          2 => {
+            self.reborrow().init_implicit_method_parameter();
+            self.builder.set_data_field::<u16>(5, other.reader.get_data_field::<u16>(5));
+          }
+          x => return ::core::result::Result::Err(crate::NotInSchema(x).into()),
+        }
+        ::core::result::Result::Ok(())
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+    impl crate::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+      let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [crate::Word; 45] = [
+        crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+        crate::word(241, 73, 62, 162, 232, 63, 87, 194),
+        crate::word(18, 0, 0, 0, 1, 0, 3, 0),
+        crate::word(96, 204, 249, 225, 237, 120, 115, 208),
+        crate::word(1, 0, 7, 0, 1, 0, 3, 0),
+        crate::word(4, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 234, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(25, 0, 0, 0, 175, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+        crate::word(97, 112, 110, 112, 58, 84, 121, 112),
+        crate::word(101, 46, 97, 110, 121, 80, 111, 105),
+        crate::word(110, 116, 101, 114, 0, 0, 0, 0),
+        crate::word(12, 0, 0, 0, 3, 0, 4, 0),
+        crate::word(0, 0, 255, 255, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(86, 54, 89, 254, 121, 95, 59, 142),
+        crate::word(69, 0, 0, 0, 114, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(1, 0, 254, 255, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(133, 74, 97, 244, 36, 247, 209, 157),
+        crate::word(49, 0, 0, 0, 82, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(2, 0, 253, 255, 0, 0, 0, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(116, 226, 86, 12, 18, 201, 239, 186),
+        crate::word(29, 0, 0, 0, 194, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(117, 110, 99, 111, 110, 115, 116, 114),
+        crate::word(97, 105, 110, 101, 100, 0, 0, 0),
+        crate::word(112, 97, 114, 97, 109, 101, 116, 101),
+        crate::word(114, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(105, 109, 112, 108, 105, 99, 105, 116),
+        crate::word(77, 101, 116, 104, 111, 100, 80, 97),
+        crate::word(114, 97, 109, 101, 116, 101, 114, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "schema.capnp:Type.anyPointer";
+      pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        match index {
+          0 => <crate::schema_capnp::type_::any_pointer::unconstrained::Owned as crate::introspect::Introspect>::introspect(),
+          1 => <crate::schema_capnp::type_::any_pointer::parameter::Owned as crate::introspect::Introspect>::introspect(),
+          2 => <crate::schema_capnp::type_::any_pointer::implicit_method_parameter::Owned as crate::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[0,1,2];
+      pub mod layout {
+        pub const DISCRIMINANT_OFFSET: usize = 8;
+        pub const FIELD_UNCONSTRAINED_DISCRIMINANT: u16 = 0;
+        pub const FIELD_PARAMETER_DISCRIMINANT: u16 = 1;
+        pub const FIELD_IMPLICIT_METHOD_PARAMETER_DISCRIMINANT: u16 = 2;
+      }
+      pub const TYPE_ID: u64 = 0xc257_3fe8_a23e_49f1;
+    }
+    pub enum Which<A0,A1,A2> {
+      Unconstrained(A0),
+      Parameter(A1),
+      ImplicitMethodParameter(A2),
+    }
+    pub type WhichReader<'a,> = Which<crate::schema_capnp::type_::any_pointer::unconstrained::Reader<'a>,crate::schema_capnp::type_::any_pointer::parameter::Reader<'a>,crate::schema_capnp::type_::any_pointer::implicit_method_parameter::Reader<'a>>;
+    pub type WhichBuilder<'a,> = Which<crate::schema_capnp::type_::any_pointer::unconstrained::Builder<'a>,crate::schema_capnp::type_::any_pointer::parameter::Builder<'a>,crate::schema_capnp::type_::any_pointer::implicit_method_parameter::Builder<'a>>;
+
+    pub mod unconstrained {
+    
+    impl Unconstrained {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+ match self {
+ Unconstrained::UNINITIALIZED => (),
+ Unconstrained::_AnyKind(t) => builder.reborrow().set_any_kind(t),
+ Unconstrained::_Struct(t) => builder.reborrow().set_struct(t),
+ Unconstrained::_List(t) => builder.reborrow().set_list(t),
+ Unconstrained::_Capability(t) => builder.reborrow().set_capability(t),
+ }
+  ::core::result::Result::Ok(())
+}}
+    pub enum Unconstrained {
+ UNINITIALIZED,
+ _AnyKind(()),
+ _Struct(()),
+ _List(()),
+ _Capability(()),
+}
+    impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Unconstrained  {
+      type Error = crate::Error;
+      fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(match reader.which()? {
+          Which::AnyKind(value) => Unconstrained::_AnyKind(value),
+          Which::Struct(value) => Unconstrained::_Struct(value),
+          Which::List(value) => Unconstrained::_List(value),
+          Which::Capability(value) => Unconstrained::_Capability(value),
+        })
+      }
+    }
+    impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Unconstrained {
+      type Error = crate::Error;
+      fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+        <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+      }
+    }
+    impl Unconstrained {
+      /// Writes the value into `builder`, which must be a struct of this type.
+      pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+        self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+      }
+    }
+      pub use self::Which::{AnyKind,Struct,List,Capability};
+
+      /// The members of the union, as `(discriminant, name, field index)` in discriminant order.
+      pub static UNION_MEMBERS: &[(u16, &str, u16)] = &[(0, "anyKind", 0), (1, "struct", 1), (2, "list", 2), (3, "capability", 3)];
+      /// Returns the schema name of the union member with discriminant `d`.
+      pub fn discriminant_name(d: u16) -> ::core::option::Option<&'static str> {
+        UNION_MEMBERS.get(d as usize).map(|member| member.1)
+      }
+
+      /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+      ///
+      /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+      /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+      /// the pointer to them. Objects that list elements point to are not included.
+      #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+      pub struct FieldCounts {
+      }
+      impl FieldCounts {
+        /// Zero for all fields except structs, which get the size of a struct with no content.
+        pub const fn new() -> Self {
+          Self {
+          }
+        }
+      }
+      impl ::core::default::Default for FieldCounts {
+        fn default() -> Self { Self::new() }
+      }
+      /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+      pub const fn variable_words_for(_counts: FieldCounts) -> usize { 0 }
+      #[derive(Copy, Clone)]
+      pub struct Owned(());
+      impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+      impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+      impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+      impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+      impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+      pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+      impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+      impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+        fn clone(&self) -> Self { *self }
+      }
+
+      impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+        const TYPE_ID: u64 = _private::TYPE_ID;
+      }
+      impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+        fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+          Self { reader,  }
+        }
+      }
+
+      impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+        fn from(reader: Reader<'a,>) -> Self {
+          Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+        }
+      }
+
+      impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+          core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+        }
+      }
+
+      impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+        fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+          ::core::result::Result::Ok(reader.get_struct(default)?.into())
+        }
+      }
+
+      impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+        fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+          self.reader
+        }
+      }
+
+      impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+        fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+          self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+        }
+      }
+
+      impl <'a,> Reader<'a,>  {
+        pub fn reborrow(&self) -> Reader<'_,> {
+          Self { .. *self }
+        }
+
+        pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+          self.reader.total_size()
+        }
+
+        /// The number of words that serializing a message with a copy of this struct as its
+        /// root takes, including the segment table.
+        pub fn serialized_words(&self) -> crate::Result<usize> {
+          self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+        }
+
+        /// Views this struct through the dynamic API.
+        pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+          ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+        }
+        #[inline]
+        pub fn is_any_kind(&self) -> bool {
+          self.reader.get_data_field::<u16>(5) == 0
+        }
+        #[inline]
+        pub fn is_struct(&self) -> bool {
+          self.reader.get_data_field::<u16>(5) == 1
+        }
+        #[inline]
+        pub fn is_list(&self) -> bool {
+          self.reader.get_data_field::<u16>(5) == 2
+        }
+        #[inline]
+        pub fn is_capability(&self) -> bool {
+          self.reader.get_data_field::<u16>(5) == 3
+        }
+        #[inline]
+        pub fn which(self) -> ::core::result::Result<WhichReader, crate::NotInSchema> {
+          match self.reader.get_data_field::<u16>(5) {
+            0 => {
+              ::core::result::Result::Ok(Which::AnyKind(
+                ()
+              ))
+            }
+            1 => {
+              ::core::result::Result::Ok(Which::Struct(
+                ()
+              ))
+            }
+            2 => {
+              ::core::result::Result::Ok(Which::List(
+                ()
+              ))
+            }
+            3 => {
+              ::core::result::Result::Ok(Which::Capability(
+                ()
+              ))
+            }
+            x => ::core::result::Result::Err(crate::NotInSchema(x))
+          }
+        }
+      }
+
+      pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+      impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+        const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 3, pointers: 1 };
+      }
+      impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+        const TYPE_ID: u64 = _private::TYPE_ID;
+      }
+      impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+        fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+          Self { builder,  }
+        }
+      }
+
+      impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+        fn from(builder: Builder<'a,>) -> Self {
+          Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+        }
+      }
+
+      impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+        fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+          self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+        }
+      }
+
+      impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+        fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+          builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+        }
+        fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+          ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+        }
+        fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+          ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+        }
+      }
+
+      impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+        fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+      }
+
+      impl <'a,> Builder<'a,>  {
+        pub fn into_reader(self) -> Reader<'a,> {
+          self.builder.into_reader().into()
+        }
+        pub fn reborrow(&mut self) -> Builder<'_,> {
+          Builder { builder: self.builder.reborrow() }
+        }
+        pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+          self.builder.as_reader().into()
+        }
+
+        pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+          self.builder.as_reader().total_size()
+        }
+
+        /// The number of words that serializing a message with a copy of this struct as its
+        /// root takes, including the segment table.
+        pub fn serialized_words(&self) -> crate::Result<usize> {
+          self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+        }
+
+        /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+        pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+          ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+        }
+
+        /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+        /// Fails with `TypeMismatch`, naming both types, if it is not.
+        pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+          let src_proto = src.get_schema().get_proto();
+          if src_proto.get_id() != _private::TYPE_ID {
+            let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+            ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+            return ::core::result::Result::Err(error);
+          }
+          let src: Reader<'_,> = src.downcast()?;
+          self.builder.copy_content_from(&src.reader)
+        }
+        #[inline]
+        pub fn set_any_kind(&mut self, _value: ())  {
+          self.builder.set_data_field::<u16>(5, 0);
+        }
+        #[inline]
+        pub fn is_any_kind(&self) -> bool {
+          self.builder.get_data_field::<u16>(5) == 0
+        }
+        #[inline]
+        pub fn set_struct(&mut self, _value: ())  {
+          self.builder.set_data_field::<u16>(5, 1);
+        }
+        #[inline]
+        pub fn is_struct(&self) -> bool {
+          self.builder.get_data_field::<u16>(5) == 1
+        }
+        #[inline]
+        pub fn set_list(&mut self, _value: ())  {
+          self.builder.set_data_field::<u16>(5, 2);
+        }
+        #[inline]
+        pub fn is_list(&self) -> bool {
+          self.builder.get_data_field::<u16>(5) == 2
+        }
+        #[inline]
+        pub fn set_capability(&mut self, _value: ())  {
+          self.builder.set_data_field::<u16>(5, 3);
+        }
+        #[inline]
+        pub fn is_capability(&self) -> bool {
+          self.builder.get_data_field::<u16>(5) == 3
+        }
+        #[inline]
+        pub fn which(self) -> ::core::result::Result<WhichBuilder, crate::NotInSchema> {
+          match self.builder.get_data_field::<u16>(5) {
+            0 => {
+              ::core::result::Result::Ok(Which::AnyKind(
+                ()
+              ))
+            }
+            1 => {
+              ::core::result::Result::Ok(Which::Struct(
+                ()
+              ))
+            }
+            2 => {
+              ::core::result::Result::Ok(Which::List(
+                ()
+              ))
+            }
+            3 => {
+              ::core::result::Result::Ok(Which::Capability(
+                ()
+              ))
+            }
+            x => ::core::result::Result::Err(crate::NotInSchema(x))
+          }
+        }
+        /// Sets the union to the variant that is active in `other`, copied from there as stored.
+        /// The fields outside the union are left unchanged.
+        ///
+        /// Returns an `EnumValueOrUnionDiscriminantNotPresent` error without changing anything if
+        /// `other` has a variant that is not in the schema, for example one added by a newer version
+        /// of it. A union nested in a group of the active variant is handled the same way, but by
+        /// then the group has been partially copied.
+        pub fn copy_union_from(&mut self, other: Reader<'_,>) -> crate::Result<()> {
+          match other.reader.get_data_field::<u16>(5) {
+            0 => {
+              self.builder.set_data_field::<u16>(5, 0);
+            }
+            1 => {
+              self.builder.set_data_field::<u16>(5, 1);
+            }
+            2 => {
+              self.builder.set_data_field::<u16>(5, 2);
+            }
+            3 => {
+              self.builder.set_data_field::<u16>(5, 3);
+            }
+            x => return ::core::result::Result::Err(crate::NotInSchema(x).into()),
+          }
+          ::core::result::Result::Ok(())
+        }
+      }
+
+      impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+          ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+        }
+      }
+
+      pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+      impl crate::capability::FromTypelessPipeline for Pipeline {
+        fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+          Self { _typeless: typeless,  }
+        }
+      }
+      impl ::core::fmt::Debug for Pipeline {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+          f.write_str(::core::any::type_name::<Self>())
+        }
+      }
+      impl Pipeline  {
+      }
+      /// The `(field_index, annotation_id)` pair of each annotation on a field.
+      pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+      ];
+      /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+      /// it has one.
+      pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+        let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+        if field_index >= children.len() {
+          return ::core::option::Option::None;
+        }
+        children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+      }
+      pub mod _private {
+        pub static ENCODED_NODE: [crate::Word; 80] = [
+          crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+          crate::word(86, 54, 89, 254, 121, 95, 59, 142),
+          crate::word(29, 0, 0, 0, 1, 0, 3, 0),
+          crate::word(241, 73, 62, 162, 232, 63, 87, 194),
+          crate::word(1, 0, 7, 0, 1, 0, 4, 0),
+          crate::word(5, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(21, 0, 0, 0, 90, 1, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(33, 0, 0, 0, 231, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+          crate::word(97, 112, 110, 112, 58, 84, 121, 112),
+          crate::word(101, 46, 97, 110, 121, 80, 111, 105),
+          crate::word(110, 116, 101, 114, 46, 117, 110, 99),
+          crate::word(111, 110, 115, 116, 114, 97, 105, 110),
+          crate::word(101, 100, 0, 0, 0, 0, 0, 0),
+          crate::word(16, 0, 0, 0, 3, 0, 4, 0),
+          crate::word(0, 0, 255, 255, 0, 0, 0, 0),
+          crate::word(0, 0, 1, 0, 18, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(97, 0, 0, 0, 66, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(92, 0, 0, 0, 3, 0, 1, 0),
+          crate::word(104, 0, 0, 0, 2, 0, 1, 0),
+          crate::word(1, 0, 254, 255, 0, 0, 0, 0),
+          crate::word(0, 0, 1, 0, 25, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(101, 0, 0, 0, 58, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(96, 0, 0, 0, 3, 0, 1, 0),
+          crate::word(108, 0, 0, 0, 2, 0, 1, 0),
+          crate::word(2, 0, 253, 255, 0, 0, 0, 0),
+          crate::word(0, 0, 1, 0, 26, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(105, 0, 0, 0, 42, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(100, 0, 0, 0, 3, 0, 1, 0),
+          crate::word(112, 0, 0, 0, 2, 0, 1, 0),
+          crate::word(3, 0, 252, 255, 0, 0, 0, 0),
+          crate::word(0, 0, 1, 0, 27, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(109, 0, 0, 0, 90, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(108, 0, 0, 0, 3, 0, 1, 0),
+          crate::word(120, 0, 0, 0, 2, 0, 1, 0),
+          crate::word(97, 110, 121, 75, 105, 110, 100, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(115, 116, 114, 117, 99, 116, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(108, 105, 115, 116, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(99, 97, 112, 97, 98, 105, 108, 105),
+          crate::word(116, 121, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ];
+        pub const DISPLAY_NAME: &str = "schema.capnp:Type.anyPointer.unconstrained";
+        pub fn get_field_types(index: u16) -> crate::introspect::Type {
+          match index {
+            0 => <() as crate::introspect::Introspect>::introspect(),
+            1 => <() as crate::introspect::Introspect>::introspect(),
+            2 => <() as crate::introspect::Introspect>::introspect(),
+            3 => <() as crate::introspect::Introspect>::introspect(),
+            _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+          }
+        }
+        pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+          panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+        }
+        pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+          encoded_node: &ENCODED_NODE,
+          nonunion_members: NONUNION_MEMBERS,
+          members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+        };
+        pub static NONUNION_MEMBERS : &[u16] = &[];
+        pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[0,1,2,3];
+        pub mod layout {
+          pub const DISCRIMINANT_OFFSET: usize = 10;
+          pub const FIELD_ANY_KIND_DISCRIMINANT: u16 = 0;
+          pub const FIELD_STRUCT_DISCRIMINANT: u16 = 1;
+          pub const FIELD_LIST_DISCRIMINANT: u16 = 2;
+          pub const FIELD_CAPABILITY_DISCRIMINANT: u16 = 3;
+        }
+        pub const TYPE_ID: u64 = 0x8e3b_5f79_fe59_3656;
+      }
+      pub enum Which {
+        AnyKind(()),
+        Struct(()),
+        List(()),
+        Capability(()),
+      }
+      pub type WhichReader = Which;
+      pub type WhichBuilder = Which;
+    }
+
+    pub mod parameter {
+    pub struct Parameter {
+ pub _scope_id: u64,
+ pub _parameter_index: u16,  
+}
+    impl Parameter {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+  builder.set_scope_id(self._scope_id);
+  builder.set_parameter_index(self._parameter_index);
+  ::core::result::Result::Ok(())
+}}
+    
+    impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Parameter  {
+      type Error = crate::Error;
+      fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(Self {
+          _scope_id: reader.get_scope_id(),
+          _parameter_index: reader.get_parameter_index(),
+        })
+      }
+    }
+    impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Parameter {
+      type Error = crate::Error;
+      fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+        <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+      }
+    }
+    impl Parameter {
+      /// Writes the value into `builder`, which must be a struct of this type.
+      pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+        self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+      }
+    }
+      /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+      ///
+      /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+      /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+      /// the pointer to them. Objects that list elements point to are not included.
+      #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+      pub struct FieldCounts {
+      }
+      impl FieldCounts {
+        /// Zero for all fields except structs, which get the size of a struct with no content.
+        pub const fn new() -> Self {
+          Self {
+          }
+        }
+      }
+      impl ::core::default::Default for FieldCounts {
+        fn default() -> Self { Self::new() }
+      }
+      /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+      pub const fn variable_words_for(_counts: FieldCounts) -> usize { 0 }
+      #[derive(Copy, Clone)]
+      pub struct Owned(());
+      impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+      impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+      impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+      impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+      impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+      pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+      impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+      impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+        fn clone(&self) -> Self { *self }
+      }
+
+      impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+        const TYPE_ID: u64 = _private::TYPE_ID;
+      }
+      impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+        fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+          Self { reader,  }
+        }
+      }
+
+      impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+        fn from(reader: Reader<'a,>) -> Self {
+          Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+        }
+      }
+
+      impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+          core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+        }
+      }
+
+      impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+        fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+          ::core::result::Result::Ok(reader.get_struct(default)?.into())
+        }
+      }
+
+      impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+        fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+          self.reader
+        }
+      }
+
+      impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+        fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+          self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+        }
+      }
+
+      impl <'a,> Reader<'a,>  {
+        pub fn reborrow(&self) -> Reader<'_,> {
+          Self { .. *self }
+        }
+
+        pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+          self.reader.total_size()
+        }
+
+        /// The number of words that serializing a message with a copy of this struct as its
+        /// root takes, including the segment table.
+        pub fn serialized_words(&self) -> crate::Result<usize> {
+          self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+        }
+
+        /// Views this struct through the dynamic API.
+        pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+          ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+        }
+        #[inline]
+        pub fn get_scope_id(self) -> u64 {
+          self.reader.get_data_field::<u64>(2)
+        }
+        #[inline]
+        pub fn get_parameter_index(self) -> u16 {
+          self.reader.get_data_field::<u16>(5)
+        }
+      }
+
+      pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+      impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+        const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 3, pointers: 1 };
+      }
+      impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+        const TYPE_ID: u64 = _private::TYPE_ID;
+      }
+      impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+        fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+          Self { builder,  }
+        }
+      }
+
+      impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+        fn from(builder: Builder<'a,>) -> Self {
+          Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+        }
+      }
+
+      impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+        fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+          self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+        }
+      }
+
+      impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+        fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+          builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+        }
+        fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+          ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+        }
+        fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+          ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+        }
+      }
+
+      impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+        fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+      }
+
+      impl <'a,> Builder<'a,>  {
+        pub fn into_reader(self) -> Reader<'a,> {
+          self.builder.into_reader().into()
+        }
+        pub fn reborrow(&mut self) -> Builder<'_,> {
+          Builder { builder: self.builder.reborrow() }
+        }
+        pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+          self.builder.as_reader().into()
+        }
+
+        pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+          self.builder.as_reader().total_size()
+        }
+
+        /// The number of words that serializing a message with a copy of this struct as its
+        /// root takes, including the segment table.
+        pub fn serialized_words(&self) -> crate::Result<usize> {
+          self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+        }
+
+        /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+        pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+          ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+        }
+
+        /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+        /// Fails with `TypeMismatch`, naming both types, if it is not.
+        pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+          let src_proto = src.get_schema().get_proto();
+          if src_proto.get_id() != _private::TYPE_ID {
+            let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+            ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+            return ::core::result::Result::Err(error);
+          }
+          let src: Reader<'_,> = src.downcast()?;
+          self.builder.copy_content_from(&src.reader)
+        }
+        #[inline]
+        pub fn get_scope_id(self) -> u64 {
+          self.builder.get_data_field::<u64>(2)
+        }
+        #[inline]
+        pub fn set_scope_id(&mut self, value: u64)  {
+          self.builder.set_data_field::<u64>(2, value);
+        }
+        #[inline]
+        pub fn set_scope_id_from(&mut self, value: impl ::core::convert::TryInto<u64>) -> crate::Result<()> {
+          let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+          self.set_scope_id(value);
+          ::core::result::Result::Ok(())
+        }
+        #[inline]
+        pub fn get_parameter_index(self) -> u16 {
+          self.builder.get_data_field::<u16>(5)
+        }
+        #[inline]
+        pub fn set_parameter_index(&mut self, value: u16)  {
+          self.builder.set_data_field::<u16>(5, value);
+        }
+        #[inline]
+        pub fn set_parameter_index_from(&mut self, value: impl ::core::convert::TryInto<u16>) -> crate::Result<()> {
+          let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+          self.set_parameter_index(value);
+          ::core::result::Result::Ok(())
+        }
+      }
+
+      impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+          ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+        }
+      }
+
+      pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+      impl crate::capability::FromTypelessPipeline for Pipeline {
+        fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+          Self { _typeless: typeless,  }
+        }
+      }
+      impl ::core::fmt::Debug for Pipeline {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+          f.write_str(::core::any::type_name::<Self>())
+        }
+      }
+      impl Pipeline  {
+      }
+      /// The `(field_index, annotation_id)` pair of each annotation on a field.
+      pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+      ];
+      /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+      /// it has one.
+      pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+        let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+        if field_index >= children.len() {
+          return ::core::option::Option::None;
+        }
+        children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+      }
+      pub mod _private {
+        pub static ENCODED_NODE: [crate::Word; 49] = [
+          crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+          crate::word(133, 74, 97, 244, 36, 247, 209, 157),
+          crate::word(29, 0, 0, 0, 1, 0, 3, 0),
+          crate::word(241, 73, 62, 162, 232, 63, 87, 194),
+          crate::word(1, 0, 7, 0, 1, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(21, 0, 0, 0, 58, 1, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(29, 0, 0, 0, 119, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+          crate::word(97, 112, 110, 112, 58, 84, 121, 112),
+          crate::word(101, 46, 97, 110, 121, 80, 111, 105),
+          crate::word(110, 116, 101, 114, 46, 112, 97, 114),
+          crate::word(97, 109, 101, 116, 101, 114, 0, 0),
+          crate::word(8, 0, 0, 0, 3, 0, 4, 0),
+          crate::word(0, 0, 0, 0, 2, 0, 0, 0),
+          crate::word(0, 0, 1, 0, 19, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(41, 0, 0, 0, 66, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(36, 0, 0, 0, 3, 0, 1, 0),
+          crate::word(48, 0, 0, 0, 2, 0, 1, 0),
+          crate::word(1, 0, 0, 0, 5, 0, 0, 0),
+          crate::word(0, 0, 1, 0, 20, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(45, 0, 0, 0, 122, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(44, 0, 0, 0, 3, 0, 1, 0),
+          crate::word(56, 0, 0, 0, 2, 0, 1, 0),
+          crate::word(115, 99, 111, 112, 101, 73, 100, 0),
+          crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(112, 97, 114, 97, 109, 101, 116, 101),
+          crate::word(114, 73, 110, 100, 101, 120, 0, 0),
+          crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ];
+        pub const DISPLAY_NAME: &str = "schema.capnp:Type.anyPointer.parameter";
+        pub fn get_field_types(index: u16) -> crate::introspect::Type {
+          match index {
+            0 => <u64 as crate::introspect::Introspect>::introspect(),
+            1 => <u16 as crate::introspect::Introspect>::introspect(),
+            _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+          }
+        }
+        pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+          panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+        }
+        pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+          encoded_node: &ENCODED_NODE,
+          nonunion_members: NONUNION_MEMBERS,
+          members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+        };
+        pub static NONUNION_MEMBERS : &[u16] = &[0,1];
+        pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+        pub mod layout {
+          pub const FIELD_SCOPE_ID_OFFSET: usize = 16;
+          pub const FIELD_PARAMETER_INDEX_OFFSET: usize = 10;
+        }
+        pub const TYPE_ID: u64 = 0x9dd1_f724_f461_4a85;
+      }
+    }
+
+    pub mod implicit_method_parameter {
+    pub struct ImplicitMethodParameter {
+ pub _parameter_index: u16,  
+}
+    impl ImplicitMethodParameter {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+  builder.set_parameter_index(self._parameter_index);
+  ::core::result::Result::Ok(())
+}}
+    
+    impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for ImplicitMethodParameter  {
+      type Error = crate::Error;
+      fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(Self {
+          _parameter_index: reader.get_parameter_index(),
+        })
+      }
+    }
+    impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for ImplicitMethodParameter {
+      type Error = crate::Error;
+      fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+        <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+      }
+    }
+    impl ImplicitMethodParameter {
+      /// Writes the value into `builder`, which must be a struct of this type.
+      pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+        self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+      }
+    }
+      /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+      ///
+      /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+      /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+      /// the pointer to them. Objects that list elements point to are not included.
+      #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+      pub struct FieldCounts {
+      }
+      impl FieldCounts {
+        /// Zero for all fields except structs, which get the size of a struct with no content.
+        pub const fn new() -> Self {
+          Self {
+          }
+        }
+      }
+      impl ::core::default::Default for FieldCounts {
+        fn default() -> Self { Self::new() }
+      }
+      /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+      pub const fn variable_words_for(_counts: FieldCounts) -> usize { 0 }
+      #[derive(Copy, Clone)]
+      pub struct Owned(());
+      impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+      impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+      impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+      impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+      impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+      pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+      impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+      impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+        fn clone(&self) -> Self { *self }
+      }
+
+      impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+        const TYPE_ID: u64 = _private::TYPE_ID;
+      }
+      impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+        fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+          Self { reader,  }
+        }
+      }
+
+      impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+        fn from(reader: Reader<'a,>) -> Self {
+          Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+        }
+      }
+
+      impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+          core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+        }
+      }
+
+      impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+        fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+          ::core::result::Result::Ok(reader.get_struct(default)?.into())
+        }
+      }
+
+      impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+        fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+          self.reader
+        }
+      }
+
+      impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+        fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+          self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+        }
+      }
+
+      impl <'a,> Reader<'a,>  {
+        pub fn reborrow(&self) -> Reader<'_,> {
+          Self { .. *self }
+        }
+
+        pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+          self.reader.total_size()
+        }
+
+        /// The number of words that serializing a message with a copy of this struct as its
+        /// root takes, including the segment table.
+        pub fn serialized_words(&self) -> crate::Result<usize> {
+          self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+        }
+
+        /// Views this struct through the dynamic API.
+        pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+          ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+        }
+        #[inline]
+        pub fn get_parameter_index(self) -> u16 {
+          self.reader.get_data_field::<u16>(5)
+        }
+      }
+
+      pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+      impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+        const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 3, pointers: 1 };
+      }
+      impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+        const TYPE_ID: u64 = _private::TYPE_ID;
+      }
+      impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+        fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+          Self { builder,  }
+        }
+      }
+
+      impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+        fn from(builder: Builder<'a,>) -> Self {
+          Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+        }
+      }
+
+      impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+        fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+          self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+        }
+      }
+
+      impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+        fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+          builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+        }
+        fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+          ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+        }
+        fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+          ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+        }
+      }
+
+      impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+        fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+      }
+
+      impl <'a,> Builder<'a,>  {
+        pub fn into_reader(self) -> Reader<'a,> {
+          self.builder.into_reader().into()
+        }
+        pub fn reborrow(&mut self) -> Builder<'_,> {
+          Builder { builder: self.builder.reborrow() }
+        }
+        pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+          self.builder.as_reader().into()
+        }
+
+        pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+          self.builder.as_reader().total_size()
+        }
+
+        /// The number of words that serializing a message with a copy of this struct as its
+        /// root takes, including the segment table.
+        pub fn serialized_words(&self) -> crate::Result<usize> {
+          self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+        }
+
+        /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+        pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+          ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+        }
+
+        /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+        /// Fails with `TypeMismatch`, naming both types, if it is not.
+        pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+          let src_proto = src.get_schema().get_proto();
+          if src_proto.get_id() != _private::TYPE_ID {
+            let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+            ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+            return ::core::result::Result::Err(error);
+          }
+          let src: Reader<'_,> = src.downcast()?;
+          self.builder.copy_content_from(&src.reader)
+        }
+        #[inline]
+        pub fn get_parameter_index(self) -> u16 {
+          self.builder.get_data_field::<u16>(5)
+        }
+        #[inline]
+        pub fn set_parameter_index(&mut self, value: u16)  {
+          self.builder.set_data_field::<u16>(5, value);
+        }
+        #[inline]
+        pub fn set_parameter_index_from(&mut self, value: impl ::core::convert::TryInto<u16>) -> crate::Result<()> {
+          let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+          self.set_parameter_index(value);
+          ::core::result::Result::Ok(())
+        }
+      }
+
+      impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+          ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+        }
+      }
+
+      pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+      impl crate::capability::FromTypelessPipeline for Pipeline {
+        fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+          Self { _typeless: typeless,  }
+        }
+      }
+      impl ::core::fmt::Debug for Pipeline {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+          f.write_str(::core::any::type_name::<Self>())
+        }
+      }
+      impl Pipeline  {
+      }
+      /// The `(field_index, annotation_id)` pair of each annotation on a field.
+      pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+      ];
+      /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+      /// it has one.
+      pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+        let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+        if field_index >= children.len() {
+          return ::core::option::Option::None;
+        }
+        children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+      }
+      pub mod _private {
+        pub static ENCODED_NODE: [crate::Word; 36] = [
+          crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+          crate::word(116, 226, 86, 12, 18, 201, 239, 186),
+          crate::word(29, 0, 0, 0, 1, 0, 3, 0),
+          crate::word(241, 73, 62, 162, 232, 63, 87, 194),
+          crate::word(1, 0, 7, 0, 1, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(21, 0, 0, 0, 170, 1, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(37, 0, 0, 0, 63, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+          crate::word(97, 112, 110, 112, 58, 84, 121, 112),
+          crate::word(101, 46, 97, 110, 121, 80, 111, 105),
+          crate::word(110, 116, 101, 114, 46, 105, 109, 112),
+          crate::word(108, 105, 99, 105, 116, 77, 101, 116),
+          crate::word(104, 111, 100, 80, 97, 114, 97, 109),
+          crate::word(101, 116, 101, 114, 0, 0, 0, 0),
+          crate::word(4, 0, 0, 0, 3, 0, 4, 0),
+          crate::word(0, 0, 0, 0, 5, 0, 0, 0),
+          crate::word(0, 0, 1, 0, 24, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(13, 0, 0, 0, 122, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(12, 0, 0, 0, 3, 0, 1, 0),
+          crate::word(24, 0, 0, 0, 2, 0, 1, 0),
+          crate::word(112, 97, 114, 97, 109, 101, 116, 101),
+          crate::word(114, 73, 110, 100, 101, 120, 0, 0),
+          crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ];
+        pub const DISPLAY_NAME: &str = "schema.capnp:Type.anyPointer.implicitMethodParameter";
+        pub fn get_field_types(index: u16) -> crate::introspect::Type {
+          match index {
+            0 => <u16 as crate::introspect::Introspect>::introspect(),
+            _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+          }
+        }
+        pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+          panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+        }
+        pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+          encoded_node: &ENCODED_NODE,
+          nonunion_members: NONUNION_MEMBERS,
+          members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+        };
+        pub static NONUNION_MEMBERS : &[u16] = &[0];
+        pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+        pub mod layout {
+          pub const FIELD_PARAMETER_INDEX_OFFSET: usize = 10;
+        }
+        pub const TYPE_ID: u64 = 0xbaef_c912_0c56_e274;
+      }
+    }
+  }
+}
+
+pub mod brand {
+pub struct Brand {
+ pub _scopes: Vec<crate::schema_capnp::brand::scope::Scope>,  
+}
+impl Brand {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+            
+if self._scopes.len() > 0 {
+                let mut list_builder = builder.reborrow().init_scopes(crate::private::checked_len(self._scopes.len())?);
+                for (i, item) in self._scopes.into_iter().enumerate() {
+                    item.build_capnp_struct(list_builder.reborrow().get(i as u32))?;
+                }
+            }
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Brand  {
+  type Error = crate::Error;
+  fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _scopes: reader.get_scopes()?.iter().map(|item| crate::Result::Ok(<crate::schema_capnp::brand::scope::Scope as ::core::convert::TryFrom<_>>::try_from(item)?)).collect::<crate::Result<Vec<_>>>()?,
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Brand {
+  type Error = crate::Error;
+  fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl Brand {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub scopes: usize,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        scopes: 0,
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    1 + counts.scopes * (crate::schema_capnp::brand::scope::MIN_SIZE_WORDS - 1)
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 0 + 1 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+      self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> crate::Result<usize> {
+      self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_scopes(self) -> crate::Result<crate::struct_list::Reader<'a,crate::schema_capnp::brand::scope::Owned>> {
+      crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_scopes(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+  }
+
+  pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+  impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 0, pointers: 1 };
+  }
+  impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+      self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> crate::Result<usize> {
+      self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_scopes(self) -> crate::Result<crate::struct_list::Builder<'a,crate::schema_capnp::brand::scope::Owned>> {
+      crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_scopes(&mut self, value: crate::struct_list::Reader<'a,crate::schema_capnp::brand::scope::Owned>) -> crate::Result<()> {
+      crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_scopes(self, size: u32) -> crate::struct_list::Builder<'a,crate::schema_capnp::brand::scope::Owned> {
+      crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), size)
+    }
+    #[inline]
+    pub fn disown_scopes(&mut self) -> crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::brand::scope::Owned>> {
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_scopes(&mut self, orphan: crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::brand::scope::Owned>>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_scopes(&mut self, size: u32) -> crate::Result<crate::struct_list::Builder<'_,crate::schema_capnp::brand::scope::Owned>> {
+      if self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_scopes(size))
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_scopes(&self) -> bool {
+      !self.builder.is_pointer_field_null(0)
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+  impl crate::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+    let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [crate::Word; 42] = [
+      crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+      crate::word(43, 66, 101, 96, 240, 85, 52, 144),
+      crate::word(13, 0, 0, 0, 1, 0, 0, 0),
+      crate::word(217, 114, 76, 98, 9, 197, 63, 169),
+      crate::word(1, 0, 7, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(21, 0, 0, 0, 154, 0, 0, 0),
+      crate::word(29, 0, 0, 0, 39, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(49, 0, 0, 0, 63, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+      crate::word(97, 112, 110, 112, 58, 66, 114, 97),
+      crate::word(110, 100, 0, 0, 0, 0, 0, 0),
+      crate::word(8, 0, 0, 0, 1, 0, 1, 0),
+      crate::word(201, 107, 99, 169, 133, 52, 215, 171),
+      crate::word(9, 0, 0, 0, 50, 0, 0, 0),
+      crate::word(252, 231, 158, 150, 22, 205, 99, 200),
+      crate::word(5, 0, 0, 0, 66, 0, 0, 0),
+      crate::word(83, 99, 111, 112, 101, 0, 0, 0),
+      crate::word(66, 105, 110, 100, 105, 110, 103, 0),
+      crate::word(4, 0, 0, 0, 3, 0, 4, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(13, 0, 0, 0, 58, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(8, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(36, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(115, 99, 111, 112, 101, 115, 0, 0),
+      crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(201, 107, 99, 169, 133, 52, 215, 171),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "schema.capnp:Brand";
+    pub fn get_field_types(index: u16) -> crate::introspect::Type {
+      match index {
+        0 => <crate::struct_list::Owned<crate::schema_capnp::brand::scope::Owned> as crate::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_SCOPES_POINTER_INDEX: usize = 0;
+    }
+    pub const TYPE_ID: u64 = 0x9034_55f0_6065_422b;
+  }
+
+  pub mod scope {
+  pub struct Scope {
+   pub uni: ScopeUnion,
+ pub _scope_id: u64,  
+}
+  impl Scope {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+ match self.uni {
+ ScopeUnion::UNINITIALIZED => (),
+ ScopeUnion::_Bind(t) => {
+
+            
+if t.len() > 0 {
+                let mut list_builder = builder.reborrow().init_bind(crate::private::checked_len(t.len())?);
+                for (i, item) in t.into_iter().enumerate() {
+                    item.build_capnp_struct(list_builder.reborrow().get(i as u32))?;
+                }
+            }
+},
+ ScopeUnion::_Inherit(t) => builder.reborrow().set_inherit(t),
+ }
+  builder.set_scope_id(self._scope_id);
+  ::core::result::Result::Ok(())
+}}
+  pub enum ScopeUnion {
+ UNINITIALIZED,
+ _Bind(Vec<crate::schema_capnp::brand::binding::Binding>),
+ _Inherit(()),
+}
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Scope  {
+    type Error = crate::Error;
+    fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(Self {
+        _scope_id: reader.get_scope_id(),
+        uni: match reader.which()? {
+          Which::Bind(value) => ScopeUnion::_Bind(value?.iter().map(|item| crate::Result::Ok(<crate::schema_capnp::brand::binding::Binding as ::core::convert::TryFrom<_>>::try_from(item)?)).collect::<crate::Result<Vec<_>>>()?),
+          Which::Inherit(value) => ScopeUnion::_Inherit(value),
+        },
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Scope {
+    type Error = crate::Error;
+    fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl Scope {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    pub use self::Which::{Bind,Inherit};
+
+    /// The members of the union, as `(discriminant, name, field index)` in discriminant order.
+    pub static UNION_MEMBERS: &[(u16, &str, u16)] = &[(0, "bind", 1), (1, "inherit", 2)];
+    /// Returns the schema name of the union member with discriminant `d`.
+    pub fn discriminant_name(d: u16) -> ::core::option::Option<&'static str> {
+      UNION_MEMBERS.get(d as usize).map(|member| member.1)
+    }
+
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+      pub bind: usize,
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+          bind: 0,
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(counts: FieldCounts) -> usize {
+      1 + counts.bind * (crate::schema_capnp::brand::binding::MIN_SIZE_WORDS - 1)
+    }
+    /// Words taken up by a message holding just this struct: its data and pointer sections
+    /// plus the root pointer.
+    pub const MIN_SIZE_WORDS: usize = 2 + 1 + 1;
+    /// An upper bound on the words that a single-segment message holding this struct takes up.
+    pub const fn total_words_for(counts: FieldCounts) -> usize {
+      MIN_SIZE_WORDS + variable_words_for(counts)
+    }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+        self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn get_scope_id(self) -> u64 {
+        self.reader.get_data_field::<u64>(0)
+      }
+      #[inline]
+      pub fn is_bind(&self) -> bool {
+        self.reader.get_data_field::<u16>(4) == 0
+      }
+      #[inline]
+      pub fn has_bind(&self) -> bool {
+        if self.reader.get_data_field::<u16>(4) != 0 { return false; }
+        !self.reader.get_pointer_field(0).is_null()
+      }
+      #[inline]
+      pub fn is_inherit(&self) -> bool {
+        self.reader.get_data_field::<u16>(4) == 1
+      }
+      #[inline]
+      pub fn which(self) -> ::core::result::Result<WhichReader<'a,>, crate::NotInSchema> {
+        match self.reader.get_data_field::<u16>(4) {
+          0 => {
+            ::core::result::Result::Ok(Which::Bind(
+              crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+            ))
+          }
+          1 => {
+            ::core::result::Result::Ok(Which::Inherit(
+              ()
+            ))
+          }
+          x => ::core::result::Result::Err(crate::NotInSchema(x))
+        }
+      }
+    }
+
+    pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+    impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 2, pointers: 1 };
+    }
+    impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+        self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn get_scope_id(self) -> u64 {
+        self.builder.get_data_field::<u64>(0)
+      }
+      #[inline]
+      pub fn set_scope_id(&mut self, value: u64)  {
+        self.builder.set_data_field::<u64>(0, value);
+      }
+      #[inline]
+      pub fn set_scope_id_from(&mut self, value: impl ::core::convert::TryInto<u64>) -> crate::Result<()> {
+        let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+        self.set_scope_id(value);
+        ::core::result::Result::Ok(())
+      }
+      #[inline]
+      pub fn set_bind(&mut self, value: crate::struct_list::Reader<'a,crate::schema_capnp::brand::binding::Owned>) -> crate::Result<()> {
+        self.builder.set_data_field::<u16>(4, 0);
+        crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+      }
+      #[inline]
+      pub fn init_bind(self, size: u32) -> crate::struct_list::Builder<'a,crate::schema_capnp::brand::binding::Owned> {
+        self.builder.set_data_field::<u16>(4, 0);
+        crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), size)
+      }
+      #[inline]
+      pub fn disown_bind(&mut self) -> crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::brand::binding::Owned>> {
+        if self.builder.get_data_field::<u16>(4) != 0 {
+          return crate::Orphan::null();
+        }
+        crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+      }
+      #[inline]
+      pub fn adopt_bind(&mut self, orphan: crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::brand::binding::Owned>>) -> crate::Result<()> {
+        self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+        self.builder.set_data_field::<u16>(4, 0);
+        ::core::result::Result::Ok(())
+      }
+      #[inline]
+      pub fn get_or_init_bind(&mut self, size: u32) -> crate::Result<crate::struct_list::Builder<'_,crate::schema_capnp::brand::binding::Owned>> {
+        if self.builder.get_data_field::<u16>(4) != 0 || self.builder.is_pointer_field_null(0) {
+          ::core::result::Result::Ok(self.reborrow().init_bind(size))
+        } else {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+        }
+      }
+      #[inline]
+      pub fn is_bind(&self) -> bool {
+        self.builder.get_data_field::<u16>(4) == 0
+      }
+      #[inline]
+      pub fn has_bind(&self) -> bool {
+        if self.builder.get_data_field::<u16>(4) != 0 { return false; }
+        !self.builder.is_pointer_field_null(0)
+      }
+      #[inline]
+      pub fn set_inherit(&mut self, _value: ())  {
+        self.builder.set_data_field::<u16>(4, 1);
+      }
+      #[inline]
+      pub fn is_inherit(&self) -> bool {
+        self.builder.get_data_field::<u16>(4) == 1
+      }
+      #[inline]
+      pub fn which(self) -> ::core::result::Result<WhichBuilder<'a,>, crate::NotInSchema> {
+        match self.builder.get_data_field::<u16>(4) {
+          0 => {
+            ::core::result::Result::Ok(Which::Bind(
+              crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+            ))
+          }
+          1 => {
+            ::core::result::Result::Ok(Which::Inherit(
+              ()
+            ))
+          }
+          x => ::core::result::Result::Err(crate::NotInSchema(x))
+        }
+      }
+      /// Sets the union to the variant that is active in `other`, copied from there as stored.
+      /// The fields outside the union are left unchanged.
+      ///
+      /// Returns an `EnumValueOrUnionDiscriminantNotPresent` error without changing anything if
+      /// `other` has a variant that is not in the schema, for example one added by a newer version
+      /// of it. A union nested in a group of the active variant is handled the same way, but by
+      /// then the group has been partially copied.
+      pub fn copy_union_from(&mut self, other: Reader<'_,>) -> crate::Result<()> {
+        match other.reader.get_data_field::<u16>(4) {
+          0 => {
+            self.builder.set_data_field::<u16>(4, 0);
+            crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), crate::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+          }
+          1 => {
+            self.builder.set_data_field::<u16>(4, 1);
+          }
+          x => return ::core::result::Result::Err(crate::NotInSchema(x).into()),
+        }
+        ::core::result::Result::Ok(())
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+    impl crate::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+      let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [crate::Word; 67] = [
+        crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+        crate::word(201, 107, 99, 169, 133, 52, 215, 171),
+        crate::word(19, 0, 0, 0, 1, 0, 2, 0),
+        crate::word(43, 66, 101, 96, 240, 85, 52, 144),
+        crate::word(1, 0, 7, 0, 0, 0, 2, 0),
+        crate::word(4, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 202, 0, 0, 0),
+        crate::word(33, 0, 0, 0, 7, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(29, 0, 0, 0, 175, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+        crate::word(97, 112, 110, 112, 58, 66, 114, 97),
+        crate::word(110, 100, 46, 83, 99, 111, 112, 101),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 1, 0, 1, 0),
+        crate::word(12, 0, 0, 0, 3, 0, 4, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(69, 0, 0, 0, 66, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(64, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(76, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(1, 0, 255, 255, 0, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 1, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(73, 0, 0, 0, 42, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(68, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(96, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(2, 0, 254, 255, 0, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 2, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(93, 0, 0, 0, 66, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(88, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(100, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(115, 99, 111, 112, 101, 73, 100, 0),
+        crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(98, 105, 110, 100, 0, 0, 0, 0),
+        crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(252, 231, 158, 150, 22, 205, 99, 200),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(105, 110, 104, 101, 114, 105, 116, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "schema.capnp:Brand.Scope";
+      pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        match index {
+          0 => <u64 as crate::introspect::Introspect>::introspect(),
+          1 => <crate::struct_list::Owned<crate::schema_capnp::brand::binding::Owned> as crate::introspect::Introspect>::introspect(),
+          2 => <() as crate::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[0];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[1,2];
+      pub mod layout {
+        pub const DISCRIMINANT_OFFSET: usize = 8;
+        pub const FIELD_SCOPE_ID_OFFSET: usize = 0;
+        pub const FIELD_BIND_DISCRIMINANT: u16 = 0;
+        pub const FIELD_BIND_POINTER_INDEX: usize = 0;
+        pub const FIELD_INHERIT_DISCRIMINANT: u16 = 1;
+      }
+      pub const TYPE_ID: u64 = 0xabd7_3485_a963_6bc9;
+    }
+    pub enum Which<A0> {
+      Bind(A0),
+      Inherit(()),
+    }
+    pub type WhichReader<'a,> = Which<crate::Result<crate::struct_list::Reader<'a,crate::schema_capnp::brand::binding::Owned>>>;
+    pub type WhichBuilder<'a,> = Which<crate::Result<crate::struct_list::Builder<'a,crate::schema_capnp::brand::binding::Owned>>>;
+  }
+
+  pub mod binding {
+  
+  impl Binding {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+ match self {
+ Binding::UNINITIALIZED => (),
+ Binding::_Unbound(t) => builder.reborrow().set_unbound(t),
+ Binding::_Type(t) => t.build_capnp_struct(builder.reborrow().init_type())?,
+ }
+  ::core::result::Result::Ok(())
+}}
+  pub enum Binding {
+ UNINITIALIZED,
+ _Unbound(()),
+ _Type(crate::schema_capnp::type_::Type),
+}
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Binding  {
+    type Error = crate::Error;
+    fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(match reader.which()? {
+        Which::Unbound(value) => Binding::_Unbound(value),
+        Which::Type(value) => Binding::_Type(<crate::schema_capnp::type_::Type as ::core::convert::TryFrom<_>>::try_from(value?)?),
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Binding {
+    type Error = crate::Error;
+    fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl Binding {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    pub use self::Which::{Unbound,Type};
+
+    /// The members of the union, as `(discriminant, name, field index)` in discriminant order.
+    pub static UNION_MEMBERS: &[(u16, &str, u16)] = &[(0, "unbound", 0), (1, "type", 1)];
+    /// Returns the schema name of the union member with discriminant `d`.
+    pub fn discriminant_name(d: u16) -> ::core::option::Option<&'static str> {
+      UNION_MEMBERS.get(d as usize).map(|member| member.1)
+    }
+
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+      pub type_: usize,
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+          type_: crate::schema_capnp::type_::MIN_SIZE_WORDS - 1,
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(counts: FieldCounts) -> usize {
+      counts.type_
+    }
+    /// Words taken up by a message holding just this struct: its data and pointer sections
+    /// plus the root pointer.
+    pub const MIN_SIZE_WORDS: usize = 1 + 1 + 1;
+    /// An upper bound on the words that a single-segment message holding this struct takes up.
+    pub const fn total_words_for(counts: FieldCounts) -> usize {
+      MIN_SIZE_WORDS + variable_words_for(counts)
+    }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+        self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn is_unbound(&self) -> bool {
+        self.reader.get_data_field::<u16>(0) == 0
+      }
+      #[inline]
+      pub fn is_type(&self) -> bool {
+        self.reader.get_data_field::<u16>(0) == 1
+      }
+      #[inline]
+      pub fn has_type(&self) -> bool {
+        if self.reader.get_data_field::<u16>(0) != 1 { return false; }
+        !self.reader.get_pointer_field(0).is_null()
+      }
+      #[inline]
+      pub fn which(self) -> ::core::result::Result<WhichReader<'a,>, crate::NotInSchema> {
+        match self.reader.get_data_field::<u16>(0) {
+          0 => {
+            ::core::result::Result::Ok(Which::Unbound(
+              ()
+            ))
+          }
+          1 => {
+            ::core::result::Result::Ok(Which::Type(
+              crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+            ))
+          }
+          x => ::core::result::Result::Err(crate::NotInSchema(x))
+        }
+      }
+    }
+
+    pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+    impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 1, pointers: 1 };
+    }
+    impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+        self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn set_unbound(&mut self, _value: ())  {
+        self.builder.set_data_field::<u16>(0, 0);
+      }
+      #[inline]
+      pub fn is_unbound(&self) -> bool {
+        self.builder.get_data_field::<u16>(0) == 0
+      }
+      #[inline]
+      pub fn set_type(&mut self, value: crate::schema_capnp::type_::Reader<'_>) -> crate::Result<()> {
+        self.builder.set_data_field::<u16>(0, 1);
+        crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+      }
+      #[inline]
+      pub fn init_type(self, ) -> crate::schema_capnp::type_::Builder<'a> {
+        self.builder.set_data_field::<u16>(0, 1);
+        crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+      }
+      #[inline]
+      pub fn disown_type(&mut self) -> crate::Orphan<crate::schema_capnp::type_::Owned> {
+        if self.builder.get_data_field::<u16>(0) != 1 {
+          return crate::Orphan::null();
+        }
+        crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+      }
+      #[inline]
+      pub fn adopt_type(&mut self, orphan: crate::Orphan<crate::schema_capnp::type_::Owned>) -> crate::Result<()> {
+        self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+        self.builder.set_data_field::<u16>(0, 1);
+        ::core::result::Result::Ok(())
+      }
+      #[inline]
+      pub fn get_or_init_type(&mut self, ) -> crate::Result<crate::schema_capnp::type_::Builder<'_>> {
+        if self.builder.get_data_field::<u16>(0) != 1 || self.builder.is_pointer_field_null(0) {
+          ::core::result::Result::Ok(self.reborrow().init_type())
+        } else {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+        }
+      }
+      #[inline]
+      pub fn is_type(&self) -> bool {
+        self.builder.get_data_field::<u16>(0) == 1
+      }
+      #[inline]
+      pub fn has_type(&self) -> bool {
+        if self.builder.get_data_field::<u16>(0) != 1 { return false; }
+        !self.builder.is_pointer_field_null(0)
+      }
+      #[inline]
+      pub fn which(self) -> ::core::result::Result<WhichBuilder<'a,>, crate::NotInSchema> {
+        match self.builder.get_data_field::<u16>(0) {
+          0 => {
+            ::core::result::Result::Ok(Which::Unbound(
+              ()
+            ))
+          }
+          1 => {
+            ::core::result::Result::Ok(Which::Type(
+              crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+            ))
+          }
+          x => ::core::result::Result::Err(crate::NotInSchema(x))
+        }
+      }
+      /// Sets the union to the variant that is active in `other`, copied from there as stored.
+      /// The fields outside the union are left unchanged.
+      ///
+      /// Returns an `EnumValueOrUnionDiscriminantNotPresent` error without changing anything if
+      /// `other` has a variant that is not in the schema, for example one added by a newer version
+      /// of it. A union nested in a group of the active variant is handled the same way, but by
+      /// then the group has been partially copied.
+      pub fn copy_union_from(&mut self, other: Reader<'_,>) -> crate::Result<()> {
+        match other.reader.get_data_field::<u16>(0) {
+          0 => {
+            self.builder.set_data_field::<u16>(0, 0);
+          }
+          1 => {
+            self.builder.set_data_field::<u16>(0, 1);
+            crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), crate::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+          }
+          x => return ::core::result::Result::Err(crate::NotInSchema(x).into()),
+        }
+        ::core::result::Result::Ok(())
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+    impl crate::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+      let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [crate::Word; 48] = [
+        crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+        crate::word(252, 231, 158, 150, 22, 205, 99, 200),
+        crate::word(19, 0, 0, 0, 1, 0, 1, 0),
+        crate::word(43, 66, 101, 96, 240, 85, 52, 144),
+        crate::word(1, 0, 7, 0, 0, 0, 2, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 218, 0, 0, 0),
+        crate::word(33, 0, 0, 0, 7, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(29, 0, 0, 0, 119, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+        crate::word(97, 112, 110, 112, 58, 66, 114, 97),
+        crate::word(110, 100, 46, 66, 105, 110, 100, 105),
+        crate::word(110, 103, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 1, 0, 1, 0),
+        crate::word(8, 0, 0, 0, 3, 0, 4, 0),
+        crate::word(0, 0, 255, 255, 0, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(41, 0, 0, 0, 66, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(36, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(48, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(1, 0, 254, 255, 0, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 1, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(45, 0, 0, 0, 42, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(40, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(52, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(117, 110, 98, 111, 117, 110, 100, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(116, 121, 112, 101, 0, 0, 0, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(96, 204, 249, 225, 237, 120, 115, 208),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "schema.capnp:Brand.Binding";
+      pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        match index {
+          0 => <() as crate::introspect::Introspect>::introspect(),
+          1 => <crate::schema_capnp::type_::Owned as crate::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[0,1];
+      pub mod layout {
+        pub const DISCRIMINANT_OFFSET: usize = 0;
+        pub const FIELD_UNBOUND_DISCRIMINANT: u16 = 0;
+        pub const FIELD_TYPE_DISCRIMINANT: u16 = 1;
+        pub const FIELD_TYPE_POINTER_INDEX: usize = 0;
+      }
+      pub const TYPE_ID: u64 = 0xc863_cd16_969e_e7fc;
+    }
+    pub enum Which<A0> {
+      Unbound(()),
+      Type(A0),
+    }
+    pub type WhichReader<'a,> = Which<crate::Result<crate::schema_capnp::type_::Reader<'a>>>;
+    pub type WhichBuilder<'a,> = Which<crate::Result<crate::schema_capnp::type_::Builder<'a>>>;
+  }
+}
+
+pub mod value {
+
+impl Value {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+ match self {
+ Value::UNINITIALIZED => (),
+ Value::_Void(t) => builder.reborrow().set_void(t),
+ Value::_Bool(t) => builder.reborrow().set_bool(t),
+ Value::_Int8(t) => builder.reborrow().set_int8(t),
+ Value::_Int16(t) => builder.reborrow().set_int16(t),
+ Value::_Int32(t) => builder.reborrow().set_int32(t),
+ Value::_Int64(t) => builder.reborrow().set_int64(t),
+ Value::_Uint8(t) => builder.reborrow().set_uint8(t),
+ Value::_Uint16(t) => builder.reborrow().set_uint16(t),
+ Value::_Uint32(t) => builder.reborrow().set_uint32(t),
+ Value::_Uint64(t) => builder.reborrow().set_uint64(t),
+ Value::_Float32(t) => builder.reborrow().set_float32(t),
+ Value::_Float64(t) => builder.reborrow().set_float64(t),
+ Value::_Text(t) => builder.reborrow().set_text(t.as_str().into()),
+ Value::_Data(t) => builder.reborrow().set_data(t.as_slice()),
+ Value::_List(t) => builder.reborrow().init_list().set_as_capability(t),
+ Value::_Enum(t) => builder.reborrow().set_enum(t),
+ Value::_Struct(t) => builder.reborrow().init_struct().set_as_capability(t),
+ Value::_Interface(t) => builder.reborrow().set_interface(t),
+ Value::_AnyPointer(t) => builder.reborrow().init_any_pointer().set_as_capability(t),
+ }
+  ::core::result::Result::Ok(())
+}}
+pub enum Value {
+ UNINITIALIZED,
+ _Void(()),
+ _Bool(bool),
+ _Int8(i8),
+ _Int16(i16),
+ _Int32(i32),
+ _Int64(i64),
+ _Uint8(u8),
+ _Uint16(u16),
+ _Uint32(u32),
+ _Uint64(u64),
+ _Float32(f32),
+ _Float64(f64),
+ _Text(String),
+ _Data(Vec<u8>),
+ _List(Box<dyn crate::private::capability::ClientHook>),
+ _Enum(u16),
+ _Struct(Box<dyn crate::private::capability::ClientHook>),
+ _Interface(()),
+ _AnyPointer(Box<dyn crate::private::capability::ClientHook>),
+}
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Value  {
+  type Error = crate::Error;
+  fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+    ::core::result::Result::Ok(match reader.which()? {
+      Which::Void(value) => Value::_Void(value),
+      Which::Bool(value) => Value::_Bool(value),
+      Which::Int8(value) => Value::_Int8(value),
+      Which::Int16(value) => Value::_Int16(value),
+      Which::Int32(value) => Value::_Int32(value),
+      Which::Int64(value) => Value::_Int64(value),
+      Which::Uint8(value) => Value::_Uint8(value),
+      Which::Uint16(value) => Value::_Uint16(value),
+      Which::Uint32(value) => Value::_Uint32(value),
+      Which::Uint64(value) => Value::_Uint64(value),
+      Which::Float32(value) => Value::_Float32(value),
+      Which::Float64(value) => Value::_Float64(value),
+      Which::Text(value) => Value::_Text(value?.to_string()?),
+      Which::Data(value) => Value::_Data(value?.to_vec()),
+      Which::List(value) => Value::_List(value.get_as_capability::<crate::capability::Client>()?.hook),
+      Which::Enum(value) => Value::_Enum(value),
+      Which::Struct(value) => Value::_Struct(value.get_as_capability::<crate::capability::Client>()?.hook),
+      Which::Interface(value) => Value::_Interface(value),
+      Which::AnyPointer(value) => Value::_AnyPointer(value.get_as_capability::<crate::capability::Client>()?.hook),
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Value {
+  type Error = crate::Error;
+  fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl Value {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  pub use self::Which::{Void,Bool,Int8,Int16,Int32,Int64,Uint8,Uint16,Uint32,Uint64,Float32,Float64,Text,Data,List,Enum,Struct,Interface,AnyPointer};
+
+  /// The members of the union, as `(discriminant, name, field index)` in discriminant order.
+  pub static UNION_MEMBERS: &[(u16, &str, u16)] = &[(0, "void", 0), (1, "bool", 1), (2, "int8", 2), (3, "int16", 3), (4, "int32", 4), (5, "int64", 5), (6, "uint8", 6), (7, "uint16", 7), (8, "uint32", 8), (9, "uint64", 9), (10, "float32", 10), (11, "float64", 11), (12, "text", 12), (13, "data", 13), (14, "list", 14), (15, "enum", 15), (16, "struct", 16), (17, "interface", 17), (18, "anyPointer", 18)];
+  /// Returns the schema name of the union member with discriminant `d`.
+  pub fn discriminant_name(d: u16) -> ::core::option::Option<&'static str> {
+    UNION_MEMBERS.get(d as usize).map(|member| member.1)
+  }
+
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub text: usize,
+    pub data: usize,
+    pub list: usize,
+    pub struct_: usize,
+    pub any_pointer: usize,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        text: 0,
+        data: 0,
+        list: 0,
+        struct_: 0,
+        any_pointer: 0,
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    (counts.text + 8) / 8 + (counts.data + 7) / 8 + counts.list + counts.struct_ + counts.any_pointer
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 2 + 1 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+      self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> crate::Result<usize> {
+      self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn is_void(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 0
+    }
+    #[inline]
+    pub fn is_bool(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 1
+    }
+    #[inline]
+    pub fn is_int8(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 2
+    }
+    #[inline]
+    pub fn is_int16(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 3
+    }
+    #[inline]
+    pub fn is_int32(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 4
+    }
+    #[inline]
+    pub fn is_int64(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 5
+    }
+    #[inline]
+    pub fn is_uint8(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 6
+    }
+    #[inline]
+    pub fn is_uint16(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 7
+    }
+    #[inline]
+    pub fn is_uint32(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 8
+    }
+    #[inline]
+    pub fn is_uint64(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 9
+    }
+    #[inline]
+    pub fn is_float32(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 10
+    }
+    #[inline]
+    pub fn is_float64(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 11
+    }
+    #[inline]
+    pub fn is_text(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 12
+    }
+    #[inline]
+    pub fn has_text(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 12 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn is_data(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 13
+    }
+    #[inline]
+    pub fn has_data(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 13 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn is_list(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 14
+    }
+    #[inline]
+    pub fn has_list(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 14 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn is_enum(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 15
+    }
+    #[inline]
+    pub fn is_struct(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 16
+    }
+    #[inline]
+    pub fn has_struct(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 16 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn is_interface(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 17
+    }
+    #[inline]
+    pub fn is_any_pointer(&self) -> bool {
+      self.reader.get_data_field::<u16>(0) == 18
+    }
+    #[inline]
+    pub fn has_any_pointer(&self) -> bool {
+      if self.reader.get_data_field::<u16>(0) != 18 { return false; }
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn which(self) -> ::core::result::Result<WhichReader<'a,>, crate::NotInSchema> {
+      match self.reader.get_data_field::<u16>(0) {
+        0 => {
+          ::core::result::Result::Ok(Which::Void(
+            ()
+          ))
+        }
+        1 => {
+          ::core::result::Result::Ok(Which::Bool(
+            self.reader.get_bool_field(16)
+          ))
+        }
+        2 => {
+          ::core::result::Result::Ok(Which::Int8(
+            self.reader.get_data_field::<i8>(2)
+          ))
+        }
+        3 => {
+          ::core::result::Result::Ok(Which::Int16(
+            self.reader.get_data_field::<i16>(1)
+          ))
+        }
+        4 => {
+          ::core::result::Result::Ok(Which::Int32(
+            self.reader.get_data_field::<i32>(1)
+          ))
+        }
+        5 => {
+          ::core::result::Result::Ok(Which::Int64(
+            self.reader.get_data_field::<i64>(1)
+          ))
+        }
+        6 => {
+          ::core::result::Result::Ok(Which::Uint8(
+            self.reader.get_data_field::<u8>(2)
+          ))
+        }
+        7 => {
+          ::core::result::Result::Ok(Which::Uint16(
+            self.reader.get_data_field::<u16>(1)
+          ))
+        }
+        8 => {
+          ::core::result::Result::Ok(Which::Uint32(
+            self.reader.get_data_field::<u32>(1)
+          ))
+        }
+        9 => {
+          ::core::result::Result::Ok(Which::Uint64(
+            self.reader.get_data_field::<u64>(1)
+          ))
+        }
+        10 => {
+          ::core::result::Result::Ok(Which::Float32(
+            self.reader.get_data_field::<f32>(1)
+          ))
+        }
+        11 => {
+          ::core::result::Result::Ok(Which::Float64(
+            self.reader.get_data_field::<f64>(1)
+          ))
+        }
+        12 => {
+          ::core::result::Result::Ok(Which::Text(
+            crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        13 => {
+          ::core::result::Result::Ok(Which::Data(
+            crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        14 => {
+          ::core::result::Result::Ok(Which::List(
+            crate::any_pointer::Reader::new(self.reader.get_pointer_field(0))
+          ))
+        }
+        15 => {
+          ::core::result::Result::Ok(Which::Enum(
+            self.reader.get_data_field::<u16>(1)
+          ))
+        }
+        16 => {
+          ::core::result::Result::Ok(Which::Struct(
+            crate::any_pointer::Reader::new(self.reader.get_pointer_field(0))
+          ))
+        }
+        17 => {
+          ::core::result::Result::Ok(Which::Interface(
+            ()
+          ))
+        }
+        18 => {
+          ::core::result::Result::Ok(Which::AnyPointer(
+            crate::any_pointer::Reader::new(self.reader.get_pointer_field(0))
+          ))
+        }
+        x => ::core::result::Result::Err(crate::NotInSchema(x))
+      }
+    }
+  }
+
+  pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+  impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 2, pointers: 1 };
+  }
+  impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+      self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> crate::Result<usize> {
+      self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn set_void(&mut self, _value: ())  {
+      self.builder.set_data_field::<u16>(0, 0);
+    }
+    #[inline]
+    pub fn is_void(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 0
+    }
+    #[inline]
+    pub fn set_bool(&mut self, value: bool)  {
+      self.builder.set_data_field::<u16>(0, 1);
+      self.builder.set_bool_field(16, value);
+    }
+    #[inline]
+    pub fn is_bool(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 1
+    }
+    #[inline]
+    pub fn set_int8(&mut self, value: i8)  {
+      self.builder.set_data_field::<u16>(0, 2);
+      self.builder.set_data_field::<i8>(2, value);
+    }
+    #[inline]
+    pub fn set_int8_from(&mut self, value: impl ::core::convert::TryInto<i8>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_int8(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn is_int8(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 2
+    }
+    #[inline]
+    pub fn set_int16(&mut self, value: i16)  {
+      self.builder.set_data_field::<u16>(0, 3);
+      self.builder.set_data_field::<i16>(1, value);
+    }
+    #[inline]
+    pub fn set_int16_from(&mut self, value: impl ::core::convert::TryInto<i16>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_int16(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn is_int16(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 3
+    }
+    #[inline]
+    pub fn set_int32(&mut self, value: i32)  {
+      self.builder.set_data_field::<u16>(0, 4);
+      self.builder.set_data_field::<i32>(1, value);
+    }
+    #[inline]
+    pub fn set_int32_from(&mut self, value: impl ::core::convert::TryInto<i32>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_int32(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn is_int32(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 4
+    }
+    #[inline]
+    pub fn set_int64(&mut self, value: i64)  {
+      self.builder.set_data_field::<u16>(0, 5);
+      self.builder.set_data_field::<i64>(1, value);
+    }
+    #[inline]
+    pub fn set_int64_from(&mut self, value: impl ::core::convert::TryInto<i64>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_int64(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn is_int64(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 5
+    }
+    #[inline]
+    pub fn set_uint8(&mut self, value: u8)  {
+      self.builder.set_data_field::<u16>(0, 6);
+      self.builder.set_data_field::<u8>(2, value);
+    }
+    #[inline]
+    pub fn set_uint8_from(&mut self, value: impl ::core::convert::TryInto<u8>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_uint8(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn is_uint8(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 6
+    }
+    #[inline]
+    pub fn set_uint16(&mut self, value: u16)  {
+      self.builder.set_data_field::<u16>(0, 7);
+      self.builder.set_data_field::<u16>(1, value);
+    }
+    #[inline]
+    pub fn set_uint16_from(&mut self, value: impl ::core::convert::TryInto<u16>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_uint16(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn is_uint16(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 7
+    }
+    #[inline]
+    pub fn set_uint32(&mut self, value: u32)  {
+      self.builder.set_data_field::<u16>(0, 8);
+      self.builder.set_data_field::<u32>(1, value);
+    }
+    #[inline]
+    pub fn set_uint32_from(&mut self, value: impl ::core::convert::TryInto<u32>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_uint32(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn is_uint32(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 8
+    }
+    #[inline]
+    pub fn set_uint64(&mut self, value: u64)  {
+      self.builder.set_data_field::<u16>(0, 9);
+      self.builder.set_data_field::<u64>(1, value);
+    }
+    #[inline]
+    pub fn set_uint64_from(&mut self, value: impl ::core::convert::TryInto<u64>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_uint64(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn is_uint64(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 9
+    }
+    #[inline]
+    pub fn set_float32(&mut self, value: f32)  {
+      self.builder.set_data_field::<u16>(0, 10);
+      self.builder.set_data_field::<f32>(1, value);
+    }
+    #[inline]
+    pub fn is_float32(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 10
+    }
+    #[inline]
+    pub fn set_float64(&mut self, value: f64)  {
+      self.builder.set_data_field::<u16>(0, 11);
+      self.builder.set_data_field::<f64>(1, value);
+    }
+    #[inline]
+    pub fn is_float64(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 11
+    }
+    #[inline]
+    pub fn set_text(&mut self, value: crate::text::Reader<'_>)  {
+      self.builder.set_data_field::<u16>(0, 12);
+      self.builder.reborrow().get_pointer_field(0).set_text(value);
+    }
+    #[inline]
+    pub fn init_text(self, size: u32) -> crate::text::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 12);
+      self.builder.get_pointer_field(0).init_text(size)
+    }
+    #[inline]
+    pub fn disown_text(&mut self) -> crate::Orphan<crate::text::Owned> {
+      if self.builder.get_data_field::<u16>(0) != 12 {
+        return crate::Orphan::null();
+      }
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_text(&mut self, orphan: crate::Orphan<crate::text::Owned>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(0, 12);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_or_init_text(&mut self, size: u32) -> crate::Result<crate::text::Builder<'_>> {
+      if self.builder.get_data_field::<u16>(0) != 12 || self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_text(size))
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn is_text(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 12
+    }
+    #[inline]
+    pub fn has_text(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 12 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn set_data(&mut self, value: crate::data::Reader<'_>)  {
+      self.builder.set_data_field::<u16>(0, 13);
+      self.builder.reborrow().get_pointer_field(0).set_data(value);
+    }
+    #[inline]
+    pub fn init_data(self, size: u32) -> crate::data::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 13);
+      self.builder.get_pointer_field(0).init_data(size)
+    }
+    #[inline]
+    pub fn disown_data(&mut self) -> crate::Orphan<crate::data::Owned> {
+      if self.builder.get_data_field::<u16>(0) != 13 {
+        return crate::Orphan::null();
+      }
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_data(&mut self, orphan: crate::Orphan<crate::data::Owned>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(0, 13);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_or_init_data(&mut self, size: u32) -> crate::Result<crate::data::Builder<'_>> {
+      if self.builder.get_data_field::<u16>(0) != 13 || self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_data(size))
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn is_data(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 13
+    }
+    #[inline]
+    pub fn has_data(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 13 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn init_list(self, ) -> crate::any_pointer::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 14);
+      let mut result = crate::any_pointer::Builder::new(self.builder.get_pointer_field(0));
+      result.clear();
+      result
+    }
+    #[inline]
+    pub fn disown_list(&mut self) -> crate::Orphan<crate::any_pointer::Owned> {
+      if self.builder.get_data_field::<u16>(0) != 14 {
+        return crate::Orphan::null();
+      }
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_list(&mut self, orphan: crate::Orphan<crate::any_pointer::Owned>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(0, 14);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn is_list(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 14
+    }
+    #[inline]
+    pub fn has_list(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 14 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn set_enum(&mut self, value: u16)  {
+      self.builder.set_data_field::<u16>(0, 15);
+      self.builder.set_data_field::<u16>(1, value);
+    }
+    #[inline]
+    pub fn set_enum_from(&mut self, value: impl ::core::convert::TryInto<u16>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_enum(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn is_enum(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 15
+    }
+    #[inline]
+    pub fn init_struct(self, ) -> crate::any_pointer::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 16);
+      let mut result = crate::any_pointer::Builder::new(self.builder.get_pointer_field(0));
+      result.clear();
+      result
+    }
+    #[inline]
+    pub fn disown_struct(&mut self) -> crate::Orphan<crate::any_pointer::Owned> {
+      if self.builder.get_data_field::<u16>(0) != 16 {
+        return crate::Orphan::null();
+      }
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_struct(&mut self, orphan: crate::Orphan<crate::any_pointer::Owned>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(0, 16);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn is_struct(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 16
+    }
+    #[inline]
+    pub fn has_struct(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 16 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn set_interface(&mut self, _value: ())  {
+      self.builder.set_data_field::<u16>(0, 17);
+    }
+    #[inline]
+    pub fn is_interface(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 17
+    }
+    #[inline]
+    pub fn init_any_pointer(self, ) -> crate::any_pointer::Builder<'a> {
+      self.builder.set_data_field::<u16>(0, 18);
+      let mut result = crate::any_pointer::Builder::new(self.builder.get_pointer_field(0));
+      result.clear();
+      result
+    }
+    #[inline]
+    pub fn disown_any_pointer(&mut self) -> crate::Orphan<crate::any_pointer::Owned> {
+      if self.builder.get_data_field::<u16>(0) != 18 {
+        return crate::Orphan::null();
+      }
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_any_pointer(&mut self, orphan: crate::Orphan<crate::any_pointer::Owned>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())?;
+      self.builder.set_data_field::<u16>(0, 18);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn is_any_pointer(&self) -> bool {
+      self.builder.get_data_field::<u16>(0) == 18
+    }
+    #[inline]
+    pub fn has_any_pointer(&self) -> bool {
+      if self.builder.get_data_field::<u16>(0) != 18 { return false; }
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn which(self) -> ::core::result::Result<WhichBuilder<'a,>, crate::NotInSchema> {
+      match self.builder.get_data_field::<u16>(0) {
+        0 => {
+          ::core::result::Result::Ok(Which::Void(
+            ()
+          ))
+        }
+        1 => {
+          ::core::result::Result::Ok(Which::Bool(
+            self.builder.get_bool_field(16)
+          ))
+        }
+        2 => {
+          ::core::result::Result::Ok(Which::Int8(
+            self.builder.get_data_field::<i8>(2)
+          ))
+        }
+        3 => {
+          ::core::result::Result::Ok(Which::Int16(
+            self.builder.get_data_field::<i16>(1)
+          ))
+        }
+        4 => {
+          ::core::result::Result::Ok(Which::Int32(
+            self.builder.get_data_field::<i32>(1)
+          ))
+        }
+        5 => {
+          ::core::result::Result::Ok(Which::Int64(
+            self.builder.get_data_field::<i64>(1)
+          ))
+        }
+        6 => {
+          ::core::result::Result::Ok(Which::Uint8(
+            self.builder.get_data_field::<u8>(2)
+          ))
+        }
+        7 => {
+          ::core::result::Result::Ok(Which::Uint16(
+            self.builder.get_data_field::<u16>(1)
+          ))
+        }
+        8 => {
+          ::core::result::Result::Ok(Which::Uint32(
+            self.builder.get_data_field::<u32>(1)
+          ))
+        }
+        9 => {
+          ::core::result::Result::Ok(Which::Uint64(
+            self.builder.get_data_field::<u64>(1)
+          ))
+        }
+        10 => {
+          ::core::result::Result::Ok(Which::Float32(
+            self.builder.get_data_field::<f32>(1)
+          ))
+        }
+        11 => {
+          ::core::result::Result::Ok(Which::Float64(
+            self.builder.get_data_field::<f64>(1)
+          ))
+        }
+        12 => {
+          ::core::result::Result::Ok(Which::Text(
+            crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        13 => {
+          ::core::result::Result::Ok(Which::Data(
+            crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+          ))
+        }
+        14 => {
+          ::core::result::Result::Ok(Which::List(
+            crate::any_pointer::Builder::new(self.builder.get_pointer_field(0))
+          ))
+        }
+        15 => {
+          ::core::result::Result::Ok(Which::Enum(
+            self.builder.get_data_field::<u16>(1)
+          ))
+        }
+        16 => {
+          ::core::result::Result::Ok(Which::Struct(
+            crate::any_pointer::Builder::new(self.builder.get_pointer_field(0))
+          ))
+        }
+        17 => {
+          ::core::result::Result::Ok(Which::Interface(
+            ()
+          ))
+        }
+        18 => {
+          ::core::result::Result::Ok(Which::AnyPointer(
+            crate::any_pointer::Builder::new(self.builder.get_pointer_field(0))
+          ))
+        }
+        x => ::core::result::Result::Err(crate::NotInSchema(x))
+      }
+    }
+    /// Sets the union to the variant that is active in `other`, copied from there as stored.
+    /// The fields outside the union are left unchanged.
+    ///
+    /// Returns an `EnumValueOrUnionDiscriminantNotPresent` error without changing anything if
+    /// `other` has a variant that is not in the schema, for example one added by a newer version
+    /// of it. A union nested in a group of the active variant is handled the same way, but by
+    /// then the group has been partially copied.
+    pub fn copy_union_from(&mut self, other: Reader<'_,>) -> crate::Result<()> {
+      match other.reader.get_data_field::<u16>(0) {
+        0 => {
+          self.builder.set_data_field::<u16>(0, 0);
+        }
+        1 => {
+          self.builder.set_data_field::<u16>(0, 1);
+          self.builder.set_bool_field(16, other.reader.get_bool_field(16));
+        }
+        2 => {
+          self.builder.set_data_field::<u16>(0, 2);
+          self.builder.set_data_field::<u8>(2, other.reader.get_data_field::<u8>(2));
+        }
+        3 => {
+          self.builder.set_data_field::<u16>(0, 3);
+          self.builder.set_data_field::<u16>(1, other.reader.get_data_field::<u16>(1));
+        }
+        4 => {
+          self.builder.set_data_field::<u16>(0, 4);
+          self.builder.set_data_field::<u32>(1, other.reader.get_data_field::<u32>(1));
+        }
+        5 => {
+          self.builder.set_data_field::<u16>(0, 5);
+          self.builder.set_data_field::<u64>(1, other.reader.get_data_field::<u64>(1));
+        }
+        6 => {
+          self.builder.set_data_field::<u16>(0, 6);
+          self.builder.set_data_field::<u8>(2, other.reader.get_data_field::<u8>(2));
+        }
+        7 => {
+          self.builder.set_data_field::<u16>(0, 7);
+          self.builder.set_data_field::<u16>(1, other.reader.get_data_field::<u16>(1));
+        }
+        8 => {
+          self.builder.set_data_field::<u16>(0, 8);
+          self.builder.set_data_field::<u32>(1, other.reader.get_data_field::<u32>(1));
+        }
+        9 => {
+          self.builder.set_data_field::<u16>(0, 9);
+          self.builder.set_data_field::<u64>(1, other.reader.get_data_field::<u64>(1));
+        }
+        10 => {
+          self.builder.set_data_field::<u16>(0, 10);
+          self.builder.set_data_field::<u32>(1, other.reader.get_data_field::<u32>(1));
+        }
+        11 => {
+          self.builder.set_data_field::<u16>(0, 11);
+          self.builder.set_data_field::<u64>(1, other.reader.get_data_field::<u64>(1));
+        }
+        12 => {
+          self.builder.set_data_field::<u16>(0, 12);
+          crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), crate::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        13 => {
+          self.builder.set_data_field::<u16>(0, 13);
+          crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), crate::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        14 => {
+          self.builder.set_data_field::<u16>(0, 14);
+          crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), crate::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        15 => {
+          self.builder.set_data_field::<u16>(0, 15);
+          self.builder.set_data_field::<u16>(1, other.reader.get_data_field::<u16>(1));
+        }
+        16 => {
+          self.builder.set_data_field::<u16>(0, 16);
+          crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), crate::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        17 => {
+          self.builder.set_data_field::<u16>(0, 17);
+        }
+        18 => {
+          self.builder.set_data_field::<u16>(0, 18);
+          crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), crate::any_pointer::Reader::new(other.reader.get_pointer_field(0)), false)?;
+        }
+        x => return ::core::result::Result::Err(crate::NotInSchema(x).into()),
+      }
+      ::core::result::Result::Ok(())
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+  impl crate::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+    let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [crate::Word; 304] = [
+      crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+      crate::word(155, 12, 176, 215, 210, 220, 35, 206),
+      crate::word(13, 0, 0, 0, 1, 0, 2, 0),
+      crate::word(217, 114, 76, 98, 9, 197, 63, 169),
+      crate::word(1, 0, 7, 0, 0, 0, 19, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(21, 0, 0, 0, 154, 0, 0, 0),
+      crate::word(29, 0, 0, 0, 7, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(25, 0, 0, 0, 47, 4, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+      crate::word(97, 112, 110, 112, 58, 86, 97, 108),
+      crate::word(117, 101, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 1, 0, 1, 0),
+      crate::word(76, 0, 0, 0, 3, 0, 4, 0),
+      crate::word(0, 0, 255, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(5, 2, 0, 0, 42, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(12, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(1, 0, 254, 255, 16, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 1, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(9, 2, 0, 0, 42, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(4, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(16, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(2, 0, 253, 255, 2, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 2, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(13, 2, 0, 0, 42, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(8, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(20, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(3, 0, 252, 255, 1, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 3, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(17, 2, 0, 0, 50, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(12, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(24, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(4, 0, 251, 255, 1, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 4, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(21, 2, 0, 0, 50, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(16, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(28, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(5, 0, 250, 255, 1, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 5, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(25, 2, 0, 0, 50, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(20, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(32, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(6, 0, 249, 255, 2, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 6, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(29, 2, 0, 0, 50, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(24, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(36, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(7, 0, 248, 255, 1, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 7, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(33, 2, 0, 0, 58, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(28, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(40, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(8, 0, 247, 255, 1, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 8, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(37, 2, 0, 0, 58, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(32, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(44, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(9, 0, 246, 255, 1, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 9, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(41, 2, 0, 0, 58, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(36, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(48, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(10, 0, 245, 255, 1, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 10, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(45, 2, 0, 0, 66, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(40, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(52, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(11, 0, 244, 255, 1, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 11, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(49, 2, 0, 0, 66, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(44, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(56, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(12, 0, 243, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 12, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(53, 2, 0, 0, 42, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(48, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(60, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(13, 0, 242, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 13, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(57, 2, 0, 0, 42, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(52, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(64, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(14, 0, 241, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 14, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(61, 2, 0, 0, 42, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(56, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(68, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(15, 0, 240, 255, 1, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 15, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(65, 2, 0, 0, 42, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(60, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(72, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(16, 0, 239, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 16, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(69, 2, 0, 0, 58, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(64, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(76, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(17, 0, 238, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 17, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(73, 2, 0, 0, 82, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(72, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(84, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(18, 0, 237, 255, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 18, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(81, 2, 0, 0, 90, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(80, 2, 0, 0, 3, 0, 1, 0),
+      crate::word(92, 2, 0, 0, 2, 0, 1, 0),
+      crate::word(118, 111, 105, 100, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(98, 111, 111, 108, 0, 0, 0, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(105, 110, 116, 56, 0, 0, 0, 0),
+      crate::word(2, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(2, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(105, 110, 116, 49, 54, 0, 0, 0),
+      crate::word(3, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(3, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(105, 110, 116, 51, 50, 0, 0, 0),
+      crate::word(4, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(4, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(105, 110, 116, 54, 52, 0, 0, 0),
+      crate::word(5, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(5, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(117, 105, 110, 116, 56, 0, 0, 0),
+      crate::word(6, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(6, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(117, 105, 110, 116, 49, 54, 0, 0),
+      crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(117, 105, 110, 116, 51, 50, 0, 0),
+      crate::word(8, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(8, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(117, 105, 110, 116, 54, 52, 0, 0),
+      crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(102, 108, 111, 97, 116, 51, 50, 0),
+      crate::word(10, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(10, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(102, 108, 111, 97, 116, 54, 52, 0),
+      crate::word(11, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(11, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(116, 101, 120, 116, 0, 0, 0, 0),
+      crate::word(12, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(12, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(100, 97, 116, 97, 0, 0, 0, 0),
+      crate::word(13, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(13, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(108, 105, 115, 116, 0, 0, 0, 0),
+      crate::word(18, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(18, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(101, 110, 117, 109, 0, 0, 0, 0),
+      crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(115, 116, 114, 117, 99, 116, 0, 0),
+      crate::word(18, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(18, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(105, 110, 116, 101, 114, 102, 97, 99),
+      crate::word(101, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(97, 110, 121, 80, 111, 105, 110, 116),
+      crate::word(101, 114, 0, 0, 0, 0, 0, 0),
+      crate::word(18, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(18, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "schema.capnp:Value";
+    pub fn get_field_types(index: u16) -> crate::introspect::Type {
+      match index {
+        0 => <() as crate::introspect::Introspect>::introspect(),
+        1 => <bool as crate::introspect::Introspect>::introspect(),
+        2 => <i8 as crate::introspect::Introspect>::introspect(),
+        3 => <i16 as crate::introspect::Introspect>::introspect(),
+        4 => <i32 as crate::introspect::Introspect>::introspect(),
+        5 => <i64 as crate::introspect::Introspect>::introspect(),
+        6 => <u8 as crate::introspect::Introspect>::introspect(),
+        7 => <u16 as crate::introspect::Introspect>::introspect(),
+        8 => <u32 as crate::introspect::Introspect>::introspect(),
+        9 => <u64 as crate::introspect::Introspect>::introspect(),
+        10 => <f32 as crate::introspect::Introspect>::introspect(),
+        11 => <f64 as crate::introspect::Introspect>::introspect(),
+        12 => <crate::text::Owned as crate::introspect::Introspect>::introspect(),
+        13 => <crate::data::Owned as crate::introspect::Introspect>::introspect(),
+        14 => <crate::any_pointer::Owned as crate::introspect::Introspect>::introspect(),
+        15 => <u16 as crate::introspect::Introspect>::introspect(),
+        16 => <crate::any_pointer::Owned as crate::introspect::Introspect>::introspect(),
+        17 => <() as crate::introspect::Introspect>::introspect(),
+        18 => <crate::any_pointer::Owned as crate::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18];
+    pub mod layout {
+      pub const DISCRIMINANT_OFFSET: usize = 0;
+      pub const FIELD_VOID_DISCRIMINANT: u16 = 0;
+      pub const FIELD_BOOL_DISCRIMINANT: u16 = 1;
+      pub const FIELD_BOOL_BIT_OFFSET: usize = 16;
+      pub const FIELD_INT8_DISCRIMINANT: u16 = 2;
+      pub const FIELD_INT8_OFFSET: usize = 2;
+      pub const FIELD_INT16_DISCRIMINANT: u16 = 3;
+      pub const FIELD_INT16_OFFSET: usize = 2;
+      pub const FIELD_INT32_DISCRIMINANT: u16 = 4;
+      pub const FIELD_INT32_OFFSET: usize = 4;
+      pub const FIELD_INT64_DISCRIMINANT: u16 = 5;
+      pub const FIELD_INT64_OFFSET: usize = 8;
+      pub const FIELD_UINT8_DISCRIMINANT: u16 = 6;
+      pub const FIELD_UINT8_OFFSET: usize = 2;
+      pub const FIELD_UINT16_DISCRIMINANT: u16 = 7;
+      pub const FIELD_UINT16_OFFSET: usize = 2;
+      pub const FIELD_UINT32_DISCRIMINANT: u16 = 8;
+      pub const FIELD_UINT32_OFFSET: usize = 4;
+      pub const FIELD_UINT64_DISCRIMINANT: u16 = 9;
+      pub const FIELD_UINT64_OFFSET: usize = 8;
+      pub const FIELD_FLOAT32_DISCRIMINANT: u16 = 10;
+      pub const FIELD_FLOAT32_OFFSET: usize = 4;
+      pub const FIELD_FLOAT64_DISCRIMINANT: u16 = 11;
+      pub const FIELD_FLOAT64_OFFSET: usize = 8;
+      pub const FIELD_TEXT_DISCRIMINANT: u16 = 12;
+      pub const FIELD_TEXT_POINTER_INDEX: usize = 0;
+      pub const FIELD_DATA_DISCRIMINANT: u16 = 13;
+      pub const FIELD_DATA_POINTER_INDEX: usize = 0;
+      pub const FIELD_LIST_DISCRIMINANT: u16 = 14;
+      pub const FIELD_LIST_POINTER_INDEX: usize = 0;
+      pub const FIELD_ENUM_DISCRIMINANT: u16 = 15;
+      pub const FIELD_ENUM_OFFSET: usize = 2;
+      pub const FIELD_STRUCT_DISCRIMINANT: u16 = 16;
+      pub const FIELD_STRUCT_POINTER_INDEX: usize = 0;
+      pub const FIELD_INTERFACE_DISCRIMINANT: u16 = 17;
+      pub const FIELD_ANY_POINTER_DISCRIMINANT: u16 = 18;
+      pub const FIELD_ANY_POINTER_POINTER_INDEX: usize = 0;
+    }
+    pub const TYPE_ID: u64 = 0xce23_dcd2_d7b0_0c9b;
+  }
+  pub enum Which<A0,A1,A2,A3,A4> {
+    Void(()),
+    Bool(bool),
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    Uint8(u8),
+    Uint16(u16),
+    Uint32(u32),
+    Uint64(u64),
+    Float32(f32),
+    Float64(f64),
+    Text(A0),
+    Data(A1),
+    List(A2),
+    Enum(u16),
+    Struct(A3),
+    Interface(()),
+    AnyPointer(A4),
+  }
+  pub type WhichReader<'a,> = Which<crate::Result<crate::text::Reader<'a>>,crate::Result<crate::data::Reader<'a>>,crate::any_pointer::Reader<'a>,crate::any_pointer::Reader<'a>,crate::any_pointer::Reader<'a>>;
+  pub type WhichBuilder<'a,> = Which<crate::Result<crate::text::Builder<'a>>,crate::Result<crate::data::Builder<'a>>,crate::any_pointer::Builder<'a>,crate::any_pointer::Builder<'a>,crate::any_pointer::Builder<'a>>;
+}
+
+pub mod annotation {
+pub struct Annotation {
+ pub _id: u64,
+ pub _value: Option<crate::schema_capnp::value::Value>,
+ pub _brand: Option<crate::schema_capnp::brand::Brand>,  
+}
+impl Annotation {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+  builder.set_id(self._id);
+  if let Some(st) = self._value {st.build_capnp_struct(builder.reborrow().init_value())?;}
+  if let Some(st) = self._brand {st.build_capnp_struct(builder.reborrow().init_brand())?;}
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Annotation  {
+  type Error = crate::Error;
+  fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _id: reader.get_id(),
+      _value: if reader.has_value() { ::core::option::Option::Some(<crate::schema_capnp::value::Value as ::core::convert::TryFrom<_>>::try_from(reader.get_value()?)?) } else { ::core::option::Option::None },
+      _brand: if reader.has_brand() { ::core::option::Option::Some(<crate::schema_capnp::brand::Brand as ::core::convert::TryFrom<_>>::try_from(reader.get_brand()?)?) } else { ::core::option::Option::None },
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Annotation {
+  type Error = crate::Error;
+  fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl Annotation {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub value: usize,
+    pub brand: usize,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        value: crate::schema_capnp::value::MIN_SIZE_WORDS - 1,
+        brand: crate::schema_capnp::brand::MIN_SIZE_WORDS - 1,
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    counts.value + counts.brand
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 1 + 2 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+      self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> crate::Result<usize> {
+      self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_id(self) -> u64 {
+      self.reader.get_data_field::<u64>(0)
+    }
+    #[inline]
+    pub fn get_value(self) -> crate::Result<crate::schema_capnp::value::Reader<'a>> {
+      crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_value(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_brand(self) -> crate::Result<crate::schema_capnp::brand::Reader<'a>> {
+      crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_brand(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
+    }
+  }
+
+  pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+  impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 1, pointers: 2 };
+  }
+  impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+      self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> crate::Result<usize> {
+      self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_id(self) -> u64 {
+      self.builder.get_data_field::<u64>(0)
+    }
+    #[inline]
+    pub fn set_id(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(0, value);
+    }
+    #[inline]
+    pub fn set_id_from(&mut self, value: impl ::core::convert::TryInto<u64>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_id(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_value(self) -> crate::Result<crate::schema_capnp::value::Builder<'a>> {
+      crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_value(&mut self, value: crate::schema_capnp::value::Reader<'_>) -> crate::Result<()> {
+      crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_value(self, ) -> crate::schema_capnp::value::Builder<'a> {
+      crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    #[inline]
+    pub fn disown_value(&mut self) -> crate::Orphan<crate::schema_capnp::value::Owned> {
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_value(&mut self, orphan: crate::Orphan<crate::schema_capnp::value::Owned>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_value(&mut self, ) -> crate::Result<crate::schema_capnp::value::Builder<'_>> {
+      if self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_value())
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_value(&self) -> bool {
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn get_brand(self) -> crate::Result<crate::schema_capnp::brand::Builder<'a>> {
+      crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_brand(&mut self, value: crate::schema_capnp::brand::Reader<'_>) -> crate::Result<()> {
+      crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(1), value, false)
+    }
+    #[inline]
+    pub fn init_brand(self, ) -> crate::schema_capnp::brand::Builder<'a> {
+      crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), 0)
+    }
+    #[inline]
+    pub fn disown_brand(&mut self) -> crate::Orphan<crate::schema_capnp::brand::Owned> {
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+    }
+    #[inline]
+    pub fn adopt_brand(&mut self, orphan: crate::Orphan<crate::schema_capnp::brand::Owned>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(1).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_brand(&mut self, ) -> crate::Result<crate::schema_capnp::brand::Builder<'_>> {
+      if self.builder.is_pointer_field_null(1) {
+        ::core::result::Result::Ok(self.reborrow().init_brand())
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(1), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_brand(&self) -> bool {
+      !self.builder.is_pointer_field_null(1)
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+  impl crate::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+    #[inline]
+    pub fn get_value(&self) -> crate::schema_capnp::value::Pipeline {
+      crate::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+    }
+    #[inline]
+    pub fn get_brand(&self) -> crate::schema_capnp::brand::Pipeline {
+      crate::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(1))
+    }
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+    let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [crate::Word; 62] = [
+      crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+      crate::word(66, 117, 37, 171, 13, 149, 200, 241),
+      crate::word(13, 0, 0, 0, 1, 0, 1, 0),
+      crate::word(217, 114, 76, 98, 9, 197, 63, 169),
+      crate::word(2, 0, 7, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(21, 0, 0, 0, 194, 0, 0, 0),
+      crate::word(29, 0, 0, 0, 7, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(25, 0, 0, 0, 175, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+      crate::word(97, 112, 110, 112, 58, 65, 110, 110),
+      crate::word(111, 116, 97, 116, 105, 111, 110, 0),
+      crate::word(0, 0, 0, 0, 1, 0, 1, 0),
+      crate::word(12, 0, 0, 0, 3, 0, 4, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(69, 0, 0, 0, 26, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(64, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(76, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(2, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 1, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(73, 0, 0, 0, 50, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(68, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(80, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(1, 0, 0, 0, 1, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 2, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(77, 0, 0, 0, 50, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(72, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(84, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(105, 100, 0, 0, 0, 0, 0, 0),
+      crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(118, 97, 108, 117, 101, 0, 0, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(155, 12, 176, 215, 210, 220, 35, 206),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(98, 114, 97, 110, 100, 0, 0, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(43, 66, 101, 96, 240, 85, 52, 144),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "schema.capnp:Annotation";
+    pub fn get_field_types(index: u16) -> crate::introspect::Type {
+      match index {
+        0 => <u64 as crate::introspect::Introspect>::introspect(),
+        1 => <crate::schema_capnp::value::Owned as crate::introspect::Introspect>::introspect(),
+        2 => <crate::schema_capnp::brand::Owned as crate::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1,2];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_ID_OFFSET: usize = 0;
+      pub const FIELD_VALUE_POINTER_INDEX: usize = 0;
+      pub const FIELD_BRAND_POINTER_INDEX: usize = 1;
+    }
+    pub const TYPE_ID: u64 = 0xf1c8_950d_ab25_7542;
+  }
+}
+
+#[repr(u16)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ElementSize {
+  Empty = 0,
+  Bit = 1,
+  Byte = 2,
+  TwoBytes = 3,
+  FourBytes = 4,
+  EightBytes = 5,
+  Pointer = 6,
+  InlineComposite = 7,
+}
+
+impl crate::introspect::Introspect for ElementSize {
+  fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Enum(crate::introspect::RawEnumSchema { encoded_node: &element_size::ENCODED_NODE, annotation_types: element_size::get_annotation_types }).into() }
+}
+impl ElementSize {
+  /// Every enumerant, in ordinal order.
+  pub const VALUES: &[Self] = &[Self::Empty, Self::Bit, Self::Byte, Self::TwoBytes, Self::FourBytes, Self::EightBytes, Self::Pointer, Self::InlineComposite];
+  /// The number of enumerants.
+  pub const COUNT: u16 = 8;
+  /// Iterates over every enumerant, in ordinal order.
+  pub fn iter() -> impl ::core::iter::Iterator<Item = Self> {
+    Self::VALUES.iter().copied()
+  }
+  /// The `(enumerant_index, annotation_id)` pair of each annotation on a enumerant.
+  pub const ENUMERANT_ANNOTATIONS: &'static [(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the enumerant at `enumerant_index`, if
+  /// it has one.
+  pub fn enumerant_annotation(enumerant_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+    let children = crate::schema::EnumSchema::new(crate::introspect::RawEnumSchema { encoded_node: &element_size::ENCODED_NODE, annotation_types: element_size::get_annotation_types }).get_enumerants().ok()?;
+    if enumerant_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(enumerant_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+}
+impl <'a> ::core::convert::From<ElementSize> for crate::dynamic_value::Reader<'a> {
+  fn from(e: ElementSize) -> Self { crate::dynamic_value::Enum::new(e.into(), crate::introspect::RawEnumSchema { encoded_node: &element_size::ENCODED_NODE, annotation_types: element_size::get_annotation_types }.into()).into() }
+}
+impl ::core::convert::TryFrom<u16> for ElementSize {
+  type Error = crate::NotInSchema;
+  fn try_from(value: u16) -> ::core::result::Result<Self, <ElementSize as ::core::convert::TryFrom<u16>>::Error> {
+    match value {
+      0 => ::core::result::Result::Ok(Self::Empty),
+      1 => ::core::result::Result::Ok(Self::Bit),
+      2 => ::core::result::Result::Ok(Self::Byte),
+      3 => ::core::result::Result::Ok(Self::TwoBytes),
+      4 => ::core::result::Result::Ok(Self::FourBytes),
+      5 => ::core::result::Result::Ok(Self::EightBytes),
+      6 => ::core::result::Result::Ok(Self::Pointer),
+      7 => ::core::result::Result::Ok(Self::InlineComposite),
+      n => ::core::result::Result::Err(crate::NotInSchema(n)),
+    }
+  }
+}
+impl ::core::convert::From<ElementSize> for u16 {
+  #[inline]
+  fn from(x: ElementSize) -> u16 { x as u16 }
+}
+impl crate::traits::HasTypeId for ElementSize {
+  const TYPE_ID: u64 = 0xd195_8f7d_ba52_1926u64;
+}
+mod element_size {
+pub static ENCODED_NODE: [crate::Word; 54] = [
+  crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+  crate::word(38, 25, 82, 186, 125, 143, 149, 209),
+  crate::word(13, 0, 0, 0, 2, 0, 0, 0),
+  crate::word(217, 114, 76, 98, 9, 197, 63, 169),
+  crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(21, 0, 0, 0, 202, 0, 0, 0),
+  crate::word(33, 0, 0, 0, 7, 0, 0, 0),
+  crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(29, 0, 0, 0, 199, 0, 0, 0),
+  crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+  crate::word(97, 112, 110, 112, 58, 69, 108, 101),
+  crate::word(109, 101, 110, 116, 83, 105, 122, 101),
+  crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(0, 0, 0, 0, 1, 0, 1, 0),
+  crate::word(32, 0, 0, 0, 1, 0, 2, 0),
+  crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(89, 0, 0, 0, 50, 0, 0, 0),
+  crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(81, 0, 0, 0, 34, 0, 0, 0),
+  crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(2, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(73, 0, 0, 0, 42, 0, 0, 0),
+  crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(3, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(65, 0, 0, 0, 74, 0, 0, 0),
+  crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(4, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(61, 0, 0, 0, 82, 0, 0, 0),
+  crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(5, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(57, 0, 0, 0, 90, 0, 0, 0),
+  crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(6, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(53, 0, 0, 0, 66, 0, 0, 0),
+  crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(45, 0, 0, 0, 130, 0, 0, 0),
+  crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(101, 109, 112, 116, 121, 0, 0, 0),
+  crate::word(98, 105, 116, 0, 0, 0, 0, 0),
+  crate::word(98, 121, 116, 101, 0, 0, 0, 0),
+  crate::word(116, 119, 111, 66, 121, 116, 101, 115),
+  crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(102, 111, 117, 114, 66, 121, 116, 101),
+  crate::word(115, 0, 0, 0, 0, 0, 0, 0),
+  crate::word(101, 105, 103, 104, 116, 66, 121, 116),
+  crate::word(101, 115, 0, 0, 0, 0, 0, 0),
+  crate::word(112, 111, 105, 110, 116, 101, 114, 0),
+  crate::word(105, 110, 108, 105, 110, 101, 67, 111),
+  crate::word(109, 112, 111, 115, 105, 116, 101, 0),
+];
+pub const DISPLAY_NAME: &str = "schema.capnp:ElementSize";
+pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+  panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+}
+}
+
+pub mod capnp_version {
+pub struct CapnpVersion {
+ pub _major: u16,
+ pub _minor: u8,
+ pub _micro: u8,  
+}
+impl CapnpVersion {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+  builder.set_major(self._major);
+  builder.set_minor(self._minor);
+  builder.set_micro(self._micro);
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for CapnpVersion  {
+  type Error = crate::Error;
+  fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _major: reader.get_major(),
+      _minor: reader.get_minor(),
+      _micro: reader.get_micro(),
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for CapnpVersion {
+  type Error = crate::Error;
+  fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl CapnpVersion {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(_counts: FieldCounts) -> usize { 0 }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 1 + 0 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+      self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> crate::Result<usize> {
+      self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_major(self) -> u16 {
+      self.reader.get_data_field::<u16>(0)
+    }
+    #[inline]
+    pub fn get_minor(self) -> u8 {
+      self.reader.get_data_field::<u8>(2)
+    }
+    #[inline]
+    pub fn get_micro(self) -> u8 {
+      self.reader.get_data_field::<u8>(3)
+    }
+  }
+
+  pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+  impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 1, pointers: 0 };
+  }
+  impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+      self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> crate::Result<usize> {
+      self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_major(self) -> u16 {
+      self.builder.get_data_field::<u16>(0)
+    }
+    #[inline]
+    pub fn set_major(&mut self, value: u16)  {
+      self.builder.set_data_field::<u16>(0, value);
+    }
+    #[inline]
+    pub fn set_major_from(&mut self, value: impl ::core::convert::TryInto<u16>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_major(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_minor(self) -> u8 {
+      self.builder.get_data_field::<u8>(2)
+    }
+    #[inline]
+    pub fn set_minor(&mut self, value: u8)  {
+      self.builder.set_data_field::<u8>(2, value);
+    }
+    #[inline]
+    pub fn set_minor_from(&mut self, value: impl ::core::convert::TryInto<u8>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_minor(value);
+      ::core::result::Result::Ok(())
+    }
+    #[inline]
+    pub fn get_micro(self) -> u8 {
+      self.builder.get_data_field::<u8>(3)
+    }
+    #[inline]
+    pub fn set_micro(&mut self, value: u8)  {
+      self.builder.set_data_field::<u8>(3, value);
+    }
+    #[inline]
+    pub fn set_micro_from(&mut self, value: impl ::core::convert::TryInto<u8>) -> crate::Result<()> {
+      let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+      self.set_micro(value);
+      ::core::result::Result::Ok(())
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+  impl crate::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+    let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [crate::Word; 63] = [
+      crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+      crate::word(99, 153, 131, 125, 91, 48, 93, 216),
+      crate::word(13, 0, 0, 0, 1, 0, 1, 0),
+      crate::word(217, 114, 76, 98, 9, 197, 63, 169),
+      crate::word(0, 0, 7, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(21, 0, 0, 0, 210, 0, 0, 0),
+      crate::word(33, 0, 0, 0, 7, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(29, 0, 0, 0, 175, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+      crate::word(97, 112, 110, 112, 58, 67, 97, 112),
+      crate::word(110, 112, 86, 101, 114, 115, 105, 111),
+      crate::word(110, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 1, 0, 1, 0),
+      crate::word(12, 0, 0, 0, 3, 0, 4, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(69, 0, 0, 0, 50, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(64, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(76, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(1, 0, 0, 0, 2, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 1, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(73, 0, 0, 0, 50, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(68, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(80, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(2, 0, 0, 0, 3, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 2, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(77, 0, 0, 0, 50, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(72, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(84, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(109, 97, 106, 111, 114, 0, 0, 0),
+      crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(7, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(109, 105, 110, 111, 114, 0, 0, 0),
+      crate::word(6, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(6, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(109, 105, 99, 114, 111, 0, 0, 0),
+      crate::word(6, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(6, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "schema.capnp:CapnpVersion";
+    pub fn get_field_types(index: u16) -> crate::introspect::Type {
+      match index {
+        0 => <u16 as crate::introspect::Introspect>::introspect(),
+        1 => <u8 as crate::introspect::Introspect>::introspect(),
+        2 => <u8 as crate::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1,2];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_MAJOR_OFFSET: usize = 0;
+      pub const FIELD_MINOR_OFFSET: usize = 2;
+      pub const FIELD_MICRO_OFFSET: usize = 3;
+    }
+    pub const TYPE_ID: u64 = 0xd85d_305b_7d83_9963;
+  }
+}
+
+pub mod code_generator_request {
+pub struct CodeGeneratorRequest {
+ pub _nodes: Vec<crate::schema_capnp::node::Node>,
+ pub _requested_files: Vec<crate::schema_capnp::code_generator_request::requested_file::RequestedFile>,
+ pub _capnp_version: Option<crate::schema_capnp::capnp_version::CapnpVersion>,
+ pub _source_info: Vec<crate::schema_capnp::node::source_info::SourceInfo>,  
+}
+impl CodeGeneratorRequest {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+            
+if self._nodes.len() > 0 {
+                let mut list_builder = builder.reborrow().init_nodes(crate::private::checked_len(self._nodes.len())?);
+                for (i, item) in self._nodes.into_iter().enumerate() {
+                    item.build_capnp_struct(list_builder.reborrow().get(i as u32))?;
+                }
+            }
+            
+if self._requested_files.len() > 0 {
+                let mut list_builder = builder.reborrow().init_requested_files(crate::private::checked_len(self._requested_files.len())?);
+                for (i, item) in self._requested_files.into_iter().enumerate() {
+                    item.build_capnp_struct(list_builder.reborrow().get(i as u32))?;
+                }
+            }
+  if let Some(st) = self._capnp_version {st.build_capnp_struct(builder.reborrow().init_capnp_version())?;}
+            
+if self._source_info.len() > 0 {
+                let mut list_builder = builder.reborrow().init_source_info(crate::private::checked_len(self._source_info.len())?);
+                for (i, item) in self._source_info.into_iter().enumerate() {
+                    item.build_capnp_struct(list_builder.reborrow().get(i as u32))?;
+                }
+            }
+  ::core::result::Result::Ok(())
+}}
+
+impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for CodeGeneratorRequest  {
+  type Error = crate::Error;
+  fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+    ::core::result::Result::Ok(Self {
+      _nodes: reader.get_nodes()?.iter().map(|item| crate::Result::Ok(<crate::schema_capnp::node::Node as ::core::convert::TryFrom<_>>::try_from(item)?)).collect::<crate::Result<Vec<_>>>()?,
+      _requested_files: reader.get_requested_files()?.iter().map(|item| crate::Result::Ok(<crate::schema_capnp::code_generator_request::requested_file::RequestedFile as ::core::convert::TryFrom<_>>::try_from(item)?)).collect::<crate::Result<Vec<_>>>()?,
+      _capnp_version: if reader.has_capnp_version() { ::core::option::Option::Some(<crate::schema_capnp::capnp_version::CapnpVersion as ::core::convert::TryFrom<_>>::try_from(reader.get_capnp_version()?)?) } else { ::core::option::Option::None },
+      _source_info: reader.get_source_info()?.iter().map(|item| crate::Result::Ok(<crate::schema_capnp::node::source_info::SourceInfo as ::core::convert::TryFrom<_>>::try_from(item)?)).collect::<crate::Result<Vec<_>>>()?,
+    })
+  }
+}
+impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for CodeGeneratorRequest {
+  type Error = crate::Error;
+  fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+    <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+  }
+}
+impl CodeGeneratorRequest {
+  /// Writes the value into `builder`, which must be a struct of this type.
+  pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+    self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+  }
+}
+  /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+  ///
+  /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+  /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+  /// the pointer to them. Objects that list elements point to are not included.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct FieldCounts {
+    pub nodes: usize,
+    pub requested_files: usize,
+    pub capnp_version: usize,
+    pub source_info: usize,
+  }
+  impl FieldCounts {
+    /// Zero for all fields except structs, which get the size of a struct with no content.
+    pub const fn new() -> Self {
+      Self {
+        nodes: 0,
+        requested_files: 0,
+        capnp_version: crate::schema_capnp::capnp_version::MIN_SIZE_WORDS - 1,
+        source_info: 0,
+      }
+    }
+  }
+  impl ::core::default::Default for FieldCounts {
+    fn default() -> Self { Self::new() }
+  }
+  /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+  pub const fn variable_words_for(counts: FieldCounts) -> usize {
+    1 + counts.nodes * (crate::schema_capnp::node::MIN_SIZE_WORDS - 1) + 1 + counts.requested_files * (crate::schema_capnp::code_generator_request::requested_file::MIN_SIZE_WORDS - 1) + counts.capnp_version + 1 + counts.source_info * (crate::schema_capnp::node::source_info::MIN_SIZE_WORDS - 1)
+  }
+  /// Words taken up by a message holding just this struct: its data and pointer sections
+  /// plus the root pointer.
+  pub const MIN_SIZE_WORDS: usize = 0 + 4 + 1;
+  /// An upper bound on the words that a single-segment message holding this struct takes up.
+  pub const fn total_words_for(counts: FieldCounts) -> usize {
+    MIN_SIZE_WORDS + variable_words_for(counts)
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+  impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+  impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+  impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+      self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+      self.reader.total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> crate::Result<usize> {
+      self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API.
+    pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+      ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+    }
+    #[inline]
+    pub fn get_nodes(self) -> crate::Result<crate::struct_list::Reader<'a,crate::schema_capnp::node::Owned>> {
+      crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_nodes(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_requested_files(self) -> crate::Result<crate::struct_list::Reader<'a,crate::schema_capnp::code_generator_request::requested_file::Owned>> {
+      crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_requested_files(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
+    }
+    #[inline]
+    pub fn get_capnp_version(self) -> crate::Result<crate::schema_capnp::capnp_version::Reader<'a>> {
+      crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(2), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_capnp_version(&self) -> bool {
+      !self.reader.get_pointer_field(2).is_null()
+    }
+    #[inline]
+    pub fn get_source_info(self) -> crate::Result<crate::struct_list::Reader<'a,crate::schema_capnp::node::source_info::Owned>> {
+      crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(3), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_source_info(&self) -> bool {
+      !self.reader.get_pointer_field(3).is_null()
+    }
+  }
+
+  pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+  impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+    const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 0, pointers: 4 };
+  }
+  impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+      self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+      ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+    }
+    fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+    fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+
+    /// The number of words that serializing a message with a copy of this struct as its
+    /// root takes, including the segment table.
+    pub fn serialized_words(&self) -> crate::Result<usize> {
+      self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+    }
+
+    /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+    pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+      ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+    }
+
+    /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+    /// Fails with `TypeMismatch`, naming both types, if it is not.
+    pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+      let src_proto = src.get_schema().get_proto();
+      if src_proto.get_id() != _private::TYPE_ID {
+        let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+        ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+        return ::core::result::Result::Err(error);
+      }
+      let src: Reader<'_,> = src.downcast()?;
+      self.builder.copy_content_from(&src.reader)
+    }
+    #[inline]
+    pub fn get_nodes(self) -> crate::Result<crate::struct_list::Builder<'a,crate::schema_capnp::node::Owned>> {
+      crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_nodes(&mut self, value: crate::struct_list::Reader<'a,crate::schema_capnp::node::Owned>) -> crate::Result<()> {
+      crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_nodes(self, size: u32) -> crate::struct_list::Builder<'a,crate::schema_capnp::node::Owned> {
+      crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), size)
+    }
+    #[inline]
+    pub fn disown_nodes(&mut self) -> crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::node::Owned>> {
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+    }
+    #[inline]
+    pub fn adopt_nodes(&mut self, orphan: crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::node::Owned>>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_nodes(&mut self, size: u32) -> crate::Result<crate::struct_list::Builder<'_,crate::schema_capnp::node::Owned>> {
+      if self.builder.is_pointer_field_null(0) {
+        ::core::result::Result::Ok(self.reborrow().init_nodes(size))
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_nodes(&self) -> bool {
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn get_requested_files(self) -> crate::Result<crate::struct_list::Builder<'a,crate::schema_capnp::code_generator_request::requested_file::Owned>> {
+      crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_requested_files(&mut self, value: crate::struct_list::Reader<'a,crate::schema_capnp::code_generator_request::requested_file::Owned>) -> crate::Result<()> {
+      crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(1), value, false)
+    }
+    #[inline]
+    pub fn init_requested_files(self, size: u32) -> crate::struct_list::Builder<'a,crate::schema_capnp::code_generator_request::requested_file::Owned> {
+      crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
+    }
+    #[inline]
+    pub fn disown_requested_files(&mut self) -> crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::code_generator_request::requested_file::Owned>> {
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+    }
+    #[inline]
+    pub fn adopt_requested_files(&mut self, orphan: crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::code_generator_request::requested_file::Owned>>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(1).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_requested_files(&mut self, size: u32) -> crate::Result<crate::struct_list::Builder<'_,crate::schema_capnp::code_generator_request::requested_file::Owned>> {
+      if self.builder.is_pointer_field_null(1) {
+        ::core::result::Result::Ok(self.reborrow().init_requested_files(size))
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(1), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_requested_files(&self) -> bool {
+      !self.builder.is_pointer_field_null(1)
+    }
+    #[inline]
+    pub fn get_capnp_version(self) -> crate::Result<crate::schema_capnp::capnp_version::Builder<'a>> {
+      crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(2), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_capnp_version(&mut self, value: crate::schema_capnp::capnp_version::Reader<'_>) -> crate::Result<()> {
+      crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(2), value, false)
+    }
+    #[inline]
+    pub fn init_capnp_version(self, ) -> crate::schema_capnp::capnp_version::Builder<'a> {
+      crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), 0)
+    }
+    #[inline]
+    pub fn disown_capnp_version(&mut self) -> crate::Orphan<crate::schema_capnp::capnp_version::Owned> {
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(2).disown())
+    }
+    #[inline]
+    pub fn adopt_capnp_version(&mut self, orphan: crate::Orphan<crate::schema_capnp::capnp_version::Owned>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(2).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_capnp_version(&mut self, ) -> crate::Result<crate::schema_capnp::capnp_version::Builder<'_>> {
+      if self.builder.is_pointer_field_null(2) {
+        ::core::result::Result::Ok(self.reborrow().init_capnp_version())
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(2), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_capnp_version(&self) -> bool {
+      !self.builder.is_pointer_field_null(2)
+    }
+    #[inline]
+    pub fn get_source_info(self) -> crate::Result<crate::struct_list::Builder<'a,crate::schema_capnp::node::source_info::Owned>> {
+      crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(3), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_source_info(&mut self, value: crate::struct_list::Reader<'a,crate::schema_capnp::node::source_info::Owned>) -> crate::Result<()> {
+      crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(3), value, false)
+    }
+    #[inline]
+    pub fn init_source_info(self, size: u32) -> crate::struct_list::Builder<'a,crate::schema_capnp::node::source_info::Owned> {
+      crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), size)
+    }
+    #[inline]
+    pub fn disown_source_info(&mut self) -> crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::node::source_info::Owned>> {
+      crate::Orphan::new(self.builder.reborrow().get_pointer_field(3).disown())
+    }
+    #[inline]
+    pub fn adopt_source_info(&mut self, orphan: crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::node::source_info::Owned>>) -> crate::Result<()> {
+      self.builder.reborrow().get_pointer_field(3).adopt(orphan.into_internal())
+    }
+    #[inline]
+    pub fn get_or_init_source_info(&mut self, size: u32) -> crate::Result<crate::struct_list::Builder<'_,crate::schema_capnp::node::source_info::Owned>> {
+      if self.builder.is_pointer_field_null(3) {
+        ::core::result::Result::Ok(self.reborrow().init_source_info(size))
+      } else {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(3), ::core::option::Option::None)
+      }
+    }
+    #[inline]
+    pub fn has_source_info(&self) -> bool {
+      !self.builder.is_pointer_field_null(3)
+    }
+  }
+
+  impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+    }
+  }
+
+  pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+  impl crate::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl ::core::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      f.write_str(::core::any::type_name::<Self>())
+    }
+  }
+  impl Pipeline  {
+    #[inline]
+    pub fn get_capnp_version(&self) -> crate::schema_capnp::capnp_version::Pipeline {
+      crate::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(2))
+    }
+  }
+  /// The `(field_index, annotation_id)` pair of each annotation on a field.
+  pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+  ];
+  /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+  /// it has one.
+  pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+    let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+    if field_index >= children.len() {
+      return ::core::option::Option::None;
+    }
+    children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+  }
+  pub mod _private {
+    pub static ENCODED_NODE: [crate::Word; 98] = [
+      crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+      crate::word(206, 215, 10, 33, 246, 70, 197, 191),
+      crate::word(13, 0, 0, 0, 1, 0, 0, 0),
+      crate::word(217, 114, 76, 98, 9, 197, 63, 169),
+      crate::word(4, 0, 7, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(21, 0, 0, 0, 18, 1, 0, 0),
+      crate::word(37, 0, 0, 0, 23, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(49, 0, 0, 0, 231, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+      crate::word(97, 112, 110, 112, 58, 67, 111, 100),
+      crate::word(101, 71, 101, 110, 101, 114, 97, 116),
+      crate::word(111, 114, 82, 101, 113, 117, 101, 115),
+      crate::word(116, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(4, 0, 0, 0, 1, 0, 1, 0),
+      crate::word(98, 0, 129, 46, 176, 14, 234, 207),
+      crate::word(1, 0, 0, 0, 114, 0, 0, 0),
+      crate::word(82, 101, 113, 117, 101, 115, 116, 101),
+      crate::word(100, 70, 105, 108, 101, 0, 0, 0),
+      crate::word(16, 0, 0, 0, 3, 0, 4, 0),
+      crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(97, 0, 0, 0, 50, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(92, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(120, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(3, 0, 0, 0, 1, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 1, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(117, 0, 0, 0, 122, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(116, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(144, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(0, 0, 0, 0, 2, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 2, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(141, 0, 0, 0, 106, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(140, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(152, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(2, 0, 0, 0, 3, 0, 0, 0),
+      crate::word(0, 0, 1, 0, 3, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(149, 0, 0, 0, 90, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(148, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(176, 0, 0, 0, 2, 0, 1, 0),
+      crate::word(110, 111, 100, 101, 115, 0, 0, 0),
+      crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(23, 164, 35, 249, 76, 171, 130, 230),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(114, 101, 113, 117, 101, 115, 116, 101),
+      crate::word(100, 70, 105, 108, 101, 115, 0, 0),
+      crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(98, 0, 129, 46, 176, 14, 234, 207),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(99, 97, 112, 110, 112, 86, 101, 114),
+      crate::word(115, 105, 111, 110, 0, 0, 0, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(99, 153, 131, 125, 91, 48, 93, 216),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(115, 111, 117, 114, 99, 101, 73, 110),
+      crate::word(102, 111, 0, 0, 0, 0, 0, 0),
+      crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 3, 0, 1, 0),
+      crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(174, 87, 19, 4, 227, 29, 142, 243),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub const DISPLAY_NAME: &str = "schema.capnp:CodeGeneratorRequest";
+    pub fn get_field_types(index: u16) -> crate::introspect::Type {
+      match index {
+        0 => <crate::struct_list::Owned<crate::schema_capnp::node::Owned> as crate::introspect::Introspect>::introspect(),
+        1 => <crate::struct_list::Owned<crate::schema_capnp::code_generator_request::requested_file::Owned> as crate::introspect::Introspect>::introspect(),
+        2 => <crate::schema_capnp::capnp_version::Owned as crate::introspect::Introspect>::introspect(),
+        3 => <crate::struct_list::Owned<crate::schema_capnp::node::source_info::Owned> as crate::introspect::Introspect>::introspect(),
+        _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+      panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+    }
+    pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1,2,3];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub mod layout {
+      pub const FIELD_NODES_POINTER_INDEX: usize = 0;
+      pub const FIELD_REQUESTED_FILES_POINTER_INDEX: usize = 1;
+      pub const FIELD_CAPNP_VERSION_POINTER_INDEX: usize = 2;
+      pub const FIELD_SOURCE_INFO_POINTER_INDEX: usize = 3;
+    }
+    pub const TYPE_ID: u64 = 0xbfc5_46f6_210a_d7ce;
+  }
+
+  pub mod requested_file {
+  pub struct RequestedFile {
+ pub _id: u64,
+ pub _filename: String,
+ pub _imports: Vec<crate::schema_capnp::code_generator_request::requested_file::import::Import>,  
+}
+  impl RequestedFile {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+  builder.set_id(self._id);
+  builder.set_filename(self._filename.as_str().into());
+            
+if self._imports.len() > 0 {
+                let mut list_builder = builder.reborrow().init_imports(crate::private::checked_len(self._imports.len())?);
+                for (i, item) in self._imports.into_iter().enumerate() {
+                    item.build_capnp_struct(list_builder.reborrow().get(i as u32))?;
+                }
+            }
+  ::core::result::Result::Ok(())
+}}
+  
+  impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for RequestedFile  {
+    type Error = crate::Error;
+    fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+      ::core::result::Result::Ok(Self {
+        _id: reader.get_id(),
+        _filename: reader.get_filename()?.to_string()?,
+        _imports: reader.get_imports()?.iter().map(|item| crate::Result::Ok(<crate::schema_capnp::code_generator_request::requested_file::import::Import as ::core::convert::TryFrom<_>>::try_from(item)?)).collect::<crate::Result<Vec<_>>>()?,
+      })
+    }
+  }
+  impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for RequestedFile {
+    type Error = crate::Error;
+    fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+      <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+    }
+  }
+  impl RequestedFile {
+    /// Writes the value into `builder`, which must be a struct of this type.
+    pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+      self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+    }
+  }
+    /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+    ///
+    /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+    /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+    /// the pointer to them. Objects that list elements point to are not included.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldCounts {
+      pub filename: usize,
+      pub imports: usize,
+    }
+    impl FieldCounts {
+      /// Zero for all fields except structs, which get the size of a struct with no content.
+      pub const fn new() -> Self {
+        Self {
+          filename: 0,
+          imports: 0,
+        }
+      }
+    }
+    impl ::core::default::Default for FieldCounts {
+      fn default() -> Self { Self::new() }
+    }
+    /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+    pub const fn variable_words_for(counts: FieldCounts) -> usize {
+      (counts.filename + 8) / 8 + 1 + counts.imports * (crate::schema_capnp::code_generator_request::requested_file::import::MIN_SIZE_WORDS - 1)
+    }
+    /// Words taken up by a message holding just this struct: its data and pointer sections
+    /// plus the root pointer.
+    pub const MIN_SIZE_WORDS: usize = 1 + 2 + 1;
+    /// An upper bound on the words that a single-segment message holding this struct takes up.
+    pub const fn total_words_for(counts: FieldCounts) -> usize {
+      MIN_SIZE_WORDS + variable_words_for(counts)
+    }
+    #[derive(Copy, Clone)]
+    pub struct Owned(());
+    impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+    impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+    impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+    impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+    pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+    impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+    impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+      fn clone(&self) -> Self { *self }
+    }
+
+    impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+      fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+        Self { reader,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+      fn from(reader: Reader<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+      fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(reader.get_struct(default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+      fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+      fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+        self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> Reader<'a,>  {
+      pub fn reborrow(&self) -> Reader<'_,> {
+        Self { .. *self }
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.reader.total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API.
+      pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+        ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+      }
+      #[inline]
+      pub fn get_id(self) -> u64 {
+        self.reader.get_data_field::<u64>(0)
+      }
+      #[inline]
+      pub fn get_filename(self) -> crate::Result<crate::text::Reader<'a>> {
+        crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn has_filename(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
+      #[inline]
+      pub fn get_imports(self) -> crate::Result<crate::struct_list::Reader<'a,crate::schema_capnp::code_generator_request::requested_file::import::Owned>> {
+        crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(1), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn has_imports(&self) -> bool {
+        !self.reader.get_pointer_field(1).is_null()
+      }
+    }
+
+    pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+    impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+      const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 1, pointers: 2 };
+    }
+    impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+      const TYPE_ID: u64 = _private::TYPE_ID;
+    }
+    impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+      fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+        Self { builder,  }
+      }
+    }
+
+    impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+      fn from(builder: Builder<'a,>) -> Self {
+        Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+      }
+    }
+
+    impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+      fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+        self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+      fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+        builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+      }
+      fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+      }
+      fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+      }
+    }
+
+    impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+      fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+    }
+
+    impl <'a,> Builder<'a,>  {
+      pub fn into_reader(self) -> Reader<'a,> {
+        self.builder.into_reader().into()
+      }
+      pub fn reborrow(&mut self) -> Builder<'_,> {
+        Builder { builder: self.builder.reborrow() }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+        self.builder.as_reader().into()
+      }
+
+      pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+        self.builder.as_reader().total_size()
+      }
+
+      /// The number of words that serializing a message with a copy of this struct as its
+      /// root takes, including the segment table.
+      pub fn serialized_words(&self) -> crate::Result<usize> {
+        self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+      }
+
+      /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+      pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+        ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+      }
+
+      /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+      /// Fails with `TypeMismatch`, naming both types, if it is not.
+      pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+        let src_proto = src.get_schema().get_proto();
+        if src_proto.get_id() != _private::TYPE_ID {
+          let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+          ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+          return ::core::result::Result::Err(error);
+        }
+        let src: Reader<'_,> = src.downcast()?;
+        self.builder.copy_content_from(&src.reader)
+      }
+      #[inline]
+      pub fn get_id(self) -> u64 {
+        self.builder.get_data_field::<u64>(0)
+      }
+      #[inline]
+      pub fn set_id(&mut self, value: u64)  {
+        self.builder.set_data_field::<u64>(0, value);
+      }
+      #[inline]
+      pub fn set_id_from(&mut self, value: impl ::core::convert::TryInto<u64>) -> crate::Result<()> {
+        let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+        self.set_id(value);
+        ::core::result::Result::Ok(())
+      }
+      #[inline]
+      pub fn get_filename(self) -> crate::Result<crate::text::Builder<'a>> {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn set_filename(&mut self, value: crate::text::Reader<'_>)  {
+        self.builder.reborrow().get_pointer_field(0).set_text(value);
+      }
+      #[inline]
+      pub fn init_filename(self, size: u32) -> crate::text::Builder<'a> {
+        self.builder.get_pointer_field(0).init_text(size)
+      }
+      #[inline]
+      pub fn disown_filename(&mut self) -> crate::Orphan<crate::text::Owned> {
+        crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+      }
+      #[inline]
+      pub fn adopt_filename(&mut self, orphan: crate::Orphan<crate::text::Owned>) -> crate::Result<()> {
+        self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+      }
+      #[inline]
+      pub fn get_or_init_filename(&mut self, size: u32) -> crate::Result<crate::text::Builder<'_>> {
+        if self.builder.is_pointer_field_null(0) {
+          ::core::result::Result::Ok(self.reborrow().init_filename(size))
+        } else {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+        }
+      }
+      #[inline]
+      pub fn has_filename(&self) -> bool {
+        !self.builder.is_pointer_field_null(0)
+      }
+      #[inline]
+      pub fn get_imports(self) -> crate::Result<crate::struct_list::Builder<'a,crate::schema_capnp::code_generator_request::requested_file::import::Owned>> {
+        crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(1), ::core::option::Option::None)
+      }
+      #[inline]
+      pub fn set_imports(&mut self, value: crate::struct_list::Reader<'a,crate::schema_capnp::code_generator_request::requested_file::import::Owned>) -> crate::Result<()> {
+        crate::traits::SetPointerBuilder::set_pointer_builder(self.builder.reborrow().get_pointer_field(1), value, false)
+      }
+      #[inline]
+      pub fn init_imports(self, size: u32) -> crate::struct_list::Builder<'a,crate::schema_capnp::code_generator_request::requested_file::import::Owned> {
+        crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
+      }
+      #[inline]
+      pub fn disown_imports(&mut self) -> crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::code_generator_request::requested_file::import::Owned>> {
+        crate::Orphan::new(self.builder.reborrow().get_pointer_field(1).disown())
+      }
+      #[inline]
+      pub fn adopt_imports(&mut self, orphan: crate::Orphan<crate::struct_list::Owned<crate::schema_capnp::code_generator_request::requested_file::import::Owned>>) -> crate::Result<()> {
+        self.builder.reborrow().get_pointer_field(1).adopt(orphan.into_internal())
+      }
+      #[inline]
+      pub fn get_or_init_imports(&mut self, size: u32) -> crate::Result<crate::struct_list::Builder<'_,crate::schema_capnp::code_generator_request::requested_file::import::Owned>> {
+        if self.builder.is_pointer_field_null(1) {
+          ::core::result::Result::Ok(self.reborrow().init_imports(size))
+        } else {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(1), ::core::option::Option::None)
+        }
+      }
+      #[inline]
+      pub fn has_imports(&self) -> bool {
+        !self.builder.is_pointer_field_null(1)
+      }
+    }
+
+    impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+      }
+    }
+
+    pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+    impl crate::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+        Self { _typeless: typeless,  }
+      }
+    }
+    impl ::core::fmt::Debug for Pipeline {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+        f.write_str(::core::any::type_name::<Self>())
+      }
+    }
+    impl Pipeline  {
+    }
+    /// The `(field_index, annotation_id)` pair of each annotation on a field.
+    pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+    ];
+    /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+    /// it has one.
+    pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+      let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+      if field_index >= children.len() {
+        return ::core::option::Option::None;
+      }
+      children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+    }
+    pub mod _private {
+      pub static ENCODED_NODE: [crate::Word; 73] = [
+        crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+        crate::word(98, 0, 129, 46, 176, 14, 234, 207),
+        crate::word(34, 0, 0, 0, 1, 0, 1, 0),
+        crate::word(206, 215, 10, 33, 246, 70, 197, 191),
+        crate::word(2, 0, 7, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(21, 0, 0, 0, 130, 1, 0, 0),
+        crate::word(41, 0, 0, 0, 23, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(49, 0, 0, 0, 175, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+        crate::word(97, 112, 110, 112, 58, 67, 111, 100),
+        crate::word(101, 71, 101, 110, 101, 114, 97, 116),
+        crate::word(111, 114, 82, 101, 113, 117, 101, 115),
+        crate::word(116, 46, 82, 101, 113, 117, 101, 115),
+        crate::word(116, 101, 100, 70, 105, 108, 101, 0),
+        crate::word(4, 0, 0, 0, 1, 0, 1, 0),
+        crate::word(229, 87, 35, 18, 147, 65, 80, 174),
+        crate::word(1, 0, 0, 0, 58, 0, 0, 0),
+        crate::word(73, 109, 112, 111, 114, 116, 0, 0),
+        crate::word(12, 0, 0, 0, 3, 0, 4, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(69, 0, 0, 0, 26, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(64, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(76, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 1, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(73, 0, 0, 0, 74, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(72, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(84, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(2, 0, 0, 0, 1, 0, 0, 0),
+        crate::word(0, 0, 1, 0, 2, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(81, 0, 0, 0, 66, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(76, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(104, 0, 0, 0, 2, 0, 1, 0),
+        crate::word(105, 100, 0, 0, 0, 0, 0, 0),
+        crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(102, 105, 108, 101, 110, 97, 109, 101),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(12, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(12, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(105, 109, 112, 111, 114, 116, 115, 0),
+        crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 3, 0, 1, 0),
+        crate::word(16, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(229, 87, 35, 18, 147, 65, 80, 174),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(14, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ];
+      pub const DISPLAY_NAME: &str = "schema.capnp:CodeGeneratorRequest.RequestedFile";
+      pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        match index {
+          0 => <u64 as crate::introspect::Introspect>::introspect(),
+          1 => <crate::text::Owned as crate::introspect::Introspect>::introspect(),
+          2 => <crate::struct_list::Owned<crate::schema_capnp::code_generator_request::requested_file::import::Owned> as crate::introspect::Introspect>::introspect(),
+          _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+        }
+      }
+      pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+        panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+      }
+      pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+        encoded_node: &ENCODED_NODE,
+        nonunion_members: NONUNION_MEMBERS,
+        members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      };
+      pub static NONUNION_MEMBERS : &[u16] = &[0,1,2];
+      pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+      pub mod layout {
+        pub const FIELD_ID_OFFSET: usize = 0;
+        pub const FIELD_FILENAME_POINTER_INDEX: usize = 0;
+        pub const FIELD_IMPORTS_POINTER_INDEX: usize = 1;
+      }
+      pub const TYPE_ID: u64 = 0xcfea_0eb0_2e81_0062;
+    }
+
+    pub mod import {
+    pub struct Import {
+ pub _id: u64,
+ pub _name: String,  
+}
+    impl Import {
+pub fn build_capnp_struct<'a,>(self, mut builder: Builder<'a,>) -> crate::Result<()>  {
+  builder.set_id(self._id);
+  builder.set_name(self._name.as_str().into());
+  ::core::result::Result::Ok(())
+}}
+    
+    impl <'a,> ::core::convert::TryFrom<Reader<'a,>> for Import  {
+      type Error = crate::Error;
+      fn try_from(reader: Reader<'a,>) -> crate::Result<Self> {
+        ::core::result::Result::Ok(Self {
+          _id: reader.get_id(),
+          _name: reader.get_name()?.to_string()?,
+        })
+      }
+    }
+    impl <'a> ::core::convert::TryFrom<crate::dynamic_struct::Reader<'a>> for Import {
+      type Error = crate::Error;
+      fn try_from(reader: crate::dynamic_struct::Reader<'a>) -> crate::Result<Self> {
+        <Self as ::core::convert::TryFrom<Reader<'a>>>::try_from(reader.downcast::<Reader<'a>>()?)
+      }
+    }
+    impl Import {
+      /// Writes the value into `builder`, which must be a struct of this type.
+      pub fn to_dynamic(self, builder: crate::dynamic_struct::Builder<'_>) -> crate::Result<()> {
+        self.build_capnp_struct(builder.downcast::<Builder<'_>>()?)
+      }
+    }
+      /// Sizes of the variable-size fields of this struct, for computing message size bounds.
+      ///
+      /// Text and data fields are measured in bytes, excluding the NUL terminator of text, and
+      /// list fields in elements. Struct and `AnyPointer` fields are measured in words, excluding
+      /// the pointer to them. Objects that list elements point to are not included.
+      #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+      pub struct FieldCounts {
+        pub name: usize,
+      }
+      impl FieldCounts {
+        /// Zero for all fields except structs, which get the size of a struct with no content.
+        pub const fn new() -> Self {
+          Self {
+            name: 0,
+          }
+        }
+      }
+      impl ::core::default::Default for FieldCounts {
+        fn default() -> Self { Self::new() }
+      }
+      /// An upper bound on the words that the variable-size fields take up beyond the struct itself.
+      pub const fn variable_words_for(counts: FieldCounts) -> usize {
+        (counts.name + 8) / 8
+      }
+      /// Words taken up by a message holding just this struct: its data and pointer sections
+      /// plus the root pointer.
+      pub const MIN_SIZE_WORDS: usize = 1 + 1 + 1;
+      /// An upper bound on the words that a single-segment message holding this struct takes up.
+      pub const fn total_words_for(counts: FieldCounts) -> usize {
+        MIN_SIZE_WORDS + variable_words_for(counts)
+      }
+      #[derive(Copy, Clone)]
+      pub struct Owned(());
+      impl ::core::fmt::Debug for Owned { fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { f.write_str(_private::DISPLAY_NAME) } }
+      impl crate::introspect::Introspect for Owned { fn introspect() -> crate::introspect::Type { crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+      impl crate::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+      impl crate::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+      impl crate::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+      pub struct Reader<'a> { reader: crate::private::layout::StructReader<'a> }
+      impl <'a,> ::core::marker::Copy for Reader<'a,>  {}
+      impl <'a,> ::core::clone::Clone for Reader<'a,>  {
+        fn clone(&self) -> Self { *self }
+      }
+
+      impl <'a,> crate::traits::HasTypeId for Reader<'a,>  {
+        const TYPE_ID: u64 = _private::TYPE_ID;
+      }
+      impl <'a,> ::core::convert::From<crate::private::layout::StructReader<'a>> for Reader<'a,>  {
+        fn from(reader: crate::private::layout::StructReader<'a>) -> Self {
+          Self { reader,  }
+        }
+      }
+
+      impl <'a,> ::core::convert::From<Reader<'a,>> for crate::dynamic_value::Reader<'a>  {
+        fn from(reader: Reader<'a,>) -> Self {
+          Self::Struct(crate::dynamic_struct::Reader::new(reader.reader, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+        }
+      }
+
+      impl <'a,> ::core::fmt::Debug for Reader<'a,>  {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+          core::fmt::Debug::fmt(&::core::convert::Into::<crate::dynamic_value::Reader<'_>>::into(*self), f)
+        }
+      }
+
+      impl <'a,> crate::traits::FromPointerReader<'a> for Reader<'a,>  {
+        fn get_from_pointer(reader: &crate::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+          ::core::result::Result::Ok(reader.get_struct(default)?.into())
+        }
+      }
+
+      impl <'a,> crate::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+        fn into_internal_struct_reader(self) -> crate::private::layout::StructReader<'a> {
+          self.reader
+        }
+      }
+
+      impl <'a,> crate::traits::Imbue<'a> for Reader<'a,>  {
+        fn imbue(&mut self, cap_table: &'a crate::private::layout::CapTable) {
+          self.reader.imbue(crate::private::layout::CapTableReader::Plain(cap_table))
+        }
+      }
+
+      impl <'a,> Reader<'a,>  {
+        pub fn reborrow(&self) -> Reader<'_,> {
+          Self { .. *self }
+        }
+
+        pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+          self.reader.total_size()
+        }
+
+        /// The number of words that serializing a message with a copy of this struct as its
+        /// root takes, including the segment table.
+        pub fn serialized_words(&self) -> crate::Result<usize> {
+          self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+        }
+
+        /// Views this struct through the dynamic API.
+        pub fn as_dynamic(self) -> crate::dynamic_struct::Reader<'a> {
+          ::core::convert::Into::<crate::dynamic_value::Reader<'a>>::into(self).downcast()
+        }
+        #[inline]
+        pub fn get_id(self) -> u64 {
+          self.reader.get_data_field::<u64>(0)
+        }
+        #[inline]
+        pub fn get_name(self) -> crate::Result<crate::text::Reader<'a>> {
+          crate::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+        }
+        #[inline]
+        pub fn has_name(&self) -> bool {
+          !self.reader.get_pointer_field(0).is_null()
+        }
+      }
+
+      pub struct Builder<'a> { builder: crate::private::layout::StructBuilder<'a> }
+      impl <'a,> crate::traits::HasStructSize for Builder<'a,>  {
+        const STRUCT_SIZE: crate::private::layout::StructSize = crate::private::layout::StructSize { data: 1, pointers: 1 };
+      }
+      impl <'a,> crate::traits::HasTypeId for Builder<'a,>  {
+        const TYPE_ID: u64 = _private::TYPE_ID;
+      }
+      impl <'a,> ::core::convert::From<crate::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+        fn from(builder: crate::private::layout::StructBuilder<'a>) -> Self {
+          Self { builder,  }
+        }
+      }
+
+      impl <'a,> ::core::convert::From<Builder<'a,>> for crate::dynamic_value::Builder<'a>  {
+        fn from(builder: Builder<'a,>) -> Self {
+          Self::Struct(crate::dynamic_struct::Builder::new(builder.builder, crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+        }
+      }
+
+      impl <'a,> crate::traits::ImbueMut<'a> for Builder<'a,>  {
+        fn imbue_mut(&mut self, cap_table: &'a mut crate::private::layout::CapTable) {
+          self.builder.imbue(crate::private::layout::CapTableBuilder::Plain(cap_table))
+        }
+      }
+
+      impl <'a,> crate::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+        fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+          builder.init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE).into()
+        }
+        fn try_init_pointer(builder: crate::private::layout::PointerBuilder<'a>, _size: u32) -> crate::Result<Self> {
+          ::core::result::Result::Ok(builder.try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?.into())
+        }
+        fn get_from_pointer(builder: crate::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [crate::Word]>) -> crate::Result<Self> {
+          ::core::result::Result::Ok(builder.get_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+        }
+      }
+
+      impl <'a,> crate::traits::SetPointerBuilder for Reader<'a,>  {
+        fn set_pointer_builder(mut pointer: crate::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> crate::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+      }
+
+      impl <'a,> Builder<'a,>  {
+        pub fn into_reader(self) -> Reader<'a,> {
+          self.builder.into_reader().into()
+        }
+        pub fn reborrow(&mut self) -> Builder<'_,> {
+          Builder { builder: self.builder.reborrow() }
+        }
+        pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+          self.builder.as_reader().into()
+        }
+
+        pub fn total_size(&self) -> crate::Result<crate::MessageSize> {
+          self.builder.as_reader().total_size()
+        }
+
+        /// The number of words that serializing a message with a copy of this struct as its
+        /// root takes, including the segment table.
+        pub fn serialized_words(&self) -> crate::Result<usize> {
+          self.total_size().map(crate::serialize::compute_serialized_size_of_root)
+        }
+
+        /// Views this struct through the dynamic API, for as long as this builder is borrowed.
+        pub fn as_dynamic_mut(&mut self) -> crate::dynamic_struct::Builder<'_> {
+          ::core::convert::Into::<crate::dynamic_value::Builder<'_>>::into(self.reborrow()).downcast()
+        }
+
+        /// Overwrites this struct with a copy of `src`, which must be a struct of this same type.
+        /// Fails with `TypeMismatch`, naming both types, if it is not.
+        pub fn copy_from_dynamic(&mut self, src: crate::dynamic_struct::Reader<'_>) -> crate::Result<()> {
+          let src_proto = src.get_schema().get_proto();
+          if src_proto.get_id() != _private::TYPE_ID {
+            let mut error = crate::Error::from_kind(crate::ErrorKind::TypeMismatch);
+            ::core::write!(error, "cannot copy a `{}` into a `{}`", src_proto.get_display_name()?.to_str()?, _private::DISPLAY_NAME);
+            return ::core::result::Result::Err(error);
+          }
+          let src: Reader<'_,> = src.downcast()?;
+          self.builder.copy_content_from(&src.reader)
+        }
+        #[inline]
+        pub fn get_id(self) -> u64 {
+          self.builder.get_data_field::<u64>(0)
+        }
+        #[inline]
+        pub fn set_id(&mut self, value: u64)  {
+          self.builder.set_data_field::<u64>(0, value);
+        }
+        #[inline]
+        pub fn set_id_from(&mut self, value: impl ::core::convert::TryInto<u64>) -> crate::Result<()> {
+          let value = value.try_into().map_err(|_| crate::Error::from_kind(crate::ErrorKind::ValueOutOfRange))?;
+          self.set_id(value);
+          ::core::result::Result::Ok(())
+        }
+        #[inline]
+        pub fn get_name(self) -> crate::Result<crate::text::Builder<'a>> {
+          crate::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+        }
+        #[inline]
+        pub fn set_name(&mut self, value: crate::text::Reader<'_>)  {
+          self.builder.reborrow().get_pointer_field(0).set_text(value);
+        }
+        #[inline]
+        pub fn init_name(self, size: u32) -> crate::text::Builder<'a> {
+          self.builder.get_pointer_field(0).init_text(size)
+        }
+        #[inline]
+        pub fn disown_name(&mut self) -> crate::Orphan<crate::text::Owned> {
+          crate::Orphan::new(self.builder.reborrow().get_pointer_field(0).disown())
+        }
+        #[inline]
+        pub fn adopt_name(&mut self, orphan: crate::Orphan<crate::text::Owned>) -> crate::Result<()> {
+          self.builder.reborrow().get_pointer_field(0).adopt(orphan.into_internal())
+        }
+        #[inline]
+        pub fn get_or_init_name(&mut self, size: u32) -> crate::Result<crate::text::Builder<'_>> {
+          if self.builder.is_pointer_field_null(0) {
+            ::core::result::Result::Ok(self.reborrow().init_name(size))
+          } else {
+            crate::traits::FromPointerBuilder::get_from_pointer(self.builder.reborrow().get_pointer_field(0), ::core::option::Option::None)
+          }
+        }
+        #[inline]
+        pub fn has_name(&self) -> bool {
+          !self.builder.is_pointer_field_null(0)
+        }
+      }
+
+      impl <'a,> ::core::fmt::Debug for Builder<'a,>  {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+          ::core::fmt::Debug::fmt(&self.reborrow_as_reader(), f)
+        }
+      }
+
+      pub struct Pipeline { _typeless: crate::any_pointer::Pipeline }
+      impl crate::capability::FromTypelessPipeline for Pipeline {
+        fn new(typeless: crate::any_pointer::Pipeline) -> Self {
+          Self { _typeless: typeless,  }
+        }
+      }
+      impl ::core::fmt::Debug for Pipeline {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+          f.write_str(::core::any::type_name::<Self>())
+        }
+      }
+      impl Pipeline  {
+      }
+      /// The `(field_index, annotation_id)` pair of each annotation on a field.
+      pub const FIELD_ANNOTATIONS: &[(u16, u64)] = &[
+      ];
+      /// Returns the value of annotation `annotation_id` on the field at `field_index`, if
+      /// it has one.
+      pub fn field_annotation(field_index: u16, annotation_id: u64) -> ::core::option::Option<crate::dynamic_value::Reader<'static>> {
+        let children = crate::schema::StructSchema::new(crate::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).get_fields().ok()?;
+        if field_index >= children.len() {
+          return ::core::option::Option::None;
+        }
+        children.get(field_index).get_annotations().ok()?.find(annotation_id)?.get_value().ok()
+      }
+      pub mod _private {
+        pub static ENCODED_NODE: [crate::Word; 51] = [
+          crate::word(0, 0, 0, 0, 5, 0, 6, 0),
+          crate::word(229, 87, 35, 18, 147, 65, 80, 174),
+          crate::word(48, 0, 0, 0, 1, 0, 1, 0),
+          crate::word(98, 0, 129, 46, 176, 14, 234, 207),
+          crate::word(1, 0, 7, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(21, 0, 0, 0, 186, 1, 0, 0),
+          crate::word(45, 0, 0, 0, 7, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(41, 0, 0, 0, 119, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(115, 99, 104, 101, 109, 97, 46, 99),
+          crate::word(97, 112, 110, 112, 58, 67, 111, 100),
+          crate::word(101, 71, 101, 110, 101, 114, 97, 116),
+          crate::word(111, 114, 82, 101, 113, 117, 101, 115),
+          crate::word(116, 46, 82, 101, 113, 117, 101, 115),
+          crate::word(116, 101, 100, 70, 105, 108, 101, 46),
+          crate::word(73, 109, 112, 111, 114, 116, 0, 0),
+          crate::word(0, 0, 0, 0, 1, 0, 1, 0),
+          crate::word(8, 0, 0, 0, 3, 0, 4, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 1, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(41, 0, 0, 0, 26, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(36, 0, 0, 0, 3, 0, 1, 0),
+          crate::word(48, 0, 0, 0, 2, 0, 1, 0),
+          crate::word(1, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 1, 0, 1, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(45, 0, 0, 0, 42, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(40, 0, 0, 0, 3, 0, 1, 0),
+          crate::word(52, 0, 0, 0, 2, 0, 1, 0),
+          crate::word(105, 100, 0, 0, 0, 0, 0, 0),
+          crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(9, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(110, 97, 109, 101, 0, 0, 0, 0),
+          crate::word(12, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(12, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+          crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+        ];
+        pub const DISPLAY_NAME: &str = "schema.capnp:CodeGeneratorRequest.RequestedFile.Import";
+        pub fn get_field_types(index: u16) -> crate::introspect::Type {
+          match index {
+            0 => <u64 as crate::introspect::Introspect>::introspect(),
+            1 => <crate::text::Owned as crate::introspect::Introspect>::introspect(),
+            _ => panic!("invalid field index {} of {}", index, DISPLAY_NAME),
+          }
+        }
+        pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+          panic!("invalid annotation indices ({:?}, {}) of {}", child_index, index, DISPLAY_NAME)
+        }
+        pub static RAW_SCHEMA: crate::introspect::RawStructSchema = crate::introspect::RawStructSchema {
+          encoded_node: &ENCODED_NODE,
+          nonunion_members: NONUNION_MEMBERS,
+          members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+        };
+        pub static NONUNION_MEMBERS : &[u16] = &[0,1];
+        pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+        pub mod layout {
+          pub const FIELD_ID_OFFSET: usize = 0;
+          pub const FIELD_NAME_POINTER_INDEX: usize = 0;
+        }
+        pub const TYPE_ID: u64 = 0xae50_4193_1223_57e5;
+      }
+    }
+  }
+}
@@ -0,0 +1,179 @@
+use capnpc::schema_diff::{diff, IncompatibilityKind, Severity};
+
+fn compile(source: &str) -> Vec<u8> {
+    capnpc::CompilerCommand::new()
+        .capnp_executable(env!("CAPNP_EXECUTABLE"))
+        .compile_from_memory(&[("diff.capnp", source)])
+        .unwrap()
+}
+
+// Compiles both versions of a schema and returns `(path, kind)` of each change.
+fn changes(old: &str, new: &str) -> Vec<(String, IncompatibilityKind)> {
+    diff(&compile(old), &compile(new))
+        .unwrap()
+        .into_iter()
+        .map(|change| {
+            assert_eq!(change.severity, change.kind.severity());
+            (change.path, change.kind)
+        })
+        .collect()
+}
+
+fn change(path: &str, kind: IncompatibilityKind) -> (String, IncompatibilityKind) {
+    (format!("diff.capnp:{path}"), kind)
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn unchanged_and_renamed() {
+    let old = r#"
+        @0xd0a1c5f3e6b7a829;
+        struct Foo { a @0 :UInt32; b @1 :Text; grp :group { c @2 :Bool; } }
+        enum Color { red @0; green @1; }
+        interface Service { call @0 (x :Foo) -> (y :Color); }
+    "#;
+    assert_eq!(changes(old, old), []);
+
+    let renamed = r#"
+        @0xd0a1c5f3e6b7a829;
+        struct Foo { alpha @0 :UInt32; beta @1 :Text; grp :group { gamma @2 :Bool; } }
+        enum Color { rouge @0; vert @1; }
+        interface Service { invoke @0 (x :Foo) -> (y :Color); }
+    "#;
+    assert_eq!(changes(old, renamed), []);
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn fields() {
+    use IncompatibilityKind::*;
+
+    let old = r#"
+        @0xd0a1c5f3e6b7a829;
+        struct Foo { a @0 :UInt16; b @1 :UInt16; c @2 :Text; }
+    "#;
+    let new = r#"
+        @0xd0a1c5f3e6b7a829;
+        struct Foo { a @0 :UInt32; b @1 :UInt16; }
+    "#;
+    assert_eq!(
+        changes(old, new),
+        [
+            change("Foo.a", ChangedFieldType),
+            change("Foo.b", ChangedFieldOffset),
+            change("Foo.c", RemovedField),
+        ]
+    );
+
+    let added = changes(
+        new,
+        &new.replace("b @1 :UInt16;", "b @1 :UInt16; d @2 :Data;"),
+    );
+    assert_eq!(added, [change("Foo.d", AddedField)]);
+    assert_eq!(added[0].1.severity(), Severity::Benign);
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn discriminants() {
+    use IncompatibilityKind::*;
+
+    let old = r#"
+        @0xd0a1c5f3e6b7a829;
+        struct Foo { a @0 :UInt32; b @1 :Bool; }
+    "#;
+    let new = r#"
+        @0xd0a1c5f3e6b7a829;
+        struct Foo { union { a @0 :UInt32; b @1 :Bool; } }
+    "#;
+    assert_eq!(
+        changes(old, new),
+        [
+            change("Foo.a", ChangedDiscriminant),
+            change("Foo.b", ChangedDiscriminant),
+            // The discriminant now takes the space that `b` had.
+            change("Foo.b", ChangedFieldOffset),
+        ]
+    );
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn enumerants() {
+    use IncompatibilityKind::*;
+
+    let old = r#"
+        @0xd0a1c5f3e6b7a829;
+        enum Color { red @0; green @1; blue @2; }
+    "#;
+    let new = r#"
+        @0xd0a1c5f3e6b7a829;
+        enum Color { red @0; green @1; }
+    "#;
+    assert_eq!(changes(old, new), [change("Color.blue", RemovedEnumerant)]);
+    assert_eq!(changes(new, old), [change("Color.blue", AddedEnumerant)]);
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn methods() {
+    use IncompatibilityKind::*;
+
+    let old = r#"
+        @0xd0a1c5f3e6b7a829;
+        struct Args { x @0 :Int32; }
+        struct OtherArgs { y @0 :Int32; }
+        interface Service {
+          first @0 Args -> ();
+          second @1 () -> ();
+          third @2 () -> ();
+        }
+    "#;
+    let new = r#"
+        @0xd0a1c5f3e6b7a829;
+        struct Args { x @0 :Int32; }
+        struct OtherArgs { y @0 :Int32; }
+        interface Service {
+          first @0 OtherArgs -> ();
+          third @1 () -> ();
+          fourth @2 () -> ();
+          fifth @3 () -> ();
+        }
+    "#;
+    assert_eq!(
+        changes(old, new),
+        [
+            change("Service.first", ChangedMethodType),
+            change("Service.second", RemovedMethod),
+            change("Service.third", ChangedMethodOrdinal),
+            change("Service.fifth", AddedMethod),
+        ]
+    );
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn node_ids() {
+    use IncompatibilityKind::*;
+
+    let old = r#"
+        @0xd0a1c5f3e6b7a829;
+        struct Foo @0xe1a2b3c4d5e6f708 { a @0 :UInt32; }
+        struct Bar { a @0 :UInt32; }
+    "#;
+    let new = r#"
+        @0xd0a1c5f3e6b7a829;
+        struct Foo @0xe1a2b3c4d5e6f709 { a @0 :UInt32; }
+    "#;
+    let diff = diff(&compile(old), &compile(new)).unwrap();
+    assert_eq!(diff.len(), 2);
+    assert_eq!(diff[0].path, "diff.capnp:Foo");
+    assert_eq!(diff[0].kind, ChangedNodeId);
+    assert_eq!(
+        diff[0].to_string(),
+        "`diff.capnp:Foo` changed its ID from 0xe1a2b3c4d5e6f708 to 0xe1a2b3c4d5e6f709"
+    );
+    assert_eq!(diff[1].path, "diff.capnp:Bar");
+    assert_eq!(diff[1].kind, RemovedNode);
+    assert_eq!(diff[1].severity, Severity::Breaking);
+}
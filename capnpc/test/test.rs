@@ -62,6 +62,22 @@ pub mod test_default_parent_module {
     }
 }
 
+pub mod test_recursion_capnp {
+    include!(concat!(env!("OUT_DIR"), "/test_recursion_capnp.rs"));
+}
+
+pub mod test_upgrade_v1_capnp {
+    include!(concat!(env!("OUT_DIR"), "/test_upgrade_v1_capnp.rs"));
+}
+
+pub mod test_upgrade_v2_capnp {
+    include!(concat!(env!("OUT_DIR"), "/test_upgrade_v2_capnp.rs"));
+}
+
+pub mod test_reserved_names_capnp {
+    include!(concat!(env!("OUT_DIR"), "/test_reserved_names_capnp.rs"));
+}
+
 pub mod test_in_dir_capnp {
     include!(concat!(env!("OUT_DIR"), "/schema/test_in_dir_capnp.rs"));
 }
@@ -78,18 +94,115 @@ pub mod test_output_path_capnp {
     ));
 }
 
+pub mod test_no_convenience_builders_capnp {
+    include!(concat!(
+        env!("OUT_DIR"),
+        "/no-convenience-builders/test_no_convenience_builders_capnp.rs"
+    ));
+}
+
+pub mod test_lenient_enums_capnp {
+    // Generated code must not depend on the prelude's `From`.
+    #[allow(dead_code)]
+    trait From {}
+
+    include!(concat!(
+        env!("OUT_DIR"),
+        "/lenient-enums/test_lenient_enums_capnp.rs"
+    ));
+}
+
+pub mod test_canonical_nans_capnp {
+    include!(concat!(
+        env!("OUT_DIR"),
+        "/canonical-nans/test_canonical_nans_capnp.rs"
+    ));
+}
+
+pub mod capnp_get {
+    pub mod test_accessor_style_capnp {
+        include!(concat!(
+            env!("OUT_DIR"),
+            "/capnp_get/test_accessor_style_capnp.rs"
+        ));
+    }
+    pub mod test_reserved_names_capnp {
+        include!(concat!(
+            env!("OUT_DIR"),
+            "/capnp_get/test_reserved_names_capnp.rs"
+        ));
+    }
+}
+
+pub mod rust_naming {
+    pub mod test_accessor_style_capnp {
+        include!(concat!(
+            env!("OUT_DIR"),
+            "/rust_naming/test_accessor_style_capnp.rs"
+        ));
+    }
+    pub mod test_reserved_names_capnp {
+        include!(concat!(
+            env!("OUT_DIR"),
+            "/rust_naming/test_reserved_names_capnp.rs"
+        ));
+    }
+}
+
+pub mod test_import_annotations_capnp {
+    include!(concat!(
+        env!("OUT_DIR"),
+        "/skip-files/test_import_annotations_capnp.rs"
+    ));
+}
+
+pub mod test_aliases_capnp {
+    include!(concat!(env!("OUT_DIR"), "/aliases/test_aliases_capnp.rs"));
+}
+
+pub mod test_split_capnp {
+    include!(concat!(env!("OUT_DIR"), "/split/test_split_capnp.rs"));
+}
+
+pub mod extensions {
+    pub mod test_split_capnp {
+        include!(concat!(env!("OUT_DIR"), "/extensions/test_split_capnp.rs"));
+    }
+}
+
+pub mod cfg_on {
+    pub mod test_split_capnp {
+        include!(concat!(env!("OUT_DIR"), "/cfg-on/test_split_capnp.rs"));
+    }
+}
+
+pub mod cfg_off {
+    pub mod test_split_capnp {
+        include!(concat!(env!("OUT_DIR"), "/cfg-off/test_split_capnp.rs"));
+    }
+}
+
 #[cfg(test)]
 mod test_util;
 
 #[cfg(test)]
 mod dynamic;
 
+#[cfg(test)]
+mod arbitrary;
+
+#[cfg(test)]
+mod schema_diff;
+
+#[cfg(test)]
+mod golden;
+
 #[cfg(test)]
 mod tests {
     use crate::test_util::{init_test_message, CheckTestMessage};
     use capnp::message::ReaderOptions;
     use capnp::message::{self, TypedBuilder, TypedReader};
-    use capnp::{primitive_list, text, Word};
+    use capnp::{primitive_list, text, text_list, Word};
 
     // like the unstable std::assert_matches::assert_matches but doesn't
     // require $left implement Debug
@@ -208,6 +321,185 @@ mod tests {
         assert_eq!(test_prim_list_reader.get_void_list().unwrap().len(), 1025);
     }
 
+    #[test]
+    fn test_prim_list_bulk_operations() {
+        use crate::test_capnp::test_defaults;
+
+        let mut message = message::Builder::new_default();
+        let mut root = message.init_root::<test_defaults::Builder<'_>>();
+
+        // The fields all have defaults, which must not leak into the initialized lists.
+        let bytes: Vec<u8> = (0..=255).collect();
+        let words: Vec<u32> = (0..1000u32).map(|i| i.wrapping_mul(0x0101_0101)).collect();
+        let doubles = [1.5, -0.0, f64::INFINITY, 1e300];
+        let bools = [true, false, false, true, true, false, true, true, false];
+        root.reborrow().init_u_int8_list_from_slice(&bytes).unwrap();
+        root.reborrow()
+            .init_u_int32_list_from_slice(&words)
+            .unwrap();
+        root.reborrow()
+            .init_float64_list_from_slice(&doubles)
+            .unwrap();
+        root.reborrow().init_bool_list_from_slice(&bools).unwrap();
+
+        {
+            let reader = root.reborrow_as_reader();
+            assert_eq!(
+                reader.get_u_int8_list().unwrap().iter().collect::<Vec<_>>(),
+                bytes
+            );
+            assert_eq!(
+                reader
+                    .get_u_int32_list()
+                    .unwrap()
+                    .iter()
+                    .collect::<Vec<_>>(),
+                words
+            );
+            let float64_list = reader.get_float64_list().unwrap();
+            assert_eq!(float64_list.len(), 4);
+            for (i, value) in doubles.iter().enumerate() {
+                assert_eq!(float64_list.get(i as u32).to_bits(), value.to_bits());
+            }
+            assert_eq!(
+                reader.get_bool_list().unwrap().iter().collect::<Vec<_>>(),
+                bools
+            );
+        }
+
+        let mut uint32_list = root.reborrow().get_u_int32_list().unwrap();
+        uint32_list.fill(7);
+        assert!(uint32_list.reborrow().into_reader().iter().all(|x| x == 7));
+        uint32_list.as_mut_slice().unwrap()[3] = 8;
+        assert_eq!(uint32_list.get(3), 8);
+
+        let mut bool_list = root.reborrow().get_bool_list().unwrap();
+        bool_list.fill(true);
+        assert!(bool_list.reborrow().into_reader().iter().all(|x| x));
+        bool_list.set_from_slice(&[false; 9]);
+        assert!(bool_list.into_reader().iter().all(|x| !x));
+    }
+
+    #[test]
+    #[should_panic(expected = "source slice length does not match list length")]
+    fn test_prim_list_set_from_slice_length_mismatch() {
+        use crate::test_capnp::test_all_types;
+
+        let mut message = message::Builder::new_default();
+        let root = message.init_root::<test_all_types::Builder<'_>>();
+        root.init_u_int16_list(3).set_from_slice(&[1, 2]);
+    }
+
+    #[test]
+    fn test_list_too_long() {
+        use crate::test_capnp::{test_all_types, test_prim_list, test_struct_list};
+
+        let max = (1 << 29) - 1;
+        assert_eq!(capnp::private::checked_len(max).unwrap(), max as u32);
+        for len in [1 << 29, u32::MAX as usize, usize::MAX] {
+            let error = capnp::private::checked_len(len).unwrap_err();
+            assert_eq!(error.kind, capnp::ErrorKind::ListTooLong(len));
+        }
+
+        // A `Vec` of zero-sized items can claim to be too long without allocating anything.
+        let huge = || vec![(); 1 << 29];
+
+        let mut message = message::Builder::new_default();
+        let root = message.init_root::<test_all_types::Builder<'_>>();
+        let error = root.init_void_list_from_slice(&huge()).err().unwrap();
+        assert_eq!(error.kind, capnp::ErrorKind::ListTooLong(1 << 29));
+
+        let prim_list = || test_prim_list::TestPrimList {
+            _uint8_list: vec![1, 2],
+            _int8_list: Vec::new(),
+            _uint16_list: Vec::new(),
+            _int16_list: Vec::new(),
+            _uint32_list: Vec::new(),
+            _int32_list: Vec::new(),
+            _uint64_list: Vec::new(),
+            _int64_list: Vec::new(),
+            _float32_list: Vec::new(),
+            _bool_list: Vec::new(),
+            _void_list: huge(),
+        };
+        let error = prim_list()
+            .build_capnp_struct(message.init_root())
+            .unwrap_err();
+        assert_eq!(error.kind, capnp::ErrorKind::ListTooLong(1 << 29));
+
+        // The error comes back out of nested params structs too.
+        let struct_list = test_struct_list::TestStructList {
+            _struct_list: vec![prim_list()],
+        };
+        let error = struct_list
+            .build_capnp_struct(message.init_root())
+            .unwrap_err();
+        assert_eq!(error.kind, capnp::ErrorKind::ListTooLong(1 << 29));
+    }
+
+    #[test]
+    fn test_text_and_data_list_from_iter() {
+        use crate::test_capnp::test_defaults;
+
+        let mut message = message::Builder::new_default();
+        let mut root = message.init_root::<test_defaults::Builder<'_>>();
+
+        // An empty iterator replaces the default with an empty list.
+        root.set_text_list_from_iter(std::iter::empty::<&str>())
+            .unwrap();
+        root.set_data_list_from_iter(Vec::<Vec<u8>>::new()).unwrap();
+        {
+            let reader = root.reborrow_as_reader();
+            assert!(reader.has_text_list());
+            assert_eq!(reader.get_text_list().unwrap().len(), 0);
+            assert!(reader.has_data_list());
+            assert_eq!(reader.get_data_list().unwrap().len(), 0);
+        }
+
+        root.set_text_list_from_iter(["only"]).unwrap();
+        root.set_data_list_from_iter([b"only"]).unwrap();
+        {
+            let reader = root.reborrow_as_reader();
+            let text_list = reader.get_text_list().unwrap();
+            assert_eq!(text_list.len(), 1);
+            assert_eq!(text_list.get(0).unwrap(), "only");
+            let data_list = reader.get_data_list().unwrap();
+            assert_eq!(data_list.len(), 1);
+            assert_eq!(data_list.get(0).unwrap(), b"only");
+        }
+
+        let words = vec![String::from("foo"), String::new(), String::from("baz")];
+        root.set_text_list_from_iter(&words).unwrap();
+        root.set_data_list_from_iter(words.iter().map(|w| w.as_bytes()))
+            .unwrap();
+        {
+            let reader = root.reborrow_as_reader();
+            let text_list = reader.get_text_list().unwrap();
+            assert_eq!(
+                text_list
+                    .iter()
+                    .map(|t| t.unwrap().to_str().unwrap())
+                    .collect::<Vec<_>>(),
+                ["foo", "", "baz"]
+            );
+            let data_list = reader.get_data_list().unwrap();
+            assert_eq!(
+                data_list.iter().map(|d| d.unwrap()).collect::<Vec<_>>(),
+                [&b"foo"[..], b"", b"baz"]
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "fewer values than list elements")]
+    fn test_text_list_set_all_length_mismatch() {
+        use crate::test_capnp::test_all_types;
+
+        let mut message = message::Builder::new_default();
+        let root = message.init_root::<test_all_types::Builder<'_>>();
+        root.init_text_list(3).set_all(["a", "b"]);
+    }
+
     #[test]
     fn test_struct_list() {
         use crate::test_capnp::test_struct_list;
@@ -265,7 +557,7 @@ mod tests {
         {
             let mut text = test_blob.reborrow().init_text_field(10);
             assert_eq!(text, "\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00");
-            text.push_str("aabbccddee");
+            text.push_str("aabbccddee").unwrap();
         }
 
         test_blob.reborrow().init_data_field(7);
@@ -299,6 +591,201 @@ mod tests {
         );
     }
 
+    #[test]
+    fn text_with_invalid_utf8() {
+        use crate::test_capnp::test_blob;
+        use capnp::serialize;
+
+        let mut message = message::Builder::new_default();
+        let mut root = message.init_root::<test_blob::Builder<'_>>();
+        root.set_text_field(capnp::text::Reader(b"caf\xe9 ok"));
+        assert_eq!(
+            root.reborrow().get_text_field().unwrap().to_string_lossy(),
+            "caf\u{fffd} ok"
+        );
+
+        let words = serialize::write_message_to_words(&message);
+        let message = serialize::read_message(words.as_slice(), ReaderOptions::new()).unwrap();
+        let text = message
+            .get_root::<test_blob::Reader<'_>>()
+            .unwrap()
+            .get_text_field()
+            .unwrap();
+        assert!(text.to_str().is_err());
+        assert_eq!(text.as_bytes(), b"caf\xe9 ok");
+        assert_eq!(text.to_string_lossy(), "caf\u{fffd} ok");
+
+        // Valid text is borrowed rather than copied.
+        let valid = capnp::text::Reader::from("ok");
+        assert!(matches!(
+            valid.to_string_lossy(),
+            std::borrow::Cow::Borrowed("ok")
+        ));
+    }
+
+    #[test]
+    fn text_builder_resizing() {
+        use crate::test_capnp::{test_all_types, test_blob};
+
+        let mut message = message::Builder::new_default();
+        let mut root = message.init_root::<test_blob::Builder<'_>>();
+
+        // A freshly initialized text is already at capacity, so pushing can only overwrite it.
+        {
+            let mut text = root.reborrow().init_text_field(3);
+            assert_eq!(text.len(), text.capacity());
+            text.push_str("abc").unwrap();
+            assert_eq!(
+                text.push_str("d").unwrap_err().kind,
+                capnp::ErrorKind::TextCapacityExceeded
+            );
+            assert_eq!(text, "abc");
+        }
+        {
+            let mut text = root.reborrow().init_text_field(12);
+            assert_eq!(text.capacity(), 12);
+            text.push_str("hello world!").unwrap();
+            text.truncate(5);
+            assert_eq!(text, "hello");
+            assert_eq!(text.capacity(), 12);
+        }
+        assert_eq!(root.reborrow_as_reader().get_text_field().unwrap(), "hello");
+
+        // Pushing grows the string back within its allocation.
+        {
+            let mut text = root.reborrow().get_text_field().unwrap();
+            assert_eq!(text.len(), 5);
+            assert_eq!(text.capacity(), 5);
+            assert_eq!(
+                text.push_str("!").unwrap_err().kind,
+                capnp::ErrorKind::TextCapacityExceeded
+            );
+        }
+        {
+            let mut text = root.reborrow().init_text_field(8);
+            text.clear();
+            assert!(text.is_empty());
+            text.push_str("abc").unwrap();
+            text.push_ascii(b'd');
+            text.push_str("efgh").unwrap();
+            assert_eq!(
+                text.push_str("i").unwrap_err().kind,
+                capnp::ErrorKind::TextCapacityExceeded
+            );
+            assert_eq!(text, "abcdefgh");
+            text.truncate(2);
+            text.push_str("Z").unwrap();
+        }
+        let reader = root.reborrow_as_reader();
+        assert_eq!(reader.get_text_field().unwrap(), "abZ");
+
+        // The message stays valid after a round trip through serialization.
+        let mut buffer = Vec::new();
+        capnp::serialize::write_message(&mut buffer, &message).unwrap();
+        let message =
+            capnp::serialize::read_message(&mut buffer.as_slice(), ReaderOptions::new()).unwrap();
+        let root = message.get_root::<test_blob::Reader<'_>>().unwrap();
+        assert_eq!(root.get_text_field().unwrap(), "abZ");
+
+        // Text in a list, reached through a far pointer.
+        let mut message =
+            message::Builder::new(message::HeapAllocator::new().first_segment_words(1));
+        let root = message.init_root::<test_all_types::Builder<'_>>();
+        let mut list = root.init_text_list(1);
+        {
+            list.set(0, "abcd".into());
+            let mut text = list.reborrow().get(0).unwrap();
+            text.truncate(1);
+            text.push_str("z").unwrap();
+        }
+        assert_eq!(list.reborrow().get(0).unwrap(), "az");
+        assert_eq!(list.into_reader().get(0).unwrap(), "az");
+    }
+
+    #[test]
+    fn size_bounds() {
+        use crate::test_capnp::{test_all_types, test_groups, test_lists};
+
+        fn words_used<A: message::Allocator>(message: &message::Builder<A>) -> usize {
+            let segments = message.get_segments_for_output();
+            assert_eq!(segments.len(), 1);
+            segments[0].len() / 8
+        }
+
+        // An empty struct takes up exactly its minimum size.
+        let mut message = message::Builder::new_default();
+        message.init_root::<test_all_types::Builder<'_>>();
+        assert_eq!(words_used(&message), test_all_types::MIN_SIZE_WORDS);
+
+        let counts = test_all_types::FieldCounts {
+            text_field: 11,
+            data_field: 3,
+            bool_list: 70,
+            int16_list: 5,
+            u_int64_list: 2,
+            struct_list: 3,
+            ..Default::default()
+        };
+        let bound = test_all_types::total_words_for(counts);
+        let mut buffer = vec![capnp::word(0, 0, 0, 0, 0, 0, 0, 0); bound];
+        let mut message = message::Builder::new(message::SingleSegmentAllocator::new(
+            capnp::Word::words_to_bytes_mut(&mut buffer),
+        ));
+        {
+            let mut root = message.init_root::<test_all_types::Builder<'_>>();
+            root.set_text_field("hello world".into());
+            root.set_data_field(&[1, 2, 3]);
+            root.reborrow().init_bool_list(70);
+            root.reborrow().init_int16_list(5);
+            root.reborrow().init_u_int64_list(2);
+            root.reborrow().init_struct_list(3);
+            root.reborrow().init_struct_field();
+        }
+        assert_eq!(words_used(&message), bound);
+
+        // The text that the elements of a text list point to is not counted.
+        let counts = test_all_types::FieldCounts {
+            text_list: 2,
+            struct_field: 0,
+            ..Default::default()
+        };
+        let mut message = message::Builder::new_default();
+        {
+            let mut texts = message
+                .init_root::<test_all_types::Builder<'_>>()
+                .init_text_list(2);
+            texts.set(0, "a".into());
+            texts.set(1, "b".into());
+        }
+        // The bound covers an empty text field and an empty struct list, which were not set,
+        // but misses the two words of text that the list elements point to.
+        assert_eq!(
+            words_used(&message),
+            test_all_types::total_words_for(counts) - 2 + 2
+        );
+
+        // Groups contribute through their own counts.
+        let mut counts = test_groups::FieldCounts::new();
+        counts.groups.baz.grault = 20;
+        counts.groups.baz.garply = 3;
+        let mut message = message::Builder::new_default();
+        {
+            let mut baz = message
+                .init_root::<test_groups::Builder<'_>>()
+                .init_groups()
+                .init_baz();
+            baz.set_grault("twenty characters!!!".into());
+            baz.set_garply("abc".into());
+        }
+        assert!(words_used(&message) <= test_groups::total_words_for(counts));
+
+        // Lists of lists only count the inner list pointers.
+        const LISTS_BOUND: usize = test_lists::total_words_for(test_lists::FieldCounts::new());
+        let mut message = message::Builder::new_default();
+        message.init_root::<test_lists::Builder<'_>>();
+        assert!(words_used(&message) <= LISTS_BOUND);
+    }
+
     #[test]
     fn test_big_struct() {
         use crate::test_capnp::test_big_struct;
@@ -611,6 +1098,48 @@ mod tests {
         CheckTestMessage::check_test_message(test_defaults);
     }
 
+    #[test]
+    fn test_default_constants() {
+        use crate::test_capnp::test_default_constants;
+        use capnp::{dynamic_struct, dynamic_value};
+
+        let mut message = message::Builder::new_default();
+        let mut root = message.init_root::<test_default_constants::Builder<'_>>();
+
+        // Fields of the same name in different groups keep their own defaults.
+        let first = root.reborrow().get_first().get_config().unwrap();
+        assert_eq!(
+            first.get_int8_list().unwrap().as_slice(),
+            Some(&mut [1, 2][..])
+        );
+        let second = root.reborrow().get_second().get_config().unwrap();
+        assert_eq!(
+            second.get_int8_list().unwrap().as_slice(),
+            Some(&mut [3, 4][..])
+        );
+
+        // Makes `shared` the active member without setting it, so that it reads its default.
+        let mut dynamic =
+            dynamic_value::Builder::from(root.reborrow()).downcast::<dynamic_struct::Builder<'_>>();
+        let field = dynamic.get_schema().get_field_by_name("shared").unwrap();
+        dynamic.clear(field).unwrap();
+
+        // Identical defaults are read from the same array.
+        let root = root.into_reader();
+        let test_default_constants::Shared(shared) = root.which().unwrap() else {
+            panic!("`shared` is not the active member");
+        };
+        let shared = shared.unwrap();
+        let also_shared = root.get_also_shared().unwrap();
+        assert_eq!(shared, "shared default");
+        assert_eq!(also_shared, "shared default");
+        assert_eq!(shared.as_bytes().as_ptr(), also_shared.as_bytes().as_ptr());
+        assert_eq!(
+            root.get_shared_list().unwrap().as_slice(),
+            Some(&[1, 2][..])
+        );
+    }
+
     #[test]
     fn test_any_pointer() {
         use crate::test_capnp::{test_any_pointer, test_big_struct, test_empty_struct};
@@ -670,42 +1199,192 @@ mod tests {
     }
 
     #[test]
-    fn test_writable_struct_pointer() {
-        use crate::test_capnp::test_big_struct;
+    fn test_any_pointer_checked() {
+        use crate::test_capnp::{test_all_types, test_any_pointer, test_blob, test_prim_list};
 
         let mut message = message::Builder::new_default();
-        let mut big_struct = message.init_root::<test_big_struct::Builder<'_>>();
+        let mut root = message.init_root::<test_any_pointer::Builder<'_>>();
 
-        let neg_seven: u64 = (-7i64) as u64;
-        {
-            let mut struct_field = big_struct.reborrow().init_struct_field();
-            assert_eq!(struct_field.reborrow().get_uint64_field(), 0);
+        root.reborrow().get_any_pointer_field().set_as_text("xyzzy");
+        assert_eq!(
+            root.reborrow()
+                .into_reader()
+                .get_any_pointer_field_as::<::capnp::text::Owned>()
+                .unwrap(),
+            "xyzzy"
+        );
+        let error = root
+            .reborrow()
+            .into_reader()
+            .get_any_pointer_field_as::<test_all_types::Owned>()
+            .err()
+            .unwrap();
+        assert_eq!(error.kind, capnp::ErrorKind::TypeMismatch);
+        assert_eq!(
+            error.to_string(),
+            "type mismatch: expected a pointer to struct `test.capnp:TestAllTypes`, but found a list"
+        );
 
-            struct_field.set_uint64_field(neg_seven);
-            assert_eq!(struct_field.get_uint64_field(), neg_seven);
-        }
+        root.reborrow()
+            .get_any_pointer_field()
+            .set_as_data(&[1, 2, 3]);
         assert_eq!(
-            big_struct
-                .reborrow()
-                .get_struct_field()
-                .unwrap()
-                .get_uint64_field(),
-            neg_seven
+            root.reborrow()
+                .get_any_pointer_field_as::<::capnp::data::Owned>()
+                .unwrap(),
+            &[1, 2, 3]
         );
+
         {
-            let mut struct_field = big_struct.reborrow().init_struct_field();
-            assert_eq!(struct_field.reborrow().get_uint64_field(), 0);
-            assert_eq!(struct_field.get_uint32_field(), 0);
+            let mut message = message::Builder::new_default();
+            let mut blob = message.init_root::<test_blob::Builder<'_>>();
+            blob.set_text_field("abc".into());
+            root.reborrow()
+                .get_any_pointer_field()
+                .set_as_struct::<test_blob::Owned>(blob.into_reader())
+                .unwrap();
         }
+        let blob = root
+            .reborrow()
+            .into_reader()
+            .get_any_pointer_field_as::<test_blob::Owned>()
+            .unwrap();
+        assert_eq!(blob.get_text_field().unwrap(), "abc");
+        // TestAllTypes also starts with a text and a data field, so it is accepted too.
+        root.reborrow()
+            .into_reader()
+            .get_any_pointer_field()
+            .get_as_checked::<test_all_types::Owned>()
+            .unwrap();
+        assert!(root
+            .reborrow()
+            .into_reader()
+            .get_any_pointer_field_as::<::capnp::struct_list::Owned<test_blob::Owned>>()
+            .is_err());
 
         {
-            // getting before init is the same as init
-            assert_eq!(
-                big_struct
-                    .reborrow()
-                    .get_another_struct_field()
-                    .unwrap()
-                    .get_uint64_field(),
+            let mut message = message::Builder::new_default();
+            let mut prim_list = message.init_root::<test_prim_list::Builder<'_>>();
+            prim_list.reborrow().init_uint16_list(2).set(1, 7);
+            root.reborrow()
+                .get_any_pointer_field()
+                .set_as_struct::<test_prim_list::Owned>(prim_list.into_reader())
+                .unwrap();
+        }
+        // Unchecked, the list in the third pointer is silently read as `structField`.
+        root.reborrow()
+            .into_reader()
+            .get_any_pointer_field()
+            .get_as::<test_all_types::Reader<'_>>()
+            .unwrap();
+        let error = root
+            .reborrow()
+            .get_any_pointer_field_as::<test_all_types::Owned>()
+            .err()
+            .unwrap();
+        assert_eq!(error.kind, capnp::ErrorKind::TypeMismatch);
+        assert_eq!(
+            error.to_string(),
+            "type mismatch: field `structField` of `test.capnp:TestAllTypes` expects struct \
+             `test.capnp:TestAllTypes`, but its pointer holds a list"
+        );
+    }
+
+    #[test]
+    fn test_allocation_budget() {
+        use crate::test_capnp::test_all_types;
+
+        let mut big = message::Builder::new_default();
+        {
+            let mut list = big.initn_root::<primitive_list::Builder<'_, u64>>(1000);
+            for i in 0..1000 {
+                list.set(i, u64::from(i));
+            }
+        }
+        let big_list = big
+            .get_root_as_reader::<primitive_list::Reader<'_, u64>>()
+            .unwrap();
+
+        let mut message = message::Builder::new_default();
+        message.set_allocation_budget(100);
+        {
+            let mut root = message
+                .try_init_root::<test_all_types::Builder<'_>>()
+                .unwrap();
+            root.set_u_int32_field(12345);
+            root.set_text_field("hello".into());
+
+            let error = root.set_u_int64_list(big_list).unwrap_err();
+            assert_eq!(error.kind, capnp::ErrorKind::Overloaded);
+            assert!(!root.has_u_int64_list());
+
+            // Smaller allocations still fit in what is left of the budget.
+            root.init_u_int16_list(3);
+        }
+        assert!(message.allocated_words() <= 100);
+
+        let root = message
+            .get_root_as_reader::<test_all_types::Reader<'_>>()
+            .unwrap();
+        assert_eq!(root.get_u_int32_field(), 12345);
+        assert_eq!(root.get_text_field().unwrap(), "hello");
+        assert!(!root.has_u_int64_list());
+        assert_eq!(root.get_u_int16_list().unwrap().len(), 3);
+
+        let mut small = message::Builder::new_default();
+        small.set_allocation_budget(10);
+        let error = small
+            .try_initn_root::<primitive_list::Builder<'_, u64>>(1000)
+            .err()
+            .unwrap();
+        assert_eq!(error.kind, capnp::ErrorKind::Overloaded);
+        small.clear_allocation_budget();
+        assert_eq!(
+            small
+                .try_initn_root::<primitive_list::Builder<'_, u64>>(1000)
+                .unwrap()
+                .len(),
+            1000
+        );
+    }
+
+    #[test]
+    fn test_writable_struct_pointer() {
+        use crate::test_capnp::test_big_struct;
+
+        let mut message = message::Builder::new_default();
+        let mut big_struct = message.init_root::<test_big_struct::Builder<'_>>();
+
+        let neg_seven: u64 = (-7i64) as u64;
+        {
+            let mut struct_field = big_struct.reborrow().init_struct_field();
+            assert_eq!(struct_field.reborrow().get_uint64_field(), 0);
+
+            struct_field.set_uint64_field(neg_seven);
+            assert_eq!(struct_field.get_uint64_field(), neg_seven);
+        }
+        assert_eq!(
+            big_struct
+                .reborrow()
+                .get_struct_field()
+                .unwrap()
+                .get_uint64_field(),
+            neg_seven
+        );
+        {
+            let mut struct_field = big_struct.reborrow().init_struct_field();
+            assert_eq!(struct_field.reborrow().get_uint64_field(), 0);
+            assert_eq!(struct_field.get_uint32_field(), 0);
+        }
+
+        {
+            // getting before init is the same as init
+            assert_eq!(
+                big_struct
+                    .reborrow()
+                    .get_another_struct_field()
+                    .unwrap()
+                    .get_uint64_field(),
                 0
             );
             big_struct
@@ -766,6 +1445,294 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_presence_tracking() -> capnp::Result<()> {
+        use crate::test_capnp::test_presence::{self, TestPresenceWithPresence};
+        use crate::test_capnp::TestEnum;
+
+        // The wrapper tells an unset field apart from one set to its default.
+        let written = TestPresenceWithPresence {
+            count: Some(7),
+            flag: Some(true),
+            ..Default::default()
+        };
+        assert_ne!(written, TestPresenceWithPresence::default());
+        assert_eq!(written.ratio, None);
+
+        let mut message = message::Builder::new_default();
+        written.write_to(message.init_root::<test_presence::Builder<'_>>());
+        let reader = message.get_root_as_reader::<test_presence::Reader<'_>>()?;
+        assert_eq!(reader.get_count(), 7);
+        assert!(reader.get_flag());
+        assert_eq!(reader.get_ratio(), 0.5);
+        assert_eq!(reader.get_color()?, TestEnum::Bar);
+
+        // Fields written with a non-default value survive the round trip; everything else,
+        // including `count`, whose value equals its default, reads back as unset.
+        let read = TestPresenceWithPresence::read_from(reader)?;
+        assert_eq!(
+            read,
+            TestPresenceWithPresence {
+                flag: Some(true),
+                ..Default::default()
+            }
+        );
+
+        let mut root = message.get_root::<test_presence::Builder<'_>>()?;
+        root.set_count(0);
+        root.set_ratio(-1.0);
+        root.set_color(TestEnum::Foo);
+        let read = TestPresenceWithPresence::read_from(root.into_reader())?;
+        assert_eq!(
+            read,
+            TestPresenceWithPresence {
+                count: Some(0),
+                flag: Some(true),
+                ratio: Some(-1.0),
+                color: Some(TestEnum::Foo),
+            }
+        );
+
+        // Writing onto an existing struct leaves the unset fields alone.
+        TestPresenceWithPresence {
+            count: Some(3),
+            ..Default::default()
+        }
+        .write_to(message.get_root::<test_presence::Builder<'_>>()?);
+        let reader = message.get_root_as_reader::<test_presence::Reader<'_>>()?;
+        assert_eq!(reader.get_count(), 3);
+        assert_eq!(reader.get_ratio(), -1.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_visitor() -> capnp::Result<()> {
+        use crate::test_capnp::test_visitor::{self, TestVisitorVisitor};
+        use crate::test_capnp::TestEnum;
+
+        #[derive(Default)]
+        struct Recorder(Vec<String>);
+
+        impl TestVisitorVisitor for Recorder {
+            fn visit_count(&mut self, value: u32) -> capnp::Result<()> {
+                self.0.push(format!("count {value}"));
+                Ok(())
+            }
+            fn visit_name(&mut self, value: capnp::text::Reader<'_>) -> capnp::Result<()> {
+                self.0.push(format!("name {}", value.to_str()?));
+                Ok(())
+            }
+            fn visit_color(&mut self, value: TestEnum) -> capnp::Result<()> {
+                self.0.push(format!("color {value:?}"));
+                Ok(())
+            }
+            fn visit_flags(
+                &mut self,
+                value: capnp::primitive_list::Reader<'_, bool>,
+            ) -> capnp::Result<()> {
+                self.0.push(format!("flags {}", value.len()));
+                Ok(())
+            }
+            fn visit_inner_x(&mut self, value: i16) -> capnp::Result<()> {
+                self.0.push(format!("inner.x {value}"));
+                Ok(())
+            }
+            fn visit_inner_nickname(
+                &mut self,
+                value: Option<capnp::text::Reader<'_>>,
+            ) -> capnp::Result<()> {
+                self.0.push(format!("inner.nickname {}", value.is_some()));
+                Ok(())
+            }
+            fn visit_empty(&mut self) -> capnp::Result<()> {
+                self.0.push("empty".to_string());
+                Ok(())
+            }
+            fn visit_number(&mut self, value: i64) -> capnp::Result<()> {
+                self.0.push(format!("number {value}"));
+                Ok(())
+            }
+            fn visit_pair_left(&mut self, value: u8) -> capnp::Result<()> {
+                self.0.push(format!("pair.left {value}"));
+                Ok(())
+            }
+            fn visit_pair_right(&mut self, value: u8) -> capnp::Result<()> {
+                self.0.push(format!("pair.right {value}"));
+                Ok(())
+            }
+            fn visit_last(&mut self, value: f32) -> capnp::Result<()> {
+                self.0.push(format!("last {value}"));
+                Ok(())
+            }
+        }
+
+        let mut message = message::Builder::new_default();
+        let mut root = message.init_root::<test_visitor::Builder<'_>>();
+        root.set_count(3);
+        root.set_name("foo".into());
+        root.set_color(TestEnum::Baz);
+        root.reborrow().init_flags(2);
+        root.reborrow().init_inner().set_x(-4);
+        root.set_number(12);
+        root.set_last(1.5);
+
+        let mut recorder = Recorder::default();
+        test_visitor::visit_test_visitor(root.reborrow_as_reader(), &mut recorder)?;
+        assert_eq!(
+            recorder.0,
+            [
+                "count 3",
+                "name foo",
+                "color Baz",
+                "flags 2",
+                "inner.x -4",
+                "inner.nickname false",
+                "number 12",
+                "last 1.5",
+            ]
+        );
+
+        // Only the member of the union that is set gets visited.
+        let mut pair = root.reborrow().init_pair();
+        pair.set_left(1);
+        pair.set_right(2);
+        let mut recorder = Recorder::default();
+        test_visitor::visit_test_visitor(root.reborrow_as_reader(), &mut recorder)?;
+        assert_eq!(recorder.0[6..8], ["pair.left 1", "pair.right 2"]);
+        assert_eq!(recorder.0.len(), 9);
+
+        root.set_empty(());
+        let mut recorder = Recorder::default();
+        test_visitor::visit_test_visitor(root.into_reader(), &mut recorder)?;
+        assert_eq!(recorder.0[6], "empty");
+        assert_eq!(recorder.0.len(), 8);
+        Ok(())
+    }
+
+    #[test]
+    fn test_required_fields() -> capnp::Result<()> {
+        use crate::test_capnp::test_required;
+
+        fn missing(result: capnp::Result<()>) -> String {
+            result.unwrap_err().extra
+        }
+
+        let mut message = message::Builder::new_default();
+        let mut root = message.init_root::<test_required::Builder<'_>>();
+        assert_eq!(
+            missing(root.validate()),
+            "missing required fields of `test.capnp:TestRequired`: name, tags, inner.data"
+        );
+        assert_eq!(
+            missing(root.reborrow_as_reader().validate()),
+            "missing required fields of `test.capnp:TestRequired`: name, tags, inner.data"
+        );
+        assert_eq!(
+            missing(root.reborrow().get_inner().validate()),
+            "missing required fields of `test.capnp:TestRequired.inner`: data"
+        );
+
+        root.set_name("foo".into());
+        root.reborrow().init_tags(0);
+        root.reborrow().get_inner().set_data(&[1, 2]);
+        root.validate()?;
+        root.reborrow_as_reader().validate()?;
+
+        // Only the member of the union that is set gets checked.
+        root.reborrow().init_pair().set_right("r".into());
+        assert_eq!(
+            missing(root.reborrow_as_reader().validate()),
+            "missing required fields of `test.capnp:TestRequired`: pair.left"
+        );
+        let mut pair = root.reborrow().init_pair();
+        pair.set_left("l".into());
+        pair.set_right("r".into());
+        root.validate()?;
+
+        root.set_empty(());
+        root.validate()?;
+        root.reborrow().init_payload();
+        root.validate()?;
+
+        // Switching to an unset member makes its field missing again.
+        root.reborrow().init_pair();
+        assert_eq!(
+            missing(root.into_reader().validate()),
+            "missing required fields of `test.capnp:TestRequired`: pair.left"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_required_params() -> capnp::Result<()> {
+        use crate::test_capnp::test_required_params;
+
+        let mut message = message::Builder::new_default();
+        let mut params = message.init_root::<test_required_params::send_params::Builder<'_>>();
+        assert!(params.validate().is_err());
+        params.set_name("foo".into());
+        params.validate()?;
+
+        // Only methods whose params have required fields get a checked builder.
+        let _ = test_required_params::Client::build_send_request_checked;
+        let generated = include_str!(concat!(env!("OUT_DIR"), "/test_capnp.rs"));
+        assert_eq!(generated.matches("_request_checked").count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_params_name() {
+        use crate::test_capnp::test_params_name;
+
+        let mut message = message::Builder::new_default();
+        let mut params = message.init_root::<test_params_name::get_foo_params::Builder<'_>>();
+        params.set_id(7);
+        let mut message = message::Builder::new_default();
+        let mut results = message.init_root::<test_params_name::get_foo_results::Builder<'_>>();
+        results.set_value("foo".into());
+
+        // The method keeps its old param and result module names.
+        let _: fn(
+            &test_params_name::Client,
+        ) -> capnp::capability::Request<
+            test_params_name::get_foo_params::Owned,
+            test_params_name::get_foo_results::Owned,
+        > = test_params_name::Client::fetch_foo_request;
+    }
+
+    #[test]
+    fn test_display_name() {
+        use crate::test_capnp::{test_groups, test_interface};
+
+        assert_eq!(test_groups::_private::DISPLAY_NAME, "test.capnp:TestGroups");
+        assert_eq!(
+            test_groups::groups::foo::_private::DISPLAY_NAME,
+            "test.capnp:TestGroups.groups.foo"
+        );
+        assert_eq!(
+            test_interface::_private::DISPLAY_NAME,
+            "test.capnp:TestInterface"
+        );
+
+        fn debug_name<T: core::fmt::Debug>(value: &T) -> String {
+            format!("{value:?}")
+        }
+        let _: fn(&test_groups::Owned) -> String = debug_name;
+        let _: fn(&test_interface::Owned) -> String = debug_name;
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid field index 7 of test.capnp:TestGroups.groups.foo")]
+    fn test_display_name_in_field_types_panic() {
+        crate::test_capnp::test_groups::groups::foo::_private::get_field_types(7);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid annotation indices (None, 3) of test.capnp:TestGroups")]
+    fn test_display_name_in_annotation_types_panic() {
+        crate::test_capnp::test_groups::_private::get_annotation_types(None, 3);
+    }
+
     #[test]
     fn test_field_get_option() -> capnp::Result<()> {
         use crate::test_capnp::test_field_get_option as subject;
@@ -1098,86 +2065,547 @@ mod tests {
     }
 
     #[test]
-    fn test_union_defaults() {
-        use crate::test_capnp::{test_union, test_union_defaults};
-
-        {
-            let message = message::Builder::new_default();
-            let reader = message
-                .get_root_as_reader::<test_union_defaults::Reader<'_>>()
-                .expect("get_root_as_reader()");
-            let field = reader.get_s16s8s64s8_set().unwrap();
-            let test_union::union0::U0f0s16(_) = field.get_union0().which().unwrap() else {
-                panic!("expected U0f0s16");
-            };
+    fn test_union_members() {
+        use crate::test_capnp::{test_groups, test_union};
+        use capnp::{dynamic_struct, dynamic_value};
 
-            let test_union_defaults::inner1::A(17) = reader.get_inner1().which().unwrap() else {
-                panic!("")
-            };
+        // Makes each member of `union0` active in turn and checks that the table agrees with
+        // the schema about which member that is.
+        for &(discriminant, name, index) in test_union::union0::UNION_MEMBERS {
+            let mut message = message::Builder::new_default();
+            let mut root = message.init_root::<test_union::Builder<'_>>();
+            let mut union0 = dynamic_value::Builder::from(root.reborrow().init_union0())
+                .downcast::<dynamic_struct::Builder<'_>>();
+            let field = union0.get_schema().get_fields().unwrap().get(index);
+            union0.clear(field).unwrap();
+
+            let union0 = dynamic_value::Reader::from(root.into_reader().get_union0())
+                .downcast::<dynamic_struct::Reader<'_>>();
+            let active = union0.which().unwrap().unwrap();
+            assert_eq!(active.get_index(), index);
+            assert_eq!(active.get_proto().get_name().unwrap(), name);
+            assert_eq!(active.get_proto().get_discriminant_value(), discriminant);
+            assert_eq!(
+                test_union::union0::discriminant_name(discriminant),
+                Some(name)
+            );
+        }
+        assert_eq!(test_union::union0::UNION_MEMBERS.len(), 14);
+        assert_eq!(test_union::union0::discriminant_name(14), None);
 
-            let test_union_defaults::inner2::C(Ok(capnp::text::Reader(b"grault"))) =
-                reader.get_inner2().which().unwrap()
-            else {
-                panic!("")
+        assert_eq!(
+            test_groups::groups::UNION_MEMBERS,
+            &[(0, "foo", 0), (1, "baz", 1), (2, "bar", 2)]
+        );
+        let mut message = message::Builder::new_default();
+        let mut root = message.init_root::<test_groups::Builder<'_>>();
+        for &(discriminant, name, _) in test_groups::groups::UNION_MEMBERS {
+            let groups = root.reborrow().init_groups();
+            match name {
+                "foo" => groups.init_foo().set_corge(1),
+                "bar" => groups.init_bar().set_corge(2),
+                "baz" => groups.init_baz().set_corge(3),
+                _ => panic!("unexpected member {name}"),
+            }
+            let variant = match root.reborrow().into_reader().get_groups().which().unwrap() {
+                test_groups::groups::Foo(foo) => ("foo", foo.get_corge()),
+                test_groups::groups::Bar(bar) => ("bar", bar.get_corge()),
+                test_groups::groups::Baz(baz) => ("baz", baz.get_corge()),
             };
+            assert_eq!(
+                Some(variant.0),
+                test_groups::groups::discriminant_name(discriminant)
+            );
         }
     }
 
     #[test]
-    fn test_constants() {
-        use crate::test_capnp::{test_constants, TestEnum};
-        assert_eq!(test_constants::BOOL_CONST, true);
-        assert_eq!(test_constants::INT8_CONST, -123);
-        assert_eq!(test_constants::INT16_CONST, -12345);
-        assert_eq!(test_constants::INT32_CONST, -12345678);
-        assert_eq!(test_constants::INT64_CONST, -123456789012345);
-        assert_eq!(test_constants::UINT8_CONST, 234);
-        assert_eq!(test_constants::UINT16_CONST, 45678);
-        assert_eq!(test_constants::UINT32_CONST, 3456789012);
-        assert_eq!(test_constants::UINT64_CONST, 12345678901234567890);
-        assert_eq!(test_constants::FLOAT32_CONST, 1234.5);
-        assert_eq!(test_constants::FLOAT64_CONST, -123e45);
-        assert_eq!(test_constants::TEXT_CONST, "foo");
-        assert_eq!(test_constants::COMPLEX_TEXT_CONST, "foo\"☺\'$$$");
-        assert_eq!(test_constants::DATA_CONST, b"bar");
-        {
-            let struct_const_root = test_constants::STRUCT_CONST.get().unwrap();
-            assert_eq!(struct_const_root.get_bool_field(), true);
-            assert_eq!(struct_const_root.get_int8_field(), -12);
-            assert_eq!(struct_const_root.get_int16_field(), 3456);
-            assert_eq!(struct_const_root.get_int32_field(), -78901234);
-            // ...
-            assert_eq!(struct_const_root.get_text_field().unwrap(), "baz");
-            assert_eq!(struct_const_root.get_data_field().unwrap(), b"qux");
-            {
-                let sub_reader = struct_const_root.get_struct_field().unwrap();
-                assert_eq!(sub_reader.get_text_field().unwrap(), "nested");
-                assert_eq!(
-                    sub_reader
-                        .get_struct_field()
-                        .unwrap()
-                        .get_text_field()
-                        .unwrap(),
-                    "really nested"
-                );
-            }
-            // ...
+    fn test_member_presence() {
+        use crate::test_capnp::{test_groups, test_member_presence};
+
+        let mut message = message::Builder::new_default();
+        let mut root = message.init_root::<test_member_presence::Builder<'_>>();
+        assert!(root.is_none());
+        assert!(!root.is_count());
+        assert!(!root.is_name());
+        assert!(!root.has_name());
+        assert!(!root.has_settings());
+
+        root.set_count(0);
+        assert!(!root.is_none());
+        assert!(root.is_count());
+        root.set_name("".into());
+        assert!(root.is_name());
+        assert!(root.has_name());
+        root.set_none(());
+        assert!(root.is_none());
+        assert!(!root.has_name());
+
+        // Setting a field to its default does not make the group present...
+        let mut settings = root.reborrow().get_settings();
+        settings.set_level(5);
+        settings.set_ratio(1.5);
+        settings.set_enabled(true);
+        settings.reborrow().get_limits().set_high(10);
+        assert!(!root.has_settings());
+
+        // ...but any other value does, down to the sign of a zero.
+        let checks: [fn(test_member_presence::settings::Builder<'_>); 6] = [
+            |mut s| s.set_level(0),
+            |mut s| s.set_ratio(-1.5),
+            |mut s| s.set_enabled(false),
+            // A pointer counts as soon as it is set, even to its default.
+            |mut s| s.set_label("default".into()),
+            |s| s.get_limits().set_low(0),
+            |s| s.get_limits().set_high(11),
+        ];
+        for set in checks {
+            let mut message = message::Builder::new_default();
+            let mut root = message.init_root::<test_member_presence::Builder<'_>>();
+            set(root.reborrow().get_settings());
+            assert!(root.has_settings());
+            assert!(root.into_reader().has_settings());
         }
 
-        assert!(test_constants::ENUM_CONST == TestEnum::Corge);
+        // A group in a union is present only while it is the active member.
+        let mut message = message::Builder::new_default();
+        let root = message.init_root::<test_groups::Builder<'_>>();
+        let mut groups = root.get_groups();
+        assert!(groups.is_foo());
+        assert!(!groups.has_foo());
+        groups.reborrow().init_bar().set_garply(1);
+        assert!(groups.is_bar());
+        assert!(groups.has_bar());
+        assert!(!groups.is_foo());
+        assert!(!groups.has_foo());
+        groups.reborrow().init_bar();
+        assert!(!groups.has_bar());
+        assert!(groups.into_reader().is_bar());
+    }
 
-        let void_list = test_constants::VOID_LIST_CONST;
-        assert_eq!(void_list.get().unwrap().len(), 6);
+    #[test]
+    fn test_copy_union_from() {
+        use crate::test_capnp::{test_groups, test_union, test_unnamed_union, TestEnum};
+
+        let setters: [fn(test_union::union0::Builder<'_>); 14] = [
+            |mut u| u.set_u0f0s0(()),
+            |mut u| u.set_u0f0s1(true),
+            |mut u| u.set_u0f0s8(-8),
+            |mut u| u.set_u0f0s16(-16),
+            |mut u| u.set_u0f0s32(-32),
+            |mut u| u.set_u0f0s64(-64),
+            |mut u| u.set_u0f0sp("foo".into()),
+            |mut u| u.set_u0f1s0(()),
+            |mut u| u.set_u0f1s1(true),
+            |mut u| u.set_u0f1s8(8),
+            |mut u| u.set_u0f1s16(16),
+            |mut u| u.set_u0f1s32(32),
+            |mut u| u.set_u0f1s64(64),
+            |mut u| u.set_u0f1sp("bar".into()),
+        ];
+        for set in setters {
+            let mut src = message::Builder::new_default();
+            set(src.init_root::<test_union::Builder<'_>>().init_union0());
+            let src = src.get_root_as_reader::<test_union::Reader<'_>>().unwrap();
+
+            let mut dst = message::Builder::new_default();
+            let mut root = dst.init_root::<test_union::Builder<'_>>();
+            root.set_bit0(true);
+            root.reborrow()
+                .init_union0()
+                .set_u0f1sp("a stale value".into());
+            root.reborrow()
+                .get_union0()
+                .copy_union_from(src.get_union0())
+                .unwrap();
+            let root = root.into_reader();
+            assert_eq!(
+                format!("{:?}", root.get_union0()),
+                format!("{:?}", src.get_union0())
+            );
+            assert!(root.get_bit0());
+        }
 
-        let bool_list_const = test_constants::BOOL_LIST_CONST;
-        let bool_list = bool_list_const.get().unwrap();
-        assert_eq!(bool_list.len(), 4);
-        assert_eq!(bool_list.get(0), true);
-        assert_eq!(bool_list.get(1), false);
-        assert_eq!(bool_list.get(2), false);
-        assert_eq!(bool_list.get(3), true);
+        // Group variants, with the stored value of an enum and a float.
+        let mut src = message::Builder::new_default();
+        let mut baz = src
+            .init_root::<test_groups::Builder<'_>>()
+            .init_groups()
+            .init_baz();
+        baz.set_corge(3);
+        baz.set_grault("grault".into());
+        baz.set_quz(f64::NAN);
+        baz.set_an_enum(TestEnum::Garply);
+        let src = src.get_root_as_reader::<test_groups::Reader<'_>>().unwrap();
+        let mut dst = message::Builder::new_default();
+        let mut groups = dst.init_root::<test_groups::Builder<'_>>().init_groups();
+        groups.reborrow().init_foo().set_garply("stale".into());
+        groups.copy_union_from(src.get_groups()).unwrap();
+        let test_groups::groups::Baz(baz) = groups.into_reader().which().unwrap() else {
+            panic!("expected baz");
+        };
+        assert_eq!(baz.get_corge(), 3);
+        assert_eq!(baz.get_grault().unwrap(), "grault");
+        assert!(!baz.has_garply());
+        assert_eq!(baz.get_quz().to_bits(), f64::NAN.to_bits());
+        assert_eq!(baz.get_an_enum(), Ok(TestEnum::Garply));
+
+        // An unnamed union leaves the other fields of the struct alone.
+        let mut src = message::Builder::new_default();
+        let mut root = src.init_root::<test_unnamed_union::Builder<'_>>();
+        root.set_bar(321);
+        root.set_before("source".into());
+        let src_words = src.get_segments_for_output()[0].to_vec();
+        let src = src
+            .get_root_as_reader::<test_unnamed_union::Reader<'_>>()
+            .unwrap();
+        let mut dst = message::Builder::new_default();
+        let mut root = dst.init_root::<test_unnamed_union::Builder<'_>>();
+        root.set_foo(123);
+        root.set_before("destination".into());
+        root.copy_union_from(src).unwrap();
+        let root = root.into_reader();
+        assert!(matches!(root.which(), Ok(test_unnamed_union::Bar(321))));
+        assert_eq!(root.get_before().unwrap(), "destination");
+
+        // A variant from a newer schema is rejected without changing anything.
+        let mut bytes = src_words;
+        let offset = 8 + test_unnamed_union::_private::layout::DISCRIMINANT_OFFSET;
+        bytes[offset..offset + 2].copy_from_slice(&7u16.to_le_bytes());
+        let segments: &[&[u8]] = &[&bytes];
+        let newer = message::Reader::new(message::SegmentArray::new(segments), Default::default());
+        let newer = newer.get_root::<test_unnamed_union::Reader<'_>>().unwrap();
+        let mut dst = message::Builder::new_default();
+        let mut root = dst.init_root::<test_unnamed_union::Builder<'_>>();
+        root.set_foo(123);
+        let error = root.copy_union_from(newer).unwrap_err();
+        assert_eq!(
+            error.kind,
+            capnp::ErrorKind::EnumValueOrUnionDiscriminantNotPresent(capnp::NotInSchema(7))
+        );
+        assert!(matches!(
+            root.into_reader().which(),
+            Ok(test_unnamed_union::Foo(123))
+        ));
+    }
 
-        let int8_list_const = test_constants::INT8_LIST_CONST;
+    #[test]
+    fn test_enum_list_unknown_values() {
+        use crate::test_capnp::TestEnum;
+        use capnp::{enum_list, primitive_list, NotInSchema};
+
+        // A value from a newer version of the schema sits among known ones.
+        let mut message = message::Builder::new_default();
+        let mut raw = message.initn_root::<primitive_list::Builder<'_, u16>>(4);
+        for (index, value) in [2, 99, 0, 7].into_iter().enumerate() {
+            raw.set(index as u32, value);
+        }
+        let list = message
+            .get_root_as_reader::<enum_list::Reader<'_, TestEnum>>()
+            .unwrap();
+
+        assert!(matches!(list.try_collect(), Err(NotInSchema(99))));
+        let lenient: Vec<_> = list.iter_lenient().collect();
+        assert!(matches!(
+            lenient[..],
+            [
+                Ok(TestEnum::Baz),
+                Err(NotInSchema(99)),
+                Ok(TestEnum::Foo),
+                Ok(TestEnum::Garply)
+            ]
+        ));
+        let valid: Vec<_> = list.iter_lenient().filter_valid().collect();
+        assert_eq!(valid, [TestEnum::Baz, TestEnum::Foo, TestEnum::Garply]);
+
+        message
+            .get_root::<primitive_list::Builder<'_, u16>>()
+            .unwrap()
+            .set(1, 3);
+        let list = message
+            .get_root_as_reader::<enum_list::Reader<'_, TestEnum>>()
+            .unwrap();
+        assert_eq!(
+            list.try_collect().unwrap(),
+            [
+                TestEnum::Baz,
+                TestEnum::Qux,
+                TestEnum::Foo,
+                TestEnum::Garply
+            ]
+        );
+    }
+
+    #[test]
+    fn test_enum_values() {
+        use crate::test_capnp::renamed_struct::RenamedEnum;
+        use crate::test_capnp::TestEnum;
+        use capnp::introspect::{Introspect, TypeVariant};
+
+        let TypeVariant::Enum(raw) = TestEnum::introspect().which() else {
+            panic!("expected an enum");
+        };
+        let schema = capnp::schema::EnumSchema::new(raw);
+        let enumerants = schema.get_enumerants().unwrap();
+        assert_eq!(TestEnum::VALUES.len(), enumerants.len() as usize);
+        assert_eq!(TestEnum::COUNT as usize, TestEnum::VALUES.len());
+        for (ii, value) in TestEnum::iter().enumerate() {
+            assert_eq!(u16::from(value), ii as u16);
+            assert_eq!(TestEnum::try_from(ii as u16), Ok(value));
+            assert_eq!(enumerants.get(ii as u16).get_ordinal(), u16::from(value));
+        }
+        assert_eq!(TestEnum::VALUES[7], TestEnum::Garply);
+
+        assert_eq!(
+            RenamedEnum::VALUES,
+            [RenamedEnum::Foo, RenamedEnum::Bar, RenamedEnum::Qux]
+        );
+        assert_eq!(RenamedEnum::COUNT, 3);
+    }
+
+    #[test]
+    fn test_checked_integer_setters() {
+        use crate::test_capnp::{test_all_types, test_defaults};
+
+        let mut message = message::Builder::new_default();
+        let mut all_types = message.init_root::<test_all_types::Builder<'_>>();
+        all_types.set_int64_field_from(-7i8).unwrap();
+        all_types.set_u_int64_field_from(7u16).unwrap();
+        all_types.set_int8_field_from(-128i64).unwrap();
+        all_types.set_u_int16_field_from(65535usize).unwrap();
+        assert_eq!(all_types.reborrow().get_int64_field(), -7);
+        assert_eq!(all_types.reborrow().get_u_int64_field(), 7);
+        assert_eq!(all_types.reborrow().get_int8_field(), -128);
+        assert_eq!(all_types.reborrow().get_u_int16_field(), 65535);
+
+        // Out-of-range values leave the field unchanged.
+        for error in [
+            all_types.set_int8_field_from(128i32).unwrap_err(),
+            all_types.set_u_int16_field_from(-1i8).unwrap_err(),
+            all_types.set_u_int64_field_from(-1i64).unwrap_err(),
+        ] {
+            assert_eq!(error.kind, capnp::ErrorKind::ValueOutOfRange);
+        }
+        assert_eq!(all_types.reborrow().get_int8_field(), -128);
+        assert_eq!(all_types.reborrow().get_u_int16_field(), 65535);
+        assert_eq!(all_types.reborrow().get_u_int64_field(), 7);
+
+        // Fields with defaults are masked as usual.
+        let mut message = message::Builder::new_default();
+        let mut defaults = message.init_root::<test_defaults::Builder<'_>>();
+        assert_eq!(defaults.reborrow().get_u_int32_field(), 3456789012);
+        defaults.set_u_int32_field_from(0u8).unwrap();
+        defaults.set_int16_field_from(-12345i64).unwrap();
+        assert_eq!(defaults.reborrow().get_u_int32_field(), 0);
+        assert_eq!(defaults.reborrow().get_int16_field(), -12345);
+        assert!(defaults.set_u_int8_field_from(256u32).is_err());
+        assert_eq!(defaults.reborrow().get_u_int8_field(), 234);
+    }
+
+    #[test]
+    fn get_or_init_pointer_fields() -> capnp::Result<()> {
+        use crate::test_capnp::test_all_types;
+
+        fn allocated_bytes(message: &message::Builder<message::HeapAllocator>) -> usize {
+            message
+                .get_segments_for_output()
+                .iter()
+                .map(|s| s.len())
+                .sum()
+        }
+
+        let mut message = message::Builder::new_default();
+        let mut root = message.init_root::<test_all_types::Builder<'_>>();
+        assert!(!root.has_struct_field());
+        root.get_or_init_struct_field()?.set_int32_field(17);
+        assert!(root.has_struct_field());
+        {
+            // A freshly initialized text is full, so make room before pushing.
+            let mut text = root.get_or_init_text_field(5)?;
+            text.truncate(0);
+            text.push_str("hello")?;
+        }
+        {
+            let mut list = root.get_or_init_int32_list(3)?;
+            list.set(0, 1);
+            list.set(2, 3);
+        }
+        let allocated = allocated_bytes(&message);
+
+        // Repeated calls neither reallocate nor clobber what is already there.
+        let mut root = message.get_root::<test_all_types::Builder<'_>>()?;
+        for _ in 0..3 {
+            assert_eq!(root.get_or_init_struct_field()?.get_int32_field(), 17);
+            assert_eq!(root.get_or_init_text_field(100)?.to_str()?, "hello");
+            let list = root.get_or_init_int32_list(100)?;
+            assert_eq!(list.len(), 3);
+            assert_eq!(list.get(2), 3);
+        }
+        assert_eq!(allocated_bytes(&message), allocated);
+        Ok(())
+    }
+
+    #[test]
+    fn get_or_init_union_member() -> capnp::Result<()> {
+        use crate::test_capnp::test_union;
+
+        let mut message = message::Builder::new_default();
+        let mut root = message.init_root::<test_union::Builder<'_>>();
+        root.reborrow().get_union0().set_u0f1sp("other".into());
+
+        // The pointer is shared with another union member, so it gets initialized afresh.
+        let mut union0 = root.get_union0();
+        assert_eq!(union0.get_or_init_u0f0sp(3)?.len(), 3);
+        match union0.reborrow().which()? {
+            test_union::union0::U0f0sp(text) => assert_eq!(text?.len(), 3),
+            _ => panic!("expected u0f0sp"),
+        }
+        union0.set_u0f0sp("abc".into());
+        assert_eq!(union0.get_or_init_u0f0sp(10)?.to_str()?, "abc");
+        Ok(())
+    }
+
+    #[test]
+    fn reserved_field_names() -> capnp::Result<()> {
+        use crate::test_reserved_names_capnp::{internals, only_union};
+
+        let mut message = message::Builder::new_default();
+        let mut root = message.init_root::<internals::Builder<'_>>();
+        root.set_builder(1);
+        root.set_reader("reader".into());
+        root.set_value(-2);
+        root.reborrow().init_result().set_value(3);
+        root.reborrow().init_self(1).set(0, "self".into());
+        root.set_uni(&[4]);
+        root.set_size(5);
+        root.reborrow().init_length().set_size(6);
+        root.reborrow().init_builder2().set_builder(7);
+        root.reborrow()
+            .get_group()
+            .init_builder()
+            .set_reader("group".into());
+
+        let reader = root.into_reader();
+        assert_eq!(reader.get_builder(), 1);
+        assert_eq!(reader.get_reader()?, "reader");
+        assert_eq!(reader.get_value(), -2);
+        assert_eq!(reader.get_result()?.get_value(), 3);
+        assert_eq!(reader.get_self()?.get(0)?, "self");
+        assert_eq!(reader.get_uni()?, &[4]);
+        assert_eq!(reader.get_length()?.get_size(), 6);
+        assert_eq!(reader.get_default(), 7);
+        assert_eq!(reader.get_group().get_builder()?.get_reader()?, "group");
+        match reader.which()? {
+            internals::Builder2(value) => assert_eq!(value?.get_builder(), 7),
+            _ => panic!("expected builder2"),
+        }
+
+        let params = internals::Internals::try_from(reader)?;
+        assert_eq!(params._builder, 1);
+        assert_eq!(params._value, -2);
+        assert_eq!(params._self, vec!["self".to_string()]);
+        let mut copy = message::Builder::new_default();
+        params.build_capnp_struct(copy.init_root()).unwrap();
+        let copy = copy.get_root_as_reader::<internals::Reader<'_>>()?;
+        assert_eq!(copy.get_result()?.get_value(), 3);
+        assert_eq!(copy.get_group().get_builder()?.get_reader()?, "group");
+
+        let mut message = message::Builder::new_default();
+        message
+            .init_root::<only_union::Builder<'_>>()
+            .init_builder()
+            .set_builder(8);
+        match message
+            .get_root_as_reader::<only_union::Reader<'_>>()?
+            .which()?
+        {
+            only_union::Which::Builder(value) => assert_eq!(value?.get_builder(), 8),
+            _ => panic!("expected builder"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_union_defaults() {
+        use crate::test_capnp::{test_union, test_union_defaults};
+
+        {
+            let message = message::Builder::new_default();
+            let reader = message
+                .get_root_as_reader::<test_union_defaults::Reader<'_>>()
+                .expect("get_root_as_reader()");
+            let field = reader.get_s16s8s64s8_set().unwrap();
+            let test_union::union0::U0f0s16(_) = field.get_union0().which().unwrap() else {
+                panic!("expected U0f0s16");
+            };
+
+            let test_union_defaults::inner1::A(17) = reader.get_inner1().which().unwrap() else {
+                panic!("")
+            };
+
+            let test_union_defaults::inner2::C(Ok(capnp::text::Reader(b"grault"))) =
+                reader.get_inner2().which().unwrap()
+            else {
+                panic!("")
+            };
+        }
+    }
+
+    #[test]
+    fn test_constants() {
+        use crate::test_capnp::{test_constants, TestEnum};
+        assert_eq!(test_constants::BOOL_CONST, true);
+        assert_eq!(test_constants::INT8_CONST, -123);
+        assert_eq!(test_constants::INT16_CONST, -12345);
+        assert_eq!(test_constants::INT32_CONST, -12345678);
+        assert_eq!(test_constants::INT64_CONST, -123456789012345);
+        assert_eq!(test_constants::UINT8_CONST, 234);
+        assert_eq!(test_constants::UINT16_CONST, 45678);
+        assert_eq!(test_constants::UINT32_CONST, 3456789012);
+        assert_eq!(test_constants::UINT64_CONST, 12345678901234567890);
+        assert_eq!(test_constants::FLOAT32_CONST, 1234.5);
+        assert_eq!(test_constants::FLOAT64_CONST, -123e45);
+        assert_eq!(test_constants::TEXT_CONST, "foo");
+        assert_eq!(test_constants::COMPLEX_TEXT_CONST, "foo\"☺\'$$$");
+        assert_eq!(test_constants::DATA_CONST, b"bar");
+        {
+            let struct_const_root = test_constants::STRUCT_CONST.get().unwrap();
+            assert_eq!(struct_const_root.get_bool_field(), true);
+            assert_eq!(struct_const_root.get_int8_field(), -12);
+            assert_eq!(struct_const_root.get_int16_field(), 3456);
+            assert_eq!(struct_const_root.get_int32_field(), -78901234);
+            // ...
+            assert_eq!(struct_const_root.get_text_field().unwrap(), "baz");
+            assert_eq!(struct_const_root.get_data_field().unwrap(), b"qux");
+            {
+                let sub_reader = struct_const_root.get_struct_field().unwrap();
+                assert_eq!(sub_reader.get_text_field().unwrap(), "nested");
+                assert_eq!(
+                    sub_reader
+                        .get_struct_field()
+                        .unwrap()
+                        .get_text_field()
+                        .unwrap(),
+                    "really nested"
+                );
+            }
+            // ...
+        }
+
+        assert!(test_constants::ENUM_CONST == TestEnum::Corge);
+
+        let void_list = test_constants::VOID_LIST_CONST;
+        assert_eq!(void_list.get().unwrap().len(), 6);
+
+        let bool_list_const = test_constants::BOOL_LIST_CONST;
+        let bool_list = bool_list_const.get().unwrap();
+        assert_eq!(bool_list.len(), 4);
+        assert_eq!(bool_list.get(0), true);
+        assert_eq!(bool_list.get(1), false);
+        assert_eq!(bool_list.get(2), false);
+        assert_eq!(bool_list.get(3), true);
+
+        let int8_list_const = test_constants::INT8_LIST_CONST;
         let int8_list = int8_list_const.get().unwrap();
         assert_eq!(int8_list.len(), 2);
         assert_eq!(int8_list.get(0), 111);
@@ -1202,6 +2630,57 @@ mod tests {
         assert_eq!(struct_list.get(2).get_text_field().unwrap(), "structlist 3");
     }
 
+    #[test]
+    fn pointer_constant_accessors() {
+        use crate::test_capnp::{test_constants, test_pointer_constants};
+
+        let struct_const = test_constants::struct_const();
+        assert_eq!(struct_const.get_int32_field(), -78901234);
+        assert_eq!(struct_const.get_text_field().unwrap(), "baz");
+        assert_eq!(
+            struct_const
+                .get_struct_field()
+                .unwrap()
+                .get_text_field()
+                .unwrap(),
+            "nested"
+        );
+
+        let struct_list = test_constants::struct_list_const();
+        assert_eq!(struct_list.len(), 3);
+        assert_eq!(struct_list.get(1).get_text_field().unwrap(), "structlist 2");
+
+        // Identical values share one word array.
+        assert_eq!(
+            test_pointer_constants::FIRST.words.as_ptr(),
+            test_pointer_constants::SECOND.words.as_ptr()
+        );
+        assert_eq!(test_pointer_constants::second().get_int32_field(), 7);
+        assert_eq!(
+            test_pointer_constants::second().get_text_field().unwrap(),
+            "shared"
+        );
+    }
+
+    #[test]
+    fn generic_pointer_constants() {
+        use crate::test_capnp::test_pointer_constants;
+
+        let generic = test_pointer_constants::generic();
+        assert_eq!(generic.get_foo().unwrap(), "bound");
+        assert_eq!(generic.get_bar().unwrap().get_int16_field(), 5);
+
+        let generic_list = test_pointer_constants::generic_list();
+        assert_eq!(generic_list.len(), 1);
+        assert_eq!(generic_list.get(0).get_foo().unwrap().get_u_int8_field(), 3);
+        assert_eq!(generic_list.get(0).get_bar().unwrap(), "one");
+
+        let inner = test_pointer_constants::GENERIC_INNER.get().unwrap();
+        assert_eq!(inner.get_bar().unwrap(), b"b");
+        assert_eq!(inner.get_baz().unwrap().get_int8_field(), 4);
+        assert_eq!(inner.get_inner_bound().unwrap().get_foo().unwrap(), "in");
+    }
+
     #[test]
     fn test_set_root() {
         use crate::test_capnp::test_big_struct;
@@ -2137,6 +3616,53 @@ mod tests {
         CheckTestMessage::check_test_message(message_reader.get().unwrap());
     }
 
+    #[test]
+    fn test_typed_builder_from_reader() {
+        use crate::test_capnp::{test_all_types, test_generics};
+        use capnp::text;
+
+        let mut original = TypedBuilder::<test_all_types::Owned>::new_default();
+        init_test_message(original.init_root());
+
+        let mut copy = TypedBuilder::<test_all_types::Owned>::from_reader(
+            original.get_root_as_reader().unwrap(),
+        )
+        .unwrap();
+        CheckTestMessage::check_test_message(copy.get_root_as_reader().unwrap());
+        {
+            let mut sub = copy.get_root().unwrap().get_struct_field().unwrap();
+            sub.set_text_field("changed".into());
+            sub.get_int32_list().unwrap().set(1, 42);
+        }
+
+        let copy_sub = copy
+            .get_root_as_reader()
+            .unwrap()
+            .get_struct_field()
+            .unwrap();
+        assert_eq!(copy_sub.get_text_field().unwrap(), "changed");
+        assert_eq!(copy_sub.get_int32_list().unwrap().get(1), 42);
+        CheckTestMessage::check_test_message(original.get_root_as_reader().unwrap());
+
+        type Generic = test_generics::Owned<test_all_types::Owned, text::Owned>;
+        let mut original = TypedBuilder::<Generic>::new_default();
+        {
+            let mut root = original.init_root();
+            init_test_message(root.reborrow().init_foo());
+            root.set_bar("bar".into()).unwrap();
+        }
+        let mut copy =
+            TypedBuilder::<Generic>::from_reader(original.get_root_as_reader().unwrap()).unwrap();
+        copy.get_root().unwrap().set_bar("other".into()).unwrap();
+        let copy_reader = copy.get_root_as_reader().unwrap();
+        assert_eq!(copy_reader.get_bar().unwrap(), "other");
+        CheckTestMessage::check_test_message(copy_reader.get_foo().unwrap());
+        assert_eq!(
+            original.get_root_as_reader().unwrap().get_bar().unwrap(),
+            "bar"
+        );
+    }
+
     #[test]
     fn test_slice_segments() {
         use crate::test_capnp::test_all_types;
@@ -2269,6 +3795,2067 @@ mod tests {
         assert!(!generator_context.scope_map.is_empty());
     }
 
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn compile_from_memory() {
+        use capnp::schema_capnp::code_generator_request;
+        use capnp::serialize;
+
+        let shapes = r#"
+            @0xd5dd6ae6bbb7e95a;
+            struct Point { x @0 :Float64; y @1 :Float64; }
+        "#;
+        let drawing = r#"
+            @0xf5c9d3a0b1e34d27;
+            using Shapes = import "/shapes.capnp";
+            using import "../shapes.capnp".Point;
+            struct Drawing { origin @0 :Point; points @1 :List(Shapes.Point); }
+        "#;
+
+        let raw = capnpc::CompilerCommand::new()
+            .capnp_executable(env!("CAPNP_EXECUTABLE"))
+            .compile_from_memory(&[("shapes.capnp", shapes), ("sub/drawing.capnp", drawing)])
+            .unwrap();
+        let message = serialize::read_message(raw.as_slice(), ReaderOptions::new()).unwrap();
+        let request = message
+            .get_root::<code_generator_request::Reader<'_>>()
+            .unwrap();
+        let filenames = request
+            .get_requested_files()
+            .unwrap()
+            .iter()
+            .map(|f| f.get_filename().unwrap().to_string().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(filenames, ["shapes.capnp", "sub/drawing.capnp"]);
+
+        let ctx = capnpc::codegen::GeneratorContext::new(&message).unwrap();
+        assert!(ctx.node_map.contains_key(&0xd5dd6ae6bbb7e95a));
+        assert!(ctx.node_map.contains_key(&0xf5c9d3a0b1e34d27));
+
+        let error = capnpc::CompilerCommand::new()
+            .capnp_executable(env!("CAPNP_EXECUTABLE"))
+            .compile_from_memory(&[("broken.capnp", "@0xc8a3b2f1e4d5a697; struct {")])
+            .unwrap_err();
+        assert!(error.to_string().contains("broken.capnp"), "{error}");
+
+        let error = capnpc::compile_from_memory(&[("../escape.capnp", shapes)], &[]).unwrap_err();
+        assert!(
+            error.to_string().contains("Invalid virtual path"),
+            "{error}"
+        );
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn generated_code_is_deterministic() {
+        let schema = r#"
+            @0xb6a0d3c25e8f4a71;
+            struct Pair(First, Second) {
+              first @0 :First;
+              second @1 :Second;
+            }
+            struct Outer(A, B, C) {
+              struct Inner(D) {
+                value @0 :Pair(D, C);
+              }
+              triple @0 :Pair(Pair(C, B), A);
+              union {
+                left @1 :Pair(B, A);
+                right @2 :Pair(C, A);
+              }
+            }
+            interface Store(K, V, W) {
+              swap @0 Pair(W, Pair(V, K)) -> Pair(Pair(K, V), W);
+              nested @1 Outer(W, V, K).Inner(K) -> Outer(K, W, V);
+            }
+        "#;
+
+        let raw = capnpc::CompilerCommand::new()
+            .capnp_executable(env!("CAPNP_EXECUTABLE"))
+            .compile_from_memory(&[("generics.capnp", schema)])
+            .unwrap();
+
+        let base = std::env::temp_dir().join(format!("capnpc-determinism-{}", std::process::id()));
+        let mut outputs = Vec::new();
+        for run in 0..8 {
+            let dir = base.join(run.to_string());
+            capnpc::codegen::CodeGenerationCommand::new()
+                .output_directory(&dir)
+                .run(raw.as_slice())
+                .unwrap();
+            outputs.push(std::fs::read(dir.join("generics_capnp.rs")).unwrap());
+        }
+        std::fs::remove_dir_all(&base).unwrap();
+        for output in &outputs[1..] {
+            assert!(*output == outputs[0], "generated code differs between runs");
+        }
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn schema_fingerprint() {
+        let generate = |schema: &str| {
+            let raw = capnpc::CompilerCommand::new()
+                .capnp_executable(env!("CAPNP_EXECUTABLE"))
+                .compile_from_memory(&[("points.capnp", schema)])
+                .unwrap();
+            let dir = tempfile::tempdir().unwrap();
+            capnpc::codegen::CodeGenerationCommand::new()
+                .output_directory(dir.path())
+                .run(raw.as_slice())
+                .unwrap();
+            let text = std::fs::read_to_string(dir.path().join("points_capnp.rs")).unwrap();
+            let constant = |name: &str| {
+                let start = text.find(&format!("pub const {name}: u64 = ")).unwrap();
+                text[start..]
+                    .split(['=', ';'])
+                    .nth(1)
+                    .unwrap()
+                    .trim()
+                    .to_string()
+            };
+            (constant("FILE_ID"), constant("SCHEMA_FINGERPRINT"))
+        };
+
+        let original =
+            generate("@0xd18f5a2c4b7e9036;\nstruct Point { x @0 :Int32; y @1 :Int32; }\n");
+        assert_eq!(original.0, "0xd18f5a2c4b7e9036");
+        assert_eq!(
+            original,
+            generate("@0xd18f5a2c4b7e9036;\nstruct Point { x @0 :Int32; y @1 :Int32; }\n")
+        );
+
+        // Formatting and comments do not change the fingerprint.
+        let reformatted = generate(
+            "@0xd18f5a2c4b7e9036;\n\n# A point.\nstruct Point {\n  x @0 :Int32;\n\n  y @1 :Int32;  # y\n}\n",
+        );
+        assert_eq!(original, reformatted);
+
+        let extended = generate(
+            "@0xd18f5a2c4b7e9036;\nstruct Point { x @0 :Int32; y @1 :Int32; z @2 :Int32; }\n",
+        );
+        assert_eq!(original.0, extended.0);
+        assert_ne!(original.1, extended.1);
+    }
+
+    #[test]
+    fn reexported_aliases() {
+        use crate::test_aliases_capnp as aliases;
+
+        let mut message = message::Builder::new_default();
+        let mut outer = message.init_root::<aliases::outer::Builder<'_>>();
+        outer.set_color(aliases::Color::Green);
+        outer.reborrow().init_inner().set_value(7);
+        outer.reborrow().init_baz();
+
+        let outer = outer.into_reader();
+        let inner: aliases::inner::Reader<'_> = outer.get_inner().unwrap();
+        let _: aliases::inner_again::Reader<'_> = inner;
+        let _: aliases::outer::sibling::Reader<'_> = inner;
+        let _: aliases::service::request::Reader<'_> = inner;
+        let _: aliases::outer::root::Reader<'_> = outer;
+        assert_eq!(inner.get_value(), 7);
+        assert_eq!(outer.get_color().unwrap(), aliases::Color::Green);
+        let _: aliases::other_baz::Reader<'_> = outer.get_baz().unwrap();
+        let _: aliases::baz::Reader<'_> = outer.get_baz().unwrap();
+
+        let mut message = message::Builder::new_default();
+        let mut opts = message.init_root::<aliases::external_opts::Builder<'_>>();
+        opts.set_field("opts".into());
+        let _: external_crate::external_capnp::opts::Reader<'_> = opts.into_reader();
+    }
+
+    #[test]
+    fn conflicting_aliases() {
+        let generate = |schema: &str| {
+            let dir = tempfile::tempdir().unwrap();
+            std::fs::write(dir.path().join("aliases.capnp"), schema).unwrap();
+            let raw = capnpc::CompilerCommand::new()
+                .capnp_executable(env!("CAPNP_EXECUTABLE"))
+                .compile_from_memory(&[("aliases.capnp", schema)])
+                .unwrap();
+            capnpc::codegen::CodeGenerationCommand::new()
+                .output_directory(dir.path())
+                .reexport_aliases([dir.path().to_path_buf()])
+                .run(raw.as_slice())
+                .map(|()| std::fs::read_to_string(dir.path().join("aliases_capnp.rs")).unwrap())
+                .map_err(|error| error.to_string())
+        };
+
+        let text =
+            generate("@0xd18f5a2c4b7e9036;\nstruct Foo {}\nstruct Bar {\n  using Foo = .Foo;\n}\n")
+                .unwrap();
+        assert!(
+            text.contains("pub use crate::aliases_capnp::foo as foo;"),
+            "{text}"
+        );
+
+        let error = generate(
+            "@0xd18f5a2c4b7e9036;\nstruct Foo {}\nstruct Bar {\n  using Baz = Foo;\n  baz :group { x @0 :Int32; }\n}\n",
+        )
+        .unwrap_err();
+        assert!(
+            error.contains(
+                "alias `Baz` at aliases.capnp:4 conflicts with group `aliases.capnp:Bar.baz`"
+            ),
+            "{error}"
+        );
+
+        let error = generate(
+            "@0xd18f5a2c4b7e9036;\nstruct Bar {\n  enum Kind { a @0; }\n  # Kinds.\n  using Reader = Kind;\n}\n",
+        )
+        .unwrap_err();
+        assert!(
+            error.contains(
+                "alias `Reader` at aliases.capnp:5 conflicts with the generated `Reader`"
+            ),
+            "{error}"
+        );
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn params_name_collisions() {
+        let generate = |methods: &str| {
+            let schema = format!(
+                "@0xe2a6b4f1c3d58079;\nusing Rust = import \"/rust.capnp\";\n\
+                 interface Service {{\n{methods}\n}}\n"
+            );
+            let raw = capnpc::CompilerCommand::new()
+                .capnp_executable(env!("CAPNP_EXECUTABLE"))
+                .compile_from_memory(&[
+                    ("service.capnp", schema.as_str()),
+                    ("rust.capnp", capnpc::standard_import("rust.capnp").unwrap()),
+                ])
+                .unwrap();
+            let dir = tempfile::tempdir().unwrap();
+            capnpc::codegen::CodeGenerationCommand::new()
+                .output_directory(dir.path())
+                .run(raw.as_slice())
+                .map(|()| std::fs::read_to_string(dir.path().join("service_capnp.rs")).unwrap())
+                .map_err(|error| error.to_string())
+        };
+
+        let original = generate("getFoo @0 (id :UInt64) -> (value :Text);").unwrap();
+        let renamed =
+            generate("fetchFoo @0 (id :UInt64) -> (value :Text) $Rust.paramsName(\"getFoo\");")
+                .unwrap();
+        for text in [&original, &renamed] {
+            assert!(text.contains("pub mod get_foo_params {"), "{text}");
+            assert!(text.contains("pub mod get_foo_results {"), "{text}");
+        }
+        assert!(renamed.contains("fn fetch_foo_request("), "{renamed}");
+
+        let error =
+            generate("getFoo @0 () -> ();\nother @1 () -> () $Rust.paramsName(\"getFoo\");")
+                .unwrap_err();
+        assert!(
+            error.contains(
+                "method `getFoo` and method `other` of interface `service.capnp:Service` both \
+                 generate a module named `get_foo_params`"
+            ),
+            "{error}"
+        );
+
+        let error = generate("getFoo @0 () -> ();\nstruct GetFooResults {}").unwrap_err();
+        assert!(
+            error.contains(
+                "nested node `GetFooResults` and method `getFoo` of interface \
+                 `service.capnp:Service` both generate a module named `get_foo_results`"
+            ),
+            "{error}"
+        );
+
+        let error = generate("getFoo @0 () -> () $Rust.paramsName(\"get-foo\");").unwrap_err();
+        assert!(error.contains("`get-foo` of method `getFoo`"), "{error}");
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn member_name_collisions() {
+        use capnpc::codegen::AccessorStyle::{CapnpGet, RustNaming};
+
+        let generate = |body: &str, style: capnpc::codegen::AccessorStyle| {
+            let schema =
+                format!("@0xc4e1a7d93b2f6085;\nusing Rust = import \"/rust.capnp\";\n{body}\n");
+            let raw = capnpc::CompilerCommand::new()
+                .capnp_executable(env!("CAPNP_EXECUTABLE"))
+                .compile_from_memory(&[
+                    ("names.capnp", schema.as_str()),
+                    ("rust.capnp", capnpc::standard_import("rust.capnp").unwrap()),
+                ])
+                .unwrap();
+            let dir = tempfile::tempdir().unwrap();
+            capnpc::codegen::CodeGenerationCommand::new()
+                .output_directory(dir.path())
+                .accessor_style(style)
+                .run(raw.as_slice())
+                .map_err(|error| error.to_string())
+        };
+
+        generate("struct Foo { foo @0 :Text; hasFoo @1 :Bool; }", CapnpGet).unwrap();
+
+        let error = generate(
+            "struct Foo { fooBar @0 :Int32; other @1 :Int32 $Rust.name(\"foo_bar\"); }",
+            CapnpGet,
+        )
+        .unwrap_err();
+        assert!(
+            error.contains(
+                "field `fooBar` and field `other` of `names.capnp:Foo` both generate a Rust \
+                 item named `get_foo_bar`; rename one of them with a $Rust.name annotation"
+            ),
+            "{error}"
+        );
+
+        // Without the `get_` prefix, a getter can collide with another field's accessors.
+        let error =
+            generate("struct Foo { foo @0 :Text; hasFoo @1 :Bool; }", RustNaming).unwrap_err();
+        assert!(
+            error.contains(
+                "field `foo` and field `hasFoo` of `names.capnp:Foo` both generate a Rust item \
+                 named `has_foo`"
+            ),
+            "{error}"
+        );
+
+        let error = generate(
+            "struct Foo { union { a @0 :Void; b @1 :Void $Rust.name(\"a\"); } }",
+            CapnpGet,
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("field `a` and field `b` of `names.capnp:Foo`"),
+            "{error}"
+        );
+
+        let error = generate(
+            "enum Color { red @0; crimson @1 $Rust.name(\"red\"); }",
+            CapnpGet,
+        )
+        .unwrap_err();
+        assert!(
+            error.contains(
+                "enumerant `red` and enumerant `crimson` of `names.capnp:Color` both generate \
+                 a Rust item named `Red`"
+            ),
+            "{error}"
+        );
+
+        let error = generate(
+            "interface Service { foo @0 () -> (); buildFoo @1 () -> (); }",
+            CapnpGet,
+        )
+        .unwrap_err();
+        assert!(
+            error.contains(
+                "method `foo` and method `buildFoo` of `names.capnp:Service` both generate a \
+                 Rust item named `build_foo_request`"
+            ),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn module_extensions() {
+        use crate::extensions::test_split_capnp::{alpha, beta, store};
+
+        assert_eq!(alpha::field_count(), 2);
+        assert_eq!(beta::field_count(), 3);
+        assert_eq!(beta::inner::field_count(), 1);
+        assert_eq!(store::put_params::field_count(), 1);
+        assert_eq!(store::put_results::field_count(), 0);
+    }
+
+    #[test]
+    fn format_output() {
+        let dir = tempfile::tempdir().unwrap();
+        let run = || {
+            capnpc::CompilerCommand::new()
+                .capnp_executable(env!("CAPNP_EXECUTABLE"))
+                .file("test.capnp")
+                .import_path("..")
+                .output_path(dir.path())
+                .format_output(capnpc::codegen::Format::PrettyPlease)
+                .run()
+                .unwrap();
+            let path = dir.path().join("test_capnp.rs");
+            let modified = std::fs::metadata(&path).unwrap().modified().unwrap();
+            (std::fs::read_to_string(path).unwrap(), modified)
+        };
+
+        let (text, modified) = run();
+        assert!(
+            text.starts_with("// @generated by the capnpc-rust plugin"),
+            "{}",
+            &text[..200]
+        );
+        assert!(text.contains("\npub mod test_all_types {\n    "));
+        assert!(text
+            .lines()
+            .all(|line| !line.starts_with("  ") || line.starts_with("    ")));
+
+        // The same output is not rewritten.
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        let (again, modified_again) = run();
+        assert_eq!(again, text);
+        assert_eq!(modified_again, modified);
+    }
+
+    #[test]
+    fn provenance_header() {
+        let dir = tempfile::tempdir().unwrap();
+        let command = |import_paths: &[&str]| {
+            let mut command = capnpc::CompilerCommand::new();
+            command
+                .capnp_executable(env!("CAPNP_EXECUTABLE"))
+                .file("test.capnp")
+                .output_path(dir.path())
+                .default_parent_module(vec!["generated".into()])
+                .provenance_header();
+            for import_path in import_paths {
+                command.import_path(import_path);
+            }
+            command
+        };
+
+        command(&[".."]).run().unwrap();
+        let text = std::fs::read_to_string(dir.path().join("test_capnp.rs")).unwrap();
+        assert!(text.contains("// source: test.capnp\n// capnpc-version: "));
+        assert!(text.contains("\n// capnpc-import-paths: [\"..\"]\n"));
+        assert!(text.contains("\n// capnpc-default-parent-module: [\"generated\"]\n"));
+        assert!(command(&[".."])
+            .verify_headers(dir.path())
+            .unwrap()
+            .is_empty());
+
+        let drifts = command(&["..", "./schema"])
+            .verify_headers(dir.path())
+            .unwrap();
+        assert_eq!(drifts.len(), 1);
+        assert_eq!(drifts[0].path, dir.path().join("test_capnp.rs"));
+        assert_eq!(drifts[0].field, "import-paths");
+        assert_eq!(drifts[0].recorded.as_deref(), Some("[\"..\"]"));
+        assert_eq!(drifts[0].current, "[\"..\", \"schema\"]");
+
+        // Other options show up in the hash.
+        let drifts = command(&[".."])
+            .no_alloc(true)
+            .verify_headers(dir.path())
+            .unwrap();
+        assert_eq!(drifts.len(), 1);
+        assert_eq!(drifts[0].field, "options-hash");
+
+        command(&["..", "./schema"]).run().unwrap();
+        assert!(command(&["..", "schema"])
+            .verify_headers(dir.path())
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            command(&[".."]).verify_headers(dir.path()).unwrap().len(),
+            1
+        );
+
+        // Without the option, the header has nothing to verify.
+        let mut plain = capnpc::CompilerCommand::new();
+        plain
+            .capnp_executable(env!("CAPNP_EXECUTABLE"))
+            .file("test.capnp")
+            .import_path("..")
+            .output_path(dir.path())
+            .run()
+            .unwrap();
+        let text = std::fs::read_to_string(dir.path().join("test_capnp.rs")).unwrap();
+        assert!(!text.contains("// capnpc-"));
+        assert_eq!(
+            command(&[".."]).verify_headers(dir.path()).unwrap().len(),
+            5
+        );
+    }
+
+    #[test]
+    fn cfg_for_file() {
+        let generate = |sources: &[(&str, &str)], gated: &str| {
+            let dir = tempfile::tempdir().unwrap();
+            let raw = capnpc::CompilerCommand::new()
+                .capnp_executable(env!("CAPNP_EXECUTABLE"))
+                .compile_from_memory(sources)
+                .unwrap();
+            capnpc::codegen::CodeGenerationCommand::new()
+                .output_directory(dir.path())
+                .cfg_for_file(gated, "feature = \"admin\"")
+                .run(raw.as_slice())
+                .map(|()| {
+                    let read = |name| std::fs::read_to_string(dir.path().join(name)).unwrap();
+                    (read("admin_capnp.rs"), read("user_capnp.rs"))
+                })
+                .map_err(|error| error.to_string())
+        };
+
+        let admin = "@0xc3a1f07b5e2d4968;\nstruct Ban { reason @0 :Text; }\n";
+        let user = "@0xd4b2e18c6f3a5079;\nstruct User { name @0 :Text; }\n";
+        let (admin_text, user_text) = generate(
+            &[("admin.capnp", admin), ("user.capnp", user)],
+            "admin.capnp",
+        )
+        .unwrap();
+        let body = admin_text
+            .split_once("// source: admin.capnp\n\n")
+            .unwrap()
+            .1;
+        assert!(
+            body.starts_with("#[cfg(feature = \"admin\")]\nmod _cfg_gated {\n"),
+            "{admin_text}"
+        );
+        assert!(
+            body.ends_with("}\n#[cfg(feature = \"admin\")]\npub use self::_cfg_gated::*;\n"),
+            "{admin_text}"
+        );
+        assert!(body.contains("\n  pub mod ban {\n"), "{admin_text}");
+        assert!(!user_text.contains("cfg("), "{user_text}");
+
+        // A gated file may refer to an ungated one, but not the other way around.
+        let importer = "@0xd4b2e18c6f3a5079;\nusing Admin = import \"admin.capnp\";\n\
+                        struct User { ban @0 :Admin.Ban; bans @1 :List(Admin.Ban); }\n\
+                        interface Moderation { ban @0 (ban :Admin.Ban) -> (); }\n";
+        let sources = [("admin.capnp", admin), ("user.capnp", importer)];
+        assert!(generate(&sources, "user.capnp").is_ok());
+        let error = generate(&sources, "admin.capnp").unwrap_err();
+        for reference in [
+            "user.capnp:User.ban",
+            "user.capnp:User.bans",
+            "user.capnp:Moderation.ban$Params.ban",
+        ] {
+            assert!(
+                error.contains(&format!(
+                    "`{reference}` refers to `admin.capnp:Ban`, which is gated by `cfg(feature = \"admin\")`"
+                )),
+                "{error}"
+            );
+        }
+
+        // Through `build.rs`, with the predicate holding and not.
+        let mut message = message::Builder::new_default();
+        let mut alpha = message.init_root::<crate::cfg_on::test_split_capnp::alpha::Builder<'_>>();
+        alpha.set_name("on".into());
+        assert_eq!(
+            crate::cfg_on::test_split_capnp::Kind::Small,
+            alpha.get_kind().unwrap()
+        );
+        let off = std::fs::read_to_string(concat!(env!("OUT_DIR"), "/cfg-off/test_split_capnp.rs"))
+            .unwrap();
+        assert!(off.contains("#[cfg(any())]\nmod _cfg_gated {\n"), "{off}");
+        assert!(
+            off.contains("  pub mod alpha {\n    include!(\"test_split_capnp/alpha.rs\");\n"),
+            "{off}"
+        );
+    }
+
+    #[test]
+    fn capnp_root_for_file() {
+        let generate = |sources: &[(&str, &str)], roots: &[(&str, &str)]| {
+            let dir = tempfile::tempdir().unwrap();
+            let raw = capnpc::CompilerCommand::new()
+                .capnp_executable(env!("CAPNP_EXECUTABLE"))
+                .compile_from_memory(sources)
+                .unwrap();
+            let mut command = capnpc::codegen::CodeGenerationCommand::new();
+            command.output_directory(dir.path());
+            for (file, root) in roots {
+                command.capnp_root_for_file(*file, root);
+            }
+            command
+                .run(raw.as_slice())
+                .map(|()| {
+                    let read = |name| std::fs::read_to_string(dir.path().join(name)).unwrap();
+                    (read("embedded_capnp.rs"), read("host_capnp.rs"))
+                })
+                .map_err(|error| error.to_string())
+        };
+
+        let embedded = "@0xe1c2a3b4d5f60718;\nstruct Sample { value @0 :UInt32; }\n";
+        let host = "@0xf2d3b4c5e6a70829;\nstruct Report { title @0 :Text; }\n";
+        let sources = [("embedded.capnp", embedded), ("host.capnp", host)];
+        let (embedded_text, host_text) =
+            generate(&sources, &[("embedded.capnp", "::facade_core::capnp")]).unwrap();
+        assert!(
+            embedded_text.contains("::facade_core::capnp::traits::Owned"),
+            "{embedded_text}"
+        );
+        assert!(!embedded_text.contains(" ::capnp::"), "{embedded_text}");
+        assert!(host_text.contains("::capnp::traits::Owned"), "{host_text}");
+        assert!(!host_text.contains("facade_core"), "{host_text}");
+
+        // Both files may use the same overridden root.
+        let (embedded_text, host_text) = generate(
+            &sources,
+            &[
+                ("embedded.capnp", "::facade_std::capnp"),
+                ("host.capnp", "::facade_std::capnp"),
+            ],
+        )
+        .unwrap();
+        assert!(embedded_text.contains("::facade_std::capnp::traits::Owned"));
+        assert!(host_text.contains("::facade_std::capnp::traits::Owned"));
+
+        // A file may not refer to a type generated against a different root.
+        let importer = "@0xf2d3b4c5e6a70829;\nusing Embedded = import \"embedded.capnp\";\n\
+                        struct Report { samples @0 :List(Embedded.Sample); }\n";
+        let sources = [("embedded.capnp", embedded), ("host.capnp", importer)];
+        let error = generate(&sources, &[("embedded.capnp", "::facade_core::capnp")]).unwrap_err();
+        assert!(
+            error.contains(
+                "`host.capnp:Report.samples` (capnp root `::capnp`) refers to \
+                 `embedded.capnp:Sample` (capnp root `::facade_core::capnp`)"
+            ),
+            "{error}"
+        );
+        let error = generate(
+            &sources,
+            &[
+                ("embedded.capnp", "::facade_core::capnp"),
+                ("embedded.capnp", "::facade_std::capnp"),
+            ],
+        )
+        .unwrap_err();
+        assert!(
+            error.contains(
+                "`embedded.capnp` is given both `::facade_core::capnp` and \
+                 `::facade_std::capnp` as its capnp root"
+            ),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn file_constants() {
+        use crate::test_import_annotations_capnp as file;
+
+        assert_eq!(file::FILE_ID, 0xad492c67d79b533c);
+        assert!(file::NODE_IDS.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(file::NODE_IDS.contains(&file::FILE_ID));
+        assert!(file::NODE_IDS.contains(&file::account::_private::TYPE_ID));
+        assert_ne!(
+            file::SCHEMA_FINGERPRINT,
+            crate::test_capnp::SCHEMA_FINGERPRINT
+        );
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn compile_without_cargo_env() {
+        // Runs `compile_in_working_dir` in a child process without any of the environment
+        // variables that Cargo sets, such as `OUT_DIR` and `CARGO_MANIFEST_DIR`.
+        let output = std::process::Command::new(std::env::current_exe().unwrap())
+            .args(["--ignored", "--exact", "tests::compile_in_working_dir"])
+            .env_clear()
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stdout)
+        );
+    }
+
+    #[cfg(unix)]
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn generation_cache() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+
+        // Counts the invocations of `capnp` by appending a line to a file for each.
+        let counter = dir.path().join("invocations");
+        let wrapper = dir.path().join("capnp-counting");
+        std::fs::write(
+            &wrapper,
+            format!(
+                "#!/bin/sh\necho >> '{}'\nexec '{}' \"$@\"\n",
+                counter.display(),
+                env!("CAPNP_EXECUTABLE")
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&wrapper, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let invocations = || {
+            std::fs::read_to_string(&counter)
+                .map(|s| s.lines().count())
+                .unwrap_or(0)
+        };
+
+        std::fs::create_dir(dir.path().join("schema")).unwrap();
+        std::fs::write(
+            dir.path().join("schema/shape.capnp"),
+            "@0xe4c1b7a9d3f25068;\nusing import \"point.capnp\".Point;\n\
+             struct Shape { points @0 :List(Point); }\n",
+        )
+        .unwrap();
+        let write_point = |fields: &str| {
+            std::fs::write(
+                dir.path().join("schema/point.capnp"),
+                format!("@0xa9f3c5e7b1d24680;\nstruct Point {{ {fields} }}\n"),
+            )
+            .unwrap();
+        };
+        write_point("x @0 :Int32;");
+
+        let run = |force: bool| {
+            let mut command = capnpc::CompilerCommand::new();
+            command
+                .capnp_executable(&wrapper)
+                .working_dir(dir.path())
+                .file("schema/shape.capnp")
+                .src_prefix("schema")
+                .output_path("generated")
+                .cache(true);
+            if force {
+                command.force();
+            }
+            command.run().unwrap();
+            invocations()
+        };
+
+        let first = run(false);
+        assert!(first > 0);
+        assert!(dir.path().join("generated/.capnpc-cache.json").exists());
+
+        // Nothing changed, so the compiler is not called.
+        assert_eq!(run(false), first);
+
+        // Only the imported file changed.
+        write_point("x @0 :Int32; y @1 :Int32;");
+        let second = run(false);
+        assert!(second > first);
+        assert_eq!(run(false), second);
+
+        assert!(run(true) > second);
+    }
+
+    #[cfg(unix)]
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn external_compiler() {
+        use capnp::schema_capnp::code_generator_request;
+        use std::os::unix::fs::PermissionsExt;
+
+        // What `capnp compile -o-` would write for a file declaring `struct Fake {}`.
+        let mut message = capnp::message::Builder::new_default();
+        let mut request = message.init_root::<code_generator_request::Builder<'_>>();
+        let mut nodes = request.reborrow().init_nodes(2);
+        let mut file = nodes.reborrow().get(0);
+        file.set_id(0xc4d2_a6e8_f1b3_5079);
+        file.set_display_name("fake.capnp".into());
+        file.set_display_name_prefix_length(0);
+        file.reborrow()
+            .init_nested_nodes(1)
+            .get(0)
+            .set_name("Fake".into());
+        file.reborrow()
+            .get_nested_nodes()
+            .unwrap()
+            .get(0)
+            .set_id(0xd1e3_b5f7_a2c4_6089);
+        file.set_file(());
+        let mut fake = nodes.get(1);
+        fake.set_id(0xd1e3_b5f7_a2c4_6089);
+        fake.set_display_name("fake.capnp:Fake".into());
+        fake.set_display_name_prefix_length(11);
+        fake.set_scope_id(0xc4d2_a6e8_f1b3_5079);
+        fake.init_struct();
+        let mut requested = request.init_requested_files(1).get(0);
+        requested.set_id(0xc4d2_a6e8_f1b3_5079);
+        requested.set_filename("fake.capnp".into());
+
+        let dir = tempfile::tempdir().unwrap();
+        let recorded = dir.path().join("request.bin");
+        std::fs::write(
+            &recorded,
+            capnp::serialize::write_message_to_words(&message),
+        )
+        .unwrap();
+        std::fs::create_dir(dir.path().join("schema")).unwrap();
+        std::fs::write(dir.path().join("schema/fake.capnp"), "").unwrap();
+
+        let write_script = |name: &str, compile: &str| {
+            let script = dir.path().join(name);
+            std::fs::write(
+                &script,
+                format!(
+                    "#!/bin/sh\n\
+                     if [ \"$1\" = --version ]; then echo \"Cap'n Proto version 2.0-fs\"; exit; fi\n\
+                     {compile}\n"
+                ),
+            )
+            .unwrap();
+            std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+            script
+        };
+        let args = dir.path().join("args");
+        let fake = write_script(
+            "capnp-fake",
+            &format!(
+                "echo \"$@\" > '{}'\ncat '{}'",
+                args.display(),
+                recorded.display()
+            ),
+        );
+        let failing = write_script(
+            "capnp-failing",
+            "echo 'fake.capnp:1:1: error: Parse error.' >&2\nexit 1",
+        );
+
+        let command = |dir: &std::path::Path| {
+            let mut command = capnpc::CompilerCommand::new();
+            command
+                .working_dir(dir)
+                .file("schema/fake.capnp")
+                .src_prefix("schema")
+                .import_path("imports")
+                .no_standard_import()
+                .output_path("generated");
+            command
+        };
+
+        command(dir.path())
+            .capnp_executable("/nonexistent/capnp")
+            .use_external_compiler(Some(fake))
+            .run()
+            .unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&args).unwrap(),
+            "compile -o - --no-standard-import --import-path=imports --src-prefix=schema \
+             schema/fake.capnp\n"
+        );
+        let generated =
+            std::fs::read_to_string(dir.path().join("generated/fake_capnp.rs")).unwrap();
+        assert!(generated.contains("pub mod fake {"));
+
+        // The error carries what the compiler wrote to stderr.
+        let error = command(dir.path())
+            .use_external_compiler(Some(failing.clone()))
+            .run()
+            .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("fake.capnp:1:1: error: Parse error."),
+            "{error}"
+        );
+
+        // `CAPNP` is only looked at when asked to.
+        std::env::set_var("CAPNP", &failing);
+        let fake = dir.path().join("capnp-fake");
+        command(dir.path()).capnp_executable(&fake).run().unwrap();
+        assert!(command(dir.path())
+            .capnp_executable(&fake)
+            .prefer_external()
+            .run()
+            .is_err());
+        assert!(command(dir.path())
+            .capnp_executable(&fake)
+            .use_external_compiler(None)
+            .run()
+            .is_err());
+        std::env::remove_var("CAPNP");
+    }
+
+    #[test]
+    #[ignore = "run by compile_without_cargo_env"]
+    fn compile_in_working_dir() {
+        assert!(std::env::var_os("OUT_DIR").is_none());
+        assert!(std::env::var_os("CARGO_MANIFEST_DIR").is_none());
+
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("schema")).unwrap();
+        std::fs::write(
+            dir.path().join("schema/point.capnp"),
+            "@0xb7d3e5f1a2c4e608;\nstruct Point { x @0 :Int32; y @1 :Int32; }\n",
+        )
+        .unwrap();
+
+        capnpc::CompilerCommand::new()
+            .capnp_executable(env!("CAPNP_EXECUTABLE"))
+            .working_dir(dir.path())
+            .file("schema/point.capnp")
+            .src_prefix("schema")
+            .output_path("generated")
+            .raw_code_generator_request_path("generated/request.bin")
+            .run()
+            .unwrap();
+
+        let generated =
+            std::fs::read_to_string(dir.path().join("generated/point_capnp.rs")).unwrap();
+        assert!(generated.contains("pub mod point {"));
+        assert!(dir.path().join("generated/request.bin").exists());
+
+        let error = capnpc::CompilerCommand::new()
+            .capnp_executable(env!("CAPNP_EXECUTABLE"))
+            .working_dir(dir.path())
+            .file("schema/missing.capnp")
+            .output_path("generated")
+            .run()
+            .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains(&format!("`{}`", dir.path().display())),
+            "{error}"
+        );
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn compile_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let schemas = dir.path().join("schemas");
+        std::fs::create_dir_all(schemas.join("geometry/shapes")).unwrap();
+        std::fs::create_dir_all(schemas.join(".git")).unwrap();
+        std::fs::write(
+            schemas.join("geometry/point.capnp"),
+            "@0xd1a3c5e7f9b2d4e6;\nstruct Point { x @0 :Int32; y @1 :Int32; }\n",
+        )
+        .unwrap();
+        std::fs::write(
+            schemas.join("geometry/shapes/circle.capnp"),
+            "@0xe2b4d6f8a1c3e5f7;\nusing P = import \"/geometry/point.capnp\";\n\
+             struct Circle { center @0 :P.Point; radius @1 :UInt32; }\n",
+        )
+        .unwrap();
+        std::fs::write(
+            schemas.join("drawing.capnp"),
+            "@0xf3c5e7a9b2d4f6a8;\nusing C = import \"geometry/shapes/circle.capnp\";\n\
+             struct Drawing { circles @0 :List(C.Circle); }\n",
+        )
+        .unwrap();
+        // Not a valid schema; compiling it would fail.
+        std::fs::write(schemas.join(".git/broken.capnp"), "struct {").unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(&schemas, schemas.join("geometry/loop")).unwrap();
+
+        let output = dir.path().join("generated");
+        capnpc::CompilerCommand::new()
+            .capnp_executable(env!("CAPNP_EXECUTABLE"))
+            .dir(&schemas)
+            .output_path(&output)
+            .run()
+            .unwrap();
+        for generated in [
+            "drawing_capnp.rs",
+            "geometry/point_capnp.rs",
+            "geometry/shapes/circle_capnp.rs",
+        ] {
+            assert!(output.join(generated).exists(), "missing {generated}");
+        }
+
+        let empty = dir.path().join("empty");
+        std::fs::create_dir_all(empty.join("nested")).unwrap();
+        let error = capnpc::CompilerCommand::new()
+            .capnp_executable(env!("CAPNP_EXECUTABLE"))
+            .dir(&empty)
+            .output_path(&output)
+            .run()
+            .unwrap_err();
+        assert!(error.to_string().contains("No schema files"), "{error}");
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn no_alloc_rejects_interfaces() {
+        let raw_code_gen_request =
+            std::fs::read(concat!(env!("OUT_DIR"), "/raw_code_gen_request.bin"))
+                .expect("Failed to open raw code gen request file");
+
+        let error = capnpc::codegen::CodeGenerationCommand::new()
+            .output_directory(concat!(env!("OUT_DIR"), "/no-alloc-rejected"))
+            .no_alloc(true)
+            .run(raw_code_gen_request.as_slice())
+            .expect_err("test.capnp declares interfaces");
+        assert!(error.to_string().contains("no_alloc"), "{error}");
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn inline_hints() {
+        use capnpc::codegen::InlineHints;
+
+        let raw_code_gen_request =
+            std::fs::read(concat!(env!("OUT_DIR"), "/raw_code_gen_request.bin"))
+                .expect("Failed to open raw code gen request file");
+        let schema_fns = ["get_field_types", "get_annotation_types", "get_type"];
+
+        for hints in [InlineHints::Default, InlineHints::All, InlineHints::None] {
+            let dir = tempfile::tempdir().unwrap();
+            capnpc::codegen::CodeGenerationCommand::new()
+                .output_directory(dir.path())
+                .inline_hints(hints)
+                .run(raw_code_gen_request.as_slice())
+                .unwrap();
+            let generated = std::fs::read_to_string(dir.path().join("test_capnp.rs")).unwrap();
+
+            let lines = generated.lines().map(str::trim).collect::<Vec<_>>();
+            let mut audited = 0;
+            for (index, line) in lines.iter().enumerate() {
+                let Some(name) = line
+                    .strip_prefix("pub fn ")
+                    .and_then(|rest| rest.split(['(', '<']).next())
+                else {
+                    continue;
+                };
+                if !["get_", "set_", "has_"].iter().any(|p| name.starts_with(p)) && name != "which"
+                {
+                    continue;
+                }
+                let expected = match hints {
+                    InlineHints::Default => !schema_fns.contains(&name),
+                    InlineHints::All => true,
+                    InlineHints::None => false,
+                };
+                assert_eq!(
+                    lines[index - 1] == "#[inline]",
+                    expected,
+                    "{hints:?}: {line}"
+                );
+                audited += 1;
+            }
+            assert!(audited > 1000, "{audited}");
+            if hints == InlineHints::None {
+                assert!(!generated.contains("#[inline]"));
+            }
+        }
+    }
+
+    // Returns the 1-based lines on which `text` uses the `unsafe` keyword, skipping comments,
+    // string and character literals, raw identifiers and names like `unsafe_`.
+    fn unsafe_keyword_lines(text: &str) -> Vec<usize> {
+        let chars = text.chars().collect::<Vec<_>>();
+        let mut lines = Vec::new();
+        let mut line = 1;
+        let mut pos = 0;
+        let skip_to = |pos: &mut usize, line: &mut usize, end: usize| {
+            *line += chars[*pos..end].iter().filter(|&&c| c == '\n').count();
+            *pos = end;
+        };
+        while pos < chars.len() {
+            let rest = &chars[pos..];
+            match rest {
+                ['\n', ..] => {
+                    line += 1;
+                    pos += 1;
+                }
+                ['/', '/', ..] => {
+                    let end = rest
+                        .iter()
+                        .position(|&c| c == '\n')
+                        .map_or(chars.len(), |n| pos + n);
+                    skip_to(&mut pos, &mut line, end);
+                }
+                ['/', '*', ..] => {
+                    let mut depth = 0;
+                    let mut end = pos;
+                    while end < chars.len() {
+                        match &chars[end..] {
+                            ['/', '*', ..] => {
+                                depth += 1;
+                                end += 2;
+                            }
+                            ['*', '/', ..] => {
+                                depth -= 1;
+                                end += 2;
+                                if depth == 0 {
+                                    break;
+                                }
+                            }
+                            _ => end += 1,
+                        }
+                    }
+                    skip_to(&mut pos, &mut line, end);
+                }
+                ['r', '#', c, ..] if c.is_alphabetic() || *c == '_' => {
+                    let end = rest[2..]
+                        .iter()
+                        .position(|c| !c.is_alphanumeric() && *c != '_')
+                        .map_or(chars.len(), |n| pos + 2 + n);
+                    skip_to(&mut pos, &mut line, end);
+                }
+                ['r', '#', ..] | ['r', '"', ..] | ['b', 'r', '#' | '"', ..] => {
+                    let start = rest.iter().position(|&c| c == '"').unwrap() + 1;
+                    let hashes = rest[..start].iter().filter(|&&c| c == '#').count();
+                    let closing = std::iter::once('"')
+                        .chain(std::iter::repeat('#').take(hashes))
+                        .collect::<Vec<_>>();
+                    let end = rest[start..]
+                        .windows(closing.len())
+                        .position(|window| window == closing.as_slice())
+                        .map_or(chars.len(), |n| pos + start + n + closing.len());
+                    skip_to(&mut pos, &mut line, end);
+                }
+                ['"', ..] | ['b', '"', ..] => {
+                    let mut end = pos + rest.iter().position(|&c| c == '"').unwrap() + 1;
+                    while end < chars.len() && chars[end] != '"' {
+                        end += if chars[end] == '\\' { 2 } else { 1 };
+                    }
+                    skip_to(&mut pos, &mut line, end + 1);
+                }
+                ['\'', '\\', ..] => {
+                    let end = rest[2..]
+                        .iter()
+                        .position(|&c| c == '\'')
+                        .map_or(chars.len(), |n| pos + 3 + n);
+                    skip_to(&mut pos, &mut line, end);
+                }
+                ['\'', _, '\'', ..] => pos += 3,
+                [c, ..] if c.is_alphanumeric() || *c == '_' => {
+                    let end = rest
+                        .iter()
+                        .position(|c| !c.is_alphanumeric() && *c != '_')
+                        .map_or(chars.len(), |n| pos + n);
+                    if chars[pos..end].iter().copied().eq("unsafe".chars()) {
+                        lines.push(line);
+                    }
+                    pos = end;
+                }
+                _ => pos += 1,
+            }
+        }
+        lines
+    }
+
+    #[test]
+    fn unsafe_keyword_scanner() {
+        assert_eq!(
+            unsafe_keyword_lines("fn f() {\n    unsafe { g() }\n}\nunsafe fn h() {}\n"),
+            [2, 4]
+        );
+        assert!(unsafe_keyword_lines(
+            "// unsafe\n/* unsafe /* unsafe */ */\nlet s = \"unsafe \\\" unsafe\";\n\
+             let r = r#\"unsafe\"#;\nmod unsafe_ {}\nlet r#unsafe = 'u';\nfn f<'a>(x: &'a u8) {}\n"
+        )
+        .is_empty());
+    }
+
+    // Checks all code generated for the test schemas, under every option this crate's build
+    // script exercises. `capnpc-test-forbid-unsafe` also builds most of it with
+    // `#![forbid(unsafe_code)]`.
+    #[test]
+    fn generated_code_has_no_unsafe() {
+        fn visit(dir: &std::path::Path, files: &mut Vec<std::path::PathBuf>) {
+            for entry in std::fs::read_dir(dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    visit(&path, files);
+                } else if path.extension().is_some_and(|extension| extension == "rs") {
+                    files.push(path);
+                }
+            }
+        }
+
+        let mut files = Vec::new();
+        visit(std::path::Path::new(env!("OUT_DIR")), &mut files);
+        assert!(files.len() > 20, "{files:?}");
+        for file in files {
+            let text = std::fs::read_to_string(&file).unwrap();
+            let lines = unsafe_keyword_lines(&text);
+            assert!(
+                lines.is_empty(),
+                "`unsafe` in {} on lines {lines:?}",
+                file.display()
+            );
+        }
+    }
+
+    #[test]
+    fn no_convenience_builders() {
+        use crate::test_no_convenience_builders_capnp::{box_, shape};
+
+        let generated = include_str!(concat!(
+            env!("OUT_DIR"),
+            "/no-convenience-builders/test_no_convenience_builders_capnp.rs"
+        ));
+        assert!(!generated.contains("build_capnp_struct"));
+        assert!(!generated.contains("pub struct Shape {"));
+        assert!(!generated.contains("pub enum ShapeUnion"));
+        assert!(!generated.contains("build_draw_request"));
+        assert!(!generated.contains("build_store_request"));
+        assert!(generated.contains("pub fn draw_request(&self)"));
+
+        let mut message = message::Builder::new_default();
+        let mut root = message.init_root::<box_::Builder<'_, shape::Owned>>();
+        root.set_label("crate".into());
+        let mut content = root.reborrow().init_content();
+        content.set_name("c".into());
+        content.set_circle(1.5);
+        let reader = root.into_reader();
+        assert_eq!(reader.get_label().unwrap(), "crate");
+        let content = reader.get_content().unwrap();
+        assert_eq!(content.get_name().unwrap(), "c");
+        assert_matches!(content.which(), Ok(shape::Circle(_)));
+    }
+
+    #[test]
+    fn canonical_nans() {
+        use crate::test_canonical_nans_capnp::measurement;
+
+        let encode = |single: f32, double: f64| {
+            let mut message = message::Builder::new_default();
+            let mut root = message.init_root::<measurement::Builder<'_>>();
+            root.set_single(single);
+            root.set_double(double);
+            root.set_single_one(single);
+            root.set_double_nan(double);
+            root.set_reading(single);
+            capnp::serialize::write_message_to_words(&message)
+        };
+
+        let singles = [
+            f32::NAN,
+            -f32::NAN,
+            f32::from_bits(0x7fc0_0001),
+            f32::from_bits(0xffc1_2345),
+            f32::from_bits(0x7f80_0001),
+            f32::INFINITY - f32::INFINITY,
+        ];
+        let doubles = [
+            f64::NAN,
+            -f64::NAN,
+            f64::from_bits(0x7ff8_0000_0000_0001),
+            f64::from_bits(0xfff8_dead_beef_0000),
+            f64::from_bits(0x7ff0_0000_0000_0001),
+            f64::INFINITY - f64::INFINITY,
+        ];
+        let expected = encode(singles[0], doubles[0]);
+        for (&single, &double) in singles.iter().zip(doubles.iter()) {
+            assert_eq!(encode(single, double), expected);
+        }
+
+        let message = capnp::serialize::read_message_from_flat_slice(
+            &mut &expected[..],
+            ReaderOptions::new(),
+        )
+        .unwrap();
+        let root = message.get_root::<measurement::Reader<'_>>().unwrap();
+        assert_eq!(
+            root.get_single().to_bits(),
+            capnp::private::mask::CANONICAL_NAN_F32_BITS
+        );
+        assert_eq!(
+            root.get_double().to_bits(),
+            capnp::private::mask::CANONICAL_NAN_F64_BITS
+        );
+        assert_eq!(
+            root.get_single_one().to_bits(),
+            capnp::private::mask::CANONICAL_NAN_F32_BITS
+        );
+        assert!(root.get_double_nan().is_nan());
+        match root.which() {
+            Ok(measurement::Reading(r)) => {
+                assert_eq!(r.to_bits(), capnp::private::mask::CANONICAL_NAN_F32_BITS)
+            }
+            _ => panic!("expected reading"),
+        }
+
+        // Setting a NaN on a field with a NaN default stores zero, as if it were unset.
+        let mut message = message::Builder::new_default();
+        message.init_root::<measurement::Builder<'_>>();
+        let unset = capnp::serialize::write_message_to_words(&message);
+        let mut message = message::Builder::new_default();
+        message
+            .init_root::<measurement::Builder<'_>>()
+            .set_double_nan(f64::from_bits(0x7ff8_0000_0000_0042));
+        assert_eq!(capnp::serialize::write_message_to_words(&message), unset);
+
+        // Other values are stored as usual.
+        assert_ne!(encode(1.5, 2.5), expected);
+        let words = encode(1.5, 2.5);
+        let message =
+            capnp::serialize::read_message_from_flat_slice(&mut &words[..], ReaderOptions::new())
+                .unwrap();
+        let root = message.get_root::<measurement::Reader<'_>>().unwrap();
+        assert_eq!(root.get_single(), 1.5);
+        assert_eq!(root.get_single_one(), 1.5);
+        assert_eq!(root.get_double_nan(), 2.5);
+    }
+
+    #[test]
+    fn lenient_enums() {
+        use crate::test_lenient_enums_capnp::{
+            new_paint, old_paint, NewColor, OldColor, OldColorOrUnknown,
+        };
+
+        let mut message = message::Builder::new_default();
+        let mut paint = message.init_root::<new_paint::Builder<'_>>();
+        paint.set_color(NewColor::Blue);
+        paint.set_shade(NewColor::Blue);
+        paint.set_tinted(NewColor::Blue);
+
+        // Read it back with the old schema, which doesn't know about `blue`.
+        let old = message
+            .get_root_as_reader::<old_paint::Reader<'_>>()
+            .unwrap();
+        assert!(old.get_color().is_err());
+        assert_eq!(old.get_color_lenient(), OldColorOrUnknown::Unknown(2));
+        assert_eq!(old.get_shade_lenient(), OldColorOrUnknown::Unknown(2));
+        assert_matches!(old.which(), Ok(old_paint::Tinted(Err(_))));
+
+        // Copy the fields through the old schema's setters.
+        let mut copy = message::Builder::new_default();
+        let mut copied = copy.init_root::<old_paint::Builder<'_>>();
+        copied.set_color_lenient(old.get_color_lenient());
+        copied.set_shade_lenient(old.get_shade_lenient());
+        copied.set_tinted_lenient(OldColorOrUnknown::from(2));
+        assert_eq!(
+            copied.reborrow().get_shade_lenient(),
+            OldColorOrUnknown::Unknown(2)
+        );
+
+        let new = copy.get_root_as_reader::<new_paint::Reader<'_>>().unwrap();
+        assert_eq!(new.get_color().unwrap(), NewColor::Blue);
+        assert_eq!(new.get_shade().unwrap(), NewColor::Blue);
+        assert_matches!(new.which(), Ok(new_paint::Tinted(Ok(NewColor::Blue))));
+
+        // Known values go through unchanged.
+        let mut copied = copy.get_root::<old_paint::Builder<'_>>().unwrap();
+        copied.set_shade_lenient(OldColor::Red.into());
+        assert_eq!(copied.get_shade().unwrap(), OldColor::Red);
+        assert_eq!(
+            OldColorOrUnknown::from(1),
+            OldColorOrUnknown::Known(OldColor::Green)
+        );
+        assert_eq!(u16::from(OldColorOrUnknown::Unknown(7)), 7);
+    }
+
+    #[test]
+    fn recursive_params_structs() {
+        use crate::test_recursion_capnp::{expr, forest, leaf, ping, pong, tree};
+
+        let generated = include_str!(concat!(env!("OUT_DIR"), "/test_recursion_capnp.rs"));
+        assert!(
+            generated.contains("pub _pong: Option<Box<crate::test_recursion_capnp::pong::Pong>>")
+        );
+        assert!(
+            generated.contains("pub _ping: Option<Box<crate::test_recursion_capnp::ping::Ping>>")
+        );
+        assert!(
+            generated.contains("pub _children: Vec<crate::test_recursion_capnp::forest::Forest>")
+        );
+        // Neither `Forest` nor `Leaf` is part of `Tree`'s cycle.
+        assert!(!generated.contains("Option<Box<crate::test_recursion_capnp::forest::Forest>>"));
+        assert!(!generated.contains("Option<Box<crate::test_recursion_capnp::leaf::Leaf>>"));
+        assert!(generated.contains("pub _tree: Option<crate::test_recursion_capnp::tree::Tree>"));
+
+        let tree = tree::Tree {
+            _value: 1,
+            _left: Some(Box::new(tree::Tree {
+                _value: 2,
+                _left: None,
+                _right: None,
+            })),
+            _right: None,
+        };
+        let mut message = message::Builder::new_default();
+        tree.build_capnp_struct(message.init_root()).unwrap();
+        let root = message.get_root_as_reader::<tree::Reader<'_>>().unwrap();
+        assert_eq!(root.get_value(), 1);
+        assert_eq!(root.get_left().unwrap().get_value(), 2);
+        assert!(!root.has_right());
+
+        let ping = ping::Ping {
+            _count: 1,
+            _pong: Some(Box::new(pong::Pong {
+                _count: 2,
+                _ping: Some(Box::new(ping::Ping {
+                    _count: 3,
+                    _pong: None,
+                })),
+            })),
+        };
+        let mut message = message::Builder::new_default();
+        ping.build_capnp_struct(message.init_root()).unwrap();
+        let root = message.get_root_as_reader::<ping::Reader<'_>>().unwrap();
+        let pong = root.get_pong().unwrap();
+        assert_eq!(pong.get_count(), 2);
+        assert_eq!(pong.get_ping().unwrap().get_count(), 3);
+
+        let forest = forest::Forest {
+            _name: "root".into(),
+            _children: vec![forest::Forest {
+                _name: "child".into(),
+                _children: Vec::new(),
+                _tree: None,
+            }],
+            _tree: Some(tree::Tree {
+                _value: 5,
+                _left: None,
+                _right: None,
+            }),
+        };
+        let mut message = message::Builder::new_default();
+        forest.build_capnp_struct(message.init_root()).unwrap();
+        let root = message.get_root_as_reader::<forest::Reader<'_>>().unwrap();
+        assert_eq!(
+            root.get_children().unwrap().get(0).get_name().unwrap(),
+            "child"
+        );
+        assert_eq!(root.get_tree().unwrap().get_value(), 5);
+
+        let expr = expr::Expr::_Negate(Box::new(expr::Expr::_Literal(7)));
+        let mut message = message::Builder::new_default();
+        expr.build_capnp_struct(message.init_root()).unwrap();
+        let root = message.get_root_as_reader::<expr::Reader<'_>>().unwrap();
+        match root.which().unwrap() {
+            expr::Negate(inner) => {
+                assert_matches!(inner.unwrap().which(), Ok(expr::Literal(7)))
+            }
+            _ => panic!("expected negate"),
+        }
+
+        let leaf = leaf::Leaf {
+            _label: "leaf".into(),
+            _tree: None,
+        };
+        let mut message = message::Builder::new_default();
+        leaf.build_capnp_struct(message.init_root()).unwrap();
+        let root = message.get_root_as_reader::<leaf::Reader<'_>>().unwrap();
+        assert_eq!(root.get_label().unwrap(), "leaf");
+    }
+
+    #[test]
+    fn params_struct_from_reader() {
+        use crate::test_capnp::{
+            test_all_types, test_complex_list, test_struct_list, test_unnamed_union, AnEnum,
+        };
+        use crate::test_recursion_capnp::{expr, forest, tree};
+
+        // Reader -> params struct -> mutate -> build_capnp_struct.
+        let mut message = message::Builder::new_default();
+        {
+            let mut root = message.init_root::<test_unnamed_union::Builder<'_>>();
+            root.set_before("before".into());
+            root.set_bar(321);
+            root.set_middle(7);
+            root.set_after("after".into());
+        }
+        let reader = message
+            .get_root_as_reader::<test_unnamed_union::Reader<'_>>()
+            .unwrap();
+        let mut plain = test_unnamed_union::TestUnnamedUnion::try_from(reader).unwrap();
+        assert_eq!(plain._before, "before");
+        assert_eq!(plain._middle, 7);
+        assert_eq!(plain._after, "after");
+        assert_matches!(
+            plain.uni,
+            test_unnamed_union::TestUnnamedUnionUnion::_Bar(321)
+        );
+        plain._after.push_str(" edited");
+        plain.uni = test_unnamed_union::TestUnnamedUnionUnion::_Foo(5);
+        let mut message = message::Builder::new_default();
+        plain.build_capnp_struct(message.init_root()).unwrap();
+        let root = message
+            .get_root_as_reader::<test_unnamed_union::Reader<'_>>()
+            .unwrap();
+        assert_eq!(root.get_before().unwrap(), "before");
+        assert_eq!(root.get_after().unwrap(), "after edited");
+        assert_matches!(root.which(), Ok(test_unnamed_union::Foo(5)));
+
+        let mut message = message::Builder::new_default();
+        {
+            let mut root = message.init_root::<test_complex_list::Builder<'_>>();
+            {
+                let mut enums = root.reborrow().init_enum_list(2);
+                enums.set(0, AnEnum::Qux);
+                enums.set(1, AnEnum::Foo);
+            }
+            {
+                let mut texts = root.reborrow().init_text_list(2);
+                texts.set(0, "a".into());
+                texts.set(1, "b".into());
+            }
+            {
+                let mut lists = root.reborrow().init_prim_list_list(2);
+                lists.reborrow().init(0, 1).set(0, -1);
+                lists.reborrow().init(1, 2).set(1, 9);
+            }
+            let mut data = root.init_data_list(1);
+            data.set(0, &[1, 2, 3]);
+        }
+        let reader = message
+            .get_root_as_reader::<test_complex_list::Reader<'_>>()
+            .unwrap();
+        let mut plain = test_complex_list::TestComplexList::try_from(reader).unwrap();
+        assert_eq!(plain._enum_list, vec![AnEnum::Qux, AnEnum::Foo]);
+        assert_eq!(plain._text_list, vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(plain._prim_list_list, vec![vec![-1], vec![0, 9]]);
+        assert_eq!(plain._data_list, vec![vec![1, 2, 3]]);
+        assert!(plain._struct_list_list.is_empty());
+        plain._text_list.push("c".into());
+        let mut copy = message::Builder::new_default();
+        plain.build_capnp_struct(copy.init_root()).unwrap();
+        let root = copy
+            .get_root_as_reader::<test_complex_list::Reader<'_>>()
+            .unwrap();
+        assert_eq!(root.get_text_list().unwrap().len(), 3);
+        assert_eq!(root.get_text_list().unwrap().get(2).unwrap(), "c");
+        assert_eq!(root.get_enum_list().unwrap().get(0).unwrap(), AnEnum::Qux);
+        assert_eq!(root.get_prim_list_list().unwrap().get(1).unwrap().get(1), 9);
+
+        let mut message = message::Builder::new_default();
+        {
+            let root = message.init_root::<test_struct_list::Builder<'_>>();
+            let mut list = root.init_struct_list(2);
+            list.reborrow().get(0).init_uint8_list(1).set(0, 4);
+            list.reborrow().get(1).init_bool_list(2).set(1, true);
+        }
+        let reader = message
+            .get_root_as_reader::<test_struct_list::Reader<'_>>()
+            .unwrap();
+        let plain = test_struct_list::TestStructList::try_from(reader).unwrap();
+        assert_eq!(plain._struct_list.len(), 2);
+        assert_eq!(plain._struct_list[0]._uint8_list, vec![4]);
+        assert_eq!(plain._struct_list[1]._bool_list, vec![false, true]);
+
+        // A field that cannot be read surfaces its error; `TestAllTypes` has an unset interface.
+        let message = message::Builder::new_default();
+        let reader = message
+            .get_root_as_reader::<test_all_types::Reader<'_>>()
+            .unwrap();
+        assert!(test_all_types::TestAllTypes::try_from(reader).is_err());
+
+        // Unset struct fields come back as `None`; recursive fields stay boxed.
+        let forest = forest::Forest {
+            _name: "root".into(),
+            _children: vec![forest::Forest {
+                _name: "child".into(),
+                _children: Vec::new(),
+                _tree: None,
+            }],
+            _tree: Some(tree::Tree {
+                _value: 5,
+                _left: Some(Box::new(tree::Tree {
+                    _value: 6,
+                    _left: None,
+                    _right: None,
+                })),
+                _right: None,
+            }),
+        };
+        let mut message = message::Builder::new_default();
+        forest.build_capnp_struct(message.init_root()).unwrap();
+        let reader = message.get_root_as_reader::<forest::Reader<'_>>().unwrap();
+        let plain = forest::Forest::try_from(reader).unwrap();
+        assert_eq!(plain._children[0]._name, "child");
+        assert!(plain._children[0]._tree.is_none());
+        let tree = plain._tree.unwrap();
+        assert_eq!(tree._left.unwrap()._value, 6);
+        assert!(tree._right.is_none());
+
+        let expr = expr::Expr::_Negate(Box::new(expr::Expr::_Literal(7)));
+        let mut message = message::Builder::new_default();
+        expr.build_capnp_struct(message.init_root()).unwrap();
+        let reader = message.get_root_as_reader::<expr::Reader<'_>>().unwrap();
+        match expr::Expr::try_from(reader).unwrap() {
+            expr::Expr::_Negate(inner) => assert_matches!(*inner, expr::Expr::_Literal(7)),
+            _ => panic!("expected negate"),
+        }
+    }
+
+    #[test]
+    fn params_enum_group_variants() {
+        use crate::test_capnp::test_group_union::{self, shape};
+
+        let circle = test_group_union::TestGroupUnion {
+            _shape: shape::Shape::_Circle(shape::circle::Circle {
+                _radius: 1.5,
+                _label: "unit".into(),
+            }),
+        };
+        let mut message = message::Builder::new_default();
+        circle.build_capnp_struct(message.init_root()).unwrap();
+        let root = message
+            .get_root_as_reader::<test_group_union::Reader<'_>>()
+            .unwrap();
+        match root.get_shape().which().unwrap() {
+            shape::Circle(circle) => {
+                assert_eq!(circle.get_radius(), 1.5);
+                assert_eq!(circle.get_label().unwrap(), "unit");
+            }
+            shape::Rectangle(_) => panic!("expected a circle"),
+        }
+
+        // Reader -> params enum -> other variant -> build_capnp_struct.
+        let mut plain = test_group_union::TestGroupUnion::try_from(root).unwrap();
+        match &plain._shape {
+            shape::Shape::_Circle(circle) => {
+                assert_eq!(circle._radius, 1.5);
+                assert_eq!(circle._label, "unit");
+            }
+            _ => panic!("expected a circle"),
+        }
+        plain._shape = shape::Shape::_Rectangle(shape::rectangle::Rectangle {
+            _width: 2.0,
+            _height: 3.0,
+        });
+        plain.build_capnp_struct(message.init_root()).unwrap();
+        let root = message
+            .get_root_as_reader::<test_group_union::Reader<'_>>()
+            .unwrap();
+        match root.get_shape().which().unwrap() {
+            shape::Rectangle(rectangle) => {
+                assert_eq!(rectangle.get_width(), 2.0);
+                assert_eq!(rectangle.get_height(), 3.0);
+            }
+            shape::Circle(_) => panic!("expected a rectangle"),
+        }
+    }
+
+    #[test]
+    fn disown_and_adopt() {
+        use crate::test_capnp::test_all_types;
+
+        // Tiny segments so that adoption has to go through far pointers.
+        let mut message =
+            message::Builder::new(message::HeapAllocator::new().first_segment_words(2));
+        let mut root = message.init_root::<test_all_types::Builder<'_>>();
+        {
+            let mut list = root.reborrow().init_int32_list(3);
+            list.set(0, 10);
+            list.set(1, 20);
+            list.set(2, 30);
+        }
+        root.reborrow()
+            .init_struct_field()
+            .set_text_field("nested".into());
+
+        let orphan = root.disown_int32_list();
+        assert!(!orphan.is_null());
+        assert!(!root.has_int32_list());
+
+        let mut nested = root.reborrow().get_struct_field().unwrap();
+        nested.adopt_int32_list(orphan).unwrap();
+        assert_eq!(
+            nested
+                .reborrow()
+                .get_int32_list()
+                .unwrap()
+                .into_reader()
+                .iter()
+                .collect::<Vec<_>>(),
+            [10, 20, 30]
+        );
+
+        let text = nested.disown_text_field();
+        root.adopt_text_field(text).unwrap();
+        assert!(!root.reborrow().get_struct_field().unwrap().has_text_field());
+
+        let reader = message
+            .get_root_as_reader::<test_all_types::Reader<'_>>()
+            .unwrap();
+        assert!(!reader.has_int32_list());
+        assert_eq!(reader.get_text_field().unwrap(), "nested");
+        let nested = reader.get_struct_field().unwrap();
+        assert_eq!(nested.get_int32_list().unwrap().len(), 3);
+        assert!(!nested.has_text_field());
+
+        // Disowning a null pointer gives a null orphan.
+        let mut root = message.get_root::<test_all_types::Builder<'_>>().unwrap();
+        assert!(root.disown_data_field().is_null());
+    }
+
+    #[test]
+    fn disown_and_adopt_list_of_lists() {
+        use crate::test_capnp::test_lists;
+
+        let mut message = message::Builder::new_default();
+        let mut root = message.init_root::<test_lists::Builder<'_>>();
+        {
+            let mut lists = root.reborrow().init_int32_list_list(2);
+            let mut first = lists.reborrow().init(0, 2);
+            first.set(0, 1);
+            first.set(1, 2);
+        }
+        let mut lists = root.reborrow().get_int32_list_list().unwrap();
+        let orphan = lists.disown(0);
+        lists.adopt(1, orphan).unwrap();
+
+        let reader = message
+            .get_root_as_reader::<test_lists::Reader<'_>>()
+            .unwrap();
+        let lists = reader.get_int32_list_list().unwrap();
+        assert!(lists.get(0).unwrap().is_empty());
+        assert_eq!(lists.get(1).unwrap().iter().collect::<Vec<_>>(), [1, 2]);
+    }
+
+    #[test]
+    fn new_orphan() {
+        use crate::test_capnp::test_all_types;
+
+        let mut message = message::Builder::new_default();
+        let (orphan, mut builder) = message.new_orphan::<test_all_types::Owned>();
+        builder.set_u_int32_field(7);
+        let (list_orphan, mut list) = message.new_orphan_list::<text_list::Owned>(1);
+        list.set(0, "orphaned".into());
+
+        let mut root = message.init_root::<test_all_types::Builder<'_>>();
+        root.adopt_struct_field(orphan).unwrap();
+        root.adopt_text_list(list_orphan).unwrap();
+
+        let reader = message
+            .get_root_as_reader::<test_all_types::Reader<'_>>()
+            .unwrap();
+        assert_eq!(reader.get_struct_field().unwrap().get_u_int32_field(), 7);
+        assert_eq!(reader.get_text_list().unwrap().get(0).unwrap(), "orphaned");
+    }
+
+    #[test]
+    fn new_orphan_allocates_only_its_object() {
+        use crate::test_capnp::test_all_types;
+
+        let mut expected = message::Builder::new_default();
+        expected.init_root::<test_all_types::Builder<'_>>();
+        let root_size = expected.allocated_words();
+
+        let mut message = message::Builder::new_default();
+        message.new_orphan::<test_all_types::Owned>();
+        assert_eq!(message.allocated_words(), root_size);
+
+        let (orphan, mut text) = message.new_orphan_list::<capnp::text::Owned>(5);
+        text.truncate(0);
+        text.push_str("hello").unwrap();
+        assert_eq!(message.allocated_words(), root_size + 1);
+        let mut root = message.init_root::<test_all_types::Builder<'_>>();
+        root.adopt_text_field(orphan).unwrap();
+        assert_eq!(root.get_text_field().unwrap(), "hello");
+    }
+
+    #[test]
+    fn disown_inactive_union_member() {
+        use crate::test_capnp::test_union;
+
+        let mut message = message::Builder::new_default();
+        let mut root = message.init_root::<test_union::Builder<'_>>();
+        root.reborrow().get_union0().set_u0f0sp("abc".into());
+        assert!(root.reborrow().get_union0().disown_u0f1sp().is_null());
+        assert!(root.reborrow().get_union0().has_u0f0sp());
+    }
+
+    #[test]
+    fn adopt_from_other_message() {
+        use crate::test_capnp::test_all_types;
+
+        let mut other = message::Builder::new_default();
+        let (orphan, _) = other.new_orphan::<test_all_types::Owned>();
+
+        let mut message = message::Builder::new_default();
+        let mut root = message.init_root::<test_all_types::Builder<'_>>();
+        let err = root.adopt_struct_field(orphan).unwrap_err();
+        assert_eq!(
+            err.kind,
+            capnp::ErrorKind::AdoptedObjectMustLiveInTheSameMessage
+        );
+        assert!(!root.has_struct_field());
+    }
+
+    #[test]
+    fn builder_debug() {
+        use crate::test_capnp::{test_all_types, test_union};
+
+        let mut message = message::Builder::new_default();
+        let mut root = message.init_root::<test_union::Builder<'_>>();
+        root.reborrow().init_union0().set_u0f0sp("abc".into());
+        let formatted = format!("{root:?}");
+        assert_eq!(formatted, format!("{:?}", root.reborrow_as_reader()));
+        assert!(formatted.starts_with("(union0 = (u0f0sp = \"abc\"), union1 = (u1f0s0 = ()),"));
+
+        // Pointer fields that were never set.
+        let mut message = message::Builder::new_default();
+        let mut root = message.init_root::<test_all_types::Builder<'_>>();
+        root.set_u_int8_field(3);
+        root.reborrow().init_struct_field();
+        let formatted = format!("{root:?}");
+        assert!(formatted.contains("uInt8Field = 3"));
+        assert!(formatted.contains("structField = (voidField = ()"));
+        assert!(!formatted.contains("textField"));
+    }
+
+    #[test]
+    fn split_output() {
+        use crate::test_split_capnp::{alpha, beta, Kind, DEFAULT_ALPHA};
+        use std::path::Path;
+
+        let out_dir = Path::new(env!("OUT_DIR"));
+        let shim = std::fs::read_to_string(out_dir.join("split/test_split_capnp.rs")).unwrap();
+        for name in ["alpha", "beta"] {
+            assert!(shim.contains(&format!(
+                "pub mod {name} {{\n  include!(\"test_split_capnp/{name}.rs\");\n}}"
+            )));
+        }
+        // Inner attributes stay in the shim, since `include!` does not accept them.
+        assert!(shim.contains(
+            "pub mod store {\n  #![allow(unused_variables)]\n  include!(\"test_split_capnp/store.rs\");\n}"
+        ));
+        assert!(shim.contains("pub enum Kind {"));
+        assert!(!shim.contains("pub mod inner"));
+
+        // Splicing the split files back into the shim gives the unsplit output, up to indentation.
+        let mut spliced = String::new();
+        for line in shim.lines() {
+            match line
+                .trim()
+                .strip_prefix("include!(\"")
+                .and_then(|l| l.strip_suffix("\");"))
+            {
+                Some(path) => spliced
+                    .push_str(&std::fs::read_to_string(out_dir.join("split").join(path)).unwrap()),
+                None => spliced.push_str(line),
+            }
+            spliced.push('\n');
+        }
+        let unsplit = std::fs::read_to_string(out_dir.join("unsplit/test_split_capnp.rs")).unwrap();
+        fn normalize(text: &str) -> Vec<&str> {
+            text.lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty() && !l.starts_with("// "))
+                .collect()
+        }
+        assert_eq!(normalize(&spliced), normalize(&unsplit));
+
+        // The split modules are usable through their usual paths.
+        let mut message = message::Builder::new_default();
+        let mut root = message.init_root::<beta::Builder<'_>>();
+        root.set_alpha(DEFAULT_ALPHA.get().unwrap()).unwrap();
+        root.reborrow().init_alphas(1).get(0).set_kind(Kind::Small);
+        let root = root.into_reader();
+        let alpha: alpha::Reader<'_> = root.get_alpha().unwrap();
+        assert_eq!(alpha.get_name().unwrap(), "default");
+        assert_eq!(alpha.get_kind().unwrap(), Kind::Large);
+        assert_eq!(
+            root.get_alphas().unwrap().get(0).get_kind().unwrap(),
+            Kind::Small
+        );
+        assert_eq!(root.get_inner().unwrap().get_count(), 7);
+    }
+
+    #[test]
+    fn accessor_styles() -> capnp::Result<()> {
+        use crate::capnp_get::test_accessor_style_capnp as get_style;
+        use crate::rust_naming::test_accessor_style_capnp as rust_style;
+
+        // Build with the `with_*` setters, read back with the `get_*` getters.
+        let mut message = message::Builder::new_default();
+        let gadget = message
+            .init_root::<rust_style::gadget::Builder<'_>>()
+            .with_count(12)
+            .with_name("widget".into())
+            .with_type("tool".into())
+            .with_which(4)
+            .with_shape(rust_style::Shape::Round)
+            .with_label("front".into());
+        gadget.size().set_width(1.5);
+        let mut tags = message::Builder::new_default();
+        tags.initn_root::<capnp::text_list::Builder<'_>>(1)
+            .set(0, "new".into());
+        let mut inner = message::Builder::new_default();
+        inner
+            .init_root::<rust_style::gadget::Builder<'_>>()
+            .set_count(5);
+        let gadget = message.get_root::<rust_style::gadget::Builder<'_>>()?;
+        gadget
+            .with_tags(tags.get_root_as_reader()?)?
+            .with_inner(inner.get_root_as_reader()?)?;
+
+        let reader = message.get_root_as_reader::<get_style::gadget::Reader<'_>>()?;
+        assert_eq!(reader.get_count(), 12);
+        assert_eq!(reader.get_name()?, "widget");
+        assert_eq!(reader.get_type()?, "tool");
+        assert_eq!(reader.get_which(), 4);
+        assert_eq!(reader.get_shape()?, get_style::Shape::Round);
+        assert_eq!(reader.get_shape_lenient(), get_style::Shape::Round.into());
+        assert_eq!(reader.get_tags()?.get(0)?, "new");
+        assert_eq!(reader.get_inner()?.get_count(), 5);
+        assert_eq!(reader.get_size().get_width(), 1.5);
+        assert!(matches!(reader.which()?, get_style::gadget::Label(Ok(l)) if l == "front"));
+
+        // And the other way around.
+        let mut message = message::Builder::new_default();
+        let mut gadget = message.init_root::<get_style::gadget::Builder<'_>>();
+        gadget.set_type("tool".into());
+        gadget.set_which(9);
+        gadget.reborrow().init_part().set_count(6);
+        let reader = message.get_root_as_reader::<rust_style::gadget::Reader<'_>>()?;
+        assert_eq!(reader.count(), 3);
+        assert_eq!(reader.type_()?, "tool");
+        assert_eq!(reader.which_(), 9);
+        assert_eq!(reader.shape()?, rust_style::Shape::Square);
+        assert_eq!(reader.shape_lenient(), rust_style::Shape::Square.into());
+        assert!(!reader.has_name());
+        let rust_style::gadget::Part(part) = reader.which()? else {
+            panic!("expected `part`");
+        };
+        assert_eq!(part?.count(), 6);
+
+        // Getters on builders, and the presence-tracking wrapper.
+        let mut message = message::Builder::new_default();
+        let settings = message
+            .init_root::<rust_style::settings::Builder<'_>>()
+            .with_level(8);
+        assert_eq!(settings.reborrow_as_reader().level(), 8);
+        let presence =
+            rust_style::settings::SettingsWithPresence::read_from(message.get_root_as_reader()?)?;
+        assert_eq!(presence.level, Some(8));
+        assert_eq!(presence.shape, None);
+        let params = rust_style::settings::Settings::try_from(
+            message.get_root_as_reader::<rust_style::settings::Reader<'_>>()?,
+        )?;
+        assert_eq!(params._level, 8);
+        Ok(())
+    }
+
+    // Only needs to typecheck: pipelined getters follow the accessor style too.
+    #[allow(unused)]
+    fn accessor_style_pipelines(
+        get_style: crate::capnp_get::test_accessor_style_capnp::gadget::Pipeline,
+        rust_style: crate::rust_naming::test_accessor_style_capnp::gadget::Pipeline,
+    ) {
+        let _ = get_style.get_inner().get_size();
+        let _ = get_style.get_maker();
+        let _ = rust_style.inner().size();
+        let _ = rust_style.maker();
+    }
+
+    #[test]
+    fn upgrade_into() -> capnp::Result<()> {
+        use crate::test_upgrade_v1_capnp as v1;
+        use crate::test_upgrade_v2_capnp as v2;
+
+        let mut message = message::Builder::new_default();
+        let mut shape = message.init_root::<v1::shape::Builder<'_>>();
+        shape.set_name("triangle".into());
+        shape.set_sides(250);
+        shape.set_scale(1.5);
+        shape.set_label("old label".into());
+        shape.set_depth(7);
+        {
+            let mut center = shape.reborrow().init_center();
+            center.set_x(-3);
+            center.set_y(4);
+        }
+        {
+            let mut corners = shape.reborrow().init_corners(2);
+            corners.reborrow().get(0).set_x(i16::MIN);
+            corners.reborrow().get(1).set_y(i16::MAX);
+        }
+        {
+            let mut weights = shape.reborrow().init_weights(2);
+            weights.set(0, 1);
+            weights.set(1, u16::MAX);
+        }
+        shape.reborrow().init_tags(1).set(0, "pointy".into());
+        {
+            let mut style = shape.reborrow().get_style();
+            style.set_color(v2::Color::Green);
+            style.set_thick(true);
+        }
+        shape.reborrow().init_origin().set_x(9);
+
+        let mut upgraded = message::Builder::new_default();
+        let mut target = upgraded.init_root::<v2::shape::Builder<'_>>();
+        shape.into_reader().upgrade_into(target.reborrow())?;
+        let target = target.into_reader();
+
+        assert_eq!(target.get_name()?, "triangle");
+        assert_eq!(target.get_sides(), 250);
+        assert_eq!(target.get_scale(), 1.5);
+        assert_eq!(target.get_center()?.get_x(), -3);
+        assert_eq!(target.get_center()?.get_y(), 4);
+        assert_eq!(target.get_center()?.get_z(), 0);
+        let corners = target.get_corners()?;
+        assert_eq!(corners.len(), 2);
+        assert_eq!(corners.get(0).get_x(), i32::from(i16::MIN));
+        assert_eq!(corners.get(1).get_y(), i32::from(i16::MAX));
+        let weights = target.get_weights()?;
+        assert_eq!(weights.len(), 2);
+        assert_eq!(weights.get(1), u64::from(u16::MAX));
+        assert_eq!(target.get_tags()?.get(0)?, "pointy");
+        assert_eq!(target.get_style().get_color()?, v2::Color::Green);
+        assert!(target.get_style().get_thick());
+
+        // Renamed and narrowed fields are not copied.
+        assert!(!target.has_title());
+        assert_eq!(target.get_depth(), 0);
+        assert!(!target.get_style().has_pattern());
+
+        match target.which()? {
+            v2::shape::Origin(origin) => assert_eq!(origin?.get_x(), 9),
+            _ => panic!("expected origin"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn upgrade_into_union_primitive() -> capnp::Result<()> {
+        use crate::test_upgrade_v1_capnp as v1;
+        use crate::test_upgrade_v2_capnp as v2;
+
+        let mut message = message::Builder::new_default();
+        let mut shape = message.init_root::<v1::shape::Builder<'_>>();
+        shape.set_radius(u32::MAX);
+
+        let mut upgraded = message::Builder::new_default();
+        let mut target = upgraded.init_root::<v2::shape::Builder<'_>>();
+        shape.into_reader().upgrade_into(target.reborrow())?;
+        match target.into_reader().which()? {
+            v2::shape::Radius(radius) => assert_eq!(radius, u64::from(u32::MAX)),
+            _ => panic!("expected radius"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn serialized_words() -> capnp::Result<()> {
+        use crate::test_capnp::test_all_types;
+        use capnp::serialize;
+
+        let mut message = message::Builder::new_default();
+        crate::test_util::init_test_message(message.init_root::<test_all_types::Builder<'_>>());
+        let root = message.get_root::<test_all_types::Builder<'_>>()?;
+        let words = root.serialized_words()?;
+        assert_eq!(words, root.into_reader().serialized_words()?);
+        assert_eq!(words * 8, serialize::write_message_to_words(&message).len());
+
+        let reader = message.get_root_as_reader::<test_all_types::Reader<'_>>()?;
+        let mut copy =
+            message::Builder::new(message::HeapAllocator::new().first_segment_words(words as u32));
+        copy.set_root(reader)?;
+        assert_eq!(copy.get_segments_for_output().len(), 1);
+        assert_eq!(words * 8, serialize::write_message_to_words(&copy).len());
+        assert_eq!(words, serialize::compute_serialized_size_in_words(&copy));
+        Ok(())
+    }
+
+    #[test]
+    fn skip_annotations_only_file() {
+        use crate::test_import_annotations_capnp::{account, Tier};
+
+        let skipped = concat!(
+            env!("OUT_DIR"),
+            "/skip-files/test_annotations_only_capnp.rs"
+        );
+        assert!(!std::path::Path::new(skipped).exists());
+
+        let mut message = message::Builder::new_default();
+        let mut root = message.init_root::<account::Builder<'_>>();
+        root.set_name("alice".into());
+        root.set_tier(Tier::Paid);
+        assert_eq!(root.into_reader().get_tier().unwrap(), Tier::Paid);
+
+        // The annotations are still readable, by the IDs declared in the skipped file.
+        const WEIGHT_ID: u64 = 0xc5b93e07a6d412f1;
+        let weight = account::field_annotation(2, WEIGHT_ID).unwrap();
+        assert_eq!(3u16, weight.downcast::<u16>());
+        let weight = Tier::enumerant_annotation(1, WEIGHT_ID).unwrap();
+        assert_eq!(10u16, weight.downcast::<u16>());
+    }
+
     // At one point, the lifetimes in the generated code made the following function
     // fail to typecheck.
     //#[allow(unused)]